@@ -0,0 +1,256 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Account Rescue Module
+//!
+//! Recovers the funds sent by mistake to the default EVM-padded account of an EVM address,
+//! i.e. `evm:` + the address + zero padding, which is unreachable once the EVM address is bound to
+//! another account. The owner of the EVM address signs an EIP-712 witness of the padded account
+//! and the new destination, and the rescue origin sweeps all currencies of the padded account to
+//! the destination, minus a processing fee to the treasury.
+//!
+//! Only padded accounts which have never sent a transaction, i.e. the nonce is zero, can be
+//! rescued. Addresses encoded with a wrong SS58 prefix are the same account and don't need a
+//! rescue.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
+use frame_system::pallet_prelude::*;
+use module_evm_utility_macro::keccak256;
+use orml_traits::{currency::TransferAll, MultiCurrency};
+use primitives::{evm::EvmAddress, to_bytes, Balance, CurrencyId};
+use sp_core::{H160, H256};
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_runtime::traits::{StaticLookup, Zero};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The EIP-712 signature of the owner of the EVM address.
+pub type Witness = [u8; 65];
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currencies to charge the processing fee.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// Sweeps all currencies of the rescued account.
+		type TransferAll: TransferAll<Self::AccountId>;
+
+		/// The native currency id, in which the processing fee is charged.
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
+		/// The processing fee of a rescue, charged from the native currency of the rescued
+		/// account. If the account holds less, all of its native currency is charged.
+		#[pallet::constant]
+		type ProcessingFee: Get<Balance>;
+
+		/// The account receiving the processing fee.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The chain id signed in the witness.
+		#[pallet::constant]
+		type ChainId: Get<u64>;
+
+		/// The origin which may rescue accounts.
+		type RescueOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not a default EVM-padded account.
+		NotRescuableAccount,
+		/// The account has sent transactions, it's not unreachable.
+		AccountHasHistory,
+		/// The witness is not signed by the EVM address of the account.
+		InvalidWitness,
+		/// The destination is the rescued account.
+		InvalidDestination,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// All currencies of the account are swept to the destination.
+		AccountRescued {
+			source: T::AccountId,
+			dest: T::AccountId,
+			evm_address: EvmAddress,
+			/// The native currency swept to the destination, after the processing fee.
+			native_amount: Balance,
+			/// The processing fee transferred to the treasury.
+			fee: Balance,
+		},
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Sweep all currencies of the default EVM-padded account `source` to `dest`, minus the
+		/// processing fee.
+		///
+		/// The dispatch origin of this call must be `RescueOrigin`.
+		///
+		/// - `source`: the default EVM-padded account which has never sent a transaction.
+		/// - `dest`: the new destination.
+		/// - `witness`: the EIP-712 signature of `source` and `dest` by the EVM address of
+		///   `source`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::rescue_account())]
+		pub fn rescue_account(
+			origin: OriginFor<T>,
+			source: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			witness: Witness,
+		) -> DispatchResult {
+			T::RescueOrigin::ensure_origin(origin)?;
+			let source = T::Lookup::lookup(source)?;
+			let dest = T::Lookup::lookup(dest)?;
+			ensure!(source != dest, Error::<T>::InvalidDestination);
+
+			let evm_address = Self::padded_evm_address(&source).ok_or(Error::<T>::NotRescuableAccount)?;
+			ensure!(
+				frame_system::Pallet::<T>::account_nonce(&source).is_zero(),
+				Error::<T>::AccountHasHistory
+			);
+			ensure!(
+				Self::verify_witness(&source, &dest, &witness) == Some(evm_address),
+				Error::<T>::InvalidWitness
+			);
+
+			let native_currency_id = T::GetNativeCurrencyId::get();
+			let fee = T::Currency::free_balance(native_currency_id, &source).min(T::ProcessingFee::get());
+			T::Currency::transfer(
+				native_currency_id,
+				&source,
+				&T::TreasuryAccount::get(),
+				fee,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			let native_amount = T::Currency::free_balance(native_currency_id, &source);
+			T::TransferAll::transfer_all(&source, &dest)?;
+
+			Self::deposit_event(Event::AccountRescued {
+				source,
+				dest,
+				evm_address,
+				native_amount,
+				fee,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Returns an Ethereum address derived from an Ethereum secret key.
+	// Only for tests
+	pub fn eth_address(secret: &libsecp256k1::SecretKey) -> EvmAddress {
+		EvmAddress::from_slice(&keccak_256(&libsecp256k1::PublicKey::from_secret_key(secret).serialize()[1..65])[12..])
+	}
+
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Returns the default EVM-padded account of the EVM address.
+	// Only for tests
+	pub fn padded_account(evm_address: &EvmAddress) -> T::AccountId {
+		let mut data = [0u8; 32];
+		data[0..4].copy_from_slice(b"evm:");
+		data[4..24].copy_from_slice(&evm_address[..]);
+		T::AccountId::decode(&mut &data[..]).expect("account id is 32 bytes; qed")
+	}
+
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Signs the witness of the rescue.
+	pub fn eth_sign(secret: &libsecp256k1::SecretKey, source: &T::AccountId, dest: &T::AccountId) -> Witness {
+		let msg = keccak_256(&Self::eip712_signable_message(source, dest));
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+		let mut r = [0u8; 65];
+		r[0..64].copy_from_slice(&sig.serialize()[..]);
+		r[64] = recovery_id.serialize();
+		r
+	}
+
+	/// The EVM address of the default EVM-padded account, `None` if the account doesn't match the
+	/// pattern `evm:` + the address + 8 bytes of zeros.
+	pub fn padded_evm_address(who: &T::AccountId) -> Option<EvmAddress> {
+		let data = who.encode();
+		if data.len() == 32 && data.starts_with(b"evm:") && data.ends_with(&[0u8; 8]) {
+			Some(EvmAddress::from_slice(&data[4..24]))
+		} else {
+			None
+		}
+	}
+
+	fn verify_witness(source: &T::AccountId, dest: &T::AccountId, witness: &Witness) -> Option<EvmAddress> {
+		let msg_hash = keccak_256(&Self::eip712_signable_message(source, dest));
+		secp256k1_ecdsa_recover(witness, &msg_hash)
+			.map(|pubkey| H160::from(H256::from_slice(&keccak_256(&pubkey))))
+			.ok()
+	}
+
+	// Eip-712 message to be signed
+	fn eip712_signable_message(source: &T::AccountId, dest: &T::AccountId) -> Vec<u8> {
+		let mut msg = b"\x19\x01".to_vec();
+		msg.extend_from_slice(&Self::rescue_domain_separator());
+		msg.extend_from_slice(&Self::rescue_payload_hash(source, dest));
+		msg
+	}
+
+	fn rescue_payload_hash(source: &T::AccountId, dest: &T::AccountId) -> [u8; 32] {
+		let tx_type_hash = keccak256!("Rescue(bytes source,bytes dest)");
+		let mut tx_msg = tx_type_hash.to_vec();
+		tx_msg.extend_from_slice(&keccak_256(&source.encode()));
+		tx_msg.extend_from_slice(&keccak_256(&dest.encode()));
+		keccak_256(tx_msg.as_slice())
+	}
+
+	fn rescue_domain_separator() -> [u8; 32] {
+		let domain_hash = keccak256!("EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)");
+		let mut domain_separator_msg = domain_hash.to_vec();
+		domain_separator_msg.extend_from_slice(keccak256!("Acala account rescue")); // name
+		domain_separator_msg.extend_from_slice(keccak256!("1")); // version
+		domain_separator_msg.extend_from_slice(&to_bytes(T::ChainId::get())); // chain id
+		domain_separator_msg
+			.extend_from_slice(frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()).as_ref()); // genesis block hash
+		keccak_256(domain_separator_msg.as_slice())
+	}
+}
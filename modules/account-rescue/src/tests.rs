@@ -0,0 +1,174 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the account rescue module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn padded_evm_address_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AccountRescueModule::eth_address(&owner_secret_key());
+		assert_eq!(
+			AccountRescueModule::padded_evm_address(&padded_account_id()),
+			Some(evm_address)
+		);
+		assert_eq!(AccountRescueModule::padded_evm_address(&BOB), None);
+
+		// the padding must be zeros
+		let mut data = [0u8; 32];
+		data[0..4].copy_from_slice(b"evm:");
+		data[31] = 1;
+		assert_eq!(AccountRescueModule::padded_evm_address(&AccountId::new(data)), None);
+	});
+}
+
+#[test]
+fn rescue_account_sweeps_all_currencies() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source = padded_account_id();
+		let witness = AccountRescueModule::eth_sign(&owner_secret_key(), &source, &BOB);
+
+		assert_ok!(AccountRescueModule::rescue_account(
+			RuntimeOrigin::signed(ALICE),
+			source.clone(),
+			BOB,
+			witness
+		));
+		System::assert_last_event(RuntimeEvent::AccountRescueModule(crate::Event::AccountRescued {
+			source: source.clone(),
+			dest: BOB,
+			evm_address: AccountRescueModule::eth_address(&owner_secret_key()),
+			native_amount: 990,
+			fee: 10,
+		}));
+
+		assert_eq!(Currencies::free_balance(ACA, &source), 0);
+		assert_eq!(Currencies::free_balance(DOT, &source), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &source), 0);
+		assert_eq!(Currencies::free_balance(ACA, &TREASURY), 10);
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 1990);
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 500);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 300);
+	});
+}
+
+#[test]
+fn processing_fee_is_capped_by_native_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Another")).unwrap();
+		let source = AccountRescueModule::padded_account(&AccountRescueModule::eth_address(&secret_key));
+		assert_ok!(Currencies::deposit(DOT, &source, 100));
+		let witness = AccountRescueModule::eth_sign(&secret_key, &source, &BOB);
+
+		assert_ok!(AccountRescueModule::rescue_account(
+			RuntimeOrigin::signed(ALICE),
+			source.clone(),
+			BOB,
+			witness
+		));
+		System::assert_last_event(
+			crate::Event::<Runtime>::AccountRescued {
+				source: source.clone(),
+				dest: BOB,
+				evm_address: AccountRescueModule::eth_address(&secret_key),
+				native_amount: 0,
+				fee: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Currencies::free_balance(DOT, &source), 0);
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 100);
+		assert_eq!(Currencies::free_balance(ACA, &TREASURY), 0);
+	});
+}
+
+#[test]
+fn rescue_account_requires_rescue_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source = padded_account_id();
+		let witness = AccountRescueModule::eth_sign(&owner_secret_key(), &source, &BOB);
+
+		assert_noop!(
+			AccountRescueModule::rescue_account(RuntimeOrigin::signed(BOB), source, BOB, witness),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn rescue_account_rejects_unmatched_patterns() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source = padded_account_id();
+
+		// not a padded account
+		let witness = AccountRescueModule::eth_sign(&owner_secret_key(), &BOB, &ALICE);
+		assert_noop!(
+			AccountRescueModule::rescue_account(RuntimeOrigin::signed(ALICE), BOB, ALICE, witness),
+			Error::<Runtime>::NotRescuableAccount
+		);
+
+		// the destination is the source
+		let witness = AccountRescueModule::eth_sign(&owner_secret_key(), &source, &source);
+		assert_noop!(
+			AccountRescueModule::rescue_account(RuntimeOrigin::signed(ALICE), source.clone(), source.clone(), witness),
+			Error::<Runtime>::InvalidDestination
+		);
+
+		// the account has sent transactions
+		System::inc_account_nonce(&source);
+		let witness = AccountRescueModule::eth_sign(&owner_secret_key(), &source, &BOB);
+		assert_noop!(
+			AccountRescueModule::rescue_account(RuntimeOrigin::signed(ALICE), source, BOB, witness),
+			Error::<Runtime>::AccountHasHistory
+		);
+	});
+}
+
+#[test]
+fn rescue_account_verifies_witness() {
+	ExtBuilder::default().build().execute_with(|| {
+		let source = padded_account_id();
+
+		// signed by another key
+		let other_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Another")).unwrap();
+		let witness = AccountRescueModule::eth_sign(&other_key, &source, &BOB);
+		assert_noop!(
+			AccountRescueModule::rescue_account(RuntimeOrigin::signed(ALICE), source.clone(), BOB, witness),
+			Error::<Runtime>::InvalidWitness
+		);
+
+		// signed for another destination
+		let witness = AccountRescueModule::eth_sign(&owner_secret_key(), &source, &TREASURY);
+		assert_noop!(
+			AccountRescueModule::rescue_account(RuntimeOrigin::signed(ALICE), source.clone(), BOB, witness),
+			Error::<Runtime>::InvalidWitness
+		);
+
+		// malformed signature
+		assert_noop!(
+			AccountRescueModule::rescue_account(RuntimeOrigin::signed(ALICE), source, BOB, [0u8; 65]),
+			Error::<Runtime>::InvalidWitness
+		);
+	});
+}
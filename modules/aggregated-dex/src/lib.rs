@@ -22,12 +22,16 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::type_complexity)]
 
-use frame_support::{pallet_prelude::*, transactional};
+use frame_support::{pallet_prelude::*, traits::ReservableCurrency, transactional};
 use frame_system::pallet_prelude::*;
-use module_support::{AggregatedSwapPath, DEXManager, RebasedStableAssetError, Swap, SwapLimit};
+use module_support::{AggregatedSwapPath, DEXManager, Ratio, RebasedStableAssetError, Swap, SwapLimit};
 use nutsfinance_stable_asset::traits::StableAsset as StableAssetT;
 use primitives::{Balance, CurrencyId};
-use sp_runtime::traits::{Convert, Zero};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Convert, One, Saturating, Zero},
+	FixedPointNumber, RuntimeDebug,
+};
 use sp_std::{marker::PhantomData, vec::Vec};
 
 mod mock;
@@ -39,12 +43,44 @@ pub use weights::WeightInfo;
 
 pub type SwapPath = AggregatedSwapPath<CurrencyId>;
 
+/// The number of the hops of an aggregated swap by DEX and Taiga.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SwapRouteSummary {
+	/// The number of the DEX trading pairs swapped through.
+	pub dex_hops: u32,
+	/// The number of the Taiga pools swapped through.
+	pub taiga_hops: u32,
+}
+
+/// An aggregated swap recorded to the swap history of an account, the amounts are end-to-end.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SwapRecord<BlockNumber> {
+	/// The block which the swap executed at.
+	pub block_number: BlockNumber,
+	/// The route of the swap.
+	pub route: SwapRouteSummary,
+	/// The supply currency.
+	pub supply_currency_id: CurrencyId,
+	/// The actual supply amount.
+	pub supply_amount: Balance,
+	/// The target currency.
+	pub target_currency_id: CurrencyId,
+	/// The actual target amount.
+	pub target_amount: Balance,
+	/// The exchange fees of all the hops, in the supply currency.
+	pub fee: Balance,
+}
+
+pub type SwapRecordOf<T> = SwapRecord<BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// DEX
 		type DEX: DEXManager<Self::AccountId, Balance, CurrencyId>;
 
@@ -68,6 +104,26 @@ pub mod module {
 		#[pallet::constant]
 		type SwapPathLimit: Get<u32>;
 
+		/// The exchange fee of DEX, the same as `GetExchangeFee` of `module_dex`.
+		#[pallet::constant]
+		type DEXExchangeFee: Get<(u32, u32)>;
+
+		/// The precision of the swap fee of Taiga pools.
+		#[pallet::constant]
+		type StableAssetFeePrecision: Get<Balance>;
+
+		/// Currency to reserve the deposit of the swap history.
+		type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+		/// The deposit reserved per entry of the swap history when enabling it.
+		#[pallet::constant]
+		type DepositPerSwapRecord: Get<Balance>;
+
+		/// The maximum number of the swaps kept in the swap history of an account, the oldest
+		/// swap is dropped when exceeded.
+		#[pallet::constant]
+		type MaxSwapHistory: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -81,6 +137,19 @@ pub mod module {
 		InvalidTokenIndex,
 		/// The SwapPath is invalid.
 		InvalidSwapPath,
+		/// The swap history is already enabled.
+		SwapHistoryAlreadyEnabled,
+		/// The swap history is not enabled.
+		SwapHistoryNotEnabled,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The swap history of `who` is enabled, and `deposit` is reserved.
+		SwapHistoryEnabled { who: T::AccountId, deposit: Balance },
+		/// The swap history of `who` is disabled and purged, and `deposit` is unreserved.
+		SwapHistoryDisabled { who: T::AccountId, deposit: Balance },
 	}
 
 	/// The specific swap paths for  AggregatedSwap do aggreated_swap to swap TokenA to TokenB
@@ -91,7 +160,24 @@ pub mod module {
 	pub type AggregatedSwapPaths<T: Config> =
 		StorageMap<_, Twox64Concat, (CurrencyId, CurrencyId), BoundedVec<SwapPath, T::SwapPathLimit>, OptionQuery>;
 
+	/// The reserved deposit and the recent aggregated swaps, oldest first, of the accounts which
+	/// enabled the swap history.
+	///
+	/// SwapHistories: Map: AccountId => Option<(deposit: Balance, records: Vec<SwapRecord>)>
+	#[pallet::storage]
+	#[pallet::getter(fn swap_histories)]
+	pub type SwapHistories<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		(Balance, BoundedVec<SwapRecordOf<T>, T::MaxSwapHistory>),
+		OptionQuery,
+	>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -121,7 +207,9 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 			let paths: BoundedVec<SwapPath, T::SwapPathLimit> =
 				paths.try_into().map_err(|_| Error::<T>::InvalidSwapPath)?;
-			let _ = Self::do_aggregated_swap(&who, &paths, SwapLimit::ExactSupply(supply_amount, min_target_amount))?;
+			let swapped =
+				Self::do_aggregated_swap(&who, &paths, SwapLimit::ExactSupply(supply_amount, min_target_amount))?;
+			Self::note_swap(&who, &paths, swapped);
 			Ok(())
 		}
 
@@ -141,7 +229,9 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 			let paths: BoundedVec<SwapPath, T::SwapPathLimit> =
 				paths.try_into().map_err(|_| Error::<T>::InvalidSwapPath)?;
-			let _ = Self::do_aggregated_swap(&who, &paths, SwapLimit::ExactTarget(max_supply_amount, target_amount))?;
+			let swapped =
+				Self::do_aggregated_swap(&who, &paths, SwapLimit::ExactTarget(max_supply_amount, target_amount))?;
+			Self::note_swap(&who, &paths, swapped);
 			Ok(())
 		}
 
@@ -176,10 +266,115 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Enable the swap history of the origin, which records the recent aggregated swaps by
+		/// `swap_with_exact_supply` and `swap_with_exact_target`.
+		///
+		/// Reserves `DepositPerSwapRecord` for each of the `MaxSwapHistory` entries.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::enable_swap_history())]
+		pub fn enable_swap_history(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!SwapHistories::<T>::contains_key(&who),
+				Error::<T>::SwapHistoryAlreadyEnabled
+			);
+
+			let deposit = T::DepositPerSwapRecord::get().saturating_mul(T::MaxSwapHistory::get().into());
+			T::Currency::reserve(&who, deposit)?;
+			SwapHistories::<T>::insert(&who, (deposit, BoundedVec::default()));
+
+			Self::deposit_event(Event::SwapHistoryEnabled { who, deposit });
+			Ok(())
+		}
+
+		/// Disable the swap history of the origin, purge the recorded swaps and unreserve the
+		/// deposit.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::disable_swap_history())]
+		pub fn disable_swap_history(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (deposit, _) = SwapHistories::<T>::take(&who).ok_or(Error::<T>::SwapHistoryNotEnabled)?;
+
+			T::Currency::unreserve(&who, deposit);
+
+			Self::deposit_event(Event::SwapHistoryDisabled { who, deposit });
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The recent aggregated swaps of `who`, oldest first, empty if the swap history is not
+	/// enabled.
+	pub fn get_swap_history(who: &T::AccountId) -> Vec<SwapRecordOf<T>> {
+		SwapHistories::<T>::get(who)
+			.map(|(_, records)| records.into_inner())
+			.unwrap_or_default()
+	}
+
+	/// The hops of `paths`, and the exchange fees of them in the supply currency for
+	/// `supply_amount`. The fee rates of the hops compound as each hop swaps the output of the
+	/// previous one.
+	fn swap_route_and_fee(paths: &[SwapPath], supply_amount: Balance) -> (SwapRouteSummary, Balance) {
+		let (fee_numerator, fee_denominator) = T::DEXExchangeFee::get();
+		let dex_retained = Ratio::one().saturating_sub(Ratio::saturating_from_rational(fee_numerator, fee_denominator));
+
+		let mut route = SwapRouteSummary {
+			dex_hops: 0,
+			taiga_hops: 0,
+		};
+		let mut retained = Ratio::one();
+		for path in paths {
+			match path {
+				SwapPath::Dex(dex_path) => {
+					let hops = dex_path.len().saturating_sub(1) as u32;
+					route.dex_hops = route.dex_hops.saturating_add(hops);
+					retained = retained.saturating_mul(dex_retained.saturating_pow(hops as usize));
+				}
+				SwapPath::Taiga(pool_id, _, _) => {
+					route.taiga_hops = route.taiga_hops.saturating_add(1);
+					if let Some(pool_info) = T::StableAsset::pool(*pool_id) {
+						let fee_rate =
+							Ratio::saturating_from_rational(pool_info.swap_fee, T::StableAssetFeePrecision::get());
+						retained = retained.saturating_mul(Ratio::one().saturating_sub(fee_rate));
+					}
+				}
+			}
+		}
+
+		let fee = supply_amount.saturating_sub(retained.saturating_mul_int(supply_amount));
+		(route, fee)
+	}
+
+	/// Record the aggregated swap of `who` by `paths` to the swap history if `who` enabled it.
+	/// The oldest swap is dropped if `MaxSwapHistory` is exceeded.
+	fn note_swap(who: &T::AccountId, paths: &[SwapPath], (supply_amount, target_amount): (Balance, Balance)) {
+		let Some((deposit, mut records)) = SwapHistories::<T>::get(who) else {
+			return;
+		};
+		// the paths have been checked by the swap.
+		let Ok((supply_currency_id, target_currency_id)) = Self::check_swap_paths(paths) else {
+			return;
+		};
+		let (route, fee) = Self::swap_route_and_fee(paths, supply_amount);
+
+		if records.is_full() {
+			records.remove(0);
+		}
+		// a slot is released above if it's full.
+		let _ = records.try_push(SwapRecord {
+			block_number: frame_system::Pallet::<T>::block_number(),
+			route,
+			supply_currency_id,
+			supply_amount,
+			target_currency_id,
+			target_amount,
+			fee,
+		});
+		SwapHistories::<T>::insert(who, (deposit, records));
+	}
+
 	fn check_swap_paths(paths: &[SwapPath]) -> sp_std::result::Result<(CurrencyId, CurrencyId), DispatchError> {
 		ensure!(!paths.is_empty(), Error::<T>::InvalidSwapPath);
 		let mut supply_currency_id: Option<CurrencyId> = None;
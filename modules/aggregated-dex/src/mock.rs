@@ -27,11 +27,12 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
+use module_support::mocks::MockAddressMapping;
 pub use module_support::{ExchangeRate, RebasedStableAsset};
 use orml_tokens::ConvertBalance;
 pub use orml_traits::{parameter_type_with_key, MultiCurrency};
-use primitives::{Amount, TokenSymbol, TradingPair};
-use sp_runtime::{traits::IdentityLookup, AccountId32, ArithmeticError, BuildStorage, FixedPointNumber};
+use primitives::{Amount, ReserveIdentifier, TokenSymbol, TradingPair};
+use sp_runtime::{traits::IdentityLookup, AccountId32, ArithmeticError, BuildStorage, FixedPointNumber, Percent};
 
 pub type AccountId = AccountId32;
 
@@ -75,6 +76,22 @@ impl orml_tokens::Config for Runtime {
 	type DustRemovalWhitelist = Nothing;
 }
 
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
 ord_parameter_types! {
 	pub const Admin: AccountId = BOB;
 }
@@ -84,6 +101,8 @@ parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const GetNativeCurrencyId: CurrencyId = ACA;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![];
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
+	pub const TreasuryAccount: AccountId = AccountId32::new([10u8; 32]);
 }
 
 impl module_dex::Config for Runtime {
@@ -99,6 +118,18 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<Admin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type OnTradingPairEnabled = ();
+	type OnTradingPairDisabled = ();
+	type EvmAddressMapping = MockAddressMapping;
+	type FlashSwapEvmBridge = ();
+	type GasToWeight = ();
+	type TreasuryAccount = TreasuryAccount;
+	type MinSwapCommitDeposit = ConstU128<100>;
+	type SwapRevealPeriod = ConstU64<10>;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = ConstU32<2>;
+	type LbpCheckpointPeriod = ConstU64<10>;
+	type MaxLbpPools = ConstU32<2>;
 }
 
 pub struct EnsurePoolAssetId;
@@ -174,11 +205,17 @@ parameter_types! {
 }
 
 impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type DEX = Dex;
 	type StableAsset = StableAssetWrapper;
 	type GovernanceOrigin = EnsureSignedBy<Admin, AccountId>;
 	type DexSwapJointList = DexSwapJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type DEXExchangeFee = GetExchangeFee;
+	type StableAssetFeePrecision = ConstU128<10_000_000_000>;
+	type Currency = PalletBalances;
+	type DepositPerSwapRecord = ConstU128<10>;
+	type MaxSwapHistory = ConstU32<3>;
 	type WeightInfo = ();
 }
 
@@ -193,6 +230,7 @@ frame_support::construct_runtime!(
 		AggregatedDex: aggregated_dex,
 		Dex: module_dex,
 		Tokens: orml_tokens,
+		PalletBalances: pallet_balances,
 		StableAsset: nutsfinance_stable_asset,
 	}
 );
@@ -226,6 +264,12 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(ALICE, 1_000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		t.into()
 	}
 }
@@ -1301,3 +1301,214 @@ fn aggregated_swap_swap_work() {
 		);
 	});
 }
+
+#[test]
+fn enable_and_disable_swap_history_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			AggregatedDex::enable_swap_history(RuntimeOrigin::signed(BOB)),
+			pallet_balances::Error::<Runtime>::InsufficientBalance
+		);
+		assert_noop!(
+			AggregatedDex::disable_swap_history(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::SwapHistoryNotEnabled
+		);
+
+		assert_ok!(AggregatedDex::enable_swap_history(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::AggregatedDex(crate::Event::SwapHistoryEnabled {
+			who: ALICE,
+			deposit: 30,
+		}));
+		assert_eq!(PalletBalances::reserved_balance(&ALICE), 30);
+		assert_eq!(PalletBalances::free_balance(&ALICE), 970);
+		assert_eq!(AggregatedDex::swap_histories(&ALICE), Some((30, Default::default())));
+		assert_eq!(AggregatedDex::get_swap_history(&ALICE), vec![]);
+		assert_noop!(
+			AggregatedDex::enable_swap_history(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::SwapHistoryAlreadyEnabled
+		);
+
+		assert_ok!(inject_liquidity(DOT, AUSD, 100_000_000_000u128, 100_000_000_000u128));
+		assert_ok!(AggregatedDex::swap_with_exact_supply(
+			RuntimeOrigin::signed(ALICE),
+			vec![SwapPath::Dex(vec![DOT, AUSD])],
+			1_000_000,
+			0
+		));
+		assert_eq!(AggregatedDex::get_swap_history(&ALICE).len(), 1);
+
+		assert_ok!(AggregatedDex::disable_swap_history(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::AggregatedDex(crate::Event::SwapHistoryDisabled {
+			who: ALICE,
+			deposit: 30,
+		}));
+		assert_eq!(PalletBalances::reserved_balance(&ALICE), 0);
+		assert_eq!(PalletBalances::free_balance(&ALICE), 1_000);
+		assert_eq!(AggregatedDex::swap_histories(&ALICE), None);
+		assert_eq!(AggregatedDex::get_swap_history(&ALICE), vec![]);
+
+		// the swaps are not recorded after disabled.
+		assert_ok!(AggregatedDex::swap_with_exact_supply(
+			RuntimeOrigin::signed(ALICE),
+			vec![SwapPath::Dex(vec![DOT, AUSD])],
+			1_000_000,
+			0
+		));
+		assert_eq!(AggregatedDex::swap_histories(&ALICE), None);
+	});
+}
+
+#[test]
+fn swap_history_drops_oldest_swap() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(inject_liquidity(DOT, AUSD, 100_000_000_000u128, 100_000_000_000u128));
+		assert_ok!(AggregatedDex::enable_swap_history(RuntimeOrigin::signed(ALICE)));
+
+		for i in 1..=4u128 {
+			System::set_block_number(i as u64);
+			assert_ok!(AggregatedDex::swap_with_exact_supply(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD])],
+				i * 1_000_000,
+				0
+			));
+		}
+
+		let history = AggregatedDex::get_swap_history(&ALICE);
+		assert_eq!(history.len(), 3);
+		assert_eq!(
+			history
+				.iter()
+				.map(|record| (record.block_number, record.supply_amount))
+				.collect::<Vec<_>>(),
+			vec![(2, 2_000_000), (3, 3_000_000), (4, 4_000_000)]
+		);
+
+		// the swaps of the accounts without swap history are not recorded.
+		assert_ok!(AggregatedDex::swap_with_exact_supply(
+			RuntimeOrigin::signed(BOB),
+			vec![SwapPath::Dex(vec![DOT, AUSD])],
+			1_000_000,
+			0
+		));
+		assert_eq!(AggregatedDex::swap_histories(&BOB), None);
+	});
+}
+
+#[test]
+fn swap_history_records_end_to_end_amounts_of_aggregated_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(initial_taiga_dot_ldot_pool());
+		assert_ok!(inject_liquidity(
+			LDOT,
+			AUSD,
+			100_000_000_000u128,
+			20_000_000_000_000u128
+		));
+		assert_ok!(AggregatedDex::enable_swap_history(RuntimeOrigin::signed(ALICE)));
+
+		let paths = vec![SwapPath::Taiga(0, 0, 1), SwapPath::Dex(vec![LDOT, AUSD])];
+		let route = SwapRouteSummary {
+			dex_hops: 1,
+			taiga_hops: 1,
+		};
+
+		let (supply_amount, target_amount) =
+			AggregatedDex::get_aggregated_swap_amount(&paths, SwapLimit::ExactSupply(1_000_000_000u128, 0)).unwrap();
+		assert_ok!(AggregatedDex::swap_with_exact_supply(
+			RuntimeOrigin::signed(ALICE),
+			paths.clone(),
+			1_000_000_000u128,
+			0
+		));
+		assert_eq!(Tokens::free_balance(LDOT, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), target_amount);
+
+		let dot_before = Tokens::free_balance(DOT, &ALICE);
+		let ausd_before = Tokens::free_balance(AUSD, &ALICE);
+		assert_ok!(AggregatedDex::swap_with_exact_target(
+			RuntimeOrigin::signed(ALICE),
+			paths,
+			1_000_000_000_000u128,
+			1_000_000_000_000u128
+		));
+
+		// one record per aggregated swap, with the amounts of the supply and target currencies
+		// rather than the intermediate LDOT.
+		assert_eq!(
+			AggregatedDex::get_swap_history(&ALICE),
+			vec![
+				SwapRecord {
+					block_number: 1,
+					route,
+					supply_currency_id: DOT,
+					supply_amount,
+					target_currency_id: AUSD,
+					target_amount,
+					fee: 0,
+				},
+				SwapRecord {
+					block_number: 1,
+					route,
+					supply_currency_id: DOT,
+					supply_amount: dot_before - Tokens::free_balance(DOT, &ALICE),
+					target_currency_id: AUSD,
+					target_amount: Tokens::free_balance(AUSD, &ALICE) - ausd_before,
+					fee: 0,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn swap_route_and_fee_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			AggregatedDex::swap_route_and_fee(&[SwapPath::Dex(vec![DOT, LDOT, AUSD])], 1_000_000),
+			(
+				SwapRouteSummary {
+					dex_hops: 2,
+					taiga_hops: 0
+				},
+				0
+			)
+		);
+
+		StableAssetWrapper::create_pool(
+			STABLE_ASSET,
+			vec![DOT, LDOT],
+			vec![1u128, 1u128],
+			0,
+			// 1%
+			100_000_000u128,
+			0,
+			3000u128,
+			BOB,
+			BOB,
+			10_000_000_000u128,
+		)
+		.unwrap();
+		assert_eq!(
+			AggregatedDex::swap_route_and_fee(
+				&[
+					SwapPath::Taiga(0, 0, 1),
+					SwapPath::Taiga(0, 1, 0),
+					SwapPath::Dex(vec![DOT, AUSD])
+				],
+				1_000_000
+			),
+			(
+				SwapRouteSummary {
+					dex_hops: 1,
+					taiga_hops: 2
+				},
+				// 1_000_000 * (1 - 0.99 * 0.99)
+				19_900
+			)
+		);
+	});
+}
@@ -49,6 +49,8 @@ pub trait WeightInfo {
 	fn swap_with_exact_supply(u: u32, ) -> Weight;
 	fn swap_with_exact_target(u: u32, ) -> Weight;
 	fn update_aggregated_swap_paths(u: u32, ) -> Weight;
+	fn enable_swap_history() -> Weight;
+	fn disable_swap_history() -> Weight;
 }
 
 /// Weights for module_aggregated_dex using the Acala node and recommended hardware.
@@ -58,18 +60,18 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		Weight::from_parts(70_917_000, 0)
 			// Standard Error: 1_041_000
 			.saturating_add(Weight::from_parts(8_550_000, 0).saturating_mul(u as u64))
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		Weight::from_parts(58_717_000, 0)
 			// Standard Error: 147_000
 			.saturating_add(Weight::from_parts(15_650_000, 0).saturating_mul(u as u64))
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
 	}
 	fn update_aggregated_swap_paths(n: u32, ) -> Weight {
@@ -79,6 +81,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
+	fn enable_swap_history() -> Weight {
+		Weight::from_parts(27_405_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn disable_swap_history() -> Weight {
+		Weight::from_parts(31_862_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -87,18 +99,18 @@ impl WeightInfo for () {
 		Weight::from_parts(70_917_000, 0)
 			// Standard Error: 1_041_000
 			.saturating_add(Weight::from_parts(8_550_000, 0).saturating_mul(u as u64))
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
-			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		Weight::from_parts(58_717_000, 0)
 			// Standard Error: 147_000
 			.saturating_add(Weight::from_parts(15_650_000, 0).saturating_mul(u as u64))
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
-			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
 	}
 	fn update_aggregated_swap_paths(n: u32, ) -> Weight {
@@ -108,4 +120,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
+	fn enable_swap_history() -> Weight {
+		Weight::from_parts(27_405_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn disable_swap_history() -> Weight {
+		Weight::from_parts(31_862_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
 }
@@ -96,6 +96,8 @@ pub mod module {
 		AssetIdNotExists,
 		/// AssetId exists
 		AssetIdExisted,
+		/// The symbol is already used by another asset
+		DuplicateSymbol,
 	}
 
 	#[pallet::event]
@@ -123,6 +125,12 @@ pub mod module {
 			asset_id: AssetIds,
 			metadata: AssetMetadata<BalanceOf<T>>,
 		},
+		/// The canonical asset of the symbol changed.
+		CanonicalAssetChanged {
+			symbol: Vec<u8>,
+			previous: Option<AssetIds>,
+			asset_id: AssetIds,
+		},
 	}
 
 	/// Next available Foreign AssetId ID.
@@ -168,7 +176,17 @@ pub mod module {
 	pub type AssetMetadatas<T: Config> =
 		StorageMap<_, Twox64Concat, AssetIds, AssetMetadata<BalanceOf<T>>, OptionQuery>;
 
+	/// The canonical asset of the lowercase symbol, at most one asset per symbol.
+	///
+	/// CanonicalAssets: map Symbol => Option<AssetIds>
+	#[pallet::storage]
+	#[pallet::getter(fn canonical_assets)]
+	pub type CanonicalAssets<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, AssetIds, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -189,7 +207,8 @@ pub mod module {
 						symbol: asset.symbol().unwrap().as_bytes().to_vec(),
 						decimals: asset.decimals().unwrap(),
 						minimal_balance: *ed,
-					}
+					},
+					false,
 				));
 			});
 		}
@@ -203,11 +222,12 @@ pub mod module {
 			origin: OriginFor<T>,
 			location: Box<VersionedLocation>,
 			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+			allow_duplicate_symbol: bool,
 		) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
 			let location: Location = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
-			let foreign_asset_id = Self::do_register_foreign_asset(&location, &metadata)?;
+			let foreign_asset_id = Self::do_register_foreign_asset(&location, &metadata, allow_duplicate_symbol)?;
 
 			Self::deposit_event(Event::<T>::ForeignAssetRegistered {
 				asset_id: foreign_asset_id,
@@ -224,11 +244,12 @@ pub mod module {
 			foreign_asset_id: ForeignAssetId,
 			location: Box<VersionedLocation>,
 			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+			allow_duplicate_symbol: bool,
 		) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
 			let location: Location = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
-			Self::do_update_foreign_asset(foreign_asset_id, &location, &metadata)?;
+			Self::do_update_foreign_asset(foreign_asset_id, &location, &metadata, allow_duplicate_symbol)?;
 
 			Self::deposit_event(Event::<T>::ForeignAssetUpdated {
 				asset_id: foreign_asset_id,
@@ -243,10 +264,11 @@ pub mod module {
 		pub fn register_stable_asset(
 			origin: OriginFor<T>,
 			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+			allow_duplicate_symbol: bool,
 		) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
-			let stable_asset_id = Self::do_register_stable_asset(&metadata)?;
+			let stable_asset_id = Self::do_register_stable_asset(&metadata, allow_duplicate_symbol)?;
 
 			Self::deposit_event(Event::<T>::AssetRegistered {
 				asset_id: AssetIds::StableAssetId(stable_asset_id),
@@ -261,10 +283,11 @@ pub mod module {
 			origin: OriginFor<T>,
 			stable_asset_id: StableAssetPoolId,
 			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+			allow_duplicate_symbol: bool,
 		) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
-			Self::do_update_stable_asset(&stable_asset_id, &metadata)?;
+			Self::do_update_stable_asset(&stable_asset_id, &metadata, allow_duplicate_symbol)?;
 
 			Self::deposit_event(Event::<T>::AssetUpdated {
 				asset_id: AssetIds::StableAssetId(stable_asset_id),
@@ -279,10 +302,11 @@ pub mod module {
 			origin: OriginFor<T>,
 			contract: EvmAddress,
 			minimal_balance: BalanceOf<T>,
+			allow_duplicate_symbol: bool,
 		) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
-			let metadata = Self::do_register_erc20_asset(contract, minimal_balance)?;
+			let metadata = Self::do_register_erc20_asset(contract, minimal_balance, allow_duplicate_symbol)?;
 
 			Self::deposit_event(Event::<T>::AssetRegistered {
 				asset_id: AssetIds::Erc20(contract),
@@ -297,10 +321,11 @@ pub mod module {
 			origin: OriginFor<T>,
 			contract: EvmAddress,
 			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+			allow_duplicate_symbol: bool,
 		) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
-			Self::do_update_erc20_asset(contract, &metadata)?;
+			Self::do_update_erc20_asset(contract, &metadata, allow_duplicate_symbol)?;
 
 			Self::deposit_event(Event::<T>::AssetUpdated {
 				asset_id: AssetIds::Erc20(contract),
@@ -315,10 +340,11 @@ pub mod module {
 			origin: OriginFor<T>,
 			currency_id: CurrencyId,
 			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+			allow_duplicate_symbol: bool,
 		) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
-			Self::do_register_native_asset(currency_id, &metadata)?;
+			Self::do_register_native_asset(currency_id, &metadata, allow_duplicate_symbol)?;
 
 			Self::deposit_event(Event::<T>::AssetRegistered {
 				asset_id: AssetIds::NativeAssetId(currency_id),
@@ -333,10 +359,11 @@ pub mod module {
 			origin: OriginFor<T>,
 			currency_id: CurrencyId,
 			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+			allow_duplicate_symbol: bool,
 		) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
-			Self::do_update_native_asset(currency_id, &metadata)?;
+			Self::do_update_native_asset(currency_id, &metadata, allow_duplicate_symbol)?;
 
 			Self::deposit_event(Event::<T>::AssetUpdated {
 				asset_id: AssetIds::NativeAssetId(currency_id),
@@ -344,10 +371,121 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Mark the registered asset as the canonical asset of its symbol, the flag is moved from
+		/// the previous canonical asset.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_canonical_asset())]
+		pub fn set_canonical_asset(origin: OriginFor<T>, asset_id: AssetIds) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let metadata = AssetMetadatas::<T>::get(&asset_id).ok_or(Error::<T>::AssetIdNotExists)?;
+			let symbol = metadata.symbol.to_ascii_lowercase();
+			let previous = CanonicalAssets::<T>::mutate(&symbol, |canonical| canonical.replace(asset_id.clone()));
+
+			if previous.as_ref() != Some(&asset_id) {
+				Self::deposit_event(Event::<T>::CanonicalAssetChanged {
+					symbol,
+					previous,
+					asset_id,
+				});
+			}
+			Ok(())
+		}
 	}
 }
 
+/// Maximum number of assets returned by a single `all_assets` query.
+pub const MAX_ASSETS_PAGE_SIZE: u32 = 100;
+
 impl<T: Config> Pallet<T> {
+	/// Resolve the metadata of any kind of `CurrencyId`.
+	///
+	/// Token symbols are resolved from the primitives definitions, DexShare by composing its parts,
+	/// unregistered Erc20 through the EVM bridge, and the registry-backed variants from storage.
+	pub fn asset_metadata(currency_id: CurrencyId) -> Option<AssetMetadata<BalanceOf<T>>> {
+		match currency_id {
+			CurrencyId::Token(_) => {
+				let minimal_balance = AssetMetadatas::<T>::get(AssetIds::NativeAssetId(currency_id))
+					.map(|v| v.minimal_balance)
+					.unwrap_or_default();
+
+				Some(AssetMetadata {
+					name: currency_id.name()?.as_bytes().to_vec(),
+					symbol: currency_id.symbol()?.as_bytes().to_vec(),
+					decimals: currency_id.decimals()?,
+					minimal_balance,
+				})
+			}
+			CurrencyId::DexShare(left, _) => {
+				// dex share amount is based on currency_id_0, so does the minimal balance.
+				let minimal_balance = Self::asset_metadata(left.into())?.minimal_balance;
+
+				Some(AssetMetadata {
+					name: EvmErc20InfoMapping::<T>::name(currency_id)?,
+					symbol: EvmErc20InfoMapping::<T>::symbol(currency_id)?,
+					decimals: EvmErc20InfoMapping::<T>::decimals(currency_id)?,
+					minimal_balance,
+				})
+			}
+			CurrencyId::Erc20(contract) => AssetMetadatas::<T>::get(AssetIds::Erc20(contract)).or_else(|| {
+				// the gas of each call is bounded by the EVM bridge.
+				let invoke_context = InvokeContext {
+					contract,
+					sender: Default::default(),
+					origin: Default::default(),
+				};
+
+				Some(AssetMetadata {
+					name: T::EVMBridge::name(invoke_context).ok()?,
+					symbol: T::EVMBridge::symbol(invoke_context).ok()?,
+					decimals: T::EVMBridge::decimals(invoke_context).ok()?,
+					minimal_balance: Default::default(),
+				})
+			}),
+			CurrencyId::StableAssetPoolToken(stable_asset_id) => {
+				AssetMetadatas::<T>::get(AssetIds::StableAssetId(stable_asset_id))
+			}
+			CurrencyId::LiquidCrowdloan(_) => {
+				let minimal_balance = Self::asset_metadata(T::StakingCurrencyId::get())?.minimal_balance;
+
+				Some(AssetMetadata {
+					name: EvmErc20InfoMapping::<T>::name(currency_id)?,
+					symbol: EvmErc20InfoMapping::<T>::symbol(currency_id)?,
+					decimals: EvmErc20InfoMapping::<T>::decimals(currency_id)?,
+					minimal_balance,
+				})
+			}
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				AssetMetadatas::<T>::get(AssetIds::ForeignAssetId(foreign_asset_id))
+			}
+		}
+	}
+
+	/// List the registered assets in pages of at most `MAX_ASSETS_PAGE_SIZE`, starting after
+	/// `cursor`. Returns the cursor of the next page if there are more assets.
+	pub fn all_assets(
+		cursor: Option<AssetIds>,
+		limit: u32,
+	) -> (Vec<(CurrencyId, AssetMetadata<BalanceOf<T>>)>, Option<AssetIds>) {
+		let limit = limit.min(MAX_ASSETS_PAGE_SIZE) as usize;
+		let mut iter = match cursor {
+			Some(asset_ids) => AssetMetadatas::<T>::iter_from(AssetMetadatas::<T>::hashed_key_for(asset_ids)),
+			None => AssetMetadatas::<T>::iter(),
+		};
+
+		let mut assets = Vec::new();
+		let mut last = None;
+		for (asset_ids, metadata) in iter.by_ref().take(limit) {
+			let currency_id = currency_id_of(asset_ids.clone());
+			assets.push((currency_id, Self::asset_metadata(currency_id).unwrap_or(metadata)));
+			last = Some(asset_ids);
+		}
+
+		let next = if iter.next().is_some() { last } else { None };
+		(assets, next)
+	}
+
 	fn get_next_stable_asset_id() -> Result<StableAssetPoolId, DispatchError> {
 		NextStableAssetId::<T>::try_mutate(|current| -> Result<StableAssetPoolId, DispatchError> {
 			let id = *current;
@@ -364,9 +502,52 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Whether the asset is the canonical asset of its symbol.
+	pub fn is_canonical_asset(asset_ids: AssetIds) -> bool {
+		AssetMetadatas::<T>::get(&asset_ids)
+			.is_some_and(|metadata| CanonicalAssets::<T>::get(metadata.symbol.to_ascii_lowercase()) == Some(asset_ids))
+	}
+
+	/// The canonical asset of the symbol, the symbol is case-insensitive.
+	pub fn canonical_asset(symbol: &[u8]) -> Option<CurrencyId> {
+		CanonicalAssets::<T>::get(symbol.to_ascii_lowercase()).map(currency_id_of)
+	}
+
+	/// Check the `symbol` of `asset_ids` against the other registered assets case-insensitively,
+	/// it's rejected if used by another asset unless `allow_duplicate_symbol`. The asset becomes
+	/// the canonical asset of the symbol if no other asset uses it. Nothing is checked if the
+	/// symbol is not changed.
+	fn do_set_symbol(
+		asset_ids: AssetIds,
+		old_symbol: Option<&[u8]>,
+		symbol: &[u8],
+		allow_duplicate_symbol: bool,
+	) -> DispatchResult {
+		if old_symbol.is_some_and(|old_symbol| old_symbol.eq_ignore_ascii_case(symbol)) {
+			return Ok(());
+		}
+
+		let duplicated = AssetMetadatas::<T>::iter()
+			.any(|(id, metadata)| id != asset_ids && metadata.symbol.eq_ignore_ascii_case(symbol));
+		ensure!(!duplicated || allow_duplicate_symbol, Error::<T>::DuplicateSymbol);
+
+		if let Some(old_symbol) = old_symbol {
+			CanonicalAssets::<T>::mutate_exists(old_symbol.to_ascii_lowercase(), |canonical| {
+				if canonical.as_ref() == Some(&asset_ids) {
+					*canonical = None;
+				}
+			});
+		}
+		if !duplicated {
+			CanonicalAssets::<T>::insert(symbol.to_ascii_lowercase(), asset_ids);
+		}
+		Ok(())
+	}
+
 	fn do_register_foreign_asset(
 		location: &Location,
 		metadata: &AssetMetadata<BalanceOf<T>>,
+		allow_duplicate_symbol: bool,
 	) -> Result<ForeignAssetId, DispatchError> {
 		let foreign_asset_id = Self::get_next_foreign_asset_id()?;
 		let v3_location = v3::Location::try_from(location.clone()).map_err(|()| Error::<T>::BadLocation)?;
@@ -382,6 +563,12 @@ impl<T: Config> Pallet<T> {
 					AssetIds::ForeignAssetId(foreign_asset_id),
 					|maybe_asset_metadatas| -> DispatchResult {
 						ensure!(maybe_asset_metadatas.is_none(), Error::<T>::AssetIdExisted);
+						Self::do_set_symbol(
+							AssetIds::ForeignAssetId(foreign_asset_id),
+							None,
+							&metadata.symbol,
+							allow_duplicate_symbol,
+						)?;
 
 						*maybe_asset_metadatas = Some(metadata.clone());
 						Ok(())
@@ -397,6 +584,7 @@ impl<T: Config> Pallet<T> {
 		foreign_asset_id: ForeignAssetId,
 		location: &Location,
 		metadata: &AssetMetadata<BalanceOf<T>>,
+		allow_duplicate_symbol: bool,
 	) -> DispatchResult {
 		let v3_location = v3::Location::try_from(location.clone()).map_err(|()| Error::<T>::BadLocation)?;
 		ForeignAssetLocations::<T>::try_mutate(foreign_asset_id, |maybe_locations| -> DispatchResult {
@@ -405,7 +593,7 @@ impl<T: Config> Pallet<T> {
 			AssetMetadatas::<T>::try_mutate(
 				AssetIds::ForeignAssetId(foreign_asset_id),
 				|maybe_asset_metadatas| -> DispatchResult {
-					ensure!(maybe_asset_metadatas.is_some(), Error::<T>::AssetIdNotExists);
+					let old_metadata = maybe_asset_metadatas.as_ref().ok_or(Error::<T>::AssetIdNotExists)?;
 
 					// modify location
 					if v3_location != *old_locations {
@@ -416,6 +604,12 @@ impl<T: Config> Pallet<T> {
 							Ok(())
 						})?;
 					}
+					Self::do_set_symbol(
+						AssetIds::ForeignAssetId(foreign_asset_id),
+						Some(old_metadata.symbol.as_slice()),
+						&metadata.symbol,
+						allow_duplicate_symbol,
+					)?;
 					*maybe_asset_metadatas = Some(metadata.clone());
 					*old_locations = v3_location;
 					Ok(())
@@ -424,12 +618,21 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	fn do_register_stable_asset(metadata: &AssetMetadata<BalanceOf<T>>) -> Result<StableAssetPoolId, DispatchError> {
+	fn do_register_stable_asset(
+		metadata: &AssetMetadata<BalanceOf<T>>,
+		allow_duplicate_symbol: bool,
+	) -> Result<StableAssetPoolId, DispatchError> {
 		let stable_asset_id = Self::get_next_stable_asset_id()?;
 		AssetMetadatas::<T>::try_mutate(
 			AssetIds::StableAssetId(stable_asset_id),
 			|maybe_asset_metadatas| -> DispatchResult {
 				ensure!(maybe_asset_metadatas.is_none(), Error::<T>::AssetIdExisted);
+				Self::do_set_symbol(
+					AssetIds::StableAssetId(stable_asset_id),
+					None,
+					&metadata.symbol,
+					allow_duplicate_symbol,
+				)?;
 
 				*maybe_asset_metadatas = Some(metadata.clone());
 				Ok(())
@@ -442,11 +645,18 @@ impl<T: Config> Pallet<T> {
 	fn do_update_stable_asset(
 		stable_asset_id: &StableAssetPoolId,
 		metadata: &AssetMetadata<BalanceOf<T>>,
+		allow_duplicate_symbol: bool,
 	) -> DispatchResult {
 		AssetMetadatas::<T>::try_mutate(
 			AssetIds::StableAssetId(*stable_asset_id),
 			|maybe_asset_metadatas| -> DispatchResult {
-				ensure!(maybe_asset_metadatas.is_some(), Error::<T>::AssetIdNotExists);
+				let old_metadata = maybe_asset_metadatas.as_ref().ok_or(Error::<T>::AssetIdNotExists)?;
+				Self::do_set_symbol(
+					AssetIds::StableAssetId(*stable_asset_id),
+					Some(old_metadata.symbol.as_slice()),
+					&metadata.symbol,
+					allow_duplicate_symbol,
+				)?;
 
 				*maybe_asset_metadatas = Some(metadata.clone());
 				Ok(())
@@ -457,6 +667,7 @@ impl<T: Config> Pallet<T> {
 	fn do_register_erc20_asset(
 		contract: EvmAddress,
 		minimal_balance: BalanceOf<T>,
+		allow_duplicate_symbol: bool,
 	) -> Result<AssetMetadata<BalanceOf<T>>, DispatchError> {
 		let invoke_context = InvokeContext {
 			contract,
@@ -482,6 +693,12 @@ impl<T: Config> Pallet<T> {
 
 				Ok(())
 			})?;
+			Self::do_set_symbol(
+				AssetIds::Erc20(contract),
+				None,
+				&metadata.symbol,
+				allow_duplicate_symbol,
+			)?;
 
 			*maybe_asset_metadatas = Some(metadata.clone());
 			Ok(())
@@ -490,20 +707,40 @@ impl<T: Config> Pallet<T> {
 		Ok(metadata)
 	}
 
-	fn do_update_erc20_asset(contract: EvmAddress, metadata: &AssetMetadata<BalanceOf<T>>) -> DispatchResult {
+	fn do_update_erc20_asset(
+		contract: EvmAddress,
+		metadata: &AssetMetadata<BalanceOf<T>>,
+		allow_duplicate_symbol: bool,
+	) -> DispatchResult {
 		AssetMetadatas::<T>::try_mutate(AssetIds::Erc20(contract), |maybe_asset_metadatas| -> DispatchResult {
-			ensure!(maybe_asset_metadatas.is_some(), Error::<T>::AssetIdNotExists);
+			let old_metadata = maybe_asset_metadatas.as_ref().ok_or(Error::<T>::AssetIdNotExists)?;
+			Self::do_set_symbol(
+				AssetIds::Erc20(contract),
+				Some(old_metadata.symbol.as_slice()),
+				&metadata.symbol,
+				allow_duplicate_symbol,
+			)?;
 
 			*maybe_asset_metadatas = Some(metadata.clone());
 			Ok(())
 		})
 	}
 
-	fn do_register_native_asset(asset: CurrencyId, metadata: &AssetMetadata<BalanceOf<T>>) -> DispatchResult {
+	fn do_register_native_asset(
+		asset: CurrencyId,
+		metadata: &AssetMetadata<BalanceOf<T>>,
+		allow_duplicate_symbol: bool,
+	) -> DispatchResult {
 		AssetMetadatas::<T>::try_mutate(
 			AssetIds::NativeAssetId(asset),
 			|maybe_asset_metadatas| -> DispatchResult {
 				ensure!(maybe_asset_metadatas.is_none(), Error::<T>::AssetIdExisted);
+				Self::do_set_symbol(
+					AssetIds::NativeAssetId(asset),
+					None,
+					&metadata.symbol,
+					allow_duplicate_symbol,
+				)?;
 
 				*maybe_asset_metadatas = Some(metadata.clone());
 				Ok(())
@@ -513,11 +750,21 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn do_update_native_asset(currency_id: CurrencyId, metadata: &AssetMetadata<BalanceOf<T>>) -> DispatchResult {
+	fn do_update_native_asset(
+		currency_id: CurrencyId,
+		metadata: &AssetMetadata<BalanceOf<T>>,
+		allow_duplicate_symbol: bool,
+	) -> DispatchResult {
 		AssetMetadatas::<T>::try_mutate(
 			AssetIds::NativeAssetId(currency_id),
 			|maybe_asset_metadatas| -> DispatchResult {
-				ensure!(maybe_asset_metadatas.is_some(), Error::<T>::AssetIdNotExists);
+				let old_metadata = maybe_asset_metadatas.as_ref().ok_or(Error::<T>::AssetIdNotExists)?;
+				Self::do_set_symbol(
+					AssetIds::NativeAssetId(currency_id),
+					Some(old_metadata.symbol.as_slice()),
+					&metadata.symbol,
+					allow_duplicate_symbol,
+				)?;
 
 				*maybe_asset_metadatas = Some(metadata.clone());
 				Ok(())
@@ -526,6 +773,15 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+fn currency_id_of(asset_ids: AssetIds) -> CurrencyId {
+	match asset_ids {
+		AssetIds::Erc20(contract) => CurrencyId::Erc20(contract),
+		AssetIds::StableAssetId(stable_asset_id) => CurrencyId::StableAssetPoolToken(stable_asset_id),
+		AssetIds::ForeignAssetId(foreign_asset_id) => CurrencyId::ForeignAsset(foreign_asset_id),
+		AssetIds::NativeAssetId(currency_id) => currency_id,
+	}
+}
+
 pub struct AssetIdMaps<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> AssetIdMapping<ForeignAssetId, Location, AssetMetadata<BalanceOf<T>>> for AssetIdMaps<T> {
@@ -104,6 +104,9 @@ impl module_evm::Config for Runtime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ();
 	type IdleScheduler = ();
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
@@ -203,7 +206,8 @@ pub fn deploy_contracts_same_prefix() {
 		0,
 		2_100_000,
 		10000,
-		vec![]
+		vec![],
+		None
 	));
 
 	System::assert_has_event(RuntimeEvent::EVM(module_evm::Event::Created {
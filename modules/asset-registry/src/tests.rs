@@ -28,6 +28,7 @@ use mock::{
 };
 use primitives::TokenSymbol;
 use sp_core::H160;
+use sp_runtime::traits::BadOrigin;
 use std::str::{from_utf8, FromStr};
 
 #[test]
@@ -128,7 +129,8 @@ fn register_foreign_asset_work() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		let v3_location: v3::Location = v2_versioned_location.try_into().unwrap();
@@ -176,7 +178,8 @@ fn register_foreign_asset_work() {
 				symbol: b"ATN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		let v3_location: v3::Location = v3_versioned_location.try_into().unwrap();
@@ -224,7 +227,8 @@ fn register_foreign_asset_work() {
 				symbol: b"ATN2".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		let v3_location: v3::Location = v4_versioned_location.clone().try_into().unwrap();
@@ -270,7 +274,8 @@ fn register_foreign_asset_should_not_work() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		assert_noop!(
@@ -282,7 +287,8 @@ fn register_foreign_asset_should_not_work() {
 					symbol: b"TN".to_vec(),
 					decimals: 12,
 					minimal_balance: 1,
-				})
+				}),
+				false
 			),
 			Error::<Runtime>::LocationExisted
 		);
@@ -297,7 +303,8 @@ fn register_foreign_asset_should_not_work() {
 					symbol: b"TN".to_vec(),
 					decimals: 12,
 					minimal_balance: 1,
-				})
+				}),
+				false
 			),
 			ArithmeticError::Overflow
 		);
@@ -317,7 +324,8 @@ fn update_foreign_asset_work() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		assert_ok!(AssetRegistry::update_foreign_asset(
@@ -329,7 +337,8 @@ fn update_foreign_asset_work() {
 				symbol: b"NTN".to_vec(),
 				decimals: 13,
 				minimal_balance: 2,
-			})
+			}),
+			false
 		));
 
 		let v3_location: v3::Location = v4_versioned_location.clone().try_into().unwrap();
@@ -372,7 +381,8 @@ fn update_foreign_asset_work() {
 				symbol: b"NTN".to_vec(),
 				decimals: 13,
 				minimal_balance: 2,
-			})
+			}),
+			false
 		));
 		assert_eq!(
 			AssetMetadatas::<Runtime>::get(AssetIds::ForeignAssetId(0)),
@@ -408,7 +418,8 @@ fn update_foreign_asset_should_not_work() {
 					symbol: b"NTN".to_vec(),
 					decimals: 13,
 					minimal_balance: 2,
-				})
+				}),
+				false
 			),
 			Error::<Runtime>::AssetIdNotExists
 		);
@@ -421,7 +432,8 @@ fn update_foreign_asset_should_not_work() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		assert_ok!(AssetRegistry::update_foreign_asset(
@@ -433,7 +445,8 @@ fn update_foreign_asset_should_not_work() {
 				symbol: b"NTN".to_vec(),
 				decimals: 13,
 				minimal_balance: 2,
-			})
+			}),
+			false
 		));
 
 		// existed location
@@ -446,7 +459,8 @@ fn update_foreign_asset_should_not_work() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 		assert_noop!(
 			AssetRegistry::update_foreign_asset(
@@ -458,7 +472,8 @@ fn update_foreign_asset_should_not_work() {
 					symbol: b"NTN".to_vec(),
 					decimals: 13,
 					minimal_balance: 2,
-				})
+				}),
+				false
 			),
 			Error::<Runtime>::LocationExisted
 		);
@@ -475,7 +490,8 @@ fn register_stable_asset_work() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetRegistered {
@@ -510,7 +526,8 @@ fn register_stable_asset_should_not_work() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		NextStableAssetId::<Runtime>::set(0);
@@ -522,7 +539,8 @@ fn register_stable_asset_should_not_work() {
 					symbol: b"TN".to_vec(),
 					decimals: 12,
 					minimal_balance: 1,
-				})
+				}),
+				false
 			),
 			Error::<Runtime>::AssetIdExisted
 		);
@@ -536,7 +554,8 @@ fn register_stable_asset_should_not_work() {
 					symbol: b"TN".to_vec(),
 					decimals: 12,
 					minimal_balance: 1,
-				})
+				}),
+				false
 			),
 			ArithmeticError::Overflow
 		);
@@ -553,7 +572,8 @@ fn update_stable_asset_work() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		assert_ok!(AssetRegistry::update_stable_asset(
@@ -564,7 +584,8 @@ fn update_stable_asset_work() {
 				symbol: b"NTN".to_vec(),
 				decimals: 13,
 				minimal_balance: 2,
-			})
+			}),
+			false
 		));
 
 		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetUpdated {
@@ -601,7 +622,8 @@ fn update_stable_asset_should_not_work() {
 					symbol: b"NTN".to_vec(),
 					decimals: 13,
 					minimal_balance: 2,
-				})
+				}),
+				false
 			),
 			Error::<Runtime>::AssetIdNotExists
 		);
@@ -618,7 +640,8 @@ fn register_erc20_asset_work() {
 			assert_ok!(AssetRegistry::register_erc20_asset(
 				RuntimeOrigin::signed(CouncilAccount::get()),
 				erc20_address(),
-				1
+				1,
+				false
 			));
 
 			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetRegistered {
@@ -658,14 +681,16 @@ fn register_erc20_asset_should_not_work() {
 			assert_ok!(AssetRegistry::register_erc20_asset(
 				RuntimeOrigin::signed(CouncilAccount::get()),
 				erc20_address(),
-				1
+				1,
+				false
 			));
 
 			assert_noop!(
 				AssetRegistry::register_erc20_asset(
 					RuntimeOrigin::signed(CouncilAccount::get()),
 					erc20_address_same_prefix(),
-					1
+					1,
+					false
 				),
 				Error::<Runtime>::AssetIdExisted
 			);
@@ -674,7 +699,8 @@ fn register_erc20_asset_should_not_work() {
 				AssetRegistry::register_erc20_asset(
 					RuntimeOrigin::signed(CouncilAccount::get()),
 					erc20_address_not_exists(),
-					1
+					1,
+					false
 				),
 				module_evm_bridge::Error::<Runtime>::InvalidReturnValue,
 			);
@@ -691,7 +717,8 @@ fn update_erc20_asset_work() {
 			assert_ok!(AssetRegistry::register_erc20_asset(
 				RuntimeOrigin::signed(CouncilAccount::get()),
 				erc20_address(),
-				1
+				1,
+				false
 			));
 
 			assert_ok!(AssetRegistry::update_erc20_asset(
@@ -702,7 +729,8 @@ fn update_erc20_asset_work() {
 					symbol: b"NTN".to_vec(),
 					decimals: 13,
 					minimal_balance: 2,
-				})
+				}),
+				false
 			));
 
 			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetUpdated {
@@ -738,7 +766,8 @@ fn register_native_asset_works() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetRegistered {
 			asset_id: AssetIds::NativeAssetId(CurrencyId::Token(TokenSymbol::DOT)),
@@ -769,7 +798,8 @@ fn register_native_asset_works() {
 					symbol: b"TN".to_vec(),
 					decimals: 12,
 					minimal_balance: 1,
-				})
+				}),
+				false
 			),
 			Error::<Runtime>::AssetIdExisted
 		);
@@ -788,7 +818,8 @@ fn update_native_asset_works() {
 					symbol: b"NTN".to_vec(),
 					decimals: 13,
 					minimal_balance: 2,
-				})
+				}),
+				false
 			),
 			Error::<Runtime>::AssetIdNotExists
 		);
@@ -801,7 +832,8 @@ fn update_native_asset_works() {
 				symbol: b"TN".to_vec(),
 				decimals: 12,
 				minimal_balance: 1,
-			})
+			}),
+			false
 		));
 
 		assert_ok!(AssetRegistry::update_native_asset(
@@ -812,7 +844,8 @@ fn update_native_asset_works() {
 				symbol: b"NTN".to_vec(),
 				decimals: 13,
 				minimal_balance: 2,
-			})
+			}),
+			false
 		));
 
 		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetUpdated {
@@ -849,7 +882,8 @@ fn update_erc20_asset_should_not_work() {
 					symbol: b"NTN".to_vec(),
 					decimals: 13,
 					minimal_balance: 2,
-				})
+				}),
+				false
 			),
 			Error::<Runtime>::AssetIdNotExists
 		);
@@ -866,7 +900,8 @@ fn name_works() {
 			assert_ok!(AssetRegistry::register_erc20_asset(
 				RuntimeOrigin::signed(CouncilAccount::get()),
 				erc20_address(),
-				1
+				1,
+				false
 			));
 			assert_eq!(
 				EvmErc20InfoMapping::<Runtime>::name(CurrencyId::Token(TokenSymbol::ACA)),
@@ -924,7 +959,8 @@ fn symbol_works() {
 			assert_ok!(AssetRegistry::register_erc20_asset(
 				RuntimeOrigin::signed(CouncilAccount::get()),
 				erc20_address(),
-				1
+				1,
+				false
 			));
 			assert_eq!(
 				EvmErc20InfoMapping::<Runtime>::symbol(CurrencyId::Token(TokenSymbol::ACA)),
@@ -997,7 +1033,8 @@ fn decimals_works() {
 			assert_ok!(AssetRegistry::register_erc20_asset(
 				RuntimeOrigin::signed(CouncilAccount::get()),
 				erc20_address(),
-				1
+				1,
+				false
 			));
 			assert_eq!(
 				EvmErc20InfoMapping::<Runtime>::decimals(CurrencyId::Token(TokenSymbol::ACA)),
@@ -1062,7 +1099,8 @@ fn encode_evm_address_works() {
 			assert_ok!(AssetRegistry::register_erc20_asset(
 				RuntimeOrigin::signed(CouncilAccount::get()),
 				erc20_address(),
-				1
+				1,
+				false
 			));
 
 			// Token
@@ -1176,7 +1214,8 @@ fn decode_evm_address_works() {
 			assert_ok!(AssetRegistry::register_erc20_asset(
 				RuntimeOrigin::signed(CouncilAccount::get()),
 				erc20_address(),
-				1
+				1,
+				false
 			));
 
 			// Token
@@ -1325,3 +1364,298 @@ fn decode_evm_address_works() {
 			);
 		});
 }
+
+#[test]
+fn asset_metadata_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let invoke_context = InvokeContext {
+				contract: erc20_address(),
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+			let bridge_decimals = <Runtime as Config>::EVMBridge::decimals(invoke_context).unwrap();
+
+			// unregistered erc20 is resolved through the EVM bridge
+			let metadata = AssetRegistry::asset_metadata(CurrencyId::Erc20(erc20_address())).unwrap();
+			assert_eq!(metadata.decimals, bridge_decimals);
+			assert_eq!(metadata.symbol, b"TestToken".to_vec());
+			assert_eq!(metadata.minimal_balance, 0);
+			assert_eq!(
+				AssetRegistry::asset_metadata(CurrencyId::Erc20(erc20_address_not_exists())),
+				None
+			);
+
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				erc20_address(),
+				1,
+				false
+			));
+			let metadata = AssetRegistry::asset_metadata(CurrencyId::Erc20(erc20_address())).unwrap();
+			assert_eq!(metadata.decimals, bridge_decimals);
+			assert_eq!(metadata.minimal_balance, 1);
+
+			assert_eq!(
+				AssetRegistry::asset_metadata(CurrencyId::Token(TokenSymbol::ACA)),
+				Some(AssetMetadata {
+					name: b"Acala".to_vec(),
+					symbol: b"ACA".to_vec(),
+					decimals: 12,
+					minimal_balance: 1,
+				})
+			);
+			assert_eq!(
+				AssetRegistry::asset_metadata(CurrencyId::Token(TokenSymbol::AUSD)),
+				Some(AssetMetadata {
+					name: b"Acala Dollar".to_vec(),
+					symbol: b"AUSD".to_vec(),
+					decimals: 12,
+					minimal_balance: 0,
+				})
+			);
+
+			let metadata = AssetRegistry::asset_metadata(CurrencyId::DexShare(
+				DexShare::Erc20(erc20_address()),
+				DexShare::Token(TokenSymbol::ACA),
+			))
+			.unwrap();
+			assert_eq!(metadata.symbol, b"LP_TestToken_ACA".to_vec());
+			assert_eq!(metadata.decimals, bridge_decimals);
+			assert_eq!(metadata.minimal_balance, 1);
+			assert_eq!(
+				AssetRegistry::asset_metadata(CurrencyId::DexShare(
+					DexShare::Token(TokenSymbol::ACA),
+					DexShare::Erc20(erc20_address_not_exists()),
+				)),
+				None
+			);
+
+			assert_eq!(
+				AssetRegistry::asset_metadata(CurrencyId::LiquidCrowdloan(0)),
+				Some(AssetMetadata {
+					name: b"LiquidCrowdloan-Kusama-0".to_vec(),
+					symbol: b"LCKSM-0".to_vec(),
+					decimals: 12,
+					minimal_balance: 0,
+				})
+			);
+
+			assert_eq!(AssetRegistry::asset_metadata(CurrencyId::StableAssetPoolToken(0)), None);
+			assert_eq!(AssetRegistry::asset_metadata(CurrencyId::ForeignAsset(0)), None);
+		});
+}
+
+#[test]
+fn all_assets_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				erc20_address(),
+				1,
+				false
+			));
+			for _ in 0..2 {
+				assert_ok!(AssetRegistry::register_stable_asset(
+					RuntimeOrigin::signed(CouncilAccount::get()),
+					Box::new(AssetMetadata {
+						name: b"Token Name".to_vec(),
+						symbol: b"TN".to_vec(),
+						decimals: 12,
+						minimal_balance: 1,
+					}),
+					true
+				));
+			}
+
+			let (first_page, cursor) = AssetRegistry::all_assets(None, 3);
+			assert_eq!(first_page.len(), 3);
+			assert!(cursor.is_some());
+
+			let (second_page, cursor) = AssetRegistry::all_assets(cursor, 3);
+			assert_eq!(second_page.len(), 1);
+			assert_eq!(cursor, None);
+
+			let mut assets = first_page
+				.into_iter()
+				.chain(second_page)
+				.map(|(currency_id, metadata)| {
+					assert_eq!(AssetRegistry::asset_metadata(currency_id), Some(metadata));
+					currency_id
+				})
+				.collect::<Vec<_>>();
+			assets.sort();
+			assert_eq!(
+				assets,
+				vec![
+					CurrencyId::Token(TokenSymbol::ACA),
+					CurrencyId::Erc20(erc20_address()),
+					CurrencyId::StableAssetPoolToken(0),
+					CurrencyId::StableAssetPoolToken(1),
+				]
+			);
+
+			let (all, cursor) = AssetRegistry::all_assets(None, u32::MAX);
+			assert_eq!(all.len(), 4);
+			assert_eq!(cursor, None);
+		});
+}
+
+#[test]
+fn duplicate_symbol_requires_explicit_flag() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = |symbol: &[u8]| {
+			Box::new(AssetMetadata {
+				name: b"Tether USD".to_vec(),
+				symbol: symbol.to_vec(),
+				decimals: 6,
+				minimal_balance: 1,
+			})
+		};
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			Box::new(VersionedLocation::V4(Location::new(0, [Parachain(1000)]))),
+			metadata(b"USDT"),
+			false
+		));
+		assert_eq!(
+			AssetRegistry::canonical_assets(b"usdt".to_vec()),
+			Some(AssetIds::ForeignAssetId(0))
+		);
+
+		// the symbol is compared case-insensitively
+		assert_noop!(
+			AssetRegistry::register_stable_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				metadata(b"usdt"),
+				false
+			),
+			Error::<Runtime>::DuplicateSymbol
+		);
+		assert_noop!(
+			AssetRegistry::register_native_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				CurrencyId::Token(TokenSymbol::AUSD),
+				metadata(b"aca"),
+				false
+			),
+			Error::<Runtime>::DuplicateSymbol
+		);
+
+		// explicit override registers the asset, the canonical asset is not changed
+		assert_ok!(AssetRegistry::register_stable_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			metadata(b"usdt"),
+			true
+		));
+		assert!(AssetRegistry::is_canonical_asset(AssetIds::ForeignAssetId(0)));
+		assert!(!AssetRegistry::is_canonical_asset(AssetIds::StableAssetId(0)));
+
+		// update without changing the symbol is not checked
+		assert_ok!(AssetRegistry::update_stable_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			0,
+			metadata(b"USDt"),
+			false
+		));
+
+		// update to another used symbol requires the flag
+		assert_ok!(AssetRegistry::register_stable_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			metadata(b"TN"),
+			false
+		));
+		assert_noop!(
+			AssetRegistry::update_stable_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				1,
+				metadata(b"USDT"),
+				false
+			),
+			Error::<Runtime>::DuplicateSymbol
+		);
+
+		// update the canonical asset to a new symbol releases the old one
+		assert_ok!(AssetRegistry::update_foreign_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			0,
+			Box::new(VersionedLocation::V4(Location::new(0, [Parachain(1000)]))),
+			metadata(b"USDT.e"),
+			false
+		));
+		assert_eq!(AssetRegistry::canonical_assets(b"usdt".to_vec()), None);
+		assert_eq!(
+			AssetRegistry::canonical_asset(b"USDT.E"),
+			Some(CurrencyId::ForeignAsset(0))
+		);
+	});
+}
+
+#[test]
+fn set_canonical_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let metadata = Box::new(AssetMetadata {
+			name: b"Tether USD".to_vec(),
+			symbol: b"USDT".to_vec(),
+			decimals: 6,
+			minimal_balance: 1,
+		});
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			Box::new(VersionedLocation::V4(Location::new(0, [Parachain(1000)]))),
+			metadata.clone(),
+			false
+		));
+		assert_ok!(AssetRegistry::register_stable_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			metadata,
+			true
+		));
+
+		assert_noop!(
+			AssetRegistry::set_canonical_asset(RuntimeOrigin::signed(alice()), AssetIds::StableAssetId(0)),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetRegistry::set_canonical_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				AssetIds::StableAssetId(1)
+			),
+			Error::<Runtime>::AssetIdNotExists
+		);
+
+		assert_ok!(AssetRegistry::set_canonical_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			AssetIds::StableAssetId(0)
+		));
+		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::CanonicalAssetChanged {
+			symbol: b"usdt".to_vec(),
+			previous: Some(AssetIds::ForeignAssetId(0)),
+			asset_id: AssetIds::StableAssetId(0),
+		}));
+		assert!(AssetRegistry::is_canonical_asset(AssetIds::StableAssetId(0)));
+		assert!(!AssetRegistry::is_canonical_asset(AssetIds::ForeignAssetId(0)));
+		assert_eq!(
+			AssetRegistry::canonical_asset(b"USDT"),
+			Some(CurrencyId::StableAssetPoolToken(0))
+		);
+
+		// no event if already canonical
+		System::reset_events();
+		assert_ok!(AssetRegistry::set_canonical_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			AssetIds::StableAssetId(0)
+		));
+		assert!(System::events().is_empty());
+	});
+}
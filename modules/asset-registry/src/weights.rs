@@ -54,6 +54,7 @@ pub trait WeightInfo {
 	fn update_erc20_asset() -> Weight;
 	fn register_native_asset() -> Weight;
 	fn update_native_asset() -> Weight;
+	fn set_canonical_asset() -> Weight;
 }
 
 /// Weights for module_asset_registry using the Acala node and recommended hardware.
@@ -116,6 +117,13 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: AssetRegistry AssetMetadatas (r:1 w:0)
+	// Storage: AssetRegistry CanonicalAssets (r:1 w:1)
+	fn set_canonical_asset() -> Weight {
+		Weight::from_parts(14_213_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -160,4 +168,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn set_canonical_asset() -> Weight {
+		Weight::from_parts(14_213_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
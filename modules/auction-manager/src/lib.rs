@@ -24,21 +24,33 @@
 //! business. Auction types include:
 //!   - `collateral auction`: sell collateral assets for getting stable currency to eliminate the
 //!     system's bad debit by auction
+//!
+//! The `get_auction_info` and `get_active_auctions` queries preview the state of collateral
+//! auctions for keepers, including the minimum acceptable next bid and the collateral the
+//! last bidder would receive, computed with the same functions that handle the bids.
+//!
+//! The `CheckAuctionBid` signed extension assigns `BidPriority` of the collateral type to the
+//! bids of collateral auctions which are about to close, so they aren't crowded out of congested
+//! blocks.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 #![allow(clippy::unnecessary_unwrap)]
 
-use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, transactional};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{ExistenceRequirement, IsSubType},
+	transactional,
+};
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
 };
 use module_support::{
-	AuctionManager, CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, PriceProvider, Rate, SwapLimit,
+	AuctionManager, CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, IssuanceOrigin, PriceProvider, Rate, SwapLimit,
 };
-use orml_traits::{Auction, AuctionHandler, Change, MultiCurrency, OnNewBidResult};
+use orml_traits::{Auction, AuctionHandler, Change, GetByKey, MultiCurrency, OnNewBidResult};
 use orml_utilities::OffchainErr;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use primitives::{AuctionId, Balance, CurrencyId};
@@ -49,13 +61,14 @@ use sp_runtime::{
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{CheckedDiv, Saturating, Zero},
+	traits::{CheckedDiv, DispatchInfoOf, One, Saturating, SignedExtension, Zero},
 	transaction_validity::{
-		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
+		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
 	},
 	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::prelude::*;
+use sp_std::{marker::PhantomData, prelude::*};
 
 mod mock;
 mod tests;
@@ -69,6 +82,8 @@ pub const OFFCHAIN_WORKER_LOCK: &[u8] = b"acala/auction-manager/lock/";
 pub const OFFCHAIN_WORKER_MAX_ITERATIONS: &[u8] = b"acala/auction-manager/max-iterations/";
 pub const LOCK_DURATION: u64 = 100;
 pub const DEFAULT_MAX_ITERATIONS: u32 = 1000;
+/// The maximum number of auctions returned by a single `get_active_auctions` query.
+pub const MAX_AUCTION_QUERY_LIMIT: u32 = 100;
 
 /// Information of an collateral auction
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
@@ -115,6 +130,21 @@ impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
 		}
 	}
 
+	/// Return the minimum acceptable price of the bid after `last_bid_price`
+	///
+	/// Formula: last_price + max(last_price, target_price) * minimum_increment
+	fn minimum_next_bid_price(&self, last_bid_price: Balance, minimum_increment: Rate) -> Option<Balance> {
+		minimum_increment
+			.checked_mul_int(sp_std::cmp::max(self.target, last_bid_price))
+			.and_then(|increment| last_bid_price.checked_add(increment))
+	}
+
+	/// Return whether the collateral auction is dealt by the last bidder if
+	/// it ends at `last_bid_price` and the DEX doesn't take it
+	fn dealt_by_last_bidder(&self, last_bid_price: Option<Balance>) -> bool {
+		last_bid_price.map_or(false, |bid_price| bid_price >= self.target)
+	}
+
 	/// Return new collateral amount at specific last bid price and new bid
 	/// price
 	fn collateral_amount(&self, last_bid_price: Balance, new_bid_price: Balance) -> Balance {
@@ -128,6 +158,52 @@ impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
 	}
 }
 
+/// The kind of an auction.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum AuctionKind {
+	/// Sells collateral for stable currency.
+	Collateral,
+}
+
+/// The phase of a collateral auction.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum AuctionPhase {
+	/// Bids compete on the stable currency paid for the collateral.
+	Normal,
+	/// The target is reached, bids compete on the collateral refunded to the refund recipient.
+	Reverse,
+}
+
+/// The state of an auction returned by the auction manager runtime api.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct AuctionInfoView<AccountId, BlockNumber> {
+	/// The auction id
+	pub auction_id: AuctionId,
+	/// The auction kind
+	pub kind: AuctionKind,
+	/// Collateral type for sale
+	pub currency_id: CurrencyId,
+	/// Current collateral amount for sale
+	pub amount: Balance,
+	/// Target sales amount of the auction, zero if it never enters the reverse phase
+	pub target: Balance,
+	/// Refund recipient of the collateral
+	pub refund_recipient: AccountId,
+	/// The last bidder and bid price
+	pub bid: Option<(AccountId, Balance)>,
+	/// The minimum acceptable next bid price at the current block
+	pub minimum_next_bid: Balance,
+	/// The current auction phase
+	pub phase: AuctionPhase,
+	/// The collateral amount the last bidder would receive if the auction ended now and the
+	/// DEX didn't take it
+	pub settlement_amount: Balance,
+	/// Auction start block
+	pub start: BlockNumber,
+	/// Auction end block
+	pub end: Option<BlockNumber>,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -162,7 +238,8 @@ pub mod module {
 		/// CDP treasury to escrow assets related to auction
 		type CDPTreasury: CDPTreasuryExtended<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
-		/// The price source of currencies
+		/// The price source of currencies, it should respect the prices locked by governance or
+		/// the emergency shutdown.
 		type PriceSource: PriceProvider<CurrencyId>;
 
 		/// A configuration for base priority of unsigned transactions.
@@ -172,6 +249,10 @@ pub mod module {
 		#[pallet::constant]
 		type UnsignedPriority: Get<TransactionPriority>;
 
+		/// The priority of the bids of collateral auctions about to close, by
+		/// the collateral type.
+		type BidPriority: GetByKey<CurrencyId, TransactionPriority>;
+
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
@@ -256,7 +337,10 @@ pub mod module {
 	#[pallet::getter(fn total_target_in_auction)]
 	pub type TotalTargetInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -457,7 +541,7 @@ impl<T: Config> Pallet<T> {
 		// if there's bid
 		if let Some((bidder, bid_price)) = last_bid {
 			// refund stable token to the bidder
-			T::CDPTreasury::issue_debit(&bidder, bid_price, false)?;
+			T::CDPTreasury::issue_debit(&bidder, bid_price, false, IssuanceOrigin::System)?;
 
 			// decrease account ref of bidder
 			frame_system::Pallet::<T>::dec_consumers(&bidder);
@@ -475,27 +559,108 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Return `true` if price increment rate is greater than or equal to
-	/// minimum.
+	/// Return the state of the collateral auction `auction_id` at the current block.
+	pub fn get_auction_info(auction_id: AuctionId) -> Option<AuctionInfoView<T::AccountId, BlockNumberFor<T>>> {
+		let collateral_auction = Self::collateral_auctions(auction_id)?;
+		let auction_info = T::Auction::auction_info(auction_id)?;
+		Some(Self::auction_info_view(
+			frame_system::Pallet::<T>::block_number(),
+			auction_id,
+			collateral_auction,
+			auction_info.bid,
+			auction_info.start,
+			auction_info.end,
+		))
+	}
+
+	/// Enumerate the active collateral auctions after the auction id `cursor`, in storage order.
 	///
-	/// Formula: new_price - last_price >=
-	///     max(last_price, target_price) * minimum_increment
-	fn check_minimum_increment(
-		new_price: Balance,
-		last_price: Balance,
-		target_price: Balance,
-		minimum_increment: Rate,
-	) -> bool {
-		if let (Some(target), Some(result)) = (
-			minimum_increment.checked_mul_int(sp_std::cmp::max(target_price, last_price)),
-			new_price.checked_sub(last_price),
-		) {
-			result >= target
+	/// At most `limit` auctions are returned, capped at `MAX_AUCTION_QUERY_LIMIT`. Returns the
+	/// auctions and the cursor of the next page, `None` once all auctions have been returned.
+	pub fn get_active_auctions(
+		cursor: Option<AuctionId>,
+		limit: u32,
+	) -> (Vec<AuctionInfoView<T::AccountId, BlockNumberFor<T>>>, Option<AuctionId>) {
+		let mut iter = match cursor {
+			Some(auction_id) => CollateralAuctions::<T>::iter_from(CollateralAuctions::<T>::hashed_key_for(auction_id)),
+			None => CollateralAuctions::<T>::iter(),
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut auctions = vec![];
+		let mut last = None;
+
+		for (auction_id, collateral_auction) in iter.by_ref().take(limit.clamp(1, MAX_AUCTION_QUERY_LIMIT) as usize) {
+			if let Some(auction_info) = T::Auction::auction_info(auction_id) {
+				auctions.push(Self::auction_info_view(
+					now,
+					auction_id,
+					collateral_auction,
+					auction_info.bid,
+					auction_info.start,
+					auction_info.end,
+				));
+			}
+			last = Some(auction_id);
+		}
+
+		let next = if iter.next().is_some() { last } else { None };
+		(auctions, next)
+	}
+
+	fn auction_info_view(
+		now: BlockNumberFor<T>,
+		auction_id: AuctionId,
+		collateral_auction: CollateralAuctionItem<T::AccountId, BlockNumberFor<T>>,
+		bid: Option<(T::AccountId, Balance)>,
+		start: BlockNumberFor<T>,
+		end: Option<BlockNumberFor<T>>,
+	) -> AuctionInfoView<T::AccountId, BlockNumberFor<T>> {
+		let last_bid_price = bid.as_ref().map(|(_, price)| *price);
+		let minimum_next_bid = Self::minimum_next_bid(now, &collateral_auction, last_bid_price.unwrap_or_default())
+			.unwrap_or(Balance::MAX);
+		let phase = if collateral_auction.in_reverse_stage(last_bid_price.unwrap_or_default()) {
+			AuctionPhase::Reverse
 		} else {
-			false
+			AuctionPhase::Normal
+		};
+		let settlement_amount = if collateral_auction.dealt_by_last_bidder(last_bid_price) {
+			collateral_auction.amount
+		} else {
+			Zero::zero()
+		};
+
+		AuctionInfoView {
+			auction_id,
+			kind: AuctionKind::Collateral,
+			currency_id: collateral_auction.currency_id,
+			amount: collateral_auction.amount,
+			target: collateral_auction.target,
+			refund_recipient: collateral_auction.refund_recipient,
+			bid,
+			minimum_next_bid,
+			phase,
+			settlement_amount,
+			start,
+			end,
 		}
 	}
 
+	/// Return the minimum acceptable price of the bid after `last_bid_price` at block `now`,
+	/// `None` if no bid can be accepted.
+	fn minimum_next_bid(
+		now: BlockNumberFor<T>,
+		collateral_auction: &CollateralAuctionItem<T::AccountId, BlockNumberFor<T>>,
+		last_bid_price: Balance,
+	) -> Option<Balance> {
+		collateral_auction
+			.minimum_next_bid_price(
+				last_bid_price,
+				Self::get_minimum_increment_size(now, collateral_auction.start_time),
+			)
+			// the bid price must be positive
+			.map(|price| price.max(One::one()))
+	}
+
 	fn get_minimum_increment_size(now: BlockNumberFor<T>, start_block: BlockNumberFor<T>) -> Rate {
 		if now >= start_block + T::AuctionDurationSoftCap::get() {
 			// double the minimum increment size when reach soft cap
@@ -538,12 +703,8 @@ impl<T: Config> Pallet<T> {
 
 				// ensure new bid price is valid
 				ensure!(
-					Self::check_minimum_increment(
-						new_bid_price,
-						last_bid_price,
-						collateral_auction.target,
-						Self::get_minimum_increment_size(now, collateral_auction.start_time),
-					),
+					Self::minimum_next_bid(now, collateral_auction, last_bid_price)
+						.map_or(false, |minimum_bid_price| new_bid_price >= minimum_bid_price),
 					Error::<T>::InvalidBidPrice
 				);
 
@@ -647,7 +808,7 @@ impl<T: Config> Pallet<T> {
 				supply_collateral_amount: actual_supply_amount,
 				target_stable_amount: actual_target_amount,
 			});
-		} else if last_bidder.is_some() && bid_price >= collateral_auction.target {
+		} else if collateral_auction.dealt_by_last_bidder(last_bidder.as_ref().map(|_| bid_price)) {
 			// if these's bid which is gte target, auction should dealt by the last bidder.
 			let winner = last_bidder.expect("ensured last bidder not empty; qed");
 
@@ -691,7 +852,12 @@ impl<T: Config> Pallet<T> {
 	) {
 		if let Some((bidder, bid_price)) = last_bid {
 			// If failed, just the bid did not get the stable. It can be fixed by treasury council.
-			let res = T::CDPTreasury::issue_debit(&bidder, collateral_auction.payment_amount(bid_price), false);
+			let res = T::CDPTreasury::issue_debit(
+				&bidder,
+				collateral_auction.payment_amount(bid_price),
+				false,
+				IssuanceOrigin::System,
+			);
 			if let Err(e) = res {
 				log::warn!(
 					target: "auction-manager",
@@ -855,3 +1021,92 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		Self::total_target_in_auction()
 	}
 }
+
+/// Boosts the priority of the bids of collateral auctions which are about to close.
+///
+/// A bid on a collateral auction whose end is within `AuctionTimeToClose` blocks is assigned
+/// `BidPriority` of the collateral type, other transactions are not affected.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckAuctionBid<T: Config + orml_auction::Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + orml_auction::Config + Send + Sync> sp_std::fmt::Debug for CheckAuctionBid<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckAuctionBid")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + orml_auction::Config + Send + Sync> CheckAuctionBid<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + orml_auction::Config + Send + Sync> Default for CheckAuctionBid<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + orml_auction::Config<AuctionId = AuctionId> + Send + Sync> CheckAuctionBid<T> {
+	/// Returns the bid priority of the collateral auction `id` if it is about to close.
+	fn closing_auction_priority(id: AuctionId) -> Option<TransactionPriority> {
+		let collateral_auction = <CollateralAuctions<T>>::get(id)?;
+		let end = T::Auction::auction_info(id)?.end?;
+		let now = frame_system::Pallet::<T>::block_number();
+		if end.saturating_sub(now) <= T::AuctionTimeToClose::get() {
+			Some(T::BidPriority::get(&collateral_auction.currency_id))
+		} else {
+			None
+		}
+	}
+}
+
+impl<T: Config + orml_auction::Config<AuctionId = AuctionId> + Send + Sync> SignedExtension for CheckAuctionBid<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<orml_auction::Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckAuctionBid";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if let Some(orml_auction::Call::bid { id, .. }) = call.is_sub_type() {
+			if let Some(priority) = Self::closing_auction_priority(*id) {
+				return Ok(ValidTransaction {
+					priority,
+					..Default::default()
+				});
+			}
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{
 	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU32, ConstU64, Nothing},
+	traits::{ConstU128, ConstU32, ConstU64, Nothing},
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
@@ -34,7 +34,7 @@ use primitives::{TokenSymbol, TradingPair};
 use sp_runtime::{
 	testing::{Header, TestXt},
 	traits::{AccountIdConversion, IdentityLookup, One as OneT},
-	BuildStorage,
+	BuildStorage, Percent,
 };
 
 pub type AccountId = u128;
@@ -117,6 +117,8 @@ impl module_cdp_treasury::Config for Runtime {
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
+	type PriceSource = MockPriceSource;
+	type MaxBasketAssets = ConstU32<10>;
 }
 
 parameter_types! {
@@ -148,6 +150,7 @@ parameter_types! {
 		TradingPair::from_currency_ids(DOT, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap()
 	];
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
 }
 
 impl module_dex::Config for Runtime {
@@ -163,6 +166,18 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type OnTradingPairEnabled = ();
+	type OnTradingPairDisabled = ();
+	type EvmAddressMapping = ();
+	type FlashSwapEvmBridge = ();
+	type GasToWeight = ();
+	type TreasuryAccount = TreasuryAccount;
+	type MinSwapCommitDeposit = ConstU128<100>;
+	type SwapRevealPeriod = ConstU64<10>;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = ConstU32<2>;
+	type LbpCheckpointPeriod = ConstU64<10>;
+	type MaxLbpPools = ConstU32<2>;
 }
 
 parameter_types! {
@@ -184,6 +199,12 @@ parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(1, 20);
 }
 
+parameter_type_with_key! {
+	pub BidPriority: |_currency_id: CurrencyId| -> TransactionPriority {
+		1 << 16
+	};
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Tokens;
@@ -195,6 +216,7 @@ impl Config for Runtime {
 	type CDPTreasury = CDPTreasuryModule;
 	type PriceSource = MockPriceSource;
 	type UnsignedPriority = ConstU64<1048576>; // 1 << 20
+	type BidPriority = BidPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
@@ -529,6 +529,69 @@ fn collateral_auction_with_bid_aborted() {
 	});
 }
 
+#[test]
+fn collateral_auction_split_into_lots_reconciles() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 300));
+		assert_ok!(CDPTreasuryModule::set_collateral_auction_lot_params(
+			RuntimeOrigin::signed(1),
+			100,
+			0
+		));
+
+		// the seized collateral of 300 for the target of 300 is split into 3 lots
+		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
+			BTC, 300, 300, ALICE, true
+		));
+		for auction_id in 0..3 {
+			let lot = AuctionManagerModule::collateral_auctions(auction_id).unwrap();
+			assert_eq!((lot.amount, lot.target), (100, 100));
+		}
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 300);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 300);
+
+		// lot 0 is sold at the target, lot 1 is sold in the reverse stage and lot 2 has no bid
+		assert_ok!(AuctionManagerModule::collateral_auction_bid_handler(
+			1,
+			0,
+			(BOB, 100),
+			None
+		));
+		assert_ok!(AuctionManagerModule::collateral_auction_bid_handler(
+			1,
+			1,
+			(BOB, 125),
+			None
+		));
+		// the over-raised collateral of lot 1 is refunded to the CDP owner
+		assert_eq!(AuctionManagerModule::collateral_auctions(1).unwrap().amount, 80);
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 1020);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 100)));
+		AuctionManagerModule::on_auction_ended(1, Some((BOB, 125)));
+		AuctionManagerModule::on_auction_ended(2, None);
+		System::assert_last_event(RuntimeEvent::AuctionManagerModule(
+			crate::Event::CollateralAuctionAborted {
+				auction_id: 2,
+				collateral_type: BTC,
+				collateral_amount: 100,
+				target_stable_amount: 100,
+				refund_recipient: ALICE,
+			},
+		));
+
+		// the lots reconcile to the seized collateral
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 1000 + 100 + 80);
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 1000 + 20);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 200);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 800);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+	});
+}
+
 #[test]
 fn swap_bidders_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -734,3 +797,153 @@ fn offchain_default_max_iterator_works() {
 		assert_eq!(pool_state.write().transactions.len(), 1001);
 	});
 }
+
+#[test]
+fn check_auction_bid_boosts_bids_of_closing_auctions() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let info = frame_support::dispatch::DispatchInfo::default();
+		let check = CheckAuctionBid::<Runtime>::new();
+		let bid_call = MockCall::AuctionModule(orml_auction::Call::bid { id: 0, value: 100 });
+
+		// the auction doesn't exist
+		assert_eq!(check.validate(&ALICE, &bid_call, &info, 0).unwrap().priority, 0);
+
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		// the auction ends at block 2001, far from closing
+		assert_eq!(check.validate(&ALICE, &bid_call, &info, 0).unwrap().priority, 0);
+
+		System::set_block_number(1901);
+		assert_eq!(check.validate(&ALICE, &bid_call, &info, 0).unwrap().priority, 1 << 16);
+		assert_ok!(check.clone().pre_dispatch(&ALICE, &bid_call, &info, 0));
+
+		// other calls are not boosted
+		let cancel_call = MockCall::AuctionManagerModule(crate::Call::cancel { id: 0 });
+		assert_eq!(check.validate(&ALICE, &cancel_call, &info, 0).unwrap().priority, 0);
+	});
+}
+
+#[test]
+fn get_auction_info_across_soft_cap_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(AuctionManagerModule::get_auction_info(0), None);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+
+		assert_eq!(
+			AuctionManagerModule::get_auction_info(0),
+			Some(AuctionInfoView {
+				auction_id: 0,
+				kind: AuctionKind::Collateral,
+				currency_id: BTC,
+				amount: 10,
+				target: 100,
+				refund_recipient: ALICE,
+				bid: None,
+				minimum_next_bid: 5,
+				phase: AuctionPhase::Normal,
+				settlement_amount: 0,
+				start: 1,
+				end: Some(2001),
+			})
+		);
+		assert!(AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 4).is_err());
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 5));
+
+		let info = AuctionManagerModule::get_auction_info(0).unwrap();
+		assert_eq!(info.bid, Some((BOB, 5)));
+		assert_eq!(info.minimum_next_bid, 10);
+		assert_eq!(info.phase, AuctionPhase::Normal);
+		// the bid is below the target, the auction would be aborted
+		assert_eq!(info.settlement_amount, 0);
+		assert_eq!(info.end, Some(101));
+
+		// the minimum increment size doubles after the soft cap
+		System::set_block_number(2000);
+		assert_eq!(AuctionManagerModule::get_auction_info(0).unwrap().minimum_next_bid, 10);
+		System::set_block_number(2001);
+		assert_eq!(AuctionManagerModule::get_auction_info(0).unwrap().minimum_next_bid, 15);
+		assert!(!AuctionManagerModule::on_new_bid(2001, 0, (CAROL, 14), Some((BOB, 5))).accept_bid);
+		assert!(AuctionManagerModule::on_new_bid(2001, 0, (CAROL, 15), Some((BOB, 5))).accept_bid);
+	});
+}
+
+#[test]
+fn get_auction_info_in_reverse_phase_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 100));
+		let info = AuctionManagerModule::get_auction_info(0).unwrap();
+		assert_eq!(info.phase, AuctionPhase::Reverse);
+		assert_eq!(info.minimum_next_bid, 105);
+		assert_eq!(info.amount, 10);
+		assert_eq!(info.settlement_amount, 10);
+
+		// the collateral is refunded to the CDP owner in the reverse phase
+		assert!(AuctionModule::bid(RuntimeOrigin::signed(CAROL), 0, 104).is_err());
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(CAROL), 0, 200));
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 995);
+
+		let info = AuctionManagerModule::get_auction_info(0).unwrap();
+		assert_eq!(info.bid, Some((CAROL, 200)));
+		assert_eq!(info.phase, AuctionPhase::Reverse);
+		assert_eq!(info.minimum_next_bid, 210);
+		assert_eq!(info.amount, 5);
+		assert_eq!(info.settlement_amount, 5);
+
+		// the winner receives the previewed collateral
+		AuctionManagerModule::on_auction_ended(0, Some((CAROL, 200)));
+		System::assert_last_event(RuntimeEvent::AuctionManagerModule(
+			crate::Event::CollateralAuctionDealt {
+				auction_id: 0,
+				collateral_type: BTC,
+				collateral_amount: 5,
+				winner: CAROL,
+				payment_amount: 100,
+			},
+		));
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 1005);
+		assert_eq!(AuctionManagerModule::get_auction_info(0), None);
+	});
+}
+
+#[test]
+fn get_active_auctions_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(AuctionManagerModule::get_active_auctions(None, 10), (vec![], None));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 50));
+		for _ in 0..5 {
+			assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		}
+
+		let (page, mut cursor) = AuctionManagerModule::get_active_auctions(None, 2);
+		assert_eq!(page.len(), 2);
+		let mut seen: Vec<AuctionId> = page.iter().map(|info| info.auction_id).collect();
+
+		// end an auction not returned yet
+		let ended = (0..5).find(|id| !seen.contains(id)).unwrap();
+		AuctionManagerModule::on_auction_ended(ended, None);
+
+		while cursor.is_some() {
+			let (page, next) = AuctionManagerModule::get_active_auctions(cursor, 2);
+			assert!(page.len() <= 2);
+			seen.extend(page.iter().map(|info| info.auction_id));
+			cursor = next;
+		}
+		seen.sort();
+		let mut expected: Vec<AuctionId> = (0..5).filter(|id| *id != ended).collect();
+		expected.sort();
+		assert_eq!(seen, expected);
+
+		// the limit is bounded
+		let (page, cursor) = AuctionManagerModule::get_active_auctions(None, 0);
+		assert_eq!(page.len(), 1);
+		assert!(cursor.is_some());
+		assert_eq!(AuctionManagerModule::get_active_auctions(None, 1_000).0.len(), 4);
+	});
+}
@@ -23,27 +23,57 @@
 //! The core module of Honzon protocol. CDP engine is responsible for handle
 //! internal processes about CDPs, including liquidation, settlement and risk
 //! management.
+//!
+//! The offchain worker also checks the liquidation price alerts of the CDPs it
+//! iterates, and submits the tripped ones in a batched unsigned transaction,
+//! which emits the alert events for the indexers and notification services.
+//!
+//! Governance can rebase the debit balances of all the positions of a
+//! collateral type by a rational ratio, while the debit exchange rate is
+//! adjusted by the reciprocal so that the debit values are unchanged. The
+//! positions are rebased in batches by the idle scheduler and are frozen until
+//! the rebase completes or is aborted, the rounding dust is accounted to CDP
+//! treasury.
+//!
+//! The stability fees accrued and the liquidation penalties charged are summed
+//! up per `RevenuePeriod` blocks, the latest `MaxRevenuePeriods` periods are
+//! kept for the revenue dashboards.
+//!
+//! Every `RiskSnapshotPeriod` blocks, the aggregate risk exposure of every collateral type is
+//! computed and kept for the risk dashboards: the totals, the debit-weighted collateral ratio,
+//! the debit value which would be unsafe after the price drops of `RISK_PRICE_SHOCKS` and the
+//! debit concentration of the largest positions. The per-position figures are computed from at
+//! most `MaxRiskSnapshotPositions` positions of each collateral type.
+//!
+//! When a bridged collateral depegs permanently, governance can enable the substitution of it
+//! by another collateral type at a fixed rate until a deadline. The CDP owners swap the whole
+//! collateral of their positions atomically, the old collateral is absorbed by CDP treasury for
+//! the later recovery and the new one is paid from it, up to the cap of the incident.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
 use frame_support::{
-	pallet_prelude::*, traits::ExistenceRequirement, traits::UnixTime, transactional, BoundedVec, PalletId,
+	pallet_prelude::*, storage::with_storage_layer, traits::ExistenceRequirement, traits::UnixTime, transactional,
+	BoundedVec, PalletId,
 };
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
 };
+use module_loans::PositionIndex;
 use module_support::{
-	AddressMapping, CDPTreasury, CDPTreasuryExtended, DEXManager, EVMBridge, EmergencyShutdown, ExchangeRate,
-	FractionalRate, InvokeContext, LiquidateCollateral, LiquidationEvmBridge, Price, PriceProvider, Rate, Ratio,
-	RiskManager, Swap, SwapLimit,
+	AddressMapping, CDPTreasury, CDPTreasuryExtended, DEXManager, DispatchableTask, EVMBridge, EmergencyShutdown,
+	ExchangeRate, FractionalRate, IdleScheduler, InvokeContext, IssuanceOrigin, LiquidateCollateral,
+	LiquidatedValueProvider, LiquidationEvmBridge, LiquidationKind, LiquidationRecord, LiquidationRoute,
+	LiquidationStats, Price, PriceProvider, PriceTimestampProvider, Rate, Ratio, RiskManager, RiskSnapshot, SafeMode,
+	Swap, SwapLimit, RISK_CONCENTRATION_POSITIONS, RISK_PRICE_SHOCKS,
 };
 use orml_traits::{Change, GetByKey, MultiCurrency};
 use orml_utilities::OffchainErr;
-use parity_scale_codec::MaxEncodedLen;
-use primitives::{evm::EvmAddress, Amount, Balance, CurrencyId, Position};
+use parity_scale_codec::{FullCodec, MaxEncodedLen};
+use primitives::{evm::EvmAddress, task::TaskResult, Amount, Balance, CurrencyId, Moment, Nonce, Position};
 use rand_chacha::{
 	rand_core::{RngCore, SeedableRng},
 	ChaChaRng,
@@ -61,9 +91,9 @@ use sp_runtime::{
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Permill, RuntimeDebug,
 };
-use sp_std::{marker::PhantomData, prelude::*};
+use sp_std::{cmp, fmt::Debug, marker::PhantomData, prelude::*};
 
 mod mock;
 mod tests;
@@ -77,6 +107,13 @@ pub const OFFCHAIN_WORKER_LOCK: &[u8] = b"acala/cdp-engine/lock/";
 pub const OFFCHAIN_WORKER_MAX_ITERATIONS: &[u8] = b"acala/cdp-engine/max-iterations/";
 pub const LOCK_DURATION: u64 = 100;
 pub const DEFAULT_MAX_ITERATIONS: u32 = 1000;
+/// The custom code of the invalid unsigned liquidation when the price is stale.
+pub const STALE_PRICE_LIQUIDATION_FROZEN: u8 = 1;
+/// The custom code of the invalid unsigned alert trigger when an alert of the batch is not tripped.
+pub const POSITION_ALERT_NOT_TRIPPED: u8 = 2;
+/// The liquidity-sensitive debit ceiling is only updated when it moves by more
+/// than this share, to avoid flapping on small liquidity changes.
+pub const LIQUIDITY_CEILING_HYSTERESIS: Permill = Permill::from_percent(5);
 
 pub type LoansOf<T> = module_loans::Pallet<T>;
 pub type CurrencyOf<T> = <T as Config>::Currency;
@@ -106,6 +143,135 @@ pub struct RiskManagementParams {
 	/// of CDP so that the current collateral ratio is lower than the
 	/// required collateral ratio. `None` value means not set
 	pub required_collateral_ratio: Option<Ratio>,
+
+	/// Maximum age of the oracle price in milliseconds, when the price of
+	/// the collateral type is older than it, liquidation and new debit are
+	/// frozen until a fresh price arrives. `None` value means not set
+	pub max_price_age: Option<Moment>,
+}
+
+/// Promotional override of the interest rate of a collateral type
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PromotionalRate<BlockNumber> {
+	/// Interest rate per sec applied instead of the base interest rate
+	/// while the promotion is active
+	pub interest_rate_per_sec: FractionalRate,
+
+	/// The block at which the promotion expires, the base interest rate
+	/// resumes from then on
+	pub expires_at: BlockNumber,
+}
+
+/// Liquidation penalty cashback params of a collateral type
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PenaltyCashbackParams<BlockNumber> {
+	/// The share of the liquidation penalty set aside as cashback for the
+	/// liquidated CDP owner
+	pub split: Permill,
+
+	/// The period in blocks after the liquidation within which the owner can
+	/// claim the cashback
+	pub claim_period: BlockNumber,
+}
+
+/// Liquidation penalty cashback pending for the liquidated CDP owner
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PendingCashback<BlockNumber> {
+	/// The cashback amount of stable currency, paid from the surplus of CDP
+	/// treasury when claimed
+	pub amount: Balance,
+
+	/// The block at which the cashback expires and falls through to CDP
+	/// treasury
+	pub expires_at: BlockNumber,
+}
+
+/// The emergency substitution of a depegged collateral type
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct CollateralSubstitution<BlockNumber> {
+	/// The collateral type substituting the depegged one
+	pub new_currency_id: CurrencyId,
+
+	/// The amount of the new collateral paid per unit of the old collateral
+	pub rate: ExchangeRate,
+
+	/// The last block at which the collateral can be substituted
+	pub deadline: BlockNumber,
+
+	/// The maximum amount of the old collateral absorbed by CDP treasury in
+	/// the incident
+	pub cap: Balance,
+
+	/// The amount of the old collateral absorbed by CDP treasury so far
+	pub absorbed: Balance,
+}
+
+/// The protocol revenue of the CDPs within a revenue period
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct PeriodRevenue<BlockNumber> {
+	/// The first block of the period
+	pub period_start: BlockNumber,
+
+	/// The stability fees accrued as the interest of the debits, in stable
+	/// currency
+	pub stability_fee: Balance,
+
+	/// The liquidation penalties charged, including the cashback of the
+	/// liquidated owners, in stable currency
+	pub liquidation_penalty: Balance,
+}
+
+/// The maximum length of the raw storage key of the debit rebase cursor.
+pub const MAX_REBASE_CURSOR_LEN: u32 = 256;
+
+/// The raw storage key of a position visited by the debit rebase.
+pub type RebaseCursor = BoundedVec<u8, ConstU32<MAX_REBASE_CURSOR_LEN>>;
+
+/// The progress of the debit rebase of a collateral type
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct DebitRebaseState {
+	/// The debit balances of the positions are multiplied by `numerator /
+	/// denominator`, and the debit exchange rate by `denominator / numerator`
+	pub numerator: u32,
+
+	/// See `numerator`
+	pub denominator: u32,
+
+	/// The raw storage key of the last visited position, the next batch
+	/// starts after it
+	pub cursor: Option<RebaseCursor>,
+
+	/// The number of the positions rebased
+	pub rebased: u32,
+
+	/// The total debit balance of the rebased positions after the rebase
+	pub rebased_debit: Balance,
+
+	/// The total debit balance of the rebased positions before the rebase
+	pub original_debit: Balance,
+
+	/// The rounding dust of the rebased positions, in `1 / numerator` debit
+	/// units before the rebase. It is accounted to CDP treasury as debit when
+	/// the rebase finishes
+	pub dust: Balance,
+
+	/// Whether the rebase is paused, the collateral type stays frozen
+	pub paused: bool,
+
+	/// The raw storage key of the last rebased position when the rebase is
+	/// aborted, the rebased positions up to it are reverted. `None` means the
+	/// rebase is not aborted
+	pub revert_until: Option<RebaseCursor>,
+}
+
+/// Risk management params before `max_price_age` was added.
+#[derive(Decode)]
+struct RiskManagementParamsV0 {
+	maximum_total_debit_value: Balance,
+	interest_rate_per_sec: Option<FractionalRate>,
+	liquidation_ratio: Option<Ratio>,
+	liquidation_penalty: Option<FractionalRate>,
+	required_collateral_ratio: Option<Ratio>,
 }
 
 // typedef to help polkadot.js disambiguate Change with different generic
@@ -122,6 +288,26 @@ pub enum CDPStatus {
 	ChecksFailed(DispatchError),
 }
 
+/// The liquidation price alerts of the CDPs, checked by the offchain worker.
+pub trait PositionAlertHandler<AccountId> {
+	/// Whether any armed alert of the CDP is tripped at `collateral_ratio`.
+	fn is_alert_tripped(who: &AccountId, currency_id: CurrencyId, collateral_ratio: Ratio) -> bool;
+
+	/// Trigger the armed alerts of the CDP tripped at `collateral_ratio`, which are disarmed
+	/// until set again. Returns the number of the alerts triggered.
+	fn trigger_alerts(who: &AccountId, currency_id: CurrencyId, collateral_ratio: Ratio) -> u32;
+}
+
+impl<AccountId> PositionAlertHandler<AccountId> for () {
+	fn is_alert_tripped(_who: &AccountId, _currency_id: CurrencyId, _collateral_ratio: Ratio) -> bool {
+		false
+	}
+
+	fn trigger_alerts(_who: &AccountId, _currency_id: CurrencyId, _collateral_ratio: Ratio) -> u32 {
+		0
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -150,6 +336,31 @@ pub mod module {
 		#[pallet::constant]
 		type MinimumDebitValue: Get<Balance>;
 
+		/// The lower bound of the liquidation ratio, enforced on every update
+		/// regardless of the origin.
+		#[pallet::constant]
+		type MinLiquidationRatio: Get<Ratio>;
+
+		/// The upper bound of the liquidation ratio, enforced on every update
+		/// regardless of the origin.
+		#[pallet::constant]
+		type MaxLiquidationRatio: Get<Ratio>;
+
+		/// The upper bound of the liquidation penalty rate.
+		#[pallet::constant]
+		type MaxLiquidationPenalty: Get<Rate>;
+
+		/// The upper bound of the interest rate per sec, the promotional rate
+		/// included.
+		#[pallet::constant]
+		type MaxInterestRatePerSec: Get<Rate>;
+
+		/// The maximum multiple of the current hard cap of total debit value
+		/// that one update can raise it to, larger increases are clamped. The
+		/// first hard cap of a collateral type is not bounded.
+		#[pallet::constant]
+		type MaxDebitCeilingIncrease: Get<Ratio>;
+
 		/// Gets the minimum collateral value for the given currency.
 		type MinimumCollateralAmount: GetByKey<CurrencyId, Balance>;
 
@@ -164,19 +375,27 @@ pub mod module {
 		/// The CDP treasury to maintain bad debts and surplus generated by CDPs
 		type CDPTreasury: CDPTreasuryExtended<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
-		/// The price source of all types of currencies related to CDP
+		/// The price source of all types of currencies related to CDP, it should respect the
+		/// prices locked by governance or the emergency shutdown.
 		type PriceSource: PriceProvider<CurrencyId>;
 
-		/// A configuration for base priority of unsigned transactions.
+		/// The timestamp source of the oracle prices, to freeze the collateral
+		/// types whose price is stale
+		type PriceTimestampSource: PriceTimestampProvider<CurrencyId>;
+
+		/// A configuration for base priority of unsigned transactions, by the
+		/// collateral type of the CDP.
 		///
 		/// This is exposed so that it can be tuned for particular runtime, when
 		/// multiple modules send unsigned transactions.
-		#[pallet::constant]
-		type UnsignedPriority: Get<TransactionPriority>;
+		type UnsignedPriority: GetByKey<CurrencyId, TransactionPriority>;
 
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// Safe mode, new debit is frozen while it is active.
+		type SafeMode: SafeMode;
+
 		/// Time used for computing era duration.
 		///
 		/// It is guaranteed to start being called from the first `on_finalize`.
@@ -203,6 +422,11 @@ pub mod module {
 		#[pallet::constant]
 		type MaxLiquidationContracts: Get<u32>;
 
+		/// The maximum number of collateral types in the cross-margin
+		/// preference of an account.
+		#[pallet::constant]
+		type MaxCrossMarginPreferences: Get<u32>;
+
 		type LiquidationEvmBridge: LiquidationEvmBridge;
 
 		#[pallet::constant]
@@ -216,6 +440,58 @@ pub mod module {
 		/// Evm Origin account when settle erc20 type CDP
 		type SettleErc20EvmOrigin: Get<Self::AccountId>;
 
+		/// The maximum number of the latest liquidation records kept for an
+		/// account, the oldest one is rotated out when exceeded.
+		#[pallet::constant]
+		type MaxLiquidationRecords: Get<u32>;
+
+		/// The liquidation price alerts of the CDPs.
+		type PositionAlerts: PositionAlertHandler<Self::AccountId>;
+
+		/// The maximum number of the CDPs in a batch of the tripped alerts
+		/// submitted by the offchain worker.
+		#[pallet::constant]
+		type MaxPositionAlertBatch: Get<u32>;
+
+		/// The maximum factor of a debit rebase, neither the ratio nor its
+		/// reciprocal can exceed it.
+		#[pallet::constant]
+		type MaxDebitRebaseFactor: Get<u32>;
+
+		/// The number of blocks of a revenue period.
+		#[pallet::constant]
+		type RevenuePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of the latest revenue periods kept, the oldest
+		/// one is rotated out when exceeded.
+		#[pallet::constant]
+		type MaxRevenuePeriods: Get<u32>;
+
+		/// The number of blocks between the risk snapshots.
+		#[pallet::constant]
+		type RiskSnapshotPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of the positions of a collateral type sampled by a risk
+		/// snapshot.
+		#[pallet::constant]
+		type MaxRiskSnapshotPositions: Get<u32>;
+
+		/// The debit value from which an unsafe CDP is only partially liquidated, the CDPs below
+		/// it are liquidated as a whole.
+		#[pallet::constant]
+		type PartialLiquidationThreshold: Get<Balance>;
+
+		/// The buffer above the target collateral ratio that a partial liquidation restores the
+		/// remaining position to.
+		#[pallet::constant]
+		type PartialLiquidationBuffer: Get<Ratio>;
+
+		/// Dispatchable tasks
+		type Task: DispatchableTask + FullCodec + Debug + Clone + PartialEq + TypeInfo + From<CDPEngineTask<Self>>;
+
+		/// Idle scheduler for the debit rebase
+		type IdleScheduler: IdleScheduler<Nonce, Self::Task>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -262,6 +538,70 @@ pub mod module {
 		CollateralContractNotFound,
 		/// Invalid rate
 		InvalidRate,
+		/// Liquidation is frozen because the oracle price of the collateral is stale
+		LiquidationFrozenByStalePrice,
+		/// New debit is frozen because the oracle price of the collateral is stale
+		DebitFrozenByStalePrice,
+		/// New debit is frozen because the safe mode is active
+		DebitFrozenBySafeMode,
+		/// Exceeds `T::MaxCrossMarginPreferences`.
+		TooManyCrossMarginPreferences,
+		/// The cross-margin preference contains duplicated, lp token or invalid
+		/// collateral types
+		InvalidCrossMarginPreference,
+		/// The promotional rate must expire after the current block
+		InvalidPromotionExpiry,
+		/// The claim period of the penalty cashback must be non-zero
+		InvalidCashbackClaimPeriod,
+		/// No pending penalty cashback
+		NoPendingCashback,
+		/// The penalty cashback has expired
+		CashbackExpired,
+		/// The penalty cashback has not expired yet
+		CashbackNotExpired,
+		/// Claiming the penalty cashback requires an active safe CDP of the
+		/// same collateral type
+		NoQualifyingPosition,
+		/// The liquidation ratio is out of the bounds of the runtime
+		LiquidationRatioOutOfBounds,
+		/// The liquidation penalty exceeds the cap of the runtime
+		LiquidationPenaltyTooHigh,
+		/// The interest rate per sec exceeds the cap of the runtime
+		InterestRateTooHigh,
+		/// The debit rebase ratio is zero, identity or exceeds the max factor
+		InvalidDebitRebaseRatio,
+		/// A debit rebase of the collateral type is already scheduled
+		DebitRebaseAlreadyScheduled,
+		/// No debit rebase of the collateral type is scheduled
+		DebitRebaseNotFound,
+		/// The debit rebase is already paused
+		DebitRebaseAlreadyPaused,
+		/// The debit rebase is not paused
+		DebitRebaseNotPaused,
+		/// The debit rebase is already aborted
+		DebitRebaseAlreadyAborted,
+		/// The debit value of a position changed by more than the rounding of
+		/// the debit rebase
+		DebitRebaseValueMismatch,
+		/// The positions of the collateral type are frozen by an ongoing debit
+		/// rebase
+		FrozenByDebitRebase,
+		/// The raw storage key of the debit rebase cursor is too long
+		InvalidRebaseCursor,
+		/// The collateral substitution params are invalid
+		InvalidCollateralSubstitution,
+		/// The substitution of the collateral type is already enabled
+		CollateralSubstitutionExists,
+		/// The substitution of the collateral type is not enabled
+		CollateralSubstitutionNotFound,
+		/// The deadline of the collateral substitution has passed
+		CollateralSubstitutionExpired,
+		/// The cap of the collateral substitution is exhausted
+		CollateralSubstitutionCapExceeded,
+		/// There is no position of the collateral type to substitute
+		NoPositionToSubstitute,
+		/// The collateral to add is zero
+		ZeroCollateralIncrease,
 	}
 
 	#[pallet::event]
@@ -313,10 +653,172 @@ pub mod module {
 			collateral_type: CurrencyId,
 			new_total_debit_value: Balance,
 		},
+		/// The requested hard cap of total debit value for specific collateral type exceeds
+		/// the maximum increase of one update, the clamped value is applied instead.
+		MaximumTotalDebitValueClamped {
+			collateral_type: CurrencyId,
+			requested: Balance,
+			applied: Balance,
+		},
 		/// A new liquidation contract is registered.
 		LiquidationContractRegistered { address: EvmAddress },
 		/// A new liquidation contract is deregistered.
 		LiquidationContractDeregistered { address: EvmAddress },
+		/// The maximum price age for specific collateral type updated.
+		MaxPriceAgeUpdated {
+			collateral_type: CurrencyId,
+			new_max_price_age: Option<Moment>,
+		},
+		/// Liquidation and new debit of the collateral type are frozen as its price is stale.
+		FrozenByStalePrice { collateral_type: CurrencyId },
+		/// Liquidation and new debit of the collateral type are resumed as a fresh price arrived.
+		ResumedByFreshPrice { collateral_type: CurrencyId },
+		/// The cross-margin preference of the account updated.
+		CrossMarginPreferenceUpdated {
+			owner: T::AccountId,
+			collaterals: Vec<CurrencyId>,
+		},
+		/// Excess collateral of a CDP is swapped into another unsafe CDP of the owner.
+		CrossMarginRebalanced {
+			owner: T::AccountId,
+			from_collateral_type: CurrencyId,
+			to_collateral_type: CurrencyId,
+			supply_amount: Balance,
+			target_amount: Balance,
+		},
+		/// The cross-margin rescue of the unsafe CDP is attempted, the CDP is
+		/// liquidated if it is not rescued.
+		CrossMarginRescueAttempted {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			rebalanced_count: u32,
+			rescued: bool,
+		},
+		/// The promotional interest rate for specific collateral type activated or extended.
+		PromotionalRateActivated {
+			collateral_type: CurrencyId,
+			interest_rate_per_sec: Rate,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// The promotional interest rate for specific collateral type expired, the base
+		/// interest rate resumed.
+		PromotionalRateExpired { collateral_type: CurrencyId },
+		/// The promotional interest rate for specific collateral type cancelled before expiry.
+		PromotionalRateCancelled { collateral_type: CurrencyId },
+		/// The liquidation penalty cashback params for specific collateral type updated.
+		PenaltyCashbackParamsUpdated {
+			collateral_type: CurrencyId,
+			split: Permill,
+			claim_period: BlockNumberFor<T>,
+		},
+		/// The share of the liquidation penalty is set aside as cashback for the liquidated
+		/// CDP owner.
+		LiquidationPenaltySplit {
+			collateral_type: CurrencyId,
+			owner: T::AccountId,
+			penalty_amount: Balance,
+			cashback_amount: Balance,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// The penalty cashback is claimed by the owner.
+		PenaltyCashbackClaimed {
+			collateral_type: CurrencyId,
+			owner: T::AccountId,
+			amount: Balance,
+		},
+		/// The penalty cashback expired unclaimed and falls through to CDP treasury.
+		PenaltyCashbackExpired {
+			collateral_type: CurrencyId,
+			owner: T::AccountId,
+			amount: Balance,
+		},
+		/// The multiplier of the liquidity-sensitive debit ceiling for specific collateral type
+		/// updated, `None` means the static hard cap is used alone.
+		LiquidityCeilingMultiplierUpdated {
+			collateral_type: CurrencyId,
+			multiplier: Option<Ratio>,
+		},
+		/// The liquidity-sensitive debit ceiling for specific collateral type updated.
+		LiquidityDebitCeilingUpdated {
+			collateral_type: CurrencyId,
+			liquidity_value: Balance,
+			new_debit_ceiling: Balance,
+		},
+		/// The debit rebase of the collateral type is scheduled, its positions are frozen
+		/// until the rebase finishes.
+		DebitRebaseScheduled {
+			collateral_type: CurrencyId,
+			numerator: u32,
+			denominator: u32,
+		},
+		/// A batch of the positions of the collateral type is rebased.
+		DebitRebaseProgressed { collateral_type: CurrencyId, rebased: u32 },
+		/// All the positions of the collateral type are rebased and the debit exchange rate
+		/// is adjusted, the rounding dust is accounted to CDP treasury.
+		DebitRebaseCompleted {
+			collateral_type: CurrencyId,
+			rebased: u32,
+			new_debit_exchange_rate: ExchangeRate,
+			dust_value: Balance,
+		},
+		/// The debit rebase of the collateral type is paused.
+		DebitRebasePaused { collateral_type: CurrencyId },
+		/// The debit rebase of the collateral type is resumed.
+		DebitRebaseResumed { collateral_type: CurrencyId },
+		/// The debit rebase is halted as the debit value of the position is not preserved,
+		/// it is paused before the position.
+		DebitRebaseHalted {
+			collateral_type: CurrencyId,
+			owner: T::AccountId,
+		},
+		/// The debit rebase of the collateral type is aborted, the rebased positions are
+		/// being reverted.
+		DebitRebaseAborting { collateral_type: CurrencyId, rebased: u32 },
+		/// The debit rebase of the collateral type is aborted and all the rebased positions are
+		/// reverted, the rounding dust is accounted to CDP treasury.
+		DebitRebaseAborted {
+			collateral_type: CurrencyId,
+			dust_value: Balance,
+		},
+		/// The substitution of the depegged collateral type is enabled.
+		CollateralSubstitutionEnabled {
+			old_collateral_type: CurrencyId,
+			new_collateral_type: CurrencyId,
+			rate: ExchangeRate,
+			deadline: BlockNumberFor<T>,
+			cap: Balance,
+		},
+		/// The collateral of the CDP is substituted, the old collateral is absorbed by CDP
+		/// treasury.
+		CollateralSubstituted {
+			owner: T::AccountId,
+			old_collateral_type: CurrencyId,
+			new_collateral_type: CurrencyId,
+			old_collateral_amount: Balance,
+			new_collateral_amount: Balance,
+			old_debit_amount: Balance,
+			new_debit_amount: Balance,
+		},
+		/// The substitution of the depegged collateral type is disabled.
+		CollateralSubstitutionDisabled {
+			old_collateral_type: CurrencyId,
+			absorbed: Balance,
+		},
+		/// The risk snapshot of the collateral type is updated.
+		RiskSnapshotUpdated {
+			collateral_type: CurrencyId,
+			snapshot: RiskSnapshot<BlockNumberFor<T>>,
+		},
+		/// Collateral is added to the CDP urgently, `collateral_ratio` is `None` if the price is
+		/// unavailable.
+		PositionCollateralExpanded {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			position_index: PositionIndex,
+			collateral_amount: Balance,
+			collateral_ratio: Option<Ratio>,
+			is_safe: bool,
+		},
 	}
 
 	/// Mapping from collateral type to its exchange rate of debit units and
@@ -341,11 +843,138 @@ pub mod module {
 	#[pallet::getter(fn last_accumulation_secs)]
 	pub type LastAccumulationSecs<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// The collateral types frozen by stale price, only used to emit the
+	/// events on the freeze and resume transitions
+	///
+	/// FrozenByStalePrice: CurrencyId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_by_stale_price)]
+	pub type FrozenByStalePrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, bool, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn liquidation_contracts)]
 	pub type LiquidationContracts<T: Config> =
 		StorageValue<_, BoundedVec<EvmAddress, T::MaxLiquidationContracts>, ValueQuery>;
 
+	/// Mapping from account to the ordered collateral types of its CDPs to
+	/// draw excess collateral from when any of its CDPs becomes unsafe
+	///
+	/// CrossMarginPreferences: AccountId => BoundedVec<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn cross_margin_preferences)]
+	pub type CrossMarginPreferences<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<CurrencyId, T::MaxCrossMarginPreferences>, ValueQuery>;
+
+	/// Mapping from collateral type to its promotional interest rate, which
+	/// overrides the base interest rate until it expires
+	///
+	/// PromotionalRates: CurrencyId => Option<PromotionalRate>
+	#[pallet::storage]
+	#[pallet::getter(fn promotional_rates)]
+	pub type PromotionalRates<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, PromotionalRate<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Mapping from collateral type to its liquidation penalty cashback
+	/// params, the whole penalty goes to CDP treasury if not set
+	///
+	/// CashbackParams: CurrencyId => Option<PenaltyCashbackParams>
+	#[pallet::storage]
+	#[pallet::getter(fn cashback_params)]
+	pub type CashbackParams<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, PenaltyCashbackParams<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The liquidation penalty cashbacks pending for the liquidated CDP
+	/// owners
+	///
+	/// PendingCashbacks: double_map CurrencyId, AccountId => Option<PendingCashback>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_cashbacks)]
+	pub type PendingCashbacks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		Twox64Concat,
+		T::AccountId,
+		PendingCashback<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Mapping from collateral type to the multiplier of its liquidity-sensitive
+	/// debit ceiling, only the static hard cap applies if not set
+	///
+	/// LiquidityCeilingMultipliers: CurrencyId => Option<Ratio>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidity_ceiling_multipliers)]
+	pub type LiquidityCeilingMultipliers<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Ratio, OptionQuery>;
+
+	/// Mapping from collateral type to its debit ceiling measured from the
+	/// liquidity to sell the collateral
+	///
+	/// LiquidityDebitCeilings: CurrencyId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidity_debit_ceilings)]
+	pub type LiquidityDebitCeilings<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, OptionQuery>;
+
+	/// Mapping from account to its latest liquidation records, the oldest
+	/// first
+	///
+	/// LiquidationHistory: AccountId => BoundedVec<LiquidationRecord>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_history)]
+	pub type LiquidationHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<LiquidationRecord<CurrencyId, BlockNumberFor<T>>, T::MaxLiquidationRecords>,
+		ValueQuery,
+	>;
+
+	/// Mapping from account to the aggregate of all its liquidation records,
+	/// including the rotated out ones
+	///
+	/// LiquidationStatistics: AccountId => LiquidationStats
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_statistics)]
+	pub type LiquidationStatistics<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, LiquidationStats, ValueQuery>;
+
+	/// The cumulative debit value of all the liquidated CDPs
+	///
+	/// TotalLiquidatedValue: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_liquidated_value)]
+	pub type TotalLiquidatedValue<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The protocol revenue of the latest revenue periods, the oldest first
+	///
+	/// RevenueHistory: BoundedVec<PeriodRevenue>
+	#[pallet::storage]
+	#[pallet::getter(fn revenue_history)]
+	pub type RevenueHistory<T: Config> =
+		StorageValue<_, BoundedVec<PeriodRevenue<BlockNumberFor<T>>, T::MaxRevenuePeriods>, ValueQuery>;
+
+	/// The ongoing debit rebase of the collateral type.
+	///
+	/// DebitRebases: map CurrencyId => Option<DebitRebaseState>
+	#[pallet::storage]
+	#[pallet::getter(fn debit_rebases)]
+	pub type DebitRebases<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, DebitRebaseState, OptionQuery>;
+
+	/// Mapping from the depegged collateral type to its emergency substitution
+	///
+	/// CollateralSubstitutions: map CurrencyId => Option<CollateralSubstitution>
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_substitutions)]
+	pub type CollateralSubstitutions<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, CollateralSubstitution<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The latest risk snapshot of the collateral type.
+	///
+	/// RiskSnapshots: map CurrencyId => Option<RiskSnapshot>
+	#[pallet::storage]
+	#[pallet::getter(fn risk_snapshots)]
+	pub type RiskSnapshots<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, RiskSnapshot<BlockNumberFor<T>>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T> {
@@ -383,6 +1012,7 @@ pub mod module {
 							liquidation_penalty: liquidation_penalty
 								.map(|v| FractionalRate::try_from(v).expect("liquidation_penalty out of bound")),
 							required_collateral_ratio: *required_collateral_ratio,
+							max_price_age: None,
 						},
 					);
 				},
@@ -390,7 +1020,10 @@ pub mod module {
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -400,16 +1033,34 @@ pub mod module {
 		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
 			// only after the block #1, `T::UnixTime::now()` will not report error.
 			// https://github.com/paritytech/substrate/blob/4ff92f10058cfe1b379362673dd369e33a919e66/frame/timestamp/src/lib.rs#L276
-			// so accumulate interest at the beginning of the block #2
-			let now_as_secs: u64 = if now > One::one() {
-				T::UnixTime::now().as_secs()
+			// so accumulate interest and check stale prices at the beginning of the block #2
+			let (now_as_secs, checked_count): (u64, u32) = if now > One::one() {
+				(T::UnixTime::now().as_secs(), Self::update_stale_price_freezes())
 			} else {
 				Default::default()
 			};
-			<T as Config>::WeightInfo::on_initialize(Self::accumulate_interest(
-				now_as_secs,
-				Self::last_accumulation_secs(),
-			))
+			let accumulated_count = Self::accumulate_interest(now_as_secs, Self::last_accumulation_secs());
+			// expire the promotions after the accumulation, as the interval accumulated in this
+			// block elapsed before the expiry block
+			let (promotion_count, expired_count) = Self::expire_promotional_rates(now);
+			let ceiling_count = Self::update_liquidity_debit_ceilings();
+			let snapshot_weight = Self::update_risk_snapshots(now);
+			<T as Config>::WeightInfo::on_initialize(accumulated_count)
+				.saturating_add(snapshot_weight)
+				// the revenue history
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(
+					T::DbWeight::get().reads_writes(3u64.saturating_mul(checked_count.into()), checked_count.into()),
+				)
+				.saturating_add(
+					T::DbWeight::get().reads_writes(promotion_count.saturating_add(1).into(), expired_count.into()),
+				)
+				// the params, the oracle price, the swap routes and the ceiling of every collateral
+				// type with the multiplier set
+				.saturating_add(T::DbWeight::get().reads_writes(
+					10u64.saturating_mul(ceiling_count.into()).saturating_add(1),
+					ceiling_count.into(),
+				))
 		}
 
 		/// Runs after every block. Start offchain worker to check CDP and
@@ -430,6 +1081,11 @@ pub mod module {
 				);
 			}
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -441,7 +1097,7 @@ pub mod module {
 		/// - `currency_id`: CDP's collateral type.
 		/// - `who`: CDP's owner.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::liquidate_by_auction(<T as Config>::CDPTreasury::max_auction()))]
+		#[pallet::weight(Pallet::<T>::max_liquidate_weight())]
 		pub fn liquidate(
 			origin: OriginFor<T>,
 			currency_id: CurrencyId,
@@ -487,7 +1143,11 @@ pub mod module {
 		///   means update it to `None`.
 		/// - `required_collateral_ratio`: required collateral ratio, `None` means do not update,
 		///   `Some(None)` means update it to `None`.
-		/// - `maximum_total_debit_value`: maximum total debit value.
+		/// - `maximum_total_debit_value`: maximum total debit value, an increase beyond
+		///   `MaxDebitCeilingIncrease` times the current value is clamped.
+		///
+		/// The interest rate, liquidation ratio and liquidation penalty must be within the
+		/// bounds of the runtime, whatever the origin is.
 		#[pallet::call_index(2)]
 		#[pallet::weight((<T as Config>::WeightInfo::set_collateral_params(), DispatchClass::Operational))]
 		pub fn set_collateral_params(
@@ -503,6 +1163,9 @@ pub mod module {
 
 			let mut collateral_params = Self::collateral_params(currency_id).unwrap_or_default();
 			if let Change::NewValue(maybe_rate) = interest_rate_per_sec {
+				if let Some(rate) = maybe_rate {
+					ensure!(rate <= T::MaxInterestRatePerSec::get(), Error::<T>::InterestRateTooHigh);
+				}
 				match (collateral_params.interest_rate_per_sec.as_mut(), maybe_rate) {
 					(Some(existing), Some(rate)) => existing.try_set(rate).map_err(|_| Error::<T>::InvalidRate)?,
 					(None, Some(rate)) => {
@@ -517,6 +1180,12 @@ pub mod module {
 				});
 			}
 			if let Change::NewValue(update) = liquidation_ratio {
+				if let Some(ratio) = update {
+					ensure!(
+						ratio >= T::MinLiquidationRatio::get() && ratio <= T::MaxLiquidationRatio::get(),
+						Error::<T>::LiquidationRatioOutOfBounds
+					);
+				}
 				collateral_params.liquidation_ratio = update;
 				Self::deposit_event(Event::LiquidationRatioUpdated {
 					collateral_type: currency_id,
@@ -524,6 +1193,12 @@ pub mod module {
 				});
 			}
 			if let Change::NewValue(maybe_rate) = liquidation_penalty {
+				if let Some(rate) = maybe_rate {
+					ensure!(
+						rate <= T::MaxLiquidationPenalty::get(),
+						Error::<T>::LiquidationPenaltyTooHigh
+					);
+				}
 				match (collateral_params.liquidation_penalty.as_mut(), maybe_rate) {
 					(Some(existing), Some(rate)) => existing.try_set(rate).map_err(|_| Error::<T>::InvalidRate)?,
 					(None, Some(rate)) => {
@@ -544,7 +1219,23 @@ pub mod module {
 					new_required_collateral_ratio: update,
 				});
 			}
-			if let Change::NewValue(val) = maximum_total_debit_value {
+			if let Change::NewValue(requested) = maximum_total_debit_value {
+				let current = collateral_params.maximum_total_debit_value;
+				let val = if current.is_zero() {
+					requested
+				} else {
+					let cap = T::MaxDebitCeilingIncrease::get()
+						.saturating_mul_int(current)
+						.max(current);
+					if requested > cap {
+						Self::deposit_event(Event::MaximumTotalDebitValueClamped {
+							collateral_type: currency_id,
+							requested,
+							applied: cap,
+						});
+					}
+					requested.min(cap)
+				};
 				collateral_params.maximum_total_debit_value = val;
 				Self::deposit_event(Event::MaximumTotalDebitValueUpdated {
 					collateral_type: currency_id,
@@ -574,77 +1265,757 @@ pub mod module {
 			Self::deposit_event(Event::LiquidationContractDeregistered { address });
 			Ok(())
 		}
-	}
 
-	#[pallet::validate_unsigned]
-	impl<T: Config> ValidateUnsigned for Pallet<T> {
-		type Call = Call<T>;
+		/// Update the maximum age of the oracle price for specific collateral
+		/// type. When the price is older than it, liquidation and new debit of
+		/// the collateral type are frozen until a fresh price arrives.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `max_price_age`: maximum price age in milliseconds, `None` means no freeze.
+		#[pallet::call_index(5)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_max_price_age(), DispatchClass::Operational))]
+		pub fn set_max_price_age(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			max_price_age: Option<Moment>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			CollateralParams::<T>::try_mutate(currency_id, |maybe_params| -> DispatchResult {
+				let params = maybe_params.as_mut().ok_or(Error::<T>::InvalidCollateralType)?;
+				params.max_price_age = max_price_age;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::MaxPriceAgeUpdated {
+				collateral_type: currency_id,
+				new_max_price_age: max_price_age,
+			});
+			Ok(())
+		}
 
-		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
-			match call {
-				Call::liquidate { currency_id, who } => {
-					let account = T::Lookup::lookup(who.clone())?;
-					let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &account);
-					if !matches!(
-						Self::check_cdp_status(*currency_id, collateral, debit),
-						CDPStatus::Unsafe
-					) || T::EmergencyShutdown::is_shutdown()
-					{
-						return InvalidTransaction::Stale.into();
-					}
+		/// Register the cross-margin preference of the caller. When any CDP of
+		/// the caller becomes unsafe, the excess collateral of the CDPs in the
+		/// preference is swapped into it in order before falling back to
+		/// liquidation.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `collaterals`: ordered collateral types to draw excess collateral from, empty means
+		///   remove the preference.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_cross_margin_preference())]
+		pub fn set_cross_margin_preference(origin: OriginFor<T>, collaterals: Vec<CurrencyId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let preference: BoundedVec<CurrencyId, T::MaxCrossMarginPreferences> = collaterals
+				.clone()
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyCrossMarginPreferences)?;
+			for (index, currency_id) in preference.iter().enumerate() {
+				ensure!(
+					CollateralParams::<T>::contains_key(currency_id)
+						&& !matches!(currency_id, CurrencyId::DexShare(..))
+						&& !preference[..index].contains(currency_id),
+					Error::<T>::InvalidCrossMarginPreference
+				);
+			}
 
-					ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
-						.priority(T::UnsignedPriority::get())
-						.and_provides((<frame_system::Pallet<T>>::block_number(), currency_id, who))
-						.longevity(64_u64)
-						.propagate(true)
-						.build()
+			if preference.is_empty() {
+				CrossMarginPreferences::<T>::remove(&who);
+			} else {
+				CrossMarginPreferences::<T>::insert(&who, preference);
+			}
+			Self::deposit_event(Event::CrossMarginPreferenceUpdated {
+				owner: who,
+				collaterals,
+			});
+			Ok(())
+		}
+
+		/// Set or cancel the promotional interest rate for specific collateral
+		/// type. The promotional rate overrides the base interest rate until
+		/// the expiry block, from then on the base interest rate resumes
+		/// without further action. Updating the base interest rate by
+		/// `set_collateral_params` during the promotion only takes effect after
+		/// it expires.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `promotion`: the promotional interest rate per sec and its expiry block, replaces
+		///   the active promotion to extend or adjust it. `None` means cancel the promotion.
+		#[pallet::call_index(7)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_promotional_rate(), DispatchClass::Operational))]
+		pub fn set_promotional_rate(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			promotion: Option<(Rate, BlockNumberFor<T>)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				CollateralParams::<T>::contains_key(currency_id),
+				Error::<T>::InvalidCollateralType
+			);
+
+			match promotion {
+				Some((rate, expires_at)) => {
+					ensure!(
+						expires_at > <frame_system::Pallet<T>>::block_number(),
+						Error::<T>::InvalidPromotionExpiry
+					);
+					ensure!(rate <= T::MaxInterestRatePerSec::get(), Error::<T>::InterestRateTooHigh);
+					let interest_rate_per_sec = FractionalRate::try_from(rate).map_err(|_| Error::<T>::InvalidRate)?;
+					PromotionalRates::<T>::insert(
+						currency_id,
+						PromotionalRate {
+							interest_rate_per_sec,
+							expires_at,
+						},
+					);
+					Self::deposit_event(Event::PromotionalRateActivated {
+						collateral_type: currency_id,
+						interest_rate_per_sec: rate,
+						expires_at,
+					});
 				}
-				Call::settle { currency_id, who } => {
-					let account = T::Lookup::lookup(who.clone())?;
-					let Position { debit, .. } = <LoansOf<T>>::positions(currency_id, account);
-					if debit.is_zero() || !T::EmergencyShutdown::is_shutdown() {
-						return InvalidTransaction::Stale.into();
+				None => {
+					if PromotionalRates::<T>::take(currency_id).is_some() {
+						Self::deposit_event(Event::PromotionalRateCancelled {
+							collateral_type: currency_id,
+						});
 					}
-
-					ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
-						.priority(T::UnsignedPriority::get())
-						.and_provides((currency_id, who))
-						.longevity(64_u64)
-						.propagate(true)
-						.build()
 				}
-				_ => InvalidTransaction::Call.into(),
 			}
+			Ok(())
 		}
-	}
-}
-
-impl<T: Config> Pallet<T> {
-	fn accumulate_interest(now_secs: u64, last_accumulation_secs: u64) -> u32 {
-		let mut count: u32 = 0;
 
-		if !T::EmergencyShutdown::is_shutdown() && !now_secs.is_zero() {
-			let interval_secs = now_secs.saturating_sub(last_accumulation_secs);
-
-			for currency_id in Self::get_collateral_currency_ids() {
-				if let Ok(interest_rate) = Self::get_interest_rate_per_sec(currency_id) {
-					let rate_to_accumulate = Self::compound_interest_rate(interest_rate, interval_secs);
-					let total_debits = <LoansOf<T>>::total_positions(currency_id).debit;
-
-					if !rate_to_accumulate.is_zero() && !total_debits.is_zero() {
+		/// Set the liquidation penalty cashback params for specific collateral
+		/// type. When a CDP is liquidated, the `split` share of the penalty is
+		/// set aside as cashback for the owner, who can claim it within the
+		/// `claim_period` by holding an active safe CDP of the same collateral
+		/// type. Zero `split` disables the cashback.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `split`: the share of the liquidation penalty for the cashback.
+		/// - `claim_period`: the period in blocks to claim the cashback.
+		#[pallet::call_index(8)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_penalty_cashback_params(), DispatchClass::Operational))]
+		pub fn set_penalty_cashback_params(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			split: Permill,
+			claim_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				CollateralParams::<T>::contains_key(currency_id),
+				Error::<T>::InvalidCollateralType
+			);
+
+			if split.is_zero() {
+				CashbackParams::<T>::remove(currency_id);
+			} else {
+				ensure!(!claim_period.is_zero(), Error::<T>::InvalidCashbackClaimPeriod);
+				CashbackParams::<T>::insert(currency_id, PenaltyCashbackParams { split, claim_period });
+			}
+			Self::deposit_event(Event::PenaltyCashbackParamsUpdated {
+				collateral_type: currency_id,
+				split,
+				claim_period,
+			});
+			Ok(())
+		}
+
+		/// Claim the pending liquidation penalty cashback before it expires.
+		/// The caller must hold an active safe CDP of the same collateral type.
+		///
+		/// The dispatch origin of this call must be Signed by the liquidated
+		/// CDP owner.
+		///
+		/// - `currency_id`: collateral type.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_penalty_cashback())]
+		pub fn claim_penalty_cashback(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let cashback = PendingCashbacks::<T>::take(currency_id, &who).ok_or(Error::<T>::NoPendingCashback)?;
+			ensure!(
+				cashback.expires_at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::CashbackExpired
+			);
+
+			let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
+			ensure!(
+				!debit.is_zero() && matches!(Self::check_cdp_status(currency_id, collateral, debit), CDPStatus::Safe),
+				Error::<T>::NoQualifyingPosition
+			);
+
+			<T as Config>::CDPTreasury::withdraw_surplus(&who, cashback.amount)?;
+			Self::deposit_event(Event::PenaltyCashbackClaimed {
+				collateral_type: currency_id,
+				owner: who,
+				amount: cashback.amount,
+			});
+			Ok(())
+		}
+
+		/// Remove the expired liquidation penalty cashback, it falls through
+		/// to CDP treasury.
+		///
+		/// The dispatch origin of this call must be Signed.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `who`: the liquidated CDP owner.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::sweep_expired_penalty_cashback())]
+		pub fn sweep_expired_penalty_cashback(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let cashback = PendingCashbacks::<T>::take(currency_id, &who).ok_or(Error::<T>::NoPendingCashback)?;
+			ensure!(
+				cashback.expires_at <= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::CashbackNotExpired
+			);
+
+			Self::deposit_event(Event::PenaltyCashbackExpired {
+				collateral_type: currency_id,
+				owner: who,
+				amount: cashback.amount,
+			});
+			Ok(())
+		}
+
+		/// Set the multiplier of the liquidity-sensitive debit ceiling for
+		/// specific collateral type. The effective hard cap is the lower of
+		/// the static hard cap and `multiplier` times the liquidity value of
+		/// the collateral, updated every block. Lowering it only blocks new
+		/// debit, existing positions are not affected.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `multiplier`: the multiplier of the liquidity value, `None` means only the static
+		///   hard cap applies.
+		#[pallet::call_index(11)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_liquidity_ceiling_multiplier(), DispatchClass::Operational))]
+		pub fn set_liquidity_ceiling_multiplier(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			multiplier: Option<Ratio>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				CollateralParams::<T>::contains_key(currency_id),
+				Error::<T>::InvalidCollateralType
+			);
+
+			match multiplier {
+				Some(multiplier) => {
+					LiquidityCeilingMultipliers::<T>::insert(currency_id, multiplier);
+					// measure the ceiling right away, regardless of the hysteresis
+					LiquidityDebitCeilings::<T>::remove(currency_id);
+					Self::update_liquidity_debit_ceiling(currency_id, multiplier);
+				}
+				None => {
+					LiquidityCeilingMultipliers::<T>::remove(currency_id);
+					LiquidityDebitCeilings::<T>::remove(currency_id);
+				}
+			}
+			Self::deposit_event(Event::LiquidityCeilingMultiplierUpdated {
+				collateral_type: currency_id,
+				multiplier,
+			});
+			Ok(())
+		}
+
+		/// Trigger the tripped liquidation price alerts of the CDPs, the
+		/// alerts which are no longer tripped are skipped.
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `currency_id`: CDP's collateral type.
+		/// - `owners`: the owners of the CDPs, in ascending order.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::trigger_position_alerts(owners.len() as u32))]
+		pub fn trigger_position_alerts(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			owners: BoundedVec<T::AccountId, T::MaxPositionAlertBatch>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			for who in owners.iter() {
+				if let Some(collateral_ratio) = Self::position_collateral_ratio(currency_id, who) {
+					T::PositionAlerts::trigger_alerts(who, currency_id, collateral_ratio);
+				}
+			}
+			Ok(())
+		}
+
+		/// Schedule a rebase of the debit balances of all the positions of
+		/// specific collateral type. The debit balance of every position is
+		/// multiplied by `numerator / denominator` and the debit exchange rate
+		/// by `denominator / numerator`, so that the debit values are
+		/// unchanged. The positions are rebased in batches by the idle
+		/// scheduler, they are frozen until the rebase finishes, and the
+		/// rounding dust is accounted to CDP treasury.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `numerator`: the numerator of the ratio applied to the debit balances.
+		/// - `denominator`: the denominator of the ratio applied to the debit balances.
+		#[pallet::call_index(13)]
+		#[pallet::weight((<T as Config>::WeightInfo::schedule_debit_rebase(), DispatchClass::Operational))]
+		pub fn schedule_debit_rebase(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			numerator: u32,
+			denominator: u32,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(
+				CollateralParams::<T>::contains_key(currency_id),
+				Error::<T>::InvalidCollateralType
+			);
+			let max_factor = u64::from(T::MaxDebitRebaseFactor::get());
+			ensure!(
+				!numerator.is_zero()
+					&& !denominator.is_zero()
+					&& numerator != denominator
+					&& u64::from(numerator) <= max_factor.saturating_mul(denominator.into())
+					&& u64::from(denominator) <= max_factor.saturating_mul(numerator.into()),
+				Error::<T>::InvalidDebitRebaseRatio
+			);
+			ensure!(
+				!DebitRebases::<T>::contains_key(currency_id),
+				Error::<T>::DebitRebaseAlreadyScheduled
+			);
+
+			DebitRebases::<T>::insert(
+				currency_id,
+				DebitRebaseState {
+					numerator,
+					denominator,
+					..Default::default()
+				},
+			);
+			T::IdleScheduler::schedule(
+				CDPEngineTask::DebitRebase {
+					currency_id,
+					scheduled_at: <frame_system::Pallet<T>>::block_number(),
+				}
+				.into(),
+			)?;
+
+			Self::deposit_event(Event::DebitRebaseScheduled {
+				collateral_type: currency_id,
+				numerator,
+				denominator,
+			});
+			Ok(())
+		}
+
+		/// Pause the debit rebase of specific collateral type, the positions
+		/// stay frozen until it is resumed or aborted.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		#[pallet::call_index(14)]
+		#[pallet::weight((<T as Config>::WeightInfo::pause_debit_rebase(), DispatchClass::Operational))]
+		pub fn pause_debit_rebase(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			DebitRebases::<T>::try_mutate(currency_id, |maybe_state| -> DispatchResult {
+				let state = maybe_state.as_mut().ok_or(Error::<T>::DebitRebaseNotFound)?;
+				ensure!(!state.paused, Error::<T>::DebitRebaseAlreadyPaused);
+				state.paused = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::DebitRebasePaused {
+				collateral_type: currency_id,
+			});
+			Ok(())
+		}
+
+		/// Resume the paused debit rebase of specific collateral type.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		#[pallet::call_index(15)]
+		#[pallet::weight((<T as Config>::WeightInfo::resume_debit_rebase(), DispatchClass::Operational))]
+		pub fn resume_debit_rebase(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			DebitRebases::<T>::try_mutate(currency_id, |maybe_state| -> DispatchResult {
+				let state = maybe_state.as_mut().ok_or(Error::<T>::DebitRebaseNotFound)?;
+				ensure!(state.paused, Error::<T>::DebitRebaseNotPaused);
+				state.paused = false;
+				Ok(())
+			})?;
+			// the task of a paused rebase is finished, schedule a new one
+			T::IdleScheduler::schedule(
+				CDPEngineTask::DebitRebase {
+					currency_id,
+					scheduled_at: <frame_system::Pallet<T>>::block_number(),
+				}
+				.into(),
+			)?;
+
+			Self::deposit_event(Event::DebitRebaseResumed {
+				collateral_type: currency_id,
+			});
+			Ok(())
+		}
+
+		/// Abort the debit rebase of specific collateral type. The rebased
+		/// positions are reverted in batches by the idle scheduler, and the
+		/// debit exchange rate is unchanged.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		#[pallet::call_index(16)]
+		#[pallet::weight((<T as Config>::WeightInfo::abort_debit_rebase(), DispatchClass::Operational))]
+		pub fn abort_debit_rebase(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let mut state = Self::debit_rebases(currency_id).ok_or(Error::<T>::DebitRebaseNotFound)?;
+			ensure!(state.revert_until.is_none(), Error::<T>::DebitRebaseAlreadyAborted);
+
+			let Some(last_rebased) = state.cursor.take() else {
+				// nothing is rebased yet
+				DebitRebases::<T>::remove(currency_id);
+				Self::deposit_event(Event::DebitRebaseAborted {
+					collateral_type: currency_id,
+					dust_value: Zero::zero(),
+				});
+				return Ok(());
+			};
+
+			state.revert_until = Some(last_rebased);
+			let was_paused = sp_std::mem::replace(&mut state.paused, false);
+			DebitRebases::<T>::insert(currency_id, &state);
+			if was_paused {
+				T::IdleScheduler::schedule(
+					CDPEngineTask::DebitRebase {
+						currency_id,
+						scheduled_at: <frame_system::Pallet<T>>::block_number(),
+					}
+					.into(),
+				)?;
+			}
+
+			Self::deposit_event(Event::DebitRebaseAborting {
+				collateral_type: currency_id,
+				rebased: state.rebased,
+			});
+			Ok(())
+		}
+
+		/// Enable the emergency substitution of a depegged collateral type.
+		/// Until the deadline, the CDP owners can substitute the whole
+		/// collateral of their positions by the new collateral type at the
+		/// fixed rate, paid from CDP treasury, which absorbs the old collateral
+		/// up to the cap.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `old_currency_id`: the depegged collateral type.
+		/// - `new_currency_id`: the collateral type substituting it.
+		/// - `rate`: the amount of the new collateral per unit of the old one.
+		/// - `deadline`: the last block at which the collateral can be substituted.
+		/// - `cap`: the maximum amount of the old collateral absorbed.
+		#[pallet::call_index(17)]
+		#[pallet::weight((<T as Config>::WeightInfo::enable_collateral_substitution(), DispatchClass::Operational))]
+		pub fn enable_collateral_substitution(
+			origin: OriginFor<T>,
+			old_currency_id: CurrencyId,
+			new_currency_id: CurrencyId,
+			rate: ExchangeRate,
+			deadline: BlockNumberFor<T>,
+			cap: Balance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				CollateralParams::<T>::contains_key(old_currency_id)
+					&& CollateralParams::<T>::contains_key(new_currency_id),
+				Error::<T>::InvalidCollateralType
+			);
+			ensure!(
+				old_currency_id != new_currency_id
+					&& !rate.is_zero()
+					&& !cap.is_zero()
+					&& deadline > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidCollateralSubstitution
+			);
+			ensure!(
+				!CollateralSubstitutions::<T>::contains_key(old_currency_id),
+				Error::<T>::CollateralSubstitutionExists
+			);
+
+			CollateralSubstitutions::<T>::insert(
+				old_currency_id,
+				CollateralSubstitution {
+					new_currency_id,
+					rate,
+					deadline,
+					cap,
+					absorbed: Zero::zero(),
+				},
+			);
+			Self::deposit_event(Event::CollateralSubstitutionEnabled {
+				old_collateral_type: old_currency_id,
+				new_collateral_type: new_currency_id,
+				rate,
+				deadline,
+				cap,
+			});
+			Ok(())
+		}
+
+		/// Disable the substitution of a depegged collateral type, before or
+		/// after its deadline. The absorbed old collateral stays in CDP
+		/// treasury.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `old_currency_id`: the depegged collateral type.
+		#[pallet::call_index(18)]
+		#[pallet::weight((<T as Config>::WeightInfo::disable_collateral_substitution(), DispatchClass::Operational))]
+		pub fn disable_collateral_substitution(origin: OriginFor<T>, old_currency_id: CurrencyId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let substitution = CollateralSubstitutions::<T>::take(old_currency_id)
+				.ok_or(Error::<T>::CollateralSubstitutionNotFound)?;
+			Self::deposit_event(Event::CollateralSubstitutionDisabled {
+				old_collateral_type: old_currency_id,
+				absorbed: substitution.absorbed,
+			});
+			Ok(())
+		}
+
+		/// Substitute the whole collateral of the caller's CDP of the depegged
+		/// collateral type by the new collateral type, the debit value is
+		/// carried over. The resulting CDP must be safe under the risk params
+		/// of the new collateral type.
+		///
+		/// The dispatch origin of this call must be Signed by the CDP owner.
+		///
+		/// - `old_currency_id`: the depegged collateral type.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::substitute_collateral())]
+		pub fn substitute_collateral(origin: OriginFor<T>, old_currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_substitute_collateral(&who, old_currency_id)
+		}
+
+		/// Add collateral to the caller's CDP without any other adjustment, even if the CDP is
+		/// unsafe or the system is shutdown. The pending liquidation of the CDP fails once it's
+		/// safe again.
+		///
+		/// The dispatch origin of this call must be Signed by the CDP owner.
+		///
+		/// - `currency_id`: CDP's collateral type.
+		/// - `position_index`: the index of the position.
+		/// - `increase_collateral`: the amount of the collateral to add.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::expand_position_collateral_urgent())]
+		pub fn expand_position_collateral_urgent(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			position_index: PositionIndex,
+			#[pallet::compact] increase_collateral: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_expand_position_collateral_urgent(&who, currency_id, position_index, increase_collateral)
+		}
+
+		/// Liquidate the unsafe position of CDP at `position_index`
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `currency_id`: CDP's collateral type.
+		/// - `who`: CDP's owner.
+		/// - `position_index`: the index of the position.
+		#[pallet::call_index(20)]
+		#[pallet::weight(Pallet::<T>::max_liquidate_weight())]
+		pub fn liquidate_position(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+			position_index: PositionIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			let consumed_weight: Weight = Self::liquidate_unsafe_position(who, currency_id, position_index)?;
+			Ok(Some(consumed_weight).into())
+		}
+
+		/// Settle the position of CDP at `position_index` has debit after system shutdown
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `currency_id`: CDP's collateral type.
+		/// - `who`: CDP's owner.
+		/// - `position_index`: the index of the position.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle())]
+		pub fn settle_position(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+			position_index: PositionIndex,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(T::EmergencyShutdown::is_shutdown(), Error::<T>::MustAfterShutdown);
+			Self::settle_position_has_debit(who, currency_id, position_index)?;
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::liquidate { currency_id, who } => Self::validate_liquidation(*currency_id, who, 0),
+				Call::liquidate_position {
+					currency_id,
+					who,
+					position_index,
+				} => Self::validate_liquidation(*currency_id, who, *position_index),
+				Call::trigger_position_alerts { currency_id, owners } => {
+					// the owners are strictly ascending to rule out the duplicates
+					if owners.is_empty() || !owners.windows(2).all(|pair| pair[0] < pair[1]) {
+						return InvalidTransaction::Call.into();
+					}
+					if T::EmergencyShutdown::is_shutdown() || Self::is_price_stale(*currency_id) {
+						return InvalidTransaction::Stale.into();
+					}
+					// every alert of the batch must be tripped, so fake alerts can't be submitted
+					let all_tripped = owners.iter().all(|who| {
+						Self::position_collateral_ratio(*currency_id, who).map_or(false, |collateral_ratio| {
+							T::PositionAlerts::is_alert_tripped(who, *currency_id, collateral_ratio)
+						})
+					});
+					if !all_tripped {
+						return InvalidTransaction::Custom(POSITION_ALERT_NOT_TRIPPED).into();
+					}
+
+					owners
+						.iter()
+						.fold(
+							ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
+								// the alerts yield to the liquidations
+								.priority(T::UnsignedPriority::get(currency_id) / 2)
+								.longevity(64_u64)
+								.propagate(true),
+							|builder, who| builder.and_provides((b"alert", currency_id, who)),
+						)
+						.build()
+				}
+				Call::settle { currency_id, who } => Self::validate_settlement(*currency_id, who, 0),
+				Call::settle_position {
+					currency_id,
+					who,
+					position_index,
+				} => Self::validate_settlement(*currency_id, who, *position_index),
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn validate_liquidation(
+		currency_id: CurrencyId,
+		who: &<T::Lookup as StaticLookup>::Source,
+		position_index: PositionIndex,
+	) -> TransactionValidity {
+		let account = T::Lookup::lookup(who.clone())?;
+		if Self::is_price_stale(currency_id) {
+			return InvalidTransaction::Custom(STALE_PRICE_LIQUIDATION_FROZEN).into();
+		}
+		let Position { collateral, debit } = <LoansOf<T>>::position_at(currency_id, &account, position_index);
+		if !matches!(
+			Self::check_cdp_status(currency_id, collateral, debit),
+			CDPStatus::Unsafe
+		) || T::EmergencyShutdown::is_shutdown()
+			|| DebitRebases::<T>::contains_key(currency_id)
+		{
+			return InvalidTransaction::Stale.into();
+		}
+
+		ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
+			.priority(T::UnsignedPriority::get(&currency_id))
+			.and_provides((
+				<frame_system::Pallet<T>>::block_number(),
+				currency_id,
+				who,
+				position_index,
+			))
+			.longevity(64_u64)
+			.propagate(true)
+			.build()
+	}
+
+	fn validate_settlement(
+		currency_id: CurrencyId,
+		who: &<T::Lookup as StaticLookup>::Source,
+		position_index: PositionIndex,
+	) -> TransactionValidity {
+		let account = T::Lookup::lookup(who.clone())?;
+		let Position { debit, .. } = <LoansOf<T>>::position_at(currency_id, account, position_index);
+		if debit.is_zero() || !T::EmergencyShutdown::is_shutdown() || DebitRebases::<T>::contains_key(currency_id) {
+			return InvalidTransaction::Stale.into();
+		}
+
+		ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
+			.priority(T::UnsignedPriority::get(&currency_id))
+			.and_provides((currency_id, who, position_index))
+			.longevity(64_u64)
+			.propagate(true)
+			.build()
+	}
+
+	fn accumulate_interest(now_secs: u64, last_accumulation_secs: u64) -> u32 {
+		let mut count: u32 = 0;
+
+		if !T::EmergencyShutdown::is_shutdown() && !now_secs.is_zero() {
+			let interval_secs = now_secs.saturating_sub(last_accumulation_secs);
+			let mut stability_fee: Balance = Zero::zero();
+
+			for currency_id in Self::get_collateral_currency_ids() {
+				if let Ok(interest_rate) = Self::get_interest_rate_per_sec(currency_id) {
+					let rate_to_accumulate = Self::compound_interest_rate(interest_rate, interval_secs);
+					let total_debits = Self::total_debit_in_rate_basis(currency_id);
+
+					if !rate_to_accumulate.is_zero() && !total_debits.is_zero() {
 						let debit_exchange_rate = Self::get_debit_exchange_rate(currency_id);
 						let debit_exchange_rate_increment = debit_exchange_rate.saturating_mul(rate_to_accumulate);
 						let issued_stable_coin_balance = debit_exchange_rate_increment.saturating_mul_int(total_debits);
 
 						// issue stablecoin to surplus pool
-						let res = <T as Config>::CDPTreasury::on_system_surplus(issued_stable_coin_balance);
+						let res = <T as Config>::CDPTreasury::on_system_surplus(
+							issued_stable_coin_balance,
+							IssuanceOrigin::Collateral(currency_id),
+						);
 						match res {
 							Ok(_) => {
 								// update exchange rate when issue success
 								let new_debit_exchange_rate =
 									debit_exchange_rate.saturating_add(debit_exchange_rate_increment);
 								DebitExchangeRate::<T>::insert(currency_id, new_debit_exchange_rate);
+								stability_fee = stability_fee.saturating_add(issued_stable_coin_balance);
 							}
 							Err(e) => {
 								log::warn!(
@@ -659,6 +2030,12 @@ impl<T: Config> Pallet<T> {
 					count += 1;
 				}
 			}
+
+			if !stability_fee.is_zero() {
+				Self::record_revenue(|revenue| {
+					revenue.stability_fee = revenue.stability_fee.saturating_add(stability_fee)
+				});
+			}
 		}
 
 		// update last accumulation timestamp
@@ -666,32 +2043,274 @@ impl<T: Config> Pallet<T> {
 		count
 	}
 
-	fn submit_unsigned_liquidation_tx(currency_id: CurrencyId, who: T::AccountId) {
+	/// Freeze or resume the collateral types by the age of their prices, the events are only
+	/// emitted on the transitions.
+	fn update_stale_price_freezes() -> u32 {
+		let mut count: u32 = 0;
+
+		for currency_id in Self::get_collateral_currency_ids() {
+			let is_stale = Self::is_price_stale(currency_id);
+			if is_stale != Self::frozen_by_stale_price(currency_id) {
+				if is_stale {
+					FrozenByStalePrice::<T>::insert(currency_id, true);
+					Self::deposit_event(Event::FrozenByStalePrice {
+						collateral_type: currency_id,
+					});
+				} else {
+					FrozenByStalePrice::<T>::remove(currency_id);
+					Self::deposit_event(Event::ResumedByFreshPrice {
+						collateral_type: currency_id,
+					});
+				}
+			}
+			count += 1;
+		}
+
+		count
+	}
+
+	/// Remove the promotional interest rates expiring at `now`, the base interest rates
+	/// resume from then on. Returns the count of the promotions checked and expired.
+	fn expire_promotional_rates(now: BlockNumberFor<T>) -> (u32, u32) {
+		let mut count: u32 = 0;
+		let expired = PromotionalRates::<T>::iter()
+			.inspect(|_| count += 1)
+			.filter(|(_, promotion)| promotion.expires_at <= now)
+			.map(|(currency_id, _)| currency_id)
+			.collect::<Vec<_>>();
+
+		for currency_id in expired.iter() {
+			PromotionalRates::<T>::remove(currency_id);
+			Self::deposit_event(Event::PromotionalRateExpired {
+				collateral_type: *currency_id,
+			});
+		}
+
+		(count, expired.len() as u32)
+	}
+
+	/// Update the liquidity-sensitive debit ceilings of the collateral types which have the
+	/// multiplier set. Returns the count of the collateral types updated.
+	fn update_liquidity_debit_ceilings() -> u32 {
+		let mut count: u32 = 0;
+		for (currency_id, multiplier) in LiquidityCeilingMultipliers::<T>::iter() {
+			Self::update_liquidity_debit_ceiling(currency_id, multiplier);
+			count += 1;
+		}
+		count
+	}
+
+	/// Measure the liquidity-sensitive debit ceiling of the collateral type, it's only updated
+	/// when it moves by more than `LIQUIDITY_CEILING_HYSTERESIS`, or when it recovers to the static
+	/// hard cap. It's kept unchanged if the oracle price is not available.
+	fn update_liquidity_debit_ceiling(currency_id: CurrencyId, multiplier: Ratio) {
+		let Ok(hard_cap) = Self::maximum_total_debit_value(currency_id) else {
+			return;
+		};
+		let Some(liquidity_value) = Self::collateral_liquidity_value(currency_id, hard_cap) else {
+			return;
+		};
+		let new_debit_ceiling = multiplier.saturating_mul_int(liquidity_value).min(hard_cap);
+
+		let should_update = match Self::liquidity_debit_ceilings(currency_id) {
+			Some(debit_ceiling) => {
+				new_debit_ceiling != debit_ceiling
+					&& (new_debit_ceiling == hard_cap
+						|| new_debit_ceiling.abs_diff(debit_ceiling)
+							> LIQUIDITY_CEILING_HYSTERESIS.mul_floor(debit_ceiling))
+			}
+			None => true,
+		};
+
+		if should_update {
+			LiquidityDebitCeilings::<T>::insert(currency_id, new_debit_ceiling);
+			Self::deposit_event(Event::LiquidityDebitCeilingUpdated {
+				collateral_type: currency_id,
+				liquidity_value,
+				new_debit_ceiling,
+			});
+		}
+	}
+
+	/// Take the risk snapshots of all the collateral types every `RiskSnapshotPeriod` blocks.
+	/// Returns the weight consumed.
+	fn update_risk_snapshots(now: BlockNumberFor<T>) -> Weight {
+		let period = T::RiskSnapshotPeriod::get();
+		if period.is_zero() || !(now % period).is_zero() {
+			return Weight::zero();
+		}
+
+		Self::get_collateral_currency_ids()
+			.into_iter()
+			.fold(Weight::zero(), |weight, currency_id| {
+				let sampled_positions = Self::update_risk_snapshot(currency_id, now);
+				weight.saturating_add(<T as Config>::WeightInfo::update_risk_snapshot(sampled_positions))
+			})
+	}
+
+	/// Take the risk snapshot of the collateral type, the per-position figures are computed from
+	/// at most `MaxRiskSnapshotPositions` of its positions. The snapshot is kept unchanged if the
+	/// oracle price is not available or the debit rebase is ongoing. Returns the number of the
+	/// positions sampled.
+	pub fn update_risk_snapshot(currency_id: CurrencyId, now: BlockNumberFor<T>) -> u32 {
+		if DebitRebases::<T>::contains_key(currency_id) {
+			return 0;
+		}
+		let Ok(liquidation_ratio) = Self::get_liquidation_ratio(currency_id) else {
+			return 0;
+		};
+		let Some(price) = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get()) else {
+			return 0;
+		};
+
+		let sample_cap = T::MaxRiskSnapshotPositions::get();
+		let mut sampled_positions: u32 = 0;
+		let mut is_complete = true;
+		let mut sampled_collateral_value: Balance = Zero::zero();
+		let mut sampled_debit_value: Balance = Zero::zero();
+		let mut unsafe_debit_value_under_shocks = RISK_PRICE_SHOCKS.map(|shock| (shock, Balance::zero()));
+		// the debit values of the largest positions, in descending order
+		let mut top_debit_values: Vec<Balance> = Vec::with_capacity(RISK_CONCENTRATION_POSITIONS + 1);
+
+		for (_, position) in <LoansOf<T>>::iter_positions_of_collateral(currency_id, None) {
+			if sampled_positions == sample_cap {
+				is_complete = false;
+				break;
+			}
+			sampled_positions += 1;
+
+			let collateral_value = price.saturating_mul_int(position.collateral);
+			let debit_value = Self::get_debit_value(currency_id, position.debit);
+			sampled_collateral_value = sampled_collateral_value.saturating_add(collateral_value);
+			sampled_debit_value = sampled_debit_value.saturating_add(debit_value);
+			if debit_value.is_zero() {
+				continue;
+			}
+
+			for (shock, unsafe_debit_value) in unsafe_debit_value_under_shocks.iter_mut() {
+				let shocked_collateral_value = collateral_value.saturating_sub(shock.mul_floor(collateral_value));
+				if Ratio::checked_from_rational(shocked_collateral_value, debit_value)
+					.map_or(false, |ratio| ratio < liquidation_ratio)
+				{
+					*unsafe_debit_value = unsafe_debit_value.saturating_add(debit_value);
+				}
+			}
+
+			let index = top_debit_values.partition_point(|v| *v >= debit_value);
+			if index < RISK_CONCENTRATION_POSITIONS {
+				top_debit_values.insert(index, debit_value);
+				top_debit_values.truncate(RISK_CONCENTRATION_POSITIONS);
+			}
+		}
+
+		let total_positions = <LoansOf<T>>::total_positions(currency_id);
+		let top_debit_value = top_debit_values
+			.iter()
+			.fold(Balance::zero(), |acc, v| acc.saturating_add(*v));
+		let snapshot = RiskSnapshot {
+			block_number: now,
+			total_debit_value: Self::get_debit_value(currency_id, total_positions.debit),
+			total_collateral_value: price.saturating_mul_int(total_positions.collateral),
+			weighted_collateral_ratio: Ratio::checked_from_rational(sampled_collateral_value, sampled_debit_value),
+			unsafe_debit_value_under_shocks,
+			top_positions_debit_share: if sampled_debit_value.is_zero() {
+				Zero::zero()
+			} else {
+				Permill::from_rational(top_debit_value, sampled_debit_value)
+			},
+			sampled_positions,
+			sample_cap,
+			is_complete,
+		};
+
+		RiskSnapshots::<T>::insert(currency_id, &snapshot);
+		Self::deposit_event(Event::RiskSnapshotUpdated {
+			collateral_type: currency_id,
+			snapshot,
+		});
+		sampled_positions
+	}
+
+	/// The stable currency value of the liquidity to sell the collateral through the swap routes,
+	/// which is the stable currency quoted for selling the collateral worth `value` at the oracle
+	/// price. It drops along with the slippage when the liquidity shrinks. `None` if the oracle
+	/// price is not available.
+	fn collateral_liquidity_value(currency_id: CurrencyId, value: Balance) -> Option<Balance> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let supply_amount =
+			T::PriceSource::get_relative_price(stable_currency_id, currency_id)?.saturating_mul_int(value);
+		Some(
+			T::Swap::get_swap_amount(
+				currency_id,
+				stable_currency_id,
+				SwapLimit::ExactSupply(supply_amount, Zero::zero()),
+			)
+			.map(|(_, target_amount)| target_amount)
+			.unwrap_or_default(),
+		)
+	}
+
+	fn submit_unsigned_liquidation_tx(currency_id: CurrencyId, who: T::AccountId, position_index: PositionIndex) {
 		let who = T::Lookup::unlookup(who);
-		let call = Call::<T>::liquidate {
-			currency_id,
-			who: who.clone(),
+		// the primary positions keep the original call
+		let call = if position_index.is_zero() {
+			Call::<T>::liquidate {
+				currency_id,
+				who: who.clone(),
+			}
+		} else {
+			Call::<T>::liquidate_position {
+				currency_id,
+				who: who.clone(),
+				position_index,
+			}
 		};
 		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
 			log::info!(
 				target: "cdp-engine offchain worker",
-				"submit unsigned liquidation tx for \nCDP - AccountId {:?} CurrencyId {:?} \nfailed!",
-				who, currency_id,
+				"submit unsigned liquidation tx for \nCDP - AccountId {:?} CurrencyId {:?} PositionIndex {:?} \nfailed!",
+				who, currency_id, position_index,
 			);
 		}
 	}
 
-	fn submit_unsigned_settlement_tx(currency_id: CurrencyId, who: T::AccountId) {
+	fn submit_unsigned_settlement_tx(currency_id: CurrencyId, who: T::AccountId, position_index: PositionIndex) {
 		let who = T::Lookup::unlookup(who);
-		let call = Call::<T>::settle {
+		let call = if position_index.is_zero() {
+			Call::<T>::settle {
+				currency_id,
+				who: who.clone(),
+			}
+		} else {
+			Call::<T>::settle_position {
+				currency_id,
+				who: who.clone(),
+				position_index,
+			}
+		};
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "cdp-engine offchain worker",
+				"submit unsigned settlement tx for \nCDP - AccountId {:?} CurrencyId {:?} PositionIndex {:?} \nfailed!",
+				who, currency_id, position_index,
+			);
+		}
+	}
+
+	fn submit_unsigned_alerts_tx(currency_id: CurrencyId, mut owners: Vec<T::AccountId>) {
+		owners.sort();
+		let Ok(owners) = BoundedVec::<T::AccountId, T::MaxPositionAlertBatch>::try_from(owners) else {
+			return;
+		};
+		let call = Call::<T>::trigger_position_alerts {
 			currency_id,
-			who: who.clone(),
+			owners: owners.clone(),
 		};
 		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
 			log::info!(
 				target: "cdp-engine offchain worker",
-				"submit unsigned settlement tx for \nCDP - AccountId {:?} CurrencyId {:?} \nfailed!",
-				who, currency_id,
+				"submit unsigned position alerts tx for \nCDPs - AccountIds {:?} CurrencyId {:?} \nfailed!",
+				owners, currency_id,
 			);
 		}
 	}
@@ -745,30 +2364,53 @@ impl<T: Config> Pallet<T> {
 		};
 
 		let is_shutdown = T::EmergencyShutdown::is_shutdown();
+		// do not liquidate against a stale price
+		let is_liquidation_frozen = Self::is_price_stale(currency_id);
+		if is_liquidation_frozen && !is_shutdown {
+			log::debug!(
+				target: "cdp-engine offchain worker",
+				"liquidation of {:?} is frozen by stale price",
+				currency_id,
+			);
+		}
 
 		// If start key is Some(value) continue iterating from that point in storage otherwise start
-		// iterating from the beginning of <module_loans::Positions<T>>
-		let mut map_iterator = match start_key.clone() {
-			Some(key) => <module_loans::Positions<T>>::iter_prefix_from(currency_id, key),
-			None => <module_loans::Positions<T>>::iter_prefix(currency_id),
-		};
+		// iterating from the beginning of <module_loans::Positions<T>>, including the positions not
+		// indexed yet by the loans migration
+		let mut map_iterator = <LoansOf<T>>::iter_positions_of_collateral(currency_id, start_key.clone());
 
 		let mut finished = true;
 		let mut iteration_count = 0;
 		let iteration_start_time = sp_io::offchain::timestamp();
+		let mut tripped_alerts: Vec<T::AccountId> = vec![];
 
 		#[allow(clippy::while_let_on_iterator)]
-		while let Some((who, Position { collateral, debit })) = map_iterator.next() {
+		while let Some(((who, position_index), Position { collateral, debit })) = map_iterator.next() {
+			// do not trigger the alerts against a stale price, the alerts are of the primary positions
+			if !is_shutdown && !is_liquidation_frozen && position_index.is_zero() {
+				let is_alert_tripped = Self::position_collateral_ratio(currency_id, &who)
+					.map_or(false, |collateral_ratio| {
+						T::PositionAlerts::is_alert_tripped(&who, currency_id, collateral_ratio)
+					});
+				if is_alert_tripped {
+					tripped_alerts.push(who.clone());
+					if tripped_alerts.len() as u32 >= T::MaxPositionAlertBatch::get() {
+						Self::submit_unsigned_alerts_tx(currency_id, sp_std::mem::take(&mut tripped_alerts));
+					}
+				}
+			}
+
 			if !is_shutdown
+				&& !is_liquidation_frozen
 				&& matches!(
 					Self::check_cdp_status(currency_id, collateral, debit),
 					CDPStatus::Unsafe
 				) {
 				// liquidate unsafe CDPs before emergency shutdown occurs
-				Self::submit_unsigned_liquidation_tx(currency_id, who);
+				Self::submit_unsigned_liquidation_tx(currency_id, who, position_index);
 			} else if is_shutdown && !debit.is_zero() {
 				// settle CDPs with debit after emergency shutdown occurs.
-				Self::submit_unsigned_settlement_tx(currency_id, who);
+				Self::submit_unsigned_settlement_tx(currency_id, who, position_index);
 			}
 
 			iteration_count += 1;
@@ -779,6 +2421,9 @@ impl<T: Config> Pallet<T> {
 			// extend offchain worker lock
 			guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
 		}
+		if !tripped_alerts.is_empty() {
+			Self::submit_unsigned_alerts_tx(currency_id, tripped_alerts);
+		}
 		let iteration_end_time = sp_io::offchain::timestamp();
 		log::debug!(
 			target: "cdp-engine offchain worker",
@@ -827,8 +2472,32 @@ impl<T: Config> Pallet<T> {
 				}
 				Err(e) => CDPStatus::ChecksFailed(e),
 			}
-		} else {
-			CDPStatus::ChecksFailed(Error::<T>::InvalidFeedPrice.into())
+		} else {
+			CDPStatus::ChecksFailed(Error::<T>::InvalidFeedPrice.into())
+		}
+	}
+
+	/// The collateral ratio of the CDP with debit, `None` if it has no debit or the price is not
+	/// available.
+	pub fn position_collateral_ratio(currency_id: CurrencyId, who: &T::AccountId) -> Option<Ratio> {
+		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, who);
+		if debit.is_zero() {
+			return None;
+		}
+		let price = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())?;
+		Some(Self::calculate_collateral_ratio(currency_id, collateral, debit, price))
+	}
+
+	/// Check if the oracle price of the collateral type is older than its `max_price_age`, a
+	/// missing price timestamp is regarded as stale.
+	pub fn is_price_stale(currency_id: CurrencyId) -> bool {
+		match Self::collateral_params(currency_id).and_then(|params| params.max_price_age) {
+			Some(max_price_age) => {
+				let now: Moment = T::UnixTime::now().as_millis().unique_saturated_into();
+				T::PriceTimestampSource::get_price_timestamp(currency_id)
+					.map_or(true, |timestamp| now.saturating_sub(timestamp) > max_price_age)
+			}
+			None => false,
 		}
 	}
 
@@ -837,13 +2506,25 @@ impl<T: Config> Pallet<T> {
 		Ok(params.maximum_total_debit_value)
 	}
 
+	/// The effective hard cap of the total debit value of the collateral type, the lower of the
+	/// static hard cap and the liquidity-sensitive debit ceiling if set.
+	pub fn effective_maximum_total_debit_value(currency_id: CurrencyId) -> Result<Balance, DispatchError> {
+		let hard_cap = Self::maximum_total_debit_value(currency_id)?;
+		Ok(Self::liquidity_debit_ceilings(currency_id).map_or(hard_cap, |ceiling| ceiling.min(hard_cap)))
+	}
+
 	pub fn required_collateral_ratio(currency_id: CurrencyId) -> Result<Option<Ratio>, DispatchError> {
 		let params = Self::collateral_params(currency_id).ok_or(Error::<T>::InvalidCollateralType)?;
 		Ok(params.required_collateral_ratio)
 	}
 
+	/// The effective interest rate per sec of the collateral type, the promotional rate while it
+	/// is active, otherwise the base interest rate.
 	pub fn get_interest_rate_per_sec(currency_id: CurrencyId) -> Result<Rate, DispatchError> {
 		let params = Self::collateral_params(currency_id).ok_or(Error::<T>::InvalidCollateralType)?;
+		if let Some(promotion) = Self::promotional_rates(currency_id) {
+			return Ok(promotion.interest_rate_per_sec.into_inner());
+		}
 		params
 			.interest_rate_per_sec
 			.map(|v| v.into_inner())
@@ -906,6 +2587,14 @@ impl<T: Config> Pallet<T> {
 			CollateralParams::<T>::contains_key(currency_id),
 			Error::<T>::InvalidCollateralType,
 		);
+		ensure!(
+			!DebitRebases::<T>::contains_key(currency_id),
+			Error::<T>::FrozenByDebitRebase
+		);
+		if debit_adjustment.is_positive() {
+			ensure!(!Self::is_price_stale(currency_id), Error::<T>::DebitFrozenByStalePrice);
+			ensure!(!T::SafeMode::is_active(), Error::<T>::DebitFrozenBySafeMode);
+		}
 		<LoansOf<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)?;
 		Ok(())
 	}
@@ -985,10 +2674,17 @@ impl<T: Config> Pallet<T> {
 			CollateralParams::<T>::contains_key(currency_id),
 			Error::<T>::InvalidCollateralType,
 		);
+		ensure!(!Self::is_price_stale(currency_id), Error::<T>::DebitFrozenByStalePrice);
+		ensure!(!T::SafeMode::is_active(), Error::<T>::DebitFrozenBySafeMode);
 		let loans_module_account = <LoansOf<T>>::account_id();
 
 		// issue stable coin in advance
-		<T as Config>::CDPTreasury::issue_debit(&loans_module_account, increase_debit_value, true)?;
+		<T as Config>::CDPTreasury::issue_debit(
+			&loans_module_account,
+			increase_debit_value,
+			true,
+			IssuanceOrigin::Collateral(currency_id),
+		)?;
 
 		// get the actual increased collateral amount
 		let increase_collateral = match currency_id {
@@ -1149,7 +2845,11 @@ impl<T: Config> Pallet<T> {
 		<LoansOf<T>>::update_loan(who, currency_id, collateral_adjustment, debit_adjustment)?;
 
 		// repay the debit of CDP
-		<T as Config>::CDPTreasury::burn_debit(&loans_module_account, decrease_debit_value)?;
+		<T as Config>::CDPTreasury::burn_debit(
+			&loans_module_account,
+			decrease_debit_value,
+			IssuanceOrigin::Collateral(currency_id),
+		)?;
 
 		// check the CDP if is still at valid risk.
 		Self::check_position_valid(
@@ -1161,9 +2861,113 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Shift the CDP of `who` at `position_index` from `from_currency_id` to the CDP at the same
+	/// index of `to_currency_id`: swap `collateral_amount` of the collateral to the new collateral
+	/// type by DEX, in limit of the slippage compared to the oracle price, and move all the debit
+	/// value along with it. The new CDP must be above the required collateral ratio of its
+	/// collateral type. Return the received collateral amount and the moved debit value.
+	#[transactional]
+	pub fn shift_position_collateral(
+		who: &T::AccountId,
+		from_currency_id: CurrencyId,
+		to_currency_id: CurrencyId,
+		position_index: PositionIndex,
+		collateral_amount: Balance,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		ensure!(
+			CollateralParams::<T>::contains_key(from_currency_id)
+				&& CollateralParams::<T>::contains_key(to_currency_id),
+			Error::<T>::InvalidCollateralType,
+		);
+		ensure!(
+			!Self::is_price_stale(from_currency_id) && !Self::is_price_stale(to_currency_id),
+			Error::<T>::DebitFrozenByStalePrice
+		);
+		ensure!(!T::SafeMode::is_active(), Error::<T>::DebitFrozenBySafeMode);
+		ensure!(
+			!DebitRebases::<T>::contains_key(from_currency_id) && !DebitRebases::<T>::contains_key(to_currency_id),
+			Error::<T>::FrozenByDebitRebase
+		);
+
+		let Position { collateral, debit } = <LoansOf<T>>::position_at(from_currency_id, who, position_index);
+		ensure!(collateral_amount <= collateral, Error::<T>::CollateralNotEnough);
+
+		// swap the collateral at the oracle price, allowing the max slippage
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let from_price = T::PriceSource::get_relative_price(from_currency_id, stable_currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let to_price_reciprocal = T::PriceSource::get_relative_price(to_currency_id, stable_currency_id)
+			.and_then(|price| price.reciprocal())
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let expected_target = to_price_reciprocal.saturating_mul_int(from_price.saturating_mul_int(collateral_amount));
+		let min_target_amount = Ratio::one()
+			.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+			.saturating_mul_int(expected_target);
+		let (_, target_amount) = T::Swap::swap(
+			&<LoansOf<T>>::account_id(),
+			from_currency_id,
+			to_currency_id,
+			SwapLimit::ExactSupply(collateral_amount, min_target_amount),
+		)?;
+
+		// move the debit value to the new collateral type
+		let debit_value = Self::get_debit_value(from_currency_id, debit);
+		let to_debit = Self::try_convert_to_debit_balance(to_currency_id, debit_value)
+			.ok_or(Error::<T>::ConvertDebitBalanceFailed)?;
+		<LoansOf<T>>::update_loan_at(
+			who,
+			from_currency_id,
+			position_index,
+			<LoansOf<T>>::amount_try_from_balance(collateral_amount)?.saturating_neg(),
+			<LoansOf<T>>::amount_try_from_balance(debit)?.saturating_neg(),
+		)?;
+		<LoansOf<T>>::update_loan_at(
+			who,
+			to_currency_id,
+			position_index,
+			<LoansOf<T>>::amount_try_from_balance(target_amount)?,
+			<LoansOf<T>>::amount_try_from_balance(to_debit)?,
+		)?;
+
+		// account the issuance to the new collateral type
+		if !debit_value.is_zero() {
+			<T as Config>::CDPTreasury::transfer_issuance(
+				IssuanceOrigin::Collateral(from_currency_id),
+				IssuanceOrigin::Collateral(to_currency_id),
+				debit_value,
+			)?;
+		}
+
+		// the remaining collateral of the old CDP must be above the minimum, and the new CDP
+		// must be at valid risk
+		Self::check_position_valid(
+			from_currency_id,
+			collateral.saturating_sub(collateral_amount),
+			Zero::zero(),
+			false,
+		)?;
+		let Position { collateral, debit } = <LoansOf<T>>::position_at(to_currency_id, who, position_index);
+		Self::check_position_valid(to_currency_id, collateral, debit, true)?;
+		Self::check_debit_cap(to_currency_id, <LoansOf<T>>::total_positions(to_currency_id).debit)?;
+		Ok((target_amount, debit_value))
+	}
+
 	// settle cdp has debit when emergency shutdown
 	pub fn settle_cdp_has_debit(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
-		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
+		Self::settle_position_has_debit(who, currency_id, 0)
+	}
+
+	// settle the position of cdp at `position_index` has debit when emergency shutdown
+	pub fn settle_position_has_debit(
+		who: T::AccountId,
+		currency_id: CurrencyId,
+		position_index: PositionIndex,
+	) -> DispatchResult {
+		ensure!(
+			!DebitRebases::<T>::contains_key(currency_id),
+			Error::<T>::FrozenByDebitRebase
+		);
+		let Position { collateral, debit } = <LoansOf<T>>::position_at(currency_id, &who, position_index);
 		ensure!(!debit.is_zero(), Error::<T>::NoDebitValue);
 
 		// confiscate collateral in cdp to cdp treasury
@@ -1179,150 +2983,762 @@ impl<T: Config> Pallet<T> {
 		}
 
 		// confiscate collateral and all debit
-		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, confiscate_collateral_amount, debit)?;
+		<LoansOf<T>>::confiscate_collateral_and_debit_at(
+			&who,
+			currency_id,
+			position_index,
+			confiscate_collateral_amount,
+			debit,
+		)?;
+
+		if let CurrencyId::Erc20(_) = currency_id {
+			T::EVMBridge::kill_origin();
+		}
+
+		Self::record_liquidation(
+			&who,
+			currency_id,
+			LiquidationKind::ShutdownSettled,
+			confiscate_collateral_amount,
+			bad_debt_value,
+			Zero::zero(),
+			None,
+		);
+		Self::deposit_event(Event::SettleCDPInDebit {
+			collateral_type: currency_id,
+			owner: who,
+		});
+		Ok(())
+	}
+
+	// close cdp has debit by swap collateral to exact debit
+	#[transactional]
+	pub fn close_cdp_has_debit_by_dex(
+		who: T::AccountId,
+		currency_id: CurrencyId,
+		max_collateral_amount: Balance,
+	) -> DispatchResult {
+		ensure!(
+			!DebitRebases::<T>::contains_key(currency_id),
+			Error::<T>::FrozenByDebitRebase
+		);
+		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
+		ensure!(!debit.is_zero(), Error::<T>::NoDebitValue);
+		ensure!(
+			matches!(Self::check_cdp_status(currency_id, collateral, debit), CDPStatus::Safe),
+			Error::<T>::MustBeSafe
+		);
+
+		// confiscate all collateral and debit of unsafe cdp to cdp treasury
+		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
+
+		// swap exact stable with DEX in limit of price impact
+		let debit_value = Self::get_debit_value(currency_id, debit);
+		let collateral_supply = collateral.min(max_collateral_amount);
+
+		let (actual_supply_collateral, _) = <T as Config>::CDPTreasury::swap_collateral_to_stable(
+			currency_id,
+			SwapLimit::ExactTarget(collateral_supply, debit_value),
+			false,
+		)?;
+
+		// refund remain collateral to CDP owner
+		let refund_collateral_amount = collateral
+			.checked_sub(actual_supply_collateral)
+			.expect("swap success means collateral >= actual_supply_collateral; qed");
+		<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_collateral_amount)?;
+
+		Self::record_liquidation(
+			&who,
+			currency_id,
+			LiquidationKind::SelfClosed,
+			collateral,
+			debit_value,
+			Zero::zero(),
+			None,
+		);
+		Self::deposit_event(Event::CloseCDPInDebitByDEX {
+			collateral_type: currency_id,
+			owner: who,
+			sold_collateral_amount: actual_supply_collateral,
+			refund_collateral_amount,
+			debit_value,
+		});
+		Ok(())
+	}
+
+	// liquidate unsafe cdp
+	pub fn liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> Result<Weight, DispatchError> {
+		Self::liquidate_unsafe_position(who, currency_id, 0)
+	}
+
+	// liquidate the unsafe position of cdp at `position_index`
+	#[transactional]
+	pub fn liquidate_unsafe_position(
+		who: T::AccountId,
+		currency_id: CurrencyId,
+		position_index: PositionIndex,
+	) -> Result<Weight, DispatchError> {
+		ensure!(
+			!Self::is_price_stale(currency_id),
+			Error::<T>::LiquidationFrozenByStalePrice
+		);
+		ensure!(
+			!DebitRebases::<T>::contains_key(currency_id),
+			Error::<T>::FrozenByDebitRebase
+		);
+		let Position { collateral, debit } = <LoansOf<T>>::position_at(currency_id, &who, position_index);
+
+		// ensure the cdp is unsafe
+		ensure!(
+			matches!(
+				Self::check_cdp_status(currency_id, collateral, debit),
+				CDPStatus::Unsafe
+			),
+			Error::<T>::MustBeUnsafe
+		);
+
+		// try to rescue the cdp with the excess collateral of the owner's other cdps first,
+		// the cross margin only works with the primary positions
+		let rebalanced_count = if position_index.is_zero() {
+			Self::try_cross_margin_rescue(&who, currency_id)
+		} else {
+			0
+		};
+		let rescue_weight = Self::cross_margin_rescue_weight(rebalanced_count);
+		let Position { collateral, debit } = <LoansOf<T>>::position_at(currency_id, &who, position_index);
+		if !matches!(
+			Self::check_cdp_status(currency_id, collateral, debit),
+			CDPStatus::Unsafe
+		) {
+			return Ok(rescue_weight);
+		}
+
+		// a large cdp only gives up the part that restores the rest of it above the target ratio,
+		// the others confiscate all collateral and debit of unsafe cdp to cdp treasury
+		let partial_liquidation = Self::partial_liquidation_amounts(currency_id, collateral, debit);
+		let (collateral, debit) = partial_liquidation.unwrap_or((collateral, debit));
+		<LoansOf<T>>::confiscate_collateral_and_debit_at(&who, currency_id, position_index, collateral, debit)?;
+
+		let bad_debt_value = Self::get_debit_value(currency_id, debit);
+		let liquidation_penalty = Self::get_liquidation_penalty(currency_id)?;
+		let target_stable_amount = liquidation_penalty.saturating_mul_acc_int(bad_debt_value);
+
+		let (route, auction_lots) = match currency_id {
+			CurrencyId::DexShare(dex_share_0, dex_share_1) => {
+				let token_0: CurrencyId = dex_share_0.into();
+				let token_1: CurrencyId = dex_share_1.into();
+
+				// remove liquidity first
+				let (amount_0, amount_1) =
+					<T as Config>::CDPTreasury::remove_liquidity_for_lp_collateral(currency_id, collateral)?;
+
+				// if these's stable
+				let stable_currency_id = T::GetStableCurrencyId::get();
+				if token_0 == stable_currency_id || token_1 == stable_currency_id {
+					let (existing_stable, need_handle_currency, handle_amount) = if token_0 == stable_currency_id {
+						(amount_0, token_1, amount_1)
+					} else {
+						(amount_1, token_0, amount_0)
+					};
+
+					// these's stable refund
+					if existing_stable > target_stable_amount {
+						<T as Config>::CDPTreasury::withdraw_collateral(
+							&who,
+							stable_currency_id,
+							existing_stable
+								.checked_sub(target_stable_amount)
+								.expect("ensured existing stable amount greater than target; qed"),
+						)?;
+					}
+
+					let remain_target = target_stable_amount.saturating_sub(existing_stable);
+					Self::handle_liquidated_collateral(&who, need_handle_currency, handle_amount, remain_target)?
+				} else {
+					// token_0 and token_1 each take half target_stable
+					let target_0 = target_stable_amount / 2;
+					let target_1 = target_stable_amount.saturating_sub(target_0);
+					let (route_0, lots_0) = Self::handle_liquidated_collateral(&who, token_0, amount_0, target_0)?;
+					let (route_1, lots_1) = Self::handle_liquidated_collateral(&who, token_1, amount_1, target_1)?;
+					(route_0.max(route_1), lots_0.saturating_add(lots_1))
+				}
+			}
+			_ => Self::handle_liquidated_collateral(&who, currency_id, collateral, target_stable_amount)?,
+		};
+
+		let penalty_amount = target_stable_amount.saturating_sub(bad_debt_value);
+		Self::split_liquidation_penalty(&who, currency_id, penalty_amount);
+		Self::record_liquidation(
+			&who,
+			currency_id,
+			LiquidationKind::Liquidated,
+			collateral,
+			bad_debt_value,
+			penalty_amount,
+			route,
+		);
+
+		Self::deposit_event(Event::LiquidateUnsafeCDP {
+			collateral_type: currency_id,
+			owner: who,
+			collateral_amount: collateral,
+			bad_debt_value,
+			target_amount: target_stable_amount,
+		});
+		// the auction route is only taken after the dex route failed
+		let liquidation_weight = match (auction_lots, partial_liquidation) {
+			(0, _) => T::WeightInfo::liquidate_by_dex(),
+			(lots, Some(_)) => {
+				T::WeightInfo::liquidate_partially_by_auction(lots).max(T::WeightInfo::liquidate_by_dex())
+			}
+			(lots, None) => T::WeightInfo::liquidate_by_auction(lots).max(T::WeightInfo::liquidate_by_dex()),
+		};
+		Ok(liquidation_weight
+			.saturating_add(rescue_weight)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1)))
+	}
+
+	/// The collateral and debit to confiscate from an unsafe cdp whose debit value reaches
+	/// `PartialLiquidationThreshold`, so that the remaining position is restored to the target
+	/// collateral ratio plus `PartialLiquidationBuffer`. The confiscated collateral covers the
+	/// liquidated debit value and the penalty at the oracle price.
+	///
+	/// Returns `None` if the cdp should be liquidated as a whole.
+	fn partial_liquidation_amounts(
+		currency_id: CurrencyId,
+		collateral: Balance,
+		debit: Balance,
+	) -> Option<(Balance, Balance)> {
+		let debit_value = Self::get_debit_value(currency_id, debit);
+		if debit_value < T::PartialLiquidationThreshold::get() {
+			return None;
+		}
+
+		let price = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())?;
+		let target_ratio = Self::cross_margin_target_ratio(currency_id)
+			.ok()?
+			.saturating_add(T::PartialLiquidationBuffer::get());
+		let liquidation_penalty = Self::get_liquidation_penalty(currency_id).ok()?;
+
+		// each unit of the liquidated debit value takes `1 + penalty` of the collateral value,
+		// the ratio of the remaining position only rises if the target ratio is above it
+		let ratio_gain = target_ratio.saturating_sub(Ratio::one().saturating_add(liquidation_penalty));
+		if ratio_gain.is_zero() {
+			return None;
+		}
+		let lacking_value = target_ratio
+			.saturating_mul_int(debit_value)
+			.saturating_sub(price.saturating_mul_int(collateral));
+		let liquidate_debit_value = ratio_gain.reciprocal()?.saturating_mul_int(lacking_value);
+		let remaining_debit_value = debit_value.checked_sub(liquidate_debit_value)?;
+		if liquidate_debit_value.is_zero() || remaining_debit_value < T::MinimumDebitValue::get() {
+			return None;
+		}
+
+		let liquidate_debit = Self::try_convert_to_debit_balance(currency_id, liquidate_debit_value)?.min(debit);
+		let liquidate_collateral = price
+			.reciprocal()?
+			.saturating_mul_int(liquidation_penalty.saturating_mul_acc_int(liquidate_debit_value));
+		if liquidate_collateral >= collateral {
+			return None;
+		}
+		Some((liquidate_collateral, liquidate_debit))
+	}
+
+	/// Append the record to the liquidation history of `who`, rotating out the
+	/// oldest one when full, and account it in the aggregate statistics.
+	fn record_liquidation(
+		who: &T::AccountId,
+		collateral_type: CurrencyId,
+		kind: LiquidationKind,
+		collateral_amount: Balance,
+		debit_value: Balance,
+		penalty_amount: Balance,
+		route: Option<LiquidationRoute>,
+	) {
+		LiquidationStatistics::<T>::mutate(who, |stats| {
+			match kind {
+				LiquidationKind::Liquidated => {
+					stats.liquidated_count = stats.liquidated_count.saturating_add(1);
+					TotalLiquidatedValue::<T>::mutate(|total| *total = total.saturating_add(debit_value));
+				}
+				LiquidationKind::SelfClosed => stats.self_closed_count = stats.self_closed_count.saturating_add(1),
+				LiquidationKind::ShutdownSettled => {
+					stats.shutdown_settled_count = stats.shutdown_settled_count.saturating_add(1)
+				}
+			}
+			stats.total_penalty = stats.total_penalty.saturating_add(penalty_amount);
+		});
+		if !penalty_amount.is_zero() {
+			Self::record_revenue(|revenue| {
+				revenue.liquidation_penalty = revenue.liquidation_penalty.saturating_add(penalty_amount)
+			});
+		}
+		LiquidationHistory::<T>::mutate(who, |history| {
+			if !history.is_empty() && history.len() >= T::MaxLiquidationRecords::get() as usize {
+				history.remove(0);
+			}
+			// only fails when `MaxLiquidationRecords` is zero
+			let _ = history.try_push(LiquidationRecord {
+				block_number: <frame_system::Pallet<T>>::block_number(),
+				collateral_type,
+				kind,
+				collateral_amount,
+				debit_value,
+				penalty_amount,
+				route,
+			});
+		});
+	}
+
+	/// Add the revenue to the current revenue period, a new period rotates
+	/// out the oldest one when full.
+	fn record_revenue(f: impl FnOnce(&mut PeriodRevenue<BlockNumberFor<T>>)) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let period = T::RevenuePeriod::get().max(One::one());
+		let period_start = now.saturating_sub(now % period);
+
+		RevenueHistory::<T>::mutate(|history| {
+			if history
+				.last()
+				.map_or(true, |revenue| revenue.period_start != period_start)
+			{
+				// only fails when `MaxRevenuePeriods` is zero
+				let _ = history.force_insert_keep_right(
+					history.len(),
+					PeriodRevenue {
+						period_start,
+						..Default::default()
+					},
+				);
+			}
+			if let Some(revenue) = history.last_mut() {
+				f(revenue);
+			}
+		});
+	}
+
+	/// The stability fees and the liquidation penalties of the revenue periods
+	/// overlapping the trailing `period` blocks. Returns `None` if the periods
+	/// kept don't cover it.
+	pub fn get_revenue(period: BlockNumberFor<T>) -> Option<(Balance, Balance)> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let revenue_period = T::RevenuePeriod::get().max(One::one());
+		let start = now.saturating_sub(period);
+		let history = Self::revenue_history();
+
+		// the rotated out periods may overlap
+		if history.is_full()
+			&& history
+				.first()
+				.is_some_and(|revenue| revenue.period_start > start.saturating_sub(start % revenue_period))
+		{
+			return None;
+		}
+
+		Some(
+			history
+				.iter()
+				.filter(|revenue| revenue.period_start.saturating_add(revenue_period) > start)
+				.fold(
+					(Zero::zero(), Zero::zero()),
+					|(stability_fee, liquidation_penalty), revenue| {
+						(
+							stability_fee.saturating_add(revenue.stability_fee),
+							liquidation_penalty.saturating_add(revenue.liquidation_penalty),
+						)
+					},
+				),
+		)
+	}
+
+	/// The aggregate statistics and the latest records of the liquidation
+	/// history of `who`, the oldest record first.
+	pub fn get_liquidation_history(
+		who: &T::AccountId,
+	) -> (LiquidationStats, Vec<LiquidationRecord<CurrencyId, BlockNumberFor<T>>>) {
+		(
+			Self::liquidation_statistics(who),
+			Self::liquidation_history(who).into_inner(),
+		)
+	}
+
+	/// Set aside the share of the liquidation penalty as the cashback of the
+	/// liquidated owner. The cashback stays in the surplus of CDP treasury
+	/// until claimed, the expired cashback of previous liquidation is swept.
+	fn split_liquidation_penalty(who: &T::AccountId, currency_id: CurrencyId, penalty_amount: Balance) {
+		let Some(params) = Self::cashback_params(currency_id) else {
+			return;
+		};
+		let cashback_amount = params.split.mul_floor(penalty_amount);
+		if cashback_amount.is_zero() {
+			return;
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let pending_amount = match PendingCashbacks::<T>::take(currency_id, who) {
+			Some(cashback) if cashback.expires_at > now => cashback.amount,
+			Some(cashback) => {
+				Self::deposit_event(Event::PenaltyCashbackExpired {
+					collateral_type: currency_id,
+					owner: who.clone(),
+					amount: cashback.amount,
+				});
+				Zero::zero()
+			}
+			None => Zero::zero(),
+		};
+		let expires_at = now.saturating_add(params.claim_period);
+		PendingCashbacks::<T>::insert(
+			currency_id,
+			who,
+			PendingCashback {
+				amount: pending_amount.saturating_add(cashback_amount),
+				expires_at,
+			},
+		);
+		Self::deposit_event(Event::LiquidationPenaltySplit {
+			collateral_type: currency_id,
+			owner: who.clone(),
+			penalty_amount,
+			cashback_amount,
+			expires_at,
+		});
+	}
+
+	/// Try to rescue the unsafe CDP by swapping the excess collateral of the
+	/// owner's CDPs into it in the order of the cross-margin preference, stop
+	/// once the CDP is safe. Return the count of the rebalances.
+	fn try_cross_margin_rescue(who: &T::AccountId, currency_id: CurrencyId) -> u32 {
+		let preference = Self::cross_margin_preferences(who);
+		if preference.is_empty() || matches!(currency_id, CurrencyId::DexShare(..)) {
+			return 0;
+		}
+
+		let mut rebalanced_count: u32 = 0;
+		let mut rescued = false;
+		for from_currency_id in preference.into_iter().filter(|c| *c != currency_id) {
+			match Self::rebalance_collateral(who, from_currency_id, currency_id) {
+				Ok((supply_amount, target_amount)) => {
+					rebalanced_count = rebalanced_count.saturating_add(1);
+					Self::deposit_event(Event::CrossMarginRebalanced {
+						owner: who.clone(),
+						from_collateral_type: from_currency_id,
+						to_collateral_type: currency_id,
+						supply_amount,
+						target_amount,
+					});
+				}
+				Err(e) => {
+					log::debug!(
+						target: "cdp-engine",
+						"cross-margin rebalance from {:?} to {:?} of {:?} failed: {:?}",
+						from_currency_id,
+						currency_id,
+						who,
+						e,
+					);
+				}
+			}
 
-		if let CurrencyId::Erc20(_) = currency_id {
-			T::EVMBridge::kill_origin();
+			let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, who);
+			if matches!(Self::check_cdp_status(currency_id, collateral, debit), CDPStatus::Safe) {
+				rescued = true;
+				break;
+			}
 		}
 
-		Self::deposit_event(Event::SettleCDPInDebit {
+		Self::deposit_event(Event::CrossMarginRescueAttempted {
+			owner: who.clone(),
 			collateral_type: currency_id,
-			owner: who,
+			rebalanced_count,
+			rescued,
 		});
-		Ok(())
+		rebalanced_count
 	}
 
-	// close cdp has debit by swap collateral to exact debit
+	/// Swap the excess collateral of the `from_currency_id` CDP into the
+	/// `to_currency_id` CDP of `who` by DEX, as much as it needs to reach the
+	/// target collateral ratio, in limit of the slippage compared to the oracle
+	/// price. The `from_currency_id` CDP must still be safe after the swap.
 	#[transactional]
-	pub fn close_cdp_has_debit_by_dex(
-		who: T::AccountId,
-		currency_id: CurrencyId,
-		max_collateral_amount: Balance,
-	) -> DispatchResult {
-		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
-		ensure!(!debit.is_zero(), Error::<T>::NoDebitValue);
+	fn rebalance_collateral(
+		who: &T::AccountId,
+		from_currency_id: CurrencyId,
+		to_currency_id: CurrencyId,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
 		ensure!(
-			matches!(Self::check_cdp_status(currency_id, collateral, debit), CDPStatus::Safe),
-			Error::<T>::MustBeSafe
+			!Self::is_price_stale(from_currency_id),
+			Error::<T>::LiquidationFrozenByStalePrice
 		);
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let from_price = T::PriceSource::get_relative_price(from_currency_id, stable_currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let to_price = T::PriceSource::get_relative_price(to_currency_id, stable_currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let from_price_reciprocal = from_price.reciprocal().ok_or(Error::<T>::InvalidFeedPrice)?;
+		let to_price_reciprocal = to_price.reciprocal().ok_or(Error::<T>::InvalidFeedPrice)?;
 
-		// confiscate all collateral and debit of unsafe cdp to cdp treasury
-		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
+		let from_position = <LoansOf<T>>::positions(from_currency_id, who);
+		let to_position = <LoansOf<T>>::positions(to_currency_id, who);
 
-		// swap exact stable with DEX in limit of price impact
-		let debit_value = Self::get_debit_value(currency_id, debit);
-		let collateral_supply = collateral.min(max_collateral_amount);
+		// the collateral value the unsafe CDP lacks to reach the target ratio
+		let lacking_value = Self::cross_margin_target_ratio(to_currency_id)?
+			.saturating_mul_int(Self::get_debit_value(to_currency_id, to_position.debit))
+			.saturating_sub(to_price.saturating_mul_int(to_position.collateral));
 
-		let (actual_supply_collateral, _) = <T as Config>::CDPTreasury::swap_collateral_to_stable(
-			currency_id,
-			SwapLimit::ExactTarget(collateral_supply, debit_value),
+		// the collateral exceeding the target ratio of the supplying CDP
+		let required_collateral = if from_position.debit.is_zero() {
+			Zero::zero()
+		} else {
+			let required_value = Self::cross_margin_target_ratio(from_currency_id)?
+				.saturating_mul_int(Self::get_debit_value(from_currency_id, from_position.debit));
+			from_price_reciprocal
+				.saturating_mul_int(required_value)
+				.saturating_add(One::one())
+		};
+		let excess_collateral = from_position.collateral.saturating_sub(required_collateral);
+
+		// supply for the lacking value at the oracle price, allowing the max slippage
+		let max_slippage = T::MaxSwapSlippageCompareToOracle::get();
+		let supply_at_oracle = from_price_reciprocal.saturating_mul_int(lacking_value);
+		let supply_amount = max_slippage
+			.saturating_mul_acc_int(supply_at_oracle)
+			.min(excess_collateral);
+		ensure!(!supply_amount.is_zero(), Error::<T>::CollateralNotEnough);
+
+		let expected_target = to_price_reciprocal.saturating_mul_int(from_price.saturating_mul_int(supply_amount));
+		let min_target_amount = Ratio::one()
+			.saturating_sub(max_slippage)
+			.saturating_mul_int(expected_target);
+		let (_, target_amount) = T::Swap::swap(
+			&<LoansOf<T>>::account_id(),
+			from_currency_id,
+			to_currency_id,
+			SwapLimit::ExactSupply(supply_amount, min_target_amount),
+		)?;
+
+		<LoansOf<T>>::update_loan(
+			who,
+			from_currency_id,
+			<LoansOf<T>>::amount_try_from_balance(supply_amount)?.saturating_neg(),
+			Zero::zero(),
+		)?;
+		<LoansOf<T>>::update_loan(
+			who,
+			to_currency_id,
+			<LoansOf<T>>::amount_try_from_balance(target_amount)?,
+			Zero::zero(),
+		)?;
+
+		// the supplying CDP must be still at valid risk
+		Self::check_position_valid(
+			from_currency_id,
+			from_position.collateral.saturating_sub(supply_amount),
+			from_position.debit,
 			false,
 		)?;
+		Ok((supply_amount, target_amount))
+	}
 
-		// refund remain collateral to CDP owner
-		let refund_collateral_amount = collateral
-			.checked_sub(actual_supply_collateral)
-			.expect("swap success means collateral >= actual_supply_collateral; qed");
-		<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_collateral_amount)?;
+	/// Add collateral to the position of CDP at `position_index` which has debit, without the
+	/// risk check of the adjustment.
+	#[transactional]
+	fn do_expand_position_collateral_urgent(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		position_index: PositionIndex,
+		increase_collateral: Balance,
+	) -> DispatchResult {
+		ensure!(!increase_collateral.is_zero(), Error::<T>::ZeroCollateralIncrease);
+		ensure!(
+			CollateralParams::<T>::contains_key(currency_id),
+			Error::<T>::InvalidCollateralType,
+		);
+		// the collateral ratio is meaningless while the debit balances are in mixed bases
+		ensure!(
+			!DebitRebases::<T>::contains_key(currency_id),
+			Error::<T>::FrozenByDebitRebase
+		);
+		let Position { collateral, debit } = <LoansOf<T>>::position_at(currency_id, who, position_index);
+		ensure!(!debit.is_zero(), Error::<T>::NoDebitValue);
 
-		Self::deposit_event(Event::CloseCDPInDebitByDEX {
+		<LoansOf<T>>::update_loan_at(
+			who,
+			currency_id,
+			position_index,
+			<LoansOf<T>>::amount_try_from_balance(increase_collateral)?,
+			Zero::zero(),
+		)?;
+		<T as Config>::Currency::transfer(
+			currency_id,
+			who,
+			&<LoansOf<T>>::account_id(),
+			increase_collateral,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		let collateral = collateral.saturating_add(increase_collateral);
+		let collateral_ratio =
+			<T as Config>::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+				.map(|price| Self::calculate_collateral_ratio(currency_id, collateral, debit, price));
+		let is_safe = matches!(Self::check_cdp_status(currency_id, collateral, debit), CDPStatus::Safe);
+		Self::deposit_event(Event::PositionCollateralExpanded {
+			owner: who.clone(),
 			collateral_type: currency_id,
-			owner: who,
-			sold_collateral_amount: actual_supply_collateral,
-			refund_collateral_amount,
-			debit_value,
+			position_index,
+			collateral_amount: increase_collateral,
+			collateral_ratio,
+			is_safe,
 		});
 		Ok(())
 	}
 
-	// liquidate unsafe cdp
-	pub fn liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> Result<Weight, DispatchError> {
-		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
-
-		// ensure the cdp is unsafe
+	/// Substitute the whole collateral of the CDP at the fixed rate of the substitution, the old
+	/// collateral is absorbed by CDP treasury and the new one is paid from it. The rounding dust
+	/// of the debit value is accounted to CDP treasury.
+	#[transactional]
+	fn do_substitute_collateral(who: &T::AccountId, old_currency_id: CurrencyId) -> DispatchResult {
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		let mut substitution =
+			Self::collateral_substitutions(old_currency_id).ok_or(Error::<T>::CollateralSubstitutionNotFound)?;
 		ensure!(
-			matches!(
-				Self::check_cdp_status(currency_id, collateral, debit),
-				CDPStatus::Unsafe
-			),
-			Error::<T>::MustBeUnsafe
+			<frame_system::Pallet<T>>::block_number() <= substitution.deadline,
+			Error::<T>::CollateralSubstitutionExpired
+		);
+		let new_currency_id = substitution.new_currency_id;
+		ensure!(
+			!DebitRebases::<T>::contains_key(old_currency_id),
+			Error::<T>::FrozenByDebitRebase
+		);
+		ensure!(
+			!Self::is_price_stale(new_currency_id),
+			Error::<T>::DebitFrozenByStalePrice
 		);
 
-		// confiscate all collateral and debit of unsafe cdp to cdp treasury
-		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
-
-		let bad_debt_value = Self::get_debit_value(currency_id, debit);
-		let liquidation_penalty = Self::get_liquidation_penalty(currency_id)?;
-		let target_stable_amount = liquidation_penalty.saturating_mul_acc_int(bad_debt_value);
+		let Position {
+			collateral: old_collateral,
+			debit: old_debit,
+		} = <LoansOf<T>>::positions(old_currency_id, who);
+		ensure!(!old_collateral.is_zero(), Error::<T>::NoPositionToSubstitute);
+		substitution.absorbed = substitution
+			.absorbed
+			.checked_add(old_collateral)
+			.filter(|absorbed| *absorbed <= substitution.cap)
+			.ok_or(Error::<T>::CollateralSubstitutionCapExceeded)?;
+
+		let new_collateral = substitution.rate.saturating_mul_int(old_collateral);
+		let debit_value = Self::get_debit_value(old_currency_id, old_debit);
+		let new_debit = Self::try_convert_to_debit_balance(new_currency_id, debit_value)
+			.ok_or(Error::<T>::ConvertDebitBalanceFailed)?;
 
-		match currency_id {
-			CurrencyId::DexShare(dex_share_0, dex_share_1) => {
-				let token_0: CurrencyId = dex_share_0.into();
-				let token_1: CurrencyId = dex_share_1.into();
+		let loans_module_account = <LoansOf<T>>::account_id();
+		<T as Config>::CDPTreasury::deposit_collateral(&loans_module_account, old_currency_id, old_collateral)?;
+		<T as Config>::CDPTreasury::withdraw_collateral(&loans_module_account, new_currency_id, new_collateral)?;
+		let dust_value = debit_value.saturating_sub(Self::get_debit_value(new_currency_id, new_debit));
+		if !dust_value.is_zero() {
+			<T as Config>::CDPTreasury::on_system_debit(dust_value)?;
+		}
 
-				// remove liquidity first
-				let (amount_0, amount_1) =
-					<T as Config>::CDPTreasury::remove_liquidity_for_lp_collateral(currency_id, collateral)?;
+		<LoansOf<T>>::update_loan(
+			who,
+			old_currency_id,
+			<LoansOf<T>>::amount_try_from_balance(old_collateral)?.saturating_neg(),
+			<LoansOf<T>>::amount_try_from_balance(old_debit)?.saturating_neg(),
+		)?;
+		<LoansOf<T>>::update_loan(
+			who,
+			new_currency_id,
+			<LoansOf<T>>::amount_try_from_balance(new_collateral)?,
+			<LoansOf<T>>::amount_try_from_balance(new_debit)?,
+		)?;
 
-				// if these's stable
-				let stable_currency_id = T::GetStableCurrencyId::get();
-				if token_0 == stable_currency_id || token_1 == stable_currency_id {
-					let (existing_stable, need_handle_currency, handle_amount) = if token_0 == stable_currency_id {
-						(amount_0, token_1, amount_1)
-					} else {
-						(amount_1, token_0, amount_0)
-					};
+		// the resulting CDP must be safe under the risk params of the new collateral type
+		let Position { collateral, debit } = <LoansOf<T>>::positions(new_currency_id, who);
+		Self::check_position_valid(new_currency_id, collateral, debit, true)?;
+		Self::check_debit_cap(new_currency_id, <LoansOf<T>>::total_positions(new_currency_id).debit)?;
+
+		CollateralSubstitutions::<T>::insert(old_currency_id, substitution);
+		Self::deposit_event(Event::CollateralSubstituted {
+			owner: who.clone(),
+			old_collateral_type: old_currency_id,
+			new_collateral_type: new_currency_id,
+			old_collateral_amount: old_collateral,
+			new_collateral_amount: new_collateral,
+			old_debit_amount: old_debit,
+			new_debit_amount: new_debit,
+		});
+		Ok(())
+	}
 
-					// these's stable refund
-					if existing_stable > target_stable_amount {
-						<T as Config>::CDPTreasury::withdraw_collateral(
-							&who,
-							stable_currency_id,
-							existing_stable
-								.checked_sub(target_stable_amount)
-								.expect("ensured existing stable amount greater than target; qed"),
-						)?;
-					}
+	/// The collateral ratio the cross-margin rebalance targets, the higher one of
+	/// the required collateral ratio and the liquidation ratio.
+	fn cross_margin_target_ratio(currency_id: CurrencyId) -> Result<Ratio, DispatchError> {
+		let liquidation_ratio = Self::get_liquidation_ratio(currency_id)?;
+		Ok(Self::required_collateral_ratio(currency_id)?
+			.map_or(liquidation_ratio, |ratio| ratio.max(liquidation_ratio)))
+	}
 
-					let remain_target = target_stable_amount.saturating_sub(existing_stable);
-					Self::handle_liquidated_collateral(&who, need_handle_currency, handle_amount, remain_target)?;
-				} else {
-					// token_0 and token_1 each take half target_stable
-					let target_0 = target_stable_amount / 2;
-					let target_1 = target_stable_amount.saturating_sub(target_0);
-					Self::handle_liquidated_collateral(&who, token_0, amount_0, target_0)?;
-					Self::handle_liquidated_collateral(&who, token_1, amount_1, target_1)?;
-				}
-			}
-			_ => {
-				Self::handle_liquidated_collateral(&who, currency_id, collateral, target_stable_amount)?;
-			}
-		}
+	/// The maximum weight of liquidating an unsafe cdp, the actual weight is refunded according to
+	/// the route and the number of collateral auctions created.
+	pub fn max_liquidate_weight() -> Weight {
+		// the collateral of a dex share is liquidated as its two tokens, each may create `max_auction` lots
+		let max_auction = <T as Config>::CDPTreasury::max_auction().saturating_mul(2);
+		<T as Config>::WeightInfo::liquidate_by_auction(max_auction)
+			.max(<T as Config>::WeightInfo::liquidate_partially_by_auction(max_auction))
+			.saturating_add(Self::cross_margin_rescue_weight(T::MaxCrossMarginPreferences::get()))
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
 
-		Self::deposit_event(Event::LiquidateUnsafeCDP {
-			collateral_type: currency_id,
-			owner: who,
-			collateral_amount: collateral,
-			bad_debt_value,
-			target_amount: target_stable_amount,
-		});
-		Ok(T::WeightInfo::liquidate_by_dex())
+	fn cross_margin_rescue_weight(rebalanced_count: u32) -> Weight {
+		T::WeightInfo::liquidate_by_dex().saturating_mul(rebalanced_count.into())
 	}
 
+	/// Liquidate the confiscated collateral, return the route that succeeded and the number of
+	/// collateral auctions created.
 	pub fn handle_liquidated_collateral(
 		who: &T::AccountId,
 		currency_id: CurrencyId,
 		amount: Balance,
 		target_stable_amount: Balance,
-	) -> DispatchResult {
+	) -> Result<(Option<LiquidationRoute>, u32), DispatchError> {
 		if target_stable_amount.is_zero() {
 			// refund collateral to CDP owner
 			if !amount.is_zero() {
 				<T as Config>::CDPTreasury::withdraw_collateral(who, currency_id, amount)?;
 			}
-			return Ok(());
+			return Ok((None, 0));
 		}
-		LiquidateByPriority::<T>::liquidate(who, currency_id, amount, target_stable_amount)
+		Self::liquidate_by_priority(who, currency_id, amount, target_stable_amount)
+			.map(|(route, auction_lots)| (Some(route), auction_lots))
+	}
+
+	/// Liquidate the collateral by the routes in the priority order, return
+	/// the route that succeeded with the number of collateral auctions created,
+	/// or the last error.
+	fn liquidate_by_priority(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> Result<(LiquidationRoute, u32), DispatchError> {
+		LiquidateViaDex::<T>::liquidate(who, currency_id, amount, target_stable_amount)
+			.map(|_| (LiquidationRoute::Dex, 0))
+			.or_else(|_| {
+				LiquidateViaContracts::<T>::liquidate(who, currency_id, amount, target_stable_amount)
+					.map(|_| (LiquidationRoute::Contract, 0))
+			})
+			.or_else(|_| {
+				<T as Config>::CDPTreasury::create_collateral_auctions(
+					currency_id,
+					amount,
+					target_stable_amount,
+					who.clone(),
+					true,
+				)
+				.map(|auction_lots| (LiquidationRoute::Auction, auction_lots))
+			})
 	}
 
 	pub fn get_collateral_currency_ids() -> Vec<CurrencyId> {
@@ -1339,8 +3755,6 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
-type LiquidateByPriority<T> = (LiquidateViaDex<T>, LiquidateViaContracts<T>, LiquidateViaAuction<T>);
-
 pub struct LiquidateViaDex<T>(PhantomData<T>);
 impl<T: Config> LiquidateCollateral<T::AccountId> for LiquidateViaDex<T> {
 	fn liquidate(
@@ -1554,6 +3968,12 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 		debit_balance: Balance,
 		check_required_ratio: bool,
 	) -> DispatchResult {
+		// the debit balances are in mixed bases until the rebase finishes
+		ensure!(
+			!DebitRebases::<T>::contains_key(currency_id),
+			Error::<T>::FrozenByDebitRebase
+		);
+
 		if !debit_balance.is_zero() {
 			let debit_value = Self::get_debit_value(currency_id, debit_balance);
 			let feed_price = <T as Config>::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
@@ -1592,7 +4012,7 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 	}
 
 	fn check_debit_cap(currency_id: CurrencyId, total_debit_balance: Balance) -> DispatchResult {
-		let hard_cap = Self::maximum_total_debit_value(currency_id)?;
+		let hard_cap = Self::effective_maximum_total_debit_value(currency_id)?;
 		let total_debit_value = Self::get_debit_value(currency_id, total_debit_balance);
 
 		ensure!(total_debit_value <= hard_cap, Error::<T>::ExceedDebitValueHardCap);
@@ -1601,6 +4021,318 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// The total debit balance of the collateral type in the basis of its debit exchange rate,
+	/// the positions rebased by an ongoing debit rebase are counted by their debit balances
+	/// before the rebase.
+	pub fn total_debit_in_rate_basis(currency_id: CurrencyId) -> Balance {
+		let total_debit = <LoansOf<T>>::total_positions(currency_id).debit;
+		match Self::debit_rebases(currency_id) {
+			Some(state) => total_debit
+				.saturating_sub(state.rebased_debit)
+				.saturating_add(state.original_debit),
+			None => total_debit,
+		}
+	}
+
+	/// Multiply the debit balance by `numerator / denominator` rounding down, returns the new
+	/// debit balance and the remainder `debit * numerator - new_debit * denominator`.
+	fn rebase_debit_balance(debit: Balance, numerator: u32, denominator: u32) -> Option<(Balance, Balance)> {
+		let (numerator, denominator) = (Balance::from(numerator), Balance::from(denominator));
+		// split the debit balance to avoid the overflow of `debit * numerator`
+		let (quotient, remainder) = (debit / denominator, debit % denominator);
+		let new_debit = quotient
+			.checked_mul(numerator)?
+			.checked_add(remainder * numerator / denominator)?;
+		Some((new_debit, remainder * numerator % denominator))
+	}
+
+	/// Multiply the debit exchange rate by `numerator / denominator`.
+	fn rebase_debit_exchange_rate(rate: ExchangeRate, numerator: u32, denominator: u32) -> ExchangeRate {
+		rate.saturating_mul(ExchangeRate::saturating_from_rational(numerator, denominator))
+	}
+
+	/// Rebase the debit balance of the position by `numerator / denominator`, the debit value
+	/// at `debit_exchange_rate` must be preserved within the rounding. Returns the new debit
+	/// balance and the rounding remainder.
+	fn rebase_position(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		position_index: PositionIndex,
+		debit: Balance,
+		numerator: u32,
+		denominator: u32,
+		debit_exchange_rate: ExchangeRate,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (new_debit, remainder) =
+			Self::rebase_debit_balance(debit, numerator, denominator).ok_or(ArithmeticError::Overflow)?;
+		let new_debit_exchange_rate = Self::rebase_debit_exchange_rate(debit_exchange_rate, denominator, numerator);
+
+		// the value lost by rounding down the debit balance is less than a debit unit, plus the
+		// precision lost by the exchange rates
+		let value_before = debit_exchange_rate.saturating_mul_int(debit);
+		let value_after = new_debit_exchange_rate.saturating_mul_int(new_debit);
+		let tolerance = new_debit_exchange_rate
+			.saturating_mul_int(Balance::one())
+			.saturating_add(
+				debit_exchange_rate
+					.saturating_add(ExchangeRate::one())
+					.saturating_mul_int(new_debit)
+					/ ExchangeRate::accuracy(),
+			)
+			.saturating_add(2);
+		ensure!(
+			value_before.abs_diff(value_after) <= tolerance,
+			Error::<T>::DebitRebaseValueMismatch
+		);
+
+		let debit_adjustment = if new_debit >= debit {
+			<LoansOf<T>>::amount_try_from_balance(new_debit - debit)?
+		} else {
+			<LoansOf<T>>::amount_try_from_balance(debit - new_debit)?.saturating_neg()
+		};
+		<LoansOf<T>>::update_loan_at(who, currency_id, position_index, Zero::zero(), debit_adjustment)?;
+
+		Ok((new_debit, remainder))
+	}
+
+	/// Rebase the next batch of the positions of the collateral type within the weight limit,
+	/// or revert them if the rebase is aborted.
+	fn process_debit_rebase(currency_id: CurrencyId, weight: Weight) -> TaskResult {
+		let Some(mut state) = Self::debit_rebases(currency_id).filter(|state| !state.paused) else {
+			return TaskResult {
+				result: Ok(()),
+				used_weight: T::DbWeight::get().reads(1),
+				finished: true,
+			};
+		};
+		// the positions not indexed by the loans migration yet would be missed by the rebase
+		if <LoansOf<T>>::index_positions_cursor().is_some() {
+			return TaskResult {
+				result: Ok(()),
+				used_weight: T::DbWeight::get().reads(2),
+				finished: false,
+			};
+		}
+
+		let base_weight = <T as Config>::WeightInfo::debit_rebase(0).ref_time();
+		let weight_per_position = <T as Config>::WeightInfo::debit_rebase(1)
+			.ref_time()
+			.saturating_sub(base_weight)
+			.max(1);
+		let limit = cmp::min(
+			weight.ref_time().saturating_sub(base_weight) / weight_per_position,
+			u32::MAX.into(),
+		) as u32;
+		if limit.is_zero() {
+			return TaskResult {
+				result: Ok(()),
+				used_weight: Weight::zero(),
+				finished: false,
+			};
+		}
+
+		// the debit exchange rate is only adjusted when the rebase completes, the reverted
+		// positions are rebased by the reciprocal from the adjusted basis
+		let debit_exchange_rate = Self::get_debit_exchange_rate(currency_id);
+		let (numerator, denominator, from_rate) = if state.revert_until.is_some() {
+			(
+				state.denominator,
+				state.numerator,
+				Self::rebase_debit_exchange_rate(debit_exchange_rate, state.denominator, state.numerator),
+			)
+		} else {
+			(state.numerator, state.denominator, debit_exchange_rate)
+		};
+
+		let iter = match state.cursor.as_ref() {
+			Some(cursor) => module_loans::Positions::<T>::iter_prefix_from(currency_id, cursor.to_vec()),
+			None => module_loans::Positions::<T>::iter_prefix(currency_id),
+		};
+		let positions: Vec<((T::AccountId, PositionIndex), Position)> = iter.take(limit as usize).collect();
+		let mut finished = positions.len() < limit as usize;
+		let mut visited: u32 = 0;
+		let mut result: DispatchResult = Ok(());
+
+		for ((who, position_index), Position { debit, .. }) in positions {
+			let key = module_loans::Positions::<T>::hashed_key_for((currency_id, &who, position_index));
+			// the positions after the last rebased one are not rebased
+			if let Some(revert_until) = state.revert_until.as_ref() {
+				if key.as_slice() > revert_until.as_slice() {
+					finished = true;
+					break;
+				}
+			}
+			let Ok(cursor) = RebaseCursor::try_from(key) else {
+				result = Err(Error::<T>::InvalidRebaseCursor.into());
+				break;
+			};
+			visited += 1;
+
+			if !debit.is_zero() {
+				match with_storage_layer(|| {
+					Self::rebase_position(
+						&who,
+						currency_id,
+						position_index,
+						debit,
+						numerator,
+						denominator,
+						from_rate,
+					)
+				}) {
+					Ok((new_debit, remainder)) if state.revert_until.is_some() => {
+						state.rebased = state.rebased.saturating_sub(1);
+						state.rebased_debit = state.rebased_debit.saturating_sub(debit);
+						state.original_debit = state.original_debit.saturating_sub(new_debit);
+						state.dust = state.dust.saturating_add(remainder);
+					}
+					Ok((new_debit, remainder)) => {
+						state.rebased = state.rebased.saturating_add(1);
+						state.rebased_debit = state.rebased_debit.saturating_add(new_debit);
+						state.original_debit = state.original_debit.saturating_add(debit);
+						state.dust = state.dust.saturating_add(remainder);
+					}
+					Err(e) => {
+						Self::deposit_event(Event::DebitRebaseHalted {
+							collateral_type: currency_id,
+							owner: who,
+						});
+						result = Err(e);
+						break;
+					}
+				}
+			}
+			state.cursor = Some(cursor);
+		}
+		let used_weight = <T as Config>::WeightInfo::debit_rebase(visited);
+
+		// stop before the failed position, the rebase can be resumed or aborted by governance
+		if result.is_err() {
+			state.paused = true;
+			DebitRebases::<T>::insert(currency_id, &state);
+			Self::deposit_event(Event::DebitRebasePaused {
+				collateral_type: currency_id,
+			});
+			return TaskResult {
+				result,
+				used_weight,
+				finished: true,
+			};
+		}
+
+		if finished {
+			result = Self::finish_debit_rebase(currency_id, state);
+		} else {
+			DebitRebases::<T>::insert(currency_id, &state);
+			Self::deposit_event(Event::DebitRebaseProgressed {
+				collateral_type: currency_id,
+				rebased: state.rebased,
+			});
+		}
+
+		TaskResult {
+			result,
+			used_weight,
+			finished,
+		}
+	}
+
+	/// Adjust the debit exchange rate of the completed rebase, and account the rounding dust to
+	/// CDP treasury.
+	fn finish_debit_rebase(currency_id: CurrencyId, state: DebitRebaseState) -> DispatchResult {
+		let debit_exchange_rate = Self::get_debit_exchange_rate(currency_id);
+		// the dust is in `1 / numerator` debit units before the rebase
+		let dust_value = debit_exchange_rate.saturating_mul_int(state.dust) / Balance::from(state.numerator);
+		DebitRebases::<T>::remove(currency_id);
+
+		if state.revert_until.is_some() {
+			Self::deposit_event(Event::DebitRebaseAborted {
+				collateral_type: currency_id,
+				dust_value,
+			});
+		} else {
+			let new_debit_exchange_rate =
+				Self::rebase_debit_exchange_rate(debit_exchange_rate, state.denominator, state.numerator);
+			DebitExchangeRate::<T>::insert(currency_id, new_debit_exchange_rate);
+			Self::deposit_event(Event::DebitRebaseCompleted {
+				collateral_type: currency_id,
+				rebased: state.rebased,
+				new_debit_exchange_rate,
+				dust_value,
+			});
+		}
+
+		// the debit value lost by the rounding is taken over by CDP treasury
+		<T as Config>::CDPTreasury::on_system_debit(dust_value)
+	}
+
+	/// Ensure the debit balances of the rebased positions of the ongoing debit rebases add up
+	/// with the rounding dust, which proves the debit values are preserved.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		for (currency_id, state) in DebitRebases::<T>::iter() {
+			ensure!(
+				!state.numerator.is_zero() && !state.denominator.is_zero(),
+				"the ratio of the debit rebase is zero"
+			);
+			if let (Some(cursor), Some(revert_until)) = (state.cursor.as_ref(), state.revert_until.as_ref()) {
+				ensure!(
+					cursor.as_slice() <= revert_until.as_slice(),
+					"the debit rebase reverts beyond the rebased positions"
+				);
+			}
+			ensure!(
+				state.rebased_debit <= <LoansOf<T>>::total_positions(currency_id).debit,
+				"the rebased debit exceeds the total debit of the collateral type"
+			);
+
+			// `debit * numerator == new_debit * denominator + remainder` for every rebased position
+			let original = state.original_debit.checked_mul(state.numerator.into());
+			let rebased = state
+				.rebased_debit
+				.checked_mul(state.denominator.into())
+				.and_then(|rebased| rebased.checked_add(state.dust));
+			ensure!(
+				original.is_some() && original == rebased,
+				"the rebased debit and the rounding dust do not add up to the original debit"
+			);
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum CDPEngineTask<T: Config> {
+	/// Rebase the debit balances of the positions of the collateral type.
+	DebitRebase {
+		currency_id: CurrencyId,
+		scheduled_at: BlockNumberFor<T>,
+	},
+}
+
+impl<T: Config> DispatchableTask for CDPEngineTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			CDPEngineTask::DebitRebase { currency_id, .. } => Pallet::<T>::process_debit_rebase(currency_id, weight),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: Config> From<CDPEngineTask<T>> for () {
+	fn from(_task: CDPEngineTask<T>) -> Self {
+		unimplemented!()
+	}
+}
+
+impl<T: Config> LiquidatedValueProvider for Pallet<T> {
+	fn total_liquidated_value() -> Balance {
+		TotalLiquidatedValue::<T>::get()
+	}
+}
+
 pub struct CollateralCurrencyIds<T>(PhantomData<T>);
 // Returns a list of currently supported/configured collateral currency
 impl<T: Config> Get<Vec<CurrencyId>> for CollateralCurrencyIds<T> {
@@ -1613,3 +4345,29 @@ impl<T: Config> Get<Vec<CurrencyId>> for CollateralCurrencyIds<T> {
 fn pick_u32<R: RngCore>(rng: &mut R, max: u32) -> u32 {
 	rng.next_u32() % max
 }
+
+/// Migrate `CollateralParams` to the risk management params with `max_price_age`.
+pub struct AddMaxPriceAgeToCollateralParams<T>(PhantomData<T>);
+impl<T: Config> frame_support::traits::OnRuntimeUpgrade for AddMaxPriceAgeToCollateralParams<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut count: u64 = 0;
+		CollateralParams::<T>::translate::<RiskManagementParamsV0, _>(|_, old| {
+			count += 1;
+			Some(RiskManagementParams {
+				maximum_total_debit_value: old.maximum_total_debit_value,
+				interest_rate_per_sec: old.interest_rate_per_sec,
+				liquidation_ratio: old.liquidation_ratio,
+				liquidation_penalty: old.liquidation_penalty,
+				required_collateral_ratio: old.required_collateral_ratio,
+				max_price_age: None,
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+	}
+}
@@ -32,12 +32,15 @@ use module_support::{
 	AuctionManager, EmergencyShutdown, SpecificJointsSwap,
 };
 use orml_traits::parameter_type_with_key;
-use primitives::{evm::convert_decimals_to_evm, DexShare, Moment, ReserveIdentifier, TokenSymbol, TradingPair};
+use primitives::{
+	define_combined_task, evm::convert_decimals_to_evm, DexShare, Moment, ReserveIdentifier, TokenSymbol, TradingPair,
+};
 use sp_core::crypto::AccountId32;
 use sp_runtime::{
 	testing::TestXt,
-	traits::{AccountIdConversion, IdentityLookup, One as OneT},
-	BuildStorage,
+	traits::{AccountIdConversion, BlockNumberProvider, IdentityLookup, One as OneT},
+	transaction_validity::TransactionPriority,
+	BuildStorage, Percent,
 };
 use sp_std::str::FromStr;
 
@@ -127,6 +130,7 @@ impl module_loans::Config for Runtime {
 	type CDPTreasury = CDPTreasuryModule;
 	type PalletId = LoansPalletId;
 	type OnUpdateLoan = ();
+	type MaxPositionsPerAccount = ConstU32<3>;
 }
 
 parameter_types! {
@@ -161,6 +165,31 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 	}
 }
 
+parameter_types! {
+	static BtcPriceTimestamp: Option<Moment> = None;
+	static DotPriceTimestamp: Option<Moment> = None;
+}
+
+pub struct MockPriceTimestampSource;
+impl MockPriceTimestampSource {
+	pub fn set_price_timestamp(currency_id: CurrencyId, timestamp: Option<Moment>) {
+		match currency_id {
+			BTC => BtcPriceTimestamp::mutate(|v| *v = timestamp),
+			DOT => DotPriceTimestamp::mutate(|v| *v = timestamp),
+			_ => {}
+		}
+	}
+}
+impl PriceTimestampProvider<CurrencyId> for MockPriceTimestampSource {
+	fn get_price_timestamp(currency_id: CurrencyId) -> Option<Moment> {
+		match currency_id {
+			BTC => BtcPriceTimestamp::get(),
+			DOT => DotPriceTimestamp::get(),
+			_ => None,
+		}
+	}
+}
+
 parameter_types! {
 	pub static Auction: Option<(AccountId, CurrencyId, Balance, Balance)> = None;
 }
@@ -222,6 +251,8 @@ impl module_cdp_treasury::Config for Runtime {
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
+	type PriceSource = MockPriceSource;
+	type MaxBasketAssets = ConstU32<10>;
 }
 
 parameter_types! {
@@ -234,6 +265,7 @@ parameter_types! {
 		TradingPair::from_currency_ids(ACA, DOT).unwrap(),
 		TradingPair::from_currency_ids(ACA, AUSD).unwrap(),
 	];
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
 }
 
 impl module_dex::Config for Runtime {
@@ -249,6 +281,18 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type OnTradingPairEnabled = ();
+	type OnTradingPairDisabled = ();
+	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
+	type FlashSwapEvmBridge = ();
+	type GasToWeight = ();
+	type TreasuryAccount = TreasuryAccount;
+	type MinSwapCommitDeposit = ConstU128<100>;
+	type SwapRevealPeriod = ConstU64<10>;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = ConstU32<2>;
+	type LbpCheckpointPeriod = ConstU64<10>;
+	type MaxLbpPools = ConstU32<2>;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -264,6 +308,7 @@ impl module_evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type MaxNonceLanes = ConstU32<8>;
 	type WeightInfo = ();
 }
 
@@ -302,6 +347,9 @@ impl module_evm::Config for Runtime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ();
 	type IdleScheduler = ();
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
@@ -311,6 +359,7 @@ impl module_evm_bridge::Config for Runtime {
 
 parameter_types! {
 	static IsShutdown: bool = false;
+	pub static IsSafeModeActive: bool = false;
 }
 
 pub fn mock_shutdown() {
@@ -328,6 +377,13 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub struct MockSafeMode;
+impl SafeMode for MockSafeMode {
+	fn is_active() -> bool {
+		IsSafeModeActive::get()
+	}
+}
+
 parameter_types! {
 	static LIQUIDATED: (EvmAddress, EvmAddress, Balance, Balance) = (EvmAddress::default(), EvmAddress::default(), 0, 0);
 	static TRANSFERRED: (EvmAddress, Balance) = (EvmAddress::default(), 0);
@@ -396,42 +452,134 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_type_with_key! {
+	pub CdpEngineUnsignedPriority: |_currency_id: CurrencyId| -> TransactionPriority {
+		1 << 20
+	};
+}
+
 parameter_types! {
 	pub DefaultLiquidationRatio: Ratio = Ratio::saturating_from_rational(3, 2);
 	pub DefaultDebitExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub DefaultLiquidationPenalty: FractionalRate = FractionalRate::try_from(Rate::saturating_from_rational(10, 100)).unwrap();
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
 	pub MaxLiquidationContractSlippage: Ratio = Ratio::saturating_from_rational(80, 100);
+	pub PartialLiquidationBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 	pub const SettleErc20EvmOrigin: AccountId = AccountId32::new([255u8; 32]);
 }
 
+parameter_types! {
+	pub static MinLiquidationRatio: Ratio = Ratio::zero();
+	pub static MaxLiquidationRatio: Ratio = Ratio::max_value();
+	pub static MaxLiquidationPenalty: Rate = Rate::max_value();
+	pub static MaxInterestRatePerSec: Rate = Rate::max_value();
+	pub static MaxDebitCeilingIncrease: Ratio = Ratio::max_value();
+}
+
+parameter_types! {
+	pub static ArmedAlerts: Vec<(AccountId, CurrencyId, Ratio)> = vec![];
+	pub static TriggeredAlerts: Vec<(AccountId, CurrencyId, Ratio)> = vec![];
+}
+
+/// The alerts are armed by `ArmedAlerts`, and moved to `TriggeredAlerts` with the collateral
+/// ratio once triggered.
+pub struct MockPositionAlerts;
+impl PositionAlertHandler<AccountId> for MockPositionAlerts {
+	fn is_alert_tripped(who: &AccountId, currency_id: CurrencyId, collateral_ratio: Ratio) -> bool {
+		ArmedAlerts::get()
+			.iter()
+			.any(|(owner, key, trigger_ratio)| owner == who && *key == currency_id && collateral_ratio < *trigger_ratio)
+	}
+
+	fn trigger_alerts(who: &AccountId, currency_id: CurrencyId, collateral_ratio: Ratio) -> u32 {
+		if !Self::is_alert_tripped(who, currency_id, collateral_ratio) {
+			return 0;
+		}
+		ArmedAlerts::mutate(|alerts| alerts.retain(|(owner, key, _)| !(owner == who && *key == currency_id)));
+		TriggeredAlerts::mutate(|alerts| alerts.push((who.clone(), currency_id, collateral_ratio)));
+		1
+	}
+}
+
+define_combined_task! {
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum ScheduledTasks {
+		CDPEngineTask(CDPEngineTask<Runtime>),
+	}
+}
+
+pub struct MockBlockNumberProvider;
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = u32;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Zero::zero()
+	}
+}
+
+parameter_types! {
+	pub MinimumWeightRemainInBlock: Weight = Weight::zero();
+}
+
+impl module_idle_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Index = Nonce;
+	type Task = ScheduledTasks;
+	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
+	type DisableBlockThreshold = ConstU32<6>;
+}
+
+pub const MAX_DEBIT_REBASE_FACTOR: u32 = 100;
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PriceSource = MockPriceSource;
+	type PriceTimestampSource = MockPriceTimestampSource;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
 	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
 	type MinimumDebitValue = ConstU128<2>;
+	type MinLiquidationRatio = MinLiquidationRatio;
+	type MaxLiquidationRatio = MaxLiquidationRatio;
+	type MaxLiquidationPenalty = MaxLiquidationPenalty;
+	type MaxInterestRatePerSec = MaxInterestRatePerSec;
+	type MaxDebitCeilingIncrease = MaxDebitCeilingIncrease;
 	type MinimumCollateralAmount = MinimumCollateralAmount;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
-	type UnsignedPriority = ConstU64<1048576>; // 1 << 20
+	type UnsignedPriority = CdpEngineUnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type SafeMode = MockSafeMode;
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = DEXModule;
 	type LiquidationContractsUpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxCrossMarginPreferences = ConstU32<5>;
 	type LiquidationEvmBridge = MockLiquidationEvmBridge;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type Swap = SpecificJointsSwap<DEXModule, AlternativeSwapPathJointList>;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type SettleErc20EvmOrigin = SettleErc20EvmOrigin;
+	type MaxLiquidationRecords = ConstU32<3>;
+	type PositionAlerts = MockPositionAlerts;
+	type MaxPositionAlertBatch = ConstU32<2>;
+	type MaxDebitRebaseFactor = ConstU32<MAX_DEBIT_REBASE_FACTOR>;
+	type RevenuePeriod = ConstU64<10>;
+	type MaxRevenuePeriods = ConstU32<3>;
+	type RiskSnapshotPeriod = ConstU64<10>;
+	type MaxRiskSnapshotPositions = ConstU32<12>;
+	type PartialLiquidationThreshold = ConstU128<1_000>;
+	type PartialLiquidationBuffer = PartialLiquidationBuffer;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -451,6 +599,7 @@ construct_runtime!(
 		EvmAccounts: module_evm_accounts,
 		EVM: module_evm,
 		EVMBridge: module_evm_bridge,
+		IdleScheduler: module_idle_scheduler,
 	}
 );
 
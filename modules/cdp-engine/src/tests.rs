@@ -25,6 +25,7 @@ use frame_support::{assert_err, assert_noop, assert_ok};
 use mock::{RuntimeCall as MockCall, RuntimeEvent, *};
 use module_support::{DEXManager, SwapError};
 use orml_traits::MultiCurrency;
+use primitives::TradingPair;
 use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
 use sp_io::offchain;
 use sp_runtime::{
@@ -234,6 +235,139 @@ fn set_collateral_params_work() {
 	});
 }
 
+#[test]
+fn set_collateral_params_enforces_risk_param_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		MinLiquidationRatio::set(Ratio::saturating_from_rational(110, 100));
+		MaxLiquidationRatio::set(Ratio::saturating_from_rational(5, 1));
+		MaxLiquidationPenalty::set(Rate::saturating_from_rational(25, 100));
+		MaxInterestRatePerSec::set(Rate::saturating_from_rational(1, 10000));
+
+		// (interest rate per sec, liquidation ratio, liquidation penalty, expected result)
+		let cases: Vec<(Option<Rate>, Option<Ratio>, Option<Rate>, DispatchResult)> = vec![
+			(
+				Some(Rate::saturating_from_rational(1, 10000)),
+				Some(Ratio::saturating_from_rational(110, 100)),
+				Some(Rate::saturating_from_rational(25, 100)),
+				Ok(()),
+			),
+			(None, Some(Ratio::saturating_from_rational(5, 1)), None, Ok(())),
+			(None, None, None, Ok(())),
+			(
+				Some(Rate::saturating_from_rational(2, 10000)),
+				None,
+				None,
+				Err(Error::<Runtime>::InterestRateTooHigh.into()),
+			),
+			(
+				None,
+				Some(Ratio::saturating_from_rational(109, 100)),
+				None,
+				Err(Error::<Runtime>::LiquidationRatioOutOfBounds.into()),
+			),
+			(
+				None,
+				Some(Ratio::saturating_from_rational(501, 100)),
+				None,
+				Err(Error::<Runtime>::LiquidationRatioOutOfBounds.into()),
+			),
+			(
+				None,
+				None,
+				Some(Rate::saturating_from_rational(26, 100)),
+				Err(Error::<Runtime>::LiquidationPenaltyTooHigh.into()),
+			),
+		];
+
+		for (interest_rate_per_sec, liquidation_ratio, liquidation_penalty, expected) in cases {
+			assert_eq!(
+				CDPEngineModule::set_collateral_params(
+					RuntimeOrigin::signed(ALICE),
+					BTC,
+					Change::NewValue(interest_rate_per_sec),
+					Change::NewValue(liquidation_ratio),
+					Change::NewValue(liquidation_penalty),
+					Change::NoChange,
+					Change::NoChange,
+				),
+				expected
+			);
+		}
+	});
+}
+
+#[test]
+fn set_collateral_params_clamps_maximum_total_debit_value_increase() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		MaxDebitCeilingIncrease::set(Ratio::saturating_from_rational(2, 1));
+
+		// the first hard cap is not bounded
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+		));
+		assert_eq!(CDPEngineModule::maximum_total_debit_value(BTC), Ok(10000));
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(20000),
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::MaximumTotalDebitValueUpdated {
+				collateral_type: BTC,
+				new_total_debit_value: 20000,
+			},
+		));
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(100000),
+		));
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::MaximumTotalDebitValueClamped {
+				collateral_type: BTC,
+				requested: 100000,
+				applied: 40000,
+			},
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::MaximumTotalDebitValueUpdated {
+				collateral_type: BTC,
+				new_total_debit_value: 40000,
+			},
+		));
+		assert_eq!(CDPEngineModule::maximum_total_debit_value(BTC), Ok(40000));
+
+		// decrease is not bounded
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(1000),
+		));
+		assert_eq!(CDPEngineModule::maximum_total_debit_value(BTC), Ok(1000));
+	});
+}
+
 #[test]
 fn calculate_collateral_ratio_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1738,6 +1872,170 @@ fn offchain_worker_works_cdp() {
 	});
 }
 
+#[test]
+fn liquidate_and_settle_sub_position_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		setup_default_collateral(AUSD);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 200, 900));
+		assert_ok!(LoansModule::split_position(&ALICE, BTC, vec![(110, 400), (90, 500)]));
+
+		// only the sub-position is unsafe
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(2, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_noop!(
+			CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+		assert_ok!(CDPEngineModule::liquidate_position(
+			RuntimeOrigin::none(),
+			BTC,
+			ALICE,
+			1
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP {
+			collateral_type: BTC,
+			owner: ALICE,
+			collateral_amount: 90,
+			bad_debt_value: 50,
+			target_amount: 60,
+		}));
+		assert_eq!(LoansModule::position_at(BTC, ALICE, 1), Position::default());
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 110);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 400);
+		assert_eq!(LoansModule::total_positions(BTC).collateral, 110);
+		assert_eq!(LoansModule::total_positions(BTC).debit, 400);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+
+		mock_shutdown();
+		assert_noop!(
+			CDPEngineModule::settle_position(RuntimeOrigin::none(), BTC, ALICE, 1),
+			Error::<Runtime>::NoDebitValue,
+		);
+		assert_ok!(LoansModule::split_position(&ALICE, BTC, vec![(60, 200), (50, 200)]));
+		assert_ok!(CDPEngineModule::settle_position(RuntimeOrigin::none(), BTC, ALICE, 1));
+		assert_eq!(LoansModule::position_at(BTC, ALICE, 1).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 200);
+		assert_eq!(LoansModule::total_positions(BTC).debit, 200);
+	});
+}
+
+// move the primary position back to the unindexed key, as if the loans migration is in progress
+fn unindex_position(currency_id: CurrencyId, who: &AccountId) {
+	let key = module_loans::Positions::<Runtime>::hashed_key_for((currency_id, who, 0));
+	let position: Position = frame_support::storage::unhashed::take(&key).unwrap();
+	frame_support::storage::unhashed::put(&key[..key.len() - 12], &position);
+	module_loans::IndexPositionsCursor::<Runtime>::put(BoundedVec::truncate_from(vec![0u8]));
+}
+
+#[test]
+fn offchain_worker_liquidates_unindexed_position() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		setup_default_collateral(BTC);
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		unindex_position(BTC, &ALICE);
+		assert_eq!(module_loans::Positions::<Runtime>::iter().count(), 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		run_to_block_offchain(System::block_number() + 1);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			MockCall::CDPEngineModule(crate::Call::liquidate {
+				currency_id: BTC,
+				who: ALICE,
+			})
+		);
+		assert_ok!(CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE));
+		assert_eq!(LoansModule::positions(BTC, ALICE), Position::default());
+		assert_eq!(LoansModule::positions_of(BTC, &ALICE), vec![]);
+		assert_eq!(LoansModule::total_positions(BTC), Position::default());
+	});
+}
+
+#[test]
+fn offchain_worker_settles_sub_positions() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		setup_default_collateral(BTC);
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 200, 900));
+		assert_ok!(LoansModule::split_position(&ALICE, BTC, vec![(110, 400), (90, 500)]));
+
+		// emergency shutdown will settle both positions of alice
+		mock_shutdown();
+		run_to_block_offchain(System::block_number() + 1);
+		let transactions = sp_std::mem::take(&mut pool_state.write().transactions);
+		assert_eq!(transactions.len(), 2);
+		for tx in transactions {
+			let tx = Extrinsic::decode(&mut &*tx).unwrap();
+			match tx.call {
+				MockCall::CDPEngineModule(crate::Call::settle { currency_id, who }) => {
+					assert_ok!(CDPEngineModule::settle(RuntimeOrigin::none(), currency_id, who));
+				}
+				MockCall::CDPEngineModule(crate::Call::settle_position {
+					currency_id,
+					who,
+					position_index,
+				}) => {
+					assert_eq!(position_index, 1);
+					assert_ok!(CDPEngineModule::settle_position(
+						RuntimeOrigin::none(),
+						currency_id,
+						who,
+						position_index
+					));
+				}
+				_ => panic!("unexpected call"),
+			}
+		}
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::position_at(BTC, ALICE, 1).debit, 0);
+		assert_eq!(LoansModule::total_positions(BTC).debit, 0);
+	});
+}
+
 #[test]
 fn offchain_worker_iteration_limit_works() {
 	let (mut offchain, _offchain_state) = testing::TestOffchainExt::new();
@@ -2032,3 +2330,2563 @@ fn liquidation_fails_if_insufficient_repayment() {
 		);
 	});
 }
+
+#[test]
+fn set_max_price_age_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_max_price_age(RuntimeOrigin::signed(AccountId::new([5u8; 32])), BTC, Some(10_000)),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_max_price_age(RuntimeOrigin::signed(ALICE), BTC, Some(10_000)),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		setup_default_collateral(BTC);
+		assert_eq!(CDPEngineModule::collateral_params(BTC).unwrap().max_price_age, None);
+		assert_ok!(CDPEngineModule::set_max_price_age(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(10_000)
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::MaxPriceAgeUpdated {
+			collateral_type: BTC,
+			new_max_price_age: Some(10_000),
+		}));
+		assert_eq!(
+			CDPEngineModule::collateral_params(BTC).unwrap().max_price_age,
+			Some(10_000)
+		);
+
+		assert_ok!(CDPEngineModule::set_max_price_age(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			None
+		));
+		assert_eq!(CDPEngineModule::collateral_params(BTC).unwrap().max_price_age, None);
+	});
+}
+
+#[test]
+fn stale_price_freezes_and_resumes_liquidation_and_debit() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(INIT_TIMESTAMP);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Default::default())),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		setup_default_collateral(AUSD);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		// the price fed at the beginning
+		MockPriceTimestampSource::set_price_timestamp(BTC, Some(INIT_TIMESTAMP));
+		assert_ok!(CDPEngineModule::set_max_price_age(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(10_000)
+		));
+		Timestamp::set_timestamp(INIT_TIMESTAMP + 10_000);
+		assert!(!CDPEngineModule::is_price_stale(BTC));
+
+		// the feed gap is longer than the max price age
+		System::set_block_number(2);
+		Timestamp::set_timestamp(INIT_TIMESTAMP + 10_001);
+		assert!(CDPEngineModule::is_price_stale(BTC));
+		CDPEngineModule::on_initialize(2);
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(crate::Event::FrozenByStalePrice {
+			collateral_type: BTC,
+		}));
+		assert!(CDPEngineModule::frozen_by_stale_price(BTC));
+
+		// new debit is frozen, repaying debit is not
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, 10),
+			Error::<Runtime>::DebitFrozenByStalePrice
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -10));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 490);
+
+		// liquidation is frozen
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_noop!(
+			CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC),
+			Error::<Runtime>::LiquidationFrozenByStalePrice
+		);
+		assert_eq!(
+			<CDPEngineModule as ValidateUnsigned>::validate_unsigned(
+				TransactionSource::Local,
+				&crate::Call::liquidate {
+					currency_id: BTC,
+					who: ALICE
+				}
+			),
+			InvalidTransaction::Custom(STALE_PRICE_LIQUIDATION_FROZEN).into()
+		);
+
+		// the event is only emitted on the transition
+		System::set_block_number(3);
+		Timestamp::set_timestamp(INIT_TIMESTAMP + 20_000);
+		System::reset_events();
+		CDPEngineModule::on_initialize(3);
+		assert!(System::events().is_empty());
+
+		// resume when a fresh price arrives
+		System::set_block_number(4);
+		Timestamp::set_timestamp(INIT_TIMESTAMP + 30_000);
+		MockPriceTimestampSource::set_price_timestamp(BTC, Some(INIT_TIMESTAMP + 29_000));
+		assert!(!CDPEngineModule::is_price_stale(BTC));
+		CDPEngineModule::on_initialize(4);
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(crate::Event::ResumedByFreshPrice {
+			collateral_type: BTC,
+		}));
+		assert!(!CDPEngineModule::frozen_by_stale_price(BTC));
+
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
+
+#[test]
+fn offchain_worker_skips_liquidation_with_stale_price() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		setup_default_collateral(BTC);
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		// changes alice into unsafe position, but BTC has no fresh price
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_max_price_age(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(10_000)
+		));
+		run_to_block_offchain(2);
+		assert!(CDPEngineModule::frozen_by_stale_price(BTC));
+		assert!(pool_state.write().transactions.pop().is_none());
+
+		// a fresh price arrives
+		MockPriceTimestampSource::set_price_timestamp(BTC, Some(3 * BLOCK_TIME + INIT_TIMESTAMP));
+		run_to_block_offchain(3);
+		assert!(!CDPEngineModule::frozen_by_stale_price(BTC));
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			MockCall::CDPEngineModule(crate::Call::liquidate {
+				currency_id: BTC,
+				who: ALICE
+			})
+		);
+	});
+}
+
+fn setup_cross_margin() {
+	setup_default_collateral(BTC);
+	setup_default_collateral(DOT);
+	assert_ok!(DEXModule::add_liquidity(
+		RuntimeOrigin::signed(CAROL),
+		DOT,
+		ACA,
+		3000,
+		3000,
+		0,
+		false
+	));
+	assert_ok!(DEXModule::add_liquidity(
+		RuntimeOrigin::signed(CAROL),
+		ACA,
+		BTC,
+		3000,
+		3000,
+		0,
+		false
+	));
+}
+
+fn make_btc_cdp_unsafe() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+		Change::NoChange,
+		Change::NoChange,
+		Change::NoChange,
+	));
+	let Position { collateral, debit } = LoansModule::positions(BTC, ALICE);
+	assert_eq!(
+		CDPEngineModule::check_cdp_status(BTC, collateral, debit),
+		CDPStatus::Unsafe
+	);
+}
+
+fn cross_margin_events() -> Vec<crate::Event<Runtime>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::CDPEngineModule(
+				event @ (crate::Event::CrossMarginRebalanced { .. } | crate::Event::CrossMarginRescueAttempted { .. }),
+			) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn set_cross_margin_preference_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_default_collateral(BTC);
+		setup_default_collateral(DOT);
+		setup_default_collateral(LP_AUSD_DOT);
+
+		assert_noop!(
+			CDPEngineModule::set_cross_margin_preference(RuntimeOrigin::none(), vec![DOT]),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_cross_margin_preference(RuntimeOrigin::signed(ALICE), vec![BTC; 6]),
+			Error::<Runtime>::TooManyCrossMarginPreferences
+		);
+		assert_noop!(
+			CDPEngineModule::set_cross_margin_preference(RuntimeOrigin::signed(ALICE), vec![DOT, BTC, DOT]),
+			Error::<Runtime>::InvalidCrossMarginPreference
+		);
+		assert_noop!(
+			CDPEngineModule::set_cross_margin_preference(RuntimeOrigin::signed(ALICE), vec![DOT, ACA]),
+			Error::<Runtime>::InvalidCrossMarginPreference
+		);
+		assert_noop!(
+			CDPEngineModule::set_cross_margin_preference(RuntimeOrigin::signed(ALICE), vec![LP_AUSD_DOT]),
+			Error::<Runtime>::InvalidCrossMarginPreference
+		);
+
+		assert_ok!(CDPEngineModule::set_cross_margin_preference(
+			RuntimeOrigin::signed(ALICE),
+			vec![DOT, BTC]
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::CrossMarginPreferenceUpdated {
+				owner: ALICE,
+				collaterals: vec![DOT, BTC],
+			},
+		));
+		assert_eq!(
+			CDPEngineModule::cross_margin_preferences(ALICE).into_inner(),
+			vec![DOT, BTC]
+		);
+
+		assert_ok!(CDPEngineModule::set_cross_margin_preference(
+			RuntimeOrigin::signed(ALICE),
+			vec![]
+		));
+		assert!(!CrossMarginPreferences::<Runtime>::contains_key(ALICE));
+	});
+}
+
+#[test]
+fn cross_margin_rescue_unsafe_cdp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_margin();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 1000, 500));
+		assert_ok!(CDPEngineModule::set_cross_margin_preference(
+			RuntimeOrigin::signed(ALICE),
+			vec![BTC, DOT]
+		));
+		make_btc_cdp_unsafe();
+
+		// BTC lacks 50 value to reach the ratio of 3, supply 75 DOT with the max slippage of 50%,
+		// DOT keeps 76 to stay above its liquidation ratio of 1.5.
+		let (_, target_amount) = DEXModule::get_swap_amount(&[DOT, ACA, BTC], SwapLimit::ExactSupply(75, 0)).unwrap();
+		assert_eq!(target_amount, 71);
+
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		assert_eq!(
+			cross_margin_events(),
+			vec![
+				crate::Event::CrossMarginRebalanced {
+					owner: ALICE,
+					from_collateral_type: DOT,
+					to_collateral_type: BTC,
+					supply_amount: 75,
+					target_amount: 71,
+				},
+				crate::Event::CrossMarginRescueAttempted {
+					owner: ALICE,
+					collateral_type: BTC,
+					rebalanced_count: 1,
+					rescued: true,
+				},
+			]
+		);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP { .. })
+		)));
+
+		// both positions are safe and the debits are untouched
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 171);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(LoansModule::positions(DOT, ALICE).collateral, 925);
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 500);
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 171, 500), CDPStatus::Safe);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), 171);
+		assert_eq!(Currencies::free_balance(DOT, &LoansModule::account_id()), 925);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+	});
+}
+
+#[test]
+fn cross_margin_rescue_partially_then_liquidate() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_margin();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 100, 500));
+		assert_ok!(CDPEngineModule::set_cross_margin_preference(
+			RuntimeOrigin::signed(ALICE),
+			vec![DOT]
+		));
+		make_btc_cdp_unsafe();
+
+		// DOT only has 24 excess collateral above the 76 needed for its liquidation ratio.
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		assert_eq!(
+			cross_margin_events(),
+			vec![
+				crate::Event::CrossMarginRebalanced {
+					owner: ALICE,
+					from_collateral_type: DOT,
+					to_collateral_type: BTC,
+					supply_amount: 24,
+					target_amount: 22,
+				},
+				crate::Event::CrossMarginRescueAttempted {
+					owner: ALICE,
+					collateral_type: BTC,
+					rebalanced_count: 1,
+					rescued: false,
+				},
+			]
+		);
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP {
+			collateral_type: BTC,
+			owner: ALICE,
+			collateral_amount: 122,
+			bad_debt_value: 50,
+			target_amount: 55,
+		}));
+
+		// the BTC position is liquidated, the DOT position stays safe
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(DOT, ALICE).collateral, 76);
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 500);
+		assert_eq!(CDPEngineModule::check_cdp_status(DOT, 76, 500), CDPStatus::Safe);
+	});
+}
+
+#[test]
+fn cross_margin_rescue_skipped_without_preference() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_margin();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 1000, 500));
+		make_btc_cdp_unsafe();
+
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		assert_eq!(cross_margin_events(), vec![]);
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP {
+			collateral_type: BTC,
+			owner: ALICE,
+			collateral_amount: 100,
+			bad_debt_value: 50,
+			target_amount: 55,
+		}));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_eq!(LoansModule::positions(DOT, ALICE).collateral, 1000);
+	});
+}
+
+fn run_to_block_with_secs(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		// one second per block since the accumulation starts
+		Timestamp::set_timestamp((System::block_number() - 1) * BLOCK_TIME);
+		CDPEngineModule::on_initialize(System::block_number());
+	}
+}
+
+fn setup_promotional_collateral() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 100))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+		Change::NewValue(10000),
+	));
+	assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 300));
+}
+
+#[test]
+fn set_promotional_rate_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_promotional_rate(
+				RuntimeOrigin::signed(AccountId::new([5u8; 32])),
+				BTC,
+				Some((Rate::zero(), 10))
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_promotional_rate(RuntimeOrigin::signed(ALICE), BTC, Some((Rate::zero(), 10))),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		setup_promotional_collateral();
+		assert_noop!(
+			CDPEngineModule::set_promotional_rate(RuntimeOrigin::signed(ALICE), BTC, Some((Rate::zero(), 1))),
+			Error::<Runtime>::InvalidPromotionExpiry
+		);
+		assert_noop!(
+			CDPEngineModule::set_promotional_rate(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Some((Rate::saturating_from_integer(2), 10))
+			),
+			Error::<Runtime>::InvalidRate
+		);
+		MaxInterestRatePerSec::set(Rate::saturating_from_rational(1, 10000));
+		assert_noop!(
+			CDPEngineModule::set_promotional_rate(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Some((Rate::saturating_from_rational(2, 10000), 10))
+			),
+			Error::<Runtime>::InterestRateTooHigh
+		);
+
+		assert_ok!(CDPEngineModule::set_promotional_rate(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some((Rate::zero(), 10))
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::PromotionalRateActivated {
+			collateral_type: BTC,
+			interest_rate_per_sec: Rate::zero(),
+			expires_at: 10,
+		}));
+		assert_eq!(CDPEngineModule::get_interest_rate_per_sec(BTC), Ok(Rate::zero()));
+
+		assert_ok!(CDPEngineModule::set_promotional_rate(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			None
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::PromotionalRateCancelled {
+			collateral_type: BTC,
+		}));
+		assert_eq!(CDPEngineModule::promotional_rates(BTC), None);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(1, 100))
+		);
+	});
+}
+
+#[test]
+fn promotional_rate_expires_mid_accumulation_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_promotional_collateral();
+		assert_ok!(CDPEngineModule::set_promotional_rate(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some((Rate::zero(), 3))
+		));
+
+		// the promotional rate applies to the interval elapsed before the expiry block
+		run_to_block_with_secs(3);
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::PromotionalRateExpired {
+			collateral_type: BTC,
+		}));
+		assert_eq!(CDPEngineModule::last_accumulation_secs(), 2);
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(1, 10)
+		);
+		assert_eq!(CDPEngineModule::promotional_rates(BTC), None);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(1, 100))
+		);
+
+		// the base rate resumes from the expiry block
+		run_to_block_with_secs(4);
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(101, 1000)
+		);
+	});
+}
+
+#[test]
+fn promotional_rate_extended_before_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_promotional_collateral();
+		assert_ok!(CDPEngineModule::set_promotional_rate(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some((Rate::zero(), 3))
+		));
+
+		run_to_block_with_secs(2);
+		assert_ok!(CDPEngineModule::set_promotional_rate(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some((Rate::zero(), 5))
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::PromotionalRateActivated {
+			collateral_type: BTC,
+			interest_rate_per_sec: Rate::zero(),
+			expires_at: 5,
+		}));
+		// the base rate updated during the promotion only applies after it expires
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 100))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_eq!(CDPEngineModule::get_interest_rate_per_sec(BTC), Ok(Rate::zero()));
+
+		run_to_block_with_secs(4);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::CDPEngineModule(crate::Event::PromotionalRateExpired { .. })
+		)));
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(1, 10)
+		);
+
+		run_to_block_with_secs(5);
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::PromotionalRateExpired {
+			collateral_type: BTC,
+		}));
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(1, 10)
+		);
+
+		run_to_block_with_secs(6);
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(102, 1000)
+		);
+	});
+}
+
+fn setup_liquidated_cdp_with_cashback(split: Permill) {
+	System::set_block_number(1);
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+		Change::NewValue(10000),
+	));
+	setup_default_collateral(DOT);
+	setup_default_collateral(AUSD);
+	assert_ok!(DEXModule::add_liquidity(
+		RuntimeOrigin::signed(CAROL),
+		BTC,
+		AUSD,
+		100,
+		121,
+		0,
+		false
+	));
+	assert_ok!(CDPEngineModule::set_penalty_cashback_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		split,
+		100,
+	));
+
+	assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::max_value())),
+		Change::NoChange,
+		Change::NoChange,
+		Change::NoChange,
+	));
+	assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+}
+
+fn restore_liquidation_ratio() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NoChange,
+		Change::NoChange,
+		Change::NoChange,
+	));
+}
+
+#[test]
+fn set_penalty_cashback_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_penalty_cashback_params(
+				RuntimeOrigin::signed(BOB),
+				BTC,
+				Permill::from_percent(50),
+				100
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_penalty_cashback_params(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Permill::from_percent(50),
+				100
+			),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		setup_default_collateral(BTC);
+		assert_noop!(
+			CDPEngineModule::set_penalty_cashback_params(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Permill::from_percent(50),
+				0
+			),
+			Error::<Runtime>::InvalidCashbackClaimPeriod
+		);
+
+		assert_ok!(CDPEngineModule::set_penalty_cashback_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Permill::from_percent(50),
+			100
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::PenaltyCashbackParamsUpdated {
+				collateral_type: BTC,
+				split: Permill::from_percent(50),
+				claim_period: 100,
+			},
+		));
+		assert_eq!(
+			CDPEngineModule::cashback_params(BTC),
+			Some(PenaltyCashbackParams {
+				split: Permill::from_percent(50),
+				claim_period: 100,
+			})
+		);
+
+		assert_ok!(CDPEngineModule::set_penalty_cashback_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Permill::zero(),
+			0
+		));
+		assert_eq!(CDPEngineModule::cashback_params(BTC), None);
+	});
+}
+
+#[test]
+fn claim_penalty_cashback_just_before_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_liquidated_cdp_with_cashback(Permill::from_percent(50));
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidationPenaltySplit {
+			collateral_type: BTC,
+			owner: ALICE,
+			penalty_amount: 10,
+			cashback_amount: 5,
+			expires_at: 101,
+		}));
+		assert_eq!(
+			CDPEngineModule::pending_cashbacks(BTC, ALICE),
+			Some(PendingCashback {
+				amount: 5,
+				expires_at: 101,
+			})
+		);
+		// the penalty stays in the surplus of cdp treasury until claimed
+		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 60);
+
+		// open a new safe cdp
+		restore_liquidation_ratio();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 100);
+
+		System::set_block_number(100);
+		assert_ok!(CDPEngineModule::claim_penalty_cashback(
+			RuntimeOrigin::signed(ALICE),
+			BTC
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::PenaltyCashbackClaimed {
+			collateral_type: BTC,
+			owner: ALICE,
+			amount: 5,
+		}));
+		assert_eq!(CDPEngineModule::pending_cashbacks(BTC, ALICE), None);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 105);
+		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 55);
+
+		assert_noop!(
+			CDPEngineModule::claim_penalty_cashback(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::NoPendingCashback
+		);
+	});
+}
+
+#[test]
+fn claim_penalty_cashback_without_qualifying_position_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_liquidated_cdp_with_cashback(Permill::from_percent(50));
+		restore_liquidation_ratio();
+
+		System::set_block_number(50);
+		assert_noop!(
+			CDPEngineModule::claim_penalty_cashback(RuntimeOrigin::signed(BOB), BTC),
+			Error::<Runtime>::NoPendingCashback
+		);
+
+		// no cdp after the liquidation
+		assert_noop!(
+			CDPEngineModule::claim_penalty_cashback(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::NoQualifyingPosition
+		);
+
+		// cdp without debit
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
+		assert_noop!(
+			CDPEngineModule::claim_penalty_cashback(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::NoQualifyingPosition
+		);
+
+		// unsafe cdp
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_noop!(
+			CDPEngineModule::claim_penalty_cashback(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::NoQualifyingPosition
+		);
+	});
+}
+
+#[test]
+fn sweep_expired_penalty_cashback_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_liquidated_cdp_with_cashback(Permill::from_percent(50));
+		restore_liquidation_ratio();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		System::set_block_number(100);
+		assert_noop!(
+			CDPEngineModule::sweep_expired_penalty_cashback(RuntimeOrigin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::CashbackNotExpired
+		);
+
+		System::set_block_number(101);
+		assert_noop!(
+			CDPEngineModule::claim_penalty_cashback(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::CashbackExpired
+		);
+		assert_ok!(CDPEngineModule::sweep_expired_penalty_cashback(
+			RuntimeOrigin::signed(BOB),
+			BTC,
+			ALICE
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::PenaltyCashbackExpired {
+			collateral_type: BTC,
+			owner: ALICE,
+			amount: 5,
+		}));
+		assert_eq!(CDPEngineModule::pending_cashbacks(BTC, ALICE), None);
+		// the cashback falls through to cdp treasury
+		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 60);
+		assert_noop!(
+			CDPEngineModule::sweep_expired_penalty_cashback(RuntimeOrigin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::NoPendingCashback
+		);
+	});
+}
+
+#[test]
+fn zero_cashback_split_keeps_liquidation_unchanged() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_liquidated_cdp_with_cashback(Permill::zero());
+
+		// same as `liquidate_unsafe_cdp_by_swap`
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP {
+			collateral_type: BTC,
+			owner: ALICE,
+			collateral_amount: 100,
+			bad_debt_value: 50,
+			target_amount: 60,
+		}));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (199, 61));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 60);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 901);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+
+		assert_eq!(CDPEngineModule::cashback_params(BTC), None);
+		assert_eq!(CDPEngineModule::pending_cashbacks(BTC, ALICE), None);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::CDPEngineModule(crate::Event::LiquidationPenaltySplit { .. })
+		)));
+	});
+}
+
+fn setup_liquidity_ceiling_collateral() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(None),
+		Change::NewValue(1000),
+	));
+	assert_ok!(DEXModule::add_liquidity(
+		RuntimeOrigin::signed(CAROL),
+		BTC,
+		AUSD,
+		1000,
+		1000,
+		0,
+		false
+	));
+	assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (1000, 1000));
+}
+
+#[test]
+fn set_liquidity_ceiling_multiplier_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_liquidity_ceiling_multiplier(
+				RuntimeOrigin::signed(BOB),
+				BTC,
+				Some(Ratio::saturating_from_integer(2))
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidity_ceiling_multiplier(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Some(Ratio::saturating_from_integer(2))
+			),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		setup_liquidity_ceiling_collateral();
+		// selling the collateral worth the hard cap gets 500 from the pool of 1000/1000
+		assert_ok!(CDPEngineModule::set_liquidity_ceiling_multiplier(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(Ratio::saturating_from_rational(3, 2))
+		));
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidityDebitCeilingUpdated {
+				collateral_type: BTC,
+				liquidity_value: 500,
+				new_debit_ceiling: 750,
+			},
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidityCeilingMultiplierUpdated {
+				collateral_type: BTC,
+				multiplier: Some(Ratio::saturating_from_rational(3, 2)),
+			},
+		));
+		assert_eq!(
+			CDPEngineModule::liquidity_ceiling_multipliers(BTC),
+			Some(Ratio::saturating_from_rational(3, 2))
+		);
+		assert_eq!(CDPEngineModule::liquidity_debit_ceilings(BTC), Some(750));
+		assert_eq!(CDPEngineModule::effective_maximum_total_debit_value(BTC), Ok(750));
+
+		// the static hard cap still applies when it's lower
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(600),
+		));
+		assert_eq!(CDPEngineModule::effective_maximum_total_debit_value(BTC), Ok(600));
+
+		assert_ok!(CDPEngineModule::set_liquidity_ceiling_multiplier(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			None
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidityCeilingMultiplierUpdated {
+				collateral_type: BTC,
+				multiplier: None,
+			},
+		));
+		assert_eq!(CDPEngineModule::liquidity_ceiling_multipliers(BTC), None);
+		assert_eq!(CDPEngineModule::liquidity_debit_ceilings(BTC), None);
+		assert_eq!(CDPEngineModule::effective_maximum_total_debit_value(BTC), Ok(600));
+	});
+}
+
+#[test]
+fn liquidity_debit_ceiling_follows_dex_liquidity() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_liquidity_ceiling_collateral();
+		assert_ok!(CDPEngineModule::set_liquidity_ceiling_multiplier(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(Ratio::saturating_from_integer(2))
+		));
+		assert_eq!(CDPEngineModule::effective_maximum_total_debit_value(BTC), Ok(1000));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 1000, 5000));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 5000);
+
+		// liquidity drains to 100/100, the ceiling shrinks below the existing debit value
+		let lp_btc_ausd = TradingPair::from_currency_ids(BTC, AUSD)
+			.unwrap()
+			.dex_share_currency_id();
+		assert_eq!(Currencies::free_balance(lp_btc_ausd, &CAROL), 2000);
+		assert_ok!(DEXModule::remove_liquidity(
+			RuntimeOrigin::signed(CAROL),
+			BTC,
+			AUSD,
+			1800,
+			0,
+			0,
+			false
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 100));
+		System::set_block_number(2);
+		CDPEngineModule::on_initialize(2);
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidityDebitCeilingUpdated {
+				collateral_type: BTC,
+				liquidity_value: 90,
+				new_debit_ceiling: 180,
+			},
+		));
+		assert_eq!(CDPEngineModule::effective_maximum_total_debit_value(BTC), Ok(180));
+
+		// the existing position is kept, only new debit is blocked
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 5000);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, 10),
+			Error::<Runtime>::ExceedDebitValueHardCap
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -1000));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 4000);
+
+		// a small recovery within the hysteresis doesn't move the ceiling
+		assert_ok!(DEXModule::add_liquidity(
+			RuntimeOrigin::signed(CAROL),
+			BTC,
+			AUSD,
+			4,
+			4,
+			0,
+			false
+		));
+		System::reset_events();
+		System::set_block_number(3);
+		CDPEngineModule::on_initialize(3);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::CDPEngineModule(crate::Event::LiquidityDebitCeilingUpdated { .. })
+		)));
+		assert_eq!(CDPEngineModule::effective_maximum_total_debit_value(BTC), Ok(180));
+
+		// liquidity recovers, the ceiling increases at the next block up to the static hard cap
+		assert_ok!(DEXModule::add_liquidity(
+			RuntimeOrigin::signed(CAROL),
+			BTC,
+			AUSD,
+			900,
+			900,
+			0,
+			false
+		));
+		System::set_block_number(4);
+		CDPEngineModule::on_initialize(4);
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidityDebitCeilingUpdated {
+				collateral_type: BTC,
+				liquidity_value: 500,
+				new_debit_ceiling: 1000,
+			},
+		));
+		assert_eq!(CDPEngineModule::effective_maximum_total_debit_value(BTC), Ok(1000));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, 1000));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 5000);
+	});
+}
+
+fn liquidate_btc_cdp_at(block_number: u64) {
+	System::set_block_number(block_number);
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NoChange,
+		Change::NoChange,
+		Change::NoChange,
+	));
+	assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+		Change::NoChange,
+		Change::NoChange,
+		Change::NoChange,
+	));
+	assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+}
+
+#[test]
+fn liquidation_history_rotates_and_statistics_continue() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		setup_default_collateral(AUSD);
+		assert_eq!(
+			CDPEngineModule::get_liquidation_history(&ALICE),
+			(Default::default(), vec![])
+		);
+
+		for block_number in 1..=5 {
+			liquidate_btc_cdp_at(block_number);
+		}
+
+		let (stats, records) = CDPEngineModule::get_liquidation_history(&ALICE);
+		assert_eq!(
+			stats,
+			LiquidationStats {
+				liquidated_count: 5,
+				self_closed_count: 0,
+				shutdown_settled_count: 0,
+				total_penalty: 50,
+			}
+		);
+		// only the latest `MaxLiquidationRecords` are kept, the oldest first
+		assert_eq!(
+			records.iter().map(|record| record.block_number).collect::<Vec<_>>(),
+			vec![3, 4, 5]
+		);
+		assert_eq!(
+			records[2],
+			LiquidationRecord {
+				block_number: 5,
+				collateral_type: BTC,
+				kind: LiquidationKind::Liquidated,
+				collateral_amount: 100,
+				debit_value: 50,
+				penalty_amount: 10,
+				route: Some(LiquidationRoute::Auction),
+			}
+		);
+		assert_eq!(
+			CDPEngineModule::get_liquidation_history(&BOB),
+			(Default::default(), vec![])
+		);
+		// the liquidated debit value is accumulated
+		assert_eq!(CDPEngineModule::total_liquidated_value(), 250);
+	});
+}
+
+#[test]
+fn self_close_and_shutdown_settlement_recorded_distinctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DEXModule::add_liquidity(
+			RuntimeOrigin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			1000,
+			0,
+			false
+		));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 6));
+
+		System::set_block_number(2);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::settle_cdp_has_debit(ALICE, BTC));
+
+		assert_eq!(
+			CDPEngineModule::get_liquidation_history(&ALICE),
+			(
+				LiquidationStats {
+					liquidated_count: 0,
+					self_closed_count: 1,
+					shutdown_settled_count: 1,
+					total_penalty: 0,
+				},
+				vec![
+					LiquidationRecord {
+						block_number: 1,
+						collateral_type: BTC,
+						kind: LiquidationKind::SelfClosed,
+						collateral_amount: 100,
+						debit_value: 50,
+						penalty_amount: 0,
+						route: None,
+					},
+					LiquidationRecord {
+						block_number: 2,
+						collateral_type: BTC,
+						kind: LiquidationKind::ShutdownSettled,
+						collateral_amount: 50,
+						debit_value: 50,
+						penalty_amount: 0,
+						route: None,
+					},
+				]
+			)
+		);
+	});
+}
+
+#[test]
+fn safe_mode_freezes_new_debit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Default::default())),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		setup_default_collateral(AUSD);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		IsSafeModeActive::set(true);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, 10),
+			Error::<Runtime>::DebitFrozenBySafeMode
+		);
+		assert_noop!(
+			CDPEngineModule::expand_position_collateral(&ALICE, BTC, 100, 0),
+			Error::<Runtime>::DebitFrozenBySafeMode
+		);
+
+		// repaying debit, topping up and withdrawing collateral are still allowed
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -10));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 10, 0));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, -10, 0));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 490);
+
+		IsSafeModeActive::set(false);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, 10));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+	});
+}
+
+fn validate_position_alerts(currency_id: CurrencyId, owners: Vec<AccountId>) -> TransactionValidity {
+	<CDPEngineModule as ValidateUnsigned>::validate_unsigned(
+		TransactionSource::Local,
+		&crate::Call::trigger_position_alerts {
+			currency_id,
+			owners: owners.try_into().unwrap(),
+		},
+	)
+}
+
+#[test]
+fn trigger_position_alerts_validate_unsigned_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_default_collateral(BTC);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		assert_eq!(
+			CDPEngineModule::position_collateral_ratio(BTC, &ALICE),
+			Some(Ratio::saturating_from_rational(2, 1))
+		);
+		ArmedAlerts::set(vec![
+			(ALICE, BTC, Ratio::saturating_from_rational(3, 1)),
+			(BOB, BTC, Ratio::saturating_from_rational(3, 2)),
+		]);
+
+		assert_eq!(
+			validate_position_alerts(BTC, vec![ALICE]),
+			ValidTransaction::with_tag_prefix("CDPEngineOffchainWorker")
+				.priority(CdpEngineUnsignedPriority::get(&BTC) / 2)
+				.and_provides((b"alert", BTC, ALICE))
+				.longevity(64_u64)
+				.propagate(true)
+				.build()
+		);
+
+		// the batch must be non-empty and strictly ascending
+		assert_eq!(validate_position_alerts(BTC, vec![]), InvalidTransaction::Call.into());
+		assert_eq!(
+			validate_position_alerts(BTC, vec![ALICE, ALICE]),
+			InvalidTransaction::Call.into()
+		);
+		assert_eq!(
+			validate_position_alerts(BTC, vec![BOB, ALICE]),
+			InvalidTransaction::Call.into()
+		);
+
+		// the batch can't exceed `MaxPositionAlertBatch`
+		let oversized = vec![ALICE, BOB, CAROL];
+		assert!(oversized.len() as u32 > <Runtime as Config>::MaxPositionAlertBatch::get());
+		assert!(
+			BoundedVec::<AccountId, <Runtime as Config>::MaxPositionAlertBatch>::decode(&mut &oversized.encode()[..])
+				.is_err()
+		);
+
+		// the batch is rejected if any alert is not tripped
+		assert_eq!(
+			validate_position_alerts(BTC, vec![ALICE, BOB]),
+			InvalidTransaction::Custom(POSITION_ALERT_NOT_TRIPPED).into()
+		);
+		// the position without alert or debit
+		assert_eq!(
+			validate_position_alerts(BTC, vec![CAROL]),
+			InvalidTransaction::Custom(POSITION_ALERT_NOT_TRIPPED).into()
+		);
+		assert_eq!(
+			validate_position_alerts(DOT, vec![ALICE]),
+			InvalidTransaction::Custom(POSITION_ALERT_NOT_TRIPPED).into()
+		);
+
+		// the tripped alert is disarmed once triggered
+		assert_ok!(CDPEngineModule::trigger_position_alerts(
+			RuntimeOrigin::none(),
+			BTC,
+			vec![ALICE, BOB].try_into().unwrap()
+		));
+		assert_eq!(
+			TriggeredAlerts::get(),
+			vec![(ALICE, BTC, Ratio::saturating_from_rational(2, 1))]
+		);
+		assert_eq!(
+			validate_position_alerts(BTC, vec![ALICE]),
+			InvalidTransaction::Custom(POSITION_ALERT_NOT_TRIPPED).into()
+		);
+
+		// no alerts after emergency shutdown
+		ArmedAlerts::set(vec![(ALICE, BTC, Ratio::saturating_from_rational(3, 1))]);
+		mock_shutdown();
+		assert_eq!(
+			validate_position_alerts(BTC, vec![ALICE]),
+			InvalidTransaction::Stale.into()
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_triggers_position_alerts_in_batches() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		setup_default_collateral(BTC);
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&CAROL, BTC, 100, 500));
+		ArmedAlerts::set(vec![
+			(ALICE, BTC, Ratio::saturating_from_rational(3, 1)),
+			(BOB, BTC, Ratio::saturating_from_rational(3, 1)),
+			(CAROL, BTC, Ratio::saturating_from_rational(3, 1)),
+		]);
+
+		run_to_block_offchain(2);
+		let mut alerted = vec![];
+		while let Some(tx) = pool_state.write().transactions.pop() {
+			let tx = Extrinsic::decode(&mut &*tx).unwrap();
+			let MockCall::CDPEngineModule(crate::Call::trigger_position_alerts { currency_id, owners }) = tx.call
+			else {
+				panic!("unexpected call");
+			};
+			assert_eq!(currency_id, BTC);
+			assert_ok!(validate_position_alerts(currency_id, owners.to_vec()));
+			alerted.extend(owners.to_vec());
+			assert_ok!(CDPEngineModule::trigger_position_alerts(
+				RuntimeOrigin::none(),
+				currency_id,
+				owners
+			));
+		}
+		alerted.sort();
+		assert_eq!(alerted, vec![ALICE, BOB, CAROL]);
+		assert_eq!(TriggeredAlerts::get().len(), 3);
+
+		// the triggered alerts are disarmed
+		run_to_block_offchain(3);
+		assert!(pool_state.write().transactions.pop().is_none());
+	});
+}
+
+fn run_idle_for(positions: u32) {
+	IdleScheduler::on_idle(
+		System::block_number(),
+		<() as module_idle_scheduler::WeightInfo>::on_idle_base() + <() as crate::WeightInfo>::debit_rebase(positions),
+	);
+}
+
+fn rebase_account(index: u32) -> AccountId {
+	let mut raw = [0u8; 32];
+	raw[28..].copy_from_slice(&index.to_be_bytes());
+	AccountId::new(raw)
+}
+
+// a few thousand positions, every tenth one has no debit
+fn setup_rebase_positions(count: u32) -> Vec<(AccountId, Balance)> {
+	setup_default_collateral(BTC);
+	DebitExchangeRate::<Runtime>::insert(BTC, ExchangeRate::saturating_from_rational(1_234_567, 1_000_000));
+	(0..count)
+		.map(|i| {
+			let who = rebase_account(i);
+			let debit = if i % 10 == 0 {
+				0
+			} else {
+				Balance::from(i).saturating_mul(7_919) % 1_000_003 + 1
+			};
+			assert_ok!(LoansModule::update_loan(&who, BTC, 100, debit.try_into().unwrap()));
+			(who, debit)
+		})
+		.collect()
+}
+
+fn debit_rebase_batches() -> usize {
+	System::events()
+		.iter()
+		.filter(|r| {
+			matches!(
+				r.event,
+				RuntimeEvent::CDPEngineModule(crate::Event::DebitRebaseProgressed { .. })
+			)
+		})
+		.count()
+}
+
+#[test]
+fn schedule_debit_rebase_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::schedule_debit_rebase(RuntimeOrigin::signed(BOB), BTC, 2, 1),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::schedule_debit_rebase(RuntimeOrigin::signed(ALICE), BTC, 2, 1),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		setup_default_collateral(BTC);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		for (numerator, denominator) in [
+			(0, 1),
+			(1, 0),
+			(3, 3),
+			(MAX_DEBIT_REBASE_FACTOR + 1, 1),
+			(1, MAX_DEBIT_REBASE_FACTOR + 1),
+		] {
+			assert_noop!(
+				CDPEngineModule::schedule_debit_rebase(RuntimeOrigin::signed(ALICE), BTC, numerator, denominator),
+				Error::<Runtime>::InvalidDebitRebaseRatio
+			);
+		}
+
+		assert_ok!(CDPEngineModule::schedule_debit_rebase(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			MAX_DEBIT_REBASE_FACTOR,
+			1
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::DebitRebaseScheduled {
+			collateral_type: BTC,
+			numerator: MAX_DEBIT_REBASE_FACTOR,
+			denominator: 1,
+		}));
+		assert_eq!(
+			CDPEngineModule::debit_rebases(BTC),
+			Some(DebitRebaseState {
+				numerator: MAX_DEBIT_REBASE_FACTOR,
+				denominator: 1,
+				..Default::default()
+			})
+		);
+		assert_noop!(
+			CDPEngineModule::schedule_debit_rebase(RuntimeOrigin::signed(ALICE), BTC, 2, 1),
+			Error::<Runtime>::DebitRebaseAlreadyScheduled
+		);
+
+		// the positions of the collateral type are frozen until the rebase finishes
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, -100),
+			Error::<Runtime>::FrozenByDebitRebase
+		);
+		assert_noop!(
+			CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC),
+			Error::<Runtime>::FrozenByDebitRebase
+		);
+
+		run_idle_for(10);
+		assert_eq!(CDPEngineModule::debit_rebases(BTC), None);
+		assert_eq!(LoansModule::positions(BTC, &ALICE).debit, 50_000);
+		assert_eq!(
+			CDPEngineModule::get_debit_exchange_rate(BTC),
+			ExchangeRate::saturating_from_rational(1, 1000)
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -10_000));
+	});
+}
+
+#[test]
+fn debit_rebase_preserves_debit_values() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let positions = setup_rebase_positions(3_000);
+		let rate = CDPEngineModule::get_debit_exchange_rate(BTC);
+		let total_debit = LoansModule::total_positions(BTC).debit;
+		let total_value_before: Balance = positions.iter().map(|(_, debit)| rate.saturating_mul_int(*debit)).sum();
+
+		assert_ok!(CDPEngineModule::schedule_debit_rebase(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			7,
+			3
+		));
+
+		// not enough weight for a position
+		run_idle_for(0);
+		assert_eq!(CDPEngineModule::debit_rebases(BTC).unwrap().cursor, None);
+
+		run_idle_for(500);
+		let state = CDPEngineModule::debit_rebases(BTC).unwrap();
+		assert!(!state.rebased.is_zero() && state.rebased <= 500);
+		assert_ok!(CDPEngineModule::do_try_state());
+		// the interest still accrues on the debit balances before the rebase
+		assert_eq!(CDPEngineModule::total_debit_in_rate_basis(BTC), total_debit);
+		assert_eq!(CDPEngineModule::get_debit_exchange_rate(BTC), rate);
+
+		while CDPEngineModule::debit_rebases(BTC).is_some() {
+			run_idle_for(500);
+			assert_ok!(CDPEngineModule::do_try_state());
+		}
+		assert_eq!(debit_rebase_batches(), 6);
+		assert_eq!(IdleScheduler::tasks(0), None);
+
+		let new_rate = CDPEngineModule::get_debit_exchange_rate(BTC);
+		assert_eq!(
+			new_rate,
+			rate.saturating_mul(ExchangeRate::saturating_from_rational(3, 7))
+		);
+		let mut total_value_after: Balance = 0;
+		for (who, debit) in positions.iter() {
+			let new_debit = LoansModule::positions(BTC, who).debit;
+			assert_eq!(new_debit, debit * 7 / 3);
+			let value_before = rate.saturating_mul_int(*debit);
+			let value_after = new_rate.saturating_mul_int(new_debit);
+			// less than the value of a debit unit after the rebase
+			assert!(value_before.abs_diff(value_after) <= 1);
+			total_value_after += value_after;
+		}
+
+		// the rounding dust is taken over by CDP treasury
+		let dust: Balance = positions.iter().map(|(_, debit)| debit * 7 % 3).sum();
+		assert_eq!(total_debit * 7, LoansModule::total_positions(BTC).debit * 3 + dust);
+		let dust_value = rate.saturating_mul_int(dust) / 7;
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(crate::Event::DebitRebaseCompleted {
+			collateral_type: BTC,
+			rebased: 2_700,
+			new_debit_exchange_rate: new_rate,
+			dust_value,
+		}));
+		assert_eq!(CDPTreasuryModule::debit_pool(), dust_value);
+		assert!(total_value_before.abs_diff(total_value_after + dust_value) <= positions.len() as Balance);
+	});
+}
+
+#[test]
+fn debit_rebase_waits_for_loans_migration() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let positions = setup_rebase_positions(100);
+		assert_ok!(CDPEngineModule::schedule_debit_rebase(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			2,
+			1
+		));
+
+		// the positions are not all indexed yet
+		module_loans::IndexPositionsCursor::<Runtime>::put(BoundedVec::truncate_from(vec![0u8]));
+		run_idle_for(500);
+		assert_eq!(CDPEngineModule::debit_rebases(BTC).unwrap().rebased, 0);
+		assert_eq!(debit_rebase_batches(), 0);
+
+		module_loans::IndexPositionsCursor::<Runtime>::kill();
+		while CDPEngineModule::debit_rebases(BTC).is_some() {
+			run_idle_for(500);
+		}
+		for (who, debit) in positions.iter() {
+			assert_eq!(LoansModule::positions(BTC, who).debit, debit * 2);
+		}
+	});
+}
+
+#[test]
+fn pause_and_resume_debit_rebase() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let positions = setup_rebase_positions(1_000);
+		assert_noop!(
+			CDPEngineModule::pause_debit_rebase(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::DebitRebaseNotFound
+		);
+		assert_ok!(CDPEngineModule::schedule_debit_rebase(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			1,
+			4
+		));
+		assert_noop!(
+			CDPEngineModule::resume_debit_rebase(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::DebitRebaseNotPaused
+		);
+
+		run_idle_for(300);
+		assert_ok!(CDPEngineModule::pause_debit_rebase(RuntimeOrigin::signed(ALICE), BTC));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::DebitRebasePaused {
+			collateral_type: BTC,
+		}));
+		assert_noop!(
+			CDPEngineModule::pause_debit_rebase(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::DebitRebaseAlreadyPaused
+		);
+
+		// the task of the paused rebase is finished without progress
+		let paused = CDPEngineModule::debit_rebases(BTC).unwrap();
+		run_idle_for(300);
+		assert_eq!(CDPEngineModule::debit_rebases(BTC), Some(paused));
+		assert_eq!(IdleScheduler::tasks(0), None);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0),
+			Error::<Runtime>::FrozenByDebitRebase
+		);
+
+		assert_ok!(CDPEngineModule::resume_debit_rebase(RuntimeOrigin::signed(ALICE), BTC));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::DebitRebaseResumed {
+			collateral_type: BTC,
+		}));
+		while CDPEngineModule::debit_rebases(BTC).is_some() {
+			run_idle_for(300);
+		}
+		for (who, debit) in positions.iter() {
+			assert_eq!(LoansModule::positions(BTC, who).debit, debit / 4);
+		}
+	});
+}
+
+#[test]
+fn abort_debit_rebase_reverts_rebased_positions() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let positions = setup_rebase_positions(2_000);
+		let rate = CDPEngineModule::get_debit_exchange_rate(BTC);
+
+		// nothing is rebased yet
+		assert_ok!(CDPEngineModule::schedule_debit_rebase(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			5,
+			2
+		));
+		assert_ok!(CDPEngineModule::abort_debit_rebase(RuntimeOrigin::signed(ALICE), BTC));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::DebitRebaseAborted {
+			collateral_type: BTC,
+			dust_value: 0,
+		}));
+		assert_eq!(CDPEngineModule::debit_rebases(BTC), None);
+
+		assert_ok!(CDPEngineModule::schedule_debit_rebase(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			2,
+			5
+		));
+		run_idle_for(700);
+		run_idle_for(700);
+		assert_ok!(CDPEngineModule::pause_debit_rebase(RuntimeOrigin::signed(ALICE), BTC));
+		let DebitRebaseState { rebased, cursor, .. } = CDPEngineModule::debit_rebases(BTC).unwrap();
+		let last_rebased = cursor.unwrap();
+		let is_rebased = |who: &AccountId| {
+			module_loans::Positions::<Runtime>::hashed_key_for((BTC, who, 0)).as_slice() <= last_rebased.as_slice()
+		};
+		assert_eq!(positions.iter().filter(|(who, _)| is_rebased(who)).count(), 1_400);
+		assert_eq!(
+			positions
+				.iter()
+				.filter(|(who, debit)| !debit.is_zero() && is_rebased(who))
+				.count(),
+			rebased as usize
+		);
+
+		// the paused rebase can be aborted, the task is scheduled again
+		assert_ok!(CDPEngineModule::abort_debit_rebase(RuntimeOrigin::signed(ALICE), BTC));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::DebitRebaseAborting {
+			collateral_type: BTC,
+			rebased,
+		}));
+		assert_noop!(
+			CDPEngineModule::abort_debit_rebase(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::DebitRebaseAlreadyAborted
+		);
+		while CDPEngineModule::debit_rebases(BTC).is_some() {
+			run_idle_for(500);
+			assert_ok!(CDPEngineModule::do_try_state());
+		}
+
+		// the debit exchange rate is unchanged, and the debit values are preserved within the rounding
+		assert_eq!(CDPEngineModule::get_debit_exchange_rate(BTC), rate);
+		let mut dust: Balance = 0;
+		for (who, debit) in positions.iter() {
+			let new_debit = LoansModule::positions(BTC, who).debit;
+			if is_rebased(who) {
+				let rebased_debit = debit * 2 / 5;
+				assert_eq!(new_debit, rebased_debit * 5 / 2);
+				dust += debit * 2 % 5 + rebased_debit * 5 % 2;
+			} else {
+				assert_eq!(new_debit, *debit);
+			}
+			assert!(rate.saturating_mul_int(*debit) - rate.saturating_mul_int(new_debit) <= rate.saturating_mul_int(4));
+		}
+		let dust_value = rate.saturating_mul_int(dust) / 2;
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(crate::Event::DebitRebaseAborted {
+			collateral_type: BTC,
+			dust_value,
+		}));
+		assert_eq!(CDPTreasuryModule::debit_pool(), dust_value);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
+	});
+}
+
+#[test]
+fn revenue_history_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 1000, 3000));
+
+		System::set_block_number(1);
+		assert_eq!(CDPEngineModule::get_revenue(10), Some((0, 0)));
+		CDPEngineModule::accumulate_interest(2, 1);
+		assert_eq!(
+			CDPEngineModule::revenue_history().into_inner(),
+			vec![PeriodRevenue {
+				period_start: 0,
+				stability_fee: 3,
+				liquidation_penalty: 0,
+			}]
+		);
+
+		System::set_block_number(5);
+		CDPEngineModule::record_revenue(|revenue| revenue.liquidation_penalty += 4);
+		System::set_block_number(12);
+		CDPEngineModule::record_revenue(|revenue| revenue.stability_fee += 20);
+		assert_eq!(
+			CDPEngineModule::revenue_history().into_inner(),
+			vec![
+				PeriodRevenue {
+					period_start: 0,
+					stability_fee: 3,
+					liquidation_penalty: 4,
+				},
+				PeriodRevenue {
+					period_start: 10,
+					stability_fee: 20,
+					liquidation_penalty: 0,
+				}
+			]
+		);
+		// the periods overlapping the trailing blocks are summed up
+		assert_eq!(CDPEngineModule::get_revenue(5), Some((23, 4)));
+		assert_eq!(CDPEngineModule::get_revenue(2), Some((20, 0)));
+
+		// the oldest period is rotated out
+		System::set_block_number(25);
+		CDPEngineModule::record_revenue(|revenue| revenue.liquidation_penalty += 5);
+		System::set_block_number(31);
+		CDPEngineModule::record_revenue(|revenue| revenue.stability_fee += 1);
+		assert_eq!(
+			CDPEngineModule::revenue_history()
+				.iter()
+				.map(|revenue| revenue.period_start)
+				.collect::<Vec<_>>(),
+			vec![10, 20, 30]
+		);
+		assert_eq!(CDPEngineModule::get_revenue(20), Some((21, 5)));
+		assert_eq!(CDPEngineModule::get_revenue(25), None);
+	});
+}
+
+// open the positions of BTC by (collateral ratio in percent, debit value), the price of BTC is 1
+// and the debit exchange rate is 1/10
+fn open_risk_positions(positions: &[(u128, Balance)]) {
+	for (i, (ratio, debit_value)) in positions.iter().enumerate() {
+		let position = Position {
+			collateral: debit_value * ratio / 100,
+			debit: debit_value * 10,
+		};
+		module_loans::Positions::<Runtime>::insert((BTC, AccountId::new([100 + i as u8; 32]), 0), position);
+		module_loans::TotalPositions::<Runtime>::mutate(BTC, |total| {
+			total.collateral += position.collateral;
+			total.debit += position.debit;
+		});
+	}
+}
+
+#[test]
+fn risk_snapshot_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_default_collateral(BTC);
+		// the debit values are 100, 200, ..., 1200, the liquidation ratio is 150%, so the first
+		// position is unsafe after the price drop of 10%, the second after 25% and the third
+		// after 50%
+		let positions = (1..=12u128)
+			.map(|i| {
+				let ratio = match i {
+					1 => 160,
+					2 => 180,
+					3 => 250,
+					_ => 400,
+				};
+				(ratio, 100 * i)
+			})
+			.collect::<Vec<_>>();
+		open_risk_positions(&positions);
+
+		CDPEngineModule::on_initialize(9);
+		assert_eq!(CDPEngineModule::risk_snapshots(BTC), None);
+
+		CDPEngineModule::on_initialize(10);
+		let snapshot = RiskSnapshot {
+			block_number: 10,
+			total_debit_value: 7800,
+			total_collateral_value: 30070,
+			weighted_collateral_ratio: Some(Ratio::saturating_from_rational(30070, 7800)),
+			unsafe_debit_value_under_shocks: [
+				(Permill::from_percent(10), 100),
+				(Permill::from_percent(25), 300),
+				(Permill::from_percent(50), 600),
+			],
+			// the largest 10 positions hold all the debit value but the first two positions
+			top_positions_debit_share: Permill::from_rational(7500u128, 7800u128),
+			sampled_positions: 12,
+			sample_cap: 12,
+			is_complete: true,
+		};
+		assert_eq!(CDPEngineModule::risk_snapshots(BTC), Some(snapshot.clone()));
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(crate::Event::RiskSnapshotUpdated {
+			collateral_type: BTC,
+			snapshot: snapshot.clone(),
+		}));
+
+		// kept unchanged without the oracle price
+		MockPriceSource::set_price(BTC, None);
+		CDPEngineModule::on_initialize(20);
+		assert_eq!(CDPEngineModule::risk_snapshots(BTC), Some(snapshot));
+	});
+}
+
+#[test]
+fn risk_snapshot_samples_capped_positions() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_default_collateral(BTC);
+		open_risk_positions(&[(200, 100); 13]);
+
+		assert_eq!(CDPEngineModule::update_risk_snapshot(BTC, 10), 12);
+		assert_eq!(
+			CDPEngineModule::risk_snapshots(BTC),
+			Some(RiskSnapshot {
+				block_number: 10,
+				// the totals cover all the positions
+				total_debit_value: 1300,
+				total_collateral_value: 2600,
+				weighted_collateral_ratio: Some(Ratio::saturating_from_integer(2)),
+				unsafe_debit_value_under_shocks: [
+					(Permill::from_percent(10), 0),
+					(Permill::from_percent(25), 0),
+					(Permill::from_percent(50), 1200),
+				],
+				top_positions_debit_share: Permill::from_rational(1000u128, 1200u128),
+				sampled_positions: 12,
+				sample_cap: 12,
+				is_complete: false,
+			})
+		);
+	});
+}
+
+// enable the substitution of BTC by DOT at `rate`, 1000 DOT is funded to CDP treasury
+fn enable_btc_substitution(rate: ExchangeRate, deadline: u64, cap: Balance) {
+	setup_default_collateral(BTC);
+	setup_default_collateral(DOT);
+	assert_ok!(Currencies::deposit(DOT, &CDPTreasuryModule::account_id(), 1000));
+	assert_ok!(CDPEngineModule::enable_collateral_substitution(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		DOT,
+		rate,
+		deadline,
+		cap
+	));
+}
+
+#[test]
+fn enable_collateral_substitution_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_default_collateral(BTC);
+		setup_default_collateral(DOT);
+		let rate = ExchangeRate::saturating_from_integer(2);
+
+		assert_noop!(
+			CDPEngineModule::enable_collateral_substitution(RuntimeOrigin::signed(BOB), BTC, DOT, rate, 10, 500),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::enable_collateral_substitution(RuntimeOrigin::signed(ALICE), BTC, ACA, rate, 10, 500),
+			Error::<Runtime>::InvalidCollateralType
+		);
+		assert_noop!(
+			CDPEngineModule::enable_collateral_substitution(RuntimeOrigin::signed(ALICE), BTC, BTC, rate, 10, 500),
+			Error::<Runtime>::InvalidCollateralSubstitution
+		);
+		assert_noop!(
+			CDPEngineModule::enable_collateral_substitution(RuntimeOrigin::signed(ALICE), BTC, DOT, rate, 1, 500),
+			Error::<Runtime>::InvalidCollateralSubstitution
+		);
+
+		assert_ok!(CDPEngineModule::enable_collateral_substitution(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			DOT,
+			rate,
+			10,
+			500
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::CollateralSubstitutionEnabled {
+				old_collateral_type: BTC,
+				new_collateral_type: DOT,
+				rate,
+				deadline: 10,
+				cap: 500,
+			},
+		));
+		assert_eq!(
+			CDPEngineModule::collateral_substitutions(BTC),
+			Some(CollateralSubstitution {
+				new_currency_id: DOT,
+				rate,
+				deadline: 10,
+				cap: 500,
+				absorbed: 0,
+			})
+		);
+		assert_noop!(
+			CDPEngineModule::enable_collateral_substitution(RuntimeOrigin::signed(ALICE), BTC, DOT, rate, 10, 500),
+			Error::<Runtime>::CollateralSubstitutionExists
+		);
+	});
+}
+
+#[test]
+fn substitute_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		enable_btc_substitution(ExchangeRate::saturating_from_integer(2), 10, 500);
+		assert_noop!(
+			CDPEngineModule::substitute_collateral(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::NoPositionToSubstitute
+		);
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::substitute_collateral(
+			RuntimeOrigin::signed(ALICE),
+			BTC
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::CollateralSubstituted {
+			owner: ALICE,
+			old_collateral_type: BTC,
+			new_collateral_type: DOT,
+			old_collateral_amount: 100,
+			new_collateral_amount: 200,
+			old_debit_amount: 500,
+			new_debit_amount: 500,
+		}));
+
+		assert_eq!(LoansModule::positions(BTC, ALICE), Default::default());
+		assert_eq!(LoansModule::positions(DOT, ALICE).collateral, 200);
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 500);
+		assert_eq!(LoansModule::total_positions(BTC), Default::default());
+		// the old collateral is absorbed by CDP treasury, which pays the new one
+		assert_eq!(Currencies::free_balance(BTC, &CDPTreasuryModule::account_id()), 100);
+		assert_eq!(Currencies::free_balance(DOT, &CDPTreasuryModule::account_id()), 800);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), 0);
+		assert_eq!(Currencies::free_balance(DOT, &LoansModule::account_id()), 200);
+		assert_eq!(CDPEngineModule::collateral_substitutions(BTC).unwrap().absorbed, 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+	});
+}
+
+#[test]
+fn substitute_collateral_rejects_unsafe_position() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// 50 DOT for 100 BTC, the collateral ratio drops from 200% to 100%
+		enable_btc_substitution(ExchangeRate::saturating_from_rational(1, 2), 10, 500);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		assert_noop!(
+			CDPEngineModule::substitute_collateral(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::BelowLiquidationRatio
+		);
+	});
+}
+
+#[test]
+fn substitute_collateral_closes_after_deadline() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		enable_btc_substitution(ExchangeRate::saturating_from_integer(2), 10, 500);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		System::set_block_number(11);
+		assert_noop!(
+			CDPEngineModule::substitute_collateral(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::CollateralSubstitutionExpired
+		);
+
+		assert_noop!(
+			CDPEngineModule::disable_collateral_substitution(RuntimeOrigin::signed(BOB), BTC),
+			BadOrigin
+		);
+		assert_ok!(CDPEngineModule::disable_collateral_substitution(
+			RuntimeOrigin::signed(ALICE),
+			BTC
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::CollateralSubstitutionDisabled {
+				old_collateral_type: BTC,
+				absorbed: 0,
+			},
+		));
+		assert_noop!(
+			CDPEngineModule::substitute_collateral(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::CollateralSubstitutionNotFound
+		);
+	});
+}
+
+#[test]
+fn substitute_collateral_within_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		enable_btc_substitution(ExchangeRate::saturating_from_integer(2), 10, 150);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+
+		assert_ok!(CDPEngineModule::substitute_collateral(
+			RuntimeOrigin::signed(ALICE),
+			BTC
+		));
+		assert_noop!(
+			CDPEngineModule::substitute_collateral(RuntimeOrigin::signed(BOB), BTC),
+			Error::<Runtime>::CollateralSubstitutionCapExceeded
+		);
+		assert_eq!(CDPEngineModule::collateral_substitutions(BTC).unwrap().absorbed, 100);
+	});
+}
+
+fn setup_unsafe_btc_cdp() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+		Change::NewValue(10000),
+	));
+	setup_default_collateral(AUSD);
+	assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+	make_btc_cdp_unsafe();
+}
+
+fn validate_btc_liquidation() -> TransactionValidity {
+	<CDPEngineModule as ValidateUnsigned>::validate_unsigned(
+		TransactionSource::Local,
+		&crate::Call::liquidate {
+			currency_id: BTC,
+			who: ALICE,
+		},
+	)
+}
+
+#[test]
+fn expand_position_collateral_urgent_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_unsafe_btc_cdp();
+		assert_noop!(
+			CDPEngineModule::expand_position_collateral_urgent(RuntimeOrigin::signed(ALICE), BTC, 0, 0),
+			Error::<Runtime>::ZeroCollateralIncrease
+		);
+		assert_noop!(
+			CDPEngineModule::expand_position_collateral_urgent(RuntimeOrigin::signed(BOB), BTC, 0, 100),
+			Error::<Runtime>::NoDebitValue
+		);
+		assert_noop!(
+			CDPEngineModule::expand_position_collateral_urgent(RuntimeOrigin::signed(ALICE), DOT, 0, 100),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		// a partial top-up is accepted even if the position stays unsafe
+		assert_ok!(CDPEngineModule::expand_position_collateral_urgent(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			0,
+			20
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::PositionCollateralExpanded {
+				owner: ALICE,
+				collateral_type: BTC,
+				position_index: 0,
+				collateral_amount: 20,
+				collateral_ratio: Some(Ratio::saturating_from_rational(12, 5)),
+				is_safe: false,
+			},
+		));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 120);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 880);
+		assert!(validate_btc_liquidation().is_ok());
+
+		assert_ok!(CDPEngineModule::expand_position_collateral_urgent(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			0,
+			80
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::PositionCollateralExpanded {
+				owner: ALICE,
+				collateral_type: BTC,
+				position_index: 0,
+				collateral_amount: 80,
+				collateral_ratio: Some(Ratio::saturating_from_integer(4)),
+				is_safe: true,
+			},
+		));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 200);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 800);
+	});
+}
+
+#[test]
+fn expand_position_collateral_urgent_of_sub_position() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		setup_default_collateral(AUSD);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 200, 900));
+		assert_ok!(LoansModule::split_position(&ALICE, BTC, vec![(110, 400), (90, 500)]));
+
+		// only the sub-position is unsafe
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(2, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_noop!(
+			CDPEngineModule::expand_position_collateral_urgent(RuntimeOrigin::signed(ALICE), BTC, 2, 10),
+			Error::<Runtime>::NoDebitValue
+		);
+
+		assert_ok!(CDPEngineModule::expand_position_collateral_urgent(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			1,
+			10
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::PositionCollateralExpanded {
+				owner: ALICE,
+				collateral_type: BTC,
+				position_index: 1,
+				collateral_amount: 10,
+				collateral_ratio: Some(Ratio::saturating_from_integer(2)),
+				is_safe: true,
+			},
+		));
+		assert_eq!(LoansModule::position_at(BTC, ALICE, 1).collateral, 100);
+		assert_eq!(LoansModule::position_at(BTC, ALICE, 1).debit, 500);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 110);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 400);
+		assert_eq!(LoansModule::total_positions(BTC).collateral, 210);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 790);
+		assert_noop!(
+			CDPEngineModule::liquidate_position(RuntimeOrigin::none(), BTC, ALICE, 1),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+	});
+}
+
+#[test]
+fn expand_position_collateral_urgent_after_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_unsafe_btc_cdp();
+		mock_shutdown();
+
+		assert_ok!(CDPEngineModule::expand_position_collateral_urgent(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			0,
+			100
+		));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 200);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+	});
+}
+
+#[test]
+fn expand_position_collateral_urgent_before_liquidation_in_same_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_unsafe_btc_cdp();
+		assert!(validate_btc_liquidation().is_ok());
+
+		// the top-up lands first, the pending liquidation becomes stale
+		assert_ok!(CDPEngineModule::expand_position_collateral_urgent(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			0,
+			100
+		));
+		assert_eq!(validate_btc_liquidation(), InvalidTransaction::Stale.into());
+		assert_noop!(
+			CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE),
+			Error::<Runtime>::MustBeUnsafe
+		);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 200);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+	});
+}
+
+#[test]
+fn expand_position_collateral_urgent_after_liquidation_in_same_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_unsafe_btc_cdp();
+
+		// the liquidation lands first, the top-up finds no debit and keeps the funds
+		assert_ok!(CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE));
+		assert_noop!(
+			CDPEngineModule::expand_position_collateral_urgent(RuntimeOrigin::signed(ALICE), BTC, 0, 100),
+			Error::<Runtime>::NoDebitValue
+		);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+	});
+}
+
+fn setup_large_btc_cdp(collateral: Balance, debit: Balance) {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+		Change::NewValue(100_000),
+	));
+	setup_default_collateral(AUSD);
+	assert_ok!(Currencies::deposit(BTC, &ALICE, collateral));
+	assert_ok!(CDPEngineModule::adjust_position(
+		&ALICE,
+		BTC,
+		collateral as Amount,
+		debit as Amount
+	));
+	make_btc_cdp_unsafe();
+}
+
+#[test]
+fn liquidate_large_cdp_partially() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// the debit value is 10 times the partial liquidation threshold
+		setup_large_btc_cdp(20_000, 100_000);
+		assert_eq!(CDPEngineModule::get_debit_value(BTC, 100_000), 10_000);
+
+		assert_ok!(CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE));
+
+		// the target ratio is 300% + 10%, each liquidated debit value takes 120% of the
+		// collateral value, so (31_000 - 20_000) / 1.9 of the debit value is liquidated
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP {
+			collateral_type: BTC,
+			owner: ALICE,
+			collateral_amount: 6_946,
+			bad_debt_value: 5_789,
+			target_amount: 6_946,
+		}));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 5_789);
+
+		// the remaining position stays open and safe
+		let Position { collateral, debit } = LoansModule::positions(BTC, ALICE);
+		assert_eq!(collateral, 13_054);
+		assert_eq!(debit, 42_110);
+		assert_eq!(
+			CDPEngineModule::check_cdp_status(BTC, collateral, debit),
+			CDPStatus::Safe
+		);
+		assert_noop!(
+			CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE),
+			Error::<Runtime>::MustBeUnsafe
+		);
+	});
+}
+
+#[test]
+fn liquidate_large_cdp_as_a_whole_if_partial_cannot_restore_it() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_large_btc_cdp(20_000, 100_000);
+		// the collateral ratio drops to 110%, below the 120% taken by each liquidated debit value
+		MockPriceSource::set_price(BTC, Some(Price::saturating_from_rational(55, 100)));
+
+		assert_ok!(CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP {
+			collateral_type: BTC,
+			owner: ALICE,
+			collateral_amount: 20_000,
+			bad_debt_value: 10_000,
+			target_amount: 12_000,
+		}));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+	});
+}
+
+#[test]
+fn liquidate_charges_weight_by_created_auction_lots() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_large_btc_cdp(20_000, 100_000);
+		assert_ok!(CDPTreasuryModule::set_expected_collateral_auction_size(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			1_000
+		));
+
+		// the confiscated 6_946 collateral is split into 7 lots
+		let post_info = CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE).unwrap();
+		let expected_weight = <() as crate::WeightInfo>::liquidate_partially_by_auction(7)
+			.max(<() as crate::WeightInfo>::liquidate_by_dex())
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 1));
+		assert_eq!(post_info.actual_weight, Some(expected_weight));
+		assert!(expected_weight.all_lte(CDPEngineModule::max_liquidate_weight()));
+		assert_eq!(MockAuctionManager::auction(), Some((ALICE, BTC, 994, 994)));
+	});
+}
+
+#[test]
+fn shift_position_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_margin();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 1000, 500));
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(DOT)),
+			50
+		);
+
+		assert_noop!(
+			CDPEngineModule::shift_position_collateral(&ALICE, DOT, LP_AUSD_DOT, 0, 200),
+			Error::<Runtime>::InvalidCollateralType
+		);
+		assert_noop!(
+			CDPEngineModule::shift_position_collateral(&ALICE, DOT, BTC, 0, 1001),
+			Error::<Runtime>::CollateralNotEnough
+		);
+
+		let (_, target_amount) = DEXModule::get_swap_amount(&[DOT, ACA, BTC], SwapLimit::ExactSupply(200, 0)).unwrap();
+		let stable_issuance = Currencies::total_issuance(AUSD);
+		assert_eq!(
+			CDPEngineModule::shift_position_collateral(&ALICE, DOT, BTC, 0, 200),
+			Ok((target_amount, 50))
+		);
+		// the issuance origin is moved without issuing or burning
+		assert_eq!(Currencies::total_issuance(AUSD), stable_issuance);
+
+		// the debit value is moved along with the swapped collateral
+		assert_eq!(LoansModule::positions(DOT, ALICE).collateral, 800);
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, target_amount);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(Currencies::free_balance(DOT, &LoansModule::account_id()), 800);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), target_amount);
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(DOT)),
+			0
+		);
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(BTC)),
+			50
+		);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+	});
+}
+
+#[test]
+fn shift_position_collateral_at_index() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_margin();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 1000, 500));
+		assert_ok!(LoansModule::split_position(&ALICE, DOT, vec![(600, 300), (400, 200)]));
+
+		let (_, target_amount) = DEXModule::get_swap_amount(&[DOT, ACA, BTC], SwapLimit::ExactSupply(200, 0)).unwrap();
+		assert_eq!(
+			CDPEngineModule::shift_position_collateral(&ALICE, DOT, BTC, 1, 200),
+			Ok((target_amount, 20))
+		);
+
+		// only the position at the index is shifted, to the position at the same index
+		assert_eq!(
+			LoansModule::position_at(DOT, &ALICE, 0),
+			Position {
+				collateral: 600,
+				debit: 300
+			}
+		);
+		assert_eq!(
+			LoansModule::position_at(DOT, &ALICE, 1),
+			Position {
+				collateral: 200,
+				debit: 0
+			}
+		);
+		assert_eq!(LoansModule::position_at(BTC, &ALICE, 0), Position::default());
+		assert_eq!(
+			LoansModule::position_at(BTC, &ALICE, 1),
+			Position {
+				collateral: target_amount,
+				debit: 200
+			}
+		);
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(DOT)),
+			30
+		);
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(BTC)),
+			20
+		);
+	});
+}
+
+#[test]
+fn shift_position_collateral_frozen_by_debit_rebase_of_old_collateral() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_margin();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 1000, 500));
+		assert_ok!(CDPEngineModule::schedule_debit_rebase(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			2,
+			1
+		));
+
+		assert_noop!(
+			CDPEngineModule::shift_position_collateral(&ALICE, DOT, BTC, 0, 200),
+			Error::<Runtime>::FrozenByDebitRebase
+		);
+	});
+}
+
+#[test]
+fn shift_position_collateral_frozen_by_debit_rebase_of_new_collateral() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_margin();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 1000, 500));
+		assert_ok!(CDPEngineModule::schedule_debit_rebase(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			2,
+			1
+		));
+
+		assert_noop!(
+			CDPEngineModule::shift_position_collateral(&ALICE, DOT, BTC, 0, 200),
+			Error::<Runtime>::FrozenByDebitRebase
+		);
+	});
+}
+
+#[test]
+fn shift_position_collateral_fails_on_slippage_and_required_ratio() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_margin();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 1000, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_integer(2))),
+			Change::NoChange,
+		));
+
+		// the new position holds less than 100 BTC for the debit value of 50
+		assert_noop!(
+			CDPEngineModule::shift_position_collateral(&ALICE, DOT, BTC, 0, 50),
+			Error::<Runtime>::BelowRequiredCollateralRatio
+		);
+
+		// the DEX price drops more than 50% below the oracle price of DOT
+		MockPriceSource::set_price(DOT, Some(Price::saturating_from_integer(3)));
+		assert_noop!(
+			CDPEngineModule::shift_position_collateral(&ALICE, DOT, BTC, 0, 200),
+			SwapError::CannotSwap
+		);
+	});
+}
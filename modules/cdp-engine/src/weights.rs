@@ -51,9 +51,28 @@ pub trait WeightInfo {
 	fn set_collateral_params() -> Weight;
 	fn liquidate_by_auction(b: u32) -> Weight;
 	fn liquidate_by_dex() -> Weight;
+	fn liquidate_partially_by_auction(b: u32) -> Weight;
 	fn settle() -> Weight;
 	fn register_liquidation_contract() -> Weight;
 	fn deregister_liquidation_contract() -> Weight;
+	fn set_max_price_age() -> Weight;
+	fn set_cross_margin_preference() -> Weight;
+	fn set_promotional_rate() -> Weight;
+	fn set_penalty_cashback_params() -> Weight;
+	fn claim_penalty_cashback() -> Weight;
+	fn sweep_expired_penalty_cashback() -> Weight;
+	fn set_liquidity_ceiling_multiplier() -> Weight;
+	fn trigger_position_alerts(n: u32, ) -> Weight;
+	fn schedule_debit_rebase() -> Weight;
+	fn pause_debit_rebase() -> Weight;
+	fn resume_debit_rebase() -> Weight;
+	fn abort_debit_rebase() -> Weight;
+	fn debit_rebase(n: u32, ) -> Weight;
+	fn update_risk_snapshot(n: u32, ) -> Weight;
+	fn enable_collateral_substitution() -> Weight;
+	fn disable_collateral_substitution() -> Weight;
+	fn substitute_collateral() -> Weight;
+	fn expand_position_collateral_urgent() -> Weight;
 }
 
 /// Weights for module_cdp_engine using the Acala node and recommended hardware.
@@ -72,18 +91,23 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn liquidate_by_auction(_b: u32) -> Weight {
 		Weight::from_parts(203_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(28 as u64))
-			.saturating_add(T::DbWeight::get().writes(17 as u64))
+			.saturating_add(T::DbWeight::get().reads(30 as u64))
+			.saturating_add(T::DbWeight::get().writes(19 as u64))
 	}
 	fn liquidate_by_dex() -> Weight {
 		Weight::from_parts(252_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(29 as u64))
-			.saturating_add(T::DbWeight::get().writes(15 as u64))
+			.saturating_add(T::DbWeight::get().reads(31 as u64))
+			.saturating_add(T::DbWeight::get().writes(17 as u64))
+	}
+	fn liquidate_partially_by_auction(_b: u32) -> Weight {
+		Weight::from_parts(211_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(33 as u64))
+			.saturating_add(T::DbWeight::get().writes(19 as u64))
 	}
 	fn settle() -> Weight {
 		Weight::from_parts(97_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(11 as u64))
-			.saturating_add(T::DbWeight::get().writes(8 as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	fn register_liquidation_contract() -> Weight {
 		Weight::from_parts(97_000_000, 0)
@@ -95,6 +119,103 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
+	fn set_max_price_age() -> Weight {
+		Weight::from_parts(37_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_cross_margin_preference() -> Weight {
+		Weight::from_parts(31_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_promotional_rate() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_penalty_cashback_params() -> Weight {
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn claim_penalty_cashback() -> Weight {
+		Weight::from_parts(78_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	fn sweep_expired_penalty_cashback() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_liquidity_ceiling_multiplier() -> Weight {
+		Weight::from_parts(48_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn trigger_position_alerts(n: u32, ) -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn schedule_debit_rebase() -> Weight {
+		Weight::from_parts(29_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn pause_debit_rebase() -> Weight {
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn resume_debit_rebase() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn abort_debit_rebase() -> Weight {
+		Weight::from_parts(26_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn debit_rebase(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+	}
+	fn update_risk_snapshot(n: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn enable_collateral_substitution() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn disable_collateral_substitution() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn substitute_collateral() -> Weight {
+		Weight::from_parts(152_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(23 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
+	}
+	fn expand_position_collateral_urgent() -> Weight {
+		Weight::from_parts(78_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -112,18 +233,23 @@ impl WeightInfo for () {
 	}
 	fn liquidate_by_auction(_b: u32) -> Weight {
 		Weight::from_parts(203_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(28 as u64))
-			.saturating_add(RocksDbWeight::get().writes(17 as u64))
+			.saturating_add(RocksDbWeight::get().reads(30 as u64))
+			.saturating_add(RocksDbWeight::get().writes(19 as u64))
 	}
 	fn liquidate_by_dex() -> Weight {
 		Weight::from_parts(252_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(29 as u64))
-			.saturating_add(RocksDbWeight::get().writes(15 as u64))
+			.saturating_add(RocksDbWeight::get().reads(31 as u64))
+			.saturating_add(RocksDbWeight::get().writes(17 as u64))
+	}
+	fn liquidate_partially_by_auction(_b: u32) -> Weight {
+		Weight::from_parts(211_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(33 as u64))
+			.saturating_add(RocksDbWeight::get().writes(19 as u64))
 	}
 	fn settle() -> Weight {
 		Weight::from_parts(97_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(11 as u64))
-			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
 	fn register_liquidation_contract() -> Weight {
 		Weight::from_parts(97_000_000, 0)
@@ -136,4 +262,101 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11 as u64))
 			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
+	fn set_max_price_age() -> Weight {
+		Weight::from_parts(37_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_cross_margin_preference() -> Weight {
+		Weight::from_parts(31_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_promotional_rate() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_penalty_cashback_params() -> Weight {
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn claim_penalty_cashback() -> Weight {
+		Weight::from_parts(78_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	fn sweep_expired_penalty_cashback() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_liquidity_ceiling_multiplier() -> Weight {
+		Weight::from_parts(48_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn trigger_position_alerts(n: u32, ) -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn schedule_debit_rebase() -> Weight {
+		Weight::from_parts(29_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn pause_debit_rebase() -> Weight {
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn resume_debit_rebase() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn abort_debit_rebase() -> Weight {
+		Weight::from_parts(26_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn debit_rebase(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+	}
+	fn update_risk_snapshot(n: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn enable_collateral_substitution() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn disable_collateral_substitution() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn substitute_collateral() -> Weight {
+		Weight::from_parts(152_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(23 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
+	fn expand_position_collateral_urgent() -> Weight {
+		Weight::from_parts(78_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
 }
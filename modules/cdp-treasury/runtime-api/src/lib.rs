@@ -0,0 +1,82 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use module_support::IssuanceOrigin;
+use parity_scale_codec::{Decode, Encode};
+use primitives::{Amount, Balance, BlockNumber, CurrencyId};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// The total issuance of the stable currency broken down by origin.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct IssuanceBreakdown {
+	/// The net amount issued by each origin since the tracking started.
+	pub by_origin: Vec<(IssuanceOrigin<CurrencyId>, Amount)>,
+	/// The total issuance when the tracking started, which is not accounted to any origin.
+	pub untracked: Balance,
+	/// The current total issuance of the stable currency.
+	pub total_issuance: Balance,
+}
+
+/// The purchases of a treasury diversification basket asset against its target.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BasketAssetProgress {
+	/// The basket asset.
+	pub currency_id: CurrencyId,
+	/// The relative weight of the asset in the basket.
+	pub weight: u32,
+	/// The share of the stable currency spent by the program targeted at the asset.
+	pub target_spent: Balance,
+	/// The stable currency spent on the asset.
+	pub spent: Balance,
+	/// The amount of the asset purchased.
+	pub purchased: Balance,
+	/// The amount of the asset held by the treasury account.
+	pub holding: Balance,
+	/// The max amount of the asset held by the treasury account.
+	pub max_position: Balance,
+}
+
+/// The progress of the treasury diversification program.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DiversificationProgress {
+	/// The total stable currency budget of the program.
+	pub total_budget: Balance,
+	/// The stable currency spent by the program.
+	pub spent: Balance,
+	/// The max stable currency spent per period.
+	pub period_cap: Balance,
+	/// The block of the next purchase period.
+	pub next_period_at: BlockNumber,
+	/// Whether the program is stopped.
+	pub stopped: bool,
+	/// The progress of each basket asset.
+	pub assets: Vec<BasketAssetProgress>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait CdpTreasuryApi {
+		fn get_issuance_breakdown() -> IssuanceBreakdown;
+
+		fn get_diversification_progress() -> Option<DiversificationProgress>;
+	}
+}
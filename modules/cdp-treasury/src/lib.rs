@@ -24,6 +24,10 @@
 //! CDPs, and handle excessive surplus or debits timely in order to keep the
 //! system healthy with low risk. It's the only entry for issuing/burning stable
 //! coin for whole system.
+//!
+//! Governance can also run a diversification program, which periodically spends
+//! part of the stable currency held by the treasury account on a basket of
+//! assets, swapping with slippage bounded by the oracle prices.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -31,14 +35,17 @@
 
 use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use module_support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, Ratio, Swap, SwapLimit};
+use module_cdp_treasury_runtime_api::{BasketAssetProgress, DiversificationProgress, IssuanceBreakdown};
+use module_support::{
+	AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, IssuanceOrigin, PriceProvider, Ratio, Swap, SwapLimit,
+};
 use nutsfinance_stable_asset::traits::StableAsset;
 use nutsfinance_stable_asset::RedeemProportionResult;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
-use primitives::{Balance, CurrencyId};
+use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber,
+	traits::{AccountIdConversion, One, Saturating, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::prelude::*;
 
@@ -49,6 +56,45 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// An asset of the treasury diversification basket.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct BasketAsset {
+	/// The asset to purchase
+	pub currency_id: CurrencyId,
+	/// The relative weight of the asset in the basket
+	pub weight: u32,
+	/// The max amount of the asset held by the treasury account
+	pub max_position: Balance,
+}
+
+/// The treasury diversification program.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DiversificationProgram<BlockNumber> {
+	/// The blocks between two purchase periods
+	pub period: BlockNumber,
+	/// The max amount of stable currency spent per period
+	pub period_cap: Balance,
+	/// The total amount of stable currency to spend
+	pub total_budget: Balance,
+	/// The max slippage of the swaps from the oracle price
+	pub max_slippage: Ratio,
+	/// The amount of stable currency spent
+	pub spent: Balance,
+	/// The block of the next purchase period
+	pub next_period_at: BlockNumber,
+	/// Whether the program is stopped by the emergency stop
+	pub stopped: bool,
+}
+
+/// The purchases of a treasury diversification basket asset.
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct BasketPurchase {
+	/// The amount of stable currency spent
+	pub spent: Balance,
+	/// The amount of the asset purchased
+	pub purchased: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -99,6 +145,13 @@ pub mod module {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The oracle price source bounding the slippage of the diversification swaps.
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// The max count of the assets in the diversification basket.
+		#[pallet::constant]
+		type MaxBasketAssets: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -115,6 +168,20 @@ pub mod module {
 		CannotSwap,
 		/// The currency id is not DexShare type
 		NotDexShare,
+		/// The max count of the collateral auction lots exceeds `MaxAuctionsCount`
+		InvalidMaxCollateralAuctionLots,
+		/// The diversification program parameters are invalid
+		InvalidDiversificationProgram,
+		/// The diversification basket is invalid
+		InvalidBasket,
+		/// The diversification basket has more than `MaxBasketAssets` assets
+		TooManyBasketAssets,
+		/// There is no running diversification program
+		NoRunningDiversification,
+		/// The treasury account holds the max position of the basket asset
+		BasketPositionLimitReached,
+		/// The oracle price is unavailable
+		InvalidFeedPrice,
 	}
 
 	#[pallet::event]
@@ -128,6 +195,33 @@ pub mod module {
 		},
 		/// The buffer amount of debit pool that will not be offset by suplus pool updated.
 		DebitOffsetBufferUpdated { amount: Balance },
+		/// The stable target size and the max count of the collateral auction lots updated.
+		CollateralAuctionLotParamsUpdated { lot_target: Balance, max_lots: u32 },
+		/// A new diversification program is set.
+		DiversificationProgramSet {
+			period: BlockNumberFor<T>,
+			period_cap: Balance,
+			total_budget: Balance,
+			max_slippage: Ratio,
+		},
+		/// The diversification basket updated.
+		DiversificationBasketUpdated { basket: Vec<BasketAsset> },
+		/// The diversification program is stopped.
+		DiversificationStopped { spent: Balance },
+		/// A basket asset is purchased by the diversification program.
+		DiversificationPurchased {
+			currency_id: CurrencyId,
+			supply_amount: Balance,
+			target_amount: Balance,
+		},
+		/// The purchase of a basket asset is skipped this period.
+		DiversificationPurchaseSkipped {
+			currency_id: CurrencyId,
+			amount: Balance,
+			error: DispatchError,
+		},
+		/// A diversification period is executed.
+		DiversificationPeriodExecuted { spent: Balance, total_spent: Balance },
 	}
 
 	/// The expected amount size for per lot collateral auction of specific
@@ -138,6 +232,22 @@ pub mod module {
 	#[pallet::getter(fn expected_collateral_auction_size)]
 	pub type ExpectedCollateralAuctionSize<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, ValueQuery>;
 
+	/// The expected stable target size for per lot collateral auction, the collateral
+	/// auctions with a bigger target are split into lots of this target. Zero to disable.
+	///
+	/// CollateralAuctionLotTarget: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_auction_lot_target)]
+	pub type CollateralAuctionLotTarget<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The max count of the lots of a collateral auction, which run in parallel. Zero for
+	/// `MaxAuctionsCount`.
+	///
+	/// MaxCollateralAuctionLots: u32
+	#[pallet::storage]
+	#[pallet::getter(fn max_collateral_auction_lots)]
+	pub type MaxCollateralAuctionLots<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Current total debit value of system. It's not same as debit in CDP
 	/// engine, it is the bad debt of the system.
 	///
@@ -153,6 +263,44 @@ pub mod module {
 	#[pallet::getter(fn debit_offset_buffer)]
 	pub type DebitOffsetBuffer<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// The net amount of stable currency issued by each origin since the
+	/// issuance tracking started.
+	///
+	/// IssuanceByOrigin: map IssuanceOrigin<CurrencyId> => Amount
+	#[pallet::storage]
+	#[pallet::getter(fn issuance_by_origin)]
+	pub type IssuanceByOrigin<T: Config> = StorageMap<_, Twox64Concat, IssuanceOrigin<CurrencyId>, Amount, ValueQuery>;
+
+	/// The total issuance of stable currency when the issuance tracking
+	/// started, which is not accounted to any origin.
+	///
+	/// UntrackedIssuance: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn untracked_issuance)]
+	pub type UntrackedIssuance<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The treasury diversification program.
+	///
+	/// Diversification: DiversificationProgram
+	#[pallet::storage]
+	#[pallet::getter(fn diversification)]
+	pub type Diversification<T: Config> = StorageValue<_, DiversificationProgram<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The assets purchased by the diversification program.
+	///
+	/// DiversificationBasket: Vec<BasketAsset>
+	#[pallet::storage]
+	#[pallet::getter(fn diversification_basket)]
+	pub type DiversificationBasket<T: Config> =
+		StorageValue<_, BoundedVec<BasketAsset, T::MaxBasketAssets>, ValueQuery>;
+
+	/// The purchases of each basket asset by the diversification program.
+	///
+	/// BasketPurchases: map CurrencyId => BasketPurchase
+	#[pallet::storage]
+	#[pallet::getter(fn basket_purchases)]
+	pub type BasketPurchases<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, BasketPurchase, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T> {
@@ -168,19 +316,33 @@ pub mod module {
 				.for_each(|(currency_id, size)| {
 					ExpectedCollateralAuctionSize::<T>::insert(currency_id, size);
 				});
+			UntrackedIssuance::<T>::put(T::Currency::total_issuance(T::GetStableCurrencyId::get()));
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Execute the diversification period when it's due
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::diversify(now)
+		}
+
 		/// Handle excessive surplus or debits of system when block end
 		fn on_finalize(_now: BlockNumberFor<T>) {
 			// offset the same amount between debit pool and surplus pool
 			Self::offset_surplus_and_debit();
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -292,10 +454,150 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Update the stable target size and the max count of the collateral auction lots
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `lot_target`: expected stable target of per lot collateral auction, zero to disable
+		/// - `max_lots`: max count of the lots of a collateral auction, zero for `MaxAuctionsCount`
+		#[pallet::call_index(5)]
+		#[pallet::weight((T::WeightInfo::set_collateral_auction_lot_params(), DispatchClass::Operational))]
+		pub fn set_collateral_auction_lot_params(
+			origin: OriginFor<T>,
+			#[pallet::compact] lot_target: Balance,
+			max_lots: u32,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				max_lots <= T::MaxAuctionsCount::get(),
+				Error::<T>::InvalidMaxCollateralAuctionLots
+			);
+			CollateralAuctionLotTarget::<T>::put(lot_target);
+			MaxCollateralAuctionLots::<T>::put(max_lots);
+			Self::deposit_event(Event::CollateralAuctionLotParamsUpdated { lot_target, max_lots });
+			Ok(())
+		}
+
+		/// Start a new diversification program, which spends stable currency of the treasury
+		/// account on the diversification basket every period. Replaces the current program and
+		/// resets its progress.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `period`: the blocks between two purchase periods
+		/// - `period_cap`: the max amount of stable currency spent per period
+		/// - `total_budget`: the total amount of stable currency to spend
+		/// - `max_slippage`: the max slippage of the swaps from the oracle price
+		#[pallet::call_index(6)]
+		#[pallet::weight((T::WeightInfo::set_diversification_program(), DispatchClass::Operational))]
+		pub fn set_diversification_program(
+			origin: OriginFor<T>,
+			period: BlockNumberFor<T>,
+			#[pallet::compact] period_cap: Balance,
+			#[pallet::compact] total_budget: Balance,
+			max_slippage: Ratio,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				!period.is_zero() && !period_cap.is_zero() && max_slippage <= Ratio::one(),
+				Error::<T>::InvalidDiversificationProgram
+			);
+
+			let _ = BasketPurchases::<T>::clear(u32::MAX, None);
+			Diversification::<T>::put(DiversificationProgram {
+				period,
+				period_cap,
+				total_budget,
+				max_slippage,
+				spent: Zero::zero(),
+				next_period_at: frame_system::Pallet::<T>::block_number().saturating_add(period),
+				stopped: false,
+			});
+			Self::deposit_event(Event::DiversificationProgramSet {
+				period,
+				period_cap,
+				total_budget,
+				max_slippage,
+			});
+			Ok(())
+		}
+
+		/// Update the diversification basket. Takes effect from the next period, the purchases
+		/// of the program so far are kept.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `basket`: the basket assets with non-zero weights
+		#[pallet::call_index(7)]
+		#[pallet::weight((T::WeightInfo::set_diversification_basket(), DispatchClass::Operational))]
+		pub fn set_diversification_basket(origin: OriginFor<T>, basket: Vec<BasketAsset>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (i, asset) in basket.iter().enumerate() {
+				ensure!(
+					!asset.weight.is_zero()
+						&& asset.currency_id != T::GetStableCurrencyId::get()
+						&& !basket[..i].iter().any(|a| a.currency_id == asset.currency_id),
+					Error::<T>::InvalidBasket
+				);
+			}
+
+			let bounded_basket: BoundedVec<BasketAsset, T::MaxBasketAssets> =
+				basket.clone().try_into().map_err(|_| Error::<T>::TooManyBasketAssets)?;
+			DiversificationBasket::<T>::put(bounded_basket);
+			Self::deposit_event(Event::DiversificationBasketUpdated { basket });
+			Ok(())
+		}
+
+		/// Emergency stop the diversification program. A new program must be set to restart.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::call_index(8)]
+		#[pallet::weight((T::WeightInfo::stop_diversification(), DispatchClass::Operational))]
+		pub fn stop_diversification(origin: OriginFor<T>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Diversification::<T>::try_mutate(|maybe_program| -> DispatchResult {
+				let program = maybe_program
+					.as_mut()
+					.filter(|program| !program.stopped)
+					.ok_or(Error::<T>::NoRunningDiversification)?;
+				program.stopped = true;
+				Self::deposit_event(Event::DiversificationStopped { spent: program.spent });
+				Ok(())
+			})
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The count of the lots to split the collateral auction of `amount` and `target` into.
+	/// The lots are sized by the expected collateral auction size of the collateral type and
+	/// the stable target size of the lots, whichever requires more lots, and bounded by the
+	/// max count of the collateral auction lots.
+	pub fn collateral_auction_lots_count(currency_id: CurrencyId, amount: Balance, target: Balance) -> Balance {
+		let max_auctions_count: Balance = T::MaxAuctionsCount::get().into();
+		if max_auctions_count.is_zero() {
+			return One::one();
+		}
+		let max_lots: Balance = match Self::max_collateral_auction_lots() {
+			0 => max_auctions_count,
+			max_lots => max_lots.into(),
+		};
+
+		let lots_of = |total: Balance, lot_size: Balance| -> Balance {
+			if lot_size.is_zero() {
+				One::one()
+			} else {
+				total.div_ceil(lot_size)
+			}
+		};
+		let lots_count = lots_of(amount, Self::expected_collateral_auction_size(currency_id))
+			.max(lots_of(target, Self::collateral_auction_lot_target()));
+
+		// every lot has some collateral
+		lots_count.min(max_lots).min(amount).max(One::one())
+	}
+
 	/// Get account of cdp treasury module.
 	pub fn account_id() -> T::AccountId {
 		T::PalletId::get().into_account_truncating()
@@ -317,6 +619,182 @@ impl<T: Config> Pallet<T> {
 			.saturating_sub(T::AuctionManagerHandler::get_total_collateral_in_auction(currency_id))
 	}
 
+	/// Get the total issuance of stable currency broken down by origin.
+	pub fn get_issuance_breakdown() -> IssuanceBreakdown {
+		IssuanceBreakdown {
+			by_origin: IssuanceByOrigin::<T>::iter().collect(),
+			untracked: Self::untracked_issuance(),
+			total_issuance: T::Currency::total_issuance(T::GetStableCurrencyId::get()),
+		}
+	}
+
+	/// Get the progress of the diversification program against the basket targets.
+	pub fn get_diversification_progress() -> Option<DiversificationProgress> {
+		let program = Self::diversification()?;
+		let basket = Self::diversification_basket();
+		let total_weight = basket
+			.iter()
+			.fold(Balance::zero(), |acc, asset| acc.saturating_add(asset.weight.into()));
+		let treasury = T::TreasuryAccount::get();
+
+		Some(DiversificationProgress {
+			total_budget: program.total_budget,
+			spent: program.spent,
+			period_cap: program.period_cap,
+			next_period_at: program.next_period_at.saturated_into(),
+			stopped: program.stopped,
+			assets: basket
+				.iter()
+				.map(|asset| {
+					let purchase = Self::basket_purchases(asset.currency_id);
+					BasketAssetProgress {
+						currency_id: asset.currency_id,
+						weight: asset.weight,
+						target_spent: Ratio::checked_from_rational(asset.weight, total_weight)
+							.unwrap_or_default()
+							.saturating_mul_int(program.spent),
+						spent: purchase.spent,
+						purchased: purchase.purchased,
+						holding: T::Currency::free_balance(asset.currency_id, &treasury),
+						max_position: asset.max_position,
+					}
+				})
+				.collect(),
+		})
+	}
+
+	/// Spend the budget of the period on the basket assets in proportion to their weights,
+	/// skipping the assets that can't be purchased within the slippage and position limits.
+	fn diversify(now: BlockNumberFor<T>) -> Weight {
+		let mut program = match Self::diversification() {
+			Some(program)
+				if !program.stopped && program.spent < program.total_budget && now >= program.next_period_at =>
+			{
+				program
+			}
+			_ => return T::DbWeight::get().reads(1),
+		};
+		program.next_period_at = now.saturating_add(program.period);
+
+		let basket = Self::diversification_basket();
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let treasury = T::TreasuryAccount::get();
+		let budget = program
+			.period_cap
+			.min(program.total_budget.saturating_sub(program.spent))
+			.min(T::Currency::free_balance(stable_currency_id, &treasury));
+		let total_weight = basket
+			.iter()
+			.fold(Balance::zero(), |acc, asset| acc.saturating_add(asset.weight.into()));
+
+		let mut period_spent: Balance = Zero::zero();
+		if !budget.is_zero() && !total_weight.is_zero() {
+			for asset in basket.iter() {
+				let amount = Ratio::checked_from_rational(asset.weight, total_weight)
+					.unwrap_or_default()
+					.saturating_mul_int(budget);
+				if amount.is_zero() {
+					continue;
+				}
+
+				match Self::purchase_basket_asset(&treasury, asset, amount, program.max_slippage) {
+					Ok((supply_amount, target_amount)) => {
+						period_spent = period_spent.saturating_add(supply_amount);
+						BasketPurchases::<T>::mutate(asset.currency_id, |purchase| {
+							purchase.spent = purchase.spent.saturating_add(supply_amount);
+							purchase.purchased = purchase.purchased.saturating_add(target_amount);
+						});
+						Self::deposit_event(Event::DiversificationPurchased {
+							currency_id: asset.currency_id,
+							supply_amount,
+							target_amount,
+						});
+					}
+					Err(error) => {
+						Self::deposit_event(Event::DiversificationPurchaseSkipped {
+							currency_id: asset.currency_id,
+							amount,
+							error,
+						});
+					}
+				}
+			}
+		}
+
+		program.spent = program.spent.saturating_add(period_spent);
+		Self::deposit_event(Event::DiversificationPeriodExecuted {
+			spent: period_spent,
+			total_spent: program.spent,
+		});
+		Diversification::<T>::put(program);
+
+		T::WeightInfo::on_initialize(basket.len() as u32)
+	}
+
+	/// Swap up to `amount` stable currency of the treasury account to the basket asset, with
+	/// the received amount bounded by the oracle price and the max position of the asset.
+	#[transactional]
+	fn purchase_basket_asset(
+		treasury: &T::AccountId,
+		asset: &BasketAsset,
+		amount: Balance,
+		max_slippage: Ratio,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let room = asset
+			.max_position
+			.saturating_sub(T::Currency::free_balance(asset.currency_id, treasury));
+		ensure!(!room.is_zero(), Error::<T>::BasketPositionLimitReached);
+
+		// the amount of the basket asset per stable currency
+		let price = T::PriceSource::get_relative_price(stable_currency_id, asset.currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let expected_target = price.saturating_mul_int(amount);
+		let min_rate = Ratio::one().saturating_sub(max_slippage);
+
+		let limit = if expected_target <= room {
+			SwapLimit::ExactSupply(amount, min_rate.saturating_mul_int(expected_target))
+		} else {
+			// buy the room left below the max position, paying at most the slippage over the
+			// oracle price for it
+			let room_cost = Ratio::checked_from_rational(room, expected_target)
+				.unwrap_or_default()
+				.saturating_mul_int(amount);
+			let max_supply = min_rate
+				.reciprocal()
+				.map_or(amount, |rate| rate.saturating_mul_int(room_cost))
+				.min(amount);
+			SwapLimit::ExactTarget(max_supply, room)
+		};
+
+		T::Swap::swap(treasury, stable_currency_id, asset.currency_id, limit)
+	}
+
+	fn record_issuance(origin: IssuanceOrigin<CurrencyId>, amount: Amount) {
+		IssuanceByOrigin::<T>::mutate_exists(origin, |maybe_amount| {
+			let new_amount = maybe_amount.unwrap_or_default().saturating_add(amount);
+			*maybe_amount = (!new_amount.is_zero()).then_some(new_amount);
+		});
+	}
+
+	/// Ensure the issuance accounted to all origins adds up to the total
+	/// issuance of stable currency.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		let tracked = IssuanceByOrigin::<T>::iter_values().fold(0, |acc: Amount, amount| acc.saturating_add(amount));
+		let total_issuance = T::Currency::total_issuance(T::GetStableCurrencyId::get());
+		let expected = Amount::try_from(total_issuance)
+			.ok()
+			.and_then(|total| total.checked_sub(Amount::try_from(Self::untracked_issuance()).ok()?));
+
+		ensure!(
+			expected == Some(tracked),
+			"the issuance of all origins does not match the total issuance of stable currency"
+		);
+
+		Ok(())
+	}
+
 	fn offset_surplus_and_debit() {
 		// The part of the debit pool that exceeds the debit offset buffer can be offset by the surplus
 		let offset_amount = sp_std::cmp::min(
@@ -326,7 +804,7 @@ impl<T: Config> Pallet<T> {
 
 		// Burn the amount that is equal to offset amount of stable currency.
 		if !offset_amount.is_zero() {
-			let res = Self::burn_debit(&Self::account_id(), offset_amount);
+			let res = Self::burn_debit(&Self::account_id(), offset_amount, IssuanceOrigin::System);
 			match res {
 				Ok(_) => {
 					DebitPool::<T>::mutate(|debit| {
@@ -375,29 +853,56 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 		})
 	}
 
-	fn on_system_surplus(amount: Self::Balance) -> DispatchResult {
-		Self::issue_debit(&Self::account_id(), amount, true)
+	fn on_system_surplus(amount: Self::Balance, origin: IssuanceOrigin<Self::CurrencyId>) -> DispatchResult {
+		Self::issue_debit(&Self::account_id(), amount, true, origin)
 	}
 
 	/// This should be the only function in the system that issues stable coin
-	fn issue_debit(who: &T::AccountId, debit: Self::Balance, backed: bool) -> DispatchResult {
+	fn issue_debit(
+		who: &T::AccountId,
+		debit: Self::Balance,
+		backed: bool,
+		origin: IssuanceOrigin<Self::CurrencyId>,
+	) -> DispatchResult {
+		let amount = Amount::try_from(debit).map_err(|_| ArithmeticError::Overflow)?;
 		// increase system debit if the debit is unbacked
 		if !backed {
 			Self::on_system_debit(debit)?;
 		}
 		T::Currency::deposit(T::GetStableCurrencyId::get(), who, debit)?;
+		Self::record_issuance(origin, amount);
 
 		Ok(())
 	}
 
 	/// This should be the only function in the system that burns stable coin
-	fn burn_debit(who: &T::AccountId, debit: Self::Balance) -> DispatchResult {
+	fn burn_debit(
+		who: &T::AccountId,
+		debit: Self::Balance,
+		origin: IssuanceOrigin<Self::CurrencyId>,
+	) -> DispatchResult {
+		let amount = Amount::try_from(debit).map_err(|_| ArithmeticError::Overflow)?;
 		T::Currency::withdraw(
 			T::GetStableCurrencyId::get(),
 			who,
 			debit,
 			ExistenceRequirement::AllowDeath,
-		)
+		)?;
+		Self::record_issuance(origin, amount.saturating_neg());
+
+		Ok(())
+	}
+
+	fn transfer_issuance(
+		from: IssuanceOrigin<Self::CurrencyId>,
+		to: IssuanceOrigin<Self::CurrencyId>,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		let amount = Amount::try_from(amount).map_err(|_| ArithmeticError::Overflow)?;
+		Self::record_issuance(from, amount.saturating_neg());
+		Self::record_issuance(to, amount);
+
+		Ok(())
 	}
 
 	fn deposit_surplus(from: &T::AccountId, surplus: Self::Balance) -> DispatchResult {
@@ -534,26 +1039,10 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 
 		let mut unhandled_collateral_amount = amount;
 		let mut unhandled_target = target;
-		let expected_collateral_auction_size = Self::expected_collateral_auction_size(currency_id);
-		let max_auctions_count: Balance = T::MaxAuctionsCount::get().into();
-		let lots_count = if !splited
-			|| max_auctions_count.is_zero()
-			|| expected_collateral_auction_size.is_zero()
-			|| amount <= expected_collateral_auction_size
-		{
-			One::one()
+		let lots_count = if splited {
+			Self::collateral_auction_lots_count(currency_id, amount, target)
 		} else {
-			let mut count = amount
-				.checked_div(expected_collateral_auction_size)
-				.expect("collateral auction maximum size is not zero; qed");
-
-			let remainder = amount
-				.checked_rem(expected_collateral_auction_size)
-				.expect("collateral auction maximum size is not zero; qed");
-			if !remainder.is_zero() {
-				count = count.saturating_add(One::one());
-			}
-			sp_std::cmp::min(count, max_auctions_count)
+			One::one()
 		};
 		let average_amount_per_lot = amount.checked_div(lots_count).expect("lots count is at least 1; qed");
 		let average_target_per_lot = target.checked_div(lots_count).expect("lots count is at least 1; qed");
@@ -624,3 +1113,19 @@ impl<T: Config, GetBufferSize: Get<Balance>> frame_support::traits::OnRuntimeUpg
 		Weight::from_parts(0, 0)
 	}
 }
+
+/// Record the total issuance of stable currency before the issuance tracking
+/// started as untracked issuance.
+pub struct RecordUntrackedIssuance<T>(PhantomData<T>);
+impl<T: Config> frame_support::traits::OnRuntimeUpgrade for RecordUntrackedIssuance<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		UntrackedIssuance::<T>::put(T::Currency::total_issuance(T::GetStableCurrencyId::get()));
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
@@ -26,14 +26,14 @@ use frame_support::{
 	traits::{ConstU128, ConstU32, ConstU64, EitherOfDiverse, Nothing},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
-use module_support::SpecificJointsSwap;
+use module_support::{Price, SpecificJointsSwap};
 use nutsfinance_stable_asset::traits::StableAsset;
 use nutsfinance_stable_asset::{
 	PoolTokenIndex, RedeemProportionResult, StableAssetPoolId, StableAssetPoolInfo, SwapResult,
 };
 use orml_traits::parameter_type_with_key;
 use primitives::{DexShare, TokenSymbol, TradingPair};
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Percent};
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -120,6 +120,7 @@ parameter_types! {
 		TradingPair::from_currency_ids(BTC, DOT).unwrap(),
 	];
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
 }
 
 impl module_dex::Config for Runtime {
@@ -135,6 +136,18 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type OnTradingPairEnabled = ();
+	type OnTradingPairDisabled = ();
+	type EvmAddressMapping = ();
+	type FlashSwapEvmBridge = ();
+	type GasToWeight = ();
+	type TreasuryAccount = TreasuryAccount;
+	type MinSwapCommitDeposit = ConstU128<100>;
+	type SwapRevealPeriod = ConstU64<10>;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = ConstU32<2>;
+	type LbpCheckpointPeriod = ConstU64<10>;
+	type MaxLbpPools = ConstU32<2>;
 }
 
 parameter_types! {
@@ -172,6 +185,23 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	}
 }
 
+parameter_types! {
+	pub static BtcPrice: Option<Price> = Some(Price::saturating_from_integer(10));
+	pub static DotPrice: Option<Price> = Some(Price::one());
+}
+
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => Some(Price::one()),
+			BTC => BtcPrice::get(),
+			DOT => DotPrice::get(),
+			_ => None,
+		}
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -197,6 +227,8 @@ impl Config for Runtime {
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type StableAsset = MockStableAsset;
+	type PriceSource = MockPriceSource;
+	type MaxBasketAssets = ConstU32<10>;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -255,6 +287,13 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		cdp_treasury::GenesisConfig::<Runtime> {
+			expected_collateral_auction_size: vec![],
+			_phantom: Default::default(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		t.into()
 	}
 }
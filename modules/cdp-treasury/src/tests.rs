@@ -21,7 +21,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::OnRuntimeUpgrade};
 use mock::{RuntimeEvent, *};
 use module_support::SwapError;
 use sp_runtime::traits::BadOrigin;
@@ -66,7 +66,10 @@ fn on_system_surplus_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 0);
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
-		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(
+			1000,
+			IssuanceOrigin::Collateral(BTC)
+		));
 		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 1000);
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
 	});
@@ -78,7 +81,10 @@ fn offset_surplus_and_debit_on_finalize_work() {
 		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 0);
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
 		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
-		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(
+			1000,
+			IssuanceOrigin::Collateral(BTC)
+		));
 		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 1000);
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
 		CDPTreasuryModule::on_finalize(1);
@@ -106,11 +112,21 @@ fn issue_debit_work() {
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 1000);
 		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
 
-		assert_ok!(CDPTreasuryModule::issue_debit(&ALICE, 1000, true));
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&ALICE,
+			1000,
+			true,
+			IssuanceOrigin::Collateral(BTC)
+		));
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 2000);
 		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
 
-		assert_ok!(CDPTreasuryModule::issue_debit(&ALICE, 1000, false));
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&ALICE,
+			1000,
+			false,
+			IssuanceOrigin::System
+		));
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 3000);
 		assert_eq!(CDPTreasuryModule::debit_pool(), 1000);
 	});
@@ -121,12 +137,186 @@ fn burn_debit_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 1000);
 		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
-		assert_ok!(CDPTreasuryModule::burn_debit(&ALICE, 300));
+		assert_ok!(CDPTreasuryModule::burn_debit(
+			&ALICE,
+			300,
+			IssuanceOrigin::Collateral(BTC)
+		));
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 700);
 		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
 	});
 }
 
+#[test]
+fn issuance_breakdown_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			CDPTreasuryModule::get_issuance_breakdown(),
+			IssuanceBreakdown {
+				by_origin: vec![],
+				untracked: 2000,
+				total_issuance: 2000,
+			}
+		);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&ALICE,
+			1000,
+			true,
+			IssuanceOrigin::Collateral(BTC)
+		));
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&BOB,
+			500,
+			true,
+			IssuanceOrigin::Collateral(DOT)
+		));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(
+			100,
+			IssuanceOrigin::Collateral(BTC)
+		));
+		assert_ok!(CDPTreasuryModule::issue_debit(&BOB, 200, false, IssuanceOrigin::System));
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(BTC)),
+			1100
+		);
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(DOT)),
+			500
+		);
+		assert_eq!(CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::System), 200);
+		assert_eq!(Currencies::total_issuance(AUSD), 3800);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+
+		// the surplus offsetting burns are accounted to system
+		CDPTreasuryModule::on_finalize(1);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::System), 100);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+
+		// burns can exceed the issuance of an origin when they repay untracked issuance
+		assert_ok!(CDPTreasuryModule::burn_debit(
+			&ALICE,
+			1500,
+			IssuanceOrigin::Collateral(BTC)
+		));
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(BTC)),
+			-400
+		);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+	});
+}
+
+#[test]
+fn issuance_breakdown_round_trip_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&ALICE,
+			1000,
+			true,
+			IssuanceOrigin::Collateral(BTC)
+		));
+		assert_ok!(CDPTreasuryModule::issue_debit(&BOB, 300, false, IssuanceOrigin::System));
+		assert_ok!(CDPTreasuryModule::burn_debit(
+			&ALICE,
+			1000,
+			IssuanceOrigin::Collateral(BTC)
+		));
+		assert_ok!(CDPTreasuryModule::burn_debit(&BOB, 300, IssuanceOrigin::System));
+
+		// the origins are removed once their net issuance returns to zero
+		assert!(!IssuanceByOrigin::<Runtime>::contains_key(IssuanceOrigin::Collateral(
+			BTC
+		)));
+		assert!(!IssuanceByOrigin::<Runtime>::contains_key(IssuanceOrigin::System));
+		assert_eq!(
+			CDPTreasuryModule::get_issuance_breakdown(),
+			IssuanceBreakdown {
+				by_origin: vec![],
+				untracked: 2000,
+				total_issuance: 2000,
+			}
+		);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+
+		// failed burns are not accounted
+		assert!(CDPTreasuryModule::burn_debit(&CHARLIE, 100, IssuanceOrigin::System).is_err());
+		assert_eq!(CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::System), 0);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+	});
+}
+
+#[test]
+fn transfer_issuance_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&ALICE,
+			1000,
+			true,
+			IssuanceOrigin::Collateral(BTC)
+		));
+		assert_ok!(CDPTreasuryModule::transfer_issuance(
+			IssuanceOrigin::Collateral(BTC),
+			IssuanceOrigin::Collateral(DOT),
+			400
+		));
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(BTC)),
+			600
+		);
+		assert_eq!(
+			CDPTreasuryModule::issuance_by_origin(IssuanceOrigin::Collateral(DOT)),
+			400
+		);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 2000);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+
+		assert_ok!(CDPTreasuryModule::transfer_issuance(
+			IssuanceOrigin::Collateral(BTC),
+			IssuanceOrigin::Collateral(DOT),
+			600
+		));
+		assert!(!IssuanceByOrigin::<Runtime>::contains_key(IssuanceOrigin::Collateral(
+			BTC
+		)));
+		assert_ok!(CDPTreasuryModule::do_try_state());
+	});
+}
+
+#[test]
+fn record_untracked_issuance_migration_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(0).put::<CDPTreasuryModule>();
+		UntrackedIssuance::<Runtime>::kill();
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&ALICE,
+			1000,
+			true,
+			IssuanceOrigin::Collateral(BTC)
+		));
+		IssuanceByOrigin::<Runtime>::remove(IssuanceOrigin::Collateral(BTC));
+		assert!(CDPTreasuryModule::do_try_state().is_err());
+
+		RecordUntrackedIssuance::<Runtime>::on_runtime_upgrade();
+		assert_eq!(CDPTreasuryModule::untracked_issuance(), 3000);
+		assert_eq!(StorageVersion::get::<CDPTreasuryModule>(), 1);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+
+		// runs only once
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&ALICE,
+			1000,
+			true,
+			IssuanceOrigin::System
+		));
+		RecordUntrackedIssuance::<Runtime>::on_runtime_upgrade();
+		assert_eq!(CDPTreasuryModule::untracked_issuance(), 3000);
+		assert_ok!(CDPTreasuryModule::do_try_state());
+	});
+}
+
 #[test]
 fn deposit_surplus_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -493,10 +683,96 @@ fn set_expected_collateral_auction_size_work() {
 	});
 }
 
+#[test]
+fn set_collateral_auction_lot_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPTreasuryModule::set_collateral_auction_lot_params(RuntimeOrigin::signed(5), 400, 3),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_collateral_auction_lot_params(RuntimeOrigin::signed(1), 400, 6),
+			Error::<Runtime>::InvalidMaxCollateralAuctionLots
+		);
+		assert_ok!(CDPTreasuryModule::set_collateral_auction_lot_params(
+			RuntimeOrigin::signed(1),
+			400,
+			3
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::CollateralAuctionLotParamsUpdated {
+				lot_target: 400,
+				max_lots: 3,
+			},
+		));
+		assert_eq!(CDPTreasuryModule::collateral_auction_lot_target(), 400);
+		assert_eq!(CDPTreasuryModule::max_collateral_auction_lots(), 3);
+	});
+}
+
+#[test]
+fn create_collateral_auctions_split_by_lot_target() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Currencies::deposit(BTC, &CDPTreasuryModule::account_id(), 10000));
+		assert_ok!(CDPTreasuryModule::set_collateral_auction_lot_params(
+			RuntimeOrigin::signed(1),
+			400,
+			0
+		));
+
+		// not split if not required
+		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
+			BTC, 1000, 1000, ALICE, false
+		));
+		assert_eq!(TotalCollateralAuction::get(), 1);
+
+		// split into 3 lots of the target 400
+		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
+			BTC, 1000, 1000, ALICE, true
+		));
+		assert_eq!(TotalCollateralAuction::get(), 4);
+		assert_eq!(TotalCollateralInAuction::get(), 2000);
+
+		// the auctions which never reverse are split by the collateral size only
+		assert_ok!(CDPTreasuryModule::create_collateral_auctions(BTC, 1000, 0, ALICE, true));
+		assert_eq!(TotalCollateralAuction::get(), 5);
+
+		// split by the collateral size if it requires more lots
+		assert_ok!(CDPTreasuryModule::set_expected_collateral_auction_size(
+			RuntimeOrigin::signed(1),
+			BTC,
+			250
+		));
+		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
+			BTC, 1000, 1000, ALICE, true
+		));
+		assert_eq!(TotalCollateralAuction::get(), 9);
+
+		// bounded by the max count of lots
+		assert_ok!(CDPTreasuryModule::set_collateral_auction_lot_params(
+			RuntimeOrigin::signed(1),
+			400,
+			2
+		));
+		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
+			BTC, 1000, 1000, ALICE, true
+		));
+		assert_eq!(TotalCollateralAuction::get(), 11);
+
+		// every lot has some collateral
+		assert_eq!(CDPTreasuryModule::collateral_auction_lots_count(BTC, 1, 1000), 1);
+		assert_eq!(TotalCollateralInAuction::get(), 5000);
+	});
+}
+
 #[test]
 fn extract_surplus_to_treasury_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(
+			1000,
+			IssuanceOrigin::Collateral(BTC)
+		));
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
 		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 1000);
 		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 0);
@@ -641,7 +917,10 @@ fn set_debit_offset_buffer_work() {
 #[test]
 fn offset_surplus_and_debit_limited_by_debit_offset_buffer() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(
+			1000,
+			IssuanceOrigin::Collateral(BTC)
+		));
 		assert_ok!(CDPTreasuryModule::on_system_debit(2000));
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
 		assert_eq!(CDPTreasuryModule::debit_pool(), 2000);
@@ -658,7 +937,10 @@ fn offset_surplus_and_debit_limited_by_debit_offset_buffer() {
 			100
 		));
 		assert_eq!(CDPTreasuryModule::debit_offset_buffer(), 100);
-		assert_ok!(CDPTreasuryModule::on_system_surplus(2000));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(
+			2000,
+			IssuanceOrigin::Collateral(BTC)
+		));
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 2000);
 
 		// keep the buffer for debit pool when surplus is enough
@@ -681,3 +963,448 @@ fn offset_surplus_and_debit_limited_by_debit_offset_buffer() {
 		assert_eq!(CDPTreasuryModule::debit_offset_buffer(), 200);
 	});
 }
+
+fn basket_asset(currency_id: CurrencyId, weight: u32, max_position: Balance) -> BasketAsset {
+	BasketAsset {
+		currency_id,
+		weight,
+		max_position,
+	}
+}
+
+fn treasury_balance(currency_id: CurrencyId) -> Balance {
+	Currencies::free_balance(currency_id, &TreasuryAccount::get())
+}
+
+// BTC is 10 AUSD and DOT is 1 AUSD on both the oracle and the dex
+fn setup_diversification(basket: Vec<BasketAsset>) {
+	System::set_block_number(1);
+	assert_ok!(Currencies::deposit(AUSD, &ALICE, 2_000_000));
+	assert_ok!(Currencies::deposit(BTC, &ALICE, 100_000));
+	assert_ok!(Currencies::deposit(DOT, &ALICE, 1_000_000));
+	assert_ok!(DEXModule::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		AUSD,
+		BTC,
+		1_000_000,
+		100_000,
+		0,
+		false
+	));
+	assert_ok!(DEXModule::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		AUSD,
+		DOT,
+		1_000_000,
+		1_000_000,
+		0,
+		false
+	));
+	assert_ok!(Currencies::deposit(AUSD, &TreasuryAccount::get(), 100_000));
+
+	assert_ok!(CDPTreasuryModule::set_diversification_program(
+		RuntimeOrigin::signed(1),
+		10,
+		10_000,
+		25_000,
+		Ratio::saturating_from_rational(5, 100)
+	));
+	assert_ok!(CDPTreasuryModule::set_diversification_basket(
+		RuntimeOrigin::signed(1),
+		basket
+	));
+}
+
+fn run_diversification_at(n: BlockNumber) {
+	System::set_block_number(n);
+	CDPTreasuryModule::on_initialize(n);
+}
+
+#[test]
+fn set_diversification_program_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let max_slippage = Ratio::saturating_from_rational(5, 100);
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_program(RuntimeOrigin::signed(5), 10, 100, 1_000, max_slippage),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_program(RuntimeOrigin::signed(1), 0, 100, 1_000, max_slippage),
+			Error::<Runtime>::InvalidDiversificationProgram
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_program(RuntimeOrigin::signed(1), 10, 0, 1_000, max_slippage),
+			Error::<Runtime>::InvalidDiversificationProgram
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_program(
+				RuntimeOrigin::signed(1),
+				10,
+				100,
+				1_000,
+				Ratio::saturating_from_rational(101, 100)
+			),
+			Error::<Runtime>::InvalidDiversificationProgram
+		);
+
+		BasketPurchases::<Runtime>::insert(
+			BTC,
+			BasketPurchase {
+				spent: 10,
+				purchased: 1,
+			},
+		);
+		assert_ok!(CDPTreasuryModule::set_diversification_program(
+			RuntimeOrigin::signed(1),
+			10,
+			100,
+			1_000,
+			max_slippage
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationProgramSet {
+				period: 10,
+				period_cap: 100,
+				total_budget: 1_000,
+				max_slippage,
+			},
+		));
+		assert_eq!(
+			CDPTreasuryModule::diversification(),
+			Some(DiversificationProgram {
+				period: 10,
+				period_cap: 100,
+				total_budget: 1_000,
+				max_slippage,
+				spent: 0,
+				next_period_at: 11,
+				stopped: false,
+			})
+		);
+		assert_eq!(CDPTreasuryModule::basket_purchases(BTC), Default::default());
+	});
+}
+
+#[test]
+fn set_diversification_basket_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let basket = vec![basket_asset(BTC, 1, 1_000), basket_asset(DOT, 3, 1_000)];
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_basket(RuntimeOrigin::signed(5), basket.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_basket(
+				RuntimeOrigin::signed(1),
+				vec![basket_asset(BTC, 1, 1_000), basket_asset(DOT, 0, 1_000)]
+			),
+			Error::<Runtime>::InvalidBasket
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_basket(RuntimeOrigin::signed(1), vec![basket_asset(AUSD, 1, 1_000)]),
+			Error::<Runtime>::InvalidBasket
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_basket(
+				RuntimeOrigin::signed(1),
+				vec![basket_asset(BTC, 1, 1_000), basket_asset(BTC, 3, 1_000)]
+			),
+			Error::<Runtime>::InvalidBasket
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_diversification_basket(
+				RuntimeOrigin::signed(1),
+				(0..11)
+					.map(|i| basket_asset(CurrencyId::ForeignAsset(i), 1, 1_000))
+					.collect()
+			),
+			Error::<Runtime>::TooManyBasketAssets
+		);
+
+		assert_ok!(CDPTreasuryModule::set_diversification_basket(
+			RuntimeOrigin::signed(1),
+			basket.clone()
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationBasketUpdated { basket: basket.clone() },
+		));
+		assert_eq!(CDPTreasuryModule::diversification_basket().into_inner(), basket);
+	});
+}
+
+#[test]
+fn stop_diversification_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CDPTreasuryModule::stop_diversification(RuntimeOrigin::signed(1)),
+			Error::<Runtime>::NoRunningDiversification
+		);
+		setup_diversification(vec![basket_asset(BTC, 1, 100_000), basket_asset(DOT, 3, 1_000_000)]);
+		run_diversification_at(11);
+		assert_eq!(CDPTreasuryModule::diversification().unwrap().spent, 10_000);
+
+		assert_noop!(
+			CDPTreasuryModule::stop_diversification(RuntimeOrigin::signed(5)),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::stop_diversification(RuntimeOrigin::signed(1)));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(crate::Event::DiversificationStopped {
+			spent: 10_000,
+		}));
+		assert!(CDPTreasuryModule::diversification().unwrap().stopped);
+		assert_noop!(
+			CDPTreasuryModule::stop_diversification(RuntimeOrigin::signed(1)),
+			Error::<Runtime>::NoRunningDiversification
+		);
+
+		// no more purchases once stopped
+		run_diversification_at(21);
+		assert_eq!(treasury_balance(AUSD), 90_000);
+		assert_eq!(CDPTreasuryModule::diversification().unwrap().spent, 10_000);
+	});
+}
+
+#[test]
+fn diversification_enforces_period_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_diversification(vec![basket_asset(BTC, 1, 100_000), basket_asset(DOT, 3, 1_000_000)]);
+
+		// not due yet
+		run_diversification_at(10);
+		assert_eq!(treasury_balance(AUSD), 100_000);
+
+		run_diversification_at(11);
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPurchased {
+				currency_id: BTC,
+				supply_amount: 2_500,
+				target_amount: 249,
+			},
+		));
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPurchased {
+				currency_id: DOT,
+				supply_amount: 7_500,
+				target_amount: 7_444,
+			},
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPeriodExecuted {
+				spent: 10_000,
+				total_spent: 10_000,
+			},
+		));
+		assert_eq!(treasury_balance(AUSD), 90_000);
+		assert_eq!(treasury_balance(BTC), 249);
+		assert_eq!(treasury_balance(DOT), 7_444);
+		assert_eq!(CDPTreasuryModule::diversification().unwrap().next_period_at, 21);
+
+		// once per period
+		run_diversification_at(12);
+		assert_eq!(treasury_balance(AUSD), 90_000);
+
+		run_diversification_at(21);
+		assert_eq!(treasury_balance(AUSD), 80_000);
+
+		// the last period spends the rest of the budget
+		run_diversification_at(31);
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPeriodExecuted {
+				spent: 5_000,
+				total_spent: 25_000,
+			},
+		));
+		assert_eq!(treasury_balance(AUSD), 75_000);
+		assert_eq!(treasury_balance(BTC), 249 + 248 + 123);
+		assert_eq!(treasury_balance(DOT), 7_444 + 7_334 + 3_626);
+		assert_eq!(
+			CDPTreasuryModule::basket_purchases(BTC),
+			BasketPurchase {
+				spent: 6_250,
+				purchased: 620,
+			}
+		);
+		assert_eq!(
+			CDPTreasuryModule::basket_purchases(DOT),
+			BasketPurchase {
+				spent: 18_750,
+				purchased: 18_404,
+			}
+		);
+
+		// the program is finished
+		run_diversification_at(41);
+		assert_eq!(treasury_balance(AUSD), 75_000);
+	});
+}
+
+#[test]
+fn diversification_limited_by_treasury_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_diversification(vec![basket_asset(BTC, 1, 100_000), basket_asset(DOT, 3, 1_000_000)]);
+		assert_ok!(Currencies::withdraw(
+			AUSD,
+			&TreasuryAccount::get(),
+			96_000,
+			ExistenceRequirement::AllowDeath
+		));
+
+		run_diversification_at(11);
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPeriodExecuted {
+				spent: 4_000,
+				total_spent: 4_000,
+			},
+		));
+		assert_eq!(treasury_balance(AUSD), 0);
+
+		// nothing to spend
+		run_diversification_at(21);
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPeriodExecuted {
+				spent: 0,
+				total_spent: 4_000,
+			},
+		));
+	});
+}
+
+#[test]
+fn diversification_skips_purchase_beyond_slippage() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_diversification(vec![basket_asset(BTC, 1, 100_000), basket_asset(DOT, 3, 1_000_000)]);
+
+		// the oracle price of BTC is much lower than the dex price
+		BtcPrice::set(Some(Price::saturating_from_integer(5)));
+		run_diversification_at(11);
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPurchaseSkipped {
+				currency_id: BTC,
+				amount: 2_500,
+				error: SwapError::CannotSwap.into(),
+			},
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPeriodExecuted {
+				spent: 7_500,
+				total_spent: 7_500,
+			},
+		));
+		assert_eq!(treasury_balance(AUSD), 92_500);
+		assert_eq!(treasury_balance(BTC), 0);
+		assert_eq!(CDPTreasuryModule::basket_purchases(BTC), Default::default());
+
+		// no oracle price
+		BtcPrice::set(Some(Price::saturating_from_integer(10)));
+		DotPrice::set(None);
+		run_diversification_at(21);
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPurchaseSkipped {
+				currency_id: DOT,
+				amount: 7_500,
+				error: Error::<Runtime>::InvalidFeedPrice.into(),
+			},
+		));
+		assert_eq!(treasury_balance(AUSD), 90_000);
+		assert_eq!(treasury_balance(BTC), 249);
+		assert_eq!(CDPTreasuryModule::diversification().unwrap().spent, 10_000);
+	});
+}
+
+#[test]
+fn diversification_respects_max_position() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_diversification(vec![basket_asset(BTC, 1, 100), basket_asset(DOT, 3, 1_000_000)]);
+
+		// buys up to the max position
+		run_diversification_at(11);
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPurchased {
+				currency_id: BTC,
+				supply_amount: 1_002,
+				target_amount: 100,
+			},
+		));
+		assert_eq!(treasury_balance(BTC), 100);
+		assert_eq!(CDPTreasuryModule::diversification().unwrap().spent, 8_502);
+
+		run_diversification_at(21);
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPurchaseSkipped {
+				currency_id: BTC,
+				amount: 2_500,
+				error: Error::<Runtime>::BasketPositionLimitReached.into(),
+			},
+		));
+		assert_eq!(treasury_balance(BTC), 100);
+	});
+}
+
+#[test]
+fn diversification_basket_weight_change_mid_program() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_diversification(vec![basket_asset(BTC, 1, 100_000), basket_asset(DOT, 3, 1_000_000)]);
+		run_diversification_at(11);
+
+		assert_ok!(CDPTreasuryModule::set_diversification_basket(
+			RuntimeOrigin::signed(1),
+			vec![basket_asset(BTC, 3, 100_000), basket_asset(DOT, 1, 1_000_000)]
+		));
+		run_diversification_at(21);
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPurchased {
+				currency_id: BTC,
+				supply_amount: 7_500,
+				target_amount: 740,
+			},
+		));
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DiversificationPurchased {
+				currency_id: DOT,
+				supply_amount: 2_500,
+				target_amount: 2_456,
+			},
+		));
+
+		let progress = CDPTreasuryModule::get_diversification_progress().unwrap();
+		assert_eq!(progress.total_budget, 25_000);
+		assert_eq!(progress.spent, 20_000);
+		assert_eq!(progress.next_period_at, 31);
+		assert!(!progress.stopped);
+		assert_eq!(
+			progress.assets,
+			vec![
+				BasketAssetProgress {
+					currency_id: BTC,
+					weight: 3,
+					target_spent: 15_000,
+					spent: 10_000,
+					purchased: 989,
+					holding: 989,
+					max_position: 100_000,
+				},
+				BasketAssetProgress {
+					currency_id: DOT,
+					weight: 1,
+					target_spent: 5_000,
+					spent: 10_000,
+					purchased: 9_900,
+					holding: 9_900,
+					max_position: 1_000_000,
+				},
+			]
+		);
+
+		// removing an asset from the basket stops its purchases
+		assert_ok!(CDPTreasuryModule::set_diversification_basket(
+			RuntimeOrigin::signed(1),
+			vec![basket_asset(DOT, 1, 1_000_000)]
+		));
+		run_diversification_at(31);
+		assert_eq!(treasury_balance(BTC), 989);
+		assert_eq!(CDPTreasuryModule::basket_purchases(DOT).spent, 15_000);
+		assert_eq!(CDPTreasuryModule::diversification().unwrap().spent, 25_000);
+	});
+}
@@ -51,6 +51,11 @@ pub trait WeightInfo {
 	fn auction_collateral(b: u32) -> Weight;
 	fn exchange_collateral_to_stable() -> Weight;
 	fn set_expected_collateral_auction_size() -> Weight;
+	fn set_collateral_auction_lot_params() -> Weight;
+	fn set_diversification_program() -> Weight;
+	fn set_diversification_basket() -> Weight;
+	fn stop_diversification() -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
 }
 
 /// Weights for module_cdp_treasury using the Acala node and recommended hardware.
@@ -73,11 +78,37 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		Weight::from_parts(25_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn set_collateral_auction_lot_params() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 	fn extract_surplus_to_treasury() -> Weight {
 		Weight::from_parts(75_000_000, 0)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	fn set_diversification_program() -> Weight {
+		Weight::from_parts(27_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn set_diversification_basket() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn stop_diversification() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(98_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((9 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -98,9 +129,35 @@ impl WeightInfo for () {
 		Weight::from_parts(25_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn set_collateral_auction_lot_params() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
 	fn extract_surplus_to_treasury() -> Weight {
 		Weight::from_parts(75_000_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(4 as u64))
 			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
+	fn set_diversification_program() -> Weight {
+		Weight::from_parts(27_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn set_diversification_basket() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn stop_diversification() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(98_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((9 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
 }
@@ -55,6 +55,18 @@
 //!
 //! Note: Eventually the Pot distribution may be modified as discussed in
 //! [this issue](https://github.com/paritytech/asset_hub_polkadot/issues/21#issuecomment-810481073).
+//!
+//! ### Reward Smoothing
+//!
+//! Pot inflows are lumpy, so the payout of each session is capped by a target computed as an
+//! exponential moving average (EMA) of recent pot inflows. Any excess income is retained in the Pot
+//! as a buffer. When the Pot cannot cover the target, the shortfall is recorded in
+//! [`RewardShortfall`] and paid back in the following sessions once inflows recover.
+//!
+//! ### Reward Destination
+//!
+//! A candidate can choose to top up its candidacy bond with its rewards, see [`RewardDestination`].
+//! The auto-bonded amount is reserved as the rest of the bond and released by `withdraw_bond`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -78,8 +90,8 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::DispatchClass,
 		sp_runtime::{
-			traits::{AccountIdConversion, CheckedSub, Zero},
-			Permill,
+			traits::{AccountIdConversion, CheckedSub, Saturating, Zero},
+			PerThing, Permill, RuntimeDebug,
 		},
 	};
 	use frame_support::{
@@ -102,8 +114,37 @@ pub mod pallet {
 	pub const POINT_PER_BLOCK: u32 = 10;
 	pub const SESSION_DELAY: SessionIndex = 2;
 
+	/// The smoothing factor applied before it is set by governance.
+	pub const DEFAULT_SMOOTHING_FACTOR: Permill = Permill::from_percent(20);
+
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 
+	/// The state of the collator reward smoothing.
+	#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct RewardSmoothingState<Balance> {
+		/// The EMA of pot inflows per session.
+		pub ema_inflow: Balance,
+		/// The target payout of the current session.
+		pub target: Balance,
+		/// The balance of the pot at the start of the current session.
+		pub pot_balance: Balance,
+		/// The amount already distributed in the current session.
+		pub distributed: Balance,
+	}
+
+	/// Where the rewards of a collator are paid.
+	#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub enum RewardDestination {
+		/// Paid to the free balance.
+		#[default]
+		FreeBalance,
+		/// Tops up the candidacy bond to the current [`CandidacyBond`], the surplus is paid to the
+		/// free balance.
+		Bond,
+	}
+
 	/// A convertor from collators id. Since this pallet does not have stash/controller, this is
 	/// just identity.
 	pub struct IdentityCollator;
@@ -166,6 +207,7 @@ pub mod pallet {
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The invulnerable, fixed collators.
@@ -211,6 +253,41 @@ pub mod pallet {
 	#[pallet::getter(fn non_candidates)]
 	pub type NonCandidates<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, SessionIndex, ValueQuery>;
 
+	#[pallet::type_value]
+	pub fn DefaultSmoothingFactor() -> Permill {
+		DEFAULT_SMOOTHING_FACTOR
+	}
+
+	/// The weight of the latest session inflow in the EMA of pot inflows.
+	///
+	/// SmoothingFactor: Permill
+	#[pallet::storage]
+	#[pallet::getter(fn smoothing_factor)]
+	pub type SmoothingFactor<T> = StorageValue<_, Permill, ValueQuery, DefaultSmoothingFactor>;
+
+	/// The reward smoothing state. Rewards are distributed without a cap until the first session
+	/// ends and initializes it.
+	///
+	/// RewardSmoothing: RewardSmoothingState
+	#[pallet::storage]
+	#[pallet::getter(fn reward_smoothing)]
+	pub type RewardSmoothing<T: Config> = StorageValue<_, RewardSmoothingState<BalanceOf<T>>, OptionQuery>;
+
+	/// The accumulated amount by which the actual payouts fell short of the session targets, to be
+	/// paid back when inflows recover.
+	///
+	/// RewardShortfall: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn reward_shortfall)]
+	pub type RewardShortfall<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The reward destination of each collator.
+	///
+	/// RewardDestinations: map AccountId => RewardDestination
+	#[pallet::storage]
+	#[pallet::getter(fn reward_destinations)]
+	pub type RewardDestinations<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, RewardDestination, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -261,6 +338,29 @@ pub mod pallet {
 		CandidateAdded { who: T::AccountId, bond: BalanceOf<T> },
 		/// A candidate was removed.
 		CandidateRemoved { who: T::AccountId },
+		/// Smoothing factor was updated.
+		NewSmoothingFactor { new_smoothing_factor: Permill },
+		/// The collator rewards of a session were settled.
+		SessionRewardSettled {
+			session_index: SessionIndex,
+			inflow: BalanceOf<T>,
+			target: BalanceOf<T>,
+			actual: BalanceOf<T>,
+			shortfall: BalanceOf<T>,
+			next_target: BalanceOf<T>,
+		},
+		/// Reward destination was updated.
+		RewardDestinationSet {
+			who: T::AccountId,
+			destination: RewardDestination,
+		},
+		/// A collator was rewarded, `bonded` of the `reward` topped up the candidacy bond.
+		CollatorRewarded {
+			who: T::AccountId,
+			reward: BalanceOf<T>,
+			destination: RewardDestination,
+			bonded: BalanceOf<T>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -279,6 +379,7 @@ pub mod pallet {
 		AlreadyInvulnerable,
 		InvalidProof,
 		MaxInvulnerablesExceeded,
+		InvalidSmoothingFactor,
 	}
 
 	#[pallet::hooks]
@@ -383,6 +484,31 @@ pub mod pallet {
 				}
 			})
 		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_smoothing_factor())]
+		pub fn set_smoothing_factor(origin: OriginFor<T>, smoothing_factor: Permill) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!smoothing_factor.is_zero(), Error::<T>::InvalidSmoothingFactor);
+			<SmoothingFactor<T>>::put(smoothing_factor);
+			Self::deposit_event(Event::NewSmoothingFactor {
+				new_smoothing_factor: smoothing_factor,
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_reward_destination())]
+		pub fn set_reward_destination(origin: OriginFor<T>, destination: RewardDestination) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if destination == RewardDestination::default() {
+				<RewardDestinations<T>>::remove(&who);
+			} else {
+				<RewardDestinations<T>>::insert(&who, destination);
+			}
+			Self::deposit_event(Event::RewardDestinationSet { who, destination });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -436,6 +562,107 @@ pub mod pallet {
 				Ok(candidates.len())
 			})
 		}
+
+		/// The pot balance available for rewards, assumes an ED will be kept in pot.
+		fn available_pot_balance() -> BalanceOf<T> {
+			T::Currency::free_balance(&Self::account_id())
+				.checked_sub(&T::Currency::minimum_balance())
+				.unwrap_or_default()
+		}
+
+		/// The remaining payout allowed in the current session, `None` if smoothing is not
+		/// initialized yet.
+		fn remaining_session_payout() -> Option<BalanceOf<T>> {
+			Self::reward_smoothing().map(|state| {
+				state
+					.target
+					.saturating_add(Self::reward_shortfall())
+					.saturating_sub(state.distributed)
+			})
+		}
+
+		/// The expected total reward of the collators in the next session.
+		pub fn expected_session_reward() -> BalanceOf<T> {
+			let available = Self::available_pot_balance();
+			match Self::reward_smoothing() {
+				Some(state) => state.target.saturating_add(Self::reward_shortfall()).min(available),
+				None => available,
+			}
+		}
+
+		/// The expected reward of `who` in the next session, assumes the blocks are authored evenly by
+		/// the collators.
+		pub fn expected_collator_reward(who: &T::AccountId) -> BalanceOf<T> {
+			let collators = T::ValidatorSet::validators();
+			if !collators.contains(who) {
+				return Zero::zero();
+			}
+			Self::expected_session_reward().div((collators.len() as u32).into())
+		}
+
+		/// Tops up the candidacy bond of `who` with `reward` if it's a candidate and its reward
+		/// destination is `Bond`. Returns the bonded amount.
+		fn bond_reward(who: &T::AccountId, reward: BalanceOf<T>) -> BalanceOf<T> {
+			if Self::reward_destinations(who) != RewardDestination::Bond || !Self::candidates().contains(who) {
+				return Zero::zero();
+			}
+
+			let bonded = T::Currency::reserved_balance_named(&RESERVE_ID, who);
+			let amount = Self::candidacy_bond().saturating_sub(bonded).min(reward);
+			if amount.is_zero() {
+				return Zero::zero();
+			}
+
+			match T::Currency::reserve_named(&RESERVE_ID, who, amount) {
+				Ok(()) => amount,
+				Err(_) => Zero::zero(),
+			}
+		}
+
+		/// Settle the collator rewards of the ending session and compute the target of the next one.
+		fn settle_session_rewards(index: SessionIndex) {
+			let pot_balance = T::Currency::free_balance(&Self::account_id());
+
+			let Some(state) = Self::reward_smoothing() else {
+				// the whole available pot is treated as the first inflow.
+				let ema_inflow = Self::available_pot_balance();
+				<RewardSmoothing<T>>::put(RewardSmoothingState {
+					ema_inflow,
+					target: ema_inflow,
+					pot_balance,
+					distributed: Zero::zero(),
+				});
+				return;
+			};
+
+			let inflow = pot_balance
+				.saturating_add(state.distributed)
+				.saturating_sub(state.pot_balance);
+			let target = state.target.saturating_add(Self::reward_shortfall());
+			let shortfall = target.saturating_sub(state.distributed);
+
+			let smoothing_factor = Self::smoothing_factor();
+			let ema_inflow = smoothing_factor
+				.mul_floor(inflow)
+				.saturating_add(smoothing_factor.left_from_one().mul_floor(state.ema_inflow));
+
+			<RewardShortfall<T>>::put(shortfall);
+			<RewardSmoothing<T>>::put(RewardSmoothingState {
+				ema_inflow,
+				target: ema_inflow,
+				pot_balance,
+				distributed: Zero::zero(),
+			});
+
+			Self::deposit_event(Event::SessionRewardSettled {
+				session_index: index,
+				inflow,
+				target,
+				actual: state.distributed,
+				shortfall,
+				next_target: ema_inflow.saturating_add(shortfall),
+			});
+		}
 	}
 
 	/// Keep track of number of authored blocks per authority, uncles are counted as well since
@@ -451,16 +678,38 @@ pub mod pallet {
 				<frame_system::Pallet<T>>::block_number(),
 			);
 			let pot = Self::account_id();
-			// assumes an ED will be sent to pot.
-			let reward = T::Currency::free_balance(&pot)
-				.checked_sub(&T::Currency::minimum_balance())
-				.unwrap_or_default()
-				.div(2u32.into());
+			let mut reward = Self::available_pot_balance().div(2u32.into());
+			let remaining_payout = Self::remaining_session_payout();
+			if let Some(remaining) = remaining_payout {
+				reward = reward.min(remaining);
+			}
 
 			if reward >= T::MinRewardDistributeAmount::get() {
-				// `reward` is half of pot account minus ED, this should never fail.
-				let _success = T::Currency::transfer(&pot, &author, reward, KeepAlive);
-				debug_assert!(_success.is_ok());
+				// `reward` is at most half of pot account minus ED, this should never fail.
+				let success = T::Currency::transfer(&pot, &author, reward, KeepAlive);
+				debug_assert!(success.is_ok());
+
+				if remaining_payout.is_some() && success.is_ok() {
+					<RewardSmoothing<T>>::mutate(|maybe_state| {
+						if let Some(state) = maybe_state {
+							state.distributed = state.distributed.saturating_add(reward);
+						}
+					});
+				}
+
+				if success.is_ok() {
+					let bonded = Self::bond_reward(&author, reward);
+					Self::deposit_event(Event::CollatorRewarded {
+						who: author.clone(),
+						reward,
+						destination: if bonded.is_zero() {
+							RewardDestination::FreeBalance
+						} else {
+							RewardDestination::Bond
+						},
+						bonded,
+					});
+				}
 			}
 
 			if <SessionPoints<T>>::contains_key(&author) {
@@ -523,6 +772,8 @@ pub mod pallet {
 		}
 
 		fn end_session(index: SessionIndex) {
+			Self::settle_session_rewards(index);
+
 			let mut removed_len = 0;
 			let session_points = <SessionPoints<T>>::drain().collect::<Vec<_>>();
 			let candidates_len: u32 = session_points.len() as u32;
@@ -569,3 +820,20 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Introduce the reward destination of the collators. Every collator keeps being paid to the free
+/// balance, which is the default of `RewardDestinations`, so only the storage version is bumped.
+pub struct DefaultRewardDestinationToFreeBalance<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> frame_support::traits::OnRuntimeUpgrade for DefaultRewardDestinationToFreeBalance<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		use frame_support::traits::{Get, StorageVersion};
+
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
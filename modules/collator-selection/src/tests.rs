@@ -17,14 +17,17 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate as collator_selection;
-use crate::{mock::*, Error, NonCandidates, RESERVE_ID};
+use crate::{
+	mock::*, DefaultRewardDestinationToFreeBalance, Error, Event, NonCandidates, RewardDestination, RewardDestinations,
+	RewardShortfall, RewardSmoothing, RewardSmoothingState, RESERVE_ID,
+};
 use frame_support::{
 	assert_noop, assert_ok,
 	storage::bounded_btree_set::BoundedBTreeSet,
-	traits::{ConstU32, Currency, NamedReservableCurrency, OnInitialize},
+	traits::{ConstU32, Currency, NamedReservableCurrency, OnInitialize, OnRuntimeUpgrade, StorageVersion},
 };
 use pallet_balances::Error as BalancesError;
-use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin, BuildStorage};
+use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin, BuildStorage, Permill};
 
 type Collators = BoundedBTreeSet<u64, ConstU32<4>>;
 
@@ -555,6 +558,347 @@ fn kick_mechanism() {
 	});
 }
 
+#[test]
+fn set_smoothing_factor_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(CollatorSelection::smoothing_factor(), Permill::from_percent(20));
+
+		assert_noop!(
+			CollatorSelection::set_smoothing_factor(RuntimeOrigin::signed(1), Permill::from_percent(50)),
+			BadOrigin
+		);
+		assert_noop!(
+			CollatorSelection::set_smoothing_factor(RuntimeOrigin::signed(RootAccount::get()), Permill::zero()),
+			Error::<Test>::InvalidSmoothingFactor
+		);
+
+		assert_ok!(CollatorSelection::set_smoothing_factor(
+			RuntimeOrigin::signed(RootAccount::get()),
+			Permill::from_percent(50)
+		));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(Event::NewSmoothingFactor {
+			new_smoothing_factor: Permill::from_percent(50),
+		}));
+		assert_eq!(CollatorSelection::smoothing_factor(), Permill::from_percent(50));
+	});
+}
+
+#[test]
+fn set_reward_destination_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(
+			CollatorSelection::reward_destinations(4),
+			RewardDestination::FreeBalance
+		);
+
+		assert_noop!(
+			CollatorSelection::set_reward_destination(RuntimeOrigin::none(), RewardDestination::Bond),
+			BadOrigin
+		);
+
+		assert_ok!(CollatorSelection::set_reward_destination(
+			RuntimeOrigin::signed(4),
+			RewardDestination::Bond
+		));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(Event::RewardDestinationSet {
+			who: 4,
+			destination: RewardDestination::Bond,
+		}));
+		assert_eq!(CollatorSelection::reward_destinations(4), RewardDestination::Bond);
+		assert!(RewardDestinations::<Test>::contains_key(4));
+
+		// the default destination is not stored
+		assert_ok!(CollatorSelection::set_reward_destination(
+			RuntimeOrigin::signed(4),
+			RewardDestination::FreeBalance
+		));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(Event::RewardDestinationSet {
+			who: 4,
+			destination: RewardDestination::FreeBalance,
+		}));
+		assert_eq!(
+			CollatorSelection::reward_destinations(4),
+			RewardDestination::FreeBalance
+		);
+		assert!(!RewardDestinations::<Test>::contains_key(4));
+	});
+}
+
+#[test]
+fn reward_destination_bond_tops_up_candidacy_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Session::set_keys(
+			RuntimeOrigin::signed(4),
+			MockSessionKeys {
+				aura: UintAuthorityId(4)
+			},
+			vec![]
+		));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::set_reward_destination(
+			RuntimeOrigin::signed(4),
+			RewardDestination::Bond
+		));
+		assert_eq!(Balances::free_balance(4), 90);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 10);
+
+		// bond already meets the candidacy bond, the whole reward goes to the free balance.
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 45);
+		Authorship::on_initialize(1);
+		System::assert_last_event(RuntimeEvent::CollatorSelection(Event::CollatorRewarded {
+			who: 4,
+			reward: 20,
+			destination: RewardDestination::FreeBalance,
+			bonded: 0,
+		}));
+		assert_eq!(Balances::free_balance(4), 110);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 10);
+
+		// the candidacy bond is raised, the reward tops up the bond and the surplus is free.
+		assert_ok!(CollatorSelection::set_candidacy_bond(
+			RuntimeOrigin::signed(RootAccount::get()),
+			25
+		));
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 45);
+		Authorship::on_initialize(1);
+		System::assert_last_event(RuntimeEvent::CollatorSelection(Event::CollatorRewarded {
+			who: 4,
+			reward: 20,
+			destination: RewardDestination::Bond,
+			bonded: 15,
+		}));
+		assert_eq!(Balances::free_balance(4), 115);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 25);
+
+		// switching back mid-session pays the next reward to the free balance.
+		assert_ok!(CollatorSelection::set_reward_destination(
+			RuntimeOrigin::signed(4),
+			RewardDestination::FreeBalance
+		));
+		assert_ok!(CollatorSelection::set_candidacy_bond(
+			RuntimeOrigin::signed(RootAccount::get()),
+			50
+		));
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 45);
+		Authorship::on_initialize(1);
+		System::assert_last_event(RuntimeEvent::CollatorSelection(Event::CollatorRewarded {
+			who: 4,
+			reward: 20,
+			destination: RewardDestination::FreeBalance,
+			bonded: 0,
+		}));
+		assert_eq!(Balances::free_balance(4), 135);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 25);
+	});
+}
+
+#[test]
+fn auto_bonded_reward_is_returned_by_withdraw_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for who in 3..=5 {
+			assert_ok!(Session::set_keys(
+				RuntimeOrigin::signed(who),
+				MockSessionKeys {
+					aura: UintAuthorityId(who)
+				},
+				vec![]
+			));
+		}
+		assert_ok!(CollatorSelection::set_desired_candidates(
+			RuntimeOrigin::signed(RootAccount::get()),
+			4
+		));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(5)));
+		assert_ok!(CollatorSelection::set_reward_destination(
+			RuntimeOrigin::signed(4),
+			RewardDestination::Bond
+		));
+		assert_ok!(CollatorSelection::set_candidacy_bond(
+			RuntimeOrigin::signed(RootAccount::get()),
+			25
+		));
+
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 45);
+		Authorship::on_initialize(1);
+		assert_eq!(Balances::free_balance(4), 95);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 25);
+
+		assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(4)));
+
+		// no longer a candidate, the reward is not bonded.
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 45);
+		Authorship::on_initialize(1);
+		System::assert_last_event(RuntimeEvent::CollatorSelection(Event::CollatorRewarded {
+			who: 4,
+			reward: 20,
+			destination: RewardDestination::FreeBalance,
+			bonded: 0,
+		}));
+		assert_eq!(Balances::free_balance(4), 115);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 25);
+
+		assert_noop!(
+			CollatorSelection::withdraw_bond(RuntimeOrigin::signed(4)),
+			Error::<Test>::StillLocked
+		);
+
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 0);
+		initialize_to_block(2 * PERIOD);
+		let free = Balances::free_balance(4);
+		// the auto-bonded reward is returned together with the bond.
+		assert_ok!(CollatorSelection::withdraw_bond(RuntimeOrigin::signed(4)));
+		assert_eq!(Balances::free_balance(4), free + 25);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 0);
+	});
+}
+
+#[test]
+fn default_reward_destination_migration_works() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<CollatorSelection>();
+		assert_ok!(CollatorSelection::set_reward_destination(
+			RuntimeOrigin::signed(4),
+			RewardDestination::Bond
+		));
+
+		DefaultRewardDestinationToFreeBalance::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<CollatorSelection>(), 1);
+		assert_eq!(CollatorSelection::reward_destinations(4), RewardDestination::Bond);
+		assert_eq!(
+			CollatorSelection::reward_destinations(3),
+			RewardDestination::FreeBalance
+		);
+	});
+}
+
+fn settled_session_rewards() -> Vec<(u32, u64, u64, u64, u64, u64)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::CollatorSelection(Event::SessionRewardSettled {
+				session_index,
+				inflow,
+				target,
+				actual,
+				shortfall,
+				next_target,
+			}) => Some((session_index, inflow, target, actual, shortfall, next_target)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn rewards_are_smoothed_over_feast_famine_sessions() {
+	new_test_ext().execute_with(|| {
+		let pot = CollatorSelection::account_id();
+		// 4 is the default author.
+		let initial_funds = Balances::free_balance(4) + Balances::free_balance(&pot);
+
+		// the first session end initializes the smoothing with an empty pot
+		initialize_to_block(10);
+		assert_eq!(
+			CollatorSelection::reward_smoothing(),
+			Some(RewardSmoothingState {
+				ema_inflow: 0,
+				target: 0,
+				pot_balance: 0,
+				distributed: 0,
+			})
+		);
+
+		// three sessions of feast-famine inflows
+		let inflows: Vec<u64> = vec![1000, 0, 1000, 0, 1000, 0];
+		for (i, inflow) in inflows.iter().enumerate() {
+			if *inflow > 0 {
+				Balances::deposit_creating(&pot, *inflow);
+			}
+			initialize_to_block(10 * (i as u64 + 2));
+		}
+
+		// (session_index, inflow, target, actual, shortfall, next_target)
+		assert_eq!(
+			settled_session_rewards(),
+			vec![
+				(1, 1000, 0, 0, 0, 200),
+				(2, 0, 200, 200, 0, 160),
+				(3, 1000, 160, 160, 0, 328),
+				(4, 0, 328, 328, 0, 262),
+				(5, 1000, 262, 262, 0, 409),
+				(6, 0, 409, 409, 0, 327),
+			]
+		);
+
+		// payouts are smoother than inflows
+		let payouts = settled_session_rewards()
+			.into_iter()
+			.skip(1)
+			.map(|(_, _, _, actual, _, _)| actual)
+			.collect::<Vec<_>>();
+		let spread = |amounts: &[u64]| amounts.iter().max().unwrap() - amounts.iter().min().unwrap();
+		assert_eq!(spread(&payouts), 249);
+		assert_eq!(spread(&inflows), 1000);
+		assert!(spread(&payouts) < spread(&inflows));
+
+		// the excess is retained in the pot and total funds are conserved
+		assert_eq!(Balances::free_balance(4), 100 + 1686);
+		assert_eq!(Balances::free_balance(&pot), 1314);
+		assert_eq!(
+			Balances::free_balance(4) + Balances::free_balance(&pot),
+			initial_funds + inflows.iter().sum::<u64>()
+		);
+	});
+}
+
+#[test]
+fn reward_shortfall_is_paid_back() {
+	new_test_ext().execute_with(|| {
+		let pot = CollatorSelection::account_id();
+		initialize_to_block(10);
+
+		// the pot cannot cover the target of the session
+		Balances::make_free_balance_be(&pot, 45);
+		RewardSmoothing::<Test>::put(RewardSmoothingState {
+			ema_inflow: 100,
+			target: 100,
+			pot_balance: 45,
+			distributed: 0,
+		});
+		assert_eq!(CollatorSelection::expected_session_reward(), 40);
+
+		// rewards are 20 and 10, the next reward of 5 is below the min of 10
+		initialize_to_block(20);
+		assert_eq!(Balances::free_balance(4), 130);
+		assert_eq!(CollatorSelection::reward_shortfall(), 70);
+		assert_eq!(settled_session_rewards(), vec![(1, 0, 100, 30, 70, 150)]);
+		assert_eq!(CollatorSelection::expected_session_reward(), 10);
+
+		// inflows recover, the shortfall is paid back on top of the target
+		Balances::deposit_creating(&pot, 1000);
+		assert_eq!(CollatorSelection::expected_session_reward(), 150);
+		initialize_to_block(29);
+		assert_eq!(Balances::free_balance(4), 280);
+		initialize_to_block(30);
+		assert_eq!(RewardShortfall::<Test>::get(), 0);
+		assert_eq!(
+			settled_session_rewards(),
+			vec![(1, 0, 100, 30, 70, 150), (2, 1000, 150, 150, 0, 264)]
+		);
+
+		// rewards are expected to be shared by the collators 1 and 2
+		assert_eq!(CollatorSelection::expected_session_reward(), 264);
+		assert_eq!(CollatorSelection::expected_collator_reward(&1), 132);
+		assert_eq!(CollatorSelection::expected_collator_reward(&4), 0);
+	});
+}
+
 #[test]
 fn exceeding_max_invulnerables_should_fail() {
 	new_test_ext().execute_with(|| {
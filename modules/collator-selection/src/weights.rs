@@ -58,6 +58,8 @@ pub trait WeightInfo {
 	fn new_session() -> Weight;
 	fn start_session(r: u32, c: u32, ) -> Weight;
 	fn end_session(r: u32, c: u32, ) -> Weight;
+	fn set_smoothing_factor() -> Weight;
+	fn set_reward_destination() -> Weight;
 }
 
 /// Weights for module_collator_selection using the Acala node and recommended hardware.
@@ -130,6 +132,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(199 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
 	}
+	fn set_smoothing_factor() -> Weight {
+		Weight::from_parts(16_810_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_reward_destination() -> Weight {
+		Weight::from_parts(17_925_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -201,4 +211,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(199 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
 	}
+	fn set_smoothing_factor() -> Weight {
+		Weight::from_parts(16_810_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_reward_destination() -> Weight {
+		Weight::from_parts(17_925_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
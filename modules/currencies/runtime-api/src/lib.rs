@@ -19,7 +19,29 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
 
-use sp_runtime::codec::Codec;
+use parity_scale_codec::{Decode, Encode};
+use primitives::{CurrencyId, ReserveIdentifier};
+use scale_info::TypeInfo;
+use sp_runtime::{codec::Codec, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// A reserved amount of an account and the feature it is reserved by.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReserveEntry<Balance> {
+	/// The identifier of the named reserve, `None` for the anonymous reserves.
+	pub identifier: Option<ReserveIdentifier>,
+	pub currency_id: CurrencyId,
+	pub amount: Balance,
+	/// Human-readable explanation of the reserve, e.g. `honzon authorization: DOT to 0x..`.
+	pub tag: Vec<u8>,
+}
+
+/// A configured deposit of a feature, e.g. `proxy_deposit_base`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DepositConstant<Balance> {
+	pub name: Vec<u8>,
+	pub amount: Balance,
+}
 
 sp_api::decl_runtime_apis! {
 	#[api_version(2)]
@@ -30,4 +52,15 @@ sp_api::decl_runtime_apis! {
 	{
 		fn query_free_balance(currency_id: CurrencyId, who: AccountId) -> Balance;
 	}
+
+	pub trait ReservesApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The reserved balances of `who`, explained by the features reserving them.
+		fn get_reserve_breakdown(who: AccountId) -> Vec<ReserveEntry<Balance>>;
+
+		/// The currently configured deposits of the features reserving balances.
+		fn get_deposit_constants() -> Vec<DepositConstant<Balance>>;
+	}
 }
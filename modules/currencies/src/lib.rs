@@ -35,7 +35,9 @@ use frame_support::{
 	transactional,
 };
 use frame_system::pallet_prelude::*;
-use module_support::{evm::limits::erc20, AddressMapping, EVMBridge, InvokeContext};
+use module_support::{
+	evm::limits::erc20, AccountCurrencies, AddressMapping, EVMBridge, InvokeContext, Swap, SwapLimit,
+};
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	currency::{OnDust, TransferAll},
@@ -62,6 +64,46 @@ type AmountOf<T> =
 	<<T as Config>::MultiCurrency as MultiCurrencyExtended<<T as frame_system::Config>::AccountId>>::Amount;
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// How `transfer_all_currencies` handles the balance below the existential deposit of the
+/// destination.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DustHandling {
+	/// Keep the dust in the source account.
+	Skip,
+	/// Swap the dust to native currency, which is transferred with the native currency.
+	SwapToNative,
+}
+
+/// Where the dust of a currency goes when an account is reaped, unless the account has
+/// designated a dust consolidation beneficiary.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub enum DustMode<AccountId> {
+	/// Transfer the dust to the treasury account.
+	#[default]
+	TransferToTreasury,
+	/// Burn the dust, which reduces the total issuance.
+	Burn,
+	/// Credit the dust to the account of the provider, e.g. the bridge operator of a bridged
+	/// asset.
+	CreditToProvider(AccountId),
+}
+
+/// The outcome of a currency in `transfer_all_currencies`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum TransferAllOutcome<Balance> {
+	/// Transferred the whole transferable balance.
+	Transferred(Balance),
+	/// The balance is below the existential deposit of the destination and kept in the source.
+	DustSkipped(Balance),
+	/// The dust is swapped to native currency.
+	DustSwapped {
+		supply_amount: Balance,
+		target_amount: Balance,
+	},
+	/// The transfer failed and the balance is kept in the source.
+	Failed(Balance),
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -107,11 +149,21 @@ pub mod module {
 		/// Convert gas to weight.
 		type GasToWeight: Convert<u64, Weight>;
 
-		/// The AccountId that can perform a sweep dust.
+		/// The origin that can sweep dust and set the dust mode of currencies.
 		type SweepOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// Handler to burn or transfer account's dust
 		type OnDust: OnDust<Self::AccountId, CurrencyId, BalanceOf<Self>>;
+
+		/// Enumerates the non-native currencies held by an account for `transfer_all_currencies`.
+		type AccountCurrencies: AccountCurrencies<Self::AccountId>;
+
+		/// Swap the dust to native currency for `transfer_all_currencies`.
+		type Swap: Swap<Self::AccountId, BalanceOf<Self>, CurrencyId>;
+
+		/// The maximum number of non-native currencies processed by one `transfer_all_currencies`.
+		#[pallet::constant]
+		type MaxTransferAllCurrencies: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -128,6 +180,10 @@ pub mod module {
 		RealOriginNotFound,
 		/// Deposit result is not expected
 		DepositFailed,
+		/// The destination is the source account
+		TransferToSelf,
+		/// The dust consolidation beneficiary is the account itself
+		DustConsolidationToSelf,
 	}
 
 	#[pallet::event]
@@ -158,9 +214,61 @@ pub mod module {
 			who: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// A currency is processed by `transfer_all_currencies`.
+		CurrencyTransferredAll {
+			currency_id: CurrencyId,
+			from: T::AccountId,
+			to: T::AccountId,
+			outcome: TransferAllOutcome<BalanceOf<T>>,
+		},
+		/// A page of `transfer_all_currencies` is done, the native currency is transferred once
+		/// finished.
+		TransferAllCurrenciesProgressed {
+			from: T::AccountId,
+			to: T::AccountId,
+			finished: bool,
+		},
+		/// The dust mode of a currency is updated.
+		CurrencyDustModeUpdated {
+			currency_id: CurrencyId,
+			mode: DustMode<T::AccountId>,
+		},
+		/// The dust consolidation beneficiary of an account is updated.
+		DustConsolidationUpdated {
+			who: T::AccountId,
+			beneficiary: Option<T::AccountId>,
+		},
 	}
 
+	/// The progress of `transfer_all_currencies` of the source account: the destination and the
+	/// last processed currency.
+	///
+	/// TransferAllCursors: map AccountId => Option<(AccountId, CurrencyId)>
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_all_cursors)]
+	pub type TransferAllCursors<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (T::AccountId, CurrencyId), OptionQuery>;
+
+	/// The dust mode of currencies set by governance, `TransferToTreasury` if not set.
+	///
+	/// CurrencyDustModes: map CurrencyId => Option<DustMode<AccountId>>
+	#[pallet::storage]
+	#[pallet::getter(fn currency_dust_modes)]
+	pub type CurrencyDustModes<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, DustMode<T::AccountId>, OptionQuery>;
+
+	/// The account designated by an account to receive its dust of all currencies, which
+	/// overrides the dust mode of the currencies.
+	///
+	/// DustConsolidations: map AccountId => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn dust_consolidations)]
+	pub type DustConsolidations<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -289,6 +397,73 @@ pub mod module {
 			let who = T::Lookup::lookup(who)?;
 			<Self as MultiLockableCurrency<T::AccountId>>::remove_lock(lock_id, currency_id, &who)
 		}
+
+		/// Transfer all the transferable non-native currencies and then the native currency to
+		/// another account.
+		///
+		/// At most `MaxTransferAllCurrencies` non-native currencies are processed per call, the
+		/// next call continues after the last processed one. The native currency is kept to pay
+		/// the fee of the next calls, and is transferred when all the non-native currencies are
+		/// processed. The balance below the existential deposit of a currency the destination
+		/// doesn't hold is handled by `dust_handling`. ERC20 balances are held by the contracts and
+		/// are not transferred.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// transactor.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::transfer_all_currencies(T::MaxTransferAllCurrencies::get()))]
+		pub fn transfer_all_currencies(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			dust_handling: DustHandling,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			ensure!(from != to, Error::<T>::TransferToSelf);
+			Self::do_transfer_all_currencies(&from, &to, dust_handling);
+			Ok(())
+		}
+
+		/// Set the dust mode of `currency_id`, `None` resets it to `TransferToTreasury`.
+		///
+		/// The dispatch origin of this call must be `SweepOrigin`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_currency_dust_mode())]
+		pub fn set_currency_dust_mode(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			mode: Option<DustMode<T::AccountId>>,
+		) -> DispatchResult {
+			T::SweepOrigin::ensure_origin(origin)?;
+			if let CurrencyId::Erc20(_) = currency_id {
+				return Err(Error::<T>::Erc20InvalidOperation.into());
+			}
+			CurrencyDustModes::<T>::set(currency_id, mode.clone());
+			Self::deposit_event(Event::<T>::CurrencyDustModeUpdated {
+				currency_id,
+				mode: mode.unwrap_or_default(),
+			});
+			Ok(())
+		}
+
+		/// Designate `beneficiary` to receive the dust of the caller instead of the dust mode of
+		/// the currencies, `None` clears it.
+		///
+		/// The dispatch origin for this call must be `Signed` by the
+		/// transactor.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_dust_consolidation())]
+		pub fn set_dust_consolidation(
+			origin: OriginFor<T>,
+			beneficiary: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let beneficiary = beneficiary.map(T::Lookup::lookup).transpose()?;
+			ensure!(beneficiary.as_ref() != Some(&who), Error::<T>::DustConsolidationToSelf);
+			DustConsolidations::<T>::set(&who, beneficiary.clone());
+			Self::deposit_event(Event::<T>::DustConsolidationUpdated { who, beneficiary });
+			Ok(())
+		}
 	}
 }
 
@@ -297,6 +472,107 @@ impl<T: Config> Pallet<T> {
 		let origin = T::EVMBridge::get_real_or_xcm_origin().ok_or(Error::<T>::RealOriginNotFound)?;
 		Ok(T::AddressMapping::get_or_create_evm_address(&origin))
 	}
+
+	fn do_transfer_all_currencies(from: &T::AccountId, to: &T::AccountId, dust_handling: DustHandling) {
+		let native_currency_id = T::GetNativeCurrencyId::get();
+		// restart if the destination is changed
+		let start_after = Self::transfer_all_cursors(from)
+			.and_then(|(cursor_dest, currency_id)| (cursor_dest == *to).then_some(currency_id));
+		let max_currencies = T::MaxTransferAllCurrencies::get();
+
+		// query one more currency to know whether there are remaining ones
+		let mut currencies =
+			T::AccountCurrencies::account_currencies(from, start_after, max_currencies.saturating_add(1));
+		let finished = currencies.len() <= max_currencies as usize;
+		currencies.truncate(max_currencies as usize);
+
+		for currency_id in currencies.iter() {
+			if *currency_id == native_currency_id || currency_id.is_erc20_currency_id() {
+				continue;
+			}
+			let amount = <T::MultiCurrency as fungibles::Inspect<_>>::reducible_balance(
+				*currency_id,
+				from,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			);
+			Self::transfer_all_currency(*currency_id, from, to, amount, dust_handling);
+		}
+
+		match currencies.last() {
+			Some(last) if !finished => {
+				TransferAllCursors::<T>::insert(from, (to.clone(), *last));
+			}
+			_ => {
+				TransferAllCursors::<T>::remove(from);
+				// includes the native currency swapped from dust
+				let amount = <T::NativeCurrency as fungible::Inspect<_>>::reducible_balance(
+					from,
+					Preservation::Expendable,
+					Fortitude::Polite,
+				);
+				Self::transfer_all_currency(native_currency_id, from, to, amount, DustHandling::Skip);
+			}
+		}
+
+		Self::deposit_event(Event::<T>::TransferAllCurrenciesProgressed {
+			from: from.clone(),
+			to: to.clone(),
+			finished,
+		});
+	}
+
+	fn transfer_all_currency(
+		currency_id: CurrencyId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+		dust_handling: DustHandling,
+	) {
+		if amount.is_zero() {
+			return;
+		}
+
+		let is_dust = amount < <Self as MultiCurrency<_>>::minimum_balance(currency_id)
+			&& <Self as MultiCurrency<_>>::total_balance(currency_id, to).is_zero();
+		let outcome = if !is_dust {
+			let transferred = frame_support::storage::with_storage_layer(|| {
+				<Self as MultiCurrency<_>>::transfer(currency_id, from, to, amount, ExistenceRequirement::AllowDeath)
+			});
+			match transferred {
+				Ok(_) => TransferAllOutcome::Transferred(amount),
+				Err(_) => TransferAllOutcome::Failed(amount),
+			}
+		} else {
+			match dust_handling {
+				DustHandling::SwapToNative => {
+					let swapped = frame_support::storage::with_storage_layer(|| {
+						T::Swap::swap(
+							from,
+							currency_id,
+							T::GetNativeCurrencyId::get(),
+							SwapLimit::ExactSupply(amount, Zero::zero()),
+						)
+					});
+					match swapped {
+						Ok((supply_amount, target_amount)) => TransferAllOutcome::DustSwapped {
+							supply_amount,
+							target_amount,
+						},
+						Err(_) => TransferAllOutcome::DustSkipped(amount),
+					}
+				}
+				DustHandling::Skip => TransferAllOutcome::DustSkipped(amount),
+			}
+		};
+
+		Self::deposit_event(Event::<T>::CurrencyTransferredAll {
+			currency_id,
+			from: from.clone(),
+			to: to.clone(),
+			outcome,
+		});
+	}
 }
 
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
@@ -1852,6 +2128,42 @@ impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
 	}
 }
 
+/// Handle the dust by the dust consolidation beneficiary of the account if any, or else by the
+/// dust mode of the currency. The dust of ERC20 is kept.
+pub struct HandleDust<T, GetTreasuryAccount>(marker::PhantomData<(T, GetTreasuryAccount)>);
+impl<T: Config, GetTreasuryAccount> OnDust<T::AccountId, CurrencyId, BalanceOf<T>> for HandleDust<T, GetTreasuryAccount>
+where
+	T: Config,
+	GetTreasuryAccount: Get<T::AccountId>,
+{
+	fn on_dust(who: &T::AccountId, currency_id: CurrencyId, amount: BalanceOf<T>) {
+		if currency_id.is_erc20_currency_id() {
+			return;
+		}
+		let dest = Pallet::<T>::dust_consolidations(who).or_else(|| {
+			match Pallet::<T>::currency_dust_modes(currency_id).unwrap_or_default() {
+				DustMode::TransferToTreasury => Some(GetTreasuryAccount::get()),
+				DustMode::Burn => None,
+				DustMode::CreditToProvider(provider) => Some(provider),
+			}
+		});
+
+		// ignore the result, if failed will leave some dust which still could be recycled.
+		let _ = match dest {
+			Some(dest) => <Pallet<T> as MultiCurrency<_>>::transfer(
+				currency_id,
+				who,
+				&dest,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			),
+			None => {
+				<Pallet<T> as MultiCurrency<_>>::withdraw(currency_id, who, amount, ExistenceRequirement::AllowDeath)
+			}
+		};
+	}
+}
+
 fn reserve_address(address: EvmAddress) -> EvmAddress {
 	let payload = (b"erc20:", address);
 	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
@@ -31,7 +31,7 @@ use frame_support::{
 use frame_system::EnsureSignedBy;
 use module_support::{
 	mocks::{MockAddressMapping, TestRandomness},
-	AddressMapping,
+	AddressMapping, AggregatedSwapPath,
 };
 use orml_traits::{currency::MutationHooks, parameter_type_with_key};
 use primitives::{evm::convert_decimals_to_evm, CurrencyId, ReserveIdentifier, TokenSymbol};
@@ -73,11 +73,8 @@ parameter_types! {
 }
 
 pub struct CurrencyHooks<T>(marker::PhantomData<T>);
-impl<T: orml_tokens::Config> MutationHooks<T::AccountId, T::CurrencyId, T::Balance> for CurrencyHooks<T>
-where
-	T::AccountId: From<AccountId>,
-{
-	type OnDust = orml_tokens::TransferDust<T, DustAccount>;
+impl MutationHooks<AccountId, CurrencyId, Balance> for CurrencyHooks<Runtime> {
+	type OnDust = crate::HandleDust<Runtime, DustAccount>;
 	type OnSlash = ();
 	type PreDeposit = ();
 	type PostDeposit = ();
@@ -189,6 +186,9 @@ impl module_evm::Config for Runtime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ();
 	type IdleScheduler = ();
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
@@ -200,6 +200,60 @@ parameter_types! {
 	pub Erc20HoldingAccount: H160 = primitives::evm::ERC20_HOLDING_ACCOUNT;
 }
 
+pub struct MockAccountCurrencies;
+impl AccountCurrencies<AccountId> for MockAccountCurrencies {
+	fn account_currencies(who: &AccountId, start_after: Option<CurrencyId>, limit: u32) -> Vec<CurrencyId> {
+		let accounts = match start_after {
+			Some(currency_id) => orml_tokens::Accounts::<Runtime>::iter_prefix_from(
+				who,
+				orml_tokens::Accounts::<Runtime>::hashed_key_for(who, currency_id),
+			),
+			None => orml_tokens::Accounts::<Runtime>::iter_prefix(who),
+		};
+		accounts
+			.map(|(currency_id, _)| currency_id)
+			.take(limit as usize)
+			.collect()
+	}
+}
+
+/// Swaps at the fixed price 1:2.
+pub struct MockSwap;
+impl Swap<AccountId, Balance, CurrencyId> for MockSwap {
+	fn get_swap_amount(_: CurrencyId, _: CurrencyId, _: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+		unimplemented!()
+	}
+
+	fn swap(
+		who: &AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> result::Result<(Balance, Balance), DispatchError> {
+		let SwapLimit::ExactSupply(supply_amount, min_target_amount) = limit else {
+			unimplemented!()
+		};
+		let target_amount = supply_amount * 2;
+		ensure!(target_amount >= min_target_amount, DispatchError::Other("Cannot swap"));
+		<Tokens as MultiCurrency<_>>::withdraw(
+			supply_currency_id,
+			who,
+			supply_amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		<Currencies as MultiCurrency<_>>::deposit(target_currency_id, who, target_amount)?;
+		Ok((supply_amount, target_amount))
+	}
+
+	fn swap_by_aggregated_path(
+		_: &AccountId,
+		_: &[AggregatedSwapPath<CurrencyId>],
+		_: SwapLimit<Balance>,
+	) -> result::Result<(Balance, Balance), DispatchError> {
+		unimplemented!()
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Tokens;
@@ -211,7 +265,10 @@ impl Config for Runtime {
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
-	type OnDust = crate::TransferDust<Runtime, DustAccount>;
+	type OnDust = crate::HandleDust<Runtime, DustAccount>;
+	type AccountCurrencies = MockAccountCurrencies;
+	type Swap = MockSwap;
+	type MaxTransferAllCurrencies = ConstU32<2>;
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
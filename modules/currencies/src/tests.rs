@@ -31,6 +31,7 @@ use mock::{
 };
 use module_support::mocks::MockAddressMapping;
 use module_support::EVM as EVMTrait;
+use primitives::TokenSymbol;
 use sp_core::H160;
 use sp_runtime::{
 	traits::{BadOrigin, Bounded},
@@ -3061,3 +3062,384 @@ fn transfer_erc20_will_charge_gas() {
 		);
 	});
 }
+
+#[test]
+fn transfer_all_currencies_skips_dust_of_absent_currencies() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 100),
+			(alice(), X_TOKEN_ID, 100),
+			(alice(), DOT, 5),
+			(bob(), DOT, 10),
+		])
+		.build()
+		.execute_with(|| {
+			// only 1 DOT is transferable, below the ED of DOT
+			assert_ok!(<Tokens as MultiLockableCurrency<_>>::set_lock(ID_1, DOT, &alice(), 4));
+
+			assert_noop!(
+				Currencies::transfer_all_currencies(RuntimeOrigin::signed(alice()), alice(), DustHandling::Skip),
+				Error::<Runtime>::TransferToSelf
+			);
+			assert_ok!(Currencies::transfer_all_currencies(
+				RuntimeOrigin::signed(alice()),
+				eva(),
+				DustHandling::Skip
+			));
+
+			System::assert_has_event(RuntimeEvent::Currencies(crate::Event::CurrencyTransferredAll {
+				currency_id: X_TOKEN_ID,
+				from: alice(),
+				to: eva(),
+				outcome: TransferAllOutcome::Transferred(100),
+			}));
+			System::assert_has_event(RuntimeEvent::Currencies(crate::Event::CurrencyTransferredAll {
+				currency_id: DOT,
+				from: alice(),
+				to: eva(),
+				outcome: TransferAllOutcome::DustSkipped(1),
+			}));
+			System::assert_last_event(RuntimeEvent::Currencies(
+				crate::Event::TransferAllCurrenciesProgressed {
+					from: alice(),
+					to: eva(),
+					finished: true,
+				},
+			));
+
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 100);
+			assert_eq!(Currencies::free_balance(DOT, &eva()), 0);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &eva()), 100);
+			assert_eq!(Currencies::free_balance(DOT, &alice()), 5);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &alice()), 0);
+			assert_eq!(Currencies::transfer_all_cursors(alice()), None);
+
+			// the destination holds DOT now, the balance below ED can be transferred
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(bob()), eva(), DOT, 2));
+			assert_ok!(Currencies::transfer_all_currencies(
+				RuntimeOrigin::signed(alice()),
+				eva(),
+				DustHandling::Skip
+			));
+			assert_eq!(Currencies::free_balance(DOT, &eva()), 3);
+			assert_eq!(Currencies::free_balance(DOT, &alice()), 4);
+		});
+}
+
+#[test]
+fn transfer_all_currencies_swaps_dust_to_native() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 100),
+			(alice(), X_TOKEN_ID, 100),
+			(alice(), DOT, 5),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(<Tokens as MultiLockableCurrency<_>>::set_lock(ID_1, DOT, &alice(), 4));
+
+			assert_ok!(Currencies::transfer_all_currencies(
+				RuntimeOrigin::signed(alice()),
+				eva(),
+				DustHandling::SwapToNative
+			));
+
+			// the mock swap price is 1:2
+			System::assert_has_event(RuntimeEvent::Currencies(crate::Event::CurrencyTransferredAll {
+				currency_id: DOT,
+				from: alice(),
+				to: eva(),
+				outcome: TransferAllOutcome::DustSwapped {
+					supply_amount: 1,
+					target_amount: 2,
+				},
+			}));
+			System::assert_has_event(RuntimeEvent::Currencies(crate::Event::CurrencyTransferredAll {
+				currency_id: NATIVE_CURRENCY_ID,
+				from: alice(),
+				to: eva(),
+				outcome: TransferAllOutcome::Transferred(102),
+			}));
+
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &eva()), 100);
+			assert_eq!(Currencies::free_balance(DOT, &eva()), 0);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &eva()), 102);
+			assert_eq!(Currencies::free_balance(DOT, &alice()), 4);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &alice()), 0);
+		});
+}
+
+#[test]
+fn transfer_all_currencies_keeps_native_until_finished() {
+	let ldot = CurrencyId::Token(TokenSymbol::LDOT);
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 100),
+			(alice(), X_TOKEN_ID, 100),
+			(alice(), DOT, 100),
+			(alice(), ldot, 100),
+		])
+		.build()
+		.execute_with(|| {
+			let currencies = [X_TOKEN_ID, DOT, ldot];
+			let held_by = |who: &AccountId| {
+				currencies
+					.iter()
+					.filter(|currency_id| !Currencies::free_balance(**currency_id, who).is_zero())
+					.count()
+			};
+
+			// MaxTransferAllCurrencies is 2
+			assert_ok!(Currencies::transfer_all_currencies(
+				RuntimeOrigin::signed(alice()),
+				eva(),
+				DustHandling::Skip
+			));
+			System::assert_last_event(RuntimeEvent::Currencies(
+				crate::Event::TransferAllCurrenciesProgressed {
+					from: alice(),
+					to: eva(),
+					finished: false,
+				},
+			));
+			assert_eq!(held_by(&alice()), 1);
+			assert_eq!(held_by(&eva()), 2);
+			// the native currency is kept for the fee of the next call
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &alice()), 100);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &eva()), 0);
+			assert_eq!(
+				Currencies::transfer_all_cursors(alice()).map(|(dest, _)| dest),
+				Some(eva())
+			);
+
+			assert_ok!(Currencies::transfer_all_currencies(
+				RuntimeOrigin::signed(alice()),
+				eva(),
+				DustHandling::Skip
+			));
+			System::assert_last_event(RuntimeEvent::Currencies(
+				crate::Event::TransferAllCurrenciesProgressed {
+					from: alice(),
+					to: eva(),
+					finished: true,
+				},
+			));
+			assert_eq!(held_by(&alice()), 0);
+			assert_eq!(held_by(&eva()), 3);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &alice()), 0);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &eva()), 100);
+			assert_eq!(Currencies::transfer_all_cursors(alice()), None);
+		});
+}
+
+#[test]
+fn set_currency_dust_mode_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Currencies::set_currency_dust_mode(RuntimeOrigin::signed(bob()), DOT, Some(DustMode::Burn)),
+			BadOrigin
+		);
+		assert_noop!(
+			Currencies::set_currency_dust_mode(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				CurrencyId::Erc20(erc20_address()),
+				Some(DustMode::Burn)
+			),
+			Error::<Runtime>::Erc20InvalidOperation
+		);
+
+		assert_ok!(Currencies::set_currency_dust_mode(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			DOT,
+			Some(DustMode::CreditToProvider(CHARLIE))
+		));
+		System::assert_last_event(RuntimeEvent::Currencies(crate::Event::CurrencyDustModeUpdated {
+			currency_id: DOT,
+			mode: DustMode::CreditToProvider(CHARLIE),
+		}));
+		assert_eq!(
+			Currencies::currency_dust_modes(DOT),
+			Some(DustMode::CreditToProvider(CHARLIE))
+		);
+
+		assert_ok!(Currencies::set_currency_dust_mode(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			DOT,
+			None
+		));
+		System::assert_last_event(RuntimeEvent::Currencies(crate::Event::CurrencyDustModeUpdated {
+			currency_id: DOT,
+			mode: DustMode::TransferToTreasury,
+		}));
+		assert_eq!(Currencies::currency_dust_modes(DOT), None);
+	});
+}
+
+#[test]
+fn set_dust_consolidation_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Currencies::set_dust_consolidation(RuntimeOrigin::signed(bob()), Some(bob())),
+			Error::<Runtime>::DustConsolidationToSelf
+		);
+
+		assert_ok!(Currencies::set_dust_consolidation(
+			RuntimeOrigin::signed(bob()),
+			Some(DAVE)
+		));
+		System::assert_last_event(RuntimeEvent::Currencies(crate::Event::DustConsolidationUpdated {
+			who: bob(),
+			beneficiary: Some(DAVE),
+		}));
+		assert_eq!(Currencies::dust_consolidations(bob()), Some(DAVE));
+
+		assert_ok!(Currencies::set_dust_consolidation(RuntimeOrigin::signed(bob()), None));
+		System::assert_last_event(RuntimeEvent::Currencies(crate::Event::DustConsolidationUpdated {
+			who: bob(),
+			beneficiary: None,
+		}));
+		assert_eq!(Currencies::dust_consolidations(bob()), None);
+	});
+}
+
+#[test]
+fn dust_transferred_to_treasury_by_default() {
+	ExtBuilder::default()
+		.balances(vec![(bob(), DOT, 3), (DustAccount::get(), DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(bob()), eva(), DOT, 2));
+			assert_eq!(orml_tokens::Accounts::<Runtime>::contains_key(bob(), DOT), false);
+			assert_eq!(Currencies::free_balance(DOT, &eva()), 2);
+			assert_eq!(Currencies::free_balance(DOT, &DustAccount::get()), 101);
+			assert_eq!(Currencies::total_issuance(DOT), 103);
+		});
+}
+
+#[test]
+fn dust_burned_by_dust_mode() {
+	ExtBuilder::default()
+		.balances(vec![(bob(), DOT, 3), (DustAccount::get(), DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::set_currency_dust_mode(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				DOT,
+				Some(DustMode::Burn)
+			));
+
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(bob()), eva(), DOT, 2));
+			assert_eq!(orml_tokens::Accounts::<Runtime>::contains_key(bob(), DOT), false);
+			assert_eq!(Currencies::free_balance(DOT, &eva()), 2);
+			assert_eq!(Currencies::free_balance(DOT, &DustAccount::get()), 100);
+			assert_eq!(Currencies::total_issuance(DOT), 102);
+		});
+}
+
+#[test]
+fn dust_credited_to_provider_by_dust_mode() {
+	ExtBuilder::default()
+		.balances(vec![
+			(bob(), DOT, 3),
+			(CHARLIE, DOT, 100),
+			(DustAccount::get(), DOT, 100),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::set_currency_dust_mode(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				DOT,
+				Some(DustMode::CreditToProvider(CHARLIE))
+			));
+
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(bob()), eva(), DOT, 2));
+			assert_eq!(orml_tokens::Accounts::<Runtime>::contains_key(bob(), DOT), false);
+			assert_eq!(Currencies::free_balance(DOT, &CHARLIE), 101);
+			assert_eq!(Currencies::free_balance(DOT, &DustAccount::get()), 100);
+			assert_eq!(Currencies::total_issuance(DOT), 203);
+		});
+}
+
+#[test]
+fn dust_consolidation_overrides_dust_mode() {
+	ExtBuilder::default()
+		.balances(vec![
+			(bob(), DOT, 3),
+			(alice(), DOT, 3),
+			(DAVE, DOT, 100),
+			(DustAccount::get(), DOT, 100),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::set_currency_dust_mode(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				DOT,
+				Some(DustMode::Burn)
+			));
+			assert_ok!(Currencies::set_dust_consolidation(
+				RuntimeOrigin::signed(bob()),
+				Some(DAVE)
+			));
+
+			// bob's dust goes to the beneficiary
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(bob()), eva(), DOT, 2));
+			assert_eq!(orml_tokens::Accounts::<Runtime>::contains_key(bob(), DOT), false);
+			assert_eq!(Currencies::free_balance(DOT, &DAVE), 101);
+			assert_eq!(Currencies::total_issuance(DOT), 206);
+
+			// alice's dust is burned by the dust mode
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(alice()), eva(), DOT, 2));
+			assert_eq!(orml_tokens::Accounts::<Runtime>::contains_key(alice(), DOT), false);
+			assert_eq!(Currencies::free_balance(DOT, &DAVE), 101);
+			assert_eq!(Currencies::total_issuance(DOT), 205);
+
+			// sweep dust respects the dust consolidation as well
+			orml_tokens::Accounts::<Runtime>::insert(
+				bob(),
+				DOT,
+				orml_tokens::AccountData {
+					free: 1,
+					frozen: 0,
+					reserved: 0,
+				},
+			);
+			orml_tokens::TotalIssuance::<Runtime>::mutate(DOT, |total| *total += 1);
+			assert_ok!(Currencies::sweep_dust(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				DOT,
+				vec![bob()]
+			));
+			assert_eq!(orml_tokens::Accounts::<Runtime>::contains_key(bob(), DOT), false);
+			assert_eq!(Currencies::free_balance(DOT, &DAVE), 102);
+			assert_eq!(Currencies::total_issuance(DOT), 206);
+		});
+}
+
+#[test]
+fn dust_boundary_unchanged_by_dust_mode() {
+	ExtBuilder::default()
+		.balances(vec![(bob(), DOT, 4), (DAVE, DOT, 100), (DustAccount::get(), DOT, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::set_currency_dust_mode(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				DOT,
+				Some(DustMode::Burn)
+			));
+			assert_ok!(Currencies::set_dust_consolidation(
+				RuntimeOrigin::signed(bob()),
+				Some(DAVE)
+			));
+
+			// the remaining equals to ED, no dust
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(bob()), eva(), DOT, 2));
+			assert_eq!(Currencies::free_balance(DOT, &bob()), 2);
+			assert_eq!(Currencies::free_balance(DOT, &DAVE), 100);
+
+			// transfer all, no dust
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(bob()), eva(), DOT, 2));
+			assert_eq!(orml_tokens::Accounts::<Runtime>::contains_key(bob(), DOT), false);
+			assert_eq!(Currencies::free_balance(DOT, &DAVE), 100);
+			assert_eq!(Currencies::total_issuance(DOT), 204);
+		});
+}
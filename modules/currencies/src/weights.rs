@@ -55,6 +55,9 @@ pub trait WeightInfo {
 	fn sweep_dust(c: u32, ) -> Weight;
 	fn force_set_lock() -> Weight;
 	fn force_remove_lock() -> Weight;
+	fn transfer_all_currencies(c: u32, ) -> Weight;
+	fn set_currency_dust_mode() -> Weight;
+	fn set_dust_consolidation() -> Weight;
 }
 
 /// Weights for module_currencies using the Acala node and recommended hardware.
@@ -131,6 +134,10 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
 	// Storage: System Account (r:3 w:3)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Currencies DustConsolidations (r:3 w:0)
+	// Proof Skipped: Currencies DustConsolidations (max_values: None, max_size: None, mode: Measured)
+	// Storage: Currencies CurrencyDustModes (r:1 w:0)
+	// Proof Skipped: Currencies CurrencyDustModes (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `c` is `[1, 3]`.
 	fn sweep_dust(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -141,7 +148,7 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			// Standard Error: 55_030
 			.saturating_add(Weight::from_parts(37_716_994, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 5225).saturating_mul(c.into()))
@@ -176,6 +183,47 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	// Storage: Currencies TransferAllCursors (r:1 w:1)
+	// Proof Skipped: Currencies TransferAllCursors (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:9 w:8)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 8]`.
+	fn transfer_all_currencies(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2215 + c * (339 ±0)`
+		//  Estimated: `6196 + c * (2622 ±0)`
+		// Minimum execution time: 96_517 nanoseconds.
+		Weight::from_parts(71_402_116, 6196)
+			// Standard Error: 41_286
+			.saturating_add(Weight::from_parts(38_517_904, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2622).saturating_mul(c.into()))
+	}
+	// Storage: Currencies CurrencyDustModes (r:0 w:1)
+	// Proof Skipped: Currencies CurrencyDustModes (max_values: None, max_size: None, mode: Measured)
+	fn set_currency_dust_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_108 nanoseconds.
+		Weight::from_parts(12_611_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Currencies DustConsolidations (r:0 w:1)
+	// Proof Skipped: Currencies DustConsolidations (max_values: None, max_size: None, mode: Measured)
+	fn set_dust_consolidation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_874 nanoseconds.
+		Weight::from_parts(13_302_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 
 // For backwards compatibility and tests
@@ -251,6 +299,10 @@ impl WeightInfo for () {
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
 	// Storage: System Account (r:3 w:3)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Currencies DustConsolidations (r:3 w:0)
+	// Proof Skipped: Currencies DustConsolidations (max_values: None, max_size: None, mode: Measured)
+	// Storage: Currencies CurrencyDustModes (r:1 w:0)
+	// Proof Skipped: Currencies CurrencyDustModes (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `c` is `[1, 3]`.
 	fn sweep_dust(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -261,7 +313,7 @@ impl WeightInfo for () {
 			// Standard Error: 55_030
 			.saturating_add(Weight::from_parts(37_716_994, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(1))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 5225).saturating_mul(c.into()))
@@ -296,4 +348,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3))
 			.saturating_add(RocksDbWeight::get().writes(3))
 	}
+	// Storage: Currencies TransferAllCursors (r:1 w:1)
+	// Proof Skipped: Currencies TransferAllCursors (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:9 w:8)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 8]`.
+	fn transfer_all_currencies(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2215 + c * (339 ±0)`
+		//  Estimated: `6196 + c * (2622 ±0)`
+		// Minimum execution time: 96_517 nanoseconds.
+		Weight::from_parts(71_402_116, 6196)
+			// Standard Error: 41_286
+			.saturating_add(Weight::from_parts(38_517_904, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2622).saturating_mul(c.into()))
+	}
+	// Storage: Currencies CurrencyDustModes (r:0 w:1)
+	// Proof Skipped: Currencies CurrencyDustModes (max_values: None, max_size: None, mode: Measured)
+	fn set_currency_dust_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_108 nanoseconds.
+		Weight::from_parts(12_611_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Currencies DustConsolidations (r:0 w:1)
+	// Proof Skipped: Currencies DustConsolidations (max_values: None, max_size: None, mode: Measured)
+	fn set_dust_consolidation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_874 nanoseconds.
+		Weight::from_parts(13_302_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,308 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Dev Setup Module
+//!
+//! Spins up a local development network in one root call. `apply_setup` takes a SCALE-encoded
+//! `DevSetup` which declares the foreign assets to register, the oracle prices to feed, the
+//! trading pairs to enable with their initial liquidity and the risk management params of
+//! collaterals, and applies all of them atomically. Every step which has already been applied is
+//! skipped, so applying the same setup again is a no-op.
+//!
+//! This module is only meant for development runtimes and must never be included in production
+//! runtimes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::{pallet_prelude::*, RawOrigin};
+use module_dex::TradingPairStatus;
+use module_support::{DEXManager, Price, Rate, Ratio};
+use orml_traits::{Change, DataFeeder, DataProvider};
+use parity_scale_codec::DecodeAll;
+use primitives::{currency::AssetMetadata, Balance, CurrencyId, TradingPair};
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::{boxed::Box, prelude::*};
+use xcm::{v3, v4::Location, VersionedLocation};
+
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// A trading pair to enable, and the initial liquidity added by the faucet account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TradingPairSetup {
+	pub currency_id_a: CurrencyId,
+	pub currency_id_b: CurrencyId,
+	pub amount_a: Balance,
+	pub amount_b: Balance,
+}
+
+/// The risk management params of a collateral.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CollateralSetup {
+	pub currency_id: CurrencyId,
+	pub interest_rate_per_sec: Option<Rate>,
+	pub liquidation_ratio: Option<Ratio>,
+	pub liquidation_penalty: Option<Rate>,
+	pub required_collateral_ratio: Option<Ratio>,
+	pub maximum_total_debit_value: Balance,
+}
+
+/// The declarative setup of a development network.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DevSetup<AccountId> {
+	/// The account which provides the initial liquidity.
+	pub faucet: AccountId,
+	/// Foreign assets to register.
+	pub foreign_assets: Vec<(VersionedLocation, AssetMetadata<Balance>)>,
+	/// Oracle prices to feed by the root operator.
+	pub prices: Vec<(CurrencyId, Price)>,
+	/// Trading pairs to enable.
+	pub trading_pairs: Vec<TradingPairSetup>,
+	/// Collaterals to set risk management params for.
+	pub collaterals: Vec<CollateralSetup>,
+}
+
+impl<AccountId> DevSetup<AccountId> {
+	/// The number of steps of the setup.
+	pub fn steps(&self) -> u32 {
+		self.foreign_assets
+			.len()
+			.saturating_add(self.prices.len())
+			// enable the trading pair and add liquidity
+			.saturating_add(self.trading_pairs.len().saturating_mul(2))
+			.saturating_add(self.collaterals.len())
+			.unique_saturated_into()
+	}
+}
+
+pub type DevSetupOf<T> = DevSetup<<T as frame_system::Config>::AccountId>;
+
+/// A step of the setup, identified in events.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum SetupStep {
+	RegisterForeignAsset(CurrencyId),
+	FeedPrice(CurrencyId),
+	EnableTradingPair(TradingPair),
+	AddLiquidity(TradingPair),
+	SetCollateralParams(CurrencyId),
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + module_asset_registry::Config + module_cdp_engine::Config + module_dex::Config
+	{
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The oracle to feed prices by the root operator.
+		type Oracle: DataFeeder<CurrencyId, Price, Self::AccountId> + DataProvider<CurrencyId, Price>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The setup can't be decoded.
+		InvalidSetup,
+		/// The location of a foreign asset is invalid.
+		InvalidLocation,
+		/// The trading pair is invalid.
+		InvalidTradingPair,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A setup step has been applied.
+		StepApplied { step: SetupStep },
+		/// A setup step has been skipped because it had already been applied.
+		StepSkipped { step: SetupStep },
+		/// The setup has been applied.
+		SetupApplied { applied: u32, skipped: u32 },
+	}
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The SCALE-encoded `DevSetup` to apply at genesis, ignored if empty.
+		pub setup: Vec<u8>,
+		pub _phantom: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			if !self.setup.is_empty() {
+				let setup = DevSetupOf::<T>::decode_all(&mut &self.setup[..]).expect("dev setup should be valid");
+				Pallet::<T>::do_apply_setup(setup).expect("dev setup should be applied");
+			}
+		}
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Apply a SCALE-encoded `DevSetup`, skipping the steps which have already been applied.
+		///
+		/// The dispatch origin of this call must be Root.
+		///
+		/// - `blob`: the SCALE-encoded `DevSetup`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::apply_setup(
+			DevSetupOf::<T>::decode_all(&mut &blob[..]).map_or(0, |setup| setup.steps())
+		))]
+		pub fn apply_setup(origin: OriginFor<T>, blob: Vec<u8>) -> DispatchResult {
+			ensure_root(origin)?;
+			let setup = DevSetupOf::<T>::decode_all(&mut &blob[..]).map_err(|_| Error::<T>::InvalidSetup)?;
+			Self::do_apply_setup(setup)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn do_apply_setup(setup: DevSetupOf<T>) -> DispatchResult {
+		let mut applied: u32 = 0;
+		let mut skipped: u32 = 0;
+		let mut record = |step: SetupStep, is_applied: bool| {
+			if is_applied {
+				applied = applied.saturating_add(1);
+				Self::deposit_event(Event::StepApplied { step });
+			} else {
+				skipped = skipped.saturating_add(1);
+				Self::deposit_event(Event::StepSkipped { step });
+			}
+		};
+
+		for (location, metadata) in setup.foreign_assets {
+			let (currency_id, is_applied) = Self::register_foreign_asset(location, metadata)?;
+			record(SetupStep::RegisterForeignAsset(currency_id), is_applied);
+		}
+
+		for (currency_id, price) in setup.prices {
+			let is_applied = T::Oracle::get(&currency_id) != Some(price);
+			if is_applied {
+				T::Oracle::feed_value(None, currency_id, price)?;
+			}
+			record(SetupStep::FeedPrice(currency_id), is_applied);
+		}
+
+		for trading_pair_setup in setup.trading_pairs {
+			let TradingPairSetup {
+				currency_id_a,
+				currency_id_b,
+				amount_a,
+				amount_b,
+			} = trading_pair_setup;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidTradingPair)?;
+
+			let is_applied = !matches!(
+				module_dex::Pallet::<T>::trading_pair_statuses(trading_pair),
+				TradingPairStatus::Enabled
+			);
+			if is_applied {
+				module_dex::Pallet::<T>::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b)?;
+			}
+			record(SetupStep::EnableTradingPair(trading_pair), is_applied);
+
+			let is_applied = module_dex::Pallet::<T>::get_liquidity_pool(currency_id_a, currency_id_b) == (0, 0);
+			if is_applied {
+				<module_dex::Pallet<T> as DEXManager<_, _, _>>::add_liquidity(
+					&setup.faucet,
+					currency_id_a,
+					currency_id_b,
+					amount_a,
+					amount_b,
+					Default::default(),
+					false,
+				)?;
+			}
+			record(SetupStep::AddLiquidity(trading_pair), is_applied);
+		}
+
+		for collateral in setup.collaterals {
+			let is_applied = !Self::collateral_params_applied(&collateral);
+			if is_applied {
+				module_cdp_engine::Pallet::<T>::set_collateral_params(
+					RawOrigin::Root.into(),
+					collateral.currency_id,
+					Change::NewValue(collateral.interest_rate_per_sec),
+					Change::NewValue(collateral.liquidation_ratio),
+					Change::NewValue(collateral.liquidation_penalty),
+					Change::NewValue(collateral.required_collateral_ratio),
+					Change::NewValue(collateral.maximum_total_debit_value),
+				)?;
+			}
+			record(SetupStep::SetCollateralParams(collateral.currency_id), is_applied);
+		}
+
+		Self::deposit_event(Event::SetupApplied { applied, skipped });
+		Ok(())
+	}
+
+	/// Register the foreign asset if its location has not been registered, returns the currency id
+	/// of the location and whether it has been registered now.
+	fn register_foreign_asset(
+		location: VersionedLocation,
+		metadata: AssetMetadata<Balance>,
+	) -> Result<(CurrencyId, bool), DispatchError> {
+		let location: Location = location.try_into().map_err(|()| Error::<T>::InvalidLocation)?;
+		let v3_location = v3::Location::try_from(location.clone()).map_err(|()| Error::<T>::InvalidLocation)?;
+		if let Some(currency_id) = module_asset_registry::Pallet::<T>::location_to_currency_ids(&v3_location) {
+			return Ok((currency_id, false));
+		}
+
+		module_asset_registry::Pallet::<T>::register_foreign_asset(
+			RawOrigin::Root.into(),
+			Box::new(location.into()),
+			Box::new(AssetMetadata {
+				name: metadata.name,
+				symbol: metadata.symbol,
+				decimals: metadata.decimals,
+				minimal_balance: metadata.minimal_balance.unique_saturated_into(),
+			}),
+			false,
+		)?;
+		let currency_id = module_asset_registry::Pallet::<T>::location_to_currency_ids(v3_location)
+			.ok_or(Error::<T>::InvalidLocation)?;
+		Ok((currency_id, true))
+	}
+
+	fn collateral_params_applied(collateral: &CollateralSetup) -> bool {
+		module_cdp_engine::Pallet::<T>::collateral_params(collateral.currency_id).map_or(false, |params| {
+			params.interest_rate_per_sec.map(|rate| rate.into_inner()) == collateral.interest_rate_per_sec
+				&& params.liquidation_ratio == collateral.liquidation_ratio
+				&& params.liquidation_penalty.map(|rate| rate.into_inner()) == collateral.liquidation_penalty
+				&& params.required_collateral_ratio == collateral.required_collateral_ratio
+				&& params.maximum_total_debit_value == collateral.maximum_total_debit_value
+		})
+	}
+}
@@ -0,0 +1,72 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_dev_setup
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for module_dev_setup.
+pub trait WeightInfo {
+	fn apply_setup(n: u32, ) -> Weight;
+}
+
+/// Weights for module_dev_setup using the Acala node and recommended hardware.
+pub struct AcalaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::TradingPairListings` (r:1 w:1)
+	// Proof: `Dex::TradingPairListings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:3 w:3)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn apply_setup(n: u32, ) -> Weight {
+		Weight::from_parts(12_537_000, 1489)
+			.saturating_add(Weight::from_parts(61_382_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_parts(0, 2622).saturating_mul(n as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn apply_setup(n: u32, ) -> Weight {
+		Weight::from_parts(12_537_000, 1489)
+			.saturating_add(Weight::from_parts(61_382_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_parts(0, 2622).saturating_mul(n as u64))
+	}
+}
@@ -24,9 +24,11 @@
 
 use frame_support::{pallet_prelude::*, traits::Time};
 use frame_system::pallet_prelude::*;
-use module_support::{DEXManager, DEXPriceProvider, ExchangeRate};
+use module_support::{
+	DEXManager, DEXPriceProvider, ExchangeRate, OnTradingPairDisabled, OnTradingPairEnabled, TradingPairListing,
+};
 use orml_traits::Happened;
-use primitives::{Balance, CurrencyId, TradingPair};
+use primitives::{Balance, CurrencyId, Moment, TradingPair};
 use sp_core::U256;
 use sp_runtime::{
 	traits::{Saturating, Zero},
@@ -97,7 +99,10 @@ pub mod module {
 		OptionQuery,
 	>;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -177,32 +182,7 @@ pub mod module {
 
 			let trading_pair =
 				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
-			ensure!(
-				Self::average_prices(trading_pair).is_none(),
-				Error::<T>::AveragePriceAlreadyEnabled
-			);
-			ensure!(!interval.is_zero(), Error::<T>::IntervalIsZero,);
-
-			let (initial_price_0, initial_price_1) =
-				Self::get_current_price(&trading_pair).ok_or(Error::<T>::InvalidPool)?;
-			let now = T::Time::now();
-			let initial_cumulative_0 = U256::zero();
-			let initial_cumulative_1 = U256::zero();
-
-			AveragePrices::<T>::insert(
-				trading_pair,
-				(
-					initial_price_0,
-					initial_price_1,
-					initial_cumulative_0,
-					initial_cumulative_1,
-					now,
-					interval,
-				),
-			);
-			Cumulatives::<T>::insert(trading_pair, (initial_cumulative_0, initial_cumulative_1, now));
-
-			Ok(())
+			Self::do_enable_average_price(&trading_pair, interval)
 		}
 
 		/// Disable average price for trading pair.
@@ -258,6 +238,35 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_enable_average_price(trading_pair: &TradingPair, interval: MomentOf<T>) -> DispatchResult {
+		ensure!(
+			Self::average_prices(trading_pair).is_none(),
+			Error::<T>::AveragePriceAlreadyEnabled
+		);
+		ensure!(!interval.is_zero(), Error::<T>::IntervalIsZero,);
+
+		let (initial_price_0, initial_price_1) =
+			Self::get_current_price(trading_pair).ok_or(Error::<T>::InvalidPool)?;
+		let now = T::Time::now();
+		let initial_cumulative_0 = U256::zero();
+		let initial_cumulative_1 = U256::zero();
+
+		AveragePrices::<T>::insert(
+			trading_pair,
+			(
+				initial_price_0,
+				initial_price_1,
+				initial_cumulative_0,
+				initial_cumulative_1,
+				now,
+				interval,
+			),
+		);
+		Cumulatives::<T>::insert(trading_pair, (initial_cumulative_0, initial_cumulative_1, now));
+
+		Ok(())
+	}
+
 	/// For same trading pair, if now is gt last update cumulative timestamp, update it's
 	/// cumulative, otherwise do nothing. It means that in one block, the cumulative of a trading
 	/// pair may be updated only once.
@@ -313,6 +322,27 @@ impl<T: Config> Happened<(TradingPair, Balance, Balance)> for Pallet<T> {
 	}
 }
 
+impl<T: Config> OnTradingPairEnabled<TradingPair, TradingPairListing<CurrencyId, Balance, Moment>> for Pallet<T> {
+	fn on_trading_pair_enabled(
+		trading_pair: &TradingPair,
+		listing: Option<&TradingPairListing<CurrencyId, Balance, Moment>>,
+	) -> DispatchResult {
+		if let Some(interval) = listing.and_then(|listing| listing.average_price_interval) {
+			Self::do_enable_average_price(trading_pair, interval.saturated_into())?;
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config> OnTradingPairDisabled<TradingPair> for Pallet<T> {
+	fn on_trading_pair_disabled(trading_pair: &TradingPair) -> DispatchResult {
+		// the average price is meaningless after the trading pair is disabled.
+		AveragePrices::<T>::remove(trading_pair);
+		Cumulatives::<T>::remove(trading_pair);
+		Ok(())
+	}
+}
+
 /// CurrentDEXPriceProvider that always provider real-time prices from dex.
 pub struct CurrentDEXPriceProvider<T>(PhantomData<T>);
 impl<T: Config> DEXPriceProvider<CurrencyId> for CurrentDEXPriceProvider<T> {
@@ -190,6 +190,47 @@ fn update_average_price_interval_work() {
 	});
 }
 
+#[test]
+fn trading_pair_hooks_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_pool(&AUSDDOTPair::get(), 1_000, 100);
+		Timestamp::set_timestamp(100);
+
+		assert_ok!(DexOracle::on_trading_pair_enabled(&AUSDDOTPair::get(), None));
+		assert_eq!(DexOracle::average_prices(AUSDDOTPair::get()), None);
+
+		let listing = TradingPairListing {
+			average_price_interval: Some(1000),
+			incentive_rewards: vec![],
+		};
+		assert_ok!(DexOracle::on_trading_pair_enabled(&AUSDDOTPair::get(), Some(&listing)));
+		assert_eq!(
+			DexOracle::average_prices(AUSDDOTPair::get()),
+			Some((
+				ExchangeRate::saturating_from_rational(100, 1000),
+				ExchangeRate::saturating_from_rational(1000, 100),
+				U256::from(0),
+				U256::from(0),
+				100,
+				1000,
+			))
+		);
+		assert_noop!(
+			DexOracle::on_trading_pair_enabled(&AUSDDOTPair::get(), Some(&listing)),
+			Error::<Runtime>::AveragePriceAlreadyEnabled
+		);
+
+		assert_ok!(DexOracle::on_trading_pair_disabled(&AUSDDOTPair::get()));
+		assert_eq!(DexOracle::average_prices(AUSDDOTPair::get()), None);
+		assert_eq!(
+			DexOracle::cumulatives(AUSDDOTPair::get()),
+			(U256::from(0), U256::from(0), 0)
+		);
+		// cleanup of a trading pair without average price is a no-op
+		assert_ok!(DexOracle::on_trading_pair_disabled(&AUSDDOTPair::get()));
+	});
+}
+
 #[test]
 fn try_update_cumulative_work() {
 	ExtBuilder::default().build().execute_with(|| {
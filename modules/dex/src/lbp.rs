@@ -0,0 +1,221 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Liquidity bootstrapping pools and their weighted math, which refers to the design of
+//! Balancer: the spot price is `(supply_reserve / supply_weight) / (target_reserve /
+//! target_weight)`, and the weights shift linearly over the launch window.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use primitives::{Balance, CurrencyId};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{One, Zero},
+	FixedPointNumber, FixedU128, Permill, RuntimeDebug,
+};
+
+/// The minimum weight of either side of a liquidity bootstrapping pool.
+pub const MIN_LBP_WEIGHT: Permill = Permill::from_percent(1);
+
+/// The maximum share of the supply reserve that can be supplied by a swap.
+const MAX_IN_RATIO: (u128, u128) = (1, 2);
+
+/// The maximum share of the target reserve that can be taken by a swap.
+const MAX_OUT_RATIO: (u128, u128) = (1, 3);
+
+/// The maximum number of terms of the binomial series approximating the fractional power.
+const MAX_POW_ITERATIONS: u32 = 100;
+
+/// What happens to the liquidity bootstrapping pool at the end of its window.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub enum LbpEndAction {
+	/// Convert into a constant-product liquidity pool of the trading pair owned by the owner,
+	/// at the spot price of the end of the window.
+	ConvertToDex,
+	/// Return the reserves to the owner.
+	Dissolve,
+}
+
+/// The liquidity bootstrapping pool of a trading pair.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct LbpPool<AccountId, BlockNumber> {
+	/// The project launching the token, which receives the reserves at the end.
+	pub owner: AccountId,
+	/// The currency launched by the project.
+	pub project_currency: CurrencyId,
+	/// The currency used to buy the project currency.
+	pub base_currency: CurrencyId,
+	/// The reserve of the project currency.
+	pub project_reserve: Balance,
+	/// The reserve of the base currency.
+	pub base_reserve: Balance,
+	/// The weight of the project currency at the start of the window, the weight of the base
+	/// currency is the complement.
+	pub start_weight: Permill,
+	/// The weight of the project currency at the end of the window.
+	pub end_weight: Permill,
+	/// The block number at which the swaps start.
+	pub start: BlockNumber,
+	/// The block number at which the swaps stop and the pool is settled.
+	pub end: BlockNumber,
+	/// What happens to the pool at the end of the window.
+	pub end_action: LbpEndAction,
+}
+
+/// The weight of the project currency after `elapsed` of `duration`, linearly interpolated
+/// between the start and end weights.
+pub fn interpolated_weight(start_weight: Permill, end_weight: Permill, elapsed: u128, duration: u128) -> Permill {
+	if duration.is_zero() || elapsed >= duration {
+		return end_weight;
+	}
+
+	let (start, end) = (start_weight.deconstruct() as u128, end_weight.deconstruct() as u128);
+	let parts = if end >= start {
+		start + (end - start) * elapsed / duration
+	} else {
+		start - (start - end) * elapsed / duration
+	};
+	Permill::from_parts(parts as u32)
+}
+
+/// Get how much target amount will be got for the specific supply amount, charged with the
+/// exchange fee `(numerator, denominator)`.
+///
+/// `target = target_reserve * (1 - (supply_reserve / (supply_reserve + supply)) ^
+/// (supply_weight / target_weight))`
+pub fn get_target_amount(
+	supply_reserve: Balance,
+	supply_weight: Permill,
+	target_reserve: Balance,
+	target_weight: Permill,
+	supply_amount: Balance,
+	fee: (u32, u32),
+) -> Option<Balance> {
+	if supply_reserve.is_zero() || target_reserve.is_zero() || supply_weight.is_zero() || target_weight.is_zero() {
+		return None;
+	}
+	if supply_amount > supply_reserve.saturating_mul(MAX_IN_RATIO.0) / MAX_IN_RATIO.1 {
+		return None;
+	}
+
+	let (fee_numerator, fee_denominator) = fee;
+	let supply_amount_with_fee =
+		FixedU128::checked_from_rational(fee_denominator.saturating_sub(fee_numerator), fee_denominator)?
+			.saturating_mul_int(supply_amount);
+	let base = FixedU128::checked_from_rational(supply_reserve, supply_reserve.checked_add(supply_amount_with_fee)?)?;
+	let exponent = FixedU128::checked_from_rational(supply_weight.deconstruct(), target_weight.deconstruct())?;
+	let ratio = FixedU128::one().checked_sub(&pow(base, exponent)?)?;
+
+	Some(ratio.saturating_mul_int(target_reserve))
+}
+
+/// Get how much supply amount will be paid for the specific target amount, charged with the
+/// exchange fee `(numerator, denominator)`.
+///
+/// `supply = supply_reserve * ((target_reserve / (target_reserve - target)) ^
+/// (target_weight / supply_weight) - 1)`
+pub fn get_supply_amount(
+	supply_reserve: Balance,
+	supply_weight: Permill,
+	target_reserve: Balance,
+	target_weight: Permill,
+	target_amount: Balance,
+	fee: (u32, u32),
+) -> Option<Balance> {
+	if supply_reserve.is_zero() || target_reserve.is_zero() || supply_weight.is_zero() || target_weight.is_zero() {
+		return None;
+	}
+	if target_amount > target_reserve.saturating_mul(MAX_OUT_RATIO.0) / MAX_OUT_RATIO.1 {
+		return None;
+	}
+
+	let base = FixedU128::checked_from_rational(target_reserve, target_reserve.checked_sub(target_amount)?)?;
+	let exponent = FixedU128::checked_from_rational(target_weight.deconstruct(), supply_weight.deconstruct())?;
+	let ratio = pow(base, exponent)?.checked_sub(&FixedU128::one())?;
+	let supply_amount_with_fee = ratio
+		.checked_mul_int(supply_reserve)?
+		// round up to never favor the trader.
+		.checked_add(One::one())?;
+
+	let (fee_numerator, fee_denominator) = fee;
+	FixedU128::checked_from_rational(fee_denominator, fee_denominator.saturating_sub(fee_numerator))?
+		.checked_mul_int(supply_amount_with_fee)?
+		.checked_add(One::one())
+}
+
+/// `base ^ exponent`, the integer part of the exponent is computed exactly and the
+/// fractional part is approximated by the binomial series, `base` must be within `(0, 2)`.
+fn pow(base: FixedU128, exponent: FixedU128) -> Option<FixedU128> {
+	let whole = exponent.into_inner() / FixedU128::accuracy();
+	let remain = exponent.checked_sub(&FixedU128::saturating_from_integer(whole))?;
+	let whole_pow = base.saturating_pow(whole as usize);
+
+	if remain.is_zero() {
+		return Some(whole_pow);
+	}
+	whole_pow.checked_mul(&pow_approx(base, remain)?)
+}
+
+/// Approximate `base ^ exponent` for the fractional `exponent` by the binomial series
+/// `(1 + x) ^ a = 1 + a * x + a * (a - 1) * x ^ 2 / 2! + ...`.
+fn pow_approx(base: FixedU128, exponent: FixedU128) -> Option<FixedU128> {
+	let one = FixedU128::one();
+	let precision = FixedU128::from_inner(FixedU128::accuracy() / 10_000_000_000);
+	let (x, x_negative) = if base >= one {
+		(base - one, false)
+	} else {
+		(one - base, true)
+	};
+	if x >= one {
+		return None;
+	}
+
+	let mut term = one;
+	let mut sum = one;
+	let mut negative = false;
+	for k in 1..=MAX_POW_ITERATIONS {
+		let big_k = FixedU128::saturating_from_integer(k);
+		let previous_k = big_k - one;
+		let (c, c_negative) = if exponent >= previous_k {
+			(exponent - previous_k, false)
+		} else {
+			(previous_k - exponent, true)
+		};
+		term = term.checked_mul(&c.checked_mul(&x)?)?.checked_div(&big_k)?;
+		if term.is_zero() {
+			break;
+		}
+
+		if x_negative {
+			negative = !negative;
+		}
+		if c_negative {
+			negative = !negative;
+		}
+		sum = if negative {
+			sum.checked_sub(&term)?
+		} else {
+			sum.checked_add(&term)?
+		};
+
+		if term < precision {
+			break;
+		}
+	}
+
+	Some(sum)
+}
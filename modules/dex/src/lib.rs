@@ -26,6 +26,20 @@
 //! liquidation by auction when the liquidity is sufficient. And providing
 //! market making liquidity for DEX will also receive stable currency as
 //! additional reward for its participation in the CDP liquidation.
+//!
+//! Large swaps can be protected from frontrunning by committing to the hash of
+//! the swap first, and revealing it in a later block within the reveal period.
+//! The revealed swap is executed within the committed limits, and the plain
+//! swaps on its trading pairs are rejected for the rest of the block. The
+//! deposit of the commit is returned on reveal, and partly forfeited to the
+//! treasury if the commit expires.
+//!
+//! Tokens can be launched by liquidity bootstrapping pools (LBP), whose weights shift linearly
+//! from the start weights to the end weights over the launch window, which discovers the price
+//! with less upfront capital than the 50/50 provisioning. The trading pair stays `Disabled`
+//! during the window, so the LBP is only swapped directly and never routed through. At the end
+//! of the window the LBP is converted into the liquidity pool of the trading pair at its final
+//! spot price, returning the surplus single-sided to the project, or dissolved.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::too_many_arguments)]
@@ -35,25 +49,33 @@
 
 use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use module_support::{DEXBootstrap, DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, Ratio, SwapLimit};
-use orml_traits::{Happened, MultiCurrency, MultiCurrencyExtended};
+use module_support::{
+	AddressMapping, DEXBootstrap, DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, FlashSwapEvmBridge,
+	InvokeContext, OnTradingPairDisabled, OnTradingPairEnabled, Ratio, SwapLimit, TradingPairListing,
+};
+use orml_traits::{BalanceStatus, Happened, MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency};
 use parity_scale_codec::MaxEncodedLen;
-use primitives::{Balance, CurrencyId, TradingPair};
+use primitives::{evm::EvmAddress, Balance, CurrencyId, Moment, TradingPair};
 use scale_info::TypeInfo;
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Saturating, Zero},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
+	traits::{AccountIdConversion, BlakeTwo256, Convert, Hash, One, Saturating, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Percent, Permill, RuntimeDebug,
+	SaturatedConversion,
 };
-use sp_std::{prelude::*, vec};
+use sp_std::{collections::btree_set::BTreeSet, prelude::*, vec};
 
+pub mod lbp;
 mod mock;
 mod tests;
 pub mod weights;
 
+pub use lbp::{LbpEndAction, LbpPool};
 pub use module::*;
 pub use weights::WeightInfo;
 
+pub type TradingPairListingOf = TradingPairListing<CurrencyId, Balance, Moment>;
+
 /// Parameters of TradingPair in Provisioning status
 #[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
 pub struct ProvisioningParameters<Balance, BlockNumber> {
@@ -79,6 +101,9 @@ pub enum TradingPairStatus<Balance, BlockNumber> {
 	/// TradingPair is Enabled,
 	/// can add/remove liquidity, trading and disable this trading pair.
 	Enabled,
+	/// TradingPair is Paused,
+	/// can remove liquidity, unpause and disable this trading pair.
+	Paused,
 }
 
 impl<Balance, BlockNumber> Default for TradingPairStatus<Balance, BlockNumber> {
@@ -87,6 +112,15 @@ impl<Balance, BlockNumber> Default for TradingPairStatus<Balance, BlockNumber> {
 	}
 }
 
+/// The commit of a swap to be revealed later.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct SwapCommit<Balance, BlockNumber> {
+	/// The deposit reserved from the committer.
+	pub deposit: Balance,
+	/// The block number of the commit, it can be revealed in the following blocks.
+	pub committed_at: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -96,7 +130,8 @@ pub mod module {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Currency for transfer currencies
-		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>
+			+ MultiReservableCurrency<Self::AccountId>;
 
 		/// Trading fee rate
 		/// The first item of the tuple is the numerator of the fee rate, second
@@ -137,6 +172,49 @@ pub mod module {
 
 		/// Event handler which calls when update liquidity pool.
 		type OnLiquidityPoolUpdated: Happened<(TradingPair, Balance, Balance)>;
+
+		/// Hook which calls when trading pair becomes `Enabled`, with its listing bundle.
+		type OnTradingPairEnabled: OnTradingPairEnabled<TradingPair, TradingPairListingOf>;
+
+		/// Hook which calls when trading pair is disabled.
+		type OnTradingPairDisabled: OnTradingPairDisabled<TradingPair>;
+
+		/// Evm address mapping, used to transfer the target of flash swaps to EVM contracts.
+		type EvmAddressMapping: AddressMapping<Self::AccountId>;
+
+		/// Evm bridge for calling the flash swap callback of EVM contracts.
+		type FlashSwapEvmBridge: FlashSwapEvmBridge;
+
+		/// Convert the gas limit of the flash swap callback to weight.
+		type GasToWeight: Convert<u64, Weight>;
+
+		/// The treasury receiving the forfeited deposits of the expired swap commits.
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The minimum deposit in native currency of a swap commit.
+		#[pallet::constant]
+		type MinSwapCommitDeposit: Get<Balance>;
+
+		/// The number of blocks after the commit in which the swap can be revealed.
+		#[pallet::constant]
+		type SwapRevealPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The share of the deposit forfeited when the swap commit expires.
+		#[pallet::constant]
+		type ExpiredSwapCommitPenalty: Get<Percent>;
+
+		/// The maximum number of swap commits expiring in the same block.
+		#[pallet::constant]
+		type MaxSwapCommitsPerBlock: Get<u32>;
+
+		/// The number of blocks between the weight checkpoints of the liquidity bootstrapping
+		/// pools.
+		#[pallet::constant]
+		type LbpCheckpointPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of liquidity bootstrapping pools at the same time.
+		#[pallet::constant]
+		type MaxLbpPools: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -187,6 +265,47 @@ pub mod module {
 		NotAllowedRefund,
 		/// Cannot swap
 		CannotSwap,
+		/// The module account holdings or the dex share issuance are inconsistent with the
+		/// trading pairs
+		InconsistentTradingPair,
+		/// The trading pair can only be repaired to `Enabled` or `Disabled`
+		InvalidRepairTarget,
+		/// The DEX is reentered during the callback of a flash swap
+		FlashSwapReentrancy,
+		/// The supply amount of the flash swap is not repaid by the callback
+		FlashSwapNotRepaid,
+		/// The deposit of the swap commit is below the minimum
+		InsufficientSwapCommitDeposit,
+		/// The swap commit already exists
+		SwapCommitExists,
+		/// Too many swap commits expiring in the same block
+		TooManySwapCommits,
+		/// The swap commit is not found, or doesn't match the revealed swap
+		SwapCommitNotFound,
+		/// The swap can only be revealed after the block of the commit
+		RevealTooEarly,
+		/// The trading pair is swapped by a revealed swap in this block
+		TradingPairReservedByReveal,
+		/// The trading pair is paused
+		TradingPairPaused,
+		/// Trading pair must be paused
+		MustBePaused,
+		/// The weights or the window of the liquidity bootstrapping pool are invalid
+		InvalidLbpParameters,
+		/// The trading pair has a liquidity bootstrapping pool
+		LbpExists,
+		/// Too many liquidity bootstrapping pools
+		TooManyLbpPools,
+		/// The liquidity bootstrapping pool is not found
+		LbpNotFound,
+		/// The liquidity bootstrapping pool can only be funded before its window
+		LbpStarted,
+		/// The liquidity bootstrapping pool can only be swapped in its window
+		LbpNotInWindow,
+		/// The liquidity bootstrapping pool can only be exited after its window
+		LbpNotEnded,
+		/// The caller isn't the owner of the liquidity bootstrapping pool
+		NotLbpOwner,
 	}
 
 	#[pallet::event]
@@ -218,18 +337,36 @@ pub mod module {
 			pool_1: Balance,
 			share_decrement: Balance,
 		},
+		/// Add or remove liquidity with zero amount, nothing changed.
+		NoOp {
+			who: T::AccountId,
+			currency_0: CurrencyId,
+			currency_1: CurrencyId,
+		},
 		/// Use supply currency to swap target currency.
 		Swap {
 			trader: T::AccountId,
 			path: Vec<CurrencyId>,
 			liquidity_changes: Vec<Balance>,
 		},
+		/// Flash swap target currency to the EVM contract, repaid by supply currency in the
+		/// callback.
+		FlashSwap {
+			trader: T::AccountId,
+			recipient: EvmAddress,
+			path: Vec<CurrencyId>,
+			liquidity_changes: Vec<Balance>,
+		},
 		/// Enable trading pair.
 		EnableTradingPair { trading_pair: TradingPair },
 		/// List provisioning trading pair.
 		ListProvisioning { trading_pair: TradingPair },
 		/// Disable trading pair.
 		DisableTradingPair { trading_pair: TradingPair },
+		/// Pause trading pair.
+		PauseTradingPair { trading_pair: TradingPair },
+		/// Unpause trading pair.
+		UnpauseTradingPair { trading_pair: TradingPair },
 		/// Provisioning trading pair convert to Enabled.
 		ProvisioningToEnabled {
 			trading_pair: TradingPair,
@@ -251,6 +388,84 @@ pub mod module {
 			accumulated_provision_0: Balance,
 			accumulated_provision_1: Balance,
 		},
+		/// The listing bundle of trading pair updated.
+		TradingPairListingUpdated {
+			trading_pair: TradingPair,
+			listing: Option<TradingPairListingOf>,
+		},
+		/// The status of trading pair repaired, the provision not injected yet is injected into
+		/// the liquidity pool when repaired to `Enabled`.
+		TradingPairRepaired {
+			trading_pair: TradingPair,
+			old_status: TradingPairStatus<Balance, BlockNumberFor<T>>,
+			new_status: TradingPairStatus<Balance, BlockNumberFor<T>>,
+			injected_provision_0: Balance,
+			injected_provision_1: Balance,
+			issued_share_amount: Balance,
+		},
+		/// A swap is committed.
+		SwapCommitted {
+			who: T::AccountId,
+			hash: H256,
+			deposit: Balance,
+		},
+		/// A committed swap is revealed and executed, the deposit is returned.
+		SwapRevealed { who: T::AccountId, hash: H256 },
+		/// A swap commit expired without being revealed, part of the deposit is forfeited.
+		SwapCommitExpired {
+			who: T::AccountId,
+			hash: H256,
+			forfeited: Balance,
+		},
+		/// A liquidity bootstrapping pool is created.
+		LbpCreated {
+			trading_pair: TradingPair,
+			pool: LbpPool<T::AccountId, BlockNumberFor<T>>,
+		},
+		/// A liquidity bootstrapping pool is funded by its owner.
+		LbpFunded {
+			trading_pair: TradingPair,
+			project_amount: Balance,
+			base_amount: Balance,
+		},
+		/// Swap with a liquidity bootstrapping pool.
+		LbpSwap {
+			trader: T::AccountId,
+			trading_pair: TradingPair,
+			supply_currency_id: CurrencyId,
+			supply_amount: Balance,
+			target_currency_id: CurrencyId,
+			target_amount: Balance,
+		},
+		/// The weights of a liquidity bootstrapping pool reached a checkpoint.
+		LbpWeightCheckpoint {
+			trading_pair: TradingPair,
+			project_weight: Permill,
+			project_reserve: Balance,
+			base_reserve: Balance,
+		},
+		/// A liquidity bootstrapping pool is converted into the liquidity pool of the trading
+		/// pair, the surplus of the reserves is returned to the owner.
+		LbpConverted {
+			trading_pair: TradingPair,
+			owner: T::AccountId,
+			project_amount: Balance,
+			base_amount: Balance,
+			share_amount: Balance,
+		},
+		/// A liquidity bootstrapping pool is dissolved, the reserves are returned to the owner.
+		LbpDissolved {
+			trading_pair: TradingPair,
+			owner: T::AccountId,
+			project_amount: Balance,
+			base_amount: Balance,
+		},
+		/// A liquidity bootstrapping pool failed to be settled at the end of its window, the owner
+		/// can exit it.
+		LbpSettlementFailed {
+			trading_pair: TradingPair,
+			error: DispatchError,
+		},
 	}
 
 	/// Liquidity pool for TradingPair.
@@ -285,6 +500,67 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// The listing bundle applied by hooks when the trading pair becomes `Enabled`.
+	///
+	/// TradingPairListings: map TradingPair => Option<TradingPairListing>
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn trading_pair_listings)]
+	pub type TradingPairListings<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, TradingPairListingOf, OptionQuery>;
+
+	/// Whether the callback of a flash swap is being executed, swaps and liquidity changes are
+	/// rejected meanwhile.
+	///
+	/// FlashSwapLocked: bool
+	#[pallet::storage]
+	#[pallet::getter(fn flash_swap_locked)]
+	pub type FlashSwapLocked<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The swap commits of the accounts.
+	///
+	/// SwapCommits: double_map AccountId, H256 => Option<SwapCommit>
+	#[pallet::storage]
+	#[pallet::getter(fn swap_commits)]
+	pub type SwapCommits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Identity,
+		H256,
+		SwapCommit<Balance, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The swap commits expiring at the block.
+	///
+	/// SwapCommitExpiries: map BlockNumber => BoundedVec<(AccountId, H256)>
+	#[pallet::storage]
+	#[pallet::getter(fn swap_commit_expiries)]
+	pub type SwapCommitExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, H256), T::MaxSwapCommitsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The last block in which the trading pair is swapped by a revealed swap, the plain swaps
+	/// on the trading pair are rejected for the rest of that block.
+	///
+	/// RevealedTradingPairs: map TradingPair => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn revealed_trading_pairs)]
+	pub type RevealedTradingPairs<T: Config> = StorageMap<_, Twox64Concat, TradingPair, BlockNumberFor<T>, OptionQuery>;
+
+	/// The liquidity bootstrapping pools of the trading pairs.
+	///
+	/// LbpPools: map TradingPair => Option<LbpPool>
+	#[pallet::storage]
+	#[pallet::getter(fn lbp_pools)]
+	pub type LbpPools<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, LbpPool<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -340,11 +616,53 @@ pub mod module {
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expired = SwapCommitExpiries::<T>::take(now);
+			let count = expired.len() as u32;
+			for (who, hash) in expired {
+				if let Some(commit) = SwapCommits::<T>::take(&who, hash) {
+					Self::forfeit_swap_commit(&who, hash, commit.deposit);
+				}
+			}
+
+			let mut lbp_count: u32 = 0;
+			for (trading_pair, pool) in LbpPools::<T>::iter() {
+				lbp_count += 1;
+				if now == pool.end {
+					if let Err(error) = Self::do_settle_lbp(trading_pair, pool) {
+						Self::deposit_event(Event::LbpSettlementFailed { trading_pair, error });
+					}
+				} else if now >= pool.start
+					&& now < pool.end
+					&& !T::LbpCheckpointPeriod::get().is_zero()
+					&& ((now - pool.start) % T::LbpCheckpointPeriod::get()).is_zero()
+				{
+					Self::deposit_event(Event::LbpWeightCheckpoint {
+						trading_pair,
+						project_weight: Self::lbp_project_weight(&pool, now),
+						project_reserve: pool.project_reserve,
+						base_reserve: pool.base_reserve,
+					});
+				}
+			}
+
+			<T as Config>::WeightInfo::on_initialize(count)
+				.saturating_add(<T as Config>::WeightInfo::lbp_on_initialize(lbp_count))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -362,6 +680,7 @@ pub mod module {
 			#[pallet::compact] min_target_amount: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::ensure_not_reserved_by_reveal(&path)?;
 			Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount)?;
 			Ok(())
 		}
@@ -380,6 +699,7 @@ pub mod module {
 			#[pallet::compact] max_supply_amount: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::ensure_not_reserved_by_reveal(&path)?;
 			Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount)?;
 			Ok(())
 		}
@@ -411,8 +731,13 @@ pub mod module {
 			#[pallet::compact] max_amount_b: Balance,
 			#[pallet::compact] min_share_increment: Balance,
 			stake_increment_share: bool,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			if max_amount_a.is_zero() && max_amount_b.is_zero() {
+				Self::deposit_no_op_event(who, currency_id_a, currency_id_b)?;
+				return Ok(Some(T::WeightInfo::add_liquidity_no_op()).into());
+			}
+
 			Self::do_add_liquidity(
 				&who,
 				currency_id_a,
@@ -422,7 +747,7 @@ pub mod module {
 				min_share_increment,
 				stake_increment_share,
 			)?;
-			Ok(())
+			Ok(().into())
 		}
 
 		/// Add provision to Provisioning trading pair.
@@ -489,8 +814,13 @@ pub mod module {
 			#[pallet::compact] min_withdrawn_a: Balance,
 			#[pallet::compact] min_withdrawn_b: Balance,
 			by_unstake: bool,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			if remove_share.is_zero() {
+				Self::deposit_no_op_event(who, currency_id_a, currency_id_b)?;
+				return Ok(Some(T::WeightInfo::remove_liquidity_no_op()).into());
+			}
+
 			Self::do_remove_liquidity(
 				&who,
 				currency_id_a,
@@ -500,7 +830,7 @@ pub mod module {
 				min_withdrawn_b,
 				by_unstake,
 			)?;
-			Ok(())
+			Ok(().into())
 		}
 
 		/// List a new provisioning trading pair.
@@ -532,6 +862,7 @@ pub mod module {
 					&& ProvisioningPool::<T>::iter_prefix(trading_pair).next().is_none(),
 				Error::<T>::NotAllowedList
 			);
+			ensure!(!LbpPools::<T>::contains_key(trading_pair), Error::<T>::LbpExists);
 
 			let check_asset_registry = |currency_id: CurrencyId| match currency_id {
 				CurrencyId::Erc20(_) | CurrencyId::ForeignAsset(_) | CurrencyId::StableAssetPoolToken(_) => {
@@ -641,44 +972,13 @@ pub mod module {
 						Error::<T>::UnqualifiedProvision
 					);
 
-					// directly use token_0 as base to calculate initial dex share amount.
-					let (share_exchange_rate_0, share_exchange_rate_1) = (
-						ExchangeRate::one(),
-						ExchangeRate::checked_from_rational(total_provision_0, total_provision_1)
-							.ok_or(ArithmeticError::Overflow)?,
-					);
-					let shares_from_provision_0 = share_exchange_rate_0
-						.checked_mul_int(total_provision_0)
-						.ok_or(ArithmeticError::Overflow)?;
-					let shares_from_provision_1 = share_exchange_rate_1
-						.checked_mul_int(total_provision_1)
-						.ok_or(ArithmeticError::Overflow)?;
-					let total_shares_to_issue = shares_from_provision_0
-						.checked_add(shares_from_provision_1)
-						.ok_or(ArithmeticError::Overflow)?;
-
-					// issue total shares to module account
-					T::Currency::deposit(
-						trading_pair.dex_share_currency_id(),
-						&Self::account_id(),
-						total_shares_to_issue,
-					)?;
-
-					// inject provision to liquidity pool
-					Self::try_mutate_liquidity_pool(&trading_pair, |(pool_0, pool_1)| -> DispatchResult {
-						*pool_0 = pool_0.checked_add(total_provision_0).ok_or(ArithmeticError::Overflow)?;
-						*pool_1 = pool_1.checked_add(total_provision_1).ok_or(ArithmeticError::Overflow)?;
-						Ok(())
-					})?;
+					let total_shares_to_issue =
+						Self::do_inject_provision(&trading_pair, total_provision_0, total_provision_1)?;
 
 					// update trading_pair to Enabled status
 					TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
-
-					// record initial exchange rate so that founders can use it to calculate their own shares
-					InitialShareExchangeRates::<T>::insert(
-						trading_pair,
-						(share_exchange_rate_0, share_exchange_rate_1),
-					);
+					let listing = TradingPairListings::<T>::take(trading_pair);
+					T::OnTradingPairEnabled::on_trading_pair_enabled(&trading_pair, listing.as_ref())?;
 
 					Self::deposit_event(Event::ProvisioningToEnabled {
 						trading_pair,
@@ -716,14 +1016,14 @@ pub mod module {
 					);
 				}
 				TradingPairStatus::<_, _>::Enabled => return Err(Error::<T>::AlreadyEnabled.into()),
+				TradingPairStatus::<_, _>::Paused => return Err(Error::<T>::TradingPairPaused.into()),
 			}
+			ensure!(!LbpPools::<T>::contains_key(trading_pair), Error::<T>::LbpExists);
 
-			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
-			Self::deposit_event(Event::EnableTradingPair { trading_pair });
-			Ok(())
+			Self::do_enable_trading_pair(trading_pair)
 		}
 
-		/// Disable a `Enabled` trading pair.
+		/// Disable a `Enabled` or `Paused` trading pair.
 		#[pallet::call_index(10)]
 		#[pallet::weight((<T as Config>::WeightInfo::disable_trading_pair(), DispatchClass::Operational))]
 		pub fn disable_trading_pair(
@@ -737,82 +1037,780 @@ pub mod module {
 			ensure!(
 				matches!(
 					Self::trading_pair_statuses(trading_pair),
-					TradingPairStatus::<_, _>::Enabled
+					TradingPairStatus::<_, _>::Enabled | TradingPairStatus::<_, _>::Paused
 				),
 				Error::<T>::MustBeEnabled
 			);
 
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Disabled);
+			T::OnTradingPairDisabled::on_trading_pair_disabled(&trading_pair)?;
 			Self::deposit_event(Event::DisableTradingPair { trading_pair });
 			Ok(())
 		}
 
-		/// Refund provision if the provision has already aborted.
-		///
-		/// - `owner`: founder account.
-		/// - `currency_id_a`: currency id A.
-		/// - `currency_id_b`: currency id B.
-		#[pallet::call_index(11)]
-		#[pallet::weight(<T as Config>::WeightInfo::refund_provision())]
-		pub fn refund_provision(
-			origin: OriginFor<T>,
-			owner: T::AccountId,
-			currency_id_a: CurrencyId,
-			currency_id_b: CurrencyId,
-		) -> DispatchResult {
-			let _ = ensure_signed(origin)?;
+		/// Refund provision if the provision has already aborted.
+		///
+		/// - `owner`: founder account.
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::refund_provision())]
+		pub fn refund_provision(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			Self::do_refund_provision(&owner, currency_id_a, currency_id_b)?;
+			Ok(())
+		}
+
+		/// Abort provision when it's don't meet the target and expired.
+		#[pallet::call_index(12)]
+		#[pallet::weight((<T as Config>::WeightInfo::abort_provisioning(), DispatchClass::Operational))]
+		pub fn abort_provisioning(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+
+			match Self::trading_pair_statuses(trading_pair) {
+				TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
+					let (total_provision_0, total_provision_1) = provisioning_parameters.accumulated_provision;
+					let met_target = !total_provision_0.is_zero()
+						&& !total_provision_1.is_zero()
+						&& (total_provision_0 >= provisioning_parameters.target_provision.0
+							|| total_provision_1 >= provisioning_parameters.target_provision.1);
+					let expired = frame_system::Pallet::<T>::block_number()
+						> provisioning_parameters
+							.not_before
+							.saturating_add(T::ExtendedProvisioningBlocks::get());
+
+					if !met_target && expired {
+						// update trading_pair to disabled status
+						TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Disabled);
+
+						Self::deposit_event(Event::ProvisioningAborted {
+							trading_pair,
+							accumulated_provision_0: total_provision_0,
+							accumulated_provision_1: total_provision_1,
+						});
+					}
+				}
+				_ => return Err(Error::<T>::MustBeProvisioning.into()),
+			}
+
+			Ok(())
+		}
+
+		/// Set the listing bundle of a trading pair which is not `Enabled`, the bundle will be
+		/// applied by `OnTradingPairEnabled` atomically when the trading pair becomes `Enabled`.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `listing`: the listing bundle, `None` means remove it.
+		#[pallet::call_index(13)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_trading_pair_listing(), DispatchClass::Operational))]
+		pub fn set_trading_pair_listing(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			listing: Option<TradingPairListingOf>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				!matches!(
+					Self::trading_pair_statuses(trading_pair),
+					TradingPairStatus::<_, _>::Enabled | TradingPairStatus::<_, _>::Paused
+				),
+				Error::<T>::AlreadyEnabled
+			);
+
+			TradingPairListings::<T>::set(trading_pair, listing.clone());
+			Self::deposit_event(Event::TradingPairListingUpdated { trading_pair, listing });
+			Ok(())
+		}
+
+		/// Force a trading pair in inconsistent state into `Enabled` or `Disabled` status, e.g. a
+		/// trading pair stuck in `Provisioning` whose provision has been injected into the
+		/// liquidity pool. The provision not injected yet is injected into the liquidity pool when
+		/// repaired to `Enabled`, and becomes refundable when repaired to `Disabled`.
+		///
+		/// Refuse to repair if the module account holdings don't equal the liquidity pools and
+		/// provisions of the trading pairs, or the dex share issuance doesn't match the liquidity
+		/// pool and the unclaimed provision.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `target_status`: `Enabled` or `Disabled`.
+		#[pallet::call_index(14)]
+		#[pallet::weight((<T as Config>::WeightInfo::force_repair_trading_pair(), DispatchClass::Operational))]
+		pub fn force_repair_trading_pair(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			target_status: TradingPairStatus<Balance, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+
+			Self::ensure_holdings_consistent(&[trading_pair.first(), trading_pair.second()])?;
+			Self::ensure_shares_consistent(&trading_pair)?;
+
+			let old_status = Self::trading_pair_statuses(trading_pair);
+			let provision = Self::total_provision(&trading_pair);
+			if let TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) = old_status {
+				ensure!(
+					provisioning_parameters.accumulated_provision == provision,
+					Error::<T>::InconsistentTradingPair
+				);
+			}
+			let not_injected = Self::initial_share_exchange_rates(trading_pair) == Default::default()
+				&& (!provision.0.is_zero() || !provision.1.is_zero());
+
+			let (injected_provision, issued_share_amount) = match target_status {
+				TradingPairStatus::<_, _>::Enabled => {
+					let injected = if not_injected {
+						ensure!(
+							Self::liquidity_pool(trading_pair) == Default::default(),
+							Error::<T>::InconsistentTradingPair
+						);
+						let share_amount = Self::do_inject_provision(&trading_pair, provision.0, provision.1)?;
+						(provision, share_amount)
+					} else {
+						Default::default()
+					};
+
+					match old_status {
+						TradingPairStatus::<_, _>::Enabled => {}
+						TradingPairStatus::<_, _>::Paused => {
+							TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+						}
+						_ => {
+							TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+							let listing = TradingPairListings::<T>::take(trading_pair);
+							T::OnTradingPairEnabled::on_trading_pair_enabled(&trading_pair, listing.as_ref())?;
+						}
+					}
+					injected
+				}
+				TradingPairStatus::<_, _>::Disabled => {
+					if !matches!(old_status, TradingPairStatus::<_, _>::Disabled) {
+						TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Disabled);
+					}
+					if matches!(
+						old_status,
+						TradingPairStatus::<_, _>::Enabled | TradingPairStatus::<_, _>::Paused
+					) {
+						T::OnTradingPairDisabled::on_trading_pair_disabled(&trading_pair)?;
+					}
+					Default::default()
+				}
+				TradingPairStatus::<_, _>::Provisioning(_) | TradingPairStatus::<_, _>::Paused => {
+					return Err(Error::<T>::InvalidRepairTarget.into())
+				}
+			};
+
+			Self::deposit_event(Event::TradingPairRepaired {
+				trading_pair,
+				old_status,
+				new_status: target_status,
+				injected_provision_0: injected_provision.0,
+				injected_provision_1: injected_provision.1,
+				issued_share_amount,
+			});
+			Ok(())
+		}
+
+		/// Flash swap with DEX. The exact target amount is transferred to the EVM contract
+		/// `recipient` first, then `onFlashSwap(address,address,uint256,uint256,address,bytes)` of
+		/// it is called with `callback_data`, which must leave the supply amount including the
+		/// fee in `recipient` for the DEX to collect, otherwise the whole flash swap is reverted.
+		///
+		/// - `path`: trading path.
+		/// - `target_amount`: exact target amount transferred to `recipient`.
+		/// - `recipient`: the EVM contract receiving the target amount and the callback.
+		/// - `callback_data`: the data passed through to the callback.
+		/// - `gas_limit`: the gas limit of the callback.
+		/// - `storage_limit`: the storage limit of the callback, charged from the caller.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::flash_swap(path.len() as u32)
+			.saturating_add(T::GasToWeight::convert(*gas_limit)))]
+		pub fn flash_swap(
+			origin: OriginFor<T>,
+			path: Vec<CurrencyId>,
+			#[pallet::compact] target_amount: Balance,
+			recipient: EvmAddress,
+			callback_data: Vec<u8>,
+			#[pallet::compact] gas_limit: u64,
+			#[pallet::compact] storage_limit: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_reserved_by_reveal(&path)?;
+			Self::do_flash_swap(
+				&who,
+				&path,
+				target_amount,
+				recipient,
+				callback_data,
+				gas_limit,
+				storage_limit,
+			)?;
+			Ok(())
+		}
+
+		/// Commit to a swap to be revealed in the following blocks within `SwapRevealPeriod`,
+		/// which hides the swap from the frontrunners until it's executed. The deposit in
+		/// native currency is reserved, returned when revealed and partly forfeited when expired.
+		///
+		/// - `hash`: the blake2-256 hash of the SCALE-encoded `(who, path, limit, salt)` of the
+		///   swap.
+		/// - `deposit`: the deposit, at least `MinSwapCommitDeposit`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::commit_swap())]
+		pub fn commit_swap(origin: OriginFor<T>, hash: H256, #[pallet::compact] deposit: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				deposit >= T::MinSwapCommitDeposit::get(),
+				Error::<T>::InsufficientSwapCommitDeposit
+			);
+			ensure!(
+				!SwapCommits::<T>::contains_key(&who, hash),
+				Error::<T>::SwapCommitExists
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			SwapCommitExpiries::<T>::try_mutate(Self::swap_commit_expiry(now), |commits| {
+				commits.try_push((who.clone(), hash))
+			})
+			.map_err(|_| Error::<T>::TooManySwapCommits)?;
+			T::Currency::reserve(T::GetNativeCurrencyId::get(), &who, deposit)?;
+			SwapCommits::<T>::insert(
+				&who,
+				hash,
+				SwapCommit {
+					deposit,
+					committed_at: now,
+				},
+			);
+
+			Self::deposit_event(Event::SwapCommitted { who, hash, deposit });
+			Ok(())
+		}
+
+		/// Reveal and execute the committed swap, the limit is checked against the liquidity
+		/// pools at the time of the reveal. The plain swaps on the trading pairs of the path are
+		/// rejected for the rest of the block. The deposit is returned.
+		///
+		/// - `path`: trading path.
+		/// - `limit`: the exact supply or target amount and the acceptable amount of the other
+		///   side.
+		/// - `salt`: the salt of the commit.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::reveal_swap(path.len() as u32))]
+		pub fn reveal_swap(
+			origin: OriginFor<T>,
+			path: Vec<CurrencyId>,
+			limit: SwapLimit<Balance>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let hash = Self::swap_commit_hash(&who, &path, limit, salt);
+			let commit = SwapCommits::<T>::get(&who, hash).ok_or(Error::<T>::SwapCommitNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			// the expired commits are removed by `on_initialize`, so it's in the reveal period.
+			ensure!(now > commit.committed_at, Error::<T>::RevealTooEarly);
+
+			match limit {
+				SwapLimit::ExactSupply(supply_amount, min_target_amount) => {
+					Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount)?;
+				}
+				SwapLimit::ExactTarget(max_supply_amount, target_amount) => {
+					Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount)?;
+				}
+			}
+			for pair in path.windows(2) {
+				if let Some(trading_pair) = TradingPair::from_currency_ids(pair[0], pair[1]) {
+					RevealedTradingPairs::<T>::insert(trading_pair, now);
+				}
+			}
+
+			SwapCommits::<T>::remove(&who, hash);
+			SwapCommitExpiries::<T>::mutate(Self::swap_commit_expiry(commit.committed_at), |commits| {
+				commits.retain(|(committer, commit_hash)| *committer != who || *commit_hash != hash)
+			});
+			T::Currency::unreserve(T::GetNativeCurrencyId::get(), &who, commit.deposit);
+
+			Self::deposit_event(Event::SwapRevealed { who, hash });
+			Ok(())
+		}
+
+		/// Pause a `Enabled` trading pair, the swaps through it and adding liquidity to it are
+		/// rejected, while removing liquidity keeps working.
+		#[pallet::call_index(18)]
+		#[pallet::weight((<T as Config>::WeightInfo::pause_trading_pair(), DispatchClass::Operational))]
+		pub fn pause_trading_pair(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				matches!(
+					Self::trading_pair_statuses(trading_pair),
+					TradingPairStatus::<_, _>::Enabled
+				),
+				Error::<T>::MustBeEnabled
+			);
+
+			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Paused);
+			Self::deposit_event(Event::PauseTradingPair { trading_pair });
+			Ok(())
+		}
+
+		/// Unpause a `Paused` trading pair, which returns to `Enabled`.
+		#[pallet::call_index(19)]
+		#[pallet::weight((<T as Config>::WeightInfo::unpause_trading_pair(), DispatchClass::Operational))]
+		pub fn unpause_trading_pair(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				matches!(
+					Self::trading_pair_statuses(trading_pair),
+					TradingPairStatus::<_, _>::Paused
+				),
+				Error::<T>::MustBePaused
+			);
+
+			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+			Self::deposit_event(Event::UnpauseTradingPair { trading_pair });
+			Ok(())
+		}
+
+		/// Create a liquidity bootstrapping pool for a `Disabled` trading pair, which is funded by
+		/// `owner` before `start` and swapped in `[start, end)`.
+		///
+		/// - `owner`: the project launching the token.
+		/// - `project_currency_id`: the currency launched by the project.
+		/// - `base_currency_id`: the currency used to buy the project currency.
+		/// - `start_weight`: the weight of the project currency at `start`.
+		/// - `end_weight`: the weight of the project currency at `end`.
+		/// - `start`: the block number at which the swaps start.
+		/// - `end`: the block number at which the pool is settled.
+		/// - `end_action`: convert the pool into the liquidity pool or dissolve it at `end`.
+		#[pallet::call_index(20)]
+		#[pallet::weight((<T as Config>::WeightInfo::create_lbp(), DispatchClass::Operational))]
+		pub fn create_lbp(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			project_currency_id: CurrencyId,
+			base_currency_id: CurrencyId,
+			start_weight: Permill,
+			end_weight: Permill,
+			#[pallet::compact] start: BlockNumberFor<T>,
+			#[pallet::compact] end: BlockNumberFor<T>,
+			end_action: LbpEndAction,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair = TradingPair::from_currency_ids(project_currency_id, base_currency_id)
+				.ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				matches!(
+					Self::trading_pair_statuses(trading_pair),
+					TradingPairStatus::<_, _>::Disabled
+				),
+				Error::<T>::MustBeDisabled
+			);
+			ensure!(
+				T::Currency::total_issuance(trading_pair.dex_share_currency_id()).is_zero()
+					&& ProvisioningPool::<T>::iter_prefix(trading_pair).next().is_none(),
+				Error::<T>::NotAllowedList
+			);
+			ensure!(!LbpPools::<T>::contains_key(trading_pair), Error::<T>::LbpExists);
+			ensure!(
+				(LbpPools::<T>::iter_keys().count() as u32) < T::MaxLbpPools::get(),
+				Error::<T>::TooManyLbpPools
+			);
+
+			let max_weight = Permill::one().saturating_sub(lbp::MIN_LBP_WEIGHT);
+			let valid_weight = |weight: Permill| weight >= lbp::MIN_LBP_WEIGHT && weight <= max_weight;
+			ensure!(
+				valid_weight(start_weight)
+					&& valid_weight(end_weight)
+					&& start >= frame_system::Pallet::<T>::block_number()
+					&& end > start,
+				Error::<T>::InvalidLbpParameters
+			);
+
+			let pool = LbpPool {
+				owner,
+				project_currency: project_currency_id,
+				base_currency: base_currency_id,
+				project_reserve: Zero::zero(),
+				base_reserve: Zero::zero(),
+				start_weight,
+				end_weight,
+				start,
+				end,
+				end_action,
+			};
+			LbpPools::<T>::insert(trading_pair, pool.clone());
+			Self::deposit_event(Event::LbpCreated { trading_pair, pool });
+			Ok(())
+		}
+
+		/// Fund the liquidity bootstrapping pool before its window, only by its owner.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `project_amount`: the amount of the project currency.
+		/// - `base_amount`: the amount of the base currency.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::fund_lbp())]
+		pub fn fund_lbp(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			#[pallet::compact] project_amount: Balance,
+			#[pallet::compact] base_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+
+			LbpPools::<T>::try_mutate(trading_pair, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::LbpNotFound)?;
+				ensure!(pool.owner == who, Error::<T>::NotLbpOwner);
+				ensure!(
+					frame_system::Pallet::<T>::block_number() < pool.start,
+					Error::<T>::LbpStarted
+				);
+
+				T::Currency::transfer(
+					pool.project_currency,
+					&who,
+					&Self::lbp_account_id(),
+					project_amount,
+					ExistenceRequirement::AllowDeath,
+				)?;
+				T::Currency::transfer(
+					pool.base_currency,
+					&who,
+					&Self::lbp_account_id(),
+					base_amount,
+					ExistenceRequirement::AllowDeath,
+				)?;
+				pool.project_reserve = pool
+					.project_reserve
+					.checked_add(project_amount)
+					.ok_or(ArithmeticError::Overflow)?;
+				pool.base_reserve = pool
+					.base_reserve
+					.checked_add(base_amount)
+					.ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::LbpFunded {
+				trading_pair,
+				project_amount,
+				base_amount,
+			});
+			Ok(())
+		}
+
+		/// Swap with the liquidity bootstrapping pool in its window, priced by the weights
+		/// interpolated at the current block.
+		///
+		/// - `supply_currency_id`: the currency supplied.
+		/// - `target_currency_id`: the currency received.
+		/// - `limit`: the exact supply or target amount and the acceptable amount of the other
+		///   side.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_lbp())]
+		pub fn swap_with_lbp(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			limit: SwapLimit<Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_swap_with_lbp(&who, supply_currency_id, target_currency_id, limit)?;
+			Ok(())
+		}
+
+		/// Exit the liquidity bootstrapping pool which failed to be settled at the end of its
+		/// window, the reserves are returned to the owner.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::exit_lbp())]
+		pub fn exit_lbp(origin: OriginFor<T>, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			let pool = Self::lbp_pools(trading_pair).ok_or(Error::<T>::LbpNotFound)?;
+			ensure!(pool.owner == who, Error::<T>::NotLbpOwner);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= pool.end,
+				Error::<T>::LbpNotEnded
+			);
+
+			Self::do_dissolve_lbp(trading_pair, pool)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The account holding the reserves of the liquidity bootstrapping pools, separated from the
+	/// module account holding the liquidity pools.
+	pub fn lbp_account_id() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"lbp")
+	}
+
+	fn do_enable_trading_pair(trading_pair: TradingPair) -> DispatchResult {
+		TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+		let listing = TradingPairListings::<T>::take(trading_pair);
+		T::OnTradingPairEnabled::on_trading_pair_enabled(&trading_pair, listing.as_ref())?;
+		Self::deposit_event(Event::EnableTradingPair { trading_pair });
+		Ok(())
+	}
+
+	/// The weight of the project currency of the liquidity bootstrapping pool at `now`.
+	pub fn lbp_project_weight(pool: &LbpPool<T::AccountId, BlockNumberFor<T>>, now: BlockNumberFor<T>) -> Permill {
+		lbp::interpolated_weight(
+			pool.start_weight,
+			pool.end_weight,
+			now.saturating_sub(pool.start).saturated_into(),
+			pool.end.saturating_sub(pool.start).saturated_into(),
+		)
+	}
+
+	/// Get the supply amount and the target amount of the swap with the liquidity bootstrapping
+	/// pool at the current block.
+	pub fn get_lbp_swap_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
+		let trading_pair = TradingPair::from_currency_ids(supply_currency_id, target_currency_id)?;
+		let pool = Self::lbp_pools(trading_pair)?;
+		let project_weight = Self::lbp_project_weight(&pool, frame_system::Pallet::<T>::block_number());
+		let base_weight = Permill::one().saturating_sub(project_weight);
+		let (supply_reserve, supply_weight, target_reserve, target_weight) =
+			if supply_currency_id == pool.project_currency {
+				(pool.project_reserve, project_weight, pool.base_reserve, base_weight)
+			} else {
+				(pool.base_reserve, base_weight, pool.project_reserve, project_weight)
+			};
+
+		let fee = T::GetExchangeFee::get();
+		match limit {
+			SwapLimit::ExactSupply(supply_amount, min_target_amount) => {
+				let target_amount = lbp::get_target_amount(
+					supply_reserve,
+					supply_weight,
+					target_reserve,
+					target_weight,
+					supply_amount,
+					fee,
+				)?;
+				if !supply_amount.is_zero() && !target_amount.is_zero() && target_amount >= min_target_amount {
+					return Some((supply_amount, target_amount));
+				}
+			}
+			SwapLimit::ExactTarget(max_supply_amount, target_amount) => {
+				let supply_amount = lbp::get_supply_amount(
+					supply_reserve,
+					supply_weight,
+					target_reserve,
+					target_weight,
+					target_amount,
+					fee,
+				)?;
+				if !target_amount.is_zero() && supply_amount <= max_supply_amount {
+					return Some((supply_amount, target_amount));
+				}
+			}
+		}
+
+		None
+	}
+
+	#[transactional]
+	fn do_swap_with_lbp(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		let trading_pair = TradingPair::from_currency_ids(supply_currency_id, target_currency_id)
+			.ok_or(Error::<T>::InvalidCurrencyId)?;
+		let mut pool = Self::lbp_pools(trading_pair).ok_or(Error::<T>::LbpNotFound)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now >= pool.start && now < pool.end, Error::<T>::LbpNotInWindow);
+
+		let (supply_amount, target_amount) = Self::get_lbp_swap_amount(supply_currency_id, target_currency_id, limit)
+			.ok_or(match limit {
+			SwapLimit::ExactSupply(..) => Error::<T>::InsufficientTargetAmount,
+			SwapLimit::ExactTarget(..) => Error::<T>::ExcessiveSupplyAmount,
+		})?;
+
+		let lbp_account_id = Self::lbp_account_id();
+		T::Currency::transfer(
+			supply_currency_id,
+			who,
+			&lbp_account_id,
+			supply_amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		T::Currency::transfer(
+			target_currency_id,
+			&lbp_account_id,
+			who,
+			target_amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		if supply_currency_id == pool.project_currency {
+			pool.project_reserve = pool
+				.project_reserve
+				.checked_add(supply_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			pool.base_reserve = pool
+				.base_reserve
+				.checked_sub(target_amount)
+				.ok_or(ArithmeticError::Underflow)?;
+		} else {
+			pool.base_reserve = pool
+				.base_reserve
+				.checked_add(supply_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			pool.project_reserve = pool
+				.project_reserve
+				.checked_sub(target_amount)
+				.ok_or(ArithmeticError::Underflow)?;
+		}
+		LbpPools::<T>::insert(trading_pair, pool);
+
+		Self::deposit_event(Event::LbpSwap {
+			trader: who.clone(),
+			trading_pair,
+			supply_currency_id,
+			supply_amount,
+			target_currency_id,
+			target_amount,
+		});
+		Ok((supply_amount, target_amount))
+	}
 
-			Self::do_refund_provision(&owner, currency_id_a, currency_id_b)?;
-			Ok(())
+	/// Settle the liquidity bootstrapping pool at the end of its window by its end action. The
+	/// pool is converted at its final spot price: the side exceeding the price is added to the
+	/// liquidity pool partly and its surplus is returned to the owner.
+	#[transactional]
+	fn do_settle_lbp(trading_pair: TradingPair, pool: LbpPool<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
+		if pool.end_action == LbpEndAction::Dissolve || pool.project_reserve.is_zero() || pool.base_reserve.is_zero() {
+			return Self::do_dissolve_lbp(trading_pair, pool);
 		}
 
-		/// Abort provision when it's don't meet the target and expired.
-		#[pallet::call_index(12)]
-		#[pallet::weight((<T as Config>::WeightInfo::abort_provisioning(), DispatchClass::Operational))]
-		pub fn abort_provisioning(
-			origin: OriginFor<T>,
-			currency_id_a: CurrencyId,
-			currency_id_b: CurrencyId,
-		) -> DispatchResult {
-			let _ = ensure_signed(origin)?;
-
-			let trading_pair =
-				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+		ensure!(
+			matches!(
+				Self::trading_pair_statuses(trading_pair),
+				TradingPairStatus::<_, _>::Disabled
+			),
+			Error::<T>::MustBeDisabled
+		);
 
-			match Self::trading_pair_statuses(trading_pair) {
-				TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
-					let (total_provision_0, total_provision_1) = provisioning_parameters.accumulated_provision;
-					let met_target = !total_provision_0.is_zero()
-						&& !total_provision_1.is_zero()
-						&& (total_provision_0 >= provisioning_parameters.target_provision.0
-							|| total_provision_1 >= provisioning_parameters.target_provision.1);
-					let expired = frame_system::Pallet::<T>::block_number()
-						> provisioning_parameters
-							.not_before
-							.saturating_add(T::ExtendedProvisioningBlocks::get());
+		// the spot price of the project currency is `(base_reserve / base_weight) /
+		// (project_reserve / project_weight)`, keep it in the constant-product pool.
+		let project_weight = pool.end_weight;
+		let base_weight = Permill::one().saturating_sub(project_weight);
+		let project_needed = Ratio::checked_from_rational(base_weight.deconstruct(), project_weight.deconstruct())
+			.and_then(|ratio| ratio.checked_mul_int(pool.project_reserve))
+			.ok_or(ArithmeticError::Overflow)?;
+		let (project_amount, base_amount) = if project_needed <= pool.project_reserve {
+			(project_needed, pool.base_reserve)
+		} else {
+			let base_needed = Ratio::checked_from_rational(project_weight.deconstruct(), base_weight.deconstruct())
+				.and_then(|ratio| ratio.checked_mul_int(pool.base_reserve))
+				.ok_or(ArithmeticError::Overflow)?;
+			(pool.project_reserve, base_needed)
+		};
 
-					if !met_target && expired {
-						// update trading_pair to disabled status
-						TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Disabled);
+		Self::withdraw_lbp_reserves(&pool)?;
+		LbpPools::<T>::remove(trading_pair);
+
+		Self::do_enable_trading_pair(trading_pair)?;
+		let (_, _, share_amount) = Self::do_add_liquidity(
+			&pool.owner,
+			pool.project_currency,
+			pool.base_currency,
+			project_amount,
+			base_amount,
+			Zero::zero(),
+			false,
+		)?;
 
-						Self::deposit_event(Event::ProvisioningAborted {
-							trading_pair,
-							accumulated_provision_0: total_provision_0,
-							accumulated_provision_1: total_provision_1,
-						});
-					}
-				}
-				_ => return Err(Error::<T>::MustBeProvisioning.into()),
-			}
+		Self::deposit_event(Event::LbpConverted {
+			trading_pair,
+			owner: pool.owner,
+			project_amount,
+			base_amount,
+			share_amount,
+		});
+		Ok(())
+	}
 
-			Ok(())
-		}
+	/// Return the reserves of the liquidity bootstrapping pool to its owner.
+	fn withdraw_lbp_reserves(pool: &LbpPool<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
+		let lbp_account_id = Self::lbp_account_id();
+		T::Currency::transfer(
+			pool.project_currency,
+			&lbp_account_id,
+			&pool.owner,
+			pool.project_reserve,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		T::Currency::transfer(
+			pool.base_currency,
+			&lbp_account_id,
+			&pool.owner,
+			pool.base_reserve,
+			ExistenceRequirement::AllowDeath,
+		)
 	}
-}
 
-impl<T: Config> Pallet<T> {
-	fn account_id() -> T::AccountId {
-		T::PalletId::get().into_account_truncating()
+	fn do_dissolve_lbp(trading_pair: TradingPair, pool: LbpPool<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
+		Self::withdraw_lbp_reserves(&pool)?;
+		LbpPools::<T>::remove(trading_pair);
+
+		Self::deposit_event(Event::LbpDissolved {
+			trading_pair,
+			owner: pool.owner,
+			project_amount: pool.project_reserve,
+			base_amount: pool.base_reserve,
+		});
+		Ok(())
 	}
 
 	fn try_mutate_liquidity_pool<R, E>(
@@ -830,11 +1828,185 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Issue the dex shares of the provision to the module account, inject the provision into
+	/// the liquidity pool and record the initial exchange rates for founders to claim their
+	/// shares. Returns the issued share amount.
+	fn do_inject_provision(
+		trading_pair: &TradingPair,
+		total_provision_0: Balance,
+		total_provision_1: Balance,
+	) -> Result<Balance, DispatchError> {
+		// directly use token_0 as base to calculate initial dex share amount.
+		let (share_exchange_rate_0, share_exchange_rate_1) = (
+			ExchangeRate::one(),
+			ExchangeRate::checked_from_rational(total_provision_0, total_provision_1)
+				.ok_or(ArithmeticError::Overflow)?,
+		);
+		let shares_from_provision_0 = share_exchange_rate_0
+			.checked_mul_int(total_provision_0)
+			.ok_or(ArithmeticError::Overflow)?;
+		let shares_from_provision_1 = share_exchange_rate_1
+			.checked_mul_int(total_provision_1)
+			.ok_or(ArithmeticError::Overflow)?;
+		let total_shares_to_issue = shares_from_provision_0
+			.checked_add(shares_from_provision_1)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		// issue total shares to module account
+		T::Currency::deposit(
+			trading_pair.dex_share_currency_id(),
+			&Self::account_id(),
+			total_shares_to_issue,
+		)?;
+
+		// inject provision to liquidity pool
+		Self::try_mutate_liquidity_pool(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			*pool_0 = pool_0.checked_add(total_provision_0).ok_or(ArithmeticError::Overflow)?;
+			*pool_1 = pool_1.checked_add(total_provision_1).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+
+		// record initial exchange rate so that founders can use it to calculate their own shares
+		InitialShareExchangeRates::<T>::insert(trading_pair, (share_exchange_rate_0, share_exchange_rate_1));
+
+		Ok(total_shares_to_issue)
+	}
+
+	/// The total provision of the founders of the trading pair.
+	fn total_provision(trading_pair: &TradingPair) -> (Balance, Balance) {
+		ProvisioningPool::<T>::iter_prefix_values(trading_pair).fold(
+			(Zero::zero(), Zero::zero()),
+			|(total_0, total_1): (Balance, Balance), (contribution_0, contribution_1)| {
+				(
+					total_0.saturating_add(contribution_0),
+					total_1.saturating_add(contribution_1),
+				)
+			},
+		)
+	}
+
+	/// The amounts held by the module account for the trading pair, including the liquidity
+	/// pool and the provision not injected into it yet.
+	fn trading_pair_holdings(trading_pair: &TradingPair) -> (Balance, Balance) {
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+		if Self::initial_share_exchange_rates(trading_pair) == Default::default() {
+			let (provision_0, provision_1) = Self::total_provision(trading_pair);
+			(pool_0.saturating_add(provision_0), pool_1.saturating_add(provision_1))
+		} else {
+			(pool_0, pool_1)
+		}
+	}
+
+	/// Ensure the module account holdings of the currencies equal the holdings of all the trading
+	/// pairs.
+	fn ensure_holdings_consistent(currency_ids: &[CurrencyId]) -> DispatchResult {
+		let trading_pairs = TradingPairStatuses::<T>::iter_keys()
+			.chain(LiquidityPool::<T>::iter_keys())
+			.chain(ProvisioningPool::<T>::iter_keys().map(|(trading_pair, _)| trading_pair))
+			.collect::<BTreeSet<_>>();
+
+		for currency_id in currency_ids {
+			let expected = trading_pairs.iter().fold(Balance::zero(), |total, trading_pair| {
+				let (holding_0, holding_1) = Self::trading_pair_holdings(trading_pair);
+				if trading_pair.first() == *currency_id {
+					total.saturating_add(holding_0)
+				} else if trading_pair.second() == *currency_id {
+					total.saturating_add(holding_1)
+				} else {
+					total
+				}
+			});
+			ensure!(
+				expected == T::Currency::free_balance(*currency_id, &Self::account_id()),
+				Error::<T>::InconsistentTradingPair
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure the dex share issuance of the trading pair is zero only if its liquidity pool is
+	/// empty, and the module account holds the shares of the unclaimed provision.
+	fn ensure_shares_consistent(trading_pair: &TradingPair) -> DispatchResult {
+		let share_currency_id = trading_pair.dex_share_currency_id();
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+		ensure!(
+			T::Currency::total_issuance(share_currency_id).is_zero() == (pool_0.is_zero() && pool_1.is_zero()),
+			Error::<T>::InconsistentTradingPair
+		);
+
+		let (exchange_rate_0, exchange_rate_1) = Self::initial_share_exchange_rates(trading_pair);
+		if (exchange_rate_0, exchange_rate_1) != Default::default() {
+			let (provision_0, provision_1) = Self::total_provision(trading_pair);
+			let unclaimed_shares = exchange_rate_0
+				.saturating_mul_int(provision_0)
+				.saturating_add(exchange_rate_1.saturating_mul_int(provision_1));
+			ensure!(
+				T::Currency::free_balance(share_currency_id, &Self::account_id()) >= unclaimed_shares,
+				Error::<T>::InconsistentTradingPair
+			);
+		}
+		Ok(())
+	}
+
+	/// The number of the enabled trading pairs whose provision is not injected into the liquidity
+	/// pool, i.e. stuck in the transition from provisioning to enabled.
+	pub fn transitional_trading_pairs() -> u32 {
+		TradingPairStatuses::<T>::iter()
+			.filter(|(trading_pair, status)| {
+				matches!(
+					status,
+					TradingPairStatus::<_, _>::Enabled | TradingPairStatus::<_, _>::Paused
+				) && Self::initial_share_exchange_rates(trading_pair) == Default::default()
+					&& ProvisioningPool::<T>::iter_prefix(trading_pair).next().is_some()
+			})
+			.count() as u32
+	}
+
+	/// Ensure the statuses of all the trading pairs are consistent with their provisions, liquidity
+	/// pools and dex share issuance, and the module account holdings equal the holdings of all the
+	/// trading pairs.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		let mut currency_ids = BTreeSet::new();
+		for (trading_pair, status) in TradingPairStatuses::<T>::iter() {
+			let injected = Self::initial_share_exchange_rates(trading_pair) != Default::default();
+			match status {
+				TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
+					ensure!(
+						!injected
+							&& provisioning_parameters.accumulated_provision == Self::total_provision(&trading_pair),
+						"the provision of the provisioning trading pair is inconsistent"
+					);
+				}
+				TradingPairStatus::<_, _>::Enabled | TradingPairStatus::<_, _>::Paused => {
+					ensure!(
+						injected || ProvisioningPool::<T>::iter_prefix(trading_pair).next().is_none(),
+						"the provision of the enabled trading pair is not injected into the liquidity pool"
+					);
+				}
+				TradingPairStatus::<_, _>::Disabled => {}
+			}
+			ensure!(
+				Self::ensure_shares_consistent(&trading_pair).is_ok(),
+				"the dex share issuance of the trading pair is inconsistent"
+			);
+			currency_ids.insert(trading_pair.first());
+			currency_ids.insert(trading_pair.second());
+		}
+
+		ensure!(
+			Self::ensure_holdings_consistent(&currency_ids.into_iter().collect::<Vec<_>>()).is_ok(),
+			"the module account holdings are inconsistent with the trading pairs"
+		);
+		Ok(())
+	}
+
 	fn do_claim_dex_share(
 		who: &T::AccountId,
 		currency_id_a: CurrencyId,
 		currency_id_b: CurrencyId,
 	) -> Result<Balance, DispatchError> {
+		Self::ensure_not_flash_swap_locked()?;
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
 		ensure!(
@@ -888,6 +2060,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn do_refund_provision(who: &T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
+		Self::ensure_not_flash_swap_locked()?;
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
 		ensure!(
@@ -943,6 +2116,7 @@ impl<T: Config> Pallet<T> {
 		contribution_a: Balance,
 		contribution_b: Balance,
 	) -> DispatchResult {
+		Self::ensure_not_flash_swap_locked()?;
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
 		let mut provision_parameters = match Self::trading_pair_statuses(trading_pair) {
@@ -1033,15 +2207,10 @@ impl<T: Config> Pallet<T> {
 		min_share_increment: Balance,
 		stake_increment_share: bool,
 	) -> sp_std::result::Result<(Balance, Balance, Balance), DispatchError> {
+		Self::ensure_not_flash_swap_locked()?;
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
-		ensure!(
-			matches!(
-				Self::trading_pair_statuses(trading_pair),
-				TradingPairStatus::<_, _>::Enabled
-			),
-			Error::<T>::MustBeEnabled,
-		);
+		Self::ensure_trading_pair_enabled(&trading_pair)?;
 
 		ensure!(
 			!max_amount_a.is_zero() && !max_amount_b.is_zero(),
@@ -1159,6 +2328,17 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[transactional]
+	fn deposit_no_op_event(who: T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+		Self::deposit_event(Event::NoOp {
+			who,
+			currency_0: trading_pair.first(),
+			currency_1: trading_pair.second(),
+		});
+		Ok(())
+	}
+
 	fn do_remove_liquidity(
 		who: &T::AccountId,
 		currency_id_a: CurrencyId,
@@ -1171,6 +2351,7 @@ impl<T: Config> Pallet<T> {
 		if remove_share.is_zero() {
 			return Ok((Zero::zero(), Zero::zero()));
 		}
+		Self::ensure_not_flash_swap_locked()?;
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
 		let dex_share_currency_id = trading_pair.dex_share_currency_id();
@@ -1183,6 +2364,12 @@ impl<T: Config> Pallet<T> {
 				} else {
 					(min_withdrawn_b, min_withdrawn_a)
 				};
+				// only the shares returned after the instant exit fee of unstaking are removed
+				let remove_share = if by_unstake {
+					T::DEXIncentives::do_withdraw_dex_share(who, dex_share_currency_id, remove_share)?
+				} else {
+					remove_share
+				};
 				let total_shares = T::Currency::total_issuance(dex_share_currency_id);
 				let proportion =
 					Ratio::checked_from_rational(remove_share, total_shares).ok_or(ArithmeticError::Overflow)?;
@@ -1195,9 +2382,6 @@ impl<T: Config> Pallet<T> {
 					Error::<T>::UnacceptableLiquidityWithdrawn,
 				);
 
-				if by_unstake {
-					T::DEXIncentives::do_withdraw_dex_share(who, dex_share_currency_id, remove_share)?;
-				}
 				T::Currency::withdraw(
 					dex_share_currency_id,
 					who,
@@ -1308,13 +2492,7 @@ impl<T: Config> Pallet<T> {
 		while i + 1 < path_length {
 			let trading_pair =
 				TradingPair::from_currency_ids(path[i], path[i + 1]).ok_or(Error::<T>::InvalidCurrencyId)?;
-			ensure!(
-				matches!(
-					Self::trading_pair_statuses(trading_pair),
-					TradingPairStatus::<_, _>::Enabled
-				),
-				Error::<T>::MustBeEnabled
-			);
+			Self::ensure_trading_pair_enabled(&trading_pair)?;
 			let (supply_pool, target_pool) = Self::get_liquidity(path[i], path[i + 1]);
 			ensure!(
 				!supply_pool.is_zero() && !target_pool.is_zero(),
@@ -1344,13 +2522,7 @@ impl<T: Config> Pallet<T> {
 		while i > 0 {
 			let trading_pair =
 				TradingPair::from_currency_ids(path[i - 1], path[i]).ok_or(Error::<T>::InvalidCurrencyId)?;
-			ensure!(
-				matches!(
-					Self::trading_pair_statuses(trading_pair),
-					TradingPairStatus::<_, _>::Enabled
-				),
-				Error::<T>::MustBeEnabled
-			);
+			Self::ensure_trading_pair_enabled(&trading_pair)?;
 			let (supply_pool, target_pool) = Self::get_liquidity(path[i - 1], path[i]);
 			ensure!(
 				!supply_pool.is_zero() && !target_pool.is_zero(),
@@ -1366,6 +2538,16 @@ impl<T: Config> Pallet<T> {
 		Ok(supply_amounts)
 	}
 
+	/// Ensure the trading pair is `Enabled`, the `Paused` trading pair is rejected with a specific
+	/// error.
+	fn ensure_trading_pair_enabled(trading_pair: &TradingPair) -> DispatchResult {
+		match Self::trading_pair_statuses(trading_pair) {
+			TradingPairStatus::<_, _>::Enabled => Ok(()),
+			TradingPairStatus::<_, _>::Paused => Err(Error::<T>::TradingPairPaused.into()),
+			_ => Err(Error::<T>::MustBeEnabled.into()),
+		}
+	}
+
 	fn validate_path(path: &[CurrencyId]) -> DispatchResult {
 		let path_length = path.len();
 		ensure!(
@@ -1430,6 +2612,7 @@ impl<T: Config> Pallet<T> {
 		supply_amount: Balance,
 		min_target_amount: Balance,
 	) -> sp_std::result::Result<Balance, DispatchError> {
+		Self::ensure_not_flash_swap_locked()?;
 		let amounts = Self::get_target_amounts(path, supply_amount)?;
 		ensure!(
 			amounts[amounts.len() - 1] >= min_target_amount,
@@ -1475,6 +2658,7 @@ impl<T: Config> Pallet<T> {
 		target_amount: Balance,
 		max_supply_amount: Balance,
 	) -> sp_std::result::Result<Balance, DispatchError> {
+		Self::ensure_not_flash_swap_locked()?;
 		let amounts = Self::get_supply_amounts(path, target_amount)?;
 		ensure!(amounts[0] <= max_supply_amount, Error::<T>::ExcessiveSupplyAmount);
 		let module_account_id = Self::account_id();
@@ -1509,6 +2693,135 @@ impl<T: Config> Pallet<T> {
 		});
 		Ok(actual_supply_amount)
 	}
+
+	fn ensure_not_flash_swap_locked() -> DispatchResult {
+		ensure!(!Self::flash_swap_locked(), Error::<T>::FlashSwapReentrancy);
+		Ok(())
+	}
+
+	/// The plain swaps can't trade against the revealed swaps of the same block.
+	fn ensure_not_reserved_by_reveal(path: &[CurrencyId]) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		for pair in path.windows(2) {
+			if let Some(trading_pair) = TradingPair::from_currency_ids(pair[0], pair[1]) {
+				ensure!(
+					Self::revealed_trading_pairs(trading_pair) != Some(now),
+					Error::<T>::TradingPairReservedByReveal
+				);
+			}
+		}
+		Ok(())
+	}
+
+	/// The hash committed by `commit_swap`.
+	pub fn swap_commit_hash(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		limit: SwapLimit<Balance>,
+		salt: [u8; 32],
+	) -> H256 {
+		BlakeTwo256::hash_of(&(who, path, limit, salt))
+	}
+
+	/// The block at which the commit of the block `committed_at` expires.
+	fn swap_commit_expiry(committed_at: BlockNumberFor<T>) -> BlockNumberFor<T> {
+		committed_at
+			.saturating_add(T::SwapRevealPeriod::get())
+			.saturating_add(One::one())
+	}
+
+	/// Forfeit `ExpiredSwapCommitPenalty` of the deposit to the treasury and return the rest.
+	fn forfeit_swap_commit(who: &T::AccountId, hash: H256, deposit: Balance) {
+		let native_currency_id = T::GetNativeCurrencyId::get();
+		let penalty = T::ExpiredSwapCommitPenalty::get().mul_floor(deposit);
+		let not_forfeited = T::Currency::repatriate_reserved(
+			native_currency_id,
+			who,
+			&T::TreasuryAccount::get(),
+			penalty,
+			BalanceStatus::Free,
+		)
+		.unwrap_or(penalty);
+		T::Currency::unreserve(native_currency_id, who, deposit.saturating_sub(penalty));
+
+		Self::deposit_event(Event::SwapCommitExpired {
+			who: who.clone(),
+			hash,
+			forfeited: penalty.saturating_sub(not_forfeited),
+		});
+	}
+
+	/// Transfer the exact target amount to `recipient` and call back the contract, which must
+	/// repay the supply amount to the module account. Returns the supply amount.
+	#[transactional]
+	fn do_flash_swap(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		target_amount: Balance,
+		recipient: EvmAddress,
+		callback_data: Vec<u8>,
+		gas_limit: u64,
+		storage_limit: u32,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		Self::ensure_not_flash_swap_locked()?;
+		let amounts = Self::get_supply_amounts(path, target_amount)?;
+		let supply_currency_id = path[0];
+		let target_currency_id = path[path.len() - 1];
+		let supply_token =
+			T::Erc20InfoMapping::encode_evm_address(supply_currency_id).ok_or(Error::<T>::InvalidCurrencyId)?;
+		let target_token =
+			T::Erc20InfoMapping::encode_evm_address(target_currency_id).ok_or(Error::<T>::InvalidCurrencyId)?;
+		let module_account_id = Self::account_id();
+		let module_evm_address = T::EvmAddressMapping::get_or_create_evm_address(&module_account_id);
+		let recipient_account_id = T::EvmAddressMapping::get_account_id(&recipient);
+		let repayment = amounts[0];
+
+		Self::_swap_by_path(path, &amounts)?;
+		T::Currency::transfer(
+			target_currency_id,
+			&module_account_id,
+			&recipient_account_id,
+			target_amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		FlashSwapLocked::<T>::put(true);
+		T::FlashSwapEvmBridge::on_flash_swap(
+			InvokeContext {
+				contract: recipient,
+				sender: module_evm_address,
+				origin: T::EvmAddressMapping::get_or_create_evm_address(who),
+			},
+			supply_token,
+			target_token,
+			target_amount,
+			repayment,
+			recipient,
+			callback_data,
+			gas_limit,
+			storage_limit,
+		)?;
+		FlashSwapLocked::<T>::kill();
+
+		// collect the repayment from the recipient, the other deposits to DEX during the callback
+		// don't count as the repayment
+		T::Currency::transfer(
+			supply_currency_id,
+			&recipient_account_id,
+			&module_account_id,
+			repayment,
+			ExistenceRequirement::AllowDeath,
+		)
+		.map_err(|_| Error::<T>::FlashSwapNotRepaid)?;
+
+		Self::deposit_event(Event::FlashSwap {
+			trader: who.clone(),
+			recipient,
+			path: path.to_vec(),
+			liquidity_changes: amounts,
+		});
+		Ok(repayment)
+	}
 }
 
 impl<T: Config> DEXManager<T::AccountId, Balance, CurrencyId> for Pallet<T> {
@@ -1520,9 +2833,9 @@ impl<T: Config> DEXManager<T::AccountId, Balance, CurrencyId> for Pallet<T> {
 		let trading_pair = TradingPair::from_currency_ids(currency_id_a, currency_id_b)?;
 		match Self::trading_pair_statuses(trading_pair) {
 			TradingPairStatus::<_, _>::Disabled => None,
-			TradingPairStatus::<_, _>::Provisioning(_) | TradingPairStatus::<_, _>::Enabled => {
-				T::Erc20InfoMapping::encode_evm_address(trading_pair.dex_share_currency_id())
-			}
+			TradingPairStatus::<_, _>::Provisioning(_)
+			| TradingPairStatus::<_, _>::Enabled
+			| TradingPairStatus::<_, _>::Paused => T::Erc20InfoMapping::encode_evm_address(trading_pair.dex_share_currency_id()),
 		}
 	}
 
@@ -1724,3 +3037,19 @@ impl<T: Config> DEXBootstrap<T::AccountId, Balance, CurrencyId> for Pallet<T> {
 		Self::do_refund_provision(who, currency_id_a, currency_id_b)
 	}
 }
+
+/// Introduce the `Paused` status of the trading pairs. The new variant is appended to
+/// `TradingPairStatus`, so the encoding of the existing statuses is unchanged and only the
+/// storage version is bumped.
+pub struct AddPausedTradingPairStatus<T>(PhantomData<T>);
+impl<T: Config> frame_support::traits::OnRuntimeUpgrade for AddPausedTradingPairStatus<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
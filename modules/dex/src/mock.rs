@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{
 	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU32, ConstU64, Nothing},
+	traits::{ConstU128, ConstU32, ConstU64, Nothing},
 };
 use frame_system::EnsureSignedBy;
 use module_support::{mocks::MockErc20InfoMapping, SpecificJointsSwap};
@@ -84,15 +84,26 @@ impl orml_tokens::Config for Runtime {
 	type DustRemovalWhitelist = Nothing;
 }
 
+parameter_types! {
+	pub static InstantExitFee: Balance = 0;
+}
+
 pub struct MockDEXIncentives;
 impl DEXIncentives<AccountId, CurrencyId, Balance> for MockDEXIncentives {
 	fn do_deposit_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
 		Tokens::reserve(lp_currency_id, who, amount)
 	}
 
-	fn do_withdraw_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+	fn do_withdraw_dex_share(
+		who: &AccountId,
+		lp_currency_id: CurrencyId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
 		let _ = Tokens::unreserve(lp_currency_id, who, amount);
-		Ok(())
+		// the instant exit fee is kept by CAROL
+		let fee = InstantExitFee::get().min(amount);
+		<Tokens as MultiCurrency<_>>::transfer(lp_currency_id, who, &CAROL, fee)?;
+		Ok(amount - fee)
 	}
 }
 
@@ -123,6 +134,154 @@ impl Happened<(TradingPair, Balance, Balance)> for MockOnLiquidityPoolUpdated {
 	}
 }
 
+parameter_types! {
+	pub static EnabledTradingPairRecord: Vec<(TradingPair, Option<TradingPairListingOf>)> = vec![];
+	pub static DisabledTradingPairRecord: Vec<TradingPair> = vec![];
+}
+
+pub struct MockOnTradingPairEnabled;
+impl OnTradingPairEnabled<TradingPair, TradingPairListingOf> for MockOnTradingPairEnabled {
+	fn on_trading_pair_enabled(trading_pair: &TradingPair, listing: Option<&TradingPairListingOf>) -> DispatchResult {
+		// reject zero interval to simulate a failed hook
+		ensure!(
+			listing.and_then(|listing| listing.average_price_interval) != Some(0),
+			DispatchError::Other("zero interval")
+		);
+		EnabledTradingPairRecord::mutate(|v| v.push((*trading_pair, listing.cloned())));
+		Ok(())
+	}
+}
+
+pub struct MockOnTradingPairDisabled;
+impl OnTradingPairDisabled<TradingPair> for MockOnTradingPairDisabled {
+	fn on_trading_pair_disabled(trading_pair: &TradingPair) -> DispatchResult {
+		DisabledTradingPairRecord::mutate(|v| v.push(*trading_pair));
+		Ok(())
+	}
+}
+
+pub struct MockEvmAddressMapping;
+impl AddressMapping<AccountId> for MockEvmAddressMapping {
+	fn get_account_id(evm: &EvmAddress) -> AccountId {
+		let mut data = [0u8; 16];
+		data.copy_from_slice(&evm[4..20]);
+		AccountId::from_be_bytes(data)
+	}
+	fn get_evm_address(account_id: &AccountId) -> Option<EvmAddress> {
+		Some(Self::get_default_evm_address(account_id))
+	}
+	fn get_or_create_evm_address(account_id: &AccountId) -> EvmAddress {
+		Self::get_default_evm_address(account_id)
+	}
+	fn get_default_evm_address(account_id: &AccountId) -> EvmAddress {
+		let mut data = [0u8; 20];
+		data[4..20].copy_from_slice(&account_id.to_be_bytes());
+		EvmAddress::from(data)
+	}
+	fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool {
+		Self::get_default_evm_address(account_id) == *evm
+	}
+}
+
+/// The behaviors of the flash swap callback, mirrors `FlashSwapArbitrage.sol` in ts-tests.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum FlashSwapCallback {
+	/// Sell the target to `BOB` at the given price of supply per target, then repay.
+	Arbitrage(Balance),
+	/// Repay less than the repayment by the shortfall.
+	Shortfall(Balance),
+	/// Swap the target back on the DEX in the callback.
+	Reenter,
+	/// Buy the repayment from `BOB`, then deposit it to DEX as a provision of the supply with
+	/// `ACA` instead of repaying.
+	Provision,
+}
+
+parameter_types! {
+	pub static FlashSwapCallbackBehavior: FlashSwapCallback = FlashSwapCallback::Arbitrage(1);
+	pub static FlashSwapCallbackRecord: Option<(EvmAddress, EvmAddress, Balance, Balance, EvmAddress, Vec<u8>)> = None;
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
+	pub const TreasuryAccount: AccountId = 10;
+}
+
+pub struct MockFlashSwapEvmBridge;
+impl FlashSwapEvmBridge for MockFlashSwapEvmBridge {
+	fn on_flash_swap(
+		context: InvokeContext,
+		supply_token: EvmAddress,
+		target_token: EvmAddress,
+		target_amount: Balance,
+		repayment: Balance,
+		repay_dest: EvmAddress,
+		data: Vec<u8>,
+		_gas_limit: u64,
+		_storage_limit: u32,
+	) -> DispatchResult {
+		FlashSwapCallbackRecord::mutate(|v| {
+			*v = Some((supply_token, target_token, target_amount, repayment, repay_dest, data))
+		});
+		let contract = MockEvmAddressMapping::get_account_id(&context.contract);
+		let supply_currency_id = MockErc20InfoMapping::decode_evm_address(supply_token).unwrap();
+		let target_currency_id = MockErc20InfoMapping::decode_evm_address(target_token).unwrap();
+		let origin = MockEvmAddressMapping::get_account_id(&context.origin);
+		assert_eq!(repay_dest, context.contract);
+
+		let repaid = match FlashSwapCallbackBehavior::get() {
+			FlashSwapCallback::Arbitrage(price) => {
+				<Tokens as MultiCurrency<_>>::transfer(
+					target_currency_id,
+					&contract,
+					&BOB,
+					target_amount,
+					ExistenceRequirement::AllowDeath,
+				)?;
+				<Tokens as MultiCurrency<_>>::transfer(
+					supply_currency_id,
+					&BOB,
+					&contract,
+					target_amount * price,
+					ExistenceRequirement::AllowDeath,
+				)?;
+				repayment
+			}
+			FlashSwapCallback::Shortfall(shortfall) => repayment - shortfall,
+			FlashSwapCallback::Reenter => {
+				DexModule::swap_with_exact_supply(
+					RuntimeOrigin::signed(contract),
+					vec![target_currency_id, supply_currency_id],
+					target_amount,
+					0,
+				)?;
+				repayment
+			}
+			FlashSwapCallback::Provision => {
+				<Tokens as MultiCurrency<_>>::transfer(
+					supply_currency_id,
+					&BOB,
+					&contract,
+					repayment,
+					ExistenceRequirement::AllowDeath,
+				)?;
+				DexModule::add_provision(RuntimeOrigin::signed(contract), supply_currency_id, ACA, repayment, 0)?;
+				0
+			}
+		};
+
+		// leave the repayment for the DEX to collect, the profit goes to the origin
+		let balance = <Tokens as MultiCurrency<_>>::free_balance(supply_currency_id, &contract);
+		if balance > repaid {
+			<Tokens as MultiCurrency<_>>::transfer(
+				supply_currency_id,
+				&contract,
+				&origin,
+				balance - repaid,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+		Ok(())
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Tokens;
@@ -136,6 +295,18 @@ impl Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<2000>;
 	type OnLiquidityPoolUpdated = MockOnLiquidityPoolUpdated;
+	type OnTradingPairEnabled = MockOnTradingPairEnabled;
+	type OnTradingPairDisabled = MockOnTradingPairDisabled;
+	type EvmAddressMapping = MockEvmAddressMapping;
+	type FlashSwapEvmBridge = MockFlashSwapEvmBridge;
+	type GasToWeight = ();
+	type TreasuryAccount = TreasuryAccount;
+	type MinSwapCommitDeposit = ConstU128<100>;
+	type SwapRevealPeriod = ConstU64<10>;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = ConstU32<2>;
+	type LbpCheckpointPeriod = ConstU64<10>;
+	type MaxLbpPools = ConstU32<2>;
 }
 
 parameter_types! {
@@ -21,12 +21,17 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnInitialize, OnRuntimeUpgrade},
+};
 use mock::{
-	ACAJointSwap, AUSDBTCPair, AUSDDOTPair, AUSDJointSwap, DOTBTCPair, DexModule, ExtBuilder, ListingOrigin, Runtime,
-	RuntimeEvent, RuntimeOrigin, System, Tokens, ACA, ALICE, AUSD, AUSD_DOT_POOL_RECORD, BOB, BTC, CAROL, DOT,
+	ACAJointSwap, AUSDBTCPair, AUSDDOTPair, AUSDJointSwap, DOTBTCPair, DexModule, DisabledTradingPairRecord,
+	EnabledTradingPairRecord, ExtBuilder, FlashSwapCallback, FlashSwapCallbackBehavior, FlashSwapCallbackRecord,
+	ListingOrigin, MockEvmAddressMapping, Runtime, RuntimeEvent, RuntimeOrigin, System, Tokens, ACA, ALICE, AUSD,
+	AUSD_DOT_POOL_RECORD, BOB, BTC, CAROL, DOT,
 };
-use module_support::{Swap, SwapError};
+use module_support::{mocks::MockErc20InfoMapping, Swap, SwapError};
 use orml_traits::MultiReservableCurrency;
 use sp_core::H160;
 use sp_runtime::traits::BadOrigin;
@@ -738,6 +743,150 @@ fn disable_trading_pair_work() {
 	});
 }
 
+#[test]
+fn set_trading_pair_listing_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let listing = TradingPairListing {
+			average_price_interval: Some(1_000),
+			incentive_rewards: vec![(ACA, 100)],
+		};
+
+		assert_noop!(
+			DexModule::set_trading_pair_listing(RuntimeOrigin::signed(ALICE), AUSD, DOT, Some(listing.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			DexModule::set_trading_pair_listing(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				AUSD,
+				Some(listing.clone())
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		assert_ok!(DexModule::set_trading_pair_listing(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			DOT,
+			AUSD,
+			Some(listing.clone())
+		));
+		assert_eq!(
+			DexModule::trading_pair_listings(AUSDDOTPair::get()),
+			Some(listing.clone())
+		);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::TradingPairListingUpdated {
+			trading_pair: AUSDDOTPair::get(),
+			listing: Some(listing.clone()),
+		}));
+
+		assert_ok!(DexModule::set_trading_pair_listing(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			None
+		));
+		assert_eq!(DexModule::trading_pair_listings(AUSDDOTPair::get()), None);
+
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_noop!(
+			DexModule::set_trading_pair_listing(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, DOT, Some(listing)),
+			Error::<Runtime>::AlreadyEnabled
+		);
+	});
+}
+
+#[test]
+fn trading_pair_lifecycle_hooks_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let listing = TradingPairListing {
+			average_price_interval: Some(1_000),
+			incentive_rewards: vec![(ACA, 100)],
+		};
+
+		// enable without listing
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC
+		));
+		assert_eq!(EnabledTradingPairRecord::get(), vec![(AUSDBTCPair::get(), None)]);
+
+		// disable
+		assert_ok!(DexModule::disable_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC
+		));
+		assert_eq!(DisabledTradingPairRecord::get(), vec![AUSDBTCPair::get()]);
+
+		// a failed hook reverts enable, and the listing is kept
+		let invalid_listing = TradingPairListing {
+			average_price_interval: Some(0),
+			incentive_rewards: vec![],
+		};
+		assert_ok!(DexModule::set_trading_pair_listing(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC,
+			Some(invalid_listing.clone())
+		));
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, BTC),
+			DispatchError::Other("zero interval")
+		);
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDBTCPair::get()),
+			TradingPairStatus::<_, _>::Disabled
+		);
+		assert_eq!(
+			DexModule::trading_pair_listings(AUSDBTCPair::get()),
+			Some(invalid_listing)
+		);
+
+		// list -> provision -> end provisioning applies the listing once
+		assert_ok!(DexModule::list_provisioning(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			0,
+		));
+		assert_ok!(DexModule::set_trading_pair_listing(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			Some(listing.clone())
+		));
+		assert_ok!(DexModule::add_provision(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			DOT,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128
+		));
+		assert_ok!(DexModule::end_provisioning(RuntimeOrigin::signed(BOB), AUSD, DOT));
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Enabled
+		);
+		assert_eq!(
+			EnabledTradingPairRecord::get(),
+			vec![(AUSDBTCPair::get(), None), (AUSDDOTPair::get(), Some(listing))]
+		);
+		assert_eq!(DexModule::trading_pair_listings(AUSDDOTPair::get()), None);
+	});
+}
+
 #[test]
 fn on_liquidity_pool_updated_work() {
 	ExtBuilder::default()
@@ -1292,6 +1441,43 @@ fn add_liquidity_work() {
 		});
 }
 
+#[test]
+fn add_and_remove_liquidity_with_zero_amount_is_no_op() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_noop!(
+				DexModule::add_liquidity(RuntimeOrigin::signed(ALICE), AUSD, AUSD, 0, 0, 0, false),
+				Error::<Runtime>::InvalidCurrencyId
+			);
+
+			let post_info = DexModule::add_liquidity(RuntimeOrigin::signed(ALICE), DOT, AUSD, 0, 0, 0, true).unwrap();
+			assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::add_liquidity_no_op()));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::NoOp {
+				who: ALICE,
+				currency_0: AUSD,
+				currency_1: DOT,
+			}));
+
+			let post_info =
+				DexModule::remove_liquidity(RuntimeOrigin::signed(ALICE), DOT, AUSD, 0, 0, 0, true).unwrap();
+			assert_eq!(
+				post_info.actual_weight,
+				Some(<() as WeightInfo>::remove_liquidity_no_op())
+			);
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::NoOp {
+				who: ALICE,
+				currency_0: AUSD,
+				currency_1: DOT,
+			}));
+
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
+		});
+}
+
 #[test]
 fn remove_liquidity_work() {
 	ExtBuilder::default()
@@ -1453,6 +1639,72 @@ fn remove_liquidity_work() {
 		});
 }
 
+#[test]
+fn remove_liquidity_by_unstake_with_instant_exit_fee() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			InstantExitFee::set(1_000_000_000_000);
+			let dex_share_currency_id = AUSDDOTPair::get().dex_share_currency_id();
+
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				AUSD,
+				DOT,
+				5_000_000_000_000,
+				1_000_000_000_000,
+				0,
+				true
+			));
+			assert_eq!(
+				Tokens::reserved_balance(dex_share_currency_id, &BOB),
+				10_000_000_000_000
+			);
+
+			// the minimum withdrawn is checked against the shares left after the fee
+			assert_noop!(
+				DexModule::remove_liquidity(
+					RuntimeOrigin::signed(BOB),
+					AUSD,
+					DOT,
+					2_000_000_000_000,
+					1_000_000_000_000,
+					0,
+					true,
+				),
+				Error::<Runtime>::UnacceptableLiquidityWithdrawn
+			);
+
+			assert_ok!(DexModule::remove_liquidity(
+				RuntimeOrigin::signed(BOB),
+				AUSD,
+				DOT,
+				2_000_000_000_000,
+				0,
+				0,
+				true,
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::RemoveLiquidity {
+				who: BOB,
+				currency_0: AUSD,
+				pool_0: 500_000_000_000,
+				currency_1: DOT,
+				pool_1: 100_000_000_000,
+				share_decrement: 1_000_000_000_000,
+			}));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(4_500_000_000_000, 900_000_000_000)
+			);
+			assert_eq!(Tokens::free_balance(dex_share_currency_id, &BOB), 0);
+			assert_eq!(Tokens::reserved_balance(dex_share_currency_id, &BOB), 8_000_000_000_000);
+			assert_eq!(Tokens::free_balance(dex_share_currency_id, &CAROL), 1_000_000_000_000);
+			assert_eq!(Tokens::total_issuance(dex_share_currency_id), 9_000_000_000_000);
+		});
+}
+
 #[test]
 fn do_swap_with_exact_supply_work() {
 	ExtBuilder::default()
@@ -2041,3 +2293,1448 @@ fn do_swap_should_keep_alive_work() {
 			));
 		});
 }
+
+fn setup_provisioned_trading_pair() -> TradingPair {
+	assert_ok!(DexModule::list_provisioning(
+		RuntimeOrigin::signed(ListingOrigin::get()),
+		AUSD,
+		DOT,
+		1_000_000_000_000u128,
+		1_000_000_000_000u128,
+		5_000_000_000_000u128,
+		2_000_000_000_000u128,
+		10,
+	));
+	assert_ok!(DexModule::add_provision(
+		RuntimeOrigin::signed(ALICE),
+		AUSD,
+		DOT,
+		1_000_000_000_000u128,
+		2_000_000_000_000u128
+	));
+	assert_ok!(DexModule::add_provision(
+		RuntimeOrigin::signed(BOB),
+		AUSD,
+		DOT,
+		3_000_000_000_000u128,
+		1_000_000_000_000u128
+	));
+	AUSDDOTPair::get()
+}
+
+// the provision is injected into the liquidity pool, but the trading pair is still `Provisioning`
+fn seed_stuck_after_injection(trading_pair: TradingPair) {
+	let (total_0, total_1) = DexModule::total_provision(&trading_pair);
+	assert_ok!(DexModule::do_inject_provision(&trading_pair, total_0, total_1));
+}
+
+// the trading pair is `Enabled`, but the provision is not injected into the liquidity pool
+fn seed_stuck_before_injection(trading_pair: TradingPair) {
+	TradingPairStatuses::<Runtime>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+}
+
+// all the currencies of the trading pair are held by the founders and the module account
+fn assert_no_balance_created_or_destroyed(trading_pair: TradingPair, total_issuance: (Balance, Balance)) {
+	for (currency_id, issuance) in [
+		(trading_pair.first(), total_issuance.0),
+		(trading_pair.second(), total_issuance.1),
+	] {
+		assert_eq!(Tokens::total_issuance(currency_id), issuance);
+		assert_eq!(
+			Tokens::free_balance(currency_id, &ALICE)
+				+ Tokens::free_balance(currency_id, &BOB)
+				+ Tokens::free_balance(currency_id, &DexModule::account_id()),
+			issuance
+		);
+	}
+	assert_ok!(DexModule::do_try_state());
+}
+
+#[test]
+fn force_repair_trading_pair_refuses_inconsistent_state() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let trading_pair = setup_provisioned_trading_pair();
+		seed_stuck_after_injection(trading_pair);
+
+		assert_noop!(
+			DexModule::force_repair_trading_pair(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				TradingPairStatus::<_, _>::Enabled
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			DexModule::force_repair_trading_pair(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				TradingPairStatus::<_, _>::Provisioning(ProvisioningParameters {
+					min_contribution: (0, 0),
+					target_provision: (0, 0),
+					accumulated_provision: (4_000_000_000_000u128, 3_000_000_000_000u128),
+					not_before: 0,
+				})
+			),
+			Error::<Runtime>::InvalidRepairTarget
+		);
+
+		// the module account holds more than the trading pairs
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(ALICE),
+			DexModule::account_id(),
+			DOT,
+			1
+		));
+		assert_noop!(
+			DexModule::force_repair_trading_pair(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				TradingPairStatus::<_, _>::Enabled
+			),
+			Error::<Runtime>::InconsistentTradingPair
+		);
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(DexModule::account_id()),
+			ALICE,
+			DOT,
+			1
+		));
+
+		// the dex shares of the unclaimed provision are not held by the module account
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(DexModule::account_id()),
+			ALICE,
+			trading_pair.dex_share_currency_id(),
+			1
+		));
+		assert_noop!(
+			DexModule::force_repair_trading_pair(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				TradingPairStatus::<_, _>::Enabled
+			),
+			Error::<Runtime>::InconsistentTradingPair
+		);
+	});
+}
+
+#[test]
+fn force_repair_trading_pair_stuck_after_injection_work() {
+	// repair to `Enabled`, founders claim their shares
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let trading_pair = setup_provisioned_trading_pair();
+		let total_issuance = (
+			Tokens::total_issuance(trading_pair.first()),
+			Tokens::total_issuance(trading_pair.second()),
+		);
+		seed_stuck_after_injection(trading_pair);
+		let old_status = DexModule::trading_pair_statuses(trading_pair);
+		let share_issuance = Tokens::total_issuance(trading_pair.dex_share_currency_id());
+		assert!(DexModule::do_try_state().is_err());
+
+		assert_ok!(DexModule::force_repair_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			TradingPairStatus::<_, _>::Enabled
+		));
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::TradingPairRepaired {
+			trading_pair,
+			old_status,
+			new_status: TradingPairStatus::<_, _>::Enabled,
+			injected_provision_0: 0,
+			injected_provision_1: 0,
+			issued_share_amount: 0,
+		}));
+		assert_eq!(
+			DexModule::trading_pair_statuses(trading_pair),
+			TradingPairStatus::<_, _>::Enabled
+		);
+		assert_eq!(EnabledTradingPairRecord::get(), vec![(trading_pair, None)]);
+		assert_eq!(
+			Tokens::total_issuance(trading_pair.dex_share_currency_id()),
+			share_issuance
+		);
+		assert_no_balance_created_or_destroyed(trading_pair, total_issuance);
+
+		assert_ok!(DexModule::claim_dex_share(
+			RuntimeOrigin::signed(ALICE),
+			ALICE,
+			AUSD,
+			DOT
+		));
+		assert_ok!(DexModule::claim_dex_share(RuntimeOrigin::signed(BOB), BOB, AUSD, DOT));
+		let alice_share = Tokens::free_balance(trading_pair.dex_share_currency_id(), &ALICE);
+		assert!(alice_share > 0);
+		assert_ok!(DexModule::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			DOT,
+			alice_share,
+			0,
+			0,
+			false
+		));
+		assert_no_balance_created_or_destroyed(trading_pair, total_issuance);
+	});
+
+	// repair to `Disabled`, founders claim their shares and withdraw the liquidity
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let trading_pair = setup_provisioned_trading_pair();
+		let total_issuance = (
+			Tokens::total_issuance(trading_pair.first()),
+			Tokens::total_issuance(trading_pair.second()),
+		);
+		seed_stuck_after_injection(trading_pair);
+
+		assert_ok!(DexModule::force_repair_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			TradingPairStatus::<_, _>::Disabled
+		));
+		assert_eq!(
+			DexModule::trading_pair_statuses(trading_pair),
+			TradingPairStatus::<_, _>::Disabled
+		);
+		assert_eq!(DisabledTradingPairRecord::get(), vec![]);
+		assert_no_balance_created_or_destroyed(trading_pair, total_issuance);
+
+		for who in [ALICE, BOB] {
+			assert_ok!(DexModule::claim_dex_share(RuntimeOrigin::signed(who), who, AUSD, DOT));
+			let share = Tokens::free_balance(trading_pair.dex_share_currency_id(), &who);
+			assert_ok!(DexModule::remove_liquidity(
+				RuntimeOrigin::signed(who),
+				AUSD,
+				DOT,
+				share,
+				0,
+				0,
+				false
+			));
+		}
+		assert_eq!(DexModule::liquidity_pool(trading_pair), (0, 0));
+		assert_no_balance_created_or_destroyed(trading_pair, total_issuance);
+	});
+}
+
+#[test]
+fn force_repair_trading_pair_stuck_before_injection_work() {
+	// repair to `Enabled`, the provision is injected into the liquidity pool
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let trading_pair = setup_provisioned_trading_pair();
+		let total_issuance = (
+			Tokens::total_issuance(trading_pair.first()),
+			Tokens::total_issuance(trading_pair.second()),
+		);
+		let (total_0, total_1) = DexModule::total_provision(&trading_pair);
+		assert_eq!(DexModule::transitional_trading_pairs(), 0);
+		seed_stuck_before_injection(trading_pair);
+		assert!(DexModule::do_try_state().is_err());
+		assert_eq!(DexModule::transitional_trading_pairs(), 1);
+
+		assert_ok!(DexModule::force_repair_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			TradingPairStatus::<_, _>::Enabled
+		));
+		let share_issuance = Tokens::total_issuance(trading_pair.dex_share_currency_id());
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::TradingPairRepaired {
+			trading_pair,
+			old_status: TradingPairStatus::<_, _>::Enabled,
+			new_status: TradingPairStatus::<_, _>::Enabled,
+			injected_provision_0: total_0,
+			injected_provision_1: total_1,
+			issued_share_amount: share_issuance,
+		}));
+		assert_eq!(DexModule::liquidity_pool(trading_pair), (total_0, total_1));
+		assert_ne!(
+			DexModule::initial_share_exchange_rates(trading_pair),
+			Default::default()
+		);
+		// the trading pair has been `Enabled`, the hook is not called again
+		assert_eq!(EnabledTradingPairRecord::get(), vec![]);
+		assert_no_balance_created_or_destroyed(trading_pair, total_issuance);
+		assert_eq!(DexModule::transitional_trading_pairs(), 0);
+
+		assert_ok!(DexModule::claim_dex_share(
+			RuntimeOrigin::signed(ALICE),
+			ALICE,
+			AUSD,
+			DOT
+		));
+		assert_ok!(DexModule::claim_dex_share(RuntimeOrigin::signed(BOB), BOB, AUSD, DOT));
+		assert!(Tokens::free_balance(trading_pair.dex_share_currency_id(), &ALICE) > 0);
+		assert!(Tokens::free_balance(trading_pair.dex_share_currency_id(), &BOB) > 0);
+		assert_no_balance_created_or_destroyed(trading_pair, total_issuance);
+	});
+
+	// repair to `Disabled`, founders get their provision refunded
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let alice_balances = (Tokens::free_balance(AUSD, &ALICE), Tokens::free_balance(DOT, &ALICE));
+		let bob_balances = (Tokens::free_balance(AUSD, &BOB), Tokens::free_balance(DOT, &BOB));
+		let trading_pair = setup_provisioned_trading_pair();
+		let total_issuance = (
+			Tokens::total_issuance(trading_pair.first()),
+			Tokens::total_issuance(trading_pair.second()),
+		);
+		seed_stuck_before_injection(trading_pair);
+
+		assert_ok!(DexModule::force_repair_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			TradingPairStatus::<_, _>::Disabled
+		));
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::TradingPairRepaired {
+			trading_pair,
+			old_status: TradingPairStatus::<_, _>::Enabled,
+			new_status: TradingPairStatus::<_, _>::Disabled,
+			injected_provision_0: 0,
+			injected_provision_1: 0,
+			issued_share_amount: 0,
+		}));
+		assert_eq!(DisabledTradingPairRecord::get(), vec![trading_pair]);
+		assert_eq!(Tokens::total_issuance(trading_pair.dex_share_currency_id()), 0);
+		assert_no_balance_created_or_destroyed(trading_pair, total_issuance);
+
+		assert_ok!(DexModule::refund_provision(
+			RuntimeOrigin::signed(ALICE),
+			ALICE,
+			AUSD,
+			DOT
+		));
+		assert_ok!(DexModule::refund_provision(RuntimeOrigin::signed(BOB), BOB, AUSD, DOT));
+		assert_eq!(
+			(Tokens::free_balance(AUSD, &ALICE), Tokens::free_balance(DOT, &ALICE)),
+			alice_balances
+		);
+		assert_eq!(
+			(Tokens::free_balance(AUSD, &BOB), Tokens::free_balance(DOT, &BOB)),
+			bob_balances
+		);
+		assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 0);
+		assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 0);
+		assert_no_balance_created_or_destroyed(trading_pair, total_issuance);
+	});
+}
+
+const FLASH_SWAP_CONTRACT: mock::AccountId = 100;
+
+#[test]
+fn flash_swap_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			FlashSwapCallbackBehavior::set(FlashSwapCallback::Arbitrage(1));
+			let recipient = MockEvmAddressMapping::get_default_evm_address(&FLASH_SWAP_CONTRACT);
+			let amounts = DexModule::get_supply_amounts(&[AUSD, DOT], 1_000).unwrap();
+			let repayment = amounts[0];
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_000_000, 2_000_000));
+			let module_ausd = Tokens::free_balance(AUSD, &DexModule::account_id());
+			let module_dot = Tokens::free_balance(DOT, &DexModule::account_id());
+			let alice_ausd = Tokens::free_balance(AUSD, &ALICE);
+
+			assert_ok!(DexModule::flash_swap(
+				RuntimeOrigin::signed(ALICE),
+				vec![AUSD, DOT],
+				1_000,
+				recipient,
+				vec![1, 2, 3],
+				100_000,
+				0,
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::FlashSwap {
+				trader: ALICE,
+				recipient,
+				path: vec![AUSD, DOT],
+				liquidity_changes: amounts,
+			}));
+			assert_eq!(
+				FlashSwapCallbackRecord::get(),
+				Some((
+					MockErc20InfoMapping::encode_evm_address(AUSD).unwrap(),
+					MockErc20InfoMapping::encode_evm_address(DOT).unwrap(),
+					1_000,
+					repayment,
+					recipient,
+					vec![1, 2, 3],
+				))
+			);
+
+			// the fee is included in the repayment
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(1_000_000 + repayment, 2_000_000 - 1_000)
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSD, &DexModule::account_id()),
+				module_ausd + repayment
+			);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), module_dot - 1_000);
+			// the repayment is collected from the contract, and the arbitrage profit is sent to the
+			// trader
+			assert_eq!(Tokens::free_balance(AUSD, &FLASH_SWAP_CONTRACT), 0);
+			assert_eq!(Tokens::free_balance(DOT, &FLASH_SWAP_CONTRACT), 0);
+			assert_eq!(Tokens::free_balance(AUSD, &ALICE), alice_ausd + 1_000 - repayment);
+			assert!(!DexModule::flash_swap_locked());
+		});
+}
+
+#[test]
+fn flash_swap_fails_if_not_repaid() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let recipient = MockEvmAddressMapping::get_default_evm_address(&FLASH_SWAP_CONTRACT);
+			assert_ok!(Tokens::deposit(AUSD, &FLASH_SWAP_CONTRACT, 10_000));
+
+			FlashSwapCallbackBehavior::set(FlashSwapCallback::Shortfall(1));
+			assert_noop!(
+				DexModule::flash_swap(
+					RuntimeOrigin::signed(ALICE),
+					vec![AUSD, DOT],
+					1_000,
+					recipient,
+					vec![],
+					100_000,
+					0,
+				),
+				Error::<Runtime>::FlashSwapNotRepaid
+			);
+
+			FlashSwapCallbackBehavior::set(FlashSwapCallback::Shortfall(0));
+			assert_ok!(DexModule::flash_swap(
+				RuntimeOrigin::signed(ALICE),
+				vec![AUSD, DOT],
+				1_000,
+				recipient,
+				vec![],
+				100_000,
+				0,
+			));
+		});
+}
+
+#[test]
+fn flash_swap_cannot_be_repaid_by_provision() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let recipient = MockEvmAddressMapping::get_default_evm_address(&FLASH_SWAP_CONTRACT);
+			assert_ok!(DexModule::list_provisioning(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				ACA,
+				10,
+				10,
+				1_000_000,
+				1_000_000,
+				10,
+			));
+
+			// the provision raises the AUSD balance of DEX as much as the repayment
+			FlashSwapCallbackBehavior::set(FlashSwapCallback::Provision);
+			assert_noop!(
+				DexModule::flash_swap(
+					RuntimeOrigin::signed(ALICE),
+					vec![AUSD, DOT],
+					1_000,
+					recipient,
+					vec![],
+					100_000,
+					0,
+				),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert_eq!(
+				DexModule::provisioning_pool(TradingPair::from_currency_ids(AUSD, ACA).unwrap(), FLASH_SWAP_CONTRACT),
+				(0, 0)
+			);
+			assert!(!DexModule::flash_swap_locked());
+		});
+}
+
+#[test]
+fn flash_swap_rejects_reentrancy() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let recipient = MockEvmAddressMapping::get_default_evm_address(&FLASH_SWAP_CONTRACT);
+
+			FlashSwapCallbackBehavior::set(FlashSwapCallback::Reenter);
+			assert_noop!(
+				DexModule::flash_swap(
+					RuntimeOrigin::signed(ALICE),
+					vec![AUSD, DOT],
+					1_000,
+					recipient,
+					vec![],
+					100_000,
+					0,
+				),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert!(!DexModule::flash_swap_locked());
+
+			// swaps and liquidity changes are rejected during the callback
+			FlashSwapLocked::<Runtime>::put(true);
+			assert_noop!(
+				DexModule::swap_with_exact_supply(RuntimeOrigin::signed(BOB), vec![DOT, AUSD], 1_000, 0),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_target(RuntimeOrigin::signed(BOB), vec![DOT, AUSD], 1_000, 10_000),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert_noop!(
+				DexModule::add_liquidity(RuntimeOrigin::signed(BOB), AUSD, DOT, 1_000, 2_000, 0, false),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert_noop!(
+				DexModule::remove_liquidity(RuntimeOrigin::signed(ALICE), AUSD, DOT, 1_000, 0, 0, false),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert_noop!(
+				DexModule::add_provision(RuntimeOrigin::signed(BOB), AUSD, ACA, 1_000, 0),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert_noop!(
+				DexModule::claim_dex_share(RuntimeOrigin::signed(BOB), BOB, AUSD, ACA),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert_noop!(
+				DexModule::refund_provision(RuntimeOrigin::signed(BOB), BOB, AUSD, ACA),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+			assert_noop!(
+				DexModule::flash_swap(
+					RuntimeOrigin::signed(BOB),
+					vec![AUSD, DOT],
+					1_000,
+					recipient,
+					vec![],
+					100_000,
+					0,
+				),
+				Error::<Runtime>::FlashSwapReentrancy
+			);
+		});
+}
+
+#[test]
+fn reveal_swap_executes_within_committed_bounds_and_blocks_back_run() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let quote = DexModule::get_target_amounts(&[AUSD, DOT], 10_000).unwrap()[1];
+			// accept 1% slippage from the quote
+			let limit = SwapLimit::ExactSupply(10_000, quote * 99 / 100);
+			let salt = [1u8; 32];
+			let hash = DexModule::swap_commit_hash(&BOB, &[AUSD, DOT], limit, salt);
+
+			assert_ok!(DexModule::commit_swap(RuntimeOrigin::signed(BOB), hash, 100));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::SwapCommitted {
+				who: BOB,
+				hash,
+				deposit: 100,
+			}));
+			assert_eq!(Tokens::reserved_balance(ACA, &BOB), 100);
+
+			System::set_block_number(2);
+			// the front-run swap in the same block moves the price
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(ALICE),
+				vec![AUSD, DOT],
+				1_000,
+				0,
+			));
+			let bob_dot = Tokens::free_balance(DOT, &BOB);
+			assert_ok!(DexModule::reveal_swap(
+				RuntimeOrigin::signed(BOB),
+				vec![AUSD, DOT],
+				limit,
+				salt
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::SwapRevealed { who: BOB, hash }));
+			assert!(Tokens::free_balance(DOT, &BOB) - bob_dot >= quote * 99 / 100);
+			assert_eq!(Tokens::reserved_balance(ACA, &BOB), 0);
+			assert_eq!(DexModule::swap_commits(BOB, hash), None);
+			assert!(DexModule::swap_commit_expiries(12).is_empty());
+
+			// the back-run swaps on the revealed trading pair are rejected in the same block
+			assert_noop!(
+				DexModule::swap_with_exact_supply(RuntimeOrigin::signed(ALICE), vec![DOT, AUSD], 1_000, 0),
+				Error::<Runtime>::TradingPairReservedByReveal
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_target(RuntimeOrigin::signed(ALICE), vec![BTC, DOT, AUSD], 1_000, 10_000),
+				Error::<Runtime>::TradingPairReservedByReveal
+			);
+			// the other trading pairs are not affected
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(ALICE),
+				vec![DOT, BTC],
+				1_000,
+				0,
+			));
+
+			System::set_block_number(3);
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(ALICE),
+				vec![DOT, AUSD],
+				1_000,
+				0,
+			));
+		});
+}
+
+#[test]
+fn reveal_swap_fails_if_front_run_exceeds_bounds() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let quote = DexModule::get_target_amounts(&[AUSD, DOT], 10_000).unwrap()[1];
+			let limit = SwapLimit::ExactSupply(10_000, quote * 99 / 100);
+			let salt = [1u8; 32];
+			let hash = DexModule::swap_commit_hash(&BOB, &[AUSD, DOT], limit, salt);
+			assert_ok!(DexModule::commit_swap(RuntimeOrigin::signed(BOB), hash, 100));
+
+			System::set_block_number(2);
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(ALICE),
+				vec![AUSD, DOT],
+				100_000,
+				0,
+			));
+			assert_noop!(
+				DexModule::reveal_swap(RuntimeOrigin::signed(BOB), vec![AUSD, DOT], limit, salt),
+				Error::<Runtime>::InsufficientTargetAmount
+			);
+			// the commit is kept for the rest of the reveal period
+			assert!(DexModule::swap_commits(BOB, hash).is_some());
+			assert_eq!(Tokens::reserved_balance(ACA, &BOB), 100);
+		});
+}
+
+#[test]
+fn reveal_swap_rejects_invalid_reveal() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let limit = SwapLimit::ExactTarget(10_000, 1_000);
+			let salt = [1u8; 32];
+			let hash = DexModule::swap_commit_hash(&BOB, &[AUSD, DOT], limit, salt);
+			assert_ok!(DexModule::commit_swap(RuntimeOrigin::signed(BOB), hash, 100));
+
+			assert_noop!(
+				DexModule::reveal_swap(RuntimeOrigin::signed(BOB), vec![AUSD, DOT], limit, salt),
+				Error::<Runtime>::RevealTooEarly
+			);
+
+			System::set_block_number(2);
+			assert_noop!(
+				DexModule::reveal_swap(RuntimeOrigin::signed(BOB), vec![AUSD, DOT], limit, [2u8; 32]),
+				Error::<Runtime>::SwapCommitNotFound
+			);
+			assert_noop!(
+				DexModule::reveal_swap(
+					RuntimeOrigin::signed(BOB),
+					vec![AUSD, DOT],
+					SwapLimit::ExactTarget(10_000, 2_000),
+					salt
+				),
+				Error::<Runtime>::SwapCommitNotFound
+			);
+			assert_noop!(
+				DexModule::reveal_swap(RuntimeOrigin::signed(ALICE), vec![AUSD, DOT], limit, salt),
+				Error::<Runtime>::SwapCommitNotFound
+			);
+			assert_ok!(DexModule::reveal_swap(
+				RuntimeOrigin::signed(BOB),
+				vec![AUSD, DOT],
+				limit,
+				salt
+			));
+		});
+}
+
+#[test]
+fn commit_swap_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			DexModule::commit_swap(RuntimeOrigin::signed(BOB), H256::repeat_byte(1), 99),
+			Error::<Runtime>::InsufficientSwapCommitDeposit
+		);
+		assert_ok!(DexModule::commit_swap(
+			RuntimeOrigin::signed(BOB),
+			H256::repeat_byte(1),
+			100
+		));
+		assert_noop!(
+			DexModule::commit_swap(RuntimeOrigin::signed(BOB), H256::repeat_byte(1), 100),
+			Error::<Runtime>::SwapCommitExists
+		);
+		assert_ok!(DexModule::commit_swap(
+			RuntimeOrigin::signed(ALICE),
+			H256::repeat_byte(1),
+			100
+		));
+		assert_noop!(
+			DexModule::commit_swap(RuntimeOrigin::signed(BOB), H256::repeat_byte(2), 100),
+			Error::<Runtime>::TooManySwapCommits
+		);
+		assert_eq!(
+			DexModule::swap_commit_expiries(12).into_inner(),
+			vec![(BOB, H256::repeat_byte(1)), (ALICE, H256::repeat_byte(1))]
+		);
+	});
+}
+
+#[test]
+fn expired_swap_commit_forfeits_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let treasury = mock::TreasuryAccount::get();
+		let bob_aca = Tokens::free_balance(ACA, &BOB);
+		assert_ok!(DexModule::commit_swap(
+			RuntimeOrigin::signed(BOB),
+			H256::repeat_byte(1),
+			1_000
+		));
+
+		// the commit can be revealed until the end of the reveal period
+		DexModule::on_initialize(11);
+		assert!(DexModule::swap_commits(BOB, H256::repeat_byte(1)).is_some());
+
+		DexModule::on_initialize(12);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::SwapCommitExpired {
+			who: BOB,
+			hash: H256::repeat_byte(1),
+			forfeited: 100,
+		}));
+		assert_eq!(DexModule::swap_commits(BOB, H256::repeat_byte(1)), None);
+		assert!(DexModule::swap_commit_expiries(12).is_empty());
+		assert_eq!(Tokens::reserved_balance(ACA, &BOB), 0);
+		assert_eq!(Tokens::free_balance(ACA, &BOB), bob_aca - 100);
+		assert_eq!(Tokens::free_balance(ACA, &treasury), 100);
+	});
+}
+
+#[test]
+fn pause_trading_pair_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::pause_trading_pair(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, DOT),
+			Error::<Runtime>::MustBeEnabled
+		);
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+
+		assert_noop!(
+			DexModule::pause_trading_pair(RuntimeOrigin::signed(ALICE), AUSD, DOT),
+			BadOrigin
+		);
+		assert_ok!(DexModule::pause_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Paused
+		);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::PauseTradingPair {
+			trading_pair: AUSDDOTPair::get(),
+		}));
+
+		assert_noop!(
+			DexModule::pause_trading_pair(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, DOT),
+			Error::<Runtime>::MustBeEnabled
+		);
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, DOT),
+			Error::<Runtime>::TradingPairPaused
+		);
+		assert_noop!(
+			DexModule::set_trading_pair_listing(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, DOT, None),
+			Error::<Runtime>::AlreadyEnabled
+		);
+
+		assert_noop!(
+			DexModule::unpause_trading_pair(RuntimeOrigin::signed(ALICE), AUSD, DOT),
+			BadOrigin
+		);
+		assert_ok!(DexModule::unpause_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Enabled
+		);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::UnpauseTradingPair {
+			trading_pair: AUSDDOTPair::get(),
+		}));
+		assert_noop!(
+			DexModule::unpause_trading_pair(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, DOT),
+			Error::<Runtime>::MustBePaused
+		);
+
+		// a paused trading pair can be disabled
+		assert_ok!(DexModule::pause_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_ok!(DexModule::disable_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Disabled
+		);
+		assert_eq!(DisabledTradingPairRecord::get(), vec![AUSDDOTPair::get()]);
+	});
+}
+
+#[test]
+fn paused_trading_pair_rejects_swaps_and_allows_withdrawal() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.initialize_added_liquidity_pools(ALICE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert!(DexModule::get_swap_amount(&[AUSD, DOT, BTC], SwapLimit::ExactSupply(1_000, 0)).is_some());
+			assert_ok!(DexModule::pause_trading_pair(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				DOT,
+				BTC
+			));
+
+			// the swaps through the paused trading pair are rejected
+			assert_noop!(
+				DexModule::swap_with_exact_supply(RuntimeOrigin::signed(BOB), vec![AUSD, DOT, BTC], 1_000, 0),
+				Error::<Runtime>::TradingPairPaused
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_target(RuntimeOrigin::signed(BOB), vec![AUSD, DOT, BTC], 1_000, 10_000),
+				Error::<Runtime>::TradingPairPaused
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_supply(RuntimeOrigin::signed(BOB), vec![DOT, BTC], 1_000, 0),
+				Error::<Runtime>::TradingPairPaused
+			);
+			assert_eq!(
+				DexModule::get_swap_amount(&[AUSD, DOT, BTC], SwapLimit::ExactSupply(1_000, 0)),
+				None
+			);
+			assert_eq!(
+				DexModule::get_best_price_swap_path(AUSD, BTC, SwapLimit::ExactSupply(1_000, 0), vec![vec![DOT]])
+					.map(|(path, _, _)| path),
+				Some(vec![AUSD, BTC])
+			);
+
+			// the other trading pairs are not affected
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(BOB),
+				vec![AUSD, DOT],
+				1_000,
+				0
+			));
+
+			// adding liquidity is rejected
+			assert_noop!(
+				DexModule::add_liquidity(RuntimeOrigin::signed(BOB), DOT, BTC, 1_000, 2_000, 0, false),
+				Error::<Runtime>::TradingPairPaused
+			);
+
+			// LPs can withdraw
+			let dex_share_currency_id = DOTBTCPair::get().dex_share_currency_id();
+			let share = Tokens::free_balance(dex_share_currency_id, &ALICE);
+			let pool = DexModule::liquidity_pool(DOTBTCPair::get());
+			assert_ok!(DexModule::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				BTC,
+				share,
+				0,
+				0,
+				false,
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::RemoveLiquidity {
+				who: ALICE,
+				currency_0: DOTBTCPair::get().first(),
+				pool_0: pool.0,
+				currency_1: DOTBTCPair::get().second(),
+				pool_1: pool.1,
+				share_decrement: share,
+			}));
+			assert_eq!(DexModule::liquidity_pool(DOTBTCPair::get()), (0, 0));
+			assert_eq!(Tokens::free_balance(dex_share_currency_id, &ALICE), 0);
+
+			// routable again after unpaused
+			assert_ok!(DexModule::unpause_trading_pair(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				DOT,
+				BTC
+			));
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000,
+				2_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(BOB),
+				vec![AUSD, DOT, BTC],
+				1_000,
+				0
+			));
+		});
+}
+
+#[test]
+fn add_paused_trading_pair_status_migration_works() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			StorageVersion::new(0).put::<DexModule>();
+
+			AddPausedTradingPairStatus::<Runtime>::on_runtime_upgrade();
+			assert_eq!(StorageVersion::get::<DexModule>(), 1);
+			assert_eq!(
+				DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+				TradingPairStatus::<_, _>::Enabled
+			);
+		});
+}
+
+const LBP_PROJECT_RESERVE: Balance = 1_000_000_000_000_000;
+const LBP_BASE_RESERVE: Balance = 100_000_000_000_000;
+
+fn create_funded_lbp(end_weight: Permill, end_action: LbpEndAction) -> TradingPair {
+	assert_ok!(DexModule::create_lbp(
+		RuntimeOrigin::signed(ListingOrigin::get()),
+		BOB,
+		DOT,
+		AUSD,
+		Permill::from_percent(90),
+		end_weight,
+		10,
+		110,
+		end_action,
+	));
+	assert_ok!(DexModule::fund_lbp(
+		RuntimeOrigin::signed(BOB),
+		DOT,
+		AUSD,
+		LBP_PROJECT_RESERVE,
+		LBP_BASE_RESERVE
+	));
+	AUSDDOTPair::get()
+}
+
+fn lbp_target_amount_formula(
+	supply_reserve: Balance,
+	supply_weight: Permill,
+	target_reserve: Balance,
+	target_weight: Permill,
+	supply_amount: Balance,
+) -> f64 {
+	let supply_amount_with_fee = supply_amount as f64 * 0.99;
+	let exponent = supply_weight.deconstruct() as f64 / target_weight.deconstruct() as f64;
+	target_reserve as f64
+		* (1.0 - (supply_reserve as f64 / (supply_reserve as f64 + supply_amount_with_fee)).powf(exponent))
+}
+
+fn lbp_supply_amount_formula(
+	supply_reserve: Balance,
+	supply_weight: Permill,
+	target_reserve: Balance,
+	target_weight: Permill,
+	target_amount: Balance,
+) -> f64 {
+	let exponent = target_weight.deconstruct() as f64 / supply_weight.deconstruct() as f64;
+	supply_reserve as f64 * ((target_reserve as f64 / (target_reserve - target_amount) as f64).powf(exponent) - 1.0)
+		/ 0.99
+}
+
+fn assert_approx_eq(actual: Balance, expected: f64) {
+	let error = (actual as f64 - expected).abs() / expected;
+	assert!(error < 1e-9, "actual: {}, expected: {}", actual, expected);
+}
+
+#[test]
+fn create_lbp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::create_lbp(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				DOT,
+				AUSD,
+				Permill::from_percent(90),
+				Permill::from_percent(50),
+				10,
+				110,
+				LbpEndAction::ConvertToDex,
+			),
+			BadOrigin
+		);
+		for (start_weight, end_weight, start, end) in [
+			(Permill::zero(), Permill::from_percent(50), 10, 110),
+			(Permill::from_percent(90), Permill::from_percent(100), 10, 110),
+			(Permill::from_percent(90), Permill::from_percent(50), 0, 110),
+			(Permill::from_percent(90), Permill::from_percent(50), 10, 10),
+		] {
+			assert_noop!(
+				DexModule::create_lbp(
+					RuntimeOrigin::signed(ListingOrigin::get()),
+					BOB,
+					DOT,
+					AUSD,
+					start_weight,
+					end_weight,
+					start,
+					end,
+					LbpEndAction::ConvertToDex,
+				),
+				Error::<Runtime>::InvalidLbpParameters
+			);
+		}
+
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC
+		));
+		assert_noop!(
+			DexModule::create_lbp(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				BOB,
+				BTC,
+				AUSD,
+				Permill::from_percent(90),
+				Permill::from_percent(50),
+				10,
+				110,
+				LbpEndAction::ConvertToDex,
+			),
+			Error::<Runtime>::MustBeDisabled
+		);
+
+		assert_ok!(DexModule::create_lbp(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			BOB,
+			DOT,
+			AUSD,
+			Permill::from_percent(90),
+			Permill::from_percent(50),
+			10,
+			110,
+			LbpEndAction::ConvertToDex,
+		));
+		let pool = LbpPool {
+			owner: BOB,
+			project_currency: DOT,
+			base_currency: AUSD,
+			project_reserve: 0,
+			base_reserve: 0,
+			start_weight: Permill::from_percent(90),
+			end_weight: Permill::from_percent(50),
+			start: 10,
+			end: 110,
+			end_action: LbpEndAction::ConvertToDex,
+		};
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::LbpCreated {
+			trading_pair: AUSDDOTPair::get(),
+			pool: pool.clone(),
+		}));
+		assert_eq!(DexModule::lbp_pools(AUSDDOTPair::get()), Some(pool));
+
+		assert_noop!(
+			DexModule::create_lbp(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				ALICE,
+				AUSD,
+				DOT,
+				Permill::from_percent(90),
+				Permill::from_percent(50),
+				10,
+				110,
+				LbpEndAction::Dissolve,
+			),
+			Error::<Runtime>::LbpExists
+		);
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, DOT),
+			Error::<Runtime>::LbpExists
+		);
+		assert_noop!(
+			DexModule::list_provisioning(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				1,
+				1,
+				100,
+				100,
+				0
+			),
+			Error::<Runtime>::LbpExists
+		);
+
+		assert_ok!(DexModule::create_lbp(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			BOB,
+			BTC,
+			DOT,
+			Permill::from_percent(90),
+			Permill::from_percent(50),
+			10,
+			110,
+			LbpEndAction::ConvertToDex,
+		));
+		assert_noop!(
+			DexModule::create_lbp(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				BOB,
+				ACA,
+				DOT,
+				Permill::from_percent(90),
+				Permill::from_percent(50),
+				10,
+				110,
+				LbpEndAction::ConvertToDex,
+			),
+			Error::<Runtime>::TooManyLbpPools
+		);
+	});
+}
+
+#[test]
+fn fund_lbp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::fund_lbp(RuntimeOrigin::signed(BOB), DOT, AUSD, 1_000, 100),
+			Error::<Runtime>::LbpNotFound
+		);
+		let trading_pair = create_funded_lbp(Permill::from_percent(50), LbpEndAction::ConvertToDex);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::LbpFunded {
+			trading_pair,
+			project_amount: LBP_PROJECT_RESERVE,
+			base_amount: LBP_BASE_RESERVE,
+		}));
+
+		assert_noop!(
+			DexModule::fund_lbp(RuntimeOrigin::signed(ALICE), DOT, AUSD, 1_000, 100),
+			Error::<Runtime>::NotLbpOwner
+		);
+		assert_ok!(DexModule::fund_lbp(RuntimeOrigin::signed(BOB), AUSD, DOT, 1_000, 100));
+		let pool = DexModule::lbp_pools(trading_pair).unwrap();
+		assert_eq!(pool.project_reserve, LBP_PROJECT_RESERVE + 1_000);
+		assert_eq!(pool.base_reserve, LBP_BASE_RESERVE + 100);
+		assert_eq!(
+			Tokens::free_balance(DOT, &DexModule::lbp_account_id()),
+			LBP_PROJECT_RESERVE + 1_000
+		);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &DexModule::lbp_account_id()),
+			LBP_BASE_RESERVE + 100
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			DexModule::fund_lbp(RuntimeOrigin::signed(BOB), DOT, AUSD, 1_000, 100),
+			Error::<Runtime>::LbpStarted
+		);
+	});
+}
+
+#[test]
+fn lbp_swap_price_follows_weight_formula() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let trading_pair = create_funded_lbp(Permill::from_percent(50), LbpEndAction::ConvertToDex);
+
+		assert_noop!(
+			DexModule::swap_with_lbp(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				SwapLimit::ExactSupply(1_000_000_000_000, 0)
+			),
+			Error::<Runtime>::LbpNotInWindow
+		);
+
+		for now in [10, 35, 60, 85, 109] {
+			System::set_block_number(now);
+			let pool = DexModule::lbp_pools(trading_pair).unwrap();
+			let project_weight = DexModule::lbp_project_weight(&pool, now);
+			let base_weight = Permill::one() - project_weight;
+			assert_eq!(
+				project_weight,
+				Permill::from_parts(900_000 - 400_000 * (now as u32 - 10) / 100)
+			);
+
+			// the LBP is swapped directly, never routed through by the DEX.
+			assert_eq!(
+				DexModule::get_swap_amount(&[AUSD, DOT], SwapLimit::ExactSupply(1_000_000_000_000, 0)),
+				None
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_supply(RuntimeOrigin::signed(ALICE), vec![AUSD, DOT], 1_000_000_000_000, 0),
+				Error::<Runtime>::MustBeEnabled
+			);
+
+			let (_, target_amount) =
+				DexModule::get_lbp_swap_amount(AUSD, DOT, SwapLimit::ExactSupply(1_000_000_000_000, 0)).unwrap();
+			assert_approx_eq(
+				target_amount,
+				lbp_target_amount_formula(
+					pool.base_reserve,
+					base_weight,
+					pool.project_reserve,
+					project_weight,
+					1_000_000_000_000,
+				),
+			);
+
+			let (supply_amount, _) =
+				DexModule::get_lbp_swap_amount(AUSD, DOT, SwapLimit::ExactTarget(Balance::MAX, 10_000_000_000_000))
+					.unwrap();
+			let expected_supply_amount = lbp_supply_amount_formula(
+				pool.base_reserve,
+				base_weight,
+				pool.project_reserve,
+				project_weight,
+				10_000_000_000_000,
+			);
+			assert!(supply_amount as f64 >= expected_supply_amount);
+			assert_approx_eq(supply_amount, expected_supply_amount);
+
+			let alice_dot = Tokens::free_balance(DOT, &ALICE);
+			assert_ok!(DexModule::swap_with_lbp(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				SwapLimit::ExactSupply(1_000_000_000_000, target_amount)
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::LbpSwap {
+				trader: ALICE,
+				trading_pair,
+				supply_currency_id: AUSD,
+				supply_amount: 1_000_000_000_000,
+				target_currency_id: DOT,
+				target_amount,
+			}));
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), alice_dot + target_amount);
+
+			let new_pool = DexModule::lbp_pools(trading_pair).unwrap();
+			assert_eq!(new_pool.base_reserve, pool.base_reserve + 1_000_000_000_000);
+			assert_eq!(new_pool.project_reserve, pool.project_reserve - target_amount);
+		}
+
+		assert_noop!(
+			DexModule::swap_with_lbp(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				SwapLimit::ExactSupply(1_000_000_000_000, Balance::MAX)
+			),
+			Error::<Runtime>::InsufficientTargetAmount
+		);
+		assert_noop!(
+			DexModule::swap_with_lbp(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				SwapLimit::ExactTarget(1, 10_000_000_000_000)
+			),
+			Error::<Runtime>::ExcessiveSupplyAmount
+		);
+
+		System::set_block_number(110);
+		assert_noop!(
+			DexModule::swap_with_lbp(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				SwapLimit::ExactSupply(1_000_000_000_000, 0)
+			),
+			Error::<Runtime>::LbpNotInWindow
+		);
+	});
+}
+
+#[test]
+fn lbp_weight_checkpoint_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let trading_pair = create_funded_lbp(Permill::from_percent(50), LbpEndAction::ConvertToDex);
+
+		System::reset_events();
+		DexModule::on_initialize(25);
+		assert!(System::events().is_empty());
+
+		DexModule::on_initialize(30);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::LbpWeightCheckpoint {
+			trading_pair,
+			project_weight: Permill::from_percent(82),
+			project_reserve: LBP_PROJECT_RESERVE,
+			base_reserve: LBP_BASE_RESERVE,
+		}));
+	});
+}
+
+#[test]
+fn lbp_converts_into_liquidity_pool_at_end() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let trading_pair = create_funded_lbp(Permill::from_percent(40), LbpEndAction::ConvertToDex);
+		let bob_ausd = Tokens::free_balance(AUSD, &BOB);
+		let bob_dot = Tokens::free_balance(DOT, &BOB);
+
+		System::set_block_number(110);
+		DexModule::on_initialize(110);
+
+		// the spot price at the end is `(base_reserve / 60%) / (project_reserve / 40%)`, the
+		// surplus of the base currency is returned to the owner.
+		let base_amount = LBP_BASE_RESERVE * 2 / 3;
+		let share_amount = Tokens::free_balance(trading_pair.dex_share_currency_id(), &BOB);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::LbpConverted {
+			trading_pair,
+			owner: BOB,
+			project_amount: LBP_PROJECT_RESERVE,
+			base_amount,
+			share_amount,
+		}));
+		assert!(share_amount > 0);
+		assert_eq!(DexModule::lbp_pools(trading_pair), None);
+		assert_eq!(
+			DexModule::trading_pair_statuses(trading_pair),
+			TradingPairStatus::<_, _>::Enabled
+		);
+		assert_eq!(EnabledTradingPairRecord::get(), vec![(trading_pair, None)]);
+		assert_eq!(
+			DexModule::get_liquidity_pool(DOT, AUSD),
+			(LBP_PROJECT_RESERVE, base_amount)
+		);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &BOB),
+			bob_ausd + LBP_BASE_RESERVE - base_amount
+		);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), bob_dot);
+		assert_eq!(Tokens::free_balance(DOT, &DexModule::lbp_account_id()), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &DexModule::lbp_account_id()), 0);
+
+		// the liquidity pool is swapped as a standard trading pair.
+		assert!(DexModule::get_swap_amount(&[AUSD, DOT], SwapLimit::ExactSupply(1_000_000_000_000, 0)).is_some());
+	});
+}
+
+#[test]
+fn lbp_dissolves_at_end_and_exit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let bob_ausd = Tokens::free_balance(AUSD, &BOB);
+		let bob_dot = Tokens::free_balance(DOT, &BOB);
+		let trading_pair = create_funded_lbp(Permill::from_percent(50), LbpEndAction::Dissolve);
+
+		DexModule::on_initialize(110);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::LbpDissolved {
+			trading_pair,
+			owner: BOB,
+			project_amount: LBP_PROJECT_RESERVE,
+			base_amount: LBP_BASE_RESERVE,
+		}));
+		assert_eq!(DexModule::lbp_pools(trading_pair), None);
+		assert_eq!(
+			DexModule::trading_pair_statuses(trading_pair),
+			TradingPairStatus::<_, _>::Disabled
+		);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), bob_ausd);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), bob_dot);
+
+		// the pool failed to be converted is exited by the owner.
+		let trading_pair = create_funded_lbp(Permill::from_percent(50), LbpEndAction::ConvertToDex);
+		TradingPairStatuses::<Runtime>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+		DexModule::on_initialize(110);
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::LbpSettlementFailed {
+			trading_pair,
+			error: Error::<Runtime>::MustBeDisabled.into(),
+		}));
+		assert!(DexModule::lbp_pools(trading_pair).is_some());
+
+		assert_noop!(
+			DexModule::exit_lbp(RuntimeOrigin::signed(BOB), DOT, AUSD),
+			Error::<Runtime>::LbpNotEnded
+		);
+		System::set_block_number(110);
+		assert_noop!(
+			DexModule::exit_lbp(RuntimeOrigin::signed(ALICE), DOT, AUSD),
+			Error::<Runtime>::NotLbpOwner
+		);
+		assert_ok!(DexModule::exit_lbp(RuntimeOrigin::signed(BOB), DOT, AUSD));
+		System::assert_last_event(RuntimeEvent::DexModule(crate::Event::LbpDissolved {
+			trading_pair,
+			owner: BOB,
+			project_amount: LBP_PROJECT_RESERVE,
+			base_amount: LBP_BASE_RESERVE,
+		}));
+		assert_eq!(DexModule::lbp_pools(trading_pair), None);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), bob_ausd);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), bob_dot);
+	});
+}
@@ -54,13 +54,28 @@ pub trait WeightInfo {
 	fn add_provision() -> Weight;
 	fn claim_dex_share() -> Weight;
 	fn add_liquidity() -> Weight;
+	fn add_liquidity_no_op() -> Weight;
 	fn add_liquidity_and_stake() -> Weight;
 	fn remove_liquidity() -> Weight;
+	fn remove_liquidity_no_op() -> Weight;
 	fn remove_liquidity_by_unstake() -> Weight;
 	fn swap_with_exact_supply(u: u32, ) -> Weight;
 	fn swap_with_exact_target(u: u32, ) -> Weight;
 	fn refund_provision() -> Weight;
 	fn abort_provisioning() -> Weight;
+	fn set_trading_pair_listing() -> Weight;
+	fn force_repair_trading_pair() -> Weight;
+	fn flash_swap(u: u32, ) -> Weight;
+	fn commit_swap() -> Weight;
+	fn reveal_swap(u: u32, ) -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
+	fn pause_trading_pair() -> Weight;
+	fn unpause_trading_pair() -> Weight;
+	fn create_lbp() -> Weight;
+	fn fund_lbp() -> Weight;
+	fn swap_with_lbp() -> Weight;
+	fn exit_lbp() -> Weight;
+	fn lbp_on_initialize(n: u32, ) -> Weight;
 }
 
 /// Weights for module_dex using the Acala node and recommended hardware.
@@ -68,8 +83,8 @@ pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn enable_trading_pair() -> Weight {
 		Weight::from_parts(24_728_000, 0)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	fn disable_trading_pair() -> Weight {
 		Weight::from_parts(24_891_000, 0)
@@ -88,8 +103,8 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn end_provisioning() -> Weight {
 		Weight::from_parts(78_617_000, 0)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	fn add_provision() -> Weight {
 		Weight::from_parts(127_543_000, 0)
@@ -106,6 +121,9 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
+	fn add_liquidity_no_op() -> Weight {
+		Weight::from_parts(8_861_000, 0)
+	}
 	fn add_liquidity_and_stake() -> Weight {
 		Weight::from_parts(258_276_000, 0)
 			.saturating_add(T::DbWeight::get().reads(12 as u64))
@@ -116,6 +134,9 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
+	fn remove_liquidity_no_op() -> Weight {
+		Weight::from_parts(8_645_000, 0)
+	}
 	fn remove_liquidity_by_unstake() -> Weight {
 		Weight::from_parts(277_297_000, 0)
 			.saturating_add(T::DbWeight::get().reads(12 as u64))
@@ -126,7 +147,7 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			// Standard Error: 117_000
 			.saturating_add(Weight::from_parts(16_008_000, 0).saturating_mul(u as u64))
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(u as u64)))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
 	}
@@ -135,7 +156,7 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			// Standard Error: 226_000
 			.saturating_add(Weight::from_parts(16_058_000, 0).saturating_mul(u as u64))
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(u as u64)))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
 	}
@@ -149,14 +170,90 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
+	fn set_trading_pair_listing() -> Weight {
+		Weight::from_parts(21_352_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn force_repair_trading_pair() -> Weight {
+		Weight::from_parts(86_213_000, 0)
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	fn flash_swap(u: u32, ) -> Weight {
+		Weight::from_parts(131_966_000, 0)
+			.saturating_add(Weight::from_parts(16_058_000, 0).saturating_mul(u as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(u as u64)))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
+	}
+	fn commit_swap() -> Weight {
+		Weight::from_parts(39_517_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn reveal_swap(u: u32, ) -> Weight {
+		Weight::from_parts(93_604_000, 0)
+			.saturating_add(Weight::from_parts(13_208_000, 0).saturating_mul(u as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(u as u64)))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(6_312_000, 0)
+			.saturating_add(Weight::from_parts(29_846_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	fn pause_trading_pair() -> Weight {
+		Weight::from_parts(24_303_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn unpause_trading_pair() -> Weight {
+		Weight::from_parts(24_177_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn create_lbp() -> Weight {
+		Weight::from_parts(35_412_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn fund_lbp() -> Weight {
+		Weight::from_parts(98_236_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	fn swap_with_lbp() -> Weight {
+		Weight::from_parts(142_870_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	fn exit_lbp() -> Weight {
+		Weight::from_parts(91_504_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	fn lbp_on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(4_981_000, 0)
+			.saturating_add(Weight::from_parts(263_455_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((13 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((12 as u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn enable_trading_pair() -> Weight {
 		Weight::from_parts(24_728_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	fn disable_trading_pair() -> Weight {
 		Weight::from_parts(24_891_000, 0)
@@ -175,8 +272,8 @@ impl WeightInfo for () {
 	}
 	fn end_provisioning() -> Weight {
 		Weight::from_parts(78_617_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 	}
 	fn add_provision() -> Weight {
 		Weight::from_parts(127_543_000, 0)
@@ -193,6 +290,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9 as u64))
 			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 	}
+	fn add_liquidity_no_op() -> Weight {
+		Weight::from_parts(8_861_000, 0)
+	}
 	fn add_liquidity_and_stake() -> Weight {
 		Weight::from_parts(258_276_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(12 as u64))
@@ -203,6 +303,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
+	fn remove_liquidity_no_op() -> Weight {
+		Weight::from_parts(8_645_000, 0)
+	}
 	fn remove_liquidity_by_unstake() -> Weight {
 		Weight::from_parts(277_297_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(12 as u64))
@@ -213,7 +316,7 @@ impl WeightInfo for () {
 			// Standard Error: 117_000
 			.saturating_add(Weight::from_parts(16_008_000, 0).saturating_mul(u as u64))
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(u as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
 	}
@@ -222,7 +325,7 @@ impl WeightInfo for () {
 			// Standard Error: 226_000
 			.saturating_add(Weight::from_parts(16_058_000, 0).saturating_mul(u as u64))
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(u as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
 	}
@@ -236,4 +339,80 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
+	fn set_trading_pair_listing() -> Weight {
+		Weight::from_parts(21_352_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn force_repair_trading_pair() -> Weight {
+		Weight::from_parts(86_213_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	fn flash_swap(u: u32, ) -> Weight {
+		Weight::from_parts(131_966_000, 0)
+			.saturating_add(Weight::from_parts(16_058_000, 0).saturating_mul(u as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(u as u64)))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
+	}
+	fn commit_swap() -> Weight {
+		Weight::from_parts(39_517_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn reveal_swap(u: u32, ) -> Weight {
+		Weight::from_parts(93_604_000, 0)
+			.saturating_add(Weight::from_parts(13_208_000, 0).saturating_mul(u as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(u as u64)))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(6_312_000, 0)
+			.saturating_add(Weight::from_parts(29_846_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	fn pause_trading_pair() -> Weight {
+		Weight::from_parts(24_303_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn unpause_trading_pair() -> Weight {
+		Weight::from_parts(24_177_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn create_lbp() -> Weight {
+		Weight::from_parts(35_412_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn fund_lbp() -> Weight {
+		Weight::from_parts(98_236_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	fn swap_with_lbp() -> Weight {
+		Weight::from_parts(142_870_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	fn exit_lbp() -> Weight {
+		Weight::from_parts(91_504_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	fn lbp_on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(4_981_000, 0)
+			.saturating_add(Weight::from_parts(263_455_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((13 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((12 as u64).saturating_mul(n as u64)))
+	}
 }
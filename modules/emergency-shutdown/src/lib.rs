@@ -27,17 +27,26 @@
 //! some user entry, freeze feed prices, run offchain worker to settle
 //! CDPs has debit, cancel all active auctions module, when debits and gaps are
 //! settled, the stable currency holder are allowed to refund a basket of
-//! remaining collateral assets.
+//! remaining collateral assets. After a grace period, the remaining holders
+//! can be refunded by a sweep, which is processed in batches by the idle
+//! scheduler.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer};
 use frame_system::{ensure_signed, pallet_prelude::*};
-use module_support::{AuctionManager, CDPTreasury, EmergencyShutdown, LockablePrice, Ratio};
-use primitives::{Balance, CurrencyId};
-use sp_runtime::{traits::Zero, FixedPointNumber};
-use sp_std::prelude::*;
+use module_support::{
+	AuctionManager, CDPTreasury, DispatchableTask, EmergencyShutdown, IdleScheduler, IssuanceOrigin, LockablePrice,
+	Ratio,
+};
+use parity_scale_codec::FullCodec;
+use primitives::{task::TaskResult, Balance, CurrencyId, Nonce};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	FixedPointNumber,
+};
+use sp_std::{cmp, fmt::Debug, marker::PhantomData, prelude::*};
 
 mod mock;
 mod tests;
@@ -46,6 +55,63 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The maximum length of the raw storage key of the refund sweep cursor.
+pub const MAX_SWEEP_CURSOR_LEN: u32 = 256;
+
+/// The state of the refund sweep.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RefundSweepState {
+	/// The maximum number of accounts visited in a batch.
+	pub batch_size: u32,
+	/// The raw storage key of the last visited account, the next batch starts after it.
+	pub cursor: Option<BoundedVec<u8, ConstU32<MAX_SWEEP_CURSOR_LEN>>>,
+	/// The number of holders refunded.
+	pub refunded: u32,
+	/// The number of dust holders whose collaterals are refunded to the treasury.
+	pub dust: u32,
+	/// The number of holders failed to be refunded, they can still refund by themselves.
+	pub failed: u32,
+	/// Whether all the accounts have been visited.
+	pub completed: bool,
+}
+
+/// Iterate the stable currency holders for the refund sweep.
+pub trait StableCurrencyHolders<AccountId> {
+	/// Visit at most `limit` accounts after the raw storage key `start_key`, return the
+	/// holders with their free stable currency balances, and the raw storage key to continue
+	/// from, or `None` if all the accounts have been visited.
+	fn holders(start_key: Option<Vec<u8>>, limit: u32) -> (Vec<(AccountId, Balance)>, Option<Vec<u8>>);
+}
+
+/// Iterate the stable currency holders of `orml_tokens`.
+pub struct TokensStableCurrencyHolders<T, GetStableCurrencyId>(PhantomData<(T, GetStableCurrencyId)>);
+impl<T, GetStableCurrencyId> StableCurrencyHolders<T::AccountId> for TokensStableCurrencyHolders<T, GetStableCurrencyId>
+where
+	T: orml_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>,
+	GetStableCurrencyId: Get<CurrencyId>,
+{
+	fn holders(start_key: Option<Vec<u8>>, limit: u32) -> (Vec<(T::AccountId, Balance)>, Option<Vec<u8>>) {
+		let stable_currency_id = GetStableCurrencyId::get();
+		let mut iter = match start_key {
+			Some(key) => orml_tokens::Accounts::<T>::iter_from(key),
+			None => orml_tokens::Accounts::<T>::iter(),
+		};
+
+		let mut holders = vec![];
+		for _ in 0..limit {
+			match iter.next() {
+				Some((who, currency_id, account)) => {
+					if currency_id == stable_currency_id && !account.free.is_zero() {
+						holders.push((who, account.free));
+					}
+				}
+				None => return (holders, None),
+			}
+		}
+		(holders, Some(iter.last_raw_key().to_vec()))
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -71,6 +137,35 @@ pub mod module {
 		/// this.
 		type ShutdownOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The stable currency holders to be refunded by the refund sweep
+		type StableCurrencyHolders: StableCurrencyHolders<Self::AccountId>;
+
+		/// The period after the final redemption opened, before the refund
+		/// sweep can be started
+		#[pallet::constant]
+		type RefundSweepGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The stable currency balances below the threshold are dust, the
+		/// refund sweep refunds their collaterals to the treasury
+		#[pallet::constant]
+		type RefundDustThreshold: Get<Balance>;
+
+		/// The treasury account to receive the collaterals of dust balances
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Dispatchable tasks
+		type Task: DispatchableTask
+			+ FullCodec
+			+ Debug
+			+ Clone
+			+ PartialEq
+			+ TypeInfo
+			+ From<EmergencyShutdownTask<Self>>;
+
+		/// Idle scheduler for the refund sweep
+		type IdleScheduler: IdleScheduler<Nonce, Self::Task>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -87,6 +182,14 @@ pub mod module {
 		ExistPotentialSurplus,
 		/// Exist unhandled debit, means settlement has not been completed
 		ExistUnhandledDebit,
+		/// The grace period of the refund sweep has not passed
+		RefundSweepGracePeriodNotPassed,
+		/// The refund sweep has already been started
+		RefundSweepAlreadyStarted,
+		/// The batch size of the refund sweep is zero
+		InvalidBatchSize,
+		/// The cursor of the refund sweep is too long
+		InvalidSweepCursor,
 	}
 
 	#[pallet::event]
@@ -102,6 +205,18 @@ pub mod module {
 			stable_coin_amount: Balance,
 			refund_list: Vec<(CurrencyId, Balance)>,
 		},
+		/// The collaterals of a dust balance are refunded to the treasury by the refund sweep.
+		DustRefunded {
+			who: T::AccountId,
+			stable_coin_amount: Balance,
+			refund_list: Vec<(CurrencyId, Balance)>,
+		},
+		/// The refund sweep started.
+		RefundSweepStarted { batch_size: u32 },
+		/// A batch of the refund sweep has been processed.
+		RefundSweepProgressed { refunded: u32, dust: u32, failed: u32 },
+		/// All the stable currency holders have been visited by the refund sweep.
+		RefundSweepCompleted { refunded: u32, dust: u32, failed: u32 },
 	}
 
 	/// Emergency shutdown flag
@@ -118,7 +233,24 @@ pub mod module {
 	#[pallet::getter(fn can_refund)]
 	pub type CanRefund<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The block number when the final redemption opened
+	///
+	/// RefundOpenedAt: Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn refund_opened_at)]
+	pub type RefundOpenedAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The state of the refund sweep, exists after the sweep started
+	///
+	/// RefundSweep: Option<RefundSweepState>
+	#[pallet::storage]
+	#[pallet::getter(fn refund_sweep)]
+	pub type RefundSweep<T: Config> = StorageValue<_, RefundSweepState, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -179,10 +311,10 @@ pub mod module {
 			}
 
 			// Open refund stage
+			let block_number = <frame_system::Pallet<T>>::block_number();
 			CanRefund::<T>::put(true);
-			Self::deposit_event(Event::OpenRefund {
-				block_number: <frame_system::Pallet<T>>::block_number(),
-			});
+			RefundOpenedAt::<T>::put(block_number);
+			Self::deposit_event(Event::OpenRefund { block_number });
 			Ok(())
 		}
 
@@ -195,25 +327,7 @@ pub mod module {
 			let who = ensure_signed(origin)?;
 			ensure!(Self::can_refund(), Error::<T>::CanNotRefund);
 
-			let refund_ratio: Ratio = <T as Config>::CDPTreasury::get_debit_proportion(amount);
-			let collateral_currency_ids = T::CollateralCurrencyIds::get();
-
-			// burn caller's stable currency by CDP treasury
-			<T as Config>::CDPTreasury::burn_debit(&who, amount)?;
-
-			let mut refund_assets: Vec<(CurrencyId, Balance)> = vec![];
-			// refund collaterals to caller by CDP treasury
-			for currency_id in collateral_currency_ids {
-				let refund_amount =
-					refund_ratio.saturating_mul_int(<T as Config>::CDPTreasury::get_total_collaterals(currency_id));
-
-				if !refund_amount.is_zero() {
-					let res = <T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_amount);
-					if res.is_ok() {
-						refund_assets.push((currency_id, refund_amount));
-					}
-				}
-			}
+			let refund_assets = Self::do_refund(&who, &who, amount)?;
 
 			Self::deposit_event(Event::Refund {
 				who,
@@ -222,6 +336,189 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Start the refund sweep for the remaining stable currency holders,
+		/// after the grace period since the final redemption opened. The
+		/// sweep is processed by the idle scheduler in batches.
+		///
+		/// The dispatch origin of this call must be `ShutdownOrigin`.
+		///
+		/// - `batch_size`: the maximum number of accounts visited in a batch.
+		#[pallet::call_index(3)]
+		#[pallet::weight((T::WeightInfo::start_refund_sweep(), DispatchClass::Operational))]
+		pub fn start_refund_sweep(origin: OriginFor<T>, batch_size: u32) -> DispatchResult {
+			T::ShutdownOrigin::ensure_origin(origin)?;
+			ensure!(Self::can_refund(), Error::<T>::CanNotRefund);
+			ensure!(!batch_size.is_zero(), Error::<T>::InvalidBatchSize);
+			ensure!(Self::refund_sweep().is_none(), Error::<T>::RefundSweepAlreadyStarted);
+
+			// the final redemption opened before the block number is recorded has passed the grace period
+			let now = <frame_system::Pallet<T>>::block_number();
+			let opened_at = Self::refund_opened_at().unwrap_or_default();
+			ensure!(
+				now >= opened_at.saturating_add(T::RefundSweepGracePeriod::get()),
+				Error::<T>::RefundSweepGracePeriodNotPassed
+			);
+
+			RefundSweep::<T>::put(RefundSweepState {
+				batch_size,
+				..Default::default()
+			});
+			T::IdleScheduler::schedule(EmergencyShutdownTask::RefundSweep { started_at: now }.into())?;
+
+			Self::deposit_event(Event::RefundSweepStarted { batch_size });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Burn `amount` stable currency of `who` and refund the basket of
+	/// collaterals to `beneficiary`.
+	fn do_refund(
+		who: &T::AccountId,
+		beneficiary: &T::AccountId,
+		amount: Balance,
+	) -> Result<Vec<(CurrencyId, Balance)>, DispatchError> {
+		let refund_ratio: Ratio = <T as Config>::CDPTreasury::get_debit_proportion(amount);
+		let collateral_currency_ids = T::CollateralCurrencyIds::get();
+
+		// burn stable currency by CDP treasury
+		<T as Config>::CDPTreasury::burn_debit(who, amount, IssuanceOrigin::System)?;
+
+		let mut refund_assets: Vec<(CurrencyId, Balance)> = vec![];
+		// refund collaterals to beneficiary by CDP treasury
+		for currency_id in collateral_currency_ids {
+			let refund_amount =
+				refund_ratio.saturating_mul_int(<T as Config>::CDPTreasury::get_total_collaterals(currency_id));
+
+			if !refund_amount.is_zero() {
+				let res = <T as Config>::CDPTreasury::withdraw_collateral(beneficiary, currency_id, refund_amount);
+				if res.is_ok() {
+					refund_assets.push((currency_id, refund_amount));
+				}
+			}
+		}
+
+		Ok(refund_assets)
+	}
+
+	/// Refund the next batch of stable currency holders within the weight limit.
+	fn process_refund_sweep(weight: Weight) -> TaskResult {
+		let Some(mut sweep) = Self::refund_sweep().filter(|sweep| !sweep.completed) else {
+			return TaskResult {
+				result: Ok(()),
+				used_weight: T::DbWeight::get().reads(1),
+				finished: true,
+			};
+		};
+
+		let base_weight = T::WeightInfo::refund_sweep(0).ref_time();
+		let weight_per_account = T::WeightInfo::refund_sweep(1)
+			.ref_time()
+			.saturating_sub(base_weight)
+			.max(1);
+		let limit = cmp::min(
+			weight.ref_time().saturating_sub(base_weight) / weight_per_account,
+			sweep.batch_size.into(),
+		) as u32;
+		if limit.is_zero() {
+			return TaskResult {
+				result: Ok(()),
+				used_weight: Weight::zero(),
+				finished: false,
+			};
+		}
+
+		let (holders, next_key) =
+			T::StableCurrencyHolders::holders(sweep.cursor.as_ref().map(|cursor| cursor.to_vec()), limit);
+		let dust_threshold = T::RefundDustThreshold::get();
+		let treasury_account = T::TreasuryAccount::get();
+
+		for (who, amount) in holders {
+			// the holders claimed by themselves only have the remaining balances to refund
+			let is_dust = amount < dust_threshold;
+			let beneficiary = if is_dust { &treasury_account } else { &who };
+			match with_storage_layer(|| Self::do_refund(&who, beneficiary, amount)) {
+				Ok(refund_list) if is_dust => {
+					sweep.dust = sweep.dust.saturating_add(1);
+					Self::deposit_event(Event::DustRefunded {
+						who,
+						stable_coin_amount: amount,
+						refund_list,
+					});
+				}
+				Ok(refund_list) => {
+					sweep.refunded = sweep.refunded.saturating_add(1);
+					Self::deposit_event(Event::Refund {
+						who,
+						stable_coin_amount: amount,
+						refund_list,
+					});
+				}
+				Err(_) => {
+					sweep.failed = sweep.failed.saturating_add(1);
+				}
+			}
+		}
+		let used_weight = T::WeightInfo::refund_sweep(limit);
+
+		let result: DispatchResult = match next_key {
+			Some(key) => BoundedVec::try_from(key)
+				.map(|cursor| sweep.cursor = Some(cursor))
+				.map_err(|_| Error::<T>::InvalidSweepCursor.into()),
+			None => {
+				sweep.cursor = None;
+				sweep.completed = true;
+				Ok(())
+			}
+		};
+		// stop the sweep if the cursor can't be stored, the remaining holders can still refund by themselves
+		if result.is_err() {
+			sweep.completed = true;
+		}
+		RefundSweep::<T>::put(&sweep);
+
+		if sweep.completed {
+			Self::deposit_event(Event::RefundSweepCompleted {
+				refunded: sweep.refunded,
+				dust: sweep.dust,
+				failed: sweep.failed,
+			});
+		} else {
+			Self::deposit_event(Event::RefundSweepProgressed {
+				refunded: sweep.refunded,
+				dust: sweep.dust,
+				failed: sweep.failed,
+			});
+		}
+
+		TaskResult {
+			result,
+			used_weight,
+			finished: sweep.completed,
+		}
+	}
+}
+
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum EmergencyShutdownTask<T: Config> {
+	/// Refund the remaining stable currency holders.
+	RefundSweep { started_at: BlockNumberFor<T> },
+}
+
+impl<T: Config> DispatchableTask for EmergencyShutdownTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			EmergencyShutdownTask::RefundSweep { .. } => Pallet::<T>::process_refund_sweep(weight),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: Config> From<EmergencyShutdownTask<T>> for () {
+	fn from(_task: EmergencyShutdownTask<T>) -> Self {
+		unimplemented!()
 	}
 }
 
@@ -23,15 +23,15 @@
 use super::*;
 use frame_support::{
 	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU128, ConstU32, Nothing},
+	traits::{ConstU128, ConstU32, ConstU64, Nothing},
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
 use module_support::{mocks::MockStableAsset, AuctionManager, LockablePrice, RiskManager, SpecificJointsSwap};
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, TokenSymbol};
+use primitives::{define_combined_task, Amount, TokenSymbol};
 use sp_runtime::{
-	traits::{AccountIdConversion, IdentityLookup},
+	traits::{AccountIdConversion, BlockNumberProvider, IdentityLookup},
 	BuildStorage, DispatchResult,
 };
 
@@ -41,6 +41,9 @@ pub type BlockNumber = u64;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CAROL: AccountId = 3;
+pub const DAVE: AccountId = 4;
+pub const EVE: AccountId = 5;
 pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::ForeignAsset(255);
@@ -137,6 +140,7 @@ impl module_loans::Config for Runtime {
 	type CDPTreasury = CDPTreasuryModule;
 	type PalletId = LoansPalletId;
 	type OnUpdateLoan = ();
+	type MaxPositionsPerAccount = ConstU32<3>;
 }
 
 pub struct MockLockablePrice;
@@ -202,12 +206,47 @@ impl module_cdp_treasury::Config for Runtime {
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
+	type PriceSource = ();
+	type MaxBasketAssets = ConstU32<10>;
+}
+
+define_combined_task! {
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum ScheduledTasks {
+		EmergencyShutdownTask(EmergencyShutdownTask<Runtime>),
+	}
+}
+
+pub struct MockBlockNumberProvider;
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = u32;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Zero::zero()
+	}
+}
+
+parameter_types! {
+	pub MinimumWeightRemainInBlock: Weight = Weight::zero();
+}
+
+impl module_idle_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Index = Nonce;
+	type Task = ScheduledTasks;
+	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
+	type DisableBlockThreshold = ConstU32<6>;
 }
 
 ord_parameter_types! {
 	pub const MockCollateralCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
 }
 
+pub const REFUND_SWEEP_GRACE_PERIOD: BlockNumber = 10;
+pub const REFUND_DUST_THRESHOLD: Balance = 10;
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollateralCurrencyIds = MockCollateralCurrencyIds;
@@ -215,6 +254,12 @@ impl Config for Runtime {
 	type CDPTreasury = CDPTreasuryModule;
 	type AuctionManagerHandler = MockAuctionManager;
 	type ShutdownOrigin = EnsureSignedBy<One, AccountId>;
+	type StableCurrencyHolders = TokensStableCurrencyHolders<Runtime, GetStableCurrencyId>;
+	type RefundSweepGracePeriod = ConstU64<REFUND_SWEEP_GRACE_PERIOD>;
+	type RefundDustThreshold = ConstU128<REFUND_DUST_THRESHOLD>;
+	type TreasuryAccount = TreasuryAccount;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -229,6 +274,7 @@ construct_runtime!(
 		Currencies: orml_currencies,
 		CDPTreasuryModule: module_cdp_treasury,
 		Loans: module_loans,
+		IdleScheduler: module_idle_scheduler,
 	}
 );
 
@@ -21,8 +21,9 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use mock::{RuntimeEvent, *};
+use orml_traits::MultiCurrency;
 use sp_runtime::traits::BadOrigin;
 
 #[test]
@@ -86,3 +87,192 @@ fn refund_collaterals_fail() {
 		);
 	});
 }
+
+fn setup_refund() {
+	System::set_block_number(1);
+	assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, BTC, 500));
+	assert_ok!(CDPTreasuryModule::deposit_collateral(&BOB, BTC, 500));
+	assert_ok!(CDPTreasuryModule::deposit_collateral(&ALICE, DOT, 500));
+	assert_ok!(CDPTreasuryModule::deposit_collateral(&BOB, DOT, 500));
+	for (who, amount) in [(ALICE, 500), (BOB, 300), (CAROL, 100), (DAVE, 5), (EVE, 95)] {
+		assert_ok!(CDPTreasuryModule::issue_debit(
+			&who,
+			amount,
+			true,
+			IssuanceOrigin::System
+		));
+	}
+	assert_ok!(EmergencyShutdownModule::emergency_shutdown(RuntimeOrigin::signed(1)));
+	assert_ok!(EmergencyShutdownModule::open_collateral_refund(RuntimeOrigin::signed(
+		1
+	)));
+}
+
+fn run_idle_for(accounts: u32) {
+	IdleScheduler::on_idle(
+		System::block_number(),
+		<() as module_idle_scheduler::WeightInfo>::on_idle_base() + <() as crate::WeightInfo>::refund_sweep(accounts),
+	);
+}
+
+// the pro-rata refunds are rounded down, which leaves the later holders a little more
+fn assert_refunded(who: AccountId, expected: Balance) {
+	for currency_id in [BTC, DOT] {
+		let balance = Currencies::free_balance(currency_id, &who);
+		assert!(
+			balance.abs_diff(expected) <= 3,
+			"{:?} of {:?}: {:?} != {:?}",
+			currency_id,
+			who,
+			balance,
+			expected
+		);
+	}
+	assert_eq!(Currencies::free_balance(AUSD, &who), 0);
+}
+
+#[test]
+fn refund_collaterals_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_refund();
+		assert_ok!(EmergencyShutdownModule::refund_collaterals(
+			RuntimeOrigin::signed(ALICE),
+			500
+		));
+		System::assert_last_event(RuntimeEvent::EmergencyShutdownModule(crate::Event::Refund {
+			who: ALICE,
+			stable_coin_amount: 500,
+			refund_list: vec![(BTC, 500), (DOT, 500)],
+		}));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 0);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 1000);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 1000);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 500);
+	});
+}
+
+#[test]
+fn start_refund_sweep_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			EmergencyShutdownModule::start_refund_sweep(RuntimeOrigin::signed(1), 2),
+			Error::<Runtime>::CanNotRefund,
+		);
+
+		setup_refund();
+		assert_noop!(
+			EmergencyShutdownModule::start_refund_sweep(RuntimeOrigin::signed(5), 2),
+			BadOrigin,
+		);
+		assert_noop!(
+			EmergencyShutdownModule::start_refund_sweep(RuntimeOrigin::signed(1), 0),
+			Error::<Runtime>::InvalidBatchSize,
+		);
+		assert_noop!(
+			EmergencyShutdownModule::start_refund_sweep(RuntimeOrigin::signed(1), 2),
+			Error::<Runtime>::RefundSweepGracePeriodNotPassed,
+		);
+
+		System::set_block_number(1 + REFUND_SWEEP_GRACE_PERIOD);
+		assert_ok!(EmergencyShutdownModule::start_refund_sweep(RuntimeOrigin::signed(1), 2));
+		assert_noop!(
+			EmergencyShutdownModule::start_refund_sweep(RuntimeOrigin::signed(1), 2),
+			Error::<Runtime>::RefundSweepAlreadyStarted,
+		);
+	});
+}
+
+#[test]
+fn refund_sweep_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_refund();
+		assert_eq!(EmergencyShutdownModule::refund_opened_at(), Some(1));
+
+		// ALICE claims all, BOB claims partially
+		assert_ok!(EmergencyShutdownModule::refund_collaterals(
+			RuntimeOrigin::signed(ALICE),
+			500
+		));
+		assert_ok!(EmergencyShutdownModule::refund_collaterals(
+			RuntimeOrigin::signed(BOB),
+			100
+		));
+		assert_eq!(Currencies::free_balance(BTC, &BOB), 600);
+
+		System::set_block_number(1 + REFUND_SWEEP_GRACE_PERIOD);
+		assert_ok!(EmergencyShutdownModule::start_refund_sweep(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(RuntimeEvent::EmergencyShutdownModule(
+			crate::Event::RefundSweepStarted { batch_size: 2 },
+		));
+		assert_eq!(
+			EmergencyShutdownModule::refund_sweep(),
+			Some(RefundSweepState {
+				batch_size: 2,
+				..Default::default()
+			})
+		);
+
+		// not enough weight for an account
+		run_idle_for(0);
+		assert_eq!(EmergencyShutdownModule::refund_sweep().unwrap().cursor, None);
+
+		// the weight allows more accounts than the batch size
+		let mut batches = 0;
+		while !EmergencyShutdownModule::refund_sweep().unwrap().completed {
+			run_idle_for(3);
+			batches += 1;
+		}
+		assert!(batches > 1);
+		assert_eq!(
+			System::events()
+				.iter()
+				.filter(|r| matches!(
+					r.event,
+					RuntimeEvent::EmergencyShutdownModule(crate::Event::RefundSweepProgressed { .. })
+				))
+				.count(),
+			batches - 1
+		);
+		System::assert_has_event(RuntimeEvent::EmergencyShutdownModule(
+			crate::Event::RefundSweepCompleted {
+				refunded: 3,
+				dust: 1,
+				failed: 0,
+			},
+		));
+		assert_eq!(IdleScheduler::tasks(0), None);
+
+		// claimed holders are not refunded again
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 1000);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 1000);
+		assert_refunded(BOB, 800);
+		assert_refunded(CAROL, 100);
+		assert_refunded(EVE, 95);
+
+		// the entitlement of the dust holder goes to the treasury
+		assert_refunded(DAVE, 0);
+		assert_refunded(TreasuryAccount::get(), 5);
+		assert!(System::events().iter().any(|r| matches!(
+			r.event,
+			RuntimeEvent::EmergencyShutdownModule(crate::Event::DustRefunded {
+				who: DAVE,
+				stable_coin_amount: 5,
+				..
+			})
+		)));
+
+		assert_eq!(Currencies::total_issuance(AUSD), 0);
+		assert!(CDPTreasuryModule::total_collaterals(BTC) <= 3);
+		assert_noop!(
+			EmergencyShutdownModule::refund_collaterals(RuntimeOrigin::signed(CAROL), 1),
+			orml_tokens::Error::<Runtime>::BalanceTooLow,
+		);
+
+		// the completed sweep can't be restarted
+		assert_noop!(
+			EmergencyShutdownModule::start_refund_sweep(RuntimeOrigin::signed(1), 2),
+			Error::<Runtime>::RefundSweepAlreadyStarted,
+		);
+	});
+}
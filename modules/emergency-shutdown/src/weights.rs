@@ -51,6 +51,8 @@ pub trait WeightInfo {
 	fn emergency_shutdown(c: u32, ) -> Weight;
 	fn open_collateral_refund() -> Weight;
 	fn refund_collaterals(c: u32, ) -> Weight;
+	fn start_refund_sweep() -> Weight;
+	fn refund_sweep(n: u32, ) -> Weight;
 }
 
 /// Weights for module_emergency_shutdown using the Acala node and recommended hardware.
@@ -78,6 +80,20 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
 	}
+	fn start_refund_sweep() -> Weight {
+		Weight::from_parts(31_452_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn refund_sweep(n: u32, ) -> Weight {
+		Weight::from_parts(18_204_000, 0)
+			// Standard Error: 96_000
+			.saturating_add(Weight::from_parts(162_831_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().reads((6 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -104,4 +120,18 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
 	}
+	fn start_refund_sweep() -> Weight {
+		Weight::from_parts(31_452_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn refund_sweep(n: u32, ) -> Weight {
+		Weight::from_parts(18_204_000, 0)
+			// Standard Error: 96_000
+			.saturating_add(Weight::from_parts(162_831_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
 }
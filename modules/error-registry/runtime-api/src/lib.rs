@@ -0,0 +1,36 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+pub use module_error_registry::ErrorInfo;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ErrorRegistryApi {
+		/// The pallet name, the error name and the docs of the module error, resolved by the
+		/// runtime metadata. `None` if the pallet or the error doesn't exist.
+		fn decode_module_error(index: u8, error: [u8; 4]) -> Option<ErrorInfo>;
+
+		/// The human readable UTF-8 name of the dispatch error, e.g. `CdpEngine::BelowRequiredCollateralRatio`
+		/// or `Token::FundsUnavailable`.
+		fn decode_dispatch_error(error: DispatchError) -> Vec<u8>;
+	}
+}
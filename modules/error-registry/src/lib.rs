@@ -0,0 +1,170 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Error Registry Module
+//!
+//! Decodes the `index` and `error` bytes of module errors to the pallet name, the error name and
+//! the docs of the error, by the error types of the pallets embedded in the runtime metadata. The
+//! runtime exposes the decoding by a runtime API, so clients can explain failed extrinsics without
+//! parsing the metadata themselves.
+//!
+//! The pallet itself is only meant for development runtimes: on finalize it emits the decoded name
+//! of the error of every failed extrinsic of the block.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::{pallet_prelude::*, Phase};
+use scale_info::{prelude::format, MetaType, TypeDef, Variant};
+use sp_runtime::ModuleError;
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+
+pub use module::*;
+
+/// The decoded module error.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ErrorInfo {
+	/// The name of the pallet in the runtime.
+	pub pallet: Vec<u8>,
+	/// The name of the error variant.
+	pub error: Vec<u8>,
+	/// The docs of the error variant, one line per line.
+	pub docs: Vec<u8>,
+}
+
+/// The error type of a pallet of the runtime.
+pub struct PalletErrorMetadata {
+	pub name: &'static str,
+	pub index: u8,
+	pub ty: Option<MetaType>,
+}
+
+/// Provides the error types of the pallets of the runtime.
+pub trait PalletErrors {
+	fn pallet_errors() -> Vec<PalletErrorMetadata>;
+}
+
+impl PalletErrors for () {
+	fn pallet_errors() -> Vec<PalletErrorMetadata> {
+		Vec::new()
+	}
+}
+
+fn find_error<P: PalletErrors>(index: u8, error: [u8; 4]) -> Option<(&'static str, Variant)> {
+	let pallet = P::pallet_errors().into_iter().find(|pallet| pallet.index == index)?;
+	let TypeDef::Variant(type_def) = pallet.ty?.type_info().type_def else {
+		return None;
+	};
+	let variant = type_def
+		.variants
+		.into_iter()
+		.find(|variant| variant.index == error[0])?;
+	Some((pallet.name, variant))
+}
+
+/// Decode the module error to the pallet name, the error name and the docs of the error.
+pub fn decode_module_error<P: PalletErrors>(index: u8, error: [u8; 4]) -> Option<ErrorInfo> {
+	find_error::<P>(index, error).map(|(pallet, variant)| ErrorInfo {
+		pallet: pallet.as_bytes().to_vec(),
+		error: variant.name.as_bytes().to_vec(),
+		docs: variant.docs.join("\n").into_bytes(),
+	})
+}
+
+/// The human readable name of the dispatch error. Module errors are named by
+/// `Pallet::Error`, unknown module errors fallback to the debug format.
+pub fn decode_dispatch_error<P: PalletErrors>(error: DispatchError) -> Vec<u8> {
+	match error {
+		DispatchError::Module(ModuleError {
+			index, error: bytes, ..
+		}) => match find_error::<P>(index, bytes) {
+			Some((pallet, variant)) => format!("{}::{}", pallet, variant.name),
+			None => format!("{:?}", error),
+		},
+		DispatchError::Token(e) => format!("Token::{:?}", e),
+		DispatchError::Arithmetic(e) => format!("Arithmetic::{:?}", e),
+		DispatchError::Transactional(e) => format!("Transactional::{:?}", e),
+		e => format!("{:?}", e),
+	}
+	.into_bytes()
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>
+			+ TryInto<frame_system::Event<Self>>
+			+ Clone;
+
+		/// The error types of the pallets of the runtime.
+		type PalletErrors: PalletErrors;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The extrinsic failed with the decoded error.
+		ExtrinsicFailedDecoded { extrinsic_index: u32, error: Vec<u8> },
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// reads the events of the block on finalize
+			T::DbWeight::get().reads(1)
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			let failed: Vec<(u32, DispatchError)> = frame_system::Pallet::<T>::read_events_no_consensus()
+				.filter_map(|record| {
+					let Phase::ApplyExtrinsic(extrinsic_index) = record.phase else {
+						return None;
+					};
+					let event = <T as Config>::RuntimeEvent::from_ref(&record.event).clone();
+					match event.try_into() {
+						Ok(frame_system::Event::ExtrinsicFailed { dispatch_error, .. }) => {
+							Some((extrinsic_index, dispatch_error))
+						}
+						_ => None,
+					}
+				})
+				.collect();
+
+			for (extrinsic_index, dispatch_error) in failed {
+				Self::deposit_event(Event::ExtrinsicFailedDecoded {
+					extrinsic_index,
+					error: decode_dispatch_error::<T::PalletErrors>(dispatch_error),
+				});
+			}
+		}
+	}
+}
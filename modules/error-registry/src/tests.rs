@@ -0,0 +1,139 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the error registry module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::traits::OnFinalize;
+use mock::{RuntimeEvent, *};
+use sp_runtime::{ArithmeticError, TokenError, TransactionalError};
+
+fn module_error(error: DispatchError) -> (u8, [u8; 4]) {
+	match error {
+		DispatchError::Module(ModuleError { index, error, .. }) => (index, error),
+		_ => panic!("not a module error"),
+	}
+}
+
+#[test]
+fn decode_module_error_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (index, error) = module_error(frame_system::Error::<Runtime>::CallFiltered.into());
+		let info = decode_module_error::<MockPalletErrors>(index, error).unwrap();
+		assert_eq!(info.pallet, b"System".to_vec());
+		assert_eq!(info.error, b"CallFiltered".to_vec());
+		assert!(!info.docs.is_empty());
+
+		let (index, error) = module_error(frame_system::Error::<Runtime>::InvalidSpecName.into());
+		assert_eq!(
+			decode_module_error::<MockPalletErrors>(index, error).map(|info| info.error),
+			Some(b"InvalidSpecName".to_vec())
+		);
+	});
+}
+
+#[test]
+fn decode_unknown_module_error_returns_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		// unknown pallet
+		assert_eq!(decode_module_error::<MockPalletErrors>(100, [0; 4]), None);
+		// the pallet has no errors
+		assert_eq!(decode_module_error::<MockPalletErrors>(1, [0; 4]), None);
+		// unknown error
+		assert_eq!(decode_module_error::<MockPalletErrors>(0, [200, 0, 0, 0]), None);
+		// no pallets
+		assert_eq!(decode_module_error::<()>(0, [0; 4]), None);
+	});
+}
+
+#[test]
+fn decode_dispatch_error_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			decode_dispatch_error::<MockPalletErrors>(frame_system::Error::<Runtime>::CallFiltered.into()),
+			b"System::CallFiltered".to_vec()
+		);
+		assert_eq!(
+			decode_dispatch_error::<MockPalletErrors>(DispatchError::Token(TokenError::FundsUnavailable)),
+			b"Token::FundsUnavailable".to_vec()
+		);
+		assert_eq!(
+			decode_dispatch_error::<MockPalletErrors>(DispatchError::Arithmetic(ArithmeticError::Overflow)),
+			b"Arithmetic::Overflow".to_vec()
+		);
+		assert_eq!(
+			decode_dispatch_error::<MockPalletErrors>(DispatchError::Transactional(TransactionalError::LimitReached)),
+			b"Transactional::LimitReached".to_vec()
+		);
+		assert_eq!(
+			decode_dispatch_error::<MockPalletErrors>(DispatchError::BadOrigin),
+			b"BadOrigin".to_vec()
+		);
+
+		let unknown = DispatchError::Module(ModuleError {
+			index: 100,
+			error: [0; 4],
+			message: None,
+		});
+		assert_eq!(
+			decode_dispatch_error::<MockPalletErrors>(unknown),
+			format!("{:?}", unknown).into_bytes()
+		);
+	});
+}
+
+#[test]
+fn emit_decoded_error_of_failed_extrinsics() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::note_finished_initialize();
+		System::note_applied_extrinsic(
+			&Err(DispatchError::Token(TokenError::FundsUnavailable).into()),
+			Default::default(),
+		);
+		System::note_applied_extrinsic(&Ok(().into()), Default::default());
+		System::note_applied_extrinsic(
+			&Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+			Default::default(),
+		);
+
+		ErrorRegistry::on_finalize(1);
+
+		let decoded: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::ErrorRegistry(event) => Some(event),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(
+			decoded,
+			vec![
+				crate::Event::ExtrinsicFailedDecoded {
+					extrinsic_index: 0,
+					error: b"Token::FundsUnavailable".to_vec(),
+				},
+				crate::Event::ExtrinsicFailedDecoded {
+					extrinsic_index: 2,
+					error: b"System::CallFiltered".to_vec(),
+				},
+			]
+		);
+	});
+}
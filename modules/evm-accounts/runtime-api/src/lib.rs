@@ -0,0 +1,82 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use parity_scale_codec::{Decode, Encode};
+use primitives::evm::EvmAddress;
+use scale_info::TypeInfo;
+use sp_runtime::{codec::Codec, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The identifier to resolve the mapping of.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AddressOrAccount<AccountId> {
+	Address(EvmAddress),
+	Account(AccountId),
+}
+
+/// How an EVM address and an AccountId are mapped.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum MappingKind {
+	/// Bound by `claim_account` with a signature of the EVM address.
+	Claimed,
+	/// Bound to the default EVM address derived from the AccountId.
+	Default,
+	/// Not bound, the counterpart is derived and the binding is created on first use.
+	Unbound,
+}
+
+/// The state of the EVM address in the EVM.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EvmAccountState {
+	/// The address has a contract deployed.
+	pub is_contract: bool,
+	/// The address has a non-zero EVM nonce.
+	pub has_nonce: bool,
+}
+
+/// The mapping between an EVM address and an AccountId.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MappingInfo<AccountId> {
+	pub evm_address: EvmAddress,
+	pub account_id: AccountId,
+	pub kind: MappingKind,
+	pub evm_state: EvmAccountState,
+}
+
+/// A page of the bound accounts.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BoundAccountsPage<AccountId> {
+	pub bindings: Vec<MappingInfo<AccountId>>,
+	/// The cursor to query the next page, `None` if all bindings are returned.
+	pub next_cursor: Option<EvmAddress>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait EvmAccountsApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Resolve the mappings of the EVM addresses and AccountIds, in the same order.
+		fn resolve(targets: Vec<AddressOrAccount<AccountId>>) -> Vec<MappingInfo<AccountId>>;
+
+		/// The bound accounts after the `cursor`, in the deterministic order of the storage keys.
+		fn get_bound_accounts(cursor: Option<EvmAddress>, limit: u32) -> BoundAccountsPage<AccountId>;
+	}
+}
@@ -32,11 +32,15 @@ use frame_support::{
 	traits::{Currency, IsType, OnKilledAccount},
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
+use module_evm_accounts_runtime_api::{AddressOrAccount, BoundAccountsPage, EvmAccountState, MappingInfo, MappingKind};
 use module_evm_utility_macro::keccak256;
 use module_support::{AddressMapping, EVMAccountsManager};
 use orml_traits::currency::TransferAll;
 use parity_scale_codec::Encode;
-use primitives::{evm::EvmAddress, to_bytes, AccountIndex};
+use primitives::{
+	evm::{EvmAddress, NONCE_LANE_BITS},
+	to_bytes, AccountIndex,
+};
 use sp_core::crypto::AccountId32;
 use sp_core::{H160, H256};
 use sp_io::{
@@ -59,6 +63,9 @@ pub use weights::WeightInfo;
 /// A signature (a 512-bit value, plus 8 bits for recovery ID).
 pub type Eip712Signature = [u8; 65];
 
+/// The maximum number of bindings in a page of the bound accounts.
+pub const MAX_BOUND_ACCOUNTS_PAGE_SIZE: u32 = 1000;
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -80,6 +87,10 @@ pub mod module {
 		/// Merge free balance from source to dest.
 		type TransferAll: TransferAll<Self::AccountId>;
 
+		/// The maximum number of nonce lanes an account can opt in to.
+		#[pallet::constant]
+		type MaxNonceLanes: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -93,6 +104,8 @@ pub mod module {
 			account_id: T::AccountId,
 			evm_address: EvmAddress,
 		},
+		/// The number of nonce lanes of an account has been updated. 0 means opted out.
+		NonceLanesUpdated { account_id: T::AccountId, lanes: u32 },
 	}
 
 	/// Error for evm accounts module.
@@ -108,6 +121,8 @@ pub mod module {
 		InvalidSignature,
 		/// Account ref count is not zero
 		NonZeroRefCount,
+		/// The number of nonce lanes exceeds `MaxNonceLanes`
+		TooManyNonceLanes,
 	}
 
 	/// The Substrate Account for EvmAddresses
@@ -124,11 +139,34 @@ pub mod module {
 	#[pallet::getter(fn evm_addresses)]
 	pub type EvmAddresses<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+	/// The number of independent nonce lanes for the eth transactions of an account.
+	///
+	/// NonceLanes: map AccountId => Option<u32>
+	#[pallet::storage]
+	#[pallet::getter(fn nonce_lanes)]
+	pub type NonceLanes<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, OptionQuery>;
+
+	/// The next sequence of each nonce lane of an account. Never reset, so that transactions
+	/// can't be replayed after changing the number of lanes.
+	///
+	/// LaneNonces: double_map AccountId, u32 => Nonce
+	#[pallet::storage]
+	#[pallet::getter(fn lane_nonces)]
+	pub type LaneNonces<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, u32, T::Nonce, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(T::MaxNonceLanes::get() <= 1 << NONCE_LANE_BITS);
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -185,6 +223,35 @@ pub mod module {
 			let _ = Self::do_claim_default_evm_address(who)?;
 			Ok(())
 		}
+
+		/// Opt in to independent nonce lanes for the eth transactions of the caller, so a stuck
+		/// transaction only blocks its own lane.
+		///
+		/// The lane index is encoded in the high bits of the nonce, see
+		/// `primitives::evm::encode_nonce_lane`. Lane 0 continues from the account nonce.
+		/// The EVM nonce stays the sum of all lanes. Setting `lanes` to 0 or 1 opts out.
+		///
+		/// - `lanes`: the number of nonce lanes, at most `MaxNonceLanes`
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_nonce_lanes())]
+		pub fn set_nonce_lanes(origin: OriginFor<T>, #[pallet::compact] lanes: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(lanes <= T::MaxNonceLanes::get(), Error::<T>::TooManyNonceLanes);
+
+			let lanes = if lanes <= 1 {
+				NonceLanes::<T>::remove(&who);
+				0
+			} else {
+				// eth txs signed before opting in must stay stale in lane 0
+				let account_nonce = frame_system::Pallet::<T>::account_nonce(&who);
+				LaneNonces::<T>::mutate(&who, 0, |nonce| *nonce = (*nonce).max(account_nonce));
+				NonceLanes::<T>::insert(&who, lanes);
+				lanes
+			};
+
+			Self::deposit_event(Event::NonceLanesUpdated { account_id: who, lanes });
+			Ok(())
+		}
 	}
 }
 
@@ -249,6 +316,84 @@ impl<T: Config> Pallet<T> {
 		keccak_256(domain_seperator_msg.as_slice())
 	}
 
+	/// Resolve the mappings of the EVM addresses and AccountIds, in the same order.
+	///
+	/// - `evm_state`: the state of an EVM address in the EVM
+	pub fn resolve(
+		targets: Vec<AddressOrAccount<T::AccountId>>,
+		evm_state: impl Fn(&EvmAddress) -> EvmAccountState,
+	) -> Vec<MappingInfo<T::AccountId>>
+	where
+		T::AccountId: IsType<AccountId32>,
+	{
+		targets
+			.into_iter()
+			.map(|target| {
+				let (evm_address, account_id) = match target {
+					AddressOrAccount::Address(evm_address) => {
+						(evm_address, EvmAddressMapping::<T>::get_account_id(&evm_address))
+					}
+					AddressOrAccount::Account(account_id) => (
+						EvmAddressMapping::<T>::get_evm_address(&account_id)
+							.unwrap_or_else(|| account_to_default_evm_address(&account_id)),
+						account_id,
+					),
+				};
+				Self::mapping_info(evm_address, account_id, &evm_state)
+			})
+			.collect()
+	}
+
+	/// The bound accounts after the `cursor`, at most `limit` of them.
+	///
+	/// - `evm_state`: the state of an EVM address in the EVM
+	pub fn get_bound_accounts(
+		cursor: Option<EvmAddress>,
+		limit: u32,
+		evm_state: impl Fn(&EvmAddress) -> EvmAccountState,
+	) -> BoundAccountsPage<T::AccountId> {
+		let limit = limit.clamp(1, MAX_BOUND_ACCOUNTS_PAGE_SIZE) as usize;
+		let iter = match cursor {
+			Some(evm_address) => Accounts::<T>::iter_from(Accounts::<T>::hashed_key_for(evm_address)),
+			None => Accounts::<T>::iter(),
+		};
+
+		// take one more to know if there are more bindings
+		let mut bindings: Vec<MappingInfo<T::AccountId>> = iter
+			.take(limit.saturating_add(1))
+			.map(|(evm_address, account_id)| Self::mapping_info(evm_address, account_id, &evm_state))
+			.collect();
+		let next_cursor = if bindings.len() > limit {
+			bindings.truncate(limit);
+			bindings.last().map(|binding| binding.evm_address)
+		} else {
+			None
+		};
+
+		BoundAccountsPage { bindings, next_cursor }
+	}
+
+	fn mapping_info(
+		evm_address: EvmAddress,
+		account_id: T::AccountId,
+		evm_state: &impl Fn(&EvmAddress) -> EvmAccountState,
+	) -> MappingInfo<T::AccountId> {
+		let kind = if Accounts::<T>::get(evm_address).as_ref() != Some(&account_id) {
+			MappingKind::Unbound
+		} else if account_to_default_evm_address(&account_id) == evm_address {
+			MappingKind::Default
+		} else {
+			MappingKind::Claimed
+		};
+
+		MappingInfo {
+			evm_state: evm_state(&evm_address),
+			evm_address,
+			account_id,
+			kind,
+		}
+	}
+
 	fn do_claim_default_evm_address(who: T::AccountId) -> Result<EvmAddress, DispatchError> {
 		// ensure account_id has not been mapped
 		ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
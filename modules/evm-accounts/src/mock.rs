@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
-	traits::{ConstU128, Nothing},
+	traits::{ConstU128, ConstU32, Nothing},
 };
 use orml_traits::parameter_type_with_key;
 use primitives::{Amount, Balance, CurrencyId, TokenSymbol};
@@ -103,6 +103,7 @@ impl Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type MaxNonceLanes = ConstU32<8>;
 	type WeightInfo = ();
 }
 
@@ -22,7 +22,9 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{alice, bob, EvmAccountsModule, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System, ALICE, BOB};
+use mock::{
+	alice, bob, bob_account_id, EvmAccountsModule, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System, ALICE, BOB,
+};
 use std::str::FromStr;
 
 #[test]
@@ -211,3 +213,173 @@ fn account_to_evm_with_create_default() {
 		);
 	});
 }
+
+#[test]
+fn set_nonce_lanes_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		frame_system::Account::<Runtime>::mutate(ALICE, |account| account.nonce = 5);
+
+		assert_noop!(
+			EvmAccountsModule::set_nonce_lanes(RuntimeOrigin::signed(ALICE), 9),
+			Error::<Runtime>::TooManyNonceLanes
+		);
+
+		assert_ok!(EvmAccountsModule::set_nonce_lanes(RuntimeOrigin::signed(ALICE), 4));
+		System::assert_last_event(RuntimeEvent::EvmAccountsModule(crate::Event::NonceLanesUpdated {
+			account_id: ALICE,
+			lanes: 4,
+		}));
+		assert_eq!(EvmAccountsModule::nonce_lanes(ALICE), Some(4));
+		// lane 0 continues from the account nonce
+		assert_eq!(EvmAccountsModule::lane_nonces(ALICE, 0), 5);
+		assert_eq!(EvmAccountsModule::lane_nonces(ALICE, 1), 0);
+
+		LaneNonces::<Runtime>::insert(ALICE, 0, 8);
+		LaneNonces::<Runtime>::insert(ALICE, 1, 3);
+
+		// opt out
+		assert_ok!(EvmAccountsModule::set_nonce_lanes(RuntimeOrigin::signed(ALICE), 1));
+		System::assert_last_event(RuntimeEvent::EvmAccountsModule(crate::Event::NonceLanesUpdated {
+			account_id: ALICE,
+			lanes: 0,
+		}));
+		assert_eq!(EvmAccountsModule::nonce_lanes(ALICE), None);
+
+		// lane sequences are never reset
+		assert_ok!(EvmAccountsModule::set_nonce_lanes(RuntimeOrigin::signed(ALICE), 2));
+		assert_eq!(EvmAccountsModule::lane_nonces(ALICE, 0), 8);
+		assert_eq!(EvmAccountsModule::lane_nonces(ALICE, 1), 3);
+	});
+}
+
+#[test]
+fn resolve_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice_address = EvmAccountsModule::eth_address(&alice());
+		assert_ok!(EvmAccountsModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			alice_address,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+		assert_ok!(EvmAccountsModule::claim_default_account(RuntimeOrigin::signed(BOB)));
+		let bob_default_address = account_to_default_evm_address(&BOB);
+		let charlie = AccountId32::new([2u8; 32]);
+		let bob_address = EvmAccountsModule::eth_address(&bob());
+
+		let evm_state = |address: &EvmAddress| EvmAccountState {
+			is_contract: *address == alice_address,
+			has_nonce: *address == bob_default_address,
+		};
+
+		assert_eq!(
+			EvmAccountsModule::resolve(
+				vec![
+					AddressOrAccount::Address(alice_address),
+					AddressOrAccount::Account(ALICE),
+					AddressOrAccount::Account(BOB),
+					AddressOrAccount::Account(charlie.clone()),
+					AddressOrAccount::Address(bob_address),
+					AddressOrAccount::Account(bob_account_id()),
+				],
+				evm_state
+			),
+			vec![
+				MappingInfo {
+					evm_address: alice_address,
+					account_id: ALICE,
+					kind: MappingKind::Claimed,
+					evm_state: EvmAccountState {
+						is_contract: true,
+						has_nonce: false,
+					},
+				},
+				MappingInfo {
+					evm_address: alice_address,
+					account_id: ALICE,
+					kind: MappingKind::Claimed,
+					evm_state: EvmAccountState {
+						is_contract: true,
+						has_nonce: false,
+					},
+				},
+				MappingInfo {
+					evm_address: bob_default_address,
+					account_id: BOB,
+					kind: MappingKind::Default,
+					evm_state: EvmAccountState {
+						is_contract: false,
+						has_nonce: true,
+					},
+				},
+				MappingInfo {
+					evm_address: account_to_default_evm_address(&charlie),
+					account_id: charlie,
+					kind: MappingKind::Unbound,
+					evm_state: Default::default(),
+				},
+				MappingInfo {
+					evm_address: bob_address,
+					account_id: bob_account_id(),
+					kind: MappingKind::Unbound,
+					evm_state: Default::default(),
+				},
+				MappingInfo {
+					evm_address: bob_address,
+					account_id: bob_account_id(),
+					kind: MappingKind::Unbound,
+					evm_state: Default::default(),
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn get_bound_accounts_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_state = |_: &EvmAddress| EvmAccountState::default();
+		assert_eq!(
+			EvmAccountsModule::get_bound_accounts(None, 10, evm_state),
+			BoundAccountsPage {
+				bindings: vec![],
+				next_cursor: None,
+			}
+		);
+
+		for i in 10..15u8 {
+			assert_ok!(EvmAccountsModule::claim_default_account(RuntimeOrigin::signed(
+				AccountId32::new([i; 32])
+			)));
+		}
+
+		let all = EvmAccountsModule::get_bound_accounts(None, 10, evm_state);
+		assert_eq!(all.bindings.len(), 5);
+		assert_eq!(all.next_cursor, None);
+		assert!(all.bindings.iter().all(|binding| binding.kind == MappingKind::Default
+			&& EvmAccountsModule::accounts(binding.evm_address) == Some(binding.account_id.clone())));
+
+		// the pages cover all the bindings in the same order
+		let mut paged = vec![];
+		let mut cursor = None;
+		loop {
+			let page = EvmAccountsModule::get_bound_accounts(cursor, 2, evm_state);
+			assert!(page.bindings.len() <= 2);
+			paged.extend(page.bindings);
+			cursor = page.next_cursor;
+			if cursor.is_none() {
+				break;
+			}
+		}
+		assert_eq!(paged, all.bindings);
+
+		// the limit is at least 1
+		let first = EvmAccountsModule::get_bound_accounts(None, 0, evm_state);
+		assert_eq!(first.bindings, all.bindings[..1].to_vec());
+		assert_eq!(first.next_cursor, Some(all.bindings[0].evm_address));
+		assert_eq!(
+			EvmAccountsModule::get_bound_accounts(first.next_cursor, 10, evm_state).bindings,
+			all.bindings[1..].to_vec()
+		);
+	});
+}
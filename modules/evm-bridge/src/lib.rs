@@ -25,12 +25,13 @@ use frame_system::pallet_prelude::*;
 use module_evm::{ExitReason, ExitSucceed};
 use module_support::{
 	evm::limits::{erc20, liquidation},
-	EVMBridge as EVMBridgeTrait, ExecutionMode, InvokeContext, LiquidationEvmBridge as LiquidationEvmBridgeT, EVM,
+	EVMBridge as EVMBridgeTrait, ExecutionMode, FlashSwapEvmBridge as FlashSwapEvmBridgeT, InvokeContext,
+	LiquidationEvmBridge as LiquidationEvmBridgeT, EVM,
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use primitives::{evm::EvmAddress, Balance};
+use primitives::{evm::EvmAddress, Balance, CurrencyId};
 use sp_core::{H160, H256, U256};
-use sp_runtime::{ArithmeticError, DispatchError, SaturatedConversion};
+use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, SaturatedConversion};
 use sp_std::vec::Vec;
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -49,6 +50,7 @@ pub enum Action {
 	Liquidate = "liquidate(address,address,uint256,uint256)",
 	OnCollateralTransfer = "onCollateralTransfer(address,uint256)",
 	OnRepaymentRefund = "onRepaymentRefund(address,uint256)",
+	OnFlashSwap = "onFlashSwap(address,address,uint256,uint256,address,bytes)",
 }
 
 mod mock;
@@ -78,9 +80,25 @@ pub mod module {
 		ExecutionError,
 		/// Invalid return value
 		InvalidReturnValue,
+		/// The allowance of the spender is not enough
+		InsufficientAllowance,
+		/// Decreased the allowance below zero
+		AllowanceBelowZero,
 	}
 
+	/// The amount of currency that the spender is allowed to transfer on
+	/// behalf of the owner through the ERC20 mirror of the currency.
+	///
+	/// Allowances: double_map (CurrencyId, EvmAddress), EvmAddress => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, (CurrencyId, EvmAddress), Twox64Concat, EvmAddress, Balance, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -345,7 +363,114 @@ impl<T: Config> LiquidationEvmBridgeT for LiquidationEvmBridge<T> {
 	}
 }
 
+pub struct FlashSwapEvmBridge<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> FlashSwapEvmBridgeT for FlashSwapEvmBridge<T> {
+	fn on_flash_swap(
+		context: InvokeContext,
+		supply_token: EvmAddress,
+		target_token: EvmAddress,
+		target_amount: Balance,
+		repayment: Balance,
+		repay_dest: EvmAddress,
+		data: Vec<u8>,
+		gas_limit: u64,
+		storage_limit: u32,
+	) -> DispatchResult {
+		// flash swap callback method hash
+		let mut input = Into::<u32>::into(Action::OnFlashSwap).to_be_bytes().to_vec();
+
+		// append supply token ERC20 address
+		input.extend_from_slice(H256::from(supply_token).as_bytes());
+		// append target token ERC20 address
+		input.extend_from_slice(H256::from(target_token).as_bytes());
+		// append target amount
+		input.extend_from_slice(H256::from_uint(&U256::from(target_amount)).as_bytes());
+		// append repayment amount
+		input.extend_from_slice(H256::from_uint(&U256::from(repayment)).as_bytes());
+		// append repay dest address
+		input.extend_from_slice(H256::from(repay_dest).as_bytes());
+		// append the offset of data, which follows the 6 head words
+		input.extend_from_slice(H256::from_uint(&U256::from(6 * 32)).as_bytes());
+		// append data length
+		input.extend_from_slice(H256::from_uint(&U256::from(data.len())).as_bytes());
+		// append data, right padded to 32 bytes
+		let padded_len = data.len().div_ceil(32) * 32;
+		input.extend_from_slice(&data);
+		input.resize(input.len() + padded_len - data.len(), 0);
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			gas_limit,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)
+	}
+}
+
 impl<T: Config> Pallet<T> {
+	/// Set the allowance of `spender` over the `currency_id` of `owner`.
+	pub fn approve(currency_id: CurrencyId, owner: EvmAddress, spender: EvmAddress, amount: Balance) {
+		if amount.is_zero() {
+			Allowances::<T>::remove((currency_id, owner), spender);
+		} else {
+			Allowances::<T>::insert((currency_id, owner), spender, amount);
+		}
+	}
+
+	/// Increase the allowance of `spender` by `added_amount`, returns the
+	/// new allowance.
+	pub fn increase_allowance(
+		currency_id: CurrencyId,
+		owner: EvmAddress,
+		spender: EvmAddress,
+		added_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let amount = Self::allowances((currency_id, owner), spender)
+			.checked_add(added_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		Self::approve(currency_id, owner, spender, amount);
+		Ok(amount)
+	}
+
+	/// Decrease the allowance of `spender` by `subtracted_amount`, returns
+	/// the new allowance.
+	pub fn decrease_allowance(
+		currency_id: CurrencyId,
+		owner: EvmAddress,
+		spender: EvmAddress,
+		subtracted_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let amount = Self::allowances((currency_id, owner), spender)
+			.checked_sub(subtracted_amount)
+			.ok_or(Error::<T>::AllowanceBelowZero)?;
+		Self::approve(currency_id, owner, spender, amount);
+		Ok(amount)
+	}
+
+	/// Spend `amount` of the allowance of `spender`, returns the remaining
+	/// allowance. An allowance of `Balance::MAX` is infinite and never
+	/// decreased.
+	pub fn spend_allowance(
+		currency_id: CurrencyId,
+		owner: EvmAddress,
+		spender: EvmAddress,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let allowance = Self::allowances((currency_id, owner), spender);
+		if allowance == Balance::MAX {
+			return Ok(allowance);
+		}
+
+		let remaining = allowance.checked_sub(amount).ok_or(Error::<T>::InsufficientAllowance)?;
+		Self::approve(currency_id, owner, spender, remaining);
+		Ok(remaining)
+	}
+
 	fn handle_exit_reason(exit_reason: ExitReason) -> Result<(), DispatchError> {
 		match exit_reason {
 			ExitReason::Succeed(ExitSucceed::Returned) => Ok(()),
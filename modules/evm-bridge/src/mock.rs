@@ -109,6 +109,9 @@ impl module_evm::Config for Runtime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ();
 	type IdleScheduler = ();
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
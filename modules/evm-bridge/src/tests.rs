@@ -24,6 +24,7 @@ use super::*;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use insta::assert_json_snapshot;
 use mock::*;
+use primitives::TokenSymbol;
 
 #[test]
 fn should_read_name() {
@@ -356,6 +357,66 @@ fn liquidation_err_fails_as_expected() {
 }
 
 #[cfg(feature = "tracing")]
+#[test]
+fn allowance_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let currency_id = CurrencyId::Token(TokenSymbol::ACA);
+		let owner = alice_evm_addr();
+		let spender = bob_evm_addr();
+		assert_eq!(EVMBridgeModule::allowances((currency_id, owner), spender), 0);
+
+		EVMBridgeModule::approve(currency_id, owner, spender, 100);
+		assert_eq!(EVMBridgeModule::allowances((currency_id, owner), spender), 100);
+		assert_eq!(
+			EVMBridgeModule::allowances((CurrencyId::Token(TokenSymbol::AUSD), owner), spender),
+			0
+		);
+		assert_eq!(EVMBridgeModule::allowances((currency_id, spender), owner), 0);
+
+		assert_eq!(
+			EVMBridgeModule::increase_allowance(currency_id, owner, spender, 50),
+			Ok(150)
+		);
+		assert_noop!(
+			EVMBridgeModule::increase_allowance(currency_id, owner, spender, Balance::MAX),
+			ArithmeticError::Overflow
+		);
+		assert_eq!(
+			EVMBridgeModule::decrease_allowance(currency_id, owner, spender, 30),
+			Ok(120)
+		);
+		assert_noop!(
+			EVMBridgeModule::decrease_allowance(currency_id, owner, spender, 121),
+			Error::<Runtime>::AllowanceBelowZero
+		);
+
+		assert_eq!(
+			EVMBridgeModule::spend_allowance(currency_id, owner, spender, 20),
+			Ok(100)
+		);
+		assert_noop!(
+			EVMBridgeModule::spend_allowance(currency_id, owner, spender, 101),
+			Error::<Runtime>::InsufficientAllowance
+		);
+		assert_eq!(
+			EVMBridgeModule::spend_allowance(currency_id, owner, spender, 100),
+			Ok(0)
+		);
+		assert!(!Allowances::<Runtime>::contains_key((currency_id, owner), spender));
+
+		// infinite allowance is not decreased
+		EVMBridgeModule::approve(currency_id, owner, spender, Balance::MAX);
+		assert_eq!(
+			EVMBridgeModule::spend_allowance(currency_id, owner, spender, 1_000),
+			Ok(Balance::MAX)
+		);
+		assert_eq!(EVMBridgeModule::allowances((currency_id, owner), spender), Balance::MAX);
+
+		EVMBridgeModule::approve(currency_id, owner, spender, 0);
+		assert!(!Allowances::<Runtime>::contains_key((currency_id, owner), spender));
+	});
+}
+
 #[test]
 fn tracing_should_work() {
 	use module_evm::runner::tracing;
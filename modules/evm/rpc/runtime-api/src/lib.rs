@@ -19,7 +19,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
 
-use primitives::evm::{AccessListItem, BlockLimits, CallInfo, CreateInfo, EstimateResourcesRequest};
+use primitives::{
+	evm::{
+		AccessListItem, BlockLimits, CallInfo, ContractPurgeProgress, CreateInfo, EstimateResourcesRequest,
+		EstimateResourcesResponse, EthCallRequest, EvmTransactionIndex,
+	},
+	BlockNumber,
+};
 use sp_core::H160;
 use sp_runtime::{
 	codec::Codec,
@@ -28,7 +34,7 @@ use sp_runtime::{
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(7)]
 	pub trait EVMRuntimeRPCApi<Balance, AccountId> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 		AccountId: Codec + MaybeDisplay + MaybeFromStr,
@@ -78,6 +84,35 @@ sp_api::decl_runtime_apis! {
 			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
 		) -> Result<CreateInfo, sp_runtime::DispatchError>;
+
+		/// Returns the index of the EVM transactions executed in the block. Returns `None` if the
+		/// block has no EVM transactions or is out of the retention.
+		#[api_version(3)]
+		fn get_block_tx_index(block_number: BlockNumber) -> Option<Vec<EvmTransactionIndex<AccountId>>>;
+
+		/// Returns the purge progress of a selfdestructed contract. Returns `None` if the contract
+		/// is not being removed.
+		#[api_version(4)]
+		fn get_contract_purge_progress(contract: H160) -> Option<ContractPurgeProgress<Balance>>;
+
+		/// Executes multiple read-only calls against the same state. Fails if the batch exceeds
+		/// the max number of calls or the total gas limit exceeds the block gas limit.
+		#[api_version(5)]
+		fn batch_call(
+			requests: Vec<EthCallRequest<Balance>>,
+		) -> Result<Vec<Result<CallInfo, sp_runtime::DispatchError>>, sp_runtime::DispatchError>;
+
+		/// Returns the system contract registered with the well-known key. Returns `None` if the
+		/// key is not registered.
+		#[api_version(6)]
+		fn get_system_contract(key: Vec<u8>) -> Option<H160>;
+
+		/// Estimates the minimal gas limit and storage limit of the request, with the net storage
+		/// delta and the storage deposit or refund. The missing limits default to the block limits.
+		#[api_version(7)]
+		fn estimate_resources_v2(
+			request: EstimateResourcesRequest,
+		) -> Result<EstimateResourcesResponse<Balance>, sp_runtime::DispatchError>;
 	}
 }
 
@@ -26,7 +26,7 @@ use frame_support::{
 	weights::{ConstantMultiplier, IdentityFee},
 	ConsensusEngineId, PalletId,
 };
-use frame_system::EnsureSignedBy;
+use frame_system::{offchain::SendTransactionTypes, EnsureSignedBy};
 use module_support::{
 	mocks::{MockAddressMapping, MockErc20InfoMapping, TestRandomness},
 	DEXIncentives, Price, PriceProvider, SpecificJointsSwap,
@@ -199,6 +199,9 @@ impl Config for Runtime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
@@ -254,6 +257,11 @@ impl module_transaction_payment::Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type FeePayer = ();
+	type CallClassifier = ();
+	type FeeSpendPeriod = ConstU32<100>;
+	type MaxFeeSpendPeriods = ConstU32<12>;
+	type UnsignedPriority = ConstU64<1048576>;
 }
 
 pub struct MockDEXIncentives;
@@ -262,15 +270,20 @@ impl DEXIncentives<AccountId32, CurrencyId, Balance> for MockDEXIncentives {
 		Tokens::reserve(lp_currency_id, who, amount)
 	}
 
-	fn do_withdraw_dex_share(who: &AccountId32, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+	fn do_withdraw_dex_share(
+		who: &AccountId32,
+		lp_currency_id: CurrencyId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
 		let _ = Tokens::unreserve(lp_currency_id, who, amount);
-		Ok(())
+		Ok(amount)
 	}
 }
 
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
 }
 
 impl module_dex::Config for Runtime {
@@ -286,6 +299,18 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId32>;
 	type ExtendedProvisioningBlocks = ConstU32<0>;
 	type OnLiquidityPoolUpdated = ();
+	type OnTradingPairEnabled = ();
+	type OnTradingPairDisabled = ();
+	type EvmAddressMapping = MockAddressMapping;
+	type FlashSwapEvmBridge = ();
+	type GasToWeight = ();
+	type TreasuryAccount = TreasuryAccount;
+	type MinSwapCommitDeposit = ConstU128<100>;
+	type SwapRevealPeriod = ConstU32<10>;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = ConstU32<2>;
+	type LbpCheckpointPeriod = ConstU32<10>;
+	type MaxLbpPools = ConstU32<2>;
 }
 
 pub type SignedExtra = (frame_system::CheckWeight<Runtime>,);
@@ -304,3 +329,11 @@ construct_runtime!(
 		TransactionPayment: module_transaction_payment,
 	}
 );
+
+impl<LocalCall> SendTransactionTypes<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
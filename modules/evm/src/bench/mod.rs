@@ -22,8 +22,11 @@
 pub mod mock;
 
 use crate::{
-	code_hash, evm::Runtime as EVMRuntime, module::*, runner::Runner, Context, StackExecutor, StackSubstateMetadata,
-	SubstrateStackState,
+	code_hash,
+	evm::Runtime as EVMRuntime,
+	module::*,
+	runner::{EthCallRequest, Runner, RunnerExtended},
+	Context, StackExecutor, StackSubstateMetadata, SubstrateStackState,
 };
 use frame_support::{assert_ok, BoundedVec};
 use hex::FromHex;
@@ -234,6 +237,95 @@ macro_rules! evm_call {
 	};
 }
 
+macro_rules! evm_rpc_call_many {
+	($name: ident, $bench: ident, $count: expr) => {
+		fn $name(b: &mut Bencher) {
+			let (code, from, input, _, output) = get_bench_info(stringify!($bench));
+			faucet(&from);
+			let contract_address = whitelist_keys(b, from, code.clone());
+
+			assert_ok!(<Runtime as Config>::Runner::create_at_address(
+				from,
+				contract_address,
+				code.clone(),
+				0,
+				21_000_000,
+				1_000_000,
+				vec![],
+				<Runtime as Config>::config(),
+			));
+
+			let results = b.bench(|| {
+				(0..$count)
+					.map(|_| {
+						<Runtime as Config>::Runner::rpc_call(
+							from,
+							from,
+							contract_address,
+							input.clone(),
+							0,
+							1_000_000,
+							1_000_000,
+							vec![],
+							<Runtime as Config>::config(),
+						)
+					})
+					.collect::<Vec<_>>()
+			});
+
+			for result in results {
+				assert_eq!(result.unwrap().value, output);
+			}
+		}
+	};
+}
+
+macro_rules! evm_rpc_batch_call {
+	($name: ident, $bench: ident, $count: expr) => {
+		fn $name(b: &mut Bencher) {
+			let (code, from, input, _, output) = get_bench_info(stringify!($bench));
+			faucet(&from);
+			let contract_address = whitelist_keys(b, from, code.clone());
+
+			assert_ok!(<Runtime as Config>::Runner::create_at_address(
+				from,
+				contract_address,
+				code.clone(),
+				0,
+				21_000_000,
+				1_000_000,
+				vec![],
+				<Runtime as Config>::config(),
+			));
+
+			let request = EthCallRequest {
+				from,
+				to: contract_address,
+				data: input,
+				value: 0,
+				gas_limit: 1_000_000,
+				storage_limit: 1_000_000,
+				access_list: None,
+				static_call: true,
+			};
+
+			let results = b
+				.bench(|| {
+					<Runtime as Config>::Runner::rpc_batch_call(
+						vec![request.clone(); $count],
+						u64::MAX,
+						<Runtime as Config>::config(),
+					)
+				})
+				.unwrap();
+
+			for result in results {
+				assert_eq!(result.unwrap().value, output);
+			}
+		}
+	};
+}
+
 evm_create!(empty_deploy);
 evm_call!(empty_noop);
 
@@ -251,6 +343,10 @@ evm_create!(ballot_deploy);
 evm_call!(ballot_delegate);
 evm_call!(ballot_vote);
 
+// compare the overhead of 50 separate rpc calls with a single batch call
+evm_rpc_call_many!(empty_noop_50_calls, empty_noop, 50);
+evm_rpc_batch_call!(empty_noop_50_batch_call, empty_noop, 50);
+
 benches!(
 	empty_deploy,
 	empty_noop,
@@ -264,5 +360,7 @@ benches!(
 	storage_store_many,
 	ballot_deploy,
 	ballot_delegate,
-	ballot_vote
+	ballot_vote,
+	empty_noop_50_calls,
+	empty_noop_50_batch_call
 );
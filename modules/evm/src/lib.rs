@@ -61,8 +61,9 @@ pub use orml_traits::{currency::TransferAll, MultiCurrency};
 use parity_scale_codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 pub use primitives::{
 	evm::{
-		convert_decimals_from_evm, convert_decimals_to_evm, decode_gas_limit, is_system_contract, CallInfo, CreateInfo,
-		EvmAddress, ExecutionInfo, Vicinity, MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START,
+		convert_decimals_from_evm, convert_decimals_to_evm, decode_gas_limit, is_system_contract, CallInfo,
+		ContractPurgeProgress, CreateInfo, EvmAddress, EvmTransactionIndex, ExecutionInfo, SystemContractKey, Vicinity,
+		MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START, NONCE_LANE_CREATE_FLAG,
 	},
 	task::TaskResult,
 	Balance, CurrencyId, Nonce, ReserveIdentifier,
@@ -96,6 +97,8 @@ pub const STORAGE_SIZE: u32 = 64;
 pub const REMOVE_LIMIT: u32 = 100;
 /// Immediate remove contract item limit 50 DB writes
 pub const IMMEDIATE_REMOVE_LIMIT: u32 = 50;
+/// Max number of calls executed by a single rpc batch call
+pub const MAX_BATCH_CALL_SIZE: u32 = 100;
 
 /// Type alias for currency balance.
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -250,6 +253,18 @@ pub mod module {
 		/// Idle scheduler for the evm task.
 		type IdleScheduler: IdleScheduler<Nonce, Self::Task>;
 
+		/// The number of blocks the EVM transaction index is retained for. Zero disables the
+		/// index.
+		#[pallet::constant]
+		type TransactionIndexRetention: Get<u32>;
+
+		/// Computes the transaction hash of the encoded extrinsic following the Ethereum rules.
+		type TransactionHash: Convert<Vec<u8>, H256>;
+
+		/// The total net storage bytes all EVM transactions of a block can grow the storage by,
+		/// which bounds the PoV size taken by the EVM storage writes.
+		type BlockStorageBudget: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -361,6 +376,53 @@ pub mod module {
 	#[pallet::getter(fn xcm_origin)]
 	pub type XcmOrigin<T: Config> = StorageValue<_, Vec<T::AccountId>, OptionQuery>;
 
+	/// The signed nonce of the current eth transaction if the sender uses nonce lanes.
+	///
+	/// LaneCreateNonce: Option<Nonce>
+	#[pallet::storage]
+	#[pallet::getter(fn lane_create_nonce)]
+	pub type LaneCreateNonce<T: Config> = StorageValue<_, T::Nonce, OptionQuery>;
+
+	/// The EVM transactions executed in the current block, indexed on finalize.
+	///
+	/// PendingTransactionIndex: Vec<EvmTransactionIndex<AccountId>>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_transaction_index)]
+	pub type PendingTransactionIndex<T: Config> = StorageValue<_, Vec<EvmTransactionIndex<T::AccountId>>, ValueQuery>;
+
+	/// The EVM transaction index of the recent blocks. It's a ring buffer of
+	/// `TransactionIndexRetention` slots, the slot of a block is overwritten once the block is
+	/// out of retention.
+	///
+	/// BlockTransactionIndex: map u32 => Option<(BlockNumber, Vec<EvmTransactionIndex<AccountId>>)>
+	#[pallet::storage]
+	#[pallet::getter(fn block_transaction_index)]
+	pub type BlockTransactionIndex<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (BlockNumberFor<T>, Vec<EvmTransactionIndex<T::AccountId>>), OptionQuery>;
+
+	/// The net storage bytes grown by the EVM transactions of the current block, reset on
+	/// finalize.
+	///
+	/// BlockStorageUsed: u32
+	#[pallet::storage]
+	#[pallet::getter(fn block_storage_used)]
+	pub type BlockStorageUsed<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The contracts being removed and their purge progress. Calls into these contracts revert.
+	///
+	/// ContractPurges: map EvmAddress => Option<ContractPurgeProgress<Balance>>
+	#[pallet::storage]
+	#[pallet::getter(fn contract_purges)]
+	pub type ContractPurges<T: Config> =
+		StorageMap<_, Twox64Concat, EvmAddress, ContractPurgeProgress<BalanceOf<T>>, OptionQuery>;
+
+	/// The registry of the system contracts deployed by the network, by their well-known keys.
+	///
+	/// SystemContracts: map SystemContractKey => Option<EvmAddress>
+	#[pallet::storage]
+	#[pallet::getter(fn system_contracts)]
+	pub type SystemContracts<T: Config> = StorageMap<_, Blake2_128Concat, SystemContractKey, EvmAddress, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -500,6 +562,16 @@ pub mod module {
 		ContractSetCode { contract: EvmAddress },
 		/// Selfdestructed contract code.
 		ContractSelfdestructed { contract: EvmAddress },
+		/// The storage of a selfdestructed contract has been purged and the contract removed.
+		ContractRemoved {
+			contract: EvmAddress,
+			refunded: BalanceOf<T>,
+		},
+		/// The system contract is registered with the key, `None` means unregistered.
+		SystemContractUpdated {
+			key: SystemContractKey,
+			contract: Option<EvmAddress>,
+		},
 	}
 
 	#[pallet::error]
@@ -538,14 +610,46 @@ pub mod module {
 		StrictCallFailed,
 		/// Caller is not externally owned account
 		NotEOA,
-	}
+		/// Contract is being removed
+		ContractSelfDestructing,
+		/// Batch call exceeds the max number of calls
+		BatchCallTooLarge,
+		/// Total gas limit of the batch call exceeds the max gas limit
+		BatchCallGasLimitExceeded,
+		/// Static call cannot transfer value
+		StaticCallWithValue,
+		/// System contract key is already registered
+		SystemContractKeyExisted,
+		/// The storage growth of the EVM transactions exceeds the budget of the block
+		BlockStorageBudgetExceeded,
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			// reset of the block storage budget on finalize.
+			let weight = <T as frame_system::Config>::DbWeight::get().writes(1);
+
+			// the weight of each indexed transaction is registered when it's executed.
+			if T::TransactionIndexRetention::get().is_zero() {
+				weight
+			} else {
+				weight.saturating_add(T::WeightInfo::on_finalize_with_transaction_index(0))
+			}
+		}
+
+		fn on_finalize(now: BlockNumberFor<T>) {
+			BlockStorageUsed::<T>::kill();
+			Self::index_block_transactions(now);
+		}
+
 		fn integrity_test() {
 			assert!(convert_decimals_from_evm(T::StorageDepositPerByte::get()).is_some());
 		}
@@ -650,6 +754,7 @@ pub mod module {
 				Err(e) => {
 					// EVM state changes reverted, increase nonce by ourselves
 					Self::inc_nonce(&source);
+					Self::note_transaction(source, Some(who), None, 0);
 
 					Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed {
 						from: source,
@@ -665,6 +770,7 @@ pub mod module {
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::note_transaction(source, Some(who), None, info.logs.len());
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Executed {
@@ -741,6 +847,8 @@ pub mod module {
 				T::config(),
 			) {
 				Err(e) => {
+					Self::note_transaction(from, None, None, 0);
+
 					Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed {
 						from,
 						contract: target,
@@ -755,6 +863,7 @@ pub mod module {
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::note_transaction(from, None, None, info.logs.len());
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Executed {
@@ -822,20 +931,28 @@ pub mod module {
 
 			Self::ensure_eoa(&source)?;
 
-			let outcome = T::Runner::create(
-				source,
-				input,
-				value,
-				gas_limit,
-				storage_limit,
-				access_list.into_iter().map(|v| (v.address, v.storage_keys)).collect(),
-				T::config(),
-			);
+			let access_list = access_list.into_iter().map(|v| (v.address, v.storage_keys)).collect();
+			let outcome = match LaneCreateNonce::<T>::take().map(|nonce| Self::lane_create_address(&source, nonce)) {
+				// eth tx of an account using nonce lanes, the sum of the lanes is not known when
+				// signing, so the address is derived from the signed lane nonce instead.
+				Some(address) if !is_system_contract(&address) => T::Runner::create_at_address(
+					source,
+					address,
+					input,
+					value,
+					gas_limit,
+					storage_limit,
+					access_list,
+					T::config(),
+				),
+				_ => T::Runner::create(source, input, value, gas_limit, storage_limit, access_list, T::config()),
+			};
 
 			match outcome {
 				Err(e) => {
 					// EVM state changes reverted, increase nonce by ourselves
 					Self::inc_nonce(&source);
+					Self::note_transaction(source, Some(who), None, 0);
 
 					Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
 						from: source,
@@ -850,6 +967,12 @@ pub mod module {
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::note_transaction(
+						source,
+						Some(who),
+						info.exit_reason.is_succeed().then_some(info.value),
+						info.logs.len(),
+					);
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Created {
@@ -917,6 +1040,7 @@ pub mod module {
 				Err(e) => {
 					// EVM state changes reverted, increase nonce by ourselves
 					Self::inc_nonce(&source);
+					Self::note_transaction(source, Some(who), None, 0);
 
 					Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
 						from: source,
@@ -931,6 +1055,12 @@ pub mod module {
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::note_transaction(
+						source,
+						Some(who),
+						info.exit_reason.is_succeed().then_some(info.value),
+						info.logs.len(),
+					);
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Created {
@@ -966,6 +1096,7 @@ pub mod module {
 		/// - `value`: the amount sent for payable calls
 		/// - `gas_limit`: the maximum gas the call can use
 		/// - `storage_limit`: the total bytes the contract's storage can increase by
+		/// - `registry_key`: the key to register the created contract in `SystemContracts` with
 		#[pallet::call_index(5)]
 		#[pallet::weight(create_nft_contract::<T>(*gas_limit))]
 		pub fn create_nft_contract(
@@ -975,8 +1106,10 @@ pub mod module {
 			#[pallet::compact] gas_limit: u64,
 			#[pallet::compact] storage_limit: u32,
 			access_list: Vec<AccessListItem>,
+			registry_key: Option<SystemContractKey>,
 		) -> DispatchResultWithPostInfo {
 			T::NetworkContractOrigin::ensure_origin(origin)?;
+			Self::ensure_system_contract_key_available(&registry_key)?;
 
 			let source = T::NetworkContractSource::get();
 			let source_account = T::AddressMapping::get_account_id(&source);
@@ -1028,6 +1161,7 @@ pub mod module {
 							used_gas,
 							used_storage: info.used_storage,
 						});
+						Self::register_system_contract(registry_key, info.value);
 					} else {
 						Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
 							from: source,
@@ -1055,6 +1189,7 @@ pub mod module {
 		/// - `value`: the amount sent for payable calls
 		/// - `gas_limit`: the maximum gas the call can use
 		/// - `storage_limit`: the total bytes the contract's storage can increase by
+		/// - `registry_key`: the key to register the created contract in `SystemContracts` with
 		#[pallet::call_index(6)]
 		#[pallet::weight(create_predeploy_contract::<T>(*gas_limit))]
 		pub fn create_predeploy_contract(
@@ -1065,10 +1200,12 @@ pub mod module {
 			#[pallet::compact] gas_limit: u64,
 			#[pallet::compact] storage_limit: u32,
 			access_list: Vec<AccessListItem>,
+			registry_key: Option<SystemContractKey>,
 		) -> DispatchResultWithPostInfo {
 			T::NetworkContractOrigin::ensure_origin(origin)?;
 
 			ensure!(Self::accounts(target).is_none(), Error::<T>::ContractAlreadyExisted);
+			Self::ensure_system_contract_key_available(&registry_key)?;
 
 			let source = T::NetworkContractSource::get();
 			let source_account = T::AddressMapping::get_account_id(&source);
@@ -1117,6 +1254,7 @@ pub mod module {
 							used_gas,
 							used_storage: info.used_storage,
 						});
+						Self::register_system_contract(registry_key, contract);
 					} else {
 						Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
 							from: source,
@@ -1240,6 +1378,31 @@ pub mod module {
 			Ok(().into())
 		}
 
+		/// Correct the system contract registered with the key.
+		///
+		/// The dispatch origin of this call must be `NetworkContractOrigin`.
+		///
+		/// - `key`: the well-known key of the system contract
+		/// - `contract`: the contract to register, `None` to unregister the key
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_system_contract())]
+		pub fn set_system_contract(
+			origin: OriginFor<T>,
+			key: SystemContractKey,
+			contract: Option<EvmAddress>,
+		) -> DispatchResultWithPostInfo {
+			T::NetworkContractOrigin::ensure_origin(origin)?;
+
+			if let Some(contract) = contract {
+				ensure!(Self::is_contract(&contract), Error::<T>::ContractNotFound);
+			}
+			SystemContracts::<T>::set(&key, contract);
+
+			Pallet::<T>::deposit_event(Event::<T>::SystemContractUpdated { key, contract });
+
+			Ok(().into())
+		}
+
 		/// Issue an EVM call operation in `Utility::batch_all`. This is same as the evm.call but
 		/// returns error when it failed. The current evm.call always success and emit event to
 		/// indicate it failed.
@@ -1284,6 +1447,8 @@ pub mod module {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
 
 					if info.exit_reason.is_succeed() {
+						Self::note_transaction(source, Some(who), None, info.logs.len());
+
 						Pallet::<T>::deposit_event(Event::<T>::Executed {
 							from: source,
 							contract: target,
@@ -1331,6 +1496,32 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Get the system contract registered with the key.
+	pub fn get_system_contract(key: &[u8]) -> Option<EvmAddress> {
+		let key: SystemContractKey = key.to_vec().try_into().ok()?;
+		Self::system_contracts(key)
+	}
+
+	fn ensure_system_contract_key_available(key: &Option<SystemContractKey>) -> DispatchResult {
+		if let Some(key) = key {
+			ensure!(
+				!SystemContracts::<T>::contains_key(key),
+				Error::<T>::SystemContractKeyExisted
+			);
+		}
+		Ok(())
+	}
+
+	fn register_system_contract(key: Option<SystemContractKey>, contract: EvmAddress) {
+		if let Some(key) = key {
+			SystemContracts::<T>::insert(&key, contract);
+			Pallet::<T>::deposit_event(Event::<T>::SystemContractUpdated {
+				key,
+				contract: Some(contract),
+			});
+		}
+	}
+
 	/// Get StorageDepositPerByte of actual decimals
 	pub fn get_storage_deposit_per_byte() -> BalanceOf<T> {
 		// StorageDepositPerByte decimals is 18, KAR/ACA decimals is 12, convert to 12 here.
@@ -1385,7 +1576,19 @@ impl<T: Config> Pallet<T> {
 					}
 				});
 
-				let _total_size = ContractStorageSizes::<T>::take(contract);
+				let total_size = ContractStorageSizes::<T>::take(contract);
+
+				// calls revert until the storages are purged
+				ContractPurges::<T>::insert(
+					contract,
+					ContractPurgeProgress {
+						total_items: total_size
+							.saturating_sub(code_size.saturating_add(T::NewContractExtraBytes::get()))
+							.saturating_div(STORAGE_SIZE),
+						purged_items: 0,
+						refunded: Zero::zero(),
+					},
+				);
 
 				// schedule to remove
 				T::IdleScheduler::schedule(
@@ -1586,6 +1789,104 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Returns the EVM transactions executed in the block. Returns `None` if the block has no
+	/// EVM transactions or is out of the retention.
+	pub fn get_block_tx_index(block_number: BlockNumberFor<T>) -> Option<Vec<EvmTransactionIndex<T::AccountId>>> {
+		let slot = Self::transaction_index_slot(block_number)?;
+		Self::block_transaction_index(slot)
+			.and_then(|(indexed_at, transactions)| (indexed_at == block_number).then_some(transactions))
+	}
+
+	fn transaction_index_slot(block_number: BlockNumberFor<T>) -> Option<u32> {
+		let retention = T::TransactionIndexRetention::get();
+		if retention.is_zero() {
+			return None;
+		}
+		let block_number: u32 = block_number.unique_saturated_into();
+		Some(block_number % retention)
+	}
+
+	/// Notes the EVM transaction executed by the current extrinsic, the transaction hash is
+	/// computed on finalize.
+	fn note_transaction(
+		from: EvmAddress,
+		account_id: Option<T::AccountId>,
+		contract_address: Option<EvmAddress>,
+		log_count: usize,
+	) {
+		if T::TransactionIndexRetention::get().is_zero() {
+			return;
+		}
+
+		if let Some(extrinsic_index) = frame_system::Pallet::<T>::extrinsic_index() {
+			PendingTransactionIndex::<T>::append(EvmTransactionIndex {
+				extrinsic_index,
+				transaction_hash: Default::default(),
+				from,
+				account_id,
+				contract_address,
+				log_count: log_count.saturated_into(),
+			});
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::on_finalize_with_transaction_index(1)
+					.saturating_sub(T::WeightInfo::on_finalize_with_transaction_index(0)),
+				DispatchClass::Mandatory,
+			);
+		}
+	}
+
+	/// Writes the EVM transactions executed in the block to the slot of the block, which
+	/// overwrites the index of the expired block. The slot is pruned if there are no EVM
+	/// transactions.
+	fn index_block_transactions(now: BlockNumberFor<T>) {
+		let Some(slot) = Self::transaction_index_slot(now) else {
+			return;
+		};
+
+		let mut transactions = PendingTransactionIndex::<T>::take();
+		if transactions.is_empty() {
+			BlockTransactionIndex::<T>::remove(slot);
+			return;
+		}
+
+		// an extrinsic can execute multiple EVM transactions, e.g. in a batch.
+		let mut last_hash: Option<(u32, H256)> = None;
+		for transaction in transactions.iter_mut() {
+			let transaction_hash = match last_hash {
+				Some((extrinsic_index, hash)) if extrinsic_index == transaction.extrinsic_index => hash,
+				_ => {
+					T::TransactionHash::convert(frame_system::Pallet::<T>::extrinsic_data(transaction.extrinsic_index))
+				}
+			};
+			transaction.transaction_hash = transaction_hash;
+			last_hash = Some((transaction.extrinsic_index, transaction_hash));
+		}
+
+		BlockTransactionIndex::<T>::insert(slot, (now, transactions));
+	}
+
+	/// Consumes the block storage budget by the net storage growth of an EVM transaction.
+	/// Storage neutral and shrinking transactions consume nothing.
+	pub(crate) fn consume_block_storage_budget(storage: i32) -> DispatchResult {
+		let Ok(growth) = u32::try_from(storage) else {
+			return Ok(());
+		};
+		if growth.is_zero() {
+			return Ok(());
+		}
+
+		BlockStorageUsed::<T>::try_mutate(|used| -> DispatchResult {
+			let new_used = used.saturating_add(growth);
+			ensure!(
+				new_used <= T::BlockStorageBudget::get(),
+				Error::<T>::BlockStorageBudgetExceeded
+			);
+			*used = new_used;
+			Ok(())
+		})
+	}
+
 	/// Sets a given contract's contract info to a new maintainer.
 	fn do_transfer_maintainer(who: T::AccountId, contract: EvmAddress, new_maintainer: EvmAddress) -> DispatchResult {
 		Accounts::<T>::mutate(contract, |maybe_account_info| -> DispatchResult {
@@ -1861,7 +2162,34 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn refund_storage(caller: &H160, contract: &H160, maintainer: &H160) -> DispatchResult {
+	/// Refunds the storage deposit of the purged storage items of a contract being removed.
+	fn refund_purged_storage(caller: &H160, contract: &H160, purged_items: u32) -> Result<BalanceOf<T>, DispatchError> {
+		let user = T::AddressMapping::get_account_id(caller);
+		let contract_acc = T::AddressMapping::get_account_id(contract);
+		let amount = Self::get_storage_deposit_per_byte()
+			.saturating_mul(purged_items.saturating_mul(STORAGE_SIZE).into())
+			.min(T::Currency::reserved_balance_named(
+				&RESERVE_ID_STORAGE_DEPOSIT,
+				&contract_acc,
+			));
+		if amount.is_zero() {
+			return Ok(Zero::zero());
+		}
+
+		// user can't be a dead account
+		let remaining = T::Currency::repatriate_reserved_named(
+			&RESERVE_ID_STORAGE_DEPOSIT,
+			&contract_acc,
+			&user,
+			amount,
+			BalanceStatus::Free,
+		)?;
+		debug_assert!(remaining.is_zero());
+
+		Ok(amount.saturating_sub(remaining))
+	}
+
+	fn refund_storage(caller: &H160, contract: &H160, maintainer: &H160) -> Result<BalanceOf<T>, DispatchError> {
 		let user = T::AddressMapping::get_account_id(caller);
 		let contract_acc = T::AddressMapping::get_account_id(contract);
 		let maintainer_acc = T::AddressMapping::get_account_id(maintainer);
@@ -1892,9 +2220,27 @@ impl<T: Config> Pallet<T> {
 
 		T::TransferAll::transfer_all(&contract_acc, &dest)?;
 
-		Ok(())
+		Ok(amount.saturating_sub(val))
 	}
 
+	/// The CREATE address of an eth tx using nonce lanes.
+	///
+	/// Same as the legacy CREATE scheme, but the signed lane nonce (lane index in the high bits)
+	/// is used instead of the EVM account nonce, which is the sum of all lanes. The nonce is
+	/// flagged with `NONCE_LANE_CREATE_FLAG`, so lane 0 never derives an address already taken by
+	/// a create using the EVM account nonce.
+	pub fn lane_create_address(caller: &H160, nonce: T::Nonce) -> H160 {
+		let nonce = U256::from(UniqueSaturatedInto::<u64>::unique_saturated_into(nonce) | NONCE_LANE_CREATE_FLAG);
+		let mut stream = rlp::RlpStream::new_list(2);
+		stream.append(caller);
+		stream.append(&nonce);
+		H256::from_slice(Keccak256::digest(stream.out()).as_slice()).into()
+	}
+
+	/// Bump the EVM nonce of `origin`.
+	///
+	/// NOTE: For accounts using nonce lanes, the EVM nonce is bumped for transactions of every
+	/// lane, so it stays the sum of all lanes.
 	fn inc_nonce(origin: &H160) {
 		Accounts::<T>::mutate(origin, |account| {
 			if let Some(info) = account.as_mut() {
@@ -2227,12 +2573,31 @@ impl<T: Config> DispatchableTask for EvmTask<T> {
 						.write
 						.saturating_mul(count.into()),
 					0,
-				);
+				)
+				.saturating_add(<T as frame_system::Config>::DbWeight::get().reads_writes(3, 3));
 				log::debug!(
 					target: "evm",
 					"EvmTask remove: [from: {:?}, contract: {:?}, maintainer: {:?}, count: {:?}]",
 					caller, contract, maintainer, count
 				);
+
+				// refund the storage deposit of the purged storages and update the progress
+				let refunded = Pallet::<T>::refund_purged_storage(&caller, &contract, count).unwrap_or_else(|e| {
+					log::debug!(
+						target: "evm",
+						"EvmTask refund_purged_storage: [from: {:?}, contract: {:?}, count: {:?}, error: {:?}]",
+						caller, contract, count, e
+					);
+					Zero::zero()
+				});
+				let mut progress = ContractPurges::<T>::get(contract).unwrap_or(ContractPurgeProgress {
+					total_items: 0,
+					purged_items: 0,
+					refunded: Zero::zero(),
+				});
+				progress.purged_items = progress.purged_items.saturating_add(count);
+				progress.refunded = progress.refunded.saturating_add(refunded);
+
 				if r.maybe_cursor.is_none() {
 					// AllRemoved
 					let result = Pallet::<T>::refund_storage(&caller, &contract, &maintainer);
@@ -2246,13 +2611,23 @@ impl<T: Config> DispatchableTask for EvmTask<T> {
 
 					// Remove account after all of the storages are cleared.
 					Pallet::<T>::remove_account(&contract);
+					ContractPurges::<T>::remove(contract);
+
+					Pallet::<T>::deposit_event(Event::<T>::ContractRemoved {
+						contract,
+						refunded: progress
+							.refunded
+							.saturating_add(result.as_ref().copied().unwrap_or_default()),
+					});
 
 					TaskResult {
-						result,
+						result: result.map(|_| ()),
 						used_weight,
 						finished: true,
 					}
 				} else {
+					ContractPurges::<T>::insert(contract, progress);
+
 					// SomeRemaining
 					TaskResult {
 						result: Ok(()),
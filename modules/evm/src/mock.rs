@@ -151,6 +151,13 @@ impl Convert<u64, Weight> for GasToWeight {
 	}
 }
 
+pub struct MockTransactionHash;
+impl Convert<Vec<u8>, H256> for MockTransactionHash {
+	fn convert(extrinsic: Vec<u8>) -> H256 {
+		H256::from(sp_io::hashing::keccak_256(&extrinsic))
+	}
+}
+
 pub struct AuthorGiven;
 impl FindAuthor<AccountId32> for AuthorGiven {
 	fn find_author<'a, I>(_digests: I) -> Option<AccountId32>
@@ -165,6 +172,7 @@ impl FindAuthor<AccountId32> for AuthorGiven {
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice();
+	pub static BlockStorageBudget: u32 = u32::MAX;
 }
 
 ord_parameter_types! {
@@ -203,6 +211,9 @@ impl Config for Runtime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type TransactionIndexRetention = ConstU32<3>;
+	type TransactionHash = MockTransactionHash;
+	type BlockStorageBudget = BlockStorageBudget;
 	type WeightInfo = ();
 }
 
@@ -26,7 +26,7 @@ pub mod tracing;
 
 use crate::{BalanceOf, CallInfo, Config, CreateInfo};
 use module_evm_utility::evm;
-pub use primitives::evm::{EvmAddress, Vicinity};
+pub use primitives::evm::{EstimateResourcesResponse, EthCallRequest, EvmAddress, Vicinity};
 use sp_core::{H160, H256};
 use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
@@ -99,4 +99,21 @@ pub trait RunnerExtended<T: Config>: Runner<T> {
 		access_list: Vec<(H160, Vec<H256>)>,
 		config: &evm::Config,
 	) -> Result<CreateInfo, DispatchError>;
+
+	fn rpc_batch_call(
+		requests: Vec<EthCallRequest<BalanceOf<T>>>,
+		max_gas_limit: u64,
+		config: &evm::Config,
+	) -> Result<Vec<Result<CallInfo, DispatchError>>, DispatchError>;
+
+	fn rpc_estimate_resources(
+		source: H160,
+		target: Option<H160>,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+		config: &evm::Config,
+	) -> Result<EstimateResourcesResponse<BalanceOf<T>>, DispatchError>;
 }
@@ -24,10 +24,12 @@ use crate::{
 		state::{Accessed, StackExecutor, StackState as StackStateT, StackSubstateMetadata},
 		Runner as RunnerT, RunnerExtended,
 	},
-	AccountStorages, BalanceOf, CallInfo, Config, CreateInfo, Error, ExecutionInfo, Pallet, STORAGE_SIZE,
+	AccountStorages, BalanceOf, CallInfo, Config, ContractPurges, CreateInfo, Error, ExecutionInfo, Pallet,
+	MAX_BATCH_CALL_SIZE, STORAGE_SIZE,
 };
 use frame_support::{
 	ensure,
+	storage::with_transaction,
 	traits::{Currency, ExistenceRequirement, Get},
 	transactional,
 };
@@ -38,13 +40,16 @@ use module_evm_utility::{
 };
 use module_support::{AddressMapping, EVMManager, EVM};
 pub use primitives::{
-	evm::{convert_decimals_from_evm, EvmAddress, Vicinity, MIRRORED_NFT_ADDRESS_START},
+	evm::{
+		convert_decimals_from_evm, EstimateResourcesResponse, EthCallRequest, EvmAddress, Vicinity,
+		MIRRORED_NFT_ADDRESS_START,
+	},
 	ReserveIdentifier,
 };
 use sp_core::{defer, H160, H256, U256};
 use sp_runtime::{
-	traits::{UniqueSaturatedInto, Zero},
-	DispatchError,
+	traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedInto, Zero},
+	DispatchError, TransactionOutcome,
 };
 use sp_std::{
 	boxed::Box,
@@ -176,6 +181,17 @@ impl<T: Config> Runner<T> {
 			return Err(Error::<T>::ChargeStorageFailed.into());
 		}
 
+		Pallet::<T>::consume_block_storage_budget(actual_storage).map_err(|e| {
+			log::debug!(
+				target: "evm",
+				"BlockStorageBudgetExceeded [source: {:?}, actual_storage: {:?}, block_storage_used: {:?}]",
+				origin,
+				actual_storage,
+				Pallet::<T>::block_storage_used()
+			);
+			e
+		})?;
+
 		if !skip_storage_rent {
 			Pallet::<T>::unreserve_storage(&origin, storage_limit, used_storage, refunded_storage).map_err(|e| {
 				log::debug!(
@@ -222,6 +238,91 @@ impl<T: Config> Runner<T> {
 			logs: state.substate.logs,
 		})
 	}
+
+	/// Execute a call which won't charge for storage rent. A static call is executed in a
+	/// static context, any state modification will revert.
+	fn execute_rpc_call(
+		source: H160,
+		origin: H160,
+		target: H160,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+		is_static: bool,
+		config: &evm::Config,
+		precompiles: &T::PrecompilesType,
+	) -> Result<CallInfo, DispatchError> {
+		// Ensure eth_call has evm origin, otherwise xcm charge rent fee will fail.
+		Pallet::<T>::set_origin(T::AddressMapping::get_account_id(&origin));
+		defer!(Pallet::<T>::kill_origin());
+
+		let value = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(value));
+		ensure!(!is_static || value.is_zero(), Error::<T>::StaticCallWithValue);
+		Self::execute(
+			source,
+			origin,
+			value,
+			gas_limit,
+			storage_limit,
+			config,
+			true,
+			precompiles,
+			|executor| {
+				*executor.state_mut().metadata_mut().is_static_mut() = is_static;
+				executor.transact_call(source, target, value, input, gas_limit, access_list)
+			},
+		)
+	}
+
+	/// Execute a create which won't charge for storage rent.
+	fn execute_rpc_create(
+		source: H160,
+		init: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+		config: &evm::Config,
+		precompiles: &T::PrecompilesType,
+	) -> Result<CreateInfo, DispatchError> {
+		let value = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(value));
+		Self::execute(
+			source,
+			source,
+			value,
+			gas_limit,
+			storage_limit,
+			config,
+			true,
+			precompiles,
+			|executor| {
+				let address = executor
+					.create_address(evm::CreateScheme::Legacy { caller: source })
+					.unwrap_or_default(); // transact_create will check the address
+				let (reason, _) = executor.transact_create(source, value, init, gas_limit, access_list);
+				(reason, address)
+			},
+		)
+	}
+
+	/// Binary search the minimal limit in `[low, high]` the execution succeeds with, the
+	/// execution must succeed with `high`.
+	fn search_minimal_limit<L>(mut low: L, mut high: L, succeeds: impl Fn(L) -> bool) -> L
+	where
+		L: AtLeast32BitUnsigned + Copy,
+	{
+		while low < high {
+			let mid = low + (high - low) / L::from(2u32);
+			if succeeds(mid) {
+				high = mid;
+			} else {
+				low = mid + L::one();
+			}
+		}
+		high
+	}
 }
 
 impl<T: Config> RunnerT<T> for Runner<T> {
@@ -238,6 +339,12 @@ impl<T: Config> RunnerT<T> for Runner<T> {
 		access_list: Vec<(H160, Vec<H256>)>,
 		config: &evm::Config,
 	) -> Result<CallInfo, DispatchError> {
+		// the contract is being removed, its storage is partially purged.
+		ensure!(
+			!ContractPurges::<T>::contains_key(target),
+			Error::<T>::ContractSelfDestructing
+		);
+
 		// if the contract not published, the caller must be developer or contract or maintainer.
 		// if the contract not exists, let evm try to execute it and handle the error.
 		ensure!(
@@ -376,22 +483,19 @@ impl<T: Config> RunnerExtended<T> for Runner<T> {
 		access_list: Vec<(H160, Vec<H256>)>,
 		config: &evm::Config,
 	) -> Result<CallInfo, DispatchError> {
-		// Ensure eth_call has evm origin, otherwise xcm charge rent fee will fail.
-		Pallet::<T>::set_origin(T::AddressMapping::get_account_id(&origin));
-		defer!(Pallet::<T>::kill_origin());
-
 		let precompiles = T::PrecompilesValue::get();
-		let value = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(value));
-		Self::execute(
+		Self::execute_rpc_call(
 			source,
 			origin,
+			target,
+			input,
 			value,
 			gas_limit,
 			storage_limit,
+			access_list,
+			false,
 			config,
-			true,
 			&precompiles,
-			|executor| executor.transact_call(source, target, value, input, gas_limit, access_list),
 		)
 	}
 
@@ -407,25 +511,147 @@ impl<T: Config> RunnerExtended<T> for Runner<T> {
 		config: &evm::Config,
 	) -> Result<CreateInfo, DispatchError> {
 		let precompiles = T::PrecompilesValue::get();
-		let value = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(value));
-		Self::execute(
-			source,
+		Self::execute_rpc_create(
 			source,
+			init,
 			value,
 			gas_limit,
 			storage_limit,
+			access_list,
 			config,
-			true,
 			&precompiles,
-			|executor| {
-				let address = executor
-					.create_address(evm::CreateScheme::Legacy { caller: source })
-					.unwrap_or_default(); // transact_create will check the address
-				let (reason, _) = executor.transact_create(source, value, init, gas_limit, access_list);
-				(reason, address)
-			},
 		)
 	}
+
+	/// Execute multiple rpc calls against the same state, sharing the precompiles setup.
+	/// Changes made by a call are discarded before executing the next one.
+	fn rpc_batch_call(
+		requests: Vec<EthCallRequest<BalanceOf<T>>>,
+		max_gas_limit: u64,
+		config: &evm::Config,
+	) -> Result<Vec<Result<CallInfo, DispatchError>>, DispatchError> {
+		ensure!(
+			requests.len() <= MAX_BATCH_CALL_SIZE as usize,
+			Error::<T>::BatchCallTooLarge
+		);
+		let total_gas_limit = requests
+			.iter()
+			.fold(0u64, |total, request| total.saturating_add(request.gas_limit));
+		ensure!(total_gas_limit <= max_gas_limit, Error::<T>::BatchCallGasLimitExceeded);
+
+		let precompiles = T::PrecompilesValue::get();
+		Ok(requests
+			.into_iter()
+			.map(|request| {
+				with_transaction(|| {
+					TransactionOutcome::Rollback(Self::execute_rpc_call(
+						request.from,
+						request.from,
+						request.to,
+						request.data,
+						request.value,
+						request.gas_limit,
+						request.storage_limit,
+						request
+							.access_list
+							.unwrap_or_default()
+							.into_iter()
+							.map(|v| (v.address, v.storage_keys))
+							.collect(),
+						request.static_call,
+						config,
+						&precompiles,
+					))
+				})
+			})
+			.collect())
+	}
+
+	/// Estimate the minimal gas limit and storage limit of a call, or of a create if `target` is
+	/// `None`, by searching the limits the execution succeeds with. The storage limit is searched
+	/// too as the storage freed late can't cover the storage used early. All the executions are
+	/// reverted.
+	fn rpc_estimate_resources(
+		source: H160,
+		target: Option<H160>,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+		config: &evm::Config,
+	) -> Result<EstimateResourcesResponse<BalanceOf<T>>, DispatchError> {
+		let precompiles = T::PrecompilesValue::get();
+		let execute = |gas_limit: u64, storage_limit: u32| {
+			with_transaction(|| {
+				TransactionOutcome::Rollback(match target {
+					Some(target) => Self::execute_rpc_call(
+						source,
+						source,
+						target,
+						input.clone(),
+						value,
+						gas_limit,
+						storage_limit,
+						access_list.clone(),
+						false,
+						config,
+						&precompiles,
+					),
+					None => Self::execute_rpc_create(
+						source,
+						input.clone(),
+						value,
+						gas_limit,
+						storage_limit,
+						access_list.clone(),
+						config,
+						&precompiles,
+					)
+					.map(|info| CallInfo {
+						exit_reason: info.exit_reason,
+						value: info.value.as_bytes().to_vec(),
+						used_gas: info.used_gas,
+						used_storage: info.used_storage,
+						logs: info.logs,
+					}),
+				})
+			})
+		};
+		let succeeds = |gas_limit: u64, storage_limit: u32| {
+			execute(gas_limit, storage_limit).is_ok_and(|info| info.exit_reason.is_succeed())
+		};
+
+		let info = execute(gas_limit, storage_limit)?;
+		let used_gas = UniqueSaturatedInto::<u64>::unique_saturated_into(info.used_gas);
+		let (estimated_gas_limit, estimated_storage_limit) = if info.exit_reason.is_succeed() {
+			let estimated_storage_limit =
+				Self::search_minimal_limit(info.used_storage.max(0) as u32, storage_limit, |storage_limit| {
+					succeeds(gas_limit, storage_limit)
+				});
+			let estimated_gas_limit = Self::search_minimal_limit(used_gas, gas_limit, |gas_limit| {
+				succeeds(gas_limit, estimated_storage_limit)
+			});
+			(estimated_gas_limit, estimated_storage_limit)
+		} else {
+			(gas_limit, storage_limit)
+		};
+
+		let deposit_per_byte = Pallet::<T>::get_storage_deposit_per_byte();
+		let storage_deposit = deposit_per_byte.saturating_mul((info.used_storage.max(0) as u32).into());
+		let storage_refund = deposit_per_byte.saturating_mul(info.used_storage.min(0).unsigned_abs().into());
+
+		Ok(EstimateResourcesResponse {
+			exit_reason: info.exit_reason,
+			value: info.value,
+			used_gas,
+			gas_limit: estimated_gas_limit,
+			used_storage: info.used_storage,
+			storage_limit: estimated_storage_limit,
+			storage_deposit,
+			storage_refund,
+		})
+	}
 }
 
 struct SubstrateStackSubstate<'config> {
@@ -923,6 +1149,10 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 		self.substate.mark_account_dirty(_address);
 	}
 
+	fn is_self_destructing(&self, address: H160) -> bool {
+		ContractPurges::<T>::contains_key(address)
+	}
+
 	fn is_cold(&self, address: H160) -> bool {
 		self.substate
 			.recursive_is_cold(&|a| a.accessed_addresses.contains(&address))
@@ -27,7 +27,7 @@ use core::{cmp::min, convert::Infallible};
 use module_evm_utility::{
 	evm::{
 		backend::Backend, maybe_borrowed::MaybeBorrowed, Capture, Config, Context, CreateScheme, ExitError, ExitFatal,
-		ExitReason, Opcode, Resolve, Runtime, Stack, Transfer,
+		ExitReason, ExitRevert, Opcode, Resolve, Runtime, Stack, Transfer,
 	},
 	evm_gasometer::{self as gasometer, Gasometer, StorageTarget},
 	evm_runtime::Handler,
@@ -234,6 +234,10 @@ impl<'config> StackSubstateMetadata<'config> {
 		self.is_static
 	}
 
+	pub fn is_static_mut(&mut self) -> &mut bool {
+		&mut self.is_static
+	}
+
 	pub fn depth(&self) -> Option<usize> {
 		self.depth
 	}
@@ -321,6 +325,11 @@ pub trait StackState<'config>: Backend {
 	fn reset_balance(&mut self, address: H160);
 	fn touch(&mut self, address: H160);
 
+	/// Whether the contract at `address` is being removed. Calls into it always revert.
+	fn is_self_destructing(&self, _address: H160) -> bool {
+		false
+	}
+
 	/// Fetch the code size of an address.
 	/// Provide a default implementation by fetching the code, but
 	/// can be customized to use a more performant approach that don't need to
@@ -1035,6 +1044,14 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 		}
 		let code = self.code(code_address);
 
+		if self.state.is_self_destructing(code_address) {
+			let _ = self.exit_substate(StackExitKind::Reverted);
+			return Capture::Exit((
+				ExitReason::Revert(ExitRevert::Reverted),
+				encode_revert_message(b"contract is self destructing"),
+			));
+		}
+
 		if let Some(depth) = self.state.metadata().depth {
 			if depth > self.config.call_stack_limit {
 				let _ = self.exit_substate(StackExitKind::Reverted);
@@ -24,6 +24,7 @@ use mock::{evm_mod, RuntimeCall, RuntimeEvent, *};
 use crate::runner::{
 	stack::SubstrateStackState,
 	state::{StackExecutor, StackState, StackSubstateMetadata},
+	EthCallRequest, RunnerExtended,
 };
 use frame_support::{assert_noop, assert_ok};
 use insta::assert_debug_snapshot;
@@ -791,6 +792,7 @@ fn create_nft_contract_works() {
 			1000000,
 			1000000,
 			vec![],
+			None,
 		));
 
 		assert_eq!(
@@ -830,6 +832,7 @@ fn create_nft_contract_fails_if_non_network_contract_origin() {
 				1000000,
 				1000000,
 				vec![],
+				None,
 			),
 			BadOrigin
 		);
@@ -863,6 +866,7 @@ fn create_predeploy_contract_works() {
 			1000000,
 			1000000,
 			vec![],
+			None,
 		));
 
 		assert_eq!(Pallet::<Runtime>::is_account_empty(&addr), false);
@@ -884,6 +888,7 @@ fn create_predeploy_contract_works() {
 				1000000,
 				1000000,
 				vec![],
+				None,
 			),
 			Error::<Runtime>::ContractAlreadyExisted
 		);
@@ -902,6 +907,7 @@ fn create_predeploy_contract_works() {
 			1000000,
 			1000000,
 			vec![],
+			None,
 		));
 		System::assert_has_event(RuntimeEvent::EVM(crate::Event::CreatedFailed {
 			from: NetworkContractSource::get(),
@@ -918,6 +924,126 @@ fn create_predeploy_contract_works() {
 	});
 }
 
+#[test]
+fn system_contract_registry_works() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+	let key = SystemContractKey::truncate_from(b"DEX_ROUTER".to_vec());
+
+	new_test_ext().execute_with(|| {
+		let addr = H160::from_str("1111111111111111111111111111111111111111").unwrap();
+		assert_eq!(EVM::get_system_contract(b"DEX_ROUTER"), None);
+
+		// deploy contract and register it
+		assert_ok!(EVM::create_predeploy_contract(
+			RuntimeOrigin::signed(NetworkContractAccount::get()),
+			addr,
+			contract.clone(),
+			0,
+			1000000,
+			1000000,
+			vec![],
+			Some(key.clone()),
+		));
+		System::assert_has_event(RuntimeEvent::EVM(crate::Event::SystemContractUpdated {
+			key: key.clone(),
+			contract: Some(addr),
+		}));
+		assert_eq!(EVM::system_contracts(&key), Some(addr));
+		assert_eq!(EVM::get_system_contract(b"DEX_ROUTER"), Some(addr));
+
+		// the key can not be registered twice
+		assert_noop!(
+			EVM::create_predeploy_contract(
+				RuntimeOrigin::signed(NetworkContractAccount::get()),
+				H160::from_str("2222222222222222222222222222222222222222").unwrap(),
+				contract.clone(),
+				0,
+				1000000,
+				1000000,
+				vec![],
+				Some(key.clone()),
+			),
+			Error::<Runtime>::SystemContractKeyExisted
+		);
+		assert_noop!(
+			EVM::create_nft_contract(
+				RuntimeOrigin::signed(NetworkContractAccount::get()),
+				contract.clone(),
+				0,
+				1000000,
+				1000000,
+				vec![],
+				Some(key.clone()),
+			),
+			Error::<Runtime>::SystemContractKeyExisted
+		);
+
+		// deploy nft contract with another key
+		let nft_key = SystemContractKey::truncate_from(b"NFT".to_vec());
+		let nft_addr = MIRRORED_TOKENS_ADDRESS_START | H160::from_low_u64_be(MIRRORED_NFT_ADDRESS_START);
+		assert_ok!(EVM::create_nft_contract(
+			RuntimeOrigin::signed(NetworkContractAccount::get()),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			Some(nft_key.clone()),
+		));
+		assert_eq!(EVM::system_contracts(&nft_key), Some(nft_addr));
+
+		// correct the registry
+		assert_noop!(
+			EVM::set_system_contract(
+				RuntimeOrigin::signed(AccountId32::from([1u8; 32])),
+				key.clone(),
+				Some(nft_addr)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			EVM::set_system_contract(
+				RuntimeOrigin::signed(NetworkContractAccount::get()),
+				key.clone(),
+				Some(H160::from_str("2222222222222222222222222222222222222222").unwrap())
+			),
+			Error::<Runtime>::ContractNotFound
+		);
+		assert_ok!(EVM::set_system_contract(
+			RuntimeOrigin::signed(NetworkContractAccount::get()),
+			key.clone(),
+			Some(nft_addr)
+		));
+		System::assert_last_event(RuntimeEvent::EVM(crate::Event::SystemContractUpdated {
+			key: key.clone(),
+			contract: Some(nft_addr),
+		}));
+		assert_eq!(EVM::get_system_contract(b"DEX_ROUTER"), Some(nft_addr));
+
+		// unregister
+		assert_ok!(EVM::set_system_contract(
+			RuntimeOrigin::signed(NetworkContractAccount::get()),
+			key.clone(),
+			None
+		));
+		System::assert_last_event(RuntimeEvent::EVM(crate::Event::SystemContractUpdated {
+			key: key.clone(),
+			contract: None,
+		}));
+		assert_eq!(EVM::system_contracts(&key), None);
+		assert_eq!(EVM::get_system_contract(b"DEX_ROUTER"), None);
+	});
+}
+
 #[test]
 fn should_transfer_maintainer() {
 	// pragma solidity ^0.5.0;
@@ -1479,6 +1605,7 @@ fn should_selfdestruct_without_schedule_task() {
 			1000000,
 			1000000,
 			vec![],
+			None,
 		));
 	});
 }
@@ -1642,6 +1769,195 @@ fn should_selfdestruct_with_schedule_task() {
 	});
 }
 
+#[test]
+fn should_purge_selfdestructed_contract_across_idle_windows() {
+	use frame_support::traits::{Currency, ExistenceRequirement, Hooks, NamedReservableCurrency};
+
+	// pragma solidity ^0.8.0;
+	//
+	// contract Test {
+	//     mapping(uint256 => uint256) private data;
+	//
+	//     constructor() public payable {}
+	//
+	//     function setValue(uint256 key, uint256 value) public {
+	//         data[key] = value;
+	//     }
+	// }
+	let contract = from_hex(
+		"0x6080604052610105806100136000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c80637b8d56e314602d575b600080fd5b60436004803603810190603f91906096565b6045565b005b80600080848152602001908152602001600020819055505050565b600080fd5b6000819050919050565b6076816065565b8114608057600080fd5b50565b600081359050609081606f565b92915050565b6000806040838503121560aa5760a96060565b5b600060b6858286016083565b925050602060c5858286016083565b915050925092905056fea26469706673582212201cbfb5695481e8cf4c7a1206d22d0a707cb85907a10b47038ac14af0c386344464736f6c63430008120033"
+	).unwrap();
+	// setValue(1, 1)
+	let input = from_hex(
+		"0x7b8d56e300000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001"
+	).unwrap();
+	let storage_count: u32 = 10_000;
+
+	let mut ext = new_test_ext();
+	let contract_address = ext.execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+
+		// so contracts are unpublished
+		assert_ok!(EVM::enable_account_contract_development(&alice_account_id));
+
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+		let contract_account_id = <Runtime as Config>::AddressMapping::get_account_id(&contract_address);
+
+		// the storages of the contract, the storage deposit is reserved on the contract
+		for i in 0..storage_count {
+			AccountStorages::<Runtime>::insert(
+				contract_address,
+				H256::from_low_u64_be(i.into()),
+				H256::from_low_u64_be(1),
+			);
+		}
+		EVM::update_contract_storage_size(&contract_address, (storage_count * STORAGE_SIZE) as i32);
+		let deposit = (storage_count * STORAGE_SIZE) as u128 * EVM::get_storage_deposit_per_byte();
+		assert_ok!(<Balances as Currency<_>>::transfer(
+			&alice_account_id,
+			&contract_account_id,
+			deposit,
+			ExistenceRequirement::AllowDeath
+		));
+		assert_ok!(Balances::reserve_named(
+			&RESERVE_ID_STORAGE_DEPOSIT,
+			&contract_account_id,
+			deposit
+		));
+
+		contract_address
+	});
+	// Keys in the overlay are deleted without counting towards the `limit`.
+	ext.commit_all().unwrap();
+
+	let storage_deposit = |items: u32| (items * STORAGE_SIZE) as u128 * EVM::get_storage_deposit_per_byte();
+	let alice_balance = ext.execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let alice_balance = balance(alice());
+
+		assert_ok!(EVM::selfdestruct(
+			RuntimeOrigin::signed(alice_account_id),
+			contract_address
+		));
+
+		// the first chunk is purged immediately
+		assert_eq!(
+			EVM::contract_purges(contract_address),
+			Some(ContractPurgeProgress {
+				total_items: storage_count,
+				purged_items: REMOVE_LIMIT,
+				refunded: storage_deposit(REMOVE_LIMIT),
+			})
+		);
+		assert_eq!(
+			AccountStorages::<Runtime>::iter_prefix(&contract_address).count(),
+			(storage_count - REMOVE_LIMIT) as usize
+		);
+		assert_eq!(balance(alice()), alice_balance + storage_deposit(REMOVE_LIMIT));
+		assert!(!System::events()
+			.iter()
+			.any(|record| matches!(record.event, RuntimeEvent::EVM(crate::Event::ContractRemoved { .. }))));
+
+		alice_balance
+	});
+	ext.commit_all().unwrap();
+
+	let mut idle_windows: u32 = 0;
+	while ext.execute_with(|| EVM::contract_purges(contract_address)).is_some() {
+		ext.execute_with(|| {
+			// calls revert until the purge completes
+			assert_noop!(
+				<Runtime as Config>::Runner::call(
+					alice(),
+					alice(),
+					contract_address,
+					input.clone(),
+					0,
+					1000000,
+					1000,
+					vec![],
+					<Runtime as Config>::config(),
+				),
+				Error::<Runtime>::ContractSelfDestructing
+			);
+
+			// calls from other contracts revert too
+			let vicinity = Vicinity {
+				gas_price: U256::one(),
+				..Default::default()
+			};
+			let metadata = StackSubstateMetadata::new(1000000, 1000, &ACALA_CONFIG);
+			let state = SubstrateStackState::<Runtime>::new(&vicinity, metadata);
+			let mut executor = StackExecutor::new_with_precompiles(state, &ACALA_CONFIG, &());
+			assert_eq!(
+				executor.transact_call(
+					contract_a(),
+					contract_address,
+					U256::zero(),
+					input.clone(),
+					1000000,
+					vec![]
+				),
+				(
+					ExitReason::Revert(ExitRevert::Reverted),
+					encode_revert_message(b"contract is self destructing")
+				)
+			);
+
+			IdleScheduler::on_idle(idle_windows.into(), Weight::from_parts(1_000_000_000_000, 0));
+			idle_windows += 1;
+
+			// the storage deposit is refunded incrementally
+			if let Some(progress) = EVM::contract_purges(contract_address) {
+				let purged_items = REMOVE_LIMIT * (idle_windows + 1);
+				assert_eq!(progress.purged_items, purged_items);
+				assert_eq!(progress.refunded, storage_deposit(purged_items));
+				assert_eq!(balance(alice()), alice_balance + storage_deposit(purged_items));
+			}
+		});
+		ext.commit_all().unwrap();
+	}
+	assert_eq!(idle_windows, storage_count / REMOVE_LIMIT - 1);
+
+	ext.execute_with(|| {
+		let contract_account_id = <Runtime as Config>::AddressMapping::get_account_id(&contract_address);
+		let refunded = storage_deposit(storage_count) + 361 * EVM::get_storage_deposit_per_byte();
+
+		System::assert_has_event(RuntimeEvent::EVM(crate::Event::ContractRemoved {
+			contract: contract_address,
+			refunded,
+		}));
+		assert_eq!(balance(alice()), alice_balance + refunded);
+		assert_eq!(reserved_balance(contract_address), 0);
+		assert!(!System::account_exists(&contract_account_id));
+		assert!(!Accounts::<Runtime>::contains_key(&contract_address));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 0);
+
+		// the contract can be called again, it has no code
+		assert_ok!(<Runtime as Config>::Runner::call(
+			alice(),
+			alice(),
+			contract_address,
+			input.clone(),
+			0,
+			1000000,
+			1000,
+			vec![],
+			<Runtime as Config>::config(),
+		));
+	});
+}
+
 #[test]
 fn storage_limit_should_work() {
 	// pragma solidity ^0.5.0;
@@ -2122,6 +2438,324 @@ fn should_update_storage() {
 	});
 }
 
+#[test]
+fn rpc_batch_call_works() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//     mapping(address => uint256) public values;
+	//
+	//     constructor() public {
+	//         values[msg.sender] = 42;
+	//     }
+	//
+	//     function set(uint val) public {
+	//      values[msg.sender] = val;
+	//     }
+	// }
+
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b50602a6000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610154806100646000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c806354fe9fd71461003b57806360fe47b114610093575b600080fd5b61007d6004803603602081101561005157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506100c1565b6040518082815260200191505060405180910390f35b6100bf600480360360208110156100a957600080fd5b81019080803590602001909291905050506100d9565b005b60006020528060005260406000206000915090505481565b806000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055505056fea265627a7a723158207ab6991e97c9c12f57d81df0c7f955435418354adeb26116b581d7f2f035ca8f64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let contract_address = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			500000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap()
+		.value;
+
+		let request = |data: &str, value: Balance, static_call: bool| EthCallRequest {
+			from: bob(),
+			to: contract_address,
+			data: from_hex(data).unwrap(),
+			value,
+			gas_limit: 1_000_000,
+			storage_limit: STORAGE_SIZE,
+			access_list: None,
+			static_call,
+		};
+		// values(alice)
+		let get_alice = "0x54fe9fd70000000000000000000000001000000000000000000000000000000000000001";
+		// values(bob)
+		let get_bob = "0x54fe9fd70000000000000000000000001000000000000000000000000000000000000002";
+		// set(123)
+		let set = "0x60fe47b1000000000000000000000000000000000000000000000000000000000000007b";
+
+		let results = <Runtime as Config>::Runner::rpc_batch_call(
+			vec![
+				request(get_alice, 0, true),
+				request(set, 0, false),
+				request(get_bob, 0, true),
+				request(set, 0, true),
+				request(get_alice, 1, true),
+			],
+			u64::MAX,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(results.len(), 5);
+
+		let info = results[0].as_ref().unwrap();
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from(info.value.as_slice()), 42.into());
+
+		let info = results[1].as_ref().unwrap();
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+		assert_eq!(info.used_storage, STORAGE_SIZE as i32);
+
+		// the state changes of the previous call are discarded
+		let info = results[2].as_ref().unwrap();
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from(info.value.as_slice()), 0.into());
+
+		// static call cannot modify the state
+		let info = results[3].as_ref().unwrap();
+		assert!(!info.exit_reason.is_succeed());
+		assert_eq!(info.used_storage, 0);
+
+		assert_eq!(results[4], Err(Error::<Runtime>::StaticCallWithValue.into()));
+
+		// nothing is persisted
+		assert_eq!(
+			ContractStorageSizes::<Runtime>::get(&contract_address),
+			340 + NEW_CONTRACT_EXTRA_BYTES + STORAGE_SIZE
+		);
+		assert_eq!(Pallet::<Runtime>::account_basic(&bob()).nonce, 0.into());
+	});
+}
+
+#[test]
+fn rpc_batch_call_checks_limits() {
+	new_test_ext().execute_with(|| {
+		let request = EthCallRequest {
+			from: alice(),
+			to: contract_a(),
+			data: vec![],
+			value: 0,
+			gas_limit: 1_000_000,
+			storage_limit: 0,
+			access_list: None,
+			static_call: true,
+		};
+
+		assert_eq!(
+			<Runtime as Config>::Runner::rpc_batch_call(
+				vec![request.clone(); MAX_BATCH_CALL_SIZE as usize + 1],
+				u64::MAX,
+				<Runtime as Config>::config(),
+			),
+			Err(Error::<Runtime>::BatchCallTooLarge.into())
+		);
+		assert_eq!(
+			<Runtime as Config>::Runner::rpc_batch_call(
+				vec![request.clone(); 3],
+				2_999_999,
+				<Runtime as Config>::config(),
+			),
+			Err(Error::<Runtime>::BatchCallGasLimitExceeded.into())
+		);
+
+		let results = <Runtime as Config>::Runner::rpc_batch_call(
+			vec![request; MAX_BATCH_CALL_SIZE as usize],
+			MAX_BATCH_CALL_SIZE as u64 * 1_000_000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(results.len(), MAX_BATCH_CALL_SIZE as usize);
+		assert!(results.iter().all(|result| result.is_ok()));
+	});
+}
+
+fn values_contract() -> Vec<u8> {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//     mapping(address => uint256) public values;
+	//
+	//     constructor() public {
+	//         values[msg.sender] = 42;
+	//     }
+	//
+	//     function set(uint val) public {
+	//      values[msg.sender] = val;
+	//     }
+	// }
+	from_hex(
+		"0x608060405234801561001057600080fd5b50602a6000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610154806100646000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c806354fe9fd71461003b57806360fe47b114610093575b600080fd5b61007d6004803603602081101561005157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506100c1565b6040518082815260200191505060405180910390f35b6100bf600480360360208110156100a957600080fd5b81019080803590602001909291905050506100d9565b005b60006020528060005260406000206000915090505481565b806000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055505056fea265627a7a723158207ab6991e97c9c12f57d81df0c7f955435418354adeb26116b581d7f2f035ca8f64736f6c63430005110032"
+	)
+	.unwrap()
+}
+
+fn deploy_values_contract() -> H160 {
+	<Runtime as Config>::Runner::create(
+		alice(),
+		values_contract(),
+		0,
+		500000,
+		100000,
+		vec![],
+		<Runtime as Config>::config(),
+	)
+	.unwrap()
+	.value
+}
+
+#[test]
+fn rpc_estimate_resources_with_freed_storage() {
+	new_test_ext().execute_with(|| {
+		let contract_address = deploy_values_contract();
+		// set(0)
+		let clear = from_hex("0x60fe47b10000000000000000000000000000000000000000000000000000000000000000").unwrap();
+
+		let estimate = <Runtime as Config>::Runner::rpc_estimate_resources(
+			alice(),
+			Some(contract_address),
+			clear.clone(),
+			0,
+			1_000_000,
+			1_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(estimate.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+		assert_eq!(estimate.used_storage, -(STORAGE_SIZE as i32));
+		assert_eq!(estimate.storage_limit, 0);
+		assert_eq!(estimate.storage_deposit, 0);
+		assert_eq!(
+			estimate.storage_refund,
+			EVM::get_storage_deposit_per_byte() * STORAGE_SIZE as Balance
+		);
+		// the gas refunded for the cleared slot is not available during the execution
+		assert!(estimate.gas_limit > estimate.used_gas);
+		// nothing is persisted
+		assert_eq!(
+			ContractStorageSizes::<Runtime>::get(&contract_address),
+			340 + NEW_CONTRACT_EXTRA_BYTES + STORAGE_SIZE
+		);
+
+		let call = |gas_limit: u64| {
+			<Runtime as Config>::Runner::call(
+				alice(),
+				alice(),
+				contract_address,
+				clear.clone(),
+				0,
+				gas_limit,
+				estimate.storage_limit,
+				vec![],
+				<Runtime as Config>::config(),
+			)
+			.unwrap()
+		};
+		assert!(!call(estimate.gas_limit - 1).exit_reason.is_succeed());
+
+		let info = call(estimate.gas_limit);
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+		assert_eq!(info.used_gas, estimate.used_gas.into());
+		assert_eq!(info.used_storage, estimate.used_storage);
+		assert_eq!(
+			ContractStorageSizes::<Runtime>::get(&contract_address),
+			340 + NEW_CONTRACT_EXTRA_BYTES
+		);
+	});
+}
+
+#[test]
+fn rpc_estimate_resources_with_access_list() {
+	new_test_ext().execute_with(|| {
+		let contract_address = deploy_values_contract();
+		// values(alice)
+		let get_alice = from_hex("0x54fe9fd70000000000000000000000001000000000000000000000000000000000000001").unwrap();
+		let alice_slot = H256::from(sp_core::keccak_256(
+			&[H256::from(alice()).as_bytes(), H256::zero().as_bytes()].concat(),
+		));
+		let mut access_list = vec![(contract_address, vec![alice_slot])];
+		access_list.extend((1..=10).map(|i| (H160::from_low_u64_be(0x1000 + i), vec![H256::from_low_u64_be(i)])));
+
+		let estimate = |access_list: Vec<(H160, Vec<H256>)>| {
+			<Runtime as Config>::Runner::rpc_estimate_resources(
+				bob(),
+				Some(contract_address),
+				get_alice.clone(),
+				0,
+				1_000_000,
+				0,
+				access_list,
+				<Runtime as Config>::config(),
+			)
+			.unwrap()
+		};
+		let without_access_list = estimate(vec![]);
+		let with_access_list = estimate(access_list.clone());
+		assert_eq!(with_access_list.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from(with_access_list.value.as_slice()), 42.into());
+		assert_eq!(with_access_list.used_storage, 0);
+		assert_eq!(with_access_list.storage_limit, 0);
+		// the warm slot saves less than the access list costs
+		assert!(with_access_list.used_gas > without_access_list.used_gas);
+		assert!(with_access_list.gas_limit > without_access_list.gas_limit);
+
+		let call = |gas_limit: u64| {
+			<Runtime as Config>::Runner::call(
+				bob(),
+				bob(),
+				contract_address,
+				get_alice.clone(),
+				0,
+				gas_limit,
+				0,
+				access_list.clone(),
+				<Runtime as Config>::config(),
+			)
+			.unwrap()
+		};
+		assert!(!call(with_access_list.gas_limit - 1).exit_reason.is_succeed());
+
+		let info = call(with_access_list.gas_limit);
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(info.used_gas, with_access_list.used_gas.into());
+	});
+}
+
+#[test]
+fn rpc_estimate_resources_of_create() {
+	new_test_ext().execute_with(|| {
+		let nonce = Pallet::<Runtime>::account_basic(&alice()).nonce;
+		let estimate = <Runtime as Config>::Runner::rpc_estimate_resources(
+			alice(),
+			None,
+			values_contract(),
+			0,
+			1_000_000,
+			100_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(estimate.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(
+			estimate.used_storage,
+			(340 + NEW_CONTRACT_EXTRA_BYTES + STORAGE_SIZE) as i32
+		);
+		assert_eq!(estimate.storage_limit, estimate.used_storage as u32);
+		assert_eq!(
+			estimate.storage_deposit,
+			EVM::get_storage_deposit_per_byte() * estimate.used_storage as Balance
+		);
+		assert_eq!(estimate.storage_refund, 0);
+		// nothing is persisted
+		assert_eq!(Pallet::<Runtime>::account_basic(&alice()).nonce, nonce);
+	});
+}
+
 #[test]
 fn code_hash_with_non_existent_address_should_work() {
 	new_test_ext().execute_with(|| {
@@ -3252,3 +3886,291 @@ fn tracer_works() {
 		}
 	})
 }
+
+#[test]
+fn create_with_lane_create_nonce_works() {
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let lane_nonce = primitives::evm::encode_nonce_lane(1, 2).unwrap();
+		let contract = EVM::lane_create_address(&alice(), lane_nonce);
+		let nonce = EVM::account_basic(&alice()).nonce;
+
+		LaneCreateNonce::<Runtime>::put(lane_nonce);
+		assert_ok!(EVM::create(
+			RuntimeOrigin::signed(alice_account_id),
+			vec![],
+			0,
+			1_000_000,
+			100,
+			vec![]
+		));
+
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::EVM(crate::Event::Created { contract: created, .. }) if created == contract
+		)));
+		assert_ne!(contract, EVM::lane_create_address(&alice(), nonce.as_u32()));
+		// the EVM nonce is still bumped by one
+		assert_eq!(EVM::account_basic(&alice()).nonce, nonce + 1);
+		assert_eq!(LaneCreateNonce::<Runtime>::get(), None);
+	});
+}
+
+#[test]
+fn lane_zero_create_does_not_collide_with_evm_create() {
+	// deploys a single byte of code, so a created address is occupied
+	let code = from_hex("0x600160005360016000f3").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let create = |lane_sequence: Option<u32>| {
+			if let Some(sequence) = lane_sequence {
+				LaneCreateNonce::<Runtime>::put(primitives::evm::encode_nonce_lane(0, sequence).unwrap());
+			}
+			assert_ok!(EVM::create(
+				RuntimeOrigin::signed(alice_account_id.clone()),
+				code.clone(),
+				0,
+				1_000_000,
+				100,
+				vec![]
+			));
+			match System::events().last().map(|record| record.event.clone()) {
+				Some(RuntimeEvent::EVM(crate::Event::Created { contract, .. })) => contract,
+				event => panic!("unexpected event: {:?}", event),
+			}
+		};
+		let nonce = EVM::account_basic(&alice()).nonce.as_u32();
+
+		// `evm.create` uses the EVM nonce, then a lane 0 eth tx signed with the same sequence
+		let created = create(None);
+		let lane_created = create(Some(nonce));
+		assert_ne!(created, lane_created);
+		assert_eq!(lane_created, EVM::lane_create_address(&alice(), nonce));
+
+		// lane 0 eth tx first, then `evm.create` with the EVM nonce reaching the same sequence
+		let lane_created = create(Some(nonce + 3));
+		assert_eq!(EVM::account_basic(&alice()).nonce.as_u32(), nonce + 3);
+		let created = create(None);
+		assert_ne!(created, lane_created);
+		assert_eq!(EVM::account_basic(&alice()).nonce.as_u32(), nonce + 4);
+	});
+}
+
+#[test]
+fn transaction_index_works() {
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let create_extrinsic = vec![1u8; 32];
+		let call_extrinsic = vec![2u8; 32];
+
+		System::set_block_number(1);
+
+		System::set_extrinsic_index(0);
+		System::note_extrinsic(create_extrinsic.clone());
+		assert_ok!(EVM::create(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			vec![],
+			0,
+			1_000_000,
+			100,
+			vec![]
+		));
+		let contract = System::events()
+			.iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::EVM(crate::Event::Created { contract, .. }) => Some(contract),
+				_ => None,
+			})
+			.unwrap();
+
+		// out of gas
+		System::set_extrinsic_index(1);
+		System::note_extrinsic(call_extrinsic.clone());
+		assert_ok!(EVM::call(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			contract_a(),
+			vec![],
+			0,
+			100,
+			0,
+			vec![]
+		));
+		assert_eq!(EVM::pending_transaction_index().len(), 2);
+
+		EVM::on_finalize(1);
+		assert_eq!(EVM::pending_transaction_index(), vec![]);
+		assert_eq!(
+			EVM::get_block_tx_index(1),
+			Some(vec![
+				EvmTransactionIndex {
+					extrinsic_index: 0,
+					transaction_hash: H256::from(sp_io::hashing::keccak_256(&create_extrinsic)),
+					from: alice(),
+					account_id: Some(alice_account_id.clone()),
+					contract_address: Some(contract),
+					log_count: 0,
+				},
+				EvmTransactionIndex {
+					extrinsic_index: 1,
+					transaction_hash: H256::from(sp_io::hashing::keccak_256(&call_extrinsic)),
+					from: alice(),
+					account_id: Some(alice_account_id.clone()),
+					contract_address: None,
+					log_count: 0,
+				},
+			])
+		);
+
+		// no EVM transactions
+		System::set_block_number(2);
+		EVM::on_finalize(2);
+		assert_eq!(EVM::get_block_tx_index(2), None);
+		assert!(EVM::get_block_tx_index(1).is_some());
+
+		// block 4 takes the slot of block 1
+		System::set_block_number(4);
+		System::set_extrinsic_index(0);
+		System::note_extrinsic(call_extrinsic.clone());
+		assert_ok!(EVM::call(
+			RuntimeOrigin::signed(alice_account_id),
+			contract_a(),
+			vec![],
+			0,
+			100,
+			0,
+			vec![]
+		));
+		EVM::on_finalize(4);
+		assert_eq!(EVM::get_block_tx_index(1), None);
+		assert_eq!(
+			EVM::get_block_tx_index(4).map(|transactions| transactions.len()),
+			Some(1)
+		);
+		assert_eq!(
+			EVM::block_transaction_index(1).map(|(block_number, _)| block_number),
+			Some(4)
+		);
+
+		// the expired block is pruned
+		System::set_block_number(7);
+		EVM::on_finalize(7);
+		assert_eq!(EVM::get_block_tx_index(4), None);
+		assert_eq!(EVM::block_transaction_index(1), None);
+	});
+}
+
+#[test]
+fn transaction_index_skips_calls_outside_extrinsics() {
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+
+		System::set_block_number(1);
+		assert_ok!(EVM::call(
+			RuntimeOrigin::signed(alice_account_id),
+			contract_a(),
+			vec![],
+			0,
+			100,
+			0,
+			vec![]
+		));
+		assert_eq!(EVM::pending_transaction_index(), vec![]);
+
+		EVM::on_finalize(1);
+		assert_eq!(EVM::get_block_tx_index(1), None);
+	});
+}
+
+#[test]
+fn block_storage_budget_works() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+	// code size + NEW_CONTRACT_EXTRA_BYTES
+	let contract_storage = 284u32;
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		BlockStorageBudget::set(contract_storage + 100);
+		System::set_block_number(1);
+
+		// the create fills the budget
+		assert_ok!(EVM::create(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			contract.clone(),
+			0,
+			1_000_000,
+			1_000_000,
+			vec![]
+		));
+		assert!(matches!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::EVM(crate::Event::Created { used_storage: 284, .. })
+		));
+		assert_eq!(EVM::block_storage_used(), contract_storage);
+
+		// the next create exceeds the budget
+		assert_ok!(EVM::create(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			contract.clone(),
+			0,
+			1_000_000,
+			1_000_000,
+			vec![]
+		));
+		System::assert_last_event(RuntimeEvent::EVM(crate::Event::CreatedFailed {
+			from: alice(),
+			contract: H160::default(),
+			exit_reason: ExitReason::Error(ExitError::Other(
+				Into::<&str>::into(Error::<Runtime>::BlockStorageBudgetExceeded).into(),
+			)),
+			logs: vec![],
+			used_gas: 1_000_000,
+			used_storage: 0,
+		}));
+		assert_eq!(EVM::block_storage_used(), contract_storage);
+
+		// the storage neutral call still succeeds
+		assert_ok!(EVM::call(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			bob(),
+			vec![],
+			1000,
+			1_000_000,
+			1_000_000,
+			vec![]
+		));
+		assert!(matches!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::EVM(crate::Event::Executed { used_storage: 0, .. })
+		));
+		assert_eq!(EVM::block_storage_used(), contract_storage);
+
+		// the budget resets in the next block
+		EVM::on_finalize(1);
+		assert_eq!(EVM::block_storage_used(), 0);
+		System::set_block_number(2);
+
+		assert_ok!(EVM::create(
+			RuntimeOrigin::signed(alice_account_id),
+			contract,
+			0,
+			1_000_000,
+			1_000_000,
+			vec![]
+		));
+		assert!(matches!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::EVM(crate::Event::Created { used_storage: 284, .. })
+		));
+		assert_eq!(EVM::block_storage_used(), contract_storage);
+	});
+}
@@ -59,6 +59,8 @@ pub trait WeightInfo {
 	fn disable_contract_development() -> Weight;
 	fn set_code(c: u32, ) -> Weight;
 	fn selfdestruct() -> Weight;
+	fn on_finalize_with_transaction_index(n: u32, ) -> Weight;
+	fn set_system_contract() -> Weight;
 }
 
 /// Weights for module_evm using the Acala node and recommended hardware.
@@ -124,11 +126,13 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof Skipped: EVM ContractStorageSizes (max_values: None, max_size: None, mode: Measured)
 	// Storage: EVM Codes (r:0 w:1)
 	// Proof Skipped: EVM Codes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM SystemContracts (r:1 w:1)
+	// Proof Skipped: EVM SystemContracts (max_values: None, max_size: None, mode: Measured)
 	fn create_nft_contract() -> Weight {
 		// Minimum execution time: 223_480 nanoseconds.
 		Weight::from_parts(227_640_000, 0)
-			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	// Storage: EVM Accounts (r:2 w:2)
 	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
@@ -144,11 +148,13 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof Skipped: EVM ContractStorageSizes (max_values: None, max_size: None, mode: Measured)
 	// Storage: EVM Codes (r:0 w:1)
 	// Proof Skipped: EVM Codes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM SystemContracts (r:1 w:1)
+	// Proof Skipped: EVM SystemContracts (max_values: None, max_size: None, mode: Measured)
 	fn create_predeploy_contract() -> Weight {
 		// Minimum execution time: 229_528 nanoseconds.
 		Weight::from_parts(233_183_000, 0)
-			.saturating_add(T::DbWeight::get().reads(11))
-			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
 	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
@@ -267,6 +273,32 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(8))
 	}
+	// Storage: EVM PendingTransactionIndex (r:1 w:1)
+	// Proof Skipped: EVM PendingTransactionIndex (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: System ExtrinsicData (r:100 w:0)
+	// Proof Skipped: System ExtrinsicData (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM BlockTransactionIndex (r:0 w:1)
+	// Proof Skipped: EVM BlockTransactionIndex (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 100]`.
+	fn on_finalize_with_transaction_index(n: u32, ) -> Weight {
+		// Minimum execution time: 4_318 nanoseconds.
+		Weight::from_parts(5_206_713, 0)
+			// Standard Error: 1_904
+			.saturating_add(Weight::from_parts(11_732_418, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: EVM Accounts (r:1 w:0)
+	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM SystemContracts (r:0 w:1)
+	// Proof Skipped: EVM SystemContracts (max_values: None, max_size: None, mode: Measured)
+	fn set_system_contract() -> Weight {
+		// Minimum execution time: 21_305 nanoseconds.
+		Weight::from_parts(21_847_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 
 // For backwards compatibility and tests
@@ -331,11 +363,13 @@ impl WeightInfo for () {
 	// Proof Skipped: EVM ContractStorageSizes (max_values: None, max_size: None, mode: Measured)
 	// Storage: EVM Codes (r:0 w:1)
 	// Proof Skipped: EVM Codes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM SystemContracts (r:1 w:1)
+	// Proof Skipped: EVM SystemContracts (max_values: None, max_size: None, mode: Measured)
 	fn create_nft_contract() -> Weight {
 		// Minimum execution time: 223_480 nanoseconds.
 		Weight::from_parts(227_640_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(12))
-			.saturating_add(RocksDbWeight::get().writes(10))
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().writes(11))
 	}
 	// Storage: EVM Accounts (r:2 w:2)
 	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
@@ -351,11 +385,13 @@ impl WeightInfo for () {
 	// Proof Skipped: EVM ContractStorageSizes (max_values: None, max_size: None, mode: Measured)
 	// Storage: EVM Codes (r:0 w:1)
 	// Proof Skipped: EVM Codes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM SystemContracts (r:1 w:1)
+	// Proof Skipped: EVM SystemContracts (max_values: None, max_size: None, mode: Measured)
 	fn create_predeploy_contract() -> Weight {
 		// Minimum execution time: 229_528 nanoseconds.
 		Weight::from_parts(233_183_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(11))
-			.saturating_add(RocksDbWeight::get().writes(9))
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(10))
 	}
 	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
 	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
@@ -474,4 +510,30 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11))
 			.saturating_add(RocksDbWeight::get().writes(8))
 	}
+	// Storage: EVM PendingTransactionIndex (r:1 w:1)
+	// Proof Skipped: EVM PendingTransactionIndex (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: System ExtrinsicData (r:100 w:0)
+	// Proof Skipped: System ExtrinsicData (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM BlockTransactionIndex (r:0 w:1)
+	// Proof Skipped: EVM BlockTransactionIndex (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 100]`.
+	fn on_finalize_with_transaction_index(n: u32, ) -> Weight {
+		// Minimum execution time: 4_318 nanoseconds.
+		Weight::from_parts(5_206_713, 0)
+			// Standard Error: 1_904
+			.saturating_add(Weight::from_parts(11_732_418, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: EVM Accounts (r:1 w:0)
+	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM SystemContracts (r:0 w:1)
+	// Proof Skipped: EVM SystemContracts (max_values: None, max_size: None, mode: Measured)
+	fn set_system_contract() -> Weight {
+		// Minimum execution time: 21_305 nanoseconds.
+		Weight::from_parts(21_847_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,293 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Faucet Module
+//!
+//! ## Overview
+//!
+//! A public faucet for test networks. `request_tokens` drips the configured basket of tokens
+//! from the faucet account to the caller, at most once per `DripPeriod` and at most
+//! `MaxDripsPerAccount` times in total per account, and no more than `MaxDripsPerBlock` drips
+//! happen in one block. Root manages a blocklist of accounts, and the requests can be gated by
+//! a proof-of-personhood check.
+//!
+//! The faucet account is derived from `PalletId`, anyone, including governance, can refill it
+//! by transferring tokens to it.
+//!
+//! A rejected request doesn't fail, `DripRejected` is emitted with the reason instead, and
+//! requests are free so that new accounts without any balance can use the faucet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// Check whether an account is controlled by a unique person, e.g. by a captcha attestation.
+pub trait PersonhoodCheck<AccountId> {
+	fn is_person(who: &AccountId) -> bool;
+}
+
+/// Allow all accounts.
+impl<AccountId> PersonhoodCheck<AccountId> for () {
+	fn is_person(_who: &AccountId) -> bool {
+		true
+	}
+}
+
+/// The drip records of an account.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DripInfo<BlockNumber> {
+	/// The block of the last drip.
+	pub last_drip_at: BlockNumber,
+	/// The total count of the drips.
+	pub count: u32,
+}
+
+/// The reason why a request is rejected.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RejectReason {
+	/// The account is in the blocklist.
+	Blocklisted,
+	/// The account fails the proof-of-personhood check.
+	NotPerson,
+	/// The account has reached `MaxDripsPerAccount`.
+	LifetimeCapReached,
+	/// `DripPeriod` has not passed since the last drip of the account.
+	TooEarly,
+	/// The block has reached `MaxDripsPerBlock`.
+	BlockCapReached,
+	/// The faucet account doesn't have enough tokens.
+	InsufficientFunds,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency to drip.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The faucet's module id, keep the tokens to drip.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The tokens and their amounts dripped per request.
+		type DripBasket: Get<Vec<(CurrencyId, Balance)>>;
+
+		/// The minimum period between two drips of an account.
+		#[pallet::constant]
+		type DripPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum count of drips of an account.
+		#[pallet::constant]
+		type MaxDripsPerAccount: Get<u32>;
+
+		/// The maximum count of drips in a block.
+		#[pallet::constant]
+		type MaxDripsPerBlock: Get<u32>;
+
+		/// The proof-of-personhood check of the requesting accounts.
+		type PersonhoodCheck: PersonhoodCheck<Self::AccountId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already in the blocklist.
+		AlreadyBlocklisted,
+		/// The account is not in the blocklist.
+		NotBlocklisted,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The basket of tokens is dripped to the account.
+		TokensDripped {
+			who: T::AccountId,
+			basket: Vec<(CurrencyId, Balance)>,
+		},
+		/// The request of the account is rejected.
+		DripRejected { who: T::AccountId, reason: RejectReason },
+		/// The account is added to the blocklist.
+		AccountBlocklisted { who: T::AccountId },
+		/// The account is removed from the blocklist.
+		AccountUnblocklisted { who: T::AccountId },
+	}
+
+	/// The drip records of the accounts.
+	///
+	/// Drips: map AccountId => Option<DripInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn drips)]
+	pub type Drips<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, DripInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The block and the count of the drips in it.
+	///
+	/// BlockDrips: (BlockNumber, u32)
+	#[pallet::storage]
+	#[pallet::getter(fn block_drips)]
+	pub type BlockDrips<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	/// The accounts not allowed to request tokens.
+	///
+	/// Blocklist: map AccountId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn blocklist)]
+	pub type Blocklist<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request the basket of tokens from the faucet. The request is free, and
+		/// `DripRejected` is emitted with the reason if it's rejected.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		#[pallet::call_index(0)]
+		#[pallet::weight((T::WeightInfo::request_tokens(), DispatchClass::Normal, Pays::No))]
+		pub fn request_tokens(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			match Self::do_request_tokens(&who) {
+				Ok(basket) => Self::deposit_event(Event::TokensDripped { who, basket }),
+				Err(reason) => Self::deposit_event(Event::DripRejected { who, reason }),
+			}
+			Ok(())
+		}
+
+		/// Add the account to the blocklist.
+		///
+		/// The dispatch origin of this call must be _Root_.
+		///
+		/// - `who`: the account to block.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::blocklist_account())]
+		pub fn blocklist_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!Blocklist::<T>::contains_key(&who), Error::<T>::AlreadyBlocklisted);
+			Blocklist::<T>::insert(&who, ());
+			Self::deposit_event(Event::AccountBlocklisted { who });
+			Ok(())
+		}
+
+		/// Remove the account from the blocklist.
+		///
+		/// The dispatch origin of this call must be _Root_.
+		///
+		/// - `who`: the account to unblock.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::unblocklist_account())]
+		pub fn unblocklist_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(Blocklist::<T>::contains_key(&who), Error::<T>::NotBlocklisted);
+			Blocklist::<T>::remove(&who);
+			Self::deposit_event(Event::AccountUnblocklisted { who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The faucet account, which keeps the tokens to drip.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The block from which `who` can request tokens again, `None` if `who` is blocklisted or has
+	/// reached `MaxDripsPerAccount`.
+	pub fn next_request_at(who: &T::AccountId) -> Option<BlockNumberFor<T>> {
+		if Blocklist::<T>::contains_key(who) {
+			return None;
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		match Self::drips(who) {
+			Some(info) if info.count >= T::MaxDripsPerAccount::get() => None,
+			Some(info) => Some(info.last_drip_at.saturating_add(T::DripPeriod::get()).max(now)),
+			None => Some(now),
+		}
+	}
+
+	fn do_request_tokens(who: &T::AccountId) -> Result<Vec<(CurrencyId, Balance)>, RejectReason> {
+		ensure!(!Blocklist::<T>::contains_key(who), RejectReason::Blocklisted);
+		ensure!(T::PersonhoodCheck::is_person(who), RejectReason::NotPerson);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let drip_info = Self::drips(who);
+		if let Some(info) = drip_info {
+			ensure!(
+				info.count < T::MaxDripsPerAccount::get(),
+				RejectReason::LifetimeCapReached
+			);
+			ensure!(
+				now >= info.last_drip_at.saturating_add(T::DripPeriod::get()),
+				RejectReason::TooEarly
+			);
+		}
+
+		let (block, block_count) = Self::block_drips();
+		let block_count = if block == now { block_count } else { Zero::zero() };
+		ensure!(block_count < T::MaxDripsPerBlock::get(), RejectReason::BlockCapReached);
+
+		let basket = T::DripBasket::get();
+		Self::transfer_basket(who, &basket).map_err(|_| RejectReason::InsufficientFunds)?;
+
+		Drips::<T>::insert(
+			who,
+			DripInfo {
+				last_drip_at: now,
+				count: drip_info.map_or(0, |info| info.count).saturating_add(1),
+			},
+		);
+		BlockDrips::<T>::put((now, block_count.saturating_add(1)));
+
+		Ok(basket)
+	}
+
+	#[transactional]
+	fn transfer_basket(who: &T::AccountId, basket: &[(CurrencyId, Balance)]) -> DispatchResult {
+		let faucet = Self::account_id();
+		for (currency_id, amount) in basket {
+			T::Currency::transfer(*currency_id, &faucet, who, *amount, ExistenceRequirement::AllowDeath)?;
+		}
+		Ok(())
+	}
+}
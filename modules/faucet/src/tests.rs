@@ -0,0 +1,188 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the faucet module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::BadOrigin;
+
+fn basket_balances(who: AccountId) -> (Balance, Balance, Balance) {
+	(
+		Tokens::free_balance(ACA, &who),
+		Tokens::free_balance(AUSD, &who),
+		Tokens::free_balance(DOT, &who),
+	)
+}
+
+fn assert_rejected(who: AccountId, reason: RejectReason) {
+	let balances = basket_balances(who);
+	assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(who)));
+	System::assert_last_event(RuntimeEvent::Faucet(crate::Event::DripRejected { who, reason }));
+	assert_eq!(basket_balances(who), balances);
+}
+
+#[test]
+fn request_tokens_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Faucet::next_request_at(&ALICE), Some(1));
+
+		assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::Faucet(crate::Event::TokensDripped {
+			who: ALICE,
+			basket: vec![(ACA, 100), (AUSD, 100), (DOT, 10)],
+		}));
+		assert_eq!(basket_balances(ALICE), (100, 100, 10));
+		assert_eq!(basket_balances(Faucet::account_id()), (900, 900, 90));
+		assert_eq!(
+			Faucet::drips(ALICE),
+			Some(DripInfo {
+				last_drip_at: 1,
+				count: 1
+			})
+		);
+		assert_eq!(Faucet::block_drips(), (1, 1));
+		assert_eq!(Faucet::next_request_at(&ALICE), Some(11));
+	});
+}
+
+#[test]
+fn request_tokens_limited_by_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(ALICE)));
+
+		System::set_block_number(10);
+		assert_rejected(ALICE, RejectReason::TooEarly);
+		assert_eq!(Faucet::next_request_at(&ALICE), Some(11));
+
+		System::set_block_number(11);
+		assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(basket_balances(ALICE), (200, 200, 20));
+		assert_eq!(
+			Faucet::drips(ALICE),
+			Some(DripInfo {
+				last_drip_at: 11,
+				count: 2
+			})
+		);
+
+		// asking late doesn't move the next request earlier
+		System::set_block_number(50);
+		assert_eq!(Faucet::next_request_at(&ALICE), Some(50));
+	});
+}
+
+#[test]
+fn request_tokens_limited_by_lifetime_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		for block in [1, 11, 21] {
+			System::set_block_number(block);
+			assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(ALICE)));
+		}
+		assert_eq!(basket_balances(ALICE), (300, 300, 30));
+		assert_eq!(Faucet::next_request_at(&ALICE), None);
+
+		System::set_block_number(1000);
+		assert_rejected(ALICE, RejectReason::LifetimeCapReached);
+		assert_eq!(Faucet::drips(ALICE).unwrap().count, 3);
+		assert_eq!(Faucet::next_request_at(&ALICE), None);
+	});
+}
+
+#[test]
+fn request_tokens_limited_by_block_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(ALICE)));
+		assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(BOB)));
+		assert_eq!(Faucet::block_drips(), (1, 2));
+		assert_rejected(CHARLIE, RejectReason::BlockCapReached);
+		assert_eq!(Faucet::drips(CHARLIE), None);
+
+		// the cap is reset in the next block
+		System::set_block_number(2);
+		assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(CHARLIE)));
+		assert_eq!(basket_balances(CHARLIE), (100, 100, 10));
+		assert_eq!(Faucet::block_drips(), (2, 1));
+	});
+}
+
+#[test]
+fn request_tokens_requires_personhood() {
+	ExtBuilder::default().build().execute_with(|| {
+		IsPerson::set(false);
+		assert_rejected(ALICE, RejectReason::NotPerson);
+		assert_eq!(Faucet::drips(ALICE), None);
+
+		IsPerson::set(true);
+		assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(basket_balances(ALICE), (100, 100, 10));
+	});
+}
+
+#[test]
+fn request_tokens_with_insufficient_funds_drips_nothing() {
+	let faucet = Faucet::account_id();
+	ExtBuilder::default()
+		.balances(vec![(faucet, ACA, 1000), (faucet, AUSD, 1000), (faucet, DOT, 5)])
+		.build()
+		.execute_with(|| {
+			assert_rejected(ALICE, RejectReason::InsufficientFunds);
+			assert_eq!(basket_balances(faucet), (1000, 1000, 5));
+			assert_eq!(Faucet::drips(ALICE), None);
+			assert_eq!(Faucet::block_drips(), (0, 0));
+
+			// refilled by anyone
+			assert_ok!(Tokens::deposit(DOT, &faucet, 100));
+			assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(ALICE)));
+			assert_eq!(basket_balances(ALICE), (100, 100, 10));
+		});
+}
+
+#[test]
+fn blocklist_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(Faucet::blocklist_account(RuntimeOrigin::signed(BOB), ALICE), BadOrigin);
+		assert_noop!(
+			Faucet::unblocklist_account(RuntimeOrigin::root(), ALICE),
+			Error::<Runtime>::NotBlocklisted
+		);
+
+		assert_ok!(Faucet::blocklist_account(RuntimeOrigin::root(), ALICE));
+		System::assert_last_event(RuntimeEvent::Faucet(crate::Event::AccountBlocklisted { who: ALICE }));
+		assert_noop!(
+			Faucet::blocklist_account(RuntimeOrigin::root(), ALICE),
+			Error::<Runtime>::AlreadyBlocklisted
+		);
+		assert_eq!(Faucet::next_request_at(&ALICE), None);
+		assert_rejected(ALICE, RejectReason::Blocklisted);
+
+		assert_noop!(
+			Faucet::unblocklist_account(RuntimeOrigin::signed(BOB), ALICE),
+			BadOrigin
+		);
+		assert_ok!(Faucet::unblocklist_account(RuntimeOrigin::root(), ALICE));
+		System::assert_last_event(RuntimeEvent::Faucet(crate::Event::AccountUnblocklisted { who: ALICE }));
+		assert_eq!(Faucet::next_request_at(&ALICE), Some(1));
+		assert_ok!(Faucet::request_tokens(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(basket_balances(ALICE), (100, 100, 10));
+	});
+}
@@ -0,0 +1,500 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Guardian Module
+//!
+//! ## Overview
+//!
+//! Optional protection for the high-value operations of an account. When an account configures
+//! guardians, collateral withdrawals and liquid redemptions whose accumulated value exceeds the
+//! threshold within a period of `delay` blocks, as well as full loan closures, are rejected by
+//! the protocol modules. Such operations must be announced first, and can only be executed after
+//! the delay, during which the guardians (and the recovery friends if enabled) can veto them.
+//!
+//! The check is done by the protocol modules through `OperationGuard` against the account whose
+//! position is changed, so it can not be bypassed by proxies, utility batches or EVM precompiles.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::*,
+};
+use frame_system::pallet_prelude::*;
+use module_support::{GuardedOperation, OperationGuard, PriceProvider};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{Dispatchable, Hash, Saturating, StaticLookup, Zero},
+	FixedPointNumber,
+};
+use sp_std::{boxed::Box, prelude::*};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// Provides the recovery friends of an account.
+pub trait RecoveryFriends<AccountId> {
+	fn friends(who: &AccountId) -> Vec<AccountId>;
+}
+
+impl<AccountId> RecoveryFriends<AccountId> for () {
+	fn friends(_who: &AccountId) -> Vec<AccountId> {
+		Vec::new()
+	}
+}
+
+/// The guardian configuration of an account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct GuardianConfig<BlockNumber, Guardians> {
+	/// The accounts which can approve or veto the pending actions.
+	pub guardians: Guardians,
+	/// Whether the recovery friends can also approve or veto the pending actions.
+	pub use_recovery_friends: bool,
+	/// The maximum value of guarded operations allowed per period without announcement.
+	pub threshold: Balance,
+	/// The delay of pending actions, also the length of the period.
+	pub delay: BlockNumber,
+}
+
+/// An announced call waiting for execution.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingAction<Hash, BlockNumber> {
+	/// The hash of the announced call.
+	pub call_hash: Hash,
+	/// The block number since which the call can be executed.
+	pub executable_at: BlockNumber,
+	/// Whether a guardian has approved the call for immediate execution.
+	pub approved: bool,
+}
+
+pub type GuardianConfigOf<T> =
+	GuardianConfig<BlockNumberFor<T>, BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxGuardians>>;
+pub type PendingActionOf<T> = PendingAction<<T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The aggregated call type.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo;
+
+		/// The price source to value the guarded operations.
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// The stable currency id, the threshold is denominated in it.
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// The recovery friends of an account.
+		type RecoveryFriends: RecoveryFriends<Self::AccountId>;
+
+		/// The maximum number of guardians of an account.
+		#[pallet::constant]
+		type MaxGuardians: Get<u32>;
+
+		/// The maximum number of pending actions of an account.
+		#[pallet::constant]
+		type MaxPendingActions: Get<u32>;
+
+		/// The minimum delay of pending actions.
+		#[pallet::constant]
+		type MinDelay: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The delay is less than `MinDelay`.
+		InvalidDelay,
+		/// No guardian is configured.
+		NoGuardian,
+		/// Too many guardians.
+		TooManyGuardians,
+		/// The account can not be its own guardian.
+		SelfGuardian,
+		/// The account has not configured guardians.
+		GuardianNotConfigured,
+		/// Too many pending actions.
+		TooManyPendingActions,
+		/// The call has been announced already.
+		DuplicatedPendingAction,
+		/// The pending action does not exist.
+		PendingActionNotFound,
+		/// The caller is not a guardian of the account.
+		NotGuardian,
+		/// The pending action is still in delay.
+		NotYetExecutable,
+		/// The operation is guarded, it must be announced and executed by `execute_pending`.
+		MustBeAnnounced,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The guardian configuration is set.
+		GuardianSet {
+			who: T::AccountId,
+			guardians: Vec<T::AccountId>,
+			use_recovery_friends: bool,
+			threshold: Balance,
+			delay: BlockNumberFor<T>,
+		},
+		/// The guardian configuration is removed.
+		GuardianRemoved { who: T::AccountId },
+		/// A call is announced.
+		PendingAnnounced {
+			who: T::AccountId,
+			call_hash: T::Hash,
+			executable_at: BlockNumberFor<T>,
+		},
+		/// A pending action is approved by a guardian.
+		PendingApproved {
+			who: T::AccountId,
+			guardian: T::AccountId,
+			call_hash: T::Hash,
+		},
+		/// A pending action is vetoed by a guardian.
+		PendingVetoed {
+			who: T::AccountId,
+			guardian: T::AccountId,
+			call_hash: T::Hash,
+		},
+		/// A pending action is executed.
+		PendingExecuted {
+			who: T::AccountId,
+			call_hash: T::Hash,
+			result: DispatchResult,
+		},
+	}
+
+	/// The guardian configurations.
+	///
+	/// GuardianConfigs: map AccountId => Option<GuardianConfig>
+	#[pallet::storage]
+	#[pallet::getter(fn guardian_configs)]
+	pub type GuardianConfigs<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, GuardianConfigOf<T>, OptionQuery>;
+
+	/// The pending actions of accounts.
+	///
+	/// PendingActions: map AccountId => Vec<PendingAction>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_actions)]
+	pub type PendingActions<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<PendingActionOf<T>, T::MaxPendingActions>, ValueQuery>;
+
+	/// The value of guarded operations executed in the current period.
+	///
+	/// GuardedUsage: map AccountId => (PeriodStart, UsedValue)
+	#[pallet::storage]
+	#[pallet::getter(fn guarded_usage)]
+	pub type GuardedUsage<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (BlockNumberFor<T>, Balance), ValueQuery>;
+
+	/// The account whose pending action is being executed.
+	///
+	/// ExecutingFor: Option<AccountId>
+	#[pallet::storage]
+	pub type ExecutingFor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the guardian configuration of caller. If the caller has configured guardians
+		/// already, the change must be announced and executed by `execute_pending`.
+		///
+		/// - `guardians`: the accounts which can approve or veto the pending actions.
+		/// - `use_recovery_friends`: whether the recovery friends are also guardians.
+		/// - `threshold`: the maximum value of guarded operations per period without announcement.
+		/// - `delay`: the delay of pending actions.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_guardian())]
+		pub fn set_guardian(
+			origin: OriginFor<T>,
+			guardians: Vec<T::AccountId>,
+			use_recovery_friends: bool,
+			#[pallet::compact] threshold: Balance,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if GuardianConfigs::<T>::contains_key(&who) {
+				Self::ensure_executing(&who)?;
+			}
+			ensure!(delay >= T::MinDelay::get(), Error::<T>::InvalidDelay);
+			ensure!(!guardians.is_empty() || use_recovery_friends, Error::<T>::NoGuardian);
+			ensure!(!guardians.contains(&who), Error::<T>::SelfGuardian);
+			let bounded_guardians: BoundedVec<T::AccountId, T::MaxGuardians> =
+				guardians.clone().try_into().map_err(|_| Error::<T>::TooManyGuardians)?;
+
+			GuardianConfigs::<T>::insert(
+				&who,
+				GuardianConfig {
+					guardians: bounded_guardians,
+					use_recovery_friends,
+					threshold,
+					delay,
+				},
+			);
+			GuardedUsage::<T>::remove(&who);
+
+			Self::deposit_event(Event::GuardianSet {
+				who,
+				guardians,
+				use_recovery_friends,
+				threshold,
+				delay,
+			});
+			Ok(())
+		}
+
+		/// Remove the guardian configuration of caller, must be announced and executed by
+		/// `execute_pending`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_guardian())]
+		pub fn remove_guardian(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				GuardianConfigs::<T>::contains_key(&who),
+				Error::<T>::GuardianNotConfigured
+			);
+			Self::ensure_executing(&who)?;
+
+			GuardianConfigs::<T>::remove(&who);
+			GuardedUsage::<T>::remove(&who);
+
+			Self::deposit_event(Event::GuardianRemoved { who });
+			Ok(())
+		}
+
+		/// Announce a call of caller, which can be executed by `execute_pending` after the delay.
+		///
+		/// - `call_hash`: the hash of the call.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::announce())]
+		pub fn announce(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let config = Self::guardian_configs(&who).ok_or(Error::<T>::GuardianNotConfigured)?;
+			let executable_at = frame_system::Pallet::<T>::block_number().saturating_add(config.delay);
+
+			PendingActions::<T>::try_mutate(&who, |pending_actions| -> DispatchResult {
+				ensure!(
+					!pending_actions.iter().any(|action| action.call_hash == call_hash),
+					Error::<T>::DuplicatedPendingAction
+				);
+				pending_actions
+					.try_push(PendingAction {
+						call_hash,
+						executable_at,
+						approved: false,
+					})
+					.map_err(|_| Error::<T>::TooManyPendingActions)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PendingAnnounced {
+				who,
+				call_hash,
+				executable_at,
+			});
+			Ok(())
+		}
+
+		/// Approve the pending action of `who`, which can be executed immediately.
+		///
+		/// - `who`: the account which announced the call.
+		/// - `call_hash`: the hash of the call.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::approve_pending())]
+		pub fn approve_pending(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			call_hash: T::Hash,
+		) -> DispatchResult {
+			let guardian = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(Self::is_guardian(&who, &guardian), Error::<T>::NotGuardian);
+
+			PendingActions::<T>::try_mutate(&who, |pending_actions| -> DispatchResult {
+				let action = pending_actions
+					.iter_mut()
+					.find(|action| action.call_hash == call_hash)
+					.ok_or(Error::<T>::PendingActionNotFound)?;
+				action.approved = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PendingApproved {
+				who,
+				guardian,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Veto the pending action of `who`.
+		///
+		/// - `who`: the account which announced the call.
+		/// - `call_hash`: the hash of the call.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::veto_pending())]
+		pub fn veto_pending(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			call_hash: T::Hash,
+		) -> DispatchResult {
+			let guardian = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(Self::is_guardian(&who, &guardian), Error::<T>::NotGuardian);
+
+			Self::take_pending_action(&who, call_hash)?;
+
+			Self::deposit_event(Event::PendingVetoed {
+				who,
+				guardian,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Execute the announced call of caller, the guarded operations in the call are allowed.
+		///
+		/// - `call`: the announced call.
+		#[pallet::call_index(5)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(T::WeightInfo::execute_pending().saturating_add(dispatch_info.weight), dispatch_info.class)
+		})]
+		pub fn execute_pending(origin: OriginFor<T>, call: Box<<T as Config>::RuntimeCall>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let call_hash = T::Hashing::hash_of(&call);
+
+			let action = Self::take_pending_action(&who, call_hash)?;
+			ensure!(
+				action.approved || frame_system::Pallet::<T>::block_number() >= action.executable_at,
+				Error::<T>::NotYetExecutable
+			);
+
+			let previous = ExecutingFor::<T>::get();
+			ExecutingFor::<T>::put(&who);
+			let result = call.dispatch(frame_system::RawOrigin::Signed(who.clone()).into());
+			ExecutingFor::<T>::set(previous);
+
+			Self::deposit_event(Event::PendingExecuted {
+				who,
+				call_hash,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check if `guardian` is a guardian of `who`
+	pub fn is_guardian(who: &T::AccountId, guardian: &T::AccountId) -> bool {
+		if who == guardian {
+			return false;
+		}
+		match Self::guardian_configs(who) {
+			Some(config) => {
+				config.guardians.contains(guardian)
+					|| (config.use_recovery_friends && T::RecoveryFriends::friends(who).contains(guardian))
+			}
+			None => false,
+		}
+	}
+
+	fn ensure_executing(who: &T::AccountId) -> DispatchResult {
+		ensure!(
+			ExecutingFor::<T>::get().as_ref() == Some(who),
+			Error::<T>::MustBeAnnounced
+		);
+		Ok(())
+	}
+
+	fn take_pending_action(who: &T::AccountId, call_hash: T::Hash) -> Result<PendingActionOf<T>, DispatchError> {
+		PendingActions::<T>::try_mutate_exists(who, |maybe_pending_actions| -> Result<_, DispatchError> {
+			let pending_actions = maybe_pending_actions
+				.as_mut()
+				.ok_or(Error::<T>::PendingActionNotFound)?;
+			let index = pending_actions
+				.iter()
+				.position(|action| action.call_hash == call_hash)
+				.ok_or(Error::<T>::PendingActionNotFound)?;
+			let action = pending_actions.remove(index);
+			if pending_actions.is_empty() {
+				*maybe_pending_actions = None;
+			}
+			Ok(action)
+		})
+	}
+}
+
+impl<T: Config> OperationGuard<T::AccountId> for Pallet<T> {
+	fn check_operation(who: &T::AccountId, operation: GuardedOperation) -> DispatchResult {
+		let config = match Self::guardian_configs(who) {
+			Some(config) => config,
+			None => return Ok(()),
+		};
+		if ExecutingFor::<T>::get().as_ref() == Some(who) {
+			return Ok(());
+		}
+
+		let (currency_id, amount) = match operation {
+			GuardedOperation::WithdrawCollateral { currency_id, amount }
+			| GuardedOperation::Redeem { currency_id, amount } => (currency_id, amount),
+			GuardedOperation::CloseLoan { .. } => return Err(Error::<T>::MustBeAnnounced.into()),
+		};
+		// the operation without price is always guarded.
+		let value = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+			.and_then(|price| price.checked_mul_int(amount))
+			.ok_or(Error::<T>::MustBeAnnounced)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		GuardedUsage::<T>::try_mutate(who, |(period_start, used)| -> DispatchResult {
+			if used.is_zero() || now >= period_start.saturating_add(config.delay) {
+				*period_start = now;
+				*used = 0;
+			}
+			let new_used = used.saturating_add(value);
+			ensure!(new_used <= config.threshold, Error::<T>::MustBeAnnounced);
+			*used = new_used;
+			Ok(())
+		})
+	}
+}
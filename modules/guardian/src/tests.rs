@@ -0,0 +1,358 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the guardian module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeCall, RuntimeEvent, *};
+use module_support::Price;
+
+fn call_hash(call: &RuntimeCall) -> <Runtime as frame_system::Config>::Hash {
+	<Runtime as frame_system::Config>::Hashing::hash_of(call)
+}
+
+fn withdraw(amount: Balance) -> GuardedOperation {
+	GuardedOperation::WithdrawCollateral {
+		currency_id: DOT,
+		amount,
+	}
+}
+
+#[test]
+fn set_guardian_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Guardian::set_guardian(RuntimeOrigin::signed(ALICE), vec![BOB], false, 1000, 9),
+			Error::<Runtime>::InvalidDelay
+		);
+		assert_noop!(
+			Guardian::set_guardian(RuntimeOrigin::signed(ALICE), vec![], false, 1000, 10),
+			Error::<Runtime>::NoGuardian
+		);
+		assert_noop!(
+			Guardian::set_guardian(RuntimeOrigin::signed(ALICE), vec![ALICE], false, 1000, 10),
+			Error::<Runtime>::SelfGuardian
+		);
+		assert_noop!(
+			Guardian::set_guardian(RuntimeOrigin::signed(ALICE), vec![BOB, CAROL, DAVE, 5], false, 1000, 10),
+			Error::<Runtime>::TooManyGuardians
+		);
+
+		assert_ok!(Guardian::set_guardian(
+			RuntimeOrigin::signed(ALICE),
+			vec![BOB],
+			false,
+			1000,
+			10
+		));
+		System::assert_last_event(RuntimeEvent::Guardian(crate::Event::GuardianSet {
+			who: ALICE,
+			guardians: vec![BOB],
+			use_recovery_friends: false,
+			threshold: 1000,
+			delay: 10,
+		}));
+		assert_eq!(
+			Guardian::guardian_configs(ALICE),
+			Some(GuardianConfig {
+				guardians: vec![BOB].try_into().unwrap(),
+				use_recovery_friends: false,
+				threshold: 1000,
+				delay: 10,
+			})
+		);
+
+		// change of configuration is guarded
+		assert_noop!(
+			Guardian::set_guardian(RuntimeOrigin::signed(ALICE), vec![BOB], false, u128::MAX, 10),
+			Error::<Runtime>::MustBeAnnounced
+		);
+		assert_noop!(
+			Guardian::remove_guardian(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::MustBeAnnounced
+		);
+		assert_noop!(
+			Guardian::remove_guardian(RuntimeOrigin::signed(BOB)),
+			Error::<Runtime>::GuardianNotConfigured
+		);
+	});
+}
+
+#[test]
+fn check_operation_threshold_boundary() {
+	ExtBuilder::default().build().execute_with(|| {
+		// not protected
+		assert_ok!(Guardian::check_operation(&ALICE, withdraw(u128::MAX)));
+		assert_ok!(Guardian::check_operation(
+			&ALICE,
+			GuardedOperation::CloseLoan { currency_id: DOT }
+		));
+
+		assert_ok!(Guardian::set_guardian(
+			RuntimeOrigin::signed(ALICE),
+			vec![BOB],
+			false,
+			1000,
+			10
+		));
+
+		// value at the threshold is allowed, 100 * 10 = 1000
+		assert_ok!(Guardian::check_operation(&ALICE, withdraw(100)));
+		assert_eq!(Guardian::guarded_usage(ALICE), (1, 1000));
+		// accumulated value above the threshold is rejected
+		assert_noop!(
+			Guardian::check_operation(&ALICE, withdraw(1)),
+			Error::<Runtime>::MustBeAnnounced
+		);
+		assert_noop!(
+			Guardian::check_operation(
+				&ALICE,
+				GuardedOperation::Redeem {
+					currency_id: LDOT,
+					amount: 1
+				}
+			),
+			Error::<Runtime>::MustBeAnnounced
+		);
+
+		// new period
+		System::set_block_number(11);
+		assert_noop!(
+			Guardian::check_operation(&ALICE, withdraw(101)),
+			Error::<Runtime>::MustBeAnnounced
+		);
+		assert_ok!(Guardian::check_operation(&ALICE, withdraw(60)));
+		assert_ok!(Guardian::check_operation(
+			&ALICE,
+			GuardedOperation::Redeem {
+				currency_id: LDOT,
+				amount: 40
+			}
+		));
+		assert_eq!(Guardian::guarded_usage(ALICE), (11, 1000));
+
+		// closure is always guarded
+		System::set_block_number(21);
+		assert_noop!(
+			Guardian::check_operation(&ALICE, GuardedOperation::CloseLoan { currency_id: DOT }),
+			Error::<Runtime>::MustBeAnnounced
+		);
+
+		// operation without price is guarded
+		MockPriceSource::set_relative_price(None);
+		assert_noop!(
+			Guardian::check_operation(&ALICE, withdraw(1)),
+			Error::<Runtime>::MustBeAnnounced
+		);
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_integer(10)));
+		assert_ok!(Guardian::check_operation(&ALICE, withdraw(1)));
+	});
+}
+
+#[test]
+fn veto_pending_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = RuntimeCall::Guardian(crate::Call::remove_guardian {});
+		let hash = call_hash(&call);
+
+		assert_noop!(
+			Guardian::announce(RuntimeOrigin::signed(ALICE), hash),
+			Error::<Runtime>::GuardianNotConfigured
+		);
+		assert_ok!(Guardian::set_guardian(
+			RuntimeOrigin::signed(ALICE),
+			vec![BOB],
+			true,
+			1000,
+			10
+		));
+		assert_ok!(Guardian::announce(RuntimeOrigin::signed(ALICE), hash));
+		System::assert_last_event(RuntimeEvent::Guardian(crate::Event::PendingAnnounced {
+			who: ALICE,
+			call_hash: hash,
+			executable_at: 11,
+		}));
+		assert_noop!(
+			Guardian::announce(RuntimeOrigin::signed(ALICE), hash),
+			Error::<Runtime>::DuplicatedPendingAction
+		);
+
+		// neither the account itself nor others can veto
+		assert_noop!(
+			Guardian::veto_pending(RuntimeOrigin::signed(ALICE), ALICE, hash),
+			Error::<Runtime>::NotGuardian
+		);
+		assert_noop!(
+			Guardian::veto_pending(RuntimeOrigin::signed(CAROL), ALICE, hash),
+			Error::<Runtime>::NotGuardian
+		);
+
+		assert_ok!(Guardian::veto_pending(RuntimeOrigin::signed(BOB), ALICE, hash));
+		System::assert_last_event(RuntimeEvent::Guardian(crate::Event::PendingVetoed {
+			who: ALICE,
+			guardian: BOB,
+			call_hash: hash,
+		}));
+		assert!(Guardian::pending_actions(ALICE).is_empty());
+
+		System::set_block_number(11);
+		assert_noop!(
+			Guardian::execute_pending(RuntimeOrigin::signed(ALICE), Box::new(call.clone())),
+			Error::<Runtime>::PendingActionNotFound
+		);
+
+		// recovery friends can veto
+		Friends::set(vec![CAROL]);
+		assert_ok!(Guardian::announce(RuntimeOrigin::signed(ALICE), hash));
+		assert_ok!(Guardian::veto_pending(RuntimeOrigin::signed(CAROL), ALICE, hash));
+		assert!(Guardian::pending_actions(ALICE).is_empty());
+		assert!(Guardian::guardian_configs(ALICE).is_some());
+	});
+}
+
+#[test]
+fn execute_pending_after_delay_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = RuntimeCall::Guardian(crate::Call::remove_guardian {});
+		let hash = call_hash(&call);
+
+		assert_ok!(Guardian::set_guardian(
+			RuntimeOrigin::signed(ALICE),
+			vec![BOB],
+			false,
+			1000,
+			10
+		));
+		assert_ok!(Guardian::announce(RuntimeOrigin::signed(ALICE), hash));
+
+		System::set_block_number(10);
+		assert_noop!(
+			Guardian::execute_pending(RuntimeOrigin::signed(ALICE), Box::new(call.clone())),
+			Error::<Runtime>::NotYetExecutable
+		);
+		// only the account itself can execute
+		assert_noop!(
+			Guardian::execute_pending(RuntimeOrigin::signed(BOB), Box::new(call.clone())),
+			Error::<Runtime>::PendingActionNotFound
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Guardian::execute_pending(RuntimeOrigin::signed(ALICE), Box::new(call)));
+		System::assert_has_event(RuntimeEvent::Guardian(crate::Event::GuardianRemoved { who: ALICE }));
+		System::assert_last_event(RuntimeEvent::Guardian(crate::Event::PendingExecuted {
+			who: ALICE,
+			call_hash: hash,
+			result: Ok(()),
+		}));
+		assert_eq!(Guardian::guardian_configs(ALICE), None);
+		assert!(Guardian::pending_actions(ALICE).is_empty());
+		assert_eq!(ExecutingFor::<Runtime>::get(), None);
+	});
+}
+
+#[test]
+fn approve_pending_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = RuntimeCall::Guardian(crate::Call::set_guardian {
+			guardians: vec![BOB, CAROL],
+			use_recovery_friends: false,
+			threshold: 2000,
+			delay: 20,
+		});
+		let hash = call_hash(&call);
+
+		assert_ok!(Guardian::set_guardian(
+			RuntimeOrigin::signed(ALICE),
+			vec![BOB],
+			false,
+			1000,
+			10
+		));
+		assert_ok!(Guardian::announce(RuntimeOrigin::signed(ALICE), hash));
+		assert_noop!(
+			Guardian::approve_pending(RuntimeOrigin::signed(CAROL), ALICE, hash),
+			Error::<Runtime>::NotGuardian
+		);
+		assert_noop!(
+			Guardian::approve_pending(RuntimeOrigin::signed(BOB), ALICE, Default::default()),
+			Error::<Runtime>::PendingActionNotFound
+		);
+		assert_ok!(Guardian::approve_pending(RuntimeOrigin::signed(BOB), ALICE, hash));
+		System::assert_last_event(RuntimeEvent::Guardian(crate::Event::PendingApproved {
+			who: ALICE,
+			guardian: BOB,
+			call_hash: hash,
+		}));
+
+		// approved action can be executed immediately
+		assert_ok!(Guardian::execute_pending(RuntimeOrigin::signed(ALICE), Box::new(call)));
+		assert_eq!(
+			Guardian::guardian_configs(ALICE),
+			Some(GuardianConfig {
+				guardians: vec![BOB, CAROL].try_into().unwrap(),
+				use_recovery_friends: false,
+				threshold: 2000,
+				delay: 20,
+			})
+		);
+	});
+}
+
+#[test]
+fn failed_pending_call_is_consumed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// delay is too short
+		let call = RuntimeCall::Guardian(crate::Call::set_guardian {
+			guardians: vec![BOB],
+			use_recovery_friends: false,
+			threshold: 2000,
+			delay: 1,
+		});
+		let hash = call_hash(&call);
+
+		assert_ok!(Guardian::set_guardian(
+			RuntimeOrigin::signed(ALICE),
+			vec![BOB],
+			false,
+			1000,
+			10
+		));
+		assert_ok!(Guardian::announce(RuntimeOrigin::signed(ALICE), hash));
+		assert_ok!(Guardian::announce(RuntimeOrigin::signed(ALICE), Default::default()));
+		assert_noop!(
+			Guardian::announce(
+				RuntimeOrigin::signed(ALICE),
+				call_hash(&RuntimeCall::Guardian(crate::Call::remove_guardian {}))
+			),
+			Error::<Runtime>::TooManyPendingActions
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Guardian::execute_pending(RuntimeOrigin::signed(ALICE), Box::new(call)));
+		System::assert_last_event(RuntimeEvent::Guardian(crate::Event::PendingExecuted {
+			who: ALICE,
+			call_hash: hash,
+			result: Err(Error::<Runtime>::InvalidDelay.into()),
+		}));
+		assert_eq!(Guardian::pending_actions(ALICE).len(), 1);
+		assert_eq!(Guardian::guardian_configs(ALICE).map(|config| config.delay), Some(10));
+	});
+}
@@ -0,0 +1,100 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use parity_scale_codec::{Decode, Encode};
+use primitives::{Balance, EraIndex, Moment};
+use scale_info::TypeInfo;
+use sp_runtime::{codec::Codec, DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The staking ledger of a Homa subaccount on relaychain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct StakingLedgerView {
+	/// The bonded amount. For the nomination pool member, the value of the points after the
+	/// haircut.
+	pub bonded: Balance,
+	/// The unbonding chunks as `(era, amount)`, the amount is unlocked at the era.
+	pub unlocking: Vec<(EraIndex, Balance)>,
+	/// The unbonding already unlocked at the current era, to be withdrawn at the next era bump.
+	pub withdrawable: Balance,
+	/// Whether the subaccount is the member of the nomination pool.
+	pub is_nomination_pool: bool,
+}
+
+/// The XCM operations of a Homa subaccount planned for the next era bump.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PendingOperationView {
+	pub sub_account_index: u16,
+	/// The staking currency cross-transferred to the subaccount, including the XCM transfer fee.
+	pub transfer: Balance,
+	pub bond: Balance,
+	pub unbond: Balance,
+	pub withdraw: Balance,
+}
+
+/// The steps of the rebalance at era bump, in the order of processing.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum EraBumpStep {
+	StakingRewards,
+	ScheduledUnbond,
+	ToBondPool,
+	RedeemRequests,
+	Nominate,
+}
+
+/// The outcome of the rebalance at era bump.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum EraBumpOutcome {
+	Succeeded {
+		/// The number of redeem requests redeemed by unbond or expired.
+		processed_redeem_requests: u32,
+	},
+	/// The rebalance stopped at the failed step, the step and the following ones are retried
+	/// at the next era bump.
+	Failed { step: EraBumpStep, error: DispatchError },
+}
+
+/// The record of an era bump.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EraBumpInfo<BlockNumber> {
+	pub new_era: EraIndex,
+	pub previous_era: EraIndex,
+	pub block_number: BlockNumber,
+	pub relaychain_block_number: BlockNumber,
+	/// The unix time of the block in milliseconds.
+	pub timestamp: Moment,
+	pub outcome: EraBumpOutcome,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait HomaApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		/// The staking ledgers of the subaccounts, including the nomination pool member.
+		fn get_staking_ledgers() -> Vec<(u16, StakingLedgerView)>;
+
+		/// The XCM operations of the subaccounts planned for the next era bump on the current
+		/// state.
+		fn get_pending_operations() -> Vec<PendingOperationView>;
+
+		fn get_last_era_bump_info() -> Option<EraBumpInfo<BlockNumber>>;
+	}
+}
@@ -21,10 +21,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, transactional, PalletId};
+use frame_support::{
+	dispatch::WithPostDispatchInfo,
+	pallet_prelude::*,
+	storage::bounded_btree_set::BoundedBTreeSet,
+	traits::{ExistenceRequirement, UnixTime},
+	transactional, PalletId,
+};
 use frame_system::{ensure_signed, pallet_prelude::*};
+use module_homa_runtime_api::{EraBumpInfo, EraBumpOutcome, EraBumpStep, PendingOperationView, StakingLedgerView};
 use module_support::{
-	ExchangeRate, ExchangeRateProvider, FractionalRate, HomaManager, HomaSubAccountXcm, NomineesProvider, Rate, Ratio,
+	ExchangeRate, ExchangeRateProvider, FractionalRate, GuardedOperation, HomaManager, HomaSubAccountXcm,
+	NomineesProvider, OperationGuard, Rate, Ratio,
 };
 use orml_traits::MultiCurrency;
 use primitives::{Balance, CurrencyId, EraIndex};
@@ -36,7 +44,7 @@ use sp_runtime::{
 	},
 	ArithmeticError, FixedPointNumber,
 };
-use sp_std::{cmp::Ordering, convert::From, prelude::*, vec, vec::Vec};
+use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap, convert::From, prelude::*, vec, vec::Vec};
 
 pub use module::*;
 pub use weights::WeightInfo;
@@ -75,23 +83,65 @@ pub mod module {
 		pub era: EraIndex,
 	}
 
+	/// The priority options of a redeem request.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+	pub struct RedeemRequestPriority {
+		/// The liquid currency held as tip, will be transferred to TreasuryAccount when the request
+		/// is redeemed by unbond.
+		#[codec(compact)]
+		pub tip: Balance,
+		/// The last era in which the request can be redeemed by unbond, the request will be
+		/// cancelled if it has not been processed when era bumps past it.
+		pub expiry_era: Option<EraIndex>,
+	}
+
+	/// The nomination pool membership of Homa subaccount which kept by Homa protocol
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct NominationPoolLedger {
+		/// Corresponding to the points of the pool member on relaychain
+		#[codec(compact)]
+		pub points: Balance,
+		/// The staking currency per point of the nomination pool on relaychain
+		pub point_to_balance: ExchangeRate,
+		/// Corresponding to the unbonding eras of the pool member on relaychain
+		pub unlocking: Vec<UnlockChunk>,
+	}
+
+	impl Default for NominationPoolLedger {
+		fn default() -> Self {
+			Self {
+				points: Zero::zero(),
+				point_to_balance: ExchangeRate::one(),
+				unlocking: vec![],
+			}
+		}
+	}
+
+	impl NominationPoolLedger {
+		/// The staking currency of the points.
+		pub fn points_to_balance(&self, points: Balance) -> Balance {
+			self.point_to_balance.saturating_mul_int(points)
+		}
+
+		/// The points of the staking currency.
+		pub fn balance_to_points(&self, balance: Balance) -> Balance {
+			self.point_to_balance
+				.reciprocal()
+				.unwrap_or_else(ExchangeRate::one)
+				.saturating_mul_int(balance)
+		}
+
+		/// Whether the subaccount is a member of the nomination pool.
+		pub fn is_member(&self) -> bool {
+			!self.points.is_zero() || !self.unlocking.is_empty()
+		}
+	}
+
 	impl StakingLedger {
 		/// Remove entries from `unlocking` that are sufficiently old and the sum of expired
 		/// unlocking.
 		fn consolidate_unlocked(self, current_era: EraIndex) -> (Self, Balance) {
-			let mut expired_unlocking: Balance = Zero::zero();
-			let unlocking = self
-				.unlocking
-				.into_iter()
-				.filter(|chunk| {
-					if chunk.era > current_era {
-						true
-					} else {
-						expired_unlocking = expired_unlocking.saturating_add(chunk.value);
-						false
-					}
-				})
-				.collect();
+			let (unlocking, expired_unlocking) = consolidate_unlocking(self.unlocking, current_era);
 
 			(
 				Self {
@@ -160,10 +210,36 @@ pub mod module {
 		#[pallet::constant]
 		type ProcessRedeemRequestsLimit: Get<u32>;
 
+		/// The maximum number of distinct tips, and of distinct expiry eras, of prioritized redeem
+		/// requests. The expiry era must be less than this number of eras after the current era.
+		#[pallet::constant]
+		type MaxRedeemRequestBuckets: Get<u32>;
+
+		/// The maximum number of prioritized redeem requests with the same tip, or with the same
+		/// expiry era.
+		#[pallet::constant]
+		type MaxRedeemRequestsPerBucket: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 
 		type NominationsProvider: NomineesProvider<RelayChainAccountIdOf<Self>>;
+
+		/// Guard for the high-value operations of protected accounts.
+		type OperationGuard: OperationGuard<Self::AccountId>;
+
+		/// The index of Homa subaccount to join the nomination pool. It must not be in
+		/// `ActiveSubAccountsIndexList`, because a nominator cannot be a pool member on relaychain.
+		#[pallet::constant]
+		type NominationPoolSubAccountIndex: Get<u16>;
+
+		/// The upper bound of the commission rate, enforced on every update regardless of
+		/// the origin.
+		#[pallet::constant]
+		type MaxCommissionRate: Get<Rate>;
+
+		/// Time used to timestamp the era bumps.
+		type UnixTime: UnixTime;
 	}
 
 	#[pallet::error]
@@ -186,6 +262,18 @@ pub mod module {
 		InvalidRate,
 		/// Invalid last era bumped block config
 		InvalidLastEraBumpedBlock,
+		/// The expiry era of redeem request must be greater than RelayChainCurrentEra, and less
+		/// than RelayChainCurrentEra + MaxRedeemRequestBuckets
+		InvalidExpiryEra,
+		/// There are too many distinct tips of redeem requests, and the tip is not higher than the
+		/// lowest one.
+		TooManyRedeemRequestBuckets,
+		/// There are too many redeem requests with the same tip or expiry era.
+		RedeemRequestBucketFull,
+		/// The nomination pool cannot be changed while the subaccount is still a pool member.
+		NominationPoolInUse,
+		/// The commission rate exceeds the cap of the runtime.
+		CommissionRateTooHigh,
 	}
 
 	#[pallet::event]
@@ -203,11 +291,26 @@ pub mod module {
 			redeemer: T::AccountId,
 			liquid_amount: Balance,
 			allow_fast_match: bool,
+			tip: Balance,
+			expiry_era: Option<EraIndex>,
 		},
 		/// Redeem request has been cancelled.
 		RedeemRequestCancelled {
 			redeemer: T::AccountId,
 			cancelled_liquid_amount: Balance,
+			refunded_tip: Balance,
+		},
+		/// Redeem request has not been processed before the expiry era, and been cancelled.
+		RedeemRequestExpired {
+			redeemer: T::AccountId,
+			liquid_amount: Balance,
+			refunded_tip: Balance,
+		},
+		/// The tip of redeem request has been refunded, because its tip bucket was evicted by a
+		/// higher tip.
+		RedeemRequestTipEvicted {
+			redeemer: T::AccountId,
+			refunded_tip: Balance,
 		},
 		/// Redeem request is redeemed partially or fully by fast match.
 		RedeemedByFastMatch {
@@ -222,6 +325,7 @@ pub mod module {
 			era_index_when_unbond: EraIndex,
 			liquid_amount: Balance,
 			unbonding_staking_amount: Balance,
+			tip: Balance,
 		},
 		/// The redeemer withdraw expired redemption.
 		WithdrawRedemption {
@@ -267,6 +371,31 @@ pub mod module {
 			sub_account_index: u16,
 			nominations: Vec<RelayChainAccountIdOf<T>>,
 		},
+		/// The nomination pool to join has been updated.
+		NominationPoolIdUpdated { pool_id: Option<u32> },
+		/// The ratio of staking currency to bond allocated to the nomination pool has been
+		/// updated.
+		NominationPoolAllocationRatioUpdated { allocation_ratio: Ratio },
+		/// The haircut of the nomination pool points value has been updated.
+		NominationPoolPointsHaircutUpdated { points_haircut: Ratio },
+		/// The nomination pool ledger has been reset.
+		NominationPoolLedgerReset { ledger: NominationPoolLedger },
+		/// Transfer staking currency to sub account and bond to the nomination pool on RelayChain
+		HomaPoolBondExtra {
+			pool_id: u32,
+			amount: Balance,
+			points: Balance,
+		},
+		/// Unbond points from the nomination pool on RelayChain
+		HomaPoolUnbond {
+			pool_id: u32,
+			amount: Balance,
+			points: Balance,
+		},
+		/// Withdraw unbonded from the nomination pool on RelayChain
+		HomaPoolWithdrawUnbonded { amount: Balance },
+		/// Mint with zero amount, nothing changed.
+		NoOp { who: T::AccountId },
 	}
 
 	/// The current era of relaychain
@@ -322,6 +451,47 @@ pub mod module {
 	#[pallet::getter(fn redeem_requests)]
 	pub type RedeemRequests<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (Balance, bool), OptionQuery>;
 
+	/// The tip and expiry era of prioritized redeem requests.
+	///
+	/// RedeemRequestPriorities: Map: AccountId => Option<RedeemRequestPriority>
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_request_priorities)]
+	pub type RedeemRequestPriorities<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RedeemRequestPriority, OptionQuery>;
+
+	/// The non-zero tips of redeem requests in ascending order, each tip has a bucket in
+	/// RedeemRequestsByTip.
+	///
+	/// RedeemRequestTipBuckets: value: BoundedBTreeSet<Balance, MaxRedeemRequestBuckets>
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_request_tip_buckets)]
+	pub type RedeemRequestTipBuckets<T: Config> =
+		StorageValue<_, BoundedBTreeSet<Balance, T::MaxRedeemRequestBuckets>, ValueQuery>;
+
+	/// The redeemers of redeem requests in FIFO order for the same tip.
+	///
+	/// RedeemRequestsByTip: Map: tip: Balance => BoundedVec<AccountId, MaxRedeemRequestsPerBucket>
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_requests_by_tip)]
+	pub type RedeemRequestsByTip<T: Config> =
+		StorageMap<_, Twox64Concat, Balance, BoundedVec<T::AccountId, T::MaxRedeemRequestsPerBucket>, ValueQuery>;
+
+	/// The expiry eras of redeem requests in ascending order.
+	///
+	/// RedeemRequestExpiryEras: value: BoundedBTreeSet<EraIndex, MaxRedeemRequestBuckets>
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_request_expiry_eras)]
+	pub type RedeemRequestExpiryEras<T: Config> =
+		StorageValue<_, BoundedBTreeSet<EraIndex, T::MaxRedeemRequestBuckets>, ValueQuery>;
+
+	/// The redeemers of redeem requests which will expire after the era.
+	///
+	/// RedeemRequestExpiries: Map: EraIndex => BoundedVec<AccountId, MaxRedeemRequestsPerBucket>
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_request_expiries)]
+	pub type RedeemRequestExpiries<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, BoundedVec<T::AccountId, T::MaxRedeemRequestsPerBucket>, ValueQuery>;
+
 	/// The records of unbonding by AccountId.
 	///
 	/// Unbondings: double_map AccountId, ExpireEraIndex => UnbondingStakingCurrencyAmount
@@ -378,7 +548,46 @@ pub mod module {
 	#[pallet::getter(fn nominate_interval_era)]
 	pub type NominateIntervalEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
 
+	/// The nomination pool on relaychain for Homa subaccount to join.
+	///
+	/// NominationPoolId: value: Option<u32>
+	#[pallet::storage]
+	#[pallet::getter(fn nomination_pool_id)]
+	pub type NominationPoolId<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// The ratio of the staking currency to bond allocated to the nomination pool when new era.
+	///
+	/// NominationPoolAllocationRatio: value: Ratio
+	#[pallet::storage]
+	#[pallet::getter(fn nomination_pool_allocation_ratio)]
+	pub type NominationPoolAllocationRatio<T: Config> = StorageValue<_, Ratio, ValueQuery>;
+
+	/// The haircut on the value of the nomination pool points, to value the pool conservatively
+	/// in the exchange rate.
+	///
+	/// NominationPoolPointsHaircut: value: Ratio
+	#[pallet::storage]
+	#[pallet::getter(fn nomination_pool_points_haircut)]
+	pub type NominationPoolPointsHaircut<T: Config> = StorageValue<_, Ratio, ValueQuery>;
+
+	/// The nomination pool membership of `NominationPoolSubAccountIndex`.
+	///
+	/// NominationPoolLedgers: value: NominationPoolLedger
+	#[pallet::storage]
+	#[pallet::getter(fn nomination_pool_ledger)]
+	pub type NominationPoolLedgers<T: Config> = StorageValue<_, NominationPoolLedger, ValueQuery>;
+
+	/// The record of the last era bump.
+	///
+	/// LastEraBump: value: Option<EraBumpInfo<BlockNumberFor<T>>>
+	#[pallet::storage]
+	#[pallet::getter(fn last_era_bump)]
+	pub type LastEraBump<T: Config> = StorageValue<_, EraBumpInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -409,9 +618,20 @@ pub mod module {
 		/// - `amount`: The amount of staking currency used to mint liquid currency.
 		#[pallet::call_index(0)]
 		#[pallet::weight(< T as Config >::WeightInfo::mint())]
-		pub fn mint(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+		pub fn mint(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResultWithPostInfo {
 			let minter = ensure_signed(origin)?;
-			Self::do_mint(minter, amount)
+			if amount.is_zero() {
+				Self::deposit_event(Event::<T>::NoOp { who: minter });
+				return Ok(Some(T::WeightInfo::mint_no_op()).into());
+			}
+			// refund the weight if fails at the threshold check.
+			ensure!(
+				amount >= T::MintThreshold::get(),
+				Error::<T>::BelowMintThreshold.with_weight(T::WeightInfo::mint_no_op())
+			);
+
+			Self::do_mint(minter, amount)?;
+			Ok(().into())
 		}
 
 		/// Build/Cancel/Overwrite a redeem request, use liquid currency to redeem staking currency.
@@ -436,7 +656,7 @@ pub mod module {
 			allow_fast_match: bool,
 		) -> DispatchResult {
 			let redeemer = ensure_signed(origin)?;
-			Self::do_request_redeem(redeemer, amount, allow_fast_match)
+			Self::do_request_redeem(redeemer, amount, allow_fast_match, Zero::zero(), None)
 		}
 
 		/// Execute fast match for specific redeem requests.
@@ -506,7 +726,7 @@ pub mod module {
 		/// - `estimated_reward_rate_per_era`: the estimated staking yield of each era on the
 		///   current relay chain.
 		/// - `commission_rate`: the rate to draw from estimated staking rewards as commission to
-		///   HomaTreasury
+		///   HomaTreasury, it must not exceed `MaxCommissionRate`.
 		/// - `fast_match_fee_rate`: the fixed fee rate when redeem request is been fast matched.
 		#[pallet::call_index(4)]
 		#[pallet::weight(< T as Config >::WeightInfo::update_homa_params())]
@@ -531,6 +751,10 @@ pub mod module {
 				Self::deposit_event(Event::<T>::EstimatedRewardRatePerEraUpdated { reward_rate });
 			}
 			if let Some(commission_rate) = commission_rate {
+				ensure!(
+					commission_rate <= T::MaxCommissionRate::get(),
+					Error::<T>::CommissionRateTooHigh
+				);
 				CommissionRate::<T>::mutate(|rate| -> DispatchResult {
 					rate.try_set(commission_rate)
 						.map_err(|_| Error::<T>::InvalidRate.into())
@@ -687,6 +911,117 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Sets the params of the nomination pool.
+		/// Requires `GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `pool_id`: the nomination pool on relaychain to join, `Some(None)` to stop joining.
+		///   Cannot be changed while the subaccount is still a pool member.
+		/// - `allocation_ratio`: the ratio of the staking currency to bond allocated to the
+		///   nomination pool when new era.
+		/// - `points_haircut`: the haircut on the value of the pool points in the exchange rate.
+		#[pallet::call_index(10)]
+		#[pallet::weight(< T as Config >::WeightInfo::update_nomination_pool_params())]
+		pub fn update_nomination_pool_params(
+			origin: OriginFor<T>,
+			pool_id: Option<Option<u32>>,
+			allocation_ratio: Option<Ratio>,
+			points_haircut: Option<Ratio>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			if let Some(pool_id) = pool_id {
+				if Self::nomination_pool_id() != pool_id {
+					ensure!(
+						!Self::nomination_pool_ledger().is_member(),
+						Error::<T>::NominationPoolInUse
+					);
+					NominationPoolId::<T>::set(pool_id);
+					Self::deposit_event(Event::<T>::NominationPoolIdUpdated { pool_id });
+				}
+			}
+			if let Some(allocation_ratio) = allocation_ratio {
+				ensure!(allocation_ratio <= Ratio::one(), Error::<T>::InvalidRate);
+				NominationPoolAllocationRatio::<T>::put(allocation_ratio);
+				Self::deposit_event(Event::<T>::NominationPoolAllocationRatioUpdated { allocation_ratio });
+			}
+			if let Some(points_haircut) = points_haircut {
+				ensure!(points_haircut <= Ratio::one(), Error::<T>::InvalidRate);
+				NominationPoolPointsHaircut::<T>::put(points_haircut);
+				Self::deposit_event(Event::<T>::NominationPoolPointsHaircutUpdated { points_haircut });
+			}
+
+			Ok(())
+		}
+
+		/// Reset the nomination pool ledger according to the pool member and the pool on
+		/// relaychain. Requires `GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `points`: the points of the pool member.
+		/// - `point_to_balance`: the staking currency per point of the pool.
+		/// - `unlocking`: the unbonding eras of the pool member.
+		#[pallet::call_index(11)]
+		#[pallet::weight(< T as Config >::WeightInfo::reset_nomination_pool_ledger())]
+		pub fn reset_nomination_pool_ledger(
+			origin: OriginFor<T>,
+			points: Option<Balance>,
+			point_to_balance: Option<ExchangeRate>,
+			unlocking: Option<Vec<UnlockChunk>>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let ledger = NominationPoolLedgers::<T>::mutate(|ledger| {
+				if let Some(points) = points {
+					ledger.points = points;
+				}
+				if let Some(point_to_balance) = point_to_balance {
+					ledger.point_to_balance = point_to_balance;
+				}
+				if let Some(unlocking) = unlocking {
+					ledger.unlocking = unlocking;
+				}
+				ledger.clone()
+			});
+			Self::deposit_event(Event::<T>::NominationPoolLedgerReset { ledger });
+
+			Ok(())
+		}
+
+		/// Build/Cancel/Overwrite a redeem request like `request_redeem`, with a tip to prioritize
+		/// the redeem by unbond and an expiry era.
+		/// When bump era, the redeem requests with tip are processed in descending order of tip,
+		/// and in FIFO order for the same tip, before the requests without tip. Overwriting a
+		/// request will queue it again.
+		///
+		/// Parameters:
+		/// - `amount`: The amount of liquid currency to be requested  redeemed into Staking
+		///   currency.
+		/// - `allow_fast_match`: allow the request to be fast matched, fast match will take a fixed
+		///   rate as fee.
+		/// - `tip`: the extra liquid currency to prioritize the request, it will be transferred to
+		///   TreasuryAccount when the request is redeemed by unbond, and refunded if the request is
+		///   cancelled, expired or completely fast matched. If there are already
+		///   `MaxRedeemRequestBuckets` distinct tips, the bucket of the lowest tip is evicted for a
+		///   higher tip, and the tips of its requests are refunded.
+		/// - `expiry_era`: the last era in which the request can be redeemed by unbond, the request
+		///   will be cancelled and refunded if it has not been processed before era bumps past it.
+		///   It must be less than `MaxRedeemRequestBuckets` eras after the current era.
+		#[pallet::call_index(12)]
+		#[pallet::weight(< T as Config >::WeightInfo::request_redeem_with_priority()
+			.saturating_add(T::DbWeight::get().reads_writes(3, 3)
+				.saturating_mul(T::MaxRedeemRequestsPerBucket::get().into())))]
+		pub fn request_redeem_with_priority(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: Balance,
+			allow_fast_match: bool,
+			#[pallet::compact] tip: Balance,
+			expiry_era: Option<EraIndex>,
+		) -> DispatchResult {
+			let redeemer = ensure_signed(origin)?;
+			Self::do_request_redeem(redeemer, amount, allow_fast_match, tip, expiry_era)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -778,6 +1113,8 @@ pub mod module {
 			redeemer: T::AccountId,
 			amount: Balance,
 			allow_fast_match: bool,
+			tip: Balance,
+			expiry_era: Option<EraIndex>,
 		) -> DispatchResult {
 			RedeemRequests::<T>::try_mutate_exists(&redeemer, |maybe_request| -> DispatchResult {
 				let (previous_request_amount, _) = maybe_request.take().unwrap_or_default();
@@ -787,9 +1124,27 @@ pub mod module {
 					(!previous_request_amount.is_zero() && amount.is_zero()) || amount >= T::RedeemThreshold::get(),
 					Error::<T>::BelowRedeemThreshold
 				);
+				if let Some(era) = expiry_era {
+					// bound the expiry eras so that they cannot exceed MaxRedeemRequestBuckets
+					let current_era = Self::relay_chain_current_era();
+					ensure!(
+						amount.is_zero()
+							|| (era > current_era
+								&& era < current_era.saturating_add(T::MaxRedeemRequestBuckets::get())),
+						Error::<T>::InvalidExpiryEra
+					);
+				}
 
 				match amount.cmp(&previous_request_amount) {
 					Ordering::Greater => {
+						T::OperationGuard::check_operation(
+							&redeemer,
+							GuardedOperation::Redeem {
+								currency_id: liquid_currency_id,
+								amount: amount.saturating_sub(previous_request_amount),
+							},
+						)?;
+
 						// pay more liquid currency.
 						T::Currency::transfer(
 							liquid_currency_id,
@@ -812,23 +1167,152 @@ pub mod module {
 					_ => Ok(()),
 				}?;
 
+				// the tip is held by Homa until the request is redeemed by unbond.
+				let previous_tip = Self::remove_redeem_request_priority(&redeemer);
+				let tip = if amount.is_zero() { Zero::zero() } else { tip };
+				match tip.cmp(&previous_tip) {
+					Ordering::Greater => T::Currency::transfer(
+						liquid_currency_id,
+						&redeemer,
+						&Self::account_id(),
+						tip.saturating_sub(previous_tip),
+						ExistenceRequirement::AllowDeath,
+					),
+					Ordering::Less => T::Currency::transfer(
+						liquid_currency_id,
+						&Self::account_id(),
+						&redeemer,
+						previous_tip.saturating_sub(tip),
+						ExistenceRequirement::AllowDeath,
+					),
+					_ => Ok(()),
+				}?;
+
 				if !amount.is_zero() {
 					*maybe_request = Some((amount, allow_fast_match));
+					Self::insert_redeem_request_priority(&redeemer, RedeemRequestPriority { tip, expiry_era })?;
 					Self::deposit_event(Event::<T>::RequestedRedeem {
 						redeemer: redeemer.clone(),
 						liquid_amount: amount,
 						allow_fast_match,
+						tip,
+						expiry_era,
 					});
 				} else if !previous_request_amount.is_zero() {
 					Self::deposit_event(Event::<T>::RedeemRequestCancelled {
 						redeemer: redeemer.clone(),
 						cancelled_liquid_amount: previous_request_amount,
+						refunded_tip: previous_tip,
 					});
 				}
 				Ok(())
 			})
 		}
 
+		/// Insert the redeem request of `redeemer` to the end of the bucket of its tip, and to the
+		/// expiries of its expiry era. The requests without tip and expiry era are not indexed.
+		/// If the tip buckets are full, the bucket of the lowest tip is evicted for a higher tip.
+		fn insert_redeem_request_priority(redeemer: &T::AccountId, priority: RedeemRequestPriority) -> DispatchResult {
+			if priority.tip.is_zero() && priority.expiry_era.is_none() {
+				return Ok(());
+			}
+
+			if !priority.tip.is_zero() {
+				RedeemRequestsByTip::<T>::try_append(priority.tip, redeemer)
+					.map_err(|_| Error::<T>::RedeemRequestBucketFull)?;
+				let mut tips = Self::redeem_request_tip_buckets();
+				if !tips.contains(&priority.tip) && tips.len() >= T::MaxRedeemRequestBuckets::get() as usize {
+					let lowest_tip = tips.iter().next().copied().unwrap_or_default();
+					ensure!(lowest_tip < priority.tip, Error::<T>::TooManyRedeemRequestBuckets);
+					Self::evict_tip_bucket(lowest_tip)?;
+					tips.remove(&lowest_tip);
+				}
+				tips.try_insert(priority.tip)
+					.map_err(|_| Error::<T>::TooManyRedeemRequestBuckets)?;
+				RedeemRequestTipBuckets::<T>::put(tips);
+			}
+			if let Some(era) = priority.expiry_era {
+				RedeemRequestExpiries::<T>::try_append(era, redeemer)
+					.map_err(|_| Error::<T>::RedeemRequestBucketFull)?;
+				RedeemRequestExpiryEras::<T>::try_mutate(|eras| {
+					eras.try_insert(era)
+						.map_err(|_| Error::<T>::TooManyRedeemRequestBuckets)
+				})?;
+			}
+			RedeemRequestPriorities::<T>::insert(redeemer, priority);
+			Ok(())
+		}
+
+		/// Remove the redeem request of `redeemer` from the priority index, return the tip held
+		/// for it.
+		fn remove_redeem_request_priority(redeemer: &T::AccountId) -> Balance {
+			match RedeemRequestPriorities::<T>::take(redeemer) {
+				Some(priority) => {
+					if !priority.tip.is_zero() {
+						Self::remove_from_tip_bucket(redeemer, priority.tip);
+					}
+					if let Some(era) = priority.expiry_era {
+						Self::remove_from_expiries(redeemer, era);
+					}
+					priority.tip
+				}
+				None => Zero::zero(),
+			}
+		}
+
+		fn remove_from_tip_bucket(redeemer: &T::AccountId, tip: Balance) {
+			let mut queue = RedeemRequestsByTip::<T>::get(tip);
+			queue.retain(|who| who != redeemer);
+			if queue.is_empty() {
+				RedeemRequestsByTip::<T>::remove(tip);
+				RedeemRequestTipBuckets::<T>::mutate(|tips| {
+					tips.remove(&tip);
+				});
+			} else {
+				RedeemRequestsByTip::<T>::insert(tip, queue);
+			}
+		}
+
+		/// Refund the tips of the redeem requests in the bucket of `tip`, the requests remain but
+		/// lose the priority of tip.
+		fn evict_tip_bucket(tip: Balance) -> DispatchResult {
+			for redeemer in RedeemRequestsByTip::<T>::take(tip) {
+				RedeemRequestPriorities::<T>::mutate_exists(&redeemer, |maybe_priority| {
+					if let Some(priority) = maybe_priority {
+						priority.tip = Zero::zero();
+						if priority.expiry_era.is_none() {
+							*maybe_priority = None;
+						}
+					}
+				});
+				T::Currency::transfer(
+					T::LiquidCurrencyId::get(),
+					&Self::account_id(),
+					&redeemer,
+					tip,
+					ExistenceRequirement::AllowDeath,
+				)?;
+				Self::deposit_event(Event::<T>::RedeemRequestTipEvicted {
+					redeemer,
+					refunded_tip: tip,
+				});
+			}
+			Ok(())
+		}
+
+		fn remove_from_expiries(redeemer: &T::AccountId, era: EraIndex) {
+			let mut expiries = RedeemRequestExpiries::<T>::get(era);
+			expiries.retain(|who| who != redeemer);
+			if expiries.is_empty() {
+				RedeemRequestExpiries::<T>::remove(era);
+				RedeemRequestExpiryEras::<T>::mutate(|eras| {
+					eras.remove(&era);
+				});
+			} else {
+				RedeemRequestExpiries::<T>::insert(era, expiries);
+			}
+		}
+
 		/// Get the soft cap of total staking currency of Homa.
 		/// Soft cap = ActiveSubAccountsIndexList.len() * SoftBondedCapPerSubAccount
 		pub fn get_staking_currency_soft_cap() -> Balance {
@@ -836,9 +1320,26 @@ pub mod module {
 				.saturating_mul(T::ActiveSubAccountsIndexList::get().len() as Balance)
 		}
 
+		/// Calculate the value of the bonded points in the nomination pool, after the haircut.
+		pub fn get_nomination_pool_bonded() -> Balance {
+			Self::nomination_pool_bonded(&Self::nomination_pool_ledger())
+		}
+
+		fn nomination_pool_bonded(ledger: &NominationPoolLedger) -> Balance {
+			Ratio::one()
+				.saturating_sub(Self::nomination_pool_points_haircut())
+				.saturating_mul_int(ledger.points_to_balance(ledger.points))
+		}
+
+		/// Calculate the total amount of staking currency bonded on relaychain, including the
+		/// nomination pool.
+		pub fn get_total_bonded_with_pool() -> Balance {
+			TotalStakingBonded::<T>::get().saturating_add(Self::get_nomination_pool_bonded())
+		}
+
 		/// Calculate the total amount of staking currency belong to Homa.
 		pub fn get_total_staking_currency() -> Balance {
-			TotalStakingBonded::<T>::get().saturating_add(Self::to_bond_pool())
+			Self::get_total_bonded_with_pool().saturating_add(Self::to_bond_pool())
 		}
 
 		/// Calculate the total amount of liquid currency.
@@ -851,8 +1352,10 @@ pub mod module {
 		/// Note: ExchangeRate(staking : liquid) = total_staking_amount / total_liquid_amount.
 		/// If the exchange rate cannot be calculated, T::DefaultExchangeRate is used.
 		pub fn current_exchange_rate() -> ExchangeRate {
-			let total_staking = Self::get_total_staking_currency();
-			let total_liquid = Self::get_total_liquid_currency();
+			Self::exchange_rate_of(Self::get_total_staking_currency(), Self::get_total_liquid_currency())
+		}
+
+		fn exchange_rate_of(total_staking: Balance, total_liquid: Balance) -> ExchangeRate {
 			if total_staking.is_zero() {
 				T::DefaultExchangeRate::get()
 			} else {
@@ -938,6 +1441,18 @@ pub mod module {
 					if !remainder_request_amount.is_zero() {
 						ensure!(allow_partially, Error::<T>::CannotCompletelyFastMatch);
 						*maybe_request = Some((remainder_request_amount, allow_fast_match));
+					} else {
+						// refund the tip, it's only used to prioritize the redeem by unbond.
+						let tip = Self::remove_redeem_request_priority(redeemer);
+						if !tip.is_zero() {
+							T::Currency::transfer(
+								T::LiquidCurrencyId::get(),
+								&module_account,
+								redeemer,
+								tip,
+								ExistenceRequirement::AllowDeath,
+							)?;
+						}
 					}
 				}
 
@@ -953,11 +1468,10 @@ pub mod module {
 		/// be regarded as an implicit mint fee!
 		#[transactional]
 		pub fn process_staking_rewards(new_era: EraIndex, previous_era: EraIndex) -> DispatchResult {
-			let era_interval = new_era.saturating_sub(previous_era);
-			let reward_rate = Self::estimated_reward_rate_per_era()
-				.saturating_add(Rate::one())
-				.saturating_pow(era_interval.unique_saturated_into())
-				.saturating_sub(Rate::one());
+			let reward_rate = era_reward_rate(
+				Self::estimated_reward_rate_per_era(),
+				new_era.saturating_sub(previous_era),
+			);
 
 			if !reward_rate.is_zero() {
 				let mut total_reward_staking: Balance = Zero::zero();
@@ -976,16 +1490,28 @@ pub mod module {
 					}
 				}
 
+				// the rewards of the nomination pool are bonded to the pool.
+				let pool_bonded_before = Self::get_nomination_pool_bonded();
+				if let Some(reward_points) = Self::nomination_pool_id()
+					.map(|_| reward_rate.saturating_mul_int(Self::nomination_pool_ledger().points))
+					.filter(|points| !points.is_zero())
+				{
+					T::XcmInterface::bond_pool_rewards_on_sub_account(T::NominationPoolSubAccountIndex::get())?;
+					NominationPoolLedgers::<T>::mutate(|ledger| {
+						ledger.points = ledger.points.saturating_add(reward_points);
+					});
+					total_reward_staking = total_reward_staking
+						.saturating_add(Self::get_nomination_pool_bonded().saturating_sub(pool_bonded_before));
+				}
+
 				let commission_rate = Self::commission_rate();
 				if !total_reward_staking.is_zero() && !commission_rate.is_zero() {
-					let commission_staking_amount = commission_rate.saturating_mul_int(total_reward_staking);
-					let commission_ratio =
-						Ratio::checked_from_rational(commission_staking_amount, TotalStakingBonded::<T>::get())
-							.unwrap_or_else(Ratio::min_value);
-					let inflate_rate = commission_ratio
-						.checked_div(&Ratio::one().saturating_sub(commission_ratio))
-						.unwrap_or_else(Ratio::max_value);
-					let inflate_liquid_amount = inflate_rate.saturating_mul_int(Self::get_total_liquid_currency());
+					let inflate_liquid_amount = commission_inflation(
+						total_reward_staking,
+						commission_rate,
+						Self::get_total_bonded_with_pool(),
+						Self::get_total_liquid_currency(),
+					);
 
 					Self::issue_liquid_currency(&T::TreasuryAccount::get(), inflate_liquid_amount)?;
 				}
@@ -1021,6 +1547,23 @@ pub mod module {
 				}
 			}
 
+			// withdraw the expired unlocking of the nomination pool
+			let pool_ledger = Self::nomination_pool_ledger();
+			let (unlocking, expired_unlocking) = consolidate_unlocking(pool_ledger.unlocking, new_era);
+			if !expired_unlocking.is_zero() {
+				T::XcmInterface::withdraw_unbonded_from_pool_on_sub_account(
+					T::NominationPoolSubAccountIndex::get(),
+					expired_unlocking,
+				)?;
+
+				NominationPoolLedgers::<T>::mutate(|ledger| ledger.unlocking = unlocking);
+				total_withdrawn_staking = total_withdrawn_staking.saturating_add(expired_unlocking);
+
+				Self::deposit_event(Event::<T>::HomaPoolWithdrawUnbonded {
+					amount: expired_unlocking,
+				});
+			}
+
 			// issue withdrawn unbonded to module account for redeemer to claim
 			Self::issue_staking_currency(&Self::account_id(), total_withdrawn_staking)?;
 			UnclaimedRedemption::<T>::mutate(|total| *total = total.saturating_add(total_withdrawn_staking));
@@ -1028,7 +1571,8 @@ pub mod module {
 			Ok(())
 		}
 
-		/// Distribute PoolToBond to ActiveSubAccountsIndexList, then cross-transfer the
+		/// Allocate NominationPoolAllocationRatio of PoolToBond to the nomination pool, and
+		/// distribute the rest to ActiveSubAccountsIndexList, then cross-transfer the
 		/// distribution amount to the subaccounts on relaychain and bond it by XCM.
 		#[transactional]
 		pub fn process_to_bond_pool() -> DispatchResult {
@@ -1041,12 +1585,14 @@ pub mod module {
 					.iter()
 					.map(|index| (*index, Self::staking_ledgers(index).unwrap_or_default().bonded))
 					.collect();
-				let (distribution, remainder) = distribute_increment::<u16>(
-					bonded_list,
+				let (to_bond_nomination_pool, distribution, remainder) = plan_bond(
 					to_bond_pool,
-					Some(Self::soft_bonded_cap_per_sub_account().saturating_add(xcm_transfer_fee)),
-					Some(xcm_transfer_fee),
+					Self::nomination_pool_id().map(|_| Self::nomination_pool_allocation_ratio()),
+					bonded_list,
+					Self::soft_bonded_cap_per_sub_account(),
+					xcm_transfer_fee,
 				);
+				Self::process_to_bond_nomination_pool(to_bond_nomination_pool, xcm_transfer_fee)?;
 
 				// subaccounts execute the distribution
 				for (sub_account_index, amount) in distribution {
@@ -1080,46 +1626,186 @@ pub mod module {
 			Ok(())
 		}
 
-		/// Process redeem requests and subaccounts do unbond on relaychain by XCM message.
-		#[transactional]
-		pub fn process_redeem_requests(new_era: EraIndex) -> Result<u32, DispatchError> {
-			let era_index_to_expire = new_era + T::BondingDuration::get();
-			let total_bonded = TotalStakingBonded::<T>::get();
-			let mut total_redeem_amount: Balance = Zero::zero();
+		/// Cross-transfer the `amount` allocated to the nomination pool to the subaccount on
+		/// relaychain and join or bond it to the pool by XCM.
+		fn process_to_bond_nomination_pool(amount: Balance, xcm_transfer_fee: Balance) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+			let pool_id = Self::nomination_pool_id().unwrap_or_default();
+
+			let sub_account_index = T::NominationPoolSubAccountIndex::get();
+			T::XcmInterface::transfer_staking_to_sub_account(&Self::account_id(), sub_account_index, amount)?;
+
+			let bond_amount = amount.saturating_sub(xcm_transfer_fee);
+			let points = NominationPoolLedgers::<T>::try_mutate(|ledger| -> Result<Balance, DispatchError> {
+				if ledger.is_member() {
+					T::XcmInterface::bond_extra_to_pool_on_sub_account(sub_account_index, bond_amount)?;
+				} else {
+					T::XcmInterface::join_pool_on_sub_account(sub_account_index, pool_id, bond_amount)?;
+				}
+
+				let points = ledger.balance_to_points(bond_amount);
+				ledger.points = ledger.points.saturating_add(points);
+				Ok(points)
+			})?;
+
+			Self::deposit_event(Event::<T>::HomaPoolBondExtra {
+				pool_id,
+				amount: bond_amount,
+				points,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel the redeem requests whose expiry era is before `new_era`, refund the liquid
+		/// currency and tip to redeemers.
+		pub fn process_expired_redeem_requests(new_era: EraIndex) -> Result<u32, DispatchError> {
+			let expired_eras: Vec<EraIndex> = Self::redeem_request_expiry_eras()
+				.iter()
+				.take_while(|era| **era < new_era)
+				.copied()
+				.collect();
+			let mut expired_requests: u32 = 0;
+
+			for era in expired_eras {
+				for redeemer in RedeemRequestExpiries::<T>::take(era) {
+					if let Some(priority) = RedeemRequestPriorities::<T>::take(&redeemer) {
+						if !priority.tip.is_zero() {
+							Self::remove_from_tip_bucket(&redeemer, priority.tip);
+						}
+						let (liquid_amount, _) = RedeemRequests::<T>::take(&redeemer).unwrap_or_default();
+						T::Currency::transfer(
+							T::LiquidCurrencyId::get(),
+							&Self::account_id(),
+							&redeemer,
+							liquid_amount.saturating_add(priority.tip),
+							ExistenceRequirement::AllowDeath,
+						)?;
+
+						Self::deposit_event(Event::<T>::RedeemRequestExpired {
+							redeemer,
+							liquid_amount,
+							refunded_tip: priority.tip,
+						});
+						expired_requests += 1;
+					}
+				}
+				RedeemRequestExpiryEras::<T>::mutate(|eras| {
+					eras.remove(&era);
+				});
+			}
+
+			Ok(expired_requests)
+		}
+
+		/// The redeem requests to be redeemed by unbond at the era bump to `new_era`, as
+		/// `(redeemer, liquid_amount, staking_amount)` in the order of processing: the
+		/// prioritized requests in descending order of tip and FIFO in the same tip, then the
+		/// others. The requests expired before `new_era` are skipped. The selection stops at the
+		/// first request which exceeds the remaining `total_bonded` or `ProcessRedeemRequestsLimit`.
+		fn redeem_requests_to_unbond(
+			new_era: EraIndex,
+			total_bonded: Balance,
+			exchange_rate: ExchangeRate,
+		) -> Result<Vec<(T::AccountId, Balance, Balance)>, DispatchError> {
+			let limit = T::ProcessRedeemRequestsLimit::get() as usize;
 			let mut remain_total_bonded = total_bonded;
-			let mut handled_requests: u32 = 0;
+			let mut requests: Vec<(T::AccountId, Balance, Balance)> = vec![];
 
-			// iter RedeemRequests and insert to Unbondings if remain_total_bonded is enough.
-			for (redeemer, (redeem_amount, _)) in RedeemRequests::<T>::iter() {
-				let redemption_amount = Self::convert_liquid_to_staking(redeem_amount)?;
+			// select the redeem request if remain_total_bonded is enough, return false if the
+			// request cannot be handled.
+			let mut try_select = |redeemer: T::AccountId| -> Result<bool, DispatchError> {
+				let (redeem_amount, _) = Self::redeem_requests(&redeemer).unwrap_or_default();
+				let redemption_amount = exchange_rate
+					.checked_mul_int(redeem_amount)
+					.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))?;
 
-				if remain_total_bonded >= redemption_amount && handled_requests < T::ProcessRedeemRequestsLimit::get() {
-					total_redeem_amount = total_redeem_amount.saturating_add(redeem_amount);
+				if remain_total_bonded >= redemption_amount && requests.len() < limit {
 					remain_total_bonded = remain_total_bonded.saturating_sub(redemption_amount);
-					RedeemRequests::<T>::remove(&redeemer);
-					Unbondings::<T>::mutate(&redeemer, era_index_to_expire, |n| {
-						*n = n.saturating_add(redemption_amount)
-					});
-					Self::deposit_event(Event::<T>::RedeemedByUnbond {
-						redeemer,
-						era_index_when_unbond: new_era,
-						liquid_amount: redeem_amount,
-						unbonding_staking_amount: redemption_amount,
-					});
-
-					handled_requests += 1;
+					requests.push((redeemer, redeem_amount, redemption_amount));
+					Ok(true)
 				} else {
-					break;
+					Ok(false)
+				}
+			};
+
+			let mut exhausted = false;
+			'buckets: for tip in Self::redeem_request_tip_buckets().into_inner().into_iter().rev() {
+				for redeemer in RedeemRequestsByTip::<T>::get(tip) {
+					let expired = Self::redeem_request_priorities(&redeemer)
+						.and_then(|priority| priority.expiry_era)
+						.is_some_and(|era| era < new_era);
+					if expired {
+						continue;
+					}
+					if !try_select(redeemer)? {
+						exhausted = true;
+						break 'buckets;
+					}
 				}
 			}
 
-			// calculate the distribution for unbond
-			let staking_amount_to_unbond = total_bonded.saturating_sub(remain_total_bonded);
+			// the requests without tip are handled after all requests with tip.
+			if !exhausted {
+				for redeemer in RedeemRequests::<T>::iter_keys() {
+					let priority = Self::redeem_request_priorities(&redeemer).unwrap_or_default();
+					if !priority.tip.is_zero() || priority.expiry_era.is_some_and(|era| era < new_era) {
+						continue;
+					}
+					if !try_select(redeemer)? {
+						break;
+					}
+				}
+			}
+
+			Ok(requests)
+		}
+
+		/// Process redeem requests and subaccounts do unbond on relaychain by XCM message.
+		#[transactional]
+		pub fn process_redeem_requests(new_era: EraIndex) -> Result<u32, DispatchError> {
+			let expired_requests = Self::process_expired_redeem_requests(new_era)?;
+			let era_index_to_expire = new_era + T::BondingDuration::get();
+			let total_bonded = Self::get_total_bonded_with_pool();
+			let requests = Self::redeem_requests_to_unbond(new_era, total_bonded, Self::current_exchange_rate())?;
+			let handled_requests = requests.len() as u32;
+			let mut total_redeem_amount: Balance = Zero::zero();
+			let mut total_tip: Balance = Zero::zero();
+			let mut staking_amount_to_unbond: Balance = Zero::zero();
+
+			for (redeemer, redeem_amount, redemption_amount) in requests {
+				let tip = Self::remove_redeem_request_priority(&redeemer);
+				total_redeem_amount = total_redeem_amount.saturating_add(redeem_amount);
+				total_tip = total_tip.saturating_add(tip);
+				staking_amount_to_unbond = staking_amount_to_unbond.saturating_add(redemption_amount);
+				RedeemRequests::<T>::remove(&redeemer);
+				Unbondings::<T>::mutate(&redeemer, era_index_to_expire, |n| {
+					*n = n.saturating_add(redemption_amount)
+				});
+				Self::deposit_event(Event::<T>::RedeemedByUnbond {
+					redeemer,
+					era_index_when_unbond: new_era,
+					liquid_amount: redeem_amount,
+					unbonding_staking_amount: redemption_amount,
+					tip,
+				});
+			}
+
+			// calculate the distribution for unbond, the nomination pool unbond in proportion to its
+			// share of the total bonded.
 			let bonded_list: Vec<(u16, Balance)> = T::ActiveSubAccountsIndexList::get()
 				.iter()
 				.map(|index| (*index, Self::staking_ledgers(index).unwrap_or_default().bonded))
 				.collect();
-			let (distribution, _) = distribute_decrement::<u16>(bonded_list, staking_amount_to_unbond, None, None);
+			let (pool_amount_to_unbond, distribution) = plan_unbond(
+				staking_amount_to_unbond,
+				total_bonded,
+				Self::get_nomination_pool_bonded(),
+				bonded_list,
+			);
+			Self::process_unbond_nomination_pool(pool_amount_to_unbond, era_index_to_expire)?;
 
 			// subaccounts execute the distribution
 			for (sub_account_index, unbond_amount) in distribution {
@@ -1146,7 +1832,45 @@ pub mod module {
 			// burn total_redeem_amount.
 			Self::burn_liquid_currency(&Self::account_id(), total_redeem_amount)?;
 
-			Ok(handled_requests)
+			// transfer the tips of handled requests to TreasuryAccount.
+			if !total_tip.is_zero() {
+				T::Currency::transfer(
+					T::LiquidCurrencyId::get(),
+					&Self::account_id(),
+					&T::TreasuryAccount::get(),
+					total_tip,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+
+			Ok(handled_requests.saturating_add(expired_requests))
+		}
+
+		/// Unbond the points of `amount` staking currency from the nomination pool on relaychain
+		/// by XCM message.
+		fn process_unbond_nomination_pool(amount: Balance, era_index_to_expire: EraIndex) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+			let pool_id = Self::nomination_pool_id().unwrap_or_default();
+
+			NominationPoolLedgers::<T>::try_mutate(|ledger| -> DispatchResult {
+				let points = ledger.balance_to_points(amount).min(ledger.points);
+				T::XcmInterface::unbond_from_pool_on_sub_account(T::NominationPoolSubAccountIndex::get(), points)?;
+
+				ledger.points = ledger.points.saturating_sub(points);
+				ledger.unlocking.push(UnlockChunk {
+					value: amount,
+					era: era_index_to_expire,
+				});
+
+				Self::deposit_event(Event::<T>::HomaPoolUnbond {
+					pool_id,
+					amount,
+					points,
+				});
+				Ok(())
+			})
 		}
 
 		/// Process nominate validators for subaccounts on relaychain.
@@ -1186,18 +1910,19 @@ pub mod module {
 		pub fn bump_current_era(amount: EraIndex) -> Result<u32, DispatchError> {
 			let previous_era = Self::relay_chain_current_era();
 			let new_era = previous_era.saturating_add(amount);
+			let relaychain_block_number = T::RelayChainBlockNumber::current_block_number();
 			RelayChainCurrentEra::<T>::put(new_era);
-			LastEraBumpedBlock::<T>::put(T::RelayChainBlockNumber::current_block_number());
+			LastEraBumpedBlock::<T>::put(relaychain_block_number);
 			Self::deposit_event(Event::<T>::CurrentEraBumped { new_era_index: new_era });
 
 			// Rebalance:
-			let res = || -> Result<u32, DispatchError> {
+			let res = || -> Result<u32, (EraBumpStep, DispatchError)> {
 				TotalVoidLiquid::<T>::put(0);
-				Self::process_staking_rewards(new_era, previous_era)?;
-				Self::process_scheduled_unbond(new_era)?;
-				Self::process_to_bond_pool()?;
-				let count = Self::process_redeem_requests(new_era)?;
-				Self::process_nominate(new_era)?;
+				Self::process_staking_rewards(new_era, previous_era).map_err(|e| (EraBumpStep::StakingRewards, e))?;
+				Self::process_scheduled_unbond(new_era).map_err(|e| (EraBumpStep::ScheduledUnbond, e))?;
+				Self::process_to_bond_pool().map_err(|e| (EraBumpStep::ToBondPool, e))?;
+				let count = Self::process_redeem_requests(new_era).map_err(|e| (EraBumpStep::RedeemRequests, e))?;
+				Self::process_nominate(new_era).map_err(|e| (EraBumpStep::Nominate, e))?;
 				Ok(count)
 			}();
 
@@ -1207,7 +1932,201 @@ pub mod module {
 				new_era, res
 			);
 
-			res
+			LastEraBump::<T>::put(EraBumpInfo {
+				new_era,
+				previous_era,
+				block_number: frame_system::Pallet::<T>::block_number(),
+				relaychain_block_number,
+				timestamp: T::UnixTime::now().as_millis().unique_saturated_into(),
+				outcome: match res {
+					Ok(count) => EraBumpOutcome::Succeeded {
+						processed_redeem_requests: count,
+					},
+					Err((step, error)) => EraBumpOutcome::Failed { step, error },
+				},
+			});
+
+			res.map_err(|(_, error)| error)
+		}
+
+		/// The staking ledgers of the subaccounts, and the nomination pool membership of
+		/// `NominationPoolSubAccountIndex` if any, ordered by index.
+		pub fn get_staking_ledgers() -> Vec<(u16, StakingLedgerView)> {
+			let current_era = Self::relay_chain_current_era();
+			let view = |bonded: Balance, unlocking: Vec<UnlockChunk>, is_nomination_pool: bool| {
+				let (_, withdrawable) = consolidate_unlocking(unlocking.clone(), current_era);
+				StakingLedgerView {
+					bonded,
+					unlocking: unlocking.into_iter().map(|chunk| (chunk.era, chunk.value)).collect(),
+					withdrawable,
+					is_nomination_pool,
+				}
+			};
+
+			let mut ledgers: Vec<(u16, StakingLedgerView)> = StakingLedgers::<T>::iter()
+				.map(|(sub_account_index, ledger)| (sub_account_index, view(ledger.bonded, ledger.unlocking, false)))
+				.collect();
+			let pool_ledger = Self::nomination_pool_ledger();
+			if pool_ledger.is_member() {
+				ledgers.push((
+					T::NominationPoolSubAccountIndex::get(),
+					view(Self::nomination_pool_bonded(&pool_ledger), pool_ledger.unlocking, true),
+				));
+			}
+			ledgers.sort_by_key(|(sub_account_index, _)| *sub_account_index);
+
+			ledgers
+		}
+
+		/// The XCM operations of the subaccounts planned for the next era bump, which bumps at
+		/// least one era. The rebalance of `bump_current_era` is replayed on a copy of the
+		/// current state with the same planning functions. Only the subaccounts with operations
+		/// are returned, ordered by index.
+		pub fn get_pending_operations() -> Result<Vec<PendingOperationView>, DispatchError> {
+			fn operation(
+				operations: &mut BTreeMap<u16, PendingOperationView>,
+				sub_account_index: u16,
+			) -> &mut PendingOperationView {
+				operations
+					.entry(sub_account_index)
+					.or_insert_with(|| PendingOperationView {
+						sub_account_index,
+						..Default::default()
+					})
+			}
+			let active_bonded_list = |ledgers: &BTreeMap<u16, StakingLedger>| -> Vec<(u16, Balance)> {
+				T::ActiveSubAccountsIndexList::get()
+					.iter()
+					.map(|index| {
+						(
+							*index,
+							ledgers.get(index).map(|ledger| ledger.bonded).unwrap_or_default(),
+						)
+					})
+					.collect()
+			};
+
+			let previous_era = Self::relay_chain_current_era();
+			let new_era = previous_era.saturating_add(
+				Self::era_amount_should_to_bump(T::RelayChainBlockNumber::current_block_number()).max(1),
+			);
+			let pool_sub_account_index = T::NominationPoolSubAccountIndex::get();
+			let mut ledgers: BTreeMap<u16, StakingLedger> = StakingLedgers::<T>::iter().collect();
+			let mut pool_ledger = Self::nomination_pool_ledger();
+			let mut operations: BTreeMap<u16, PendingOperationView> = BTreeMap::new();
+			// TotalVoidLiquid is reset at era bump.
+			let mut total_liquid = T::Currency::total_issuance(T::LiquidCurrencyId::get());
+
+			// staking rewards, as `process_staking_rewards`
+			let reward_rate = era_reward_rate(
+				Self::estimated_reward_rate_per_era(),
+				new_era.saturating_sub(previous_era),
+			);
+			if !reward_rate.is_zero() {
+				let mut total_reward_staking: Balance = Zero::zero();
+				for ledger in ledgers.values_mut() {
+					let reward_staking = reward_rate.saturating_mul_int(ledger.bonded);
+					ledger.bonded = ledger.bonded.saturating_add(reward_staking);
+					total_reward_staking = total_reward_staking.saturating_add(reward_staking);
+				}
+				if Self::nomination_pool_id().is_some() {
+					let pool_bonded_before = Self::nomination_pool_bonded(&pool_ledger);
+					pool_ledger.points = pool_ledger
+						.points
+						.saturating_add(reward_rate.saturating_mul_int(pool_ledger.points));
+					total_reward_staking = total_reward_staking
+						.saturating_add(Self::nomination_pool_bonded(&pool_ledger).saturating_sub(pool_bonded_before));
+				}
+
+				let commission_rate = Self::commission_rate();
+				if !total_reward_staking.is_zero() && !commission_rate.is_zero() {
+					let total_bonded = ledgers
+						.values()
+						.fold(Self::nomination_pool_bonded(&pool_ledger), |total, ledger| {
+							total.saturating_add(ledger.bonded)
+						});
+					total_liquid = total_liquid.saturating_add(commission_inflation(
+						total_reward_staking,
+						commission_rate,
+						total_bonded,
+						total_liquid,
+					));
+				}
+			}
+
+			// withdraw unbonded, as `process_scheduled_unbond`
+			for (sub_account_index, ledger) in ledgers.iter() {
+				let (_, expired_unlocking) = consolidate_unlocking(ledger.unlocking.clone(), new_era);
+				if !expired_unlocking.is_zero() {
+					operation(&mut operations, *sub_account_index).withdraw = expired_unlocking;
+				}
+			}
+			let (_, expired_unlocking) = consolidate_unlocking(pool_ledger.unlocking.clone(), new_era);
+			if !expired_unlocking.is_zero() {
+				operation(&mut operations, pool_sub_account_index).withdraw = expired_unlocking;
+			}
+
+			// bond, as `process_to_bond_pool`
+			let mut to_bond_pool = Self::to_bond_pool();
+			if to_bond_pool >= T::MintThreshold::get() {
+				let xcm_transfer_fee = T::XcmInterface::get_xcm_transfer_fee();
+				let (to_bond_nomination_pool, distribution, remainder) = plan_bond(
+					to_bond_pool,
+					Self::nomination_pool_id().map(|_| Self::nomination_pool_allocation_ratio()),
+					active_bonded_list(&ledgers),
+					Self::soft_bonded_cap_per_sub_account(),
+					xcm_transfer_fee,
+				);
+
+				if !to_bond_nomination_pool.is_zero() {
+					let bond_amount = to_bond_nomination_pool.saturating_sub(xcm_transfer_fee);
+					pool_ledger.points = pool_ledger
+						.points
+						.saturating_add(pool_ledger.balance_to_points(bond_amount));
+					let pool_operation = operation(&mut operations, pool_sub_account_index);
+					pool_operation.transfer = to_bond_nomination_pool;
+					pool_operation.bond = bond_amount;
+				}
+				for (sub_account_index, amount) in distribution {
+					if !amount.is_zero() {
+						let bond_amount = amount.saturating_sub(xcm_transfer_fee);
+						let ledger = ledgers.entry(sub_account_index).or_default();
+						ledger.bonded = ledger.bonded.saturating_add(bond_amount);
+						let sub_account_operation = operation(&mut operations, sub_account_index);
+						sub_account_operation.transfer = amount;
+						sub_account_operation.bond = bond_amount;
+					}
+				}
+				to_bond_pool = remainder;
+			}
+
+			// unbond for redeem requests, as `process_redeem_requests`
+			let pool_bonded = Self::nomination_pool_bonded(&pool_ledger);
+			let total_bonded = ledgers
+				.values()
+				.fold(pool_bonded, |total, ledger| total.saturating_add(ledger.bonded));
+			let exchange_rate = Self::exchange_rate_of(total_bonded.saturating_add(to_bond_pool), total_liquid);
+			let staking_amount_to_unbond = Self::redeem_requests_to_unbond(new_era, total_bonded, exchange_rate)?
+				.into_iter()
+				.fold(Zero::zero(), |total: Balance, (_, _, redemption_amount)| {
+					total.saturating_add(redemption_amount)
+				});
+			let (pool_amount_to_unbond, distribution) = plan_unbond(
+				staking_amount_to_unbond,
+				total_bonded,
+				pool_bonded,
+				active_bonded_list(&ledgers),
+			);
+			if !pool_amount_to_unbond.is_zero() {
+				operation(&mut operations, pool_sub_account_index).unbond = pool_amount_to_unbond;
+			}
+			for (sub_account_index, unbond_amount) in distribution {
+				if !unbond_amount.is_zero() {
+					operation(&mut operations, sub_account_index).unbond = unbond_amount;
+				}
+			}
+
+			Ok(operations.into_values().collect())
 		}
 
 		/// This should be the only function in the system that issues liquid currency
@@ -1251,7 +2170,7 @@ impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
 	}
 
 	fn request_redeem(who: T::AccountId, amount: Balance, fast_match: bool) -> DispatchResult {
-		Self::do_request_redeem(who, amount, fast_match)
+		Self::do_request_redeem(who, amount, fast_match, Zero::zero(), None)
 	}
 
 	fn get_exchange_rate() -> ExchangeRate {
@@ -1271,6 +2190,25 @@ impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
 	}
 }
 
+/// Split the unlocking chunks which are not expired at `current_era`, and the sum of expired
+/// unlocking.
+fn consolidate_unlocking(unlocking: Vec<UnlockChunk>, current_era: EraIndex) -> (Vec<UnlockChunk>, Balance) {
+	let mut expired_unlocking: Balance = Zero::zero();
+	let unlocking = unlocking
+		.into_iter()
+		.filter(|chunk| {
+			if chunk.era > current_era {
+				true
+			} else {
+				expired_unlocking = expired_unlocking.saturating_add(chunk.value);
+				false
+			}
+		})
+		.collect();
+
+	(unlocking, expired_unlocking)
+}
+
 /// Helpers for distribute increment/decrement to as possible to keep the list balanced after
 /// distribution.
 pub fn distribute_increment<Index>(
@@ -1337,3 +2275,76 @@ pub fn distribute_decrement<Index>(
 
 	(distribution_list, remain_decrement)
 }
+
+/// The compound reward rate of `era_interval` eras at `reward_rate_per_era`.
+pub fn era_reward_rate(reward_rate_per_era: Rate, era_interval: EraIndex) -> Rate {
+	reward_rate_per_era
+		.saturating_add(Rate::one())
+		.saturating_pow(era_interval.unique_saturated_into())
+		.saturating_sub(Rate::one())
+}
+
+/// The liquid currency to issue to draw `commission_rate` of `total_reward` staking rewards
+/// as commission, diluting the `total_liquid` backed by `total_bonded`.
+pub fn commission_inflation(
+	total_reward: Balance,
+	commission_rate: Rate,
+	total_bonded: Balance,
+	total_liquid: Balance,
+) -> Balance {
+	let commission_staking_amount = commission_rate.saturating_mul_int(total_reward);
+	let commission_ratio =
+		Ratio::checked_from_rational(commission_staking_amount, total_bonded).unwrap_or_else(Ratio::min_value);
+	let inflate_rate = commission_ratio
+		.checked_div(&Ratio::one().saturating_sub(commission_ratio))
+		.unwrap_or_else(Ratio::max_value);
+	inflate_rate.saturating_mul_int(total_liquid)
+}
+
+/// Plan the bond of `to_bond_pool` at era bump: allocate `pool_allocation_ratio` of it to the
+/// nomination pool if any, and distribute the rest to `bonded_list` up to `bonded_cap` per
+/// subaccount. The amounts include the XCM transfer fee, the nomination pool allocation which
+/// cannot cover the fee is skipped. Return the pool allocation, the distribution and the
+/// remainder.
+pub fn plan_bond(
+	to_bond_pool: Balance,
+	pool_allocation_ratio: Option<Ratio>,
+	bonded_list: Vec<(u16, Balance)>,
+	bonded_cap: Balance,
+	xcm_transfer_fee: Balance,
+) -> (Balance, Vec<(u16, Balance)>, Balance) {
+	let to_bond_nomination_pool = pool_allocation_ratio
+		.map(|ratio| ratio.saturating_mul_int(to_bond_pool))
+		.filter(|amount| *amount > xcm_transfer_fee)
+		.unwrap_or_else(Zero::zero);
+	let (distribution, remainder) = distribute_increment::<u16>(
+		bonded_list,
+		to_bond_pool.saturating_sub(to_bond_nomination_pool),
+		Some(bonded_cap.saturating_add(xcm_transfer_fee)),
+		Some(xcm_transfer_fee),
+	);
+
+	(to_bond_nomination_pool, distribution, remainder)
+}
+
+/// Plan the unbond of `amount_to_unbond` at era bump: the nomination pool unbonds in proportion
+/// to its `pool_bonded` share of `total_bonded`, and the rest is distributed to `bonded_list`.
+/// Return the amount unbonded from the pool and the distribution.
+pub fn plan_unbond(
+	amount_to_unbond: Balance,
+	total_bonded: Balance,
+	pool_bonded: Balance,
+	bonded_list: Vec<(u16, Balance)>,
+) -> (Balance, Vec<(u16, Balance)>) {
+	let pool_amount_to_unbond = Ratio::checked_from_rational(pool_bonded, total_bonded)
+		.unwrap_or_else(Ratio::zero)
+		.saturating_mul_int(amount_to_unbond);
+	let (distribution, _) = distribute_decrement::<u16>(
+		bonded_list,
+		amount_to_unbond.saturating_sub(pool_amount_to_unbond),
+		None,
+		None,
+	);
+
+	(pool_amount_to_unbond, distribution)
+}
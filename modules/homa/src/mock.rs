@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU128, ConstU32, Nothing},
+	traits::{ConstU128, ConstU32, ConstU64, Nothing},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use module_support::mocks::MockAddressMapping;
@@ -83,6 +83,26 @@ impl HomaSubAccountXcm<AccountId, Balance> for MockHomaSubAccountXcm {
 		Ok(())
 	}
 
+	fn join_pool_on_sub_account(_: u16, _: u32, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn bond_extra_to_pool_on_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn bond_pool_rewards_on_sub_account(_: u16) -> DispatchResult {
+		Ok(())
+	}
+
+	fn unbond_from_pool_on_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn withdraw_unbonded_from_pool_on_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn get_xcm_transfer_fee() -> Balance {
 		1_000_000
 	}
@@ -136,6 +156,13 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ();
 }
 
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1_000>;
+	type WeightInfo = ();
+}
+
 pub type AdaptedBasicCurrency = module_currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 
 parameter_types! {
@@ -155,6 +182,9 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type AccountCurrencies = ();
+	type Swap = module_support::SpecificJointsSwap<(), ()>;
+	type MaxTransferAllCurrencies = ConstU32<0>;
 }
 
 impl BlockNumberProvider for MockRelayBlockNumberProvider {
@@ -180,6 +210,8 @@ parameter_types! {
 	pub static MintThreshold: Balance = 0;
 	pub static RedeemThreshold: Balance = 0;
 	pub static MockRelayBlockNumberProvider: BlockNumber = 0;
+	pub const NominationPoolSubAccountIndex: u16 = 10;
+	pub static MaxCommissionRate: Rate = Rate::saturating_from_rational(50, 100);
 }
 
 pub struct MockNominationsProvider;
@@ -222,6 +254,12 @@ impl Config for Runtime {
 	type WeightInfo = ();
 	type NominationsProvider = MockNominationsProvider;
 	type ProcessRedeemRequestsLimit = ConstU32<3>;
+	type MaxRedeemRequestBuckets = ConstU32<3>;
+	type MaxRedeemRequestsPerBucket = ConstU32<3>;
+	type OperationGuard = ();
+	type NominationPoolSubAccountIndex = NominationPoolSubAccountIndex;
+	type MaxCommissionRate = MaxCommissionRate;
+	type UnixTime = Timestamp;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -233,6 +271,7 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances,
 		Tokens: orml_tokens,
 		Currencies: module_currencies,
+		Timestamp: pallet_timestamp,
 	}
 );
 
@@ -25,6 +25,7 @@ use frame_support::{assert_noop, assert_ok};
 use mock::{RuntimeEvent, *};
 use orml_traits::MultiCurrency;
 use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+use sp_std::collections::btree_set::BTreeSet;
 
 #[test]
 fn mint_works() {
@@ -47,7 +48,7 @@ fn mint_works() {
 
 			assert_noop!(
 				Homa::mint(RuntimeOrigin::signed(ALICE), 99_999),
-				Error::<Runtime>::BelowMintThreshold
+				Error::<Runtime>::BelowMintThreshold.with_weight(<() as WeightInfo>::mint_no_op())
 			);
 			assert_noop!(
 				Homa::mint(RuntimeOrigin::signed(ALICE), 3_000_001),
@@ -120,6 +121,27 @@ fn mint_works() {
 		});
 }
 
+#[test]
+fn mint_zero_is_no_op() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, STAKING_CURRENCY_ID, 1_000_000)])
+		.build()
+		.execute_with(|| {
+			MintThreshold::set(100_000);
+
+			let post_info = Homa::mint(RuntimeOrigin::signed(ALICE), 0).unwrap();
+			assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::mint_no_op()));
+			System::assert_last_event(RuntimeEvent::Homa(crate::Event::NoOp { who: ALICE }));
+			assert_eq!(Currencies::free_balance(STAKING_CURRENCY_ID, &ALICE), 1_000_000);
+			assert_eq!(Homa::to_bond_pool(), 0);
+
+			// below the threshold fails, but the weight is refunded
+			let err = Homa::mint(RuntimeOrigin::signed(ALICE), 99_999).unwrap_err();
+			assert_eq!(err.error, Error::<Runtime>::BelowMintThreshold.into());
+			assert_eq!(err.post_info.actual_weight, Some(<() as WeightInfo>::mint_no_op()));
+		});
+}
+
 #[test]
 fn request_redeem_works() {
 	ExtBuilder::default()
@@ -147,6 +169,8 @@ fn request_redeem_works() {
 				redeemer: ALICE,
 				liquid_amount: 1_000_000,
 				allow_fast_match: false,
+				tip: 0,
+				expiry_era: None,
 			}));
 			assert_eq!(Homa::redeem_requests(&ALICE), Some((1_000_000, false)));
 			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 9_000_000);
@@ -160,6 +184,8 @@ fn request_redeem_works() {
 				redeemer: BOB,
 				liquid_amount: 10_000_000,
 				allow_fast_match: true,
+				tip: 0,
+				expiry_era: None,
 			}));
 			assert_eq!(Homa::redeem_requests(&BOB), Some((10_000_000, true)));
 			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &BOB), 0);
@@ -174,6 +200,8 @@ fn request_redeem_works() {
 				redeemer: ALICE,
 				liquid_amount: 2_000_000,
 				allow_fast_match: true,
+				tip: 0,
+				expiry_era: None,
 			}));
 			assert_eq!(Homa::redeem_requests(&ALICE), Some((2_000_000, true)));
 			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 8_000_000);
@@ -187,6 +215,7 @@ fn request_redeem_works() {
 			System::assert_last_event(RuntimeEvent::Homa(crate::Event::RedeemRequestCancelled {
 				redeemer: BOB,
 				cancelled_liquid_amount: 10_000_000,
+				refunded_tip: 0,
 			}));
 			assert_eq!(Homa::redeem_requests(&BOB), None);
 			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &BOB), 10_000_000);
@@ -197,6 +226,235 @@ fn request_redeem_works() {
 		});
 }
 
+#[test]
+fn request_redeem_with_tip_and_expiry_works() {
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, LIQUID_CURRENCY_ID, 10_000_000),
+			(BOB, LIQUID_CURRENCY_ID, 10_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Homa::relay_chain_current_era(), 0);
+			assert_noop!(
+				Homa::request_redeem_with_priority(RuntimeOrigin::signed(ALICE), 1_000_000, false, 100_000, Some(0)),
+				Error::<Runtime>::InvalidExpiryEra
+			);
+
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(ALICE),
+				1_000_000,
+				false,
+				100_000,
+				Some(2)
+			));
+			System::assert_last_event(RuntimeEvent::Homa(crate::Event::RequestedRedeem {
+				redeemer: ALICE,
+				liquid_amount: 1_000_000,
+				allow_fast_match: false,
+				tip: 100_000,
+				expiry_era: Some(2),
+			}));
+			assert_eq!(Homa::redeem_requests(&ALICE), Some((1_000_000, false)));
+			assert_eq!(
+				Homa::redeem_request_priorities(&ALICE),
+				Some(RedeemRequestPriority {
+					tip: 100_000,
+					expiry_era: Some(2)
+				})
+			);
+			assert_eq!(
+				Homa::redeem_request_tip_buckets().into_inner(),
+				BTreeSet::from([100_000])
+			);
+			assert_eq!(Homa::redeem_requests_by_tip(100_000), vec![ALICE]);
+			assert_eq!(Homa::redeem_request_expiry_eras().into_inner(), BTreeSet::from([2]));
+			assert_eq!(Homa::redeem_request_expiries(2), vec![ALICE]);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 8_900_000);
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY_ID, &Homa::account_id()),
+				1_100_000
+			);
+
+			// the request without tip and expiry era is not indexed
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(BOB), 1_000_000, false));
+			assert_eq!(Homa::redeem_request_priorities(&BOB), None);
+			assert_eq!(
+				Homa::redeem_request_tip_buckets().into_inner(),
+				BTreeSet::from([100_000])
+			);
+			assert_eq!(Homa::redeem_requests_by_tip(0), vec![]);
+
+			// Alice overwrite the redeem_request with lower tip and without expiry
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(ALICE),
+				1_000_000,
+				false,
+				50_000,
+				None
+			));
+			assert_eq!(
+				Homa::redeem_request_priorities(&ALICE),
+				Some(RedeemRequestPriority {
+					tip: 50_000,
+					expiry_era: None
+				})
+			);
+			assert_eq!(
+				Homa::redeem_request_tip_buckets().into_inner(),
+				BTreeSet::from([50_000])
+			);
+			assert_eq!(Homa::redeem_requests_by_tip(50_000), vec![ALICE]);
+			assert_eq!(Homa::redeem_requests_by_tip(100_000), vec![]);
+			assert_eq!(Homa::redeem_request_expiry_eras().into_inner(), BTreeSet::new());
+			assert_eq!(Homa::redeem_request_expiries(2), vec![]);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 8_950_000);
+
+			// Alice cancel the redeem_request, tip is refunded
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(ALICE), 0, false));
+			System::assert_last_event(RuntimeEvent::Homa(crate::Event::RedeemRequestCancelled {
+				redeemer: ALICE,
+				cancelled_liquid_amount: 1_000_000,
+				refunded_tip: 50_000,
+			}));
+			assert_eq!(Homa::redeem_requests(&ALICE), None);
+			assert_eq!(Homa::redeem_request_priorities(&ALICE), None);
+			assert_eq!(Homa::redeem_request_tip_buckets().into_inner(), BTreeSet::new());
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 10_000_000);
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY_ID, &Homa::account_id()),
+				1_000_000
+			);
+		});
+}
+
+#[test]
+fn request_redeem_with_priority_is_bounded() {
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, LIQUID_CURRENCY_ID, 10_000_000),
+			(BOB, LIQUID_CURRENCY_ID, 10_000_000),
+			(CHARLIE, LIQUID_CURRENCY_ID, 10_000_000),
+			(DAVE, LIQUID_CURRENCY_ID, 10_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			for redeemer in [ALICE, BOB, CHARLIE] {
+				assert_ok!(Homa::request_redeem_with_priority(
+					RuntimeOrigin::signed(redeemer),
+					1_000_000,
+					false,
+					1_000,
+					Some(1)
+				));
+			}
+			assert_eq!(Homa::redeem_requests_by_tip(1_000), vec![ALICE, BOB, CHARLIE]);
+			assert_eq!(Homa::redeem_request_expiries(1), vec![ALICE, BOB, CHARLIE]);
+
+			// the bucket of the tip or the expiry era is full
+			assert_noop!(
+				Homa::request_redeem_with_priority(RuntimeOrigin::signed(DAVE), 1_000_000, false, 1_000, None),
+				Error::<Runtime>::RedeemRequestBucketFull
+			);
+			assert_noop!(
+				Homa::request_redeem_with_priority(RuntimeOrigin::signed(DAVE), 1_000_000, false, 2_000, Some(1)),
+				Error::<Runtime>::RedeemRequestBucketFull
+			);
+			// the request without tip and expiry era is not limited
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(DAVE), 1_000_000, false));
+
+			// the expiry era is bounded by MaxRedeemRequestBuckets eras after the current era
+			assert_noop!(
+				Homa::request_redeem_with_priority(RuntimeOrigin::signed(DAVE), 1_000_000, false, 0, Some(3)),
+				Error::<Runtime>::InvalidExpiryEra
+			);
+
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(ALICE),
+				1_000_000,
+				false,
+				2_000,
+				Some(2)
+			));
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(BOB),
+				1_000_000,
+				false,
+				3_000,
+				Some(2)
+			));
+			assert_eq!(
+				Homa::redeem_request_tip_buckets().into_inner(),
+				BTreeSet::from([1_000, 2_000, 3_000])
+			);
+			assert_eq!(Homa::redeem_request_expiry_eras().into_inner(), BTreeSet::from([1, 2]));
+
+			// too many distinct tips, and the tip is not higher than the lowest one
+			assert_noop!(
+				Homa::request_redeem_with_priority(RuntimeOrigin::signed(DAVE), 1_000_000, false, 500, None),
+				Error::<Runtime>::TooManyRedeemRequestBuckets
+			);
+
+			// the higher tip evicts the bucket of the lowest tip, the evicted tip is refunded
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(DAVE),
+				1_000_000,
+				false,
+				4_000,
+				None
+			));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemRequestTipEvicted {
+				redeemer: CHARLIE,
+				refunded_tip: 1_000,
+			}));
+			assert_eq!(
+				Homa::redeem_request_tip_buckets().into_inner(),
+				BTreeSet::from([2_000, 3_000, 4_000])
+			);
+			assert_eq!(Homa::redeem_requests_by_tip(1_000), vec![]);
+			assert_eq!(Homa::redeem_requests_by_tip(4_000), vec![DAVE]);
+			assert_eq!(Homa::redeem_requests(&CHARLIE), Some((1_000_000, false)));
+			assert_eq!(
+				Homa::redeem_request_priorities(&CHARLIE),
+				Some(RedeemRequestPriority {
+					tip: 0,
+					expiry_era: Some(1)
+				})
+			);
+			assert_eq!(Homa::redeem_request_expiries(1), vec![CHARLIE]);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &CHARLIE), 9_000_000);
+
+			// the evicted request can be prioritized again by a tip higher than the lowest one
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(CHARLIE),
+				1_000_000,
+				false,
+				5_000,
+				Some(1)
+			));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemRequestTipEvicted {
+				redeemer: ALICE,
+				refunded_tip: 2_000,
+			}));
+			assert_eq!(
+				Homa::redeem_request_tip_buckets().into_inner(),
+				BTreeSet::from([3_000, 4_000, 5_000])
+			);
+			assert_eq!(
+				Homa::redeem_request_priorities(&ALICE),
+				Some(RedeemRequestPriority {
+					tip: 0,
+					expiry_era: Some(2)
+				})
+			);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 9_000_000);
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY_ID, &Homa::account_id()),
+				4_012_000
+			);
+		});
+}
+
 #[test]
 fn claim_redemption_works() {
 	ExtBuilder::default()
@@ -296,6 +554,35 @@ fn update_homa_params_works() {
 	});
 }
 
+#[test]
+fn update_homa_params_enforces_commission_rate_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		MaxCommissionRate::set(Rate::saturating_from_rational(20, 100));
+
+		assert_noop!(
+			Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				None,
+				Some(Rate::saturating_from_rational(21, 100)),
+				None,
+				None,
+			),
+			Error::<Runtime>::CommissionRateTooHigh
+		);
+
+		assert_ok!(Homa::update_homa_params(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			None,
+			None,
+			Some(Rate::saturating_from_rational(20, 100)),
+			None,
+			None,
+		));
+		assert_eq!(Homa::commission_rate(), Rate::saturating_from_rational(20, 100));
+	});
+}
+
 #[test]
 fn update_bump_era_params_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1063,6 +1350,7 @@ fn process_redeem_requests_works() {
 				era_index_when_unbond: 1,
 				liquid_amount: 20_000_000,
 				unbonding_staking_amount: 2_000_000,
+				tip: 0,
 			}));
 			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaUnbond {
 				sub_account_index: 1,
@@ -1112,12 +1400,14 @@ fn process_redeem_requests_works() {
 				era_index_when_unbond: 2,
 				liquid_amount: 20_000_000,
 				unbonding_staking_amount: 2_000_000,
+				tip: 0,
 			}));
 			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemedByUnbond {
 				redeemer: CHARLIE,
 				era_index_when_unbond: 2,
 				liquid_amount: 10_000_000,
 				unbonding_staking_amount: 1_000_000,
+				tip: 0,
 			}));
 			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaUnbond {
 				sub_account_index: 0,
@@ -1168,6 +1458,120 @@ fn process_redeem_requests_works() {
 		});
 }
 
+#[test]
+fn process_redeem_requests_by_tip_and_expiry_works() {
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, LIQUID_CURRENCY_ID, 10_000_000),
+			(BOB, LIQUID_CURRENCY_ID, 10_000_000),
+			(CHARLIE, LIQUID_CURRENCY_ID, 10_000_000),
+			(DAVE, LIQUID_CURRENCY_ID, 10_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Homa::reset_ledgers(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				vec![(0, Some(2_000_000), None)]
+			));
+			ToBondPool::<Runtime>::put(6_000_000);
+
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(ALICE),
+				5_000_000,
+				false,
+				0,
+				Some(1)
+			));
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(BOB),
+				5_000_000,
+				false,
+				100_000,
+				None
+			));
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(CHARLIE),
+				5_000_000,
+				false,
+				10_000,
+				Some(1)
+			));
+			assert_ok!(Homa::request_redeem_with_priority(
+				RuntimeOrigin::signed(DAVE),
+				5_000_000,
+				false,
+				200_000,
+				Some(1)
+			));
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY_ID, &Homa::account_id()),
+				20_310_000
+			);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &HOMA_TREASURY), 0);
+
+			// total_bonded is only enough for two requests, the requests with higher tip are processed.
+			assert_eq!(Homa::process_redeem_requests(1), Ok(2));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemedByUnbond {
+				redeemer: DAVE,
+				era_index_when_unbond: 1,
+				liquid_amount: 5_000_000,
+				unbonding_staking_amount: 1_000_000,
+				tip: 200_000,
+			}));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemedByUnbond {
+				redeemer: BOB,
+				era_index_when_unbond: 1,
+				liquid_amount: 5_000_000,
+				unbonding_staking_amount: 1_000_000,
+				tip: 100_000,
+			}));
+			assert_eq!(Homa::redeem_requests(&ALICE), Some((5_000_000, false)));
+			assert_eq!(Homa::redeem_requests(&BOB), None);
+			assert_eq!(Homa::redeem_requests(&CHARLIE), Some((5_000_000, false)));
+			assert_eq!(Homa::redeem_requests(&DAVE), None);
+			assert_eq!(Homa::redeem_request_priorities(&DAVE), None);
+			assert_eq!(Homa::unbondings(&BOB, 1 + BondingDuration::get()), 1_000_000);
+			assert_eq!(Homa::unbondings(&DAVE, 1 + BondingDuration::get()), 1_000_000);
+			assert_eq!(
+				Homa::redeem_request_tip_buckets().into_inner(),
+				BTreeSet::from([10_000])
+			);
+			assert_eq!(Homa::redeem_request_expiry_eras().into_inner(), BTreeSet::from([1]));
+			assert_eq!(Homa::redeem_request_expiries(1), vec![ALICE, CHARLIE]);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &HOMA_TREASURY), 300_000);
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY_ID, &Homa::account_id()),
+				10_010_000
+			);
+
+			// the requests with lower tip have not been processed before expiry era, are cancelled.
+			assert_eq!(Homa::process_redeem_requests(2), Ok(2));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemRequestExpired {
+				redeemer: ALICE,
+				liquid_amount: 5_000_000,
+				refunded_tip: 0,
+			}));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemRequestExpired {
+				redeemer: CHARLIE,
+				liquid_amount: 5_000_000,
+				refunded_tip: 10_000,
+			}));
+			assert_eq!(Homa::redeem_requests(&ALICE), None);
+			assert_eq!(Homa::redeem_requests(&CHARLIE), None);
+			assert_eq!(Homa::redeem_request_priorities(&CHARLIE), None);
+			assert_eq!(Homa::unbondings(&ALICE, 2 + BondingDuration::get()), 0);
+			assert_eq!(Homa::unbondings(&CHARLIE, 2 + BondingDuration::get()), 0);
+			assert_eq!(Homa::redeem_request_tip_buckets().into_inner(), BTreeSet::new());
+			assert_eq!(Homa::redeem_request_expiry_eras().into_inner(), BTreeSet::new());
+			assert_eq!(Homa::redeem_request_expiries(1), vec![]);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 10_000_000);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &BOB), 4_900_000);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &CHARLIE), 10_000_000);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &DAVE), 4_800_000);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &Homa::account_id()), 0);
+		});
+}
+
 #[test]
 fn process_nominate_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1365,6 +1769,7 @@ fn bump_current_era_works() {
 				era_index_when_unbond: 3,
 				liquid_amount: 280_000_000,
 				unbonding_staking_amount: 26_605_824,
+				tip: 0,
 			}));
 			assert_eq!(Homa::last_era_bumped_block(), 300);
 			assert_eq!(Homa::relay_chain_current_era(), 3);
@@ -1523,18 +1928,21 @@ fn process_redeem_requests_under_limit_works() {
 				era_index_when_unbond: 1,
 				liquid_amount: 5_000_000,
 				unbonding_staking_amount: 1_000_000,
+				tip: 0,
 			}));
 			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemedByUnbond {
 				redeemer: BOB,
 				era_index_when_unbond: 1,
 				liquid_amount: 5_000_000,
 				unbonding_staking_amount: 1_000_000,
+				tip: 0,
 			}));
 			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemedByUnbond {
 				redeemer: CHARLIE,
 				era_index_when_unbond: 1,
 				liquid_amount: 5_000_000,
 				unbonding_staking_amount: 1_000_000,
+				tip: 0,
 			}));
 			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaUnbond {
 				sub_account_index: 0,
@@ -1550,3 +1958,585 @@ fn process_redeem_requests_under_limit_works() {
 			assert_eq!(Homa::unbondings(&DAVE, 1 + BondingDuration::get()), 0);
 		});
 }
+
+#[test]
+fn update_nomination_pool_params_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Homa::update_nomination_pool_params(RuntimeOrigin::signed(ALICE), Some(Some(7)), None, None),
+			BadOrigin
+		);
+		assert_noop!(
+			Homa::update_nomination_pool_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				Some(Ratio::saturating_from_rational(101, 100)),
+				None
+			),
+			Error::<Runtime>::InvalidRate
+		);
+		assert_noop!(
+			Homa::update_nomination_pool_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				None,
+				Some(Ratio::saturating_from_rational(101, 100))
+			),
+			Error::<Runtime>::InvalidRate
+		);
+		assert_eq!(Homa::nomination_pool_id(), None);
+		assert_eq!(Homa::nomination_pool_allocation_ratio(), Ratio::zero());
+		assert_eq!(Homa::nomination_pool_points_haircut(), Ratio::zero());
+
+		assert_ok!(Homa::update_nomination_pool_params(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			Some(Some(7)),
+			Some(Ratio::saturating_from_rational(40, 100)),
+			Some(Ratio::saturating_from_rational(10, 100)),
+		));
+		System::assert_has_event(RuntimeEvent::Homa(crate::Event::NominationPoolIdUpdated {
+			pool_id: Some(7),
+		}));
+		System::assert_has_event(RuntimeEvent::Homa(crate::Event::NominationPoolAllocationRatioUpdated {
+			allocation_ratio: Ratio::saturating_from_rational(40, 100),
+		}));
+		System::assert_has_event(RuntimeEvent::Homa(crate::Event::NominationPoolPointsHaircutUpdated {
+			points_haircut: Ratio::saturating_from_rational(10, 100),
+		}));
+		assert_eq!(Homa::nomination_pool_id(), Some(7));
+		assert_eq!(
+			Homa::nomination_pool_allocation_ratio(),
+			Ratio::saturating_from_rational(40, 100)
+		);
+		assert_eq!(
+			Homa::nomination_pool_points_haircut(),
+			Ratio::saturating_from_rational(10, 100)
+		);
+
+		// the pool cannot be changed while the subaccount is still a pool member
+		assert_ok!(Homa::reset_nomination_pool_ledger(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			None,
+			None,
+			Some(vec![UnlockChunk {
+				value: 1_000_000,
+				era: 10
+			}]),
+		));
+		assert_noop!(
+			Homa::update_nomination_pool_params(RuntimeOrigin::signed(HomaAdmin::get()), Some(Some(8)), None, None),
+			Error::<Runtime>::NominationPoolInUse
+		);
+		assert_noop!(
+			Homa::update_nomination_pool_params(RuntimeOrigin::signed(HomaAdmin::get()), Some(None), None, None),
+			Error::<Runtime>::NominationPoolInUse
+		);
+
+		assert_ok!(Homa::reset_nomination_pool_ledger(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			None,
+			None,
+			Some(vec![]),
+		));
+		assert_ok!(Homa::update_nomination_pool_params(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			Some(None),
+			None,
+			None
+		));
+		System::assert_last_event(RuntimeEvent::Homa(crate::Event::NominationPoolIdUpdated {
+			pool_id: None,
+		}));
+		assert_eq!(Homa::nomination_pool_id(), None);
+	});
+}
+
+#[test]
+fn reset_nomination_pool_ledger_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Homa::reset_nomination_pool_ledger(RuntimeOrigin::signed(ALICE), Some(1_000_000), None, None),
+			BadOrigin
+		);
+		assert_eq!(Homa::nomination_pool_ledger(), NominationPoolLedger::default());
+		assert_eq!(Homa::nomination_pool_ledger().point_to_balance, ExchangeRate::one());
+
+		assert_ok!(Homa::reset_nomination_pool_ledger(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			Some(1_000_000),
+			Some(ExchangeRate::saturating_from_rational(25, 10)),
+			None,
+		));
+		let ledger = NominationPoolLedger {
+			points: 1_000_000,
+			point_to_balance: ExchangeRate::saturating_from_rational(25, 10),
+			unlocking: vec![],
+		};
+		System::assert_last_event(RuntimeEvent::Homa(crate::Event::NominationPoolLedgerReset {
+			ledger: ledger.clone(),
+		}));
+		assert_eq!(Homa::nomination_pool_ledger(), ledger);
+
+		assert_ok!(Homa::reset_nomination_pool_ledger(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			None,
+			None,
+			Some(vec![UnlockChunk {
+				value: 500_000,
+				era: 10
+			}]),
+		));
+		assert_eq!(
+			Homa::nomination_pool_ledger(),
+			NominationPoolLedger {
+				points: 1_000_000,
+				point_to_balance: ExchangeRate::saturating_from_rational(25, 10),
+				unlocking: vec![UnlockChunk {
+					value: 500_000,
+					era: 10
+				}],
+			}
+		);
+	});
+}
+
+#[test]
+fn nomination_pool_ledger_accounting_works() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, LIQUID_CURRENCY_ID, 50_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Homa::reset_ledgers(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				vec![(0, Some(3_000_000), None)]
+			));
+			ToBondPool::<Runtime>::put(1_000_000);
+			assert_eq!(Homa::get_nomination_pool_bonded(), 0);
+			assert_eq!(Homa::get_total_bonded_with_pool(), 3_000_000);
+			assert_eq!(Homa::get_total_staking_currency(), 4_000_000);
+
+			// 1_000_000 points * 2.5 = 2_500_000 staking currency
+			assert_ok!(Homa::reset_nomination_pool_ledger(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000),
+				Some(ExchangeRate::saturating_from_rational(25, 10)),
+				Some(vec![UnlockChunk {
+					value: 500_000,
+					era: 10
+				}]),
+			));
+			assert_eq!(Homa::get_nomination_pool_bonded(), 2_500_000);
+			assert_eq!(Homa::get_total_bonded(), 3_000_000);
+			assert_eq!(Homa::get_total_bonded_with_pool(), 5_500_000);
+			assert_eq!(Homa::get_total_staking_currency(), 6_500_000);
+
+			// value the pool with 20% haircut
+			assert_ok!(Homa::update_nomination_pool_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				None,
+				Some(Ratio::saturating_from_rational(20, 100)),
+			));
+			assert_eq!(Homa::get_nomination_pool_bonded(), 2_000_000);
+			assert_eq!(Homa::get_total_bonded_with_pool(), 5_000_000);
+			assert_eq!(Homa::get_total_staking_currency(), 6_000_000);
+			assert_eq!(
+				Homa::current_exchange_rate(),
+				ExchangeRate::saturating_from_rational(6_000_000, 50_000_000)
+			);
+		});
+}
+
+#[test]
+fn process_nomination_pool_works() {
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, STAKING_CURRENCY_ID, 20_000_000),
+			(BOB, LIQUID_CURRENCY_ID, 50_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(10_000_000),
+				None,
+				None,
+				None,
+				None,
+			));
+			assert_ok!(Homa::update_nomination_pool_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(Some(7)),
+				Some(Ratio::saturating_from_rational(40, 100)),
+				Some(Ratio::saturating_from_rational(20, 100)),
+			));
+			assert_ok!(Homa::reset_nomination_pool_ledger(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				Some(ExchangeRate::saturating_from_rational(25, 10)),
+				None,
+			));
+
+			// 40% of ToBondPool is allocated to join the pool, the rest is distributed to subaccounts
+			ToBondPool::<Runtime>::put(10_000_000);
+			assert_ok!(Currencies::deposit(
+				STAKING_CURRENCY_ID,
+				&Homa::account_id(),
+				10_000_000
+			));
+			assert_ok!(Homa::process_to_bond_pool());
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaPoolBondExtra {
+				pool_id: 7,
+				amount: 3_000_000,
+				points: 1_200_000,
+			}));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaBondExtra {
+				sub_account_index: 0,
+				amount: 5_000_000,
+			}));
+			assert_eq!(
+				Homa::nomination_pool_ledger(),
+				NominationPoolLedger {
+					points: 1_200_000,
+					point_to_balance: ExchangeRate::saturating_from_rational(25, 10),
+					unlocking: vec![],
+				}
+			);
+			assert_eq!(Homa::to_bond_pool(), 0);
+			assert_eq!(Homa::get_total_bonded(), 5_000_000);
+			// 1_200_000 * 2.5 * 80%
+			assert_eq!(Homa::get_nomination_pool_bonded(), 2_400_000);
+			assert_eq!(Homa::get_total_bonded_with_pool(), 7_400_000);
+
+			// the rewards of the pool are bonded
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				Some(Rate::saturating_from_rational(10, 100)),
+				None,
+				None,
+				None,
+			));
+			assert_ok!(Homa::process_staking_rewards(1, 0));
+			assert_eq!(Homa::nomination_pool_ledger().points, 1_320_000);
+			assert_eq!(Homa::get_total_bonded(), 5_500_000);
+			assert_eq!(Homa::get_nomination_pool_bonded(), 2_640_000);
+			assert_eq!(Homa::get_total_bonded_with_pool(), 8_140_000);
+
+			// the pool unbond in proportion to its share of the total bonded
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				Some(Rate::zero()),
+				None,
+				None,
+				None,
+			));
+			assert_ok!(Homa::reset_ledgers(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				vec![(0, Some(2_360_000), None)]
+			));
+			assert_eq!(Homa::get_total_bonded_with_pool(), 5_000_000);
+			assert_eq!(
+				Homa::current_exchange_rate(),
+				ExchangeRate::saturating_from_rational(1, 10)
+			);
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(BOB), 10_000_000, false));
+			assert_eq!(Homa::process_redeem_requests(1), Ok(1));
+			// 1_000_000 * 2_640_000 / 5_000_000
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaPoolUnbond {
+				pool_id: 7,
+				amount: 528_000,
+				points: 211_200,
+			}));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaUnbond {
+				sub_account_index: 0,
+				amount: 472_000,
+			}));
+			assert_eq!(
+				Homa::nomination_pool_ledger(),
+				NominationPoolLedger {
+					points: 1_108_800,
+					point_to_balance: ExchangeRate::saturating_from_rational(25, 10),
+					unlocking: vec![UnlockChunk {
+						value: 528_000,
+						era: 1 + BondingDuration::get()
+					}],
+				}
+			);
+			assert_eq!(Homa::unbondings(&BOB, 1 + BondingDuration::get()), 1_000_000);
+
+			// withdraw the expired unlocking of the pool
+			assert_eq!(Homa::unclaimed_redemption(), 0);
+			assert_ok!(Homa::process_scheduled_unbond(1 + BondingDuration::get()));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaPoolWithdrawUnbonded {
+				amount: 528_000,
+			}));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::HomaWithdrawUnbonded {
+				sub_account_index: 0,
+				amount: 472_000,
+			}));
+			assert_eq!(Homa::nomination_pool_ledger().unlocking, vec![]);
+			assert_eq!(Homa::unclaimed_redemption(), 1_000_000);
+		});
+}
+
+fn executed_operations() -> Vec<PendingOperationView> {
+	fn operation(
+		operations: &mut BTreeMap<u16, PendingOperationView>,
+		sub_account_index: u16,
+	) -> &mut PendingOperationView {
+		operations
+			.entry(sub_account_index)
+			.or_insert_with(|| PendingOperationView {
+				sub_account_index,
+				..Default::default()
+			})
+	}
+	let xcm_transfer_fee = MockHomaSubAccountXcm::get_xcm_transfer_fee();
+	let pool_sub_account_index = NominationPoolSubAccountIndex::get();
+	let mut operations: BTreeMap<u16, PendingOperationView> = BTreeMap::new();
+	for record in System::events() {
+		match record.event {
+			RuntimeEvent::Homa(crate::Event::HomaWithdrawUnbonded {
+				sub_account_index,
+				amount,
+			}) => operation(&mut operations, sub_account_index).withdraw = amount,
+			RuntimeEvent::Homa(crate::Event::HomaPoolWithdrawUnbonded { amount }) => {
+				operation(&mut operations, pool_sub_account_index).withdraw = amount
+			}
+			RuntimeEvent::Homa(crate::Event::HomaBondExtra {
+				sub_account_index,
+				amount,
+			}) => {
+				let sub_account_operation = operation(&mut operations, sub_account_index);
+				sub_account_operation.transfer = amount + xcm_transfer_fee;
+				sub_account_operation.bond = amount;
+			}
+			RuntimeEvent::Homa(crate::Event::HomaPoolBondExtra { amount, .. }) => {
+				let pool_operation = operation(&mut operations, pool_sub_account_index);
+				pool_operation.transfer = amount + xcm_transfer_fee;
+				pool_operation.bond = amount;
+			}
+			RuntimeEvent::Homa(crate::Event::HomaUnbond {
+				sub_account_index,
+				amount,
+			}) => operation(&mut operations, sub_account_index).unbond = amount,
+			RuntimeEvent::Homa(crate::Event::HomaPoolUnbond { amount, .. }) => {
+				operation(&mut operations, pool_sub_account_index).unbond = amount
+			}
+			_ => {}
+		}
+	}
+	operations.into_values().collect()
+}
+
+#[test]
+fn get_pending_operations_matches_era_bump() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, STAKING_CURRENCY_ID, 100_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(20_000_000),
+				Some(Rate::saturating_from_rational(1, 100)),
+				Some(Rate::saturating_from_rational(20, 100)),
+				None,
+				None,
+			));
+			assert_ok!(Homa::update_nomination_pool_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(Some(7)),
+				Some(Ratio::saturating_from_rational(40, 100)),
+				Some(Ratio::saturating_from_rational(20, 100)),
+			));
+			assert_ok!(Homa::reset_nomination_pool_ledger(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				Some(ExchangeRate::saturating_from_rational(5, 2)),
+				None,
+			));
+			MintThreshold::set(2_000_000);
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(ALICE), 30_000_000));
+
+			// era #1: bond to the nomination pool and the sub accounts.
+			MockRelayBlockNumberProvider::set(100);
+			let planned = Homa::get_pending_operations().unwrap();
+			assert_eq!(
+				planned,
+				vec![
+					PendingOperationView {
+						sub_account_index: 0,
+						transfer: 18_000_000,
+						bond: 17_000_000,
+						..Default::default()
+					},
+					PendingOperationView {
+						sub_account_index: 10,
+						transfer: 12_000_000,
+						bond: 11_000_000,
+						..Default::default()
+					},
+				]
+			);
+			System::reset_events();
+			assert_ok!(Homa::bump_current_era(1));
+			assert_eq!(executed_operations(), planned);
+
+			// era #2: staking rewards, withdraw, bond and unbond for redeem requests.
+			assert_ok!(Homa::reset_ledgers(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				vec![(
+					1,
+					Some(5_000_000),
+					Some(vec![UnlockChunk {
+						value: 2_000_000,
+						era: 2
+					}])
+				)]
+			));
+			assert_ok!(Homa::reset_nomination_pool_ledger(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				None,
+				Some(vec![UnlockChunk {
+					value: 1_000_000,
+					era: 2
+				}]),
+			));
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(ALICE), 10_000_000));
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(ALICE), 50_000_000, false));
+
+			MockRelayBlockNumberProvider::set(200);
+			let planned = Homa::get_pending_operations().unwrap();
+			assert!(planned.iter().any(|operation| !operation.withdraw.is_zero()));
+			assert!(planned.iter().any(|operation| !operation.bond.is_zero()));
+			assert!(planned.iter().any(|operation| !operation.unbond.is_zero()));
+			assert_eq!(
+				planned
+					.iter()
+					.find(|operation| operation.sub_account_index == 1)
+					.map(|operation| operation.withdraw),
+				Some(2_000_000)
+			);
+			System::reset_events();
+			assert_eq!(Homa::bump_current_era(1), Ok(1));
+			assert_eq!(executed_operations(), planned);
+
+			// nothing pending after the era bump.
+			assert_eq!(Homa::get_pending_operations(), Ok(vec![]));
+		});
+}
+
+#[test]
+fn get_staking_ledgers_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Homa::get_staking_ledgers(), vec![]);
+
+		assert_ok!(Homa::reset_ledgers(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			vec![
+				(
+					2,
+					Some(3_000_000),
+					Some(vec![
+						UnlockChunk {
+							value: 1_000_000,
+							era: 0
+						},
+						UnlockChunk { value: 500_000, era: 3 },
+					])
+				),
+				(0, Some(1_000_000), None),
+			]
+		));
+		assert_ok!(Homa::reset_nomination_pool_ledger(
+			RuntimeOrigin::signed(HomaAdmin::get()),
+			Some(4_000_000),
+			Some(ExchangeRate::saturating_from_rational(1, 2)),
+			Some(vec![UnlockChunk { value: 200_000, era: 1 }]),
+		));
+
+		assert_eq!(
+			Homa::get_staking_ledgers(),
+			vec![
+				(
+					0,
+					StakingLedgerView {
+						bonded: 1_000_000,
+						unlocking: vec![],
+						withdrawable: 0,
+						is_nomination_pool: false,
+					}
+				),
+				(
+					2,
+					StakingLedgerView {
+						bonded: 3_000_000,
+						unlocking: vec![(0, 1_000_000), (3, 500_000)],
+						withdrawable: 1_000_000,
+						is_nomination_pool: false,
+					}
+				),
+				(
+					10,
+					StakingLedgerView {
+						bonded: 2_000_000,
+						unlocking: vec![(1, 200_000)],
+						withdrawable: 0,
+						is_nomination_pool: true,
+					}
+				),
+			]
+		);
+	});
+}
+
+#[test]
+fn last_era_bump_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Homa::last_era_bump(), None);
+
+		Timestamp::set_timestamp(12_000);
+		MockRelayBlockNumberProvider::set(100);
+		assert_ok!(Homa::bump_current_era(1));
+		assert_eq!(
+			Homa::last_era_bump(),
+			Some(EraBumpInfo {
+				new_era: 1,
+				previous_era: 0,
+				block_number: System::block_number(),
+				relaychain_block_number: 100,
+				timestamp: 12_000,
+				outcome: EraBumpOutcome::Succeeded {
+					processed_redeem_requests: 0
+				},
+			})
+		);
+
+		// the staking currency to bond has not been minted to the homa account.
+		MintThreshold::set(0);
+		ToBondPool::<Runtime>::put(1_000_000);
+		Timestamp::set_timestamp(24_000);
+		MockRelayBlockNumberProvider::set(200);
+		assert_eq!(
+			Homa::bump_current_era(1),
+			Err(orml_tokens::Error::<Runtime>::BalanceTooLow.into())
+		);
+		assert_eq!(
+			Homa::last_era_bump(),
+			Some(EraBumpInfo {
+				new_era: 2,
+				previous_era: 1,
+				block_number: System::block_number(),
+				relaychain_block_number: 200,
+				timestamp: 24_000,
+				outcome: EraBumpOutcome::Failed {
+					step: EraBumpStep::ToBondPool,
+					error: orml_tokens::Error::<Runtime>::BalanceTooLow.into(),
+				},
+			})
+		);
+	});
+}
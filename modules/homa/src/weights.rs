@@ -50,13 +50,17 @@ pub trait WeightInfo {
 	fn on_initialize() -> Weight;
 	fn on_initialize_with_bump_era(n: u32,) -> Weight;
 	fn mint() -> Weight;
+	fn mint_no_op() -> Weight;
 	fn request_redeem() -> Weight;
+	fn request_redeem_with_priority() -> Weight;
 	fn fast_match_redeems(n: u32, ) -> Weight;
 	fn claim_redemption() -> Weight;
 	fn update_homa_params() -> Weight;
 	fn update_bump_era_params() -> Weight;
 	fn reset_ledgers(n: u32, ) -> Weight;
 	fn reset_current_era() -> Weight;
+	fn update_nomination_pool_params() -> Weight;
+	fn reset_nomination_pool_ledger() -> Weight;
 }
 
 /// Weights for module_homa using the Acala node and recommended hardware.
@@ -92,11 +96,15 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Storage: Homa RedeemRequests (r:2 w:1)
 	// Storage: Homa Unbondings (r:1 w:1)
 	// Storage: Homa TotalVoidLiquid (r:0 w:1)
+	// Storage: Homa NominationPoolId (r:1 w:0)
+	// Storage: Homa NominationPoolAllocationRatio (r:1 w:0)
+	// Storage: Homa NominationPoolPointsHaircut (r:1 w:0)
+	// Storage: Homa NominationPoolLedgers (r:1 w:1)
 	fn on_initialize_with_bump_era(n: u32,) -> Weight {
 		Weight::from_parts(253_506_000, 0)
-			.saturating_add(T::DbWeight::get().reads(31 as u64))
+			.saturating_add(T::DbWeight::get().reads(36 as u64))
 			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
-			.saturating_add(T::DbWeight::get().writes(18 as u64))
+			.saturating_add(T::DbWeight::get().writes(20 as u64))
 			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -113,14 +121,36 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
+	fn mint_no_op() -> Weight {
+		Weight::from_parts(8_019_000, 0)
+	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: Homa RedeemRequests (r:1 w:1)
+	// Storage: Homa RedeemRequestPriorities (r:1 w:1)
+	// Storage: Homa RedeemRequestsByTip (r:1 w:1)
+	// Storage: Homa RedeemRequestTipBuckets (r:1 w:1)
+	// Storage: Homa RedeemRequestExpiries (r:1 w:1)
+	// Storage: Homa RedeemRequestExpiryEras (r:1 w:1)
 	// Storage: Tokens Accounts (r:2 w:2)
 	// Storage: System Account (r:1 w:1)
 	fn request_redeem() -> Weight {
 		Weight::from_parts(53_493_000, 0)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Homa RedeemRequests (r:1 w:1)
+	// Storage: Homa RedeemRequestPriorities (r:1 w:1)
+	// Storage: Homa RedeemRequestsByTip (r:2 w:2)
+	// Storage: Homa RedeemRequestTipBuckets (r:1 w:1)
+	// Storage: Homa RedeemRequestExpiries (r:2 w:2)
+	// Storage: Homa RedeemRequestExpiryEras (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn request_redeem_with_priority() -> Weight {
+		Weight::from_parts(71_826_000, 0)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: Homa RedeemRequests (r:1 w:1)
@@ -189,6 +219,21 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// Storage: Homa NominationPoolId (r:1 w:1)
+	// Storage: Homa NominationPoolLedgers (r:1 w:0)
+	// Storage: Homa NominationPoolAllocationRatio (r:0 w:1)
+	// Storage: Homa NominationPoolPointsHaircut (r:0 w:1)
+	fn update_nomination_pool_params() -> Weight {
+		Weight::from_parts(18_462_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Homa NominationPoolLedgers (r:1 w:1)
+	fn reset_nomination_pool_ledger() -> Weight {
+		Weight::from_parts(13_587_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -199,9 +244,9 @@ impl WeightInfo for () {
 	}
 	fn on_initialize_with_bump_era(n: u32,) -> Weight {
 		Weight::from_parts(253_506_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(31 as u64))
+			.saturating_add(RocksDbWeight::get().reads(36 as u64))
 			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
-			.saturating_add(RocksDbWeight::get().writes(18 as u64))
+			.saturating_add(RocksDbWeight::get().writes(20 as u64))
 			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
 	}
 	fn mint() -> Weight {
@@ -209,10 +254,18 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11 as u64))
 			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
+	fn mint_no_op() -> Weight {
+		Weight::from_parts(8_019_000, 0)
+	}
 	fn request_redeem() -> Weight {
 		Weight::from_parts(53_493_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	fn request_redeem_with_priority() -> Weight {
+		Weight::from_parts(71_826_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
 	}
 	fn fast_match_redeems(n: u32, ) -> Weight {
 		Weight::from_parts(7_082_000, 0)
@@ -252,4 +305,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
+	fn update_nomination_pool_params() -> Weight {
+		Weight::from_parts(18_462_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn reset_nomination_pool_ledger() -> Weight {
+		Weight::from_parts(13_587_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
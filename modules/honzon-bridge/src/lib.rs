@@ -88,7 +88,10 @@ pub mod module {
 		FromBridged { who: T::AccountId, amount: Balance },
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -122,6 +122,9 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type AccountCurrencies = ();
+	type Swap = module_support::SpecificJointsSwap<(), ()>;
+	type MaxTransferAllCurrencies = ConstU32<0>;
 }
 
 parameter_types! {
@@ -158,6 +161,9 @@ impl module_evm::Config for Runtime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ();
 	type IdleScheduler = ();
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
@@ -171,6 +177,7 @@ impl module_evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type MaxNonceLanes = ConstU32<8>;
 	type WeightInfo = ();
 }
 
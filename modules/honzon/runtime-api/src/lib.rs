@@ -0,0 +1,118 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use module_support::{ExchangeRate, Price, Rate, Ratio};
+pub use module_support::{
+	LiquidationKind, LiquidationRecord, LiquidationRoute, LiquidationStats, RiskSnapshot, RISK_PRICE_SHOCKS,
+};
+use parity_scale_codec::{Decode, Encode};
+use primitives::{Balance, CurrencyId};
+use scale_info::TypeInfo;
+use sp_runtime::{codec::Codec, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The last position returned by a snapshot page, the next page starts after it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SnapshotCursor<AccountId> {
+	pub currency_id: CurrencyId,
+	pub who: AccountId,
+	pub position_index: u32,
+}
+
+/// The risk parameters and the totals of a collateral type.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CollateralSnapshot {
+	pub currency_id: CurrencyId,
+	pub maximum_total_debit_value: Balance,
+	/// The hard cap lowered by the liquidity-sensitive debit ceiling, which limits new debit.
+	pub effective_maximum_total_debit_value: Balance,
+	pub interest_rate_per_sec: Rate,
+	pub liquidation_ratio: Ratio,
+	pub liquidation_penalty: Rate,
+	pub required_collateral_ratio: Option<Ratio>,
+	pub debit_exchange_rate: ExchangeRate,
+	pub total_collateral: Balance,
+	pub total_debit: Balance,
+	pub collateral_in_auction: Balance,
+	/// The oracle price of the collateral, `None` if not available.
+	pub price: Option<Price>,
+}
+
+/// The system-wide state of Honzon.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HonzonGlobals {
+	pub surplus_pool: Balance,
+	pub debit_pool: Balance,
+	pub total_target_in_auction: Balance,
+	pub collaterals: Vec<CollateralSnapshot>,
+}
+
+/// The collateral and debit of a position.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PositionSnapshot<AccountId> {
+	pub currency_id: CurrencyId,
+	pub who: AccountId,
+	/// The index of the position among the positions of `who` under `currency_id`, 0 for the
+	/// primary position.
+	pub position_index: u32,
+	pub collateral: Balance,
+	pub debit: Balance,
+}
+
+/// A page of the Honzon snapshot. The globals are only bundled in the first page.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HonzonSnapshotPage<AccountId, BlockNumber> {
+	/// The block of the snapshot, all pages should be queried at the same block.
+	pub block_number: BlockNumber,
+	pub globals: Option<HonzonGlobals>,
+	pub positions: Vec<PositionSnapshot<AccountId>>,
+	/// The cursor to query the next page, `None` if all positions are returned.
+	pub next_cursor: Option<SnapshotCursor<AccountId>>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait HonzonSnapshotApi<AccountId, BlockNumber> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		fn get_honzon_snapshot(
+			cursor: Option<SnapshotCursor<AccountId>>,
+			limit: u32,
+		) -> HonzonSnapshotPage<AccountId, BlockNumber>;
+	}
+
+	pub trait LiquidationHistoryApi<AccountId, BlockNumber> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The aggregate statistics and the latest liquidation records of the account, the oldest record first.
+		fn get_liquidation_history(
+			account: AccountId,
+		) -> (LiquidationStats, Vec<LiquidationRecord<CurrencyId, BlockNumber>>);
+	}
+
+	pub trait RiskSnapshotApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		/// The latest risk snapshot of the collateral type, `None` if not taken yet.
+		fn get_risk_snapshot(currency_id: CurrencyId) -> Option<RiskSnapshot<BlockNumber>>;
+	}
+}
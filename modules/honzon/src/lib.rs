@@ -24,19 +24,40 @@
 //! position to loan/payback, and can also authorize others to manage the their
 //! CDP under specific collateral type.
 //!
+//! A user can also register a top-up voucher to protect the CDP while offline, anyone
+//! can execute it to move the collateral from the owner's free balance into the CDP
+//! when its collateral ratio falls below the trigger, for a bounty paid by the owner.
+//!
+//! A user can also set liquidation price alerts on the CDP. The offchain worker of the CDP
+//! engine emits `PositionAlertTriggered` once the collateral ratio falls below the trigger
+//! ratio of an alert, which is then disarmed until set again, so the indexers and
+//! notification services don't need to poll every position.
+//!
 //! After system shutdown, some operations will be restricted.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, traits::NamedReservableCurrency};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement, NamedReservableCurrency},
+};
 use frame_system::pallet_prelude::*;
-use module_support::{CDPTreasury, EmergencyShutdown, ExchangeRate, HonzonManager, PriceProvider, Ratio};
+use module_cdp_engine::PositionAlertHandler;
+use module_honzon_runtime_api::{
+	CollateralSnapshot, HonzonGlobals, HonzonSnapshotPage, PositionSnapshot, SnapshotCursor,
+};
+use module_loans::PositionIndex;
+use module_support::{
+	AuctionManager, CDPTreasury, EmergencyShutdown, ExchangeRate, GuardedOperation, HonzonManager, IssuanceOrigin,
+	OperationGuard, PriceProvider, Ratio,
+};
+use orml_traits::MultiCurrency;
 use primitives::{Amount, Balance, CurrencyId, Position, ReserveIdentifier};
 use sp_core::U256;
 use sp_runtime::{
-	traits::{StaticLookup, Zero},
-	ArithmeticError, DispatchResult,
+	traits::{Saturating, StaticLookup, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -47,6 +68,29 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The maximum number of positions in a page of the Honzon snapshot.
+pub const MAX_SNAPSHOT_PAGE_SIZE: u32 = 1000;
+
+/// The standing authorization to top up the collateral of a CDP.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct TopUpVoucher<BlockNumber> {
+	/// The remaining collateral amount the voucher can still move into the CDP.
+	pub remaining: Balance,
+	/// The voucher is executable when the collateral ratio is below it.
+	pub trigger_ratio: Ratio,
+	/// The last block the voucher is executable at.
+	pub deadline: BlockNumber,
+}
+
+/// The liquidation price alert of a CDP.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PositionAlert {
+	/// The alert is triggered when the collateral ratio is below it.
+	pub trigger_ratio: Ratio,
+	/// Whether the alert can be triggered, it's disarmed once triggered until set again.
+	pub armed: bool,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -71,6 +115,25 @@ pub mod module {
 		/// The list of valid collateral currency types
 		type CollateralCurrencyIds: Get<Vec<CurrencyId>>;
 
+		/// Guard for the high-value operations of protected accounts.
+		type OperationGuard: OperationGuard<Self::AccountId>;
+
+		/// The auction manager, queried for the Honzon snapshot.
+		type AuctionManagerHandler: AuctionManager<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The bounty paid by the owner to the executor of a top-up voucher.
+		#[pallet::constant]
+		type TopUpBounty: Get<Balance>;
+
+		/// The top-up restores the collateral ratio to the trigger ratio of the
+		/// voucher plus this buffer.
+		#[pallet::constant]
+		type TopUpRatioBuffer: Get<Ratio>;
+
+		/// The maximum number of the liquidation price alerts of a CDP.
+		#[pallet::constant]
+		type MaxPositionAlerts: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -85,6 +148,28 @@ pub mod module {
 		AuthorizationNotExists,
 		// Have authorized already
 		AlreadyAuthorized,
+		// Invalid top-up voucher params
+		InvalidTopUpVoucher,
+		// Top-up voucher not exists
+		TopUpVoucherNotExists,
+		// The top-up voucher has expired
+		TopUpVoucherExpired,
+		// The top-up voucher has not expired yet
+		TopUpVoucherNotExpired,
+		// The collateral ratio is not below the trigger ratio of the top-up voucher
+		TopUpNotTriggered,
+		// No collateral can be topped up
+		NothingToTopUp,
+		// Invalid position alert params
+		InvalidPositionAlert,
+		// Exceeds `MaxPositionAlerts`
+		TooManyPositionAlerts,
+		// Position alert not exists
+		PositionAlertNotExists,
+		// The currency is not a valid collateral currency
+		InvalidCollateralType,
+		// Shift the loan to its own collateral type
+		ShiftToSameCollateral,
 	}
 
 	#[pallet::event]
@@ -110,6 +195,66 @@ pub mod module {
 			to_currency: CurrencyId,
 			amount: Balance,
 		},
+		/// Adjust loan with zero collateral and debit adjustments, nothing changed.
+		NoOp { who: T::AccountId, currency_id: CurrencyId },
+		/// Register a top-up voucher for the CDP of specific collateral.
+		TopUpVoucherRegistered {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			max_amount: Balance,
+			trigger_ratio: Ratio,
+			deadline: BlockNumberFor<T>,
+		},
+		/// The top-up voucher is executed, the voucher is consumed if nothing remains.
+		TopUpVoucherExecuted {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			executor: T::AccountId,
+			amount: Balance,
+			bounty: Balance,
+			remaining: Balance,
+		},
+		/// The top-up voucher is cancelled by the owner.
+		TopUpVoucherCancelled {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+		},
+		/// The expired top-up voucher is removed.
+		TopUpVoucherExpired {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+		},
+		/// Set or re-arm a liquidation price alert for the CDP of specific collateral.
+		PositionAlertSet {
+			owner: T::AccountId,
+			currency_id: CurrencyId,
+			trigger_ratio: Ratio,
+		},
+		/// The liquidation price alert is cleared by the owner.
+		PositionAlertCleared {
+			owner: T::AccountId,
+			currency_id: CurrencyId,
+			trigger_ratio: Ratio,
+		},
+		/// The collateral ratio of the CDP fell below the trigger ratio of the alert, the
+		/// alert is disarmed until set again.
+		PositionAlertTriggered {
+			owner: T::AccountId,
+			currency_id: CurrencyId,
+			ratio: Ratio,
+			trigger_ratio: Ratio,
+		},
+		/// The loan is shifted to another collateral type, the collateral is swapped by DEX
+		/// and the debit value is moved along with it.
+		LoanCollateralShifted {
+			owner: T::AccountId,
+			from_currency_id: CurrencyId,
+			to_currency_id: CurrencyId,
+			position_index: PositionIndex,
+			collateral_amount: Balance,
+			target_amount: Balance,
+			debit_value: Balance,
+		},
 	}
 
 	/// The authorization relationship map from
@@ -128,7 +273,40 @@ pub mod module {
 		OptionQuery,
 	>;
 
+	/// The top-up vouchers of the CDPs.
+	///
+	/// TopUpVouchers: double_map AccountId, CurrencyId => Option<TopUpVoucher>
+	#[pallet::storage]
+	#[pallet::getter(fn topup_vouchers)]
+	pub type TopUpVouchers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		CurrencyId,
+		TopUpVoucher<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The liquidation price alerts of the CDPs.
+	///
+	/// PositionAlerts: double_map AccountId, CurrencyId => BoundedVec<PositionAlert, MaxPositionAlerts>
+	#[pallet::storage]
+	#[pallet::getter(fn position_alerts)]
+	pub type PositionAlerts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		CurrencyId,
+		BoundedVec<PositionAlert, T::MaxPositionAlerts>,
+		ValueQuery,
+	>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -152,9 +330,15 @@ pub mod module {
 			currency_id: CurrencyId,
 			collateral_adjustment: Amount,
 			debit_adjustment: Amount,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::do_adjust_loan(&who, currency_id, collateral_adjustment, debit_adjustment)
+			if collateral_adjustment.is_zero() && debit_adjustment.is_zero() {
+				Self::deposit_event(Event::NoOp { who, currency_id });
+				return Ok(Some(T::WeightInfo::adjust_loan_no_op()).into());
+			}
+
+			Self::do_adjust_loan(&who, currency_id, collateral_adjustment, debit_adjustment)?;
+			Ok(().into())
 		}
 
 		/// Close caller's CDP which has debit but still in safe by use collateral to swap
@@ -191,6 +375,7 @@ pub mod module {
 			let from = T::Lookup::lookup(from)?;
 			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
 			Self::check_authorization(&from, &to, currency_id)?;
+			T::OperationGuard::check_operation(&from, GuardedOperation::CloseLoan { currency_id })?;
 			<module_loans::Pallet<T>>::transfer_loan(&from, &to, currency_id)?;
 			Ok(())
 		}
@@ -300,6 +485,13 @@ pub mod module {
 			min_decrease_debit_value: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			T::OperationGuard::check_operation(
+				&who,
+				GuardedOperation::WithdrawCollateral {
+					currency_id,
+					amount: decrease_collateral,
+				},
+			)?;
 			<module_cdp_engine::Pallet<T>>::shrink_position_debit(
 				&who,
 				currency_id,
@@ -331,6 +523,7 @@ pub mod module {
 			if !debit_value_adjustment.is_zero() {
 				ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
 			}
+			Self::ensure_withdraw_allowed(&who, currency_id, collateral_adjustment)?;
 			<module_cdp_engine::Pallet<T>>::adjust_position_by_debit_value(
 				&who,
 				currency_id,
@@ -357,12 +550,17 @@ pub mod module {
 			let debit_amount: Amount = debit_transfer.try_into().map_err(|_| ArithmeticError::Overflow)?;
 			let negative_debit = debit_amount.checked_neg().ok_or(ArithmeticError::Overflow)?;
 			// Adds ausd to user account momentarily to adjust loan
-			<T as module_cdp_engine::Config>::CDPTreasury::issue_debit(&who, debit_transfer, true)?;
+			<T as module_cdp_engine::Config>::CDPTreasury::issue_debit(
+				&who,
+				debit_transfer,
+				true,
+				IssuanceOrigin::System,
+			)?;
 
 			<module_cdp_engine::Pallet<T>>::adjust_position(&who, from_currency, Zero::zero(), negative_debit)?;
 			<module_cdp_engine::Pallet<T>>::adjust_position(&who, to_currency, Zero::zero(), debit_amount)?;
 			// Removes debit issued for debit transfer
-			<T as module_cdp_engine::Config>::CDPTreasury::burn_debit(&who, debit_transfer)?;
+			<T as module_cdp_engine::Config>::CDPTreasury::burn_debit(&who, debit_transfer, IssuanceOrigin::System)?;
 
 			Self::deposit_event(Event::TransferDebit {
 				from_currency,
@@ -371,6 +569,344 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Register a top-up voucher for caller's CDP under `currency_id`, replaces the
+		/// existing one. Until the deadline, anyone can execute it to move the collateral
+		/// from caller's free balance into the CDP when its collateral ratio is below the
+		/// trigger ratio.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `max_amount`: the max collateral amount the voucher can move in total.
+		/// - `trigger_ratio`: the voucher is executable below this collateral ratio.
+		/// - `deadline`: the last block the voucher is executable at.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_topup_voucher())]
+		pub fn register_topup_voucher(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			max_amount: Balance,
+			trigger_ratio: Ratio,
+			deadline: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id)
+					&& !max_amount.is_zero()
+					&& !trigger_ratio.is_zero()
+					&& deadline > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidTopUpVoucher
+			);
+
+			TopUpVouchers::<T>::insert(
+				&owner,
+				currency_id,
+				TopUpVoucher {
+					remaining: max_amount,
+					trigger_ratio,
+					deadline,
+				},
+			);
+			Self::deposit_event(Event::TopUpVoucherRegistered {
+				owner,
+				collateral_type: currency_id,
+				max_amount,
+				trigger_ratio,
+				deadline,
+			});
+			Ok(())
+		}
+
+		/// Cancel the top-up voucher of caller's CDP under `currency_id`.
+		///
+		/// - `currency_id`: collateral currency id.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_topup_voucher())]
+		pub fn cancel_topup_voucher(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			TopUpVouchers::<T>::take(&owner, currency_id).ok_or(Error::<T>::TopUpVoucherNotExists)?;
+			Self::deposit_event(Event::TopUpVoucherCancelled {
+				owner,
+				collateral_type: currency_id,
+			});
+			Ok(())
+		}
+
+		/// Execute the top-up voucher of `owner`'s CDP under `currency_id`. The collateral
+		/// ratio is checked with the same price source as liquidation, the collateral needed
+		/// to restore the trigger ratio plus `TopUpRatioBuffer` is moved from `owner`'s free
+		/// balance, bounded by the remaining amount of the voucher. The caller is paid
+		/// `TopUpBounty` by `owner`.
+		///
+		/// - `owner`: the owner of the CDP.
+		/// - `currency_id`: collateral currency id.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_topup_voucher())]
+		pub fn execute_topup_voucher(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			let executor = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+
+			TopUpVouchers::<T>::try_mutate_exists(&owner, currency_id, |maybe_voucher| -> DispatchResult {
+				let voucher = maybe_voucher.as_mut().ok_or(Error::<T>::TopUpVoucherNotExists)?;
+				ensure!(
+					frame_system::Pallet::<T>::block_number() <= voucher.deadline,
+					Error::<T>::TopUpVoucherExpired
+				);
+
+				let amount = Self::topup_amount(&owner, currency_id, voucher)?;
+				let collateral_adjustment: Amount = amount.try_into().map_err(|_| ArithmeticError::Overflow)?;
+				<module_cdp_engine::Pallet<T>>::adjust_position(
+					&owner,
+					currency_id,
+					collateral_adjustment,
+					Zero::zero(),
+				)?;
+
+				let bounty = if executor == owner {
+					Zero::zero()
+				} else {
+					T::TopUpBounty::get()
+				};
+				<T as Config>::Currency::transfer(&owner, &executor, bounty, ExistenceRequirement::KeepAlive)?;
+
+				voucher.remaining = voucher.remaining.saturating_sub(amount);
+				let remaining = voucher.remaining;
+				if remaining.is_zero() {
+					*maybe_voucher = None;
+				}
+
+				Self::deposit_event(Event::TopUpVoucherExecuted {
+					owner: owner.clone(),
+					collateral_type: currency_id,
+					executor,
+					amount,
+					bounty,
+					remaining,
+				});
+				Ok(())
+			})
+		}
+
+		/// Remove the expired top-up voucher of `owner`'s CDP under `currency_id`.
+		///
+		/// - `owner`: the owner of the CDP.
+		/// - `currency_id`: collateral currency id.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_expired_topup_voucher())]
+		pub fn remove_expired_topup_voucher(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			TopUpVouchers::<T>::try_mutate_exists(&owner, currency_id, |maybe_voucher| -> DispatchResult {
+				let voucher = maybe_voucher.take().ok_or(Error::<T>::TopUpVoucherNotExists)?;
+				ensure!(
+					frame_system::Pallet::<T>::block_number() > voucher.deadline,
+					Error::<T>::TopUpVoucherNotExpired
+				);
+				Ok(())
+			})?;
+			Self::deposit_event(Event::TopUpVoucherExpired {
+				owner,
+				collateral_type: currency_id,
+			});
+			Ok(())
+		}
+
+		/// Set a liquidation price alert for caller's CDP under `currency_id`, or re-arm the
+		/// triggered alert of the same trigger ratio. The alert is triggered by the offchain
+		/// worker once the collateral ratio is below the trigger ratio, and is disarmed then.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `trigger_ratio`: the alert is triggered below this collateral ratio.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_position_alert())]
+		pub fn set_position_alert(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			trigger_ratio: Ratio,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id) && !trigger_ratio.is_zero(),
+				Error::<T>::InvalidPositionAlert
+			);
+
+			PositionAlerts::<T>::try_mutate(&owner, currency_id, |alerts| -> DispatchResult {
+				match alerts.iter_mut().find(|alert| alert.trigger_ratio == trigger_ratio) {
+					Some(alert) => alert.armed = true,
+					None => alerts
+						.try_push(PositionAlert {
+							trigger_ratio,
+							armed: true,
+						})
+						.map_err(|_| Error::<T>::TooManyPositionAlerts)?,
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::PositionAlertSet {
+				owner,
+				currency_id,
+				trigger_ratio,
+			});
+			Ok(())
+		}
+
+		/// Clear the liquidation price alert of caller's CDP under `currency_id`.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `trigger_ratio`: the trigger ratio of the alert.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_position_alert())]
+		pub fn clear_position_alert(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			trigger_ratio: Ratio,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			PositionAlerts::<T>::try_mutate_exists(&owner, currency_id, |maybe_alerts| -> DispatchResult {
+				let alerts = maybe_alerts.as_mut().ok_or(Error::<T>::PositionAlertNotExists)?;
+				let index = alerts
+					.iter()
+					.position(|alert| alert.trigger_ratio == trigger_ratio)
+					.ok_or(Error::<T>::PositionAlertNotExists)?;
+				alerts.remove(index);
+				if alerts.is_empty() {
+					*maybe_alerts = None;
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::PositionAlertCleared {
+				owner,
+				currency_id,
+				trigger_ratio,
+			});
+			Ok(())
+		}
+
+		/// Split caller's CDP under `currency_id` into `parts`, the first part remains in the
+		/// CDP and the others become the sub-positions. The parts must sum to the CDP exactly,
+		/// and each of them must be a valid CDP above the required collateral ratio.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `parts`: the collateral and debit amounts of the parts.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_loan(parts.len() as u32))]
+		pub fn split_loan(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			parts: BoundedVec<(Balance, Balance), <T as module_loans::Config>::MaxPositionsPerAccount>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			<module_loans::Pallet<T>>::split_position(&who, currency_id, parts.into_inner())?;
+			Ok(())
+		}
+
+		/// Merge all the sub-positions of caller's CDP under `currency_id` back into the CDP.
+		/// It's allowed after system shutdown to withdraw the collateral of the settled
+		/// sub-positions.
+		///
+		/// - `currency_id`: collateral currency id.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_loans(
+			<T as module_loans::Config>::MaxPositionsPerAccount::get().saturating_sub(1)
+		))]
+		pub fn merge_loans(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let merged = <module_loans::Pallet<T>>::merge_positions(&who, currency_id)?;
+			Ok(Some(T::WeightInfo::merge_loans(merged.len() as u32)).into())
+		}
+
+		/// Shift caller's loan from `from_currency_id` to `to_currency_id` in one transaction:
+		/// swap the collateral to the new collateral type by DEX, in limit of the slippage
+		/// compared to the oracle price, and move all the debit value along with it. The new CDP
+		/// must be above the required collateral ratio of its collateral type.
+		///
+		/// - `from_currency_id`: the collateral currency id of the loan.
+		/// - `to_currency_id`: the collateral currency id the loan is shifted to.
+		/// - `position_index`: the index of the loan, it's shifted to the loan at the same index.
+		/// - `collateral_amount`: the collateral amount to swap to the new collateral type.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::shift_loan_collateral())]
+		pub fn shift_loan_collateral(
+			origin: OriginFor<T>,
+			from_currency_id: CurrencyId,
+			to_currency_id: CurrencyId,
+			position_index: PositionIndex,
+			#[pallet::compact] collateral_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(from_currency_id != to_currency_id, Error::<T>::ShiftToSameCollateral);
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&to_currency_id),
+				Error::<T>::InvalidCollateralType
+			);
+			T::OperationGuard::check_operation(
+				&who,
+				GuardedOperation::WithdrawCollateral {
+					currency_id: from_currency_id,
+					amount: collateral_amount,
+				},
+			)?;
+
+			let (target_amount, debit_value) = <module_cdp_engine::Pallet<T>>::shift_position_collateral(
+				&who,
+				from_currency_id,
+				to_currency_id,
+				position_index,
+				collateral_amount,
+			)?;
+			Self::deposit_event(Event::LoanCollateralShifted {
+				owner: who,
+				from_currency_id,
+				to_currency_id,
+				position_index,
+				collateral_amount,
+				target_amount,
+				debit_value,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> PositionAlertHandler<T::AccountId> for Pallet<T> {
+	fn is_alert_tripped(who: &T::AccountId, currency_id: CurrencyId, collateral_ratio: Ratio) -> bool {
+		Self::position_alerts(who, currency_id)
+			.iter()
+			.any(|alert| alert.armed && collateral_ratio < alert.trigger_ratio)
+	}
+
+	fn trigger_alerts(who: &T::AccountId, currency_id: CurrencyId, collateral_ratio: Ratio) -> u32 {
+		let mut alerts = Self::position_alerts(who, currency_id);
+		let mut count: u32 = 0;
+		for alert in alerts
+			.iter_mut()
+			.filter(|alert| alert.armed && collateral_ratio < alert.trigger_ratio)
+		{
+			alert.armed = false;
+			count += 1;
+			Self::deposit_event(Event::PositionAlertTriggered {
+				owner: who.clone(),
+				currency_id,
+				ratio: collateral_ratio,
+				trigger_ratio: alert.trigger_ratio,
+			});
+		}
+		if !count.is_zero() {
+			PositionAlerts::<T>::insert(who, currency_id, alerts);
+		}
+		count
 	}
 }
 
@@ -384,6 +920,24 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Check the guard of `who` if `collateral_adjustment` withdraws collateral
+	fn ensure_withdraw_allowed(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+	) -> DispatchResult {
+		if collateral_adjustment.is_negative() {
+			T::OperationGuard::check_operation(
+				who,
+				GuardedOperation::WithdrawCollateral {
+					currency_id,
+					amount: collateral_adjustment.unsigned_abs(),
+				},
+			)?;
+		}
+		Ok(())
+	}
+
 	fn do_adjust_loan(
 		who: &T::AccountId,
 		currency_id: CurrencyId,
@@ -394,6 +948,7 @@ impl<T: Config> Pallet<T> {
 		if !debit_adjustment.is_zero() {
 			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
 		}
+		Self::ensure_withdraw_allowed(who, currency_id, collateral_adjustment)?;
 		<module_cdp_engine::Pallet<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)?;
 		Ok(())
 	}
@@ -404,9 +959,140 @@ impl<T: Config> Pallet<T> {
 		max_collateral_amount: Balance,
 	) -> DispatchResult {
 		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		T::OperationGuard::check_operation(&who, GuardedOperation::CloseLoan { currency_id })?;
 		<module_cdp_engine::Pallet<T>>::close_cdp_has_debit_by_dex(who, currency_id, max_collateral_amount)?;
 		Ok(())
 	}
+
+	/// The collateral amount to top up `who`'s CDP under `currency_id` by the voucher.
+	fn topup_amount(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		voucher: &TopUpVoucher<BlockNumberFor<T>>,
+	) -> Result<Balance, DispatchError> {
+		let Position { collateral, debit } = <module_loans::Pallet<T>>::positions(currency_id, who);
+		ensure!(!debit.is_zero(), Error::<T>::TopUpNotTriggered);
+
+		let price = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+			.ok_or(module_cdp_engine::Error::<T>::InvalidFeedPrice)?;
+		let collateral_ratio =
+			<module_cdp_engine::Pallet<T>>::calculate_collateral_ratio(currency_id, collateral, debit, price);
+		ensure!(collateral_ratio < voucher.trigger_ratio, Error::<T>::TopUpNotTriggered);
+
+		let target_ratio = voucher.trigger_ratio.saturating_add(T::TopUpRatioBuffer::get());
+		let debit_value = <module_cdp_engine::Pallet<T>>::convert_to_debit_value(currency_id, debit);
+		let target_collateral = price
+			.reciprocal()
+			.ok_or(module_cdp_engine::Error::<T>::InvalidFeedPrice)?
+			.saturating_mul_int(target_ratio.saturating_mul_int(debit_value));
+		let free_balance = <T as module_cdp_engine::Config>::Currency::free_balance(currency_id, who);
+
+		let amount = target_collateral
+			.saturating_sub(collateral)
+			.min(voucher.remaining)
+			.min(free_balance);
+		ensure!(!amount.is_zero(), Error::<T>::NothingToTopUp);
+		Ok(amount)
+	}
+
+	/// Get a page of the snapshot of the Honzon system.
+	///
+	/// The positions are returned in the storage order of `module_loans::Positions`, a page
+	/// starts right after the `cursor` position even if it has been removed since. The globals are
+	/// bundled in the first page, all pages should be queried at the same block to stitch a
+	/// consistent snapshot.
+	pub fn get_honzon_snapshot(
+		cursor: Option<SnapshotCursor<T::AccountId>>,
+		limit: u32,
+	) -> HonzonSnapshotPage<T::AccountId, BlockNumberFor<T>> {
+		let limit = limit.clamp(1, MAX_SNAPSHOT_PAGE_SIZE) as usize;
+		let (globals, iter) = match cursor {
+			Some(SnapshotCursor {
+				currency_id,
+				who,
+				position_index,
+			}) => (
+				None,
+				<module_loans::Pallet<T>>::iter_positions(Some(module_loans::Positions::<T>::hashed_key_for((
+					currency_id,
+					who,
+					position_index,
+				)))),
+			),
+			None => (
+				Some(Self::honzon_globals()),
+				<module_loans::Pallet<T>>::iter_positions(None),
+			),
+		};
+
+		// take one more to know if there are more positions
+		let mut positions: Vec<PositionSnapshot<T::AccountId>> = iter
+			.take(limit.saturating_add(1))
+			.map(
+				|((currency_id, who, position_index), Position { collateral, debit })| PositionSnapshot {
+					currency_id,
+					who,
+					position_index,
+					collateral,
+					debit,
+				},
+			)
+			.collect();
+		let next_cursor = if positions.len() > limit {
+			positions.truncate(limit);
+			positions.last().map(|position| SnapshotCursor {
+				currency_id: position.currency_id,
+				who: position.who.clone(),
+				position_index: position.position_index,
+			})
+		} else {
+			None
+		};
+
+		HonzonSnapshotPage {
+			block_number: frame_system::Pallet::<T>::block_number(),
+			globals,
+			positions,
+			next_cursor,
+		}
+	}
+
+	fn honzon_globals() -> HonzonGlobals {
+		let collaterals = <module_cdp_engine::Pallet<T>>::get_collateral_currency_ids()
+			.into_iter()
+			.map(|currency_id| {
+				let total = <module_loans::Pallet<T>>::total_positions(currency_id);
+				CollateralSnapshot {
+					currency_id,
+					maximum_total_debit_value: <module_cdp_engine::Pallet<T>>::maximum_total_debit_value(currency_id)
+						.unwrap_or_default(),
+					effective_maximum_total_debit_value:
+						<module_cdp_engine::Pallet<T>>::effective_maximum_total_debit_value(currency_id)
+							.unwrap_or_default(),
+					interest_rate_per_sec: <module_cdp_engine::Pallet<T>>::get_interest_rate_per_sec(currency_id)
+						.unwrap_or_default(),
+					liquidation_ratio: <module_cdp_engine::Pallet<T>>::get_liquidation_ratio(currency_id)
+						.unwrap_or_default(),
+					liquidation_penalty: <module_cdp_engine::Pallet<T>>::get_liquidation_penalty(currency_id)
+						.unwrap_or_default(),
+					required_collateral_ratio: <module_cdp_engine::Pallet<T>>::required_collateral_ratio(currency_id)
+						.unwrap_or_default(),
+					debit_exchange_rate: <module_cdp_engine::Pallet<T>>::get_debit_exchange_rate(currency_id),
+					total_collateral: total.collateral,
+					total_debit: total.debit,
+					collateral_in_auction: T::AuctionManagerHandler::get_total_collateral_in_auction(currency_id),
+					price: <T as module_cdp_engine::Config>::PriceSource::get_price(currency_id),
+				}
+			})
+			.collect();
+
+		HonzonGlobals {
+			surplus_pool: <T as module_cdp_engine::Config>::CDPTreasury::get_surplus_pool(),
+			debit_pool: <T as module_cdp_engine::Config>::CDPTreasury::get_debit_pool(),
+			total_target_in_auction: T::AuctionManagerHandler::get_total_target_in_auction(),
+			collaterals,
+		}
+	}
 }
 
 impl<T: Config> HonzonManager<T::AccountId, CurrencyId, Amount, Balance> for Pallet<T> {
@@ -41,7 +41,8 @@ use sp_core::crypto::AccountId32;
 use sp_runtime::{
 	testing::TestXt,
 	traits::{AccountIdConversion, IdentityLookup, One as OneT},
-	BuildStorage, FixedPointNumber,
+	transaction_validity::TransactionPriority,
+	BuildStorage, DispatchError, FixedPointNumber,
 };
 use sp_std::str::FromStr;
 
@@ -128,12 +129,17 @@ impl module_loans::Config for Runtime {
 	type CDPTreasury = CDPTreasuryModule;
 	type PalletId = LoansPalletId;
 	type OnUpdateLoan = ();
+	type MaxPositionsPerAccount = ConstU32<3>;
+}
+
+parameter_types! {
+	pub static RelativePrice: Price = Price::one();
 }
 
 pub struct MockPriceSource;
 impl PriceProvider<CurrencyId> for MockPriceSource {
 	fn get_relative_price(_base: CurrencyId, _quote: CurrencyId) -> Option<Price> {
-		Some(Price::one())
+		Some(RelativePrice::get())
 	}
 
 	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
@@ -210,6 +216,8 @@ impl module_cdp_treasury::Config for Runtime {
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
+	type PriceSource = MockPriceSource;
+	type MaxBasketAssets = ConstU32<10>;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -225,6 +233,7 @@ impl module_evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type MaxNonceLanes = ConstU32<8>;
 	type WeightInfo = ();
 }
 
@@ -263,6 +272,9 @@ impl module_evm::Config for Runtime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ();
 	type IdleScheduler = ();
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
@@ -276,12 +288,25 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_type_with_key! {
+	pub CdpEngineUnsignedPriority: |_currency_id: CurrencyId| -> TransactionPriority {
+		1 << 20
+	};
+}
+
 parameter_types! {
 	pub DefaultLiquidationRatio: Ratio = Ratio::saturating_from_rational(3, 2);
 	pub DefaultDebitExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub DefaultLiquidationPenalty: FractionalRate = FractionalRate::try_from(Rate::saturating_from_rational(10, 100)).unwrap();
+	pub MinLiquidationRatio: Ratio = Ratio::one();
+	pub MaxLiquidationRatio: Ratio = Ratio::saturating_from_integer(10);
+	pub MaxLiquidationPenalty: Rate = Rate::saturating_from_rational(50, 100);
+	pub MaxInterestRatePerSec: Rate = Rate::saturating_from_rational(1, 1000);
+	pub MaxDebitCeilingIncrease: Ratio = Ratio::saturating_from_integer(2);
+	pub TopUpRatioBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
 	pub MaxLiquidationContractSlippage: Ratio = Ratio::saturating_from_rational(80, 100);
+	pub PartialLiquidationBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 	pub const SettleErc20EvmOrigin: AccountId = AccountId32::new([255u8; 32]);
 }
@@ -289,39 +314,79 @@ parameter_types! {
 impl module_cdp_engine::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PriceSource = MockPriceSource;
+	type PriceTimestampSource = ();
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
 	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
 	type MinimumDebitValue = ConstU128<2>;
+	type MinLiquidationRatio = MinLiquidationRatio;
+	type MaxLiquidationRatio = MaxLiquidationRatio;
+	type MaxLiquidationPenalty = MaxLiquidationPenalty;
+	type MaxInterestRatePerSec = MaxInterestRatePerSec;
+	type MaxDebitCeilingIncrease = MaxDebitCeilingIncrease;
 	type MinimumCollateralAmount = MinimumCollateralAmount;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
-	type UnsignedPriority = ConstU64<1048576>; // 1 << 20
+	type UnsignedPriority = CdpEngineUnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type SafeMode = ();
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = ();
 	type LiquidationContractsUpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxCrossMarginPreferences = ConstU32<5>;
 	type LiquidationEvmBridge = ();
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type Swap = SpecificJointsSwap<(), AlternativeSwapPathJointList>;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type SettleErc20EvmOrigin = SettleErc20EvmOrigin;
+	type MaxLiquidationRecords = ConstU32<20>;
+	type PositionAlerts = HonzonModule;
+	type MaxPositionAlertBatch = ConstU32<2>;
+	type MaxDebitRebaseFactor = ConstU32<1_000>;
+	type RevenuePeriod = ConstU64<10>;
+	type MaxRevenuePeriods = ConstU32<10>;
+	type RiskSnapshotPeriod = ConstU64<10>;
+	type MaxRiskSnapshotPositions = ConstU32<10>;
+	type PartialLiquidationThreshold = ConstU128<1_000_000>;
+	type PartialLiquidationBuffer = PartialLiquidationBuffer;
+	type Task = ();
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
+parameter_types! {
+	pub static GuardedAccount: Option<AccountId> = None;
+}
+
+pub struct MockOperationGuard;
+impl OperationGuard<AccountId> for MockOperationGuard {
+	fn check_operation(who: &AccountId, _operation: GuardedOperation) -> DispatchResult {
+		if GuardedAccount::get().as_ref() == Some(who) {
+			Err(DispatchError::Other("guarded operation"))
+		} else {
+			Ok(())
+		}
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = PalletBalances;
 	type DepositPerAuthorization = ConstU128<100>;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
+	type OperationGuard = MockOperationGuard;
+	type AuctionManagerHandler = MockAuctionManager;
+	type TopUpBounty = ConstU128<10>;
+	type TopUpRatioBuffer = TopUpRatioBuffer;
+	type MaxPositionAlerts = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -23,9 +23,9 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{RuntimeEvent, *};
-use module_support::{Rate, Ratio};
+use module_support::{Price, Rate, Ratio};
 use orml_traits::{Change, MultiCurrency};
-use sp_runtime::FixedPointNumber;
+use sp_runtime::{traits::One as OneT, DispatchError, FixedPointNumber};
 
 #[test]
 fn authorize_should_work() {
@@ -151,6 +151,25 @@ fn adjust_loan_should_work() {
 	});
 }
 
+#[test]
+fn adjust_loan_with_zero_adjustments_is_no_op() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let post_info = HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, 0, 0).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::adjust_loan_no_op()));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::NoOp {
+			who: ALICE,
+			currency_id: BTC,
+		}));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+
+		// no-op is allowed after shutdown
+		mock_shutdown();
+		assert_ok!(HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, 0, 0));
+	});
+}
+
 #[test]
 fn adjust_loan_by_debit_value_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -200,6 +219,100 @@ fn on_emergency_shutdown_should_work() {
 			HonzonModule::close_loan_has_debit_by_dex(RuntimeOrigin::signed(ALICE), BTC, 100),
 			Error::<Runtime>::AlreadyShutdown,
 		);
+		assert_noop!(
+			HonzonModule::split_loan(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				vec![(60, 30), (40, 20)].try_into().unwrap()
+			),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn split_and_merge_loans_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, 100, 50));
+
+		// every part must be a valid position by itself
+		assert_noop!(
+			HonzonModule::split_loan(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				vec![(90, 40), (10, 10)].try_into().unwrap()
+			),
+			module_cdp_engine::Error::<Runtime>::RemainDebitValueTooSmall
+		);
+		assert_noop!(
+			HonzonModule::split_loan(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				vec![(5, 40), (95, 10)].try_into().unwrap()
+			),
+			module_cdp_engine::Error::<Runtime>::BelowRequiredCollateralRatio
+		);
+
+		assert_ok!(HonzonModule::split_loan(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			vec![(60, 30), (40, 20)].try_into().unwrap()
+		));
+		assert_eq!(
+			LoansModule::positions(BTC, ALICE),
+			Position {
+				collateral: 60,
+				debit: 30
+			}
+		);
+		assert_eq!(
+			LoansModule::position_at(BTC, ALICE, 1),
+			Position {
+				collateral: 40,
+				debit: 20
+			}
+		);
+		assert_eq!(
+			LoansModule::total_positions(BTC),
+			Position {
+				collateral: 100,
+				debit: 50
+			}
+		);
+
+		let post_info = HonzonModule::merge_loans(RuntimeOrigin::signed(ALICE), BTC).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::merge_loans(1)));
+		assert_eq!(
+			LoansModule::positions_of(BTC, &ALICE),
+			vec![(
+				0,
+				Position {
+					collateral: 100,
+					debit: 50
+				}
+			)]
+		);
+		assert_eq!(
+			LoansModule::total_positions(BTC),
+			Position {
+				collateral: 100,
+				debit: 50
+			}
+		);
+		assert_noop!(
+			HonzonModule::merge_loans(RuntimeOrigin::signed(ALICE), BTC),
+			module_loans::Error::<Runtime>::NoPositionToMerge
+		);
 	});
 }
 
@@ -229,6 +342,108 @@ fn close_loan_has_debit_by_dex_work() {
 	});
 }
 
+#[test]
+fn guarded_operations_should_be_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, 100, 50));
+		assert_ok!(HonzonModule::authorize(RuntimeOrigin::signed(ALICE), BTC, BOB));
+		GuardedAccount::set(Some(ALICE));
+
+		// deposit is not guarded
+		assert_ok!(HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, 10, 0));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 110);
+
+		assert_noop!(
+			HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, -10, 0),
+			DispatchError::Other("guarded operation")
+		);
+		assert_noop!(
+			HonzonModule::adjust_loan_by_debit_value(RuntimeOrigin::signed(ALICE), BTC, -10, 0),
+			DispatchError::Other("guarded operation")
+		);
+		assert_noop!(
+			HonzonModule::shrink_position_debit(RuntimeOrigin::signed(ALICE), BTC, 10, 0),
+			DispatchError::Other("guarded operation")
+		);
+		assert_noop!(
+			HonzonModule::close_loan_has_debit_by_dex(RuntimeOrigin::signed(ALICE), BTC, 100),
+			DispatchError::Other("guarded operation")
+		);
+		assert_noop!(
+			HonzonModule::transfer_loan_from(RuntimeOrigin::signed(BOB), BTC, ALICE),
+			DispatchError::Other("guarded operation")
+		);
+
+		GuardedAccount::set(None);
+		assert_ok!(HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, -10, 0));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+	});
+}
+
+#[test]
+fn shift_loan_collateral_checks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, 100, 50));
+
+		assert_noop!(
+			HonzonModule::shift_loan_collateral(RuntimeOrigin::signed(ALICE), BTC, BTC, 0, 100),
+			Error::<Runtime>::ShiftToSameCollateral
+		);
+		assert_noop!(
+			HonzonModule::shift_loan_collateral(RuntimeOrigin::signed(ALICE), BTC, DOT, 0, 100),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		GuardedAccount::set(Some(ALICE));
+		assert_noop!(
+			HonzonModule::shift_loan_collateral(RuntimeOrigin::signed(ALICE), BTC, DOT, 0, 100),
+			DispatchError::Other("guarded operation")
+		);
+		GuardedAccount::set(None);
+
+		// there's no DEX to swap the collateral
+		assert_noop!(
+			HonzonModule::shift_loan_collateral(RuntimeOrigin::signed(ALICE), BTC, DOT, 0, 100),
+			DispatchError::Other("Cannot swap")
+		);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 50);
+
+		mock_shutdown();
+		assert_noop!(
+			HonzonModule::shift_loan_collateral(RuntimeOrigin::signed(ALICE), BTC, DOT, 0, 100),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
+
 #[test]
 fn transfer_debit_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -341,3 +556,501 @@ fn transfer_debit_no_ausd() {
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 0);
 	});
 }
+
+#[test]
+fn get_honzon_snapshot_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+
+		// 300 positions across two collaterals
+		for i in 0..300u32 {
+			let mut who = [0u8; 32];
+			who[..4].copy_from_slice(&i.to_le_bytes());
+			let currency_id = if i % 2 == 0 { BTC } else { DOT };
+			let position = Position {
+				collateral: 1000 + i as Balance,
+				debit: i as Balance,
+			};
+			module_loans::TotalPositions::<Runtime>::mutate(currency_id, |total| {
+				total.collateral += position.collateral;
+				total.debit += position.debit;
+			});
+			module_loans::Positions::<Runtime>::insert((currency_id, AccountId::new(who), 0), position);
+		}
+
+		// the globals are only in the first page
+		let first_page = HonzonModule::get_honzon_snapshot(None, 7);
+		assert_eq!(first_page.block_number, 1);
+		assert_eq!(first_page.positions.len(), 7);
+		assert_eq!(
+			first_page.globals,
+			Some(HonzonGlobals {
+				surplus_pool: 0,
+				debit_pool: 0,
+				total_target_in_auction: 0,
+				collaterals: vec![CollateralSnapshot {
+					currency_id: BTC,
+					maximum_total_debit_value: 10000,
+					effective_maximum_total_debit_value: 10000,
+					interest_rate_per_sec: Rate::saturating_from_rational(1, 100000),
+					liquidation_ratio: Ratio::saturating_from_rational(3, 2),
+					liquidation_penalty: Rate::saturating_from_rational(2, 10),
+					required_collateral_ratio: Some(Ratio::saturating_from_rational(9, 5)),
+					debit_exchange_rate: CDPEngineModule::get_debit_exchange_rate(BTC),
+					total_collateral: 150 * 1000 + (0..300).step_by(2).sum::<Balance>(),
+					total_debit: (0..300).step_by(2).sum::<Balance>(),
+					collateral_in_auction: 0,
+					price: Some(Price::one()),
+				}],
+			})
+		);
+
+		// stitch all pages
+		let mut positions = first_page.positions;
+		let mut cursor = first_page.next_cursor;
+		let mut pages = 1;
+		while let Some(last) = cursor {
+			let page = HonzonModule::get_honzon_snapshot(Some(last), 7);
+			assert_eq!(page.globals, None);
+			assert!(!page.positions.is_empty() && page.positions.len() <= 7);
+			positions.extend(page.positions);
+			cursor = page.next_cursor;
+			pages += 1;
+		}
+		assert_eq!(pages, 43);
+
+		let expected: Vec<_> = module_loans::Positions::<Runtime>::iter()
+			.map(|((currency_id, who, position_index), position)| PositionSnapshot {
+				currency_id,
+				who,
+				position_index,
+				collateral: position.collateral,
+				debit: position.debit,
+			})
+			.collect();
+		assert_eq!(expected.len(), 300);
+		assert_eq!(positions, expected);
+
+		// the page size is limited
+		assert_eq!(HonzonModule::get_honzon_snapshot(None, 0).positions.len(), 1);
+		assert_eq!(HonzonModule::get_honzon_snapshot(None, u32::MAX).positions.len(), 300);
+		assert_eq!(HonzonModule::get_honzon_snapshot(None, 300).next_cursor, None);
+	});
+}
+
+#[test]
+fn get_honzon_snapshot_cursor_is_stable() {
+	ExtBuilder::default().build().execute_with(|| {
+		for i in 0..200u32 {
+			let mut who = [0u8; 32];
+			who[..4].copy_from_slice(&i.to_le_bytes());
+			module_loans::Positions::<Runtime>::insert(
+				(BTC, AccountId::new(who), 0),
+				Position {
+					collateral: 100,
+					debit: 50,
+				},
+			);
+		}
+
+		let first_page = HonzonModule::get_honzon_snapshot(None, 50);
+		let cursor = first_page.next_cursor.clone().unwrap();
+		let second_page = HonzonModule::get_honzon_snapshot(Some(cursor.clone()), 50);
+
+		// the position of the cursor is closed and new positions are opened in later blocks
+		System::set_block_number(2);
+		module_loans::Positions::<Runtime>::remove((cursor.currency_id, &cursor.who, cursor.position_index));
+		let mut who = [0u8; 32];
+		who[..4].copy_from_slice(&1000u32.to_le_bytes());
+		module_loans::Positions::<Runtime>::insert(
+			(DOT, AccountId::new(who), 0),
+			Position {
+				collateral: 100,
+				debit: 50,
+			},
+		);
+
+		// the page after the cursor is still the same
+		let page = HonzonModule::get_honzon_snapshot(Some(cursor), 50);
+		assert_eq!(page.block_number, 2);
+		assert_eq!(page.positions, second_page.positions);
+		assert_eq!(page.next_cursor, second_page.next_cursor);
+	});
+}
+
+fn setup_topup_position() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NewValue(None),
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(None),
+		Change::NewValue(10000),
+	));
+	// collateral ratio is 300% at the price of 1
+	assert_ok!(HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, 300, 1000));
+}
+
+#[test]
+fn register_topup_voucher_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_topup_position();
+
+		assert_noop!(
+			HonzonModule::register_topup_voucher(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				100,
+				Ratio::saturating_from_rational(5, 2),
+				10
+			),
+			Error::<Runtime>::InvalidTopUpVoucher
+		);
+		assert_noop!(
+			HonzonModule::register_topup_voucher(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				0,
+				Ratio::saturating_from_rational(5, 2),
+				10
+			),
+			Error::<Runtime>::InvalidTopUpVoucher
+		);
+		assert_noop!(
+			HonzonModule::register_topup_voucher(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				100,
+				Ratio::saturating_from_rational(5, 2),
+				1
+			),
+			Error::<Runtime>::InvalidTopUpVoucher
+		);
+
+		assert_ok!(HonzonModule::register_topup_voucher(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			100,
+			Ratio::saturating_from_rational(5, 2),
+			10
+		));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::TopUpVoucherRegistered {
+			owner: ALICE,
+			collateral_type: BTC,
+			max_amount: 100,
+			trigger_ratio: Ratio::saturating_from_rational(5, 2),
+			deadline: 10,
+		}));
+		assert_eq!(
+			HonzonModule::topup_vouchers(ALICE, BTC),
+			Some(TopUpVoucher {
+				remaining: 100,
+				trigger_ratio: Ratio::saturating_from_rational(5, 2),
+				deadline: 10,
+			})
+		);
+
+		assert_ok!(HonzonModule::cancel_topup_voucher(RuntimeOrigin::signed(ALICE), BTC));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::TopUpVoucherCancelled {
+			owner: ALICE,
+			collateral_type: BTC,
+		}));
+		assert_eq!(HonzonModule::topup_vouchers(ALICE, BTC), None);
+		assert_noop!(
+			HonzonModule::cancel_topup_voucher(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::TopUpVoucherNotExists
+		);
+	});
+}
+
+#[test]
+fn execute_topup_voucher_partially_across_triggers() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_topup_position();
+		assert_ok!(HonzonModule::register_topup_voucher(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			250,
+			Ratio::saturating_from_rational(5, 2),
+			10
+		));
+
+		// not triggered at the collateral ratio of 300%
+		assert_noop!(
+			HonzonModule::execute_topup_voucher(RuntimeOrigin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::TopUpNotTriggered
+		);
+
+		// 240% => restore to 260%
+		RelativePrice::set(Price::saturating_from_rational(8, 10));
+		assert_ok!(HonzonModule::execute_topup_voucher(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			BTC
+		));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::TopUpVoucherExecuted {
+			owner: ALICE,
+			collateral_type: BTC,
+			executor: BOB,
+			amount: 25,
+			bounty: 10,
+			remaining: 225,
+		}));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 325);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 675);
+		assert_eq!(PalletBalances::free_balance(ALICE), 990);
+		assert_eq!(PalletBalances::free_balance(BOB), 10);
+
+		// 162.5% => restore to 260%
+		RelativePrice::set(Price::saturating_from_rational(5, 10));
+		assert_ok!(HonzonModule::execute_topup_voucher(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			BTC
+		));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 520);
+		assert_eq!(HonzonModule::topup_vouchers(ALICE, BTC).unwrap().remaining, 30);
+
+		// 208% => bounded by the remaining amount, the voucher is consumed
+		RelativePrice::set(Price::saturating_from_rational(4, 10));
+		assert_ok!(HonzonModule::execute_topup_voucher(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			BTC
+		));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::TopUpVoucherExecuted {
+			owner: ALICE,
+			collateral_type: BTC,
+			executor: BOB,
+			amount: 30,
+			bounty: 10,
+			remaining: 0,
+		}));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 550);
+		assert_eq!(PalletBalances::free_balance(BOB), 30);
+		assert_eq!(HonzonModule::topup_vouchers(ALICE, BTC), None);
+		assert_noop!(
+			HonzonModule::execute_topup_voucher(RuntimeOrigin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::TopUpVoucherNotExists
+		);
+	});
+}
+
+#[test]
+fn topup_voucher_expires_after_deadline() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_topup_position();
+		assert_ok!(HonzonModule::register_topup_voucher(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			250,
+			Ratio::saturating_from_rational(5, 2),
+			10
+		));
+		RelativePrice::set(Price::saturating_from_rational(8, 10));
+
+		System::set_block_number(10);
+		assert_noop!(
+			HonzonModule::remove_expired_topup_voucher(RuntimeOrigin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::TopUpVoucherNotExpired
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			HonzonModule::execute_topup_voucher(RuntimeOrigin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::TopUpVoucherExpired
+		);
+		assert_ok!(HonzonModule::remove_expired_topup_voucher(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			BTC
+		));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::TopUpVoucherExpired {
+			owner: ALICE,
+			collateral_type: BTC,
+		}));
+		assert_eq!(HonzonModule::topup_vouchers(ALICE, BTC), None);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 300);
+	});
+}
+
+fn alert(trigger_ratio: Ratio, armed: bool) -> PositionAlert {
+	PositionAlert { trigger_ratio, armed }
+}
+
+fn triggered_alerts() -> Vec<(Ratio, Ratio)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::HonzonModule(crate::Event::PositionAlertTriggered {
+				ratio, trigger_ratio, ..
+			}) => Some((ratio, trigger_ratio)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn set_and_clear_position_alert_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_topup_position();
+		let (high, low) = (
+			Ratio::saturating_from_rational(5, 2),
+			Ratio::saturating_from_rational(2, 1),
+		);
+
+		assert_noop!(
+			HonzonModule::set_position_alert(RuntimeOrigin::signed(ALICE), DOT, high),
+			Error::<Runtime>::InvalidPositionAlert
+		);
+		assert_noop!(
+			HonzonModule::set_position_alert(RuntimeOrigin::signed(ALICE), BTC, Ratio::zero()),
+			Error::<Runtime>::InvalidPositionAlert
+		);
+
+		assert_ok!(HonzonModule::set_position_alert(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			high
+		));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::PositionAlertSet {
+			owner: ALICE,
+			currency_id: BTC,
+			trigger_ratio: high,
+		}));
+		assert_ok!(HonzonModule::set_position_alert(RuntimeOrigin::signed(ALICE), BTC, low));
+		// setting the same trigger ratio again doesn't add an alert
+		assert_ok!(HonzonModule::set_position_alert(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			high
+		));
+		assert_eq!(
+			HonzonModule::position_alerts(ALICE, BTC).to_vec(),
+			vec![alert(high, true), alert(low, true)]
+		);
+		assert_noop!(
+			HonzonModule::set_position_alert(RuntimeOrigin::signed(ALICE), BTC, Ratio::saturating_from_rational(3, 2)),
+			Error::<Runtime>::TooManyPositionAlerts
+		);
+
+		assert_ok!(HonzonModule::clear_position_alert(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			low
+		));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::PositionAlertCleared {
+			owner: ALICE,
+			currency_id: BTC,
+			trigger_ratio: low,
+		}));
+		assert_noop!(
+			HonzonModule::clear_position_alert(RuntimeOrigin::signed(ALICE), BTC, low),
+			Error::<Runtime>::PositionAlertNotExists
+		);
+		assert_noop!(
+			HonzonModule::clear_position_alert(RuntimeOrigin::signed(BOB), BTC, high),
+			Error::<Runtime>::PositionAlertNotExists
+		);
+		assert_ok!(HonzonModule::clear_position_alert(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			high
+		));
+		assert!(!PositionAlerts::<Runtime>::contains_key(ALICE, BTC));
+	});
+}
+
+#[test]
+fn position_alert_triggers_disarms_and_rearms() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_topup_position();
+		let (high, low) = (
+			Ratio::saturating_from_rational(5, 2),
+			Ratio::saturating_from_rational(2, 1),
+		);
+		assert_ok!(HonzonModule::set_position_alert(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			high
+		));
+		assert_ok!(HonzonModule::set_position_alert(RuntimeOrigin::signed(ALICE), BTC, low));
+		let trigger = || {
+			assert_ok!(CDPEngineModule::trigger_position_alerts(
+				RuntimeOrigin::none(),
+				BTC,
+				vec![ALICE].try_into().unwrap()
+			));
+		};
+
+		// not tripped at the collateral ratio of 300%
+		assert!(!HonzonModule::is_alert_tripped(
+			&ALICE,
+			BTC,
+			Ratio::saturating_from_rational(3, 1)
+		));
+		trigger();
+		assert!(triggered_alerts().is_empty());
+
+		// 240% trips the alert of 250%
+		RelativePrice::set(Price::saturating_from_rational(8, 10));
+		let ratio = Ratio::saturating_from_rational(12, 5);
+		assert!(HonzonModule::is_alert_tripped(&ALICE, BTC, ratio));
+		trigger();
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::PositionAlertTriggered {
+			owner: ALICE,
+			currency_id: BTC,
+			ratio,
+			trigger_ratio: high,
+		}));
+		assert_eq!(
+			HonzonModule::position_alerts(ALICE, BTC).to_vec(),
+			vec![alert(high, false), alert(low, true)]
+		);
+
+		// the disarmed alert is not triggered again
+		assert!(!HonzonModule::is_alert_tripped(&ALICE, BTC, ratio));
+		trigger();
+		assert_eq!(triggered_alerts(), vec![(ratio, high)]);
+
+		// 150% trips the alert of 200%
+		RelativePrice::set(Price::saturating_from_rational(5, 10));
+		trigger();
+		assert_eq!(
+			triggered_alerts(),
+			vec![(ratio, high), (Ratio::saturating_from_rational(3, 2), low)]
+		);
+
+		// the re-armed alert is triggered again
+		assert_ok!(HonzonModule::set_position_alert(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			high
+		));
+		assert_eq!(
+			HonzonModule::position_alerts(ALICE, BTC).to_vec(),
+			vec![alert(high, true), alert(low, false)]
+		);
+		trigger();
+		assert_eq!(triggered_alerts().len(), 3);
+		assert_eq!(
+			HonzonModule::position_alerts(ALICE, BTC).to_vec(),
+			vec![alert(high, false), alert(low, false)]
+		);
+	});
+}
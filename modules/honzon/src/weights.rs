@@ -51,12 +51,22 @@ pub trait WeightInfo {
 	fn unauthorize() -> Weight;
 	fn unauthorize_all(c: u32, ) -> Weight;
 	fn adjust_loan() -> Weight;
+	fn adjust_loan_no_op() -> Weight;
 	fn transfer_loan_from() -> Weight;
 	fn close_loan_has_debit_by_dex() -> Weight;
 	fn expand_position_collateral() -> Weight;
 	fn shrink_position_debit() -> Weight;
 	fn transfer_debit() -> Weight;
 	fn precompile_get_current_collateral_ratio() -> Weight;
+	fn register_topup_voucher() -> Weight;
+	fn cancel_topup_voucher() -> Weight;
+	fn execute_topup_voucher() -> Weight;
+	fn remove_expired_topup_voucher() -> Weight;
+	fn set_position_alert() -> Weight;
+	fn clear_position_alert() -> Weight;
+	fn split_loan(p: u32, ) -> Weight;
+	fn merge_loans(p: u32, ) -> Weight;
+	fn shift_loan_collateral() -> Weight;
 }
 
 /// Weights for module_honzon using the Acala node and recommended hardware.
@@ -108,6 +118,9 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(16 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
+	fn adjust_loan_no_op() -> Weight {
+		Weight::from_parts(8_263_000, 0)
+	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
 	// Storage: Honzon Authorization (r:1 w:0)
@@ -151,8 +164,8 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Storage: AggregatedDex AggregatedSwapPaths (r:1 w:0)
 	fn close_loan_has_debit_by_dex() -> Weight {
 		Weight::from_parts(349_743_000, 0)
-			.saturating_add(T::DbWeight::get().reads(35 as u64))
-			.saturating_add(T::DbWeight::get().writes(16 as u64))
+			.saturating_add(T::DbWeight::get().reads(37 as u64))
+			.saturating_add(T::DbWeight::get().writes(18 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: CdpEngine CollateralParams (r:1 w:0)
@@ -226,6 +239,108 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		Weight::from_parts(44_244_000, 0)
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
 	}
+	// Storage: Honzon TopUpVouchers (r:0 w:1)
+	fn register_topup_voucher() -> Weight {
+		Weight::from_parts(21_346_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Honzon TopUpVouchers (r:1 w:1)
+	fn cancel_topup_voucher() -> Weight {
+		Weight::from_parts(23_517_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Storage: Honzon TopUpVouchers (r:1 w:1)
+	// Storage: Loans Positions (r:1 w:1)
+	// Storage: Prices LockedPrice (r:2 w:0)
+	// Storage: AcalaOracle Values (r:1 w:0)
+	// Storage: AssetRegistry AssetMetadatas (r:2 w:0)
+	// Storage: CdpEngine DebitExchangeRate (r:1 w:0)
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Storage: Loans TotalPositions (r:1 w:1)
+	fn execute_topup_voucher() -> Weight {
+		Weight::from_parts(158_276_000, 0)
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: Honzon TopUpVouchers (r:1 w:1)
+	fn remove_expired_topup_voucher() -> Weight {
+		Weight::from_parts(24_108_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_position_alert() -> Weight {
+		Weight::from_parts(19_342_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn clear_position_alert() -> Weight {
+		Weight::from_parts(20_517_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Storage: Loans Positions (r:9 w:2)
+	// Storage: CdpEngine DebitRebases (r:1 w:0)
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Storage: CdpEngine DebitExchangeRate (r:1 w:0)
+	// Storage: AcalaOracle Values (r:1 w:0)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Loans TotalPositions (r:1 w:1)
+	fn split_loan(p: u32, ) -> Weight {
+		Weight::from_parts(52_318_000, 0)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_parts(38_674_000, 0).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Storage: Loans Positions (r:9 w:9)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Loans TotalPositions (r:1 w:1)
+	// Storage: CdpEngine DebitRebases (r:1 w:0)
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Storage: CdpEngine DebitExchangeRate (r:1 w:0)
+	// Storage: AcalaOracle Values (r:1 w:0)
+	fn merge_loans(p: u32, ) -> Weight {
+		Weight::from_parts(48_905_000, 0)
+			// Standard Error: 37_000
+			.saturating_add(Weight::from_parts(36_127_000, 0).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Storage: CdpEngine CollateralParams (r:2 w:0)
+	// Storage: SafeMode State (r:1 w:0)
+	// Storage: Loans Positions (r:2 w:2)
+	// Storage: Prices LockedPrice (r:2 w:0)
+	// Storage: AcalaOracle Values (r:2 w:0)
+	// Storage: Dex TradingPairStatuses (r:3 w:0)
+	// Storage: Dex LiquidityPool (r:3 w:2)
+	// Storage: Tokens Accounts (r:5 w:5)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: CdpEngine DebitExchangeRate (r:2 w:0)
+	// Storage: Rewards PoolInfos (r:2 w:2)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:2 w:2)
+	// Storage: Loans TotalPositions (r:2 w:2)
+	// Storage: CdpTreasury IssuanceByOrigin (r:2 w:2)
+	// Storage: CdpEngine DebitRebases (r:2 w:0)
+	fn shift_loan_collateral() -> Weight {
+		Weight::from_parts(268_416_000, 0)
+			.saturating_add(T::DbWeight::get().reads(34 as u64))
+			.saturating_add(T::DbWeight::get().writes(18 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -253,6 +368,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(16 as u64))
 			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
+	fn adjust_loan_no_op() -> Weight {
+		Weight::from_parts(8_263_000, 0)
+	}
 	fn transfer_loan_from() -> Weight {
 		Weight::from_parts(120_478_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(17 as u64))
@@ -260,8 +378,8 @@ impl WeightInfo for () {
 	}
 	fn close_loan_has_debit_by_dex() -> Weight {
 		Weight::from_parts(349_743_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(35 as u64))
-			.saturating_add(RocksDbWeight::get().writes(16 as u64))
+			.saturating_add(RocksDbWeight::get().reads(37 as u64))
+			.saturating_add(RocksDbWeight::get().writes(18 as u64))
 	}
 	fn expand_position_collateral() -> Weight {
 		Weight::from_parts(227_393_000, 0)
@@ -282,4 +400,54 @@ impl WeightInfo for () {
 		Weight::from_parts(44_244_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(11 as u64))
 	}
+	fn register_topup_voucher() -> Weight {
+		Weight::from_parts(21_346_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn cancel_topup_voucher() -> Weight {
+		Weight::from_parts(23_517_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn execute_topup_voucher() -> Weight {
+		Weight::from_parts(158_276_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(17 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	fn remove_expired_topup_voucher() -> Weight {
+		Weight::from_parts(24_108_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_position_alert() -> Weight {
+		Weight::from_parts(19_342_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn clear_position_alert() -> Weight {
+		Weight::from_parts(20_517_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn split_loan(p: u32, ) -> Weight {
+		Weight::from_parts(52_318_000, 0)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_parts(38_674_000, 0).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(17 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	fn merge_loans(p: u32, ) -> Weight {
+		Weight::from_parts(48_905_000, 0)
+			// Standard Error: 37_000
+			.saturating_add(Weight::from_parts(36_127_000, 0).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(17 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	fn shift_loan_collateral() -> Weight {
+		Weight::from_parts(268_416_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(34 as u64))
+			.saturating_add(RocksDbWeight::get().writes(18 as u64))
+	}
 }
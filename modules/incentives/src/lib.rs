@@ -34,19 +34,66 @@
 //! 1. Incentives: periodicly(AccumulatePeriod), accumulate fixed amount according to Incentive.
 //!    Rewards come from RewardsSource, please transfer enough tokens to RewardsSource before start
 //!    incentive plan.
+//!
+//! Claim vesting:
+//! Governance can configure a pool to pay only part of the claimed rewards immediately, the rest
+//! streams linearly over a number of blocks and is claimed by `claim_vested_rewards`. It only
+//! affects future claims, and the claim deduction rate is applied before the vesting split.
+//!
+//! Claim preference:
+//! An account can prefer to receive the rewards of a pool in a currency. The rewards paid
+//! immediately by a claim in other currencies are swapped into it once per claim, unless the swap
+//! is worse than the oracle price by more than the slippage bound of the preference, then they're
+//! paid as is. The rewards paid by `claim_vested_rewards` are not converted.
+//!
+//! Deposit protection:
+//! Governance can protect a pool from short-term deposits timed around the accumulation.
+//! 1. Minimum deposit age: the DEX shares deposited start to accrue rewards only at the start of
+//!    the first `AccumulatePeriod` window after they are at least the minimum age. Deposits are
+//!    bucketed by the window they mature at, and the youngest are withdrawn first.
+//! 2. Randomized accumulation: the pool accumulates at a random block within each
+//!    `AccumulatePeriod` window instead of at its start. Every block of the window which has not
+//!    accumulated yet is picked with the probability of one over the remaining blocks, so the
+//!    accumulation block is uniform within the window and known only when the block is built.
+//!
+//! Withdrawal delay:
+//! Governance can delay the withdrawals of the DEX shares deposited into a pool. A depositor either
+//! requests a withdrawal by `request_withdraw_dex_share`, the shares stop accruing rewards
+//! immediately and are executable by `execute_withdraw_dex_share` after the delay, or withdraws
+//! instantly and pays the instant exit fee. The fee is accumulated to the pool as rewards in the
+//! DEX share currency, so it's shared by the remaining depositors; no fee is charged if there are
+//! none. The instant exit fee also applies to removing liquidity by unstaking from the DEX, while
+//! the DEX shares held directly are unaffected. Both are bypassed in emergency shutdown.
+//!
+//! Pool retirement:
+//! Governance can retire a dead pool. It stops accumulating and accepting DEX shares at once, and
+//! the stakers have `RetirementGracePeriod` blocks to withdraw the shares and claim the rewards.
+//! After the grace period, the unclaimed rewards are swept back to `RewardsSource`, and the storage
+//! of the pool is removed at most `MaxRetirementRemovals` entries per block. The DEX shares still
+//! staked are refunded to their owners while the entries are removed. The pools of more total
+//! shares than `RetirementSharesThreshold` can be retired only by `RetireOrigin`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, transactional, PalletId};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{ExistenceRequirement, Randomness},
+	transactional, PalletId,
+};
 use frame_system::pallet_prelude::*;
-use module_support::{DEXIncentives, EmergencyShutdown, FractionalRate, IncentivesManager, PoolId, Rate};
+use module_support::{
+	DEXIncentives, EmergencyShutdown, FractionalRate, IncentivesManager, OnTradingPairDisabled, OnTradingPairEnabled,
+	PoolId, PriceProvider, Rate, Ratio, Swap, SwapLimit, TradingPairListing,
+};
 use orml_traits::{Handler, MultiCurrency, RewardHandler};
-use primitives::{Amount, Balance, CurrencyId};
+use primitives::{Amount, Balance, CurrencyId, Moment, TradingPair};
 use sp_runtime::{
-	traits::{AccountIdConversion, UniqueSaturatedInto, Zero},
-	DispatchResult, FixedPointNumber,
+	traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, One, Saturating, TrailingZeroInput, UniqueSaturatedInto, Zero,
+	},
+	DispatchResult, FixedPointNumber, Permill,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
@@ -57,6 +104,111 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The claim vesting configuration of a pool.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ClaimVesting<BlockNumber> {
+	/// The rate of the claimed rewards paid immediately.
+	pub immediate_rate: Rate,
+	/// The number of blocks over which the rest of the claimed rewards vest.
+	pub period: BlockNumber,
+}
+
+/// The claimed rewards of a currency which are still vesting.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct VestingReward<BlockNumber> {
+	/// Vested amount which is not claimed yet.
+	pub unlocked: Balance,
+	/// Amount vesting linearly from `start` to `end`.
+	pub locked: Balance,
+	pub start: BlockNumber,
+	pub end: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> VestingReward<BlockNumber> {
+	/// The part of `locked` vested at `now`.
+	fn vested(&self, now: BlockNumber) -> Balance {
+		if now >= self.end {
+			self.locked
+		} else if now <= self.start {
+			Zero::zero()
+		} else {
+			let elapsed: u128 = (now - self.start).unique_saturated_into();
+			let duration: u128 = (self.end - self.start).unique_saturated_into();
+			Rate::saturating_from_rational(elapsed, duration).saturating_mul_int(self.locked)
+		}
+	}
+
+	/// Take all vested amount at `now`.
+	fn release(&mut self, now: BlockNumber) -> Balance {
+		let vested = self.vested(now);
+		self.locked = self.locked.saturating_sub(vested);
+		self.start = self.start.max(now);
+		sp_std::mem::take(&mut self.unlocked).saturating_add(vested)
+	}
+
+	/// Merge `amount` into the stream, which restarts at `now` and ends at the later of the current
+	/// end and `end`.
+	fn add(&mut self, amount: Balance, now: BlockNumber, end: BlockNumber) {
+		let vested = self.vested(now);
+		self.unlocked = self.unlocked.saturating_add(vested);
+		self.locked = self.locked.saturating_sub(vested).saturating_add(amount);
+		self.start = now;
+		self.end = self.end.max(end);
+	}
+
+	fn is_empty(&self) -> bool {
+		self.unlocked.is_zero() && self.locked.is_zero()
+	}
+}
+
+/// The currency which an account prefers to receive the claimed rewards of a pool in.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ClaimPreference {
+	/// The currency which the claimed rewards are swapped into.
+	pub currency_id: CurrencyId,
+	/// The maximum slippage of the swap compared to the oracle price, the rewards are paid as is
+	/// if it can't be met.
+	pub max_slippage: Ratio,
+}
+
+/// The protection of a pool against short-term deposits.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DepositProtection<BlockNumber> {
+	/// The minimum age of the DEX shares deposited before they accrue rewards, rounded up to the
+	/// start of an `AccumulatePeriod` window. Zero means no minimum age.
+	pub min_deposit_age: BlockNumber,
+	/// Whether to accumulate at a random block within each `AccumulatePeriod` window.
+	pub randomized_accumulation: bool,
+}
+
+/// The withdrawal delay of the DEX shares deposited into a pool.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct WithdrawalDelay<BlockNumber> {
+	/// The number of blocks from requesting a withdrawal until it's executable.
+	pub delay: BlockNumber,
+	/// The fee of withdrawing instantly, paid in the DEX shares withdrawn.
+	pub instant_exit_fee: Permill,
+}
+
+/// The retirement state of a pool.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PoolRetirement<BlockNumber> {
+	/// The stakers can withdraw the shares and claim the rewards until `sweep_at`.
+	Grace { sweep_at: BlockNumber },
+	/// The unclaimed rewards are swept and the storage of the pool is being removed.
+	Sweeping,
+	/// The storage of the `PoolId::Loans` pool is removed. The pool stays retired since the
+	/// loans are still open, so their shares are not updated any more.
+	Retired,
+}
+
+/// The requested withdrawal of the DEX shares, executable from `unlock_at`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PendingWithdrawal<BlockNumber> {
+	pub amount: Balance,
+	pub unlock_at: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -93,6 +245,31 @@ pub mod module {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The randomness source to pick the accumulation block of randomized pools.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Swap to convert the claimed rewards into the preferred currency.
+		type Swap: Swap<Self::AccountId, Balance, CurrencyId>;
+
+		/// The oracle price source to bound the slippage of the conversion.
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// The number of blocks the stakers of a retired pool have to withdraw the shares and
+		/// claim the rewards.
+		#[pallet::constant]
+		type RetirementGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum total shares of a pool which `UpdateOrigin` may retire.
+		#[pallet::constant]
+		type RetirementSharesThreshold: Get<Balance>;
+
+		/// The origin which may retire the pools of any total shares.
+		type RetireOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of the storage entries of the retired pools removed per block.
+		#[pallet::constant]
+		type MaxRetirementRemovals: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -107,6 +284,18 @@ pub mod module {
 		InvalidPoolId,
 		/// Invalid rate
 		InvalidRate,
+		/// The oracle price of the claimed rewards is unavailable
+		NoPrice,
+		/// There's no requested withdrawal
+		WithdrawalNotRequested,
+		/// The requested withdrawal is still delayed
+		WithdrawalStillDelayed,
+		/// The pool is retiring
+		PoolRetiring,
+		/// The grace period of the retired pool is over
+		PoolRetired,
+		/// The total shares of the pool exceed the threshold of the origin
+		RetirementSharesExceeded,
 	}
 
 	#[pallet::event]
@@ -142,6 +331,90 @@ pub mod module {
 		ClaimRewardDeductionRateUpdated { pool: PoolId, deduction_rate: Rate },
 		/// Payout deduction currency updated.
 		ClaimRewardDeductionCurrencyUpdated { pool: PoolId, currency: Option<CurrencyId> },
+		/// Claim vesting updated, `None` means the claimed rewards are paid immediately.
+		ClaimRewardVestingUpdated {
+			pool: PoolId,
+			vesting: Option<ClaimVesting<BlockNumberFor<T>>>,
+		},
+		/// Part of the claimed rewards start vesting.
+		RewardsVestingStarted {
+			who: T::AccountId,
+			pool: PoolId,
+			reward_currency_id: CurrencyId,
+			vesting_amount: Balance,
+			end: BlockNumberFor<T>,
+		},
+		/// Claim vested rewards.
+		VestedRewardsClaimed {
+			who: T::AccountId,
+			pool: PoolId,
+			reward_currency_id: CurrencyId,
+			amount: Balance,
+		},
+		/// Deposit protection updated, `None` means no protection.
+		DepositProtectionUpdated {
+			pool: PoolId,
+			protection: Option<DepositProtection<BlockNumberFor<T>>>,
+		},
+		/// The deposited DEX shares reach the minimum age and start to accrue rewards.
+		DepositMatured {
+			who: T::AccountId,
+			pool: PoolId,
+			amount: Balance,
+		},
+		/// Claim preference updated, `None` means the claimed rewards are paid as is.
+		ClaimPreferenceUpdated {
+			who: T::AccountId,
+			pool: PoolId,
+			preference: Option<ClaimPreference>,
+		},
+		/// The claimed rewards are converted into the preferred currency, `supply` are the amounts
+		/// of the claimed rewards swapped.
+		ClaimedRewardsConverted {
+			who: T::AccountId,
+			pool: PoolId,
+			supply: Vec<(CurrencyId, Balance)>,
+			target_currency_id: CurrencyId,
+			target_amount: Balance,
+		},
+		/// The claimed rewards are paid as is because the conversion can't meet the slippage bound.
+		ClaimedRewardConversionSkipped {
+			who: T::AccountId,
+			pool: PoolId,
+			reward_currency_id: CurrencyId,
+			amount: Balance,
+		},
+		/// Withdrawal delay updated, `None` means the DEX shares are withdrawn instantly without fee.
+		WithdrawalDelayUpdated {
+			pool: PoolId,
+			withdrawal_delay: Option<WithdrawalDelay<BlockNumberFor<T>>>,
+		},
+		/// Request to withdraw DEX share, `amount` is the total requested.
+		WithdrawDexShareRequested {
+			who: T::AccountId,
+			dex_share_type: CurrencyId,
+			amount: Balance,
+			unlock_at: BlockNumberFor<T>,
+		},
+		/// The instant exit fee is charged and accumulated to the remaining depositors of the pool.
+		InstantExitFeeCharged {
+			who: T::AccountId,
+			dex_share_type: CurrencyId,
+			fee: Balance,
+		},
+		/// The pool is retired, the stakers can withdraw the shares and claim the rewards until
+		/// `sweep_at`.
+		PoolRetirementStarted { pool: PoolId, sweep_at: BlockNumberFor<T> },
+		/// The unclaimed rewards of the retired pool are swept back to `RewardsSource`.
+		RetiredPoolRewardsSwept {
+			pool: PoolId,
+			rewards: Vec<(CurrencyId, Balance)>,
+		},
+		/// The storage of the retired pool is removed.
+		PoolRetirementCompleted { pool: PoolId },
+		/// The call did nothing, e.g. deposit/withdraw zero DEX share or claim without any
+		/// rewards.
+		NoOp { who: T::AccountId, pool: PoolId },
 	}
 
 	/// Mapping from pool to its fixed incentive amounts of multi currencies per period.
@@ -164,6 +437,97 @@ pub mod module {
 	#[pallet::storage]
 	pub type ClaimRewardDeductionCurrency<T: Config> = StorageMap<_, Twox64Concat, PoolId, CurrencyId, OptionQuery>;
 
+	/// Mapping from pool to its claim vesting configuration.
+	///
+	/// ClaimRewardVestings: map Pool => Option<ClaimVesting>
+	#[pallet::storage]
+	pub type ClaimRewardVestings<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, ClaimVesting<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The claimed rewards which are still vesting. They are not part of the pending rewards.
+	///
+	/// VestingRewards: double_map PoolId, AccountId => BTreeMap<CurrencyId, VestingReward>
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_rewards)]
+	pub type VestingRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Twox64Concat,
+		T::AccountId,
+		BTreeMap<CurrencyId, VestingReward<BlockNumberFor<T>>>,
+		ValueQuery,
+	>;
+
+	/// Mapping from pool to its protection against short-term deposits.
+	///
+	/// DepositProtections: map Pool => Option<DepositProtection>
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_protections)]
+	pub type DepositProtections<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, DepositProtection<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The deposited DEX shares which don't accrue rewards yet, bucketed by the start of the
+	/// window they mature at.
+	///
+	/// MaturingDeposits: double_map PoolId, AccountId => BTreeMap<MatureAt, Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn maturing_deposits)]
+	pub type MaturingDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Twox64Concat,
+		T::AccountId,
+		BTreeMap<BlockNumberFor<T>, Balance>,
+		ValueQuery,
+	>;
+
+	/// The accounts which have deposits maturing at the start of the window.
+	///
+	/// MaturingSchedule: double_map MatureAt, (PoolId, AccountId) => ()
+	#[pallet::storage]
+	pub type MaturingSchedule<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, (PoolId, T::AccountId), (), OptionQuery>;
+
+	/// The start of the last window in which the randomized pool has accumulated.
+	///
+	/// RandomizedAccumulatedWindows: map PoolId => WindowStart
+	#[pallet::storage]
+	pub type RandomizedAccumulatedWindows<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The currency which the accounts prefer to receive the claimed rewards of the pool in.
+	///
+	/// ClaimPreferences: double_map PoolId, AccountId => Option<ClaimPreference>
+	#[pallet::storage]
+	#[pallet::getter(fn claim_preferences)]
+	pub type ClaimPreferences<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PoolId, Twox64Concat, T::AccountId, ClaimPreference, OptionQuery>;
+
+	/// Mapping from pool to its withdrawal delay.
+	///
+	/// WithdrawalDelays: map Pool => Option<WithdrawalDelay>
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_delays)]
+	pub type WithdrawalDelays<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, WithdrawalDelay<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The requested withdrawals of the DEX shares, which don't accrue rewards.
+	///
+	/// PendingWithdrawals: double_map PoolId, AccountId => Option<PendingWithdrawal>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_withdrawals)]
+	pub type PendingWithdrawals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Twox64Concat,
+		T::AccountId,
+		PendingWithdrawal<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	/// The pending rewards amount, actual available rewards amount may be deducted
 	///
 	/// PendingMultiRewards: double_map PoolId, AccountId => BTreeMap<CurrencyId, Balance>
@@ -179,41 +543,83 @@ pub mod module {
 		ValueQuery,
 	>;
 
+	/// The pools being retired, and the retired `PoolId::Loans` pools.
+	///
+	/// RetiringPools: map PoolId => Option<PoolRetirement>
+	#[pallet::storage]
+	#[pallet::getter(fn retiring_pools)]
+	pub type RetiringPools<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, PoolRetirement<BlockNumberFor<T>>, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let window_start = now - now % T::AccumulatePeriod::get();
+			let shutdown = T::EmergencyShutdown::is_shutdown();
+			let mut weight = Weight::zero();
+
+			let mut protections_count: u64 = 0;
+			let mut randomized_pools: Vec<PoolId> = Vec::new();
+			for (pool_id, protection) in DepositProtections::<T>::iter() {
+				protections_count += 1;
+				if protection.randomized_accumulation {
+					randomized_pools.push(pool_id);
+				}
+			}
+			weight = weight.saturating_add(T::DbWeight::get().reads(protections_count));
+
+			let mut count: u32 = 0;
 			// accumulate reward periodically
-			if now % T::AccumulatePeriod::get() == Zero::zero() {
-				let mut count: u32 = 0;
-				let shutdown = T::EmergencyShutdown::is_shutdown();
+			if now == window_start {
+				// the matured deposits accrue rewards from this window
+				let matured_count = Self::mature_deposits(now);
+				weight = weight.saturating_add(T::WeightInfo::mature_deposits(matured_count));
 
 				for (pool_id, pool_info) in orml_rewards::PoolInfos::<T>::iter() {
-					if !pool_info.total_shares.is_zero() {
-						match pool_id {
-							// do not accumulate incentives for PoolId::Loans after shutdown
-							PoolId::Loans(_) if shutdown => {
-								log::debug!(
-									target: "incentives",
-									"on_initialize: skip accumulate incentives for pool {:?} after shutdown",
-									pool_id
-								);
-							}
-							_ => {
-								count += 1;
-								Self::accumulate_incentives(pool_id);
-							}
-						}
+					if !pool_info.total_shares.is_zero()
+						&& !randomized_pools.contains(&pool_id)
+						&& Self::should_accumulate(pool_id, shutdown)
+					{
+						count += 1;
+						Self::accumulate_incentives(pool_id);
 					}
 				}
+			}
 
-				T::WeightInfo::on_initialize(count)
-			} else {
-				Weight::zero()
+			// accumulate reward of the randomized pools once at a random block of the window
+			for pool_id in randomized_pools {
+				if RandomizedAccumulatedWindows::<T>::get(pool_id) == Some(window_start)
+					|| !Self::is_randomized_accumulation_block(&pool_id, now)
+				{
+					continue;
+				}
+				RandomizedAccumulatedWindows::<T>::insert(pool_id, window_start);
+
+				if !orml_rewards::PoolInfos::<T>::get(pool_id).total_shares.is_zero()
+					&& Self::should_accumulate(pool_id, shutdown)
+				{
+					count += 1;
+					Self::accumulate_incentives(pool_id);
+				}
+			}
+
+			if now == window_start || !count.is_zero() {
+				weight = weight.saturating_add(T::WeightInfo::on_initialize(count));
 			}
+
+			let (retiring_count, removed_count) = Self::clean_retired_pools(now);
+			weight = weight.saturating_add(T::DbWeight::get().reads(retiring_count.into()));
+			if !removed_count.is_zero() {
+				weight = weight.saturating_add(T::WeightInfo::clean_retired_pool(removed_count));
+			}
+			weight
 		}
 	}
 
@@ -231,10 +637,19 @@ pub mod module {
 			origin: OriginFor<T>,
 			lp_currency_id: CurrencyId,
 			#[pallet::compact] amount: Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+			if amount.is_zero() {
+				Self::deposit_event(Event::NoOp {
+					who,
+					pool: PoolId::Dex(lp_currency_id),
+				});
+				return Ok(Some(T::WeightInfo::deposit_dex_share_no_op()).into());
+			}
+
 			Self::do_deposit_dex_share(&who, lp_currency_id, amount)?;
-			Ok(())
+			Ok(().into())
 		}
 
 		/// Unstake LP token to remove shares of Pool::Dex
@@ -249,10 +664,19 @@ pub mod module {
 			origin: OriginFor<T>,
 			lp_currency_id: CurrencyId,
 			#[pallet::compact] amount: Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+			if amount.is_zero() {
+				Self::deposit_event(Event::NoOp {
+					who,
+					pool: PoolId::Dex(lp_currency_id),
+				});
+				return Ok(Some(T::WeightInfo::withdraw_dex_share_no_op()).into());
+			}
+
 			Self::do_withdraw_dex_share(&who, lp_currency_id, amount)?;
-			Ok(())
+			Ok(().into())
 		}
 
 		/// Claim all available multi currencies rewards for specific PoolId.
@@ -261,11 +685,26 @@ pub mod module {
 		///
 		/// - `pool_id`: pool type
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
-		pub fn claim_rewards(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards_with_conversion())]
+		pub fn claim_rewards(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			Self::do_claim_rewards(who, pool_id)
+			// nothing can be claimed if there are neither shares nor pending rewards.
+			if <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, &who)
+				.0
+				.is_zero() && PendingMultiRewards::<T>::get(pool_id, &who).values().all(Zero::is_zero)
+			{
+				Self::deposit_event(Event::NoOp { who, pool: pool_id });
+				return Ok(Some(T::WeightInfo::claim_rewards_no_op()).into());
+			}
+
+			let converted = ClaimPreferences::<T>::contains_key(pool_id, &who);
+			Self::do_claim_rewards(who, pool_id)?;
+			if converted {
+				Ok(().into())
+			} else {
+				Ok(Some(T::WeightInfo::claim_rewards()).into())
+			}
 		}
 
 		/// Update incentive reward amount for specific PoolId
@@ -286,26 +725,9 @@ pub mod module {
 				if let PoolId::Dex(currency_id) = pool_id {
 					ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId);
 				}
+				ensure!(!RetiringPools::<T>::contains_key(pool_id), Error::<T>::PoolRetiring);
 
-				for (currency_id, amount) in update_list {
-					IncentiveRewardAmounts::<T>::mutate_exists(pool_id, currency_id, |maybe_amount| {
-						let mut v = maybe_amount.unwrap_or_default();
-						if amount != v {
-							v = amount;
-							Self::deposit_event(Event::IncentiveRewardAmountUpdated {
-								pool: pool_id,
-								reward_currency_id: currency_id,
-								reward_amount_per_period: amount,
-							});
-						}
-
-						if v.is_zero() {
-							*maybe_amount = None;
-						} else {
-							*maybe_amount = Some(v);
-						}
-					});
-				}
+				Self::do_update_incentive_rewards(pool_id, update_list);
 			}
 			Ok(())
 		}
@@ -365,6 +787,317 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Update claim vesting of specific PoolId. Paying 100% immediately or vesting over zero
+		/// blocks removes the vesting. Only affects the future claims.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `pool_id`: pool type
+		/// - `immediate_rate`: the rate of the claimed rewards paid immediately
+		/// - `period`: the number of blocks over which the rest of the claimed rewards vest
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_claim_reward_vesting())]
+		pub fn update_claim_reward_vesting(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			immediate_rate: Rate,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let PoolId::Dex(currency_id) = pool_id {
+				ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId);
+			}
+			ensure!(immediate_rate <= Rate::one(), Error::<T>::InvalidRate);
+
+			let vesting = if immediate_rate == Rate::one() || period.is_zero() {
+				None
+			} else {
+				Some(ClaimVesting { immediate_rate, period })
+			};
+			ClaimRewardVestings::<T>::set(pool_id, vesting);
+			Self::deposit_event(Event::ClaimRewardVestingUpdated { pool: pool_id, vesting });
+			Ok(())
+		}
+
+		/// Claim the vested rewards of all currencies for specific PoolId.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `pool_id`: pool type
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_vested_rewards())]
+		pub fn claim_vested_rewards(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			VestingRewards::<T>::try_mutate_exists(pool_id, &who, |maybe_vesting_rewards| -> DispatchResult {
+				if let Some(vesting_rewards) = maybe_vesting_rewards {
+					for (currency_id, vesting_reward) in vesting_rewards.iter_mut() {
+						let amount = vesting_reward.release(now);
+						if amount.is_zero() {
+							continue;
+						}
+
+						T::Currency::transfer(
+							*currency_id,
+							&Self::account_id(),
+							&who,
+							amount,
+							ExistenceRequirement::AllowDeath,
+						)?;
+						Self::deposit_event(Event::VestedRewardsClaimed {
+							who: who.clone(),
+							pool: pool_id,
+							reward_currency_id: *currency_id,
+							amount,
+						});
+					}
+
+					vesting_rewards.retain(|_, v| !v.is_empty());
+					if vesting_rewards.is_empty() {
+						*maybe_vesting_rewards = None;
+					}
+				}
+				Ok(())
+			})
+		}
+
+		/// Update the deposit protection of specific PoolId, both disabled removes the protection.
+		/// The minimum deposit age only affects the future deposits of DEX shares.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `pool_id`: pool type
+		/// - `min_deposit_age`: the minimum age of deposits before they accrue rewards, only for
+		///   `PoolId::Dex`
+		/// - `randomized_accumulation`: whether to accumulate at a random block of each window
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_deposit_protection())]
+		pub fn update_deposit_protection(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			min_deposit_age: BlockNumberFor<T>,
+			randomized_accumulation: bool,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			match pool_id {
+				PoolId::Dex(currency_id) => {
+					ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId)
+				}
+				_ => ensure!(min_deposit_age.is_zero(), Error::<T>::InvalidPoolId),
+			}
+
+			let previous_randomized = Self::deposit_protections(pool_id).is_some_and(|p| p.randomized_accumulation);
+			if randomized_accumulation && !previous_randomized {
+				// the current window has accumulated at its start
+				let now = frame_system::Pallet::<T>::block_number();
+				RandomizedAccumulatedWindows::<T>::insert(pool_id, now - now % T::AccumulatePeriod::get());
+			} else if !randomized_accumulation {
+				RandomizedAccumulatedWindows::<T>::remove(pool_id);
+			}
+
+			let protection = if min_deposit_age.is_zero() && !randomized_accumulation {
+				None
+			} else {
+				Some(DepositProtection {
+					min_deposit_age,
+					randomized_accumulation,
+				})
+			};
+			DepositProtections::<T>::set(pool_id, protection);
+			Self::deposit_event(Event::DepositProtectionUpdated {
+				pool: pool_id,
+				protection,
+			});
+			Ok(())
+		}
+
+		/// Set the currency which the origin prefers to receive the claimed rewards of specific
+		/// PoolId in, `None` removes the preference.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `pool_id`: pool type
+		/// - `currency_id`: the preferred currency
+		/// - `max_slippage`: the maximum slippage of the conversion compared to the oracle price
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_claim_preference())]
+		pub fn set_claim_preference(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			currency_id: Option<CurrencyId>,
+			max_slippage: Ratio,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(max_slippage <= Ratio::one(), Error::<T>::InvalidRate);
+
+			let preference = currency_id.map(|currency_id| ClaimPreference {
+				currency_id,
+				max_slippage,
+			});
+			ClaimPreferences::<T>::set(pool_id, &who, preference);
+			Self::deposit_event(Event::ClaimPreferenceUpdated {
+				who,
+				pool: pool_id,
+				preference,
+			});
+			Ok(())
+		}
+
+		/// Update the withdrawal delay of specific PoolId. It only affects the future requests,
+		/// zero delay and fee remove it.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `pool_id`: pool type, only `PoolId::Dex`
+		/// - `delay`: the number of blocks from requesting a withdrawal until it's executable
+		/// - `instant_exit_fee`: the fee of withdrawing instantly
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_withdrawal_delay())]
+		pub fn update_withdrawal_delay(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			delay: BlockNumberFor<T>,
+			instant_exit_fee: Permill,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			match pool_id {
+				PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id() => {}
+				_ => return Err(Error::<T>::InvalidPoolId.into()),
+			}
+
+			let withdrawal_delay = if delay.is_zero() && instant_exit_fee.is_zero() {
+				None
+			} else {
+				Some(WithdrawalDelay {
+					delay,
+					instant_exit_fee,
+				})
+			};
+			WithdrawalDelays::<T>::set(pool_id, withdrawal_delay);
+			Self::deposit_event(Event::WithdrawalDelayUpdated {
+				pool: pool_id,
+				withdrawal_delay,
+			});
+			Ok(())
+		}
+
+		/// Request to unstake LP token, which stops accruing rewards immediately and is withdrawn
+		/// by `execute_withdraw_dex_share` after the withdrawal delay of the pool. The requested
+		/// amounts are merged, and the delay restarts.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `lp_currency_id`: LP token type
+		/// - `amount`: amount to unstake
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::request_withdraw_dex_share())]
+		pub fn request_withdraw_dex_share(
+			origin: OriginFor<T>,
+			lp_currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+			let pool_id = PoolId::Dex(lp_currency_id);
+			if amount.is_zero() {
+				Self::deposit_event(Event::NoOp { who, pool: pool_id });
+				return Ok(Some(T::WeightInfo::withdraw_dex_share_no_op()).into());
+			}
+
+			Self::remove_deposit(&who, &pool_id, amount)?;
+
+			let delay = Self::withdrawal_delays(pool_id).map_or_else(Zero::zero, |d| d.delay);
+			let unlock_at = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+			let requested = PendingWithdrawals::<T>::mutate(pool_id, &who, |maybe_pending| {
+				let amount = maybe_pending
+					.map_or(Zero::zero(), |pending| pending.amount)
+					.saturating_add(amount);
+				*maybe_pending = Some(PendingWithdrawal { amount, unlock_at });
+				amount
+			});
+
+			Self::deposit_event(Event::WithdrawDexShareRequested {
+				who,
+				dex_share_type: lp_currency_id,
+				amount: requested,
+				unlock_at,
+			});
+			Ok(().into())
+		}
+
+		/// Withdraw the requested LP token after the withdrawal delay, or at any time in emergency
+		/// shutdown.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `lp_currency_id`: LP token type
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_withdraw_dex_share())]
+		pub fn execute_withdraw_dex_share(origin: OriginFor<T>, lp_currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let pool_id = PoolId::Dex(lp_currency_id);
+			let pending = PendingWithdrawals::<T>::take(pool_id, &who).ok_or(Error::<T>::WithdrawalNotRequested)?;
+			ensure!(
+				pending.unlock_at <= frame_system::Pallet::<T>::block_number() || T::EmergencyShutdown::is_shutdown(),
+				Error::<T>::WithdrawalStillDelayed
+			);
+
+			T::Currency::transfer(
+				lp_currency_id,
+				&Self::account_id(),
+				&who,
+				pending.amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::WithdrawDexShare {
+				who,
+				dex_share_type: lp_currency_id,
+				withdraw: pending.amount,
+			});
+			Ok(())
+		}
+
+		/// Retire specific PoolId. It stops accumulating and accepting DEX shares, the unclaimed
+		/// rewards are swept back to `RewardsSource` and the storage of the pool is removed after
+		/// the grace period. A retired `PoolId::Loans` pool can't be restarted.
+		///
+		/// The dispatch origin of this call must be `RetireOrigin`, or `UpdateOrigin` if the total
+		/// shares of the pool don't exceed `RetirementSharesThreshold`.
+		///
+		/// - `pool_id`: pool type, only `PoolId::Dex` and `PoolId::Loans`
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::retire_pool())]
+		pub fn retire_pool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			match pool_id {
+				PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id() => {}
+				PoolId::Loans(_) => {}
+				_ => return Err(Error::<T>::InvalidPoolId.into()),
+			}
+			if T::RetireOrigin::ensure_origin(origin.clone()).is_err() {
+				T::UpdateOrigin::ensure_origin(origin)?;
+				ensure!(
+					Self::total_shares(&pool_id) <= T::RetirementSharesThreshold::get(),
+					Error::<T>::RetirementSharesExceeded
+				);
+			}
+			ensure!(!RetiringPools::<T>::contains_key(pool_id), Error::<T>::PoolRetiring);
+
+			let update_list = IncentiveRewardAmounts::<T>::iter_prefix(pool_id)
+				.map(|(currency_id, _)| (currency_id, Zero::zero()))
+				.collect();
+			Self::do_update_incentive_rewards(pool_id, update_list);
+
+			let sweep_at = frame_system::Pallet::<T>::block_number().saturating_add(T::RetirementGracePeriod::get());
+			RetiringPools::<T>::insert(pool_id, PoolRetirement::Grace { sweep_at });
+			Self::deposit_event(Event::PoolRetirementStarted {
+				pool: pool_id,
+				sweep_at,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -377,6 +1110,124 @@ impl<T: Config> Pallet<T> {
 		ClaimRewardDeductionRates::<T>::get(pool_id).into_inner()
 	}
 
+	/// The total shares of the pool accruing rewards.
+	pub fn total_shares(pool_id: &PoolId) -> Balance {
+		orml_rewards::PoolInfos::<T>::get(pool_id).total_shares
+	}
+
+	/// The accumulated reward of `reward_currency_id` per share of the pool, which only grows by the
+	/// accumulation as the changes of the shares inflate or deflate the total rewards pro rata.
+	pub fn accumulated_reward_per_share(pool_id: &PoolId, reward_currency_id: CurrencyId) -> Option<Rate> {
+		let pool_info = orml_rewards::PoolInfos::<T>::get(pool_id);
+		let (total_reward, _) = pool_info.rewards.get(&reward_currency_id).copied().unwrap_or_default();
+		Rate::checked_from_rational(total_reward, pool_info.total_shares)
+	}
+
+	fn do_update_incentive_rewards(pool_id: PoolId, update_list: Vec<(CurrencyId, Balance)>) {
+		for (currency_id, amount) in update_list {
+			IncentiveRewardAmounts::<T>::mutate_exists(pool_id, currency_id, |maybe_amount| {
+				let mut v = maybe_amount.unwrap_or_default();
+				if amount != v {
+					v = amount;
+					Self::deposit_event(Event::IncentiveRewardAmountUpdated {
+						pool: pool_id,
+						reward_currency_id: currency_id,
+						reward_amount_per_period: amount,
+					});
+				}
+
+				if v.is_zero() {
+					*maybe_amount = None;
+				} else {
+					*maybe_amount = Some(v);
+				}
+			});
+		}
+	}
+
+	fn should_accumulate(pool_id: PoolId, shutdown: bool) -> bool {
+		match pool_id {
+			// do not accumulate incentives for PoolId::Loans after shutdown
+			PoolId::Loans(_) if shutdown => {
+				log::debug!(
+					target: "incentives",
+					"on_initialize: skip accumulate incentives for pool {:?} after shutdown",
+					pool_id
+				);
+				false
+			}
+			_ => true,
+		}
+	}
+
+	/// Whether the randomized pool accumulates at `now`, if it has not accumulated in the window.
+	/// It's picked with the probability of one over the remaining blocks of the window, and always
+	/// at the last block.
+	pub(crate) fn is_randomized_accumulation_block(pool_id: &PoolId, now: BlockNumberFor<T>) -> bool {
+		let period = T::AccumulatePeriod::get();
+		let remaining: u64 = (period - now % period).unique_saturated_into();
+		if remaining <= 1 {
+			return true;
+		}
+
+		let (random_seed, _) = T::Randomness::random(&(b"incentives/accumulation", pool_id, now).encode());
+		let random_number =
+			u64::decode(&mut TrailingZeroInput::new(random_seed.as_ref())).expect("input is padded with zeroes; qed");
+		random_number % remaining == 0
+	}
+
+	/// The start of the window at which the deposit made now to the pool matures, `None` if the
+	/// pool has no minimum deposit age.
+	fn deposit_mature_at(pool_id: &PoolId) -> Option<BlockNumberFor<T>> {
+		let min_deposit_age = Self::deposit_protections(pool_id)?.min_deposit_age;
+		if min_deposit_age.is_zero() {
+			return None;
+		}
+
+		let period = T::AccumulatePeriod::get();
+		let aged_at = frame_system::Pallet::<T>::block_number().saturating_add(min_deposit_age);
+		let offset = aged_at % period;
+		if offset.is_zero() {
+			Some(aged_at)
+		} else {
+			Some(aged_at.saturating_sub(offset).saturating_add(period))
+		}
+	}
+
+	/// Add the shares of the deposits maturing at `now`, returns the number of accounts processed.
+	fn mature_deposits(now: BlockNumberFor<T>) -> u32 {
+		let mut count: u32 = 0;
+		for ((pool_id, who), _) in MaturingSchedule::<T>::drain_prefix(now) {
+			count += 1;
+
+			let mut deposits = MaturingDeposits::<T>::take(pool_id, &who);
+			let immature_deposits = deposits.split_off(&now.saturating_add(One::one()));
+			let matured_amount = deposits
+				.values()
+				.fold(Zero::zero(), |total: Balance, amount| total.saturating_add(*amount));
+			if !immature_deposits.is_empty() {
+				MaturingDeposits::<T>::insert(pool_id, &who, immature_deposits);
+			}
+
+			if matured_amount.is_zero() {
+				continue;
+			}
+			match <orml_rewards::Pallet<T>>::add_share(&who, &pool_id, matured_amount) {
+				Ok(_) => Self::deposit_event(Event::DepositMatured {
+					who,
+					pool: pool_id,
+					amount: matured_amount,
+				}),
+				Err(e) => log::error!(
+					target: "incentives",
+					"mature_deposits: failed to add {:?} shares of pool {:?} to {:?}: {:?}",
+					matured_amount, pool_id, who, e
+				),
+			}
+		}
+		count
+	}
+
 	// accumulate incentive rewards of multi currencies
 	fn accumulate_incentives(pool_id: PoolId) {
 		for (reward_currency_id, reward_amount) in IncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
@@ -415,13 +1266,19 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn do_claim_rewards(who: T::AccountId, pool_id: PoolId) -> DispatchResult {
+		ensure!(!Self::is_retired(&pool_id), Error::<T>::PoolRetired);
+
 		// orml_rewards will claim rewards for all currencies rewards
 		<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
 
+		let mut paid: Vec<(CurrencyId, Balance)> = vec![];
+
 		PendingMultiRewards::<T>::mutate_exists(pool_id, &who, |maybe_pending_multi_rewards| {
 			if let Some(pending_multi_rewards) = maybe_pending_multi_rewards {
 				let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
 				let deduction_currency = ClaimRewardDeductionCurrency::<T>::get(pool_id);
+				let vesting = ClaimRewardVestings::<T>::get(pool_id);
+				let now = frame_system::Pallet::<T>::block_number();
 
 				for (currency_id, pending_reward) in pending_multi_rewards.iter_mut() {
 					if pending_reward.is_zero() {
@@ -448,6 +1305,15 @@ impl<T: Config> Pallet<T> {
 						)
 					};
 
+					// the vesting part is kept by the pallet account until claimed
+					let (payout_amount, vesting_amount) = match vesting {
+						Some(ClaimVesting { immediate_rate, .. }) => {
+							let immediate_amount = immediate_rate.saturating_mul_int(payout_amount);
+							(immediate_amount, payout_amount.saturating_sub(immediate_amount))
+						}
+						None => (payout_amount, Zero::zero()),
+					};
+
 					// payout reward to claimer and re-accumuated reward.
 					match Self::payout_reward_and_reaccumulate_reward(
 						pool_id,
@@ -459,6 +1325,7 @@ impl<T: Config> Pallet<T> {
 						Ok(_) => {
 							// update state
 							*pending_reward = Zero::zero();
+							paid.push((*currency_id, payout_amount));
 
 							Self::deposit_event(Event::ClaimRewards {
 								who: who.clone(),
@@ -467,6 +1334,21 @@ impl<T: Config> Pallet<T> {
 								actual_amount: payout_amount,
 								deduction_amount,
 							});
+
+							if let Some(ClaimVesting { period, .. }) = vesting.filter(|_| !vesting_amount.is_zero()) {
+								let end = VestingRewards::<T>::mutate(pool_id, &who, |vesting_rewards| {
+									let vesting_reward = vesting_rewards.entry(*currency_id).or_default();
+									vesting_reward.add(vesting_amount, now, now.saturating_add(period));
+									vesting_reward.end
+								});
+								Self::deposit_event(Event::RewardsVestingStarted {
+									who: who.clone(),
+									pool: pool_id,
+									reward_currency_id: *currency_id,
+									vesting_amount,
+									end,
+								});
+							}
 						}
 						Err(e) => {
 							log::error!(
@@ -488,9 +1370,77 @@ impl<T: Config> Pallet<T> {
 			}
 		});
 
+		if let Some(preference) = ClaimPreferences::<T>::get(pool_id, &who) {
+			Self::convert_claimed_rewards(&who, pool_id, preference, paid);
+		}
+
 		Ok(())
 	}
 
+	/// Swap the rewards `paid` to `who` into the preferred currency, the rewards which can't be
+	/// swapped within the slippage bound are kept as is.
+	fn convert_claimed_rewards(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		preference: ClaimPreference,
+		paid: Vec<(CurrencyId, Balance)>,
+	) {
+		let mut supply: Vec<(CurrencyId, Balance)> = vec![];
+		let mut target_amount: Balance = Zero::zero();
+
+		for (currency_id, amount) in paid {
+			if currency_id == preference.currency_id || amount.is_zero() {
+				continue;
+			}
+
+			match Self::swap_claimed_reward(who, currency_id, amount, preference) {
+				Ok((actual_supply, actual_target)) => {
+					supply.push((currency_id, actual_supply));
+					target_amount = target_amount.saturating_add(actual_target);
+				}
+				Err(_) => Self::deposit_event(Event::ClaimedRewardConversionSkipped {
+					who: who.clone(),
+					pool: pool_id,
+					reward_currency_id: currency_id,
+					amount,
+				}),
+			}
+		}
+
+		if !supply.is_empty() {
+			Self::deposit_event(Event::ClaimedRewardsConverted {
+				who: who.clone(),
+				pool: pool_id,
+				supply,
+				target_currency_id: preference.currency_id,
+				target_amount,
+			});
+		}
+	}
+
+	/// Ensure atomic
+	#[transactional]
+	fn swap_claimed_reward(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		preference: ClaimPreference,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		let price =
+			T::PriceSource::get_relative_price(currency_id, preference.currency_id).ok_or(Error::<T>::NoPrice)?;
+		let min_target_amount = Ratio::one()
+			.saturating_sub(preference.max_slippage)
+			.saturating_mul(price)
+			.saturating_mul_int(amount);
+
+		T::Swap::swap(
+			who,
+			currency_id,
+			preference.currency_id,
+			SwapLimit::ExactSupply(amount, min_target_amount),
+		)
+	}
+
 	/// Ensure atomic
 	#[transactional]
 	fn payout_reward_and_reaccumulate_reward(
@@ -512,11 +1462,263 @@ impl<T: Config> Pallet<T> {
 		)?;
 		Ok(())
 	}
+
+	/// Remove `amount` of the DEX shares deposited by `who` from the pool, the youngest deposits
+	/// first. The DEX shares stay in the module account.
+	fn remove_deposit(who: &T::AccountId, pool_id: &PoolId, amount: Balance) -> DispatchResult {
+		// the DEX shares are refunded while the storage of the retired pool is removed
+		ensure!(!Self::is_retired(pool_id), Error::<T>::PoolRetired);
+		let mut maturing_deposits = MaturingDeposits::<T>::get(pool_id, who);
+		let maturing_amount = maturing_deposits
+			.values()
+			.fold(Zero::zero(), |total: Balance, amount| total.saturating_add(*amount));
+		ensure!(
+			<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(pool_id, who)
+				.0
+				.saturating_add(maturing_amount)
+				>= amount,
+			Error::<T>::NotEnough,
+		);
+
+		let mut remaining = amount;
+		for deposit in maturing_deposits.values_mut().rev() {
+			let withdrawn = remaining.min(*deposit);
+			*deposit -= withdrawn;
+			remaining -= withdrawn;
+			if remaining.is_zero() {
+				break;
+			}
+		}
+		if !maturing_amount.is_zero() {
+			maturing_deposits.retain(|_, deposit| !deposit.is_zero());
+			MaturingDeposits::<T>::set(pool_id, who, maturing_deposits);
+		}
+		if !remaining.is_zero() {
+			<orml_rewards::Pallet<T>>::remove_share(who, pool_id, remaining.unique_saturated_into())?;
+		}
+		Ok(())
+	}
+
+	/// The instant exit fee of withdrawing `amount` of the DEX shares from the pool, zero in
+	/// emergency shutdown or if there are no remaining depositors to share it.
+	fn instant_exit_fee(pool_id: &PoolId, amount: Balance) -> Balance {
+		let Some(withdrawal_delay) = Self::withdrawal_delays(pool_id) else {
+			return Zero::zero();
+		};
+		if T::EmergencyShutdown::is_shutdown() || orml_rewards::PoolInfos::<T>::get(pool_id).total_shares.is_zero() {
+			return Zero::zero();
+		}
+		withdrawal_delay.instant_exit_fee.mul_floor(amount)
+	}
+
+	/// Whether the grace period of the retired pool is over.
+	pub(crate) fn is_retired(pool_id: &PoolId) -> bool {
+		matches!(
+			RetiringPools::<T>::get(pool_id),
+			Some(PoolRetirement::Sweeping | PoolRetirement::Retired)
+		)
+	}
+
+	/// Sweep the retired pools after the grace period and remove their storage, at most
+	/// `MaxRetirementRemovals` entries in total. Returns the number of the retiring pools and of
+	/// the entries removed.
+	fn clean_retired_pools(now: BlockNumberFor<T>) -> (u32, u32) {
+		let max_removals = T::MaxRetirementRemovals::get();
+		let mut retiring_count: u32 = 0;
+		let mut removed_count: u32 = 0;
+
+		for (pool_id, retirement) in RetiringPools::<T>::iter().collect::<Vec<_>>() {
+			retiring_count += 1;
+			if removed_count >= max_removals {
+				break;
+			}
+
+			match retirement {
+				PoolRetirement::Grace { sweep_at } if now < sweep_at => continue,
+				PoolRetirement::Grace { .. } => {
+					Self::sweep_retired_pool(pool_id);
+					RetiringPools::<T>::insert(pool_id, PoolRetirement::Sweeping);
+					removed_count += 1;
+				}
+				PoolRetirement::Sweeping => {}
+				PoolRetirement::Retired => continue,
+			}
+
+			let limit = max_removals.saturating_sub(removed_count);
+			let removed = Self::remove_retired_pool_entries(pool_id, limit);
+			removed_count = removed_count.saturating_add(removed);
+			if removed < limit {
+				if matches!(pool_id, PoolId::Loans(_)) {
+					// the shares of the open loans would be updated from zero if the pool was restarted
+					RetiringPools::<T>::insert(pool_id, PoolRetirement::Retired);
+				} else {
+					RetiringPools::<T>::remove(pool_id);
+				}
+				Self::deposit_event(Event::PoolRetirementCompleted { pool: pool_id });
+			}
+		}
+		(retiring_count, removed_count)
+	}
+
+	/// Sweep the rewards of the retired pool which are not withdrawn by the stakers, and remove
+	/// the pool wide storage.
+	fn sweep_retired_pool(pool_id: PoolId) {
+		let pool_info = orml_rewards::PoolInfos::<T>::take(pool_id);
+		let unclaimed = pool_info
+			.rewards
+			.into_iter()
+			.map(|(currency_id, (total_reward, total_withdrawn_reward))| {
+				(currency_id, total_reward.saturating_sub(total_withdrawn_reward))
+			})
+			.collect();
+		Self::sweep_rewards(pool_id, unclaimed);
+
+		let _ = IncentiveRewardAmounts::<T>::clear_prefix(pool_id, u32::MAX, None);
+		ClaimRewardDeductionRates::<T>::remove(pool_id);
+		ClaimRewardDeductionCurrency::<T>::remove(pool_id);
+		ClaimRewardVestings::<T>::remove(pool_id);
+		DepositProtections::<T>::remove(pool_id);
+		RandomizedAccumulatedWindows::<T>::remove(pool_id);
+		WithdrawalDelays::<T>::remove(pool_id);
+	}
+
+	/// Remove at most `limit` per account entries of the retired pool, refunds the DEX shares
+	/// still staked and sweeps the pending rewards. Returns the number of the entries removed.
+	fn remove_retired_pool_entries(pool_id: PoolId, limit: u32) -> u32 {
+		let mut removed: u32 = 0;
+		let mut pending_rewards: BTreeMap<CurrencyId, Balance> = BTreeMap::new();
+
+		for (who, (share, _)) in
+			orml_rewards::SharesAndWithdrawnRewards::<T>::drain_prefix(pool_id).take(limit as usize)
+		{
+			removed += 1;
+			Self::refund_dex_share(&pool_id, &who, share);
+		}
+		for (who, deposits) in MaturingDeposits::<T>::drain_prefix(pool_id).take(limit.saturating_sub(removed) as usize)
+		{
+			removed += 1;
+			let amount = deposits
+				.values()
+				.fold(Zero::zero(), |total: Balance, amount| total.saturating_add(*amount));
+			Self::refund_dex_share(&pool_id, &who, amount);
+		}
+		for (who, pending) in
+			PendingWithdrawals::<T>::drain_prefix(pool_id).take(limit.saturating_sub(removed) as usize)
+		{
+			removed += 1;
+			Self::refund_dex_share(&pool_id, &who, pending.amount);
+		}
+		for (_, rewards) in PendingMultiRewards::<T>::drain_prefix(pool_id).take(limit.saturating_sub(removed) as usize)
+		{
+			removed += 1;
+			for (currency_id, amount) in rewards {
+				let total = pending_rewards.entry(currency_id).or_default();
+				*total = total.saturating_add(amount);
+			}
+		}
+		removed += ClaimPreferences::<T>::drain_prefix(pool_id)
+			.take(limit.saturating_sub(removed) as usize)
+			.count() as u32;
+
+		Self::sweep_rewards(pool_id, pending_rewards.into_iter().collect());
+		removed
+	}
+
+	/// Transfer the DEX shares staked by `who` into the retired pool back.
+	fn refund_dex_share(pool_id: &PoolId, who: &T::AccountId, amount: Balance) {
+		let PoolId::Dex(lp_currency_id) = pool_id else {
+			return;
+		};
+		if amount.is_zero() {
+			return;
+		}
+
+		match T::Currency::transfer(
+			*lp_currency_id,
+			&Self::account_id(),
+			who,
+			amount,
+			ExistenceRequirement::AllowDeath,
+		) {
+			Ok(_) => Self::deposit_event(Event::WithdrawDexShare {
+				who: who.clone(),
+				dex_share_type: *lp_currency_id,
+				withdraw: amount,
+			}),
+			Err(e) => log::error!(
+				target: "incentives",
+				"refund_dex_share: failed to refund {:?} {:?} to {:?}: {:?}",
+				amount, lp_currency_id, who, e
+			),
+		}
+	}
+
+	/// Transfer the `rewards` of the retired pool back to `RewardsSource`.
+	fn sweep_rewards(pool_id: PoolId, rewards: Vec<(CurrencyId, Balance)>) {
+		let mut swept: Vec<(CurrencyId, Balance)> = vec![];
+		for (currency_id, amount) in rewards {
+			if amount.is_zero() {
+				continue;
+			}
+
+			match T::Currency::transfer(
+				currency_id,
+				&Self::account_id(),
+				&T::RewardsSource::get(),
+				amount,
+				ExistenceRequirement::AllowDeath,
+			) {
+				Ok(_) => swept.push((currency_id, amount)),
+				Err(e) => log::error!(
+					target: "incentives",
+					"sweep_rewards: failed to sweep {:?} {:?} of pool {:?}: {:?}",
+					amount, currency_id, pool_id, e
+				),
+			}
+		}
+
+		if !swept.is_empty() {
+			Self::deposit_event(Event::RetiredPoolRewardsSwept {
+				pool: pool_id,
+				rewards: swept,
+			});
+		}
+	}
+}
+
+impl<T: Config> OnTradingPairEnabled<TradingPair, TradingPairListing<CurrencyId, Balance, Moment>> for Pallet<T> {
+	fn on_trading_pair_enabled(
+		trading_pair: &TradingPair,
+		listing: Option<&TradingPairListing<CurrencyId, Balance, Moment>>,
+	) -> DispatchResult {
+		if let Some(listing) = listing {
+			Self::do_update_incentive_rewards(
+				PoolId::Dex(trading_pair.dex_share_currency_id()),
+				listing.incentive_rewards.clone(),
+			);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config> OnTradingPairDisabled<TradingPair> for Pallet<T> {
+	fn on_trading_pair_disabled(trading_pair: &TradingPair) -> DispatchResult {
+		// stop accumulating incentive rewards from the next period, the rewards already
+		// accumulated are still claimable.
+		let pool_id = PoolId::Dex(trading_pair.dex_share_currency_id());
+		let update_list = IncentiveRewardAmounts::<T>::iter_prefix(pool_id)
+			.map(|(currency_id, _)| (currency_id, Zero::zero()))
+			.collect();
+		Self::do_update_incentive_rewards(pool_id, update_list);
+		Ok(())
+	}
 }
 
 impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 	fn do_deposit_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
 		ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+		let pool_id = PoolId::Dex(lp_currency_id);
+		ensure!(!RetiringPools::<T>::contains_key(pool_id), Error::<T>::PoolRetiring);
 
 		T::Currency::transfer(
 			lp_currency_id,
@@ -525,7 +1727,17 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 			amount,
 			ExistenceRequirement::AllowDeath,
 		)?;
-		<orml_rewards::Pallet<T>>::add_share(who, &PoolId::Dex(lp_currency_id), amount.unique_saturated_into())?;
+
+		if let Some(mature_at) = Self::deposit_mature_at(&pool_id) {
+			// accrue rewards after it reaches the minimum age
+			MaturingDeposits::<T>::mutate(pool_id, who, |deposits| {
+				let deposit = deposits.entry(mature_at).or_default();
+				*deposit = deposit.saturating_add(amount);
+			});
+			MaturingSchedule::<T>::insert(mature_at, (pool_id, who.clone()), ());
+		} else {
+			<orml_rewards::Pallet<T>>::add_share(who, &pool_id, amount.unique_saturated_into())?;
+		}
 
 		Self::deposit_event(Event::DepositDexShare {
 			who: who.clone(),
@@ -535,28 +1747,41 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		Ok(())
 	}
 
-	fn do_withdraw_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+	fn do_withdraw_dex_share(
+		who: &T::AccountId,
+		lp_currency_id: CurrencyId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
 		ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
-		ensure!(
-			<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&PoolId::Dex(lp_currency_id), &who).0 >= amount,
-			Error::<T>::NotEnough,
-		);
+		let pool_id = PoolId::Dex(lp_currency_id);
+		Self::remove_deposit(who, &pool_id, amount)?;
+
+		let fee = Self::instant_exit_fee(&pool_id, amount);
+		if !fee.is_zero() {
+			// the fee stays in the module account as the rewards of the remaining depositors
+			<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, lp_currency_id, fee)?;
+			Self::deposit_event(Event::InstantExitFeeCharged {
+				who: who.clone(),
+				dex_share_type: lp_currency_id,
+				fee,
+			});
+		}
 
+		let withdraw = amount.saturating_sub(fee);
 		T::Currency::transfer(
 			lp_currency_id,
 			&Self::account_id(),
 			who,
-			amount,
+			withdraw,
 			ExistenceRequirement::AllowDeath,
 		)?;
-		<orml_rewards::Pallet<T>>::remove_share(who, &PoolId::Dex(lp_currency_id), amount.unique_saturated_into())?;
 
 		Self::deposit_event(Event::WithdrawDexShare {
 			who: who.clone(),
 			dex_share_type: lp_currency_id,
-			withdraw: amount,
+			withdraw,
 		});
-		Ok(())
+		Ok(withdraw)
 	}
 }
 
@@ -570,7 +1795,7 @@ impl<T: Config> IncentivesManager<T::AccountId, Balance, CurrencyId, PoolId> for
 	}
 
 	fn withdraw_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
-		Self::do_withdraw_dex_share(who, lp_currency_id, amount)
+		Self::do_withdraw_dex_share(who, lp_currency_id, amount).map(|_| ())
 	}
 
 	fn claim_rewards(who: T::AccountId, pool_id: PoolId) -> DispatchResult {
@@ -595,7 +1820,13 @@ impl<T: Config> IncentivesManager<T::AccountId, Balance, CurrencyId, PoolId> for
 pub struct OnUpdateLoan<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Handler<(T::AccountId, CurrencyId, Amount, Balance)> for OnUpdateLoan<T> {
 	fn handle(info: &(T::AccountId, CurrencyId, Amount, Balance)) -> DispatchResult {
+		// the previous amount is of the updated position only, the share of the account is the
+		// collateral of all its positions under the collateral type, so only the adjustment is used
 		let (who, currency_id, adjustment, _previous_amount) = info;
+		// the shares of the retired pool are removed and not updated any more
+		if Pallet::<T>::is_retired(&PoolId::Loans(*currency_id)) {
+			return Ok(());
+		}
 		let adjustment_abs = TryInto::<Balance>::try_into(adjustment.saturating_abs()).unwrap_or_default();
 
 		if adjustment.is_positive() {
@@ -23,14 +23,17 @@
 use super::*;
 use frame_support::{
 	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU64, Nothing},
+	traits::{ConstU128, ConstU32, ConstU64, Nothing},
 };
-use frame_system::EnsureSignedBy;
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use module_support::{AggregatedSwapPath, Price};
 use orml_traits::parameter_type_with_key;
 use primitives::{DexShare, TokenSymbol};
+use sp_core::H256;
 use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage};
 
 pub type AccountId = AccountId32;
+pub type BlockNumber = u64;
 
 pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
@@ -101,6 +104,72 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+/// Randomness varying with the subject, so with the block number of the subject.
+pub struct MockRandomness;
+impl Randomness<H256, BlockNumber> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumber) {
+		(H256::from(sp_io::hashing::blake2_256(subject)), System::block_number())
+	}
+}
+
+parameter_types! {
+	pub static OraclePrices: Vec<(CurrencyId, Price)> = vec![];
+	pub static SwapRates: Vec<((CurrencyId, CurrencyId), Ratio)> = vec![];
+}
+
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		OraclePrices::get()
+			.into_iter()
+			.find(|(id, _)| *id == currency_id)
+			.map(|(_, price)| price)
+	}
+}
+
+/// Swap at the fixed rates of `SwapRates`, only supports `ExactSupply`.
+pub struct MockSwap;
+impl Swap<AccountId, Balance, CurrencyId> for MockSwap {
+	fn get_swap_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
+		let rate = SwapRates::get()
+			.into_iter()
+			.find(|(pair, _)| *pair == (supply_currency_id, target_currency_id))
+			.map(|(_, rate)| rate)?;
+		match limit {
+			SwapLimit::ExactSupply(supply_amount, min_target_amount) => {
+				let target_amount = rate.saturating_mul_int(supply_amount);
+				(target_amount >= min_target_amount).then_some((supply_amount, target_amount))
+			}
+			SwapLimit::ExactTarget(..) => unimplemented!(),
+		}
+	}
+
+	fn swap(
+		who: &AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		let (supply_amount, target_amount) = Self::get_swap_amount(supply_currency_id, target_currency_id, limit)
+			.ok_or(DispatchError::Other("cannot swap"))?;
+		TokensModule::withdraw(supply_currency_id, who, supply_amount)?;
+		TokensModule::deposit(target_currency_id, who, target_amount)?;
+		Ok((supply_amount, target_amount))
+	}
+
+	fn swap_by_aggregated_path(
+		_who: &AccountId,
+		_swap_path: &[AggregatedSwapPath<CurrencyId>],
+		_limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		unimplemented!()
+	}
+}
+
 parameter_type_with_key! {
 	pub MinimalShares: |_pool_id: PoolId| -> Balance {
 		0
@@ -134,6 +203,13 @@ impl Config for Runtime {
 	type Currency = TokensModule;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type Randomness = MockRandomness;
+	type Swap = MockSwap;
+	type PriceSource = MockPriceSource;
+	type RetirementGracePeriod = ConstU64<20>;
+	type RetirementSharesThreshold = ConstU128<1_000>;
+	type RetireOrigin = EnsureRoot<AccountId>;
+	type MaxRetirementRemovals = ConstU32<3>;
 	type WeightInfo = ();
 }
 
@@ -23,9 +23,10 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{RuntimeEvent, *};
+use module_support::Price;
 use orml_rewards::PoolInfo;
 use orml_traits::MultiCurrency;
-use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+use sp_runtime::{traits::BadOrigin, FixedPointNumber, Permill};
 
 #[test]
 fn deposit_dex_share_works() {
@@ -233,6 +234,43 @@ fn update_incentive_rewards_works() {
 	});
 }
 
+#[test]
+fn trading_pair_hooks_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let trading_pair = TradingPair::from_currency_ids(DOT, AUSD).unwrap();
+		let pool_id = PoolId::Dex(trading_pair.dex_share_currency_id());
+		let listing = TradingPairListing {
+			average_price_interval: None,
+			incentive_rewards: vec![(ACA, 1000), (DOT, 100)],
+		};
+
+		assert_ok!(IncentivesModule::on_trading_pair_enabled(&trading_pair, None));
+		assert_eq!(IncentivesModule::incentive_reward_amounts(pool_id, ACA), 0);
+
+		assert_ok!(IncentivesModule::on_trading_pair_enabled(&trading_pair, Some(&listing)));
+		assert_eq!(IncentivesModule::incentive_reward_amounts(pool_id, ACA), 1000);
+		assert_eq!(IncentivesModule::incentive_reward_amounts(pool_id, DOT), 100);
+		System::assert_has_event(RuntimeEvent::IncentivesModule(
+			crate::Event::IncentiveRewardAmountUpdated {
+				pool: pool_id,
+				reward_currency_id: ACA,
+				reward_amount_per_period: 1000,
+			},
+		));
+
+		assert_ok!(IncentivesModule::on_trading_pair_disabled(&trading_pair));
+		assert_eq!(IncentiveRewardAmounts::<Runtime>::contains_key(pool_id, ACA), false);
+		assert_eq!(IncentiveRewardAmounts::<Runtime>::contains_key(pool_id, DOT), false);
+		System::assert_has_event(RuntimeEvent::IncentivesModule(
+			crate::Event::IncentiveRewardAmountUpdated {
+				pool: pool_id,
+				reward_currency_id: DOT,
+				reward_amount_per_period: 0,
+			},
+		));
+	});
+}
+
 #[test]
 fn update_claim_reward_deduction_rates_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -630,6 +668,74 @@ fn transfer_failed_when_claim_rewards() {
 	});
 }
 
+#[test]
+fn no_op_calls_refund_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			IncentivesModule::deposit_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC, 0),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		let post_info =
+			IncentivesModule::deposit_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP, 0).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::deposit_dex_share_no_op())
+		);
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::NoOp {
+			who: ALICE::get(),
+			pool: PoolId::Dex(BTC_AUSD_LP),
+		}));
+
+		let post_info =
+			IncentivesModule::withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP, 0).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::withdraw_dex_share_no_op())
+		);
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::NoOp {
+			who: ALICE::get(),
+			pool: PoolId::Dex(BTC_AUSD_LP),
+		}));
+
+		// no shares and no pending rewards
+		let post_info =
+			IncentivesModule::claim_rewards(RuntimeOrigin::signed(ALICE::get()), PoolId::Loans(BTC)).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::claim_rewards_no_op()));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::NoOp {
+			who: ALICE::get(),
+			pool: PoolId::Loans(BTC),
+		}));
+
+		// pending rewards can be claimed even without shares
+		PendingMultiRewards::<Runtime>::mutate(PoolId::Loans(BTC), ALICE::get(), |pending| {
+			pending.insert(ACA, 100);
+		});
+		assert_ok!(TokensModule::deposit(ACA, &IncentivesModule::account_id(), 100));
+		let post_info =
+			IncentivesModule::claim_rewards(RuntimeOrigin::signed(ALICE::get()), PoolId::Loans(BTC)).unwrap();
+		assert_eq!(post_info.actual_weight, None);
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimRewards {
+			who: ALICE::get(),
+			pool: PoolId::Loans(BTC),
+			reward_currency_id: ACA,
+			actual_amount: 100,
+			deduction_amount: 0,
+		}));
+
+		// deposit and withdraw non-zero amount are charged the full weight
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 10000));
+		let post_info =
+			IncentivesModule::deposit_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP, 10000).unwrap();
+		assert_eq!(post_info.actual_weight, None);
+		let post_info =
+			IncentivesModule::withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP, 10000).unwrap();
+		assert_eq!(post_info.actual_weight, None);
+	});
+}
+
 #[test]
 fn claim_rewards_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1384,3 +1490,1438 @@ fn nominees_election_should_work() {
 		);
 	});
 }
+
+#[test]
+fn update_claim_reward_vesting_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Loans(DOT);
+
+		assert_noop!(
+			IncentivesModule::update_claim_reward_vesting(
+				RuntimeOrigin::signed(ALICE::get()),
+				pool_id,
+				Rate::saturating_from_rational(40, 100),
+				100
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_claim_reward_vesting(
+				RuntimeOrigin::signed(ROOT::get()),
+				PoolId::Dex(DOT),
+				Rate::saturating_from_rational(40, 100),
+				100
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_claim_reward_vesting(
+				RuntimeOrigin::signed(ROOT::get()),
+				pool_id,
+				Rate::saturating_from_rational(101, 100),
+				100
+			),
+			Error::<Runtime>::InvalidRate
+		);
+
+		let vesting = ClaimVesting {
+			immediate_rate: Rate::saturating_from_rational(40, 100),
+			period: 100,
+		};
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::saturating_from_rational(40, 100),
+			100
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(
+			crate::Event::ClaimRewardVestingUpdated {
+				pool: pool_id,
+				vesting: Some(vesting),
+			},
+		));
+		assert_eq!(ClaimRewardVestings::<Runtime>::get(pool_id), Some(vesting));
+
+		// paying all immediately removes the vesting
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::one(),
+			100
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(
+			crate::Event::ClaimRewardVestingUpdated {
+				pool: pool_id,
+				vesting: None,
+			},
+		));
+		assert_eq!(ClaimRewardVestings::<Runtime>::get(pool_id), None);
+
+		// vesting over zero blocks removes the vesting
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::saturating_from_rational(40, 100),
+			100
+		));
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::saturating_from_rational(40, 100),
+			0
+		));
+		assert_eq!(ClaimRewardVestings::<Runtime>::get(pool_id), None);
+	});
+}
+
+#[test]
+fn claim_vested_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::Loans(DOT);
+
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::saturating_from_rational(40, 100),
+			100
+		));
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(TokensModule::deposit(LDOT, &VAULT::get(), 10000));
+		assert_ok!(RewardsModule::add_share(&ALICE::get(), &pool_id, 100));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 1000));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, LDOT, 2000));
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimRewards {
+			who: ALICE::get(),
+			pool: pool_id,
+			reward_currency_id: ACA,
+			actual_amount: 400,
+			deduction_amount: 0,
+		}));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::RewardsVestingStarted {
+			who: ALICE::get(),
+			pool: pool_id,
+			reward_currency_id: ACA,
+			vesting_amount: 600,
+			end: 101,
+		}));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::RewardsVestingStarted {
+			who: ALICE::get(),
+			pool: pool_id,
+			reward_currency_id: LDOT,
+			vesting_amount: 1200,
+			end: 101,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 400);
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 800);
+		assert_eq!(
+			IncentivesModule::vesting_rewards(pool_id, ALICE::get()).get(&ACA),
+			Some(&VestingReward {
+				unlocked: 0,
+				locked: 600,
+				start: 1,
+				end: 101,
+			})
+		);
+
+		// the vesting rewards are not pending rewards
+		assert_eq!(
+			IncentivesModule::get_pending_rewards(pool_id, ALICE::get(), vec![ACA, LDOT]),
+			vec![0, 0]
+		);
+
+		// nothing vested yet
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 400);
+
+		System::set_block_number(21);
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::VestedRewardsClaimed {
+			who: ALICE::get(),
+			pool: pool_id,
+			reward_currency_id: ACA,
+			amount: 120,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 520);
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 1040);
+
+		System::set_block_number(61);
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 760);
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 1520);
+
+		System::set_block_number(200);
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 1000);
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 2000);
+		assert!(!VestingRewards::<Runtime>::contains_key(pool_id, ALICE::get()));
+	});
+}
+
+#[test]
+fn claim_vesting_merges_streams_when_config_changes() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::Loans(DOT);
+
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::saturating_from_rational(50, 100),
+			100
+		));
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(RewardsModule::add_share(&ALICE::get(), &pool_id, 100));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 1000));
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 500);
+
+		// shorten the vesting mid-stream, the running stream is not affected
+		System::set_block_number(51);
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::saturating_from_rational(50, 100),
+			20
+		));
+		assert_eq!(
+			IncentivesModule::vesting_rewards(pool_id, ALICE::get()).get(&ACA),
+			Some(&VestingReward {
+				unlocked: 0,
+				locked: 500,
+				start: 1,
+				end: 101,
+			})
+		);
+
+		// the new vesting amount is merged into the running stream
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 1000));
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::RewardsVestingStarted {
+			who: ALICE::get(),
+			pool: pool_id,
+			reward_currency_id: ACA,
+			vesting_amount: 500,
+			end: 101,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 1000);
+		assert_eq!(
+			IncentivesModule::vesting_rewards(pool_id, ALICE::get()).get(&ACA),
+			Some(&VestingReward {
+				unlocked: 250,
+				locked: 750,
+				start: 51,
+				end: 101,
+			})
+		);
+
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 1250);
+
+		System::set_block_number(76);
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 1625);
+
+		System::set_block_number(101);
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 2000);
+		assert!(!VestingRewards::<Runtime>::contains_key(pool_id, ALICE::get()));
+
+		// removing the vesting only affects the future claims
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::one(),
+			0
+		));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 1000));
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 3000);
+		assert!(!VestingRewards::<Runtime>::contains_key(pool_id, ALICE::get()));
+	});
+}
+
+#[test]
+fn claim_vesting_applies_after_deduction() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = PoolId::Loans(DOT);
+
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			RuntimeOrigin::signed(ROOT::get()),
+			vec![(pool_id, Rate::saturating_from_rational(20, 100))]
+		));
+		assert_ok!(IncentivesModule::update_claim_reward_vesting(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			Rate::saturating_from_rational(50, 100),
+			100
+		));
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(RewardsModule::add_share(&ALICE::get(), &pool_id, 100));
+		assert_ok!(RewardsModule::add_share(&BOB::get(), &pool_id, 100));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 2000));
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimRewards {
+			who: ALICE::get(),
+			pool: pool_id,
+			reward_currency_id: ACA,
+			actual_amount: 400,
+			deduction_amount: 200,
+		}));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::RewardsVestingStarted {
+			who: ALICE::get(),
+			pool: pool_id,
+			reward_currency_id: ACA,
+			vesting_amount: 400,
+			end: 101,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 400);
+
+		// the deduction can be removed per pool, the vesting still applies
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			RuntimeOrigin::signed(ROOT::get()),
+			vec![(pool_id, Rate::zero())]
+		));
+		assert!(!ClaimRewardDeductionRates::<Runtime>::contains_key(pool_id));
+
+		// bob gets half of the re-accumulated deduction
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(BOB::get()),
+			pool_id
+		));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimRewards {
+			who: BOB::get(),
+			pool: pool_id,
+			reward_currency_id: ACA,
+			actual_amount: 550,
+			deduction_amount: 0,
+		}));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::RewardsVestingStarted {
+			who: BOB::get(),
+			pool: pool_id,
+			reward_currency_id: ACA,
+			vesting_amount: 550,
+			end: 101,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 550);
+
+		System::set_block_number(101);
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_ok!(IncentivesModule::claim_vested_rewards(
+			RuntimeOrigin::signed(BOB::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 800);
+		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 1100);
+	});
+}
+
+#[test]
+fn update_deposit_protection_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			IncentivesModule::update_deposit_protection(
+				RuntimeOrigin::signed(ALICE::get()),
+				PoolId::Dex(BTC_AUSD_LP),
+				15,
+				true
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_deposit_protection(
+				RuntimeOrigin::signed(ROOT::get()),
+				PoolId::Loans(BTC),
+				15,
+				false
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_deposit_protection(RuntimeOrigin::signed(ROOT::get()), PoolId::Dex(BTC), 0, true),
+			Error::<Runtime>::InvalidPoolId
+		);
+
+		System::set_block_number(13);
+		assert_ok!(IncentivesModule::update_deposit_protection(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			15,
+			true
+		));
+		let protection = DepositProtection {
+			min_deposit_age: 15,
+			randomized_accumulation: true,
+		};
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::DepositProtectionUpdated {
+			pool: PoolId::Dex(BTC_AUSD_LP),
+			protection: Some(protection),
+		}));
+		assert_eq!(
+			IncentivesModule::deposit_protections(PoolId::Dex(BTC_AUSD_LP)),
+			Some(protection)
+		);
+		// the current window has accumulated at its start
+		assert_eq!(
+			RandomizedAccumulatedWindows::<Runtime>::get(PoolId::Dex(BTC_AUSD_LP)),
+			Some(10)
+		);
+
+		// randomized accumulation is allowed for all pools
+		assert_ok!(IncentivesModule::update_deposit_protection(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Loans(BTC),
+			0,
+			true
+		));
+
+		assert_ok!(IncentivesModule::update_deposit_protection(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			0,
+			false
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::DepositProtectionUpdated {
+			pool: PoolId::Dex(BTC_AUSD_LP),
+			protection: None,
+		}));
+		assert_eq!(IncentivesModule::deposit_protections(PoolId::Dex(BTC_AUSD_LP)), None);
+		assert_eq!(
+			RandomizedAccumulatedWindows::<Runtime>::get(PoolId::Dex(BTC_AUSD_LP)),
+			None
+		);
+	});
+}
+
+#[test]
+fn deposit_accrues_rewards_after_min_deposit_age() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokensModule::deposit(ACA, &RewardsSource::get(), 10000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 10000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &BOB::get(), 10000));
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			RuntimeOrigin::signed(ROOT::get()),
+			vec![(PoolId::Dex(BTC_AUSD_LP), vec![(ACA, 100)])],
+		));
+
+		// deposited before the protection, accrues rewards immediately
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		assert_ok!(IncentivesModule::update_deposit_protection(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			15,
+			false
+		));
+
+		// 1 + 15 is rounded up to the window start 20
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		System::set_block_number(8);
+		// 8 + 15 is rounded up to the window start 30
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			500
+		));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 8500);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			Default::default(),
+		);
+		assert_eq!(
+			IncentivesModule::maturing_deposits(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			vec![(20, 1000), (30, 500)].into_iter().collect()
+		);
+
+		assert_noop!(
+			IncentivesModule::withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP, 1501),
+			Error::<Runtime>::NotEnough,
+		);
+		// the youngest deposits are withdrawn first
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			600
+		));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 9100);
+		assert_eq!(
+			IncentivesModule::maturing_deposits(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			vec![(20, 900)].into_iter().collect()
+		);
+
+		// only BOB accrues the rewards of the first window
+		System::set_block_number(10);
+		IncentivesModule::on_initialize(10);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
+			PoolInfo {
+				total_shares: 1000,
+				rewards: vec![(ACA, (100, 0))].into_iter().collect(),
+			}
+		);
+
+		// the deposit of ALICE matures before the accumulation
+		System::set_block_number(20);
+		IncentivesModule::on_initialize(20);
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::DepositMatured {
+			who: ALICE::get(),
+			pool: PoolId::Dex(BTC_AUSD_LP),
+			amount: 900,
+		}));
+		assert_eq!(
+			IncentivesModule::maturing_deposits(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			Default::default()
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(900, vec![(ACA, 90)].into_iter().collect()),
+		);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
+			PoolInfo {
+				total_shares: 1900,
+				rewards: vec![(ACA, (290, 90))].into_iter().collect(),
+			}
+		);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			PoolId::Dex(BTC_AUSD_LP)
+		));
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(BOB::get()),
+			PoolId::Dex(BTC_AUSD_LP)
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 47);
+		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 152);
+	});
+}
+
+#[test]
+fn randomized_accumulation_once_per_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokensModule::deposit(ACA, &RewardsSource::get(), 10000));
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			RuntimeOrigin::signed(ROOT::get()),
+			vec![
+				(PoolId::Dex(BTC_AUSD_LP), vec![(ACA, 100)]),
+				(PoolId::Dex(DOT_AUSD_LP), vec![(ACA, 100)]),
+			],
+		));
+		assert_ok!(RewardsModule::add_share(&ALICE::get(), &PoolId::Dex(BTC_AUSD_LP), 1));
+		assert_ok!(RewardsModule::add_share(&ALICE::get(), &PoolId::Dex(DOT_AUSD_LP), 1));
+		assert_ok!(IncentivesModule::update_deposit_protection(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(DOT_AUSD_LP),
+			0,
+			true
+		));
+
+		let total_rewards = |pool_id: PoolId| {
+			RewardsModule::pool_infos(pool_id)
+				.rewards
+				.get(&ACA)
+				.map_or(0, |(total, _)| *total)
+		};
+		let mut randomized_blocks = vec![];
+		for now in 2..50u64 {
+			System::set_block_number(now);
+			let before = total_rewards(PoolId::Dex(DOT_AUSD_LP));
+			IncentivesModule::on_initialize(now);
+			if total_rewards(PoolId::Dex(DOT_AUSD_LP)) > before {
+				randomized_blocks.push(now);
+			}
+
+			// the pool without protection accumulates at the window start
+			assert_eq!(total_rewards(PoolId::Dex(BTC_AUSD_LP)), 100 * (now / 10) as u128);
+		}
+
+		// exactly once within each window, none in the window of the update
+		assert_eq!(randomized_blocks.len(), 4);
+		for (i, block) in randomized_blocks.iter().enumerate() {
+			assert_eq!(block / 10, i as u64 + 1);
+		}
+		assert_eq!(total_rewards(PoolId::Dex(DOT_AUSD_LP)), 400);
+	});
+}
+
+#[test]
+fn randomized_accumulation_block_is_deterministic() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Dex(DOT_AUSD_LP);
+		for now in 10..20u64 {
+			assert_eq!(
+				IncentivesModule::is_randomized_accumulation_block(&pool_id, now),
+				IncentivesModule::is_randomized_accumulation_block(&pool_id, now)
+			);
+		}
+		// the last block of the window always accumulates
+		assert!(IncentivesModule::is_randomized_accumulation_block(&pool_id, 19));
+		assert!(IncentivesModule::is_randomized_accumulation_block(&pool_id, 29));
+	});
+}
+
+#[test]
+fn set_claim_preference_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Loans(DOT);
+		let preference = ClaimPreference {
+			currency_id: AUSD,
+			max_slippage: Ratio::saturating_from_rational(5, 100),
+		};
+
+		assert_noop!(
+			IncentivesModule::set_claim_preference(
+				RuntimeOrigin::signed(ALICE::get()),
+				pool_id,
+				Some(AUSD),
+				Ratio::saturating_from_rational(101, 100)
+			),
+			Error::<Runtime>::InvalidRate
+		);
+
+		assert_ok!(IncentivesModule::set_claim_preference(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id,
+			Some(AUSD),
+			Ratio::saturating_from_rational(5, 100)
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimPreferenceUpdated {
+			who: ALICE::get(),
+			pool: pool_id,
+			preference: Some(preference),
+		}));
+		assert_eq!(
+			IncentivesModule::claim_preferences(pool_id, ALICE::get()),
+			Some(preference)
+		);
+		assert_eq!(IncentivesModule::claim_preferences(pool_id, BOB::get()), None);
+
+		assert_ok!(IncentivesModule::set_claim_preference(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id,
+			None,
+			Ratio::saturating_from_rational(5, 100)
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimPreferenceUpdated {
+			who: ALICE::get(),
+			pool: pool_id,
+			preference: None,
+		}));
+		assert!(!ClaimPreferences::<Runtime>::contains_key(pool_id, ALICE::get()));
+	});
+}
+
+fn setup_claim_conversion(pool_id: PoolId) {
+	OraclePrices::set(vec![
+		(ACA, Price::saturating_from_integer(1)),
+		(LDOT, Price::saturating_from_integer(2)),
+		(AUSD, Price::saturating_from_integer(1)),
+	]);
+	SwapRates::set(vec![
+		((ACA, AUSD), Ratio::saturating_from_rational(95, 100)),
+		((LDOT, AUSD), Ratio::saturating_from_rational(19, 10)),
+	]);
+
+	assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+	assert_ok!(TokensModule::deposit(LDOT, &VAULT::get(), 10000));
+	assert_ok!(TokensModule::deposit(AUSD, &VAULT::get(), 10000));
+	assert_ok!(RewardsModule::add_share(&ALICE::get(), &pool_id, 100));
+	assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 1000));
+	assert_ok!(RewardsModule::accumulate_reward(&pool_id, LDOT, 2000));
+	assert_ok!(RewardsModule::accumulate_reward(&pool_id, AUSD, 500));
+}
+
+#[test]
+fn claim_rewards_converts_all_reward_currencies_once() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Loans(DOT);
+		setup_claim_conversion(pool_id);
+
+		assert_ok!(IncentivesModule::set_claim_preference(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id,
+			Some(AUSD),
+			Ratio::saturating_from_rational(10, 100)
+		));
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+
+		// the raw claimed amounts
+		for (reward_currency_id, actual_amount) in [(ACA, 1000), (LDOT, 2000), (AUSD, 500)] {
+			System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimRewards {
+				who: ALICE::get(),
+				pool: pool_id,
+				reward_currency_id,
+				actual_amount,
+				deduction_amount: 0,
+			}));
+		}
+		// the conversion of all the other reward currencies, the rewards in the preferred currency
+		// are not swapped.
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimedRewardsConverted {
+			who: ALICE::get(),
+			pool: pool_id,
+			supply: vec![(ACA, 1000), (LDOT, 2000)],
+			target_currency_id: AUSD,
+			target_amount: 4750,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(AUSD, &ALICE::get()), 5250);
+	});
+}
+
+#[test]
+fn claim_rewards_skips_conversion_beyond_slippage() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Loans(DOT);
+		setup_claim_conversion(pool_id);
+
+		// LDOT swaps at 1.9 AUSD, worse than 2 AUSD of the oracle price by more than 2%.
+		assert_ok!(IncentivesModule::set_claim_preference(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id,
+			Some(AUSD),
+			Ratio::saturating_from_rational(2, 100)
+		));
+		SwapRates::set(vec![
+			((ACA, AUSD), Ratio::saturating_from_rational(99, 100)),
+			((LDOT, AUSD), Ratio::saturating_from_rational(19, 10)),
+		]);
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+
+		System::assert_has_event(RuntimeEvent::IncentivesModule(
+			crate::Event::ClaimedRewardConversionSkipped {
+				who: ALICE::get(),
+				pool: pool_id,
+				reward_currency_id: LDOT,
+				amount: 2000,
+			},
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::ClaimedRewardsConverted {
+			who: ALICE::get(),
+			pool: pool_id,
+			supply: vec![(ACA, 1000)],
+			target_currency_id: AUSD,
+			target_amount: 990,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 0);
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 2000);
+		assert_eq!(TokensModule::free_balance(AUSD, &ALICE::get()), 1490);
+
+		// without the oracle price, the conversion is skipped too.
+		OraclePrices::set(vec![]);
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 1000));
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(
+			crate::Event::ClaimedRewardConversionSkipped {
+				who: ALICE::get(),
+				pool: pool_id,
+				reward_currency_id: ACA,
+				amount: 1000,
+			},
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 1000);
+		assert_eq!(TokensModule::free_balance(AUSD, &ALICE::get()), 1490);
+	});
+}
+
+#[test]
+fn removing_claim_preference_pays_rewards_as_is() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Loans(DOT);
+		setup_claim_conversion(pool_id);
+
+		assert_ok!(IncentivesModule::set_claim_preference(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id,
+			Some(AUSD),
+			Ratio::saturating_from_rational(10, 100)
+		));
+		assert_ok!(IncentivesModule::set_claim_preference(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id,
+			None,
+			Zero::zero()
+		));
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::IncentivesModule(crate::Event::ClaimedRewardsConverted { .. })
+				| RuntimeEvent::IncentivesModule(crate::Event::ClaimedRewardConversionSkipped { .. })
+		)));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 1000);
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 2000);
+		assert_eq!(TokensModule::free_balance(AUSD, &ALICE::get()), 500);
+	});
+}
+
+#[test]
+fn update_withdrawal_delay_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_withdrawal_delay(
+				RuntimeOrigin::signed(ALICE::get()),
+				PoolId::Dex(BTC_AUSD_LP),
+				10,
+				Permill::from_percent(1)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_withdrawal_delay(
+				RuntimeOrigin::signed(ROOT::get()),
+				PoolId::Loans(BTC),
+				10,
+				Permill::from_percent(1)
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_withdrawal_delay(
+				RuntimeOrigin::signed(ROOT::get()),
+				PoolId::Dex(BTC),
+				10,
+				Permill::from_percent(1)
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+
+		assert_ok!(IncentivesModule::update_withdrawal_delay(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			10,
+			Permill::from_percent(1)
+		));
+		let withdrawal_delay = Some(WithdrawalDelay {
+			delay: 10,
+			instant_exit_fee: Permill::from_percent(1),
+		});
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::WithdrawalDelayUpdated {
+			pool: PoolId::Dex(BTC_AUSD_LP),
+			withdrawal_delay,
+		}));
+		assert_eq!(
+			IncentivesModule::withdrawal_delays(PoolId::Dex(BTC_AUSD_LP)),
+			withdrawal_delay
+		);
+
+		assert_ok!(IncentivesModule::update_withdrawal_delay(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			0,
+			Permill::zero()
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::WithdrawalDelayUpdated {
+			pool: PoolId::Dex(BTC_AUSD_LP),
+			withdrawal_delay: None,
+		}));
+		assert_eq!(IncentivesModule::withdrawal_delays(PoolId::Dex(BTC_AUSD_LP)), None);
+	});
+}
+
+#[test]
+fn request_and_execute_withdraw_dex_share_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IncentivesModule::update_withdrawal_delay(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			10,
+			Permill::from_percent(1)
+		));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 10000));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			10000
+		));
+
+		assert_noop!(
+			IncentivesModule::request_withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP, 10001),
+			Error::<Runtime>::NotEnough
+		);
+		assert_noop!(
+			IncentivesModule::execute_withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP),
+			Error::<Runtime>::WithdrawalNotRequested
+		);
+
+		assert_ok!(IncentivesModule::request_withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			4000
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(
+			crate::Event::WithdrawDexShareRequested {
+				who: ALICE::get(),
+				dex_share_type: BTC_AUSD_LP,
+				amount: 4000,
+				unlock_at: 11,
+			},
+		));
+		// the requested shares stop accruing rewards immediately
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()).0,
+			6000
+		);
+		assert_eq!(RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)).total_shares, 6000);
+
+		// the requests are merged and the delay restarts
+		System::set_block_number(5);
+		assert_ok!(IncentivesModule::request_withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(
+			crate::Event::WithdrawDexShareRequested {
+				who: ALICE::get(),
+				dex_share_type: BTC_AUSD_LP,
+				amount: 5000,
+				unlock_at: 15,
+			},
+		));
+		assert_eq!(
+			IncentivesModule::pending_withdrawals(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			Some(PendingWithdrawal {
+				amount: 5000,
+				unlock_at: 15
+			})
+		);
+
+		System::set_block_number(14);
+		assert_noop!(
+			IncentivesModule::execute_withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP),
+			Error::<Runtime>::WithdrawalStillDelayed
+		);
+
+		System::set_block_number(15);
+		assert_ok!(IncentivesModule::execute_withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP
+		));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::WithdrawDexShare {
+			who: ALICE::get(),
+			dex_share_type: BTC_AUSD_LP,
+			withdraw: 5000,
+		}));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 5000);
+		assert_eq!(
+			TokensModule::free_balance(BTC_AUSD_LP, &IncentivesModule::account_id()),
+			5000
+		);
+		assert_eq!(
+			IncentivesModule::pending_withdrawals(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			None
+		);
+		assert_noop!(
+			IncentivesModule::execute_withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP),
+			Error::<Runtime>::WithdrawalNotRequested
+		);
+	});
+}
+
+#[test]
+fn instant_exit_fee_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IncentivesModule::update_withdrawal_delay(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			10,
+			Permill::from_percent(10)
+		));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 1000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &BOB::get(), 3000));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			3000
+		));
+
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::InstantExitFeeCharged {
+			who: ALICE::get(),
+			dex_share_type: BTC_AUSD_LP,
+			fee: 100,
+		}));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::WithdrawDexShare {
+			who: ALICE::get(),
+			dex_share_type: BTC_AUSD_LP,
+			withdraw: 900,
+		}));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 900);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
+			PoolInfo {
+				total_shares: 3000,
+				rewards: vec![(BTC_AUSD_LP, (100, 0))].into_iter().collect(),
+			}
+		);
+		assert_eq!(
+			IncentivesModule::accumulated_reward_per_share(&PoolId::Dex(BTC_AUSD_LP), BTC_AUSD_LP),
+			Some(Rate::saturating_from_rational(100, 3000))
+		);
+
+		// the fee is shared by the remaining depositors
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(BOB::get()),
+			PoolId::Dex(BTC_AUSD_LP)
+		));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &BOB::get()), 100);
+
+		// no fee for the last depositor
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			RuntimeOrigin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			3000
+		));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &BOB::get()), 3100);
+		assert_eq!(
+			TokensModule::free_balance(BTC_AUSD_LP, &IncentivesModule::account_id()),
+			0
+		);
+	});
+}
+
+#[test]
+fn emergency_shutdown_bypasses_withdrawal_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IncentivesModule::update_withdrawal_delay(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			10,
+			Permill::from_percent(10)
+		));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 1000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &BOB::get(), 1000));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		assert_ok!(IncentivesModule::request_withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		assert_noop!(
+			IncentivesModule::execute_withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP),
+			Error::<Runtime>::WithdrawalStillDelayed
+		);
+
+		mock_shutdown();
+		assert_ok!(IncentivesModule::execute_withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP
+		));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 1000);
+
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			RuntimeOrigin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			500
+		));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &BOB::get()), 500);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
+			PoolInfo {
+				total_shares: 500,
+				..Default::default()
+			}
+		);
+	});
+}
+
+fn deposit_into_pool(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) {
+	assert_ok!(TokensModule::deposit(lp_currency_id, who, amount));
+	assert_ok!(IncentivesModule::deposit_dex_share(
+		RuntimeOrigin::signed(who.clone()),
+		lp_currency_id,
+		amount
+	));
+}
+
+#[test]
+fn retire_pool_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Dex(BTC_AUSD_LP);
+		deposit_into_pool(&ALICE::get(), BTC_AUSD_LP, 2000);
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			RuntimeOrigin::signed(ROOT::get()),
+			vec![(pool_id, vec![(ACA, 100)])],
+		));
+
+		assert_noop!(
+			IncentivesModule::retire_pool(RuntimeOrigin::signed(ALICE::get()), pool_id),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::retire_pool(RuntimeOrigin::root(), PoolId::Earning(ACA)),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::retire_pool(RuntimeOrigin::signed(ROOT::get()), pool_id),
+			Error::<Runtime>::RetirementSharesExceeded
+		);
+
+		assert_ok!(IncentivesModule::retire_pool(RuntimeOrigin::root(), pool_id));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::PoolRetirementStarted {
+			pool: pool_id,
+			sweep_at: 21,
+		}));
+		assert_eq!(
+			IncentivesModule::retiring_pools(pool_id),
+			Some(PoolRetirement::Grace { sweep_at: 21 })
+		);
+		assert_eq!(IncentivesModule::incentive_reward_amounts(pool_id, ACA), 0);
+
+		assert_noop!(
+			IncentivesModule::retire_pool(RuntimeOrigin::root(), pool_id),
+			Error::<Runtime>::PoolRetiring
+		);
+		assert_noop!(
+			IncentivesModule::update_incentive_rewards(
+				RuntimeOrigin::signed(ROOT::get()),
+				vec![(pool_id, vec![(ACA, 100)])],
+			),
+			Error::<Runtime>::PoolRetiring
+		);
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &BOB::get(), 100));
+		assert_noop!(
+			IncentivesModule::deposit_dex_share(RuntimeOrigin::signed(BOB::get()), BTC_AUSD_LP, 100),
+			Error::<Runtime>::PoolRetiring
+		);
+
+		// the pools under the threshold can be retired by `UpdateOrigin`
+		deposit_into_pool(&BOB::get(), DOT_AUSD_LP, 1000);
+		assert_ok!(IncentivesModule::retire_pool(
+			RuntimeOrigin::signed(ROOT::get()),
+			PoolId::Dex(DOT_AUSD_LP)
+		));
+	});
+}
+
+#[test]
+fn claims_and_withdrawals_during_retirement_grace_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Dex(BTC_AUSD_LP);
+		deposit_into_pool(&ALICE::get(), BTC_AUSD_LP, 100);
+		deposit_into_pool(&BOB::get(), BTC_AUSD_LP, 100);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 1000));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 1000));
+
+		assert_ok!(IncentivesModule::retire_pool(RuntimeOrigin::root(), pool_id));
+		System::set_block_number(20);
+		IncentivesModule::on_initialize(20);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			RuntimeOrigin::signed(ALICE::get()),
+			pool_id
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 500);
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 100);
+
+		// the rewards of BOB are moved to the pending rewards by the withdrawal
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			RuntimeOrigin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			40
+		));
+		assert_eq!(
+			IncentivesModule::pending_multi_rewards(pool_id, BOB::get()),
+			vec![(ACA, 500)].into_iter().collect()
+		);
+		assert_eq!(
+			IncentivesModule::retiring_pools(pool_id),
+			Some(PoolRetirement::Grace { sweep_at: 21 })
+		);
+	});
+}
+
+#[test]
+fn retired_pool_sweep_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Dex(BTC_AUSD_LP);
+		deposit_into_pool(&ALICE::get(), BTC_AUSD_LP, 100);
+		deposit_into_pool(&BOB::get(), BTC_AUSD_LP, 100);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 1000));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 1000));
+		assert_ok!(IncentivesModule::set_claim_preference(
+			RuntimeOrigin::signed(BOB::get()),
+			pool_id,
+			Some(AUSD),
+			Ratio::one()
+		));
+
+		assert_ok!(IncentivesModule::retire_pool(RuntimeOrigin::root(), pool_id));
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			RuntimeOrigin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			40
+		));
+
+		System::set_block_number(21);
+		IncentivesModule::on_initialize(21);
+
+		// the rewards not withdrawn by ALICE are swept with the retirement
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::RetiredPoolRewardsSwept {
+			pool: pool_id,
+			rewards: vec![(ACA, 500)],
+		}));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::WithdrawDexShare {
+			who: BOB::get(),
+			dex_share_type: BTC_AUSD_LP,
+			withdraw: 60,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 500);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 500);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 100);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &BOB::get()), 100);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &VAULT::get()), 0);
+
+		// the sweep and the 4 entries of the shares, the pending rewards and the claim preference
+		// take two blocks
+		assert_eq!(
+			IncentivesModule::retiring_pools(pool_id),
+			Some(PoolRetirement::Sweeping)
+		);
+		assert_noop!(
+			IncentivesModule::claim_rewards(RuntimeOrigin::signed(BOB::get()), pool_id),
+			Error::<Runtime>::PoolRetired
+		);
+
+		// the pending rewards of BOB are swept with the removal of his entries
+		System::set_block_number(22);
+		IncentivesModule::on_initialize(22);
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::RetiredPoolRewardsSwept {
+			pool: pool_id,
+			rewards: vec![(ACA, 500)],
+		}));
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::PoolRetirementCompleted {
+			pool: pool_id,
+		}));
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 1000);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 0);
+		assert_eq!(IncentivesModule::retiring_pools(pool_id), None);
+		assert_eq!(RewardsModule::pool_infos(pool_id), PoolInfo::default());
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(pool_id, BOB::get()),
+			Default::default()
+		);
+		assert!(PendingMultiRewards::<Runtime>::get(pool_id, BOB::get()).is_empty());
+		assert_eq!(IncentivesModule::claim_preferences(pool_id, BOB::get()), None);
+	});
+}
+
+#[test]
+fn retired_pool_removal_is_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Dex(BTC_AUSD_LP);
+		let stakers: Vec<AccountId> = (10u8..15).map(|i| AccountId::from([i; 32])).collect();
+		for who in stakers.iter() {
+			deposit_into_pool(who, BTC_AUSD_LP, 100);
+		}
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 500));
+		assert_ok!(RewardsModule::accumulate_reward(&pool_id, ACA, 500));
+		assert_ok!(IncentivesModule::retire_pool(RuntimeOrigin::root(), pool_id));
+
+		// the sweep and 2 entries
+		System::set_block_number(21);
+		IncentivesModule::on_initialize(21);
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 500);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &VAULT::get()), 300);
+		assert_eq!(
+			IncentivesModule::retiring_pools(pool_id),
+			Some(PoolRetirement::Sweeping)
+		);
+
+		// the shares left are refunded by the removal only
+		let staked = stakers
+			.iter()
+			.find(|who| TokensModule::free_balance(BTC_AUSD_LP, who).is_zero())
+			.unwrap()
+			.clone();
+		assert_noop!(
+			IncentivesModule::withdraw_dex_share(RuntimeOrigin::signed(staked), BTC_AUSD_LP, 100),
+			Error::<Runtime>::PoolRetired
+		);
+
+		System::set_block_number(22);
+		IncentivesModule::on_initialize(22);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &VAULT::get()), 0);
+		assert_eq!(
+			IncentivesModule::retiring_pools(pool_id),
+			Some(PoolRetirement::Sweeping)
+		);
+
+		System::set_block_number(23);
+		IncentivesModule::on_initialize(23);
+		assert_eq!(IncentivesModule::retiring_pools(pool_id), None);
+		for who in stakers.iter() {
+			assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, who), 100);
+		}
+		assert_eq!(TokensModule::free_balance(ACA, &RewardsSource::get()), 500);
+	});
+}
+
+#[test]
+fn retired_pool_refunds_pending_withdrawals() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Dex(BTC_AUSD_LP);
+		assert_ok!(IncentivesModule::update_withdrawal_delay(
+			RuntimeOrigin::signed(ROOT::get()),
+			pool_id,
+			10,
+			Permill::zero()
+		));
+		deposit_into_pool(&ALICE::get(), BTC_AUSD_LP, 100);
+		assert_ok!(IncentivesModule::request_withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			40
+		));
+		assert_ok!(IncentivesModule::retire_pool(RuntimeOrigin::root(), pool_id));
+
+		// the sweep, the shares and the pending withdrawal of ALICE
+		System::set_block_number(21);
+		IncentivesModule::on_initialize(21);
+		System::assert_has_event(RuntimeEvent::IncentivesModule(crate::Event::WithdrawDexShare {
+			who: ALICE::get(),
+			dex_share_type: BTC_AUSD_LP,
+			withdraw: 40,
+		}));
+		assert_eq!(IncentivesModule::pending_withdrawals(pool_id, ALICE::get()), None);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 100);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &VAULT::get()), 0);
+
+		System::set_block_number(22);
+		IncentivesModule::on_initialize(22);
+		assert_eq!(IncentivesModule::retiring_pools(pool_id), None);
+	});
+}
+
+#[test]
+fn retired_loans_pool_stays_retired() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = PoolId::Loans(BTC);
+		assert_ok!(OnUpdateLoan::<Runtime>::handle(&(ALICE::get(), BTC, 100, 0)));
+		assert_ok!(IncentivesModule::retire_pool(RuntimeOrigin::root(), pool_id));
+
+		System::set_block_number(21);
+		IncentivesModule::on_initialize(21);
+		System::assert_last_event(RuntimeEvent::IncentivesModule(crate::Event::PoolRetirementCompleted {
+			pool: pool_id,
+		}));
+		assert_eq!(IncentivesModule::retiring_pools(pool_id), Some(PoolRetirement::Retired));
+
+		// the loan of ALICE is still open, its repayment doesn't update the removed shares
+		assert_ok!(OnUpdateLoan::<Runtime>::handle(&(ALICE::get(), BTC, -100, 100)));
+		assert_ok!(OnUpdateLoan::<Runtime>::handle(&(BOB::get(), BTC, 100, 0)));
+		assert_eq!(RewardsModule::pool_infos(pool_id), PoolInfo::default());
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(pool_id, BOB::get()),
+			Default::default()
+		);
+		assert_noop!(
+			IncentivesModule::retire_pool(RuntimeOrigin::root(), pool_id),
+			Error::<Runtime>::PoolRetiring
+		);
+		assert_noop!(
+			IncentivesModule::update_incentive_rewards(
+				RuntimeOrigin::signed(ROOT::get()),
+				vec![(pool_id, vec![(ACA, 100)])],
+			),
+			Error::<Runtime>::PoolRetiring
+		);
+
+		System::reset_events();
+		System::set_block_number(22);
+		IncentivesModule::on_initialize(22);
+		assert!(System::events().is_empty());
+	});
+}
@@ -48,11 +48,25 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn on_initialize(c: u32, ) -> Weight;
 	fn deposit_dex_share() -> Weight;
+	fn deposit_dex_share_no_op() -> Weight;
 	fn withdraw_dex_share() -> Weight;
+	fn withdraw_dex_share_no_op() -> Weight;
 	fn claim_rewards() -> Weight;
+	fn claim_rewards_no_op() -> Weight;
 	fn update_incentive_rewards(c: u32, ) -> Weight;
 	fn update_claim_reward_deduction_rates(c: u32, ) -> Weight;
 	fn update_claim_reward_deduction_currency() -> Weight;
+	fn update_claim_reward_vesting() -> Weight;
+	fn claim_vested_rewards() -> Weight;
+	fn update_deposit_protection() -> Weight;
+	fn set_claim_preference() -> Weight;
+	fn claim_rewards_with_conversion() -> Weight;
+	fn mature_deposits(c: u32, ) -> Weight;
+	fn update_withdrawal_delay() -> Weight;
+	fn request_withdraw_dex_share() -> Weight;
+	fn execute_withdraw_dex_share() -> Weight;
+	fn retire_pool() -> Weight;
+	fn clean_retired_pool(c: u32, ) -> Weight;
 }
 
 /// Weights for module_incentives using the Acala node and recommended hardware.
@@ -66,6 +80,10 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `System::Account` (r:1 w:0)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::DepositProtections` (r:1 w:0)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::RandomizedAccumulatedWindows` (r:1 w:0)
+	// Proof: `Incentives::RandomizedAccumulatedWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `c` is `[0, 4]`.
 	fn on_initialize(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -75,7 +93,7 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		Weight::from_parts(19_271_096, 5007)
 			// Standard Error: 190_479
 			.saturating_add(Weight::from_parts(24_655_412, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 5027).saturating_mul(c.into()))
 	}
@@ -89,14 +107,27 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::DepositProtections` (r:1 w:0)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingSchedule` (r:0 w:1)
+	// Proof: `Incentives::MaturingSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn deposit_dex_share() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2855`
 		//  Estimated: `6320`
 		// Minimum execution time: 98_848 nanoseconds.
 		Weight::from_parts(100_717_000, 6320)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	fn deposit_dex_share_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_764 nanoseconds.
+		Weight::from_parts(7_996_000, 0)
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -106,14 +137,27 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Rewards::PoolInfos` (r:1 w:1)
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::WithdrawalDelays` (r:1 w:0)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn withdraw_dex_share() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2945`
 		//  Estimated: `6410`
 		// Minimum execution time: 97_599 nanoseconds.
 		Weight::from_parts(99_107_000, 6410)
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn withdraw_dex_share_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_859 nanoseconds.
+		Weight::from_parts(8_094_000, 0)
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -127,6 +171,10 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof: `Incentives::ClaimRewardDeductionCurrency` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
 	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
@@ -135,9 +183,21 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		//  Estimated: `6098`
 		// Minimum execution time: 143_679 nanoseconds.
 		Weight::from_parts(146_283_000, 6098)
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:0)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:0)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn claim_rewards_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1124`
+		//  Estimated: `4589`
+		// Minimum execution time: 16_600 nanoseconds.
+		Weight::from_parts(17_098_000, 4589)
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
 	// Storage: `Incentives::IncentiveRewardAmounts` (r:4 w:4)
 	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `c` is `[0, 4]`.
@@ -179,6 +239,206 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `Incentives::ClaimRewardVestings` (r:0 w:1)
+	// Proof: `Incentives::ClaimRewardVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_claim_reward_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_152 nanoseconds.
+		Weight::from_parts(7_481_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::VestingRewards` (r:1 w:1)
+	// Proof: `Incentives::VestingRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn claim_vested_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2418`
+		//  Estimated: `6244`
+		// Minimum execution time: 78_365 nanoseconds.
+		Weight::from_parts(80_517_000, 6244)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Incentives::DepositProtections` (r:1 w:1)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::RandomizedAccumulatedWindows` (r:0 w:1)
+	// Proof: `Incentives::RandomizedAccumulatedWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_deposit_protection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `4508`
+		// Minimum execution time: 12_358 nanoseconds.
+		Weight::from_parts(12_904_000, 4508)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Incentives::ClaimPreferences` (r:0 w:1)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_claim_preference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_815 nanoseconds.
+		Weight::from_parts(10_236_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:1)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionRates` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionRates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionCurrency` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionCurrency` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardVestings` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:2 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn claim_rewards_with_conversion() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3845`
+		//  Estimated: `9083`
+		// Minimum execution time: 241_097 nanoseconds.
+		Weight::from_parts(247_560_000, 9083)
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: `Incentives::MaturingSchedule` (r:100 w:100)
+	// Proof: `Incentives::MaturingSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:100 w:100)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:100 w:100)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 100]`.
+	fn mature_deposits(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1208 + c * (262 ±0)`
+		//  Estimated: `4673 + c * (2737 ±0)`
+		// Minimum execution time: 5_124 nanoseconds.
+		Weight::from_parts(6_031_220, 4673)
+			// Standard Error: 21_472
+			.saturating_add(Weight::from_parts(28_417_936, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2737).saturating_mul(c.into()))
+	}
+	// Storage: `Incentives::WithdrawalDelays` (r:0 w:1)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_withdrawal_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_642 nanoseconds.
+		Weight::from_parts(10_011_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::WithdrawalDelays` (r:1 w:0)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingWithdrawals` (r:1 w:1)
+	// Proof: `Incentives::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_withdraw_dex_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1673`
+		//  Estimated: `5138`
+		// Minimum execution time: 38_716 nanoseconds.
+		Weight::from_parts(39_804_000, 5138)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Incentives::PendingWithdrawals` (r:1 w:1)
+	// Proof: `Incentives::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn execute_withdraw_dex_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1562`
+		//  Estimated: `6234`
+		// Minimum execution time: 41_285 nanoseconds.
+		Weight::from_parts(42_370_000, 6234)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `Incentives::RetiringPools` (r:1 w:1)
+	// Proof: `Incentives::RetiringPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:0)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::IncentiveRewardAmounts` (r:2 w:1)
+	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn retire_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6652`
+		// Minimum execution time: 26_318 nanoseconds.
+		Weight::from_parts(27_105_000, 6652)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Incentives::RetiringPools` (r:1 w:1)
+	// Proof: `Incentives::RetiringPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:51 w:50)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:51 w:51)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:50 w:50)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:0)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:0)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 50]`.
+	fn clean_retired_pool(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1394 + c * (318 ±0)`
+		//  Estimated: `4859 + c * (2793 ±0)`
+		// Minimum execution time: 14_772 nanoseconds.
+		Weight::from_parts(16_250_410, 4859)
+			// Standard Error: 24_106
+			.saturating_add(Weight::from_parts(36_904_215, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2793).saturating_mul(c.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -191,6 +451,10 @@ impl WeightInfo for () {
 	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `System::Account` (r:1 w:0)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::DepositProtections` (r:1 w:0)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::RandomizedAccumulatedWindows` (r:1 w:0)
+	// Proof: `Incentives::RandomizedAccumulatedWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `c` is `[0, 4]`.
 	fn on_initialize(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -200,7 +464,7 @@ impl WeightInfo for () {
 		Weight::from_parts(19_271_096, 5007)
 			// Standard Error: 190_479
 			.saturating_add(Weight::from_parts(24_655_412, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads(4))
 			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 5027).saturating_mul(c.into()))
 	}
@@ -214,14 +478,27 @@ impl WeightInfo for () {
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::DepositProtections` (r:1 w:0)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingSchedule` (r:0 w:1)
+	// Proof: `Incentives::MaturingSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn deposit_dex_share() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2855`
 		//  Estimated: `6320`
 		// Minimum execution time: 98_848 nanoseconds.
 		Weight::from_parts(100_717_000, 6320)
-			.saturating_add(RocksDbWeight::get().reads(6))
-			.saturating_add(RocksDbWeight::get().writes(5))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	fn deposit_dex_share_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_764 nanoseconds.
+		Weight::from_parts(7_996_000, 0)
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -231,14 +508,27 @@ impl WeightInfo for () {
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Rewards::PoolInfos` (r:1 w:1)
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::WithdrawalDelays` (r:1 w:0)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn withdraw_dex_share() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2945`
 		//  Estimated: `6410`
 		// Minimum execution time: 97_599 nanoseconds.
 		Weight::from_parts(99_107_000, 6410)
-			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	fn withdraw_dex_share_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_859 nanoseconds.
+		Weight::from_parts(8_094_000, 0)
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -252,6 +542,10 @@ impl WeightInfo for () {
 	// Proof: `Incentives::ClaimRewardDeductionCurrency` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
 	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
@@ -260,9 +554,21 @@ impl WeightInfo for () {
 		//  Estimated: `6098`
 		// Minimum execution time: 143_679 nanoseconds.
 		Weight::from_parts(146_283_000, 6098)
-			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().reads(8))
 			.saturating_add(RocksDbWeight::get().writes(4))
 	}
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:0)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:0)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn claim_rewards_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1124`
+		//  Estimated: `4589`
+		// Minimum execution time: 16_600 nanoseconds.
+		Weight::from_parts(17_098_000, 4589)
+			.saturating_add(RocksDbWeight::get().reads(2))
+	}
 	// Storage: `Incentives::IncentiveRewardAmounts` (r:4 w:4)
 	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `c` is `[0, 4]`.
@@ -304,4 +610,204 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+	// Storage: `Incentives::ClaimRewardVestings` (r:0 w:1)
+	// Proof: `Incentives::ClaimRewardVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_claim_reward_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_152 nanoseconds.
+		Weight::from_parts(7_481_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::VestingRewards` (r:1 w:1)
+	// Proof: `Incentives::VestingRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn claim_vested_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2418`
+		//  Estimated: `6244`
+		// Minimum execution time: 78_365 nanoseconds.
+		Weight::from_parts(80_517_000, 6244)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: `Incentives::DepositProtections` (r:1 w:1)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::RandomizedAccumulatedWindows` (r:0 w:1)
+	// Proof: `Incentives::RandomizedAccumulatedWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_deposit_protection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `4508`
+		// Minimum execution time: 12_358 nanoseconds.
+		Weight::from_parts(12_904_000, 4508)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: `Incentives::ClaimPreferences` (r:0 w:1)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_claim_preference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_815 nanoseconds.
+		Weight::from_parts(10_236_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:1)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionRates` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionRates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionCurrency` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionCurrency` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardVestings` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:2 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn claim_rewards_with_conversion() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3845`
+		//  Estimated: `9083`
+		// Minimum execution time: 241_097 nanoseconds.
+		Weight::from_parts(247_560_000, 9083)
+			.saturating_add(RocksDbWeight::get().reads(18))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	// Storage: `Incentives::MaturingSchedule` (r:100 w:100)
+	// Proof: `Incentives::MaturingSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:100 w:100)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:100 w:100)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 100]`.
+	fn mature_deposits(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1208 + c * (262 ±0)`
+		//  Estimated: `4673 + c * (2737 ±0)`
+		// Minimum execution time: 5_124 nanoseconds.
+		Weight::from_parts(6_031_220, 4673)
+			// Standard Error: 21_472
+			.saturating_add(Weight::from_parts(28_417_936, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2737).saturating_mul(c.into()))
+	}
+	// Storage: `Incentives::WithdrawalDelays` (r:0 w:1)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_withdrawal_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_642 nanoseconds.
+		Weight::from_parts(10_011_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::WithdrawalDelays` (r:1 w:0)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingWithdrawals` (r:1 w:1)
+	// Proof: `Incentives::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_withdraw_dex_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1673`
+		//  Estimated: `5138`
+		// Minimum execution time: 38_716 nanoseconds.
+		Weight::from_parts(39_804_000, 5138)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: `Incentives::PendingWithdrawals` (r:1 w:1)
+	// Proof: `Incentives::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn execute_withdraw_dex_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1562`
+		//  Estimated: `6234`
+		// Minimum execution time: 41_285 nanoseconds.
+		Weight::from_parts(42_370_000, 6234)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: `Incentives::RetiringPools` (r:1 w:1)
+	// Proof: `Incentives::RetiringPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:0)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::IncentiveRewardAmounts` (r:2 w:1)
+	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn retire_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6652`
+		// Minimum execution time: 26_318 nanoseconds.
+		Weight::from_parts(27_105_000, 6652)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: `Incentives::RetiringPools` (r:1 w:1)
+	// Proof: `Incentives::RetiringPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:51 w:50)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:51 w:51)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:50 w:50)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:0)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:0)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 50]`.
+	fn clean_retired_pool(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1394 + c * (318 ±0)`
+		//  Estimated: `4859 + c * (2793 ±0)`
+		// Minimum execution time: 14_772 nanoseconds.
+		Weight::from_parts(16_250_410, 4859)
+			// Standard Error: 24_106
+			.saturating_add(Weight::from_parts(36_904_215, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2793).saturating_mul(c.into()))
+	}
 }
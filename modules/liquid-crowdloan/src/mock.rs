@@ -25,7 +25,7 @@ use crate as liquid_crowdloan;
 
 use frame_support::{
 	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU128, Nothing},
+	traits::{ConstU128, ConstU32, Nothing},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use module_support::mocks::MockAddressMapping;
@@ -112,6 +112,9 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type AccountCurrencies = ();
+	type Swap = module_support::SpecificJointsSwap<(), ()>;
+	type MaxTransferAllCurrencies = ConstU32<0>;
 }
 
 parameter_types! {
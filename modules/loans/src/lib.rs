@@ -27,20 +27,37 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::collapsible_if)]
 
-use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, transactional, PalletId};
-use module_support::{CDPTreasury, RiskManager};
+use frame_support::{
+	pallet_prelude::*,
+	storage::{unhashed, PrefixIterator, StoragePrefixedMap},
+	traits::{ExistenceRequirement, OnRuntimeUpgrade},
+	transactional, PalletId, ReversibleStorageHasher, StorageHasher,
+};
+use module_support::{CDPTreasury, IssuanceOrigin, RiskManager};
 use orml_traits::{Handler, MultiCurrency, MultiCurrencyExtended};
+use parity_scale_codec::EncodeLike;
 use primitives::{Amount, Balance, CurrencyId, Position};
 use sp_runtime::{
 	traits::{AccountIdConversion, Zero},
 	ArithmeticError, DispatchResult,
 };
+use sp_std::{marker::PhantomData, prelude::*};
 
 mod mock;
 mod tests;
 
 pub use module::*;
 
+/// The index of a position among the positions of an account under the same collateral
+/// type, the position of index 0 is the primary position.
+pub type PositionIndex = u32;
+
+/// The maximum length of the raw storage key of the `IndexPositions` migration cursor.
+pub const MAX_INDEX_POSITIONS_CURSOR_LEN: u32 = 256;
+
+/// The maximum number of the positions visited by the `IndexPositions` migration in a block.
+pub const MAX_INDEXED_POSITIONS_PER_IDLE: u32 = 1_000;
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -71,11 +88,21 @@ pub mod module {
 
 		/// Event handler which calls when update loan.
 		type OnUpdateLoan: Handler<(Self::AccountId, CurrencyId, Amount, Balance)>;
+
+		/// The maximum number of the positions of an account under a collateral type.
+		#[pallet::constant]
+		type MaxPositionsPerAccount: Get<u32>;
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		AmountConvertFailed,
+		/// Exceeds `MaxPositionsPerAccount`.
+		TooManyPositions,
+		/// The parts don't sum to the position, or a part is empty.
+		InvalidSplitParts,
+		/// No sub-position to merge.
+		NoPositionToMerge,
 	}
 
 	#[pallet::event]
@@ -101,16 +128,35 @@ pub mod module {
 			to: T::AccountId,
 			currency_id: CurrencyId,
 		},
+		/// The primary position is split, the first part remains in the primary position.
+		PositionSplit {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			position_indexes: Vec<PositionIndex>,
+		},
+		/// The sub-positions are merged into the primary position.
+		PositionsMerged {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			position_indexes: Vec<PositionIndex>,
+		},
 	}
 
 	/// The collateralized debit positions, map from
-	/// Owner -> CollateralType -> Position
+	/// CollateralType -> Owner -> PositionIndex -> Position
 	///
-	/// Positions: double_map CurrencyId, AccountId => Position
+	/// Positions: nmap CurrencyId, AccountId, PositionIndex => Position
 	#[pallet::storage]
-	#[pallet::getter(fn positions)]
-	pub type Positions<T: Config> =
-		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, T::AccountId, Position, ValueQuery>;
+	pub type Positions<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, CurrencyId>,
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, PositionIndex>,
+		),
+		Position,
+		ValueQuery,
+	>;
 
 	/// The total collateralized debit positions, map from
 	/// CollateralType -> Position
@@ -120,9 +166,44 @@ pub mod module {
 	#[pallet::getter(fn total_positions)]
 	pub type TotalPositions<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Position, ValueQuery>;
 
+	/// The raw storage key of the last position visited by the `IndexPositions` migration, the
+	/// migration is in progress while it exists.
+	///
+	/// IndexPositionsCursor: Option<Vec<u8>>
+	#[pallet::storage]
+	#[pallet::getter(fn index_positions_cursor)]
+	pub type IndexPositionsCursor<T: Config> =
+		StorageValue<_, BoundedVec<u8, ConstU32<MAX_INDEX_POSITIONS_CURSOR_LEN>>, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			if !IndexPositionsCursor::<T>::exists() {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let max_count = remaining_weight
+				.saturating_sub(T::DbWeight::get().reads_writes(2, 1))
+				.checked_div_per_component(&T::DbWeight::get().reads_writes(1, 2))
+				.unwrap_or_default()
+				.min(MAX_INDEXED_POSITIONS_PER_IDLE as u64) as u32;
+			if max_count == 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let count = Self::index_positions(max_count);
+			T::DbWeight::get()
+				.reads_writes(2, 1)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 2).saturating_mul(count as u64))
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
 }
@@ -132,15 +213,113 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// The primary position of `who` under `currency_id`.
+	pub fn positions(currency_id: impl EncodeLike<CurrencyId>, who: impl EncodeLike<T::AccountId>) -> Position {
+		Self::position_at(currency_id, who, 0)
+	}
+
+	/// The position of `who` under `currency_id` at `position_index`.
+	pub fn position_at(
+		currency_id: impl EncodeLike<CurrencyId>,
+		who: impl EncodeLike<T::AccountId>,
+		position_index: PositionIndex,
+	) -> Position {
+		let key = Positions::<T>::hashed_key_for((currency_id, who, position_index));
+		unhashed::get(&key).unwrap_or_else(|| {
+			// the primary position may not be indexed yet while `IndexPositions` is in progress,
+			// its unindexed key is the indexed key without the position index.
+			if position_index.is_zero() && IndexPositionsCursor::<T>::exists() {
+				let index_len = Twox64Concat::hash(&position_index.encode()).len();
+				unhashed::get_or_default(&key[..key.len() - index_len])
+			} else {
+				Default::default()
+			}
+		})
+	}
+
+	/// All the positions of `who` under `currency_id`, ordered by the position index.
+	pub fn positions_of(currency_id: CurrencyId, who: &T::AccountId) -> Vec<(PositionIndex, Position)> {
+		let mut positions: Vec<(PositionIndex, Position)> =
+			Positions::<T>::iter_prefix((currency_id, who.clone())).collect();
+		if IndexPositionsCursor::<T>::exists() {
+			if let Some(position) = v0::Positions::<T>::get(currency_id, who) {
+				positions.push((0, position));
+			}
+		}
+		positions.sort_by_key(|(position_index, _)| *position_index);
+		positions
+	}
+
+	/// Iterate all the positions in the storage order of `Positions`, starting after the raw key
+	/// `previous_key` if any. The primary positions not indexed yet by `IndexPositions` are
+	/// included as of index 0.
+	pub fn iter_positions(
+		previous_key: Option<Vec<u8>>,
+	) -> PrefixIterator<((CurrencyId, T::AccountId, PositionIndex), Position)> {
+		let prefix = Positions::<T>::final_prefix().to_vec();
+		let previous_key = previous_key.unwrap_or_else(|| prefix.clone());
+		PrefixIterator::new(prefix, previous_key, |raw_key_without_prefix, mut raw_value| {
+			let mut key_material = Twox64Concat::reverse(raw_key_without_prefix);
+			let currency_id = CurrencyId::decode(&mut key_material)?;
+			let (who, position_index) = Self::decode_position_key(key_material)?;
+			Ok(((currency_id, who, position_index), Position::decode(&mut raw_value)?))
+		})
+	}
+
+	/// Iterate the positions under `currency_id` in the storage order of `Positions`, starting
+	/// after the raw key `previous_key` if any. The primary positions not indexed yet by
+	/// `IndexPositions` are included as of index 0.
+	pub fn iter_positions_of_collateral(
+		currency_id: CurrencyId,
+		previous_key: Option<Vec<u8>>,
+	) -> PrefixIterator<((T::AccountId, PositionIndex), Position)> {
+		let prefix = [
+			Positions::<T>::final_prefix().as_slice(),
+			&Twox64Concat::hash(&currency_id.encode()),
+		]
+		.concat();
+		let previous_key = previous_key.unwrap_or_else(|| prefix.clone());
+		PrefixIterator::new(prefix, previous_key, |raw_key_without_prefix, mut raw_value| {
+			let (who, position_index) = Self::decode_position_key(raw_key_without_prefix)?;
+			Ok(((who, position_index), Position::decode(&mut raw_value)?))
+		})
+	}
+
+	/// Decode the (AccountId, PositionIndex) of the key after the collateral type, the unindexed
+	/// key is of index 0.
+	fn decode_position_key(key: &[u8]) -> Result<(T::AccountId, PositionIndex), parity_scale_codec::Error> {
+		let mut key_material = Twox64Concat::reverse(key);
+		let who = T::AccountId::decode(&mut key_material)?;
+		if key_material.is_empty() {
+			return Ok((who, 0));
+		}
+		let mut key_material = Twox64Concat::reverse(key_material);
+		let position_index = PositionIndex::decode(&mut key_material)?;
+		Ok((who, position_index))
+	}
+
 	/// confiscate collateral and debit to cdp treasury.
 	///
 	/// Ensured atomic.
-	#[transactional]
 	pub fn confiscate_collateral_and_debit(
 		who: &T::AccountId,
 		currency_id: CurrencyId,
 		collateral_confiscate: Balance,
 		debit_decrease: Balance,
+	) -> DispatchResult {
+		Self::confiscate_collateral_and_debit_at(who, currency_id, 0, collateral_confiscate, debit_decrease)
+	}
+
+	/// confiscate collateral and debit of the position at `position_index` to cdp treasury.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn confiscate_collateral_and_debit_at(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		position_index: PositionIndex,
+		collateral_confiscate: Balance,
+		debit_decrease: Balance,
 	) -> DispatchResult {
 		// convert balance type to amount type
 		let collateral_adjustment = Self::amount_try_from_balance(collateral_confiscate)?;
@@ -154,9 +333,10 @@ impl<T: Config> Pallet<T> {
 		T::CDPTreasury::on_system_debit(bad_debt_value)?;
 
 		// update loan
-		Self::update_loan(
+		Self::update_loan_at(
 			who,
 			currency_id,
+			position_index,
 			collateral_adjustment.saturating_neg(),
 			debit_adjustment.saturating_neg(),
 		)?;
@@ -215,6 +395,7 @@ impl<T: Config> Pallet<T> {
 				who,
 				T::RiskManager::get_debit_value(currency_id, debit_balance_adjustment),
 				true,
+				IssuanceOrigin::Collateral(currency_id),
 			)?;
 		} else if debit_adjustment.is_negative() {
 			// repay debit
@@ -222,6 +403,7 @@ impl<T: Config> Pallet<T> {
 			T::CDPTreasury::burn_debit(
 				who,
 				T::RiskManager::get_debit_value(currency_id, debit_balance_adjustment),
+				IssuanceOrigin::Collateral(currency_id),
 			)?;
 		}
 
@@ -276,17 +458,154 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// split the primary position of `who` into `parts` of (collateral, debit), the first part
+	/// remains in the primary position and the others are moved to the unused position indexes.
+	/// Every part must be a valid position by itself. Returns the indexes of the parts.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn split_position(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		parts: Vec<(Balance, Balance)>,
+	) -> Result<Vec<PositionIndex>, DispatchError> {
+		ensure!(parts.len() > 1, Error::<T>::InvalidSplitParts);
+
+		let Position { collateral, debit } = Self::positions(currency_id, who);
+		let (total_collateral, total_debit) = parts.iter().try_fold(
+			(Balance::zero(), Balance::zero()),
+			|(total_collateral, total_debit), (part_collateral, part_debit)| -> Result<_, DispatchError> {
+				ensure!(
+					!part_collateral.is_zero() || !part_debit.is_zero(),
+					Error::<T>::InvalidSplitParts
+				);
+				Ok((
+					total_collateral
+						.checked_add(*part_collateral)
+						.ok_or(ArithmeticError::Overflow)?,
+					total_debit.checked_add(*part_debit).ok_or(ArithmeticError::Overflow)?,
+				))
+			},
+		)?;
+		ensure!(
+			total_collateral == collateral && total_debit == debit,
+			Error::<T>::InvalidSplitParts
+		);
+
+		// the primary position is always occupied, the parts after the first one take the
+		// unused indexes in ascending order
+		let used_indexes: Vec<PositionIndex> = Self::positions_of(currency_id, who)
+			.into_iter()
+			.map(|(position_index, _)| position_index)
+			.collect();
+		let mut position_indexes: Vec<PositionIndex> = (1..T::MaxPositionsPerAccount::get())
+			.filter(|position_index| !used_indexes.contains(position_index))
+			.take(parts.len() - 1)
+			.collect();
+		ensure!(position_indexes.len() == parts.len() - 1, Error::<T>::TooManyPositions);
+		position_indexes.insert(0, 0);
+
+		for (position_index, (part_collateral, part_debit)) in position_indexes.iter().zip(parts.iter()) {
+			T::RiskManager::check_position_valid(currency_id, *part_collateral, *part_debit, true)?;
+			if position_index.is_zero() {
+				continue;
+			}
+
+			let collateral_adjustment = Self::amount_try_from_balance(*part_collateral)?;
+			let debit_adjustment = Self::amount_try_from_balance(*part_debit)?;
+			Self::update_loan_at(
+				who,
+				currency_id,
+				0,
+				collateral_adjustment.saturating_neg(),
+				debit_adjustment.saturating_neg(),
+			)?;
+			Self::update_loan_at(
+				who,
+				currency_id,
+				*position_index,
+				collateral_adjustment,
+				debit_adjustment,
+			)?;
+		}
+
+		Self::deposit_event(Event::PositionSplit {
+			owner: who.clone(),
+			collateral_type: currency_id,
+			position_indexes: position_indexes.clone(),
+		});
+		Ok(position_indexes)
+	}
+
+	/// merge all the sub-positions of `who` under `currency_id` into the primary position.
+	/// Returns the indexes of the merged sub-positions.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn merge_positions(who: &T::AccountId, currency_id: CurrencyId) -> Result<Vec<PositionIndex>, DispatchError> {
+		let sub_positions: Vec<(PositionIndex, Position)> = Self::positions_of(currency_id, who)
+			.into_iter()
+			.filter(|(position_index, _)| !position_index.is_zero())
+			.collect();
+		ensure!(!sub_positions.is_empty(), Error::<T>::NoPositionToMerge);
+
+		let mut position_indexes: Vec<PositionIndex> = Vec::with_capacity(sub_positions.len());
+		for (position_index, Position { collateral, debit }) in sub_positions {
+			let collateral_adjustment = Self::amount_try_from_balance(collateral)?;
+			let debit_adjustment = Self::amount_try_from_balance(debit)?;
+			Self::update_loan_at(
+				who,
+				currency_id,
+				position_index,
+				collateral_adjustment.saturating_neg(),
+				debit_adjustment.saturating_neg(),
+			)?;
+			Self::update_loan_at(who, currency_id, 0, collateral_adjustment, debit_adjustment)?;
+			position_indexes.push(position_index);
+		}
+
+		// the merged position must not be liquidated right away
+		let Position { collateral, debit } = Self::positions(currency_id, who);
+		T::RiskManager::check_position_valid(currency_id, collateral, debit, false)?;
+
+		Self::deposit_event(Event::PositionsMerged {
+			owner: who.clone(),
+			collateral_type: currency_id,
+			position_indexes: position_indexes.clone(),
+		});
+		Ok(position_indexes)
+	}
+
 	/// mutate records of collaterals and debits
 	pub fn update_loan(
 		who: &T::AccountId,
 		currency_id: CurrencyId,
 		collateral_adjustment: Amount,
 		debit_adjustment: Amount,
+	) -> DispatchResult {
+		Self::update_loan_at(who, currency_id, 0, collateral_adjustment, debit_adjustment)
+	}
+
+	/// mutate records of collaterals and debits of the position at `position_index`
+	pub fn update_loan_at(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		position_index: PositionIndex,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
 	) -> DispatchResult {
 		let collateral_balance = Self::balance_try_from_amount_abs(collateral_adjustment)?;
 		let debit_balance = Self::balance_try_from_amount_abs(debit_adjustment)?;
 
-		<Positions<T>>::try_mutate_exists(currency_id, who, |may_be_position| -> DispatchResult {
+		// index the primary position before it's updated while `IndexPositions` is in progress
+		if IndexPositionsCursor::<T>::exists() {
+			if let Some(position) = v0::Positions::<T>::take(currency_id, who) {
+				Positions::<T>::insert((currency_id, who, 0), position);
+			}
+		}
+
+		let position_key = (currency_id, who, position_index);
+		<Positions<T>>::try_mutate_exists(position_key, |may_be_position| -> DispatchResult {
 			let mut p = may_be_position.take().unwrap_or_default();
 			let new_collateral = if collateral_adjustment.is_positive() {
 				p.collateral
@@ -386,3 +705,141 @@ impl<T: Config> Pallet<T> {
 		TryInto::<Balance>::try_into(a.saturating_abs()).map_err(|_| Error::<T>::AmountConvertFailed)
 	}
 }
+
+mod v0 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Positions<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		CurrencyId,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		Position,
+		OptionQuery,
+	>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Index at most `max_count` unindexed positions as the primary positions, continuing from
+	/// the `IndexPositions` migration cursor. Returns the number of visited positions.
+	pub fn index_positions(max_count: u32) -> u32 {
+		let Some(cursor) = Self::index_positions_cursor() else {
+			return 0;
+		};
+		let prefix = Positions::<T>::final_prefix().to_vec();
+		let mut iter = PrefixIterator::<(Vec<u8>, Position)>::new(
+			prefix.clone(),
+			cursor.into_inner(),
+			|raw_key_without_prefix, mut raw_value| {
+				Ok((raw_key_without_prefix.to_vec(), Position::decode(&mut raw_value)?))
+			},
+		);
+
+		let mut count: u32 = 0;
+		while count < max_count {
+			let Some((raw_key_without_prefix, position)) = iter.next() else {
+				break;
+			};
+			count += 1;
+			if let Some((currency_id, who)) = Self::decode_unindexed_key(&raw_key_without_prefix) {
+				// the indexed key follows right after the unindexed key, and is visited next
+				unhashed::kill(&[prefix.as_slice(), raw_key_without_prefix.as_slice()].concat());
+				Positions::<T>::insert((currency_id, who, 0), position);
+			}
+		}
+
+		// the migration finishes once the end is reached.
+		match BoundedVec::try_from(iter.last_raw_key().to_vec()) {
+			Ok(cursor) if count == max_count => IndexPositionsCursor::<T>::put(cursor),
+			_ => IndexPositionsCursor::<T>::kill(),
+		}
+		count
+	}
+
+	/// Decode the (CurrencyId, AccountId) of the unindexed key, `None` for the indexed key.
+	fn decode_unindexed_key(raw_key_without_prefix: &[u8]) -> Option<(CurrencyId, T::AccountId)> {
+		let mut key_material = Twox64Concat::reverse(raw_key_without_prefix);
+		let currency_id = CurrencyId::decode(&mut key_material).ok()?;
+		let mut key_material = Twox64Concat::reverse(key_material);
+		let who = T::AccountId::decode(&mut key_material).ok()?;
+		key_material.is_empty().then_some((currency_id, who))
+	}
+
+	/// The count, total collateral and total debit of all the indexed and unindexed positions.
+	#[cfg(feature = "try-runtime")]
+	fn positions_summary() -> (u32, Balance, Balance) {
+		let prefix = Positions::<T>::final_prefix().to_vec();
+		PrefixIterator::<Position>::new(prefix.clone(), prefix, |_, mut raw_value| {
+			Position::decode(&mut raw_value)
+		})
+		.fold((0, 0, 0), |(count, collateral, debit), position| {
+			(
+				count.saturating_add(1),
+				collateral.saturating_add(position.collateral),
+				debit.saturating_add(position.debit),
+			)
+		})
+	}
+}
+
+/// Migrate `Positions` from the (CurrencyId, AccountId) keys to the (CurrencyId, AccountId,
+/// PositionIndex) keys, the existing positions become the primary positions of index 0.
+///
+/// The positions are indexed in batches on idle by `Pallet::index_positions`. In the meantime
+/// the unindexed primary positions are still read and updated through the loans module, while
+/// the iterations of `Positions` skip them until the migration finishes.
+pub struct IndexPositions<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for IndexPositions<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		// start from the beginning of `Positions`
+		IndexPositionsCursor::<T>::put(BoundedVec::truncate_from(Positions::<T>::final_prefix().to_vec()));
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		Ok(Pallet::<T>::positions_summary().encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let summary = <(u32, Balance, Balance)>::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == 1,
+			"The storage version of loans must be 1"
+		);
+		ensure!(
+			Pallet::<T>::positions_summary() == summary,
+			"The positions are changed by the upgrade"
+		);
+
+		// run the whole migration, then roll it back to leave the batches for on idle
+		frame_support::storage::with_transaction(|| {
+			Pallet::<T>::index_positions(u32::MAX);
+			let indexed: (u32, Balance, Balance) =
+				Positions::<T>::iter().fold((0, 0, 0), |(count, collateral, debit), (_, position)| {
+					(
+						count.saturating_add(1),
+						collateral.saturating_add(position.collateral),
+						debit.saturating_add(position.debit),
+					)
+				});
+			let result = if IndexPositionsCursor::<T>::exists() {
+				Err("The positions migration must finish".into())
+			} else if indexed != summary {
+				Err("The positions are changed by the migration".into())
+			} else {
+				Ok(())
+			};
+			frame_support::storage::TransactionOutcome::Rollback(result)
+		})
+	}
+}
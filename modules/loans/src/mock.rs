@@ -24,6 +24,7 @@ use super::*;
 use frame_support::{
 	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
 	traits::{ConstU128, ConstU32, Nothing},
+	weights::constants::RocksDbWeight,
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
@@ -57,6 +58,7 @@ impl frame_system::Config for Runtime {
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Block = Block;
 	type AccountData = pallet_balances::AccountData<Balance>;
+	type DbWeight = RocksDbWeight;
 }
 
 parameter_type_with_key! {
@@ -159,6 +161,8 @@ impl module_cdp_treasury::Config for Runtime {
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
+	type PriceSource = ();
+	type MaxBasketAssets = ConstU32<10>;
 }
 
 // mock risk manager
@@ -235,6 +239,7 @@ impl Config for Runtime {
 	type CDPTreasury = CDPTreasuryModule;
 	type PalletId = LoansPalletId;
 	type OnUpdateLoan = MockOnUpdateLoan;
+	type MaxPositionsPerAccount = ConstU32<3>;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
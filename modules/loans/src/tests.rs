@@ -131,7 +131,7 @@ fn update_loan_should_work() {
 		assert_eq!(LoansModule::total_positions(BTC).collateral, 0);
 		assert_eq!(LoansModule::positions(BTC, &ALICE).debit, 0);
 		assert_eq!(LoansModule::positions(BTC, &ALICE).collateral, 0);
-		assert!(!<Positions<Runtime>>::contains_key(BTC, &ALICE));
+		assert!(!<Positions<Runtime>>::contains_key((BTC, &ALICE, 0)));
 
 		let alice_ref_count_0 = System::consumers(&ALICE);
 
@@ -152,11 +152,11 @@ fn update_loan_should_work() {
 		assert_eq!(Currencies::free_balance(BTC, &ALICE), 1000);
 
 		// should remove position storage if zero
-		assert!(<Positions<Runtime>>::contains_key(BTC, &ALICE));
+		assert!(<Positions<Runtime>>::contains_key((BTC, &ALICE, 0)));
 		assert_ok!(LoansModule::update_loan(&ALICE, BTC, -3000, -2000));
 		assert_eq!(LoansModule::positions(BTC, &ALICE).debit, 0);
 		assert_eq!(LoansModule::positions(BTC, &ALICE).collateral, 0);
-		assert!(!<Positions<Runtime>>::contains_key(BTC, &ALICE));
+		assert!(!<Positions<Runtime>>::contains_key((BTC, &ALICE, 0)));
 
 		// decrease ref count after remove position
 		let alice_ref_count_2 = System::consumers(&ALICE);
@@ -234,3 +234,297 @@ fn loan_updated_updated_when_adjust_collateral() {
 		assert_eq!(DOT_SHARES.with(|v| *v.borrow().get(&BOB).unwrap_or(&0)), 200);
 	});
 }
+
+#[test]
+fn split_position_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(LoansModule::adjust_position(&ALICE, BTC, 600, 300));
+		let alice_ref_count = System::consumers(&ALICE);
+
+		// the parts must sum to the position exactly
+		assert_noop!(
+			LoansModule::split_position(&ALICE, BTC, vec![(300, 100), (200, 100)]),
+			Error::<Runtime>::InvalidSplitParts
+		);
+		assert_noop!(
+			LoansModule::split_position(&ALICE, BTC, vec![(600, 300), (0, 0)]),
+			Error::<Runtime>::InvalidSplitParts
+		);
+		assert_noop!(
+			LoansModule::split_position(&ALICE, BTC, vec![(600, 300)]),
+			Error::<Runtime>::InvalidSplitParts
+		);
+		assert_noop!(
+			LoansModule::split_position(&ALICE, BTC, vec![(300, 100), (200, 100), (50, 50), (50, 50)]),
+			Error::<Runtime>::TooManyPositions
+		);
+
+		assert_eq!(
+			LoansModule::split_position(&ALICE, BTC, vec![(300, 100), (200, 100), (100, 100)]),
+			Ok(vec![0, 1, 2])
+		);
+		System::assert_last_event(RuntimeEvent::LoansModule(crate::Event::PositionSplit {
+			owner: ALICE,
+			collateral_type: BTC,
+			position_indexes: vec![0, 1, 2],
+		}));
+		assert_eq!(
+			LoansModule::positions_of(BTC, &ALICE),
+			vec![
+				(
+					0,
+					Position {
+						collateral: 300,
+						debit: 100
+					}
+				),
+				(
+					1,
+					Position {
+						collateral: 200,
+						debit: 100
+					}
+				),
+				(
+					2,
+					Position {
+						collateral: 100,
+						debit: 100
+					}
+				),
+			]
+		);
+		assert_eq!(LoansModule::positions(BTC, &ALICE).collateral, 300);
+		assert_eq!(LoansModule::position_at(BTC, &ALICE, 2).debit, 100);
+
+		// the totals and the collateral held by the module are preserved
+		assert_eq!(LoansModule::total_positions(BTC).collateral, 600);
+		assert_eq!(LoansModule::total_positions(BTC).debit, 300);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), 600);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 400);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count + 2);
+
+		// no unused position index
+		assert_noop!(
+			LoansModule::split_position(&ALICE, BTC, vec![(200, 50), (100, 50)]),
+			Error::<Runtime>::TooManyPositions
+		);
+	});
+}
+
+#[test]
+fn split_position_should_check_each_part() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(LoansModule::update_loan(&ALICE, DOT, 600, 300));
+		assert_noop!(
+			LoansModule::split_position(&ALICE, DOT, vec![(300, 150), (300, 150)]),
+			sp_runtime::DispatchError::Other("mock below required collateral ratio error")
+		);
+	});
+}
+
+#[test]
+fn merge_positions_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(LoansModule::adjust_position(&ALICE, BTC, 600, 300));
+		let alice_ref_count = System::consumers(&ALICE);
+		assert_noop!(
+			LoansModule::merge_positions(&ALICE, BTC),
+			Error::<Runtime>::NoPositionToMerge
+		);
+
+		assert_ok!(LoansModule::split_position(
+			&ALICE,
+			BTC,
+			vec![(300, 100), (200, 100), (100, 100)]
+		));
+		assert_eq!(LoansModule::merge_positions(&ALICE, BTC), Ok(vec![1, 2]));
+		System::assert_last_event(RuntimeEvent::LoansModule(crate::Event::PositionsMerged {
+			owner: ALICE,
+			collateral_type: BTC,
+			position_indexes: vec![1, 2],
+		}));
+
+		// the round trip restores the position
+		assert_eq!(
+			LoansModule::positions_of(BTC, &ALICE),
+			vec![(
+				0,
+				Position {
+					collateral: 600,
+					debit: 300
+				}
+			)]
+		);
+		assert_eq!(LoansModule::total_positions(BTC).collateral, 600);
+		assert_eq!(LoansModule::total_positions(BTC).debit, 300);
+		assert_eq!(Currencies::free_balance(BTC, &LoansModule::account_id()), 600);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count);
+
+		// the sub-positions are merged even if the primary position is closed
+		assert_ok!(LoansModule::split_position(&ALICE, BTC, vec![(400, 200), (200, 100)]));
+		assert_ok!(LoansModule::adjust_position(&ALICE, BTC, -400, -200));
+		assert_eq!(LoansModule::merge_positions(&ALICE, BTC), Ok(vec![1]));
+		assert_eq!(
+			LoansModule::positions(BTC, &ALICE),
+			Position {
+				collateral: 200,
+				debit: 100
+			}
+		);
+		assert_eq!(LoansModule::total_positions(BTC).collateral, 200);
+		assert_eq!(LoansModule::total_positions(BTC).debit, 100);
+	});
+}
+
+#[test]
+fn index_positions_migration_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(0).put::<LoansModule>();
+		let position = Position {
+			collateral: 500,
+			debit: 300,
+		};
+		v0::Positions::<Runtime>::insert(BTC, ALICE, position);
+		v0::Positions::<Runtime>::insert(DOT, ALICE, position);
+		v0::Positions::<Runtime>::insert(DOT, BOB, position);
+
+		IndexPositions::<Runtime>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<LoansModule>(), 1);
+		assert!(LoansModule::index_positions_cursor().is_some());
+
+		// the unindexed positions are still readable
+		assert_eq!(LoansModule::positions(BTC, &ALICE), position);
+		assert_eq!(LoansModule::positions_of(DOT, &BOB), vec![(0, position)]);
+		assert_eq!(LoansModule::position_at(DOT, &BOB, 1), Position::default());
+
+		// each indexed position is visited again right after its unindexed key
+		assert_eq!(LoansModule::index_positions(2), 2);
+		assert!(LoansModule::index_positions_cursor().is_some());
+		assert_eq!(LoansModule::index_positions(10), 4);
+		assert_eq!(LoansModule::index_positions_cursor(), None);
+		assert_eq!(LoansModule::index_positions(10), 0);
+
+		assert_eq!(Positions::<Runtime>::iter().count(), 3);
+		assert_eq!(LoansModule::positions(BTC, &ALICE), position);
+		assert_eq!(LoansModule::positions(DOT, &ALICE), position);
+		assert_eq!(LoansModule::positions_of(DOT, &BOB), vec![(0, position)]);
+
+		// only migrates once
+		IndexPositions::<Runtime>::on_runtime_upgrade();
+		assert_eq!(LoansModule::index_positions_cursor(), None);
+		assert_eq!(Positions::<Runtime>::iter().count(), 3);
+	});
+}
+
+#[test]
+fn update_unindexed_position_during_migration() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(0).put::<LoansModule>();
+		v0::Positions::<Runtime>::insert(
+			BTC,
+			ALICE,
+			Position {
+				collateral: 500,
+				debit: 300,
+			},
+		);
+		IndexPositions::<Runtime>::on_runtime_upgrade();
+
+		// the unindexed position is indexed before it's updated
+		assert_ok!(LoansModule::update_loan(&ALICE, BTC, 100, 50));
+		assert_eq!(v0::Positions::<Runtime>::get(BTC, ALICE), None);
+		assert_eq!(
+			LoansModule::positions(BTC, &ALICE),
+			Position {
+				collateral: 600,
+				debit: 350
+			}
+		);
+
+		// the migration doesn't override the updated position
+		assert_eq!(LoansModule::index_positions(10), 1);
+		assert_eq!(LoansModule::index_positions_cursor(), None);
+		assert_eq!(
+			LoansModule::positions(BTC, &ALICE),
+			Position {
+				collateral: 600,
+				debit: 350
+			}
+		);
+	});
+}
+
+#[test]
+fn iter_positions_include_unindexed_positions() {
+	ExtBuilder::default().build().execute_with(|| {
+		StorageVersion::new(0).put::<LoansModule>();
+		let position = Position {
+			collateral: 500,
+			debit: 300,
+		};
+		v0::Positions::<Runtime>::insert(BTC, ALICE, position);
+		v0::Positions::<Runtime>::insert(DOT, BOB, position);
+		IndexPositions::<Runtime>::on_runtime_upgrade();
+		assert_ok!(LoansModule::update_loan_at(&BOB, BTC, 1, 100, 50));
+
+		let mut positions = LoansModule::iter_positions(None).collect::<Vec<_>>();
+		positions.sort_by_key(|((currency_id, who, position_index), _)| (*currency_id, *who, *position_index));
+		let mut expected = vec![
+			((BTC, ALICE, 0), position),
+			(
+				(BTC, BOB, 1),
+				Position {
+					collateral: 100,
+					debit: 50,
+				},
+			),
+			((DOT, BOB, 0), position),
+		];
+		expected.sort_by_key(|((currency_id, who, position_index), _)| (*currency_id, *who, *position_index));
+		assert_eq!(positions, expected);
+		assert_eq!(
+			LoansModule::iter_positions_of_collateral(DOT, None).collect::<Vec<_>>(),
+			vec![((BOB, 0), position)]
+		);
+
+		// nothing is visited twice once indexed
+		LoansModule::index_positions(10);
+		assert_eq!(LoansModule::index_positions_cursor(), None);
+		assert_eq!(LoansModule::iter_positions(None).count(), 3);
+		assert_eq!(LoansModule::iter_positions_of_collateral(BTC, None).count(), 2);
+	});
+}
+
+#[test]
+fn index_positions_on_idle() {
+	ExtBuilder::default().build().execute_with(|| {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		assert_eq!(LoansModule::on_idle(1, Weight::MAX), db_weight.reads(1));
+
+		StorageVersion::new(0).put::<LoansModule>();
+		let position = Position {
+			collateral: 500,
+			debit: 300,
+		};
+		v0::Positions::<Runtime>::insert(BTC, ALICE, position);
+		v0::Positions::<Runtime>::insert(DOT, BOB, position);
+		IndexPositions::<Runtime>::on_runtime_upgrade();
+
+		// no room for a single position
+		assert_eq!(
+			LoansModule::on_idle(1, db_weight.reads_writes(2, 1)),
+			db_weight.reads(1)
+		);
+		assert!(LoansModule::index_positions_cursor().is_some());
+
+		assert_eq!(
+			LoansModule::on_idle(1, Weight::MAX),
+			db_weight
+				.reads_writes(2, 1)
+				.saturating_add(db_weight.reads_writes(1, 2).saturating_mul(4))
+		);
+		assert_eq!(LoansModule::index_positions_cursor(), None);
+		assert_eq!(Positions::<Runtime>::iter().count(), 2);
+	});
+}
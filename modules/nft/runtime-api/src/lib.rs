@@ -0,0 +1,55 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use sp_runtime::codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait NftApi<AccountId, ClassId, TokenId, ClassDetails, TokenDetails> where
+		AccountId: Codec,
+		ClassId: Codec,
+		TokenId: Codec,
+		ClassDetails: Codec,
+		TokenDetails: Codec,
+	{
+		fn get_class_info(class_id: ClassId) -> Option<ClassDetails>;
+
+		fn get_token(class_id: ClassId, token_id: TokenId) -> Option<TokenDetails>;
+
+		/// Scans at most `limit` token ids from `cursor`. `filter` is an exact-match attribute
+		/// `(key, value)` applied best-effort within the scanned page.
+		fn get_tokens_by_class(
+			class_id: ClassId,
+			cursor: Option<TokenId>,
+			limit: u32,
+			filter: Option<(Vec<u8>, Vec<u8>)>,
+		) -> (Vec<TokenDetails>, Option<TokenId>);
+
+		/// Scans at most `limit` tokens owned by `account` after `cursor`. `filter` is an
+		/// exact-match attribute `(key, value)` applied best-effort within the scanned page.
+		fn get_tokens_by_owner(
+			account: AccountId,
+			cursor: Option<(ClassId, TokenId)>,
+			limit: u32,
+			filter: Option<(Vec<u8>, Vec<u8>)>,
+		) -> (Vec<TokenDetails>, Option<(ClassId, TokenId)>);
+	}
+}
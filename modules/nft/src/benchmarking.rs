@@ -148,6 +148,51 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 	}: _(RawOrigin::Signed(module_account), 0u32.into(), Properties(ClassProperty::Transferable.into()))
+
+	// mint NFT airdrop
+	mint_airdrop {
+		let n in 1 .. MAX_AIRDROP_RECIPIENTS;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let recipients: Vec<T::AccountId> = (0..n).map(|i| account("to", i, SEED)).collect();
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), recipients.try_into().unwrap(), vec![1], test_attr())
+
+	// schedule NFT airdrop
+	schedule_airdrop {
+		let n in 1 .. T::MaxAirdropRecipients::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let recipients: Vec<T::AccountId> = (0..n).map(|i| account("to", i, SEED)).collect();
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), recipients, vec![1], test_attr())
+
+	// cancel NFT airdrop
+	cancel_airdrop {
+		let n in 1 .. T::MaxAirdropRecipients::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let recipients: Vec<T::AccountId> = (0..n).map(|i| account("to", i, SEED)).collect();
+
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::schedule_airdrop(RawOrigin::Signed(module_account.clone()).into(), 0u32.into(), recipients, vec![1], test_attr())?;
+	}: _(RawOrigin::Signed(module_account), 0)
+
+	// mint NFT airdrop by idle scheduler
+	process_airdrop {
+		let n in 0 .. MAX_AIRDROP_RECIPIENTS;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let recipients: Vec<T::AccountId> = (0..MAX_AIRDROP_RECIPIENTS + 1).map(|i| account("to", i, SEED)).collect();
+
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::schedule_airdrop(RawOrigin::Signed(module_account).into(), 0u32.into(), recipients, vec![1], test_attr())?;
+		let weight = <T as Config>::WeightInfo::process_airdrop(n);
+	}: {
+		let _ = NftTask::<T>::Airdrop { airdrop_id: 0, class_id: 0u32.into() }.dispatch(weight);
+	}
 }
 
 #[cfg(test)]
@@ -261,9 +306,35 @@ mod mock {
 		type DataDepositPerByte = ConstU128<10>;
 		type PalletId = NftPalletId;
 		type MaxAttributesBytes = ConstU32<2048>;
+		type MaxAirdropRecipients = ConstU32<1000>;
+		type Task = MockTask;
+		type IdleScheduler = MockIdleScheduler;
 		type WeightInfo = ();
 	}
 
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct MockTask;
+	impl DispatchableTask for MockTask {
+		fn dispatch(self, _weight: Weight) -> TaskResult {
+			unimplemented!()
+		}
+	}
+	impl From<NftTask<Runtime>> for MockTask {
+		fn from(_task: NftTask<Runtime>) -> Self {
+			MockTask
+		}
+	}
+
+	pub struct MockIdleScheduler;
+	impl IdleScheduler<Nonce, MockTask> for MockIdleScheduler {
+		fn schedule(_task: MockTask) -> Result<Nonce, DispatchError> {
+			Ok(0)
+		}
+		fn dispatch(_id: Nonce, weight: Weight) -> Weight {
+			weight
+		}
+	}
+
 	impl orml_nft::Config for Runtime {
 		type ClassId = u32;
 		type TokenId = u64;
@@ -24,6 +24,7 @@
 use frame_support::{
 	pallet_prelude::*,
 	require_transactional,
+	storage::with_storage_layer,
 	traits::{
 		tokens::nonfungibles::{Inspect, Mutate, Transfer},
 		Currency,
@@ -33,19 +34,22 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
+use module_support::{DispatchableTask, IdleScheduler};
 use orml_traits::InspectExtended;
+use parity_scale_codec::FullCodec;
 use primitives::{
 	nft::{Attributes, ClassProperty, NFTBalance, Properties, CID},
-	ReserveIdentifier,
+	task::TaskResult,
+	Nonce, ReserveIdentifier,
 };
 use scale_info::TypeInfo;
 
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
-	traits::{AccountIdConversion, Hash, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	traits::{AccountIdConversion, Hash, One, Saturating, StaticLookup, Zero},
+	ArithmeticError, DispatchResult, RuntimeDebug,
 };
-use sp_std::prelude::*;
+use sp_std::{cmp, fmt::Debug, prelude::*};
 
 pub mod benchmarking;
 mod mock;
@@ -73,10 +77,81 @@ pub struct TokenData<Balance> {
 	pub attributes: Attributes,
 }
 
+/// An airdrop scheduled to be minted by the idle scheduler.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct AirdropInfo<AccountId, ClassId, Balance> {
+	/// The class owner who mints the tokens
+	pub owner: AccountId,
+	/// The class of the tokens
+	pub class_id: ClassId,
+	/// Token metadata
+	pub metadata: CID,
+	/// Token attributes
+	pub attributes: Attributes,
+	/// The number of recipients
+	pub total: u32,
+	/// The number of recipients who have been minted
+	pub processed: u32,
+	/// Deposit reserved for each unprocessed recipient, include the token deposit
+	pub recipient_deposit: Balance,
+	/// Deposit reserved for the airdrop info
+	pub base_deposit: Balance,
+}
+
+/// Class details returned by the NFT runtime api.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct ClassDetails<AccountId, TokenId, Balance> {
+	/// The class owner
+	pub owner: AccountId,
+	/// Class metadata
+	pub metadata: Vec<u8>,
+	/// The number of existing tokens
+	pub total_issuance: TokenId,
+	/// The token id of the next minted token
+	pub next_token_id: TokenId,
+	/// Deposit reserved to create token class
+	pub deposit: Balance,
+	/// Class properties
+	pub properties: Properties,
+	/// Class attributes
+	pub attributes: Attributes,
+}
+
+/// Token details returned by the NFT runtime api.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct TokenDetails<AccountId, ClassId, TokenId, Balance> {
+	/// The class of the token
+	pub class_id: ClassId,
+	/// The token id
+	pub token_id: TokenId,
+	/// The token owner
+	pub owner: AccountId,
+	/// Token metadata
+	pub metadata: Vec<u8>,
+	/// Deposit reserved to create token
+	pub deposit: Balance,
+	/// Token attributes
+	pub attributes: Attributes,
+}
+
+pub type AirdropId = u32;
 pub type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
 pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
 pub type BalanceOf<T> =
 	<<T as pallet_proxy::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type AirdropInfoOf<T> = AirdropInfo<<T as frame_system::Config>::AccountId, ClassIdOf<T>, BalanceOf<T>>;
+pub type ClassDetailsOf<T> = ClassDetails<<T as frame_system::Config>::AccountId, TokenIdOf<T>, BalanceOf<T>>;
+pub type TokenDetailsOf<T> =
+	TokenDetails<<T as frame_system::Config>::AccountId, ClassIdOf<T>, TokenIdOf<T>, BalanceOf<T>>;
+
+/// An exact-match `(key, value)` filter on token attributes.
+pub type AttributeFilter = (Vec<u8>, Vec<u8>);
+
+/// The maximum number of recipients minted by `mint_airdrop` or per airdrop task dispatch.
+pub const MAX_AIRDROP_RECIPIENTS: u32 = 100;
+
+/// The maximum number of tokens scanned by a single token enumeration query.
+pub const MAX_TOKEN_QUERY_LIMIT: u32 = 100;
 
 #[frame_support::pallet]
 pub mod module {
@@ -119,6 +194,16 @@ pub mod module {
 		#[pallet::constant]
 		type MaxAttributesBytes: Get<u32>;
 
+		/// Maximum number of recipients of a scheduled airdrop
+		#[pallet::constant]
+		type MaxAirdropRecipients: Get<u32>;
+
+		/// Dispatchable tasks
+		type Task: DispatchableTask + FullCodec + Debug + Clone + PartialEq + TypeInfo + From<NftTask<Self>>;
+
+		/// Idle scheduler for the airdrop tasks.
+		type IdleScheduler: IdleScheduler<Nonce, Self::Task>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -148,6 +233,10 @@ pub mod module {
 		AttributesTooLarge,
 		/// The given token ID is not correct
 		IncorrectTokenId,
+		/// Too many recipients of the airdrop
+		TooManyAirdropRecipients,
+		/// Airdrop not found
+		AirdropNotFound,
 	}
 
 	#[pallet::event]
@@ -190,9 +279,63 @@ pub mod module {
 			owner: T::AccountId,
 			class_id: ClassIdOf<T>,
 		},
+		/// Scheduled NFT airdrop.
+		AirdropScheduled {
+			airdrop_id: AirdropId,
+			owner: T::AccountId,
+			class_id: ClassIdOf<T>,
+			total: u32,
+		},
+		/// Minted part of the NFT airdrop.
+		AirdropProgressed {
+			airdrop_id: AirdropId,
+			processed: u32,
+			total: u32,
+		},
+		/// Minted all of the NFT airdrop.
+		AirdropCompleted { airdrop_id: AirdropId },
+		/// Cancelled NFT airdrop and refunded the deposit of unprocessed recipients.
+		AirdropCancelled {
+			airdrop_id: AirdropId,
+			processed: u32,
+			refunded: BalanceOf<T>,
+		},
+		/// Aborted NFT airdrop because of minting failure and refunded the deposit of unprocessed
+		/// recipients.
+		AirdropAborted {
+			airdrop_id: AirdropId,
+			processed: u32,
+			refunded: BalanceOf<T>,
+			error: DispatchError,
+		},
 	}
 
+	/// The scheduled airdrops.
+	///
+	/// Airdrops: map AirdropId => Option<AirdropInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn airdrops)]
+	pub type Airdrops<T: Config> = StorageMap<_, Twox64Concat, AirdropId, AirdropInfoOf<T>, OptionQuery>;
+
+	/// The unprocessed recipients of the scheduled airdrops.
+	///
+	/// AirdropRecipients: double_map AirdropId, Index => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn airdrop_recipients)]
+	pub type AirdropRecipients<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, AirdropId, Twox64Concat, u32, T::AccountId, OptionQuery>;
+
+	/// The next airdrop id.
+	///
+	/// NextAirdropId: AirdropId
+	#[pallet::storage]
+	#[pallet::getter(fn next_airdrop_id)]
+	pub type NextAirdropId<T: Config> = StorageValue<_, AirdropId, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -383,6 +526,74 @@ pub mod module {
 				Ok(())
 			})
 		}
+
+		/// Mint one NFT token to each of the recipients. The deposit of each token is the same as
+		/// minting it by `mint`.
+		///
+		/// - `class_id`: token belong to the class id
+		/// - `recipients`: the token owners' accounts
+		/// - `metadata`: external metadata
+		/// - `attributes`: token attributes
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::mint_airdrop(recipients.len() as u32))]
+		pub fn mint_airdrop(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			recipients: BoundedVec<T::AccountId, ConstU32<MAX_AIRDROP_RECIPIENTS>>,
+			metadata: CID,
+			attributes: Attributes,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!recipients.is_empty(), Error::<T>::InvalidQuantity);
+			for to in recipients.iter() {
+				Self::do_mint(&who, to, class_id, metadata.clone(), attributes.clone(), 1)?;
+			}
+			Ok(())
+		}
+
+		/// Schedule an airdrop of one NFT token to each of the recipients, which is minted by the
+		/// idle scheduler across blocks. The deposit of all the tokens and the storage of the
+		/// airdrop is reserved from the caller, and the deposit of unprocessed recipients is
+		/// refunded if the airdrop is cancelled.
+		///
+		/// - `class_id`: token belong to the class id
+		/// - `recipients`: the token owners' accounts
+		/// - `metadata`: external metadata
+		/// - `attributes`: token attributes
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_airdrop(recipients.len() as u32))]
+		pub fn schedule_airdrop(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			recipients: Vec<T::AccountId>,
+			metadata: CID,
+			attributes: Attributes,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_schedule_airdrop(who, class_id, recipients, metadata, attributes)?;
+			Ok(())
+		}
+
+		/// Cancel the scheduled airdrop and refund the deposit of unprocessed recipients.
+		///
+		/// - `airdrop_id`: The airdrop ID to cancel
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_airdrop(T::MaxAirdropRecipients::get()))]
+		pub fn cancel_airdrop(origin: OriginFor<T>, airdrop_id: AirdropId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let info = Self::airdrops(airdrop_id).ok_or(Error::<T>::AirdropNotFound)?;
+			ensure!(who == info.owner, Error::<T>::NoPermission);
+
+			let remaining = info.total.saturating_sub(info.processed);
+			let refunded = Self::remove_airdrop(airdrop_id, &info);
+
+			Self::deposit_event(Event::AirdropCancelled {
+				airdrop_id,
+				processed: info.processed,
+				refunded,
+			});
+			Ok(Some(<T as Config>::WeightInfo::cancel_airdrop(remaining)).into())
+		}
 	}
 }
 
@@ -522,6 +733,181 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	#[require_transactional]
+	fn do_schedule_airdrop(
+		who: T::AccountId,
+		class_id: ClassIdOf<T>,
+		recipients: Vec<T::AccountId>,
+		metadata: CID,
+		attributes: Attributes,
+	) -> Result<AirdropId, DispatchError> {
+		ensure!(!recipients.is_empty(), Error::<T>::InvalidQuantity);
+		ensure!(
+			recipients.len() <= T::MaxAirdropRecipients::get() as usize,
+			Error::<T>::TooManyAirdropRecipients
+		);
+		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(who == class_info.owner, Error::<T>::NoPermission);
+		ensure!(
+			class_info.data.properties.0.contains(ClassProperty::Mintable),
+			Error::<T>::NonMintable
+		);
+
+		// the same token deposit as `do_mint`, plus the storage of the recipient.
+		let data_deposit = Self::data_deposit(&metadata, &attributes)?;
+		let token_deposit = T::CreateTokenDeposit::get().saturating_add(data_deposit);
+		let recipient_storage_deposit =
+			T::DataDepositPerByte::get().saturating_mul((T::AccountId::max_encoded_len() as u32).into());
+		let total = recipients.len() as u32;
+
+		let mut info = AirdropInfo {
+			owner: who.clone(),
+			class_id,
+			metadata,
+			attributes,
+			total,
+			processed: 0,
+			recipient_deposit: token_deposit.saturating_add(recipient_storage_deposit),
+			base_deposit: Zero::zero(),
+		};
+		info.base_deposit = T::DataDepositPerByte::get().saturating_mul((info.encoded_size() as u32).into());
+		let total_deposit = info
+			.recipient_deposit
+			.saturating_mul(total.into())
+			.saturating_add(info.base_deposit);
+		<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &who, total_deposit)?;
+
+		let airdrop_id = NextAirdropId::<T>::try_mutate(|id| -> Result<AirdropId, DispatchError> {
+			let current_id = *id;
+			*id = id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			Ok(current_id)
+		})?;
+		for (index, to) in recipients.into_iter().enumerate() {
+			AirdropRecipients::<T>::insert(airdrop_id, index as u32, to);
+		}
+		Airdrops::<T>::insert(airdrop_id, info);
+		T::IdleScheduler::schedule(NftTask::Airdrop { airdrop_id, class_id }.into())?;
+
+		Self::deposit_event(Event::AirdropScheduled {
+			airdrop_id,
+			owner: who,
+			class_id,
+			total,
+		});
+		Ok(airdrop_id)
+	}
+
+	/// Remove the airdrop and refund the deposit of the unprocessed recipients.
+	fn remove_airdrop(airdrop_id: AirdropId, info: &AirdropInfoOf<T>) -> BalanceOf<T> {
+		let remaining = info.total.saturating_sub(info.processed);
+		if !remaining.is_zero() {
+			let _ = AirdropRecipients::<T>::clear_prefix(airdrop_id, remaining, None);
+		}
+		Airdrops::<T>::remove(airdrop_id);
+
+		let refund = info
+			.recipient_deposit
+			.saturating_mul(remaining.into())
+			.saturating_add(info.base_deposit);
+		let remain = <T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &info.owner, refund);
+		refund.saturating_sub(remain)
+	}
+
+	/// Mint the airdrop to the next recipients within the weight limit.
+	fn process_airdrop(airdrop_id: AirdropId, weight: Weight) -> TaskResult {
+		let Some(mut info) = Self::airdrops(airdrop_id) else {
+			// the airdrop has been cancelled.
+			return TaskResult {
+				result: Ok(()),
+				used_weight: <T as frame_system::Config>::DbWeight::get().reads(1),
+				finished: true,
+			};
+		};
+
+		let base_weight = <T as Config>::WeightInfo::process_airdrop(0).ref_time();
+		let weight_per_recipient = <T as Config>::WeightInfo::process_airdrop(1)
+			.ref_time()
+			.saturating_sub(base_weight)
+			.max(1);
+		let limit = cmp::min(
+			weight.ref_time().saturating_sub(base_weight) / weight_per_recipient,
+			info.total
+				.saturating_sub(info.processed)
+				.min(MAX_AIRDROP_RECIPIENTS)
+				.into(),
+		) as u32;
+		if limit.is_zero() {
+			return TaskResult {
+				result: Ok(()),
+				used_weight: Weight::zero(),
+				finished: false,
+			};
+		}
+
+		let mut count: u32 = 0;
+		let mut result: DispatchResult = Ok(());
+		while count < limit {
+			let index = info.processed;
+			let minted = with_storage_layer(|| -> DispatchResult {
+				let to = AirdropRecipients::<T>::take(airdrop_id, index).ok_or(Error::<T>::AirdropNotFound)?;
+				<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &info.owner, info.recipient_deposit);
+				Self::do_mint(
+					&info.owner,
+					&to,
+					info.class_id,
+					info.metadata.clone(),
+					info.attributes.clone(),
+					1,
+				)?;
+				Ok(())
+			});
+			count += 1;
+			if let Err(e) = minted {
+				result = Err(e);
+				break;
+			}
+			info.processed += 1;
+		}
+		let used_weight = <T as Config>::WeightInfo::process_airdrop(count);
+
+		if let Err(error) = result {
+			let refunded = Self::remove_airdrop(airdrop_id, &info);
+			Self::deposit_event(Event::AirdropAborted {
+				airdrop_id,
+				processed: info.processed,
+				refunded,
+				error,
+			});
+			return TaskResult {
+				result,
+				used_weight,
+				finished: true,
+			};
+		}
+
+		if info.processed == info.total {
+			Self::remove_airdrop(airdrop_id, &info);
+			Self::deposit_event(Event::AirdropCompleted { airdrop_id });
+			TaskResult {
+				result,
+				used_weight,
+				finished: true,
+			}
+		} else {
+			Self::deposit_event(Event::AirdropProgressed {
+				airdrop_id,
+				processed: info.processed,
+				total: info.total,
+			});
+			Airdrops::<T>::insert(airdrop_id, info);
+			TaskResult {
+				result,
+				used_weight,
+				finished: false,
+			}
+		}
+	}
+
 	fn data_deposit(metadata: &[u8], attributes: &Attributes) -> Result<BalanceOf<T>, DispatchError> {
 		// Addition can't overflow because we will be out of memory before that
 		let attributes_len = attributes.iter().fold(0, |acc, (k, v)| {
@@ -538,6 +924,111 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Details of the class `class_id`.
+	pub fn get_class_info(class_id: ClassIdOf<T>) -> Option<ClassDetailsOf<T>> {
+		orml_nft::Pallet::<T>::classes(class_id).map(|class_info| ClassDetails {
+			owner: class_info.owner,
+			metadata: class_info.metadata.into_inner(),
+			total_issuance: class_info.total_issuance,
+			next_token_id: orml_nft::Pallet::<T>::next_token_id(class_id),
+			deposit: class_info.data.deposit,
+			properties: class_info.data.properties,
+			attributes: class_info.data.attributes,
+		})
+	}
+
+	/// Details of the token `(class_id, token_id)`.
+	pub fn get_token(class_id: ClassIdOf<T>, token_id: TokenIdOf<T>) -> Option<TokenDetailsOf<T>> {
+		orml_nft::Pallet::<T>::tokens(class_id, token_id).map(|token_info| TokenDetails {
+			class_id,
+			token_id,
+			owner: token_info.owner,
+			metadata: token_info.metadata.into_inner(),
+			deposit: token_info.data.deposit,
+			attributes: token_info.data.attributes,
+		})
+	}
+
+	/// Enumerate the tokens of `class_id` in token id order, starting at token id `cursor`.
+	///
+	/// At most `limit` token ids are scanned, capped at `MAX_TOKEN_QUERY_LIMIT`. Burned ids are
+	/// skipped and `filter` is applied within the scanned ids only, so a page may be shorter than
+	/// `limit` or even empty while the returned cursor is `Some`. Returns the tokens and the cursor
+	/// of the next page, `None` once all minted token ids have been scanned.
+	pub fn get_tokens_by_class(
+		class_id: ClassIdOf<T>,
+		cursor: Option<TokenIdOf<T>>,
+		limit: u32,
+		filter: Option<AttributeFilter>,
+	) -> (Vec<TokenDetailsOf<T>>, Option<TokenIdOf<T>>) {
+		let end = orml_nft::Pallet::<T>::next_token_id(class_id);
+		let mut token_id = cursor.unwrap_or_default();
+		let mut tokens = vec![];
+
+		for _ in 0..Self::query_limit(limit) {
+			if token_id >= end {
+				break;
+			}
+			if let Some(token) = Self::get_token(class_id, token_id) {
+				if Self::matches_filter(&token.attributes, &filter) {
+					tokens.push(token);
+				}
+			}
+			token_id = token_id.saturating_add(One::one());
+		}
+
+		let next = if token_id < end { Some(token_id) } else { None };
+		(tokens, next)
+	}
+
+	/// Enumerate the tokens owned by `owner`, starting after the `(class_id, token_id)` cursor.
+	///
+	/// Tokens are visited in storage order, which is stable across blocks: tokens minted or
+	/// transferred in after the cursor position may be skipped, but no token is returned twice.
+	/// At most `limit` tokens are scanned, capped at `MAX_TOKEN_QUERY_LIMIT`, and `filter` is
+	/// applied within the scanned tokens only. Returns the tokens and the cursor of the next page,
+	/// `None` once all tokens of the owner have been scanned.
+	pub fn get_tokens_by_owner(
+		owner: &T::AccountId,
+		cursor: Option<(ClassIdOf<T>, TokenIdOf<T>)>,
+		limit: u32,
+		filter: Option<AttributeFilter>,
+	) -> (Vec<TokenDetailsOf<T>>, Option<(ClassIdOf<T>, TokenIdOf<T>)>) {
+		let mut iter = match cursor {
+			Some((class_id, token_id)) => orml_nft::TokensByOwner::<T>::iter_prefix_from(
+				(owner,),
+				orml_nft::TokensByOwner::<T>::hashed_key_for((owner, class_id, token_id)),
+			),
+			None => orml_nft::TokensByOwner::<T>::iter_prefix((owner,)),
+		};
+		let mut tokens = vec![];
+		let mut last = None;
+
+		for ((class_id, token_id), _) in iter.by_ref().take(Self::query_limit(limit) as usize) {
+			if let Some(token) = Self::get_token(class_id, token_id) {
+				if Self::matches_filter(&token.attributes, &filter) {
+					tokens.push(token);
+				}
+			}
+			last = Some((class_id, token_id));
+		}
+
+		let next = if iter.next().is_some() { last } else { None };
+		(tokens, next)
+	}
+
+	fn query_limit(limit: u32) -> u32 {
+		limit.clamp(1, MAX_TOKEN_QUERY_LIMIT)
+	}
+
+	fn matches_filter(attributes: &Attributes, filter: &Option<AttributeFilter>) -> bool {
+		filter
+			.as_ref()
+			.map_or(true, |(key, value)| attributes.get(key) == Some(value))
+	}
+}
+
 impl<T: Config> InspectExtended<T::AccountId> for Pallet<T> {
 	type Balance = NFTBalance;
 
@@ -602,3 +1093,27 @@ impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
 		Self::do_transfer(&owner, destination, (*class, *instance))
 	}
 }
+
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum NftTask<T: Config> {
+	/// Mint the scheduled airdrop.
+	Airdrop {
+		airdrop_id: AirdropId,
+		class_id: ClassIdOf<T>,
+	},
+}
+
+impl<T: Config> DispatchableTask for NftTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			NftTask::Airdrop { airdrop_id, .. } => Pallet::<T>::process_airdrop(airdrop_id, weight),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: Config> From<NftTask<T>> for () {
+	fn from(_task: NftTask<T>) -> Self {
+		unimplemented!()
+	}
+}
@@ -29,10 +29,10 @@ use frame_system::EnsureSignedBy;
 use module_support::mocks::MockAddressMapping;
 use orml_traits::parameter_type_with_key;
 use parity_scale_codec::{Decode, Encode};
-use primitives::{Amount, Balance, CurrencyId, ReserveIdentifier, TokenSymbol};
+use primitives::{define_combined_task, Amount, Balance, CurrencyId, ReserveIdentifier, TokenSymbol};
 use sp_core::{crypto::AccountId32, H160};
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
 	BuildStorage, RuntimeDebug,
 };
 
@@ -166,6 +166,39 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<One, AccountId>;
 	type OnDust = ();
+	type AccountCurrencies = ();
+	type Swap = module_support::SpecificJointsSwap<(), ()>;
+	type MaxTransferAllCurrencies = ConstU32<0>;
+}
+
+define_combined_task! {
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum ScheduledTasks {
+		NftTask(NftTask<Runtime>),
+	}
+}
+
+pub struct MockBlockNumberProvider;
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = u32;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Zero::zero()
+	}
+}
+
+parameter_types! {
+	pub MinimumWeightRemainInBlock: Weight = Weight::zero();
+}
+
+impl module_idle_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Index = Nonce;
+	type Task = ScheduledTasks;
+	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
+	type DisableBlockThreshold = ConstU32<6>;
 }
 
 parameter_types! {
@@ -175,6 +208,7 @@ pub const CREATE_CLASS_DEPOSIT: u128 = 200;
 pub const CREATE_TOKEN_DEPOSIT: u128 = 100;
 pub const DATA_DEPOSIT_PER_BYTE: u128 = 10;
 pub const MAX_ATTRIBUTES_BYTES: u32 = 10;
+pub const MAX_AIRDROP_RECIPIENTS_TO_SCHEDULE: u32 = 200;
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -183,6 +217,9 @@ impl Config for Runtime {
 	type DataDepositPerByte = ConstU128<DATA_DEPOSIT_PER_BYTE>;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = ConstU32<MAX_ATTRIBUTES_BYTES>;
+	type MaxAirdropRecipients = ConstU32<MAX_AIRDROP_RECIPIENTS_TO_SCHEDULE>;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -209,6 +246,7 @@ construct_runtime!(
 		Utility: pallet_utility,
 		Tokens: orml_tokens,
 		Currency: module_currencies,
+		IdleScheduler: module_idle_scheduler,
 	}
 );
 
@@ -736,3 +736,527 @@ fn update_class_properties_should_work() {
 		);
 	});
 }
+
+fn airdrop_recipients(n: u8) -> Vec<AccountId> {
+	(0..n).map(|i| AccountId::new([100 + i; 32])).collect()
+}
+
+fn run_idle_for(recipients: u32) {
+	IdleScheduler::on_idle(
+		System::block_number(),
+		<() as module_idle_scheduler::WeightInfo>::on_idle_base()
+			+ <() as crate::WeightInfo>::process_airdrop(recipients),
+	);
+}
+
+fn create_airdrop_class() -> Balance {
+	assert_ok!(NFTModule::create_class(
+		RuntimeOrigin::signed(ALICE),
+		vec![1],
+		Properties(
+			ClassProperty::Transferable
+				| ClassProperty::Burnable
+				| ClassProperty::Mintable
+				| ClassProperty::ClassPropertiesMutable
+		),
+		test_attr(1),
+	));
+	assert_ok!(Balances::deposit_into_existing(&class_id_account(), 10_000));
+	reserved_balance(&class_id_account())
+}
+
+#[test]
+fn mint_airdrop_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let class_reserved = create_airdrop_class();
+		let class_free = free_balance(&class_id_account());
+		let recipients = airdrop_recipients(3);
+
+		assert_noop!(
+			NFTModule::mint_airdrop(
+				RuntimeOrigin::signed(class_id_account()),
+				CLASS_ID,
+				Default::default(),
+				vec![1],
+				test_attr(1),
+			),
+			Error::<Runtime>::InvalidQuantity
+		);
+		assert_noop!(
+			NFTModule::mint_airdrop(
+				RuntimeOrigin::signed(BOB),
+				CLASS_ID,
+				recipients.clone().try_into().unwrap(),
+				vec![1],
+				test_attr(1),
+			),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTModule::mint_airdrop(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			recipients.clone().try_into().unwrap(),
+			vec![1],
+			test_attr(1),
+		));
+
+		// the same deposit as minting the tokens one by one
+		let token_deposit = CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * (1 + TEST_ATTR_LEN);
+		for (token_id, to) in recipients.iter().enumerate() {
+			System::assert_has_event(RuntimeEvent::NFTModule(crate::Event::MintedToken {
+				from: class_id_account(),
+				to: to.clone(),
+				class_id: CLASS_ID,
+				quantity: 1,
+			}));
+			assert_eq!(reserved_balance(to), token_deposit);
+			assert_eq!(free_balance(to), Balances::minimum_balance());
+			assert_eq!(
+				orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, token_id as u64)
+					.unwrap()
+					.owner,
+				to.clone()
+			);
+		}
+		assert_eq!(reserved_balance(&class_id_account()), class_reserved);
+		assert_eq!(
+			free_balance(&class_id_account()),
+			class_free - 3 * (token_deposit + Balances::minimum_balance())
+		);
+	});
+}
+
+#[test]
+fn schedule_airdrop_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NFTModule::schedule_airdrop(
+				RuntimeOrigin::signed(class_id_account()),
+				CLASS_ID,
+				airdrop_recipients(1),
+				vec![1],
+				test_attr(1),
+			),
+			Error::<Runtime>::ClassIdNotFound
+		);
+
+		create_airdrop_class();
+
+		assert_noop!(
+			NFTModule::schedule_airdrop(
+				RuntimeOrigin::signed(class_id_account()),
+				CLASS_ID,
+				vec![],
+				vec![1],
+				test_attr(1),
+			),
+			Error::<Runtime>::InvalidQuantity
+		);
+		assert_noop!(
+			NFTModule::schedule_airdrop(
+				RuntimeOrigin::signed(class_id_account()),
+				CLASS_ID,
+				vec![BOB; MAX_AIRDROP_RECIPIENTS_TO_SCHEDULE as usize + 1],
+				vec![1],
+				test_attr(1),
+			),
+			Error::<Runtime>::TooManyAirdropRecipients
+		);
+		assert_noop!(
+			NFTModule::schedule_airdrop(
+				RuntimeOrigin::signed(BOB),
+				CLASS_ID,
+				airdrop_recipients(1),
+				vec![1],
+				test_attr(1),
+			),
+			Error::<Runtime>::NoPermission
+		);
+		// 500 deposit per recipient
+		assert_noop!(
+			NFTModule::schedule_airdrop(
+				RuntimeOrigin::signed(class_id_account()),
+				CLASS_ID,
+				vec![BOB; 20],
+				vec![1],
+				test_attr(1),
+			),
+			pallet_balances::Error::<Runtime>::InsufficientBalance
+		);
+
+		assert_ok!(NFTModule::update_class_properties(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			Properties(ClassProperty::Transferable.into())
+		));
+		assert_noop!(
+			NFTModule::schedule_airdrop(
+				RuntimeOrigin::signed(class_id_account()),
+				CLASS_ID,
+				airdrop_recipients(1),
+				vec![1],
+				test_attr(1),
+			),
+			Error::<Runtime>::NonMintable
+		);
+	});
+}
+
+#[test]
+fn scheduled_airdrop_should_continue_across_idle_periods() {
+	ExtBuilder::default().build().execute_with(|| {
+		let class_reserved = create_airdrop_class();
+		let class_free = free_balance(&class_id_account());
+		let recipients = airdrop_recipients(7);
+
+		assert_ok!(NFTModule::schedule_airdrop(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			recipients.clone(),
+			vec![1],
+			test_attr(1),
+		));
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::AirdropScheduled {
+			airdrop_id: 0,
+			owner: class_id_account(),
+			class_id: CLASS_ID,
+			total: 7,
+		}));
+
+		let token_deposit = CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * (1 + TEST_ATTR_LEN);
+		let info = NFTModule::airdrops(0).unwrap();
+		assert_eq!(info.recipient_deposit, token_deposit + DATA_DEPOSIT_PER_BYTE * 32);
+		assert_eq!(
+			info.base_deposit,
+			DATA_DEPOSIT_PER_BYTE * info.encoded_size() as Balance
+		);
+		assert_eq!(
+			reserved_balance(&class_id_account()),
+			class_reserved + 7 * info.recipient_deposit + info.base_deposit
+		);
+		assert_eq!(AirdropRecipients::<Runtime>::iter_prefix(0).count(), 7);
+
+		// not enough weight for any recipient
+		run_idle_for(0);
+		assert_eq!(NFTModule::airdrops(0).unwrap().processed, 0);
+
+		run_idle_for(3);
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::AirdropProgressed {
+			airdrop_id: 0,
+			processed: 3,
+			total: 7,
+		}));
+		assert_eq!(AirdropRecipients::<Runtime>::iter_prefix(0).count(), 4);
+		assert_eq!(
+			reserved_balance(&class_id_account()),
+			class_reserved + 4 * info.recipient_deposit + info.base_deposit
+		);
+
+		run_idle_for(3);
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::AirdropProgressed {
+			airdrop_id: 0,
+			processed: 6,
+			total: 7,
+		}));
+
+		run_idle_for(3);
+		System::assert_has_event(RuntimeEvent::NFTModule(crate::Event::AirdropCompleted {
+			airdrop_id: 0,
+		}));
+		System::assert_last_event(RuntimeEvent::IdleScheduler(
+			module_idle_scheduler::Event::TaskDispatched {
+				task_id: 0,
+				result: Ok(()),
+			},
+		));
+		assert_eq!(NFTModule::airdrops(0), None);
+		assert_eq!(AirdropRecipients::<Runtime>::iter_prefix(0).count(), 0);
+		assert_eq!(IdleScheduler::tasks(0), None);
+
+		// the same deposit as minting the tokens one by one
+		for (token_id, to) in recipients.iter().enumerate() {
+			assert_eq!(reserved_balance(to), token_deposit);
+			assert_eq!(
+				orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, token_id as u64)
+					.unwrap()
+					.owner,
+				to.clone()
+			);
+		}
+		assert_eq!(reserved_balance(&class_id_account()), class_reserved);
+		assert_eq!(
+			free_balance(&class_id_account()),
+			class_free - 7 * (token_deposit + Balances::minimum_balance())
+		);
+	});
+}
+
+#[test]
+fn cancel_airdrop_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let class_reserved = create_airdrop_class();
+		let class_free = free_balance(&class_id_account());
+		let recipients = airdrop_recipients(7);
+
+		assert_noop!(
+			NFTModule::cancel_airdrop(RuntimeOrigin::signed(class_id_account()), 0),
+			Error::<Runtime>::AirdropNotFound
+		);
+
+		assert_ok!(NFTModule::schedule_airdrop(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			recipients.clone(),
+			vec![1],
+			test_attr(1),
+		));
+		let info = NFTModule::airdrops(0).unwrap();
+
+		run_idle_for(3);
+		assert_eq!(NFTModule::airdrops(0).unwrap().processed, 3);
+
+		assert_noop!(
+			NFTModule::cancel_airdrop(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::cancel_airdrop(RuntimeOrigin::signed(class_id_account()), 0));
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::AirdropCancelled {
+			airdrop_id: 0,
+			processed: 3,
+			refunded: 4 * info.recipient_deposit + info.base_deposit,
+		}));
+		assert_eq!(NFTModule::airdrops(0), None);
+		assert_eq!(AirdropRecipients::<Runtime>::iter_prefix(0).count(), 0);
+		assert_eq!(reserved_balance(&class_id_account()), class_reserved);
+
+		// the task finishes without minting the rest
+		run_idle_for(3);
+		assert_eq!(IdleScheduler::tasks(0), None);
+
+		let token_deposit = CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * (1 + TEST_ATTR_LEN);
+		for (token_id, to) in recipients.iter().enumerate() {
+			if token_id < 3 {
+				assert_eq!(reserved_balance(to), token_deposit);
+				assert!(orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, token_id as u64).is_some());
+			} else {
+				assert_eq!(reserved_balance(to), 0);
+				assert!(orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, token_id as u64).is_none());
+			}
+		}
+		assert_eq!(
+			free_balance(&class_id_account()),
+			class_free - 3 * (token_deposit + Balances::minimum_balance())
+		);
+	});
+}
+
+#[test]
+fn scheduled_airdrop_should_abort_on_mint_failure() {
+	ExtBuilder::default().build().execute_with(|| {
+		let class_reserved = create_airdrop_class();
+		assert_ok!(NFTModule::schedule_airdrop(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			airdrop_recipients(5),
+			vec![1],
+			test_attr(1),
+		));
+		let info = NFTModule::airdrops(0).unwrap();
+
+		run_idle_for(2);
+		assert_ok!(NFTModule::update_class_properties(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			Properties(ClassProperty::Transferable.into())
+		));
+
+		run_idle_for(2);
+		System::assert_has_event(RuntimeEvent::NFTModule(crate::Event::AirdropAborted {
+			airdrop_id: 0,
+			processed: 2,
+			refunded: 3 * info.recipient_deposit + info.base_deposit,
+			error: Error::<Runtime>::NonMintable.into(),
+		}));
+		assert_eq!(NFTModule::airdrops(0), None);
+		assert_eq!(AirdropRecipients::<Runtime>::iter_prefix(0).count(), 0);
+		assert_eq!(IdleScheduler::tasks(0), None);
+		assert_eq!(reserved_balance(&class_id_account()), class_reserved);
+	});
+}
+
+fn mint_tokens(to: AccountId, attributes: Attributes, quantity: u32) {
+	assert_ok!(Balances::deposit_into_existing(
+		&class_id_account(),
+		1_000 * quantity as Balance
+	));
+	assert_ok!(NFTModule::mint(
+		RuntimeOrigin::signed(class_id_account()),
+		to,
+		CLASS_ID,
+		vec![1],
+		attributes,
+		quantity
+	));
+}
+
+fn token_ids(tokens: &[TokenDetailsOf<Runtime>]) -> Vec<u64> {
+	tokens.iter().map(|token| token.token_id).collect()
+}
+
+#[test]
+fn get_class_info_and_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(NFTModule::get_class_info(CLASS_ID), None);
+		create_airdrop_class();
+		mint_tokens(BOB, test_attr(2), 2);
+
+		let class_info = NFTModule::get_class_info(CLASS_ID).unwrap();
+		assert_eq!(class_info.owner, class_id_account());
+		assert_eq!(class_info.metadata, vec![1]);
+		assert_eq!(class_info.total_issuance, 2);
+		assert_eq!(class_info.next_token_id, 2);
+		assert_eq!(class_info.attributes, test_attr(1));
+
+		assert_eq!(
+			NFTModule::get_token(CLASS_ID, 1),
+			Some(TokenDetails {
+				class_id: CLASS_ID,
+				token_id: 1,
+				owner: BOB,
+				metadata: vec![1],
+				deposit: CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * (1 + TEST_ATTR_LEN),
+				attributes: test_attr(2),
+			})
+		);
+		assert_eq!(NFTModule::get_token(CLASS_ID, 2), None);
+		assert_eq!(NFTModule::get_token(CLASS_ID_NOT_EXIST, 0), None);
+	});
+}
+
+#[test]
+fn get_tokens_by_class_should_be_stable_across_mint_and_burn() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_airdrop_class();
+		mint_tokens(BOB, test_attr(2), 6);
+		mint_tokens(ALICE, test_attr(3), 4);
+
+		let (page, cursor) = NFTModule::get_tokens_by_class(CLASS_ID, None, 4, None);
+		assert_eq!(token_ids(&page), vec![0, 1, 2, 3]);
+		assert_eq!(cursor, Some(4));
+
+		// burn a scanned and an unscanned token, mint more at a later block
+		System::set_block_number(2);
+		assert_ok!(NFTModule::burn(RuntimeOrigin::signed(BOB), (CLASS_ID, 1)));
+		assert_ok!(NFTModule::burn(RuntimeOrigin::signed(BOB), (CLASS_ID, 5)));
+		mint_tokens(BOB, test_attr(2), 2);
+
+		let (page, cursor) = NFTModule::get_tokens_by_class(CLASS_ID, cursor, 4, None);
+		assert_eq!(token_ids(&page), vec![4, 6, 7]);
+		assert_eq!(cursor, Some(8));
+
+		System::set_block_number(3);
+		mint_tokens(BOB, test_attr(2), 1);
+
+		let (page, cursor) = NFTModule::get_tokens_by_class(CLASS_ID, cursor, 4, None);
+		assert_eq!(token_ids(&page), vec![8, 9, 10, 11]);
+		assert_eq!(cursor, Some(12));
+
+		let (page, cursor) = NFTModule::get_tokens_by_class(CLASS_ID, cursor, 4, None);
+		assert_eq!(token_ids(&page), vec![12]);
+		assert_eq!(cursor, None);
+
+		// the filter is applied within the scanned ids
+		let filter = Some((vec![4], vec![11]));
+		let (page, cursor) = NFTModule::get_tokens_by_class(CLASS_ID, None, 8, filter.clone());
+		assert_eq!(token_ids(&page), vec![6, 7]);
+		assert!(page.iter().all(|token| token.owner == ALICE));
+		assert_eq!(cursor, Some(8));
+		let (page, cursor) = NFTModule::get_tokens_by_class(CLASS_ID, cursor, 8, filter);
+		assert_eq!(token_ids(&page), vec![8, 9]);
+		assert_eq!(cursor, None);
+
+		// the limit is bounded
+		let (page, cursor) = NFTModule::get_tokens_by_class(CLASS_ID, None, 0, None);
+		assert_eq!(token_ids(&page), vec![0]);
+		assert_eq!(cursor, Some(1));
+		assert_eq!(
+			NFTModule::get_tokens_by_class(CLASS_ID_NOT_EXIST, None, 4, None),
+			(vec![], None)
+		);
+	});
+}
+
+#[test]
+fn get_tokens_by_owner_should_be_stable_across_mint_and_burn() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_airdrop_class();
+		mint_tokens(BOB, test_attr(2), 10);
+		mint_tokens(ALICE, test_attr(2), 2);
+
+		let mut seen = vec![];
+		let (page, mut cursor) = NFTModule::get_tokens_by_owner(&BOB, None, 3, None);
+		assert_eq!(page.len(), 3);
+		assert!(cursor.is_some());
+		seen.extend(token_ids(&page));
+
+		// burn a scanned and an unscanned token, mint more at a later block
+		System::set_block_number(2);
+		let unscanned = (0..10).find(|id| !seen.contains(id)).unwrap();
+		assert_ok!(NFTModule::burn(RuntimeOrigin::signed(BOB), (CLASS_ID, seen[0])));
+		assert_ok!(NFTModule::burn(RuntimeOrigin::signed(BOB), (CLASS_ID, unscanned)));
+		mint_tokens(BOB, test_attr(3), 5);
+
+		let mut pages = 1;
+		while cursor.is_some() {
+			let (page, next) = NFTModule::get_tokens_by_owner(&BOB, cursor, 3, None);
+			assert!(page.len() <= 3);
+			assert!(page.iter().all(|token| token.owner == BOB));
+			seen.extend(token_ids(&page));
+			cursor = next;
+			pages += 1;
+		}
+		assert!(pages <= 6);
+
+		// no token is returned twice
+		let mut unique = seen.clone();
+		unique.sort();
+		unique.dedup();
+		assert_eq!(unique.len(), seen.len());
+
+		// every token owned throughout the enumeration is returned, the burned unscanned one is not
+		for token_id in 0..10 {
+			if token_id != unscanned {
+				assert!(seen.contains(&token_id));
+			}
+		}
+		assert!(!seen.contains(&unscanned));
+		assert!(!seen.contains(&10) && !seen.contains(&11));
+
+		// the filter is applied within the scanned tokens
+		let filter = Some((vec![4], vec![11]));
+		let mut filtered = vec![];
+		let mut cursor = None;
+		loop {
+			let (page, next) = NFTModule::get_tokens_by_owner(&BOB, cursor, 4, filter.clone());
+			assert!(page.len() <= 4);
+			filtered.extend(token_ids(&page));
+			if next.is_none() {
+				break;
+			}
+			cursor = next;
+		}
+		filtered.sort();
+		assert_eq!(filtered, vec![12, 13, 14, 15, 16]);
+
+		// the limit is bounded
+		let (page, cursor) = NFTModule::get_tokens_by_owner(&BOB, None, 0, None);
+		assert_eq!(page.len(), 1);
+		assert!(cursor.is_some());
+		assert_eq!(NFTModule::get_tokens_by_owner(&ALICE, None, 1_000, None).0.len(), 2);
+		assert_eq!(
+			NFTModule::get_tokens_by_owner(&class_id_account(), None, 4, None),
+			(vec![], None)
+		);
+	});
+}
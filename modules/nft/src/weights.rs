@@ -54,6 +54,10 @@ pub trait WeightInfo {
 	fn burn_with_remark(b: u32, ) -> Weight;
 	fn destroy_class() -> Weight;
 	fn update_class_properties() -> Weight;
+	fn mint_airdrop(n: u32, ) -> Weight;
+	fn schedule_airdrop(n: u32, ) -> Weight;
+	fn cancel_airdrop(n: u32, ) -> Weight;
+	fn process_airdrop(n: u32, ) -> Weight;
 }
 
 /// Weights for module_nft using the Acala node and recommended hardware.
@@ -99,6 +103,39 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn mint_airdrop(n: u32, ) -> Weight {
+		Weight::from_parts(21_436_000, 0)
+			// Standard Error: 52_000
+			.saturating_add(Weight::from_parts(117_086_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((7 as u64).saturating_mul(n as u64)))
+	}
+	fn schedule_airdrop(n: u32, ) -> Weight {
+		Weight::from_parts(61_218_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(4_872_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn cancel_airdrop(n: u32, ) -> Weight {
+		Weight::from_parts(42_517_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(2_946_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn process_airdrop(n: u32, ) -> Weight {
+		Weight::from_parts(23_104_000, 0)
+			// Standard Error: 61_000
+			.saturating_add(Weight::from_parts(124_731_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((8 as u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -143,4 +180,37 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn mint_airdrop(n: u32, ) -> Weight {
+		Weight::from_parts(21_436_000, 0)
+			// Standard Error: 52_000
+			.saturating_add(Weight::from_parts(117_086_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((7 as u64).saturating_mul(n as u64)))
+	}
+	fn schedule_airdrop(n: u32, ) -> Weight {
+		Weight::from_parts(61_218_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(4_872_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn cancel_airdrop(n: u32, ) -> Weight {
+		Weight::from_parts(42_517_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(2_946_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn process_airdrop(n: u32, ) -> Weight {
+		Weight::from_parts(23_104_000, 0)
+			// Standard Error: 61_000
+			.saturating_add(Weight::from_parts(124_731_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((8 as u64).saturating_mul(n as u64)))
+	}
 }
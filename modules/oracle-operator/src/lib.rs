@@ -0,0 +1,824 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Oracle Operator Module
+//!
+//! ## Overview
+//!
+//! Makes the price feeds of the oracle operators fee-less. The operators feed prices through
+//! `feed_values` of this module, which is `Pays::No` and forwards the values to the oracle. The
+//! `CheckOracleFeed` signed extension verifies the feeder at validation time, so the fee-less
+//! feeds can't be used to spam the transaction pool:
+//!
+//! - the feeder must be a member of the oracle operators,
+//! - the feeder must have bonded at least `MinimumBond`,
+//! - the feeder can only feed `MaxFeedsPerBlock` times in a block.
+//!
+//! The valid feeds are assigned `FeedPriority`.
+//!
+//! The bond is an economic backstop against bad feeds: `SlashOrigin` can slash the bond of an
+//! operator which provably fed bad values. An operator whose bond is slashed below `MinimumBond`
+//! loses the fee-less feed privilege until the bond is topped up. The unbonded amount is locked
+//! for `UnbondingPeriod` and can still be slashed.
+//!
+//! `RotateOrigin` can rotate the operators atomically: the removed operators leave the membership
+//! and their raw values are purged from the oracle in the same block, so the next combine only
+//! uses the values of the live operators. The minimum count of the oracle values is relaxed to
+//! `RelaxedMinimumCount` for `RotationGracePeriod` blocks, so the prices stay available while the
+//! new operators begin feeding. The oracle must use `CurrentMinimumCount` as its minimum count.
+//!
+//! In sharp market moves the operators can feed with `feed_values_and_trigger`, which applies the
+//! feed and then tries to liquidate the hinted positions with the just updated prices in the same
+//! block, instead of waiting for the offchain worker of the next block. The hints are bounded by
+//! `MaxLiquidationHints`, and the combined call is subject to the same fee-less checks as
+//! `feed_values`, so an operator can trigger at most `MaxFeedsPerBlock * MaxLiquidationHints`
+//! liquidations in a block. The hinted positions which are safe are skipped.
+//!
+//! An operator can register a backup feeder key with `register_backup_feeder`, so a failed primary
+//! key doesn't take its feed down. The backup feeds with `feed_values_as_backup`, which is only
+//! accepted once the primary key of the operator has been silent for more than `FailoverThreshold`
+//! blocks, so the backup can't race or contradict a healthy primary. The values fed by the backup
+//! are attributed to the operator, and are subject to the same fee-less checks as the feeds of the
+//! operator. The failover is deactivated once the primary feeds again.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, IsSubType, NamedReservableCurrency, OnUnbalanced, SortedMembers},
+};
+use frame_system::pallet_prelude::*;
+use module_support::Price;
+use orml_traits::DataFeeder;
+use primitives::{Balance, CurrencyId, ReserveIdentifier};
+use sp_runtime::{
+	traits::{DispatchInfoOf, Saturating, SignedExtension, Zero},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+/// Custom validity error of the feed without an enough bond.
+pub const FEED_PRIVILEGE_SUSPENDED: u8 = 1;
+/// Custom validity error of the backup feed while the primary key of the operator is healthy.
+pub const PRIMARY_FEEDER_HEALTHY: u8 = 2;
+
+/// The bond of an oracle operator.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OperatorBond<BlockNumber> {
+	/// The bonded amount backing the fee-less feeds.
+	pub active: Balance,
+	/// The amount being unbonded and the block number it can be withdrawn at.
+	pub unbonding: Option<(Balance, BlockNumber)>,
+}
+
+/// The membership of the oracle operators, which is changed by the rotation.
+pub trait OperatorMembership<AccountId> {
+	/// Add `who` to the operators.
+	fn add_operator(who: &AccountId) -> DispatchResult;
+	/// Remove `who` from the operators.
+	fn remove_operator(who: &AccountId) -> DispatchResult;
+}
+
+/// The raw values fed to the oracle by the operators.
+pub trait OperatorValues<AccountId> {
+	/// Remove all the raw values fed by `who`, returns the number of the values removed.
+	fn purge_values(who: &AccountId) -> u32;
+}
+
+/// The liquidation of the unsafe positions hinted by the operators.
+pub trait UnsafePositionLiquidator<AccountId> {
+	/// The maximum weight of `try_liquidate`.
+	fn max_weight() -> Weight;
+
+	/// Try to liquidate the position of `who` with the current price, returns whether the position
+	/// was unsafe and handled, and the weight consumed.
+	fn try_liquidate(currency_id: CurrencyId, who: &AccountId) -> (bool, Weight);
+}
+
+impl<BlockNumber> OperatorBond<BlockNumber> {
+	fn total(&self) -> Balance {
+		self.active
+			.saturating_add(self.unbonding.as_ref().map_or(Zero::zero(), |(amount, _)| *amount))
+	}
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::OracleOperator;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currency used for the bond of oracle operators.
+		type Currency: NamedReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = ReserveIdentifier,
+			Balance = Balance,
+		>;
+
+		/// The oracle operators.
+		type Operators: SortedMembers<Self::AccountId>;
+
+		/// The oracle which the values are fed to.
+		type Oracle: DataFeeder<CurrencyId, Price, Self::AccountId>;
+
+		/// The minimum bond to feed fee-less.
+		#[pallet::constant]
+		type MinimumBond: Get<Balance>;
+
+		/// The period the unbonded amount is locked and can still be slashed.
+		#[pallet::constant]
+		type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of fee-less feeds of an operator in a block.
+		#[pallet::constant]
+		type MaxFeedsPerBlock: Get<u32>;
+
+		/// The maximum number of values in a feed.
+		#[pallet::constant]
+		type MaxFeedValues: Get<u32>;
+
+		/// The priority of the valid fee-less feeds.
+		#[pallet::constant]
+		type FeedPriority: Get<TransactionPriority>;
+
+		/// The maximum number of the liquidation hints of a feed.
+		#[pallet::constant]
+		type MaxLiquidationHints: Get<u32>;
+
+		/// The liquidation of the hinted positions.
+		type Liquidator: UnsafePositionLiquidator<Self::AccountId>;
+
+		/// The origin which may slash the bond of operators.
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Handler for the slashed bonds.
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The membership of the oracle operators to rotate.
+		type OperatorMembership: OperatorMembership<Self::AccountId>;
+
+		/// The raw values of the oracle to purge for the removed operators.
+		type OperatorValues: OperatorValues<Self::AccountId>;
+
+		/// The minimum count of the oracle values to combine.
+		#[pallet::constant]
+		type MinimumCount: Get<u32>;
+
+		/// The minimum count of the oracle values to combine during the grace period of the
+		/// rotation.
+		#[pallet::constant]
+		type RelaxedMinimumCount: Get<u32>;
+
+		/// The number of blocks the minimum count is relaxed for after the rotation.
+		#[pallet::constant]
+		type RotationGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The origin which may rotate the operators.
+		type RotateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The number of blocks the primary key of an operator must be silent for, before the feeds
+		/// of its backup feeder are accepted.
+		#[pallet::constant]
+		type FailoverThreshold: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The caller is not an oracle operator.
+		NotOperator,
+		/// The bond of the operator is below `MinimumBond`.
+		FeedPrivilegeSuspended,
+		/// The active bond is not enough to unbond.
+		InsufficientBond,
+		/// There's no bond of the account.
+		NoBond,
+		/// There's no unbonding amount.
+		NoUnbonding,
+		/// The unbonded amount can't be withdrawn yet.
+		UnbondingNotExpired,
+		/// The account is both added and removed by the rotation.
+		ConflictingRotation,
+		/// The backup feeder is an operator.
+		BackupIsOperator,
+		/// There's no backup feeder of the operator.
+		NoBackupFeeder,
+		/// The caller is not the backup feeder of the operator.
+		NotBackupFeeder,
+		/// The primary key of the operator is not silent for `FailoverThreshold` blocks.
+		PrimaryFeederHealthy,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The operator bonded.
+		Bonded { who: T::AccountId, amount: Balance },
+		/// The operator unbonded, the amount can be withdrawn after `unlock_at`.
+		Unbonded {
+			who: T::AccountId,
+			amount: Balance,
+			unlock_at: BlockNumberFor<T>,
+		},
+		/// The unbonded amount is withdrawn.
+		Withdrawn { who: T::AccountId, amount: Balance },
+		/// The bond of the operator is slashed.
+		Slashed { who: T::AccountId, amount: Balance },
+		/// The bond of the operator fell below `MinimumBond`, the fee-less feeds are suspended.
+		FeedPrivilegeSuspended { who: T::AccountId },
+		/// The operators are rotated, the minimum count is relaxed until `relaxed_until`.
+		OperatorsRotated {
+			removed: Vec<T::AccountId>,
+			added: Vec<T::AccountId>,
+			purged_values: u32,
+			relaxed_until: BlockNumberFor<T>,
+		},
+		/// The grace period of the rotation ended, the minimum count is restored.
+		MinimumCountRestored,
+		/// The liquidations of the hinted positions are triggered by the feed.
+		LiquidationsTriggered {
+			who: T::AccountId,
+			liquidated: u32,
+			skipped: u32,
+		},
+		/// The backup feeder of the operator is registered.
+		BackupFeederRegistered { who: T::AccountId, backup: T::AccountId },
+		/// The backup feeder of the operator is revoked.
+		BackupFeederRevoked { who: T::AccountId, backup: T::AccountId },
+		/// The primary key of the operator is silent, the backup feeder took over the feeds.
+		FailoverActivated { who: T::AccountId, backup: T::AccountId },
+		/// The failover of the operator ended, by the feed of the primary key or the revocation of
+		/// the backup feeder.
+		FailoverDeactivated { who: T::AccountId },
+	}
+
+	/// The bonds of the oracle operators.
+	///
+	/// Bonds: map AccountId => Option<OperatorBond>
+	#[pallet::storage]
+	#[pallet::getter(fn bonds)]
+	pub type Bonds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, OperatorBond<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The number of the fee-less feeds of the operator in the block.
+	///
+	/// FeedsInBlock: map AccountId => (BlockNumber, u32)
+	#[pallet::storage]
+	#[pallet::getter(fn feeds_in_block)]
+	pub type FeedsInBlock<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	/// The block number until which the minimum count is relaxed by the rotation.
+	///
+	/// MinimumCountRelaxedUntil: Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn minimum_count_relaxed_until)]
+	pub type MinimumCountRelaxedUntil<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The backup feeders of the operators.
+	///
+	/// BackupFeeders: map AccountId => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn backup_feeders)]
+	pub type BackupFeeders<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// The block number of the last feed of the operator with its primary key.
+	///
+	/// LastPrimaryFeed: map AccountId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn last_primary_feed)]
+	pub type LastPrimaryFeed<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The block number the failover of the operator to its backup feeder is activated at.
+	///
+	/// Failovers: map AccountId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn failovers)]
+	pub type Failovers<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			match Self::minimum_count_relaxed_until() {
+				Some(relaxed_until) if now >= relaxed_until => {
+					MinimumCountRelaxedUntil::<T>::kill();
+					Self::deposit_event(Event::MinimumCountRestored);
+					T::DbWeight::get().reads_writes(1, 1)
+				}
+				_ => T::DbWeight::get().reads(1),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Bond `amount` to feed fee-less.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::bond())]
+		pub fn bond(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			T::Currency::reserve_named(&RESERVE_ID, &who, amount)?;
+			Bonds::<T>::mutate(&who, |maybe_bond| {
+				let bond = maybe_bond.get_or_insert_with(Default::default);
+				bond.active = bond.active.saturating_add(amount);
+			});
+
+			Self::deposit_event(Event::Bonded { who, amount });
+			Ok(())
+		}
+
+		/// Unbond `amount`, which can be withdrawn after `UnbondingPeriod`. Unbonding again
+		/// restarts the unbonding period of the total unbonding amount.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::unbond())]
+		pub fn unbond(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let unlock_at = frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get());
+			Bonds::<T>::try_mutate(&who, |maybe_bond| -> DispatchResult {
+				let bond = maybe_bond.as_mut().ok_or(Error::<T>::NoBond)?;
+				bond.active = bond.active.checked_sub(amount).ok_or(Error::<T>::InsufficientBond)?;
+				let unbonding = bond
+					.unbonding
+					.map_or(Zero::zero(), |(unbonding, _)| unbonding)
+					.saturating_add(amount);
+				bond.unbonding = Some((unbonding, unlock_at));
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Unbonded { who, amount, unlock_at });
+			Ok(())
+		}
+
+		/// Withdraw the unbonded amount after the unbonding period.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut bond = Self::bonds(&who).ok_or(Error::<T>::NoBond)?;
+			let (amount, unlock_at) = bond.unbonding.take().ok_or(Error::<T>::NoUnbonding)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= unlock_at,
+				Error::<T>::UnbondingNotExpired
+			);
+
+			T::Currency::unreserve_named(&RESERVE_ID, &who, amount);
+			Self::update_bond(&who, bond);
+
+			Self::deposit_event(Event::Withdrawn { who, amount });
+			Ok(())
+		}
+
+		/// Slash the bond of an operator which provably fed bad values. The active bond is
+		/// slashed first, then the unbonding amount.
+		///
+		/// The dispatch origin of this call must be `SlashOrigin`.
+		///
+		/// - `who`: the operator to slash.
+		/// - `amount`: the amount to slash.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::slash())]
+		pub fn slash(origin: OriginFor<T>, who: T::AccountId, #[pallet::compact] amount: Balance) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			let mut bond = Self::bonds(&who).ok_or(Error::<T>::NoBond)?;
+			let was_privileged = bond.active >= T::MinimumBond::get();
+
+			let amount = amount.min(bond.total());
+			let (imbalance, remaining) = T::Currency::slash_reserved_named(&RESERVE_ID, &who, amount);
+			let slashed = amount.saturating_sub(remaining);
+			T::OnSlash::on_unbalanced(imbalance);
+
+			let from_active = slashed.min(bond.active);
+			bond.active = bond.active.saturating_sub(from_active);
+			if let Some((unbonding, _)) = bond.unbonding.as_mut() {
+				*unbonding = unbonding.saturating_sub(slashed.saturating_sub(from_active));
+			}
+			if bond.unbonding.map_or(false, |(unbonding, _)| unbonding.is_zero()) {
+				bond.unbonding = None;
+			}
+			let is_privileged = bond.active >= T::MinimumBond::get();
+			Self::update_bond(&who, bond);
+
+			Self::deposit_event(Event::Slashed {
+				who: who.clone(),
+				amount: slashed,
+			});
+			if was_privileged && !is_privileged {
+				Self::deposit_event(Event::FeedPrivilegeSuspended { who });
+			}
+			Ok(())
+		}
+
+		/// Feed the values to the oracle fee-less.
+		///
+		/// The caller must be an operator with at least `MinimumBond` bonded.
+		///
+		/// - `values`: the currency prices to feed.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::WeightInfo::feed_values(values.len() as u32), DispatchClass::Operational, Pays::No))]
+		pub fn feed_values(
+			origin: OriginFor<T>,
+			values: BoundedVec<(CurrencyId, Price), T::MaxFeedValues>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::Operators::contains(&who), Error::<T>::NotOperator);
+			ensure!(Self::has_enough_bond(&who), Error::<T>::FeedPrivilegeSuspended);
+
+			Self::do_feed_values(&who, values)?;
+			Self::note_primary_feed(&who);
+			Ok(())
+		}
+
+		/// Rotate the oracle operators in one block. The raw values of the removed operators are
+		/// purged from the oracle, and the minimum count is relaxed for `RotationGracePeriod`
+		/// blocks.
+		///
+		/// The dispatch origin of this call must be `RotateOrigin`.
+		///
+		/// - `remove`: the operators to remove.
+		/// - `add`: the operators to add.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::rotate_operators(remove.len() as u32, add.len() as u32))]
+		pub fn rotate_operators(
+			origin: OriginFor<T>,
+			remove: Vec<T::AccountId>,
+			add: Vec<T::AccountId>,
+		) -> DispatchResult {
+			T::RotateOrigin::ensure_origin(origin)?;
+			ensure!(
+				!remove.iter().any(|who| add.contains(who)),
+				Error::<T>::ConflictingRotation
+			);
+
+			let mut purged_values: u32 = 0;
+			for who in remove.iter() {
+				T::OperatorMembership::remove_operator(who)?;
+				purged_values = purged_values.saturating_add(T::OperatorValues::purge_values(who));
+				BackupFeeders::<T>::remove(who);
+				LastPrimaryFeed::<T>::remove(who);
+				Failovers::<T>::remove(who);
+			}
+			for who in add.iter() {
+				T::OperatorMembership::add_operator(who)?;
+			}
+
+			let relaxed_until = frame_system::Pallet::<T>::block_number().saturating_add(T::RotationGracePeriod::get());
+			MinimumCountRelaxedUntil::<T>::put(relaxed_until);
+
+			Self::deposit_event(Event::OperatorsRotated {
+				removed: remove,
+				added: add,
+				purged_values,
+				relaxed_until,
+			});
+			Ok(())
+		}
+
+		/// Feed the values to the oracle fee-less, then try to liquidate the hinted positions with
+		/// the updated prices in the same block. The hinted positions which are safe are skipped.
+		///
+		/// The caller must be an operator with at least `MinimumBond` bonded.
+		///
+		/// - `values`: the currency prices to feed.
+		/// - `liquidation_hints`: the positions to try to liquidate.
+		#[pallet::call_index(6)]
+		#[pallet::weight((
+			T::WeightInfo::feed_values(values.len() as u32)
+				.saturating_add(T::Liquidator::max_weight().saturating_mul(liquidation_hints.len() as u64)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn feed_values_and_trigger(
+			origin: OriginFor<T>,
+			values: BoundedVec<(CurrencyId, Price), T::MaxFeedValues>,
+			liquidation_hints: BoundedVec<(CurrencyId, T::AccountId), T::MaxLiquidationHints>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(T::Operators::contains(&who), Error::<T>::NotOperator);
+			ensure!(Self::has_enough_bond(&who), Error::<T>::FeedPrivilegeSuspended);
+
+			let mut consumed_weight = T::WeightInfo::feed_values(values.len() as u32);
+			Self::do_feed_values(&who, values)?;
+			Self::note_primary_feed(&who);
+
+			let mut liquidated: u32 = 0;
+			let mut skipped: u32 = 0;
+			for (currency_id, owner) in liquidation_hints {
+				let (is_liquidated, weight) = T::Liquidator::try_liquidate(currency_id, &owner);
+				consumed_weight = consumed_weight.saturating_add(weight);
+				if is_liquidated {
+					liquidated = liquidated.saturating_add(1);
+				} else {
+					skipped = skipped.saturating_add(1);
+				}
+			}
+
+			Self::deposit_event(Event::LiquidationsTriggered {
+				who,
+				liquidated,
+				skipped,
+			});
+			Ok(Some(consumed_weight).into())
+		}
+
+		/// Register the backup feeder of the caller, which replaces the existing one. The backup
+		/// can only feed once the primary key of the caller is silent for `FailoverThreshold`
+		/// blocks.
+		///
+		/// The caller must be an operator.
+		///
+		/// - `backup`: the backup feeder, which can't be an operator.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::register_backup_feeder())]
+		pub fn register_backup_feeder(origin: OriginFor<T>, backup: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::Operators::contains(&who), Error::<T>::NotOperator);
+			ensure!(!T::Operators::contains(&backup), Error::<T>::BackupIsOperator);
+
+			// the failover of the replaced backup ends
+			if BackupFeeders::<T>::mutate(&who, |maybe_backup| maybe_backup.replace(backup.clone())).is_some() {
+				Self::deactivate_failover(&who);
+			}
+
+			Self::deposit_event(Event::BackupFeederRegistered { who, backup });
+			Ok(())
+		}
+
+		/// Revoke the backup feeder of the caller, which ends the failover.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::revoke_backup_feeder())]
+		pub fn revoke_backup_feeder(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let backup = BackupFeeders::<T>::take(&who).ok_or(Error::<T>::NoBackupFeeder)?;
+			Self::deactivate_failover(&who);
+
+			Self::deposit_event(Event::BackupFeederRevoked { who, backup });
+			Ok(())
+		}
+
+		/// Feed the values to the oracle fee-less on behalf of the operator, whose primary key is
+		/// silent for more than `FailoverThreshold` blocks. The values are attributed to the
+		/// operator.
+		///
+		/// The caller must be the backup feeder of the operator, and the operator must have at
+		/// least `MinimumBond` bonded.
+		///
+		/// - `who`: the operator to feed for.
+		/// - `values`: the currency prices to feed.
+		#[pallet::call_index(9)]
+		#[pallet::weight((T::WeightInfo::feed_values_as_backup(values.len() as u32), DispatchClass::Operational, Pays::No))]
+		pub fn feed_values_as_backup(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			values: BoundedVec<(CurrencyId, Price), T::MaxFeedValues>,
+		) -> DispatchResult {
+			let backup = ensure_signed(origin)?;
+			ensure!(T::Operators::contains(&who), Error::<T>::NotOperator);
+			ensure!(
+				Self::backup_feeders(&who).as_ref() == Some(&backup),
+				Error::<T>::NotBackupFeeder
+			);
+			ensure!(Self::has_enough_bond(&who), Error::<T>::FeedPrivilegeSuspended);
+			ensure!(Self::is_primary_silent(&who), Error::<T>::PrimaryFeederHealthy);
+
+			Self::do_feed_values(&who, values)?;
+
+			if !Failovers::<T>::contains_key(&who) {
+				Failovers::<T>::insert(&who, frame_system::Pallet::<T>::block_number());
+				Self::deposit_event(Event::FailoverActivated { who, backup });
+			}
+			Ok(())
+		}
+	}
+}
+
+/// The minimum count of the oracle values to combine, relaxed to `RelaxedMinimumCount` during the
+/// grace period of the rotation.
+pub struct CurrentMinimumCount<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for CurrentMinimumCount<T> {
+	fn get() -> u32 {
+		let is_relaxed = Pallet::<T>::minimum_count_relaxed_until().map_or(false, |relaxed_until| {
+			frame_system::Pallet::<T>::block_number() < relaxed_until
+		});
+		if is_relaxed {
+			T::RelaxedMinimumCount::get().min(T::MinimumCount::get())
+		} else {
+			T::MinimumCount::get()
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the active bond of `who` is at least `MinimumBond`.
+	pub fn has_enough_bond(who: &T::AccountId) -> bool {
+		Self::bonds(who).map_or(false, |bond| bond.active >= T::MinimumBond::get())
+	}
+
+	fn do_feed_values(who: &T::AccountId, values: BoundedVec<(CurrencyId, Price), T::MaxFeedValues>) -> DispatchResult {
+		for (currency_id, price) in values {
+			T::Oracle::feed_value(Some(who.clone()), currency_id, price)?;
+		}
+		Ok(())
+	}
+
+	/// Whether the primary key of `who` is silent for more than `FailoverThreshold` blocks. The
+	/// primary key which never fed is silent.
+	pub fn is_primary_silent(who: &T::AccountId) -> bool {
+		Self::last_primary_feed(who).map_or(true, |last_feed| {
+			frame_system::Pallet::<T>::block_number().saturating_sub(last_feed) > T::FailoverThreshold::get()
+		})
+	}
+
+	fn note_primary_feed(who: &T::AccountId) {
+		LastPrimaryFeed::<T>::insert(who, frame_system::Pallet::<T>::block_number());
+		Self::deactivate_failover(who);
+	}
+
+	fn deactivate_failover(who: &T::AccountId) {
+		if Failovers::<T>::take(who).is_some() {
+			Self::deposit_event(Event::FailoverDeactivated { who: who.clone() });
+		}
+	}
+
+	fn update_bond(who: &T::AccountId, bond: OperatorBond<BlockNumberFor<T>>) {
+		if bond.total().is_zero() {
+			Bonds::<T>::remove(who);
+		} else {
+			Bonds::<T>::insert(who, bond);
+		}
+	}
+
+	/// Check the fee-less feed of `who`, and return the number of its feeds in the current block.
+	fn check_feed(who: &T::AccountId) -> Result<u32, TransactionValidityError> {
+		if !T::Operators::contains(who) {
+			return Err(InvalidTransaction::BadSigner.into());
+		}
+		if !Self::has_enough_bond(who) {
+			return Err(InvalidTransaction::Custom(FEED_PRIVILEGE_SUSPENDED).into());
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let (block_number, count) = Self::feeds_in_block(who);
+		let count = if block_number == now { count } else { Zero::zero() };
+		if count >= T::MaxFeedsPerBlock::get() {
+			return Err(InvalidTransaction::ExhaustsResources.into());
+		}
+		Ok(count)
+	}
+
+	/// Check the fee-less feed of the backup feeder `backup` for the operator `who`, and return
+	/// the number of the feeds of the operator in the current block.
+	fn check_backup_feed(backup: &T::AccountId, who: &T::AccountId) -> Result<u32, TransactionValidityError> {
+		if Self::backup_feeders(who).as_ref() != Some(backup) {
+			return Err(InvalidTransaction::BadSigner.into());
+		}
+		let count = Self::check_feed(who)?;
+		if !Self::is_primary_silent(who) {
+			return Err(InvalidTransaction::Custom(PRIMARY_FEEDER_HEALTHY).into());
+		}
+		Ok(count)
+	}
+
+	/// The operator the fee-less feed `call` of `who` is attributed to, and the number of its feeds
+	/// in the current block. Returns `None` if `call` is not a fee-less feed.
+	fn check_feed_call(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+	) -> Result<Option<(T::AccountId, u32)>, TransactionValidityError>
+	where
+		<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	{
+		match call.is_sub_type() {
+			Some(Call::feed_values { .. } | Call::feed_values_and_trigger { .. }) => {
+				Ok(Some((who.clone(), Self::check_feed(who)?)))
+			}
+			Some(Call::feed_values_as_backup { who: operator, .. }) => {
+				Ok(Some((operator.clone(), Self::check_backup_feed(who, operator)?)))
+			}
+			_ => Ok(None),
+		}
+	}
+}
+
+/// Validate the fee-less feeds of the oracle operators.
+///
+/// The feeds, including the ones triggering liquidations, of non-operators, of operators whose bond
+/// is below `MinimumBond` and over `MaxFeedsPerBlock` are rejected. The feeds of the backup feeders
+/// are rejected while the primary key of the operator is healthy, and count towards the
+/// `MaxFeedsPerBlock` of the operator. The valid feeds are assigned `FeedPriority`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckOracleFeed<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckOracleFeed<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckOracleFeed")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> CheckOracleFeed<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckOracleFeed<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckOracleFeed<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckOracleFeed";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if Pallet::<T>::check_feed_call(who, call)?.is_some() {
+			return Ok(ValidTransaction {
+				priority: T::FeedPriority::get(),
+				..Default::default()
+			});
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		if let Some((operator, count)) = Pallet::<T>::check_feed_call(who, call)? {
+			FeedsInBlock::<T>::insert(
+				operator,
+				(frame_system::Pallet::<T>::block_number(), count.saturating_add(1)),
+			);
+		}
+		Ok(())
+	}
+}
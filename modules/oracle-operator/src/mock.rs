@@ -0,0 +1,254 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the oracle operator module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
+	storage::unhashed,
+	traits::{ConstU128, ConstU32, ConstU64},
+};
+use frame_system::EnsureSignedBy;
+use primitives::TokenSymbol;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, DispatchError, DispatchResult};
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CAROL: AccountId = 3;
+pub const DAVE: AccountId = 4;
+pub const COUNCIL: AccountId = 100;
+
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const MINIMUM_BOND: Balance = 100;
+pub const UNBONDING_PERIOD: BlockNumber = 10;
+pub const MAX_FEEDS_PER_BLOCK: u32 = 2;
+pub const FEED_PRIORITY: TransactionPriority = 1_000_000;
+pub const MINIMUM_COUNT: u32 = 2;
+pub const RELAXED_MINIMUM_COUNT: u32 = 1;
+pub const ROTATION_GRACE_PERIOD: BlockNumber = 5;
+pub const MAX_LIQUIDATION_HINTS: u32 = 2;
+pub const FAILOVER_THRESHOLD: BlockNumber = 3;
+pub const CHECK_POSITION_WEIGHT: Weight = Weight::from_parts(1_000, 0);
+pub const LIQUIDATE_WEIGHT: Weight = Weight::from_parts(100_000, 0);
+
+mod oracle_operator {
+	pub use super::super::*;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+const OPERATORS_KEY: &[u8] = b"mock/operators";
+
+/// The operators are kept in the storage, so the failed rotations are reverted.
+pub struct MockOperators;
+impl SortedMembers<AccountId> for MockOperators {
+	fn sorted_members() -> Vec<AccountId> {
+		unhashed::get(OPERATORS_KEY).unwrap_or_else(|| vec![ALICE, BOB])
+	}
+}
+
+impl OperatorMembership<AccountId> for MockOperators {
+	fn add_operator(who: &AccountId) -> DispatchResult {
+		let mut operators = Self::sorted_members();
+		let index = operators
+			.binary_search(who)
+			.err()
+			.ok_or(DispatchError::Other("AlreadyMember"))?;
+		operators.insert(index, *who);
+		unhashed::put(OPERATORS_KEY, &operators);
+		Ok(())
+	}
+
+	fn remove_operator(who: &AccountId) -> DispatchResult {
+		let mut operators = Self::sorted_members();
+		let index = operators
+			.binary_search(who)
+			.map_err(|_| DispatchError::Other("NotMember"))?;
+		operators.remove(index);
+		unhashed::put(OPERATORS_KEY, &operators);
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub static FedValues: Vec<(AccountId, CurrencyId, Price)> = vec![];
+}
+
+pub struct MockOracle;
+impl DataFeeder<CurrencyId, Price, AccountId> for MockOracle {
+	fn feed_value(who: Option<AccountId>, currency_id: CurrencyId, price: Price) -> DispatchResult {
+		FedValues::mutate(|values| values.push((who.unwrap(), currency_id, price)));
+		Ok(())
+	}
+}
+
+impl OperatorValues<AccountId> for MockOracle {
+	fn purge_values(who: &AccountId) -> u32 {
+		FedValues::mutate(|values| {
+			let count = values.len();
+			values.retain(|(feeder, _, _)| feeder != who);
+			(count - values.len()) as u32
+		})
+	}
+}
+
+/// The median of the latest values of the operators, like the `DefaultCombineData` of the oracle.
+pub fn combined_price(currency_id: CurrencyId) -> Option<Price> {
+	let operators = MockOperators::sorted_members();
+	let mut prices: Vec<Price> = operators
+		.iter()
+		.filter_map(|operator| {
+			FedValues::get()
+				.into_iter()
+				.rev()
+				.find(|(feeder, key, _)| feeder == operator && *key == currency_id)
+				.map(|(_, _, price)| price)
+		})
+		.collect();
+	if (prices.len() as u32) < CurrentMinimumCount::<Runtime>::get() {
+		return None;
+	}
+
+	prices.sort();
+	prices.get(prices.len() / 2).copied()
+}
+
+parameter_types! {
+	pub static UnsafePositions: Vec<(CurrencyId, AccountId, Price)> = vec![];
+	pub static LiquidatedPositions: Vec<(CurrencyId, AccountId)> = vec![];
+}
+
+/// Liquidates the position once the combined price drops below its liquidation price.
+pub struct MockLiquidator;
+impl UnsafePositionLiquidator<AccountId> for MockLiquidator {
+	fn max_weight() -> Weight {
+		LIQUIDATE_WEIGHT
+	}
+
+	fn try_liquidate(currency_id: CurrencyId, who: &AccountId) -> (bool, Weight) {
+		let Some(price) = combined_price(currency_id) else {
+			return (false, CHECK_POSITION_WEIGHT);
+		};
+		let is_unsafe = UnsafePositions::get()
+			.iter()
+			.any(|(key, owner, liquidation_price)| *key == currency_id && owner == who && price < *liquidation_price);
+		if !is_unsafe {
+			return (false, CHECK_POSITION_WEIGHT);
+		}
+
+		UnsafePositions::mutate(|positions| positions.retain(|(key, owner, _)| !(*key == currency_id && owner == who)));
+		LiquidatedPositions::mutate(|positions| positions.push((currency_id, *who)));
+		(true, LIQUIDATE_WEIGHT)
+	}
+}
+
+ord_parameter_types! {
+	pub const Council: AccountId = COUNCIL;
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Operators = MockOperators;
+	type Oracle = MockOracle;
+	type MinimumBond = ConstU128<MINIMUM_BOND>;
+	type UnbondingPeriod = ConstU64<UNBONDING_PERIOD>;
+	type MaxFeedsPerBlock = ConstU32<MAX_FEEDS_PER_BLOCK>;
+	type MaxFeedValues = ConstU32<2>;
+	type FeedPriority = ConstU64<FEED_PRIORITY>;
+	type MaxLiquidationHints = ConstU32<MAX_LIQUIDATION_HINTS>;
+	type Liquidator = MockLiquidator;
+	type SlashOrigin = EnsureSignedBy<Council, AccountId>;
+	type OnSlash = ();
+	type OperatorMembership = MockOperators;
+	type OperatorValues = MockOracle;
+	type MinimumCount = ConstU32<MINIMUM_COUNT>;
+	type RelaxedMinimumCount = ConstU32<RELAXED_MINIMUM_COUNT>;
+	type RotationGracePeriod = ConstU64<ROTATION_GRACE_PERIOD>;
+	type RotateOrigin = EnsureSignedBy<Council, AccountId>;
+	type FailoverThreshold = ConstU64<FAILOVER_THRESHOLD>;
+	type WeightInfo = ();
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		OracleOperator: oracle_operator,
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(ALICE, 1_000), (BOB, 1_000), (CAROL, 1_000), (DAVE, 1_000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
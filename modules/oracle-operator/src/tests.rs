@@ -0,0 +1,718 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the oracle operator module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{GetDispatchInfo, Pays},
+	traits::ReservableCurrency,
+};
+use mock::{RuntimeCall, RuntimeEvent, *};
+use sp_runtime::{
+	traits::{BadOrigin, Dispatchable},
+	FixedPointNumber,
+};
+
+fn feed_call() -> RuntimeCall {
+	RuntimeCall::OracleOperator(crate::Call::feed_values {
+		values: vec![(DOT, Price::saturating_from_integer(10))].try_into().unwrap(),
+	})
+}
+
+fn validate_feed(who: AccountId) -> TransactionValidity {
+	let call = feed_call();
+	CheckOracleFeed::<Runtime>::new().validate(&who, &call, &call.get_dispatch_info(), 0)
+}
+
+fn pre_dispatch_feed(who: AccountId) -> Result<(), TransactionValidityError> {
+	let call = feed_call();
+	CheckOracleFeed::<Runtime>::new().pre_dispatch(&who, &call, &call.get_dispatch_info(), 0)
+}
+
+#[test]
+fn bond_unbond_and_withdraw_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(ALICE), 150));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::Bonded {
+			who: ALICE,
+			amount: 150,
+		}));
+		assert_eq!(Balances::reserved_balance(ALICE), 150);
+		assert!(OracleOperator::has_enough_bond(&ALICE));
+
+		assert_noop!(
+			OracleOperator::unbond(RuntimeOrigin::signed(ALICE), 151),
+			Error::<Runtime>::InsufficientBond
+		);
+		assert_noop!(
+			OracleOperator::unbond(RuntimeOrigin::signed(BOB), 1),
+			Error::<Runtime>::NoBond
+		);
+		assert_noop!(
+			OracleOperator::withdraw_unbonded(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::NoUnbonding
+		);
+
+		assert_ok!(OracleOperator::unbond(RuntimeOrigin::signed(ALICE), 100));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::Unbonded {
+			who: ALICE,
+			amount: 100,
+			unlock_at: 1 + UNBONDING_PERIOD,
+		}));
+		assert_eq!(
+			OracleOperator::bonds(ALICE),
+			Some(OperatorBond {
+				active: 50,
+				unbonding: Some((100, 1 + UNBONDING_PERIOD)),
+			})
+		);
+		assert!(!OracleOperator::has_enough_bond(&ALICE));
+		// still reserved during unbonding
+		assert_eq!(Balances::reserved_balance(ALICE), 150);
+
+		System::set_block_number(UNBONDING_PERIOD);
+		assert_noop!(
+			OracleOperator::withdraw_unbonded(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::UnbondingNotExpired
+		);
+
+		System::set_block_number(1 + UNBONDING_PERIOD);
+		assert_ok!(OracleOperator::withdraw_unbonded(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::Withdrawn {
+			who: ALICE,
+			amount: 100,
+		}));
+		assert_eq!(Balances::reserved_balance(ALICE), 50);
+		assert_eq!(
+			OracleOperator::bonds(ALICE),
+			Some(OperatorBond {
+				active: 50,
+				unbonding: None,
+			})
+		);
+
+		assert_ok!(OracleOperator::unbond(RuntimeOrigin::signed(ALICE), 50));
+		System::set_block_number(1 + 2 * UNBONDING_PERIOD);
+		assert_ok!(OracleOperator::withdraw_unbonded(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(OracleOperator::bonds(ALICE), None);
+	});
+}
+
+#[test]
+fn feed_values_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(feed_call().get_dispatch_info().pays_fee, Pays::No);
+
+		let values: BoundedVec<(CurrencyId, Price), ConstU32<2>> =
+			vec![(DOT, Price::saturating_from_integer(10))].try_into().unwrap();
+		assert_noop!(
+			OracleOperator::feed_values(RuntimeOrigin::signed(CAROL), values.clone()),
+			Error::<Runtime>::NotOperator
+		);
+		assert_noop!(
+			OracleOperator::feed_values(RuntimeOrigin::signed(ALICE), values.clone()),
+			Error::<Runtime>::FeedPrivilegeSuspended
+		);
+
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(ALICE), MINIMUM_BOND));
+		assert_ok!(OracleOperator::feed_values(RuntimeOrigin::signed(ALICE), values));
+		assert_eq!(FedValues::get(), vec![(ALICE, DOT, Price::saturating_from_integer(10))]);
+	});
+}
+
+#[test]
+fn check_oracle_feed_rejects_non_operator() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(CAROL), MINIMUM_BOND));
+		assert_eq!(validate_feed(CAROL), Err(InvalidTransaction::BadSigner.into()));
+		assert_eq!(pre_dispatch_feed(CAROL), Err(InvalidTransaction::BadSigner.into()));
+
+		// operator without enough bond
+		assert_eq!(
+			validate_feed(ALICE),
+			Err(InvalidTransaction::Custom(FEED_PRIVILEGE_SUSPENDED).into())
+		);
+
+		// other calls are not affected
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert_eq!(
+			CheckOracleFeed::<Runtime>::new().validate(&CAROL, &call, &call.get_dispatch_info(), 0),
+			Ok(ValidTransaction::default())
+		);
+	});
+}
+
+#[test]
+fn check_oracle_feed_caps_feeds_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(ALICE), MINIMUM_BOND));
+		assert_eq!(
+			validate_feed(ALICE),
+			Ok(ValidTransaction {
+				priority: FEED_PRIORITY,
+				..Default::default()
+			})
+		);
+
+		for _ in 0..MAX_FEEDS_PER_BLOCK {
+			assert_ok!(pre_dispatch_feed(ALICE));
+		}
+		assert_eq!(OracleOperator::feeds_in_block(ALICE), (1, MAX_FEEDS_PER_BLOCK));
+		assert_eq!(validate_feed(ALICE), Err(InvalidTransaction::ExhaustsResources.into()));
+		assert_eq!(
+			pre_dispatch_feed(ALICE),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+
+		// the cap is per operator
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(BOB), MINIMUM_BOND));
+		assert_ok!(pre_dispatch_feed(BOB));
+
+		// the cap is reset in the next block
+		System::set_block_number(2);
+		assert_ok!(validate_feed(ALICE));
+		assert_ok!(pre_dispatch_feed(ALICE));
+		assert_eq!(OracleOperator::feeds_in_block(ALICE), (2, 1));
+	});
+}
+
+#[test]
+fn slash_suspends_feed_privilege() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(ALICE), 150));
+		assert_ok!(OracleOperator::unbond(RuntimeOrigin::signed(ALICE), 30));
+		assert_ok!(validate_feed(ALICE));
+
+		assert_noop!(
+			OracleOperator::slash(RuntimeOrigin::signed(ALICE), ALICE, 10),
+			BadOrigin
+		);
+		assert_noop!(
+			OracleOperator::slash(RuntimeOrigin::signed(COUNCIL), BOB, 10),
+			Error::<Runtime>::NoBond
+		);
+
+		// still above the minimum bond
+		assert_ok!(OracleOperator::slash(RuntimeOrigin::signed(COUNCIL), ALICE, 20));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::Slashed {
+			who: ALICE,
+			amount: 20,
+		}));
+		assert!(OracleOperator::has_enough_bond(&ALICE));
+
+		let total_issuance = Balances::total_issuance();
+		assert_ok!(OracleOperator::slash(RuntimeOrigin::signed(COUNCIL), ALICE, 10));
+		System::assert_has_event(RuntimeEvent::OracleOperator(crate::Event::Slashed {
+			who: ALICE,
+			amount: 10,
+		}));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::FeedPrivilegeSuspended {
+			who: ALICE,
+		}));
+		assert_eq!(Balances::total_issuance(), total_issuance - 10);
+		assert_eq!(
+			OracleOperator::bonds(ALICE),
+			Some(OperatorBond {
+				active: 90,
+				unbonding: Some((30, 1 + UNBONDING_PERIOD)),
+			})
+		);
+		assert_eq!(
+			validate_feed(ALICE),
+			Err(InvalidTransaction::Custom(FEED_PRIVILEGE_SUSPENDED).into())
+		);
+		assert_noop!(
+			OracleOperator::feed_values(
+				RuntimeOrigin::signed(ALICE),
+				vec![(DOT, Price::saturating_from_integer(10))].try_into().unwrap()
+			),
+			Error::<Runtime>::FeedPrivilegeSuspended
+		);
+
+		// the unbonding amount is slashed after the active bond
+		assert_ok!(OracleOperator::slash(RuntimeOrigin::signed(COUNCIL), ALICE, 100));
+		assert_eq!(
+			OracleOperator::bonds(ALICE),
+			Some(OperatorBond {
+				active: 0,
+				unbonding: Some((20, 1 + UNBONDING_PERIOD)),
+			})
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 20);
+
+		// topping up the bond restores the privilege
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(ALICE), MINIMUM_BOND));
+		assert_ok!(validate_feed(ALICE));
+	});
+}
+
+fn bond_and_feed(who: AccountId, price: u128) {
+	if OracleOperator::bonds(who).is_none() {
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(who), MINIMUM_BOND));
+	}
+	assert_ok!(OracleOperator::feed_values(
+		RuntimeOrigin::signed(who),
+		vec![(DOT, Price::saturating_from_integer(price))].try_into().unwrap()
+	));
+}
+
+#[test]
+fn rotate_operators_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OracleOperator::rotate_operators(RuntimeOrigin::signed(ALICE), vec![BOB], vec![CAROL]),
+			BadOrigin
+		);
+		assert_noop!(
+			OracleOperator::rotate_operators(RuntimeOrigin::signed(COUNCIL), vec![BOB], vec![BOB]),
+			Error::<Runtime>::ConflictingRotation
+		);
+		// the rotation is reverted if any membership change fails
+		assert_noop!(
+			OracleOperator::rotate_operators(RuntimeOrigin::signed(COUNCIL), vec![BOB, CAROL], vec![]),
+			DispatchError::Other("NotMember")
+		);
+		assert_eq!(MockOperators::sorted_members(), vec![ALICE, BOB]);
+
+		assert_ok!(OracleOperator::rotate_operators(
+			RuntimeOrigin::signed(COUNCIL),
+			vec![BOB],
+			vec![CAROL, DAVE]
+		));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::OperatorsRotated {
+			removed: vec![BOB],
+			added: vec![CAROL, DAVE],
+			purged_values: 0,
+			relaxed_until: 1 + ROTATION_GRACE_PERIOD,
+		}));
+		assert_eq!(MockOperators::sorted_members(), vec![ALICE, CAROL, DAVE]);
+		assert_eq!(
+			OracleOperator::minimum_count_relaxed_until(),
+			Some(1 + ROTATION_GRACE_PERIOD)
+		);
+		assert_eq!(CurrentMinimumCount::<Runtime>::get(), RELAXED_MINIMUM_COUNT);
+
+		// the minimum count is restored after the grace period
+		OracleOperator::on_initialize(ROTATION_GRACE_PERIOD);
+		assert_eq!(
+			OracleOperator::minimum_count_relaxed_until(),
+			Some(1 + ROTATION_GRACE_PERIOD)
+		);
+		System::set_block_number(1 + ROTATION_GRACE_PERIOD);
+		assert_eq!(CurrentMinimumCount::<Runtime>::get(), MINIMUM_COUNT);
+		OracleOperator::on_initialize(1 + ROTATION_GRACE_PERIOD);
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::MinimumCountRestored));
+		assert_eq!(OracleOperator::minimum_count_relaxed_until(), None);
+	});
+}
+
+#[test]
+fn rotation_purges_removed_operator_values_in_flight() {
+	ExtBuilder::default().build().execute_with(|| {
+		bond_and_feed(ALICE, 10);
+		bond_and_feed(BOB, 30);
+		assert_eq!(combined_price(DOT), Some(Price::saturating_from_integer(30)));
+
+		// BOB is removed while CAROL and DAVE have not fed yet
+		assert_ok!(OracleOperator::rotate_operators(
+			RuntimeOrigin::signed(COUNCIL),
+			vec![BOB],
+			vec![CAROL, DAVE]
+		));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::OperatorsRotated {
+			removed: vec![BOB],
+			added: vec![CAROL, DAVE],
+			purged_values: 1,
+			relaxed_until: 1 + ROTATION_GRACE_PERIOD,
+		}));
+		assert!(FedValues::get().iter().all(|(feeder, _, _)| *feeder != BOB));
+
+		// the price stays available with the relaxed minimum count, without the purged feed
+		assert_eq!(combined_price(DOT), Some(Price::saturating_from_integer(10)));
+		assert_noop!(
+			OracleOperator::feed_values(
+				RuntimeOrigin::signed(BOB),
+				vec![(DOT, Price::saturating_from_integer(30))].try_into().unwrap()
+			),
+			Error::<Runtime>::NotOperator
+		);
+
+		System::set_block_number(2);
+		bond_and_feed(CAROL, 12);
+		assert_eq!(combined_price(DOT), Some(Price::saturating_from_integer(12)));
+
+		// after the grace period, the new operators have fed
+		System::set_block_number(1 + ROTATION_GRACE_PERIOD);
+		OracleOperator::on_initialize(1 + ROTATION_GRACE_PERIOD);
+		assert_eq!(combined_price(DOT), Some(Price::saturating_from_integer(12)));
+		bond_and_feed(DAVE, 11);
+		assert_eq!(combined_price(DOT), Some(Price::saturating_from_integer(11)));
+
+		// the restored minimum count applies again
+		assert_ok!(OracleOperator::rotate_operators(
+			RuntimeOrigin::signed(COUNCIL),
+			vec![CAROL, DAVE],
+			vec![]
+		));
+		System::set_block_number(1 + 2 * ROTATION_GRACE_PERIOD);
+		assert_eq!(combined_price(DOT), None);
+	});
+}
+
+fn feed_and_trigger_call(price: u128, hints: Vec<(CurrencyId, AccountId)>) -> RuntimeCall {
+	RuntimeCall::OracleOperator(crate::Call::feed_values_and_trigger {
+		values: vec![(DOT, Price::saturating_from_integer(price))].try_into().unwrap(),
+		liquidation_hints: hints.try_into().unwrap(),
+	})
+}
+
+#[test]
+fn feed_values_and_trigger_liquidates_in_the_feed_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		UnsafePositions::set(vec![
+			(DOT, CAROL, Price::saturating_from_integer(8)),
+			(DOT, DAVE, Price::saturating_from_integer(4)),
+		]);
+		bond_and_feed(ALICE, 10);
+		bond_and_feed(BOB, 10);
+
+		// the price gaps down, BOB's feed lands first
+		System::set_block_number(2);
+		bond_and_feed(BOB, 5);
+		assert_eq!(combined_price(DOT), Some(Price::saturating_from_integer(10)));
+
+		let call = feed_and_trigger_call(5, vec![(DOT, CAROL), (DOT, DAVE)]);
+		let info = call.get_dispatch_info();
+		assert_eq!(info.pays_fee, Pays::No);
+		assert_eq!(info.class, DispatchClass::Operational);
+		assert_eq!(
+			info.weight,
+			<() as WeightInfo>::feed_values(1).saturating_add(LIQUIDATE_WEIGHT.saturating_mul(2))
+		);
+
+		let post_info = call.dispatch(RuntimeOrigin::signed(ALICE)).unwrap();
+		// the unsafe position is liquidated with the just updated price, the safe one is skipped
+		assert_eq!(combined_price(DOT), Some(Price::saturating_from_integer(5)));
+		assert_eq!(LiquidatedPositions::get(), vec![(DOT, CAROL)]);
+		assert_eq!(
+			UnsafePositions::get(),
+			vec![(DOT, DAVE, Price::saturating_from_integer(4))]
+		);
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::LiquidationsTriggered {
+			who: ALICE,
+			liquidated: 1,
+			skipped: 1,
+		}));
+		assert_eq!(
+			post_info.actual_weight,
+			Some(
+				<() as WeightInfo>::feed_values(1)
+					.saturating_add(LIQUIDATE_WEIGHT)
+					.saturating_add(CHECK_POSITION_WEIGHT)
+			)
+		);
+	});
+}
+
+#[test]
+fn feed_values_liquidates_in_the_next_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		UnsafePositions::set(vec![(DOT, CAROL, Price::saturating_from_integer(8))]);
+		bond_and_feed(ALICE, 10);
+		bond_and_feed(BOB, 10);
+
+		// the price gaps down, the plain feeds don't liquidate
+		System::set_block_number(2);
+		bond_and_feed(BOB, 5);
+		bond_and_feed(ALICE, 5);
+		assert_eq!(combined_price(DOT), Some(Price::saturating_from_integer(5)));
+		assert_eq!(LiquidatedPositions::get(), vec![]);
+
+		// the offchain worker submits the liquidation for the next block
+		System::set_block_number(3);
+		assert_eq!(MockLiquidator::try_liquidate(DOT, &CAROL), (true, LIQUIDATE_WEIGHT));
+		assert_eq!(LiquidatedPositions::get(), vec![(DOT, CAROL)]);
+	});
+}
+
+#[test]
+fn feed_values_and_trigger_requires_operator() {
+	ExtBuilder::default().build().execute_with(|| {
+		UnsafePositions::set(vec![(DOT, DAVE, Price::saturating_from_integer(8))]);
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(CAROL), MINIMUM_BOND));
+		assert_noop!(
+			OracleOperator::feed_values_and_trigger(
+				RuntimeOrigin::signed(CAROL),
+				vec![(DOT, Price::saturating_from_integer(5))].try_into().unwrap(),
+				vec![(DOT, DAVE)].try_into().unwrap()
+			),
+			Error::<Runtime>::NotOperator
+		);
+		assert_noop!(
+			OracleOperator::feed_values_and_trigger(
+				RuntimeOrigin::signed(ALICE),
+				vec![(DOT, Price::saturating_from_integer(5))].try_into().unwrap(),
+				vec![(DOT, DAVE)].try_into().unwrap()
+			),
+			Error::<Runtime>::FeedPrivilegeSuspended
+		);
+		assert_eq!(LiquidatedPositions::get(), vec![]);
+
+		// the combined call is validated like the fee-less feeds
+		let call = feed_and_trigger_call(5, vec![(DOT, DAVE)]);
+		assert_eq!(
+			CheckOracleFeed::<Runtime>::new().validate(&CAROL, &call, &call.get_dispatch_info(), 0),
+			Err(InvalidTransaction::BadSigner.into())
+		);
+
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(ALICE), MINIMUM_BOND));
+		assert_eq!(
+			CheckOracleFeed::<Runtime>::new().validate(&ALICE, &call, &call.get_dispatch_info(), 0),
+			Ok(ValidTransaction {
+				priority: FEED_PRIORITY,
+				..Default::default()
+			})
+		);
+		for _ in 0..MAX_FEEDS_PER_BLOCK {
+			assert_ok!(CheckOracleFeed::<Runtime>::new().pre_dispatch(&ALICE, &call, &call.get_dispatch_info(), 0));
+		}
+		assert_eq!(
+			CheckOracleFeed::<Runtime>::new().pre_dispatch(&ALICE, &call, &call.get_dispatch_info(), 0),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+	});
+}
+
+fn backup_feed_call(who: AccountId, price: u128) -> RuntimeCall {
+	RuntimeCall::OracleOperator(crate::Call::feed_values_as_backup {
+		who,
+		values: vec![(DOT, Price::saturating_from_integer(price))].try_into().unwrap(),
+	})
+}
+
+fn validate_backup_feed(backup: AccountId, who: AccountId) -> TransactionValidity {
+	let call = backup_feed_call(who, 10);
+	CheckOracleFeed::<Runtime>::new().validate(&backup, &call, &call.get_dispatch_info(), 0)
+}
+
+fn backup_feed(backup: AccountId, who: AccountId, price: u128) -> DispatchResult {
+	OracleOperator::feed_values_as_backup(
+		RuntimeOrigin::signed(backup),
+		who,
+		vec![(DOT, Price::saturating_from_integer(price))].try_into().unwrap(),
+	)
+}
+
+#[test]
+fn register_and_revoke_backup_feeder_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OracleOperator::register_backup_feeder(RuntimeOrigin::signed(CAROL), DAVE),
+			Error::<Runtime>::NotOperator
+		);
+		assert_noop!(
+			OracleOperator::register_backup_feeder(RuntimeOrigin::signed(ALICE), BOB),
+			Error::<Runtime>::BackupIsOperator
+		);
+		assert_noop!(
+			OracleOperator::revoke_backup_feeder(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::NoBackupFeeder
+		);
+
+		assert_ok!(OracleOperator::register_backup_feeder(
+			RuntimeOrigin::signed(ALICE),
+			CAROL
+		));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::BackupFeederRegistered {
+			who: ALICE,
+			backup: CAROL,
+		}));
+		assert_eq!(OracleOperator::backup_feeders(ALICE), Some(CAROL));
+
+		// the primary never fed, the backup takes over
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(ALICE), MINIMUM_BOND));
+		assert_ok!(backup_feed(CAROL, ALICE, 10));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::FailoverActivated {
+			who: ALICE,
+			backup: CAROL,
+		}));
+		assert_eq!(OracleOperator::failovers(ALICE), Some(1));
+
+		// the revocation ends the failover
+		assert_ok!(OracleOperator::revoke_backup_feeder(RuntimeOrigin::signed(ALICE)));
+		System::assert_has_event(RuntimeEvent::OracleOperator(crate::Event::FailoverDeactivated {
+			who: ALICE,
+		}));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::BackupFeederRevoked {
+			who: ALICE,
+			backup: CAROL,
+		}));
+		assert_eq!(OracleOperator::backup_feeders(ALICE), None);
+		assert_eq!(OracleOperator::failovers(ALICE), None);
+
+		// the revoked backup can't feed
+		assert_eq!(
+			validate_backup_feed(CAROL, ALICE),
+			Err(InvalidTransaction::BadSigner.into())
+		);
+		assert_noop!(backup_feed(CAROL, ALICE, 10), Error::<Runtime>::NotBackupFeeder);
+		assert_eq!(FedValues::get(), vec![(ALICE, DOT, Price::saturating_from_integer(10))]);
+
+		// the replaced backup can't feed
+		assert_ok!(OracleOperator::register_backup_feeder(
+			RuntimeOrigin::signed(ALICE),
+			CAROL
+		));
+		assert_ok!(OracleOperator::register_backup_feeder(
+			RuntimeOrigin::signed(ALICE),
+			DAVE
+		));
+		assert_noop!(backup_feed(CAROL, ALICE, 10), Error::<Runtime>::NotBackupFeeder);
+		assert_ok!(backup_feed(DAVE, ALICE, 11));
+	});
+}
+
+#[test]
+fn backup_feeder_fails_over_after_primary_silence() {
+	ExtBuilder::default().build().execute_with(|| {
+		bond_and_feed(ALICE, 10);
+		assert_ok!(OracleOperator::register_backup_feeder(
+			RuntimeOrigin::signed(ALICE),
+			CAROL
+		));
+		assert_eq!(OracleOperator::last_primary_feed(ALICE), Some(1));
+
+		// the primary is healthy until it's silent for more than the threshold
+		System::set_block_number(1 + FAILOVER_THRESHOLD);
+		assert_eq!(
+			validate_backup_feed(CAROL, ALICE),
+			Err(InvalidTransaction::Custom(PRIMARY_FEEDER_HEALTHY).into())
+		);
+		assert_noop!(backup_feed(CAROL, ALICE, 11), Error::<Runtime>::PrimaryFeederHealthy);
+
+		// the primary feeds at the threshold, the backup is still rejected in the next block
+		bond_and_feed(ALICE, 10);
+		System::set_block_number(2 + FAILOVER_THRESHOLD);
+		assert_noop!(backup_feed(CAROL, ALICE, 11), Error::<Runtime>::PrimaryFeederHealthy);
+
+		System::set_block_number(2 + 2 * FAILOVER_THRESHOLD);
+		assert_eq!(
+			validate_backup_feed(CAROL, ALICE),
+			Ok(ValidTransaction {
+				priority: FEED_PRIORITY,
+				..Default::default()
+			})
+		);
+		assert_ok!(backup_feed(CAROL, ALICE, 11));
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::FailoverActivated {
+			who: ALICE,
+			backup: CAROL,
+		}));
+		// the values are attributed to the operator
+		assert_eq!(
+			FedValues::get().last(),
+			Some(&(ALICE, DOT, Price::saturating_from_integer(11)))
+		);
+
+		// the feeds of the backup count towards the cap of the operator
+		let call = backup_feed_call(ALICE, 11);
+		for _ in 0..MAX_FEEDS_PER_BLOCK {
+			assert_ok!(CheckOracleFeed::<Runtime>::new().pre_dispatch(&CAROL, &call, &call.get_dispatch_info(), 0));
+		}
+		assert_eq!(
+			OracleOperator::feeds_in_block(ALICE),
+			(2 + 2 * FAILOVER_THRESHOLD, MAX_FEEDS_PER_BLOCK)
+		);
+		assert_eq!(
+			pre_dispatch_feed(ALICE),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+
+		// the primary is back in the same block, the backup loses the race
+		System::reset_events();
+		bond_and_feed(ALICE, 12);
+		System::assert_last_event(RuntimeEvent::OracleOperator(crate::Event::FailoverDeactivated {
+			who: ALICE,
+		}));
+		assert_eq!(OracleOperator::failovers(ALICE), None);
+		assert_noop!(backup_feed(CAROL, ALICE, 11), Error::<Runtime>::PrimaryFeederHealthy);
+
+		// the failover is activated again after the primary is silent
+		System::set_block_number(3 + 3 * FAILOVER_THRESHOLD);
+		assert_ok!(backup_feed(CAROL, ALICE, 11));
+		assert_ok!(backup_feed(CAROL, ALICE, 11));
+		assert_eq!(
+			System::events()
+				.iter()
+				.filter(|record| matches!(
+					record.event,
+					RuntimeEvent::OracleOperator(crate::Event::FailoverActivated { .. })
+				))
+				.count(),
+			1
+		);
+	});
+}
+
+#[test]
+fn backup_feeder_cannot_feed_for_other_operator() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(ALICE), MINIMUM_BOND));
+		assert_ok!(OracleOperator::bond(RuntimeOrigin::signed(BOB), MINIMUM_BOND));
+		assert_ok!(OracleOperator::register_backup_feeder(
+			RuntimeOrigin::signed(ALICE),
+			CAROL
+		));
+		assert_ok!(OracleOperator::register_backup_feeder(RuntimeOrigin::signed(BOB), DAVE));
+
+		// both primaries are silent
+		assert_eq!(
+			validate_backup_feed(CAROL, BOB),
+			Err(InvalidTransaction::BadSigner.into())
+		);
+		assert_noop!(backup_feed(CAROL, BOB, 10), Error::<Runtime>::NotBackupFeeder);
+		assert_noop!(backup_feed(DAVE, ALICE, 10), Error::<Runtime>::NotBackupFeeder);
+		// the backup can't feed for itself
+		assert_eq!(validate_feed(CAROL), Err(InvalidTransaction::BadSigner.into()));
+
+		assert_ok!(backup_feed(CAROL, ALICE, 10));
+		assert_ok!(backup_feed(DAVE, BOB, 11));
+		assert_eq!(
+			FedValues::get(),
+			vec![
+				(ALICE, DOT, Price::saturating_from_integer(10)),
+				(BOB, DOT, Price::saturating_from_integer(11))
+			]
+		);
+
+		// the backup of the removed operator can't feed
+		assert_ok!(OracleOperator::rotate_operators(
+			RuntimeOrigin::signed(COUNCIL),
+			vec![BOB],
+			vec![]
+		));
+		assert_eq!(OracleOperator::backup_feeders(BOB), None);
+		assert_eq!(OracleOperator::failovers(BOB), None);
+		assert_noop!(backup_feed(DAVE, BOB, 11), Error::<Runtime>::NotOperator);
+	});
+}
@@ -0,0 +1,167 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_oracle_operator
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for module_oracle_operator.
+pub trait WeightInfo {
+	fn bond() -> Weight;
+	fn unbond() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn slash() -> Weight;
+	fn feed_values(c: u32, ) -> Weight;
+	fn rotate_operators(r: u32, a: u32, ) -> Weight;
+	fn register_backup_feeder() -> Weight;
+	fn revoke_backup_feeder() -> Weight;
+	fn feed_values_as_backup(c: u32, ) -> Weight;
+}
+
+/// Weights for module_oracle_operator using the Acala node and recommended hardware.
+pub struct AcalaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	fn bond() -> Weight {
+		Weight::from_parts(41_253_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn unbond() -> Weight {
+		Weight::from_parts(19_874_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn withdraw_unbonded() -> Weight {
+		Weight::from_parts(40_618_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn slash() -> Weight {
+		Weight::from_parts(45_902_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn feed_values(c: u32, ) -> Weight {
+		Weight::from_parts(18_301_000, 1567)
+			.saturating_add(Weight::from_parts(10_284_000, 0).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
+	}
+	fn rotate_operators(r: u32, a: u32, ) -> Weight {
+		Weight::from_parts(21_384_000, 1627)
+			.saturating_add(Weight::from_parts(38_516_000, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(24_941_000, 0).saturating_mul(a as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(a as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((15 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(a as u64)))
+	}
+	fn register_backup_feeder() -> Weight {
+		Weight::from_parts(17_462_000, 3697)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn revoke_backup_feeder() -> Weight {
+		Weight::from_parts(15_937_000, 1567)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn feed_values_as_backup(c: u32, ) -> Weight {
+		Weight::from_parts(21_518_000, 1567)
+			.saturating_add(Weight::from_parts(10_284_000, 0).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn bond() -> Weight {
+		Weight::from_parts(41_253_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn unbond() -> Weight {
+		Weight::from_parts(19_874_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn withdraw_unbonded() -> Weight {
+		Weight::from_parts(40_618_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn slash() -> Weight {
+		Weight::from_parts(45_902_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn feed_values(c: u32, ) -> Weight {
+		Weight::from_parts(18_301_000, 1567)
+			.saturating_add(Weight::from_parts(10_284_000, 0).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
+	}
+	fn rotate_operators(r: u32, a: u32, ) -> Weight {
+		Weight::from_parts(21_384_000, 1627)
+			.saturating_add(Weight::from_parts(38_516_000, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(24_941_000, 0).saturating_mul(a as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(a as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((15 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(a as u64)))
+	}
+	fn register_backup_feeder() -> Weight {
+		Weight::from_parts(17_462_000, 3697)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn revoke_backup_feeder() -> Weight {
+		Weight::from_parts(15_937_000, 1567)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn feed_values_as_backup(c: u32, ) -> Weight {
+		Weight::from_parts(21_518_000, 1567)
+			.saturating_add(Weight::from_parts(10_284_000, 0).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
+	}
+}
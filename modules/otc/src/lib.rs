@@ -0,0 +1,413 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # OTC Module
+//!
+//! Escrowed over-the-counter trades between two specific counterparties, for large trades which
+//! would suffer a high slippage on the dex.
+//!
+//! The maker creates an offer naming the taker, the supplied and the wanted assets, and an
+//! expiry. The supplied assets are escrowed in the module account when the offer is created. The
+//! taker accepts the offer by paying the wanted assets to the maker, receiving the escrowed assets
+//! and paying a flat settlement fee to the treasury, all in one transactional call: if any leg
+//! fails, e.g. the EVM transfer of an ERC20 token, the whole settlement is rolled back.
+//!
+//! Either party can cancel an open offer, and offers not accepted before the expiry are refunded
+//! to the maker automatically.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::ExistenceRequirement, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use module_support::EVMBridge;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::traits::{AccountIdConversion, StaticLookup, Zero};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type OfferId = u64;
+
+/// An open offer, whose supplied assets are escrowed in the module account.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Offer<AccountId, BlockNumber> {
+	/// The creator of the offer.
+	pub maker: AccountId,
+	/// The only account which can accept the offer.
+	pub taker: AccountId,
+	/// The currency escrowed from the maker.
+	pub supply_currency_id: CurrencyId,
+	/// The amount escrowed from the maker.
+	pub supply_amount: Balance,
+	/// The currency paid by the taker.
+	pub target_currency_id: CurrencyId,
+	/// The amount paid by the taker.
+	pub target_amount: Balance,
+	/// The block at which the offer is refunded if not accepted.
+	pub expiry: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currencies to trade, including ERC20 tokens.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// Sets the EVM origin of the ERC20 refunds on expiry, which have no transaction origin.
+		type EVMBridge: EVMBridge<Self::AccountId, Balance>;
+
+		/// The native currency id, in which the settlement fee is charged.
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
+		/// The flat fee paid by the taker to the treasury on settlement.
+		#[pallet::constant]
+		type SettlementFee: Get<Balance>;
+
+		/// The account receiving the settlement fee.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The module id, keep the escrowed assets.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of open offers created by an account.
+		#[pallet::constant]
+		type MaxOpenOffers: Get<u32>;
+
+		/// The maximum number of offers expiring at the same block.
+		#[pallet::constant]
+		type MaxExpiringOffersPerBlock: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The taker is the maker.
+		InvalidCounterparty,
+		/// The amount is zero or both sides are the same currency.
+		InvalidOffer,
+		/// The expiry is not in the future.
+		InvalidExpiry,
+		/// The maker has reached the maximum number of open offers.
+		TooManyOpenOffers,
+		/// Too many offers expire at the block, pick another expiry.
+		TooManyExpiringOffers,
+		/// The offer doesn't exist.
+		OfferNotFound,
+		/// Only the taker named in the offer can accept it.
+		NotCounterparty,
+		/// Only the maker or the taker can cancel the offer.
+		NotParticipant,
+		/// The offer has expired.
+		OfferExpired,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An offer is created and the supplied assets are escrowed.
+		OfferCreated {
+			offer_id: OfferId,
+			maker: T::AccountId,
+			taker: T::AccountId,
+			supply_currency_id: CurrencyId,
+			supply_amount: Balance,
+			target_currency_id: CurrencyId,
+			target_amount: Balance,
+			expiry: BlockNumberFor<T>,
+		},
+		/// An offer is accepted and settled.
+		OfferAccepted {
+			offer_id: OfferId,
+			maker: T::AccountId,
+			taker: T::AccountId,
+			/// The settlement fee paid by the taker to the treasury.
+			fee: Balance,
+		},
+		/// An offer is cancelled and the escrowed assets are refunded to the maker.
+		OfferCancelled { offer_id: OfferId, who: T::AccountId },
+		/// An offer has expired and the escrowed assets are refunded to the maker.
+		OfferExpired { offer_id: OfferId },
+	}
+
+	/// The next offer id.
+	///
+	/// NextOfferId: OfferId
+	#[pallet::storage]
+	#[pallet::getter(fn next_offer_id)]
+	pub type NextOfferId<T: Config> = StorageValue<_, OfferId, ValueQuery>;
+
+	/// The open offers.
+	///
+	/// Offers: map OfferId => Option<Offer>
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> =
+		StorageMap<_, Twox64Concat, OfferId, Offer<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+	/// The number of open offers created by an account.
+	///
+	/// OpenOffers: map AccountId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn open_offers)]
+	pub type OpenOffers<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The open offers expiring at a block.
+	///
+	/// ExpiringOffers: map BlockNumber => BoundedVec<OfferId>
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_offers)]
+	pub type ExpiringOffers<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, BoundedVec<OfferId, T::MaxExpiringOffersPerBlock>, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = ExpiringOffers::<T>::take(now);
+			let count = expiring.len() as u32;
+			for offer_id in expiring {
+				// if the refund fails, the offer stays open and can still be cancelled.
+				let _ = Self::do_expire_offer(offer_id);
+			}
+			T::WeightInfo::on_initialize(count)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create an offer to `taker` and escrow the supplied assets.
+		///
+		/// - `taker`: the only account which can accept the offer.
+		/// - `supply_currency_id`, `supply_amount`: the assets supplied by the caller.
+		/// - `target_currency_id`, `target_amount`: the assets wanted from the taker.
+		/// - `expiry`: the block at which the offer is refunded if not accepted.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_offer())]
+		pub fn create_offer(
+			origin: OriginFor<T>,
+			taker: <T::Lookup as StaticLookup>::Source,
+			supply_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] target_amount: Balance,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let maker = ensure_signed(origin)?;
+			let taker = T::Lookup::lookup(taker)?;
+			ensure!(maker != taker, Error::<T>::InvalidCounterparty);
+			ensure!(
+				!supply_amount.is_zero() && !target_amount.is_zero() && supply_currency_id != target_currency_id,
+				Error::<T>::InvalidOffer
+			);
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+
+			OpenOffers::<T>::try_mutate(&maker, |count| -> DispatchResult {
+				ensure!(*count < T::MaxOpenOffers::get(), Error::<T>::TooManyOpenOffers);
+				*count += 1;
+				Ok(())
+			})?;
+
+			let offer_id = NextOfferId::<T>::get();
+			NextOfferId::<T>::put(offer_id.saturating_add(1));
+			ExpiringOffers::<T>::try_append(expiry, offer_id).map_err(|_| Error::<T>::TooManyExpiringOffers)?;
+
+			T::Currency::transfer(
+				supply_currency_id,
+				&maker,
+				&Self::account_id(),
+				supply_amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Offers::<T>::insert(
+				offer_id,
+				Offer {
+					maker: maker.clone(),
+					taker: taker.clone(),
+					supply_currency_id,
+					supply_amount,
+					target_currency_id,
+					target_amount,
+					expiry,
+				},
+			);
+
+			Self::deposit_event(Event::OfferCreated {
+				offer_id,
+				maker,
+				taker,
+				supply_currency_id,
+				supply_amount,
+				target_currency_id,
+				target_amount,
+				expiry,
+			});
+			Ok(())
+		}
+
+		/// Accept the offer: pay the wanted assets to the maker, receive the escrowed assets and
+		/// pay the settlement fee to the treasury. Nothing is settled if any of the transfers
+		/// fails.
+		///
+		/// The dispatch origin of this call must be the taker named in the offer.
+		///
+		/// - `offer_id`: the offer to accept.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_offer())]
+		pub fn accept_offer(origin: OriginFor<T>, offer_id: OfferId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Offers::<T>::get(offer_id).ok_or(Error::<T>::OfferNotFound)?;
+			ensure!(who == offer.taker, Error::<T>::NotCounterparty);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < offer.expiry,
+				Error::<T>::OfferExpired
+			);
+
+			T::Currency::transfer(
+				offer.supply_currency_id,
+				&Self::account_id(),
+				&offer.taker,
+				offer.supply_amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			T::Currency::transfer(
+				offer.target_currency_id,
+				&offer.taker,
+				&offer.maker,
+				offer.target_amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			let fee = T::SettlementFee::get();
+			T::Currency::transfer(
+				T::GetNativeCurrencyId::get(),
+				&offer.taker,
+				&T::TreasuryAccount::get(),
+				fee,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::remove_offer(offer_id, &offer);
+
+			Self::deposit_event(Event::OfferAccepted {
+				offer_id,
+				maker: offer.maker,
+				taker: offer.taker,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Cancel the offer and refund the escrowed assets to the maker.
+		///
+		/// The dispatch origin of this call must be the maker or the taker of the offer.
+		///
+		/// - `offer_id`: the offer to cancel.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(origin: OriginFor<T>, offer_id: OfferId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Offers::<T>::get(offer_id).ok_or(Error::<T>::OfferNotFound)?;
+			ensure!(who == offer.maker || who == offer.taker, Error::<T>::NotParticipant);
+
+			Self::refund_offer(offer_id, &offer)?;
+
+			Self::deposit_event(Event::OfferCancelled { offer_id, who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account keeping the escrowed assets.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	#[transactional]
+	fn do_expire_offer(offer_id: OfferId) -> DispatchResult {
+		// the offer has been accepted or cancelled.
+		let Some(offer) = Offers::<T>::get(offer_id) else {
+			return Ok(());
+		};
+
+		// the maker pays the EVM storage of the refund.
+		let is_erc20 = matches!(offer.supply_currency_id, CurrencyId::Erc20(_));
+		if is_erc20 {
+			T::EVMBridge::set_origin(offer.maker.clone());
+		}
+		let res = Self::refund_offer(offer_id, &offer);
+		if is_erc20 {
+			T::EVMBridge::kill_origin();
+		}
+		res?;
+
+		Self::deposit_event(Event::OfferExpired { offer_id });
+		Ok(())
+	}
+
+	fn refund_offer(offer_id: OfferId, offer: &Offer<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
+		T::Currency::transfer(
+			offer.supply_currency_id,
+			&Self::account_id(),
+			&offer.maker,
+			offer.supply_amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		Self::remove_offer(offer_id, offer);
+		Ok(())
+	}
+
+	fn remove_offer(offer_id: OfferId, offer: &Offer<T::AccountId, BlockNumberFor<T>>) {
+		Offers::<T>::remove(offer_id);
+		OpenOffers::<T>::mutate_exists(&offer.maker, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| !c.is_zero());
+		});
+		ExpiringOffers::<T>::mutate_exists(offer.expiry, |maybe_offers| {
+			if let Some(offers) = maybe_offers {
+				offers.retain(|id| *id != offer_id);
+				if offers.is_empty() {
+					*maybe_offers = None;
+				}
+			}
+		});
+	}
+}
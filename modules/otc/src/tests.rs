@@ -0,0 +1,412 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the OTC module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use module_support::EVM as EVMTrait;
+
+fn create_offer(maker: AccountId, taker: AccountId, expiry: BlockNumberFor<Runtime>) -> OfferId {
+	let offer_id = Otc::next_offer_id();
+	assert_ok!(Otc::create_offer(
+		RuntimeOrigin::signed(maker),
+		taker,
+		DOT,
+		dollar(100),
+		AUSD,
+		dollar(1_000),
+		expiry
+	));
+	offer_id
+}
+
+#[test]
+fn create_offer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Otc::create_offer(
+			RuntimeOrigin::signed(alice()),
+			bob(),
+			DOT,
+			dollar(100),
+			AUSD,
+			dollar(1_000),
+			10
+		));
+		System::assert_last_event(RuntimeEvent::Otc(crate::Event::OfferCreated {
+			offer_id: 0,
+			maker: alice(),
+			taker: bob(),
+			supply_currency_id: DOT,
+			supply_amount: dollar(100),
+			target_currency_id: AUSD,
+			target_amount: dollar(1_000),
+			expiry: 10,
+		}));
+
+		assert_eq!(
+			Currencies::free_balance(DOT, &alice()),
+			dollar(INITIAL_BALANCE) - dollar(100)
+		);
+		assert_eq!(Currencies::free_balance(DOT, &Otc::account_id()), dollar(100));
+		assert_eq!(
+			Otc::offers(0),
+			Some(Offer {
+				maker: alice(),
+				taker: bob(),
+				supply_currency_id: DOT,
+				supply_amount: dollar(100),
+				target_currency_id: AUSD,
+				target_amount: dollar(1_000),
+				expiry: 10,
+			})
+		);
+		assert_eq!(Otc::next_offer_id(), 1);
+		assert_eq!(Otc::open_offers(alice()), 1);
+		assert_eq!(Otc::expiring_offers(10).into_inner(), vec![0]);
+	});
+}
+
+#[test]
+fn create_offer_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Otc::create_offer(
+				RuntimeOrigin::signed(alice()),
+				alice(),
+				DOT,
+				dollar(100),
+				AUSD,
+				dollar(1_000),
+				10
+			),
+			Error::<Runtime>::InvalidCounterparty
+		);
+		assert_noop!(
+			Otc::create_offer(RuntimeOrigin::signed(alice()), bob(), DOT, 0, AUSD, dollar(1_000), 10),
+			Error::<Runtime>::InvalidOffer
+		);
+		assert_noop!(
+			Otc::create_offer(
+				RuntimeOrigin::signed(alice()),
+				bob(),
+				DOT,
+				dollar(100),
+				DOT,
+				dollar(1_000),
+				10
+			),
+			Error::<Runtime>::InvalidOffer
+		);
+		assert_noop!(
+			Otc::create_offer(
+				RuntimeOrigin::signed(alice()),
+				bob(),
+				DOT,
+				dollar(100),
+				AUSD,
+				dollar(1_000),
+				1
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			Otc::create_offer(
+				RuntimeOrigin::signed(alice()),
+				bob(),
+				DOT,
+				dollar(INITIAL_BALANCE) + 1,
+				AUSD,
+				dollar(1_000),
+				10
+			),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		// the open offers of a maker are bounded.
+		create_offer(alice(), bob(), 10);
+		create_offer(alice(), bob(), 20);
+		assert_noop!(
+			Otc::create_offer(
+				RuntimeOrigin::signed(alice()),
+				bob(),
+				DOT,
+				dollar(100),
+				AUSD,
+				dollar(1_000),
+				30
+			),
+			Error::<Runtime>::TooManyOpenOffers
+		);
+
+		// the offers expiring at a block are bounded.
+		create_offer(bob(), alice(), 10);
+		assert_noop!(
+			Otc::create_offer(
+				RuntimeOrigin::signed(bob()),
+				alice(),
+				DOT,
+				dollar(100),
+				AUSD,
+				dollar(1_000),
+				10
+			),
+			Error::<Runtime>::TooManyExpiringOffers
+		);
+	});
+}
+
+#[test]
+fn accept_offer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let offer_id = create_offer(alice(), bob(), 10);
+
+		assert_ok!(Otc::accept_offer(RuntimeOrigin::signed(bob()), offer_id));
+		System::assert_last_event(RuntimeEvent::Otc(crate::Event::OfferAccepted {
+			offer_id,
+			maker: alice(),
+			taker: bob(),
+			fee: SETTLEMENT_FEE,
+		}));
+
+		assert_eq!(
+			Currencies::free_balance(DOT, &alice()),
+			dollar(INITIAL_BALANCE) - dollar(100)
+		);
+		assert_eq!(
+			Currencies::free_balance(AUSD, &alice()),
+			dollar(INITIAL_BALANCE) + dollar(1_000)
+		);
+		assert_eq!(
+			Currencies::free_balance(DOT, &bob()),
+			dollar(INITIAL_BALANCE) + dollar(100)
+		);
+		assert_eq!(
+			Currencies::free_balance(AUSD, &bob()),
+			dollar(INITIAL_BALANCE) - dollar(1_000)
+		);
+		assert_eq!(
+			Currencies::free_balance(ACA, &bob()),
+			dollar(INITIAL_BALANCE) - SETTLEMENT_FEE
+		);
+		assert_eq!(Currencies::free_balance(ACA, &TREASURY), SETTLEMENT_FEE);
+		assert_eq!(Currencies::free_balance(DOT, &Otc::account_id()), 0);
+
+		assert_eq!(Otc::offers(offer_id), None);
+		assert_eq!(Otc::open_offers(alice()), 0);
+		assert!(!ExpiringOffers::<Runtime>::contains_key(10));
+	});
+}
+
+#[test]
+fn accept_offer_rejects_wrong_counterparty() {
+	ExtBuilder::default().build().execute_with(|| {
+		let offer_id = create_offer(alice(), bob(), 10);
+
+		assert_noop!(
+			Otc::accept_offer(RuntimeOrigin::signed(CHARLIE), offer_id),
+			Error::<Runtime>::NotCounterparty
+		);
+		assert_noop!(
+			Otc::accept_offer(RuntimeOrigin::signed(alice()), offer_id),
+			Error::<Runtime>::NotCounterparty
+		);
+		assert_noop!(
+			Otc::accept_offer(RuntimeOrigin::signed(bob()), offer_id + 1),
+			Error::<Runtime>::OfferNotFound
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Otc::accept_offer(RuntimeOrigin::signed(bob()), offer_id),
+			Error::<Runtime>::OfferExpired
+		);
+	});
+}
+
+#[test]
+fn accept_offer_fails_without_the_target_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Otc::create_offer(
+			RuntimeOrigin::signed(alice()),
+			CHARLIE,
+			DOT,
+			dollar(100),
+			AUSD,
+			dollar(1_000),
+			10
+		));
+
+		assert_noop!(
+			Otc::accept_offer(RuntimeOrigin::signed(CHARLIE), 0),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+		assert_eq!(Currencies::free_balance(DOT, &Otc::account_id()), dollar(100));
+		assert_eq!(Currencies::free_balance(DOT, &CHARLIE), 0);
+	});
+}
+
+#[test]
+fn cancel_offer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let offer_id = create_offer(alice(), bob(), 10);
+		assert_noop!(
+			Otc::cancel_offer(RuntimeOrigin::signed(CHARLIE), offer_id),
+			Error::<Runtime>::NotParticipant
+		);
+
+		assert_ok!(Otc::cancel_offer(RuntimeOrigin::signed(alice()), offer_id));
+		System::assert_last_event(RuntimeEvent::Otc(crate::Event::OfferCancelled {
+			offer_id,
+			who: alice(),
+		}));
+		assert_eq!(Currencies::free_balance(DOT, &alice()), dollar(INITIAL_BALANCE));
+		assert_eq!(Otc::offers(offer_id), None);
+		assert_eq!(Otc::open_offers(alice()), 0);
+		assert!(!ExpiringOffers::<Runtime>::contains_key(10));
+		assert_noop!(
+			Otc::cancel_offer(RuntimeOrigin::signed(alice()), offer_id),
+			Error::<Runtime>::OfferNotFound
+		);
+
+		// the taker can cancel too.
+		let offer_id = create_offer(alice(), bob(), 10);
+		assert_ok!(Otc::cancel_offer(RuntimeOrigin::signed(bob()), offer_id));
+		System::assert_last_event(RuntimeEvent::Otc(crate::Event::OfferCancelled { offer_id, who: bob() }));
+		assert_eq!(Currencies::free_balance(DOT, &alice()), dollar(INITIAL_BALANCE));
+	});
+}
+
+#[test]
+fn expired_offers_are_refunded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let offer_0 = create_offer(alice(), bob(), 10);
+		let offer_1 = create_offer(alice(), bob(), 10);
+		let offer_2 = create_offer(bob(), alice(), 20);
+		assert_eq!(
+			Currencies::free_balance(DOT, &alice()),
+			dollar(INITIAL_BALANCE) - dollar(200)
+		);
+
+		Otc::on_initialize(9);
+		assert_eq!(Otc::open_offers(alice()), 2);
+
+		Otc::on_initialize(10);
+		System::assert_has_event(RuntimeEvent::Otc(crate::Event::OfferExpired { offer_id: offer_0 }));
+		System::assert_has_event(RuntimeEvent::Otc(crate::Event::OfferExpired { offer_id: offer_1 }));
+		assert_eq!(Currencies::free_balance(DOT, &alice()), dollar(INITIAL_BALANCE));
+		assert_eq!(Otc::offers(offer_0), None);
+		assert_eq!(Otc::offers(offer_1), None);
+		assert_eq!(Otc::open_offers(alice()), 0);
+		assert!(!ExpiringOffers::<Runtime>::contains_key(10));
+
+		// the offer of bob is untouched.
+		assert!(Otc::offers(offer_2).is_some());
+		assert_eq!(Currencies::free_balance(DOT, &Otc::account_id()), dollar(100));
+	});
+}
+
+#[test]
+fn erc20_offer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		deploy_contracts();
+		let erc20 = CurrencyId::Erc20(erc20_address());
+
+		<EVM as EVMTrait<AccountId>>::set_origin(alice());
+		assert_ok!(Otc::create_offer(
+			RuntimeOrigin::signed(alice()),
+			bob(),
+			erc20,
+			dollar(100),
+			AUSD,
+			dollar(1_000),
+			10
+		));
+		assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE - dollar(100));
+		assert_eq!(Currencies::free_balance(erc20, &Otc::account_id()), dollar(100));
+
+		<EVM as EVMTrait<AccountId>>::set_origin(bob());
+		assert_ok!(Otc::accept_offer(RuntimeOrigin::signed(bob()), 0));
+		assert_eq!(Currencies::free_balance(erc20, &bob()), dollar(100));
+		assert_eq!(Currencies::free_balance(erc20, &Otc::account_id()), 0);
+		assert_eq!(
+			Currencies::free_balance(AUSD, &alice()),
+			dollar(INITIAL_BALANCE) + dollar(1_000)
+		);
+	});
+}
+
+#[test]
+fn erc20_leg_failure_rolls_back_settlement() {
+	ExtBuilder::default().build().execute_with(|| {
+		deploy_contracts();
+		let erc20 = CurrencyId::Erc20(erc20_address());
+
+		// alice can't pay more erc20 tokens than she has.
+		assert_ok!(Otc::create_offer(
+			RuntimeOrigin::signed(bob()),
+			alice(),
+			DOT,
+			dollar(100),
+			erc20,
+			ALICE_BALANCE + 1,
+			10
+		));
+
+		<EVM as EVMTrait<AccountId>>::set_origin(alice());
+		// the escrowed DOT is released to alice before the erc20 leg fails.
+		assert_noop!(
+			Otc::accept_offer(RuntimeOrigin::signed(alice()), 0),
+			module_evm_bridge::Error::<Runtime>::ExecutionRevert
+		);
+		assert_eq!(Currencies::free_balance(DOT, &alice()), dollar(INITIAL_BALANCE));
+		assert_eq!(Currencies::free_balance(DOT, &Otc::account_id()), dollar(100));
+		assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE);
+		assert_eq!(Currencies::free_balance(ACA, &TREASURY), 0);
+		assert!(Otc::offers(0).is_some());
+	});
+}
+
+#[test]
+fn expired_erc20_offer_is_refunded() {
+	ExtBuilder::default().build().execute_with(|| {
+		deploy_contracts();
+		let erc20 = CurrencyId::Erc20(erc20_address());
+
+		<EVM as EVMTrait<AccountId>>::set_origin(alice());
+		assert_ok!(Otc::create_offer(
+			RuntimeOrigin::signed(alice()),
+			bob(),
+			erc20,
+			dollar(100),
+			AUSD,
+			dollar(1_000),
+			10
+		));
+		<EVM as EVMTrait<AccountId>>::kill_origin();
+
+		Otc::on_initialize(10);
+		System::assert_has_event(RuntimeEvent::Otc(crate::Event::OfferExpired { offer_id: 0 }));
+		assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE);
+		assert_eq!(Currencies::free_balance(erc20, &Otc::account_id()), 0);
+		assert_eq!(Otc::offers(0), None);
+	});
+}
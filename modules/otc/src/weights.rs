@@ -0,0 +1,139 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_otc
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for module_otc.
+pub trait WeightInfo {
+	fn create_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
+}
+
+/// Weights for module_otc using the Acala node and recommended hardware.
+pub struct AcalaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Storage: `Otc::OpenOffers` (r:1 w:1)
+	// Proof: `Otc::OpenOffers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `Otc::NextOfferId` (r:1 w:1)
+	// Proof: `Otc::NextOfferId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `Otc::ExpiringOffers` (r:1 w:1)
+	// Proof: `Otc::ExpiringOffers` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Otc::Offers` (r:0 w:1)
+	// Proof: `Otc::Offers` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
+	fn create_offer() -> Weight {
+		Weight::from_parts(68_214_000, 7234)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: `Otc::Offers` (r:1 w:1)
+	// Proof: `Otc::Offers` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:4 w:4)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:3 w:3)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Otc::OpenOffers` (r:1 w:1)
+	// Proof: `Otc::OpenOffers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `Otc::ExpiringOffers` (r:1 w:1)
+	// Proof: `Otc::ExpiringOffers` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	fn accept_offer() -> Weight {
+		Weight::from_parts(112_473_000, 11478)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: `Otc::Offers` (r:1 w:1)
+	// Proof: `Otc::Offers` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Otc::OpenOffers` (r:1 w:1)
+	// Proof: `Otc::OpenOffers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `Otc::ExpiringOffers` (r:1 w:1)
+	// Proof: `Otc::ExpiringOffers` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	fn cancel_offer() -> Weight {
+		Weight::from_parts(61_930_000, 7234)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: `Otc::ExpiringOffers` (r:1 w:1)
+	// Proof: `Otc::ExpiringOffers` (`max_values`: None, `max_size`: Some(813), added: 3288, mode: `MaxEncodedLen`)
+	// Storage: `Otc::Offers` (r:100 w:100)
+	// Proof: `Otc::Offers` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:101 w:101)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:100 w:100)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Otc::OpenOffers` (r:100 w:100)
+	// Proof: `Otc::OpenOffers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(4_812_000, 4278)
+			.saturating_add(Weight::from_parts(48_367_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_parts(0, 2673).saturating_mul(n as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_offer() -> Weight {
+		Weight::from_parts(68_214_000, 7234)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	fn accept_offer() -> Weight {
+		Weight::from_parts(112_473_000, 11478)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	fn cancel_offer() -> Weight {
+		Weight::from_parts(61_930_000, 7234)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(4_812_000, 4278)
+			.saturating_add(Weight::from_parts(48_367_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_parts(0, 2673).saturating_mul(n as u64))
+	}
+}
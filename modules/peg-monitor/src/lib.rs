@@ -0,0 +1,337 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Peg Monitor Module
+//!
+//! ## Overview
+//!
+//! Measures the peg of the stable currency. Every `AccumulatePeriod` blocks, the price of one
+//! stable currency token is sampled from the configured sources, such as DEX pools, stable
+//! pools and oracle prices of bridged representations, and their weighted average is compared
+//! to $1. The result is kept in a short history, exposed to other modules by `PegStatusProvider`
+//! and to clients by runtime API, and `PegDeviation` is emitted when the deviation exceeds the
+//! threshold.
+//!
+//! The module only measures, it doesn't change any parameter of other modules.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+#![allow(clippy::upper_case_acronyms)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use module_support::{
+	DEXManager, Erc20InfoMapping, PegStatus, PegStatusProvider, Price, PriceProvider, Ratio, Swap, SwapLimit,
+};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{One, SaturatedConversion, Zero},
+	FixedPointNumber,
+};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The default deviation threshold, in basis points.
+pub const DEFAULT_DEVIATION_THRESHOLD: u32 = 100;
+
+/// A venue to sample the price of the stable currency from.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PegSource {
+	/// The DEX pool of the stable currency and `CurrencyId`, valued by the oracle price of
+	/// `CurrencyId`.
+	DexPair(CurrencyId),
+	/// The stable pool swap of one stable currency token to `CurrencyId`, valued by the oracle
+	/// price of `CurrencyId`.
+	StablePool(CurrencyId),
+	/// The oracle price of `CurrencyId`, which is redeemable 1:1 for the stable currency, e.g. a
+	/// bridged representation of it.
+	OraclePrice(CurrencyId),
+}
+
+/// A peg source and its weight in the basket.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct WeightedPegSource {
+	pub source: PegSource,
+	pub weight: u32,
+}
+
+pub type PegStatusOf<T> = PegStatus<BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The stable currency id to monitor.
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// The period to measure the peg.
+		#[pallet::constant]
+		type AccumulatePeriod: Get<BlockNumberFor<Self>>;
+
+		/// DEX to sample the `DexPair` sources.
+		type DEX: DEXManager<Self::AccountId, Balance, CurrencyId>;
+
+		/// Stable pool swap to sample the `StablePool` sources.
+		type StablePoolSwap: Swap<Self::AccountId, Balance, CurrencyId>;
+
+		/// The oracle price source, which provides the price of one basic unit.
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// Mapping to get the decimals of currencies.
+		type Erc20InfoMapping: Erc20InfoMapping;
+
+		/// The origin which may update the peg sources and the threshold.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of peg sources.
+		#[pallet::constant]
+		type MaxSources: Get<u32>;
+
+		/// The maximum length of the peg status history.
+		#[pallet::constant]
+		type MaxHistory: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Too many peg sources.
+		TooManySources,
+		/// The weight of a peg source is zero.
+		ZeroWeight,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The peg sources are updated.
+		PegSourcesUpdated { sources: Vec<WeightedPegSource> },
+		/// The deviation threshold is updated.
+		DeviationThresholdUpdated { threshold_bps: u32 },
+		/// The basket price of the stable currency deviates from $1 beyond the threshold.
+		PegDeviation { price: Price, bps: u32 },
+		/// None of the peg sources could be priced.
+		PegPriceUnavailable,
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultDeviationThreshold() -> u32 {
+		DEFAULT_DEVIATION_THRESHOLD
+	}
+
+	/// The weighted peg sources.
+	///
+	/// PegSources: Vec<WeightedPegSource>
+	#[pallet::storage]
+	#[pallet::getter(fn peg_sources)]
+	pub type PegSources<T: Config> = StorageValue<_, BoundedVec<WeightedPegSource, T::MaxSources>, ValueQuery>;
+
+	/// The deviation from $1 beyond which `PegDeviation` is emitted, in basis points.
+	///
+	/// DeviationThreshold: u32
+	#[pallet::storage]
+	#[pallet::getter(fn deviation_threshold)]
+	pub type DeviationThreshold<T: Config> = StorageValue<_, u32, ValueQuery, DefaultDeviationThreshold>;
+
+	/// The recent peg statuses, from the oldest to the latest.
+	///
+	/// PegHistory: Vec<PegStatus>
+	#[pallet::storage]
+	#[pallet::getter(fn peg_history)]
+	pub type PegHistory<T: Config> = StorageValue<_, BoundedVec<PegStatusOf<T>, T::MaxHistory>, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			if now % T::AccumulatePeriod::get() == Zero::zero() {
+				let count = Self::measure_peg(now);
+				T::WeightInfo::on_initialize(count)
+			} else {
+				Weight::zero()
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the weighted peg sources.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `sources`: the peg sources and their weights in the basket.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_peg_sources(sources.len() as u32))]
+		pub fn set_peg_sources(origin: OriginFor<T>, sources: Vec<WeightedPegSource>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(sources.iter().all(|s| !s.weight.is_zero()), Error::<T>::ZeroWeight);
+			let bounded_sources: BoundedVec<WeightedPegSource, T::MaxSources> =
+				sources.clone().try_into().map_err(|_| Error::<T>::TooManySources)?;
+
+			PegSources::<T>::put(bounded_sources);
+			Self::deposit_event(Event::PegSourcesUpdated { sources });
+			Ok(())
+		}
+
+		/// Set the deviation threshold.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `threshold_bps`: the deviation threshold, in basis points.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_deviation_threshold())]
+		pub fn set_deviation_threshold(origin: OriginFor<T>, threshold_bps: u32) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			DeviationThreshold::<T>::put(threshold_bps);
+			Self::deposit_event(Event::DeviationThresholdUpdated { threshold_bps });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The amount of basic units of one token of `currency_id`.
+	fn unit(currency_id: CurrencyId) -> Option<Balance> {
+		10u128.checked_pow(T::Erc20InfoMapping::decimals(currency_id)?.into())
+	}
+
+	/// Sample the USD price of one stable currency token from `source`.
+	pub fn source_price(source: PegSource) -> Option<Price> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let stable_unit = Self::unit(stable_currency_id)?;
+
+		// the amount of basic units of `currency_id` which one basic unit of the stable currency is
+		// worth.
+		let (currency_id, rate) = match source {
+			PegSource::DexPair(currency_id) => {
+				let (stable_pool, other_pool) = T::DEX::get_liquidity_pool(stable_currency_id, currency_id);
+				if other_pool.is_zero() {
+					return None;
+				}
+				(currency_id, Ratio::checked_from_rational(other_pool, stable_pool)?)
+			}
+			PegSource::StablePool(currency_id) => {
+				let (supply_amount, target_amount) = T::StablePoolSwap::get_swap_amount(
+					stable_currency_id,
+					currency_id,
+					SwapLimit::ExactSupply(stable_unit, Zero::zero()),
+				)?;
+				(currency_id, Ratio::checked_from_rational(target_amount, supply_amount)?)
+			}
+			PegSource::OraclePrice(currency_id) => (
+				currency_id,
+				Ratio::checked_from_rational(Self::unit(currency_id)?, stable_unit)?,
+			),
+		};
+
+		rate.checked_mul(&Ratio::checked_from_integer(stable_unit)?)?
+			.checked_mul(&T::PriceSource::get_price(currency_id)?)
+	}
+
+	/// Measure the weighted basket price of the stable currency from the available sources.
+	/// Returns the price and the number of sampled sources.
+	pub fn basket_price() -> (Option<Price>, u32) {
+		let sources = PegSources::<T>::get();
+		let mut weighted_price = Price::zero();
+		let mut total_weight: u32 = 0;
+
+		for WeightedPegSource { source, weight } in sources.iter() {
+			if let Some(price) = Self::source_price(*source) {
+				weighted_price =
+					weighted_price.saturating_add(price.saturating_mul(Price::saturating_from_integer(*weight)));
+				total_weight = total_weight.saturating_add(*weight);
+			}
+		}
+
+		let price = if total_weight.is_zero() {
+			None
+		} else {
+			weighted_price.checked_div(&Price::saturating_from_integer(total_weight))
+		};
+
+		(price, sources.len() as u32)
+	}
+
+	/// The absolute deviation of `price` from $1, in basis points.
+	pub fn deviation_bps(price: Price) -> u32 {
+		let one = Price::one();
+		let deviation = if price > one { price - one } else { one - price };
+		deviation.saturating_mul_int(10_000u128).saturated_into()
+	}
+
+	fn measure_peg(now: BlockNumberFor<T>) -> u32 {
+		let (maybe_price, count) = Self::basket_price();
+		if count.is_zero() {
+			return count;
+		}
+
+		match maybe_price {
+			Some(price) => {
+				let deviation_bps = Self::deviation_bps(price);
+				let status = PegStatus {
+					price,
+					deviation_bps,
+					updated_at: now,
+				};
+				PegHistory::<T>::mutate(|history| {
+					// drop the oldest status if the history is full.
+					let _ = history.force_insert_keep_right(history.len(), status);
+				});
+
+				if deviation_bps > DeviationThreshold::<T>::get() {
+					Self::deposit_event(Event::PegDeviation {
+						price,
+						bps: deviation_bps,
+					});
+				}
+			}
+			None => Self::deposit_event(Event::PegPriceUnavailable),
+		}
+
+		count
+	}
+
+	/// The latest measured peg status.
+	pub fn get_peg_status() -> Option<PegStatusOf<T>> {
+		PegHistory::<T>::get().last().copied()
+	}
+}
+
+impl<T: Config> PegStatusProvider<BlockNumberFor<T>> for Pallet<T> {
+	fn get_peg_status() -> Option<PegStatusOf<T>> {
+		Self::get_peg_status()
+	}
+}
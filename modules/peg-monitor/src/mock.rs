@@ -0,0 +1,249 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the peg monitor module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureSignedBy;
+use module_support::mocks::MockErc20InfoMapping;
+use primitives::TokenSymbol;
+use sp_core::H160;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, DispatchError};
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const KUSD: CurrencyId = CurrencyId::Token(TokenSymbol::KUSD);
+
+mod peg_monitor {
+	pub use super::super::*;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+}
+
+parameter_types! {
+	/// The liquidity pools of the stable currency: (currency_id, stable_pool, other_pool).
+	pub static LiquidityPools: Vec<(CurrencyId, Balance, Balance)> = vec![];
+	/// The stable pool swap rates of one basic unit of the stable currency: (currency_id, rate).
+	pub static StablePoolRates: Vec<(CurrencyId, Ratio)> = vec![];
+	/// The oracle prices of one basic unit: (currency_id, price).
+	pub static OraclePrices: Vec<(CurrencyId, Price)> = vec![];
+	pub const GetStableCurrencyId: CurrencyId = AUSD;
+}
+
+ord_parameter_types! {
+	pub const Admin: AccountId = ALICE;
+}
+
+/// Set the DEX pool of the stable currency and `currency_id`, in tokens.
+pub fn set_liquidity_pool(currency_id: CurrencyId, stable_tokens: Balance, other_tokens: Balance) {
+	LiquidityPools::mutate(|pools| {
+		pools.retain(|(id, _, _)| *id != currency_id);
+		pools.push((
+			currency_id,
+			stable_tokens * unit(AUSD),
+			other_tokens * unit(currency_id),
+		));
+	});
+}
+
+/// Set the amount of `currency_id` tokens swapped out from one stable currency token.
+pub fn set_stable_pool_rate(currency_id: CurrencyId, rate: Ratio) {
+	let basic_rate = rate * Ratio::saturating_from_rational(unit(currency_id), unit(AUSD));
+	StablePoolRates::mutate(|rates| {
+		rates.retain(|(id, _)| *id != currency_id);
+		rates.push((currency_id, basic_rate));
+	});
+}
+
+/// Set the USD price of one `currency_id` token.
+pub fn set_oracle_price(currency_id: CurrencyId, price: Price) {
+	let basic_price = price / Price::saturating_from_integer(unit(currency_id));
+	OraclePrices::mutate(|prices| {
+		prices.retain(|(id, _)| *id != currency_id);
+		prices.push((currency_id, basic_price));
+	});
+}
+
+pub fn unit(currency_id: CurrencyId) -> Balance {
+	10u128.pow(MockErc20InfoMapping::decimals(currency_id).unwrap().into())
+}
+
+pub struct MockDEX;
+impl DEXManager<AccountId, Balance, CurrencyId> for MockDEX {
+	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+		assert_eq!(currency_id_a, AUSD);
+		LiquidityPools::get()
+			.into_iter()
+			.find(|(id, _, _)| *id == currency_id_b)
+			.map(|(_, stable_pool, other_pool)| (stable_pool, other_pool))
+			.unwrap_or_default()
+	}
+
+	fn get_liquidity_token_address(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> Option<H160> {
+		unimplemented!()
+	}
+
+	fn get_swap_amount(_path: &[CurrencyId], _limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+		unimplemented!()
+	}
+
+	fn get_best_price_swap_path(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_limit: SwapLimit<Balance>,
+		_alternative_path_joint_list: Vec<Vec<CurrencyId>>,
+	) -> Option<(Vec<CurrencyId>, Balance, Balance)> {
+		unimplemented!()
+	}
+
+	fn swap_with_specific_path(
+		_who: &AccountId,
+		_path: &[CurrencyId],
+		_limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		unimplemented!()
+	}
+
+	fn add_liquidity(
+		_who: &AccountId,
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_max_amount_a: Balance,
+		_max_amount_b: Balance,
+		_min_share_increment: Balance,
+		_stake_increment_share: bool,
+	) -> sp_std::result::Result<(Balance, Balance, Balance), DispatchError> {
+		unimplemented!()
+	}
+
+	fn remove_liquidity(
+		_who: &AccountId,
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_remove_share: Balance,
+		_min_withdrawn_a: Balance,
+		_min_withdrawn_b: Balance,
+		_by_unstake: bool,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		unimplemented!()
+	}
+}
+
+pub struct MockStablePoolSwap;
+impl Swap<AccountId, Balance, CurrencyId> for MockStablePoolSwap {
+	fn get_swap_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
+		assert_eq!(supply_currency_id, AUSD);
+		let rate = StablePoolRates::get()
+			.into_iter()
+			.find(|(id, _)| *id == target_currency_id)
+			.map(|(_, rate)| rate)?;
+		match limit {
+			SwapLimit::ExactSupply(supply_amount, _) => Some((supply_amount, rate.saturating_mul_int(supply_amount))),
+			SwapLimit::ExactTarget(..) => unimplemented!(),
+		}
+	}
+
+	fn swap(
+		_who: &AccountId,
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		unimplemented!()
+	}
+
+	fn swap_by_aggregated_path(
+		_who: &AccountId,
+		_swap_path: &[module_support::AggregatedSwapPath<CurrencyId>],
+		_limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		unimplemented!()
+	}
+}
+
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		OraclePrices::get()
+			.into_iter()
+			.find(|(id, _)| *id == currency_id)
+			.map(|(_, price)| price)
+	}
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type AccumulatePeriod = ConstU64<10>;
+	type DEX = MockDEX;
+	type StablePoolSwap = MockStablePoolSwap;
+	type PriceSource = MockPriceSource;
+	type Erc20InfoMapping = MockErc20InfoMapping;
+	type UpdateOrigin = EnsureSignedBy<Admin, AccountId>;
+	type MaxSources = ConstU32<3>;
+	type MaxHistory = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		PegMonitor: peg_monitor,
+	}
+);
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,302 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the peg monitor module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use sp_runtime::traits::BadOrigin;
+
+fn dex_pair(weight: u32) -> WeightedPegSource {
+	WeightedPegSource {
+		source: PegSource::DexPair(DOT),
+		weight,
+	}
+}
+
+fn stable_pool(weight: u32) -> WeightedPegSource {
+	WeightedPegSource {
+		source: PegSource::StablePool(KUSD),
+		weight,
+	}
+}
+
+fn oracle_price(weight: u32) -> WeightedPegSource {
+	WeightedPegSource {
+		source: PegSource::OraclePrice(KUSD),
+		weight,
+	}
+}
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+	PegMonitor::on_initialize(n);
+}
+
+fn peg_deviation_events() -> Vec<Event<Runtime>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::PegMonitor(event @ Event::PegDeviation { .. }) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn set_peg_sources_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PegMonitor::set_peg_sources(RuntimeOrigin::signed(BOB), vec![dex_pair(1)]),
+			BadOrigin
+		);
+		assert_noop!(
+			PegMonitor::set_peg_sources(RuntimeOrigin::signed(ALICE), vec![dex_pair(1), stable_pool(0)]),
+			Error::<Runtime>::ZeroWeight
+		);
+		assert_noop!(
+			PegMonitor::set_peg_sources(
+				RuntimeOrigin::signed(ALICE),
+				vec![dex_pair(1), stable_pool(1), oracle_price(1), oracle_price(1)]
+			),
+			Error::<Runtime>::TooManySources
+		);
+
+		assert_ok!(PegMonitor::set_peg_sources(
+			RuntimeOrigin::signed(ALICE),
+			vec![dex_pair(1), stable_pool(2)]
+		));
+		System::assert_last_event(RuntimeEvent::PegMonitor(Event::PegSourcesUpdated {
+			sources: vec![dex_pair(1), stable_pool(2)],
+		}));
+		assert_eq!(
+			PegMonitor::peg_sources().into_inner(),
+			vec![dex_pair(1), stable_pool(2)]
+		);
+
+		assert_ok!(PegMonitor::set_peg_sources(RuntimeOrigin::signed(ALICE), vec![]));
+		assert!(PegMonitor::peg_sources().is_empty());
+	});
+}
+
+#[test]
+fn set_deviation_threshold_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PegMonitor::deviation_threshold(), DEFAULT_DEVIATION_THRESHOLD);
+		assert_noop!(
+			PegMonitor::set_deviation_threshold(RuntimeOrigin::signed(BOB), 50),
+			BadOrigin
+		);
+
+		assert_ok!(PegMonitor::set_deviation_threshold(RuntimeOrigin::signed(ALICE), 50));
+		System::assert_last_event(RuntimeEvent::PegMonitor(Event::DeviationThresholdUpdated {
+			threshold_bps: 50,
+		}));
+		assert_eq!(PegMonitor::deviation_threshold(), 50);
+	});
+}
+
+#[test]
+fn source_price_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PegMonitor::source_price(PegSource::DexPair(DOT)), None);
+		assert_eq!(PegMonitor::source_price(PegSource::StablePool(KUSD)), None);
+		assert_eq!(PegMonitor::source_price(PegSource::OraclePrice(KUSD)), None);
+
+		// 1000 AUSD : 101 DOT, and DOT is $10
+		set_liquidity_pool(DOT, 1000, 101);
+		assert_eq!(PegMonitor::source_price(PegSource::DexPair(DOT)), None);
+		set_oracle_price(DOT, Price::saturating_from_integer(10));
+		assert_eq!(
+			PegMonitor::source_price(PegSource::DexPair(DOT)),
+			Some(Price::saturating_from_rational(101, 100))
+		);
+
+		// 1 AUSD swaps to 0.995 KUSD, and KUSD is $1
+		set_stable_pool_rate(KUSD, Ratio::saturating_from_rational(995, 1000));
+		assert_eq!(PegMonitor::source_price(PegSource::StablePool(KUSD)), None);
+		set_oracle_price(KUSD, Price::one());
+		assert_eq!(
+			PegMonitor::source_price(PegSource::StablePool(KUSD)),
+			Some(Price::saturating_from_rational(995, 1000))
+		);
+		assert_eq!(
+			PegMonitor::source_price(PegSource::OraclePrice(KUSD)),
+			Some(Price::one())
+		);
+
+		// empty pool can not be priced
+		set_liquidity_pool(DOT, 1000, 0);
+		assert_eq!(PegMonitor::source_price(PegSource::DexPair(DOT)), None);
+		set_liquidity_pool(DOT, 0, 101);
+		assert_eq!(PegMonitor::source_price(PegSource::DexPair(DOT)), None);
+	});
+}
+
+#[test]
+fn deviation_bps_works() {
+	assert_eq!(PegMonitor::deviation_bps(Price::one()), 0);
+	assert_eq!(
+		PegMonitor::deviation_bps(Price::saturating_from_rational(101, 100)),
+		100
+	);
+	assert_eq!(
+		PegMonitor::deviation_bps(Price::saturating_from_rational(995, 1000)),
+		50
+	);
+	assert_eq!(PegMonitor::deviation_bps(Price::saturating_from_rational(98, 100)), 200);
+	assert_eq!(PegMonitor::deviation_bps(Price::zero()), 10_000);
+	assert_eq!(PegMonitor::deviation_bps(Price::from_inner(u128::MAX)), u32::MAX);
+}
+
+#[test]
+fn measure_weighted_basket_price_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_oracle_price(DOT, Price::saturating_from_integer(10));
+		set_oracle_price(KUSD, Price::one());
+		// DEX implies $0.97
+		set_liquidity_pool(DOT, 1000, 97);
+		// stable pool implies $0.995
+		set_stable_pool_rate(KUSD, Ratio::saturating_from_rational(995, 1000));
+		assert_ok!(PegMonitor::set_peg_sources(
+			RuntimeOrigin::signed(ALICE),
+			vec![dex_pair(1), stable_pool(2), oracle_price(1)]
+		));
+
+		// not the accumulate period
+		run_to_block(9);
+		assert_eq!(PegMonitor::get_peg_status(), None);
+
+		// (0.97 * 1 + 0.995 * 2 + 1 * 1) / 4 = 0.99
+		run_to_block(10);
+		let status = PegStatus {
+			price: Price::saturating_from_rational(99, 100),
+			deviation_bps: 100,
+			updated_at: 10,
+		};
+		assert_eq!(PegMonitor::get_peg_status(), Some(status));
+		assert_eq!(<PegMonitor as PegStatusProvider<u64>>::get_peg_status(), Some(status));
+		// within the threshold
+		assert!(peg_deviation_events().is_empty());
+
+		assert_ok!(PegMonitor::set_deviation_threshold(RuntimeOrigin::signed(ALICE), 99));
+		run_to_block(20);
+		assert_eq!(
+			peg_deviation_events(),
+			vec![Event::PegDeviation {
+				price: Price::saturating_from_rational(99, 100),
+				bps: 100,
+			}]
+		);
+	});
+}
+
+#[test]
+fn unavailable_sources_are_skipped() {
+	ExtBuilder::default().build().execute_with(|| {
+		// no source configured, nothing measured
+		run_to_block(10);
+		assert_eq!(PegMonitor::get_peg_status(), None);
+		assert!(System::events().is_empty());
+
+		assert_ok!(PegMonitor::set_peg_sources(
+			RuntimeOrigin::signed(ALICE),
+			vec![dex_pair(3), oracle_price(1)]
+		));
+
+		// none of the sources can be priced
+		run_to_block(20);
+		System::assert_last_event(RuntimeEvent::PegMonitor(Event::PegPriceUnavailable));
+		assert_eq!(PegMonitor::get_peg_status(), None);
+
+		// only the oracle price is available, KUSD is $1.03
+		set_oracle_price(KUSD, Price::saturating_from_rational(103, 100));
+		run_to_block(30);
+		assert_eq!(
+			PegMonitor::get_peg_status(),
+			Some(PegStatus {
+				price: Price::saturating_from_rational(103, 100),
+				deviation_bps: 300,
+				updated_at: 30,
+			})
+		);
+		System::assert_last_event(RuntimeEvent::PegMonitor(Event::PegDeviation {
+			price: Price::saturating_from_rational(103, 100),
+			bps: 300,
+		}));
+
+		// DEX implies $1.01 after DOT is priced: (1.01 * 3 + 1.03 * 1) / 4 = 1.015
+		set_oracle_price(DOT, Price::saturating_from_integer(10));
+		set_liquidity_pool(DOT, 1000, 101);
+		run_to_block(40);
+		assert_eq!(
+			PegMonitor::get_peg_status(),
+			Some(PegStatus {
+				price: Price::saturating_from_rational(1015, 1000),
+				deviation_bps: 150,
+				updated_at: 40,
+			})
+		);
+	});
+}
+
+#[test]
+fn peg_history_is_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_oracle_price(KUSD, Price::one());
+		assert_ok!(PegMonitor::set_peg_sources(
+			RuntimeOrigin::signed(ALICE),
+			vec![oracle_price(1)]
+		));
+
+		let status_at = |price: Price, updated_at: u64| PegStatus {
+			price,
+			deviation_bps: PegMonitor::deviation_bps(price),
+			updated_at,
+		};
+
+		run_to_block(10);
+		set_oracle_price(KUSD, Price::saturating_from_rational(99, 100));
+		run_to_block(20);
+		assert_eq!(
+			PegMonitor::peg_history().into_inner(),
+			vec![
+				status_at(Price::one(), 10),
+				status_at(Price::saturating_from_rational(99, 100), 20)
+			]
+		);
+
+		// the oldest status is dropped
+		set_oracle_price(KUSD, Price::saturating_from_rational(98, 100));
+		run_to_block(30);
+		assert_eq!(
+			PegMonitor::peg_history().into_inner(),
+			vec![
+				status_at(Price::saturating_from_rational(99, 100), 20),
+				status_at(Price::saturating_from_rational(98, 100), 30)
+			]
+		);
+		assert_eq!(
+			PegMonitor::get_peg_status(),
+			Some(status_at(Price::saturating_from_rational(98, 100), 30))
+		);
+	});
+}
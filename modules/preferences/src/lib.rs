@@ -0,0 +1,251 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! # Preferences Module
+//!
+//! ## Overview
+//!
+//! Aggregates the preferences an account sets up across the modules, such as the alternative
+//! fee swap path, the claim preferences of the incentives pools, the dust consolidation
+//! beneficiary and the session keys, into a portable profile.
+//!
+//! `export_preferences` collects the preferences of an account from the storages of the modules
+//! into a `PreferencesBlob`, exposed by the runtime API. `import_preferences` applies a blob to
+//! the origin by dispatching the setter call of each entry with the signed origin, so the
+//! validations and the deposits of the modules apply as if the account set them one by one. An
+//! entry failing is skipped, the result of each entry is reported by the events.
+//!
+//! The blob is versioned, the entries of the older versions stay decodable when new kinds of
+//! preferences are added.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::*,
+};
+use frame_system::pallet_prelude::*;
+use module_session_keys::CallPattern;
+use module_support::{PoolId, Ratio};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::traits::{Dispatchable, Saturating};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// A preference of an account, applied by the setter call of the module it belongs to.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PreferenceEntry<AccountId, BlockNumber> {
+	/// The alternative fee swap path of the transaction payment module.
+	AlternativeFeeSwapPath(Vec<CurrencyId>),
+	/// The currency preferred to receive the claimed rewards of the incentives pool in.
+	ClaimPreference {
+		pool_id: PoolId,
+		currency_id: CurrencyId,
+		max_slippage: Ratio,
+	},
+	/// The dust consolidation beneficiary of the currencies module.
+	DustConsolidation(AccountId),
+	/// A session key of the session keys module.
+	SessionKey {
+		key: AccountId,
+		allowed_calls: Vec<CallPattern>,
+		expiry: BlockNumber,
+		spend_cap: Balance,
+	},
+}
+
+/// The versioned preferences of an account. New versions are added as new variants, so the
+/// exported blobs of the older versions can still be imported.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PreferencesBlob<AccountId, BlockNumber> {
+	#[codec(index = 1)]
+	V1(Vec<PreferenceEntry<AccountId, BlockNumber>>),
+}
+
+impl<AccountId, BlockNumber> PreferencesBlob<AccountId, BlockNumber> {
+	/// The preference entries of the blob.
+	pub fn entries(&self) -> &[PreferenceEntry<AccountId, BlockNumber>] {
+		match self {
+			PreferencesBlob::V1(entries) => entries,
+		}
+	}
+
+	/// Convert the blob into the preference entries.
+	pub fn into_entries(self) -> Vec<PreferenceEntry<AccountId, BlockNumber>> {
+		match self {
+			PreferencesBlob::V1(entries) => entries,
+		}
+	}
+}
+
+pub type PreferencesBlobOf<T> = PreferencesBlob<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// Reads and applies the preferences kept by a module.
+pub trait PreferencesHandler<AccountId, BlockNumber, Call> {
+	/// Collect the preference entries of `who` into `entries`.
+	fn export(who: &AccountId, entries: &mut Vec<PreferenceEntry<AccountId, BlockNumber>>);
+
+	/// The setter call applying `entry` to the signed origin, `None` if the entry is not handled.
+	fn setter_call(entry: &PreferenceEntry<AccountId, BlockNumber>) -> Option<Call>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, BlockNumber, Call> PreferencesHandler<AccountId, BlockNumber, Call> for Tuple {
+	fn export(who: &AccountId, entries: &mut Vec<PreferenceEntry<AccountId, BlockNumber>>) {
+		for_tuples!( #( Tuple::export(who, entries); )* );
+	}
+
+	fn setter_call(entry: &PreferenceEntry<AccountId, BlockNumber>) -> Option<Call> {
+		for_tuples!( #(
+			if let Some(call) = Tuple::setter_call(entry) {
+				return Some(call);
+			}
+		)* );
+		None
+	}
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The runtime call dispatched to apply the preferences.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo;
+
+		/// Reads and applies the preferences of the modules.
+		type Preferences: PreferencesHandler<Self::AccountId, BlockNumberFor<Self>, <Self as Config>::RuntimeCall>;
+
+		/// The maximum number of the entries of an imported blob.
+		#[pallet::constant]
+		type MaxPreferenceEntries: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The blob has too many entries.
+		TooManyEntries,
+		/// The entry is not handled by any module of the runtime.
+		UnsupportedEntry,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The preference entry at `index` of the blob is applied.
+		PreferenceApplied { who: T::AccountId, index: u32 },
+		/// The preference entry at `index` of the blob failed and is skipped.
+		PreferenceFailed {
+			who: T::AccountId,
+			index: u32,
+			error: DispatchError,
+		},
+		/// The preferences are imported.
+		PreferencesImported {
+			who: T::AccountId,
+			applied: u32,
+			failed: u32,
+		},
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Apply the preferences of `blob` to the origin, the entries failing are skipped.
+		///
+		/// - `blob`: the preferences, normally exported from another account.
+		#[pallet::call_index(0)]
+		#[pallet::weight({
+			let entries = blob.entries();
+			entries.iter().filter_map(T::Preferences::setter_call).fold(
+				T::WeightInfo::import_preferences(entries.len() as u32),
+				|weight, call| weight.saturating_add(call.get_dispatch_info().weight),
+			)
+		})]
+		pub fn import_preferences(origin: OriginFor<T>, blob: PreferencesBlobOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let entries = blob.into_entries();
+			ensure!(
+				entries.len() <= T::MaxPreferenceEntries::get() as usize,
+				Error::<T>::TooManyEntries
+			);
+
+			let (mut applied, mut failed) = (0u32, 0u32);
+			for (index, entry) in entries.iter().enumerate() {
+				let index = index as u32;
+				// the dispatched call reverts its own changes when it fails
+				let result = match T::Preferences::setter_call(entry) {
+					Some(call) => call
+						.dispatch(frame_system::RawOrigin::Signed(who.clone()).into())
+						.map(|_| ())
+						.map_err(|e| e.error),
+					None => Err(Error::<T>::UnsupportedEntry.into()),
+				};
+
+				match result {
+					Ok(()) => {
+						applied.saturating_inc();
+						Self::deposit_event(Event::PreferenceApplied {
+							who: who.clone(),
+							index,
+						});
+					}
+					Err(error) => {
+						failed.saturating_inc();
+						Self::deposit_event(Event::PreferenceFailed {
+							who: who.clone(),
+							index,
+							error,
+						});
+					}
+				}
+			}
+
+			Self::deposit_event(Event::PreferencesImported { who, applied, failed });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The preferences of `who` collected from the modules.
+	pub fn export_preferences(who: &T::AccountId) -> PreferencesBlobOf<T> {
+		let mut entries = Vec::new();
+		T::Preferences::export(who, &mut entries);
+		PreferencesBlob::V1(entries)
+	}
+}
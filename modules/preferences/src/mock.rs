@@ -0,0 +1,178 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the preferences module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, derive_impl,
+	traits::{ConstU128, ConstU32, ConstU64},
+};
+use primitives::ReserveIdentifier;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const DAVE: AccountId = 4;
+pub const KEY_1: AccountId = 11;
+pub const KEY_2: AccountId = 12;
+
+pub const DEPOSIT: Balance = 100;
+
+mod preferences {
+	pub use super::super::*;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+impl module_session_keys::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type EvmTarget = ();
+	type SessionKeyDeposit = ConstU128<DEPOSIT>;
+	type MaxSessionDuration = ConstU64<100>;
+	type MaxCallPatterns = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+/// Handles the session keys, the other entries are not supported by the mock.
+pub struct MockSessionKeyPreferences;
+impl PreferencesHandler<AccountId, BlockNumber, RuntimeCall> for MockSessionKeyPreferences {
+	fn export(who: &AccountId, entries: &mut Vec<PreferenceEntry<AccountId, BlockNumber>>) {
+		for (key, info) in module_session_keys::SessionKeys::<Runtime>::iter() {
+			if info.primary == *who {
+				entries.push(PreferenceEntry::SessionKey {
+					key,
+					allowed_calls: info.allowed_calls,
+					expiry: info.expiry,
+					spend_cap: info.spend_cap,
+				});
+			}
+		}
+	}
+
+	fn setter_call(entry: &PreferenceEntry<AccountId, BlockNumber>) -> Option<RuntimeCall> {
+		match entry {
+			PreferenceEntry::SessionKey {
+				key,
+				allowed_calls,
+				expiry,
+				spend_cap,
+			} => Some(RuntimeCall::SessionKeys(
+				module_session_keys::Call::register_session_key {
+					key: *key,
+					allowed_calls: allowed_calls.clone(),
+					expiry: *expiry,
+					spend_cap: *spend_cap,
+				},
+			)),
+			_ => None,
+		}
+	}
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Preferences = (MockSessionKeyPreferences,);
+	type MaxPreferenceEntries = ConstU32<4>;
+	type WeightInfo = ();
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		SessionKeys: module_session_keys,
+		PreferencesModule: preferences,
+	}
+);
+
+pub fn session_key(key: AccountId, expiry: BlockNumber) -> PreferenceEntry<AccountId, BlockNumber> {
+	PreferenceEntry::SessionKey {
+		key,
+		allowed_calls: vec![CallPattern {
+			pallet_name: b"Balances".to_vec(),
+			function_name: b"transfer_allow_death".to_vec(),
+			evm_target: None,
+		}],
+		expiry,
+		spend_cap: 1_000,
+	}
+}
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(ALICE, 10_000), (BOB, 10_000), (CHARLIE, 10_000), (DAVE, 150)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,160 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the preferences module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn import_preferences_skips_failed_entries() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PreferencesModule::import_preferences(RuntimeOrigin::none(), PreferencesBlob::V1(vec![])),
+			BadOrigin
+		);
+		assert_noop!(
+			PreferencesModule::import_preferences(
+				RuntimeOrigin::signed(ALICE),
+				PreferencesBlob::V1(vec![session_key(KEY_1, 10); 5])
+			),
+			Error::<Runtime>::TooManyEntries
+		);
+
+		assert_ok!(PreferencesModule::import_preferences(
+			RuntimeOrigin::signed(ALICE),
+			PreferencesBlob::V1(vec![
+				session_key(KEY_1, 10),
+				// the primary account can't be its own session key
+				session_key(ALICE, 10),
+				PreferenceEntry::AlternativeFeeSwapPath(vec![]),
+				session_key(KEY_2, 20),
+			])
+		));
+
+		System::assert_has_event(RuntimeEvent::PreferencesModule(crate::Event::PreferenceApplied {
+			who: ALICE,
+			index: 0,
+		}));
+		System::assert_has_event(RuntimeEvent::PreferencesModule(crate::Event::PreferenceFailed {
+			who: ALICE,
+			index: 1,
+			error: module_session_keys::Error::<Runtime>::InvalidSessionKey.into(),
+		}));
+		System::assert_has_event(RuntimeEvent::PreferencesModule(crate::Event::PreferenceFailed {
+			who: ALICE,
+			index: 2,
+			error: Error::<Runtime>::UnsupportedEntry.into(),
+		}));
+		System::assert_has_event(RuntimeEvent::PreferencesModule(crate::Event::PreferenceApplied {
+			who: ALICE,
+			index: 3,
+		}));
+		System::assert_last_event(RuntimeEvent::PreferencesModule(crate::Event::PreferencesImported {
+			who: ALICE,
+			applied: 2,
+			failed: 2,
+		}));
+
+		assert_eq!(SessionKeys::session_keys(KEY_1).unwrap().primary, ALICE);
+		assert_eq!(SessionKeys::session_keys(KEY_2).unwrap().expiry, 20);
+		assert_eq!(SessionKeys::session_keys(ALICE), None);
+	});
+}
+
+#[test]
+fn import_preferences_charges_deposits() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PreferencesModule::import_preferences(
+			RuntimeOrigin::signed(ALICE),
+			PreferencesBlob::V1(vec![session_key(KEY_1, 10), session_key(KEY_2, 10)])
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 2 * DEPOSIT);
+		assert_eq!(Balances::free_balance(ALICE), 10_000 - 2 * DEPOSIT);
+	});
+
+	ExtBuilder::default().build().execute_with(|| {
+		// DAVE can only afford the deposit of one session key
+		assert_ok!(PreferencesModule::import_preferences(
+			RuntimeOrigin::signed(DAVE),
+			PreferencesBlob::V1(vec![session_key(KEY_1, 10), session_key(KEY_2, 10)])
+		));
+		System::assert_has_event(RuntimeEvent::PreferencesModule(crate::Event::PreferenceFailed {
+			who: DAVE,
+			index: 1,
+			error: pallet_balances::Error::<Runtime>::InsufficientBalance.into(),
+		}));
+		assert_eq!(Balances::reserved_balance(DAVE), DEPOSIT);
+		assert!(SessionKeys::session_keys(KEY_1).is_some());
+		assert_eq!(SessionKeys::session_keys(KEY_2), None);
+	});
+}
+
+#[test]
+fn export_and_import_preferences_round_trip() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PreferencesModule::export_preferences(&BOB), PreferencesBlob::V1(vec![]));
+
+		for (key, expiry) in [(KEY_1, 10), (KEY_2, 20)] {
+			let PreferenceEntry::SessionKey {
+				allowed_calls,
+				spend_cap,
+				..
+			} = session_key(key, expiry)
+			else {
+				unreachable!()
+			};
+			assert_ok!(SessionKeys::register_session_key(
+				RuntimeOrigin::signed(BOB),
+				key,
+				allowed_calls,
+				expiry,
+				spend_cap
+			));
+		}
+		let blob = PreferencesModule::export_preferences(&BOB);
+		assert_eq!(blob.entries().len(), 2);
+
+		// the blob is versioned
+		assert_eq!(blob.encode()[0], 1);
+		assert_eq!(
+			PreferencesBlobOf::<Runtime>::decode(&mut &blob.encode()[..]),
+			Ok(blob.clone())
+		);
+
+		// migrate the session keys to a fresh account
+		assert_ok!(SessionKeys::revoke_session_key(RuntimeOrigin::signed(BOB), KEY_1));
+		assert_ok!(SessionKeys::revoke_session_key(RuntimeOrigin::signed(BOB), KEY_2));
+		assert_eq!(
+			PreferencesModule::export_preferences(&CHARLIE),
+			PreferencesBlob::V1(vec![])
+		);
+		assert_ok!(PreferencesModule::import_preferences(
+			RuntimeOrigin::signed(CHARLIE),
+			blob.clone()
+		));
+
+		assert_eq!(PreferencesModule::export_preferences(&CHARLIE), blob);
+		assert_eq!(PreferencesModule::export_preferences(&BOB), PreferencesBlob::V1(vec![]));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 2 * DEPOSIT);
+	});
+}
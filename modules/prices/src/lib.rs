@@ -25,6 +25,8 @@
 //!   - specify a fixed price for stable currency
 //!   - feed price in USD or related price bewteen two currencies
 //!   - lock/unlock the price data get from oracle
+//!   - lock the price at a value agreed by governance during a market event, until it expires
+//!   - price the liquid staking currency at its fair value from Homa, or at its market price
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -36,10 +38,10 @@ use orml_traits::{DataFeeder, DataProvider, GetByKey, MultiCurrency};
 use primitives::{Balance, CurrencyId, Lease};
 use sp_core::U256;
 use sp_runtime::{
-	traits::{BlockNumberProvider, CheckedMul, One, Saturating, UniqueSaturatedInto},
-	FixedPointNumber,
+	traits::{BlockNumberProvider, CheckedMul, One, Saturating, UniqueSaturatedInto, Zero},
+	FixedPointNumber, PerThing, Permill,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 mod mock;
 mod tests;
@@ -48,6 +50,26 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The way to price a currency.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum PricingMode {
+	/// The price fed by the data source.
+	Market,
+	/// The fair value of the liquid staking currency: the price of the staking currency multiplied
+	/// by the exchange rate of Homa, discounted by the haircut.
+	LiquidStakingFairValue { haircut: Permill },
+}
+
+/// A price lock declared by governance, which reverts to the real-time price when it expires.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct TimedPriceLock<BlockNumber> {
+	/// The block number at which the lock expires.
+	pub expire_at: BlockNumber,
+	/// The reason code of the lock, such as the index of the referendum declaring the market
+	/// event.
+	pub reason: u32,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -106,6 +128,14 @@ pub mod module {
 		/// equal to the price of another.
 		type PricingPegged: GetByKey<CurrencyId, Option<CurrencyId>>;
 
+		/// The max deviation of the price locked by `lock_price_at` from the real-time price.
+		#[pallet::constant]
+		type MaxLockPriceDeviation: Get<Permill>;
+
+		/// The max duration of the price locked by `lock_price_at`.
+		#[pallet::constant]
+		type MaxPriceLockDuration: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -116,6 +146,14 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The pricing mode is not supported by the currency
+		InvalidPricingMode,
+		/// The price deviates too much from the real-time price
+		PriceDeviationTooLarge,
+		/// The duration of the price lock is zero or exceeds the max duration
+		InvalidLockDuration,
+		/// The price is locked without expiry, and must be unlocked first
+		PriceLockedIndefinitely,
 	}
 
 	#[pallet::event]
@@ -128,6 +166,20 @@ pub mod module {
 		},
 		/// Unlock price.
 		UnlockPrice { currency_id: CurrencyId },
+		/// Lock price at the value set by governance until it expires.
+		LockPriceAt {
+			currency_id: CurrencyId,
+			locked_price: Price,
+			expire_at: BlockNumberFor<T>,
+			reason: u32,
+		},
+		/// The price lock expired, the price reverts to the real-time price.
+		PriceLockExpired { currency_id: CurrencyId },
+		/// The pricing mode of the currency is updated.
+		PricingModeUpdated {
+			currency_id: CurrencyId,
+			pricing_mode: Option<PricingMode>,
+		},
 	}
 
 	/// Mapping from currency id to it's locked price
@@ -137,11 +189,51 @@ pub mod module {
 	#[pallet::getter(fn locked_price)]
 	pub type LockedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// The expiry and reason of the price locked by `lock_price_at`. The locks without expiry
+	/// are not recorded.
+	///
+	/// map CurrencyId => Option<TimedPriceLock>
+	#[pallet::storage]
+	#[pallet::getter(fn timed_price_locks)]
+	pub type TimedPriceLocks<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, TimedPriceLock<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Mapping from currency id to it's pricing mode. The liquid currency is priced at its fair
+	/// value without haircut if not set.
+	///
+	/// map CurrencyId => Option<PricingMode>
+	#[pallet::storage]
+	#[pallet::getter(fn pricing_modes)]
+	pub type PricingModes<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, PricingMode, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// the timed locks are only set by governance, so there are only a few of them.
+			let mut locks: u32 = 0;
+			let mut expired: Vec<CurrencyId> = Vec::new();
+			for (currency_id, lock) in TimedPriceLocks::<T>::iter() {
+				locks = locks.saturating_add(1);
+				if lock.expire_at <= now {
+					expired.push(currency_id);
+				}
+			}
+
+			for currency_id in expired {
+				TimedPriceLocks::<T>::remove(currency_id);
+				LockedPrice::<T>::remove(currency_id);
+				Self::deposit_event(Event::PriceLockExpired { currency_id });
+			}
+
+			T::WeightInfo::on_initialize(locks)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -170,6 +262,91 @@ pub mod module {
 			<Pallet<T> as LockablePrice<CurrencyId>>::unlock_price(currency_id)?;
 			Ok(())
 		}
+
+		/// Set the pricing mode of the currency, `None` to reset to the default.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `pricing_mode`: the pricing mode, `LiquidStakingFairValue` is only supported by the
+		///   liquid currency.
+		#[pallet::call_index(2)]
+		#[pallet::weight((T::WeightInfo::set_pricing_mode(), DispatchClass::Operational))]
+		pub fn set_pricing_mode(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			pricing_mode: Option<PricingMode>,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			if let Some(PricingMode::LiquidStakingFairValue { .. }) = pricing_mode {
+				ensure!(
+					currency_id == T::GetLiquidCurrencyId::get(),
+					Error::<T>::InvalidPricingMode
+				);
+			}
+
+			PricingModes::<T>::set(currency_id, pricing_mode);
+			Self::deposit_event(Event::PricingModeUpdated {
+				currency_id,
+				pricing_mode,
+			});
+			Ok(())
+		}
+
+		/// Lock the price at the value agreed by governance during a market event, it reverts to
+		/// the real-time price when the lock expires. The locks without expiry, such as the ones
+		/// of the emergency shutdown, can't be overridden.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `price`: the price for 1 basic unit, within `MaxLockPriceDeviation` of the real-time
+		///   price.
+		/// - `duration`: the number of blocks until the lock expires, at most
+		///   `MaxPriceLockDuration`.
+		/// - `reason`: the reason code recorded with the lock.
+		#[pallet::call_index(3)]
+		#[pallet::weight((T::WeightInfo::lock_price_at(), DispatchClass::Operational))]
+		pub fn lock_price_at(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			price: Price,
+			duration: BlockNumberFor<T>,
+			reason: u32,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxPriceLockDuration::get(),
+				Error::<T>::InvalidLockDuration
+			);
+			ensure!(
+				Self::locked_price(currency_id).is_none() || Self::timed_price_locks(currency_id).is_some(),
+				Error::<T>::PriceLockedIndefinitely
+			);
+
+			let real_time_price =
+				Self::access_price_with_locked_staking_price(currency_id).ok_or(Error::<T>::AccessPriceFailed)?;
+			let deviation = if price > real_time_price {
+				price.saturating_sub(real_time_price)
+			} else {
+				real_time_price.saturating_sub(price)
+			};
+			ensure!(
+				deviation.into_inner() <= T::MaxLockPriceDeviation::get().mul_floor(real_time_price.into_inner()),
+				Error::<T>::PriceDeviationTooLarge
+			);
+
+			let expire_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			LockedPrice::<T>::insert(currency_id, price);
+			TimedPriceLocks::<T>::insert(currency_id, TimedPriceLock { expire_at, reason });
+			Self::deposit_event(Event::LockPriceAt {
+				currency_id,
+				locked_price: price,
+				expire_at,
+				reason,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -189,10 +366,9 @@ impl<T: Config> Pallet<T> {
 		let maybe_price = if currency_id == T::GetStableCurrencyId::get() {
 			// if is stable currency, use fixed price
 			Some(T::StableCurrencyFixedPrice::get())
-		} else if currency_id == T::GetLiquidCurrencyId::get() {
+		} else if let Some(haircut) = Self::liquid_staking_haircut(currency_id) {
 			// directly return real-time the multiple of the price of StakingCurrencyId and the exchange rate
-			return Self::access_price(T::GetStakingCurrencyId::get())
-				.and_then(|n| n.checked_mul(&T::LiquidStakingExchangeRateProvider::get_exchange_rate()));
+			return Self::liquid_staking_fair_price(Self::access_price(T::GetStakingCurrencyId::get()), haircut);
 		} else if let CurrencyId::LiquidCrowdloan(lease) = currency_id {
 			// Note: For LiquidCrowdloan, The reliable market price may not be available in the initial stage,
 			// the system simply discounts the price of StakingCurrency according to the StakingRewardRate and
@@ -235,13 +411,53 @@ impl<T: Config> Pallet<T> {
 			None
 		}
 	}
+
+	/// The haircut of the fair value if the currency is priced as the liquid staking currency,
+	/// `None` if it's priced at the market price.
+	fn liquid_staking_haircut(currency_id: CurrencyId) -> Option<Permill> {
+		if currency_id != T::GetLiquidCurrencyId::get() {
+			return None;
+		}
+
+		match Self::pricing_modes(currency_id) {
+			Some(PricingMode::Market) => None,
+			Some(PricingMode::LiquidStakingFairValue { haircut }) => Some(haircut),
+			None => Some(Permill::zero()),
+		}
+	}
+
+	/// The fair value of the liquid staking currency by the price of the staking currency.
+	fn liquid_staking_fair_price(staking_price: Option<Price>, haircut: Permill) -> Option<Price> {
+		staking_price
+			.and_then(|n| n.checked_mul(&T::LiquidStakingExchangeRateProvider::get_exchange_rate()))
+			.map(|n| Price::from_inner(haircut.left_from_one().mul_floor(n.into_inner())))
+	}
+
+	/// Access the locked price, if it is none, access the real-time price.
+	fn access_priority_locked_price(currency_id: CurrencyId) -> Option<Price> {
+		Self::locked_price(currency_id).or_else(|| Self::access_price_with_locked_staking_price(currency_id))
+	}
+
+	/// Access the real-time price, but the fair value of the liquid staking currency is composed
+	/// by the locked price of the staking currency, so they are consistent during the shutdown.
+	fn access_price_with_locked_staking_price(currency_id: CurrencyId) -> Option<Price> {
+		match Self::liquid_staking_haircut(currency_id) {
+			Some(haircut) => Self::liquid_staking_fair_price(
+				Self::access_priority_locked_price(T::GetStakingCurrencyId::get()),
+				haircut,
+			),
+			None => Self::access_price(currency_id),
+		}
+	}
 }
 
 impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
-	/// Record the real-time price from oracle as the locked price
+	/// Record the real-time price from oracle as the locked price, it replaces the timed lock
+	/// and never expires.
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult {
-		let price = Self::access_price(currency_id).ok_or(Error::<T>::AccessPriceFailed)?;
+		let price = Self::access_price_with_locked_staking_price(currency_id).ok_or(Error::<T>::AccessPriceFailed)?;
 		LockedPrice::<T>::insert(currency_id, price);
+		TimedPriceLocks::<T>::remove(currency_id);
 		Pallet::<T>::deposit_event(Event::LockPrice {
 			currency_id,
 			locked_price: price,
@@ -252,6 +468,7 @@ impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 	/// Unlock the locked price
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult {
 		let _ = LockedPrice::<T>::take(currency_id).ok_or(Error::<T>::NoLockedPrice)?;
+		TimedPriceLocks::<T>::remove(currency_id);
 		Pallet::<T>::deposit_event(Event::UnlockPrice { currency_id });
 		Ok(())
 	}
@@ -270,7 +487,7 @@ impl<T: Config> PriceProvider<CurrencyId> for RealTimePriceProvider<T> {
 pub struct PriorityLockedPriceProvider<T>(PhantomData<T>);
 impl<T: Config> PriceProvider<CurrencyId> for PriorityLockedPriceProvider<T> {
 	fn get_price(currency_id: CurrencyId) -> Option<Price> {
-		Pallet::<T>::locked_price(currency_id).or_else(|| Pallet::<T>::access_price(currency_id))
+		Pallet::<T>::access_priority_locked_price(currency_id)
 	}
 }
 
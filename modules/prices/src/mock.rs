@@ -85,6 +85,7 @@ impl DataProvider<CurrencyId, Price> for MockDataProvider {
 				AUSD => Some(Price::saturating_from_rational(99, 100)),
 				TAI => Some(Price::saturating_from_integer(50000)),
 				DOT => Some(Price::saturating_from_integer(100)),
+				LDOT => Some(Price::saturating_from_integer(40)),
 				ACA => Some(Price::zero()),
 				KSM => None,
 				_ => None,
@@ -229,6 +230,8 @@ parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::one();
 	pub static MockRelayBlockNumberProvider: BlockNumber = 0;
 	pub RewardRatePerRelaychainBlock: Rate = Rate::saturating_from_rational(1, 1000);
+	pub const MaxLockPriceDeviation: Permill = Permill::from_percent(20);
+	pub const MaxPriceLockDuration: BlockNumber = 100;
 }
 
 impl Config for Runtime {
@@ -247,6 +250,8 @@ impl Config for Runtime {
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type RewardRatePerRelaychainBlock = RewardRatePerRelaychainBlock;
 	type PricingPegged = PricingPegged;
+	type MaxLockPriceDeviation = MaxLockPriceDeviation;
+	type MaxPriceLockDuration = MaxPriceLockDuration;
 	type WeightInfo = ();
 }
 
@@ -185,6 +185,52 @@ fn access_price_of_liquid_currency() {
 	});
 }
 
+#[test]
+fn access_price_of_liquid_currency_by_pricing_mode() {
+	ExtBuilder::default().build().execute_with(|| {
+		// fair value by default
+		assert_eq!(
+			PricesModule::access_price(LDOT),
+			Some(Price::saturating_from_integer(5000000000u128))
+		); // dot_price * 1/2
+
+		// the market price is 20% lower than the fair value
+		assert_ok!(PricesModule::set_pricing_mode(
+			RuntimeOrigin::signed(1),
+			LDOT,
+			Some(PricingMode::Market)
+		));
+		assert_eq!(
+			PricesModule::access_price(LDOT),
+			Some(Price::saturating_from_integer(4000000000u128))
+		); // 40 USD, right shift the decimal point (18-12) places
+
+		assert_ok!(PricesModule::set_pricing_mode(
+			RuntimeOrigin::signed(1),
+			LDOT,
+			Some(PricingMode::LiquidStakingFairValue {
+				haircut: Permill::from_percent(10)
+			})
+		));
+		assert_eq!(
+			PricesModule::access_price(LDOT),
+			Some(Price::saturating_from_integer(4500000000u128))
+		); // dot_price * 1/2 * 90%
+
+		mock_oracle_update();
+		assert_eq!(
+			PricesModule::access_price(LDOT),
+			Some(Price::saturating_from_integer(540000000u128))
+		); // dot_price * 3/5 * 90%
+
+		assert_ok!(PricesModule::set_pricing_mode(RuntimeOrigin::signed(1), LDOT, None));
+		assert_eq!(
+			PricesModule::access_price(LDOT),
+			Some(Price::saturating_from_integer(600000000u128))
+		); // dot_price * 3/5
+	});
+}
+
 #[test]
 fn access_price_of_dex_share_currency() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -356,6 +402,116 @@ fn unlock_price_work() {
 	});
 }
 
+#[test]
+fn set_pricing_mode_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			PricesModule::set_pricing_mode(RuntimeOrigin::signed(5), LDOT, Some(PricingMode::Market)),
+			BadOrigin
+		);
+
+		// only the liquid currency can be priced at the fair value
+		assert_noop!(
+			PricesModule::set_pricing_mode(
+				RuntimeOrigin::signed(1),
+				DOT,
+				Some(PricingMode::LiquidStakingFairValue {
+					haircut: Permill::zero()
+				})
+			),
+			Error::<Runtime>::InvalidPricingMode
+		);
+
+		assert_eq!(PricesModule::pricing_modes(LDOT), None);
+		assert_ok!(PricesModule::set_pricing_mode(
+			RuntimeOrigin::signed(1),
+			LDOT,
+			Some(PricingMode::LiquidStakingFairValue {
+				haircut: Permill::from_percent(5)
+			})
+		));
+		System::assert_last_event(RuntimeEvent::PricesModule(crate::Event::PricingModeUpdated {
+			currency_id: LDOT,
+			pricing_mode: Some(PricingMode::LiquidStakingFairValue {
+				haircut: Permill::from_percent(5),
+			}),
+		}));
+		assert_eq!(
+			PricesModule::pricing_modes(LDOT),
+			Some(PricingMode::LiquidStakingFairValue {
+				haircut: Permill::from_percent(5)
+			})
+		);
+
+		assert_ok!(PricesModule::set_pricing_mode(RuntimeOrigin::signed(1), LDOT, None));
+		System::assert_last_event(RuntimeEvent::PricesModule(crate::Event::PricingModeUpdated {
+			currency_id: LDOT,
+			pricing_mode: None,
+		}));
+		assert_eq!(PricesModule::pricing_modes(LDOT), None);
+	});
+}
+
+#[test]
+fn liquid_currency_price_composed_with_locked_staking_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PricesModule::set_pricing_mode(
+			RuntimeOrigin::signed(1),
+			LDOT,
+			Some(PricingMode::LiquidStakingFairValue {
+				haircut: Permill::from_percent(10)
+			})
+		));
+		assert_ok!(PricesModule::lock_price(RuntimeOrigin::signed(1), DOT));
+		assert_eq!(
+			PricesModule::locked_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+
+		mock_oracle_update();
+
+		// the real-time price is composed with the real-time price of DOT
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(LDOT),
+			Some(Price::saturating_from_integer(540000000u128))
+		); // 10 USD * 3/5 * 90%
+
+		// the priority locked price is composed with the locked price of DOT
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(LDOT),
+			Some(Price::saturating_from_integer(5400000000u128))
+		); // 100 USD * 3/5 * 90%
+
+		// and locked consistently
+		assert_ok!(PricesModule::lock_price(RuntimeOrigin::signed(1), LDOT));
+		assert_eq!(
+			PricesModule::locked_price(LDOT),
+			Some(Price::saturating_from_integer(5400000000u128))
+		);
+		assert_ok!(PricesModule::unlock_price(RuntimeOrigin::signed(1), DOT));
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(LDOT),
+			Some(Price::saturating_from_integer(5400000000u128))
+		);
+		assert_ok!(PricesModule::unlock_price(RuntimeOrigin::signed(1), LDOT));
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(LDOT),
+			Some(Price::saturating_from_integer(540000000u128))
+		);
+
+		// the market price is not composed
+		assert_ok!(PricesModule::lock_price(RuntimeOrigin::signed(1), DOT));
+		assert_ok!(PricesModule::set_pricing_mode(
+			RuntimeOrigin::signed(1),
+			LDOT,
+			Some(PricingMode::Market)
+		));
+		assert_eq!(PriorityLockedPriceProvider::<Runtime>::get_price(LDOT), None);
+	});
+}
+
 #[test]
 fn price_providers_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -557,3 +713,140 @@ fn price_providers_work() {
 		assert_eq!(LockedPriceProvider::<Runtime>::get_relative_price(TAI, KSM), None);
 	});
 }
+
+#[test]
+fn lock_price_at_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let price = Price::saturating_from_integer(45000000000u128);
+		assert_noop!(
+			PricesModule::lock_price_at(RuntimeOrigin::signed(5), TAI, price, 10, 1),
+			BadOrigin
+		);
+		assert_noop!(
+			PricesModule::lock_price_at(RuntimeOrigin::signed(1), TAI, price, 0, 1),
+			Error::<Runtime>::InvalidLockDuration
+		);
+		assert_noop!(
+			PricesModule::lock_price_at(RuntimeOrigin::signed(1), TAI, price, 101, 1),
+			Error::<Runtime>::InvalidLockDuration
+		);
+
+		// cannot lock the price of KSM when the price from oracle is None
+		assert_noop!(
+			PricesModule::lock_price_at(RuntimeOrigin::signed(1), KSM, price, 10, 1),
+			Error::<Runtime>::AccessPriceFailed
+		);
+
+		// the price must be within 20% of the real-time price
+		assert_noop!(
+			PricesModule::lock_price_at(
+				RuntimeOrigin::signed(1),
+				TAI,
+				Price::saturating_from_integer(39999999999u128),
+				10,
+				1
+			),
+			Error::<Runtime>::PriceDeviationTooLarge
+		);
+		assert_noop!(
+			PricesModule::lock_price_at(
+				RuntimeOrigin::signed(1),
+				TAI,
+				Price::saturating_from_integer(60000000001u128),
+				10,
+				1
+			),
+			Error::<Runtime>::PriceDeviationTooLarge
+		);
+		assert_ok!(PricesModule::lock_price_at(
+			RuntimeOrigin::signed(1),
+			TAI,
+			Price::saturating_from_integer(60000000000u128),
+			10,
+			1
+		));
+
+		assert_ok!(PricesModule::lock_price_at(RuntimeOrigin::signed(1), TAI, price, 10, 7));
+		System::assert_last_event(RuntimeEvent::PricesModule(crate::Event::LockPriceAt {
+			currency_id: TAI,
+			locked_price: price,
+			expire_at: 11,
+			reason: 7,
+		}));
+		assert_eq!(PricesModule::locked_price(TAI), Some(price));
+		assert_eq!(
+			PricesModule::timed_price_locks(TAI),
+			Some(TimedPriceLock {
+				expire_at: 11,
+				reason: 7
+			})
+		);
+		assert_eq!(PriorityLockedPriceProvider::<Runtime>::get_price(TAI), Some(price));
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(TAI),
+			Some(Price::saturating_from_integer(50000000000u128))
+		);
+
+		// unlock clears the timed lock
+		assert_ok!(PricesModule::unlock_price(RuntimeOrigin::signed(1), TAI));
+		assert_eq!(PricesModule::locked_price(TAI), None);
+		assert_eq!(PricesModule::timed_price_locks(TAI), None);
+	});
+}
+
+#[test]
+fn timed_price_lock_expires() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let price = Price::saturating_from_integer(45000000000u128);
+		assert_ok!(PricesModule::lock_price_at(RuntimeOrigin::signed(1), TAI, price, 10, 1));
+
+		PricesModule::on_initialize(10);
+		assert_eq!(PricesModule::locked_price(TAI), Some(price));
+		assert!(PricesModule::timed_price_locks(TAI).is_some());
+
+		PricesModule::on_initialize(11);
+		System::assert_last_event(RuntimeEvent::PricesModule(crate::Event::PriceLockExpired {
+			currency_id: TAI,
+		}));
+		assert_eq!(PricesModule::locked_price(TAI), None);
+		assert_eq!(PricesModule::timed_price_locks(TAI), None);
+		assert_eq!(
+			PriorityLockedPriceProvider::<Runtime>::get_price(TAI),
+			Some(Price::saturating_from_integer(50000000000u128))
+		);
+	});
+}
+
+#[test]
+fn lock_without_expiry_takes_precedence() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let price = Price::saturating_from_integer(45000000000u128);
+		assert_ok!(PricesModule::lock_price_at(RuntimeOrigin::signed(1), TAI, price, 10, 1));
+
+		// the emergency shutdown locks the real-time price, which never expires
+		assert_ok!(<PricesModule as LockablePrice<CurrencyId>>::lock_price(TAI));
+		assert_eq!(
+			PricesModule::locked_price(TAI),
+			Some(Price::saturating_from_integer(50000000000u128))
+		);
+		assert_eq!(PricesModule::timed_price_locks(TAI), None);
+
+		PricesModule::on_initialize(11);
+		assert_eq!(
+			PricesModule::locked_price(TAI),
+			Some(Price::saturating_from_integer(50000000000u128))
+		);
+
+		// governance can't override it with a timed lock
+		assert_noop!(
+			PricesModule::lock_price_at(RuntimeOrigin::signed(1), TAI, price, 10, 1),
+			Error::<Runtime>::PriceLockedIndefinitely
+		);
+	});
+}
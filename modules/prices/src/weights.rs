@@ -50,6 +50,9 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn lock_price() -> Weight;
 	fn unlock_price() -> Weight;
+	fn set_pricing_mode() -> Weight;
+	fn lock_price_at() -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
 }
 
 /// Weights for module_prices using the Acala node and recommended hardware.
@@ -58,12 +61,29 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn lock_price() -> Weight {
 		Weight::from_parts(53_000_000, 0)
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	fn unlock_price() -> Weight {
-		Weight::from_parts(12_000_000, 0)
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn set_pricing_mode() -> Weight {
+		Weight::from_parts(11_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn lock_price_at() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -71,10 +91,27 @@ impl WeightInfo for () {
 	fn lock_price() -> Weight {
 		Weight::from_parts(53_000_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(11 as u64))
-			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
 	fn unlock_price() -> Weight {
-		Weight::from_parts(12_000_000, 0)
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn set_pricing_mode() -> Weight {
+		Weight::from_parts(11_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn lock_price_at() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
 }
@@ -0,0 +1,359 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Public Referenda Module
+//!
+//! ## Overview
+//!
+//! Allows token holders to put a whitelisted call directly to a referendum, without going through
+//! the council external-propose step. The whitelist is a set of `(pallet_name, function_name)`
+//! patterns managed by `WhitelistOrigin`.
+//!
+//! The referendum is started in `pallet_democracy`, so voting, tallying and enactment are done by
+//! the existing democracy machinery. Public referenda use their own parameters: a higher deposit,
+//! a longer voting period and a super-majority approve threshold. Because the democracy public
+//! proposal queue can not carry per-proposal parameters, the referendum is started immediately
+//! and its voting period is extended to `PublicVotingPeriod`.
+//!
+//! The deposit is returned when the referendum ends with a turnout of at least `MinimumTurnout`
+//! of the total issuance, otherwise the proposal is treated as spam and the deposit is slashed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{CallMetadata, Currency, GetCallMetadata, NamedReservableCurrency, OnUnbalanced, StorePreimage},
+};
+use frame_system::pallet_prelude::*;
+use pallet_democracy::{BalanceOf, ReferendumIndex, ReferendumInfo, ReferendumInfoOf, VoteThreshold};
+use primitives::ReserveIdentifier;
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	Perbill,
+};
+use sp_std::{boxed::Box, prelude::*, vec::Vec};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+/// The deposit and the voting end of a public referendum.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct PublicReferendumInfo<AccountId, Balance, BlockNumber> {
+	/// The account which submitted the referendum.
+	pub proposer: AccountId,
+	/// The deposit reserved from the proposer.
+	pub deposit: Balance,
+	/// The block at which the voting ends.
+	pub end: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::PublicReferenda;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_democracy::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type, the proposals are matched against the whitelist by their
+		/// call metadata.
+		type RuntimeCall: Parameter + GetCallMetadata + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The currency used for the deposit of public referenda.
+		type Currency: NamedReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = ReserveIdentifier,
+			Balance = BalanceOf<Self>,
+		>;
+
+		/// The deposit required to submit a public referendum.
+		#[pallet::constant]
+		type SubmissionDeposit: Get<BalanceOf<Self>>;
+
+		/// The voting period of public referenda.
+		#[pallet::constant]
+		type PublicVotingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The period between an approved public referendum and its enactment.
+		#[pallet::constant]
+		type PublicEnactmentPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The minimum turnout, as a proportion of the total issuance, for the deposit to be
+		/// returned.
+		#[pallet::constant]
+		type MinimumTurnout: Get<Perbill>;
+
+		/// The maximum number of public referenda ending at the same block.
+		#[pallet::constant]
+		type MaxEndingPerBlock: Get<u32>;
+
+		/// The origin which may update the whitelist.
+		type WhitelistOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Handler for the slashed deposits.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The call is not whitelisted for public referenda.
+		CallNotWhitelisted,
+		/// Too many public referenda end at the same block.
+		TooManyReferendaEnding,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The call pattern is whitelisted for public referenda.
+		CallWhitelisted {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Vec<u8>,
+		},
+		/// The call pattern is removed from the whitelist.
+		WhitelistedCallRemoved {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Vec<u8>,
+		},
+		/// A public referendum is submitted.
+		Submitted {
+			ref_index: ReferendumIndex,
+			proposer: T::AccountId,
+			deposit: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		},
+		/// The deposit of a public referendum is returned.
+		DepositReturned {
+			ref_index: ReferendumIndex,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// The deposit of a public referendum is slashed for failing the quorum.
+		DepositSlashed {
+			ref_index: ReferendumIndex,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+	}
+
+	/// The call patterns which can be proposed by public referenda.
+	///
+	/// WhitelistedCalls: map (PalletNameBytes, FunctionNameBytes) => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn whitelisted_calls)]
+	pub type WhitelistedCalls<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
+
+	/// The ongoing public referenda.
+	///
+	/// PublicReferenda: map ReferendumIndex => Option<PublicReferendumInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn public_referenda)]
+	pub type PublicReferenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ReferendumIndex,
+		PublicReferendumInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The public referenda ending at the block.
+	///
+	/// ReferendaEndingAt: map BlockNumber => BoundedVec<ReferendumIndex, MaxEndingPerBlock>
+	#[pallet::storage]
+	#[pallet::getter(fn referenda_ending_at)]
+	pub type ReferendaEndingAt<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, BoundedVec<ReferendumIndex, T::MaxEndingPerBlock>, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let count = Self::referenda_ending_at(now.saturating_add(One::one())).len() as u32;
+			if count.is_zero() {
+				<T as frame_system::Config>::DbWeight::get().reads(1)
+			} else {
+				<T as Config>::WeightInfo::on_finalize(count)
+			}
+		}
+
+		fn on_finalize(now: BlockNumberFor<T>) {
+			// democracy closes the referenda ending at the next block in its `on_initialize` and drops
+			// the tally, so they are settled here after the last votes have been counted.
+			for ref_index in ReferendaEndingAt::<T>::take(now.saturating_add(One::one())) {
+				Self::settle(ref_index);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Whitelist a call pattern for public referenda.
+		///
+		/// The dispatch origin of this call must be `WhitelistOrigin`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::whitelist_call())]
+		pub fn whitelist_call(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+
+			WhitelistedCalls::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_whitelisted| {
+				if maybe_whitelisted.is_none() {
+					*maybe_whitelisted = Some(());
+					Self::deposit_event(Event::CallWhitelisted {
+						pallet_name_bytes: pallet_name,
+						function_name_bytes: function_name,
+					});
+				}
+			});
+			Ok(())
+		}
+
+		/// Remove a call pattern from the whitelist. Ongoing public referenda are not affected.
+		///
+		/// The dispatch origin of this call must be `WhitelistOrigin`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_whitelisted_call())]
+		pub fn remove_whitelisted_call(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+
+			if WhitelistedCalls::<T>::take((&pallet_name, &function_name)).is_some() {
+				Self::deposit_event(Event::WhitelistedCallRemoved {
+					pallet_name_bytes: pallet_name,
+					function_name_bytes: function_name,
+				});
+			}
+			Ok(())
+		}
+
+		/// Submit a whitelisted call as a public referendum, reserving `SubmissionDeposit`.
+		///
+		/// - `proposal`: the call to be enacted if the referendum passes.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit())]
+		pub fn submit(origin: OriginFor<T>, proposal: Box<<T as Config>::RuntimeCall>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let CallMetadata {
+				function_name,
+				pallet_name,
+			} = proposal.get_call_metadata();
+			ensure!(
+				WhitelistedCalls::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes())),
+				Error::<T>::CallNotWhitelisted
+			);
+
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(T::PublicVotingPeriod::get());
+			ensure!(
+				(Self::referenda_ending_at(end).len() as u32) < T::MaxEndingPerBlock::get(),
+				Error::<T>::TooManyReferendaEnding
+			);
+
+			let deposit = T::SubmissionDeposit::get();
+			<T as Config>::Currency::reserve_named(&RESERVE_ID, &who, deposit)?;
+
+			let call: <T as frame_system::Config>::RuntimeCall = (*proposal).into();
+			let bounded_call = <T as pallet_democracy::Config>::Preimages::bound(call)?;
+			let ref_index = pallet_democracy::Pallet::<T>::internal_start_referendum(
+				bounded_call,
+				VoteThreshold::SuperMajorityApprove,
+				T::PublicEnactmentPeriod::get(),
+			);
+			ReferendumInfoOf::<T>::mutate(ref_index, |maybe_info| {
+				if let Some(ReferendumInfo::Ongoing(status)) = maybe_info {
+					status.end = end;
+				}
+			});
+
+			ReferendaEndingAt::<T>::try_append(end, ref_index).map_err(|_| Error::<T>::TooManyReferendaEnding)?;
+			PublicReferenda::<T>::insert(
+				ref_index,
+				PublicReferendumInfo {
+					proposer: who.clone(),
+					deposit,
+					end,
+				},
+			);
+
+			Self::deposit_event(Event::Submitted {
+				ref_index,
+				proposer: who,
+				deposit,
+				end,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return or slash the deposit of a public referendum at the end of its voting period.
+	fn settle(ref_index: ReferendumIndex) {
+		let Some(info) = PublicReferenda::<T>::take(ref_index) else {
+			return;
+		};
+
+		let quorum_reached = match ReferendumInfoOf::<T>::get(ref_index) {
+			Some(ReferendumInfo::Ongoing(status)) => {
+				let total_issuance = <T as Config>::Currency::total_issuance();
+				status.tally.turnout >= T::MinimumTurnout::get().mul_floor(total_issuance)
+			}
+			// cancelled by the governance, it's not the spam of the proposer.
+			_ => true,
+		};
+
+		if quorum_reached {
+			let remaining = <T as Config>::Currency::unreserve_named(&RESERVE_ID, &info.proposer, info.deposit);
+			Self::deposit_event(Event::DepositReturned {
+				ref_index,
+				who: info.proposer,
+				amount: info.deposit.saturating_sub(remaining),
+			});
+		} else {
+			let (imbalance, remaining) =
+				<T as Config>::Currency::slash_reserved_named(&RESERVE_ID, &info.proposer, info.deposit);
+			<T as Config>::Slash::on_unbalanced(imbalance);
+			Self::deposit_event(Event::DepositSlashed {
+				ref_index,
+				who: info.proposer,
+				amount: info.deposit.saturating_sub(remaining),
+			});
+		}
+	}
+}
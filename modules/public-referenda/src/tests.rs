@@ -0,0 +1,279 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the public referenda module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, ReservableCurrency},
+};
+use mock::{RuntimeCall, RuntimeEvent, *};
+use pallet_democracy::{AccountVote, Conviction, Vote};
+use primitives::Balance;
+use sp_runtime::traits::BadOrigin;
+
+fn set_value(value: u32) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Params(params::Call::set_value { value }))
+}
+
+fn whitelist_set_value() {
+	assert_ok!(PublicReferenda::whitelist_call(
+		RuntimeOrigin::signed(TECHNICAL_COMMITTEE),
+		b"Params".to_vec(),
+		b"set_value".to_vec()
+	));
+}
+
+fn aye(balance: Balance) -> AccountVote<Balance> {
+	AccountVote::Standard {
+		vote: Vote {
+			aye: true,
+			conviction: Conviction::Locked1x,
+		},
+		balance,
+	}
+}
+
+fn nay(balance: Balance) -> AccountVote<Balance> {
+	AccountVote::Standard {
+		vote: Vote {
+			aye: false,
+			conviction: Conviction::Locked1x,
+		},
+		balance,
+	}
+}
+
+fn run_to_block(n: BlockNumber) {
+	while System::block_number() < n {
+		PublicReferenda::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		Scheduler::on_initialize(System::block_number());
+		Democracy::on_initialize(System::block_number());
+		PublicReferenda::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn whitelist_call_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PublicReferenda::whitelist_call(RuntimeOrigin::signed(ALICE), b"Params".to_vec(), b"set_value".to_vec()),
+			BadOrigin
+		);
+
+		whitelist_set_value();
+		System::assert_last_event(RuntimeEvent::PublicReferenda(crate::Event::CallWhitelisted {
+			pallet_name_bytes: b"Params".to_vec(),
+			function_name_bytes: b"set_value".to_vec(),
+		}));
+		assert_eq!(
+			PublicReferenda::whitelisted_calls((b"Params".to_vec(), b"set_value".to_vec())),
+			Some(())
+		);
+
+		assert_noop!(
+			PublicReferenda::remove_whitelisted_call(
+				RuntimeOrigin::signed(ALICE),
+				b"Params".to_vec(),
+				b"set_value".to_vec()
+			),
+			BadOrigin
+		);
+		assert_ok!(PublicReferenda::remove_whitelisted_call(
+			RuntimeOrigin::signed(TECHNICAL_COMMITTEE),
+			b"Params".to_vec(),
+			b"set_value".to_vec()
+		));
+		System::assert_last_event(RuntimeEvent::PublicReferenda(crate::Event::WhitelistedCallRemoved {
+			pallet_name_bytes: b"Params".to_vec(),
+			function_name_bytes: b"set_value".to_vec(),
+		}));
+		assert_eq!(
+			PublicReferenda::whitelisted_calls((b"Params".to_vec(), b"set_value".to_vec())),
+			None
+		);
+	});
+}
+
+#[test]
+fn submit_rejects_call_not_whitelisted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PublicReferenda::submit(RuntimeOrigin::signed(ALICE), set_value(42)),
+			Error::<Runtime>::CallNotWhitelisted
+		);
+
+		whitelist_set_value();
+
+		// only the whitelisted function of the pallet can be proposed
+		assert_noop!(
+			PublicReferenda::submit(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(RuntimeCall::Params(params::Call::kill_value {}))
+			),
+			Error::<Runtime>::CallNotWhitelisted
+		);
+		assert_noop!(
+			PublicReferenda::submit(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+			),
+			Error::<Runtime>::CallNotWhitelisted
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(pallet_democracy::ReferendumCount::<Runtime>::get(), 0);
+	});
+}
+
+#[test]
+fn submit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		whitelist_set_value();
+
+		assert_ok!(PublicReferenda::submit(RuntimeOrigin::signed(ALICE), set_value(42)));
+		System::assert_last_event(RuntimeEvent::PublicReferenda(crate::Event::Submitted {
+			ref_index: 0,
+			proposer: ALICE,
+			deposit: SUBMISSION_DEPOSIT,
+			end: 1 + PUBLIC_VOTING_PERIOD,
+		}));
+		assert_eq!(Balances::reserved_balance(ALICE), SUBMISSION_DEPOSIT);
+		assert_eq!(
+			PublicReferenda::public_referenda(0),
+			Some(PublicReferendumInfo {
+				proposer: ALICE,
+				deposit: SUBMISSION_DEPOSIT,
+				end: 1 + PUBLIC_VOTING_PERIOD,
+			})
+		);
+		assert_eq!(
+			PublicReferenda::referenda_ending_at(1 + PUBLIC_VOTING_PERIOD).to_vec(),
+			vec![0]
+		);
+
+		// started in democracy with the parameters of public referenda
+		match pallet_democracy::ReferendumInfoOf::<Runtime>::get(0) {
+			Some(ReferendumInfo::Ongoing(status)) => {
+				assert_eq!(status.end, 1 + PUBLIC_VOTING_PERIOD);
+				assert_eq!(status.threshold, VoteThreshold::SuperMajorityApprove);
+				assert_eq!(status.delay, PUBLIC_ENACTMENT_PERIOD);
+			}
+			_ => panic!("referendum should be ongoing"),
+		}
+
+		// limited referenda ending at the same block
+		assert_ok!(PublicReferenda::submit(RuntimeOrigin::signed(BOB), set_value(43)));
+		assert_noop!(
+			PublicReferenda::submit(RuntimeOrigin::signed(CAROL), set_value(44)),
+			Error::<Runtime>::TooManyReferendaEnding
+		);
+	});
+}
+
+#[test]
+fn public_referendum_enacts_whitelisted_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		whitelist_set_value();
+		assert_ok!(PublicReferenda::submit(RuntimeOrigin::signed(ALICE), set_value(42)));
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(BOB), 0, aye(500)));
+
+		// still voting after the voting period of democracy
+		run_to_block(PUBLIC_VOTING_PERIOD);
+		assert!(matches!(
+			pallet_democracy::ReferendumInfoOf::<Runtime>::get(0),
+			Some(ReferendumInfo::Ongoing(_))
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), SUBMISSION_DEPOSIT);
+
+		// turnout 500 reaches the quorum of 10% * 3000
+		run_to_block(1 + PUBLIC_VOTING_PERIOD);
+		System::assert_has_event(RuntimeEvent::PublicReferenda(crate::Event::DepositReturned {
+			ref_index: 0,
+			who: ALICE,
+			amount: SUBMISSION_DEPOSIT,
+		}));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+		assert_eq!(PublicReferenda::public_referenda(0), None);
+		assert_eq!(
+			pallet_democracy::ReferendumInfoOf::<Runtime>::get(0),
+			Some(ReferendumInfo::Finished {
+				approved: true,
+				end: 1 + PUBLIC_VOTING_PERIOD,
+			})
+		);
+
+		// enacted after the enactment period
+		assert_eq!(Params::value(), 0);
+		run_to_block(1 + PUBLIC_VOTING_PERIOD + PUBLIC_ENACTMENT_PERIOD);
+		assert_eq!(Params::value(), 42);
+	});
+}
+
+#[test]
+fn deposit_slashed_on_failed_quorum() {
+	ExtBuilder::default().build().execute_with(|| {
+		whitelist_set_value();
+		assert_ok!(PublicReferenda::submit(RuntimeOrigin::signed(ALICE), set_value(42)));
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(CAROL), 0, nay(100)));
+		let total_issuance = Balances::total_issuance();
+
+		// turnout 100 fails the quorum of 10% * 3000
+		run_to_block(1 + PUBLIC_VOTING_PERIOD);
+		System::assert_has_event(RuntimeEvent::PublicReferenda(crate::Event::DepositSlashed {
+			ref_index: 0,
+			who: ALICE,
+			amount: SUBMISSION_DEPOSIT,
+		}));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1_000 - SUBMISSION_DEPOSIT);
+		assert_eq!(Balances::total_issuance(), total_issuance - SUBMISSION_DEPOSIT);
+		assert_eq!(
+			pallet_democracy::ReferendumInfoOf::<Runtime>::get(0),
+			Some(ReferendumInfo::Finished {
+				approved: false,
+				end: 1 + PUBLIC_VOTING_PERIOD,
+			})
+		);
+
+		run_to_block(1 + PUBLIC_VOTING_PERIOD + PUBLIC_ENACTMENT_PERIOD);
+		assert_eq!(Params::value(), 0);
+	});
+}
+
+#[test]
+fn deposit_returned_when_referendum_cancelled() {
+	ExtBuilder::default().build().execute_with(|| {
+		whitelist_set_value();
+		assert_ok!(PublicReferenda::submit(RuntimeOrigin::signed(ALICE), set_value(42)));
+		assert_ok!(Democracy::cancel_referendum(RuntimeOrigin::root(), 0));
+
+		run_to_block(1 + PUBLIC_VOTING_PERIOD);
+		System::assert_has_event(RuntimeEvent::PublicReferenda(crate::Event::DepositReturned {
+			ref_index: 0,
+			who: ALICE,
+			amount: SUBMISSION_DEPOSIT,
+		}));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Params::value(), 0);
+	});
+}
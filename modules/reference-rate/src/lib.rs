@@ -0,0 +1,326 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Reference Rate Module
+//!
+//! ## Overview
+//!
+//! Computes an on-chain reference rate for the monetary policy of the stable currency. Every
+//! `AccumulatePeriod` blocks, the USD prices of the configured components, such as bridged
+//! stablecoins, are sampled from the oracle, or from the DEX average prices for the components
+//! without oracle feeds, and the index `Σ w_i × price_i / Σ w_i` is recorded in a short history.
+//!
+//! The components whose price is unavailable are skipped and the weights of the others are
+//! renormalized, the rate is then marked as degraded. `ReferenceRateUpdated` is emitted when the
+//! rate moves beyond the threshold since the last emitted one, or when the degraded mode
+//! changes. The latest rate is exposed to other modules by `ReferenceRateProvider` and to
+//! clients by runtime API.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use module_support::{DEXPriceProvider, Erc20InfoMapping, Price, PriceProvider, ReferenceRate, ReferenceRateProvider};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{SaturatedConversion, Zero},
+	FixedPointNumber,
+};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The default movement threshold, in basis points.
+pub const DEFAULT_MOVEMENT_THRESHOLD: u32 = 10;
+
+/// A source of the USD price of a component.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ComponentSource {
+	/// The oracle price of `CurrencyId`.
+	OraclePrice(CurrencyId),
+	/// The DEX average price of the first `CurrencyId` quoted in the second one, valued by the
+	/// oracle price of the second one. For the components without oracle feeds.
+	DexAveragePrice(CurrencyId, CurrencyId),
+}
+
+/// A component of the reference rate and its weight in the index.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct WeightedComponent {
+	pub source: ComponentSource,
+	pub weight: u32,
+}
+
+pub type ReferenceRateOf<T> = ReferenceRate<BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The period to compute the reference rate.
+		#[pallet::constant]
+		type AccumulatePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The oracle price source, which provides the price of one basic unit.
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// The DEX average price source for the `DexAveragePrice` components.
+		type DEXPriceSource: DEXPriceProvider<CurrencyId>;
+
+		/// Mapping to get the decimals of currencies.
+		type Erc20InfoMapping: Erc20InfoMapping;
+
+		/// The origin which may update the components and the threshold.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of components.
+		#[pallet::constant]
+		type MaxComponents: Get<u32>;
+
+		/// The maximum length of the reference rate history.
+		#[pallet::constant]
+		type MaxHistory: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Too many components.
+		TooManyComponents,
+		/// The weight of a component is zero.
+		ZeroWeight,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The components are updated.
+		ComponentsUpdated { components: Vec<WeightedComponent> },
+		/// The movement threshold is updated.
+		MovementThresholdUpdated { threshold_bps: u32 },
+		/// The reference rate moved beyond the threshold, or its degraded mode changed.
+		ReferenceRateUpdated { rate: Price, degraded: bool },
+		/// None of the components could be priced.
+		ReferenceRateUnavailable,
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultMovementThreshold() -> u32 {
+		DEFAULT_MOVEMENT_THRESHOLD
+	}
+
+	/// The weighted components.
+	///
+	/// Components: Vec<WeightedComponent>
+	#[pallet::storage]
+	#[pallet::getter(fn components)]
+	pub type Components<T: Config> = StorageValue<_, BoundedVec<WeightedComponent, T::MaxComponents>, ValueQuery>;
+
+	/// The movement since the last `ReferenceRateUpdated` beyond which it's emitted again, in
+	/// basis points.
+	///
+	/// MovementThreshold: u32
+	#[pallet::storage]
+	#[pallet::getter(fn movement_threshold)]
+	pub type MovementThreshold<T: Config> = StorageValue<_, u32, ValueQuery, DefaultMovementThreshold>;
+
+	/// The recent reference rates, from the oldest to the latest.
+	///
+	/// ReferenceRateHistory: Vec<ReferenceRate>
+	#[pallet::storage]
+	#[pallet::getter(fn reference_rate_history)]
+	pub type ReferenceRateHistory<T: Config> =
+		StorageValue<_, BoundedVec<ReferenceRateOf<T>, T::MaxHistory>, ValueQuery>;
+
+	/// The reference rate of the last `ReferenceRateUpdated`.
+	///
+	/// LastUpdatedRate: ReferenceRate
+	#[pallet::storage]
+	#[pallet::getter(fn last_updated_rate)]
+	pub type LastUpdatedRate<T: Config> = StorageValue<_, ReferenceRateOf<T>, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			if now % T::AccumulatePeriod::get() == Zero::zero() {
+				let count = Self::update_reference_rate(now);
+				T::WeightInfo::on_initialize(count)
+			} else {
+				Weight::zero()
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the weighted components. Takes effect from the next computation, the history is
+		/// kept.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `components`: the components and their weights in the index.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_components(components.len() as u32))]
+		pub fn set_components(origin: OriginFor<T>, components: Vec<WeightedComponent>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(components.iter().all(|c| !c.weight.is_zero()), Error::<T>::ZeroWeight);
+			let bounded_components: BoundedVec<WeightedComponent, T::MaxComponents> = components
+				.clone()
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyComponents)?;
+
+			Components::<T>::put(bounded_components);
+			Self::deposit_event(Event::ComponentsUpdated { components });
+			Ok(())
+		}
+
+		/// Set the movement threshold.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `threshold_bps`: the movement threshold, in basis points.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_movement_threshold())]
+		pub fn set_movement_threshold(origin: OriginFor<T>, threshold_bps: u32) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			MovementThreshold::<T>::put(threshold_bps);
+			Self::deposit_event(Event::MovementThresholdUpdated { threshold_bps });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The amount of basic units of one token of `currency_id`.
+	fn unit(currency_id: CurrencyId) -> Option<Balance> {
+		10u128.checked_pow(T::Erc20InfoMapping::decimals(currency_id)?.into())
+	}
+
+	/// The USD price of one token of the component from `source`.
+	pub fn component_price(source: ComponentSource) -> Option<Price> {
+		let (currency_id, basic_price) = match source {
+			ComponentSource::OraclePrice(currency_id) => (currency_id, T::PriceSource::get_price(currency_id)?),
+			ComponentSource::DexAveragePrice(currency_id, quote_currency_id) => (
+				currency_id,
+				T::DEXPriceSource::get_relative_price(currency_id, quote_currency_id)?
+					.checked_mul(&T::PriceSource::get_price(quote_currency_id)?)?,
+			),
+		};
+
+		basic_price.checked_mul(&Price::checked_from_integer(Self::unit(currency_id)?)?)
+	}
+
+	/// Compute the weighted index of the available components. Returns the rate and whether some
+	/// components were unavailable, and the number of components.
+	pub fn compute_reference_rate() -> (Option<(Price, bool)>, u32) {
+		let components = Components::<T>::get();
+		let mut weighted_price = Price::zero();
+		let mut total_weight: u32 = 0;
+		let mut degraded = false;
+
+		for WeightedComponent { source, weight } in components.iter() {
+			match Self::component_price(*source) {
+				Some(price) => {
+					weighted_price =
+						weighted_price.saturating_add(price.saturating_mul(Price::saturating_from_integer(*weight)));
+					total_weight = total_weight.saturating_add(*weight);
+				}
+				None => degraded = true,
+			}
+		}
+
+		let rate = if total_weight.is_zero() {
+			None
+		} else {
+			weighted_price
+				.checked_div(&Price::saturating_from_integer(total_weight))
+				.map(|rate| (rate, degraded))
+		};
+
+		(rate, components.len() as u32)
+	}
+
+	/// The absolute movement from `from` to `to`, in basis points.
+	pub fn movement_bps(from: Price, to: Price) -> u32 {
+		let movement = if to > from { to - from } else { from - to };
+		movement
+			.checked_div(&from)
+			.map(|ratio| ratio.saturating_mul_int(10_000u128).saturated_into())
+			.unwrap_or(u32::MAX)
+	}
+
+	fn update_reference_rate(now: BlockNumberFor<T>) -> u32 {
+		let (maybe_rate, count) = Self::compute_reference_rate();
+		if count.is_zero() {
+			return count;
+		}
+
+		match maybe_rate {
+			Some((rate, degraded)) => {
+				let reference_rate = ReferenceRate {
+					rate,
+					degraded,
+					updated_at: now,
+				};
+				ReferenceRateHistory::<T>::mutate(|history| {
+					// drop the oldest rate if the history is full.
+					let _ = history.force_insert_keep_right(history.len(), reference_rate);
+				});
+
+				let should_notify = LastUpdatedRate::<T>::get().map_or(true, |last| {
+					last.degraded != degraded || Self::movement_bps(last.rate, rate) > MovementThreshold::<T>::get()
+				});
+				if should_notify {
+					LastUpdatedRate::<T>::put(reference_rate);
+					Self::deposit_event(Event::ReferenceRateUpdated { rate, degraded });
+				}
+			}
+			None => Self::deposit_event(Event::ReferenceRateUnavailable),
+		}
+
+		count
+	}
+
+	/// The latest reference rate.
+	pub fn get_reference_rate() -> Option<ReferenceRateOf<T>> {
+		ReferenceRateHistory::<T>::get().last().copied()
+	}
+}
+
+impl<T: Config> ReferenceRateProvider<BlockNumberFor<T>> for Pallet<T> {
+	fn get_reference_rate() -> Option<ReferenceRateOf<T>> {
+		Self::get_reference_rate()
+	}
+}
@@ -0,0 +1,146 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the reference rate module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureSignedBy;
+use module_support::{mocks::MockErc20InfoMapping, ExchangeRate};
+use primitives::TokenSymbol;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const KUSD: CurrencyId = CurrencyId::Token(TokenSymbol::KUSD);
+pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
+
+mod reference_rate {
+	pub use super::super::*;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+}
+
+parameter_types! {
+	/// The oracle prices of one basic unit: (currency_id, price).
+	pub static OraclePrices: Vec<(CurrencyId, Price)> = vec![];
+	/// The DEX average prices of one basic unit: (base, quote, rate).
+	pub static AveragePrices: Vec<(CurrencyId, CurrencyId, ExchangeRate)> = vec![];
+}
+
+ord_parameter_types! {
+	pub const Admin: AccountId = ALICE;
+}
+
+pub fn unit(currency_id: CurrencyId) -> Balance {
+	10u128.pow(MockErc20InfoMapping::decimals(currency_id).unwrap().into())
+}
+
+/// Set the USD price of one `currency_id` token, `None` to remove it.
+pub fn set_oracle_price(currency_id: CurrencyId, price: Option<Price>) {
+	OraclePrices::mutate(|prices| {
+		prices.retain(|(id, _)| *id != currency_id);
+		if let Some(price) = price {
+			prices.push((currency_id, price / Price::saturating_from_integer(unit(currency_id))));
+		}
+	});
+}
+
+/// Set the DEX average price of one `base` token in `quote` tokens.
+pub fn set_average_price(base: CurrencyId, quote: CurrencyId, rate: ExchangeRate) {
+	let basic_rate = rate * ExchangeRate::saturating_from_rational(unit(quote), unit(base));
+	AveragePrices::mutate(|prices| {
+		prices.retain(|(b, q, _)| (*b, *q) != (base, quote));
+		prices.push((base, quote, basic_rate));
+	});
+}
+
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		OraclePrices::get()
+			.into_iter()
+			.find(|(id, _)| *id == currency_id)
+			.map(|(_, price)| price)
+	}
+}
+
+pub struct MockDEXPriceSource;
+impl DEXPriceProvider<CurrencyId> for MockDEXPriceSource {
+	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<ExchangeRate> {
+		AveragePrices::get()
+			.into_iter()
+			.find(|(b, q, _)| (*b, *q) == (base, quote))
+			.map(|(_, _, rate)| rate)
+	}
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AccumulatePeriod = ConstU64<10>;
+	type PriceSource = MockPriceSource;
+	type DEXPriceSource = MockDEXPriceSource;
+	type Erc20InfoMapping = MockErc20InfoMapping;
+	type UpdateOrigin = EnsureSignedBy<Admin, AccountId>;
+	type MaxComponents = ConstU32<3>;
+	type MaxHistory = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		ReferenceRateModule: reference_rate,
+	}
+);
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
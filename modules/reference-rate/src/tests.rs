@@ -0,0 +1,261 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the reference rate module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use module_support::ExchangeRate;
+use sp_runtime::traits::{BadOrigin, One};
+
+fn kusd(weight: u32) -> WeightedComponent {
+	WeightedComponent {
+		source: ComponentSource::OraclePrice(KUSD),
+		weight,
+	}
+}
+
+fn ausd(weight: u32) -> WeightedComponent {
+	WeightedComponent {
+		source: ComponentSource::OraclePrice(AUSD),
+		weight,
+	}
+}
+
+fn ldot(weight: u32) -> WeightedComponent {
+	WeightedComponent {
+		source: ComponentSource::DexAveragePrice(LDOT, KUSD),
+		weight,
+	}
+}
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+	ReferenceRateModule::on_initialize(n);
+}
+
+fn reference_rate_events() -> Vec<Event<Runtime>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::ReferenceRateModule(event @ Event::ReferenceRateUpdated { .. }) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+fn rates() -> Vec<(Price, bool)> {
+	ReferenceRateModule::reference_rate_history()
+		.into_iter()
+		.map(|r| (r.rate, r.degraded))
+		.collect()
+}
+
+fn setup_prices() {
+	set_oracle_price(KUSD, Some(Price::one()));
+	set_oracle_price(AUSD, Some(Price::saturating_from_rational(98, 100)));
+	set_average_price(LDOT, KUSD, ExchangeRate::saturating_from_rational(102, 100));
+}
+
+#[test]
+fn set_components_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ReferenceRateModule::set_components(RuntimeOrigin::signed(BOB), vec![kusd(1)]),
+			BadOrigin
+		);
+		assert_noop!(
+			ReferenceRateModule::set_components(RuntimeOrigin::signed(ALICE), vec![kusd(1), ausd(0)]),
+			Error::<Runtime>::ZeroWeight
+		);
+		assert_noop!(
+			ReferenceRateModule::set_components(RuntimeOrigin::signed(ALICE), vec![kusd(1), ausd(1), ldot(1), ldot(1)]),
+			Error::<Runtime>::TooManyComponents
+		);
+
+		assert_ok!(ReferenceRateModule::set_components(
+			RuntimeOrigin::signed(ALICE),
+			vec![kusd(1), ldot(2)]
+		));
+		System::assert_last_event(RuntimeEvent::ReferenceRateModule(Event::ComponentsUpdated {
+			components: vec![kusd(1), ldot(2)],
+		}));
+		assert_eq!(ReferenceRateModule::components().into_inner(), vec![kusd(1), ldot(2)]);
+
+		assert_ok!(ReferenceRateModule::set_components(
+			RuntimeOrigin::signed(ALICE),
+			vec![]
+		));
+		assert!(ReferenceRateModule::components().is_empty());
+	});
+}
+
+#[test]
+fn set_movement_threshold_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(ReferenceRateModule::movement_threshold(), DEFAULT_MOVEMENT_THRESHOLD);
+		assert_noop!(
+			ReferenceRateModule::set_movement_threshold(RuntimeOrigin::signed(BOB), 50),
+			BadOrigin
+		);
+
+		assert_ok!(ReferenceRateModule::set_movement_threshold(
+			RuntimeOrigin::signed(ALICE),
+			50
+		));
+		System::assert_last_event(RuntimeEvent::ReferenceRateModule(Event::MovementThresholdUpdated {
+			threshold_bps: 50,
+		}));
+		assert_eq!(ReferenceRateModule::movement_threshold(), 50);
+	});
+}
+
+#[test]
+fn component_price_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(ReferenceRateModule::component_price(kusd(1).source), None);
+		assert_eq!(ReferenceRateModule::component_price(ldot(1).source), None);
+
+		setup_prices();
+		assert_eq!(ReferenceRateModule::component_price(kusd(1).source), Some(Price::one()));
+		assert_eq!(
+			ReferenceRateModule::component_price(ausd(1).source),
+			Some(Price::saturating_from_rational(98, 100))
+		);
+		assert_eq!(
+			ReferenceRateModule::component_price(ldot(1).source),
+			Some(Price::saturating_from_rational(102, 100))
+		);
+
+		// the DEX average price is valued by the oracle price of the quote currency
+		set_oracle_price(KUSD, Some(Price::saturating_from_rational(99, 100)));
+		assert_eq!(
+			ReferenceRateModule::component_price(ldot(1).source),
+			Some(Price::saturating_from_rational(10098, 10000))
+		);
+		set_oracle_price(KUSD, None);
+		assert_eq!(ReferenceRateModule::component_price(ldot(1).source), None);
+	});
+}
+
+#[test]
+fn reference_rate_renormalizes_on_component_outage() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_prices();
+		assert_ok!(ReferenceRateModule::set_components(
+			RuntimeOrigin::signed(ALICE),
+			vec![kusd(3), ausd(1), ldot(1)]
+		));
+
+		// not an accumulate period
+		run_to_block(9);
+		assert_eq!(ReferenceRateModule::get_reference_rate(), None);
+
+		// (3 * 1 + 0.98 + 1.02) / 5
+		run_to_block(10);
+		assert_eq!(
+			ReferenceRateModule::get_reference_rate(),
+			Some(module_support::ReferenceRate {
+				rate: Price::one(),
+				degraded: false,
+				updated_at: 10,
+			})
+		);
+		System::assert_last_event(RuntimeEvent::ReferenceRateModule(Event::ReferenceRateUpdated {
+			rate: Price::one(),
+			degraded: false,
+		}));
+
+		// AUSD is skipped: (3 * 1 + 1.02) / 4
+		set_oracle_price(AUSD, None);
+		run_to_block(20);
+		let degraded_rate = Price::saturating_from_rational(1005, 1000);
+		assert_eq!(rates(), vec![(Price::one(), false), (degraded_rate, true)]);
+		System::assert_last_event(RuntimeEvent::ReferenceRateModule(Event::ReferenceRateUpdated {
+			rate: degraded_rate,
+			degraded: true,
+		}));
+
+		// the DEX average price of LDOT is valued by the oracle price of KUSD
+		set_oracle_price(KUSD, None);
+		run_to_block(30);
+		System::assert_last_event(RuntimeEvent::ReferenceRateModule(Event::ReferenceRateUnavailable));
+		assert_eq!(rates(), vec![(Price::one(), false), (degraded_rate, true)]);
+
+		// recovered, the oldest rate is dropped
+		setup_prices();
+		run_to_block(40);
+		assert_eq!(rates(), vec![(degraded_rate, true), (Price::one(), false)]);
+		System::assert_last_event(RuntimeEvent::ReferenceRateModule(Event::ReferenceRateUpdated {
+			rate: Price::one(),
+			degraded: false,
+		}));
+		assert_eq!(reference_rate_events().len(), 3);
+	});
+}
+
+#[test]
+fn reference_rate_follows_weight_updates() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_prices();
+		assert_ok!(ReferenceRateModule::set_components(
+			RuntimeOrigin::signed(ALICE),
+			vec![kusd(3), ausd(1), ldot(1)]
+		));
+		run_to_block(10);
+		assert_eq!(reference_rate_events().len(), 1);
+
+		// reweighted mid-stream: (1 + 0.98) / 2, moved by 100 bps
+		assert_ok!(ReferenceRateModule::set_components(
+			RuntimeOrigin::signed(ALICE),
+			vec![kusd(1), ausd(1)]
+		));
+		run_to_block(20);
+		let rate = Price::saturating_from_rational(99, 100);
+		assert_eq!(rates(), vec![(Price::one(), false), (rate, false)]);
+		System::assert_last_event(RuntimeEvent::ReferenceRateModule(Event::ReferenceRateUpdated {
+			rate,
+			degraded: false,
+		}));
+
+		// (1 + 0.99) / 2, moved by about 50 bps which is within the threshold
+		assert_ok!(ReferenceRateModule::set_movement_threshold(
+			RuntimeOrigin::signed(ALICE),
+			60
+		));
+		set_oracle_price(AUSD, Some(Price::saturating_from_rational(99, 100)));
+		run_to_block(30);
+		assert_eq!(
+			ReferenceRateModule::get_reference_rate().unwrap().rate,
+			Price::saturating_from_rational(995, 1000)
+		);
+		assert_eq!(reference_rate_events().len(), 2);
+
+		// the movement is measured from the last notified rate: (1 + 1) / 2 moved by 101 bps
+		set_oracle_price(AUSD, Some(Price::one()));
+		run_to_block(40);
+		System::assert_last_event(RuntimeEvent::ReferenceRateModule(Event::ReferenceRateUpdated {
+			rate: Price::one(),
+			degraded: false,
+		}));
+		assert_eq!(ReferenceRateModule::last_updated_rate().unwrap().updated_at, 40);
+	});
+}
@@ -47,6 +47,8 @@ pub enum KusamaRelayChainCall {
 	Utility(Box<UtilityCall<Self>>),
 	#[codec(index = 30)]
 	Proxy(Box<ProxyCall<Self>>),
+	#[codec(index = 41)]
+	NominationPools(NominationPoolsCall),
 	#[codec(index = 99)]
 	XcmPallet(XcmCall),
 }
@@ -71,6 +73,10 @@ impl RelayChainCall for KusamaRelayChainCall {
 	fn xcm_pallet(call: XcmCall) -> Self {
 		KusamaRelayChainCall::XcmPallet(call)
 	}
+
+	fn nomination_pools(call: NominationPoolsCall) -> Self {
+		KusamaRelayChainCall::NominationPools(call)
+	}
 }
 
 /// The encoded index corresponds to Polkadot's Runtime module configuration.
@@ -85,6 +91,8 @@ pub enum PolkadotRelayChainCall {
 	Utility(Box<UtilityCall<Self>>),
 	#[codec(index = 29)]
 	Proxy(Box<ProxyCall<Self>>),
+	#[codec(index = 39)]
+	NominationPools(NominationPoolsCall),
 	#[codec(index = 99)]
 	XcmPallet(XcmCall),
 }
@@ -109,6 +117,10 @@ impl RelayChainCall for PolkadotRelayChainCall {
 	fn xcm_pallet(call: XcmCall) -> Self {
 		PolkadotRelayChainCall::XcmPallet(call)
 	}
+
+	fn nomination_pools(call: NominationPoolsCall) -> Self {
+		PolkadotRelayChainCall::NominationPools(call)
+	}
 }
 
 pub struct RelayChainCallBuilder<ParachainId, RCC>(PhantomData<(ParachainId, RCC)>);
@@ -144,6 +156,33 @@ where
 		))
 	}
 
+	fn nomination_pools_join(amount: Self::Balance, pool_id: u32) -> RCC {
+		RCC::nomination_pools(NominationPoolsCall::Join(amount, pool_id))
+	}
+
+	fn nomination_pools_bond_extra(amount: Self::Balance) -> RCC {
+		RCC::nomination_pools(NominationPoolsCall::BondExtra(PoolBondExtra::FreeBalance(amount)))
+	}
+
+	fn nomination_pools_bond_extra_rewards() -> RCC {
+		RCC::nomination_pools(NominationPoolsCall::BondExtra(PoolBondExtra::Rewards))
+	}
+
+	fn nomination_pools_claim_payout() -> RCC {
+		RCC::nomination_pools(NominationPoolsCall::ClaimPayout)
+	}
+
+	fn nomination_pools_unbond(member: Self::RelayChainAccountId, points: Self::Balance) -> RCC {
+		RCC::nomination_pools(NominationPoolsCall::Unbond(RelayChainLookup::unlookup(member), points))
+	}
+
+	fn nomination_pools_withdraw_unbonded(member: Self::RelayChainAccountId, num_slashing_spans: u32) -> RCC {
+		RCC::nomination_pools(NominationPoolsCall::WithdrawUnbonded(
+			RelayChainLookup::unlookup(member),
+			num_slashing_spans,
+		))
+	}
+
 	fn balances_transfer_keep_alive(to: Self::RelayChainAccountId, amount: Self::Balance) -> RCC {
 		RCC::balances(BalancesCall::TransferKeepAlive(RelayChainLookup::unlookup(to), amount))
 	}
@@ -0,0 +1,476 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Safe Mode Module
+//!
+//! ## Overview
+//!
+//! An automatic system-wide risk-off switch. Every block the trigger conditions are evaluated:
+//!
+//! - the deviation of the stable currency from the peg, measured by the peg monitor, exceeds
+//!   `max_peg_deviation_bps`;
+//! - the oracle price of a monitored collateral is not updated for more than `max_price_age`
+//!   blocks;
+//! - the debit value liquidated in the current liquidation window exceeds
+//!   `max_liquidated_value`. The windows are tumbling, each one starts when the previous one
+//!   ends.
+//!
+//! When any of them holds, safe mode is entered: the configured calls are paused by the
+//! transaction pause module, and new debit of CDPs is frozen. Repaying debit, topping up and
+//! withdrawing collateral stay open. Once all the conditions clear, safe mode is exited
+//! automatically after `SafeModeCooldown` blocks, unless they recur in the meantime. The
+//! `UpdateOrigin` can also exit safe mode at any time, but it is entered again in the next
+//! block if the conditions still hold.
+//!
+//! Only the calls paused by safe mode itself are unpaused on exit, the calls paused by
+//! governance stay paused.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use module_support::{LiquidatedValueProvider, PegStatusProvider, PriceTimestampProvider, SafeMode, TransactionPauser};
+use primitives::{Balance, CurrencyId, Moment};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The thresholds of the trigger conditions, a condition is disabled if its threshold is `None`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TriggerParams<BlockNumber> {
+	/// The maximum deviation of the stable currency from the peg, in basis points.
+	pub max_peg_deviation_bps: Option<u32>,
+	/// The maximum number of blocks the oracle price of a monitored collateral is not updated.
+	pub max_price_age: Option<BlockNumber>,
+	/// The maximum debit value liquidated in a liquidation window.
+	pub max_liquidated_value: Option<Balance>,
+	/// The length of the liquidation window, the liquidation condition is disabled if zero.
+	pub liquidation_window: BlockNumber,
+}
+
+/// A trigger condition which holds.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SafeModeTrigger {
+	/// The stable currency deviates from the peg.
+	PegDeviation { deviation_bps: u32 },
+	/// The oracle price of the collateral is stale.
+	StalePrice { currency_id: CurrencyId },
+	/// Too much debit value is liquidated in the current liquidation window.
+	ExcessiveLiquidation { liquidated_value: Balance },
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SafeModeState<BlockNumber> {
+	#[default]
+	Inactive,
+	Active {
+		/// The block number at which safe mode is entered.
+		entered_at: BlockNumber,
+		/// The block number at which all the trigger conditions cleared, `None` if any of them
+		/// still holds.
+		cleared_at: Option<BlockNumber>,
+	},
+}
+
+pub type TriggerParamsOf<T> = TriggerParams<BlockNumberFor<T>>;
+pub type SafeModeStateOf<T> = SafeModeState<BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The measured peg of the stable currency.
+		type PegStatus: PegStatusProvider<BlockNumberFor<Self>>;
+
+		/// The timestamp source of the oracle prices.
+		type PriceTimestamp: PriceTimestampProvider<CurrencyId>;
+
+		/// The cumulative liquidated debit value.
+		type LiquidatedValue: LiquidatedValueProvider;
+
+		/// Pause and unpause the safe mode calls.
+		type TransactionPauser: TransactionPauser;
+
+		/// The origin which may update the params and exit safe mode.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The number of blocks to stay in safe mode after all the trigger conditions cleared.
+		#[pallet::constant]
+		type SafeModeCooldown: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of the monitored collaterals.
+		#[pallet::constant]
+		type MaxMonitoredCollaterals: Get<u32>;
+
+		/// The maximum number of the calls paused in safe mode.
+		#[pallet::constant]
+		type MaxSafeModeCalls: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Too many monitored collaterals.
+		TooManyCollaterals,
+		/// Too many safe mode calls.
+		TooManyCalls,
+		/// The safe mode calls contain duplicated calls.
+		DuplicateCall,
+		/// The safe mode calls can't be updated while safe mode is active.
+		SafeModeActive,
+		/// Safe mode is not active.
+		SafeModeInactive,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The trigger params are updated.
+		TriggerParamsUpdated { params: TriggerParamsOf<T> },
+		/// The monitored collaterals are updated.
+		MonitoredCollateralsUpdated { collaterals: Vec<CurrencyId> },
+		/// The safe mode calls are updated.
+		SafeModeCallsUpdated { calls: Vec<(Vec<u8>, Vec<u8>)> },
+		/// Safe mode is entered.
+		SafeModeEntered { triggers: Vec<SafeModeTrigger> },
+		/// All the trigger conditions cleared, safe mode is exited at `exit_at` unless they recur.
+		ConditionsCleared { exit_at: BlockNumberFor<T> },
+		/// The trigger conditions recurred during the cooldown.
+		ConditionsRecurred { triggers: Vec<SafeModeTrigger> },
+		/// Safe mode is exited, by the `UpdateOrigin` or after the cooldown.
+		SafeModeExited { by_governance: bool },
+	}
+
+	/// The thresholds of the trigger conditions.
+	///
+	/// Params: TriggerParams
+	#[pallet::storage]
+	#[pallet::getter(fn params)]
+	pub type Params<T: Config> = StorageValue<_, TriggerParamsOf<T>, ValueQuery>;
+
+	/// The collaterals whose oracle price is monitored.
+	///
+	/// MonitoredCollaterals: Vec<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn monitored_collaterals)]
+	pub type MonitoredCollaterals<T: Config> =
+		StorageValue<_, BoundedVec<CurrencyId, T::MaxMonitoredCollaterals>, ValueQuery>;
+
+	/// The calls to pause in safe mode.
+	///
+	/// SafeModeCalls: Vec<(PalletNameBytes, FunctionNameBytes)>
+	#[pallet::storage]
+	#[pallet::getter(fn safe_mode_calls)]
+	pub type SafeModeCalls<T: Config> =
+		StorageValue<_, BoundedVec<(Vec<u8>, Vec<u8>), T::MaxSafeModeCalls>, ValueQuery>;
+
+	/// The calls paused by safe mode itself, to unpause on exit.
+	///
+	/// PausedBySafeMode: Vec<(PalletNameBytes, FunctionNameBytes)>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_by_safe_mode)]
+	pub type PausedBySafeMode<T: Config> =
+		StorageValue<_, BoundedVec<(Vec<u8>, Vec<u8>), T::MaxSafeModeCalls>, ValueQuery>;
+
+	/// The state of safe mode.
+	///
+	/// State: SafeModeState
+	#[pallet::storage]
+	#[pallet::getter(fn state)]
+	pub type State<T: Config> = StorageValue<_, SafeModeStateOf<T>, ValueQuery>;
+
+	/// The latest seen oracle price timestamp of the monitored collateral, and the block number
+	/// at which it is first seen.
+	///
+	/// PriceUpdatedAt: map CurrencyId => Option<(Moment, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn price_updated_at)]
+	pub type PriceUpdatedAt<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, (Moment, BlockNumberFor<T>), OptionQuery>;
+
+	/// The start block number of the current liquidation window, and the cumulative liquidated
+	/// debit value at the start.
+	///
+	/// LiquidationWindow: Option<(BlockNumber, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_window)]
+	pub type LiquidationWindow<T: Config> = StorageValue<_, (BlockNumberFor<T>, Balance), OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let (triggers, count) = Self::evaluate(now);
+			let transition_weight = Self::transit(now, triggers);
+			T::WeightInfo::on_initialize(count).saturating_add(transition_weight)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the thresholds of the trigger conditions.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `params`: the thresholds, `None` disables the condition.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_trigger_params())]
+		pub fn set_trigger_params(origin: OriginFor<T>, params: TriggerParamsOf<T>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Params::<T>::put(params);
+			Self::deposit_event(Event::TriggerParamsUpdated { params });
+			Ok(())
+		}
+
+		/// Set the collaterals whose oracle price is monitored.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `collaterals`: the monitored collaterals.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_monitored_collaterals(collaterals.len() as u32))]
+		pub fn set_monitored_collaterals(origin: OriginFor<T>, mut collaterals: Vec<CurrencyId>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			collaterals.sort();
+			collaterals.dedup();
+			let bounded_collaterals: BoundedVec<CurrencyId, T::MaxMonitoredCollaterals> = collaterals
+				.clone()
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyCollaterals)?;
+
+			for currency_id in MonitoredCollaterals::<T>::get() {
+				if !collaterals.contains(&currency_id) {
+					PriceUpdatedAt::<T>::remove(currency_id);
+				}
+			}
+			MonitoredCollaterals::<T>::put(bounded_collaterals);
+			Self::deposit_event(Event::MonitoredCollateralsUpdated { collaterals });
+			Ok(())
+		}
+
+		/// Set the calls to pause in safe mode. They can't be updated while safe mode is active.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `calls`: the pallet and function names of the calls.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_safe_mode_calls(calls.len() as u32))]
+		pub fn set_safe_mode_calls(origin: OriginFor<T>, calls: Vec<(Vec<u8>, Vec<u8>)>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_active(), Error::<T>::SafeModeActive);
+			for (i, (pallet_name, function_name)) in calls.iter().enumerate() {
+				ensure!(!calls[..i].contains(&calls[i]), Error::<T>::DuplicateCall);
+				T::TransactionPauser::ensure_pausable(pallet_name, function_name)?;
+			}
+			let bounded_calls: BoundedVec<(Vec<u8>, Vec<u8>), T::MaxSafeModeCalls> =
+				calls.clone().try_into().map_err(|_| Error::<T>::TooManyCalls)?;
+
+			SafeModeCalls::<T>::put(bounded_calls);
+			Self::deposit_event(Event::SafeModeCallsUpdated { calls });
+			Ok(())
+		}
+
+		/// Exit safe mode and unpause the calls paused by it.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::exit_safe_mode(T::MaxSafeModeCalls::get()))]
+		pub fn exit_safe_mode(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_active(), Error::<T>::SafeModeInactive);
+			let count = Self::do_exit_safe_mode(true);
+			Ok(Some(T::WeightInfo::exit_safe_mode(count)).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn is_active() -> bool {
+		matches!(State::<T>::get(), SafeModeState::Active { .. })
+	}
+
+	/// Evaluate the trigger conditions at `now`. Returns the ones which hold and the number of
+	/// the monitored collaterals.
+	pub fn evaluate(now: BlockNumberFor<T>) -> (Vec<SafeModeTrigger>, u32) {
+		let params = Params::<T>::get();
+		let mut triggers = Vec::new();
+
+		if let (Some(max_peg_deviation_bps), Some(status)) =
+			(params.max_peg_deviation_bps, T::PegStatus::get_peg_status())
+		{
+			if status.deviation_bps > max_peg_deviation_bps {
+				triggers.push(SafeModeTrigger::PegDeviation {
+					deviation_bps: status.deviation_bps,
+				});
+			}
+		}
+
+		let collaterals = MonitoredCollaterals::<T>::get();
+		for currency_id in collaterals.iter().copied() {
+			// the collateral without any oracle price is not fed yet rather than stale
+			let Some(timestamp) = T::PriceTimestamp::get_price_timestamp(currency_id) else {
+				continue;
+			};
+			// the price is updated at the block a new timestamp is first seen
+			let updated_at = match PriceUpdatedAt::<T>::get(currency_id) {
+				Some((last_timestamp, updated_at)) if last_timestamp == timestamp => updated_at,
+				_ => {
+					PriceUpdatedAt::<T>::insert(currency_id, (timestamp, now));
+					now
+				}
+			};
+			if let Some(max_price_age) = params.max_price_age {
+				if now.saturating_sub(updated_at) > max_price_age {
+					triggers.push(SafeModeTrigger::StalePrice { currency_id });
+				}
+			}
+		}
+
+		if let Some(max_liquidated_value) = params.max_liquidated_value {
+			if !params.liquidation_window.is_zero() {
+				let total = T::LiquidatedValue::total_liquidated_value();
+				let snapshot = match LiquidationWindow::<T>::get() {
+					Some((start, snapshot)) if now < start.saturating_add(params.liquidation_window) => snapshot,
+					_ => {
+						LiquidationWindow::<T>::put((now, total));
+						total
+					}
+				};
+				let liquidated_value = total.saturating_sub(snapshot);
+				if liquidated_value > max_liquidated_value {
+					triggers.push(SafeModeTrigger::ExcessiveLiquidation { liquidated_value });
+				}
+			}
+		}
+
+		(triggers, collaterals.len() as u32)
+	}
+
+	/// Transit the state by the trigger conditions which hold at `now`. Returns the weight of
+	/// entering or exiting safe mode.
+	fn transit(now: BlockNumberFor<T>, triggers: Vec<SafeModeTrigger>) -> Weight {
+		match (State::<T>::get(), triggers.is_empty()) {
+			(SafeModeState::Inactive, false) => {
+				let count = Self::do_enter_safe_mode(now, triggers);
+				return T::WeightInfo::enter_safe_mode(count);
+			}
+			(
+				SafeModeState::Active {
+					entered_at,
+					cleared_at: None,
+				},
+				true,
+			) => {
+				State::<T>::put(SafeModeState::Active {
+					entered_at,
+					cleared_at: Some(now),
+				});
+				Self::deposit_event(Event::ConditionsCleared {
+					exit_at: now.saturating_add(T::SafeModeCooldown::get()),
+				});
+			}
+			(
+				SafeModeState::Active {
+					cleared_at: Some(cleared_at),
+					..
+				},
+				true,
+			) => {
+				if now >= cleared_at.saturating_add(T::SafeModeCooldown::get()) {
+					let count = Self::do_exit_safe_mode(false);
+					return T::WeightInfo::exit_safe_mode(count);
+				}
+			}
+			(
+				SafeModeState::Active {
+					entered_at,
+					cleared_at: Some(_),
+				},
+				false,
+			) => {
+				State::<T>::put(SafeModeState::Active {
+					entered_at,
+					cleared_at: None,
+				});
+				Self::deposit_event(Event::ConditionsRecurred { triggers });
+			}
+			(SafeModeState::Inactive, true) | (SafeModeState::Active { cleared_at: None, .. }, false) => {}
+		}
+		Weight::zero()
+	}
+
+	/// Enter safe mode and pause the safe mode calls. Returns the number of the safe mode calls.
+	fn do_enter_safe_mode(now: BlockNumberFor<T>, triggers: Vec<SafeModeTrigger>) -> u32 {
+		let calls = SafeModeCalls::<T>::get();
+		let count = calls.len() as u32;
+		// the calls already paused by governance are left to it, and the calls removed by
+		// runtime upgrades are skipped
+		let paused: Vec<(Vec<u8>, Vec<u8>)> = calls
+			.into_iter()
+			.filter(|(pallet_name, function_name)| {
+				T::TransactionPauser::pause_transaction(pallet_name.clone(), function_name.clone()).unwrap_or(false)
+			})
+			.collect();
+		// never exceeds `MaxSafeModeCalls` as it is a subset of `SafeModeCalls`
+		PausedBySafeMode::<T>::put(BoundedVec::truncate_from(paused));
+		State::<T>::put(SafeModeState::Active {
+			entered_at: now,
+			cleared_at: None,
+		});
+		Self::deposit_event(Event::SafeModeEntered { triggers });
+		count
+	}
+
+	/// Exit safe mode and unpause the calls paused by it. Returns the number of the unpaused
+	/// calls.
+	fn do_exit_safe_mode(by_governance: bool) -> u32 {
+		let paused = PausedBySafeMode::<T>::take();
+		let count = paused.len() as u32;
+		for (pallet_name, function_name) in paused {
+			T::TransactionPauser::unpause_transaction(pallet_name, function_name);
+		}
+		State::<T>::put(SafeModeState::Inactive);
+		Self::deposit_event(Event::SafeModeExited { by_governance });
+		count
+	}
+}
+
+impl<T: Config> SafeMode for Pallet<T> {
+	fn is_active() -> bool {
+		Self::is_active()
+	}
+}
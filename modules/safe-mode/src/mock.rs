@@ -0,0 +1,178 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the safe mode module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureSignedBy;
+use module_support::PegStatus;
+use primitives::TokenSymbol;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, DispatchError, DispatchResult};
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
+
+pub const COOLDOWN: u64 = 10;
+
+mod safe_mode {
+	pub use super::super::*;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+}
+
+parameter_types! {
+	pub static PegDeviationBps: Option<u32> = None;
+	/// The oracle price timestamps: (currency_id, timestamp).
+	pub static PriceTimestamps: Vec<(CurrencyId, Moment)> = vec![];
+	pub static TotalLiquidatedValue: Balance = 0;
+	/// The calls of the runtime: (pallet_name, function_name).
+	pub static RuntimeCalls: Vec<(Vec<u8>, Vec<u8>)> = vec![
+		(b"Honzon".to_vec(), b"adjust_loan".to_vec()),
+		(b"Honzon".to_vec(), b"expand_position_collateral".to_vec()),
+		(b"Homa".to_vec(), b"fast_match_redeems".to_vec()),
+	];
+	pub static PausedCalls: Vec<(Vec<u8>, Vec<u8>)> = vec![];
+}
+
+ord_parameter_types! {
+	pub const Admin: AccountId = ALICE;
+}
+
+pub fn set_price_timestamp(currency_id: CurrencyId, timestamp: Moment) {
+	PriceTimestamps::mutate(|timestamps| {
+		timestamps.retain(|(id, _)| *id != currency_id);
+		timestamps.push((currency_id, timestamp));
+	});
+}
+
+pub fn call(pallet_name: &str, function_name: &str) -> (Vec<u8>, Vec<u8>) {
+	(pallet_name.as_bytes().to_vec(), function_name.as_bytes().to_vec())
+}
+
+pub struct MockPegStatus;
+impl PegStatusProvider<u64> for MockPegStatus {
+	fn get_peg_status() -> Option<PegStatus<u64>> {
+		PegDeviationBps::get().map(|deviation_bps| PegStatus {
+			price: Default::default(),
+			deviation_bps,
+			updated_at: System::block_number(),
+		})
+	}
+}
+
+pub struct MockPriceTimestamp;
+impl PriceTimestampProvider<CurrencyId> for MockPriceTimestamp {
+	fn get_price_timestamp(currency_id: CurrencyId) -> Option<Moment> {
+		PriceTimestamps::get()
+			.into_iter()
+			.find(|(id, _)| *id == currency_id)
+			.map(|(_, timestamp)| timestamp)
+	}
+}
+
+pub struct MockLiquidatedValue;
+impl LiquidatedValueProvider for MockLiquidatedValue {
+	fn total_liquidated_value() -> Balance {
+		TotalLiquidatedValue::get()
+	}
+}
+
+pub struct MockTransactionPauser;
+impl TransactionPauser for MockTransactionPauser {
+	fn ensure_pausable(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult {
+		if RuntimeCalls::get().contains(&(pallet_name.to_vec(), function_name.to_vec())) {
+			Ok(())
+		} else {
+			Err(DispatchError::Other("CallNotFound"))
+		}
+	}
+
+	fn pause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> Result<bool, DispatchError> {
+		Self::ensure_pausable(&pallet_name, &function_name)?;
+		let call = (pallet_name, function_name);
+		if PausedCalls::get().contains(&call) {
+			return Ok(false);
+		}
+		PausedCalls::mutate(|calls| calls.push(call));
+		Ok(true)
+	}
+
+	fn unpause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> bool {
+		let call = (pallet_name, function_name);
+		let paused = PausedCalls::get().contains(&call);
+		PausedCalls::mutate(|calls| calls.retain(|c| *c != call));
+		paused
+	}
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PegStatus = MockPegStatus;
+	type PriceTimestamp = MockPriceTimestamp;
+	type LiquidatedValue = MockLiquidatedValue;
+	type TransactionPauser = MockTransactionPauser;
+	type UpdateOrigin = EnsureSignedBy<Admin, AccountId>;
+	type SafeModeCooldown = ConstU64<COOLDOWN>;
+	type MaxMonitoredCollaterals = ConstU32<2>;
+	type MaxSafeModeCalls = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		SafeModeModule: safe_mode,
+	}
+);
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,365 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the safe mode module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use sp_runtime::{traits::BadOrigin, DispatchError};
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+	SafeModeModule::on_initialize(n);
+}
+
+fn set_params(params: TriggerParamsOf<Runtime>) {
+	assert_ok!(SafeModeModule::set_trigger_params(RuntimeOrigin::signed(ALICE), params));
+}
+
+fn setup_safe_mode_calls() {
+	assert_ok!(SafeModeModule::set_safe_mode_calls(
+		RuntimeOrigin::signed(ALICE),
+		vec![call("Honzon", "adjust_loan"), call("Homa", "fast_match_redeems")]
+	));
+}
+
+fn active(entered_at: u64, cleared_at: Option<u64>) -> SafeModeStateOf<Runtime> {
+	SafeModeState::Active { entered_at, cleared_at }
+}
+
+#[test]
+fn set_trigger_params_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let params = TriggerParams {
+			max_peg_deviation_bps: Some(500),
+			max_price_age: Some(10),
+			max_liquidated_value: Some(1_000),
+			liquidation_window: 100,
+		};
+		assert_noop!(
+			SafeModeModule::set_trigger_params(RuntimeOrigin::signed(BOB), params),
+			BadOrigin
+		);
+		assert_ok!(SafeModeModule::set_trigger_params(RuntimeOrigin::signed(ALICE), params));
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::TriggerParamsUpdated { params }));
+		assert_eq!(SafeModeModule::params(), params);
+	});
+}
+
+#[test]
+fn set_monitored_collaterals_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			SafeModeModule::set_monitored_collaterals(RuntimeOrigin::signed(BOB), vec![DOT]),
+			BadOrigin
+		);
+		assert_noop!(
+			SafeModeModule::set_monitored_collaterals(
+				RuntimeOrigin::signed(ALICE),
+				vec![DOT, LDOT, CurrencyId::Token(primitives::TokenSymbol::ACA)]
+			),
+			Error::<Runtime>::TooManyCollaterals
+		);
+
+		assert_ok!(SafeModeModule::set_monitored_collaterals(
+			RuntimeOrigin::signed(ALICE),
+			vec![LDOT, DOT, DOT]
+		));
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::MonitoredCollateralsUpdated {
+			collaterals: vec![DOT, LDOT],
+		}));
+		assert_eq!(SafeModeModule::monitored_collaterals().into_inner(), vec![DOT, LDOT]);
+
+		// the price tracking of the removed collaterals is cleared
+		set_price_timestamp(DOT, 1_000);
+		set_price_timestamp(LDOT, 1_000);
+		run_to_block(2);
+		assert_eq!(SafeModeModule::price_updated_at(DOT), Some((1_000, 2)));
+		assert_ok!(SafeModeModule::set_monitored_collaterals(
+			RuntimeOrigin::signed(ALICE),
+			vec![LDOT]
+		));
+		assert_eq!(SafeModeModule::price_updated_at(DOT), None);
+		assert_eq!(SafeModeModule::price_updated_at(LDOT), Some((1_000, 2)));
+	});
+}
+
+#[test]
+fn set_safe_mode_calls_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			SafeModeModule::set_safe_mode_calls(RuntimeOrigin::signed(BOB), vec![call("Honzon", "adjust_loan")]),
+			BadOrigin
+		);
+		assert_noop!(
+			SafeModeModule::set_safe_mode_calls(
+				RuntimeOrigin::signed(ALICE),
+				vec![call("Honzon", "adjust_loan"), call("Honzon", "adjust_loan")]
+			),
+			Error::<Runtime>::DuplicateCall
+		);
+		assert_noop!(
+			SafeModeModule::set_safe_mode_calls(RuntimeOrigin::signed(ALICE), vec![call("Honzon", "adjust_loans")]),
+			DispatchError::Other("CallNotFound")
+		);
+		assert_noop!(
+			SafeModeModule::set_safe_mode_calls(
+				RuntimeOrigin::signed(ALICE),
+				vec![
+					call("Honzon", "adjust_loan"),
+					call("Honzon", "expand_position_collateral"),
+					call("Homa", "fast_match_redeems")
+				]
+			),
+			Error::<Runtime>::TooManyCalls
+		);
+
+		setup_safe_mode_calls();
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::SafeModeCallsUpdated {
+			calls: vec![call("Honzon", "adjust_loan"), call("Homa", "fast_match_redeems")],
+		}));
+
+		// can't be updated while safe mode is active
+		set_params(TriggerParams {
+			max_peg_deviation_bps: Some(500),
+			..Default::default()
+		});
+		PegDeviationBps::set(Some(501));
+		run_to_block(2);
+		assert_noop!(
+			SafeModeModule::set_safe_mode_calls(RuntimeOrigin::signed(ALICE), vec![]),
+			Error::<Runtime>::SafeModeActive
+		);
+	});
+}
+
+#[test]
+fn peg_deviation_triggers_safe_mode() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_safe_mode_calls();
+		PegDeviationBps::set(Some(501));
+
+		// disabled by default
+		run_to_block(2);
+		assert_eq!(SafeModeModule::state(), SafeModeState::Inactive);
+
+		set_params(TriggerParams {
+			max_peg_deviation_bps: Some(500),
+			..Default::default()
+		});
+		PegDeviationBps::set(Some(500));
+		run_to_block(3);
+		assert_eq!(SafeModeModule::state(), SafeModeState::Inactive);
+
+		PegDeviationBps::set(Some(501));
+		run_to_block(4);
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::SafeModeEntered {
+			triggers: vec![SafeModeTrigger::PegDeviation { deviation_bps: 501 }],
+		}));
+		assert_eq!(SafeModeModule::state(), active(4, None));
+		assert!(<SafeModeModule as SafeMode>::is_active());
+		assert_eq!(
+			PausedCalls::get(),
+			vec![call("Honzon", "adjust_loan"), call("Homa", "fast_match_redeems")]
+		);
+		assert_eq!(
+			SafeModeModule::paused_by_safe_mode().into_inner(),
+			vec![call("Honzon", "adjust_loan"), call("Homa", "fast_match_redeems")]
+		);
+
+		// no event while the conditions still hold
+		System::reset_events();
+		run_to_block(5);
+		assert!(System::events().is_empty());
+		assert_eq!(SafeModeModule::state(), active(4, None));
+	});
+}
+
+#[test]
+fn stale_price_triggers_safe_mode() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_safe_mode_calls();
+		set_params(TriggerParams {
+			max_price_age: Some(5),
+			..Default::default()
+		});
+		assert_ok!(SafeModeModule::set_monitored_collaterals(
+			RuntimeOrigin::signed(ALICE),
+			vec![DOT, LDOT]
+		));
+
+		// the collateral without oracle price is not stale
+		set_price_timestamp(DOT, 1_000);
+		run_to_block(2);
+		assert_eq!(SafeModeModule::price_updated_at(DOT), Some((1_000, 2)));
+		assert_eq!(SafeModeModule::price_updated_at(LDOT), None);
+
+		// a new timestamp refreshes the price
+		set_price_timestamp(DOT, 2_000);
+		run_to_block(6);
+		assert_eq!(SafeModeModule::price_updated_at(DOT), Some((2_000, 6)));
+
+		run_to_block(11);
+		assert_eq!(SafeModeModule::state(), SafeModeState::Inactive);
+
+		run_to_block(12);
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::SafeModeEntered {
+			triggers: vec![SafeModeTrigger::StalePrice { currency_id: DOT }],
+		}));
+		assert_eq!(SafeModeModule::state(), active(12, None));
+	});
+}
+
+#[test]
+fn excessive_liquidation_triggers_safe_mode() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_safe_mode_calls();
+		TotalLiquidatedValue::set(5_000);
+		set_params(TriggerParams {
+			max_liquidated_value: Some(1_000),
+			liquidation_window: 10,
+			..Default::default()
+		});
+
+		// the window starts at the first evaluation
+		run_to_block(2);
+		assert_eq!(SafeModeModule::liquidation_window(), Some((2, 5_000)));
+
+		TotalLiquidatedValue::set(6_000);
+		run_to_block(5);
+		assert_eq!(SafeModeModule::state(), SafeModeState::Inactive);
+
+		// a new window starts when the previous one ends
+		run_to_block(12);
+		assert_eq!(SafeModeModule::liquidation_window(), Some((12, 6_000)));
+
+		TotalLiquidatedValue::set(7_001);
+		run_to_block(13);
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::SafeModeEntered {
+			triggers: vec![SafeModeTrigger::ExcessiveLiquidation {
+				liquidated_value: 1_001,
+			}],
+		}));
+		assert_eq!(SafeModeModule::state(), active(13, None));
+
+		// cleared when the window ends
+		run_to_block(22);
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::ConditionsCleared {
+			exit_at: 22 + COOLDOWN,
+		}));
+		assert_eq!(SafeModeModule::liquidation_window(), Some((22, 7_001)));
+	});
+}
+
+#[test]
+fn exit_safe_mode_after_cooldown() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_safe_mode_calls();
+		set_params(TriggerParams {
+			max_peg_deviation_bps: Some(500),
+			..Default::default()
+		});
+		// the call paused by governance stays paused
+		assert_ok!(MockTransactionPauser::pause_transaction(
+			b"Homa".to_vec(),
+			b"fast_match_redeems".to_vec()
+		));
+
+		PegDeviationBps::set(Some(600));
+		run_to_block(2);
+		assert_eq!(SafeModeModule::state(), active(2, None));
+		assert_eq!(
+			SafeModeModule::paused_by_safe_mode().into_inner(),
+			vec![call("Honzon", "adjust_loan")]
+		);
+
+		PegDeviationBps::set(Some(100));
+		run_to_block(3);
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::ConditionsCleared {
+			exit_at: 3 + COOLDOWN,
+		}));
+		assert_eq!(SafeModeModule::state(), active(2, Some(3)));
+
+		// the cooldown restarts when the conditions recur
+		PegDeviationBps::set(Some(600));
+		run_to_block(5);
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::ConditionsRecurred {
+			triggers: vec![SafeModeTrigger::PegDeviation { deviation_bps: 600 }],
+		}));
+		assert_eq!(SafeModeModule::state(), active(2, None));
+
+		PegDeviationBps::set(None);
+		run_to_block(6);
+		assert_eq!(SafeModeModule::state(), active(2, Some(6)));
+
+		run_to_block(6 + COOLDOWN - 1);
+		assert!(SafeModeModule::is_active());
+
+		run_to_block(6 + COOLDOWN);
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::SafeModeExited {
+			by_governance: false,
+		}));
+		assert_eq!(SafeModeModule::state(), SafeModeState::Inactive);
+		assert!(!<SafeModeModule as SafeMode>::is_active());
+		assert_eq!(PausedCalls::get(), vec![call("Homa", "fast_match_redeems")]);
+		assert!(SafeModeModule::paused_by_safe_mode().is_empty());
+	});
+}
+
+#[test]
+fn exit_safe_mode_by_governance_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_safe_mode_calls();
+		assert_noop!(
+			SafeModeModule::exit_safe_mode(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::SafeModeInactive
+		);
+
+		set_params(TriggerParams {
+			max_peg_deviation_bps: Some(500),
+			..Default::default()
+		});
+		PegDeviationBps::set(Some(600));
+		run_to_block(2);
+		assert!(SafeModeModule::is_active());
+
+		assert_noop!(SafeModeModule::exit_safe_mode(RuntimeOrigin::signed(BOB)), BadOrigin);
+		assert_ok!(SafeModeModule::exit_safe_mode(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::SafeModeModule(Event::SafeModeExited {
+			by_governance: true,
+		}));
+		assert_eq!(SafeModeModule::state(), SafeModeState::Inactive);
+		assert!(PausedCalls::get().is_empty());
+
+		// entered again if the conditions still hold
+		run_to_block(3);
+		assert_eq!(SafeModeModule::state(), active(3, None));
+
+		// unless the trigger params are relaxed as well
+		assert_ok!(SafeModeModule::exit_safe_mode(RuntimeOrigin::signed(ALICE)));
+		set_params(TriggerParams {
+			max_peg_deviation_bps: Some(1_000),
+			..Default::default()
+		});
+		run_to_block(4);
+		assert_eq!(SafeModeModule::state(), SafeModeState::Inactive);
+	});
+}
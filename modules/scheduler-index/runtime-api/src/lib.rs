@@ -0,0 +1,53 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{codec::Codec, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// A call pending in the agenda of the scheduler.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ScheduledCallInfo<BlockNumber, PalletsOrigin, Hash> {
+	/// The block at which the call is dispatched.
+	pub when: BlockNumber,
+	/// The index of the call in the agenda of the block.
+	pub index: u32,
+	/// The name of the task, `None` if scheduled anonymously.
+	pub maybe_id: Option<[u8; 32]>,
+	/// The origin with which the call is dispatched.
+	pub origin: PalletsOrigin,
+	pub call_hash: Hash,
+	/// The pallet name of the call, `None` if the preimage is not available.
+	pub pallet_name: Option<Vec<u8>>,
+	/// The function name of the call, `None` if the preimage is not available.
+	pub call_name: Option<Vec<u8>>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait SchedulerIndexApi<BlockNumber, PalletsOrigin, Hash> where
+		BlockNumber: Codec,
+		PalletsOrigin: Codec,
+		Hash: Codec,
+	{
+		fn pending_scheduled_calls() -> Vec<ScheduledCallInfo<BlockNumber, PalletsOrigin, Hash>>;
+	}
+}
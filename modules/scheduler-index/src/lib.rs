@@ -0,0 +1,428 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Scheduler Index Module
+//!
+//! ## Overview
+//!
+//! Keeps a reverse index from the call hash to the agenda slots of `pallet_scheduler`, so that a
+//! pending scheduled call can be audited and cancelled by its hash, without knowing the block
+//! and the index of its slot.
+//!
+//! The index is maintained by `IndexedScheduler`, which wraps `pallet_scheduler` and should be
+//! the scheduler of the pallets scheduling calls on behalf of the governance, e.g.
+//! `orml_authority` and `pallet_democracy`. The calls scheduled by the extrinsics of
+//! `pallet_scheduler` are not indexed, and the periodic calls are only indexed for their first
+//! dispatch. The index entries are checked against the agenda before use and the stale ones are
+//! pruned on idle.
+//!
+//! `pending_scheduled_calls` lists all the calls in the agenda, indexed or not, with the names of
+//! the calls decoded when the preimages are available.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		schedule::{
+			v3::{Anon as ScheduleAnon, Named as ScheduleNamed, TaskName},
+			DispatchTime, Period, Priority,
+		},
+		Bounded, CallMetadata, GetCallMetadata, QueryPreimage,
+	},
+};
+use frame_system::pallet_prelude::*;
+pub use module_scheduler_index_runtime_api::ScheduledCallInfo;
+use pallet_scheduler::{Agenda, TaskAddress};
+use sp_std::{marker::PhantomData, prelude::*, vec::Vec};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type CallOf<T> = <T as pallet_scheduler::Config>::RuntimeCall;
+pub type BoundedCallOf<T> = Bounded<CallOf<T>, <T as frame_system::Config>::Hashing>;
+pub type TaskAddressOf<T> = TaskAddress<BlockNumberFor<T>>;
+
+/// The maximum number of index entries checked in one `on_idle`.
+pub const MAX_PRUNED_PER_IDLE: u32 = 100;
+
+/// The fields of `pallet_scheduler::Scheduled` are private, they are decoded from its encoding.
+#[derive(Decode)]
+struct ScheduledView<Call, BlockNumber, PalletsOrigin> {
+	maybe_id: Option<TaskName>,
+	_priority: Priority,
+	call: Call,
+	_maybe_periodic: Option<Period<BlockNumber>>,
+	origin: PalletsOrigin,
+}
+
+type ScheduledViewOf<T> =
+	ScheduledView<BoundedCallOf<T>, BlockNumberFor<T>, <T as pallet_scheduler::Config>::PalletsOrigin>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_scheduler::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type, the scheduled calls are decoded to it to get their call
+		/// metadata.
+		type RuntimeCall: Parameter + GetCallMetadata;
+
+		/// The origin which may cancel the scheduled calls by hash.
+		type CancelOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The maximum number of scheduled calls cancelled by hash at once.
+		#[pallet::constant]
+		type MaxCancelledByHash: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No pending scheduled call with the hash.
+		ScheduledCallNotFound,
+		/// Multiple pending scheduled calls with the hash, `all` is required to cancel them.
+		MultipleMatches,
+		/// More pending scheduled calls with the hash than `MaxCancelledByHash`.
+		TooManyMatches,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A scheduled call is cancelled by its hash.
+		ScheduledCallCancelled {
+			call_hash: T::Hash,
+			when: BlockNumberFor<T>,
+			index: u32,
+		},
+	}
+
+	/// The agenda slots of the calls scheduled by `IndexedScheduler`, by the call hash.
+	///
+	/// ScheduledByHash: double_map CallHash, TaskAddress => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_by_hash)]
+	pub type ScheduledByHash<T: Config> =
+		StorageDoubleMap<_, Identity, T::Hash, Twox64Concat, TaskAddressOf<T>, (), OptionQuery>;
+
+	/// The raw key of `ScheduledByHash` at which the pruning on idle continues.
+	///
+	/// PruneCursor: Option<Vec<u8>>
+	#[pallet::storage]
+	#[pallet::getter(fn prune_cursor)]
+	pub type PruneCursor<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let max_count = remaining_weight
+				.checked_div_per_component(&<T as Config>::WeightInfo::prune_stale_entries(1))
+				.unwrap_or_default()
+				.min(MAX_PRUNED_PER_IDLE as u64) as u32;
+			if max_count == 0 {
+				return Weight::zero();
+			}
+
+			let count = Self::prune_stale_entries(max_count);
+			<T as Config>::WeightInfo::prune_stale_entries(count)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Cancel the pending scheduled call with the hash.
+		///
+		/// The dispatch origin of this call must be `CancelOrigin`.
+		///
+		/// - `call_hash`: the hash of the scheduled call.
+		/// - `all`: cancel all the pending calls with the hash, otherwise fails if there are
+		///   multiple matches.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_scheduled_by_hash(T::MaxCancelledByHash::get()))]
+		pub fn cancel_scheduled_by_hash(
+			origin: OriginFor<T>,
+			call_hash: T::Hash,
+			all: bool,
+		) -> DispatchResultWithPostInfo {
+			T::CancelOrigin::ensure_origin(origin)?;
+
+			let addresses = Self::pending_addresses(call_hash);
+			ensure!(!addresses.is_empty(), Error::<T>::ScheduledCallNotFound);
+			ensure!(all || addresses.len() == 1, Error::<T>::MultipleMatches);
+			ensure!(
+				addresses.len() as u32 <= T::MaxCancelledByHash::get(),
+				Error::<T>::TooManyMatches
+			);
+
+			for (when, index) in addresses.iter().copied() {
+				<pallet_scheduler::Pallet<T> as ScheduleAnon<_, _, _>>::cancel((when, index))?;
+				ScheduledByHash::<T>::remove(call_hash, (when, index));
+				Self::deposit_event(Event::ScheduledCallCancelled { call_hash, when, index });
+			}
+
+			Ok(Some(<T as Config>::WeightInfo::cancel_scheduled_by_hash(
+				addresses.len() as u32
+			))
+			.into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// All the calls pending in the agenda of the scheduler, ordered by their dispatch time.
+	pub fn pending_scheduled_calls(
+	) -> Vec<ScheduledCallInfo<BlockNumberFor<T>, <T as pallet_scheduler::Config>::PalletsOrigin, T::Hash>> {
+		let mut calls: Vec<_> = Agenda::<T>::iter()
+			.flat_map(|(when, agenda)| {
+				agenda
+					.into_iter()
+					.enumerate()
+					.filter_map(move |(index, maybe_scheduled)| {
+						let scheduled = ScheduledViewOf::<T>::decode(&mut &maybe_scheduled?.encode()[..]).ok()?;
+						let (pallet_name, call_name) = Self::call_names(&scheduled.call).unzip();
+						Some(ScheduledCallInfo {
+							when,
+							index: index as u32,
+							maybe_id: scheduled.maybe_id,
+							origin: scheduled.origin,
+							call_hash: scheduled.call.hash(),
+							pallet_name,
+							call_name,
+						})
+					})
+			})
+			.collect();
+		calls.sort_by_key(|info| (info.when, info.index));
+		calls
+	}
+
+	/// The indexed agenda slots which still hold the call with the hash.
+	pub fn pending_addresses(call_hash: T::Hash) -> Vec<TaskAddressOf<T>> {
+		ScheduledByHash::<T>::iter_key_prefix(call_hash)
+			.filter(|address| Self::call_hash_at(*address) == Some(call_hash))
+			.collect()
+	}
+
+	/// Remove at most `max_count` index entries whose slots no longer hold the indexed calls,
+	/// continuing from the last pruned entry. Returns the number of checked entries.
+	pub fn prune_stale_entries(max_count: u32) -> u32 {
+		let mut iter = match Self::prune_cursor() {
+			Some(cursor) => ScheduledByHash::<T>::iter_from(cursor.into_inner()),
+			None => ScheduledByHash::<T>::iter(),
+		};
+
+		let mut count: u32 = 0;
+		while count < max_count {
+			let Some((call_hash, address, _)) = iter.next() else {
+				break;
+			};
+			count += 1;
+			if Self::call_hash_at(address) != Some(call_hash) {
+				ScheduledByHash::<T>::remove(call_hash, address);
+			}
+		}
+
+		// restart from the beginning once the end is reached.
+		match BoundedVec::try_from(iter.last_raw_key().to_vec()) {
+			Ok(cursor) if count == max_count => PruneCursor::<T>::put(cursor),
+			_ => PruneCursor::<T>::kill(),
+		}
+		count
+	}
+
+	fn scheduled_at(address: TaskAddressOf<T>) -> Option<ScheduledViewOf<T>> {
+		Agenda::<T>::get(address.0)
+			.get(address.1 as usize)
+			.and_then(|maybe_scheduled| maybe_scheduled.as_ref())
+			.and_then(|scheduled| ScheduledViewOf::<T>::decode(&mut &scheduled.encode()[..]).ok())
+	}
+
+	fn call_hash_at(address: TaskAddressOf<T>) -> Option<T::Hash> {
+		Self::scheduled_at(address).map(|scheduled| scheduled.call.hash())
+	}
+
+	fn named_address(id: TaskName) -> Option<TaskAddressOf<T>> {
+		let when = <pallet_scheduler::Pallet<T> as ScheduleNamed<_, _, _>>::next_dispatch_time(id).ok()?;
+		Agenda::<T>::get(when)
+			.iter()
+			.position(|maybe_scheduled| {
+				maybe_scheduled
+					.as_ref()
+					.and_then(|scheduled| ScheduledViewOf::<T>::decode(&mut &scheduled.encode()[..]).ok())
+					.map_or(false, |scheduled| scheduled.maybe_id == Some(id))
+			})
+			.map(|index| (when, index as u32))
+	}
+
+	/// The pallet and function names of the call, `None` if the preimage is not available.
+	fn call_names(call: &BoundedCallOf<T>) -> Option<(Vec<u8>, Vec<u8>)> {
+		let data = match call {
+			Bounded::Inline(data) => data.to_vec(),
+			Bounded::Lookup { hash, len } => <T as pallet_scheduler::Config>::Preimages::fetch(hash, Some(*len))
+				.ok()?
+				.into_owned(),
+			Bounded::Legacy { hash, .. } => <T as pallet_scheduler::Config>::Preimages::fetch(hash, None)
+				.ok()?
+				.into_owned(),
+		};
+		let CallMetadata {
+			function_name,
+			pallet_name,
+		} = <T as Config>::RuntimeCall::decode(&mut &data[..])
+			.ok()?
+			.get_call_metadata();
+		Some((pallet_name.as_bytes().to_vec(), function_name.as_bytes().to_vec()))
+	}
+
+	fn on_scheduled(call_hash: T::Hash, address: TaskAddressOf<T>) {
+		ScheduledByHash::<T>::insert(call_hash, address, ());
+	}
+
+	fn on_cancelled(maybe_call_hash: Option<T::Hash>, address: TaskAddressOf<T>) {
+		if let Some(call_hash) = maybe_call_hash {
+			ScheduledByHash::<T>::remove(call_hash, address);
+		}
+	}
+
+	fn on_rescheduled(maybe_call_hash: Option<T::Hash>, address: TaskAddressOf<T>, new_address: TaskAddressOf<T>) {
+		if let Some(call_hash) = maybe_call_hash {
+			if ScheduledByHash::<T>::take(call_hash, address).is_some() {
+				ScheduledByHash::<T>::insert(call_hash, new_address, ());
+			}
+		}
+	}
+}
+
+/// `pallet_scheduler` maintaining the index from the call hash to the agenda slots.
+pub struct IndexedScheduler<T>(PhantomData<T>);
+
+impl<T: Config> ScheduleAnon<BlockNumberFor<T>, CallOf<T>, <T as pallet_scheduler::Config>::PalletsOrigin>
+	for IndexedScheduler<T>
+{
+	type Address = TaskAddressOf<T>;
+	type Hasher = T::Hashing;
+
+	fn schedule(
+		when: DispatchTime<BlockNumberFor<T>>,
+		maybe_periodic: Option<Period<BlockNumberFor<T>>>,
+		priority: Priority,
+		origin: <T as pallet_scheduler::Config>::PalletsOrigin,
+		call: BoundedCallOf<T>,
+	) -> Result<Self::Address, DispatchError> {
+		let call_hash = call.hash();
+		let address = <pallet_scheduler::Pallet<T> as ScheduleAnon<_, _, _>>::schedule(
+			when,
+			maybe_periodic,
+			priority,
+			origin,
+			call,
+		)?;
+		Pallet::<T>::on_scheduled(call_hash, address);
+		Ok(address)
+	}
+
+	fn cancel(address: Self::Address) -> Result<(), DispatchError> {
+		let maybe_call_hash = Pallet::<T>::call_hash_at(address);
+		<pallet_scheduler::Pallet<T> as ScheduleAnon<_, _, _>>::cancel(address)?;
+		Pallet::<T>::on_cancelled(maybe_call_hash, address);
+		Ok(())
+	}
+
+	fn reschedule(
+		address: Self::Address,
+		when: DispatchTime<BlockNumberFor<T>>,
+	) -> Result<Self::Address, DispatchError> {
+		let maybe_call_hash = Pallet::<T>::call_hash_at(address);
+		let new_address = <pallet_scheduler::Pallet<T> as ScheduleAnon<_, _, _>>::reschedule(address, when)?;
+		Pallet::<T>::on_rescheduled(maybe_call_hash, address, new_address);
+		Ok(new_address)
+	}
+
+	fn next_dispatch_time(address: Self::Address) -> Result<BlockNumberFor<T>, DispatchError> {
+		<pallet_scheduler::Pallet<T> as ScheduleAnon<_, _, _>>::next_dispatch_time(address)
+	}
+}
+
+impl<T: Config> ScheduleNamed<BlockNumberFor<T>, CallOf<T>, <T as pallet_scheduler::Config>::PalletsOrigin>
+	for IndexedScheduler<T>
+{
+	type Address = TaskAddressOf<T>;
+	type Hasher = T::Hashing;
+
+	fn schedule_named(
+		id: TaskName,
+		when: DispatchTime<BlockNumberFor<T>>,
+		maybe_periodic: Option<Period<BlockNumberFor<T>>>,
+		priority: Priority,
+		origin: <T as pallet_scheduler::Config>::PalletsOrigin,
+		call: BoundedCallOf<T>,
+	) -> Result<Self::Address, DispatchError> {
+		let call_hash = call.hash();
+		let address = <pallet_scheduler::Pallet<T> as ScheduleNamed<_, _, _>>::schedule_named(
+			id,
+			when,
+			maybe_periodic,
+			priority,
+			origin,
+			call,
+		)?;
+		Pallet::<T>::on_scheduled(call_hash, address);
+		Ok(address)
+	}
+
+	fn cancel_named(id: TaskName) -> Result<(), DispatchError> {
+		let maybe_address = Pallet::<T>::named_address(id);
+		let maybe_call_hash = maybe_address.and_then(Pallet::<T>::call_hash_at);
+		<pallet_scheduler::Pallet<T> as ScheduleNamed<_, _, _>>::cancel_named(id)?;
+		if let Some(address) = maybe_address {
+			Pallet::<T>::on_cancelled(maybe_call_hash, address);
+		}
+		Ok(())
+	}
+
+	fn reschedule_named(id: TaskName, when: DispatchTime<BlockNumberFor<T>>) -> Result<Self::Address, DispatchError> {
+		let maybe_address = Pallet::<T>::named_address(id);
+		let maybe_call_hash = maybe_address.and_then(Pallet::<T>::call_hash_at);
+		let new_address = <pallet_scheduler::Pallet<T> as ScheduleNamed<_, _, _>>::reschedule_named(id, when)?;
+		if let Some(address) = maybe_address {
+			Pallet::<T>::on_rescheduled(maybe_call_hash, address, new_address);
+		}
+		Ok(new_address)
+	}
+
+	fn next_dispatch_time(id: TaskName) -> Result<BlockNumberFor<T>, DispatchError> {
+		<pallet_scheduler::Pallet<T> as ScheduleNamed<_, _, _>>::next_dispatch_time(id)
+	}
+}
@@ -0,0 +1,304 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the scheduler index module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, StorePreimage},
+};
+use mock::{RuntimeCall, RuntimeEvent, *};
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash};
+
+fn remark(remark: Vec<u8>) -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark })
+}
+
+fn root() -> OriginCaller {
+	OriginCaller::system(frame_system::RawOrigin::Root)
+}
+
+fn schedule(when: BlockNumber, call: BoundedCallOf<Runtime>) -> TaskAddressOf<Runtime> {
+	<IndexedScheduler<Runtime> as ScheduleAnon<_, _, _>>::schedule(DispatchTime::At(when), None, 0, root(), call)
+		.unwrap()
+}
+
+fn schedule_named(id: TaskName, when: BlockNumber, call: BoundedCallOf<Runtime>) -> TaskAddressOf<Runtime> {
+	<IndexedScheduler<Runtime> as ScheduleNamed<_, _, _>>::schedule_named(
+		id,
+		DispatchTime::At(when),
+		None,
+		0,
+		root(),
+		call,
+	)
+	.unwrap()
+}
+
+fn bound(call: RuntimeCall) -> BoundedCallOf<Runtime> {
+	Preimage::bound(call).unwrap()
+}
+
+fn indexed(call_hash: <Runtime as frame_system::Config>::Hash) -> Vec<TaskAddressOf<Runtime>> {
+	let mut addresses: Vec<_> = ScheduledByHash::<Runtime>::iter_key_prefix(call_hash).collect();
+	addresses.sort();
+	addresses
+}
+
+#[test]
+fn index_maintained_by_indexed_scheduler() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = bound(remark(vec![1]));
+		let call_hash = call.hash();
+
+		let address = schedule(10, call.clone());
+		assert_eq!(address, (10, 0));
+		let named_address = schedule_named([1u8; 32], 20, call.clone());
+		assert_eq!(named_address, (20, 0));
+		assert_eq!(indexed(call_hash), vec![(10, 0), (20, 0)]);
+
+		// moved with the rescheduled calls
+		assert_eq!(
+			<IndexedScheduler<Runtime> as ScheduleAnon<_, _, _>>::reschedule(address, DispatchTime::At(15)),
+			Ok((15, 0))
+		);
+		assert_eq!(
+			<IndexedScheduler<Runtime> as ScheduleNamed<_, _, _>>::reschedule_named([1u8; 32], DispatchTime::At(25)),
+			Ok((25, 0))
+		);
+		assert_eq!(indexed(call_hash), vec![(15, 0), (25, 0)]);
+
+		// removed with the cancelled calls
+		assert_ok!(<IndexedScheduler<Runtime> as ScheduleNamed<_, _, _>>::cancel_named(
+			[1u8; 32]
+		));
+		assert_eq!(indexed(call_hash), vec![(15, 0)]);
+		assert_ok!(<IndexedScheduler<Runtime> as ScheduleAnon<_, _, _>>::cancel((15, 0)));
+		assert_eq!(indexed(call_hash), vec![]);
+	});
+}
+
+#[test]
+fn pending_scheduled_calls_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let inline_call = bound(remark(vec![1]));
+		schedule_named([1u8; 32], 20, inline_call.clone());
+
+		// the preimage of a large call is not provided yet
+		let encoded_call = remark(vec![0u8; 200]).encode();
+		let lookup_call = Bounded::Lookup {
+			hash: BlakeTwo256::hash(&encoded_call),
+			len: encoded_call.len() as u32,
+		};
+		schedule(10, lookup_call.clone());
+
+		// not indexed, but still pending in the agenda
+		assert_ok!(Scheduler::schedule(
+			RuntimeOrigin::root(),
+			30,
+			None,
+			0,
+			Box::new(remark(vec![2]))
+		));
+
+		assert_eq!(
+			SchedulerIndex::pending_scheduled_calls(),
+			vec![
+				ScheduledCallInfo {
+					when: 10,
+					index: 0,
+					maybe_id: None,
+					origin: root(),
+					call_hash: lookup_call.hash(),
+					pallet_name: None,
+					call_name: None,
+				},
+				ScheduledCallInfo {
+					when: 20,
+					index: 0,
+					maybe_id: Some([1u8; 32]),
+					origin: root(),
+					call_hash: inline_call.hash(),
+					pallet_name: Some(b"System".to_vec()),
+					call_name: Some(b"remark".to_vec()),
+				},
+				ScheduledCallInfo {
+					when: 30,
+					index: 0,
+					maybe_id: None,
+					origin: root(),
+					call_hash: bound(remark(vec![2])).hash(),
+					pallet_name: Some(b"System".to_vec()),
+					call_name: Some(b"remark".to_vec()),
+				},
+			]
+		);
+
+		// decoded once the preimage is provided
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(ALICE), encoded_call));
+		let calls = SchedulerIndex::pending_scheduled_calls();
+		assert_eq!(calls[0].pallet_name, Some(b"System".to_vec()));
+		assert_eq!(calls[0].call_name, Some(b"remark".to_vec()));
+	});
+}
+
+#[test]
+fn cancel_scheduled_by_hash_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = bound(remark(vec![1]));
+		let call_hash = call.hash();
+
+		assert_noop!(
+			SchedulerIndex::cancel_scheduled_by_hash(RuntimeOrigin::signed(ALICE), call_hash, false),
+			BadOrigin
+		);
+		assert_noop!(
+			SchedulerIndex::cancel_scheduled_by_hash(RuntimeOrigin::root(), call_hash, false),
+			Error::<Runtime>::ScheduledCallNotFound
+		);
+
+		schedule_named([1u8; 32], 10, call);
+		assert_ok!(SchedulerIndex::cancel_scheduled_by_hash(
+			RuntimeOrigin::root(),
+			call_hash,
+			false
+		));
+		System::assert_last_event(RuntimeEvent::SchedulerIndex(crate::Event::ScheduledCallCancelled {
+			call_hash,
+			when: 10,
+			index: 0,
+		}));
+		assert_eq!(indexed(call_hash), vec![]);
+		assert!(SchedulerIndex::pending_scheduled_calls().is_empty());
+	});
+}
+
+#[test]
+fn cancel_one_among_duplicates() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = bound(remark(vec![1]));
+		let call_hash = call.hash();
+		let other_call = bound(remark(vec![2]));
+
+		let address = schedule(10, call.clone());
+		schedule(10, other_call.clone());
+		schedule(20, call.clone());
+		assert_eq!(indexed(call_hash), vec![(10, 0), (20, 0)]);
+
+		assert_noop!(
+			SchedulerIndex::cancel_scheduled_by_hash(RuntimeOrigin::root(), call_hash, false),
+			Error::<Runtime>::MultipleMatches
+		);
+
+		// one of the duplicates is cancelled by its address
+		assert_ok!(<IndexedScheduler<Runtime> as ScheduleAnon<_, _, _>>::cancel(address));
+		assert_ok!(SchedulerIndex::cancel_scheduled_by_hash(
+			RuntimeOrigin::root(),
+			call_hash,
+			false
+		));
+		System::assert_last_event(RuntimeEvent::SchedulerIndex(crate::Event::ScheduledCallCancelled {
+			call_hash,
+			when: 20,
+			index: 0,
+		}));
+
+		// the other call is not affected
+		let calls = SchedulerIndex::pending_scheduled_calls();
+		assert_eq!(calls.len(), 1);
+		assert_eq!((calls[0].when, calls[0].index), (10, 1));
+		assert_eq!(calls[0].call_hash, other_call.hash());
+		assert_eq!(indexed(other_call.hash()), vec![(10, 1)]);
+	});
+}
+
+#[test]
+fn cancel_all_duplicates() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = bound(remark(vec![1]));
+		let call_hash = call.hash();
+
+		for when in 10..14 {
+			schedule(when, call.clone());
+		}
+		assert_noop!(
+			SchedulerIndex::cancel_scheduled_by_hash(RuntimeOrigin::root(), call_hash, true),
+			Error::<Runtime>::TooManyMatches
+		);
+
+		assert_ok!(<IndexedScheduler<Runtime> as ScheduleAnon<_, _, _>>::cancel((13, 0)));
+		assert_ok!(SchedulerIndex::cancel_scheduled_by_hash(
+			RuntimeOrigin::root(),
+			call_hash,
+			true
+		));
+		for when in 10..13 {
+			System::assert_has_event(RuntimeEvent::SchedulerIndex(crate::Event::ScheduledCallCancelled {
+				call_hash,
+				when,
+				index: 0,
+			}));
+		}
+		assert_eq!(indexed(call_hash), vec![]);
+		assert!(SchedulerIndex::pending_scheduled_calls().is_empty());
+	});
+}
+
+#[test]
+fn stale_entries_are_ignored_and_pruned() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = bound(remark(vec![1]));
+		let call_hash = call.hash();
+		let other_call = bound(remark(vec![2]));
+
+		schedule(2, call.clone());
+		schedule(10, other_call.clone());
+
+		// the call is dispatched, the index entry is stale
+		System::set_block_number(2);
+		Scheduler::on_initialize(2);
+		assert_eq!(indexed(call_hash), vec![(2, 0)]);
+		assert_noop!(
+			SchedulerIndex::cancel_scheduled_by_hash(RuntimeOrigin::root(), call_hash, false),
+			Error::<Runtime>::ScheduledCallNotFound
+		);
+
+		// pruned in batches
+		assert_eq!(SchedulerIndex::prune_stale_entries(1), 1);
+		assert!(SchedulerIndex::prune_cursor().is_some());
+		assert_eq!(SchedulerIndex::prune_stale_entries(1), 1);
+		assert_eq!(SchedulerIndex::prune_stale_entries(1), 0);
+		assert_eq!(SchedulerIndex::prune_cursor(), None);
+		assert_eq!(indexed(call_hash), vec![]);
+		assert_eq!(indexed(other_call.hash()), vec![(10, 0)]);
+
+		// pruned on idle
+		schedule(3, call.clone());
+		System::set_block_number(3);
+		Scheduler::on_initialize(3);
+		assert_eq!(
+			SchedulerIndex::on_idle(3, Weight::MAX),
+			<Runtime as Config>::WeightInfo::prune_stale_entries(2)
+		);
+		assert_eq!(indexed(call_hash), vec![]);
+		assert_eq!(SchedulerIndex::on_idle(3, Weight::zero()), Weight::zero());
+	});
+}
@@ -0,0 +1,489 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Session Keys Module
+//!
+//! ## Overview
+//!
+//! Session keys let the dApps, such as games, submit transactions on behalf of an account
+//! without prompting its wallet for each of them. The primary account registers a temporary key
+//! which may only dispatch the allowed calls, until the expiry and within the spend cap.
+//!
+//! The session key signs `session_call`, which dispatches the wrapped call with the signed
+//! origin of the primary account, filtered by the allowed call patterns. The filter also applies
+//! to the calls nested in batches. The transaction fee is paid by the primary account by
+//! `TransactionFeePayer`. Both the fee and the native currency spent by the dispatched call count
+//! towards the spend cap, the call is reverted if it exceeds the cap. `CheckSessionKey` rejects
+//! the session calls of unknown or expired keys and of disallowed calls from the transaction pool.
+//!
+//! The call patterns use the pallet and function names of the transaction pause module, with an
+//! optional constraint on the contract address targeted by the EVM calls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::*,
+	traits::{CallMetadata, Currency, GetCallMetadata, IsSubType, OriginTrait, PalletInfoAccess, ReservableCurrency},
+};
+use frame_system::pallet_prelude::*;
+use module_support::TransactionFeePayer;
+use primitives::{evm::EvmAddress, Balance};
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, Saturating, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+	SaturatedConversion,
+};
+use sp_std::{boxed::Box, prelude::*};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The session key is not registered.
+pub const UNKNOWN_SESSION_KEY: u8 = 1;
+/// The session key is expired.
+pub const SESSION_KEY_EXPIRED: u8 = 2;
+/// The call is not allowed for the session key.
+pub const CALL_NOT_ALLOWED: u8 = 3;
+
+/// A call a session key is allowed to dispatch.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CallPattern {
+	pub pallet_name: Vec<u8>,
+	pub function_name: Vec<u8>,
+	/// The contract address the EVM call must target, `None` if not constrained.
+	pub evm_target: Option<EvmAddress>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SessionKeyInfo<AccountId, BlockNumber> {
+	/// The account on behalf of which the session key dispatches calls.
+	pub primary: AccountId,
+	pub allowed_calls: Vec<CallPattern>,
+	/// The session key is valid before this block number.
+	pub expiry: BlockNumber,
+	/// The maximum native currency spent by the session key, including the transaction fees.
+	pub spend_cap: Balance,
+	pub spent: Balance,
+	/// The deposit reserved from the primary account.
+	pub deposit: Balance,
+}
+
+/// Inspects the contract address targeted by the EVM calls.
+pub trait EvmTargetInspector<Call> {
+	/// The contract address `call` targets, `None` if it is not an EVM call.
+	fn evm_target(call: &Call) -> Option<EvmAddress>;
+}
+
+impl<Call> EvmTargetInspector<Call> for () {
+	fn evm_target(_call: &Call) -> Option<EvmAddress> {
+		None
+	}
+}
+
+pub type SessionKeyInfoOf<T> = SessionKeyInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The runtime call dispatched by the session keys.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ GetCallMetadata
+			+ IsSubType<Call<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The native currency, to reserve the deposit and to measure the spending.
+		type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+		/// Inspects the contract address targeted by the EVM calls.
+		type EvmTarget: EvmTargetInspector<<Self as Config>::RuntimeCall>;
+
+		/// The deposit reserved from the primary account for a session key.
+		#[pallet::constant]
+		type SessionKeyDeposit: Get<Balance>;
+
+		/// The maximum number of blocks a session key is valid for.
+		#[pallet::constant]
+		type MaxSessionDuration: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of the allowed call patterns of a session key.
+		#[pallet::constant]
+		type MaxCallPatterns: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The session key is already registered.
+		SessionKeyAlreadyRegistered,
+		/// The session key is not registered.
+		SessionKeyNotFound,
+		/// The primary account can't be its own session key.
+		InvalidSessionKey,
+		/// The expiry must be after the current block and within `MaxSessionDuration`.
+		InvalidExpiry,
+		/// Too many allowed call patterns.
+		TooManyCallPatterns,
+		/// Invalid character encoding of the pallet or function name.
+		InvalidCharacter,
+		/// The pallet and function names don't match any call of the runtime.
+		CallNotFound,
+		/// The calls of this module can't be allowed.
+		CannotAllowCall,
+		/// The call is not allowed for the session key.
+		CallNotAllowed,
+		/// Only the primary account can revoke the unexpired session key.
+		NotPrimaryAccount,
+		/// The session key is expired.
+		SessionKeyExpired,
+		/// The spend cap of the session key is exceeded.
+		SpendCapExceeded,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A session key is registered.
+		SessionKeyRegistered {
+			primary: T::AccountId,
+			key: T::AccountId,
+			expiry: BlockNumberFor<T>,
+			spend_cap: Balance,
+		},
+		/// A session key is revoked.
+		SessionKeyRevoked { primary: T::AccountId, key: T::AccountId },
+		/// A call is dispatched by a session key on behalf of the primary account.
+		SessionCallDispatched {
+			primary: T::AccountId,
+			key: T::AccountId,
+			spent: Balance,
+		},
+	}
+
+	/// The registered session keys.
+	///
+	/// SessionKeys: map AccountId => Option<SessionKeyInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn session_keys)]
+	pub type SessionKeys<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, SessionKeyInfoOf<T>, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a session key of the origin, which is the primary account.
+		///
+		/// - `key`: the session key account.
+		/// - `allowed_calls`: the calls the session key is allowed to dispatch.
+		/// - `expiry`: the session key is valid before this block number.
+		/// - `spend_cap`: the maximum native currency spent by the session key, including the
+		///   transaction fees.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_session_key(allowed_calls.len() as u32))]
+		pub fn register_session_key(
+			origin: OriginFor<T>,
+			key: T::AccountId,
+			allowed_calls: Vec<CallPattern>,
+			expiry: BlockNumberFor<T>,
+			spend_cap: Balance,
+		) -> DispatchResult {
+			let primary = ensure_signed(origin)?;
+			ensure!(key != primary, Error::<T>::InvalidSessionKey);
+			ensure!(
+				!SessionKeys::<T>::contains_key(&key),
+				Error::<T>::SessionKeyAlreadyRegistered
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				expiry > now && expiry <= now.saturating_add(T::MaxSessionDuration::get()),
+				Error::<T>::InvalidExpiry
+			);
+			ensure!(
+				allowed_calls.len() <= T::MaxCallPatterns::get() as usize,
+				Error::<T>::TooManyCallPatterns
+			);
+			for pattern in allowed_calls.iter() {
+				Self::ensure_call_allowable(&pattern.pallet_name, &pattern.function_name)?;
+			}
+
+			let deposit = T::SessionKeyDeposit::get();
+			T::Currency::reserve(&primary, deposit)?;
+			SessionKeys::<T>::insert(
+				&key,
+				SessionKeyInfo {
+					primary: primary.clone(),
+					allowed_calls,
+					expiry,
+					spend_cap,
+					spent: 0,
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::SessionKeyRegistered {
+				primary,
+				key,
+				expiry,
+				spend_cap,
+			});
+			Ok(())
+		}
+
+		/// Revoke a session key and unreserve the deposit. The primary account can revoke it at
+		/// any time, anyone can revoke it after the expiry.
+		///
+		/// - `key`: the session key account.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::revoke_session_key())]
+		pub fn revoke_session_key(origin: OriginFor<T>, key: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let info = SessionKeys::<T>::get(&key).ok_or(Error::<T>::SessionKeyNotFound)?;
+			ensure!(
+				who == info.primary || Self::is_expired(&info),
+				Error::<T>::NotPrimaryAccount
+			);
+
+			T::Currency::unreserve(&info.primary, info.deposit);
+			SessionKeys::<T>::remove(&key);
+			Self::deposit_event(Event::SessionKeyRevoked {
+				primary: info.primary,
+				key,
+			});
+			Ok(())
+		}
+
+		/// Dispatch `call` on behalf of the primary account of the origin, which is a session
+		/// key.
+		///
+		/// - `call`: the call to dispatch, it and the calls nested in it must be allowed.
+		#[pallet::call_index(2)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(T::WeightInfo::session_call().saturating_add(dispatch_info.weight), dispatch_info.class)
+		})]
+		pub fn session_call(origin: OriginFor<T>, call: Box<<T as Config>::RuntimeCall>) -> DispatchResultWithPostInfo {
+			let key = ensure_signed(origin)?;
+			let mut info = SessionKeys::<T>::get(&key).ok_or(Error::<T>::SessionKeyNotFound)?;
+			ensure!(!Self::is_expired(&info), Error::<T>::SessionKeyExpired);
+
+			let primary = info.primary.clone();
+			let balance_before = T::Currency::free_balance(&primary);
+			let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(primary.clone()).into();
+			let allowed_calls = info.allowed_calls.clone();
+			origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
+				Self::is_allowed(&allowed_calls, <T as Config>::RuntimeCall::from_ref(c))
+			});
+			let post_info = call.dispatch(origin).map_err(|e| e.error)?;
+
+			// the call is reverted if it exceeds the spend cap
+			let spent = balance_before.saturating_sub(T::Currency::free_balance(&primary));
+			info.spent = info.spent.saturating_add(spent);
+			ensure!(info.spent <= info.spend_cap, Error::<T>::SpendCapExceeded);
+			SessionKeys::<T>::insert(&key, info);
+
+			Self::deposit_event(Event::SessionCallDispatched { primary, key, spent });
+			Ok(post_info
+				.actual_weight
+				.map(|weight| weight.saturating_add(T::WeightInfo::session_call()))
+				.into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn is_expired(info: &SessionKeyInfoOf<T>) -> bool {
+		frame_system::Pallet::<T>::block_number() >= info.expiry
+	}
+
+	/// Ensure the pallet and function names match a call of the runtime, which is not a call of
+	/// this module.
+	fn ensure_call_allowable(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult {
+		let pallet_name = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		let function_name = sp_std::str::from_utf8(function_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		ensure!(
+			pallet_name != <Self as PalletInfoAccess>::name(),
+			Error::<T>::CannotAllowCall
+		);
+		// `get_call_names` only accepts the names of the pallets with calls
+		ensure!(
+			<T as Config>::RuntimeCall::get_module_names().contains(&pallet_name)
+				&& <T as Config>::RuntimeCall::get_call_names(pallet_name).contains(&function_name),
+			Error::<T>::CallNotFound
+		);
+		Ok(())
+	}
+
+	/// Whether `call` matches any of the allowed call patterns.
+	pub fn is_allowed(allowed_calls: &[CallPattern], call: &<T as Config>::RuntimeCall) -> bool {
+		let CallMetadata {
+			pallet_name,
+			function_name,
+		} = call.get_call_metadata();
+		allowed_calls.iter().any(|pattern| {
+			pattern.pallet_name == pallet_name.as_bytes()
+				&& pattern.function_name == function_name.as_bytes()
+				&& pattern
+					.evm_target
+					.map_or(true, |target| T::EvmTarget::evm_target(call) == Some(target))
+		})
+	}
+
+	/// Ensure the session key `who` may dispatch `call` now. Returns the session key info.
+	fn ensure_session_call(
+		who: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+	) -> Result<SessionKeyInfoOf<T>, Error<T>> {
+		let info = SessionKeys::<T>::get(who).ok_or(Error::<T>::SessionKeyNotFound)?;
+		ensure!(!Self::is_expired(&info), Error::<T>::SessionKeyExpired);
+		ensure!(Self::is_allowed(&info.allowed_calls, call), Error::<T>::CallNotAllowed);
+		Ok(info)
+	}
+
+	fn validate_session_call(
+		who: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+	) -> Result<SessionKeyInfoOf<T>, TransactionValidityError> {
+		Self::ensure_session_call(who, call).map_err(|e| {
+			let code = match e {
+				Error::<T>::SessionKeyExpired => SESSION_KEY_EXPIRED,
+				Error::<T>::CallNotAllowed => CALL_NOT_ALLOWED,
+				_ => UNKNOWN_SESSION_KEY,
+			};
+			InvalidTransaction::Custom(code).into()
+		})
+	}
+}
+
+impl<T: Config> TransactionFeePayer<T::AccountId, <T as Config>::RuntimeCall> for Pallet<T> {
+	/// The fee of `session_call` is paid by the primary account and counts towards the spend cap.
+	/// The fee before the refund of the unused weight is counted.
+	fn fee_payer(
+		who: &T::AccountId,
+		call: &<T as Config>::RuntimeCall,
+		fee: Balance,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		if let Some(Call::session_call { call }) = call.is_sub_type() {
+			let mut info = Self::ensure_session_call(who, call)?;
+			info.spent = info.spent.saturating_add(fee);
+			ensure!(info.spent <= info.spend_cap, Error::<T>::SpendCapExceeded);
+			let primary = info.primary.clone();
+			SessionKeys::<T>::insert(who, info);
+			return Ok(Some(primary));
+		}
+		Ok(None)
+	}
+}
+
+/// Validate the session calls signed by the session keys.
+///
+/// The session calls of unknown or expired session keys, and of the calls not allowed for the
+/// session keys are rejected. The longevity of the valid ones is bounded by the expiry.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckSessionKey<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckSessionKey<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckSessionKey")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> CheckSessionKey<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckSessionKey<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckSessionKey<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckSessionKey";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if let Some(Call::session_call { call }) = call.is_sub_type() {
+			let info = Pallet::<T>::validate_session_call(who, call)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			return Ok(ValidTransaction {
+				longevity: info.expiry.saturating_sub(now).saturated_into(),
+				..Default::default()
+			});
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		if let Some(Call::session_call { call }) = call.is_sub_type() {
+			Pallet::<T>::validate_session_call(who, call)?;
+		}
+		Ok(())
+	}
+}
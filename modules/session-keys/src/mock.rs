@@ -0,0 +1,190 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the session keys module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64},
+};
+use primitives::ReserveIdentifier;
+use sp_core::H160;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const GAME: AccountId = 10;
+
+pub const DEPOSIT: Balance = 100;
+pub const MAX_SESSION_DURATION: u64 = 100;
+
+pub const GAME_CONTRACT: EvmAddress = H160([1u8; 20]);
+pub const OTHER_CONTRACT: EvmAddress = H160([2u8; 20]);
+
+mod session_keys {
+	pub use super::super::*;
+}
+
+/// A minimal EVM module, whose `call` pays `value` to the contract owner.
+#[frame_support::pallet]
+pub mod mock_evm {
+	use frame_support::{pallet_prelude::*, traits::Currency};
+	use frame_system::pallet_prelude::*;
+	use primitives::{evm::EvmAddress, Balance};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Currency: Currency<Self::AccountId, Balance = Balance>;
+		type ContractOwner: Get<Self::AccountId>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::from_parts(1_000, 0))]
+		pub fn call(origin: OriginFor<T>, _target: EvmAddress, value: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Currency::transfer(
+				&who,
+				&T::ContractOwner::get(),
+				value,
+				frame_support::traits::ExistenceRequirement::AllowDeath,
+			)
+		}
+	}
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_utility::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ContractOwner: AccountId = GAME;
+}
+
+impl mock_evm::Config for Runtime {
+	type Currency = Balances;
+	type ContractOwner = ContractOwner;
+}
+
+pub struct MockEvmTarget;
+impl EvmTargetInspector<RuntimeCall> for MockEvmTarget {
+	fn evm_target(call: &RuntimeCall) -> Option<EvmAddress> {
+		match call {
+			RuntimeCall::MockEvm(mock_evm::Call::call { _target: target, .. }) => Some(*target),
+			_ => None,
+		}
+	}
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type EvmTarget = MockEvmTarget;
+	type SessionKeyDeposit = ConstU128<DEPOSIT>;
+	type MaxSessionDuration = ConstU64<MAX_SESSION_DURATION>;
+	type MaxCallPatterns = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Utility: pallet_utility,
+		MockEvm: mock_evm,
+		SessionKeysModule: session_keys,
+	}
+);
+
+pub fn pattern(pallet_name: &str, function_name: &str, evm_target: Option<EvmAddress>) -> CallPattern {
+	CallPattern {
+		pallet_name: pallet_name.as_bytes().to_vec(),
+		function_name: function_name.as_bytes().to_vec(),
+		evm_target,
+	}
+}
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(ALICE, 10_000), (BOB, 10_000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,369 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the session keys module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeCall, RuntimeEvent, *};
+
+fn evm_call(target: EvmAddress, value: Balance) -> RuntimeCall {
+	RuntimeCall::MockEvm(mock_evm::Call::call { _target: target, value })
+}
+
+fn transfer_call(dest: AccountId, value: Balance) -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest, value })
+}
+
+fn session_call(call: RuntimeCall) -> RuntimeCall {
+	RuntimeCall::SessionKeysModule(crate::Call::session_call { call: Box::new(call) })
+}
+
+fn register_game_session(allowed_calls: Vec<CallPattern>, spend_cap: Balance) {
+	assert_ok!(SessionKeysModule::register_session_key(
+		RuntimeOrigin::signed(ALICE),
+		CHARLIE,
+		allowed_calls,
+		11,
+		spend_cap
+	));
+}
+
+#[test]
+fn register_and_revoke_session_key_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let allowed_calls = vec![pattern("MockEvm", "call", Some(GAME_CONTRACT))];
+
+		assert_noop!(
+			SessionKeysModule::register_session_key(
+				RuntimeOrigin::signed(ALICE),
+				ALICE,
+				allowed_calls.clone(),
+				11,
+				500
+			),
+			Error::<Runtime>::InvalidSessionKey
+		);
+		assert_noop!(
+			SessionKeysModule::register_session_key(
+				RuntimeOrigin::signed(ALICE),
+				CHARLIE,
+				allowed_calls.clone(),
+				1,
+				500
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			SessionKeysModule::register_session_key(
+				RuntimeOrigin::signed(ALICE),
+				CHARLIE,
+				allowed_calls.clone(),
+				1 + MAX_SESSION_DURATION + 1,
+				500
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			SessionKeysModule::register_session_key(
+				RuntimeOrigin::signed(ALICE),
+				CHARLIE,
+				vec![pattern("MockEvm", "call", None); 4],
+				11,
+				500
+			),
+			Error::<Runtime>::TooManyCallPatterns
+		);
+		assert_noop!(
+			SessionKeysModule::register_session_key(
+				RuntimeOrigin::signed(ALICE),
+				CHARLIE,
+				vec![pattern("MockEvm", "create", None)],
+				11,
+				500
+			),
+			Error::<Runtime>::CallNotFound
+		);
+		assert_noop!(
+			SessionKeysModule::register_session_key(
+				RuntimeOrigin::signed(ALICE),
+				CHARLIE,
+				vec![pattern("SessionKeysModule", "register_session_key", None)],
+				11,
+				500
+			),
+			Error::<Runtime>::CannotAllowCall
+		);
+
+		register_game_session(allowed_calls.clone(), 500);
+		System::assert_last_event(RuntimeEvent::SessionKeysModule(crate::Event::SessionKeyRegistered {
+			primary: ALICE,
+			key: CHARLIE,
+			expiry: 11,
+			spend_cap: 500,
+		}));
+		assert_eq!(
+			SessionKeysModule::session_keys(CHARLIE),
+			Some(SessionKeyInfo {
+				primary: ALICE,
+				allowed_calls: allowed_calls.clone(),
+				expiry: 11,
+				spend_cap: 500,
+				spent: 0,
+				deposit: DEPOSIT,
+			})
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), DEPOSIT);
+		assert_noop!(
+			SessionKeysModule::register_session_key(RuntimeOrigin::signed(BOB), CHARLIE, allowed_calls, 11, 500),
+			Error::<Runtime>::SessionKeyAlreadyRegistered
+		);
+
+		assert_noop!(
+			SessionKeysModule::revoke_session_key(RuntimeOrigin::signed(BOB), CHARLIE),
+			Error::<Runtime>::NotPrimaryAccount
+		);
+		assert_ok!(SessionKeysModule::revoke_session_key(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE
+		));
+		System::assert_last_event(RuntimeEvent::SessionKeysModule(crate::Event::SessionKeyRevoked {
+			primary: ALICE,
+			key: CHARLIE,
+		}));
+		assert_eq!(SessionKeysModule::session_keys(CHARLIE), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_noop!(
+			SessionKeysModule::revoke_session_key(RuntimeOrigin::signed(ALICE), CHARLIE),
+			Error::<Runtime>::SessionKeyNotFound
+		);
+	});
+}
+
+#[test]
+fn session_call_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			SessionKeysModule::session_call(RuntimeOrigin::signed(CHARLIE), Box::new(evm_call(GAME_CONTRACT, 100))),
+			Error::<Runtime>::SessionKeyNotFound
+		);
+
+		register_game_session(vec![pattern("MockEvm", "call", None)], 500);
+		assert_ok!(SessionKeysModule::session_call(
+			RuntimeOrigin::signed(CHARLIE),
+			Box::new(evm_call(GAME_CONTRACT, 100))
+		));
+		System::assert_last_event(RuntimeEvent::SessionKeysModule(crate::Event::SessionCallDispatched {
+			primary: ALICE,
+			key: CHARLIE,
+			spent: 100,
+		}));
+		assert_eq!(Balances::free_balance(ALICE), 10_000 - DEPOSIT - 100);
+		assert_eq!(Balances::free_balance(GAME), 100);
+		assert_eq!(SessionKeysModule::session_keys(CHARLIE).unwrap().spent, 100);
+
+		// calls not matching the patterns are filtered
+		assert_noop!(
+			SessionKeysModule::session_call(RuntimeOrigin::signed(CHARLIE), Box::new(transfer_call(CHARLIE, 100))),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+	});
+}
+
+#[test]
+fn session_key_expires() {
+	ExtBuilder::default().build().execute_with(|| {
+		register_game_session(vec![pattern("MockEvm", "call", None)], 500);
+
+		System::set_block_number(11);
+		assert_noop!(
+			SessionKeysModule::session_call(RuntimeOrigin::signed(CHARLIE), Box::new(evm_call(GAME_CONTRACT, 100))),
+			Error::<Runtime>::SessionKeyExpired
+		);
+
+		// anyone can revoke the expired session key
+		assert_ok!(SessionKeysModule::revoke_session_key(
+			RuntimeOrigin::signed(BOB),
+			CHARLIE
+		));
+		assert_eq!(SessionKeysModule::session_keys(CHARLIE), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn spend_cap_exceeded_reverts_session_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		register_game_session(vec![pattern("MockEvm", "call", None)], 500);
+
+		assert_ok!(SessionKeysModule::session_call(
+			RuntimeOrigin::signed(CHARLIE),
+			Box::new(evm_call(GAME_CONTRACT, 300))
+		));
+		assert_eq!(Balances::free_balance(GAME), 300);
+
+		// the call exhausts the spend cap midway, the transfer is reverted
+		assert_noop!(
+			SessionKeysModule::session_call(RuntimeOrigin::signed(CHARLIE), Box::new(evm_call(GAME_CONTRACT, 300))),
+			Error::<Runtime>::SpendCapExceeded
+		);
+		assert_eq!(Balances::free_balance(GAME), 300);
+		assert_eq!(SessionKeysModule::session_keys(CHARLIE).unwrap().spent, 300);
+
+		assert_ok!(SessionKeysModule::session_call(
+			RuntimeOrigin::signed(CHARLIE),
+			Box::new(evm_call(GAME_CONTRACT, 200))
+		));
+		assert_eq!(SessionKeysModule::session_keys(CHARLIE).unwrap().spent, 500);
+	});
+}
+
+#[test]
+fn nested_calls_are_filtered() {
+	ExtBuilder::default().build().execute_with(|| {
+		register_game_session(vec![pattern("MockEvm", "call", None)], 500);
+
+		// the batch itself is not allowed
+		let batch = RuntimeCall::Utility(pallet_utility::Call::batch {
+			calls: vec![evm_call(GAME_CONTRACT, 100)],
+		});
+		assert_noop!(
+			SessionKeysModule::session_call(RuntimeOrigin::signed(CHARLIE), Box::new(batch)),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+		assert_ok!(SessionKeysModule::revoke_session_key(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE
+		));
+
+		// the batch is allowed, but the transfer nested in it is not
+		register_game_session(
+			vec![pattern("Utility", "batch", None), pattern("MockEvm", "call", None)],
+			500,
+		);
+		let batch = RuntimeCall::Utility(pallet_utility::Call::batch {
+			calls: vec![evm_call(GAME_CONTRACT, 100), transfer_call(CHARLIE, 100)],
+		});
+		assert_ok!(SessionKeysModule::session_call(
+			RuntimeOrigin::signed(CHARLIE),
+			Box::new(batch)
+		));
+		System::assert_has_event(RuntimeEvent::Utility(pallet_utility::Event::BatchInterrupted {
+			index: 1,
+			error: frame_system::Error::<Runtime>::CallFiltered.into(),
+		}));
+		assert_eq!(Balances::free_balance(GAME), 100);
+		assert_eq!(Balances::free_balance(CHARLIE), 0);
+		assert_eq!(SessionKeysModule::session_keys(CHARLIE).unwrap().spent, 100);
+	});
+}
+
+#[test]
+fn evm_target_constraint_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		register_game_session(vec![pattern("MockEvm", "call", Some(GAME_CONTRACT))], 500);
+
+		assert_noop!(
+			SessionKeysModule::session_call(RuntimeOrigin::signed(CHARLIE), Box::new(evm_call(OTHER_CONTRACT, 100))),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+		assert_ok!(SessionKeysModule::session_call(
+			RuntimeOrigin::signed(CHARLIE),
+			Box::new(evm_call(GAME_CONTRACT, 100))
+		));
+		assert_eq!(Balances::free_balance(GAME), 100);
+	});
+}
+
+#[test]
+fn fee_payer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = session_call(evm_call(GAME_CONTRACT, 100));
+		assert_noop!(
+			SessionKeysModule::fee_payer(&CHARLIE, &call, 50),
+			Error::<Runtime>::SessionKeyNotFound
+		);
+
+		register_game_session(vec![pattern("MockEvm", "call", Some(GAME_CONTRACT))], 500);
+		assert_eq!(SessionKeysModule::fee_payer(&CHARLIE, &call, 50), Ok(Some(ALICE)));
+		assert_eq!(SessionKeysModule::session_keys(CHARLIE).unwrap().spent, 50);
+
+		// the fee counts towards the spend cap
+		assert_noop!(
+			SessionKeysModule::fee_payer(&CHARLIE, &call, 451),
+			Error::<Runtime>::SpendCapExceeded
+		);
+		assert_noop!(
+			SessionKeysModule::fee_payer(&CHARLIE, &session_call(evm_call(OTHER_CONTRACT, 100)), 50),
+			Error::<Runtime>::CallNotAllowed
+		);
+
+		// the other calls are paid by the signer
+		assert_eq!(
+			SessionKeysModule::fee_payer(&CHARLIE, &evm_call(GAME_CONTRACT, 100), 50),
+			Ok(None)
+		);
+	});
+}
+
+#[test]
+fn check_session_key_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let validate = |who: AccountId, call: RuntimeCall| {
+			CheckSessionKey::<Runtime>::new().validate(&who, &call, &call.get_dispatch_info(), 0)
+		};
+		let call = session_call(evm_call(GAME_CONTRACT, 100));
+
+		assert_eq!(
+			validate(CHARLIE, call.clone()),
+			Err(InvalidTransaction::Custom(UNKNOWN_SESSION_KEY).into())
+		);
+
+		register_game_session(vec![pattern("MockEvm", "call", Some(GAME_CONTRACT))], 500);
+		assert_eq!(
+			validate(CHARLIE, call.clone()),
+			Ok(ValidTransaction {
+				longevity: 10,
+				..Default::default()
+			})
+		);
+		assert_eq!(
+			validate(CHARLIE, session_call(evm_call(OTHER_CONTRACT, 100))),
+			Err(InvalidTransaction::Custom(CALL_NOT_ALLOWED).into())
+		);
+		assert_ok!(CheckSessionKey::<Runtime>::new().pre_dispatch(&CHARLIE, &call, &call.get_dispatch_info(), 0));
+
+		// the other calls are not checked
+		assert_eq!(
+			validate(CHARLIE, evm_call(OTHER_CONTRACT, 100)),
+			Ok(ValidTransaction::default())
+		);
+
+		System::set_block_number(11);
+		assert_eq!(
+			validate(CHARLIE, call.clone()),
+			Err(InvalidTransaction::Custom(SESSION_KEY_EXPIRED).into())
+		);
+		assert_eq!(
+			CheckSessionKey::<Runtime>::new().pre_dispatch(&CHARLIE, &call, &call.get_dispatch_info(), 0),
+			Err(InvalidTransaction::Custom(SESSION_KEY_EXPIRED).into())
+		);
+	});
+}
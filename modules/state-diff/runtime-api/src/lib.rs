@@ -0,0 +1,35 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+pub use module_state_diff::BlockChanges;
+use sp_runtime::codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait StateDiffApi<AccountId, BlockNumber> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The keys of the tracked storages changed by the block. `None` if the block is older
+		/// than the retained blocks, didn't change any tracked key, or the runtime isn't built
+		/// with the state diff.
+		fn get_block_changes(block: BlockNumber) -> Option<BlockChanges<AccountId>>;
+	}
+}
@@ -0,0 +1,233 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # State Diff Module
+//!
+//! Accumulates the keys of the tracked storages changed during the block, so indexers can follow
+//! the positions, balances and pools they care about without tracing the blocks on an archive
+//! node. The changes are recorded by the mutation hooks of the tracked modules rather than by
+//! diffing the storage:
+//!
+//! - `Loans`: the positions, by `OnUpdateLoan` of the loans module.
+//! - `Tokens`: the accounts, by the deposit, transfer and slash hooks of orml-tokens.
+//! - `Dex`: the liquidity pools, by `OnLiquidityPoolUpdated` of the dex module.
+//!
+//! Homa has no mutation hook, its mints and redeems are reported by the tokens accounts of the
+//! staking and liquid currencies they change. Withdrawals of orml-tokens have no hook either.
+//!
+//! On finalize the changed keys of the block are saved and exposed by a runtime API for the
+//! `RetainedBlocks` most recent blocks. The pallet is only meant for the runtimes of the RPC
+//! nodes serving indexers, the runtimes built without it wire `()` to the hooks instead.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+use orml_traits::{
+	currency::{OnDeposit, OnSlash, OnTransfer},
+	Handler, Happened,
+};
+use primitives::{Amount, Balance, CurrencyId, TradingPair};
+use sp_runtime::traits::CheckedSub;
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+mod mock;
+mod tests;
+
+pub use module::*;
+
+/// The storages tracked by the state diff.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum StatePrefix {
+	/// `Loans::Positions`
+	Loans,
+	/// `Tokens::Accounts`
+	Tokens,
+	/// `Dex::LiquidityPool`
+	Dex,
+}
+
+/// The changed key of a tracked storage.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo)]
+pub enum StateKey<AccountId> {
+	/// `Loans::Positions`
+	LoansPosition { currency_id: CurrencyId, who: AccountId },
+	/// `Tokens::Accounts`
+	TokensAccount { who: AccountId, currency_id: CurrencyId },
+	/// `Dex::LiquidityPool`
+	DexPool(TradingPair),
+}
+
+impl<AccountId> StateKey<AccountId> {
+	pub fn prefix(&self) -> StatePrefix {
+		match self {
+			StateKey::LoansPosition { .. } => StatePrefix::Loans,
+			StateKey::TokensAccount { .. } => StatePrefix::Tokens,
+			StateKey::DexPool(_) => StatePrefix::Dex,
+		}
+	}
+}
+
+/// The keys of the tracked storages changed by a block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BlockChanges<AccountId> {
+	/// The changed keys, in order.
+	pub keys: Vec<StateKey<AccountId>>,
+	/// The block changed more than `MaxChangesPerBlock` keys, the rest are omitted.
+	pub truncated: bool,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The tracked storages.
+		type TrackedPrefixes: Contains<StatePrefix>;
+
+		/// The number of the most recent blocks whose changes are retained.
+		#[pallet::constant]
+		type RetainedBlocks: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of changed keys recorded for a block.
+		#[pallet::constant]
+		type MaxChangesPerBlock: Get<u32>;
+	}
+
+	/// The keys changed by the current block, cleared on finalize.
+	///
+	/// PendingChanges: BTreeSet<StateKey>
+	#[pallet::storage]
+	pub type PendingChanges<T: Config> = StorageValue<_, BTreeSet<StateKey<T::AccountId>>, ValueQuery>;
+
+	/// Whether the current block changed more than `MaxChangesPerBlock` keys.
+	///
+	/// PendingTruncated: bool
+	#[pallet::storage]
+	pub type PendingTruncated<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The changes of the retained blocks.
+	///
+	/// Changes: map BlockNumber => Option<BlockChanges>
+	#[pallet::storage]
+	#[pallet::getter(fn block_changes)]
+	pub type Changes<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, BlockChanges<T::AccountId>, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// saves the pending changes and prunes the expired ones on finalize
+			T::DbWeight::get().reads_writes(2, 4)
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			let keys = PendingChanges::<T>::take();
+			let truncated = PendingTruncated::<T>::take();
+			if !keys.is_empty() || truncated {
+				Changes::<T>::insert(
+					n,
+					BlockChanges {
+						keys: keys.into_iter().collect(),
+						truncated,
+					},
+				);
+			}
+			if let Some(expired) = n.checked_sub(&T::RetainedBlocks::get()) {
+				Changes::<T>::remove(expired);
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Record the changed key, if its storage is tracked.
+	pub fn record(key: StateKey<T::AccountId>) {
+		if !T::TrackedPrefixes::contains(&key.prefix()) {
+			return;
+		}
+		PendingChanges::<T>::mutate(|keys| {
+			if keys.contains(&key) {
+				return;
+			}
+			if keys.len() < T::MaxChangesPerBlock::get() as usize {
+				keys.insert(key);
+			} else {
+				PendingTruncated::<T>::put(true);
+			}
+		});
+	}
+
+	fn record_tokens_account(who: &T::AccountId, currency_id: CurrencyId) {
+		Self::record(StateKey::TokensAccount {
+			who: who.clone(),
+			currency_id,
+		});
+	}
+}
+
+impl<T: Config> OnDeposit<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn on_deposit(currency_id: CurrencyId, who: &T::AccountId, _amount: Balance) -> DispatchResult {
+		Self::record_tokens_account(who, currency_id);
+		Ok(())
+	}
+}
+
+impl<T: Config> OnTransfer<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn on_transfer(
+		currency_id: CurrencyId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		_amount: Balance,
+	) -> DispatchResult {
+		Self::record_tokens_account(from, currency_id);
+		Self::record_tokens_account(to, currency_id);
+		Ok(())
+	}
+}
+
+impl<T: Config> OnSlash<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn on_slash(currency_id: CurrencyId, who: &T::AccountId, _amount: Balance) {
+		Self::record_tokens_account(who, currency_id);
+	}
+}
+
+impl<T: Config> Handler<(T::AccountId, CurrencyId, Amount, Balance)> for Pallet<T> {
+	fn handle((who, currency_id, _, _): &(T::AccountId, CurrencyId, Amount, Balance)) -> DispatchResult {
+		Self::record(StateKey::LoansPosition {
+			currency_id: *currency_id,
+			who: who.clone(),
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config> Happened<(TradingPair, Balance, Balance)> for Pallet<T> {
+	fn happened((trading_pair, _, _): &(TradingPair, Balance, Balance)) {
+		Self::record(StateKey::DexPool(*trading_pair));
+	}
+}
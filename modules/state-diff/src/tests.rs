@@ -0,0 +1,164 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the state diff module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::assert_ok;
+use mock::*;
+
+fn run_block(n: u64, execute: impl FnOnce()) {
+	System::set_block_number(n);
+	StateDiff::on_initialize(n);
+	execute();
+	StateDiff::on_finalize(n);
+}
+
+fn tokens_account(who: AccountId, currency_id: CurrencyId) -> StateKey<AccountId> {
+	StateKey::TokensAccount { who, currency_id }
+}
+
+// the hooks called by a swap of AUSD to DOT by ALICE
+fn swap() {
+	assert_ok!(StateDiff::on_transfer(AUSD, &ALICE, &DEX, 100));
+	assert_ok!(StateDiff::on_transfer(DOT, &DEX, &ALICE, 10));
+	StateDiff::happened(&(TradingPair::from_currency_ids(AUSD, DOT).unwrap(), 1_100, 90));
+}
+
+// the hooks called by BOB adjusting the DOT loan to borrow AUSD
+fn adjust_loan() {
+	assert_ok!(StateDiff::on_transfer(DOT, &BOB, &LOANS, 10));
+	assert_ok!(StateDiff::handle(&(BOB, DOT, 10, 10)));
+	assert_ok!(StateDiff::on_deposit(AUSD, &BOB, 50));
+}
+
+// the hooks called by a homa mint of CHARLIE
+fn homa_mint() {
+	assert_ok!(StateDiff::on_transfer(DOT, &CHARLIE, &HOMA, 10));
+	assert_ok!(StateDiff::on_deposit(LDOT, &CHARLIE, 100));
+}
+
+#[test]
+fn reports_touched_keys() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_block(1, || {
+			swap();
+			adjust_loan();
+			homa_mint();
+		});
+
+		let expected: BTreeSet<_> = vec![
+			tokens_account(ALICE, AUSD),
+			tokens_account(DEX, AUSD),
+			tokens_account(DEX, DOT),
+			tokens_account(ALICE, DOT),
+			StateKey::DexPool(TradingPair::from_currency_ids(AUSD, DOT).unwrap()),
+			tokens_account(BOB, DOT),
+			tokens_account(LOANS, DOT),
+			StateKey::LoansPosition {
+				currency_id: DOT,
+				who: BOB,
+			},
+			tokens_account(BOB, AUSD),
+			tokens_account(CHARLIE, DOT),
+			tokens_account(HOMA, DOT),
+			tokens_account(CHARLIE, LDOT),
+		]
+		.into_iter()
+		.collect();
+		assert_eq!(
+			StateDiff::block_changes(1),
+			Some(BlockChanges {
+				keys: expected.into_iter().collect(),
+				truncated: false,
+			})
+		);
+		assert!(PendingChanges::<Runtime>::get().is_empty());
+	});
+}
+
+#[test]
+fn repeated_keys_are_reported_once() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_block(1, || {
+			homa_mint();
+			homa_mint();
+		});
+		assert_eq!(StateDiff::block_changes(1).unwrap().keys.len(), 3);
+	});
+}
+
+#[test]
+fn untracked_prefixes_are_ignored() {
+	ExtBuilder::default().build().execute_with(|| {
+		TrackedPrefixes::set(vec![StatePrefix::Loans]);
+		run_block(1, || {
+			swap();
+			adjust_loan();
+		});
+		assert_eq!(
+			StateDiff::block_changes(1),
+			Some(BlockChanges {
+				keys: vec![StateKey::LoansPosition {
+					currency_id: DOT,
+					who: BOB,
+				}],
+				truncated: false,
+			})
+		);
+
+		// nothing is saved for the blocks without tracked changes
+		run_block(2, homa_mint);
+		assert_eq!(StateDiff::block_changes(2), None);
+	});
+}
+
+#[test]
+fn changes_are_truncated() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_block(1, || {
+			for who in 0..=MAX_CHANGES_PER_BLOCK as AccountId {
+				assert_ok!(StateDiff::on_deposit(DOT, &who, 1));
+			}
+			// the recorded keys are still deduplicated
+			assert_ok!(StateDiff::on_deposit(DOT, &0, 1));
+		});
+
+		let changes = StateDiff::block_changes(1).unwrap();
+		assert_eq!(changes.keys.len(), MAX_CHANGES_PER_BLOCK as usize);
+		assert!(changes.truncated);
+		assert!(!PendingTruncated::<Runtime>::get());
+	});
+}
+
+#[test]
+fn expired_changes_are_pruned() {
+	ExtBuilder::default().build().execute_with(|| {
+		for n in 1..=RETAINED_BLOCKS {
+			run_block(n, homa_mint);
+		}
+		assert!(StateDiff::block_changes(1).is_some());
+
+		run_block(RETAINED_BLOCKS + 1, homa_mint);
+		assert_eq!(StateDiff::block_changes(1), None);
+		assert!(StateDiff::block_changes(2).is_some());
+		assert!(StateDiff::block_changes(RETAINED_BLOCKS + 1).is_some());
+	});
+}
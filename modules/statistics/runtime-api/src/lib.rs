@@ -0,0 +1,48 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use sp_runtime::codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait StatisticsApi<YieldSource, BlockNumber, Rate> where
+		YieldSource: Codec,
+		BlockNumber: Codec,
+		Rate: Codec,
+	{
+		/// The annualized rate of `source` over the trailing `window` blocks.
+		fn get_apy(source: YieldSource, window: BlockNumber) -> Option<Rate>;
+	}
+
+	pub trait ProtocolStatsApi<TvlSource, CurrencyId, Balance, BlockNumber, ProtocolRevenue> where
+		TvlSource: Codec,
+		CurrencyId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		ProtocolRevenue: Codec,
+	{
+		/// The value locked by each source in each currency, with its value in the stable
+		/// currency if the price is available.
+		fn get_tvl() -> Vec<(TvlSource, CurrencyId, Balance, Option<Balance>)>;
+
+		/// The protocol revenue of the trailing `period` blocks.
+		fn get_protocol_revenue(period: BlockNumber) -> ProtocolRevenue;
+	}
+}
@@ -0,0 +1,272 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! # Statistics Module
+//!
+//! ## Overview
+//!
+//! Keeps compact checkpoints of the yield indexes to compute the trailing APY of Homa staking,
+//! Earning bonding rewards and the incentives pools without archive queries. Every
+//! `AccumulatePeriod` blocks, the index of each tracked source is sampled into a ring buffer of
+//! at most `MaxCheckpoints` checkpoints:
+//!
+//! - `Homa`: the exchange rate of the liquid currency to the staking currency, a compounding
+//!   index whose growth is the yield.
+//! - `Earning` and `IncentivePool`: the accumulated reward per share of the pool, whose growth is
+//!   valued against the principal of one share at query time.
+//!
+//! `get_apy` annualizes the growth over the window linearly, it only reads the storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use module_support::{PoolId, Rate};
+use primitives::CurrencyId;
+use sp_runtime::{
+	traits::{CheckedSub, Saturating, UniqueSaturatedInto, Zero},
+	FixedPointNumber, FixedU128,
+};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// A source of yield to keep the checkpoints of.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum YieldSource {
+	/// The exchange rate of the liquid currency to the staking currency of Homa.
+	Homa,
+	/// The accumulated reward of `CurrencyId` per share of the Earning bonds.
+	Earning(CurrencyId),
+	/// The accumulated reward of `CurrencyId` per share of the incentives pool.
+	IncentivePool(PoolId, CurrencyId),
+}
+
+impl YieldSource {
+	/// Whether the index compounds, i.e. the index itself is the principal.
+	pub fn is_compounding(&self) -> bool {
+		matches!(self, YieldSource::Homa)
+	}
+}
+
+/// A sampled index of a yield source.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Checkpoint<BlockNumber> {
+	pub block_number: BlockNumber,
+	pub index: FixedU128,
+}
+
+pub type CheckpointOf<T> = Checkpoint<BlockNumberFor<T>>;
+
+/// Provides the indexes of the yield sources.
+pub trait YieldIndexProvider {
+	/// The current index of `source`.
+	fn index(source: &YieldSource) -> Option<FixedU128>;
+
+	/// The current value of one share of the accumulating `source`, in the unit of its index.
+	fn principal(source: &YieldSource) -> Option<FixedU128>;
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The period to sample the checkpoints. It should be a multiple of the `AccumulatePeriod`
+		/// of the incentives, so the checkpoints are aligned with the reward accumulation.
+		#[pallet::constant]
+		type AccumulatePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks in a year, to annualize the rates.
+		#[pallet::constant]
+		type BlocksPerYear: Get<BlockNumberFor<Self>>;
+
+		/// The indexes of the yield sources.
+		type IndexProvider: YieldIndexProvider;
+
+		/// The origin which may update the tracked sources.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of tracked sources.
+		#[pallet::constant]
+		type MaxSources: Get<u32>;
+
+		/// The maximum number of checkpoints of a source.
+		#[pallet::constant]
+		type MaxCheckpoints: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Too many tracked sources.
+		TooManySources,
+		/// The source is tracked more than once.
+		DuplicateSource,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The tracked sources are updated, the checkpoints of the untracked sources are removed.
+		TrackedSourcesUpdated { sources: Vec<YieldSource> },
+		/// The index of the source is unavailable, the checkpoint is skipped.
+		IndexUnavailable { source: YieldSource },
+	}
+
+	/// The yield sources to keep the checkpoints of.
+	///
+	/// TrackedSources: Vec<YieldSource>
+	#[pallet::storage]
+	#[pallet::getter(fn tracked_sources)]
+	pub type TrackedSources<T: Config> = StorageValue<_, BoundedVec<YieldSource, T::MaxSources>, ValueQuery>;
+
+	/// The recent checkpoints of the yield sources, from the oldest to the latest.
+	///
+	/// Checkpoints: map YieldSource => Vec<Checkpoint>
+	#[pallet::storage]
+	#[pallet::getter(fn checkpoints)]
+	pub type Checkpoints<T: Config> =
+		StorageMap<_, Twox64Concat, YieldSource, BoundedVec<CheckpointOf<T>, T::MaxCheckpoints>, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			if now % T::AccumulatePeriod::get() == Zero::zero() {
+				let count = Self::sample_checkpoints(now);
+				T::WeightInfo::on_initialize(count)
+			} else {
+				Weight::zero()
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the tracked yield sources. The checkpoints of the sources no longer tracked are
+		/// removed.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `sources`: the yield sources to track.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_tracked_sources(sources.len() as u32))]
+		pub fn set_tracked_sources(origin: OriginFor<T>, sources: Vec<YieldSource>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				sources
+					.iter()
+					.enumerate()
+					.all(|(i, source)| !sources[..i].contains(source)),
+				Error::<T>::DuplicateSource
+			);
+			let bounded_sources: BoundedVec<YieldSource, T::MaxSources> =
+				sources.clone().try_into().map_err(|_| Error::<T>::TooManySources)?;
+
+			for source in TrackedSources::<T>::get().iter() {
+				if !bounded_sources.contains(source) {
+					Checkpoints::<T>::remove(source);
+				}
+			}
+			TrackedSources::<T>::put(bounded_sources);
+
+			Self::deposit_event(Event::TrackedSourcesUpdated { sources });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Sample the indexes of the tracked sources. Returns the number of the tracked sources.
+	fn sample_checkpoints(now: BlockNumberFor<T>) -> u32 {
+		let sources = TrackedSources::<T>::get();
+		for source in sources.iter() {
+			match T::IndexProvider::index(source) {
+				Some(index) => Checkpoints::<T>::mutate(source, |checkpoints| {
+					// drop the oldest checkpoint if the buffer is full.
+					let _ = checkpoints.force_insert_keep_right(
+						checkpoints.len(),
+						Checkpoint {
+							block_number: now,
+							index,
+						},
+					);
+				}),
+				None => Self::deposit_event(Event::IndexUnavailable { source: *source }),
+			}
+		}
+
+		sources.len() as u32
+	}
+
+	/// The annualized rate of the index growth from `start` to `end` in `elapsed` blocks. The
+	/// growth is valued against `start` for the compounding sources, and against the current
+	/// principal for the accumulating sources. The decrease of the index yields zero.
+	pub fn annualized_rate(
+		source: &YieldSource,
+		start: FixedU128,
+		end: FixedU128,
+		elapsed: BlockNumberFor<T>,
+	) -> Option<Rate> {
+		let principal = if source.is_compounding() {
+			start
+		} else {
+			T::IndexProvider::principal(source)?
+		};
+		let blocks_per_year: u128 = T::BlocksPerYear::get().unique_saturated_into();
+		let elapsed: u128 = elapsed.unique_saturated_into();
+
+		end.saturating_sub(start)
+			.checked_div(&principal)?
+			.checked_mul(&Rate::checked_from_integer(blocks_per_year)?)?
+			.checked_div(&Rate::checked_from_integer(elapsed)?)
+	}
+
+	/// The APY of `source` over the trailing `window` blocks, from the latest checkpoint back to
+	/// the latest checkpoint at least `window` blocks older. Returns `None` if the checkpoints
+	/// don't cover the window.
+	pub fn get_apy(source: YieldSource, window: BlockNumberFor<T>) -> Option<Rate> {
+		let checkpoints = Self::checkpoints(source);
+		let latest = checkpoints.last()?;
+		let start_at = latest.block_number.checked_sub(&window)?;
+		let start = checkpoints.iter().rev().find(|c| c.block_number <= start_at)?;
+
+		let elapsed = latest.block_number.saturating_sub(start.block_number);
+		if elapsed.is_zero() {
+			return None;
+		}
+
+		Self::annualized_rate(&source, start.index, latest.index, elapsed)
+	}
+}
@@ -0,0 +1,132 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Mocks for the statistics module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureSignedBy;
+use primitives::TokenSymbol;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const ACCUMULATE_PERIOD: u64 = 10;
+pub const BLOCKS_PER_YEAR: u64 = 1_000;
+pub const MAX_CHECKPOINTS: u32 = 4;
+
+mod statistics {
+	pub use super::super::*;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+}
+
+parameter_types! {
+	/// The current indexes of the sources.
+	pub static Indexes: Vec<(YieldSource, FixedU128)> = vec![];
+	/// The current principals of the accumulating sources.
+	pub static Principals: Vec<(YieldSource, FixedU128)> = vec![];
+}
+
+pub fn set_index(source: YieldSource, index: FixedU128) {
+	Indexes::mutate(|indexes| {
+		indexes.retain(|(s, _)| *s != source);
+		indexes.push((source, index));
+	});
+}
+
+pub fn set_principal(source: YieldSource, principal: FixedU128) {
+	Principals::mutate(|principals| {
+		principals.retain(|(s, _)| *s != source);
+		principals.push((source, principal));
+	});
+}
+
+pub struct MockIndexProvider;
+impl YieldIndexProvider for MockIndexProvider {
+	fn index(source: &YieldSource) -> Option<FixedU128> {
+		Indexes::get()
+			.into_iter()
+			.find(|(s, _)| s == source)
+			.map(|(_, index)| index)
+	}
+
+	fn principal(source: &YieldSource) -> Option<FixedU128> {
+		Principals::get()
+			.into_iter()
+			.find(|(s, _)| s == source)
+			.map(|(_, principal)| principal)
+	}
+}
+
+ord_parameter_types! {
+	pub const Admin: AccountId = ALICE;
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AccumulatePeriod = ConstU64<ACCUMULATE_PERIOD>;
+	type BlocksPerYear = ConstU64<BLOCKS_PER_YEAR>;
+	type IndexProvider = MockIndexProvider;
+	type UpdateOrigin = EnsureSignedBy<Admin, AccountId>;
+	type MaxSources = ConstU32<3>;
+	type MaxCheckpoints = ConstU32<MAX_CHECKPOINTS>;
+	type WeightInfo = ();
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Statistics: statistics,
+	}
+);
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
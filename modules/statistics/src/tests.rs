@@ -0,0 +1,209 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Unit tests for the statistics module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use sp_runtime::traits::{BadOrigin, One};
+
+const EARNING: YieldSource = YieldSource::Earning(AUSD);
+const LOANS_POOL: YieldSource = YieldSource::IncentivePool(PoolId::Loans(ACA), ACA);
+
+fn rate(n: u128, d: u128) -> Rate {
+	Rate::saturating_from_rational(n, d)
+}
+
+fn set_checkpoints(source: YieldSource, checkpoints: Vec<(u64, FixedU128)>) {
+	Checkpoints::<Runtime>::insert(
+		source,
+		BoundedVec::try_from(
+			checkpoints
+				.into_iter()
+				.map(|(block_number, index)| Checkpoint { block_number, index })
+				.collect::<Vec<_>>(),
+		)
+		.unwrap(),
+	);
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Statistics::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn set_tracked_sources_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Statistics::set_tracked_sources(RuntimeOrigin::signed(BOB), vec![YieldSource::Homa]),
+			BadOrigin
+		);
+		assert_noop!(
+			Statistics::set_tracked_sources(RuntimeOrigin::signed(ALICE), vec![YieldSource::Homa, YieldSource::Homa]),
+			Error::<Runtime>::DuplicateSource
+		);
+		assert_noop!(
+			Statistics::set_tracked_sources(
+				RuntimeOrigin::signed(ALICE),
+				vec![
+					YieldSource::Homa,
+					EARNING,
+					LOANS_POOL,
+					YieldSource::IncentivePool(PoolId::NomineesElection, ACA)
+				]
+			),
+			Error::<Runtime>::TooManySources
+		);
+
+		assert_ok!(Statistics::set_tracked_sources(
+			RuntimeOrigin::signed(ALICE),
+			vec![YieldSource::Homa, EARNING]
+		));
+		System::assert_last_event(RuntimeEvent::Statistics(crate::Event::TrackedSourcesUpdated {
+			sources: vec![YieldSource::Homa, EARNING],
+		}));
+		assert_eq!(
+			Statistics::tracked_sources().into_inner(),
+			vec![YieldSource::Homa, EARNING]
+		);
+
+		// the checkpoints of the untracked source are removed
+		set_checkpoints(YieldSource::Homa, vec![(10, FixedU128::one())]);
+		set_checkpoints(EARNING, vec![(10, FixedU128::zero())]);
+		assert_ok!(Statistics::set_tracked_sources(
+			RuntimeOrigin::signed(ALICE),
+			vec![EARNING, LOANS_POOL]
+		));
+		assert!(!Checkpoints::<Runtime>::contains_key(YieldSource::Homa));
+		assert_eq!(Statistics::checkpoints(EARNING).len(), 1);
+	});
+}
+
+#[test]
+fn on_initialize_samples_bounded_checkpoints() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Statistics::set_tracked_sources(
+			RuntimeOrigin::signed(ALICE),
+			vec![YieldSource::Homa, EARNING]
+		));
+		set_index(YieldSource::Homa, FixedU128::one());
+
+		assert_eq!(Statistics::on_initialize(ACCUMULATE_PERIOD - 1), Weight::zero());
+		assert_eq!(
+			Statistics::on_initialize(ACCUMULATE_PERIOD),
+			<() as WeightInfo>::on_initialize(2)
+		);
+		assert_eq!(
+			Statistics::checkpoints(YieldSource::Homa).into_inner(),
+			vec![Checkpoint {
+				block_number: ACCUMULATE_PERIOD,
+				index: FixedU128::one(),
+			}]
+		);
+		// the unavailable index is skipped
+		System::assert_last_event(RuntimeEvent::Statistics(crate::Event::IndexUnavailable {
+			source: EARNING,
+		}));
+		assert!(Statistics::checkpoints(EARNING).is_empty());
+
+		// the buffer keeps the latest `MaxCheckpoints` checkpoints
+		System::set_block_number(ACCUMULATE_PERIOD);
+		for i in 1..=MAX_CHECKPOINTS as u64 {
+			set_index(YieldSource::Homa, FixedU128::saturating_from_integer(i + 1));
+			run_to_block(ACCUMULATE_PERIOD * (i + 1));
+		}
+		assert_eq!(
+			Statistics::checkpoints(YieldSource::Homa).into_inner(),
+			(2..=MAX_CHECKPOINTS as u64 + 1)
+				.map(|i| Checkpoint {
+					block_number: ACCUMULATE_PERIOD * i,
+					index: FixedU128::saturating_from_integer(i),
+				})
+				.collect::<Vec<_>>()
+		);
+	});
+}
+
+#[test]
+fn compounding_apy_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_checkpoints(
+			YieldSource::Homa,
+			vec![(0, rate(15, 10)), (100, rate(16, 10)), (200, rate(162, 100))],
+		);
+
+		// (1.62 - 1.6) / 1.6 * 1000 / 100
+		assert_eq!(Statistics::get_apy(YieldSource::Homa, 100), Some(rate(125, 1_000)));
+		// (1.62 - 1.5) / 1.5 * 1000 / 200
+		assert_eq!(Statistics::get_apy(YieldSource::Homa, 200), Some(rate(4, 10)));
+		// the window is rounded to the covering checkpoint
+		assert_eq!(Statistics::get_apy(YieldSource::Homa, 150), Some(rate(4, 10)));
+		// the checkpoints don't cover the window
+		assert_eq!(Statistics::get_apy(YieldSource::Homa, 250), None);
+		assert_eq!(Statistics::get_apy(YieldSource::Homa, 0), None);
+		assert_eq!(Statistics::get_apy(EARNING, 100), None);
+
+		// the decrease of the index yields zero
+		set_checkpoints(YieldSource::Homa, vec![(0, rate(16, 10)), (100, rate(15, 10))]);
+		assert_eq!(Statistics::get_apy(YieldSource::Homa, 100), Some(Rate::zero()));
+	});
+}
+
+#[test]
+fn accumulating_apy_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_checkpoints(
+			LOANS_POOL,
+			vec![(0, FixedU128::zero()), (100, rate(5, 100)), (300, rate(15, 100))],
+		);
+
+		// the principal is unavailable
+		assert_eq!(Statistics::get_apy(LOANS_POOL, 200), None);
+
+		set_principal(LOANS_POOL, FixedU128::saturating_from_integer(2));
+		// (0.15 - 0.05) / 2 * 1000 / 200
+		assert_eq!(Statistics::get_apy(LOANS_POOL, 200), Some(rate(25, 100)));
+		// (0.15 - 0) / 2 * 1000 / 300
+		assert_eq!(Statistics::get_apy(LOANS_POOL, 300), Some(rate(25, 100)));
+
+		set_checkpoints(EARNING, vec![(0, rate(1, 10)), (500, rate(6, 10))]);
+		set_principal(EARNING, rate(1, 2));
+		// (0.6 - 0.1) / 0.5 * 1000 / 500
+		assert_eq!(
+			Statistics::get_apy(EARNING, 500),
+			Some(Rate::saturating_from_integer(2))
+		);
+	});
+}
+
+#[test]
+fn get_apy_is_read_only() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_checkpoints(YieldSource::Homa, vec![(0, rate(15, 10)), (100, rate(16, 10))]);
+		let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+
+		assert!(Statistics::get_apy(YieldSource::Homa, 100).is_some());
+		assert_eq!(Statistics::get_apy(EARNING, 100), None);
+		assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+	});
+}
@@ -322,3 +322,38 @@ where
 		Ok(())
 	}
 }
+
+/// Listing bundle applied to other subsystems when a trading pair becomes `Enabled`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TradingPairListing<CurrencyId, Balance, Moment> {
+	/// The interval to update the average price of the trading pair, if enable dex oracle.
+	pub average_price_interval: Option<Moment>,
+	/// The incentive reward amounts per period for the dex share of the trading pair.
+	pub incentive_rewards: Vec<(CurrencyId, Balance)>,
+}
+
+/// Hook called when a trading pair becomes `Enabled`, with the listing bundle if one is set.
+pub trait OnTradingPairEnabled<TradingPair, Listing> {
+	fn on_trading_pair_enabled(trading_pair: &TradingPair, listing: Option<&Listing>) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<TradingPair, Listing> OnTradingPairEnabled<TradingPair, Listing> for Tuple {
+	fn on_trading_pair_enabled(trading_pair: &TradingPair, listing: Option<&Listing>) -> DispatchResult {
+		for_tuples!( #( Tuple::on_trading_pair_enabled(trading_pair, listing)?; )* );
+		Ok(())
+	}
+}
+
+/// Hook called when an `Enabled` trading pair is disabled.
+pub trait OnTradingPairDisabled<TradingPair> {
+	fn on_trading_pair_disabled(trading_pair: &TradingPair) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<TradingPair> OnTradingPairDisabled<TradingPair> for Tuple {
+	fn on_trading_pair_disabled(trading_pair: &TradingPair) -> DispatchResult {
+		for_tuples!( #( Tuple::on_trading_pair_disabled(trading_pair)?; )* );
+		Ok(())
+	}
+}
@@ -183,6 +183,39 @@ impl LiquidationEvmBridge for () {
 	fn on_repayment_refund(_context: InvokeContext, _collateral: EvmAddress, _repayment: Balance) {}
 }
 
+/// An abstraction of the flash swap callback on EVM contracts
+pub trait FlashSwapEvmBridge {
+	/// Execute the flash swap callback. `target_amount` of `target_token` has been transferred to
+	/// the contract, `repayment` of `supply_token` is expected to be held by `repay_dest` when it
+	/// returns, from where it's collected.
+	fn on_flash_swap(
+		context: InvokeContext,
+		supply_token: EvmAddress,
+		target_token: EvmAddress,
+		target_amount: Balance,
+		repayment: Balance,
+		repay_dest: EvmAddress,
+		data: Vec<u8>,
+		gas_limit: u64,
+		storage_limit: u32,
+	) -> DispatchResult;
+}
+impl FlashSwapEvmBridge for () {
+	fn on_flash_swap(
+		_context: InvokeContext,
+		_supply_token: EvmAddress,
+		_target_token: EvmAddress,
+		_target_amount: Balance,
+		_repayment: Balance,
+		_repay_dest: EvmAddress,
+		_data: Vec<u8>,
+		_gas_limit: u64,
+		_storage_limit: u32,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+}
+
 /// An abstraction of EVMManager
 pub trait EVMManager<AccountId, Balance> {
 	/// Query the constants `NewContractExtraBytes` value from evm module.
@@ -238,6 +271,25 @@ pub trait AddressMapping<AccountId> {
 	fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool;
 }
 
+#[cfg(feature = "std")]
+impl<AccountId: Default> AddressMapping<AccountId> for () {
+	fn get_account_id(_evm: &EvmAddress) -> AccountId {
+		Default::default()
+	}
+	fn get_evm_address(_account_id: &AccountId) -> Option<EvmAddress> {
+		None
+	}
+	fn get_or_create_evm_address(_account_id: &AccountId) -> EvmAddress {
+		Default::default()
+	}
+	fn get_default_evm_address(_account_id: &AccountId) -> EvmAddress {
+		Default::default()
+	}
+	fn is_linked(_account_id: &AccountId, _evm: &EvmAddress) -> bool {
+		false
+	}
+}
+
 /// A mapping between AssetId and AssetMetadata.
 pub trait AssetIdMapping<ForeignAssetId, Location, AssetMetadata> {
 	/// Returns the AssetMetadata associated with a given `AssetIds`.
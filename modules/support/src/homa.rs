@@ -34,6 +34,20 @@ pub trait HomaSubAccountXcm<AccountId, Balance> {
 	fn unbond_on_sub_account(sub_account_index: u16, amount: Balance) -> DispatchResult;
 	/// Send XCM message to the relaychain for sub account to nominate.
 	fn nominate_on_sub_account(sub_account_index: u16, targets: Vec<Self::RelayChainAccountId>) -> DispatchResult;
+	/// Send XCM message to the relaychain for sub account to join the nomination pool.
+	fn join_pool_on_sub_account(sub_account_index: u16, pool_id: u32, amount: Balance) -> DispatchResult;
+	/// Send XCM message to the relaychain for sub account to bond extra to the joined nomination
+	/// pool.
+	fn bond_extra_to_pool_on_sub_account(sub_account_index: u16, amount: Balance) -> DispatchResult;
+	/// Send XCM message to the relaychain for sub account to bond the pending rewards of the joined
+	/// nomination pool.
+	fn bond_pool_rewards_on_sub_account(sub_account_index: u16) -> DispatchResult;
+	/// Send XCM message to the relaychain for sub account to unbond points from the joined
+	/// nomination pool.
+	fn unbond_from_pool_on_sub_account(sub_account_index: u16, points: Balance) -> DispatchResult;
+	/// Send XCM message to the relaychain for sub account to withdraw_unbonded staking currency
+	/// from the joined nomination pool and send it back.
+	fn withdraw_unbonded_from_pool_on_sub_account(sub_account_index: u16, amount: Balance) -> DispatchResult;
 	/// The fee of cross-chain transfer is deducted from the recipient.
 	fn get_xcm_transfer_fee() -> Balance;
 	/// The fee of parachain
@@ -16,17 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use parity_scale_codec::FullCodec;
-use primitives::Position;
+use parity_scale_codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+use primitives::{Balance, Position};
+use scale_info::TypeInfo;
 use sp_core::U256;
-use sp_runtime::{DispatchError, DispatchResult};
+use sp_runtime::{DispatchError, DispatchResult, Permill, RuntimeDebug};
 use sp_std::{
 	cmp::{Eq, PartialEq},
 	fmt::Debug,
 	prelude::*,
 };
 
-use crate::{dex::*, ExchangeRate, Ratio};
+use crate::{dex::*, ExchangeRate, Price, Ratio};
 
 pub trait RiskManager<AccountId, CurrencyId, Balance, DebitBalance> {
 	fn get_debit_value(currency_id: CurrencyId, debit_balance: DebitBalance) -> Balance;
@@ -79,6 +80,16 @@ pub trait AuctionManager<AccountId> {
 	fn get_total_target_in_auction() -> Self::Balance;
 }
 
+/// The origin a stable currency issuance or burn is accounted to.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum IssuanceOrigin<CurrencyId> {
+	/// Debit drawn against or repaid to the CDPs of a collateral type, including their interest.
+	Collateral(CurrencyId),
+	/// Issued or burned by the system itself, e.g. unbacked debit of auctions, surplus offsetting
+	/// and emergency shutdown refunds.
+	System,
+}
+
 /// An abstraction of cdp treasury for Honzon Protocol.
 pub trait CDPTreasury<AccountId> {
 	type Balance;
@@ -100,15 +111,29 @@ pub trait CDPTreasury<AccountId> {
 	fn on_system_debit(amount: Self::Balance) -> DispatchResult;
 
 	/// issue surplus(stable currency) for cdp treasury
-	fn on_system_surplus(amount: Self::Balance) -> DispatchResult;
+	fn on_system_surplus(amount: Self::Balance, origin: IssuanceOrigin<Self::CurrencyId>) -> DispatchResult;
 
 	/// issue debit to `who`
 	/// if backed flag is true, means the debit to issue is backed on some
 	/// assets, otherwise will increase same amount of debit to system debit.
-	fn issue_debit(who: &AccountId, debit: Self::Balance, backed: bool) -> DispatchResult;
+	/// The issuance is accounted to `origin`.
+	fn issue_debit(
+		who: &AccountId,
+		debit: Self::Balance,
+		backed: bool,
+		origin: IssuanceOrigin<Self::CurrencyId>,
+	) -> DispatchResult;
 
-	/// burn debit(stable currency) of `who`
-	fn burn_debit(who: &AccountId, debit: Self::Balance) -> DispatchResult;
+	/// burn debit(stable currency) of `who`, the burn is accounted to `origin`
+	fn burn_debit(who: &AccountId, debit: Self::Balance, origin: IssuanceOrigin<Self::CurrencyId>) -> DispatchResult;
+
+	/// move the issuance of `amount` accounted to `from` to `to`, without issuing or burning
+	/// stable currency
+	fn transfer_issuance(
+		from: IssuanceOrigin<Self::CurrencyId>,
+		to: IssuanceOrigin<Self::CurrencyId>,
+		amount: Self::Balance,
+	) -> DispatchResult;
 
 	/// deposit surplus(stable currency) to cdp treasury by `from`
 	fn deposit_surplus(from: &AccountId, surplus: Self::Balance) -> DispatchResult;
@@ -150,6 +175,29 @@ pub trait EmergencyShutdown {
 	fn is_shutdown() -> bool;
 }
 
+/// The system-wide risk-off switch, which blocks the operations increasing the debit of CDPs
+/// while active.
+pub trait SafeMode {
+	fn is_active() -> bool;
+}
+
+impl SafeMode for () {
+	fn is_active() -> bool {
+		false
+	}
+}
+
+/// Provides the cumulative debit value of the liquidated CDPs.
+pub trait LiquidatedValueProvider {
+	fn total_liquidated_value() -> Balance;
+}
+
+impl LiquidatedValueProvider for () {
+	fn total_liquidated_value() -> Balance {
+		0
+	}
+}
+
 /// Functionality of Honzon Protocol to be exposed to EVM+.
 pub trait HonzonManager<AccountId, CurrencyId, Amount, Balance> {
 	/// Adjust CDP loan
@@ -170,3 +218,134 @@ pub trait HonzonManager<AccountId, CurrencyId, Amount, Balance> {
 	/// Get exchange rate of debit units to debit value for a currency_id
 	fn get_debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate;
 }
+
+/// The measured peg of the stable currency.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PegStatus<BlockNumber> {
+	/// The weighted basket price of one stable currency token, in USD.
+	pub price: Price,
+	/// The absolute deviation of `price` from $1, in basis points.
+	pub deviation_bps: u32,
+	/// The block number at which the status was measured.
+	pub updated_at: BlockNumber,
+}
+
+pub trait PegStatusProvider<BlockNumber> {
+	/// The latest measured peg status of the stable currency, if any.
+	fn get_peg_status() -> Option<PegStatus<BlockNumber>>;
+}
+
+impl<BlockNumber> PegStatusProvider<BlockNumber> for () {
+	fn get_peg_status() -> Option<PegStatus<BlockNumber>> {
+		None
+	}
+}
+
+/// The reference rate of the stable currency, a weighted index of the USD prices of stable
+/// assets.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ReferenceRate<BlockNumber> {
+	/// The weighted average USD price of one token of the available components.
+	pub rate: Price,
+	/// Whether some components were unavailable and skipped, with their weights renormalized.
+	pub degraded: bool,
+	/// The block number at which the rate was computed.
+	pub updated_at: BlockNumber,
+}
+
+pub trait ReferenceRateProvider<BlockNumber> {
+	/// The latest reference rate of the stable currency, if any.
+	fn get_reference_rate() -> Option<ReferenceRate<BlockNumber>>;
+}
+
+impl<BlockNumber> ReferenceRateProvider<BlockNumber> for () {
+	fn get_reference_rate() -> Option<ReferenceRate<BlockNumber>> {
+		None
+	}
+}
+
+/// How a CDP with debit is closed, in the liquidation history of the owner.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum LiquidationKind {
+	/// Force closed by liquidation.
+	Liquidated,
+	/// Self closed by the owner, selling the collateral with DEX.
+	SelfClosed,
+	/// Settled after emergency shutdown.
+	ShutdownSettled,
+}
+
+/// The route the liquidated collateral is sold by, in the order they are tried.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum LiquidationRoute {
+	Dex,
+	Contract,
+	Auction,
+}
+
+/// A record in the liquidation history of a CDP owner.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LiquidationRecord<CurrencyId, BlockNumber> {
+	pub block_number: BlockNumber,
+	pub collateral_type: CurrencyId,
+	pub kind: LiquidationKind,
+	/// The collateral confiscated from the CDP.
+	pub collateral_amount: Balance,
+	/// The debit value of the CDP.
+	pub debit_value: Balance,
+	/// The liquidation penalty charged, zero if not liquidated.
+	pub penalty_amount: Balance,
+	/// The last route in the priority order used to sell the collateral, `None` if it is not
+	/// sold by liquidation.
+	pub route: Option<LiquidationRoute>,
+}
+
+/// The aggregate liquidation history of a CDP owner, counting all the records including the
+/// rotated out ones.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LiquidationStats {
+	pub liquidated_count: u32,
+	pub self_closed_count: u32,
+	pub shutdown_settled_count: u32,
+	/// The total liquidation penalty charged.
+	pub total_penalty: Balance,
+}
+
+/// The price drops of the stress test in the risk snapshot of a collateral type.
+pub const RISK_PRICE_SHOCKS: [Permill; 3] = [
+	Permill::from_percent(10),
+	Permill::from_percent(25),
+	Permill::from_percent(50),
+];
+
+/// The number of the largest positions counted in the debit concentration of the risk snapshot.
+pub const RISK_CONCENTRATION_POSITIONS: usize = 10;
+
+/// The aggregate risk exposure of a collateral type.
+///
+/// The totals cover all the positions, while the per-position figures only cover the sampled
+/// positions, which are all the positions if `is_complete`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RiskSnapshot<BlockNumber> {
+	/// The block at which the snapshot is taken.
+	pub block_number: BlockNumber,
+	/// The total debit value, in stable currency.
+	pub total_debit_value: Balance,
+	/// The total collateral value at the oracle price, in stable currency.
+	pub total_collateral_value: Balance,
+	/// The collateral ratio of the sampled positions weighted by their debit values, `None` if
+	/// they have no debit.
+	pub weighted_collateral_ratio: Option<Ratio>,
+	/// The debit value of the sampled positions which are unsafe after each price drop of
+	/// `RISK_PRICE_SHOCKS`.
+	pub unsafe_debit_value_under_shocks: [(Permill, Balance); 3],
+	/// The share of the debit value of the sampled positions held by the largest
+	/// `RISK_CONCENTRATION_POSITIONS` positions.
+	pub top_positions_debit_share: Permill,
+	/// The number of the sampled positions.
+	pub sampled_positions: u32,
+	/// The maximum number of the sampled positions.
+	pub sample_cap: u32,
+	/// Whether all the positions are sampled.
+	pub is_complete: bool,
+}
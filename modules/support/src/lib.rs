@@ -22,7 +22,7 @@
 #![allow(clippy::type_complexity)]
 
 use frame_support::pallet_prelude::{DispatchClass, Pays, Weight};
-use primitives::{task::TaskResult, Balance, CurrencyId, Multiplier, ReserveIdentifier};
+use primitives::{task::TaskResult, Balance, CurrencyId, Moment, Multiplier, ReserveIdentifier};
 use sp_runtime::{
 	traits::CheckedDiv, transaction_validity::TransactionValidityError, DispatchError, DispatchResult, FixedU128,
 };
@@ -100,6 +100,24 @@ pub trait PriceProvider<CurrencyId> {
 	}
 }
 
+impl<CurrencyId> PriceProvider<CurrencyId> for () {
+	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
+		None
+	}
+}
+
+/// Provides the timestamp of the latest oracle price of a currency.
+pub trait PriceTimestampProvider<CurrencyId> {
+	/// The timestamp in milliseconds, `None` if the currency has no oracle price.
+	fn get_price_timestamp(currency_id: CurrencyId) -> Option<Moment>;
+}
+
+impl<CurrencyId> PriceTimestampProvider<CurrencyId> for () {
+	fn get_price_timestamp(_currency_id: CurrencyId) -> Option<Moment> {
+		None
+	}
+}
+
 pub trait DEXPriceProvider<CurrencyId> {
 	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<ExchangeRate>;
 }
@@ -134,6 +152,18 @@ pub trait TransactionPayment<AccountId, Balance, NegativeImbalance> {
 }
 
 /// Dispatchable tasks
+/// Redirects the transaction fee of the calls signed on behalf of another account.
+pub trait TransactionFeePayer<AccountId, Call> {
+	/// The account which pays `fee` of `call` signed by `who`, `None` if `who` pays it.
+	fn fee_payer(who: &AccountId, call: &Call, fee: Balance) -> Result<Option<AccountId>, DispatchError>;
+}
+
+impl<AccountId, Call> TransactionFeePayer<AccountId, Call> for () {
+	fn fee_payer(_who: &AccountId, _call: &Call, _fee: Balance) -> Result<Option<AccountId>, DispatchError> {
+		Ok(None)
+	}
+}
+
 pub trait DispatchableTask {
 	fn dispatch(self, weight: Weight) -> TaskResult;
 }
@@ -213,3 +243,47 @@ impl<AccountId> LiquidateCollateral<AccountId> for Tuple {
 pub trait BuyWeightRate {
 	fn calculate_rate(location: Location) -> Option<Ratio>;
 }
+
+/// High-value operations that can be guarded by the account owner.
+#[derive(Clone, Copy, PartialEq, Eq, sp_runtime::RuntimeDebug)]
+pub enum GuardedOperation {
+	/// Withdraw `amount` of collateral `currency_id` from a CDP.
+	WithdrawCollateral { currency_id: CurrencyId, amount: Balance },
+	/// Close or move out the whole CDP of collateral `currency_id`.
+	CloseLoan { currency_id: CurrencyId },
+	/// Redeem `amount` of liquid `currency_id`.
+	Redeem { currency_id: CurrencyId, amount: Balance },
+}
+
+pub trait OperationGuard<AccountId> {
+	/// Check whether `who` is allowed to perform `operation` now, and account it if so.
+	fn check_operation(who: &AccountId, operation: GuardedOperation) -> DispatchResult;
+}
+
+impl<AccountId> OperationGuard<AccountId> for () {
+	fn check_operation(_who: &AccountId, _operation: GuardedOperation) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Pause and unpause the calls of the runtime by the pallet and function names.
+pub trait TransactionPauser {
+	/// Ensure the call exists and is allowed to be paused.
+	fn ensure_pausable(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult;
+	/// Pause the call, returns `false` if it is already paused.
+	fn pause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> Result<bool, DispatchError>;
+	/// Unpause the call, returns `false` if it is not paused.
+	fn unpause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> bool;
+}
+
+/// Enumerates the non-native currencies held by an account.
+pub trait AccountCurrencies<AccountId> {
+	/// At most `limit` currencies held by `who` in storage order, starting after `start_after`.
+	fn account_currencies(who: &AccountId, start_after: Option<CurrencyId>, limit: u32) -> Vec<CurrencyId>;
+}
+
+impl<AccountId> AccountCurrencies<AccountId> for () {
+	fn account_currencies(_who: &AccountId, _start_after: Option<CurrencyId>, _limit: u32) -> Vec<CurrencyId> {
+		vec![]
+	}
+}
@@ -52,6 +52,35 @@ pub enum StakingCall {
 	Nominate(Vec<<RelayChainLookup as StaticLookup>::Source>),
 }
 
+/// The source of funds for `pallet-nomination-pools` `bond_extra`.
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum PoolBondExtra {
+	/// Take from the free balance of the pool member.
+	FreeBalance(Balance),
+	/// Take the pending rewards of the pool member.
+	Rewards,
+}
+
+/// `pallet-nomination-pools` calls.
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum NominationPoolsCall {
+	/// `join(amount, pool_id)` call.
+	#[codec(index = 0)]
+	Join(#[codec(compact)] Balance, u32),
+	/// `bond_extra(extra)` call.
+	#[codec(index = 1)]
+	BondExtra(PoolBondExtra),
+	/// `claim_payout()` call.
+	#[codec(index = 2)]
+	ClaimPayout,
+	/// `unbond(member_account, unbonding_points)` call.
+	#[codec(index = 3)]
+	Unbond(<RelayChainLookup as StaticLookup>::Source, #[codec(compact)] Balance),
+	/// `withdraw_unbonded(member_account, num_slashing_spans)` call.
+	#[codec(index = 5)]
+	WithdrawUnbonded(<RelayChainLookup as StaticLookup>::Source, u32),
+}
+
 /// `pallet-xcm` calls.
 #[derive(Encode, Decode, RuntimeDebug)]
 pub enum XcmCall {
@@ -79,6 +108,7 @@ pub trait RelayChainCall: Sized {
 	fn utility(call: UtilityCall<Self>) -> Self;
 	fn proxy(call: ProxyCall<Self>) -> Self;
 	fn xcm_pallet(call: XcmCall) -> Self;
+	fn nomination_pools(call: NominationPoolsCall) -> Self;
 }
 
 pub trait CallBuilder {
@@ -112,6 +142,38 @@ pub trait CallBuilder {
 	/// - targets: The target validator list.
 	fn staking_nominate(targets: Vec<Self::RelayChainAccountId>) -> Self::RelayChainCall;
 
+	/// Join the nomination pool on relay-chain.
+	///  params:
+	/// - amount: The amount of staking currency to bond.
+	/// - pool_id: The id of the nomination pool to join.
+	fn nomination_pools_join(amount: Self::Balance, pool_id: u32) -> Self::RelayChainCall;
+
+	/// Bond extra from the free balance to the joined nomination pool on relay-chain.
+	///  params:
+	/// - amount: The amount of staking currency to bond.
+	fn nomination_pools_bond_extra(amount: Self::Balance) -> Self::RelayChainCall;
+
+	/// Bond the pending rewards to the joined nomination pool on relay-chain.
+	fn nomination_pools_bond_extra_rewards() -> Self::RelayChainCall;
+
+	/// Claim the pending rewards of the joined nomination pool on relay-chain.
+	fn nomination_pools_claim_payout() -> Self::RelayChainCall;
+
+	/// Unbond the points of the pool member on relay-chain.
+	///  params:
+	/// - member: The pool member.
+	/// - points: The amount of points to unbond.
+	fn nomination_pools_unbond(member: Self::RelayChainAccountId, points: Self::Balance) -> Self::RelayChainCall;
+
+	/// Withdraw unbonded funds of the pool member on relay-chain.
+	///  params:
+	/// - member: The pool member.
+	/// - num_slashing_spans: The number of slashing spans of the pool.
+	fn nomination_pools_withdraw_unbonded(
+		member: Self::RelayChainAccountId,
+		num_slashing_spans: u32,
+	) -> Self::RelayChainCall;
+
 	/// Transfer Staking currency to another account, disallowing "death".
 	///  params:
 	/// - to: The destination for the transfer
@@ -0,0 +1,39 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use sp_core::H160;
+use sp_runtime::codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait TransactionPauseApi<RuntimeCall> where
+		RuntimeCall: Codec,
+	{
+		/// The pallet and function names of the paused calls.
+		fn get_paused_calls() -> Vec<(Vec<u8>, Vec<u8>)>;
+
+		/// Whether the call is paused, matched in the same way as the call filter of the runtime.
+		fn is_call_paused(call: RuntimeCall) -> bool;
+
+		/// The addresses of the paused EVM precompiles.
+		fn get_paused_evm_precompiles() -> Vec<H160>;
+	}
+}
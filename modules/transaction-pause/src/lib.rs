@@ -24,8 +24,9 @@ use frame_support::{
 	traits::{CallMetadata, Contains, GetCallMetadata, PalletInfoAccess},
 };
 use frame_system::pallet_prelude::*;
+use module_support::TransactionPauser;
 use sp_core::H160;
-use sp_runtime::DispatchResult;
+use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::{prelude::*, vec::Vec};
 
 mod mock;
@@ -43,6 +44,9 @@ pub mod module {
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The runtime call, used to validate the pallet and function names of the paused calls.
+		type RuntimeCall: Parameter + GetCallMetadata + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
 		/// The origin which may set filter.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -56,6 +60,8 @@ pub mod module {
 		CannotPause,
 		/// invalid character encoding
 		InvalidCharacter,
+		/// The pallet and function names don't match any call of the runtime
+		CallNotFound,
 	}
 
 	#[pallet::event]
@@ -91,7 +97,10 @@ pub mod module {
 	#[pallet::getter(fn paused_evm_precompiles)]
 	pub type PausedEvmPrecompiles<T: Config> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -104,23 +113,7 @@ pub mod module {
 		#[pallet::weight(T::WeightInfo::pause_transaction())]
 		pub fn pause_transaction(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-
-			// not allowed to pause calls of this pallet to ensure safe
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotPause
-			);
-
-			PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
-					Self::deposit_event(Event::TransactionPaused {
-						pallet_name_bytes: pallet_name,
-						function_name_bytes: function_name,
-					});
-				}
-			});
+			Self::do_pause_transaction(pallet_name, function_name)?;
 			Ok(())
 		}
 
@@ -132,12 +125,11 @@ pub mod module {
 			function_name: Vec<u8>,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
-				Self::deposit_event(Event::TransactionUnpaused {
-					pallet_name_bytes: pallet_name,
-					function_name_bytes: function_name,
-				});
-			};
+			// the calls removed by runtime upgrades can still be unpaused
+			if !PausedTransactions::<T>::contains_key((&pallet_name, &function_name)) {
+				Self::ensure_call_exists(&pallet_name, &function_name)?;
+			}
+			Self::do_unpause_transaction(pallet_name, function_name);
 			Ok(())
 		}
 
@@ -166,12 +158,97 @@ pub mod module {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Ensure the call exists and is not a call of this pallet.
+	fn ensure_pausable(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult {
+		// not allowed to pause calls of this pallet to ensure safe
+		let pallet_name_string = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		ensure!(
+			pallet_name_string != <Self as PalletInfoAccess>::name(),
+			Error::<T>::CannotPause
+		);
+		Self::ensure_call_exists(pallet_name, function_name)
+	}
+
+	/// Pause the call, returns `false` if it is already paused.
+	fn do_pause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> Result<bool, DispatchError> {
+		Self::ensure_pausable(&pallet_name, &function_name)?;
+
+		Ok(PausedTransactions::<T>::mutate_exists(
+			(pallet_name.clone(), function_name.clone()),
+			|maybe_paused| {
+				if maybe_paused.is_none() {
+					*maybe_paused = Some(());
+					Self::deposit_event(Event::TransactionPaused {
+						pallet_name_bytes: pallet_name,
+						function_name_bytes: function_name,
+					});
+					true
+				} else {
+					false
+				}
+			},
+		))
+	}
+
+	/// Unpause the call, returns `false` if it is not paused.
+	fn do_unpause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> bool {
+		if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
+			Self::deposit_event(Event::TransactionUnpaused {
+				pallet_name_bytes: pallet_name,
+				function_name_bytes: function_name,
+			});
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Ensure the pallet and function names match a call of the runtime, so that the typos
+	/// don't silently pause nothing.
+	fn ensure_call_exists(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult {
+		let pallet_name = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		let function_name = sp_std::str::from_utf8(function_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		// `get_call_names` only accepts the names of the pallets with calls
+		ensure!(
+			<T as Config>::RuntimeCall::get_module_names().contains(&pallet_name)
+				&& <T as Config>::RuntimeCall::get_call_names(pallet_name).contains(&function_name),
+			Error::<T>::CallNotFound
+		);
+		Ok(())
+	}
+
+	/// The pallet and function names of the paused calls.
+	pub fn get_paused_calls() -> Vec<(Vec<u8>, Vec<u8>)> {
+		PausedTransactions::<T>::iter_keys().collect()
+	}
+
+	/// The addresses of the paused EVM precompiles.
+	pub fn get_paused_evm_precompiles() -> Vec<H160> {
+		PausedEvmPrecompiles::<T>::iter_keys().collect()
+	}
+}
+
+impl<T: Config> TransactionPauser for Pallet<T> {
+	fn ensure_pausable(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult {
+		Self::ensure_pausable(pallet_name, function_name)
+	}
+
+	fn pause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> Result<bool, DispatchError> {
+		Self::do_pause_transaction(pallet_name, function_name)
+	}
+
+	fn unpause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> bool {
+		Self::do_unpause_transaction(pallet_name, function_name)
+	}
+}
+
 pub struct PausedTransactionFilter<T>(sp_std::marker::PhantomData<T>);
-impl<T: Config> Contains<T::RuntimeCall> for PausedTransactionFilter<T>
+impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for PausedTransactionFilter<T>
 where
 	<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
 {
-	fn contains(call: &T::RuntimeCall) -> bool {
+	fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
 		let CallMetadata {
 			function_name,
 			pallet_name,
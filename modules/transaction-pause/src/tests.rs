@@ -40,25 +40,29 @@ fn pause_transaction_work() {
 		System::set_block_number(1);
 
 		assert_noop!(
-			TransactionPause::pause_transaction(RuntimeOrigin::signed(5), b"Balances".to_vec(), b"transfer".to_vec()),
+			TransactionPause::pause_transaction(
+				RuntimeOrigin::signed(5),
+				b"Balances".to_vec(),
+				b"transfer_allow_death".to_vec()
+			),
 			BadOrigin
 		);
 
 		assert_eq!(
-			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer_allow_death".to_vec())),
 			None
 		);
 		assert_ok!(TransactionPause::pause_transaction(
 			RuntimeOrigin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer_allow_death".to_vec()
 		));
 		System::assert_last_event(RuntimeEvent::TransactionPause(crate::Event::TransactionPaused {
 			pallet_name_bytes: b"Balances".to_vec(),
-			function_name_bytes: b"transfer".to_vec(),
+			function_name_bytes: b"transfer_allow_death".to_vec(),
 		}));
 		assert_eq!(
-			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer_allow_death".to_vec())),
 			Some(())
 		);
 
@@ -78,11 +82,52 @@ fn pause_transaction_work() {
 			),
 			Error::<Runtime>::CannotPause
 		);
-		assert_ok!(TransactionPause::pause_transaction(
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RuntimeOrigin::signed(1),
+				b"OtherPallet".to_vec(),
+				b"pause_transaction".to_vec()
+			),
+			Error::<Runtime>::CallNotFound
+		);
+	});
+}
+
+#[test]
+fn pause_and_unpause_transaction_reject_typos() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(RuntimeOrigin::signed(1), b"Balances".to_vec(), b"transfer".to_vec()),
+			Error::<Runtime>::CallNotFound
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RuntimeOrigin::signed(1),
+				b"balances".to_vec(),
+				b"transfer_allow_death".to_vec()
+			),
+			Error::<Runtime>::CallNotFound
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(RuntimeOrigin::signed(1), b"System".to_vec(), vec![0xff]),
+			Error::<Runtime>::InvalidCharacter
+		);
+		assert_noop!(
+			TransactionPause::unpause_transaction(RuntimeOrigin::signed(1), b"Tokens".to_vec(), b"transfr".to_vec()),
+			Error::<Runtime>::CallNotFound
+		);
+
+		// the paused calls no longer in the runtime can still be unpaused
+		PausedTransactions::<Runtime>::insert((b"Balances".to_vec(), b"transfer".to_vec()), ());
+		assert_ok!(TransactionPause::unpause_transaction(
 			RuntimeOrigin::signed(1),
-			b"OtherPallet".to_vec(),
-			b"pause_transaction".to_vec()
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
 		));
+		assert_eq!(
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			None
+		);
 	});
 }
 
@@ -94,34 +139,92 @@ fn unpause_transaction_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			RuntimeOrigin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer_allow_death".to_vec()
 		));
 		assert_eq!(
-			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer_allow_death".to_vec())),
 			Some(())
 		);
 
 		assert_noop!(
-			TransactionPause::unpause_transaction(RuntimeOrigin::signed(5), b"Balances".to_vec(), b"transfer".to_vec()),
+			TransactionPause::unpause_transaction(
+				RuntimeOrigin::signed(5),
+				b"Balances".to_vec(),
+				b"transfer_allow_death".to_vec()
+			),
 			BadOrigin
 		);
 
 		assert_ok!(TransactionPause::unpause_transaction(
 			RuntimeOrigin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer_allow_death".to_vec()
 		));
 		System::assert_last_event(RuntimeEvent::TransactionPause(crate::Event::TransactionUnpaused {
 			pallet_name_bytes: b"Balances".to_vec(),
-			function_name_bytes: b"transfer".to_vec(),
+			function_name_bytes: b"transfer_allow_death".to_vec(),
 		}));
 		assert_eq!(
-			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer_allow_death".to_vec())),
 			None
 		);
 	});
 }
 
+#[test]
+fn transaction_pauser_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			<TransactionPause as TransactionPauser>::ensure_pausable(b"TransactionPause", b"pause_transaction"),
+			Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			<TransactionPause as TransactionPauser>::ensure_pausable(b"Balances", b"transfer_alow_death"),
+			Error::<Runtime>::CallNotFound
+		);
+		assert_ok!(<TransactionPause as TransactionPauser>::ensure_pausable(
+			b"Balances",
+			b"transfer_allow_death"
+		));
+
+		assert_eq!(
+			<TransactionPause as TransactionPauser>::pause_transaction(
+				b"Balances".to_vec(),
+				b"transfer_allow_death".to_vec()
+			),
+			Ok(true)
+		);
+		System::assert_last_event(RuntimeEvent::TransactionPause(crate::Event::TransactionPaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: b"transfer_allow_death".to_vec(),
+		}));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert_eq!(
+			<TransactionPause as TransactionPauser>::pause_transaction(
+				b"Balances".to_vec(),
+				b"transfer_allow_death".to_vec()
+			),
+			Ok(false)
+		);
+
+		assert!(<TransactionPause as TransactionPauser>::unpause_transaction(
+			b"Balances".to_vec(),
+			b"transfer_allow_death".to_vec()
+		));
+		System::assert_last_event(RuntimeEvent::TransactionPause(crate::Event::TransactionUnpaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: b"transfer_allow_death".to_vec(),
+		}));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(!<TransactionPause as TransactionPauser>::unpause_transaction(
+			b"Balances".to_vec(),
+			b"transfer_allow_death".to_vec()
+		));
+	});
+}
+
 #[test]
 fn paused_transaction_filter_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -154,6 +257,36 @@ fn paused_transaction_filter_work() {
 	});
 }
 
+#[test]
+fn paused_calls_query_consistent_with_filter() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(TransactionPause::get_paused_calls(), vec![]);
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert_eq!(
+			TransactionPause::get_paused_calls(),
+			vec![(b"Tokens".to_vec(), b"transfer".to_vec())]
+		);
+
+		for call in [BALANCE_TRANSFER, TOKENS_TRANSFER] {
+			let CallMetadata {
+				function_name,
+				pallet_name,
+			} = call.get_call_metadata();
+			assert_eq!(
+				TransactionPause::get_paused_calls()
+					.contains(&(pallet_name.as_bytes().to_vec(), function_name.as_bytes().to_vec())),
+				PausedTransactionFilter::<Runtime>::contains(call)
+			);
+		}
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
 #[test]
 fn pause_and_unpause_evm_precompile_works() {
 	use module_support::PrecompilePauseFilter;
@@ -168,6 +301,7 @@ fn pause_and_unpause_evm_precompile_works() {
 		assert!(!PausedPrecompileFilter::<Runtime>::is_paused(one));
 		assert_ok!(TransactionPause::pause_evm_precompile(RuntimeOrigin::signed(1), one));
 		assert!(PausedPrecompileFilter::<Runtime>::is_paused(one));
+		assert_eq!(TransactionPause::get_paused_evm_precompiles(), vec![one]);
 
 		assert_noop!(
 			TransactionPause::unpause_evm_precompile(RuntimeOrigin::signed(2), one),
@@ -176,5 +310,6 @@ fn pause_and_unpause_evm_precompile_works() {
 
 		assert_ok!(TransactionPause::unpause_evm_precompile(RuntimeOrigin::signed(1), one));
 		assert!(!PausedPrecompileFilter::<Runtime>::is_paused(one));
+		assert_eq!(TransactionPause::get_paused_evm_precompiles(), vec![]);
 	});
 }
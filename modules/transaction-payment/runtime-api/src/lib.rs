@@ -0,0 +1,66 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use primitives::{Balance, CurrencyId};
+use scale_info::TypeInfo;
+use sp_runtime::{codec::Codec, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The coarse category of the calls which the transaction fees are spent on.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum FeeCallCategory {
+	Evm,
+	Dex,
+	Honzon,
+	Transfer,
+	Other,
+}
+
+/// The transaction fees spent on the calls of a category with a fee currency within a period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct FeeSpend {
+	/// The category of the calls.
+	pub category: FeeCallCategory,
+	/// The currency which the fees are paid with.
+	pub currency_id: CurrencyId,
+	/// The fees in `currency_id`, converted from the native fees at the swap rate when the
+	/// transaction executed.
+	pub amount: Balance,
+	/// The native fees actually paid, including the tips and the surplus.
+	pub native_amount: Balance,
+	/// The number of the transactions.
+	pub count: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait FeeSwapPathApi<AccountId> where
+		AccountId: Codec,
+	{
+		fn get_effective_fee_path(account: AccountId) -> Option<Vec<CurrencyId>>;
+	}
+
+	pub trait FeeSpendApi<AccountId> where
+		AccountId: Codec,
+	{
+		fn get_fee_summary(account: AccountId, period: u32) -> Vec<FeeSpend>;
+	}
+}
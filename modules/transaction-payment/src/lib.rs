@@ -22,6 +22,15 @@
 //!
 //! Transaction payment module is responsible for charge fee and tip in
 //! different currencies
+//!
+//! ## Fee pool rebalance
+//!
+//! The native balance of a charge fee pool is drained by the fees paid in the foreign currency.
+//! When `FeePoolRebalanceParams` is set for a pool, the offchain worker submits an unsigned
+//! `rebalance_fee_pool` to swap the accumulated foreign currency to native through `Swap` once
+//! the native balance falls below the band. The supply amount, the slippage and the number of
+//! rebalances per period are bounded by the params, and enforced when validating the unsigned
+//! transaction.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -39,8 +48,13 @@ use frame_support::{
 	weights::WeightToFee,
 	BoundedVec, PalletId,
 };
-use frame_system::pallet_prelude::*;
-use module_support::{AggregatedSwapPath, BuyWeightRate, PriceProvider, Ratio, Swap, SwapLimit, TransactionPayment};
+use frame_system::{
+	offchain::{SendTransactionTypes, SubmitTransaction},
+	pallet_prelude::*,
+};
+use module_support::{
+	AggregatedSwapPath, BuyWeightRate, PriceProvider, Ratio, Swap, SwapLimit, TransactionFeePayer, TransactionPayment,
+};
 use orml_traits::MultiCurrency;
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
 use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee};
@@ -52,9 +66,10 @@ use sp_runtime::{
 		Saturating, SignedExtension, Zero,
 	},
 	transaction_validity::{
-		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
+		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
 	},
-	FixedPointNumber, FixedPointOperand, Percent, Perquintill,
+	FixedPointNumber, FixedPointOperand, Percent, Permill, Perquintill,
 };
 use sp_std::prelude::*;
 use xcm::v4::prelude::Location;
@@ -66,12 +81,16 @@ mod tests;
 pub mod weights;
 
 pub use module::*;
+pub use module_transaction_payment_runtime_api::{FeeCallCategory, FeeSpend};
 pub use weights::WeightInfo;
 
 type PalletBalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 type CallOf<T> = <T as Config>::RuntimeCall;
+/// The currency swapped to native asset to pay the transaction fee, and the `supply / target`
+/// rate of the swap.
+type FeeSwap = (CurrencyId, Ratio);
 
 /// A struct to update the weight multiplier per block. It implements
 /// `Convert<Multiplier, Multiplier>`, meaning that it can convert the
@@ -260,6 +279,28 @@ where
 	}
 }
 
+/// Classify the calls into `FeeCallCategory` for the fee spend tracking.
+pub trait ClassifyFeeCall<Call> {
+	/// The category of `call`.
+	fn classify(call: &Call) -> FeeCallCategory;
+
+	/// The category of the batched `calls`, which is their common category, or `Other` if they
+	/// are classified differently.
+	fn classify_batch(calls: &[Call]) -> FeeCallCategory {
+		let mut categories = calls.iter().map(Self::classify);
+		match categories.next() {
+			Some(first) if categories.all(|category| category == first) => first,
+			_ => FeeCallCategory::Other,
+		}
+	}
+}
+
+impl<Call> ClassifyFeeCall<Call> for () {
+	fn classify(_call: &Call) -> FeeCallCategory {
+		FeeCallCategory::Other
+	}
+}
+
 /// Default value for NextFeeMultiplier. This is used in genesis and is also used in
 /// NextFeeMultiplierOnEmpty() to provide a value when none exists in storage.
 const MULTIPLIER_DEFAULT_VALUE: Multiplier = Multiplier::from_u32(1);
@@ -277,8 +318,24 @@ pub mod module {
 		FeeAggregatedPath(Vec<AggregatedSwapPath<CurrencyId>>),
 	}
 
+	/// The params of the offchain rebalance of a charge fee pool.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RebalanceParams<BlockNumber> {
+		/// Rebalance when the native balance of the pool is below this proportion of the pool
+		/// size.
+		pub native_lower_band: Permill,
+		/// The maximum amount of the foreign currency supplied by a rebalance.
+		pub max_supply_amount: Balance,
+		/// The acceptable max slippage of the target amount compared to the quote of the swap.
+		pub max_slippage: Permill,
+		/// The number of blocks of a rebalance period.
+		pub period: BlockNumber,
+		/// The maximum number of rebalances within a period.
+		pub max_rebalances_per_period: u32,
+	}
+
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// The aggregated call type.
@@ -395,6 +452,29 @@ pub mod module {
 
 		/// The origin which change swap balance threshold or enable charge fee pool.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Redirects the fee of the calls signed on behalf of another account, such as the calls
+		/// signed by session keys.
+		type FeePayer: TransactionFeePayer<Self::AccountId, CallOf<Self>>;
+
+		/// Classify the calls for the fee spend tracking.
+		type CallClassifier: ClassifyFeeCall<CallOf<Self>>;
+
+		/// The number of blocks of a period of the fee spend tracking.
+		#[pallet::constant]
+		type FeeSpendPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of periods of the fee spends kept for an account, the fee spends of
+		/// the oldest period are dropped when exceeded.
+		#[pallet::constant]
+		type MaxFeeSpendPeriods: Get<u32>;
+
+		/// A configuration for base priority of unsigned transactions.
+		///
+		/// This is exposed so that it can be tuned for particular runtime, when
+		/// multiple modules send unsigned transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
 	}
 
 	#[pallet::type_value]
@@ -416,6 +496,27 @@ pub mod module {
 		DexNotAvailable,
 		/// Charge fee pool is already exist
 		ChargeFeePoolAlreadyExisted,
+		/// A pair of the swap path is neither an enabled trading pair nor routable
+		UnroutableSwapPath,
+		/// The fee spend tracking is already enabled
+		FeeSpendTrackingAlreadyEnabled,
+		/// The fee spend tracking is not enabled
+		FeeSpendTrackingNotEnabled,
+		/// The fee currency of a call signed on behalf of the fee payer is not in the
+		/// `AlternativeFeeSwapPath` of the fee payer
+		FeeCurrencyNotPreferred,
+		/// The rebalance params are invalid
+		InvalidRebalanceParams,
+		/// The rebalance of the charge fee pool is not configured
+		RebalanceNotConfigured,
+		/// The native balance of the charge fee pool is not below the band
+		FeePoolInBand,
+		/// The supply amount of the rebalance exceeds the bound
+		RebalanceAmountExceeded,
+		/// The target amount of the rebalance exceeds the max slippage
+		RebalanceSlippageExceeded,
+		/// The rebalances of the charge fee pool exceed the cap of the period
+		RebalanceCountExceeded,
 	}
 
 	#[pallet::event]
@@ -453,6 +554,39 @@ pub mod module {
 			actual_tip: PalletBalanceOf<T>,
 			actual_surplus: PalletBalanceOf<T>,
 		},
+		/// The alternative fee swap path of `who` is set.
+		AlternativeFeeSwapPathSet { who: T::AccountId, path: Vec<CurrencyId> },
+		/// The alternative fee swap path of `who` is cleared.
+		AlternativeFeeSwapPathCleared { who: T::AccountId },
+		/// The alternative fee swap path is set, but `who` does not hold the supply currency above
+		/// ED.
+		AlternativeFeeSwapPathBalanceLow { who: T::AccountId, currency_id: CurrencyId },
+		/// The alternative fee swap path of `who` became unroutable, the fee is charged by
+		/// `DefaultFeeTokens` instead.
+		AlternativeFeeSwapPathUnroutable { who: T::AccountId, path: Vec<CurrencyId> },
+		/// The transaction fees and tips of the block are distributed.
+		FeesDistributed {
+			to_treasury: PalletBalanceOf<T>,
+			to_collators: PalletBalanceOf<T>,
+			burned: PalletBalanceOf<T>,
+		},
+		/// The fee spend tracking of `who` is enabled.
+		FeeSpendTrackingEnabled { who: T::AccountId },
+		/// The fee spend tracking of `who` is disabled, and the recorded fee spends are purged.
+		FeeSpendTrackingDisabled { who: T::AccountId },
+		/// The rebalance params of the charge fee pool are updated.
+		FeePoolRebalanceParamsUpdated {
+			currency_id: CurrencyId,
+			params: Option<RebalanceParams<BlockNumberFor<T>>>,
+		},
+		/// The charge fee pool is rebalanced by swapping the foreign currency to native.
+		FeePoolRebalanced {
+			currency_id: CurrencyId,
+			supply_amount: Balance,
+			target_amount: Balance,
+			native_balance: Balance,
+			foreign_balance: Balance,
+		},
 	}
 
 	/// The next fee multiplier.
@@ -509,7 +643,51 @@ pub mod module {
 	#[pallet::getter(fn override_charge_fee_method)]
 	pub type OverrideChargeFeeMethod<T: Config> = StorageValue<_, ChargeFeeMethod, OptionQuery>;
 
+	/// The transaction fees and tips distributed in the current block, removed on finalize.
+	///
+	/// BlockFeesDistributed: (to_treasury, to_collators, burned)
+	#[pallet::storage]
+	#[pallet::getter(fn block_fees_distributed)]
+	pub type BlockFeesDistributed<T: Config> =
+		StorageValue<_, (PalletBalanceOf<T>, PalletBalanceOf<T>, PalletBalanceOf<T>), OptionQuery>;
+
+	/// The periods of the recorded fee spends of the accounts which enabled the fee spend
+	/// tracking, in ascending order.
+	///
+	/// FeeSpendTracking: map AccountId => Option<Vec<u32>>
+	#[pallet::storage]
+	#[pallet::getter(fn fee_spend_tracking)]
+	pub type FeeSpendTracking<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<u32, T::MaxFeeSpendPeriods>, OptionQuery>;
+
+	/// The fee spends of accounts, by the call category and the fee currency within a period.
+	///
+	/// FeeSpends: double_map AccountId, u32 => Vec<FeeSpend>
+	#[pallet::storage]
+	#[pallet::getter(fn fee_spends)]
+	pub type FeeSpends<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, u32, Vec<FeeSpend>, ValueQuery>;
+
+	/// The offchain rebalance params of the charge fee pools.
+	///
+	/// FeePoolRebalanceParams: map CurrencyId => Option<RebalanceParams>
+	#[pallet::storage]
+	#[pallet::getter(fn fee_pool_rebalance_params)]
+	pub type FeePoolRebalanceParams<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, RebalanceParams<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The number of rebalances of the charge fee pools in the latest rebalance period.
+	///
+	/// FeePoolRebalances: map CurrencyId => (BlockNumber, u32)
+	#[pallet::storage]
+	#[pallet::getter(fn fee_pool_rebalances)]
+	pub type FeePoolRebalances<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -517,13 +695,33 @@ pub mod module {
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// `on_initialize` to return the weight used in `on_finalize`.
 		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
-			<T as Config>::WeightInfo::on_finalize()
+			<T as Config>::WeightInfo::on_finalize().saturating_add(T::DbWeight::get().reads_writes(1, 1))
 		}
 
 		fn on_finalize(_: BlockNumberFor<T>) {
 			NextFeeMultiplier::<T>::mutate(|fm| {
 				*fm = T::FeeMultiplierUpdate::convert(*fm);
 			});
+
+			if let Some((to_treasury, to_collators, burned)) = BlockFeesDistributed::<T>::take() {
+				Self::deposit_event(Event::FeesDistributed {
+					to_treasury,
+					to_collators,
+					burned,
+				});
+			}
+		}
+
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			if sp_io::offchain::is_validator() {
+				Self::rebalance_fee_pools_offchain();
+			} else {
+				log::debug!(
+					target: LOG_TARGET,
+					"offchain worker skip rebalance at block {:?}: not a validator",
+					now,
+				);
+			}
 		}
 
 		#[cfg(feature = "std")]
@@ -572,7 +770,8 @@ pub mod module {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Set fee swap path
+		/// Set fee swap path. Every adjacent pair of the path must be routable, and the path must
+		/// end with native currency. `None` or an empty path clears the fee swap path.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_alternative_fee_swap_path())]
 		pub fn set_alternative_fee_swap_path(
@@ -581,20 +780,40 @@ pub mod module {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			if let Some(path) = fee_swap_path {
-				let path: BoundedVec<CurrencyId, T::TradingPathLimit> =
-					path.try_into().map_err(|_| Error::<T>::InvalidSwapPath)?;
-				ensure!(
-					path.len() > 1
-						&& path.first() != Some(&T::NativeCurrencyId::get())
-						&& path.last() == Some(&T::NativeCurrencyId::get()),
-					Error::<T>::InvalidSwapPath
-				);
-				T::Currency::ensure_reserved_named(&DEPOSIT_ID, &who, T::AlternativeFeeSwapDeposit::get())?;
-				AlternativeFeeSwapPath::<T>::insert(&who, &path);
-			} else {
-				AlternativeFeeSwapPath::<T>::remove(&who);
-				T::Currency::unreserve_all_named(&DEPOSIT_ID, &who);
+			match fee_swap_path {
+				Some(path) if !path.is_empty() => {
+					let path: BoundedVec<CurrencyId, T::TradingPathLimit> =
+						path.try_into().map_err(|_| Error::<T>::InvalidSwapPath)?;
+					ensure!(
+						path.len() > 1
+							&& path.first() != Some(&T::NativeCurrencyId::get())
+							&& path.last() == Some(&T::NativeCurrencyId::get()),
+						Error::<T>::InvalidSwapPath
+					);
+					ensure!(Self::is_routable_path(&path), Error::<T>::UnroutableSwapPath);
+					T::Currency::ensure_reserved_named(&DEPOSIT_ID, &who, T::AlternativeFeeSwapDeposit::get())?;
+					AlternativeFeeSwapPath::<T>::insert(&who, &path);
+
+					// the supply currency is only required when the fee is charged, just warn the user.
+					let supply_currency_id = path[0];
+					if T::MultiCurrency::free_balance(supply_currency_id, &who)
+						<= T::MultiCurrency::minimum_balance(supply_currency_id)
+					{
+						Self::deposit_event(Event::AlternativeFeeSwapPathBalanceLow {
+							who: who.clone(),
+							currency_id: supply_currency_id,
+						});
+					}
+					Self::deposit_event(Event::AlternativeFeeSwapPathSet {
+						who,
+						path: path.into_inner(),
+					});
+				}
+				_ => {
+					AlternativeFeeSwapPath::<T>::remove(&who);
+					T::Currency::unreserve_all_named(&DEPOSIT_ID, &who);
+					Self::deposit_event(Event::AlternativeFeeSwapPathCleared { who });
+				}
 			}
 			Ok(())
 		}
@@ -636,8 +855,8 @@ pub mod module {
 			call.dispatch(origin)
 		}
 
-		/// Dapp wrap call, and user pay tx fee as provided currency, this dispatch call should make
-		/// sure the currency is exist in tx fee pool.
+		/// Dapp wrap call, and user pay tx fee as provided currency if native asset is not enough,
+		/// this dispatch call should make sure the currency is exist in tx fee pool.
 		#[pallet::call_index(4)]
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
@@ -670,6 +889,132 @@ pub mod module {
 			ensure_signed(origin.clone())?;
 			call.dispatch(origin)
 		}
+
+		/// Enable the tracking of the transaction fees paid by the origin. The fees are summarized
+		/// by the call category and the fee currency per `FeeSpendPeriod`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::enable_fee_spend_tracking())]
+		pub fn enable_fee_spend_tracking(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!FeeSpendTracking::<T>::contains_key(&who),
+				Error::<T>::FeeSpendTrackingAlreadyEnabled
+			);
+
+			FeeSpendTracking::<T>::insert(&who, BoundedVec::default());
+			Self::deposit_event(Event::FeeSpendTrackingEnabled { who });
+			Ok(())
+		}
+
+		/// Disable the tracking of the transaction fees paid by the origin, and purge the recorded
+		/// fee spends.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::disable_fee_spend_tracking(T::MaxFeeSpendPeriods::get()))]
+		pub fn disable_fee_spend_tracking(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let periods = FeeSpendTracking::<T>::take(&who).ok_or(Error::<T>::FeeSpendTrackingNotEnabled)?;
+
+			for period in periods {
+				FeeSpends::<T>::remove(&who, period);
+			}
+			Self::deposit_event(Event::FeeSpendTrackingDisabled { who });
+			Ok(())
+		}
+
+		/// User wrap call, and pay tx fee as provided currency even though native asset is enough.
+		#[pallet::call_index(9)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(T::WeightInfo::with_fee_preference().saturating_add(dispatch_info.weight), dispatch_info.class,)
+		})]
+		pub fn with_fee_preference(
+			origin: OriginFor<T>,
+			_currency_id: CurrencyId,
+			call: Box<CallOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin.clone())?;
+			call.dispatch(origin)
+		}
+
+		/// Set the offchain rebalance params of an enabled charge fee pool. `None` disables the
+		/// rebalance of the pool.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_pool_rebalance_params())]
+		pub fn set_fee_pool_rebalance_params(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			params: Option<RebalanceParams<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				TokenExchangeRate::<T>::contains_key(currency_id),
+				Error::<T>::InvalidToken
+			);
+
+			match params {
+				Some(params) => {
+					ensure!(
+						!params.period.is_zero() && !params.max_supply_amount.is_zero(),
+						Error::<T>::InvalidRebalanceParams
+					);
+					FeePoolRebalanceParams::<T>::insert(currency_id, params);
+				}
+				None => {
+					FeePoolRebalanceParams::<T>::remove(currency_id);
+					FeePoolRebalances::<T>::remove(currency_id);
+				}
+			}
+			Self::deposit_event(Event::FeePoolRebalanceParamsUpdated { currency_id, params });
+			Ok(())
+		}
+
+		/// Rebalance the charge fee pool by swapping `supply_amount` of the foreign currency to
+		/// native, submitted by the offchain worker as an unsigned transaction.
+		///
+		/// The dispatch origin of this call must be _None_.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::rebalance_fee_pool())]
+		pub fn rebalance_fee_pool(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			supply_amount: Balance,
+			min_target_amount: Balance,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::do_rebalance_fee_pool(currency_id, supply_amount, min_target_amount)
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::rebalance_fee_pool {
+					currency_id,
+					supply_amount,
+					min_target_amount,
+				} => {
+					let (period_index, count) =
+						Self::ensure_rebalance_allowed(*currency_id, *supply_amount, *min_target_amount).map_err(
+							|e| match e {
+								Error::<T>::FeePoolInBand => InvalidTransaction::Stale,
+								Error::<T>::RebalanceCountExceeded => InvalidTransaction::ExhaustsResources,
+								_ => InvalidTransaction::Call,
+							},
+						)?;
+
+					ValidTransaction::with_tag_prefix("TransactionPaymentOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides((currency_id, period_index, count))
+						.longevity(64_u64)
+						.propagate(true)
+						.build()
+				}
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
 	}
 }
 
@@ -860,7 +1205,7 @@ where
 		who: &T::AccountId,
 		fee: PalletBalanceOf<T>,
 		fee_aggregated_path: &[AggregatedSwapPath<CurrencyId>],
-	) -> Result<(T::AccountId, Balance), DispatchError> {
+	) -> Result<(T::AccountId, Balance, Option<FeeSwap>), DispatchError> {
 		log::debug!(
 			target: LOG_TARGET,
 			"charge_fee_aggregated_path: who: {:?}, fee: {:?}, fee_aggregated_path: {:?}",
@@ -870,19 +1215,30 @@ where
 		);
 
 		let custom_fee_surplus = T::CustomFeeSurplus::get().mul_ceil(fee);
+		// the supply currency of a path starting with a Taiga pool is unknown here.
+		let supply_currency_id = match fee_aggregated_path.first() {
+			Some(AggregatedSwapPath::<CurrencyId>::Dex(path)) => path.first().copied(),
+			_ => None,
+		};
 		T::Swap::swap_by_aggregated_path(
 			who,
 			fee_aggregated_path,
 			SwapLimit::ExactTarget(Balance::MAX, fee.saturating_add(custom_fee_surplus)),
 		)
-		.map(|_| (who.clone(), custom_fee_surplus))
+		.map(|swapped| {
+			(
+				who.clone(),
+				custom_fee_surplus,
+				supply_currency_id.map(|currency_id| Self::fee_swap(currency_id, swapped)),
+			)
+		})
 	}
 
 	fn charge_fee_currency(
 		who: &T::AccountId,
 		fee: PalletBalanceOf<T>,
 		fee_currency_id: CurrencyId,
-	) -> Result<(T::AccountId, Balance), DispatchError> {
+	) -> Result<(T::AccountId, Balance, Option<FeeSwap>), DispatchError> {
 		log::debug!(
 			target: LOG_TARGET,
 			"charge_fee_currency: who: {:?}, fee: {:?}, fee_currency_id: {:?}",
@@ -899,9 +1255,9 @@ where
 			(fee.saturating_add(custom_fee_surplus), custom_fee_surplus)
 		};
 
-		if TokenExchangeRate::<T>::contains_key(fee_currency_id) {
+		let swapped = if TokenExchangeRate::<T>::contains_key(fee_currency_id) {
 			// token in charge fee pool should have `TokenExchangeRate` info.
-			Self::swap_from_pool_or_dex(who, fee_amount, fee_currency_id).map(|_| (who.clone(), fee_surplus))
+			Self::swap_from_pool_or_dex(who, fee_amount, fee_currency_id)?
 		} else {
 			// `supply_currency_id` not in charge fee pool, direct swap.
 			T::Swap::swap(
@@ -909,23 +1265,30 @@ where
 				fee_currency_id,
 				T::NativeCurrencyId::get(),
 				SwapLimit::ExactTarget(Balance::MAX, fee_amount),
-			)
-			.map(|_| (who.clone(), fee_surplus))
-		}
+			)?
+		};
+		Ok((who.clone(), fee_surplus, Some(Self::fee_swap(fee_currency_id, swapped))))
 	}
 
-	/// Determine the fee and surplus that should be withdraw from user. There are three kind call:
-	/// - TransactionPayment::with_fee_currency: swap with tx fee pool if token is enable charge fee
-	///   pool, else swap with dex.
+	/// Determine the fee and surplus that should be withdraw from user `who`, the call is signed by
+	/// `signer`. There are four kind call:
+	/// - TransactionPayment::with_fee_currency: first use native asset, if not enough swap with tx
+	///   fee pool if token is enable charge fee pool, else swap with dex.
+	/// - TransactionPayment::with_fee_preference: swap with tx fee pool or dex even though native
+	///   asset is enough.
 	/// - TransactionPayment::with_fee_path: swap with specific trading path.
 	/// - others call: first use native asset, if not enough use alternative, or else use default.
+	///
+	/// If the call is signed on behalf of `who`, the currency of `with_fee_currency` and
+	/// `with_fee_preference` must be in the `AlternativeFeeSwapPath` of `who`.
 	#[transactional]
 	fn ensure_can_charge_fee_with_call(
+		signer: &T::AccountId,
 		who: &T::AccountId,
 		fee: PalletBalanceOf<T>,
 		call: &CallOf<T>,
 		reason: WithdrawReasons,
-	) -> Result<(T::AccountId, Balance), DispatchError> {
+	) -> Result<(T::AccountId, Balance, Option<FeeSwap>), DispatchError> {
 		log::debug!(
 			target: LOG_TARGET,
 			"ensure_can_charge_fee_with_call: who: {:?}, fee: {:?}, call: {:?}",
@@ -982,16 +1345,28 @@ where
 				}
 			}
 			Some(Call::with_fee_currency { currency_id, .. }) => {
+				Self::ensure_fee_currency_preferred(signer, who, *currency_id)?;
+				OverrideChargeFeeMethod::<T>::put(ChargeFeeMethod::FeeCurrency(*currency_id));
+
+				match Self::check_native_is_not_enough(who, fee, reason) {
+					Some(amount) => Self::charge_fee_currency(who, amount, *currency_id),
+					None => Ok((who.clone(), 0, None)),
+				}
+			}
+			Some(Call::with_fee_preference { currency_id, .. }) => {
+				Self::ensure_fee_currency_preferred(signer, who, *currency_id)?;
 				OverrideChargeFeeMethod::<T>::put(ChargeFeeMethod::FeeCurrency(*currency_id));
 
 				let fee = Self::check_native_is_not_enough(who, fee, reason).map_or_else(|| fee, |amount| amount);
 				Self::charge_fee_currency(who, fee, *currency_id)
 			}
-			_ => Self::native_then_alternative_or_default(who, fee, reason).map(|surplus| (who.clone(), surplus)),
+			_ => Self::native_then_alternative_or_default(who, fee, reason)
+				.map(|(surplus, fee_swap)| (who.clone(), surplus, fee_swap)),
 		}
 	}
 
-	/// If native is enough, do nothing, return `Ok(0)` means there are none extra surplus fee.
+	/// If native is enough, do nothing, return `Ok((0, None))` means there are none extra surplus
+	/// fee.
 	/// If native is not enough, try swap from tx fee pool or dex:
 	/// - As user can set his own `AlternativeFeeSwapPath`, this will direct swap from dex. Notice:
 	///   we're using `Swap::swap`, so the real swap path may not equal to `AlternativeFeeSwapPath`,
@@ -1010,7 +1385,7 @@ where
 		who: &T::AccountId,
 		fee: PalletBalanceOf<T>,
 		reason: WithdrawReasons,
-	) -> Result<Balance, DispatchError> {
+	) -> Result<(Balance, Option<FeeSwap>), DispatchError> {
 		log::debug!(
 			target: LOG_TARGET,
 			"native_then_alternative_or_default: who: {:?}, fee: {:?}",
@@ -1024,11 +1399,12 @@ where
 			// if override charge fee method, charge fee by the config.
 			match OverrideChargeFeeMethod::<T>::get() {
 				Some(ChargeFeeMethod::FeeCurrency(fee_currency_id)) => {
-					return Self::charge_fee_currency(who, amount, fee_currency_id).map(|(_, surplus)| surplus)
+					return Self::charge_fee_currency(who, amount, fee_currency_id)
+						.map(|(_, surplus, fee_swap)| (surplus, fee_swap))
 				}
 				Some(ChargeFeeMethod::FeeAggregatedPath(fee_aggregated_path)) => {
 					return Self::charge_fee_aggregated_path(who, amount, &fee_aggregated_path)
-						.map(|(_, surplus)| surplus)
+						.map(|(_, surplus, fee_swap)| (surplus, fee_swap))
 				}
 				None => {
 					// OverrideChargeFeeMethod not set, try other tokens
@@ -1042,16 +1418,35 @@ where
 
 			// alter native fee swap path, swap from dex: O(1)
 			if let Some(path) = AlternativeFeeSwapPath::<T>::get(who) {
-				if T::Swap::swap_by_path(who, &path, SwapLimit::ExactTarget(Balance::MAX, fee_amount)).is_ok() {
-					return Ok(fee_surplus);
+				if let Ok(swapped) = T::Swap::swap_by_path(who, &path, SwapLimit::ExactTarget(Balance::MAX, fee_amount))
+				{
+					return Ok((fee_surplus, Some(Self::fee_swap(path[0], swapped))));
+				}
+
+				if Self::is_routable_path(&path) {
+					// some pairs of the path are only routable by joints.
+					if let Ok(swapped) = T::Swap::swap(
+						who,
+						path[0],
+						T::NativeCurrencyId::get(),
+						SwapLimit::ExactTarget(Balance::MAX, fee_amount),
+					) {
+						return Ok((fee_surplus, Some(Self::fee_swap(path[0], swapped))));
+					}
+				} else {
+					// a pair of the path may have been disabled after the path is set.
+					Self::deposit_event(Event::AlternativeFeeSwapPathUnroutable {
+						who: who.clone(),
+						path: path.into_inner(),
+					});
 				}
 			}
 
 			// default fee tokens, swap from tx fee pool: O(1)
 			for supply_currency_id in T::DefaultFeeTokens::get() {
 				let res = Self::swap_from_pool_or_dex(who, fee_amount, supply_currency_id);
-				if res.is_ok() {
-					return Ok(fee_surplus);
+				if let Ok(swapped) = res {
+					return Ok((fee_surplus, Some(Self::fee_swap(supply_currency_id, swapped))));
 				} else {
 					log::debug!(
 						target: LOG_TARGET,
@@ -1070,8 +1465,8 @@ where
 				.collect::<Vec<_>>();
 			for supply_currency_id in tokens_non_default {
 				let res = Self::swap_from_pool_or_dex(who, custom_fee_amount, supply_currency_id);
-				if res.is_ok() {
-					return Ok(custom_fee_surplus);
+				if let Ok(swapped) = res {
+					return Ok((custom_fee_surplus, Some(Self::fee_swap(supply_currency_id, swapped))));
 				} else {
 					log::debug!(
 						target: LOG_TARGET,
@@ -1087,14 +1482,152 @@ where
 			Err(DispatchError::Other("charge fee failed!"))
 		} else {
 			// native asset is enough
-			Ok(0)
+			Ok((0, None))
+		}
+	}
+
+	/// The fee currency of the calls signed on behalf of `who` must be in the
+	/// `AlternativeFeeSwapPath` of `who`, so that the fee of `who` can't be drained by the
+	/// currencies chosen by others.
+	fn ensure_fee_currency_preferred(
+		signer: &T::AccountId,
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+	) -> DispatchResult {
+		ensure!(
+			signer == who || AlternativeFeeSwapPath::<T>::get(who).is_some_and(|path| path.contains(&currency_id)),
+			Error::<T>::FeeCurrencyNotPreferred
+		);
+		Ok(())
+	}
+
+	/// Check every adjacent pair of the `path` is an enabled trading pair or routable by joints.
+	fn is_routable_path(path: &[CurrencyId]) -> bool {
+		path.windows(2).all(|pair| {
+			T::Swap::get_swap_amount(pair[0], pair[1], SwapLimit::ExactTarget(Balance::MAX, One::one())).is_some()
+		})
+	}
+
+	/// The fee swap path used to charge fee for `who` when native asset is not enough. `None`
+	/// means the fee is charged by `DefaultFeeTokens` and charge fee pools.
+	pub fn get_effective_fee_path(who: &T::AccountId) -> Option<Vec<CurrencyId>> {
+		AlternativeFeeSwapPath::<T>::get(who)
+			.map(|path| path.into_inner())
+			.filter(|path| Self::is_routable_path(path))
+	}
+
+	/// Accumulate the fees distributed in the current block, the `FeesDistributed` event is
+	/// emitted on finalize. Nothing is recorded if all the amounts are zero.
+	pub fn note_fees_distributed(
+		to_treasury: PalletBalanceOf<T>,
+		to_collators: PalletBalanceOf<T>,
+		burned: PalletBalanceOf<T>,
+	) {
+		if to_treasury.is_zero() && to_collators.is_zero() && burned.is_zero() {
+			return;
+		}
+
+		BlockFeesDistributed::<T>::mutate(|distributed| {
+			let (treasury, collators, burn) = distributed.get_or_insert_with(Default::default);
+			*treasury = treasury.saturating_add(to_treasury);
+			*collators = collators.saturating_add(to_collators);
+			*burn = burn.saturating_add(burned);
+		});
+	}
+
+	/// The fee swap of `currency_id` by the `(supply_amount, target_amount)` of the swap.
+	fn fee_swap(currency_id: CurrencyId, (supply_amount, target_amount): (Balance, Balance)) -> FeeSwap {
+		(
+			currency_id,
+			Ratio::saturating_from_rational(supply_amount, target_amount),
+		)
+	}
+
+	/// The category of `call` for the fee spend tracking, the calls wrapped to specify the fee
+	/// payment are classified by the inner calls.
+	fn classify_fee_call(call: &CallOf<T>) -> FeeCallCategory {
+		match call.is_sub_type() {
+			Some(Call::with_fee_path { call, .. })
+			| Some(Call::with_fee_currency { call, .. })
+			| Some(Call::with_fee_preference { call, .. })
+			| Some(Call::with_fee_aggregated_path { call, .. }) => T::CallClassifier::classify(call),
+			_ => T::CallClassifier::classify(call),
 		}
 	}
 
+	/// The period of the fee spend tracking at the current block.
+	pub fn current_fee_spend_period() -> u32 {
+		(frame_system::Pallet::<T>::block_number() / T::FeeSpendPeriod::get()).saturated_into()
+	}
+
+	/// The fee spends of `who` within `period`, empty if none is recorded.
+	pub fn get_fee_summary(who: &T::AccountId, period: u32) -> Vec<FeeSpend> {
+		FeeSpends::<T>::get(who, period)
+	}
+
+	/// Record the native fee `paid` by `who` to the fee spends of the current period if `who`
+	/// enabled the fee spend tracking. If the fee is paid by swapping other currency, it's
+	/// recorded in the supply currency converted at the rate of the swap. The fee spends of the
+	/// oldest period are dropped if `MaxFeeSpendPeriods` is exceeded.
+	fn note_fee_spend(
+		who: &T::AccountId,
+		category: FeeCallCategory,
+		fee_swap: Option<FeeSwap>,
+		paid: PalletBalanceOf<T>,
+	) {
+		if paid.is_zero() {
+			return;
+		}
+
+		let Some(mut periods) = FeeSpendTracking::<T>::get(who) else {
+			return;
+		};
+
+		let period = Self::current_fee_spend_period();
+		if periods.last() != Some(&period) {
+			if periods.is_full() {
+				let oldest = periods.remove(0);
+				FeeSpends::<T>::remove(who, oldest);
+			}
+			// a slot is released above if it's full.
+			let _ = periods.try_push(period);
+			FeeSpendTracking::<T>::insert(who, periods);
+		}
+
+		let (currency_id, amount) = match fee_swap {
+			Some((currency_id, rate)) => (currency_id, rate.saturating_mul_int(paid)),
+			None => (T::NativeCurrencyId::get(), paid),
+		};
+		FeeSpends::<T>::mutate(who, period, |spends| {
+			match spends
+				.iter_mut()
+				.find(|spend| spend.category == category && spend.currency_id == currency_id)
+			{
+				Some(spend) => {
+					spend.amount = spend.amount.saturating_add(amount);
+					spend.native_amount = spend.native_amount.saturating_add(paid);
+					spend.count = spend.count.saturating_add(1);
+				}
+				None => spends.push(FeeSpend {
+					category,
+					currency_id,
+					amount,
+					native_amount: paid,
+					count: 1,
+				}),
+			}
+		});
+	}
+
 	/// swap user's given asset with native asset. prior exchange from charge fee pool, if native
-	/// asset balance of charge fee pool is not enough, swap from dex.
+	/// asset balance of charge fee pool is not enough, swap from dex. Return the supply amount and
+	/// the native amount of the exchange.
 	#[transactional]
-	fn swap_from_pool_or_dex(who: &T::AccountId, amount: Balance, supply_currency_id: CurrencyId) -> DispatchResult {
+	fn swap_from_pool_or_dex(
+		who: &T::AccountId,
+		amount: Balance,
+		supply_currency_id: CurrencyId,
+	) -> Result<(Balance, Balance), DispatchError> {
 		let rate = TokenExchangeRate::<T>::get(supply_currency_id).ok_or(Error::<T>::InvalidRate)?;
 		let sub_account = Self::sub_account_id(supply_currency_id);
 
@@ -1152,7 +1685,7 @@ where
 			supply_account,
 			ExistenceRequirement::AllowDeath,
 		)?;
-		Ok(())
+		Ok((supply_account, supply_amount))
 	}
 
 	/// The sub account derivated by `PalletId`.
@@ -1259,6 +1792,8 @@ where
 		PoolSize::<T>::remove(currency_id);
 		SwapBalanceThreshold::<T>::remove(currency_id);
 		GlobalFeeSwapPath::<T>::remove(currency_id);
+		FeePoolRebalanceParams::<T>::remove(currency_id);
+		FeePoolRebalances::<T>::remove(currency_id);
 
 		Self::deposit_event(Event::ChargeFeePoolDisabled {
 			currency_id,
@@ -1267,6 +1802,119 @@ where
 		});
 		Ok(())
 	}
+
+	/// Check the rebalance of the charge fee pool against the band and the bounds of the params,
+	/// return the current period index and the number of rebalances within it.
+	fn ensure_rebalance_allowed(
+		currency_id: CurrencyId,
+		supply_amount: Balance,
+		min_target_amount: Balance,
+	) -> Result<(BlockNumberFor<T>, u32), Error<T>> {
+		let params = FeePoolRebalanceParams::<T>::get(currency_id).ok_or(Error::<T>::RebalanceNotConfigured)?;
+		let sub_account = Self::sub_account_id(currency_id);
+
+		let native_band = params.native_lower_band.mul_floor(PoolSize::<T>::get(currency_id));
+		ensure!(
+			T::Currency::free_balance(&sub_account) < native_band,
+			Error::<T>::FeePoolInBand
+		);
+
+		let period_index = frame_system::Pallet::<T>::block_number() / params.period;
+		let (last_period_index, last_count) = FeePoolRebalances::<T>::get(currency_id);
+		let count = if last_period_index == period_index {
+			last_count
+		} else {
+			0
+		};
+		ensure!(
+			count < params.max_rebalances_per_period,
+			Error::<T>::RebalanceCountExceeded
+		);
+
+		// keep ED of the foreign currency in the pool, same as the swap on charging fee.
+		let available = T::MultiCurrency::free_balance(currency_id, &sub_account)
+			.saturating_sub(T::MultiCurrency::minimum_balance(currency_id));
+		ensure!(
+			!supply_amount.is_zero() && supply_amount <= params.max_supply_amount.min(available),
+			Error::<T>::RebalanceAmountExceeded
+		);
+
+		let (_, quote_amount) = T::Swap::get_swap_amount(
+			currency_id,
+			T::NativeCurrencyId::get(),
+			SwapLimit::ExactSupply(supply_amount, 0),
+		)
+		.ok_or(Error::<T>::DexNotAvailable)?;
+		ensure!(
+			min_target_amount >= quote_amount.saturating_sub(params.max_slippage.mul_ceil(quote_amount)),
+			Error::<T>::RebalanceSlippageExceeded
+		);
+
+		Ok((period_index, count))
+	}
+
+	#[transactional]
+	fn do_rebalance_fee_pool(
+		currency_id: CurrencyId,
+		supply_amount: Balance,
+		min_target_amount: Balance,
+	) -> DispatchResult {
+		let (period_index, count) = Self::ensure_rebalance_allowed(currency_id, supply_amount, min_target_amount)?;
+		let sub_account = Self::sub_account_id(currency_id);
+
+		let (supply_amount, target_amount) = T::Swap::swap(
+			&sub_account,
+			currency_id,
+			T::NativeCurrencyId::get(),
+			SwapLimit::ExactSupply(supply_amount, min_target_amount),
+		)?;
+		FeePoolRebalances::<T>::insert(currency_id, (period_index, count.saturating_add(1)));
+
+		Self::deposit_event(Event::FeePoolRebalanced {
+			currency_id,
+			supply_amount,
+			target_amount,
+			native_balance: T::Currency::free_balance(&sub_account),
+			foreign_balance: T::MultiCurrency::free_balance(currency_id, &sub_account),
+		});
+		Ok(())
+	}
+
+	/// Submit the unsigned rebalances of the charge fee pools which are below the band.
+	fn rebalance_fee_pools_offchain() {
+		for (currency_id, params) in FeePoolRebalanceParams::<T>::iter() {
+			let sub_account = Self::sub_account_id(currency_id);
+			let available = T::MultiCurrency::free_balance(currency_id, &sub_account)
+				.saturating_sub(T::MultiCurrency::minimum_balance(currency_id));
+			let supply_amount = params.max_supply_amount.min(available);
+
+			let Some((_, quote_amount)) = T::Swap::get_swap_amount(
+				currency_id,
+				T::NativeCurrencyId::get(),
+				SwapLimit::ExactSupply(supply_amount, 0),
+			) else {
+				continue;
+			};
+			let min_target_amount = quote_amount.saturating_sub(params.max_slippage.mul_ceil(quote_amount));
+
+			if Self::ensure_rebalance_allowed(currency_id, supply_amount, min_target_amount).is_err() {
+				continue;
+			}
+
+			let call = Call::<T>::rebalance_fee_pool {
+				currency_id,
+				supply_amount,
+				min_target_amount,
+			};
+			if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+				log::info!(
+					target: LOG_TARGET,
+					"submit unsigned rebalance tx for fee pool {:?} failed!",
+					currency_id,
+				);
+			}
+		}
+	}
 }
 
 /// Calculate the exchange rate of token in transaction fee pool.
@@ -1342,6 +1990,7 @@ where
 			Option<NegativeImbalanceOf<T>>,
 			PalletBalanceOf<T>,
 			T::AccountId,
+			Option<FeeSwap>,
 		),
 		TransactionValidityError,
 	> {
@@ -1350,7 +1999,7 @@ where
 
 		// Only mess with balances if fee is not zero.
 		if fee.is_zero() {
-			return Ok((fee, None, 0, who.clone()));
+			return Ok((fee, None, 0, who.clone(), None));
 		}
 
 		let reason = if tip.is_zero() {
@@ -1359,12 +2008,17 @@ where
 			WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP
 		};
 
-		let (payer, fee_surplus) =
-			Pallet::<T>::ensure_can_charge_fee_with_call(who, fee, call, reason).map_err(|e| {
+		// the fee of the calls signed on behalf of another account is paid by that account
+		let fee_payer = &T::FeePayer::fee_payer(who, call, fee)
+			.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))?
+			.unwrap_or_else(|| who.clone());
+
+		let (payer, fee_surplus, fee_swap) =
+			Pallet::<T>::ensure_can_charge_fee_with_call(who, fee_payer, fee, call, reason).map_err(|e| {
 				log::debug!(
 					target: LOG_TARGET,
 					"ensure_can_charge_fee_with_call who: {:?} fee: {:?} call: {:?} error: {:?}",
-					who,
+					fee_payer,
 					fee,
 					call,
 					e
@@ -1374,7 +2028,7 @@ where
 
 		// withdraw native currency as fee, also consider surplus when swap from dex or pool.
 		match <T as Config>::Currency::withdraw(&payer, fee + fee_surplus, reason, ExistenceRequirement::KeepAlive) {
-			Ok(imbalance) => Ok((fee + fee_surplus, Some(imbalance), fee_surplus, payer)),
+			Ok(imbalance) => Ok((fee + fee_surplus, Some(imbalance), fee_surplus, payer, fee_swap)),
 			Err(_) => Err(InvalidTransaction::Payment.into()),
 		}
 	}
@@ -1477,6 +2131,8 @@ where
 		Option<NegativeImbalanceOf<T>>,
 		PalletBalanceOf<T>, // fee includes surplus
 		PalletBalanceOf<T>, // surplus
+		FeeCallCategory,
+		Option<FeeSwap>,
 	);
 
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
@@ -1490,7 +2146,7 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		let (final_fee, _, _, _) = self.withdraw_fee(who, call, info, len)?;
+		let (final_fee, _, _, _, _) = self.withdraw_fee(who, call, info, len)?;
 		let tip = self.0;
 		Ok(ValidTransaction {
 			priority: Self::get_priority(info, len, tip, final_fee),
@@ -1505,8 +2161,9 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (fee, imbalance, surplus, payer) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, payer, imbalance, fee, surplus))
+		let (fee, imbalance, surplus, payer, fee_swap) = self.withdraw_fee(who, call, info, len)?;
+		let category = Pallet::<T>::classify_fee_call(call);
+		Ok((self.0, payer, imbalance, fee, surplus, category, fee_swap))
 	}
 
 	fn post_dispatch(
@@ -1516,7 +2173,7 @@ where
 		len: usize,
 		_result: &DispatchResult,
 	) -> Result<(), TransactionValidityError> {
-		if let Some((tip, who, Some(payed), fee, surplus)) = pre {
+		if let Some((tip, who, Some(payed), fee, surplus, category, fee_swap)) = pre {
 			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip);
 			let refund_fee = fee.saturating_sub(actual_fee);
 			let mut refund = refund_fee;
@@ -1555,6 +2212,7 @@ where
 				// is gone in that case.
 				Err(_) => payed,
 			};
+			Pallet::<T>::note_fee_spend(&who, category, fee_swap, actual_payment.peek());
 			let (tip, fee) = actual_payment.split(actual_tip);
 
 			// distribute fee
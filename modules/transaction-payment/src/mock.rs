@@ -38,8 +38,9 @@ use primitives::{Amount, ReserveIdentifier, TokenSymbol, TradingPair};
 use smallvec::smallvec;
 use sp_core::{crypto::AccountId32, H160};
 use sp_runtime::{
+	testing::TestXt,
 	traits::{AccountIdConversion, IdentityLookup, One},
-	BuildStorage, Perbill,
+	BuildStorage, DispatchError, Perbill,
 };
 
 pub type AccountId = AccountId32;
@@ -141,6 +142,9 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<Zero, AccountId>;
 	type OnDust = ();
+	type AccountCurrencies = ();
+	type Swap = SpecificJointsSwap<(), ()>;
+	type MaxTransferAllCurrencies = ConstU32<0>;
 }
 
 ord_parameter_types! {
@@ -156,6 +160,7 @@ parameter_types! {
 		TradingPair::from_currency_ids(ACA, LDOT).unwrap(),
 	];
 	pub const TradingPathLimit: u32 = 4;
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
 }
 
 impl module_dex::Config for Runtime {
@@ -171,14 +176,32 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<Zero, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type OnTradingPairEnabled = ();
+	type OnTradingPairDisabled = ();
+	type EvmAddressMapping = MockAddressMapping;
+	type FlashSwapEvmBridge = ();
+	type GasToWeight = ();
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type MinSwapCommitDeposit = ConstU128<100>;
+	type SwapRevealPeriod = ConstU64<10>;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = ConstU32<2>;
+	type LbpCheckpointPeriod = ConstU64<10>;
+	type MaxLbpPools = ConstU32<2>;
 }
 
 impl module_aggregated_dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type DEX = DEXModule;
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
 	type GovernanceOrigin = EnsureSignedBy<Zero, AccountId>;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type DEXExchangeFee = GetExchangeFee;
+	type StableAssetFeePrecision = ConstU128<10_000_000_000>;
+	type Currency = PalletBalances;
+	type DepositPerSwapRecord = ConstU128<10>;
+	type MaxSwapHistory = ConstU32<3>;
 	type WeightInfo = ();
 }
 
@@ -208,6 +231,44 @@ impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DealWithFees
 	}
 }
 
+parameter_types! {
+	/// The fee payers of the signers: (who, payer), the transactions of `who` are rejected if
+	/// the payer is `None`.
+	pub static FeePayers: Vec<(AccountId, Option<AccountId>)> = vec![];
+}
+
+pub struct MockFeePayer;
+impl TransactionFeePayer<AccountId, RuntimeCall> for MockFeePayer {
+	fn fee_payer(who: &AccountId, _call: &RuntimeCall, _fee: Balance) -> Result<Option<AccountId>, DispatchError> {
+		match FeePayers::get().into_iter().find(|(signer, _)| signer == who) {
+			Some((_, Some(payer))) => Ok(Some(payer)),
+			Some((_, None)) => Err(DispatchError::Other("FeePayerRejected")),
+			None => Ok(None),
+		}
+	}
+}
+
+pub struct MockCallClassifier;
+impl ClassifyFeeCall<RuntimeCall> for MockCallClassifier {
+	fn classify(call: &RuntimeCall) -> FeeCallCategory {
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+			| RuntimeCall::Utility(pallet_utility::Call::batch_all { calls })
+			| RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => Self::classify_batch(calls),
+			RuntimeCall::DEXModule(_) => FeeCallCategory::Dex,
+			RuntimeCall::PalletBalances(_) | RuntimeCall::Currencies(_) => FeeCallCategory::Transfer,
+			_ => FeeCallCategory::Other,
+		}
+	}
+}
+
+impl pallet_utility::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	static RelativePrice: Option<Price> = Some(Price::one());
 }
@@ -280,6 +341,11 @@ impl Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type FeePayer = MockFeePayer;
+	type CallClassifier = MockCallClassifier;
+	type FeeSpendPeriod = ConstU64<100>;
+	type MaxFeeSpendPeriods = ConstU32<2>;
+	type UnsignedPriority = ConstU64<1048576>; // 1 << 20
 }
 
 parameter_types! {
@@ -310,9 +376,21 @@ construct_runtime!(
 		Tokens: orml_tokens,
 		Currencies: module_currencies,
 		DEXModule: module_dex,
+		AggregatedDex: module_aggregated_dex,
+		Utility: pallet_utility,
 	}
 );
 
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> SendTransactionTypes<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
 pub struct ExtBuilder {
 	balances: Vec<(AccountId, CurrencyId, Balance)>,
 	base_weight: Weight,
@@ -27,18 +27,19 @@ use frame_support::{
 	dispatch::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, BlockWeights, Currencies, DEXModule, ExtBuilder, FeePoolSize, FeeUnbalancedAmount, MockPriceSource,
-	Runtime, RuntimeCall, RuntimeOrigin, System, TipUnbalancedAmount, TransactionPayment, ACA, ALICE, AUSD, BOB,
-	CHARLIE, DAVE, DOT, LDOT,
+	AccountId, BlockWeights, Currencies, DEXModule, ExtBuilder, Extrinsic, FeePayers, FeePoolSize, FeeUnbalancedAmount,
+	MockPriceSource, Runtime, RuntimeCall, RuntimeOrigin, System, TipUnbalancedAmount, TransactionPayment, ACA, ALICE,
+	AUSD, BOB, CHARLIE, DAVE, DOT, LDOT,
 };
 use module_support::{BuyWeightRate, DEXManager, Price, TransactionPayment as TransactionPaymentT};
 use orml_traits::{MultiCurrency, MultiLockableCurrency};
 use pallet_balances::ReserveData;
 use primitives::currency::*;
+use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
 use sp_io::TestExternalities;
 use sp_runtime::{
 	testing::TestXt,
-	traits::{One, UniqueSaturatedInto},
+	traits::{BadOrigin, One, UniqueSaturatedInto},
 };
 use xcm::v4::prelude::*;
 
@@ -92,6 +93,15 @@ fn with_fee_currency_call(currency_id: CurrencyId) -> <Runtime as Config>::Runti
 	fee_call
 }
 
+fn with_fee_preference_call(currency_id: CurrencyId) -> <Runtime as Config>::RuntimeCall {
+	let fee_call: <Runtime as Config>::RuntimeCall =
+		RuntimeCall::TransactionPayment(crate::mock::transaction_payment::Call::with_fee_preference {
+			currency_id,
+			call: Box::new(CALL),
+		});
+	fee_call
+}
+
 fn with_fee_aggregated_path_by_call(
 	fee_aggregated_path: Vec<AggregatedSwapPath<CurrencyId>>,
 ) -> <Runtime as Config>::RuntimeCall {
@@ -355,9 +365,9 @@ fn pre_post_dispatch_and_refund_native_is_enough() {
 }
 
 #[test]
-fn pre_post_dispatch_and_refund_with_fee_currency_call_default_fee_tokens_work() {
+fn pre_post_dispatch_and_refund_with_fee_preference_call_default_fee_tokens_work() {
 	// default fee token, and enabled by charge fee pool
-	pre_post_dispatch_and_refund_with_fee_currency_call(
+	pre_post_dispatch_and_refund_with_fee_preference_call(
 		AUSD,
 		AlternativeFeeSurplus::get(),
 		Ratio::saturating_from_rational(10, 1),
@@ -365,18 +375,23 @@ fn pre_post_dispatch_and_refund_with_fee_currency_call_default_fee_tokens_work()
 }
 
 #[test]
-fn pre_post_dispatch_and_refund_with_fee_currency_call_non_default_fee_tokens_work() {
+fn pre_post_dispatch_and_refund_with_fee_preference_call_non_default_fee_tokens_work() {
 	// non default fee token, and enabled by charge fee pool
-	pre_post_dispatch_and_refund_with_fee_currency_call(
+	pre_post_dispatch_and_refund_with_fee_preference_call(
 		DOT,
 		CustomFeeSurplus::get(),
 		Ratio::saturating_from_rational(1, 10),
 	);
 }
 
-fn pre_post_dispatch_and_refund_with_fee_currency_call(token: CurrencyId, surplus_percent: Percent, token_rate: Ratio) {
+fn pre_post_dispatch_and_refund_with_fee_preference_call(
+	token: CurrencyId,
+	surplus_percent: Percent,
+	token_rate: Ratio,
+) {
 	builder_with_dex_and_fee_pool(true).execute_with(|| {
-		// with_fee_currency call will swap user's AUSD/DOT out of ACA, then withdraw ACA as fee
+		// with_fee_preference call will swap user's AUSD/DOT out of ACA even though user has enough
+		// ACA, then withdraw ACA as fee
 		let token_subacc = Pallet::<Runtime>::sub_account_id(token);
 		let fee: Balance = 500 * 2 + 1000; // len * byte + weight
 		let surplus = surplus_percent.mul_ceil(fee);
@@ -388,12 +403,12 @@ fn pre_post_dispatch_and_refund_with_fee_currency_call(token: CurrencyId, surplu
 		assert_eq!(89000, aca_init);
 
 		let pre = ChargeTransactionPayment::<Runtime>::from(0)
-			.pre_dispatch(&ALICE, &with_fee_currency_call(token), &INFO, 500)
+			.pre_dispatch(&ALICE, &with_fee_preference_call(token), &INFO, 500)
 			.unwrap();
 		assert_eq!(pre.2, Some(pallet_balances::NegativeImbalance::new(fee_surplus)));
 		assert_eq!(pre.3, fee_surplus);
 
-		// with_fee_currency will set OverrideChargeFeeMethod when pre_dispatch
+		// with_fee_preference will set OverrideChargeFeeMethod when pre_dispatch
 		assert_eq!(
 			OverrideChargeFeeMethod::<Runtime>::get(),
 			Some(ChargeFeeMethod::FeeCurrency(token))
@@ -467,12 +482,12 @@ fn pre_post_dispatch_and_refund_with_fee_currency_call(token: CurrencyId, surplu
 		let token_transfer = token_rate.saturating_mul_int(fee_surplus);
 
 		let pre = ChargeTransactionPayment::<Runtime>::from(tip)
-			.pre_dispatch(&CHARLIE, &with_fee_currency_call(token), &INFO, 500)
+			.pre_dispatch(&CHARLIE, &with_fee_preference_call(token), &INFO, 500)
 			.unwrap();
 		assert_eq!(pre.2, Some(pallet_balances::NegativeImbalance::new(fee_surplus)));
 		assert_eq!(pre.3, fee_surplus);
 
-		// with_fee_currency will set OverrideChargeFeeMethod when pre_dispatch
+		// with_fee_preference will set OverrideChargeFeeMethod when pre_dispatch
 		assert_eq!(
 			OverrideChargeFeeMethod::<Runtime>::get(),
 			Some(ChargeFeeMethod::FeeCurrency(token))
@@ -525,8 +540,8 @@ fn pre_post_dispatch_and_refund_with_fee_currency_call(token: CurrencyId, surplu
 }
 
 #[test]
-fn pre_post_dispatch_and_refund_with_fee_currency_call_use_dex() {
-	pre_post_dispatch_and_refund_with_fee_call_use_dex(with_fee_currency_call(LDOT));
+fn pre_post_dispatch_and_refund_with_fee_preference_call_use_dex() {
+	pre_post_dispatch_and_refund_with_fee_call_use_dex(with_fee_preference_call(LDOT));
 }
 
 #[test]
@@ -663,6 +678,46 @@ fn refund_fee_according_to_actual_when_post_dispatch_and_native_currency_is_enou
 	});
 }
 
+#[test]
+fn charges_fee_from_fee_payer() {
+	builder_with_dex_and_fee_pool(false).execute_with(|| {
+		FeePayers::set(vec![(DAVE, Some(ALICE)), (CHARLIE, None)]);
+		let dave_balance = Currencies::free_balance(ACA, &DAVE);
+		let fee = 23 * 2 + 1000; // len * byte + weight
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&DAVE, &CALL, &INFO, 23)
+			.unwrap();
+		assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - fee);
+		assert_eq!(Currencies::free_balance(ACA, &DAVE), dave_balance);
+
+		// the refund goes back to the payer
+		let refund = 200; // 1000 - 800
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&INFO,
+			&POST_INFO,
+			23,
+			&Ok(())
+		));
+		assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - fee + refund);
+		assert_eq!(Currencies::free_balance(ACA, &DAVE), dave_balance);
+		System::assert_has_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::TransactionFeePaid {
+				who: ALICE,
+				actual_fee: fee - refund,
+				actual_tip: 0,
+				actual_surplus: 0,
+			},
+		));
+
+		// rejected by the fee payer
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(0).validate(&CHARLIE, &CALL, &INFO, 23),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+	});
+}
+
 #[test]
 fn refund_tip_according_to_actual_when_post_dispatch_and_native_currency_is_enough() {
 	builder_with_dex_and_fee_pool(false).execute_with(|| {
@@ -882,6 +937,91 @@ fn charges_fee_when_validate_with_fee_currency_call_use_pool() {
 	});
 }
 
+#[test]
+fn with_fee_currency_call_charges_native_when_native_is_enough() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		assert_ok!(Currencies::update_balance(RuntimeOrigin::root(), ALICE, AUSD, 20000));
+		let aca_init = Currencies::free_balance(ACA, &ALICE);
+		let fee: Balance = 500 * 2 + 1000; // len * byte + weight
+
+		// ALICE has enough ACA, AUSD is not swapped and no surplus is charged.
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&ALICE, &with_fee_currency_call(AUSD), &INFO, 500)
+			.unwrap();
+		assert_eq!(pre.3, fee);
+		assert_eq!(pre.4, 0);
+		assert_eq!(pre.6, None);
+		assert_eq!(Currencies::free_balance(ACA, &ALICE), aca_init - fee);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 20000);
+		assert_eq!(
+			OverrideChargeFeeMethod::<Runtime>::get(),
+			Some(ChargeFeeMethod::FeeCurrency(AUSD))
+		);
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&INFO,
+			&POST_INFO,
+			500,
+			&Ok(())
+		));
+
+		// with_fee_preference swaps AUSD even though ALICE has enough ACA.
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&ALICE, &with_fee_preference_call(AUSD), &INFO, 500)
+			.unwrap();
+		assert_eq!(pre.4, AlternativeFeeSurplus::get().mul_ceil(fee));
+		assert_eq!(pre.6, Some((AUSD, Ratio::saturating_from_rational(10, 1))));
+		assert!(Currencies::free_balance(AUSD, &ALICE) < 20000);
+	});
+}
+
+#[test]
+fn with_fee_currency_call_signed_on_behalf_requires_preferred_currency() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		// DAVE signs the calls on behalf of BOB, who has no ACA but some DOT funded by others.
+		FeePayers::set(vec![(DAVE, Some(BOB))]);
+		assert_ok!(Currencies::update_balance(RuntimeOrigin::root(), BOB, DOT, 10000));
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+
+		for call in [with_fee_currency_call(DOT), with_fee_preference_call(DOT)] {
+			assert_noop!(
+				ChargeTransactionPayment::<Runtime>::from(0).validate(&DAVE, &call, &INFO2, 50),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+			assert_eq!(OverrideChargeFeeMethod::<Runtime>::get(), None);
+		}
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 10000);
+
+		// the calls signed by BOB itself still work.
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).validate(
+			&BOB,
+			&with_fee_currency_call(DOT),
+			&INFO2,
+			50
+		));
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 10); // ED
+		let dot_balance = Currencies::free_balance(DOT, &BOB);
+		assert!(dot_balance < 10000);
+
+		// DOT is in the fee swap path of BOB, the calls signed on behalf of BOB work.
+		AlternativeFeeSwapPath::<Runtime>::insert(BOB, BoundedVec::try_from(vec![DOT, AUSD, ACA]).unwrap());
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).validate(
+			&DAVE,
+			&with_fee_currency_call(DOT),
+			&INFO2,
+			50
+		));
+		assert!(Currencies::free_balance(DOT, &BOB) < dot_balance);
+
+		// LDOT is not in the fee swap path of BOB.
+		assert_ok!(Currencies::update_balance(RuntimeOrigin::root(), BOB, LDOT, 10000));
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(0).validate(&DAVE, &with_fee_currency_call(LDOT), &INFO2, 50),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+	});
+}
+
 #[test]
 fn charges_fee_when_validate_and_native_is_not_enough() {
 	// Enable dex with Alice, and initialize tx charge fee pool
@@ -1080,40 +1220,216 @@ fn charges_fee_failed_by_slippage_limit() {
 
 #[test]
 fn set_alternative_fee_swap_path_work() {
-	ExtBuilder::default()
-		.one_hundred_thousand_for_alice_n_charlie()
-		.build()
-		.execute_with(|| {
-			assert_eq!(TransactionPayment::alternative_fee_swap_path(&ALICE), None);
-			assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		assert_eq!(TransactionPayment::alternative_fee_swap_path(&ALICE), None);
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(ALICE),
+			Some(vec![AUSD, ACA])
+		));
+		assert_eq!(
+			TransactionPayment::alternative_fee_swap_path(&ALICE).unwrap(),
+			vec![AUSD, ACA]
+		);
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::AlternativeFeeSwapPathSet {
+				who: ALICE,
+				path: vec![AUSD, ACA],
+			},
+		));
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(ALICE),
+			None
+		));
+		assert_eq!(TransactionPayment::alternative_fee_swap_path(&ALICE), None);
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::AlternativeFeeSwapPathCleared { who: ALICE },
+		));
+
+		// an empty path clears the fee swap path
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(ALICE),
+			Some(vec![DOT, AUSD, ACA])
+		));
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(ALICE),
+			Some(vec![])
+		));
+		assert_eq!(TransactionPayment::alternative_fee_swap_path(&ALICE), None);
+		assert_eq!(PalletBalances::reserved_balance(&ALICE), 0);
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::AlternativeFeeSwapPathCleared { who: ALICE },
+		));
+	});
+}
+
+#[test]
+fn set_alternative_fee_swap_path_rejects_invalid_path() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		// too short
+		assert_noop!(
+			TransactionPayment::set_alternative_fee_swap_path(RuntimeOrigin::signed(ALICE), Some(vec![ACA])),
+			Error::<Runtime>::InvalidSwapPath
+		);
+
+		// too long
+		assert_noop!(
+			TransactionPayment::set_alternative_fee_swap_path(
 				RuntimeOrigin::signed(ALICE),
-				Some(vec![AUSD, ACA])
-			));
-			assert_eq!(
-				TransactionPayment::alternative_fee_swap_path(&ALICE).unwrap(),
-				vec![AUSD, ACA]
-			);
-			assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+				Some(vec![DOT, AUSD, DOT, AUSD, ACA])
+			),
+			Error::<Runtime>::InvalidSwapPath
+		);
+
+		// not end with native currency
+		assert_noop!(
+			TransactionPayment::set_alternative_fee_swap_path(RuntimeOrigin::signed(ALICE), Some(vec![AUSD, DOT])),
+			Error::<Runtime>::InvalidSwapPath
+		);
+
+		// start with native currency
+		assert_noop!(
+			TransactionPayment::set_alternative_fee_swap_path(RuntimeOrigin::signed(ALICE), Some(vec![ACA, ACA])),
+			Error::<Runtime>::InvalidSwapPath
+		);
+
+		// LDOT-AUSD is not a trading pair, and not routable by joints
+		assert_noop!(
+			TransactionPayment::set_alternative_fee_swap_path(
 				RuntimeOrigin::signed(ALICE),
-				None
-			));
-			assert_eq!(TransactionPayment::alternative_fee_swap_path(&ALICE), None);
+				Some(vec![LDOT, AUSD, ACA])
+			),
+			Error::<Runtime>::UnroutableSwapPath
+		);
 
-			assert_noop!(
-				TransactionPayment::set_alternative_fee_swap_path(RuntimeOrigin::signed(ALICE), Some(vec![ACA])),
-				Error::<Runtime>::InvalidSwapPath
-			);
+		// DOT-ACA is not a trading pair, but routable by AUSD joint
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(ALICE),
+			Some(vec![DOT, ACA])
+		));
 
-			assert_noop!(
-				TransactionPayment::set_alternative_fee_swap_path(RuntimeOrigin::signed(ALICE), Some(vec![AUSD, DOT])),
-				Error::<Runtime>::InvalidSwapPath
-			);
+		// DOT-AUSD is disabled
+		assert_ok!(DEXModule::disable_trading_pair(
+			RuntimeOrigin::signed(crate::mock::Zero::get()),
+			DOT,
+			AUSD
+		));
+		assert_noop!(
+			TransactionPayment::set_alternative_fee_swap_path(RuntimeOrigin::signed(ALICE), Some(vec![DOT, AUSD, ACA])),
+			Error::<Runtime>::UnroutableSwapPath
+		);
+	});
+}
 
-			assert_noop!(
-				TransactionPayment::set_alternative_fee_swap_path(RuntimeOrigin::signed(ALICE), Some(vec![ACA, ACA])),
-				Error::<Runtime>::InvalidSwapPath
-			);
-		});
+#[test]
+fn set_alternative_fee_swap_path_warns_low_balance() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let alternative_fee_swap_deposit: u128 =
+			<<Runtime as Config>::AlternativeFeeSwapDeposit as frame_support::traits::Get<u128>>::get();
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			BOB,
+			ACA,
+			(alternative_fee_swap_deposit + PalletBalances::minimum_balance())
+				.try_into()
+				.unwrap(),
+		));
+
+		// BOB does not hold any DOT, the path is still set.
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(BOB),
+			Some(vec![DOT, AUSD, ACA])
+		));
+		System::assert_has_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::AlternativeFeeSwapPathBalanceLow {
+				who: BOB,
+				currency_id: DOT,
+			},
+		));
+		assert_eq!(
+			TransactionPayment::alternative_fee_swap_path(&BOB).unwrap(),
+			vec![DOT, AUSD, ACA]
+		);
+
+		// ALICE holds AUSD, no warning
+		System::reset_events();
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(ALICE),
+			Some(vec![AUSD, ACA])
+		));
+		assert_eq!(
+			System::events()
+				.into_iter()
+				.filter(|r| matches!(
+					r.event,
+					crate::mock::RuntimeEvent::TransactionPayment(
+						crate::Event::AlternativeFeeSwapPathBalanceLow { .. }
+					)
+				))
+				.count(),
+			0
+		);
+	});
+}
+
+#[test]
+fn unroutable_alternative_fee_swap_path_falls_back_to_default() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let sub_account = Pallet::<Runtime>::sub_account_id(DOT);
+		let dot_ed = Currencies::minimum_balance(DOT);
+		let ed = Currencies::minimum_balance(ACA);
+		let alternative_fee_swap_deposit: u128 =
+			<<Runtime as Config>::AlternativeFeeSwapDeposit as frame_support::traits::Get<u128>>::get();
+
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			BOB,
+			ACA,
+			(alternative_fee_swap_deposit + PalletBalances::minimum_balance())
+				.try_into()
+				.unwrap(),
+		));
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+			DOT,
+			&ALICE,
+			&BOB,
+			300,
+			ExistenceRequirement::AllowDeath
+		));
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(BOB),
+			Some(vec![DOT, AUSD, ACA])
+		));
+		assert_eq!(
+			TransactionPayment::get_effective_fee_path(&BOB),
+			Some(vec![DOT, AUSD, ACA])
+		);
+
+		// DOT-AUSD is disabled after the path is set
+		assert_ok!(DEXModule::disable_trading_pair(
+			RuntimeOrigin::signed(crate::mock::Zero::get()),
+			DOT,
+			AUSD
+		));
+		assert_eq!(TransactionPayment::get_effective_fee_path(&BOB), None);
+		// the stored path is kept
+		assert_eq!(
+			TransactionPayment::alternative_fee_swap_path(&BOB).unwrap(),
+			vec![DOT, AUSD, ACA]
+		);
+
+		// the transaction does not fail, the fee is charged from the DOT charge fee pool.
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).validate(&BOB, &CALL2, &INFO, 500));
+		System::assert_has_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::AlternativeFeeSwapPathUnroutable {
+				who: BOB,
+				path: vec![DOT, AUSD, ACA],
+			},
+		));
+		assert_eq!(Currencies::free_balance(ACA, &BOB), ed);
+		assert!(Currencies::free_balance(DOT, &BOB) < 300);
+		assert!(Currencies::free_balance(DOT, &sub_account) > dot_ed);
+		assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (100, 1000));
+	});
 }
 
 #[test]
@@ -2068,15 +2384,6 @@ fn charge_fee_pool_operation_works() {
 			ACA,
 			alternative_fee_swap_deposit.try_into().unwrap(),
 		));
-		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
-			RuntimeOrigin::signed(ALICE),
-			Some(vec![AUSD, ACA])
-		));
-		assert_eq!(
-			TransactionPayment::alternative_fee_swap_path(&ALICE).unwrap(),
-			vec![AUSD, ACA]
-		);
-
 		assert_ok!(Currencies::update_balance(
 			RuntimeOrigin::root(),
 			ALICE,
@@ -2094,6 +2401,15 @@ fn charge_fee_pool_operation_works() {
 			false
 		));
 
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(ALICE),
+			Some(vec![AUSD, ACA])
+		));
+		assert_eq!(
+			TransactionPayment::alternative_fee_swap_path(&ALICE).unwrap(),
+			vec![AUSD, ACA]
+		);
+
 		let treasury_account: AccountId = <Runtime as Config>::TreasuryAccount::get();
 		let sub_account: AccountId = <Runtime as Config>::PalletId::get().into_sub_account_truncating(AUSD);
 		let usd_ed = <Currencies as MultiCurrency<AccountId>>::minimum_balance(AUSD);
@@ -2210,12 +2526,12 @@ fn with_fee_call_validation_works() {
 			);
 
 			// dex swap not enabled, validate failed.
-			// with_fee_currency test
+			// with_fee_preference test
 			for token in vec![DOT, AUSD] {
 				assert_noop!(
 					ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(
 						&CHARLIE,
-						&with_fee_currency_call(token),
+						&with_fee_preference_call(token),
 						&INFO,
 						10
 					),
@@ -2310,11 +2626,11 @@ fn with_fee_call_validation_works() {
 			// enable dex and enable AUSD, DOT as fee pool
 			enable_dex_and_tx_fee_pool();
 
-			// with_fee_currency test
+			// with_fee_preference test
 			for token in vec![DOT, AUSD] {
 				assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(
 					&CHARLIE,
-					&with_fee_currency_call(token),
+					&with_fee_preference_call(token),
 					&INFO,
 					10
 				));
@@ -2326,11 +2642,11 @@ fn with_fee_call_validation_works() {
 				);
 			}
 
-			// LDOT is not enabled fee pool, cannot charge fee by with_fee_currency
+			// LDOT is not enabled fee pool, cannot charge fee by with_fee_preference
 			assert_noop!(
 				ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(
 					&CHARLIE,
-					&with_fee_currency_call(LDOT),
+					&with_fee_preference_call(LDOT),
 					&INFO,
 					10
 				),
@@ -2365,3 +2681,556 @@ fn with_fee_call_validation_works() {
 			);
 		});
 }
+
+#[test]
+fn note_fees_distributed_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// all zero amounts are ignored
+		TransactionPayment::note_fees_distributed(0, 0, 0);
+		assert_eq!(TransactionPayment::block_fees_distributed(), None);
+
+		TransactionPayment::note_fees_distributed(80, 20, 0);
+		TransactionPayment::note_fees_distributed(50, 0, 10);
+		assert_eq!(TransactionPayment::block_fees_distributed(), Some((130, 20, 10)));
+
+		TransactionPayment::on_finalize(1);
+		assert_eq!(TransactionPayment::block_fees_distributed(), None);
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::FeesDistributed {
+				to_treasury: 130,
+				to_collators: 20,
+				burned: 10,
+			},
+		));
+
+		// no event if nothing distributed in the block
+		System::reset_events();
+		TransactionPayment::on_finalize(2);
+		assert!(System::events().is_empty());
+	});
+}
+
+fn charge_fee_with_post_dispatch(who: &AccountId, call: &<Runtime as Config>::RuntimeCall) {
+	let pre = ChargeTransactionPayment::<Runtime>::from(0)
+		.pre_dispatch(who, call, &INFO, 10)
+		.unwrap();
+	assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+		Some(pre),
+		&INFO,
+		&POST_INFO,
+		10,
+		&Ok(())
+	));
+}
+
+#[test]
+fn fee_spend_tracking_works() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(TransactionPayment::enable_fee_spend_tracking(RuntimeOrigin::signed(
+				ALICE
+			)));
+			System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+				crate::Event::FeeSpendTrackingEnabled { who: ALICE },
+			));
+			assert_eq!(TransactionPayment::fee_spend_tracking(&ALICE), Some(Default::default()));
+			assert_noop!(
+				TransactionPayment::enable_fee_spend_tracking(RuntimeOrigin::signed(ALICE)),
+				Error::<Runtime>::FeeSpendTrackingAlreadyEnabled
+			);
+
+			// the actual fee is 10 * 2 + 800
+			charge_fee_with_post_dispatch(&ALICE, &CALL);
+			charge_fee_with_post_dispatch(&ALICE, &CALL2);
+			charge_fee_with_post_dispatch(&CHARLIE, &CALL);
+			assert_eq!(TransactionPayment::current_fee_spend_period(), 0);
+			assert_eq!(
+				TransactionPayment::fee_spend_tracking(&ALICE).unwrap().into_inner(),
+				vec![0]
+			);
+			assert_eq!(
+				TransactionPayment::get_fee_summary(&ALICE, 0),
+				vec![FeeSpend {
+					category: FeeCallCategory::Transfer,
+					currency_id: ACA,
+					amount: 1640,
+					native_amount: 1640,
+					count: 2,
+				}]
+			);
+			// CHARLIE didn't enable the tracking
+			assert_eq!(TransactionPayment::fee_spend_tracking(&CHARLIE), None);
+			assert!(TransactionPayment::get_fee_summary(&CHARLIE, 0).is_empty());
+
+			System::set_block_number(100);
+			charge_fee_with_post_dispatch(&ALICE, &CALL);
+			assert_eq!(
+				TransactionPayment::fee_spend_tracking(&ALICE).unwrap().into_inner(),
+				vec![0, 1]
+			);
+
+			// the fee spends of the oldest period are dropped when exceeding `MaxFeeSpendPeriods`
+			System::set_block_number(250);
+			charge_fee_with_post_dispatch(&ALICE, &CALL);
+			assert_eq!(
+				TransactionPayment::fee_spend_tracking(&ALICE).unwrap().into_inner(),
+				vec![1, 2]
+			);
+			assert!(TransactionPayment::get_fee_summary(&ALICE, 0).is_empty());
+			for period in [1, 2] {
+				assert_eq!(
+					TransactionPayment::get_fee_summary(&ALICE, period),
+					vec![FeeSpend {
+						category: FeeCallCategory::Transfer,
+						currency_id: ACA,
+						amount: 820,
+						native_amount: 820,
+						count: 1,
+					}]
+				);
+			}
+
+			assert_ok!(TransactionPayment::disable_fee_spend_tracking(RuntimeOrigin::signed(
+				ALICE
+			)));
+			System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+				crate::Event::FeeSpendTrackingDisabled { who: ALICE },
+			));
+			assert_eq!(TransactionPayment::fee_spend_tracking(&ALICE), None);
+			assert!(TransactionPayment::get_fee_summary(&ALICE, 1).is_empty());
+			assert!(TransactionPayment::get_fee_summary(&ALICE, 2).is_empty());
+			assert_noop!(
+				TransactionPayment::disable_fee_spend_tracking(RuntimeOrigin::signed(ALICE)),
+				Error::<Runtime>::FeeSpendTrackingNotEnabled
+			);
+
+			// nothing is recorded after disabled
+			charge_fee_with_post_dispatch(&ALICE, &CALL);
+			assert!(TransactionPayment::get_fee_summary(&ALICE, 2).is_empty());
+		});
+}
+
+#[test]
+fn fee_spend_tracking_attributes_batched_calls() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(TransactionPayment::enable_fee_spend_tracking(RuntimeOrigin::signed(
+				ALICE
+			)));
+
+			let dex_call = RuntimeCall::DEXModule(module_dex::Call::swap_with_exact_supply {
+				path: vec![AUSD, ACA],
+				supply_amount: 10,
+				min_target_amount: 0,
+			});
+			let transfers = RuntimeCall::Utility(pallet_utility::Call::batch {
+				calls: vec![CALL, CALL2],
+			});
+			let nested_transfers = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+				calls: vec![transfers.clone(), CALL],
+			});
+			let mixed = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+				calls: vec![CALL, dex_call.clone()],
+			});
+			let swaps = RuntimeCall::Utility(pallet_utility::Call::force_batch {
+				calls: vec![dex_call.clone(), dex_call],
+			});
+			let empty = RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![] });
+
+			for call in [transfers, nested_transfers, mixed, swaps, empty] {
+				charge_fee_with_post_dispatch(&ALICE, &call);
+			}
+
+			// the actual fee is 10 * 2 + 800
+			assert_eq!(
+				TransactionPayment::get_fee_summary(&ALICE, 0),
+				vec![
+					FeeSpend {
+						category: FeeCallCategory::Transfer,
+						currency_id: ACA,
+						amount: 1640,
+						native_amount: 1640,
+						count: 2,
+					},
+					FeeSpend {
+						category: FeeCallCategory::Other,
+						currency_id: ACA,
+						amount: 1640,
+						native_amount: 1640,
+						count: 2,
+					},
+					FeeSpend {
+						category: FeeCallCategory::Dex,
+						currency_id: ACA,
+						amount: 820,
+						native_amount: 820,
+						count: 1,
+					},
+				]
+			);
+		});
+}
+
+#[test]
+fn fee_spend_tracking_converts_alternative_fee_currency() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		assert_ok!(TransactionPayment::enable_fee_spend_tracking(RuntimeOrigin::signed(
+			ALICE
+		)));
+		assert_ok!(Currencies::update_balance(RuntimeOrigin::root(), ALICE, AUSD, 20000));
+		assert_ok!(Currencies::update_balance(RuntimeOrigin::root(), ALICE, LDOT, 500));
+
+		// swap AUSD by charge fee pool at the rate of 10 AUSD per ACA.
+		let fee_surplus = 2000 + AlternativeFeeSurplus::get().mul_ceil(2000); // 2500
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&ALICE, &with_fee_preference_call(AUSD), &INFO, 500)
+			.unwrap();
+		assert_eq!(pre.3, fee_surplus);
+		assert_eq!(pre.6, Some((AUSD, Ratio::saturating_from_rational(10, 1))));
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&INFO,
+			&POST_INFO,
+			500,
+			&Ok(())
+		));
+
+		// swap LDOT by dex for the batched dex calls.
+		let dex_call = RuntimeCall::DEXModule(module_dex::Call::swap_with_exact_supply {
+			path: vec![AUSD, ACA],
+			supply_amount: 10,
+			min_target_amount: 0,
+		});
+		let with_fee_path_batch =
+			RuntimeCall::TransactionPayment(crate::mock::transaction_payment::Call::with_fee_path {
+				fee_swap_path: vec![LDOT, ACA],
+				call: Box::new(RuntimeCall::Utility(pallet_utility::Call::batch {
+					calls: vec![dex_call.clone(), dex_call],
+				})),
+			});
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&ALICE, &with_fee_path_batch, &INFO2, 50)
+			.unwrap();
+		System::assert_has_event(crate::mock::RuntimeEvent::DEXModule(module_dex::Event::Swap {
+			trader: ALICE,
+			path: vec![LDOT, ACA],
+			liquidity_changes: vec![43, 300],
+		}));
+		assert_eq!(pre.5, FeeCallCategory::Dex);
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&INFO2,
+			&POST_INFO2,
+			50,
+			&Ok(())
+		));
+
+		assert_eq!(
+			TransactionPayment::get_fee_summary(&ALICE, 0),
+			vec![
+				// the actual payment is 2500 - 200 - 50 after refund
+				FeeSpend {
+					category: FeeCallCategory::Transfer,
+					currency_id: AUSD,
+					amount: 22500,
+					native_amount: 2250,
+					count: 1,
+				},
+				// the actual payment is 300 - 20 - 10 after refund, 270 * 43 / 300
+				FeeSpend {
+					category: FeeCallCategory::Dex,
+					currency_id: LDOT,
+					amount: 38,
+					native_amount: 270,
+					count: 1,
+				},
+			]
+		);
+	});
+}
+
+fn rebalance_params() -> RebalanceParams<u64> {
+	RebalanceParams {
+		native_lower_band: Permill::from_percent(50),
+		max_supply_amount: 100,
+		max_slippage: Permill::from_percent(5),
+		period: 10,
+		max_rebalances_per_period: 1,
+	}
+}
+
+fn validate_rebalance(supply_amount: Balance, min_target_amount: Balance) -> TransactionValidity {
+	TransactionPayment::validate_unsigned(
+		TransactionSource::Local,
+		&crate::Call::<Runtime>::rebalance_fee_pool {
+			currency_id: AUSD,
+			supply_amount,
+			min_target_amount,
+		},
+	)
+}
+
+fn rebalance_quote(supply_amount: Balance) -> (Balance, Balance) {
+	let (_, quote_amount) =
+		<Runtime as Config>::Swap::get_swap_amount(AUSD, ACA, SwapLimit::ExactSupply(supply_amount, 0)).unwrap();
+	(
+		quote_amount,
+		quote_amount - Permill::from_percent(5).mul_ceil(quote_amount),
+	)
+}
+
+#[test]
+fn set_fee_pool_rebalance_params_works() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		assert_noop!(
+			TransactionPayment::set_fee_pool_rebalance_params(
+				RuntimeOrigin::signed(BOB),
+				AUSD,
+				Some(rebalance_params())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPayment::set_fee_pool_rebalance_params(
+				RuntimeOrigin::signed(ALICE),
+				LDOT,
+				Some(rebalance_params())
+			),
+			Error::<Runtime>::InvalidToken
+		);
+		assert_noop!(
+			TransactionPayment::set_fee_pool_rebalance_params(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				Some(RebalanceParams {
+					period: 0,
+					..rebalance_params()
+				})
+			),
+			Error::<Runtime>::InvalidRebalanceParams
+		);
+
+		assert_ok!(TransactionPayment::set_fee_pool_rebalance_params(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			Some(rebalance_params())
+		));
+		assert_eq!(
+			TransactionPayment::fee_pool_rebalance_params(AUSD),
+			Some(rebalance_params())
+		);
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::FeePoolRebalanceParamsUpdated {
+				currency_id: AUSD,
+				params: Some(rebalance_params()),
+			},
+		));
+
+		// disable the pool clears the rebalance
+		FeePoolRebalances::<Runtime>::insert(AUSD, (0, 1));
+		assert_ok!(TransactionPayment::disable_charge_fee_pool(
+			RuntimeOrigin::signed(ALICE),
+			AUSD
+		));
+		assert_eq!(TransactionPayment::fee_pool_rebalance_params(AUSD), None);
+		assert_eq!(TransactionPayment::fee_pool_rebalances(AUSD), (0, 0));
+	});
+}
+
+#[test]
+fn rebalance_fee_pool_validate_unsigned_works() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let sub_account: AccountId = <Runtime as Config>::PalletId::get().into_sub_account_truncating(AUSD);
+		assert_eq!(validate_rebalance(100, 0), InvalidTransaction::Call.into());
+		assert_noop!(
+			TransactionPayment::rebalance_fee_pool(RuntimeOrigin::none(), AUSD, 100, 0),
+			Error::<Runtime>::RebalanceNotConfigured
+		);
+
+		assert_ok!(TransactionPayment::set_fee_pool_rebalance_params(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			Some(rebalance_params())
+		));
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			sub_account.clone(),
+			AUSD,
+			200
+		));
+
+		// the native balance of the pool is in band
+		assert_eq!(Currencies::free_balance(ACA, &sub_account), FeePoolSize::get());
+		let (_, min_target_amount) = rebalance_quote(100);
+		assert_eq!(
+			validate_rebalance(100, min_target_amount),
+			InvalidTransaction::Stale.into()
+		);
+		assert_noop!(
+			TransactionPayment::rebalance_fee_pool(RuntimeOrigin::none(), AUSD, 100, min_target_amount),
+			Error::<Runtime>::FeePoolInBand
+		);
+
+		// the pool is drained below the band
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			sub_account.clone(),
+			ACA,
+			-6000
+		));
+		assert!(validate_rebalance(100, min_target_amount).is_ok());
+
+		// the supply amount is out of bound
+		assert_eq!(validate_rebalance(0, 0), InvalidTransaction::Call.into());
+		let (_, min_target_amount_over) = rebalance_quote(101);
+		assert_eq!(
+			validate_rebalance(101, min_target_amount_over),
+			InvalidTransaction::Call.into()
+		);
+		assert_noop!(
+			TransactionPayment::rebalance_fee_pool(RuntimeOrigin::none(), AUSD, 101, min_target_amount_over),
+			Error::<Runtime>::RebalanceAmountExceeded
+		);
+
+		// the min target amount exceeds the max slippage
+		assert_eq!(
+			validate_rebalance(100, min_target_amount - 1),
+			InvalidTransaction::Call.into()
+		);
+		assert_noop!(
+			TransactionPayment::rebalance_fee_pool(RuntimeOrigin::none(), AUSD, 100, min_target_amount - 1),
+			Error::<Runtime>::RebalanceSlippageExceeded
+		);
+
+		// the rebalances of the period are capped
+		FeePoolRebalances::<Runtime>::insert(AUSD, (0, 1));
+		assert_eq!(
+			validate_rebalance(100, min_target_amount),
+			InvalidTransaction::ExhaustsResources.into()
+		);
+		assert_noop!(
+			TransactionPayment::rebalance_fee_pool(RuntimeOrigin::none(), AUSD, 100, min_target_amount),
+			Error::<Runtime>::RebalanceCountExceeded
+		);
+
+		// the signed origin is rejected
+		assert_noop!(
+			TransactionPayment::rebalance_fee_pool(RuntimeOrigin::signed(ALICE), AUSD, 100, min_target_amount),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn rebalance_fee_pool_offchain_worker_works() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = builder_with_dex_and_fee_pool(true);
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		let sub_account: AccountId = <Runtime as Config>::PalletId::get().into_sub_account_truncating(AUSD);
+		assert_ok!(TransactionPayment::set_fee_pool_rebalance_params(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			Some(rebalance_params())
+		));
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			sub_account.clone(),
+			AUSD,
+			200
+		));
+
+		// in band, no rebalance
+		TransactionPayment::offchain_worker(System::block_number());
+		assert!(pool_state.write().transactions.pop().is_none());
+
+		// the fees paid in AUSD drained the pool below the band
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			sub_account.clone(),
+			ACA,
+			-6000
+		));
+		assert_eq!(Currencies::free_balance(ACA, &sub_account), 4000);
+		assert_eq!(Currencies::free_balance(AUSD, &sub_account), 300);
+
+		let (target_amount, min_target_amount) = rebalance_quote(100);
+		TransactionPayment::offchain_worker(System::block_number());
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		let call = crate::Call::<Runtime>::rebalance_fee_pool {
+			currency_id: AUSD,
+			supply_amount: 100,
+			min_target_amount,
+		};
+		assert_eq!(tx.call, RuntimeCall::TransactionPayment(call.clone()));
+		assert!(pool_state.write().transactions.pop().is_none());
+		assert!(TransactionPayment::validate_unsigned(TransactionSource::Local, &call).is_ok());
+
+		assert_ok!(TransactionPayment::rebalance_fee_pool(
+			RuntimeOrigin::none(),
+			AUSD,
+			100,
+			min_target_amount
+		));
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::FeePoolRebalanced {
+				currency_id: AUSD,
+				supply_amount: 100,
+				target_amount,
+				native_balance: 4000 + target_amount,
+				foreign_balance: 200,
+			},
+		));
+		assert_eq!(Currencies::free_balance(ACA, &sub_account), 4000 + target_amount);
+		assert_eq!(Currencies::free_balance(AUSD, &sub_account), 200);
+		assert_eq!(TransactionPayment::fee_pool_rebalances(AUSD), (0, 1));
+
+		// still below the band, but the rebalances of the period are capped
+		assert!(Currencies::free_balance(ACA, &sub_account) < FeePoolSize::get() / 2);
+		TransactionPayment::offchain_worker(System::block_number());
+		assert!(pool_state.write().transactions.pop().is_none());
+
+		// rebalance again in the next period
+		System::set_block_number(10);
+		let (target_amount, min_target_amount) = rebalance_quote(100);
+		TransactionPayment::offchain_worker(System::block_number());
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			RuntimeCall::TransactionPayment(crate::Call::rebalance_fee_pool {
+				currency_id: AUSD,
+				supply_amount: 100,
+				min_target_amount,
+			})
+		);
+		let native_balance = Currencies::free_balance(ACA, &sub_account);
+		assert_ok!(TransactionPayment::rebalance_fee_pool(
+			RuntimeOrigin::none(),
+			AUSD,
+			100,
+			min_target_amount
+		));
+		assert_eq!(
+			Currencies::free_balance(ACA, &sub_account),
+			native_balance + target_amount
+		);
+		assert_eq!(TransactionPayment::fee_pool_rebalances(AUSD), (1, 1));
+
+		// back in band
+		assert!(Currencies::free_balance(ACA, &sub_account) >= FeePoolSize::get() / 2);
+		assert_eq!(
+			validate_rebalance(100, min_target_amount),
+			InvalidTransaction::Stale.into()
+		);
+	});
+}
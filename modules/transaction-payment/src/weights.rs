@@ -53,6 +53,11 @@ pub trait WeightInfo {
 	fn with_fee_path() -> Weight;
 	fn with_fee_aggregated_path() -> Weight;
 	fn with_fee_currency() -> Weight;
+	fn with_fee_preference() -> Weight;
+	fn enable_fee_spend_tracking() -> Weight;
+	fn disable_fee_spend_tracking(p: u32, ) -> Weight;
+	fn set_fee_pool_rebalance_params() -> Weight;
+	fn rebalance_fee_pool() -> Weight;
 }
 
 /// Weights for module_transaction_payment using the Acala node and recommended hardware.
@@ -101,6 +106,10 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		Weight::from_parts(193_000_000, 0)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 	}
+	fn with_fee_preference() -> Weight {
+		Weight::from_parts(193_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
 	// Storage: TransactionPayment NextFeeMultiplier (r:1 w:1)
 	// Storage: System BlockWeight (r:1 w:0)
 	fn on_finalize() -> Weight {
@@ -108,6 +117,40 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: TransactionPayment FeeSpendTracking (r:1 w:1)
+	fn enable_fee_spend_tracking() -> Weight {
+		Weight::from_parts(12_584_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TransactionPayment FeeSpendTracking (r:1 w:1)
+	// Storage: TransactionPayment FeeSpends (r:0 w:1)
+	fn disable_fee_spend_tracking(p: u32, ) -> Weight {
+		Weight::from_parts(14_233_000, 0)
+			.saturating_add(Weight::from_parts(1_702_000, 0).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: TransactionPayment TokenExchangeRate (r:1 w:0)
+	// Storage: TransactionPayment FeePoolRebalanceParams (r:0 w:1)
+	fn set_fee_pool_rebalance_params() -> Weight {
+		Weight::from_parts(14_512_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TransactionPayment FeePoolRebalanceParams (r:1 w:0)
+	// Storage: TransactionPayment PoolSize (r:1 w:0)
+	// Storage: TransactionPayment FeePoolRebalances (r:1 w:1)
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn rebalance_fee_pool() -> Weight {
+		Weight::from_parts(98_736_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -142,4 +185,30 @@ impl WeightInfo for () {
 		Weight::from_parts(193_000_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 	}
+	fn with_fee_preference() -> Weight {
+		Weight::from_parts(193_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	fn enable_fee_spend_tracking() -> Weight {
+		Weight::from_parts(12_584_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn disable_fee_spend_tracking(p: u32, ) -> Weight {
+		Weight::from_parts(14_233_000, 0)
+			.saturating_add(Weight::from_parts(1_702_000, 0).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	fn set_fee_pool_rebalance_params() -> Weight {
+		Weight::from_parts(14_512_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn rebalance_fee_pool() -> Weight {
+		Weight::from_parts(98_736_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
 }
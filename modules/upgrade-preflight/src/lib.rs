@@ -0,0 +1,162 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Upgrade Preflight Module
+//!
+//! Cheap consistency checks of the on-chain state, run before authorizing a runtime upgrade as a
+//! lighter-weight complement to try-runtime:
+//! - the on-chain storage version of every pallet matches its in-code storage version.
+//! - the migrations of the runtime.
+//! - the storage left behind by the pallets removed from the runtime.
+//! - the counts of the known bad states reported by the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	storage::unhashed,
+	traits::{GetStorageVersion, NoStorageVersionSet, PalletInfoAccess, StorageVersion},
+};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_io::hashing::twox_128;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+
+/// The on-chain storage version of a pallet doesn't match its in-code storage version.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct StorageVersionMismatch {
+	pub pallet: Vec<u8>,
+	/// `None` if the pallet doesn't declare a storage version, the on-chain storage version is
+	/// expected to be the default one.
+	pub in_code: Option<u16>,
+	pub on_chain: u16,
+}
+
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PreflightReport {
+	/// The pallets whose on-chain storage version doesn't match the in-code storage version.
+	pub storage_version_mismatches: Vec<StorageVersionMismatch>,
+	/// The names of the migrations of the runtime.
+	pub migrations: Vec<Vec<u8>>,
+	/// The removed pallets which still have storage.
+	pub orphaned_pallets: Vec<Vec<u8>>,
+	/// The name and the count of the known bad states.
+	pub known_bad_states: Vec<(Vec<u8>, u32)>,
+}
+
+impl PreflightReport {
+	/// No mismatched storage version, orphaned storage or known bad state.
+	pub fn is_ok(&self) -> bool {
+		self.storage_version_mismatches.is_empty()
+			&& self.orphaned_pallets.is_empty()
+			&& self.known_bad_states.iter().all(|(_, count)| *count == 0)
+	}
+}
+
+/// The in-code storage version of a pallet, which may not be declared.
+pub trait InCodeStorageVersion {
+	fn version(&self) -> Option<StorageVersion>;
+}
+
+impl InCodeStorageVersion for StorageVersion {
+	fn version(&self) -> Option<StorageVersion> {
+		Some(*self)
+	}
+}
+
+impl InCodeStorageVersion for NoStorageVersionSet {
+	fn version(&self) -> Option<StorageVersion> {
+		None
+	}
+}
+
+/// Compare the on-chain storage version of a pallet with its in-code storage version.
+pub trait CheckPalletStorageVersion {
+	fn storage_version_mismatch() -> Option<StorageVersionMismatch>;
+}
+
+impl<P> CheckPalletStorageVersion for P
+where
+	P: GetStorageVersion + PalletInfoAccess,
+	P::InCodeStorageVersion: InCodeStorageVersion,
+{
+	fn storage_version_mismatch() -> Option<StorageVersionMismatch> {
+		let in_code = P::in_code_storage_version().version();
+		let on_chain = P::on_chain_storage_version();
+		if in_code.unwrap_or_default() == on_chain {
+			return None;
+		}
+
+		let as_u16 = |version: StorageVersion| u16::decode(&mut &version.encode()[..]).unwrap_or_default();
+		Some(StorageVersionMismatch {
+			pallet: P::name().as_bytes().to_vec(),
+			in_code: in_code.map(as_u16),
+			on_chain: as_u16(on_chain),
+		})
+	}
+}
+
+/// Compare the storage versions of all the pallets, implemented for `AllPalletsWithSystem`.
+pub trait CheckStorageVersions {
+	fn storage_version_mismatches(mismatches: &mut Vec<StorageVersionMismatch>);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(96)]
+#[tuple_types_custom_trait_bound(CheckPalletStorageVersion)]
+impl CheckStorageVersions for Tuple {
+	fn storage_version_mismatches(mismatches: &mut Vec<StorageVersionMismatch>) {
+		for_tuples!( #(
+			if let Some(mismatch) = Tuple::storage_version_mismatch() {
+				mismatches.push(mismatch);
+			}
+		)* );
+	}
+}
+
+/// Whether the pallet named `name` still has storage.
+pub fn has_pallet_storage(name: &str) -> bool {
+	unhashed::contains_prefixed_key(&twox_128(name.as_bytes()))
+}
+
+/// Run the preflight checks.
+///
+/// - `migrations`: the names of the migrations of the runtime.
+/// - `removed_pallets`: the names of the pallets removed from the runtime.
+/// - `known_bad_states`: the name and the count of the known bad states.
+pub fn preflight<AllPallets: CheckStorageVersions>(
+	migrations: &[&str],
+	removed_pallets: &[&str],
+	known_bad_states: Vec<(Vec<u8>, u32)>,
+) -> PreflightReport {
+	let mut storage_version_mismatches = Vec::new();
+	AllPallets::storage_version_mismatches(&mut storage_version_mismatches);
+
+	PreflightReport {
+		storage_version_mismatches,
+		migrations: migrations.iter().map(|name| name.as_bytes().to_vec()).collect(),
+		orphaned_pallets: removed_pallets
+			.iter()
+			.filter(|name| has_pallet_storage(name))
+			.map(|name| name.as_bytes().to_vec())
+			.collect(),
+		known_bad_states,
+	}
+}
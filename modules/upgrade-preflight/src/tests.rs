@@ -0,0 +1,100 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the upgrade preflight module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::traits::StorageVersion;
+use mock::*;
+
+type AllPallets = (System, Versioned, Unversioned);
+
+#[test]
+fn preflight_passes_after_genesis() {
+	ExtBuilder::default().build().execute_with(|| {
+		let report = preflight::<AllPallets>(&[], &[], vec![]);
+		assert_eq!(report, PreflightReport::default());
+		assert!(report.is_ok());
+	});
+}
+
+#[test]
+fn preflight_flags_mismatched_storage_version() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the migration to the in-code storage version is not applied
+		StorageVersion::new(1).put::<Versioned>();
+		let report = preflight::<AllPallets>(&[], &[], vec![]);
+		assert_eq!(
+			report.storage_version_mismatches,
+			vec![StorageVersionMismatch {
+				pallet: b"Versioned".to_vec(),
+				in_code: Some(2),
+				on_chain: 1,
+			}]
+		);
+		assert!(!report.is_ok());
+
+		StorageVersion::new(2).put::<Versioned>();
+		assert!(preflight::<AllPallets>(&[], &[], vec![]).is_ok());
+
+		// the pallet without storage version expects the default one
+		StorageVersion::new(1).put::<Unversioned>();
+		assert_eq!(
+			preflight::<AllPallets>(&[], &[], vec![]).storage_version_mismatches,
+			vec![StorageVersionMismatch {
+				pallet: b"Unversioned".to_vec(),
+				in_code: None,
+				on_chain: 1,
+			}]
+		);
+	});
+}
+
+#[test]
+fn preflight_flags_orphaned_pallets() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut key = twox_128(b"Removed").to_vec();
+		key.extend_from_slice(&twox_128(b"Value"));
+
+		assert!(preflight::<AllPallets>(&[], &["Removed"], vec![]).is_ok());
+
+		unhashed::put(&key, &1u32);
+		let report = preflight::<AllPallets>(&[], &["Removed", "AlsoRemoved"], vec![]);
+		assert_eq!(report.orphaned_pallets, vec![b"Removed".to_vec()]);
+		assert!(!report.is_ok());
+	});
+}
+
+#[test]
+fn preflight_reports_migrations_and_known_bad_states() {
+	ExtBuilder::default().build().execute_with(|| {
+		let report = preflight::<AllPallets>(
+			&["module_x::Migration"],
+			&[],
+			vec![(b"module_x::BadStates".to_vec(), 0)],
+		);
+		assert_eq!(report.migrations, vec![b"module_x::Migration".to_vec()]);
+		assert!(report.is_ok());
+
+		let report = preflight::<AllPallets>(&[], &[], vec![(b"module_x::BadStates".to_vec(), 2)]);
+		assert_eq!(report.known_bad_states, vec![(b"module_x::BadStates".to_vec(), 2)]);
+		assert!(!report.is_ok());
+	});
+}
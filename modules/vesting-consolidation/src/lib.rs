@@ -0,0 +1,164 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Vesting Consolidation Module
+//!
+//! A thin wrapper around `orml_vesting` which allows accounts with many vesting schedules to
+//! consolidate them. Fully vested schedules are claimed into the free balance, and still vesting
+//! schedules with the same `start`, `period` and `period_count` are merged into one schedule by
+//! summing their `per_period`. Consolidation never changes the total amount or the unlock
+//! timeline, it only reduces the storage and the weight of future claims.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use orml_vesting::{VestingSchedule, VestingScheduleOf, VestingSchedules};
+use sp_runtime::traits::CheckedAdd;
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + orml_vesting::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There is no fully vested or mergeable vesting schedule.
+		NothingToConsolidate,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Vesting schedules consolidated.
+		SchedulesConsolidated {
+			who: T::AccountId,
+			/// The number of fully vested schedules claimed into the free balance.
+			claimed: u32,
+			/// The number of still vesting schedules merged into other schedules.
+			merged: u32,
+			/// The number of schedules remaining after consolidation.
+			remaining: u32,
+		},
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Consolidate the vesting schedules of the caller.
+		///
+		/// Fully vested schedules are claimed into the free balance, then still vesting schedules
+		/// are merged into the previous schedule with the same `start`, `period` and
+		/// `period_count`.
+		///
+		/// - `max_to_merge`: the maximum number of still vesting schedules to merge.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::consolidate_schedules())]
+		pub fn consolidate_schedules(origin: OriginFor<T>, max_to_merge: u32) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let count_before = VestingSchedules::<T>::decode_len(&who).unwrap_or_default();
+			// claim removes the fully vested schedules and updates the vesting lock.
+			orml_vesting::Pallet::<T>::claim(origin)?;
+
+			let mut schedules = VestingSchedules::<T>::get(&who).into_inner();
+			let claimed = count_before.saturating_sub(schedules.len()) as u32;
+			let merged = Self::merge_schedules(&mut schedules, max_to_merge);
+			ensure!(claimed > 0 || merged > 0, Error::<T>::NothingToConsolidate);
+
+			let remaining = schedules.len() as u32;
+			if merged > 0 {
+				// merging never increases the count of schedules, so nothing is truncated.
+				VestingSchedules::<T>::insert(&who, BoundedVec::truncate_from(schedules));
+			}
+
+			Self::deposit_event(Event::SchedulesConsolidated {
+				who,
+				claimed,
+				merged,
+				remaining,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Merge each schedule into the previous schedule with the same `start`, `period` and
+	/// `period_count` by summing `per_period`, performing at most `max_to_merge` merges. The
+	/// locked amount of the schedules is unchanged at any block. Returns the count of merges.
+	pub fn merge_schedules(schedules: &mut Vec<VestingScheduleOf<T>>, max_to_merge: u32) -> u32 {
+		let mut merged: u32 = 0;
+		let mut consolidated: Vec<VestingScheduleOf<T>> = Vec::with_capacity(schedules.len());
+
+		for schedule in schedules.drain(..) {
+			if merged < max_to_merge {
+				let maybe_target = consolidated.iter_mut().find(|target| {
+					target.start == schedule.start
+						&& target.period == schedule.period
+						&& target.period_count == schedule.period_count
+				});
+
+				if let Some(target) = maybe_target {
+					if let Some(combined) = Self::combine_schedules(target, &schedule) {
+						*target = combined;
+						merged += 1;
+						continue;
+					}
+				}
+			}
+
+			consolidated.push(schedule);
+		}
+
+		*schedules = consolidated;
+		merged
+	}
+
+	fn combine_schedules(a: &VestingScheduleOf<T>, b: &VestingScheduleOf<T>) -> Option<VestingScheduleOf<T>> {
+		let combined = VestingSchedule {
+			start: a.start,
+			period: a.period,
+			period_count: a.period_count,
+			per_period: a.per_period.checked_add(&b.per_period)?,
+		};
+		// the total amount of the combined schedule must not overflow.
+		combined.total_amount().map(|_| combined)
+	}
+}
@@ -0,0 +1,292 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the vesting consolidation module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use orml_vesting::VESTING_LOCK_ID;
+use sp_runtime::traits::BadOrigin;
+
+type Schedule = VestingSchedule<BlockNumber, Balance>;
+
+fn schedule(start: BlockNumber, period: BlockNumber, period_count: u32, per_period: Balance) -> Schedule {
+	Schedule {
+		start,
+		period,
+		period_count,
+		per_period,
+	}
+}
+
+fn vesting_lock(who: &AccountId) -> Balance {
+	pallet_balances::Locks::<Runtime>::get(who)
+		.into_iter()
+		.find(|lock| lock.id == VESTING_LOCK_ID)
+		.map(|lock| lock.amount)
+		.unwrap_or_default()
+}
+
+fn locked_at(schedules: &[Schedule], time: BlockNumber) -> Balance {
+	schedules.iter().map(|s| s.locked_amount(time)).sum()
+}
+
+fn total_of(schedules: &[Schedule]) -> Balance {
+	schedules.iter().map(|s| s.total_amount().unwrap()).sum()
+}
+
+fn last_end(schedules: &[Schedule]) -> BlockNumber {
+	schedules.iter().map(|s| s.end().unwrap()).max().unwrap_or_default()
+}
+
+/// A deterministic xorshift generator for property-style tests.
+struct Rng(u64);
+
+impl Rng {
+	fn next(&mut self, bound: u64) -> u64 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 7;
+		self.0 ^= self.0 << 17;
+		self.0 % bound
+	}
+}
+
+fn random_schedules(rng: &mut Rng, count: usize) -> Vec<Schedule> {
+	(0..count)
+		.map(|_| {
+			schedule(
+				1 + rng.next(4),
+				1 + rng.next(3),
+				1 + rng.next(4) as u32,
+				1 + rng.next(100) as Balance,
+			)
+		})
+		.collect()
+}
+
+#[test]
+fn consolidate_schedules_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			VestingConsolidation::consolidate_schedules(RuntimeOrigin::none(), 10),
+			BadOrigin
+		);
+
+		// fully vested at block 11
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			schedule(1, 5, 2, 100)
+		));
+		// still vesting at block 11, and mergeable
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			schedule(1, 10, 5, 100)
+		));
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			schedule(1, 10, 5, 50)
+		));
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			schedule(1, 10, 5, 30)
+		));
+		// still vesting but has different period_count
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			schedule(1, 10, 4, 100)
+		));
+		assert_eq!(Vesting::vesting_schedules(BOB).len(), 5);
+		assert_eq!(Balances::free_balance(BOB), 1 + 200 + 900 + 400);
+
+		System::set_block_number(11);
+		assert_ok!(VestingConsolidation::consolidate_schedules(
+			RuntimeOrigin::signed(BOB),
+			10
+		));
+		System::assert_last_event(RuntimeEvent::VestingConsolidation(
+			crate::Event::SchedulesConsolidated {
+				who: BOB,
+				claimed: 1,
+				merged: 2,
+				remaining: 2,
+			},
+		));
+		assert_eq!(
+			Vesting::vesting_schedules(BOB).into_inner(),
+			vec![schedule(1, 10, 5, 180), schedule(1, 10, 4, 100)]
+		);
+		assert_eq!(Balances::free_balance(BOB), 1 + 200 + 900 + 400);
+		assert_eq!(vesting_lock(&BOB), 180 * 4 + 100 * 3);
+
+		assert_noop!(
+			VestingConsolidation::consolidate_schedules(RuntimeOrigin::signed(BOB), 10),
+			Error::<Runtime>::NothingToConsolidate
+		);
+	});
+}
+
+#[test]
+fn consolidate_schedules_respects_max_to_merge() {
+	ExtBuilder::default().build().execute_with(|| {
+		for _ in 0..4 {
+			assert_ok!(Vesting::vested_transfer(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				schedule(1, 10, 5, 100)
+			));
+		}
+
+		assert_noop!(
+			VestingConsolidation::consolidate_schedules(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::NothingToConsolidate
+		);
+
+		assert_ok!(VestingConsolidation::consolidate_schedules(
+			RuntimeOrigin::signed(BOB),
+			2
+		));
+		System::assert_last_event(RuntimeEvent::VestingConsolidation(
+			crate::Event::SchedulesConsolidated {
+				who: BOB,
+				claimed: 0,
+				merged: 2,
+				remaining: 2,
+			},
+		));
+		assert_eq!(
+			Vesting::vesting_schedules(BOB).into_inner(),
+			vec![schedule(1, 10, 5, 300), schedule(1, 10, 5, 100)]
+		);
+
+		assert_ok!(VestingConsolidation::consolidate_schedules(
+			RuntimeOrigin::signed(BOB),
+			2
+		));
+		assert_eq!(
+			Vesting::vesting_schedules(BOB).into_inner(),
+			vec![schedule(1, 10, 5, 400)]
+		);
+		assert_eq!(vesting_lock(&BOB), 2_000);
+	});
+}
+
+#[test]
+fn consolidate_schedules_claims_all_vested_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(Vesting::vested_transfer(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				schedule(1, 2, 2, 100)
+			));
+		}
+		assert_eq!(vesting_lock(&BOB), 600);
+
+		System::set_block_number(5);
+		assert_ok!(VestingConsolidation::consolidate_schedules(
+			RuntimeOrigin::signed(BOB),
+			0
+		));
+		System::assert_last_event(RuntimeEvent::VestingConsolidation(
+			crate::Event::SchedulesConsolidated {
+				who: BOB,
+				claimed: 3,
+				merged: 0,
+				remaining: 0,
+			},
+		));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB));
+		assert_eq!(vesting_lock(&BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 601);
+	});
+}
+
+#[test]
+fn merge_schedules_keeps_total_and_timeline() {
+	let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+	for case in 0..200 {
+		let original = random_schedules(&mut rng, 1 + (case % 30));
+		let max_to_merge = rng.next(original.len() as u64 + 1) as u32;
+
+		let mut consolidated = original.clone();
+		let merged = VestingConsolidation::merge_schedules(&mut consolidated, max_to_merge);
+
+		assert!(merged <= max_to_merge);
+		assert_eq!(consolidated.len() + merged as usize, original.len());
+		assert_eq!(total_of(&consolidated), total_of(&original));
+		for time in 0..=last_end(&original) + 1 {
+			assert_eq!(locked_at(&consolidated, time), locked_at(&original, time));
+		}
+	}
+}
+
+#[test]
+fn merge_schedules_skips_overflowing_merge() {
+	let mut schedules = vec![
+		schedule(1, 1, 2, Balance::MAX / 4),
+		schedule(1, 1, 2, Balance::MAX / 4 + 1),
+		schedule(1, 1, 2, 1),
+	];
+	assert_eq!(VestingConsolidation::merge_schedules(&mut schedules, 10), 1);
+	assert_eq!(
+		schedules,
+		vec![
+			schedule(1, 1, 2, Balance::MAX / 4 + 1),
+			schedule(1, 1, 2, Balance::MAX / 4 + 1)
+		]
+	);
+}
+
+#[test]
+fn consolidate_schedules_keeps_balance_and_unlock_schedule() {
+	let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+	for case in 0..20 {
+		ExtBuilder::default().build().execute_with(|| {
+			let original = random_schedules(&mut rng, 10 + case * 4);
+			for s in original.iter() {
+				assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, s.clone()));
+			}
+			let free_balance = Balances::free_balance(BOB);
+			let now = rng.next(last_end(&original) + 2);
+			System::set_block_number(now);
+
+			if VestingConsolidation::consolidate_schedules(RuntimeOrigin::signed(BOB), 100).is_err() {
+				// nothing to consolidate, the schedules must be unchanged.
+				assert_eq!(Vesting::vesting_schedules(BOB).into_inner(), original);
+				return;
+			}
+
+			let consolidated = Vesting::vesting_schedules(BOB).into_inner();
+			assert_eq!(Balances::free_balance(BOB), free_balance);
+			assert_eq!(vesting_lock(&BOB), locked_at(&original, now));
+			for time in now..=last_end(&original) + 1 {
+				assert_eq!(locked_at(&consolidated, time), locked_at(&original, time));
+			}
+		});
+	}
+}
@@ -0,0 +1,53 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{codec::Codec, RuntimeDebug, Weight};
+use xcm::VersionedLocation;
+
+/// The fees of a cross-chain transfer, all amounts are in the transferred asset.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TransferFeeBreakdown<Balance> {
+	/// The weight of the XCM executed locally.
+	pub local_execution_weight: Weight,
+	/// The local execution weight priced at the rate of the XCM trader.
+	pub local_execution_fee: Balance,
+	/// The fee to deliver the XCM to the next hop.
+	pub delivery_fee: Balance,
+	/// The execution fee on the remote chains, `None` if the fee of a remote chain is unknown.
+	pub remote_execution_fee: Option<Balance>,
+	/// The sum of all known fees.
+	pub total: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait XcmFeeApi<CurrencyId, Balance> where
+		CurrencyId: Codec,
+		Balance: Codec,
+	{
+		fn estimate_transfer_fee(
+			currency_id: CurrencyId,
+			amount: Balance,
+			dest: VersionedLocation,
+		) -> Option<TransferFeeBreakdown<Balance>>;
+	}
+}
@@ -33,7 +33,8 @@ use module_support::{relaychain::CallBuilder, HomaSubAccountXcm};
 use orml_traits::XcmTransfer;
 use primitives::{Balance, CurrencyId, EraIndex};
 use scale_info::TypeInfo;
-use sp_runtime::traits::Convert;
+use sp_core::hashing::blake2_256;
+use sp_runtime::traits::{Convert, TrailingZeroInput};
 use sp_std::{convert::From, prelude::*, vec, vec::Vec};
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 
@@ -58,6 +59,10 @@ pub mod module {
 		// `XcmPallet::reserve_transfer_assets` call via proxy account
 		ProxyReserveTransferAssets,
 		HomaNominate,
+		// Homa nomination pool
+		HomaPoolBondExtra,
+		HomaPoolUnbond,
+		HomaPoolWithdrawUnbonded,
 	}
 
 	#[pallet::config]
@@ -127,7 +132,10 @@ pub mod module {
 	pub type XcmDestWeightAndFee<T: Config> =
 		StorageMap<_, Twox64Concat, XcmInterfaceOperation, (XcmWeight, Balance), ValueQuery>;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -171,6 +179,40 @@ pub mod module {
 		}
 	}
 
+	impl<T: Config> Pallet<T> {
+		/// The configured fee of the parachain at `location`, `None` if it has not been configured.
+		pub fn configured_parachain_fee(location: &Location) -> Option<Balance> {
+			XcmDestWeightAndFee::<T>::try_get(XcmInterfaceOperation::ParachainFee(Box::new(location.clone())))
+				.ok()
+				.map(|(_, fee)| fee)
+		}
+
+		/// The account of the Homa sub account on relaychain, same to the derivative account of
+		/// `pallet-utility`.
+		pub fn sub_account_id(sub_account_index: u16) -> T::AccountId {
+			let entropy =
+				(b"modlpy/utilisuba", T::ParachainAccount::get(), sub_account_index).using_encoded(blake2_256);
+			Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+				.expect("infinite length input; no invalid inputs for type; qed")
+		}
+
+		fn send_pool_call(
+			sub_account_index: u16,
+			operation: XcmInterfaceOperation,
+			call: <T::RelayChainCallBuilder as CallBuilder>::RelayChainCall,
+		) -> DispatchResult {
+			let (xcm_dest_weight, xcm_fee) = Self::xcm_dest_weight_and_fee(operation);
+			let xcm_message = T::RelayChainCallBuilder::finalize_call_into_xcm_message(
+				T::RelayChainCallBuilder::utility_as_derivative_call(call, sub_account_index),
+				xcm_fee,
+				xcm_dest_weight,
+			);
+			pallet_xcm::Pallet::<T>::send_xcm(Here, Parent, xcm_message)
+				.map(|_| ())
+				.map_err(|_| Error::<T>::XcmFailed.into())
+		}
+	}
+
 	impl<T: Config> HomaSubAccountXcm<T::AccountId, Balance> for Pallet<T> {
 		type RelayChainAccountId = T::AccountId;
 
@@ -296,6 +338,113 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Send XCM message to the relaychain for sub account to join the nomination pool.
+		fn join_pool_on_sub_account(sub_account_index: u16, pool_id: u32, amount: Balance) -> DispatchResult {
+			let result = Self::send_pool_call(
+				sub_account_index,
+				XcmInterfaceOperation::HomaPoolBondExtra,
+				T::RelayChainCallBuilder::nomination_pools_join(amount, pool_id),
+			);
+			log::debug!(
+				target: "xcm-interface",
+				"subaccount {:?} send XCM to join pool {:?} with {:?}, result: {:?}",
+				sub_account_index, pool_id, amount, result
+			);
+
+			result
+		}
+
+		/// Send XCM message to the relaychain for sub account to bond extra to the joined
+		/// nomination pool.
+		fn bond_extra_to_pool_on_sub_account(sub_account_index: u16, amount: Balance) -> DispatchResult {
+			let result = Self::send_pool_call(
+				sub_account_index,
+				XcmInterfaceOperation::HomaPoolBondExtra,
+				T::RelayChainCallBuilder::nomination_pools_bond_extra(amount),
+			);
+			log::debug!(
+				target: "xcm-interface",
+				"subaccount {:?} send XCM to bond {:?} to pool, result: {:?}",
+				sub_account_index, amount, result
+			);
+
+			result
+		}
+
+		/// Send XCM message to the relaychain for sub account to bond the pending rewards of the
+		/// joined nomination pool.
+		fn bond_pool_rewards_on_sub_account(sub_account_index: u16) -> DispatchResult {
+			let result = Self::send_pool_call(
+				sub_account_index,
+				XcmInterfaceOperation::HomaPoolBondExtra,
+				T::RelayChainCallBuilder::nomination_pools_bond_extra_rewards(),
+			);
+			log::debug!(
+				target: "xcm-interface",
+				"subaccount {:?} send XCM to bond pool rewards, result: {:?}",
+				sub_account_index, result
+			);
+
+			result
+		}
+
+		/// Send XCM message to the relaychain for sub account to unbond points from the joined
+		/// nomination pool.
+		fn unbond_from_pool_on_sub_account(sub_account_index: u16, points: Balance) -> DispatchResult {
+			let result = Self::send_pool_call(
+				sub_account_index,
+				XcmInterfaceOperation::HomaPoolUnbond,
+				T::RelayChainCallBuilder::nomination_pools_unbond(Self::sub_account_id(sub_account_index), points),
+			);
+			log::debug!(
+				target: "xcm-interface",
+				"subaccount {:?} send XCM to unbond {:?} points from pool, result: {:?}",
+				sub_account_index, points, result
+			);
+
+			result
+		}
+
+		/// Send XCM message to the relaychain for sub account to withdraw_unbonded staking
+		/// currency from the joined nomination pool and send it back.
+		fn withdraw_unbonded_from_pool_on_sub_account(sub_account_index: u16, amount: Balance) -> DispatchResult {
+			let (xcm_dest_weight, xcm_fee) =
+				Self::xcm_dest_weight_and_fee(XcmInterfaceOperation::HomaPoolWithdrawUnbonded);
+
+			let xcm_message = T::RelayChainCallBuilder::finalize_multiple_calls_into_xcm_message(
+				vec![
+					(
+						T::RelayChainCallBuilder::utility_as_derivative_call(
+							T::RelayChainCallBuilder::nomination_pools_withdraw_unbonded(
+								Self::sub_account_id(sub_account_index),
+								T::RelayChainUnbondingSlashingSpans::get(),
+							),
+							sub_account_index,
+						),
+						xcm_dest_weight,
+					),
+					(
+						T::RelayChainCallBuilder::utility_as_derivative_call(
+							T::RelayChainCallBuilder::balances_transfer_keep_alive(T::ParachainAccount::get(), amount),
+							sub_account_index,
+						),
+						xcm_dest_weight,
+					),
+				],
+				xcm_fee.saturating_mul(2),
+			);
+
+			let result = pallet_xcm::Pallet::<T>::send_xcm(Here, Parent, xcm_message);
+			log::debug!(
+				target: "xcm-interface",
+				"subaccount {:?} send XCM to withdraw unbonded {:?} from pool, result: {:?}",
+				sub_account_index, amount, result
+			);
+
+			ensure!(result.is_ok(), Error::<T>::XcmFailed);
+			Ok(())
+		}
+
 		/// The fee of cross-chain transfer is deducted from the recipient.
 		fn get_xcm_transfer_fee() -> Balance {
 			Self::xcm_dest_weight_and_fee(XcmInterfaceOperation::XtokensTransfer).1
@@ -19,3 +19,60 @@
 use super::*;
 
 impl_mock!(module_relaychain::KusamaRelayChainCall);
+
+type KusamaCallBuilder = <Runtime as Config>::RelayChainCallBuilder;
+
+#[test]
+fn sub_account_id_works() {
+	ExtBuilder::default().build::<Runtime>().execute_with(|| {
+		assert_eq!(
+			XcmInterface::sub_account_id(0),
+			AccountId32::new([
+				78, 131, 102, 18, 94, 29, 173, 148, 182, 122, 88, 220, 188, 20, 83, 216, 251, 0, 35, 16, 251, 38, 169,
+				208, 164, 255, 0, 128, 188, 130, 220, 7
+			])
+		);
+	});
+}
+
+#[test]
+fn nomination_pools_call_encoding_works() {
+	// join(amount, pool_id)
+	assert_eq!(
+		KusamaCallBuilder::nomination_pools_join(1_000_000_000_000, 5).encode(),
+		vec![41, 0, 7, 0, 16, 165, 212, 232, 5, 0, 0, 0]
+	);
+
+	// bond_extra(BondExtra::FreeBalance(amount))
+	assert_eq!(
+		KusamaCallBuilder::nomination_pools_bond_extra(1_000_000_000_000).encode(),
+		[vec![41, 1, 0], 1_000_000_000_000u128.to_le_bytes().to_vec()].concat()
+	);
+
+	// bond_extra(BondExtra::Rewards)
+	assert_eq!(
+		KusamaCallBuilder::nomination_pools_bond_extra_rewards().encode(),
+		vec![41, 1, 1]
+	);
+
+	// claim_payout()
+	assert_eq!(KusamaCallBuilder::nomination_pools_claim_payout().encode(), vec![41, 2]);
+
+	// unbond(member_account, unbonding_points)
+	assert_eq!(
+		KusamaCallBuilder::nomination_pools_unbond(ALICE, 500_000_000_000).encode(),
+		[vec![41, 3, 0], [1u8; 32].to_vec(), vec![7, 0, 136, 82, 106, 116]].concat()
+	);
+
+	// withdraw_unbonded(member_account, num_slashing_spans)
+	assert_eq!(
+		KusamaCallBuilder::nomination_pools_withdraw_unbonded(ALICE, 28).encode(),
+		[vec![41, 5, 0], [1u8; 32].to_vec(), vec![28, 0, 0, 0]].concat()
+	);
+
+	// as_derivative(index, call)
+	assert_eq!(
+		KusamaCallBuilder::utility_as_derivative_call(KusamaCallBuilder::nomination_pools_claim_payout(), 1).encode(),
+		vec![24, 1, 1, 0, 41, 2]
+	);
+}
@@ -19,3 +19,29 @@
 use super::*;
 
 impl_mock!(module_relaychain::PolkadotRelayChainCall);
+
+type PolkadotCallBuilder = <Runtime as Config>::RelayChainCallBuilder;
+
+#[test]
+fn nomination_pools_call_encoding_works() {
+	assert_eq!(
+		PolkadotCallBuilder::nomination_pools_join(1_000_000_000_000, 5).encode(),
+		vec![39, 0, 7, 0, 16, 165, 212, 232, 5, 0, 0, 0]
+	);
+	assert_eq!(
+		PolkadotCallBuilder::nomination_pools_bond_extra_rewards().encode(),
+		vec![39, 1, 1]
+	);
+	assert_eq!(
+		PolkadotCallBuilder::nomination_pools_claim_payout().encode(),
+		vec![39, 2]
+	);
+	assert_eq!(
+		PolkadotCallBuilder::nomination_pools_unbond(ALICE, 500_000_000_000).encode(),
+		[vec![39, 3, 0], [1u8; 32].to_vec(), vec![7, 0, 136, 82, 106, 116]].concat()
+	);
+	assert_eq!(
+		PolkadotCallBuilder::nomination_pools_withdraw_unbonded(ALICE, 28).encode(),
+		[vec![39, 5, 0], [1u8; 32].to_vec(), vec![28, 0, 0, 0]].concat()
+	);
+}
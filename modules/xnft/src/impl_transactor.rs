@@ -22,8 +22,8 @@ const LOG_TARGET: &str = "xcm::module_xnft::transactor";
 
 impl<T: Config> TransactAsset for Pallet<T>
 where
-	TokenIdOf<T>: TryFrom<u128>,
-	ClassIdOf<T>: TryFrom<u128>,
+	TokenIdOf<T>: TryFrom<u128> + Into<u128>,
+	ClassIdOf<T>: TryFrom<u128> + Into<u128>,
 {
 	fn can_check_in(_origin: &Location, _what: &Asset, _context: &XcmContext) -> XcmResult {
 		Err(XcmError::Unimplemented)
@@ -81,9 +81,15 @@ where
 
 		let from = <ConverterOf<T>>::convert_location(who).ok_or(XcmExecutorError::AccountIdConversionFailed)?;
 
+		let is_local = matches!(class_locality, ClassLocality::Local(_));
 		let token = Self::asset_instance_to_token(class_locality, &asset_instance)
 			.ok_or(XcmExecutorError::InstanceConversionFailed)?;
 
+		// the outbound local item must carry the current royalty of its class
+		if is_local && asset_instance != Self::local_asset_instance(token.0, token.1) {
+			return Err(XcmError::FailedToTransactAsset("non-fungible item royalty mismatch"));
+		}
+
 		<ModuleNftPallet<T>>::do_transfer(&from, &Self::account_id(), token)
 			.map(|_| what.clone().into())
 			.map_err(|_| XcmError::FailedToTransactAsset("non-fungible item withdraw failed"))
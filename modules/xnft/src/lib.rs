@@ -23,7 +23,8 @@ use cumulus_primitives_core::ParaId;
 use frame_support::{ensure, pallet_prelude::*, PalletId};
 use frame_system::pallet_prelude::*;
 use module_nft::{ClassIdOf, TokenIdOf};
-use sp_runtime::{traits::AccountIdConversion, DispatchResult};
+use primitives::Balance;
+use sp_runtime::{traits::AccountIdConversion, DispatchResult, Permill};
 use sp_std::boxed::Box;
 use xcm::{
 	v3,
@@ -39,19 +40,31 @@ use xcm_executor::{
 };
 
 pub mod impl_transactor;
+mod mock;
+mod tests;
+pub mod weights;
 pub mod xcm_helpers;
 
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 pub type ConverterOf<T> = <T as Config>::LocationToAccountId;
 pub type ModuleNftPallet<T> = module_nft::Pallet<T>;
 pub type OrmlNftPallet<T> = orml_nft::Pallet<T>;
 
+/// The royalty of a NFT class, a share of the sale price in whatever currency the item is sold.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltyInfo {
+	/// The share of the sale price paid as royalty.
+	pub rate: Permill,
+	/// The royalty beneficiary, relative to the chain of the collection.
+	pub beneficiary: v3::Location,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 
 	use super::*;
-	use module_nft::WeightInfo as _;
 	use primitives::nft::{ClassProperty, Properties};
 
 	#[pallet::config]
@@ -67,6 +80,9 @@ pub mod pallet {
 		type NtfPalletLocation: Get<InteriorLocation>;
 
 		type RegisterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Error for non-fungible-token module.
@@ -77,6 +93,15 @@ pub mod pallet {
 
 		/// The given asset ID could not be converted into the current XCM version.
 		BadAssetId,
+
+		/// The class does not exist.
+		ClassNotFound,
+
+		/// The origin is not the owner of the class.
+		NoPermission,
+
+		/// The royalty of a foreign class is curated by the `RegisterOrigin`.
+		ForeignClass,
 	}
 
 	#[pallet::event]
@@ -86,6 +111,16 @@ pub mod pallet {
 			asset_id: Box<VersionedAssetId>,
 			collection_id: ClassIdOf<T>,
 		},
+		/// The royalty of the class is updated, `None` means no royalty.
+		ClassRoyaltyUpdated {
+			class_id: ClassIdOf<T>,
+			royalty: Option<RoyaltyInfo>,
+		},
+		/// The royalty of the foreign collection is updated in the registry.
+		ForeignCollectionRoyaltyUpdated {
+			asset_id: Box<VersionedAssetId>,
+			royalty: Option<RoyaltyInfo>,
+		},
 	}
 
 	#[pallet::storage]
@@ -106,16 +141,32 @@ pub mod pallet {
 	pub type ItemToAssetInstance<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Blake2_128Concat, TokenIdOf<T>, v3::AssetInstance, OptionQuery>;
 
+	/// The royalty of the class. For local classes it is encoded into the asset instance of the
+	/// outbound items, for registered foreign classes it is reconstructed from the
+	/// `ForeignCollectionRoyalties` registry.
+	///
+	/// ClassRoyalties: map ClassId => Option<RoyaltyInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn class_royalties)]
+	pub type ClassRoyalties<T: Config> = StorageMap<_, Twox64Concat, ClassIdOf<T>, RoyaltyInfo, OptionQuery>;
+
+	/// The trusted royalty registry of the foreign collections.
+	///
+	/// ForeignCollectionRoyalties: map ForeignAssetId => Option<RoyaltyInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_collection_royalties)]
+	pub type ForeignCollectionRoyalties<T: Config> = StorageMap<_, Twox64Concat, v3::AssetId, RoyaltyInfo, OptionQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(Weight::from_parts(1_000_000, 0)
-			.saturating_add(<module_nft::weights::AcalaWeight<T>>::create_class())
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(2)))]
+		#[pallet::weight(<T as Config>::WeightInfo::register_asset())]
 		pub fn register_asset(origin: OriginFor<T>, versioned_foreign_asset: Box<VersionedAssetId>) -> DispatchResult {
 			T::RegisterOrigin::ensure_origin(origin)?;
 
@@ -141,6 +192,9 @@ pub mod pallet {
 
 			<ForeignAssetToClass<T>>::insert(foreign_asset, collection_id);
 			<ClassToForeignAsset<T>>::insert(collection_id, foreign_asset);
+			if let Some(royalty) = Self::foreign_collection_royalties(foreign_asset) {
+				<ClassRoyalties<T>>::insert(collection_id, royalty);
+			}
 
 			Self::deposit_event(Event::AssetRegistered {
 				asset_id: versioned_foreign_asset,
@@ -149,6 +203,73 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set the royalty of the local class, which is carried by the outbound items.
+		///
+		/// The dispatch origin of this call must be signed by the class owner.
+		///
+		/// - `class_id`: the local class.
+		/// - `royalty`: the royalty of the class, `None` means no royalty.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_class_royalty())]
+		pub fn set_class_royalty(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			royalty: Option<RoyaltyInfo>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let class_info = <OrmlNftPallet<T>>::classes(class_id).ok_or(Error::<T>::ClassNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				!<ClassToForeignAsset<T>>::contains_key(class_id),
+				Error::<T>::ForeignClass
+			);
+
+			<ClassRoyalties<T>>::set(class_id, royalty.clone());
+			Self::deposit_event(Event::ClassRoyaltyUpdated { class_id, royalty });
+
+			Ok(())
+		}
+
+		/// Curate the royalty of the foreign collection. The royalty is reconstructed for the
+		/// class of the collection once it's registered.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		///
+		/// - `versioned_foreign_asset`: the foreign collection.
+		/// - `royalty`: the royalty of the collection, `None` means no royalty.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_foreign_collection_royalty())]
+		pub fn set_foreign_collection_royalty(
+			origin: OriginFor<T>,
+			versioned_foreign_asset: Box<VersionedAssetId>,
+			royalty: Option<RoyaltyInfo>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let foreign_asset: v3::AssetId = versioned_foreign_asset
+				.as_ref()
+				.clone()
+				.try_into()
+				.map_err(|()| Error::<T>::BadAssetId)?;
+
+			<ForeignCollectionRoyalties<T>>::set(foreign_asset, royalty.clone());
+			if let Some(class_id) = Self::foreign_asset_to_class(foreign_asset) {
+				<ClassRoyalties<T>>::set(class_id, royalty.clone());
+				Self::deposit_event(Event::ClassRoyaltyUpdated {
+					class_id,
+					royalty: royalty.clone(),
+				});
+			}
+
+			Self::deposit_event(Event::ForeignCollectionRoyaltyUpdated {
+				asset_id: versioned_foreign_asset,
+				royalty,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -156,4 +277,16 @@ impl<T: Config> Pallet<T> {
 	pub fn account_id() -> T::AccountId {
 		<T as Config>::PalletId::get().into_account_truncating()
 	}
+
+	/// Split the sale revenue of the item of the class, returns the royalty beneficiary with the
+	/// royalty amount, and the remaining amount for the seller.
+	pub fn split_sale_revenue(class_id: ClassIdOf<T>, amount: Balance) -> (Option<(v3::Location, Balance)>, Balance) {
+		match Self::class_royalties(class_id) {
+			Some(RoyaltyInfo { rate, beneficiary }) => {
+				let royalty = rate.mul_floor(amount);
+				(Some((beneficiary, royalty)), amount.saturating_sub(royalty))
+			}
+			None => (None, amount),
+		}
+	}
 }
@@ -0,0 +1,257 @@
+// This file is part of Acala.
+
+// Copyright (C) 2023 Unique Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the xnft module.
+
+#![cfg(test)]
+
+use super::*;
+
+use crate as xnft;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU128, ConstU32, InstanceFilter},
+};
+use frame_system::EnsureRoot;
+use module_nft::{ClassData, NftTask, TokenData};
+use module_support::DispatchableTask;
+use primitives::{define_combined_task, nft::Attributes, task::TaskResult, Nonce, ReserveIdentifier};
+use sp_runtime::{
+	traits::{BlockNumberProvider, IdentityLookup, Zero},
+	BuildStorage,
+};
+
+pub type AccountId = sp_core::crypto::AccountId32;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+#[derive(
+	Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub enum ProxyType {
+	#[default]
+	Any,
+}
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, _c: &RuntimeCall) -> bool {
+		true
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ConstU128<1>;
+	type ProxyDepositFactor = ConstU128<1>;
+	type MaxProxies = ConstU32<4>;
+	type WeightInfo = ();
+	type CallHasher = sp_runtime::traits::BlakeTwo256;
+	type MaxPending = ConstU32<2>;
+	type AnnouncementDepositBase = ConstU128<1>;
+	type AnnouncementDepositFactor = ConstU128<1>;
+}
+
+define_combined_task! {
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum ScheduledTasks {
+		NftTask(NftTask<Runtime>),
+	}
+}
+
+pub struct MockBlockNumberProvider;
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = u32;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Zero::zero()
+	}
+}
+
+parameter_types! {
+	pub MinimumWeightRemainInBlock: Weight = Weight::zero();
+}
+
+impl module_idle_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Index = Nonce;
+	type Task = ScheduledTasks;
+	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
+	type DisableBlockThreshold = ConstU32<6>;
+}
+
+parameter_types! {
+	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
+}
+
+impl module_nft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type CreateClassDeposit = ConstU128<200>;
+	type CreateTokenDeposit = ConstU128<100>;
+	type DataDepositPerByte = ConstU128<10>;
+	type PalletId = NftPalletId;
+	type MaxAttributesBytes = ConstU32<10>;
+	type MaxAirdropRecipients = ConstU32<200>;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
+	type WeightInfo = ();
+}
+
+impl orml_nft::Config for Runtime {
+	type ClassId = u32;
+	type TokenId = u64;
+	type ClassData = ClassData<Balance>;
+	type TokenData = TokenData<Balance>;
+	type MaxClassMetadata = ConstU32<1024>;
+	type MaxTokenMetadata = ConstU32<1024>;
+}
+
+/// Converts the local `AccountId32` location into the account.
+pub struct AccountId32Converter;
+impl ConvertLocation<AccountId> for AccountId32Converter {
+	fn convert_location(location: &Location) -> Option<AccountId> {
+		match location.unpack() {
+			(0, [AccountId32 { id, .. }]) => Some((*id).into()),
+			_ => None,
+		}
+	}
+}
+
+pub const SELF_PARA_ID: u32 = 2000;
+pub const NFT_PALLET_INDEX: u8 = 121;
+
+parameter_types! {
+	pub const XnftPalletId: PalletId = PalletId(*b"aca/xNFT");
+	pub SelfParaId: ParaId = SELF_PARA_ID.into();
+	pub NftPalletLocation: InteriorLocation = PalletInstance(NFT_PALLET_INDEX).into();
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletId = XnftPalletId;
+	type LocationToAccountId = AccountId32Converter;
+	type SelfParaId = SelfParaId;
+	type NtfPalletLocation = NftPalletLocation;
+	type RegisterOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Proxy: pallet_proxy,
+		IdleScheduler: module_idle_scheduler,
+		OrmlNFT: orml_nft,
+		NFTModule: module_nft,
+		XNFT: xnft,
+	}
+);
+
+pub const ALICE: AccountId = AccountId::new([1u8; 32]);
+pub const BOB: AccountId = AccountId::new([2u8; 32]);
+
+pub fn account_location(who: &AccountId) -> Location {
+	Location::new(
+		0,
+		[AccountId32 {
+			network: None,
+			id: who.clone().into(),
+		}],
+	)
+}
+
+/// Create a transferable local class owned by `owner`, and mint a token of it to `owner`.
+pub fn create_local_class_and_token(owner: &AccountId) -> (ClassIdOf<Runtime>, TokenIdOf<Runtime>) {
+	let class_id = OrmlNFT::create_class(
+		owner,
+		Default::default(),
+		ClassData {
+			deposit: Default::default(),
+			properties: primitives::nft::Properties(
+				primitives::nft::ClassProperty::Transferable | primitives::nft::ClassProperty::Burnable,
+			),
+			attributes: Attributes::new(),
+		},
+	)
+	.unwrap();
+	let token_id = OrmlNFT::mint(
+		owner,
+		class_id,
+		Default::default(),
+		TokenData {
+			deposit: Default::default(),
+			attributes: Attributes::new(),
+		},
+	)
+	.unwrap();
+	(class_id, token_id)
+}
+
+pub struct ExtBuilder;
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(ALICE, 100000), (BOB, 100000), (XNFT::account_id(), 100000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,294 @@
+// This file is part of Acala.
+
+// Copyright (C) 2023 Unique Network.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the xnft module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use sp_runtime::traits::BadOrigin;
+use xcm_helpers::{decode_local_asset_instance, encode_local_asset_instance};
+
+fn royalty(percent: u32) -> RoyaltyInfo {
+	RoyaltyInfo {
+		rate: Permill::from_percent(percent),
+		beneficiary: v3::Location::new(
+			1,
+			v3::Junctions::X2(
+				v3::Junction::Parachain(SELF_PARA_ID),
+				v3::Junction::AccountId32 {
+					network: None,
+					id: [1u8; 32],
+				},
+			),
+		),
+	}
+}
+
+fn foreign_collection() -> v3::AssetId {
+	v3::AssetId::Concrete(v3::Location::new(
+		1,
+		v3::Junctions::X2(v3::Junction::Parachain(1000), v3::Junction::GeneralIndex(7)),
+	))
+}
+
+fn foreign_asset(instance: AssetInstance) -> Asset {
+	Asset {
+		id: AssetId(
+			xcm::v4::AssetId::try_from(foreign_collection())
+				.expect("valid asset id; qed")
+				.0,
+		),
+		fun: Fungibility::NonFungible(instance),
+	}
+}
+
+fn context() -> XcmContext {
+	XcmContext::with_message_id([0u8; 32])
+}
+
+#[test]
+fn local_asset_instance_encoding_works() {
+	let instance = encode_local_asset_instance(42, Permill::from_parts(123_456));
+	assert_eq!(
+		decode_local_asset_instance(&instance),
+		Some((42, Some(Permill::from_parts(123_456))))
+	);
+	assert_eq!(decode_local_asset_instance(&AssetInstance::Index(42)), Some((42, None)));
+
+	let AssetInstance::Array32(mut data) = instance else {
+		panic!("royalty is carried by Array32");
+	};
+	data[31] = 1;
+	assert_eq!(decode_local_asset_instance(&AssetInstance::Array32(data)), None);
+	assert_eq!(decode_local_asset_instance(&AssetInstance::Array8([0u8; 8])), None);
+}
+
+#[test]
+fn set_class_royalty_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XNFT::set_class_royalty(RuntimeOrigin::signed(ALICE), 0, Some(royalty(5))),
+			Error::<Runtime>::ClassNotFound
+		);
+
+		let (class_id, token_id) = create_local_class_and_token(&ALICE);
+		assert_noop!(
+			XNFT::set_class_royalty(RuntimeOrigin::signed(BOB), class_id, Some(royalty(5))),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(XNFT::set_class_royalty(
+			RuntimeOrigin::signed(ALICE),
+			class_id,
+			Some(royalty(5))
+		));
+		System::assert_last_event(RuntimeEvent::XNFT(crate::Event::ClassRoyaltyUpdated {
+			class_id,
+			royalty: Some(royalty(5)),
+		}));
+		assert_eq!(XNFT::class_royalties(class_id), Some(royalty(5)));
+		assert_eq!(
+			XNFT::local_asset_instance(class_id, token_id),
+			encode_local_asset_instance(token_id.into(), Permill::from_percent(5))
+		);
+		assert_eq!(
+			XNFT::split_sale_revenue(class_id, 1_000),
+			(Some((royalty(5).beneficiary, 50)), 950)
+		);
+
+		assert_ok!(XNFT::set_class_royalty(RuntimeOrigin::signed(ALICE), class_id, None));
+		assert_eq!(XNFT::class_royalties(class_id), None);
+		assert_eq!(
+			XNFT::local_asset_instance(class_id, token_id),
+			AssetInstance::Index(token_id.into())
+		);
+		assert_eq!(XNFT::split_sale_revenue(class_id, 1_000), (None, 1_000));
+
+		assert_ok!(XNFT::register_asset(
+			RuntimeOrigin::root(),
+			Box::new(foreign_collection().into())
+		));
+		let foreign_class_id = XNFT::foreign_asset_to_class(foreign_collection()).unwrap();
+		assert_noop!(
+			XNFT::set_class_royalty(
+				RuntimeOrigin::signed(XNFT::account_id()),
+				foreign_class_id,
+				Some(royalty(5))
+			),
+			Error::<Runtime>::ForeignClass
+		);
+	});
+}
+
+#[test]
+fn set_foreign_collection_royalty_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XNFT::set_foreign_collection_royalty(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(foreign_collection().into()),
+				Some(royalty(5))
+			),
+			BadOrigin
+		);
+
+		// curated before registration
+		assert_ok!(XNFT::set_foreign_collection_royalty(
+			RuntimeOrigin::root(),
+			Box::new(foreign_collection().into()),
+			Some(royalty(5))
+		));
+		System::assert_last_event(RuntimeEvent::XNFT(crate::Event::ForeignCollectionRoyaltyUpdated {
+			asset_id: Box::new(foreign_collection().into()),
+			royalty: Some(royalty(5)),
+		}));
+		assert_eq!(
+			XNFT::foreign_collection_royalties(foreign_collection()),
+			Some(royalty(5))
+		);
+
+		// reconstructed on registration
+		assert_ok!(XNFT::register_asset(
+			RuntimeOrigin::root(),
+			Box::new(foreign_collection().into())
+		));
+		let class_id = XNFT::foreign_asset_to_class(foreign_collection()).unwrap();
+		assert_eq!(XNFT::class_royalties(class_id), Some(royalty(5)));
+
+		// mirrored once registered
+		assert_ok!(XNFT::set_foreign_collection_royalty(
+			RuntimeOrigin::root(),
+			Box::new(foreign_collection().into()),
+			Some(royalty(10))
+		));
+		System::assert_has_event(RuntimeEvent::XNFT(crate::Event::ClassRoyaltyUpdated {
+			class_id,
+			royalty: Some(royalty(10)),
+		}));
+		assert_eq!(XNFT::class_royalties(class_id), Some(royalty(10)));
+
+		assert_ok!(XNFT::set_foreign_collection_royalty(
+			RuntimeOrigin::root(),
+			Box::new(foreign_collection().into()),
+			None
+		));
+		assert_eq!(XNFT::foreign_collection_royalties(foreign_collection()), None);
+		assert_eq!(XNFT::class_royalties(class_id), None);
+	});
+}
+
+#[test]
+fn local_item_round_trip_keeps_royalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (class_id, token_id) = create_local_class_and_token(&ALICE);
+		assert_ok!(XNFT::set_class_royalty(
+			RuntimeOrigin::signed(ALICE),
+			class_id,
+			Some(royalty(5))
+		));
+
+		// the outbound item without the royalty of its class is rejected
+		let stale = Asset {
+			id: XNFT::local_asset(class_id, token_id).unwrap().id,
+			fun: Fungibility::NonFungible(AssetInstance::Index(token_id.into())),
+		};
+		assert_eq!(
+			XNFT::withdraw_asset(&stale, &account_location(&ALICE), Some(&context())).map(|_| ()),
+			Err(XcmError::FailedToTransactAsset("non-fungible item royalty mismatch"))
+		);
+
+		let asset = XNFT::local_asset(class_id, token_id).unwrap();
+		assert_ok!(XNFT::withdraw_asset(
+			&asset,
+			&account_location(&ALICE),
+			Some(&context())
+		));
+		assert_eq!(OrmlNFT::tokens(class_id, token_id).unwrap().owner, XNFT::account_id());
+		let Fungibility::NonFungible(instance) = asset.fun else {
+			panic!("local item is non-fungible");
+		};
+		assert_eq!(
+			decode_local_asset_instance(&instance),
+			Some((token_id.into(), Some(Permill::from_percent(5))))
+		);
+
+		// returned from the other chain, re-anchored with this chain
+		let returned = Asset {
+			id: AssetId(Location::new(
+				1,
+				[
+					Parachain(SELF_PARA_ID),
+					PalletInstance(NFT_PALLET_INDEX),
+					GeneralIndex(class_id.into()),
+				],
+			)),
+			fun: Fungibility::NonFungible(instance),
+		};
+		assert_ok!(XNFT::deposit_asset(
+			&returned,
+			&account_location(&BOB),
+			Some(&context())
+		));
+		assert_eq!(OrmlNFT::tokens(class_id, token_id).unwrap().owner, BOB);
+		assert_eq!(XNFT::class_royalties(class_id), Some(royalty(5)));
+		assert_eq!(XNFT::local_asset(class_id, token_id).unwrap().fun, returned.fun);
+	});
+}
+
+#[test]
+fn foreign_item_round_trip_keeps_royalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XNFT::set_foreign_collection_royalty(
+			RuntimeOrigin::root(),
+			Box::new(foreign_collection().into()),
+			Some(royalty(5))
+		));
+		assert_ok!(XNFT::register_asset(
+			RuntimeOrigin::root(),
+			Box::new(foreign_collection().into())
+		));
+		let class_id = XNFT::foreign_asset_to_class(foreign_collection()).unwrap();
+
+		let asset = foreign_asset(AssetInstance::Index(99));
+		assert_ok!(XNFT::deposit_asset(&asset, &account_location(&ALICE), Some(&context())));
+		let token_id = XNFT::asset_instance_to_item(class_id, v3::AssetInstance::Index(99)).unwrap();
+		assert_eq!(OrmlNFT::tokens(class_id, token_id).unwrap().owner, ALICE);
+
+		assert_ok!(XNFT::withdraw_asset(
+			&asset,
+			&account_location(&ALICE),
+			Some(&context())
+		));
+		assert_eq!(OrmlNFT::tokens(class_id, token_id).unwrap().owner, XNFT::account_id());
+
+		assert_ok!(XNFT::deposit_asset(&asset, &account_location(&BOB), Some(&context())));
+		assert_eq!(
+			XNFT::asset_instance_to_item(class_id, v3::AssetInstance::Index(99)),
+			Some(token_id)
+		);
+		assert_eq!(OrmlNFT::tokens(class_id, token_id).unwrap().owner, BOB);
+		assert_eq!(XNFT::class_royalties(class_id), Some(royalty(5)));
+		assert_eq!(
+			XNFT::split_sale_revenue(class_id, 1_000),
+			(Some((royalty(5).beneficiary, 50)), 950)
+		);
+	});
+}
@@ -29,8 +29,8 @@ pub enum ClassLocality<T: Config> {
 
 impl<T: Config> Pallet<T>
 where
-	TokenIdOf<T>: TryFrom<u128>,
-	ClassIdOf<T>: TryFrom<u128>,
+	TokenIdOf<T>: TryFrom<u128> + Into<u128>,
+	ClassIdOf<T>: TryFrom<u128> + Into<u128>,
 {
 	pub fn asset_to_collection(asset: &AssetId) -> Result<ClassLocality<T>, MatchError> {
 		let v3_asset = v3::AssetId::try_from(asset.clone()).map_err(|_| MatchError::AssetIdConversionFailed)?;
@@ -110,10 +110,52 @@ where
 	}
 
 	fn convert_asset_instance(asset: &AssetInstance) -> Result<TokenIdOf<T>, MatchError> {
-		let AssetInstance::Index(index) = asset else {
-			return Err(MatchError::InstanceConversionFailed);
-		};
+		let (index, _) = decode_local_asset_instance(asset).ok_or(MatchError::InstanceConversionFailed)?;
+
+		index.try_into().map_err(|_| MatchError::InstanceConversionFailed)
+	}
+
+	/// The asset instance of the local item, carrying the royalty rate of its class.
+	pub fn local_asset_instance(class_id: ClassIdOf<T>, token_id: TokenIdOf<T>) -> AssetInstance {
+		let index: u128 = token_id.into();
+		match Self::class_royalties(class_id) {
+			Some(RoyaltyInfo { rate, .. }) => encode_local_asset_instance(index, rate),
+			None => AssetInstance::Index(index),
+		}
+	}
+
+	/// The asset of the local item to be transferred out, located by this chain.
+	pub fn local_asset(class_id: ClassIdOf<T>, token_id: TokenIdOf<T>) -> Option<Asset> {
+		let location = T::NtfPalletLocation::get()
+			.pushed_with(GeneralIndex(class_id.into()))
+			.ok()?;
 
-		(*index).try_into().map_err(|_| MatchError::InstanceConversionFailed)
+		Some(Asset {
+			id: AssetId(Location::new(0, location)),
+			fun: Fungibility::NonFungible(Self::local_asset_instance(class_id, token_id)),
+		})
+	}
+}
+
+/// Items of local classes with royalty are represented by the `Array32` asset instance:
+/// `token_id (u128, LE) ++ royalty rate (Permill parts, u32, LE) ++ [0u8; 12]`. Items of classes
+/// without royalty keep the `Index(token_id)` representation.
+pub fn encode_local_asset_instance(index: u128, rate: Permill) -> AssetInstance {
+	let mut data = [0u8; 32];
+	data[..16].copy_from_slice(&index.to_le_bytes());
+	data[16..20].copy_from_slice(&rate.deconstruct().to_le_bytes());
+	AssetInstance::Array32(data)
+}
+
+/// Decode the token index and the royalty rate from the asset instance of the local item.
+pub fn decode_local_asset_instance(asset_instance: &AssetInstance) -> Option<(u128, Option<Permill>)> {
+	match asset_instance {
+		AssetInstance::Index(index) => Some((*index, None)),
+		AssetInstance::Array32(data) if data[20..].iter().all(|b| *b == 0) => {
+			let index = u128::from_le_bytes(data[..16].try_into().ok()?);
+			let parts = u32::from_le_bytes(data[16..20].try_into().ok()?);
+			Some((index, Some(Permill::from_parts(parts))))
+		}
+		_ => None,
 	}
 }
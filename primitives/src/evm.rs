@@ -31,12 +31,20 @@ use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_core::{H160, H256, U256};
-use sp_runtime::{traits::Zero, RuntimeDebug, SaturatedConversion};
+use sp_runtime::{
+	traits::{ConstU32, Zero},
+	BoundedVec, RuntimeDebug, SaturatedConversion,
+};
 use sp_std::vec::Vec;
 
 /// Evm Address.
 pub type EvmAddress = sp_core::H160;
 
+/// The max length of the well-known key of a system contract.
+pub const MAX_SYSTEM_CONTRACT_KEY_LEN: u32 = 32;
+/// The well-known key of a system contract deployed by the network, like `DEX_ROUTER`.
+pub type SystemContractKey = BoundedVec<u8, ConstU32<MAX_SYSTEM_CONTRACT_KEY_LEN>>;
+
 /// mandala 595
 pub const CHAIN_ID_MANDALA: u64 = 595u64;
 /// karura testnet 596
@@ -56,6 +64,16 @@ const STORAGE_MASK: u64 = 100u64;
 const GAS_LIMIT_CHUNK: u64 = 30_000u64;
 // MAX GAS_LIMIT CC, log2(BLOCK_STORAGE_LIMIT)
 pub const MAX_GAS_LIMIT_CC: u32 = 22u32;
+// NONCE LANE BITS, the high bits of an eth tx nonce reserved for the lane index
+pub const NONCE_LANE_BITS: u32 = 8u32;
+// NONCE SEQUENCE MASK, the low bits of an eth tx nonce used for the in-lane sequence
+pub const NONCE_SEQUENCE_MASK: Nonce = Nonce::MAX >> NONCE_LANE_BITS;
+// NONCE LANE CREATE FLAG, set on the signed nonce of a nonce lane tx when deriving its CREATE
+// address, above any EVM nonce so the addresses never collide with the legacy CREATE scheme
+pub const NONCE_LANE_CREATE_FLAG: u64 = 1u64 << Nonce::BITS;
+// ETH TX REPLACEMENT TIP BUMP, the minimum tip increase in percent for an eth tx to replace a
+// pending tx with the same nonce
+pub const ETH_TX_REPLACEMENT_TIP_BUMP: u128 = 10u128;
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -118,6 +136,81 @@ pub struct EstimateResourcesRequest {
 	pub access_list: Option<Vec<AccessListItem>>,
 }
 
+/// The resources estimated by the `estimate_resources_v2` runtime API.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EstimateResourcesResponse<Balance> {
+	/// The exit reason of the execution with the requested limits.
+	pub exit_reason: ExitReason,
+	/// The output of the call, or the address of the created contract.
+	pub value: Vec<u8>,
+	/// The gas used by the execution with the requested limits.
+	pub used_gas: u64,
+	/// The minimal gas limit the execution succeeds with.
+	pub gas_limit: u64,
+	/// The net storage delta in bytes, negative if the execution frees storage.
+	pub used_storage: i32,
+	/// The minimal storage limit the execution succeeds with.
+	pub storage_limit: u32,
+	/// The storage deposit charged for the storage delta.
+	pub storage_deposit: Balance,
+	/// The storage deposit refunded for the freed storage.
+	pub storage_refund: Balance,
+}
+
+/// A read-only call executed by the `batch_call` runtime API.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EthCallRequest<Balance> {
+	/// From
+	pub from: H160,
+	/// To
+	pub to: H160,
+	/// Data
+	pub data: Vec<u8>,
+	/// Value
+	pub value: Balance,
+	/// Gas Limit
+	pub gas_limit: u64,
+	/// Storage Limit
+	pub storage_limit: u32,
+	/// AccessList
+	pub access_list: Option<Vec<AccessListItem>>,
+	/// Execute the call in a static context, any state modification will revert.
+	pub static_call: bool,
+}
+
+/// The index of an EVM transaction executed in a block, used to correlate the EVM logs with
+/// the extrinsic.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EvmTransactionIndex<AccountId> {
+	/// The index of the extrinsic in the block.
+	pub extrinsic_index: u32,
+	/// The transaction hash computed following the Ethereum rules.
+	pub transaction_hash: H256,
+	/// From
+	pub from: H160,
+	/// The account which signed the extrinsic, if any.
+	pub account_id: Option<AccountId>,
+	/// The created contract address, if any.
+	pub contract_address: Option<H160>,
+	/// The number of the emitted logs.
+	pub log_count: u32,
+}
+
+/// The purge progress of a contract being removed.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ContractPurgeProgress<Balance> {
+	/// The estimated number of the storage items when the contract was removed.
+	pub total_items: u32,
+	/// The number of the purged storage items.
+	pub purged_items: u32,
+	/// The storage deposit refunded so far.
+	pub refunded: Balance,
+}
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct EthereumTransactionMessage {
@@ -264,6 +357,23 @@ pub fn decode_gas_price(gas_price: u64, gas_limit: u64, tx_fee_per_gas: u128) ->
 	Some((tip, valid_until))
 }
 
+/// Returns the replacement priority of an eth tx with `tip`.
+///
+/// The priority is the number of times `tip` can be divided by `1 + ETH_TX_REPLACEMENT_TIP_BUMP%`
+/// before reaching zero, so a tx with at least `ETH_TX_REPLACEMENT_TIP_BUMP` percent higher tip
+/// always has a strictly higher priority.
+pub fn eth_tx_replacement_priority(tip: u128) -> u64 {
+	let divisor = 100u128.saturating_add(ETH_TX_REPLACEMENT_TIP_BUMP);
+	let mut remaining = tip;
+	let mut priority: u64 = 0;
+	while !remaining.is_zero() {
+		// remaining * 100 / divisor without overflow
+		remaining = remaining / divisor * 100 + remaining % divisor * 100 / divisor;
+		priority = priority.saturating_add(1);
+	}
+	priority
+}
+
 pub fn decode_gas_limit(gas_limit: u64) -> (u64, u32) {
 	let gas_and_storage: u64 = gas_limit.checked_rem(GAS_MASK).expect("constant never failed; qed");
 	let actual_gas_limit: u64 = gas_and_storage
@@ -287,6 +397,26 @@ pub fn decode_gas_limit(gas_limit: u64) -> (u64, u32) {
 	(actual_gas_limit, actual_storage_limit)
 }
 
+/// Split the nonce of an eth tx into `(lane, sequence)`.
+///
+/// Only meaningful for accounts which opted in to nonce lanes, the lane index is stored in the
+/// high `NONCE_LANE_BITS` bits and the per-lane sequence in the remaining low bits.
+pub fn decode_nonce_lane(nonce: Nonce) -> (u32, Nonce) {
+	let sequence_bits = Nonce::BITS - NONCE_LANE_BITS;
+	(nonce >> sequence_bits, nonce & NONCE_SEQUENCE_MASK)
+}
+
+/// Compose the nonce of an eth tx from `lane` and `sequence`.
+///
+/// Returns `None` if `lane` or `sequence` overflows its bits.
+pub fn encode_nonce_lane(lane: u32, sequence: Nonce) -> Option<Nonce> {
+	let sequence_bits = Nonce::BITS - NONCE_LANE_BITS;
+	if lane >= 1 << NONCE_LANE_BITS || sequence > NONCE_SEQUENCE_MASK {
+		return None;
+	}
+	Some((lane << sequence_bits) | sequence)
+}
+
 #[cfg(not(feature = "evm-tests"))]
 mod convert {
 	use sp_runtime::traits::{CheckedDiv, Saturating, Zero};
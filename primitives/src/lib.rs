@@ -126,6 +126,7 @@ pub enum AuthoritysOriginId {
 pub enum DataProviderId {
 	Aggregated = 0,
 	Acala = 1,
+	Secondary = 2,
 }
 
 #[derive(
@@ -189,6 +190,8 @@ pub enum ReserveIdentifier {
 	Nft,
 	TransactionPayment,
 	TransactionPaymentDeposit,
+	PublicReferenda,
+	OracleOperator,
 
 	// always the last, indicate number of variants
 	Count,
@@ -18,7 +18,8 @@
 
 use super::*;
 use crate::evm::{
-	decode_gas_limit, decode_gas_price, is_system_contract, EvmAddress, MAX_GAS_LIMIT_CC,
+	decode_gas_limit, decode_gas_price, decode_nonce_lane, encode_nonce_lane, eth_tx_replacement_priority,
+	is_system_contract, EvmAddress, ETH_TX_REPLACEMENT_TIP_BUMP, MAX_GAS_LIMIT_CC, NONCE_SEQUENCE_MASK,
 	SYSTEM_CONTRACT_ADDRESS_PREFIX,
 };
 use frame_support::assert_ok;
@@ -183,6 +184,32 @@ fn is_system_contract_works() {
 	assert!(!is_system_contract(&bytes.into()));
 }
 
+#[test]
+fn eth_tx_replacement_priority_works() {
+	assert_eq!(eth_tx_replacement_priority(0), 0);
+	assert_eq!(eth_tx_replacement_priority(1), 1);
+	assert!(eth_tx_replacement_priority(u128::MAX) > eth_tx_replacement_priority(u128::MAX / 2));
+
+	// tip increased by ETH_TX_REPLACEMENT_TIP_BUMP percent, rounded up
+	let bumped = |tip: u128| tip + (tip * ETH_TX_REPLACEMENT_TIP_BUMP + 99) / 100;
+	for tip in [
+		1u128,
+		9,
+		10,
+		11,
+		99,
+		1_000,
+		123_456_789,
+		1_000_000_000_000,
+		u128::MAX / 1_000,
+	] {
+		// monotonic
+		assert!(eth_tx_replacement_priority(tip + 1) >= eth_tx_replacement_priority(tip));
+		// a bump of at least ETH_TX_REPLACEMENT_TIP_BUMP percent always raises the priority
+		assert!(eth_tx_replacement_priority(bumped(tip)) > eth_tx_replacement_priority(tip));
+	}
+}
+
 #[test]
 fn decode_gas_price_works() {
 	const TX_FEE_PRE_GAS: u128 = 100_000_000_000u128; // 100 Gwei
@@ -238,3 +265,25 @@ fn decode_gas_limit_works() {
 		(15330000, 2u32.pow(MAX_GAS_LIMIT_CC))
 	);
 }
+
+#[test]
+fn nonce_lane_works() {
+	assert_eq!(decode_nonce_lane(0), (0, 0));
+	assert_eq!(decode_nonce_lane(5), (0, 5));
+	assert_eq!(decode_nonce_lane(0x0100_0000), (1, 0));
+	assert_eq!(decode_nonce_lane(0x0300_0007), (3, 7));
+	assert_eq!(decode_nonce_lane(u32::MAX), (255, NONCE_SEQUENCE_MASK));
+
+	assert_eq!(encode_nonce_lane(0, 5), Some(5));
+	assert_eq!(encode_nonce_lane(3, 7), Some(0x0300_0007));
+	assert_eq!(encode_nonce_lane(255, NONCE_SEQUENCE_MASK), Some(u32::MAX));
+	assert_eq!(encode_nonce_lane(256, 0), None);
+	assert_eq!(encode_nonce_lane(0, NONCE_SEQUENCE_MASK + 1), None);
+
+	for (lane, sequence) in [(0, 0), (1, 1), (7, 123_456), (200, NONCE_SEQUENCE_MASK)] {
+		assert_eq!(
+			decode_nonce_lane(encode_nonce_lane(lane, sequence).unwrap()),
+			(lane, sequence)
+		);
+	}
+}
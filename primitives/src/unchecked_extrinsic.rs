@@ -22,7 +22,8 @@ use frame_support::{
 	traits::{ExtrinsicCall, Get},
 };
 use module_evm_utility::ethereum::{
-	EIP1559TransactionMessage, EIP2930TransactionMessage, LegacyTransactionMessage, TransactionAction,
+	EIP1559Transaction, EIP1559TransactionMessage, EIP2930Transaction, EIP2930TransactionMessage, LegacyTransaction,
+	LegacyTransactionMessage, TransactionAction, TransactionSignature,
 };
 use module_evm_utility_macro::keccak256;
 use parity_scale_codec::{Decode, Encode};
@@ -273,6 +274,95 @@ where
 	}
 }
 
+impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+	AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+where
+	Call: Encode + Member,
+	Extra: SignedExtension,
+	ConvertEthTx: Convert<(Call, Extra), Result<(EthereumTransactionMessage, Extra), InvalidTransaction>>,
+	StorageDepositPerByte: Get<Balance>,
+	TxFeePerGas: Get<Balance>,
+{
+	/// The transaction hash following the Ethereum rules.
+	///
+	/// For the extrinsics signed by Ethereum wallets, this is the keccak256 hash of the signed
+	/// Ethereum transaction, the same hash the wallet computes. Otherwise it is the keccak256
+	/// hash of the encoded extrinsic.
+	pub fn ethereum_transaction_hash(&self) -> H256 {
+		let eth_tx_hash = match &self.0.signature {
+			Some((_, AcalaMultiSignature::Ethereum(sig), extra)) => {
+				self.eth_sign_data(extra)
+					.and_then(|(eth_msg, tx_gas_price, tx_gas_limit)| {
+						let msg = LegacyTransactionMessage {
+							nonce: eth_msg.nonce.into(),
+							gas_price: tx_gas_price.into(),
+							gas_limit: tx_gas_limit.into(),
+							action: eth_msg.action,
+							value: eth_msg.value.into(),
+							input: eth_msg.input,
+							chain_id: Some(eth_msg.chain_id),
+						};
+						legacy_transaction_hash(msg, sig)
+					})
+			}
+			Some((_, AcalaMultiSignature::Eip2930(sig), extra)) => {
+				self.eth_sign_data(extra)
+					.and_then(|(eth_msg, tx_gas_price, tx_gas_limit)| {
+						let msg = EIP2930TransactionMessage {
+							chain_id: eth_msg.chain_id,
+							nonce: eth_msg.nonce.into(),
+							gas_price: tx_gas_price.into(),
+							gas_limit: tx_gas_limit.into(),
+							action: eth_msg.action,
+							value: eth_msg.value.into(),
+							input: eth_msg.input,
+							access_list: eth_msg.access_list,
+						};
+						eip2930_transaction_hash(msg, sig)
+					})
+			}
+			Some((_, AcalaMultiSignature::Eip1559(sig), extra)) => {
+				self.eth_sign_data(extra)
+					.and_then(|(eth_msg, tx_gas_price, tx_gas_limit)| {
+						// tip = priority_fee * gas_limit
+						let priority_fee = eth_msg.tip.checked_div(eth_msg.gas_limit.into()).unwrap_or_default();
+
+						let msg = EIP1559TransactionMessage {
+							chain_id: eth_msg.chain_id,
+							nonce: eth_msg.nonce.into(),
+							max_priority_fee_per_gas: priority_fee.into(),
+							max_fee_per_gas: tx_gas_price.into(),
+							gas_limit: tx_gas_limit.into(),
+							action: eth_msg.action,
+							value: eth_msg.value.into(),
+							input: eth_msg.input,
+							access_list: eth_msg.access_list,
+						};
+						eip1559_transaction_hash(msg, sig)
+					})
+			}
+			_ => None,
+		};
+
+		eth_tx_hash.unwrap_or_else(|| H256::from(keccak_256(&self.0.encode())))
+	}
+
+	/// Returns the eth transaction message with the gas price and gas limit signed by the
+	/// Ethereum wallet.
+	fn eth_sign_data(&self, extra: &Extra) -> Option<(EthereumTransactionMessage, u128, u128)> {
+		let (eth_msg, _) = ConvertEthTx::convert((self.0.function.clone(), extra.clone())).ok()?;
+
+		let (tx_gas_price, tx_gas_limit) = if eth_msg.gas_price.is_zero() {
+			recover_sign_data(&eth_msg, TxFeePerGas::get(), StorageDepositPerByte::get())?
+		} else {
+			// eth_call_v2, the gas_price and gas_limit are encoded.
+			(eth_msg.gas_price as u128, eth_msg.gas_limit as u128)
+		};
+
+		Some((eth_msg, tx_gas_price, tx_gas_limit))
+	}
+}
+
 impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> GetDispatchInfo
 	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
 where
@@ -313,6 +403,83 @@ fn recover_signer(sig: &[u8; 65], msg_hash: &[u8; 32]) -> Option<H160> {
 		.ok()
 }
 
+/// Returns the recovery id of the signature.
+fn recovery_id(sig: &[u8; 65]) -> Option<u64> {
+	match sig[64] {
+		v @ 0..=1 => Some(v.into()),
+		v @ 27..=28 => Some(u64::from(v) - 27),
+		_ => None,
+	}
+}
+
+fn signature_rs(sig: &[u8; 65]) -> (H256, H256) {
+	(H256::from_slice(&sig[0..32]), H256::from_slice(&sig[32..64]))
+}
+
+/// keccak256(rlp(signed_tx)), with `v` following EIP-155.
+fn legacy_transaction_hash(msg: LegacyTransactionMessage, sig: &[u8; 65]) -> Option<H256> {
+	let recovery_id = recovery_id(sig)?;
+	let v = match msg.chain_id {
+		Some(chain_id) => chain_id.checked_mul(2)?.checked_add(35)?.checked_add(recovery_id)?,
+		None => recovery_id.checked_add(27)?,
+	};
+	let (r, s) = signature_rs(sig);
+
+	let tx = LegacyTransaction {
+		nonce: msg.nonce,
+		gas_price: msg.gas_price,
+		gas_limit: msg.gas_limit,
+		action: msg.action,
+		value: msg.value,
+		input: msg.input,
+		signature: TransactionSignature::new(v, r, s)?,
+	};
+	Some(tx.hash())
+}
+
+/// keccak256(0x01 || rlp(signed_tx))
+fn eip2930_transaction_hash(msg: EIP2930TransactionMessage, sig: &[u8; 65]) -> Option<H256> {
+	let odd_y_parity = recovery_id(sig)? == 1;
+	let (r, s) = signature_rs(sig);
+
+	let tx = EIP2930Transaction {
+		chain_id: msg.chain_id,
+		nonce: msg.nonce,
+		gas_price: msg.gas_price,
+		gas_limit: msg.gas_limit,
+		action: msg.action,
+		value: msg.value,
+		input: msg.input,
+		access_list: msg.access_list,
+		odd_y_parity,
+		r,
+		s,
+	};
+	Some(tx.hash())
+}
+
+/// keccak256(0x02 || rlp(signed_tx))
+fn eip1559_transaction_hash(msg: EIP1559TransactionMessage, sig: &[u8; 65]) -> Option<H256> {
+	let odd_y_parity = recovery_id(sig)? == 1;
+	let (r, s) = signature_rs(sig);
+
+	let tx = EIP1559Transaction {
+		chain_id: msg.chain_id,
+		nonce: msg.nonce,
+		max_priority_fee_per_gas: msg.max_priority_fee_per_gas,
+		max_fee_per_gas: msg.max_fee_per_gas,
+		gas_limit: msg.gas_limit,
+		action: msg.action,
+		value: msg.value,
+		input: msg.input,
+		access_list: msg.access_list,
+		odd_y_parity,
+		r,
+		s,
+	};
+	Some(tx.hash())
+}
+
 fn verify_eip712_signature(eth_msg: EthereumTransactionMessage, sig: [u8; 65]) -> Option<H160> {
 	let domain_hash = keccak256!("EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)");
 	let access_list_type_hash = keccak256!("AccessList(address address,uint256[] storageKeys)");
@@ -616,6 +783,81 @@ mod tests {
 		assert_ne!(recover_signer(&sign, new_msg.hash().as_fixed_bytes()), sender);
 	}
 
+	#[test]
+	fn legacy_transaction_hash_should_works() {
+		// same tx as `verify_eth_should_works`
+		let msg = LegacyTransactionMessage {
+			nonce: U256::from(1),
+			gas_price: U256::from("0x640000006a"),
+			gas_limit: U256::from(21000),
+			action: TransactionAction::Call(H160::from_str("0x1111111111222222222233333333334444444444").unwrap()),
+			value: U256::from(123123),
+			input: vec![],
+			chain_id: Some(595),
+		};
+		let sign = hex!("f84345a6459785986a1b2df711fe02597d70c1393757a243f8f924ea541d2ecb51476de1aa437cd820d59e1d9836e37e643fec711fe419464e637cab592918751c");
+
+		// keccak256(rlp([nonce, gasPrice, gasLimit, to, value, data, v, r, s])), v = 595 * 2 + 35 + 1
+		assert_eq!(
+			legacy_transaction_hash(msg.clone(), &sign),
+			Some(H256::from(hex!(
+				"67274cd0347795d0e2986021a19b1347948a0a93e1fb31a315048320fbfcae8a"
+			)))
+		);
+
+		// the recovery id can be either 0/1 or 27/28
+		let mut normalized_sign = sign;
+		normalized_sign[64] = 1;
+		assert_eq!(
+			legacy_transaction_hash(msg.clone(), &normalized_sign),
+			legacy_transaction_hash(msg.clone(), &sign)
+		);
+
+		let mut invalid_sign = sign;
+		invalid_sign[64] = 2;
+		assert_eq!(legacy_transaction_hash(msg.clone(), &invalid_sign), None);
+
+		let mut new_msg = msg.clone();
+		new_msg.nonce = new_msg.nonce.add(U256::one());
+		assert_ne!(
+			legacy_transaction_hash(new_msg, &sign),
+			legacy_transaction_hash(msg, &sign)
+		);
+	}
+
+	#[test]
+	fn eip1559_transaction_hash_should_works() {
+		// same tx as `verify_eth_1559_should_works`
+		let msg = EIP1559TransactionMessage {
+			chain_id: 595,
+			nonce: U256::from(1),
+			max_priority_fee_per_gas: U256::from(1),
+			max_fee_per_gas: U256::from("0x640000006a"),
+			gas_limit: U256::from(21000),
+			action: TransactionAction::Call(H160::from_str("0x1111111111222222222233333333334444444444").unwrap()),
+			value: U256::from(123123),
+			input: vec![],
+			access_list: vec![],
+		};
+		let sign = hex!("e88df53d4d66cb7a4f54ea44a44942b9b7f4fb4951525d416d3f7d24755a1f817734270872b103ac04c59d74f4dacdb8a6eff09a6638bd95dad1fa3eda921d891b");
+
+		// keccak256(0x02 || rlp([chainId, nonce, maxPriorityFeePerGas, maxFeePerGas, gasLimit, to, value, data,
+		// accessList, yParity, r, s]))
+		assert_eq!(
+			eip1559_transaction_hash(msg.clone(), &sign),
+			Some(H256::from(hex!(
+				"1c9370ce9c9f69e8478374ee3f9dbbda16240e967cc5ffbd721a265d52ec2d38"
+			)))
+		);
+
+		let mut new_msg = msg.clone();
+		new_msg.max_priority_fee_per_gas = new_msg.max_priority_fee_per_gas.add(U256::one());
+		assert_ne!(
+			eip1559_transaction_hash(new_msg, &sign),
+			eip1559_transaction_hash(msg, &sign)
+		);
+	}
+
 	#[test]
 	fn recover_sign_data_should_works() {
 		let mut msg = EthereumTransactionMessage {
@@ -50,6 +50,9 @@ pub mod dex {
 pub mod dex_oracle {
 	include!("../../../mandala/src/benchmarking/dex_oracle.rs");
 }
+pub mod earning {
+	include!("../../../mandala/src/benchmarking/earning.rs");
+}
 pub mod emergency_shutdown {
 	include!("../../../mandala/src/benchmarking/emergency_shutdown.rs");
 }
@@ -71,6 +74,9 @@ pub mod honzon {
 pub mod idle_scheduler {
 	include!("../../../mandala/src/benchmarking/idle_scheduler.rs");
 }
+pub mod scheduler_index {
+	include!("../../../mandala/src/benchmarking/scheduler_index.rs");
+}
 pub mod incentives {
 	include!("../../../mandala/src/benchmarking/incentives.rs");
 }
@@ -99,6 +105,9 @@ pub mod aggregated_dex {
 pub mod nominees_election {
 	include!("../../../mandala/src/benchmarking/nominees_election.rs");
 }
+pub mod oracle_operator {
+	include!("../../../mandala/src/benchmarking/oracle_operator.rs");
+}
 
 // orml benchmarking
 pub mod auction {
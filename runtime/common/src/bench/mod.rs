@@ -19,7 +19,10 @@
 #![cfg(feature = "wasm-bench")]
 #![allow(dead_code)]
 
-pub use crate::{precompile::mock::*, DEXPrecompile, EVMPrecompile, OraclePrecompile};
+pub use crate::{
+	precompile::{mock::*, TokenRegistryPrecompile},
+	DEXPrecompile, EVMPrecompile, OraclePrecompile,
+};
 use frame_support::assert_ok;
 use hex_literal::hex;
 use module_evm::{
@@ -109,7 +112,8 @@ fn oracle_get_price(b: &mut Bencher) {
 			symbol: DOT.symbol().unwrap().into(),
 			decimals: DOT.decimals().unwrap(),
 			minimal_balance: 0
-		})
+		}),
+		false
 	));
 
 	// getPrice(address) -> 0x41976e09
@@ -305,6 +309,91 @@ fn evm_query_developer_status(b: &mut Bencher) {
 	assert_eq!(resp.output, expected_output);
 }
 
+fn evm_query_system_contract(b: &mut Bencher) {
+	let caller = alice_evm_addr();
+	whitelist_keys(b, None);
+
+	let context = Context {
+		address: Default::default(),
+		caller,
+		apparent_value: Default::default(),
+	};
+
+	module_evm::SystemContracts::<Test>::insert(
+		primitives::evm::SystemContractKey::truncate_from(b"DEX_ROUTER".to_vec()),
+		H160::from(hex!("0000000000000000000000000000000000000801")),
+	);
+
+	// systemContractOf(string) -> 0x6143fcbc
+	// key "DEX_ROUTER"
+	let input = hex! {"
+		6143fcbc
+		00000000000000000000000000000000 00000000000000000000000000000020
+		00000000000000000000000000000000 0000000000000000000000000000000a
+		4445585f524f55544552000000000000 00000000000000000000000000000000
+	"};
+
+	let expected_output = hex! {"
+		000000000000000000000000 0000000000000000000000000000000000000801
+	"};
+
+	let resp = b
+		.bench(|| EVMPrecompile::<Test>::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)))
+		.unwrap();
+	assert_eq!(resp.output, expected_output);
+}
+
+fn token_registry_get_token(b: &mut Bencher) {
+	let caller = alice_evm_addr();
+	whitelist_keys(b, Some(caller));
+
+	let context = Context {
+		address: Default::default(),
+		caller,
+		apparent_value: Default::default(),
+	};
+
+	// getTokens(uint256,uint256) -> 0x494cfc6c
+	// cursor 0, limit 1
+	let input = hex! {"
+		494cfc6c
+		00000000000000000000000000000000 00000000000000000000000000000000
+		00000000000000000000000000000000 00000000000000000000000000000001
+	"};
+
+	let resp = b
+		.bench(|| {
+			TokenRegistryPrecompile::<Test>::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+		})
+		.unwrap();
+	assert_eq!(resp.exit_status, module_evm::ExitSucceed::Returned);
+}
+
+fn token_registry_get_token_by_address(b: &mut Bencher) {
+	let caller = alice_evm_addr();
+	whitelist_keys(b, Some(caller));
+
+	let context = Context {
+		address: Default::default(),
+		caller,
+		apparent_value: Default::default(),
+	};
+
+	// getTokenByAddress(address) -> 0x91ded8fa
+	// DOT
+	let input = hex! {"
+		91ded8fa
+		000000000000000000000000 0000000000000000000100000000000000000002
+	"};
+
+	let resp = b
+		.bench(|| {
+			TokenRegistryPrecompile::<Test>::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+		})
+		.unwrap();
+	assert_eq!(resp.exit_status, module_evm::ExitSucceed::Returned);
+}
+
 benches!(
 	oracle_get_price,
 	evm_query_new_contract_extra_bytes,
@@ -312,5 +401,8 @@ benches!(
 	evm_query_maintainer,
 	evm_query_developer_deposit,
 	evm_query_publication_fee,
-	evm_query_developer_status
+	evm_query_developer_status,
+	evm_query_system_contract,
+	token_registry_get_token,
+	token_registry_get_token_by_address
 );
@@ -0,0 +1,162 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Pre-validation of the calls of an account.
+//!
+//! `CallPermissionApi::can_account_call` evaluates the filters of the dispatch path layer by
+//! layer and reports the first one which denies the call:
+//! - the base call filter of the runtime, where the calls paused by `module_transaction_pause`,
+//!   either by governance or by safe mode, are reported separately.
+//! - the proxy definition and the `InstanceFilter` of the proxy type, if dispatched via proxy.
+//! - the deposit reserved by the call from the real account.
+//!
+//! The call itself is not dispatched, so it can still fail for its own reasons.
+
+use frame_support::traits::{Contains, Get, InstanceFilter, IsSubType, IsType, ReservableCurrency};
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	RuntimeDebug,
+};
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum CallPermission {
+	Allowed,
+	/// Excluded by the base call filter of the runtime.
+	DeniedByBaseFilter,
+	/// Paused by `module_transaction_pause`, either by governance or by safe mode.
+	Paused,
+	/// The account is not a proxy without announcement delay of the real account.
+	NotProxy,
+	/// Not allowed by the filter of the proxy type.
+	DeniedByProxyFilter,
+	/// The free balance can't cover the deposit reserved by the call.
+	InsufficientDeposit,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait CallPermissionApi<AccountId, RuntimeCall, ProxyType> where
+		AccountId: Codec,
+		RuntimeCall: Codec,
+		ProxyType: Codec,
+	{
+		/// Whether `account` can dispatch `call`, directly or via the proxy of the given type for the
+		/// real account.
+		fn can_account_call(
+			account: AccountId,
+			call: RuntimeCall,
+			via_proxy: Option<(AccountId, ProxyType)>,
+		) -> CallPermission;
+	}
+}
+
+/// Evaluates the filters of the dispatch path for `call`, see `CallPermissionApi`.
+pub fn can_account_call<T, BaseCallFilter>(
+	account: &T::AccountId,
+	call: &<T as frame_system::Config>::RuntimeCall,
+	via_proxy: Option<(T::AccountId, T::ProxyType)>,
+) -> CallPermission
+where
+	T: pallet_proxy::Config + module_honzon::Config + module_transaction_pause::Config,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<module_honzon::Call<T>>,
+	module_transaction_pause::PausedTransactionFilter<T>: Contains<<T as frame_system::Config>::RuntimeCall>,
+	BaseCallFilter: Contains<<T as frame_system::Config>::RuntimeCall>,
+{
+	if !BaseCallFilter::contains(call) {
+		return if module_transaction_pause::PausedTransactionFilter::<T>::contains(call) {
+			CallPermission::Paused
+		} else {
+			CallPermission::DeniedByBaseFilter
+		};
+	}
+
+	let who = match via_proxy {
+		Some((real, proxy_type)) => {
+			let proxy_def = match pallet_proxy::Pallet::<T>::find_proxy(&real, account, Some(proxy_type)) {
+				Ok(proxy_def) if proxy_def.delay.is_zero() => proxy_def,
+				_ => return CallPermission::NotProxy,
+			};
+			if !proxy_filter::<T>(
+				&proxy_def.proxy_type,
+				<T as pallet_proxy::Config>::RuntimeCall::from_ref(call),
+			) {
+				return CallPermission::DeniedByProxyFilter;
+			}
+			real
+		}
+		None => account.clone(),
+	};
+
+	if !can_reserve_deposit::<T>(&who, call) {
+		return CallPermission::InsufficientDeposit;
+	}
+
+	CallPermission::Allowed
+}
+
+/// The origin filter added by `pallet_proxy` to the calls dispatched via proxy.
+pub fn proxy_filter<T: pallet_proxy::Config>(
+	proxy_type: &T::ProxyType,
+	call: &<T as pallet_proxy::Config>::RuntimeCall,
+) -> bool {
+	match call.is_sub_type() {
+		// proxy types can only add or remove the proxies of their subsets
+		Some(pallet_proxy::Call::add_proxy { proxy_type: other, .. })
+		| Some(pallet_proxy::Call::remove_proxy { proxy_type: other, .. })
+			if !proxy_type.is_superset(other) =>
+		{
+			false
+		}
+		// only the default proxy type can remove all the proxies or kill the pure proxies
+		Some(pallet_proxy::Call::remove_proxies { .. }) | Some(pallet_proxy::Call::kill_pure { .. })
+			if *proxy_type != T::ProxyType::default() =>
+		{
+			false
+		}
+		_ => proxy_type.filter(call),
+	}
+}
+
+/// Whether `who` can reserve the deposit taken by `call`. The calls without deposit always can.
+fn can_reserve_deposit<T>(who: &T::AccountId, call: &<T as frame_system::Config>::RuntimeCall) -> bool
+where
+	T: pallet_proxy::Config + module_honzon::Config,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<module_honzon::Call<T>>,
+{
+	match <T as pallet_proxy::Config>::RuntimeCall::from_ref(call).is_sub_type() {
+		Some(pallet_proxy::Call::add_proxy { .. }) => {
+			let (proxies, reserved) = pallet_proxy::Proxies::<T>::get(who);
+			let deposit = pallet_proxy::Pallet::<T>::deposit(proxies.len() as u32 + 1).saturating_sub(reserved);
+			return <T as pallet_proxy::Config>::Currency::can_reserve(who, deposit);
+		}
+		Some(pallet_proxy::Call::create_pure { .. }) => {
+			return <T as pallet_proxy::Config>::Currency::can_reserve(who, pallet_proxy::Pallet::<T>::deposit(1));
+		}
+		_ => {}
+	}
+
+	if let Some(module_honzon::Call::authorize { .. }) = call.is_sub_type() {
+		return <T as module_honzon::Config>::Currency::can_reserve(
+			who,
+			<T as module_honzon::Config>::DepositPerAuthorization::get(),
+		);
+	}
+
+	true
+}
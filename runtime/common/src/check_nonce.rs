@@ -16,13 +16,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::dispatch::DispatchInfo;
+use frame_support::dispatch::{DispatchInfo, DispatchResult};
 use frame_system::pallet_prelude::*;
 use module_support::AddressMapping;
 use parity_scale_codec::{Decode, Encode};
+use primitives::{
+	evm::{decode_nonce_lane, eth_tx_replacement_priority},
+	Balance,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{DispatchInfoOf, Dispatchable, One, SignedExtension, Zero},
+	traits::{
+		DispatchInfoOf, Dispatchable, One, PostDispatchInfoOf, Saturating, SignedExtension, UniqueSaturatedInto, Zero,
+	},
 	transaction_validity::{
 		InvalidTransaction, TransactionLongevity, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
@@ -32,55 +38,102 @@ use sp_std::vec;
 
 /// Nonce check and increment to give replay protection for transactions.
 ///
+/// Ethereum transactions of accounts which opted in to nonce lanes (see
+/// `module_evm_accounts::set_nonce_lanes`) are checked against the sequence of the lane encoded
+/// in the high bits of the nonce, so transactions in different lanes don't block each other.
+/// The EVM nonce stays the sum of all lanes.
+///
 /// # Transaction Validity
 ///
-/// This extension affects `requires` and `provides` tags of validity, but DOES NOT
-/// set the `priority` field. Make sure that AT LEAST one of the signed extension sets
+/// This extension affects `requires` and `provides` tags of validity. For substrate transactions it
+/// DOES NOT set the `priority` field. Make sure that AT LEAST one of the signed extension sets
 /// some kind of priority upon validating transactions.
+///
+/// Ethereum transactions with the same address and nonce provide the same tag, so the pool keeps
+/// only the one with the higher priority. The priority of an ethereum transaction is raised by
+/// the replacement priority of its tip, so resubmitting it with at least
+/// `ETH_TX_REPLACEMENT_TIP_BUMP` percent higher tip always replaces the pending one. The longevity
+/// is the number of blocks left until `valid_until`.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
-pub struct CheckNonce<T: frame_system::Config + module_evm::Config> {
+pub struct CheckNonce<T: frame_system::Config + module_evm::Config + module_evm_accounts::Config> {
 	#[codec(compact)]
 	pub nonce: T::Nonce,
 	#[codec(skip)]
 	pub is_eth_tx: bool,
 	#[codec(skip)]
 	pub eth_tx_valid_until: BlockNumberFor<T>,
+	#[codec(skip)]
+	pub eth_tx_nonce_lanes: bool,
+	#[codec(skip)]
+	pub eth_tx_tip: Balance,
 }
 
-impl<T: frame_system::Config + module_evm::Config> Default for CheckNonce<T> {
+impl<T: frame_system::Config + module_evm::Config + module_evm_accounts::Config> Default for CheckNonce<T> {
 	fn default() -> Self {
 		Self {
 			nonce: 0u32.into(),
 			is_eth_tx: false,
 			eth_tx_valid_until: 0u32.into(),
+			eth_tx_nonce_lanes: false,
+			eth_tx_tip: 0,
 		}
 	}
 }
 
-impl<T: frame_system::Config + module_evm::Config> CheckNonce<T> {
+impl<T: frame_system::Config + module_evm::Config + module_evm_accounts::Config> CheckNonce<T> {
 	/// utility constructor. Used only in client/factory code.
 	pub fn from(nonce: T::Nonce) -> Self {
 		Self {
 			nonce,
 			is_eth_tx: false,
 			eth_tx_valid_until: Zero::zero(),
+			eth_tx_nonce_lanes: false,
+			eth_tx_tip: 0,
 		}
 	}
 
-	pub fn mark_as_ethereum_tx(&mut self, valid_until: BlockNumberFor<T>) {
+	pub fn mark_as_ethereum_tx(&mut self, valid_until: BlockNumberFor<T>, tip: Balance) {
 		self.is_eth_tx = true;
 		self.eth_tx_valid_until = valid_until;
+		self.eth_tx_tip = tip;
+	}
+
+	/// Allow the nonce of the ethereum tx to encode a nonce lane other than lane 0.
+	pub fn allow_nonce_lanes(&mut self) {
+		self.eth_tx_nonce_lanes = true;
+	}
+
+	/// Returns `(lane, sequence, expected_sequence)` of the ethereum tx if `who` opted in to nonce
+	/// lanes, otherwise `None`.
+	fn eth_nonce_lane(
+		&self,
+		who: &T::AccountId,
+	) -> Result<Option<(u32, T::Nonce, T::Nonce)>, TransactionValidityError> {
+		match module_evm_accounts::NonceLanes::<T>::get(who) {
+			Some(lanes) => {
+				let (lane, sequence) = decode_nonce_lane(self.nonce.unique_saturated_into());
+				if lane >= lanes || (!lane.is_zero() && !self.eth_tx_nonce_lanes) {
+					return Err(InvalidTransaction::BadProof.into());
+				}
+				Ok(Some((
+					lane,
+					sequence.into(),
+					module_evm_accounts::LaneNonces::<T>::get(who, lane),
+				)))
+			}
+			None => Ok(None),
+		}
 	}
 }
 
-impl<T: frame_system::Config + module_evm::Config> sp_std::fmt::Debug for CheckNonce<T> {
+impl<T: frame_system::Config + module_evm::Config + module_evm_accounts::Config> sp_std::fmt::Debug for CheckNonce<T> {
 	#[cfg(feature = "std")]
 	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
 		write!(
 			f,
-			"CheckNonce(nonce: {}, is_eth_tx: {}, eth_tx_valid_until: {})",
-			self.nonce, self.is_eth_tx, self.eth_tx_valid_until
+			"CheckNonce(nonce: {}, is_eth_tx: {}, eth_tx_valid_until: {}, eth_tx_nonce_lanes: {}, eth_tx_tip: {})",
+			self.nonce, self.is_eth_tx, self.eth_tx_valid_until, self.eth_tx_nonce_lanes, self.eth_tx_tip
 		)
 	}
 
@@ -90,15 +143,16 @@ impl<T: frame_system::Config + module_evm::Config> sp_std::fmt::Debug for CheckN
 	}
 }
 
-impl<T: frame_system::Config + module_evm::Config> SignedExtension for CheckNonce<T>
+impl<T: frame_system::Config + module_evm::Config + module_evm_accounts::Config> SignedExtension for CheckNonce<T>
 where
 	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
-	T::AddressMapping: AddressMapping<T::AccountId>,
+	<T as module_evm::Config>::AddressMapping: AddressMapping<T::AccountId>,
 {
 	type AccountId = T::AccountId;
 	type Call = T::RuntimeCall;
 	type AdditionalSigned = ();
-	type Pre = ();
+	/// Whether `module_evm::LaneCreateNonce` is set and must be cleared after dispatch.
+	type Pre = bool;
 	const IDENTIFIER: &'static str = "CheckNonce";
 
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
@@ -113,23 +167,42 @@ where
 		_len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
 		let mut account = frame_system::Account::<T>::get(who);
+		let mut uses_nonce_lane = false;
 		if self.is_eth_tx {
-			// should check evm nonce
-			let address = <T as module_evm::Config>::AddressMapping::get_evm_address(who)
-				.unwrap_or_else(|| <T as module_evm::Config>::AddressMapping::get_default_evm_address(who));
-			let evm_nonce = module_evm::Accounts::<T>::get(address)
-				.map(|x| x.nonce)
-				.unwrap_or_default();
+			if let Some((lane, sequence, expected)) = self.eth_nonce_lane(who)? {
+				// should check the sequence of the nonce lane
+				if cfg!(feature = "tracing") {
+					// skip check when enable tracing feature
+				} else if sequence != expected {
+					return Err(if sequence < expected {
+						InvalidTransaction::Stale
+					} else {
+						InvalidTransaction::Future
+					}
+					.into());
+				}
+				module_evm_accounts::LaneNonces::<T>::insert(who, lane, expected + T::Nonce::one());
+				// CREATE address is derived from the signed lane nonce, see `module_evm::lane_create_address`
+				module_evm::LaneCreateNonce::<T>::put(self.nonce);
+				uses_nonce_lane = true;
+			} else {
+				// should check evm nonce
+				let address = <T as module_evm::Config>::AddressMapping::get_evm_address(who)
+					.unwrap_or_else(|| <T as module_evm::Config>::AddressMapping::get_default_evm_address(who));
+				let evm_nonce = module_evm::Accounts::<T>::get(address)
+					.map(|x| x.nonce)
+					.unwrap_or_default();
 
-			if cfg!(feature = "tracing") {
-				// skip check when enable tracing feature
-			} else if self.nonce != evm_nonce {
-				return Err(if self.nonce < evm_nonce {
-					InvalidTransaction::Stale
-				} else {
-					InvalidTransaction::Future
+				if cfg!(feature = "tracing") {
+					// skip check when enable tracing feature
+				} else if self.nonce != evm_nonce {
+					return Err(if self.nonce < evm_nonce {
+						InvalidTransaction::Stale
+					} else {
+						InvalidTransaction::Future
+					}
+					.into());
 				}
-				.into());
 			}
 		} else if self.nonce != account.nonce {
 			return Err(if self.nonce < account.nonce {
@@ -141,7 +214,7 @@ where
 		}
 		account.nonce += T::Nonce::one();
 		frame_system::Account::<T>::insert(who, account);
-		Ok(())
+		Ok(uses_nonce_lane)
 	}
 
 	fn validate(
@@ -152,30 +225,45 @@ where
 		_len: usize,
 	) -> TransactionValidity {
 		if self.is_eth_tx {
-			// should check evm nonce
 			let address = <T as module_evm::Config>::AddressMapping::get_evm_address(who)
 				.unwrap_or_else(|| <T as module_evm::Config>::AddressMapping::get_default_evm_address(who));
-			let evm_nonce = module_evm::Accounts::<T>::get(address)
-				.map(|x| x.nonce)
-				.unwrap_or_default();
+
+			// `(expected, nonce)`, for nonce lanes both are the sequence in the lane
+			let (expected, nonce) = match self.eth_nonce_lane(who)? {
+				Some((_, sequence, expected)) => (expected, sequence),
+				None => {
+					// should check evm nonce
+					let evm_nonce = module_evm::Accounts::<T>::get(address)
+						.map(|x| x.nonce)
+						.unwrap_or_default();
+					(evm_nonce, self.nonce)
+				}
+			};
 
 			if cfg!(feature = "tracing") {
 				// skip check when enable tracing feature
-			} else if self.nonce < evm_nonce {
+			} else if nonce < expected {
 				return InvalidTransaction::Stale.into();
 			}
 
+			// the lane index is in the high bits of the nonce, so the tags never collide across
+			// lanes and the previous nonce is always in the same lane.
 			let provides = vec![Encode::encode(&(address, self.nonce))];
-			let requires = if evm_nonce < self.nonce {
+			let requires = if expected < nonce {
 				vec![Encode::encode(&(address, self.nonce - One::one()))]
 			} else {
 				vec![]
 			};
 
-			let longevity: TransactionLongevity = self.eth_tx_valid_until.saturated_into();
+			// the tx is valid until `valid_until`
+			let longevity: TransactionLongevity = self
+				.eth_tx_valid_until
+				.saturating_sub(frame_system::Pallet::<T>::block_number())
+				.saturated_into();
 
 			Ok(ValidTransaction {
-				priority: 0,
+				// same nonce tx with higher tip replaces the pending one
+				priority: eth_tx_replacement_priority(self.eth_tx_tip),
 				requires,
 				provides,
 				longevity,
@@ -204,12 +292,25 @@ where
 			})
 		}
 	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		_result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if pre == Some(true) {
+			module_evm::LaneCreateNonce::<T>::kill();
+		}
+		Ok(())
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::mock::{new_test_ext, AccountId32, RuntimeCall, TestRuntime};
+	use crate::mock::{new_test_ext, AccountId32, RuntimeCall, RuntimeOrigin, TestRuntime};
 	use frame_support::{assert_noop, assert_ok};
 
 	/// A simple call, which one doesn't matter.
@@ -286,7 +387,9 @@ mod tests {
 				CheckNonce::<TestRuntime> {
 					nonce: 0u32,
 					is_eth_tx: true,
-					eth_tx_valid_until: 10
+					eth_tx_valid_until: 10,
+					eth_tx_nonce_lanes: false,
+					eth_tx_tip: 0
 				}
 				.validate(&alice, CALL, &info, 0),
 				InvalidTransaction::Stale
@@ -295,7 +398,9 @@ mod tests {
 				CheckNonce::<TestRuntime> {
 					nonce: 0u32,
 					is_eth_tx: true,
-					eth_tx_valid_until: 10
+					eth_tx_valid_until: 10,
+					eth_tx_nonce_lanes: false,
+					eth_tx_tip: 0
 				}
 				.pre_dispatch(&alice, CALL, &info, 0),
 				InvalidTransaction::Stale
@@ -305,7 +410,9 @@ mod tests {
 				CheckNonce::<TestRuntime> {
 					nonce: 1u32,
 					is_eth_tx: true,
-					eth_tx_valid_until: 10
+					eth_tx_valid_until: 10,
+					eth_tx_nonce_lanes: false,
+					eth_tx_tip: 0
 				}
 				.validate(&alice, CALL, &info, 0),
 				Ok(ValidTransaction {
@@ -319,7 +426,9 @@ mod tests {
 			assert_ok!(CheckNonce::<TestRuntime> {
 				nonce: 1u32,
 				is_eth_tx: true,
-				eth_tx_valid_until: 10
+				eth_tx_valid_until: 10,
+				eth_tx_nonce_lanes: false,
+				eth_tx_tip: 0
 			}
 			.pre_dispatch(&alice, CALL, &info, 0),);
 
@@ -327,7 +436,9 @@ mod tests {
 				CheckNonce::<TestRuntime> {
 					nonce: 3u32,
 					is_eth_tx: true,
-					eth_tx_valid_until: 10
+					eth_tx_valid_until: 10,
+					eth_tx_nonce_lanes: false,
+					eth_tx_tip: 0
 				}
 				.validate(&alice, CALL, &info, 0),
 				Ok(ValidTransaction {
@@ -342,11 +453,181 @@ mod tests {
 				CheckNonce::<TestRuntime> {
 					nonce: 3u32,
 					is_eth_tx: true,
-					eth_tx_valid_until: 10
+					eth_tx_valid_until: 10,
+					eth_tx_nonce_lanes: false,
+					eth_tx_tip: 0
 				}
 				.pre_dispatch(&alice, CALL, &info, 0),
 				InvalidTransaction::Future
 			);
 		})
 	}
+
+	#[test]
+	fn check_evm_nonce_replacement_works() {
+		new_test_ext().execute_with(|| {
+			let alice = AccountId32::from([8; 32]);
+			let info = DispatchInfo::default();
+			frame_system::Pallet::<TestRuntime>::set_block_number(4);
+
+			let validate = |tip: Balance| {
+				let mut check_nonce = CheckNonce::<TestRuntime>::from(0);
+				check_nonce.mark_as_ethereum_tx(10, tip);
+				check_nonce.validate(&alice, CALL, &info, 0).unwrap()
+			};
+
+			let pending = validate(1_000_000);
+			assert_eq!(pending.priority, eth_tx_replacement_priority(1_000_000));
+			assert_eq!(pending.longevity, 6);
+
+			// same tip doesn't replace
+			assert_eq!(validate(1_000_000).priority, pending.priority);
+
+			// speed up with 10% higher tip
+			let replacement = validate(1_100_000);
+			assert_eq!(replacement.provides, pending.provides);
+			assert_eq!(replacement.longevity, pending.longevity);
+			assert!(replacement.priority > pending.priority);
+
+			// expired
+			frame_system::Pallet::<TestRuntime>::set_block_number(11);
+			assert_eq!(validate(1_000_000).longevity, 0);
+		})
+	}
+
+	fn eth_tx(nonce: u32) -> CheckNonce<TestRuntime> {
+		let mut check_nonce = CheckNonce::<TestRuntime>::from(nonce);
+		check_nonce.mark_as_ethereum_tx(10, 0);
+		check_nonce.allow_nonce_lanes();
+		check_nonce
+	}
+
+	#[test]
+	fn check_nonce_lanes_works() {
+		new_test_ext().execute_with(|| {
+			let alice = AccountId32::from([8; 32]);
+			let address =
+				<TestRuntime as module_evm::Config>::AddressMapping::get_evm_address(&alice).unwrap_or_else(|| {
+					<TestRuntime as module_evm::Config>::AddressMapping::get_default_evm_address(&alice)
+				});
+			let lane_1 = |sequence| primitives::evm::encode_nonce_lane(1, sequence).unwrap();
+			let lane_2 = |sequence| primitives::evm::encode_nonce_lane(2, sequence).unwrap();
+			let info = DispatchInfo::default();
+
+			frame_system::Account::<TestRuntime>::mutate(&alice, |account| account.nonce = 2);
+			module_evm::Accounts::<TestRuntime>::insert(
+				&address,
+				module_evm::AccountInfo {
+					nonce: 2,
+					contract_info: None,
+				},
+			);
+
+			// not opted in, lane encoded nonce is only a far future nonce
+			assert_ok!(eth_tx(lane_1(0)).validate(&alice, CALL, &info, 0));
+			assert_noop!(
+				eth_tx(lane_1(0)).pre_dispatch(&alice, CALL, &info, 0),
+				InvalidTransaction::Future
+			);
+
+			assert_ok!(module_evm_accounts::Pallet::<TestRuntime>::set_nonce_lanes(
+				RuntimeOrigin::signed(alice.clone()),
+				3
+			));
+
+			// lane 0 continues from the account nonce
+			assert_noop!(
+				eth_tx(1).pre_dispatch(&alice, CALL, &info, 0),
+				InvalidTransaction::Stale
+			);
+			// lane out of range
+			assert_noop!(
+				eth_tx(primitives::evm::encode_nonce_lane(3, 0).unwrap()).validate(&alice, CALL, &info, 0),
+				InvalidTransaction::BadProof
+			);
+			// eth_call can only use lane 0
+			let mut eth_call_tx = CheckNonce::<TestRuntime>::from(lane_1(0));
+			eth_call_tx.mark_as_ethereum_tx(10, 0);
+			assert_noop!(
+				eth_call_tx.validate(&alice, CALL, &info, 0),
+				InvalidTransaction::BadProof
+			);
+
+			// stuck tx in lane 1 doesn't block lane 2
+			assert_eq!(
+				eth_tx(lane_1(1)).validate(&alice, CALL, &info, 0),
+				Ok(ValidTransaction {
+					priority: 0,
+					requires: vec![Encode::encode(&(address, lane_1(0)))],
+					provides: vec![Encode::encode(&(address, lane_1(1)))],
+					longevity: 10,
+					propagate: true,
+				})
+			);
+			assert_eq!(
+				eth_tx(lane_2(0)).validate(&alice, CALL, &info, 0),
+				Ok(ValidTransaction {
+					priority: 0,
+					requires: vec![],
+					provides: vec![Encode::encode(&(address, lane_2(0)))],
+					longevity: 10,
+					propagate: true,
+				})
+			);
+			assert_eq!(eth_tx(lane_2(0)).pre_dispatch(&alice, CALL, &info, 0), Ok(true));
+			assert_eq!(module_evm::LaneCreateNonce::<TestRuntime>::get(), Some(lane_2(0)));
+			assert_ok!(CheckNonce::<TestRuntime>::post_dispatch(
+				Some(true),
+				&info,
+				&Default::default(),
+				0,
+				&Ok(())
+			));
+			assert_eq!(module_evm::LaneCreateNonce::<TestRuntime>::get(), None);
+
+			assert_ok!(eth_tx(2).pre_dispatch(&alice, CALL, &info, 0));
+			assert_eq!(module_evm_accounts::LaneNonces::<TestRuntime>::get(&alice, 0), 3);
+			assert_eq!(module_evm_accounts::LaneNonces::<TestRuntime>::get(&alice, 1), 0);
+			assert_eq!(module_evm_accounts::LaneNonces::<TestRuntime>::get(&alice, 2), 1);
+
+			// the EVM nonce is bumped by the executed txs of all lanes
+			module_evm::Accounts::<TestRuntime>::insert(
+				&address,
+				module_evm::AccountInfo {
+					nonce: 4,
+					contract_info: None,
+				},
+			);
+
+			// no replay in the same lane, nor after opting out and in again
+			assert_noop!(
+				eth_tx(lane_2(0)).validate(&alice, CALL, &info, 0),
+				InvalidTransaction::Stale
+			);
+			assert_ok!(module_evm_accounts::Pallet::<TestRuntime>::set_nonce_lanes(
+				RuntimeOrigin::signed(alice.clone()),
+				0
+			));
+			assert_noop!(
+				eth_tx(2).pre_dispatch(&alice, CALL, &info, 0),
+				InvalidTransaction::Stale
+			);
+			assert_noop!(
+				eth_tx(lane_2(0)).pre_dispatch(&alice, CALL, &info, 0),
+				InvalidTransaction::Future
+			);
+			assert_ok!(module_evm_accounts::Pallet::<TestRuntime>::set_nonce_lanes(
+				RuntimeOrigin::signed(alice.clone()),
+				3
+			));
+			assert_noop!(
+				eth_tx(lane_2(0)).pre_dispatch(&alice, CALL, &info, 0),
+				InvalidTransaction::Stale
+			);
+			assert_noop!(
+				eth_tx(2).pre_dispatch(&alice, CALL, &info, 0),
+				InvalidTransaction::Stale
+			);
+		})
+	}
 }
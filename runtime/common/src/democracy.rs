@@ -0,0 +1,282 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Vote weight boost of democracy for the long-term lockers in earning.
+
+use crate::{BondedVoteBoost, GovernanceParameters};
+use frame_support::traits::{
+	BalanceStatus, Currency, ExistenceRequirement, LockIdentifier, LockableCurrency, ReservableCurrency,
+	SignedImbalance, WithdrawReasons,
+};
+use frame_system::RawOrigin;
+use orml_traits::{parameters::ParameterStore, Handler};
+use pallet_democracy::{AccountVote, ReferendumInfo, ReferendumInfoOf, Voting, VotingOf};
+use primitives::Balance;
+use sp_runtime::{traits::StaticLookup, DispatchError, DispatchResult, Perbill, Saturating};
+use sp_std::marker::PhantomData;
+
+/// The native currency used by democracy, whose free balance includes the boost of the active
+/// bonded balance in earning: `free + BondedVoteBoost * active_bonded`.
+///
+/// The bonded tokens are already part of the free balance, only the boost is added, so the tokens
+/// locked by both earning and a conviction vote are not double counted. The boost is capped at
+/// 100% by `Permill`. All the other operations are forwarded to `C`.
+pub struct BoostedVotingCurrency<T, C, Store>(PhantomData<(T, C, Store)>);
+
+impl<T, C, Store> BoostedVotingCurrency<T, C, Store>
+where
+	T: module_earning::Config,
+	Store: ParameterStore<GovernanceParameters>,
+{
+	/// The extra voting balance of the active bonded balance in earning, the unbonding balance
+	/// gets no boost.
+	pub fn vote_boost(who: &T::AccountId) -> Balance {
+		let boost = Store::get(BondedVoteBoost).unwrap_or_default();
+		let active_bonded = module_earning::Pallet::<T>::ledger(who)
+			.map(|ledger| ledger.active())
+			.unwrap_or_default();
+		boost * active_bonded
+	}
+}
+
+impl<T, C, Store> Currency<T::AccountId> for BoostedVotingCurrency<T, C, Store>
+where
+	T: module_earning::Config,
+	C: Currency<T::AccountId, Balance = Balance>,
+	Store: ParameterStore<GovernanceParameters>,
+{
+	type Balance = Balance;
+	type PositiveImbalance = C::PositiveImbalance;
+	type NegativeImbalance = C::NegativeImbalance;
+
+	fn total_balance(who: &T::AccountId) -> Balance {
+		C::total_balance(who)
+	}
+
+	fn can_slash(who: &T::AccountId, value: Balance) -> bool {
+		C::can_slash(who, value)
+	}
+
+	fn total_issuance() -> Balance {
+		C::total_issuance()
+	}
+
+	fn active_issuance() -> Balance {
+		C::active_issuance()
+	}
+
+	fn deactivate(amount: Balance) {
+		C::deactivate(amount)
+	}
+
+	fn reactivate(amount: Balance) {
+		C::reactivate(amount)
+	}
+
+	fn minimum_balance() -> Balance {
+		C::minimum_balance()
+	}
+
+	fn burn(amount: Balance) -> Self::PositiveImbalance {
+		C::burn(amount)
+	}
+
+	fn issue(amount: Balance) -> Self::NegativeImbalance {
+		C::issue(amount)
+	}
+
+	fn free_balance(who: &T::AccountId) -> Balance {
+		C::free_balance(who).saturating_add(Self::vote_boost(who))
+	}
+
+	fn ensure_can_withdraw(
+		who: &T::AccountId,
+		amount: Balance,
+		reasons: WithdrawReasons,
+		new_balance: Balance,
+	) -> DispatchResult {
+		C::ensure_can_withdraw(who, amount, reasons, new_balance)
+	}
+
+	fn transfer(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		value: Balance,
+		existence_requirement: ExistenceRequirement,
+	) -> DispatchResult {
+		C::transfer(source, dest, value, existence_requirement)
+	}
+
+	fn slash(who: &T::AccountId, value: Balance) -> (Self::NegativeImbalance, Balance) {
+		C::slash(who, value)
+	}
+
+	fn deposit_into_existing(who: &T::AccountId, value: Balance) -> Result<Self::PositiveImbalance, DispatchError> {
+		C::deposit_into_existing(who, value)
+	}
+
+	fn deposit_creating(who: &T::AccountId, value: Balance) -> Self::PositiveImbalance {
+		C::deposit_creating(who, value)
+	}
+
+	fn withdraw(
+		who: &T::AccountId,
+		value: Balance,
+		reasons: WithdrawReasons,
+		liveness: ExistenceRequirement,
+	) -> Result<Self::NegativeImbalance, DispatchError> {
+		C::withdraw(who, value, reasons, liveness)
+	}
+
+	fn make_free_balance_be(who: &T::AccountId, balance: Balance) -> SignedImbalance<Balance, Self::PositiveImbalance> {
+		C::make_free_balance_be(who, balance)
+	}
+}
+
+impl<T, C, Store> ReservableCurrency<T::AccountId> for BoostedVotingCurrency<T, C, Store>
+where
+	T: module_earning::Config,
+	C: ReservableCurrency<T::AccountId, Balance = Balance>,
+	Store: ParameterStore<GovernanceParameters>,
+{
+	fn can_reserve(who: &T::AccountId, value: Balance) -> bool {
+		C::can_reserve(who, value)
+	}
+
+	fn slash_reserved(who: &T::AccountId, value: Balance) -> (Self::NegativeImbalance, Balance) {
+		C::slash_reserved(who, value)
+	}
+
+	fn reserved_balance(who: &T::AccountId) -> Balance {
+		C::reserved_balance(who)
+	}
+
+	fn reserve(who: &T::AccountId, value: Balance) -> DispatchResult {
+		C::reserve(who, value)
+	}
+
+	fn unreserve(who: &T::AccountId, value: Balance) -> Balance {
+		C::unreserve(who, value)
+	}
+
+	fn repatriate_reserved(
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Balance,
+		status: BalanceStatus,
+	) -> Result<Balance, DispatchError> {
+		C::repatriate_reserved(slashed, beneficiary, value, status)
+	}
+}
+
+impl<T, C, Store> LockableCurrency<T::AccountId> for BoostedVotingCurrency<T, C, Store>
+where
+	T: module_earning::Config,
+	C: LockableCurrency<T::AccountId, Balance = Balance>,
+	Store: ParameterStore<GovernanceParameters>,
+{
+	type Moment = C::Moment;
+	type MaxLocks = C::MaxLocks;
+
+	fn set_lock(id: LockIdentifier, who: &T::AccountId, amount: Balance, reasons: WithdrawReasons) {
+		C::set_lock(id, who, amount, reasons)
+	}
+
+	fn extend_lock(id: LockIdentifier, who: &T::AccountId, amount: Balance, reasons: WithdrawReasons) {
+		C::extend_lock(id, who, amount, reasons)
+	}
+
+	fn remove_lock(id: LockIdentifier, who: &T::AccountId) {
+		C::remove_lock(id, who)
+	}
+}
+
+/// Caps the votes and the delegation of ongoing referenda at the voting balance after unbonding in
+/// earning, so the boost of the unbonded balance is removed immediately. The capped votes are
+/// re-tallied by voting again.
+pub struct CapBoostedVotesOnUnbond<T>(PhantomData<T>);
+impl<T> Handler<(T::AccountId, Balance)> for CapBoostedVotesOnUnbond<T>
+where
+	T: pallet_democracy::Config,
+	T::Currency: Currency<T::AccountId, Balance = Balance>,
+{
+	fn handle((who, _): &(T::AccountId, Balance)) -> DispatchResult {
+		let cap = T::Currency::free_balance(who);
+
+		match VotingOf::<T>::get(who) {
+			Voting::Direct { votes, .. } => {
+				for (ref_index, vote) in votes {
+					if vote.balance() <= cap
+						|| !matches!(ReferendumInfoOf::<T>::get(ref_index), Some(ReferendumInfo::Ongoing(_)))
+					{
+						continue;
+					}
+
+					let capped_vote = match vote {
+						AccountVote::Standard { vote, .. } => AccountVote::Standard { vote, balance: cap },
+						AccountVote::Split { aye, nay } => {
+							let ratio = Perbill::from_rational(cap, aye.saturating_add(nay));
+							AccountVote::Split {
+								aye: ratio * aye,
+								nay: ratio * nay,
+							}
+						}
+					};
+					let _ = pallet_democracy::Pallet::<T>::vote(
+						RawOrigin::Signed(who.clone()).into(),
+						ref_index,
+						capped_vote,
+					)
+					.map_err(|e| {
+						log::warn!(
+							target: "democracy",
+							"CapBoostedVotesOnUnbond: failed to cap the vote of {:?} on referendum {:?}: {:?}",
+							who, ref_index, e
+						);
+					});
+				}
+			}
+			Voting::Delegating {
+				balance,
+				target,
+				conviction,
+				..
+			} if balance > cap => {
+				let _ = pallet_democracy::Pallet::<T>::undelegate(RawOrigin::Signed(who.clone()).into())
+					.and_then(|_| {
+						pallet_democracy::Pallet::<T>::delegate(
+							RawOrigin::Signed(who.clone()).into(),
+							T::Lookup::unlookup(target),
+							conviction,
+							cap,
+						)
+					})
+					.map_err(|e| {
+						log::warn!(
+							target: "democracy",
+							"CapBoostedVotesOnUnbond: failed to cap the delegation of {:?}: {:?}",
+							who, e
+						);
+					});
+			}
+			_ => {}
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,93 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Distribution of the transaction fees and tips between treasury, collators and burn.
+
+use crate::{BurnFeeRatio, CollatorFeeRatio, FeeParameters};
+use frame_support::traits::{Currency, Get, Imbalance, OnUnbalanced};
+use orml_traits::parameters::ParameterStore;
+use sp_runtime::{traits::Zero, Perbill};
+use sp_std::marker::PhantomData;
+
+type NegativeImbalanceOf<T> = <<T as module_transaction_payment::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+/// The `(collator, burn)` ratios of the fee distribution. The collator ratio defaults to
+/// `DefaultCollatorRatio` and the burn ratio defaults to zero if not set by governance.
+pub struct FeeDistributionRatios<DefaultCollatorRatio, Store>(PhantomData<(DefaultCollatorRatio, Store)>);
+impl<DefaultCollatorRatio: Get<Perbill>, Store: ParameterStore<FeeParameters>> Get<(Perbill, Perbill)>
+	for FeeDistributionRatios<DefaultCollatorRatio, Store>
+{
+	fn get() -> (Perbill, Perbill) {
+		(
+			Store::get(CollatorFeeRatio).unwrap_or_else(DefaultCollatorRatio::get),
+			Store::get(BurnFeeRatio).unwrap_or_default(),
+		)
+	}
+}
+
+/// Split the fees and tips of a transaction by `Ratios`: the collator part goes to
+/// `CollatorPotAccount`, the burn part is dropped to reduce the total issuance, and the rest
+/// goes to `TreasuryAccount`. The burn part is capped by what is left after the collator part.
+///
+/// The distributed amounts are accumulated by transaction payment, which emits the
+/// `FeesDistributed` event once per block.
+pub struct DealWithFees<T, TreasuryAccount, CollatorPotAccount, Ratios>(
+	PhantomData<(T, TreasuryAccount, CollatorPotAccount, Ratios)>,
+);
+
+impl<T, TreasuryAccount, CollatorPotAccount, Ratios> OnUnbalanced<NegativeImbalanceOf<T>>
+	for DealWithFees<T, TreasuryAccount, CollatorPotAccount, Ratios>
+where
+	T: module_transaction_payment::Config,
+	TreasuryAccount: Get<T::AccountId>,
+	CollatorPotAccount: Get<T::AccountId>,
+	Ratios: Get<(Perbill, Perbill)>,
+{
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalanceOf<T>>) {
+		if let Some(mut fees) = fees_then_tips.next() {
+			if let Some(tips) = fees_then_tips.next() {
+				tips.merge_into(&mut fees);
+			}
+
+			let (collator_ratio, burn_ratio) = Ratios::get();
+			let total = fees.peek();
+			let (to_collators, rest) = fees.split(collator_ratio.mul_floor(total));
+			let (burned, to_treasury) = rest.split(burn_ratio.mul_floor(total));
+
+			let (collators_amount, burned_amount, treasury_amount) =
+				(to_collators.peek(), burned.peek(), to_treasury.peek());
+
+			if !collators_amount.is_zero() {
+				T::Currency::resolve_creating(&CollatorPotAccount::get(), to_collators);
+			}
+			if !treasury_amount.is_zero() {
+				T::Currency::resolve_creating(&TreasuryAccount::get(), to_treasury);
+			}
+			// dropping the negative imbalance reduces the total issuance.
+			drop(burned);
+
+			module_transaction_payment::Pallet::<T>::note_fees_distributed(
+				treasury_amount,
+				collators_amount,
+				burned_amount,
+			);
+		}
+	}
+}
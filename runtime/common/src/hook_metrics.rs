@@ -0,0 +1,388 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Per-pallet attribution of the `on_initialize` weight and proof size.
+//!
+//! `HookMetered` wraps the pallets passed to `frame_executive::Executive` and runs their
+//! `on_initialize` hooks one by one, recording the weight returned by each hook and the proof
+//! size measured around it. The pallets with a non-zero weight or proof size are stored for the
+//! last `Retention` blocks and exposed by `HookMetricsApi::get_hook_metrics`. A warning is logged
+//! when a single hook exceeds the `Threshold` share of the max block weight or proof size.
+//!
+//! The runtimes only use the wrapper when built with the `hook-metrics` feature, otherwise the
+//! generic code is never instantiated and the production runtimes are unaffected.
+//!
+//! Overhead when enabled: two `storage_proof_size` host calls per pallet, and one storage write
+//! per block of about 50 bytes per reported pallet, which is itself included in the recorded
+//! proof size of the next blocks. The proof size is only measured when the node records the
+//! storage proof, i.e. during block production and import; it's zero otherwise.
+
+use cumulus_primitives_proof_size_hostfunction::storage_proof_size::storage_proof_size;
+use frame_support::{
+	storage_alias,
+	traits::{
+		BeforeAllRuntimeMigrations, Get, OffchainWorker, OnFinalize, OnIdle, OnInitialize, OnPoll, OnRuntimeUpgrade,
+		PalletInfoAccess,
+	},
+	weights::{Weight, WeightMeter},
+	Twox64Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::UniqueSaturatedInto, Perbill, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The `on_initialize` weight and proof size of a pallet in a block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PalletHookMetrics {
+	pub pallet: Vec<u8>,
+	/// The weight returned by the hook.
+	pub weight: Weight,
+	/// The proof size measured around the hook.
+	pub proof_size: u64,
+}
+
+/// The `on_initialize` metrics of the pallets with a non-zero weight or proof size in a block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BlockHookMetrics {
+	pub block_number: u32,
+	pub pallets: Vec<PalletHookMetrics>,
+}
+
+/// The metrics of the last blocks, keyed by `block_number % Retention`.
+#[storage_alias]
+pub type BlockMetrics = StorageMap<HookMetrics, Twox64Concat, u32, BlockHookMetrics>;
+
+sp_api::decl_runtime_apis! {
+	pub trait HookMetricsApi {
+		/// The `on_initialize` metrics of the last `last_n_blocks` blocks, the latest first.
+		/// Empty if the runtime isn't built with the `hook-metrics` feature.
+		fn get_hook_metrics(last_n_blocks: u32) -> Vec<BlockHookMetrics>;
+	}
+}
+
+/// Runs the `on_initialize` hooks of the pallets one by one and records their metrics.
+pub trait MeteredOnInitialize<BlockNumber> {
+	fn metered_on_initialize(n: BlockNumber, metrics: &mut Vec<PalletHookMetrics>) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(96)]
+#[tuple_types_custom_trait_bound(OnInitialize<BlockNumber> + PalletInfoAccess)]
+impl<BlockNumber: Clone> MeteredOnInitialize<BlockNumber> for Tuple {
+	fn metered_on_initialize(n: BlockNumber, metrics: &mut Vec<PalletHookMetrics>) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			let proof_size_before = storage_proof_size();
+			let hook_weight = Tuple::on_initialize(n.clone());
+			// `storage_proof_size` returns `u64::MAX` if the proof isn't recorded.
+			let proof_size = storage_proof_size().saturating_sub(proof_size_before);
+			if !hook_weight.is_zero() || proof_size != 0 {
+				metrics.push(PalletHookMetrics {
+					pallet: Tuple::name().as_bytes().to_vec(),
+					weight: hook_weight,
+					proof_size,
+				});
+			}
+			weight = weight.saturating_add(hook_weight);
+		)* );
+		weight
+	}
+}
+
+/// Wraps `AllPallets` to record the metrics of their `on_initialize` hooks, all the other hooks
+/// are forwarded as is.
+///
+/// - `Threshold`: the share of the max block weight or proof size above which a hook is logged.
+/// - `Retention`: the number of blocks whose metrics are kept.
+pub struct HookMetered<T, AllPallets, Threshold, Retention>(PhantomData<(T, AllPallets, Threshold, Retention)>);
+
+impl<T, AllPallets, Threshold, Retention> HookMetered<T, AllPallets, Threshold, Retention>
+where
+	T: frame_system::Config,
+	Retention: Get<u32>,
+{
+	/// The metrics of the last `last_n_blocks` blocks, the latest first.
+	pub fn hook_metrics(last_n_blocks: u32) -> Vec<BlockHookMetrics> {
+		let retention = Retention::get().max(1);
+		let now: u32 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+		(0..last_n_blocks.min(retention))
+			.map_while(|i| now.checked_sub(i))
+			.filter_map(|block_number| {
+				BlockMetrics::get(block_number % retention).filter(|metrics| metrics.block_number == block_number)
+			})
+			.collect()
+	}
+}
+
+impl<T, AllPallets, Threshold, Retention> OnInitialize<BlockNumberFor<T>>
+	for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	T: frame_system::Config,
+	AllPallets: MeteredOnInitialize<BlockNumberFor<T>>,
+	Threshold: Get<Perbill>,
+	Retention: Get<u32>,
+{
+	fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+		let mut pallets = Vec::new();
+		let weight = AllPallets::metered_on_initialize(n, &mut pallets);
+
+		let max_block = T::BlockWeights::get().max_block;
+		let threshold = Threshold::get();
+		for metrics in pallets.iter().filter(|metrics| {
+			metrics.weight.ref_time() > threshold.mul_floor(max_block.ref_time())
+				|| metrics.weight.proof_size() > threshold.mul_floor(max_block.proof_size())
+				|| metrics.proof_size > threshold.mul_floor(max_block.proof_size())
+		}) {
+			log::warn!(
+				target: "hook-metrics",
+				"on_initialize of {:?} exceeds {:?} of the block budget: weight {:?}, proof size {:?}",
+				sp_std::str::from_utf8(&metrics.pallet).unwrap_or_default(),
+				threshold,
+				metrics.weight,
+				metrics.proof_size,
+			);
+		}
+
+		let block_number: u32 = n.unique_saturated_into();
+		BlockMetrics::insert(
+			block_number % Retention::get().max(1),
+			BlockHookMetrics { block_number, pallets },
+		);
+
+		weight.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+impl<T, AllPallets, Threshold, Retention> OnFinalize<BlockNumberFor<T>>
+	for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	T: frame_system::Config,
+	AllPallets: OnFinalize<BlockNumberFor<T>>,
+{
+	fn on_finalize(n: BlockNumberFor<T>) {
+		AllPallets::on_finalize(n)
+	}
+}
+
+impl<T, AllPallets, Threshold, Retention> OnIdle<BlockNumberFor<T>> for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	T: frame_system::Config,
+	AllPallets: OnIdle<BlockNumberFor<T>>,
+{
+	fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		AllPallets::on_idle(n, remaining_weight)
+	}
+}
+
+impl<T, AllPallets, Threshold, Retention> OnPoll<BlockNumberFor<T>> for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	T: frame_system::Config,
+	AllPallets: OnPoll<BlockNumberFor<T>>,
+{
+	fn on_poll(n: BlockNumberFor<T>, weight: &mut WeightMeter) {
+		AllPallets::on_poll(n, weight)
+	}
+}
+
+impl<T, AllPallets, Threshold, Retention> OffchainWorker<BlockNumberFor<T>>
+	for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	T: frame_system::Config,
+	AllPallets: OffchainWorker<BlockNumberFor<T>>,
+{
+	fn offchain_worker(n: BlockNumberFor<T>) {
+		AllPallets::offchain_worker(n)
+	}
+}
+
+impl<T, AllPallets, Threshold, Retention> BeforeAllRuntimeMigrations
+	for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	AllPallets: BeforeAllRuntimeMigrations,
+{
+	fn before_all_runtime_migrations() -> Weight {
+		AllPallets::before_all_runtime_migrations()
+	}
+}
+
+impl<T, AllPallets, Threshold, Retention> OnRuntimeUpgrade for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	AllPallets: OnRuntimeUpgrade,
+{
+	fn on_runtime_upgrade() -> Weight {
+		AllPallets::on_runtime_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn try_on_runtime_upgrade(checks: bool) -> Result<Weight, sp_runtime::TryRuntimeError> {
+		AllPallets::try_on_runtime_upgrade(checks)
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+impl<T, AllPallets, Threshold, Retention> frame_support::traits::TryState<BlockNumberFor<T>>
+	for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	T: frame_system::Config,
+	AllPallets: frame_support::traits::TryState<BlockNumberFor<T>>,
+{
+	fn try_state(
+		n: BlockNumberFor<T>,
+		targets: frame_support::traits::TryStateSelect,
+	) -> Result<(), sp_runtime::TryRuntimeError> {
+		AllPallets::try_state(n, targets)
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+impl<T, AllPallets, Threshold, Retention> frame_support::traits::TryDecodeEntireStorage
+	for HookMetered<T, AllPallets, Threshold, Retention>
+where
+	AllPallets: frame_support::traits::TryDecodeEntireStorage,
+{
+	fn try_decode_entire_state() -> Result<usize, Vec<frame_support::traits::TryDecodeEntireStorageError>> {
+		AllPallets::try_decode_entire_state()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{new_test_ext, TestRuntime};
+	use frame_support::{parameter_types, traits::ConstU32};
+
+	parameter_types! {
+		pub static HeavyWeight: Weight = Weight::zero();
+		pub const Threshold: Perbill = Perbill::from_percent(10);
+	}
+
+	macro_rules! mock_pallet {
+		($name:ident, $index:expr, $weight:expr) => {
+			struct $name;
+			impl OnInitialize<u64> for $name {
+				fn on_initialize(_: u64) -> Weight {
+					$weight
+				}
+			}
+			impl PalletInfoAccess for $name {
+				fn index() -> usize {
+					$index
+				}
+				fn name() -> &'static str {
+					stringify!($name)
+				}
+				fn name_hash() -> [u8; 16] {
+					Default::default()
+				}
+				fn module_name() -> &'static str {
+					stringify!($name)
+				}
+				fn crate_version() -> frame_support::traits::CrateVersion {
+					frame_support::traits::CrateVersion::new(0, 0, 0)
+				}
+			}
+		};
+	}
+
+	mock_pallet!(Light, 0, Weight::from_parts(1_000, 100));
+	mock_pallet!(Heavy, 1, HeavyWeight::get());
+	mock_pallet!(Idle, 2, Weight::zero());
+
+	type Metered = HookMetered<TestRuntime, (Light, Heavy, Idle), Threshold, ConstU32<3>>;
+
+	fn run_to_block(n: u64) {
+		frame_system::Pallet::<TestRuntime>::set_block_number(n);
+		Metered::on_initialize(n);
+	}
+
+	#[test]
+	fn attributes_weight_to_the_inflated_hook() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+			HeavyWeight::set(Weight::from_parts(2_000_000_000_000, 5_000_000));
+			run_to_block(2);
+
+			assert_eq!(
+				Metered::hook_metrics(10),
+				vec![
+					BlockHookMetrics {
+						block_number: 2,
+						pallets: vec![
+							PalletHookMetrics {
+								pallet: b"Light".to_vec(),
+								weight: Weight::from_parts(1_000, 100),
+								proof_size: 0,
+							},
+							PalletHookMetrics {
+								pallet: b"Heavy".to_vec(),
+								weight: Weight::from_parts(2_000_000_000_000, 5_000_000),
+								proof_size: 0,
+							},
+						],
+					},
+					BlockHookMetrics {
+						block_number: 1,
+						pallets: vec![PalletHookMetrics {
+							pallet: b"Light".to_vec(),
+							weight: Weight::from_parts(1_000, 100),
+							proof_size: 0,
+						}],
+					},
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn returns_the_total_weight() {
+		new_test_ext().execute_with(|| {
+			HeavyWeight::set(Weight::from_parts(5_000, 500));
+			frame_system::Pallet::<TestRuntime>::set_block_number(1);
+			assert_eq!(
+				Metered::on_initialize(1),
+				Weight::from_parts(6_000, 600)
+					.saturating_add(<TestRuntime as frame_system::Config>::DbWeight::get().writes(1))
+			);
+		});
+	}
+
+	#[test]
+	fn keeps_the_last_blocks() {
+		new_test_ext().execute_with(|| {
+			for n in 1..=5 {
+				run_to_block(n);
+			}
+			assert_eq!(
+				Metered::hook_metrics(10)
+					.into_iter()
+					.map(|metrics| metrics.block_number)
+					.collect::<Vec<_>>(),
+				vec![5, 4, 3]
+			);
+			assert_eq!(
+				Metered::hook_metrics(2)
+					.into_iter()
+					.map(|metrics| metrics.block_number)
+					.collect::<Vec<_>>(),
+				vec![5, 4]
+			);
+			assert_eq!(BlockMetrics::iter().count(), 3);
+		});
+	}
+}
@@ -32,33 +32,54 @@ use frame_support::{
 	},
 };
 use frame_system::{limits, pallet_prelude::BlockNumberFor, EnsureRoot};
-use orml_traits::{currency::MutationHooks, GetByKey};
+use module_support::{AccountCurrencies, PriceTimestampProvider};
+use orml_traits::{
+	currency::{MutationHooks, OnDeposit, OnDust, OnSlash, OnTransfer},
+	define_parameters,
+	parameters::ParameterStore,
+	DataProviderExtended, GetByKey,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use polkadot_parachain_primitives::primitives::RelayChainBlockNumber;
 use primitives::{
 	evm::{is_system_contract, CHAIN_ID_ACALA_TESTNET, CHAIN_ID_KARURA_TESTNET, CHAIN_ID_MANDALA},
-	Balance, CurrencyId,
+	Balance, CurrencyId, Moment,
 };
 use scale_info::TypeInfo;
 use sp_core::H160;
 use sp_runtime::{
-	traits::{Convert, Hash},
+	traits::{Convert, Hash, Zero},
 	transaction_validity::TransactionPriority,
 	Perbill, RuntimeDebug, Saturating,
 };
 use sp_std::{marker::PhantomData, prelude::*};
 use static_assertions::const_assert;
 
+pub use call_permission::{CallPermission, CallPermissionApi};
 pub use check_nonce::CheckNonce;
+pub use democracy::{BoostedVotingCurrency, CapBoostedVotesOnUnbond};
+pub use fees::{DealWithFees, FeeDistributionRatios};
+pub use hook_metrics::{BlockHookMetrics, HookMetered, HookMetricsApi, PalletHookMetrics};
 pub use module_support::{ExchangeRate, PrecompileCallerFilter, Price, Rate, Ratio};
+pub use oracle::{
+	CdpEngineLiquidator, MembershipOperators, MultiSourceDataProvider, OraclePriceQuality, OracleRawValues,
+	OracleSourceMode, PriceQuality,
+};
 pub use precompile::{
 	AllPrecompiles, DEXPrecompile, EVMPrecompile, MultiCurrencyPrecompile, NFTPrecompile, OraclePrecompile,
 	SchedulePrecompile, StableAssetPrecompile,
 };
+pub use preferences::{
+	AlternativeFeeSwapPathPreference, DustConsolidationPreference, IncentiveClaimPreferences, SessionKeyPreferences,
+};
 pub use primitives::{
 	currency::{TokenInfo, ACA, AUSD, BNC, DOT, KAR, KBTC, KINT, KSM, KUSD, LCDOT, LDOT, LKSM, PHA, TAI, TAP, VSKSM},
 	AccountId,
 };
+pub use protocol_stats::{ProtocolRevenue, ProtocolStats, TvlSource};
+pub use session_keys::EvmCallTarget;
+pub use statistics::YieldIndexes;
+pub use system_info::{AuthorizedUpgradeStatus, ChainStatus, SystemInfoApi};
 pub use xcm_impl::{local_currency_location, native_currency_location, AcalaDropAssets, FixedRateOfAsset, XcmExecutor};
 
 #[cfg(feature = "std")]
@@ -69,8 +90,19 @@ use sp_core::bytes::from_hex;
 use std::{collections::btree_map::BTreeMap, str::FromStr};
 
 pub mod bench;
+pub mod call_permission;
 pub mod check_nonce;
+pub mod democracy;
+pub mod fees;
+pub mod hook_metrics;
+pub mod oracle;
 pub mod precompile;
+pub mod preferences;
+pub mod protocol_stats;
+pub mod session_keys;
+pub mod statistics;
+pub mod system_info;
+pub mod weights;
 pub mod xcm_config;
 pub mod xcm_impl;
 
@@ -80,6 +112,16 @@ mod mock;
 
 pub type TimeStampedPrice = orml_oracle::TimestampedValue<Price, primitives::Moment>;
 
+/// The timestamp of the oracle price, read from the `TimeStampedPrice` of the data provider.
+pub struct OraclePriceTimestamp<Source>(PhantomData<Source>);
+impl<Source: DataProviderExtended<CurrencyId, TimeStampedPrice>> PriceTimestampProvider<CurrencyId>
+	for OraclePriceTimestamp<Source>
+{
+	fn get_price_timestamp(currency_id: CurrencyId) -> Option<Moment> {
+		Source::get_no_op(&currency_id).map(|price| price.timestamp)
+	}
+}
+
 // Priority of unsigned transactions
 parameter_types! {
 	// Operational = final_fee * OperationalFeeMultiplier / TipPerWeightStep * max_tx_per_block + (tip + 1) / TipPerWeightStep * max_tx_per_block
@@ -96,8 +138,96 @@ parameter_types! {
 	MinOperationalPriority: TransactionPriority = (1_500_000_000u128 * OperationalFeeMultiplier::get() as u128 / TipPerWeightStep::get())
 		.try_into()
 		.expect("Check that there is no overflow here");
-	pub CdpEngineUnsignedPriority: TransactionPriority = MinOperationalPriority::get() - 1000;
 	pub AuctionManagerUnsignedPriority: TransactionPriority = MinOperationalPriority::get() - 2000;
+	pub TransactionPaymentUnsignedPriority: TransactionPriority = MinOperationalPriority::get() - 1000;
+	// Unsigned txs don't get the priority of fee, start the boosted ones above all signed normal txs.
+	pub UnsignedPriorityBase: TransactionPriority = MinOperationalPriority::get() / 2;
+	// Signed normal priority < MinOperationalPriority / 2, so boosted txs never outrank operational txs.
+	pub MaxPriorityBoost: TransactionPriority = MinOperationalPriority::get() / 2 - 1;
+}
+
+// Priority boosts of the txs keeping the system solvent during congestion, capped at `MaxPriorityBoost`.
+define_parameters! {
+	pub PriorityParameters = {
+		LiquidationPriorityBoost(CurrencyId): TransactionPriority = 0,
+		AuctionBidPriorityBoost(CurrencyId): TransactionPriority = 1,
+		OracleFeedPriorityBoost: TransactionPriority = 2,
+	}
+}
+
+// Voting boost of democracy for the active bonded balance in earning.
+define_parameters! {
+	pub GovernanceParameters = {
+		BondedVoteBoost: Permill = 0,
+	}
+}
+
+// Governance override of the EVM storage growth budget of a block.
+define_parameters! {
+	pub EvmParameters = {
+		BlockStorageBudget: u32 = 0,
+	}
+}
+
+// Governance selection of the oracles combined for the price of a currency.
+define_parameters! {
+	pub OracleParameters = {
+		OracleSource(CurrencyId): OracleSourceMode = 0,
+	}
+}
+
+// Governance split of the transaction fees, the rest goes to treasury.
+define_parameters! {
+	pub FeeParameters = {
+		CollatorFeeRatio: Perbill = 0,
+		BurnFeeRatio: Perbill = 1,
+	}
+}
+
+fn capped_priority_boost(
+	boost: Option<TransactionPriority>,
+	default_offset: TransactionPriority,
+) -> TransactionPriority {
+	boost
+		.unwrap_or_else(|| MaxPriorityBoost::get().saturating_sub(default_offset))
+		.min(MaxPriorityBoost::get())
+}
+
+/// Priority of the unsigned liquidate and settle of cdp-engine, by the collateral type.
+pub struct CdpEngineUnsignedPriority<Store>(PhantomData<Store>);
+impl<Store: ParameterStore<PriorityParameters>> GetByKey<CurrencyId, TransactionPriority>
+	for CdpEngineUnsignedPriority<Store>
+{
+	fn get(currency_id: &CurrencyId) -> TransactionPriority {
+		let boost = capped_priority_boost(Store::get(LiquidationPriorityBoost(*currency_id)), 999);
+		UnsignedPriorityBase::get().saturating_add(boost)
+	}
+}
+
+/// Priority of the bids of collateral auctions about to close, by the collateral type.
+pub struct AuctionBidPriority<Store>(PhantomData<Store>);
+impl<Store: ParameterStore<PriorityParameters>> GetByKey<CurrencyId, TransactionPriority>
+	for AuctionBidPriority<Store>
+{
+	fn get(currency_id: &CurrencyId) -> TransactionPriority {
+		capped_priority_boost(Store::get(AuctionBidPriorityBoost(*currency_id)), 1999)
+	}
+}
+
+/// Priority of the oracle feeds of the oracle operators.
+pub struct OracleFeedPriority<Store>(PhantomData<Store>);
+impl<Store: ParameterStore<PriorityParameters>> Get<TransactionPriority> for OracleFeedPriority<Store> {
+	fn get() -> TransactionPriority {
+		capped_priority_boost(Store::get(OracleFeedPriorityBoost), 2999)
+	}
+}
+
+/// The EVM storage growth budget of a block, `Default` unless overridden by governance.
+pub struct EvmBlockStorageBudget<Default, Store>(PhantomData<(Default, Store)>);
+impl<Default: Get<u32>, Store: ParameterStore<EvmParameters>> Get<u32> for EvmBlockStorageBudget<Default, Store> {
+	fn get() -> u32 {
+		Store::get(BlockStorageBudget).unwrap_or_else(Default::get)
+	}
 }
 
 /// The call is allowed only if caller is a system contract.
@@ -247,6 +377,7 @@ pub type FinancialCouncilMembershipInstance = pallet_membership::Instance2;
 pub type HomaCouncilMembershipInstance = pallet_membership::Instance3;
 pub type TechnicalCommitteeMembershipInstance = pallet_membership::Instance4;
 pub type OperatorMembershipInstanceAcala = pallet_membership::Instance5;
+pub type OperatorMembershipInstanceSecondary = pallet_membership::Instance6;
 
 // General Council
 pub type EnsureRootOrAllGeneralCouncil = EitherOfDiverse<
@@ -379,22 +510,48 @@ impl Default for ProxyType {
 	}
 }
 
-pub struct CurrencyHooks<T, DustAccount>(PhantomData<T>, DustAccount);
-impl<T, DustAccount> MutationHooks<T::AccountId, T::CurrencyId, T::Balance> for CurrencyHooks<T, DustAccount>
+/// The mutation hooks of orml-tokens. The dust is handled by the dust modes of module-currencies,
+/// which transfers it to `DustAccount` by default. The deposits, transfers and slashes are
+/// observed by `Observer`.
+pub struct CurrencyHooks<T, DustAccount, Observer = ()>(PhantomData<(T, Observer)>, DustAccount);
+impl<T, DustAccount, Observer> MutationHooks<T::AccountId, T::CurrencyId, T::Balance>
+	for CurrencyHooks<T, DustAccount, Observer>
 where
-	T: orml_tokens::Config,
+	T: orml_tokens::Config + module_currencies::Config,
 	DustAccount: Get<<T as frame_system::Config>::AccountId>,
+	module_currencies::HandleDust<T, DustAccount>: OnDust<T::AccountId, T::CurrencyId, T::Balance>,
+	Observer: OnDeposit<T::AccountId, T::CurrencyId, T::Balance>
+		+ OnTransfer<T::AccountId, T::CurrencyId, T::Balance>
+		+ OnSlash<T::AccountId, T::CurrencyId, T::Balance>,
 {
-	type OnDust = orml_tokens::TransferDust<T, DustAccount>;
-	type OnSlash = ();
+	type OnDust = module_currencies::HandleDust<T, DustAccount>;
+	type OnSlash = Observer;
 	type PreDeposit = ();
-	type PostDeposit = ();
+	type PostDeposit = Observer;
 	type PreTransfer = ();
-	type PostTransfer = ();
+	type PostTransfer = Observer;
 	type OnNewTokenAccount = ();
 	type OnKilledTokenAccount = ();
 }
 
+/// The currencies held by an account in orml-tokens, in storage order.
+pub struct TokensAccountCurrencies<T>(PhantomData<T>);
+impl<T: orml_tokens::Config<CurrencyId = CurrencyId>> AccountCurrencies<T::AccountId> for TokensAccountCurrencies<T> {
+	fn account_currencies(who: &T::AccountId, start_after: Option<CurrencyId>, limit: u32) -> Vec<CurrencyId> {
+		let accounts = match start_after {
+			Some(currency_id) => orml_tokens::Accounts::<T>::iter_prefix_from(
+				who,
+				orml_tokens::Accounts::<T>::hashed_key_for(who, currency_id),
+			),
+			None => orml_tokens::Accounts::<T>::iter_prefix(who),
+		};
+		accounts
+			.map(|(currency_id, _)| currency_id)
+			.take(limit as usize)
+			.collect()
+	}
+}
+
 pub struct EvmLimits<T>(PhantomData<T>);
 impl<T> EvmLimits<T>
 where
@@ -460,6 +617,16 @@ where
 	}
 }
 
+/// The state of an EVM address in the EVM, for the EVM accounts runtime API.
+pub fn evm_account_state<T: module_evm::Config>(address: &H160) -> module_evm_accounts_runtime_api::EvmAccountState {
+	module_evm::Pallet::<T>::accounts(address).map_or_else(Default::default, |account_info| {
+		module_evm_accounts_runtime_api::EvmAccountState {
+			is_contract: account_info.contract_info.is_some(),
+			has_nonce: !account_info.nonce.is_zero(),
+		}
+	})
+}
+
 #[cfg(feature = "std")]
 /// Returns `evm_genesis_accounts`
 pub fn evm_genesis(evm_accounts: Vec<H160>) -> BTreeMap<H160, GenesisAccount<Balance, primitives::Nonce>> {
@@ -533,13 +700,116 @@ mod tests {
 
 	#[test]
 	fn check_max_normal_priority() {
-		let max_normal_priority: TransactionPriority = (MaxTipsOfPriority::get() / TipPerWeightStep::get()
+		assert!(max_normal_priority() < MinOperationalPriority::get() / 2); // 50%
+	}
+
+	fn max_normal_priority() -> TransactionPriority {
+		(MaxTipsOfPriority::get() / TipPerWeightStep::get()
 			* RuntimeBlockWeights::get()
 				.max_block
 				.ref_time()
 				.min(*RuntimeBlockLength::get().max.get(DispatchClass::Normal) as u64) as u128)
 			.try_into()
-			.expect("Check that there is no overflow here");
-		assert!(max_normal_priority < MinOperationalPriority::get() / 2); // 50%
+			.expect("Check that there is no overflow here")
+	}
+
+	pub struct NoBoosts;
+	impl ParameterStore<PriorityParameters> for NoBoosts {
+		fn get<K>(_key: K) -> Option<K::Value>
+		where
+			K: orml_traits::parameters::Key
+				+ Into<<PriorityParameters as orml_traits::parameters::AggregratedKeyValue>::AggregratedKey>,
+			<PriorityParameters as orml_traits::parameters::AggregratedKeyValue>::AggregratedValue:
+				TryInto<K::WrappedValue>,
+		{
+			None
+		}
+	}
+
+	pub struct MaxBoosts;
+	impl ParameterStore<PriorityParameters> for MaxBoosts {
+		fn get<K>(key: K) -> Option<K::Value>
+		where
+			K: orml_traits::parameters::Key
+				+ Into<<PriorityParameters as orml_traits::parameters::AggregratedKeyValue>::AggregratedKey>,
+			<PriorityParameters as orml_traits::parameters::AggregratedKeyValue>::AggregratedValue:
+				TryInto<K::WrappedValue>,
+		{
+			let value = match key.into() {
+				PriorityParametersKey::LiquidationPriorityBoost(_) => {
+					PriorityParametersValue::LiquidationPriorityBoost(TransactionPriority::MAX)
+				}
+				PriorityParametersKey::AuctionBidPriorityBoost(_) => {
+					PriorityParametersValue::AuctionBidPriorityBoost(TransactionPriority::MAX)
+				}
+				PriorityParametersKey::OracleFeedPriorityBoost(_) => {
+					PriorityParametersValue::OracleFeedPriorityBoost(TransactionPriority::MAX)
+				}
+			};
+			Some(value.try_into().ok()?.into())
+		}
+	}
+
+	#[test]
+	fn default_priority_boosts_work() {
+		assert_eq!(
+			CdpEngineUnsignedPriority::<NoBoosts>::get(&DOT),
+			MinOperationalPriority::get() - 1000
+		);
+		assert_eq!(
+			AuctionBidPriority::<NoBoosts>::get(&DOT),
+			MinOperationalPriority::get() / 2 - 2000
+		);
+		assert_eq!(
+			OracleFeedPriority::<NoBoosts>::get(),
+			MinOperationalPriority::get() / 2 - 3000
+		);
+	}
+
+	#[test]
+	fn priority_boosts_are_capped() {
+		assert_eq!(
+			CdpEngineUnsignedPriority::<MaxBoosts>::get(&DOT),
+			MinOperationalPriority::get() - 1
+		);
+		assert_eq!(AuctionBidPriority::<MaxBoosts>::get(&DOT), MaxPriorityBoost::get());
+		assert_eq!(OracleFeedPriority::<MaxBoosts>::get(), MaxPriorityBoost::get());
+	}
+
+	#[test]
+	fn boosted_txs_order_between_swaps_and_operational_txs() {
+		// the priority of signed txs sums the priority of fee with the boost of the signed extension
+		let swap = max_normal_priority();
+		for (boosts, operational) in [
+			(
+				[
+					CdpEngineUnsignedPriority::<NoBoosts>::get(&DOT),
+					AuctionBidPriority::<NoBoosts>::get(&DOT) + swap,
+					OracleFeedPriority::<NoBoosts>::get() + swap,
+				],
+				MinOperationalPriority::get(),
+			),
+			(
+				[
+					CdpEngineUnsignedPriority::<MaxBoosts>::get(&DOT),
+					AuctionBidPriority::<MaxBoosts>::get(&DOT) + swap,
+					OracleFeedPriority::<MaxBoosts>::get() + swap,
+				],
+				MinOperationalPriority::get(),
+			),
+		] {
+			let mut pool = vec![("swap", swap); 100];
+			pool.push(("operational", operational));
+			pool.extend(boosts.iter().map(|priority| ("boosted", *priority)));
+			pool.sort_by(|a, b| b.1.cmp(&a.1));
+
+			assert_eq!(pool[0].0, "operational");
+			assert!(pool[1..4].iter().all(|(kind, _)| *kind == "boosted"));
+			assert!(pool[4..].iter().all(|(kind, _)| *kind == "swap"));
+		}
+
+		// boosted signed txs without any tip are still ahead of swaps with the max tips
+		assert!(AuctionBidPriority::<NoBoosts>::get(&DOT) > swap);
+		assert!(OracleFeedPriority::<NoBoosts>::get() > swap);
 	}
 }
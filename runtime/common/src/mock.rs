@@ -180,6 +180,7 @@ impl module_evm_accounts::Config for TestRuntime {
 	type AddressMapping = EvmAddressMapping<TestRuntime>;
 	type TransferAll = Currencies;
 	type ChainId = EvmChainId<TestRuntime>;
+	type MaxNonceLanes = ConstU32<8>;
 	type WeightInfo = ();
 }
 
@@ -209,6 +210,9 @@ impl module_evm::Config for TestRuntime {
 	type Randomness = TestRandomness<Self>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
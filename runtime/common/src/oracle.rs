@@ -0,0 +1,554 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Adapters of the oracle operator rotation for the membership and the oracle, of the
+//! liquidations triggered by the feeds for the cdp engine, and the aggregation of the prices of
+//! the Acala and the secondary oracles.
+
+use crate::{OracleParameters, OracleSource, TimeStampedPrice};
+use frame_support::{
+	pallet_prelude::Weight,
+	traits::{Get, Time},
+};
+use frame_system::RawOrigin;
+use module_cdp_engine::CDPStatus;
+use module_oracle_operator::{OperatorMembership, OperatorValues, UnsafePositionLiquidator};
+use module_support::{EmergencyShutdown, Price};
+use orml_traits::{parameters::ParameterStore, DataFeeder, DataProvider, DataProviderExtended};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use primitives::{CurrencyId, Moment, Position};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::StaticLookup, DispatchResult, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Change the oracle operators of the `pallet_membership` instance `I` as root.
+pub struct MembershipOperators<T, I>(PhantomData<(T, I)>);
+impl<T: pallet_membership::Config<I>, I: 'static> OperatorMembership<T::AccountId> for MembershipOperators<T, I> {
+	fn add_operator(who: &T::AccountId) -> DispatchResult {
+		pallet_membership::Pallet::<T, I>::add_member(RawOrigin::Root.into(), T::Lookup::unlookup(who.clone()))
+			.map_or_else(|e| Err(e.error), |_| Ok(()))
+	}
+
+	fn remove_operator(who: &T::AccountId) -> DispatchResult {
+		pallet_membership::Pallet::<T, I>::remove_member(RawOrigin::Root.into(), T::Lookup::unlookup(who.clone()))
+			.map_or_else(|e| Err(e.error), |_| Ok(()))
+	}
+}
+
+/// Purge the raw values of the operators from the `orml_oracle` instance `I`, at most
+/// `MaxFeedValues` values per operator. The values left are ignored by the combine as the operator
+/// is no longer a member.
+pub struct OracleRawValues<T, I>(PhantomData<(T, I)>);
+impl<T: orml_oracle::Config<I>, I: 'static> OperatorValues<T::AccountId> for OracleRawValues<T, I> {
+	fn purge_values(who: &T::AccountId) -> u32 {
+		let limit = T::MaxFeedValues::get();
+		orml_oracle::RawValues::<T, I>::clear_prefix(who, limit, None).unique
+	}
+}
+
+/// Liquidate the unsafe CDPs of `module_cdp_engine` hinted by the oracle operators, with all the
+/// checks of the `liquidate` call. The safe CDPs are skipped before the liquidation.
+pub struct CdpEngineLiquidator<T>(PhantomData<T>);
+impl<T: module_cdp_engine::Config> UnsafePositionLiquidator<T::AccountId> for CdpEngineLiquidator<T> {
+	fn max_weight() -> Weight {
+		module_cdp_engine::Pallet::<T>::max_liquidate_weight()
+	}
+
+	fn try_liquidate(currency_id: CurrencyId, who: &T::AccountId) -> (bool, Weight) {
+		// emergency shutdown, position, collateral params, debit exchange rate and the prices
+		let check_weight = T::DbWeight::get().reads(6);
+		if T::EmergencyShutdown::is_shutdown() {
+			return (false, check_weight);
+		}
+
+		let Position { collateral, debit } = module_loans::Pallet::<T>::positions(currency_id, who);
+		if !matches!(
+			module_cdp_engine::Pallet::<T>::check_cdp_status(currency_id, collateral, debit),
+			CDPStatus::Unsafe
+		) {
+			return (false, check_weight);
+		}
+
+		match module_cdp_engine::Pallet::<T>::liquidate_unsafe_cdp(who.clone(), currency_id) {
+			Ok(weight) => (true, check_weight.saturating_add(weight)),
+			// the failed liquidation is reverted, charge the maximum weight as it may fail late
+			Err(_) => (false, Self::max_weight()),
+		}
+	}
+}
+
+/// How the prices of the Acala and the secondary oracles are combined for a currency.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum OracleSourceMode {
+	/// Only the price of the Acala oracle.
+	#[default]
+	AcalaOnly,
+	/// Only the price of the secondary oracle.
+	SecondaryOnly,
+	/// The median of the fresh prices of both oracles, or the only fresh one.
+	MedianOfBoth,
+	/// The price of the Acala oracle, or of the secondary oracle once the former is missing or
+	/// stale.
+	PrimaryWithFallback,
+}
+
+/// The prices of the `Primary` and `Secondary` oracles, combined per currency by the
+/// `OracleSource` mode set by governance, `AcalaOnly` if not set. The prices older than
+/// `ExpiresIn` are stale and ignored.
+pub struct MultiSourceDataProvider<Primary, Secondary, Store, Now, ExpiresIn>(
+	PhantomData<(Primary, Secondary, Store, Now, ExpiresIn)>,
+);
+
+impl<Primary, Secondary, Store, Now, ExpiresIn> MultiSourceDataProvider<Primary, Secondary, Store, Now, ExpiresIn>
+where
+	Store: ParameterStore<OracleParameters>,
+	Now: Time<Moment = Moment>,
+	ExpiresIn: Get<Moment>,
+{
+	/// The combination mode of `currency_id`.
+	pub fn source_mode(currency_id: CurrencyId) -> OracleSourceMode {
+		Store::get(OracleSource(currency_id)).unwrap_or_default()
+	}
+
+	fn aggregate(
+		currency_id: &CurrencyId,
+		primary: impl Fn(&CurrencyId) -> Option<TimeStampedPrice>,
+		secondary: impl Fn(&CurrencyId) -> Option<TimeStampedPrice>,
+	) -> Option<TimeStampedPrice> {
+		let now = Now::now();
+		let fresh = |price: Option<TimeStampedPrice>| {
+			price.filter(|price| price.timestamp.saturating_add(ExpiresIn::get()) > now)
+		};
+
+		match Self::source_mode(*currency_id) {
+			OracleSourceMode::AcalaOnly => fresh(primary(currency_id)),
+			OracleSourceMode::SecondaryOnly => fresh(secondary(currency_id)),
+			OracleSourceMode::PrimaryWithFallback => {
+				fresh(primary(currency_id)).or_else(|| fresh(secondary(currency_id)))
+			}
+			OracleSourceMode::MedianOfBoth => {
+				match (fresh(primary(currency_id)), fresh(secondary(currency_id))) {
+					// the median of two prices is their mean, as old as the older one
+					(Some(a), Some(b)) => Some(TimeStampedPrice {
+						value: Price::from_inner(a.value.into_inner().saturating_add(b.value.into_inner()) / 2),
+						timestamp: a.timestamp.min(b.timestamp),
+					}),
+					(a, b) => a.or(b),
+				}
+			}
+		}
+	}
+}
+
+impl<Primary, Secondary, Store, Now, ExpiresIn> DataProvider<CurrencyId, Price>
+	for MultiSourceDataProvider<Primary, Secondary, Store, Now, ExpiresIn>
+where
+	Primary: DataProvider<CurrencyId, Price> + DataProviderExtended<CurrencyId, TimeStampedPrice>,
+	Secondary: DataProvider<CurrencyId, Price> + DataProviderExtended<CurrencyId, TimeStampedPrice>,
+	Store: ParameterStore<OracleParameters>,
+	Now: Time<Moment = Moment>,
+	ExpiresIn: Get<Moment>,
+{
+	fn get(key: &CurrencyId) -> Option<Price> {
+		// `get` of the oracles keeps the combined feeds in `Values` for the oracle rpc
+		Self::aggregate(
+			key,
+			|key| <Primary as DataProvider<_, _>>::get(key).and_then(|_| Primary::get_no_op(key)),
+			|key| <Secondary as DataProvider<_, _>>::get(key).and_then(|_| Secondary::get_no_op(key)),
+		)
+		.map(|price| price.value)
+	}
+}
+
+impl<Primary, Secondary, Store, Now, ExpiresIn> DataProviderExtended<CurrencyId, TimeStampedPrice>
+	for MultiSourceDataProvider<Primary, Secondary, Store, Now, ExpiresIn>
+where
+	Primary: DataProviderExtended<CurrencyId, TimeStampedPrice>,
+	Secondary: DataProviderExtended<CurrencyId, TimeStampedPrice>,
+	Store: ParameterStore<OracleParameters>,
+	Now: Time<Moment = Moment>,
+	ExpiresIn: Get<Moment>,
+{
+	fn get_no_op(key: &CurrencyId) -> Option<TimeStampedPrice> {
+		Self::aggregate(key, Primary::get_no_op, Secondary::get_no_op)
+	}
+
+	fn get_all_values() -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
+		let mut keys = Primary::get_all_values()
+			.into_iter()
+			.chain(Secondary::get_all_values())
+			.map(|(key, _)| key)
+			.collect::<Vec<_>>();
+		keys.sort();
+		keys.dedup();
+		keys.into_iter().map(|key| (key, Self::get_no_op(&key))).collect()
+	}
+}
+
+// Aggregated data provider cannot feed.
+impl<Primary, Secondary, Store, Now, ExpiresIn, AccountId> DataFeeder<CurrencyId, Price, AccountId>
+	for MultiSourceDataProvider<Primary, Secondary, Store, Now, ExpiresIn>
+where
+	Self: DataProvider<CurrencyId, Price>,
+{
+	fn feed_value(_: Option<AccountId>, _: CurrencyId, _: Price) -> DispatchResult {
+		Err("Not supported".into())
+	}
+}
+
+/// The combined price of an oracle with the quality of the raw values it is combined from.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PriceQuality {
+	/// The combined price.
+	pub value: Price,
+	/// The timestamp of the combined price.
+	pub timestamp: Moment,
+	/// The time the combined price expires at.
+	pub expires_at: Moment,
+	/// The number of the raw values fed within `ExpiresIn`.
+	pub contributors: u32,
+	/// The lowest fresh raw value, `None` if there are no contributors.
+	pub min_value: Option<Price>,
+	/// The highest fresh raw value, `None` if there are no contributors.
+	pub max_value: Option<Price>,
+	/// The median absolute deviation of the fresh raw values from their median, `None` if there
+	/// are no contributors.
+	pub median_absolute_deviation: Option<Price>,
+}
+
+impl PriceQuality {
+	/// Measure the quality of `combined` on the `raw_values` fed within `expires_in` before `now`.
+	pub fn measure(
+		combined: TimeStampedPrice,
+		raw_values: Vec<TimeStampedPrice>,
+		now: Moment,
+		expires_in: Moment,
+	) -> Self {
+		let mut fresh = raw_values
+			.into_iter()
+			.filter(|raw| raw.timestamp.saturating_add(expires_in) > now)
+			.map(|raw| raw.value.into_inner())
+			.collect::<Vec<_>>();
+		fresh.sort_unstable();
+
+		let median_absolute_deviation = (!fresh.is_empty()).then(|| {
+			let median = median_of_sorted(&fresh);
+			let mut deviations = fresh.iter().map(|value| value.abs_diff(median)).collect::<Vec<_>>();
+			deviations.sort_unstable();
+			Price::from_inner(median_of_sorted(&deviations))
+		});
+
+		Self {
+			value: combined.value,
+			timestamp: combined.timestamp,
+			expires_at: combined.timestamp.saturating_add(expires_in),
+			contributors: fresh.len() as u32,
+			min_value: fresh.first().copied().map(Price::from_inner),
+			max_value: fresh.last().copied().map(Price::from_inner),
+			median_absolute_deviation,
+		}
+	}
+}
+
+/// The median of the sorted non-empty `values`, the mean of the middle two for an even count.
+fn median_of_sorted(values: &[u128]) -> u128 {
+	let mid = values.len() / 2;
+	if values.len() % 2 == 0 {
+		values[mid - 1].saturating_add(values[mid]) / 2
+	} else {
+		values[mid]
+	}
+}
+
+/// The `PriceQuality` of the combined prices of the `orml_oracle` instance `I`, measured on the
+/// raw values of the members. The raw values older than `ExpiresIn` do not contribute.
+pub struct OraclePriceQuality<T, I, ExpiresIn>(PhantomData<(T, I, ExpiresIn)>);
+impl<T, I: 'static, ExpiresIn> OraclePriceQuality<T, I, ExpiresIn>
+where
+	T: orml_oracle::Config<I, OracleKey = CurrencyId, OracleValue = Price>,
+	<T as orml_oracle::Config<I>>::Time: Time<Moment = Moment>,
+	ExpiresIn: Get<Moment>,
+{
+	/// The combined price of `key` with its quality, `None` if there is no combined price.
+	pub fn get(key: &CurrencyId) -> Option<PriceQuality> {
+		let combined = <orml_oracle::Pallet<T, I> as DataProviderExtended<_, _>>::get_no_op(key)?;
+		Some(PriceQuality::measure(
+			combined,
+			orml_oracle::Pallet::<T, I>::read_raw_values(key),
+			<T as orml_oracle::Config<I>>::Time::now(),
+			ExpiresIn::get(),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{OracleParametersKey, OracleParametersValue};
+	use frame_support::parameter_types;
+	use orml_traits::parameters::{AggregratedKeyValue, Key};
+	use primitives::{
+		currency::{AUSD, DOT},
+		AccountId,
+	};
+	use sp_runtime::FixedPointNumber;
+	use std::{cell::RefCell, collections::BTreeMap};
+
+	thread_local! {
+		static MODES: RefCell<BTreeMap<CurrencyId, OracleSourceMode>> = RefCell::new(BTreeMap::new());
+		static PRIMARY: RefCell<BTreeMap<CurrencyId, TimeStampedPrice>> = RefCell::new(BTreeMap::new());
+		static SECONDARY: RefCell<BTreeMap<CurrencyId, TimeStampedPrice>> = RefCell::new(BTreeMap::new());
+		static NOW: RefCell<Moment> = RefCell::new(10_000);
+	}
+
+	pub struct MockModes;
+	impl ParameterStore<OracleParameters> for MockModes {
+		fn get<K>(key: K) -> Option<K::Value>
+		where
+			K: Key + Into<<OracleParameters as AggregratedKeyValue>::AggregratedKey>,
+			<OracleParameters as AggregratedKeyValue>::AggregratedValue: TryInto<K::WrappedValue>,
+		{
+			let OracleParametersKey::OracleSource(OracleSource(currency_id)) = key.into();
+			let mode = MODES.with(|v| v.borrow().get(&currency_id).copied())?;
+			let value = OracleParametersValue::OracleSource(mode);
+			Some(value.try_into().ok()?.into())
+		}
+	}
+
+	pub struct MockNow;
+	impl Time for MockNow {
+		type Moment = Moment;
+		fn now() -> Moment {
+			NOW.with(|v| *v.borrow())
+		}
+	}
+
+	macro_rules! mock_oracle {
+		($name:ident, $values:ident) => {
+			pub struct $name;
+			impl DataProvider<CurrencyId, Price> for $name {
+				fn get(key: &CurrencyId) -> Option<Price> {
+					$values.with(|v| v.borrow().get(key).map(|price| price.value))
+				}
+			}
+			impl DataProviderExtended<CurrencyId, TimeStampedPrice> for $name {
+				fn get_no_op(key: &CurrencyId) -> Option<TimeStampedPrice> {
+					$values.with(|v| v.borrow().get(key).cloned())
+				}
+				fn get_all_values() -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
+					$values.with(|v| v.borrow().iter().map(|(k, v)| (*k, Some(v.clone()))).collect())
+				}
+			}
+		};
+	}
+	mock_oracle!(MockPrimary, PRIMARY);
+	mock_oracle!(MockSecondary, SECONDARY);
+
+	parameter_types! {
+		pub const ExpiresIn: Moment = 1_000;
+	}
+
+	type Aggregated = MultiSourceDataProvider<MockPrimary, MockSecondary, MockModes, MockNow, ExpiresIn>;
+
+	fn set_mode(currency_id: CurrencyId, mode: OracleSourceMode) {
+		MODES.with(|v| v.borrow_mut().insert(currency_id, mode));
+	}
+
+	fn feed(
+		oracle: &'static std::thread::LocalKey<RefCell<BTreeMap<CurrencyId, TimeStampedPrice>>>,
+		currency_id: CurrencyId,
+		price: u128,
+		timestamp: Moment,
+	) {
+		oracle.with(|v| {
+			v.borrow_mut().insert(
+				currency_id,
+				TimeStampedPrice {
+					value: Price::saturating_from_integer(price),
+					timestamp,
+				},
+			)
+		});
+	}
+
+	fn price(currency_id: CurrencyId) -> Option<Price> {
+		<Aggregated as DataProvider<_, _>>::get(&currency_id)
+	}
+
+	#[test]
+	fn acala_only_is_default_mode() {
+		feed(&PRIMARY, DOT, 10, 9_500);
+		feed(&SECONDARY, DOT, 12, 9_500);
+		assert_eq!(Aggregated::source_mode(DOT), OracleSourceMode::AcalaOnly);
+		assert_eq!(price(DOT), Some(Price::saturating_from_integer(10)));
+
+		// stale price of the Acala oracle is ignored
+		feed(&PRIMARY, DOT, 10, 9_000);
+		assert_eq!(price(DOT), None);
+	}
+
+	#[test]
+	fn secondary_only_works() {
+		set_mode(DOT, OracleSourceMode::SecondaryOnly);
+		feed(&PRIMARY, DOT, 10, 9_500);
+		assert_eq!(price(DOT), None);
+
+		feed(&SECONDARY, DOT, 12, 9_500);
+		assert_eq!(price(DOT), Some(Price::saturating_from_integer(12)));
+
+		feed(&SECONDARY, DOT, 12, 9_000);
+		assert_eq!(price(DOT), None);
+	}
+
+	#[test]
+	fn median_of_both_works() {
+		set_mode(DOT, OracleSourceMode::MedianOfBoth);
+		assert_eq!(price(DOT), None);
+
+		// the sources disagree
+		feed(&PRIMARY, DOT, 10, 9_800);
+		feed(&SECONDARY, DOT, 14, 9_500);
+		assert_eq!(
+			<Aggregated as DataProviderExtended<_, _>>::get_no_op(&DOT),
+			Some(TimeStampedPrice {
+				value: Price::saturating_from_integer(12),
+				timestamp: 9_500,
+			})
+		);
+
+		// the only fresh source is used once the other goes stale
+		feed(&SECONDARY, DOT, 14, 8_000);
+		assert_eq!(price(DOT), Some(Price::saturating_from_integer(10)));
+		feed(&PRIMARY, DOT, 10, 8_000);
+		feed(&SECONDARY, DOT, 14, 9_500);
+		assert_eq!(price(DOT), Some(Price::saturating_from_integer(14)));
+	}
+
+	#[test]
+	fn primary_with_fallback_works() {
+		set_mode(DOT, OracleSourceMode::PrimaryWithFallback);
+		feed(&SECONDARY, DOT, 14, 9_500);
+		assert_eq!(price(DOT), Some(Price::saturating_from_integer(14)));
+
+		// the Acala oracle wins when the sources disagree
+		feed(&PRIMARY, DOT, 10, 9_500);
+		assert_eq!(price(DOT), Some(Price::saturating_from_integer(10)));
+
+		feed(&PRIMARY, DOT, 10, 9_000);
+		assert_eq!(price(DOT), Some(Price::saturating_from_integer(14)));
+
+		feed(&SECONDARY, DOT, 14, 9_000);
+		assert_eq!(price(DOT), None);
+	}
+
+	#[test]
+	fn get_all_values_covers_both_sources() {
+		set_mode(AUSD, OracleSourceMode::SecondaryOnly);
+		feed(&PRIMARY, DOT, 10, 9_500);
+		feed(&SECONDARY, DOT, 14, 9_500);
+		feed(&SECONDARY, AUSD, 1, 9_500);
+
+		assert_eq!(
+			<Aggregated as DataProviderExtended<_, _>>::get_all_values(),
+			vec![
+				(
+					AUSD,
+					Some(TimeStampedPrice {
+						value: Price::saturating_from_integer(1),
+						timestamp: 9_500,
+					})
+				),
+				(
+					DOT,
+					Some(TimeStampedPrice {
+						value: Price::saturating_from_integer(10),
+						timestamp: 9_500,
+					})
+				),
+			]
+		);
+		assert!(Aggregated::feed_value(None::<AccountId>, DOT, Price::saturating_from_integer(1)).is_err());
+	}
+
+	fn raw(price: u128, timestamp: Moment) -> TimeStampedPrice {
+		TimeStampedPrice {
+			value: Price::saturating_from_rational(price, 100),
+			timestamp,
+		}
+	}
+
+	#[test]
+	fn price_quality_measures_dispersion() {
+		let quality = PriceQuality::measure(
+			raw(1_000, 9_500),
+			vec![raw(1_000, 9_500), raw(1_020, 9_600), raw(990, 9_700)],
+			10_000,
+			1_000,
+		);
+		assert_eq!(
+			quality,
+			PriceQuality {
+				value: Price::saturating_from_integer(10),
+				timestamp: 9_500,
+				expires_at: 10_500,
+				contributors: 3,
+				min_value: Some(Price::saturating_from_rational(99, 10)),
+				max_value: Some(Price::saturating_from_rational(102, 10)),
+				median_absolute_deviation: Some(Price::saturating_from_rational(1, 10)),
+			}
+		);
+
+		// the mean of the middle two for an even count
+		let quality = PriceQuality::measure(
+			raw(1_000, 9_500),
+			vec![raw(1_000, 9_500), raw(1_020, 9_600), raw(990, 9_700), raw(1_060, 9_700)],
+			10_000,
+			1_000,
+		);
+		assert_eq!(quality.contributors, 4);
+		assert_eq!(quality.max_value, Some(Price::saturating_from_rational(106, 10)));
+		assert_eq!(
+			quality.median_absolute_deviation,
+			Some(Price::saturating_from_rational(15, 100))
+		);
+	}
+
+	#[test]
+	fn price_quality_excludes_expired_contributions() {
+		let quality = PriceQuality::measure(
+			raw(1_000, 9_500),
+			vec![raw(1_000, 9_500), raw(1_200, 9_000), raw(990, 9_700)],
+			10_000,
+			1_000,
+		);
+		assert_eq!(quality.contributors, 2);
+		assert_eq!(quality.min_value, Some(Price::saturating_from_rational(99, 10)));
+		assert_eq!(quality.max_value, Some(Price::saturating_from_integer(10)));
+		assert_eq!(
+			quality.median_absolute_deviation,
+			Some(Price::saturating_from_rational(5, 100))
+		);
+
+		let quality = PriceQuality::measure(raw(1_000, 9_000), vec![raw(1_000, 9_000)], 10_000, 1_000);
+		assert_eq!(quality.contributors, 0);
+		assert_eq!(quality.expires_at, 10_000);
+		assert_eq!(quality.min_value, None);
+		assert_eq!(quality.max_value, None);
+		assert_eq!(quality.median_absolute_deviation, None);
+	}
+}
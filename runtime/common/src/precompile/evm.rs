@@ -42,6 +42,7 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - QueryDeveloperDeposit.
 /// - QueryPublicationFee.
 /// - TransferMaintainer. Rest `input` bytes: `from`, `contract`, `new_maintainer`.
+/// - QuerySystemContract. Rest `input` bytes: `key`.
 pub struct EVMPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -58,6 +59,7 @@ pub enum Action {
 	DisableDeveloperAccount = "developerDisable(address)",
 	QueryDeveloperStatus = "developerStatus(address)",
 	PublishContract = "publishContract(address,address)",
+	QuerySystemContract = "systemContractOf(string)",
 }
 
 impl<Runtime> Precompile for EVMPrecompile<Runtime>
@@ -196,6 +198,15 @@ where
 					output: Output::encode_bool(developer_status),
 				})
 			}
+			Action::QuerySystemContract => {
+				let key = input.bytes_at(1)?;
+				// the zero address if the key is not registered
+				let contract = <module_evm::Pallet<Runtime>>::get_system_contract(&key).unwrap_or_default();
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_address(contract),
+				})
+			}
 		}
 	}
 }
@@ -266,6 +277,10 @@ where
 				let weight = PrecompileWeights::<Runtime>::evm_query_developer_status();
 				WeightToGas::convert(weight)
 			}
+			Action::QuerySystemContract => {
+				let weight = PrecompileWeights::<Runtime>::evm_query_system_contract();
+				WeightToGas::convert(weight)
+			}
 		};
 		Ok(cost)
 	}
@@ -483,4 +498,42 @@ mod tests {
 			}));
 		});
 	}
+
+	#[test]
+	fn system_contract_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// systemContractOf(string) -> 0x6143fcbc
+			// key "DEX_ROUTER"
+			let input = hex! {"
+				6143fcbc
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 0000000000000000000000000000000a
+				4445585f524f55544552000000000000 00000000000000000000000000000000
+			"};
+
+			// the zero address if the key is not registered
+			let resp = EVMPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 32].to_vec());
+
+			let dex_router = H160::from(hex!("0000000000000000000000000000000000000801"));
+			module_evm::SystemContracts::<Test>::insert(
+				primitives::evm::SystemContractKey::truncate_from(b"DEX_ROUTER".to_vec()),
+				dex_router,
+			);
+
+			let expected_output = hex! {"
+				000000000000000000000000 0000000000000000000000000000000000000801
+			"};
+			let resp = EVMPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
 }
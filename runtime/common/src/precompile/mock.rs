@@ -29,9 +29,10 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::{offchain::SendTransactionTypes, EnsureRoot, EnsureSignedBy};
-use module_cdp_engine::CollateralCurrencyIds;
+use module_cdp_engine::{CDPEngineTask, CollateralCurrencyIds};
 use module_evm::{EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
+use module_nft::NftTask;
 use module_support::{
 	mocks::{MockStableAsset, TestRandomness},
 	AddressMapping as AddressMappingT, AuctionManager, DEXIncentives, DispatchableTask, EmergencyShutdown,
@@ -51,7 +52,8 @@ use scale_info::TypeInfo;
 use sp_core::H160;
 use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, BlockNumberProvider, Convert, IdentityLookup, One as OneT, Zero},
-	AccountId32, DispatchResult, FixedPointNumber, FixedU128, Perbill, Percent, RuntimeDebug,
+	transaction_validity::TransactionPriority,
+	AccountId32, DispatchError, DispatchResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 use xcm::{prelude::*, v4::Xcm};
@@ -184,6 +186,9 @@ impl module_currencies::Config for Test {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = ();
+	type AccountCurrencies = ();
+	type Swap = SpecificJointsSwap<(), ()>;
+	type MaxTransferAllCurrencies = ConstU32<0>;
 }
 
 impl module_evm_bridge::Config for Test {
@@ -203,6 +208,8 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Test>),
+		NftTask(NftTask<Test>),
+		CDPEngineTask(CDPEngineTask<Test>),
 	}
 }
 
@@ -241,6 +248,9 @@ impl module_nft::Config for Test {
 	type DataDepositPerByte = ConstU128<10>;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = ConstU32<2048>;
+	type MaxAirdropRecipients = ConstU32<1000>;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -289,6 +299,11 @@ impl module_transaction_payment::Config for Test {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type FeePayer = ();
+	type CallClassifier = ();
+	type FeeSpendPeriod = ConstU32<100>;
+	type MaxFeeSpendPeriods = ConstU32<12>;
+	type UnsignedPriority = ConstU64<1048576>;
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -363,9 +378,13 @@ impl DEXIncentives<AccountId, CurrencyId, Balance> for MockDEXIncentives {
 		Tokens::reserve(lp_currency_id, who, amount)
 	}
 
-	fn do_withdraw_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+	fn do_withdraw_dex_share(
+		who: &AccountId,
+		lp_currency_id: CurrencyId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
 		let _ = Tokens::unreserve(lp_currency_id, who, amount);
-		Ok(())
+		Ok(amount)
 	}
 }
 
@@ -377,6 +396,7 @@ parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const TradingPathLimit: u32 = 4;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
 }
 
 impl module_dex::Config for Test {
@@ -392,6 +412,18 @@ impl module_dex::Config for Test {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU32<0>;
 	type OnLiquidityPoolUpdated = ();
+	type OnTradingPairEnabled = ();
+	type OnTradingPairDisabled = ();
+	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Test>;
+	type FlashSwapEvmBridge = module_evm_bridge::FlashSwapEvmBridge<Test>;
+	type GasToWeight = ();
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type MinSwapCommitDeposit = ConstU128<100>;
+	type SwapRevealPeriod = ConstU32<10>;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = ConstU32<2>;
+	type LbpCheckpointPeriod = ConstU32<10>;
+	type MaxLbpPools = ConstU32<2>;
 }
 
 parameter_types! {
@@ -405,6 +437,7 @@ impl module_loans::Config for Test {
 	type CDPTreasury = CDPTreasury;
 	type PalletId = LoansPalletId;
 	type OnUpdateLoan = ();
+	type MaxPositionsPerAccount = ConstU32<3>;
 }
 
 pub struct MockPriceSource;
@@ -424,11 +457,24 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_type_with_key! {
+	pub CdpEngineUnsignedPriority: |_currency_id: CurrencyId| -> TransactionPriority {
+		1 << 20
+	};
+}
+
 parameter_types! {
 	pub DefaultLiquidationRatio: Ratio = Ratio::saturating_from_rational(3, 2);
 	pub DefaultDebitExchangeRate: ExchangeRate = ExchangeRate::one();
 	pub DefaultLiquidationPenalty: FractionalRate = FractionalRate::try_from(Rate::saturating_from_rational(10, 100)).unwrap();
+	pub MinLiquidationRatio: Ratio = Ratio::one();
+	pub MaxLiquidationRatio: Ratio = Ratio::saturating_from_integer(10);
+	pub MaxLiquidationPenalty: Rate = Rate::saturating_from_rational(50, 100);
+	pub MaxInterestRatePerSec: Rate = Rate::saturating_from_rational(1, 1000);
+	pub MaxDebitCeilingIncrease: Ratio = Ratio::saturating_from_integer(2);
+	pub TopUpRatioBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub MaxLiquidationContractSlippage: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub PartialLiquidationBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 	pub SettleErc20EvmOrigin: AccountId = AccountId::from(hex_literal::hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"));
 }
@@ -436,29 +482,49 @@ parameter_types! {
 impl module_cdp_engine::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type PriceSource = MockPriceSource;
+	type PriceTimestampSource = ();
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
 	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
 	type MinimumDebitValue = ConstU128<2>;
+	type MinLiquidationRatio = MinLiquidationRatio;
+	type MaxLiquidationRatio = MaxLiquidationRatio;
+	type MaxLiquidationPenalty = MaxLiquidationPenalty;
+	type MaxInterestRatePerSec = MaxInterestRatePerSec;
+	type MaxDebitCeilingIncrease = MaxDebitCeilingIncrease;
 	type MinimumCollateralAmount = MinimumCollateralAmount;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CDPTreasury;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
-	type UnsignedPriority = ConstU64<1048576>; // 1 << 20
+	type UnsignedPriority = CdpEngineUnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type SafeMode = ();
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = DexModule;
 	type LiquidationContractsUpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxCrossMarginPreferences = ConstU32<5>;
 	type LiquidationEvmBridge = module_evm_bridge::LiquidationEvmBridge<Test>;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Test>;
 	type Swap = SpecificJointsSwap<DexModule, AlternativeSwapPathJointList>;
 	type EVMBridge = module_evm_bridge::EVMBridge<Test>;
 	type SettleErc20EvmOrigin = SettleErc20EvmOrigin;
+	type MaxLiquidationRecords = ConstU32<20>;
+	type PositionAlerts = ();
+	type MaxPositionAlertBatch = ConstU32<50>;
+	type MaxDebitRebaseFactor = ConstU32<1_000>;
+	type RevenuePeriod = ConstU32<10>;
+	type MaxRevenuePeriods = ConstU32<10>;
+	type RiskSnapshotPeriod = ConstU32<10>;
+	type MaxRiskSnapshotPositions = ConstU32<10>;
+	type PartialLiquidationThreshold = ConstU128<1_000_000_000_000_000>;
+	type PartialLiquidationBuffer = PartialLiquidationBuffer;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -518,6 +584,8 @@ impl module_cdp_treasury::Config for Test {
 	type WeightInfo = ();
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
 	type Swap = SpecificJointsSwap<DexModule, AlternativeSwapPathJointList>;
+	type PriceSource = MockPriceSource;
+	type MaxBasketAssets = ConstU32<10>;
 }
 
 impl module_honzon::Config for Test {
@@ -525,6 +593,11 @@ impl module_honzon::Config for Test {
 	type Currency = Balances;
 	type DepositPerAuthorization = ConstU128<100>;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Test>;
+	type OperationGuard = ();
+	type AuctionManagerHandler = MockAuctionManager;
+	type TopUpBounty = ConstU128<10>;
+	type TopUpRatioBuffer = TopUpRatioBuffer;
+	type MaxPositionAlerts = ConstU32<5>;
 	type WeightInfo = ();
 }
 
@@ -558,6 +631,7 @@ impl nutsfinance_stable_asset::Config for Test {
 
 impl module_transaction_pause::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type WeightInfo = ();
 }
@@ -608,6 +682,9 @@ impl module_evm::Config for Test {
 	type Randomness = TestRandomness<Self>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type TransactionIndexRetention = ConstU32<0>;
+	type TransactionHash = ();
+	type BlockStorageBudget = ConstU32<{ u32::MAX }>;
 	type WeightInfo = ();
 }
 
@@ -617,6 +694,7 @@ impl module_evm_accounts::Config for Test {
 	type AddressMapping = EvmAddressMapping<Test>;
 	type ChainId = EvmChainId<Test>;
 	type TransferAll = ();
+	type MaxNonceLanes = ConstU32<8>;
 	type WeightInfo = ();
 }
 
@@ -653,6 +731,8 @@ parameter_types! {
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub MockRelayBlockNumberProvider: BlockNumber = 0;
 	pub RewardRatePerRelaychainBlock: Rate = Rate::zero();
+	pub const MaxLockPriceDeviation: Permill = Permill::from_percent(20);
+	pub const MaxPriceLockDuration: BlockNumber = 100;
 }
 
 ord_parameter_types! {
@@ -675,6 +755,8 @@ impl module_prices::Config for Test {
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type RewardRatePerRelaychainBlock = RewardRatePerRelaychainBlock;
 	type PricingPegged = PricingPegged;
+	type MaxLockPriceDeviation = MaxLockPriceDeviation;
+	type MaxPriceLockDuration = MaxPriceLockDuration;
 	type WeightInfo = ();
 }
 
@@ -707,6 +789,26 @@ impl HomaSubAccountXcm<AccountId, Balance> for MockHomaSubAccountXcm {
 		Ok(())
 	}
 
+	fn join_pool_on_sub_account(_: u16, _: u32, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn bond_extra_to_pool_on_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn bond_pool_rewards_on_sub_account(_: u16) -> DispatchResult {
+		Ok(())
+	}
+
+	fn unbond_from_pool_on_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn withdraw_unbonded_from_pool_on_sub_account(_: u16, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn get_xcm_transfer_fee() -> Balance {
 		1_000_000
 	}
@@ -727,6 +829,8 @@ parameter_types! {
 	pub const HomaTreasuryAccount: AccountId = HOMA_TREASURY;
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 	pub ActiveSubAccountsIndexList: Vec<u16> = vec![0, 1, 2];
+	pub const NominationPoolSubAccountIndex: u16 = 10;
+	pub MaxCommissionRate: Rate = Rate::saturating_from_rational(50, 100);
 	pub const BondingDuration: EraIndex = 28;
 	pub const MintThreshold: Balance = 0;
 	pub const RedeemThreshold: Balance = 0;
@@ -750,6 +854,12 @@ impl module_homa::Config for Test {
 	type WeightInfo = ();
 	type NominationsProvider = ();
 	type ProcessRedeemRequestsLimit = ConstU32<2_000>;
+	type MaxRedeemRequestBuckets = ConstU32<100>;
+	type MaxRedeemRequestsPerBucket = ConstU32<100>;
+	type OperationGuard = ();
+	type NominationPoolSubAccountIndex = NominationPoolSubAccountIndex;
+	type MaxCommissionRate = MaxCommissionRate;
+	type UnixTime = Timestamp;
 }
 
 parameter_type_with_key! {
@@ -784,6 +894,13 @@ impl module_incentives::Config for Test {
 	type Currency = Tokens;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type Randomness = TestRandomness<Self>;
+	type Swap = SpecificJointsSwap<DexModule, AlternativeSwapPathJointList>;
+	type PriceSource = MockPriceSource;
+	type RetirementGracePeriod = ConstU32<20>;
+	type RetirementSharesThreshold = ConstU128<1_000>;
+	type RetireOrigin = EnsureRoot<AccountId>;
+	type MaxRetirementRemovals = ConstU32<10>;
 	type WeightInfo = ();
 }
 
@@ -52,6 +52,7 @@ pub mod nft;
 pub mod oracle;
 pub mod schedule;
 pub mod stable_asset;
+pub mod token_registry;
 pub mod xtokens;
 
 use crate::SystemContractsFilter;
@@ -68,6 +69,7 @@ pub use nft::NFTPrecompile;
 pub use oracle::OraclePrecompile;
 pub use schedule::SchedulePrecompile;
 pub use stable_asset::StableAssetPrecompile;
+pub use token_registry::TokenRegistryPrecompile;
 pub use xtokens::XtokensPrecompile;
 
 pub const ECRECOVER: H160 = H160(hex!("0000000000000000000000000000000000000001"));
@@ -100,6 +102,7 @@ pub const INCENTIVES: H160 = H160(hex!("000000000000000000000000000000000000040a
 pub const XTOKENS: H160 = H160(hex!("000000000000000000000000000000000000040b"));
 pub const LIQUID_CROWDLOAN: H160 = H160(hex!("000000000000000000000000000000000000040c"));
 pub const EARNING: H160 = H160(hex!("000000000000000000000000000000000000040d"));
+pub const TOKEN_REGISTRY: H160 = H160(hex!("000000000000000000000000000000000000040e"));
 
 pub struct AllPrecompiles<R, F, E> {
 	set: BTreeSet<H160>,
@@ -142,6 +145,7 @@ where
 				XTOKENS,
 				LIQUID_CROWDLOAN,
 				EARNING,
+				TOKEN_REGISTRY,
 			]),
 			_marker: Default::default(),
 		}
@@ -178,6 +182,7 @@ where
 				XTOKENS,
 				// LIQUID_CROWDLOAN,
 				EARNING,
+				TOKEN_REGISTRY,
 			]),
 			_marker: Default::default(),
 		}
@@ -214,6 +219,7 @@ where
 				XTOKENS,
 				// LIQUID_CROWDLOAN,
 				EARNING,
+				TOKEN_REGISTRY,
 			]),
 			_marker: Default::default(),
 		}
@@ -238,6 +244,7 @@ where
 	IncentivesPrecompile<R>: Precompile,
 	XtokensPrecompile<R>: Precompile,
 	EarningPrecompile<R>: Precompile,
+	TokenRegistryPrecompile<R>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
 		let context = handle.context();
@@ -345,6 +352,8 @@ where
 				Some(XtokensPrecompile::<R>::execute(handle))
 			} else if address == EARNING {
 				Some(EarningPrecompile::<R>::execute(handle))
+			} else if address == TOKEN_REGISTRY {
+				Some(TokenRegistryPrecompile::<R>::execute(handle))
 			} else {
 				E::execute(&Default::default(), handle)
 			}
@@ -19,7 +19,7 @@
 use super::input::{Input, InputPricer, InputT, Output};
 use crate::WeightToGas;
 use frame_support::{
-	pallet_prelude::IsType,
+	pallet_prelude::{IsType, Weight},
 	traits::{Currency, ExistenceRequirement, Get},
 };
 use module_currencies::WeightInfo;
@@ -43,6 +43,18 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Query total issuance.
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Transfer. Rest `input` bytes: `from`, `to`, `amount`.
+/// - Query allowance. Rest `input` bytes: `owner`, `spender`.
+/// - Approve. Rest `input` bytes: `owner`, `spender`, `amount`.
+/// - Increase allowance. Rest `input` bytes: `owner`, `spender`, `added_amount`.
+/// - Decrease allowance. Rest `input` bytes: `owner`, `spender`, `subtracted_amount`.
+/// - Transfer from. Rest `input` bytes: `spender`, `from`, `to`, `amount`.
+///
+/// The currency is decided by the caller, so only the ERC20 mirror of the
+/// currency can move it, and the mirror is responsible for passing its
+/// `msg.sender` and emitting the `Transfer` and `Approval` events. Like
+/// `transfer`, `transferFrom` allows the death of the `from` account: if the
+/// remaining balance is below the existential deposit, the account is reaped
+/// and the dust is handled by `module_currencies`.
 pub struct MultiCurrencyPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -56,12 +68,20 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	Transfer = "transfer(address,address,uint256)",
 	TransferToAccountId = "transferToAccountId(address,bytes32,uint256)",
+	QueryAllowance = "allowance(address,address)",
+	Approve = "approve(address,address,uint256)",
+	IncreaseAllowance = "increaseAllowance(address,address,uint256)",
+	DecreaseAllowance = "decreaseAllowance(address,address,uint256)",
+	TransferFrom = "transferFrom(address,address,address,uint256)",
 }
 
 impl<Runtime> Precompile for MultiCurrencyPrecompile<Runtime>
 where
-	Runtime:
-		module_currencies::Config + module_evm::Config + module_prices::Config + module_transaction_payment::Config,
+	Runtime: module_currencies::Config
+		+ module_evm::Config
+		+ module_evm_bridge::Config
+		+ module_prices::Config
+		+ module_transaction_payment::Config,
 	Runtime::AccountId: IsType<AccountId32>,
 	module_currencies::Pallet<Runtime>: MultiCurrencyT<Runtime::AccountId, CurrencyId = CurrencyId, Balance = Balance>,
 {
@@ -196,6 +216,104 @@ where
 					output: Output::encode_error_msg("Multicurrency TransferToAccountId failed", e),
 				})?;
 
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::QueryAllowance => {
+				let owner = input.evm_address_at(1)?;
+				let spender = input.evm_address_at(2)?;
+				let allowance = module_evm_bridge::Pallet::<Runtime>::allowances((currency_id, owner), spender);
+				log::debug!(target: "evm", "multicurrency: owner: {:?}, spender: {:?}, allowance: {:?}", owner, spender, allowance);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(allowance),
+				})
+			}
+			Action::Approve => {
+				let owner = input.evm_address_at(1)?;
+				let spender = input.evm_address_at(2)?;
+				let amount = input.balance_at(3)?;
+				log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+				module_evm_bridge::Pallet::<Runtime>::approve(currency_id, owner, spender, amount);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::IncreaseAllowance => {
+				let owner = input.evm_address_at(1)?;
+				let spender = input.evm_address_at(2)?;
+				let added_amount = input.balance_at(3)?;
+				log::debug!(target: "evm", "multicurrency: increaseAllowance owner: {:?}, spender: {:?}, added_amount: {:?}", owner, spender, added_amount);
+
+				let allowance =
+					module_evm_bridge::Pallet::<Runtime>::increase_allowance(currency_id, owner, spender, added_amount)
+						.map_err(|e| PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("Multicurrency IncreaseAllowance failed", e),
+						})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(allowance),
+				})
+			}
+			Action::DecreaseAllowance => {
+				let owner = input.evm_address_at(1)?;
+				let spender = input.evm_address_at(2)?;
+				let subtracted_amount = input.balance_at(3)?;
+				log::debug!(target: "evm", "multicurrency: decreaseAllowance owner: {:?}, spender: {:?}, subtracted_amount: {:?}", owner, spender, subtracted_amount);
+
+				let allowance = module_evm_bridge::Pallet::<Runtime>::decrease_allowance(
+					currency_id,
+					owner,
+					spender,
+					subtracted_amount,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Multicurrency DecreaseAllowance failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(allowance),
+				})
+			}
+			Action::TransferFrom => {
+				let spender = input.evm_address_at(1)?;
+				let owner = input.evm_address_at(2)?;
+				let from = input.account_id_at(2)?;
+				let to = input.account_id_at(3)?;
+				let amount = input.balance_at(4)?;
+				log::debug!(target: "evm", "multicurrency: transferFrom spender: {:?}, from: {:?}, to: {:?}, amount: {:?}", spender, from, to, amount);
+
+				// the state changes are reverted with the EVM substate if the transfer fails
+				if spender != owner {
+					module_evm_bridge::Pallet::<Runtime>::spend_allowance(currency_id, owner, spender, amount)
+						.map_err(|e| PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("Multicurrency TransferFrom failed", e),
+						})?;
+				}
+
+				<module_currencies::Pallet<Runtime> as MultiCurrencyT<Runtime::AccountId>>::transfer(
+					currency_id,
+					&from,
+					&to,
+					amount,
+					ExistenceRequirement::AllowDeath,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Multicurrency TransferFrom failed", e),
+				})?;
+
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
 					output: vec![],
@@ -209,8 +327,11 @@ struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
 where
-	Runtime:
-		module_currencies::Config + module_evm::Config + module_prices::Config + module_transaction_payment::Config,
+	Runtime: module_currencies::Config
+		+ module_evm::Config
+		+ module_evm_bridge::Config
+		+ module_prices::Config
+		+ module_transaction_payment::Config,
 {
 	const BASE_COST: u64 = 200;
 
@@ -243,21 +364,42 @@ where
 			Action::Transfer | Action::TransferToAccountId => {
 				let cost = InputPricer::<Runtime>::read_accounts(2);
 
-				// transfer weight
-				let weight = if currency_id == <Runtime as module_transaction_payment::Config>::NativeCurrencyId::get()
-				{
-					<Runtime as module_currencies::Config>::WeightInfo::transfer_native_currency()
-				} else {
-					<Runtime as module_currencies::Config>::WeightInfo::transfer_non_native_currency()
-				};
+				cost.saturating_add(WeightToGas::convert(Self::transfer_weight(currency_id)))
+			}
+			Action::QueryAllowance => {
+				// EVMBridge::Allowances (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::Approve => {
+				// EVMBridge::Allowances (w: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().writes(1))
+			}
+			Action::IncreaseAllowance | Action::DecreaseAllowance => {
+				// EVMBridge::Allowances (r: 1, w: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1))
+			}
+			Action::TransferFrom => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
 
-				cost.saturating_add(WeightToGas::convert(weight))
+				// EVMBridge::Allowances (r: 1, w: 1)
+				cost.saturating_add(WeightToGas::convert(
+					Self::transfer_weight(currency_id)
+						.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1)),
+				))
 			}
 		};
 
 		Ok(Self::BASE_COST.saturating_add(read_currency).saturating_add(cost))
 	}
 
+	fn transfer_weight(currency_id: CurrencyId) -> Weight {
+		if currency_id == <Runtime as module_transaction_payment::Config>::NativeCurrencyId::get() {
+			<Runtime as module_currencies::Config>::WeightInfo::transfer_native_currency()
+		} else {
+			<Runtime as module_currencies::Config>::WeightInfo::transfer_non_native_currency()
+		}
+	}
+
 	fn dex_share_read_cost(share: DexShare) -> u64 {
 		match share {
 			DexShare::Erc20(_) | DexShare::ForeignAsset(_) => WeightToGas::convert(Runtime::DbWeight::get().reads(1)),
@@ -283,12 +425,17 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		aca_evm_address, alice, ausd_evm_address, bob, erc20_address_not_exists, lp_aca_ausd_evm_address, new_test_ext,
-		Balances, Test,
+		aca_evm_address, alice, alice_evm_addr, ausd_evm_address, bob, bob_evm_addr, erc20_address_not_exists,
+		lp_aca_ausd_evm_address, new_test_ext, AssetRegistry, Balances, CouncilAccount, Currencies, EvmAddress,
+		RuntimeOrigin, Test, ACA,
 	};
-	use frame_support::assert_noop;
+	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use module_support::AddressMapping;
+	use primitives::currency::AssetMetadata;
+	use sp_core::H160;
+	use xcm::v4::{Junction::Parachain, Location};
 
 	type MultiCurrencyPrecompile = crate::MultiCurrencyPrecompile<Test>;
 
@@ -626,4 +773,265 @@ mod tests {
 			);
 		})
 	}
+
+	fn erc20_input(action: Action, params: &[Vec<u8>]) -> Vec<u8> {
+		let mut input = Into::<u32>::into(action).to_be_bytes().to_vec();
+		params.iter().for_each(|param| input.extend_from_slice(param));
+		input
+	}
+
+	fn approve_input(owner: H160, spender: H160, amount: Balance) -> Vec<u8> {
+		erc20_input(
+			Action::Approve,
+			&[
+				Output::encode_address(owner),
+				Output::encode_address(spender),
+				Output::encode_uint(amount),
+			],
+		)
+	}
+
+	fn allowance_input(owner: H160, spender: H160) -> Vec<u8> {
+		erc20_input(
+			Action::QueryAllowance,
+			&[Output::encode_address(owner), Output::encode_address(spender)],
+		)
+	}
+
+	fn transfer_from_input(spender: H160, from: H160, to: H160, amount: Balance) -> Vec<u8> {
+		erc20_input(
+			Action::TransferFrom,
+			&[
+				Output::encode_address(spender),
+				Output::encode_address(from),
+				Output::encode_address(to),
+				Output::encode_uint(amount),
+			],
+		)
+	}
+
+	fn execute(input: &[u8], context: &Context) -> PrecompileResult {
+		MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(input, None, context, false))
+	}
+
+	#[test]
+	fn allowance_works() {
+		new_test_ext().execute_with(|| {
+			let mut context = Context {
+				address: Default::default(),
+				caller: aca_evm_address(),
+				apparent_value: Default::default(),
+			};
+
+			let resp = execute(&allowance_input(alice_evm_addr(), bob_evm_addr()), &context).unwrap();
+			assert_eq!(resp.output, Output::encode_uint(0u128));
+
+			let resp = execute(&approve_input(alice_evm_addr(), bob_evm_addr(), 100), &context).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 0].to_vec());
+
+			let resp = execute(&allowance_input(alice_evm_addr(), bob_evm_addr()), &context).unwrap();
+			assert_eq!(resp.output, Output::encode_uint(100u128));
+			let resp = execute(&allowance_input(bob_evm_addr(), alice_evm_addr()), &context).unwrap();
+			assert_eq!(resp.output, Output::encode_uint(0u128));
+
+			let increase_input = erc20_input(
+				Action::IncreaseAllowance,
+				&[
+					Output::encode_address(alice_evm_addr()),
+					Output::encode_address(bob_evm_addr()),
+					Output::encode_uint(50u128),
+				],
+			);
+			let resp = execute(&increase_input, &context).unwrap();
+			assert_eq!(resp.output, Output::encode_uint(150u128));
+
+			let decrease_input = erc20_input(
+				Action::DecreaseAllowance,
+				&[
+					Output::encode_address(alice_evm_addr()),
+					Output::encode_address(bob_evm_addr()),
+					Output::encode_uint(151u128),
+				],
+			);
+			assert_noop!(
+				execute(&decrease_input, &context),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Multicurrency DecreaseAllowance failed: AllowanceBelowZero".into(),
+				}
+			);
+
+			let decrease_input = erc20_input(
+				Action::DecreaseAllowance,
+				&[
+					Output::encode_address(alice_evm_addr()),
+					Output::encode_address(bob_evm_addr()),
+					Output::encode_uint(150u128),
+				],
+			);
+			let resp = execute(&decrease_input, &context).unwrap();
+			assert_eq!(resp.output, Output::encode_uint(0u128));
+
+			// allowances are per currency
+			assert_ok!(execute(&approve_input(alice_evm_addr(), bob_evm_addr(), 100), &context));
+			context.caller = ausd_evm_address();
+			let resp = execute(&allowance_input(alice_evm_addr(), bob_evm_addr()), &context).unwrap();
+			assert_eq!(resp.output, Output::encode_uint(0u128));
+		});
+	}
+
+	#[test]
+	fn transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: aca_evm_address(),
+				apparent_value: Default::default(),
+			};
+
+			let from_balance = Balances::free_balance(alice());
+			let to_balance = Balances::free_balance(bob());
+
+			assert_ok!(execute(&approve_input(alice_evm_addr(), bob_evm_addr(), 100), &context));
+
+			assert_noop!(
+				execute(
+					&transfer_from_input(bob_evm_addr(), alice_evm_addr(), bob_evm_addr(), 101),
+					&context
+				),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Multicurrency TransferFrom failed: InsufficientAllowance".into(),
+				}
+			);
+
+			let resp = execute(
+				&transfer_from_input(bob_evm_addr(), alice_evm_addr(), bob_evm_addr(), 60),
+				&context,
+			)
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 0].to_vec());
+			assert_eq!(Balances::free_balance(alice()), from_balance - 60);
+			assert_eq!(Balances::free_balance(bob()), to_balance + 60);
+			assert_eq!(
+				module_evm_bridge::Allowances::<Test>::get((ACA, alice_evm_addr()), bob_evm_addr()),
+				40
+			);
+
+			// the owner does not need an allowance
+			assert_ok!(execute(
+				&transfer_from_input(alice_evm_addr(), alice_evm_addr(), bob_evm_addr(), 10),
+				&context
+			));
+			assert_eq!(Balances::free_balance(alice()), from_balance - 70);
+			assert_eq!(
+				module_evm_bridge::Allowances::<Test>::get((ACA, alice_evm_addr()), bob_evm_addr()),
+				40
+			);
+
+			// infinite allowance is not spent
+			assert_ok!(execute(
+				&approve_input(alice_evm_addr(), bob_evm_addr(), Balance::MAX),
+				&context
+			));
+			assert_ok!(execute(
+				&transfer_from_input(bob_evm_addr(), alice_evm_addr(), bob_evm_addr(), 30),
+				&context
+			));
+			assert_eq!(Balances::free_balance(bob()), to_balance + 100);
+			assert_eq!(
+				module_evm_bridge::Allowances::<Test>::get((ACA, alice_evm_addr()), bob_evm_addr()),
+				Balance::MAX
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_from_can_reap_owner() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: aca_evm_address(),
+				apparent_value: Default::default(),
+			};
+			let owner = EvmAddress::from(hex!("1000000000000000000000000000000000000003"));
+			let owner_account = <Test as module_evm::Config>::AddressMapping::get_account_id(&owner);
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				owner_account.clone(),
+				ACA,
+				10
+			));
+
+			assert_ok!(execute(&approve_input(owner, bob_evm_addr(), 10), &context));
+			assert_ok!(execute(
+				&transfer_from_input(bob_evm_addr(), owner, bob_evm_addr(), 10),
+				&context
+			));
+			assert_eq!(Balances::free_balance(&owner_account), 0);
+			assert!(!frame_system::Account::<Test>::contains_key(&owner_account));
+		});
+	}
+
+	#[test]
+	fn transfer_from_foreign_asset_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(AssetRegistry::register_foreign_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				Box::new(Location::new(1, [Parachain(2000)]).into()),
+				Box::new(AssetMetadata {
+					name: b"Foreign Token".to_vec(),
+					symbol: b"FT".to_vec(),
+					decimals: 12,
+					minimal_balance: 1,
+				}),
+				false
+			));
+			let currency_id = CurrencyId::ForeignAsset(0);
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				currency_id,
+				1_000
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: EvmAddress::try_from(currency_id).unwrap(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(execute(&approve_input(alice_evm_addr(), bob_evm_addr(), 500), &context));
+			assert_ok!(execute(
+				&transfer_from_input(bob_evm_addr(), alice_evm_addr(), bob_evm_addr(), 300),
+				&context
+			));
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), 700);
+			assert_eq!(Currencies::free_balance(currency_id, &bob()), 300);
+			let resp = execute(&allowance_input(alice_evm_addr(), bob_evm_addr()), &context).unwrap();
+			assert_eq!(resp.output, Output::encode_uint(200u128));
+
+			assert_ok!(execute(
+				&transfer_from_input(bob_evm_addr(), alice_evm_addr(), bob_evm_addr(), 200),
+				&context
+			));
+			assert!(!module_evm_bridge::Allowances::<Test>::contains_key(
+				(currency_id, alice_evm_addr()),
+				bob_evm_addr()
+			));
+
+			assert_eq!(
+				execute(
+					&transfer_from_input(alice_evm_addr(), alice_evm_addr(), bob_evm_addr(), 1_000),
+					&context
+				),
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Multicurrency TransferFrom failed: BalanceTooLow".into(),
+				})
+			);
+		});
+	}
 }
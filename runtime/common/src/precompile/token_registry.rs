@@ -0,0 +1,389 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT},
+	weights::PrecompileWeights,
+};
+use crate::{Weight, WeightToGas};
+use ethabi::Token;
+use frame_support::traits::Get;
+use module_asset_registry::AssetMetadatas;
+use module_evm::{
+	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use module_support::Erc20InfoMapping as Erc20InfoMappingT;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::{
+	currency::{AssetIds, CurrencyIdType},
+	CurrencyId,
+};
+use sp_core::U256;
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The maximum number of tokens returned by a single `getTokens`.
+pub const MAX_TOKENS_PER_QUERY: u32 = 50;
+
+/// The `TokenRegistry` impl precompile.
+///
+/// The token is returned as tuple `(address, uint8 decimals, bytes symbol, uint8 kind)`, the
+/// `kind` is the `CurrencyIdType` encoded in the EVM address of system token, and 0 for ERC20.
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Get tokens registered in AssetRegistry. Rest `input` bytes: `cursor`, `limit`.
+/// - Get token by address. Rest `input` bytes: `address`.
+pub struct TokenRegistryPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	GetTokens = "getTokens(uint256,uint256)",
+	GetTokenByAddress = "getTokenByAddress(address)",
+}
+
+impl<Runtime> Precompile for TokenRegistryPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_asset_registry::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+
+		let action = input.action()?;
+
+		match action {
+			Action::GetTokens => {
+				let cursor = input.u32_at(1)?;
+				let limit = input.u32_at(2)?.min(MAX_TOKENS_PER_QUERY);
+
+				let tokens: Vec<Token> = AssetMetadatas::<Runtime>::iter()
+					.skip(cursor as usize)
+					.take(limit as usize)
+					.filter_map(|(asset_ids, metadata)| {
+						Self::token_info(currency_id_of(asset_ids), metadata.decimals, metadata.symbol)
+					})
+					.collect();
+
+				log::debug!(target: "evm", "token_registry: getTokens cursor: {:?}, limit: {:?}, returned: {:?}", cursor, limit, tokens.len());
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: ethabi::encode(&[Token::Array(tokens)]),
+				})
+			}
+			Action::GetTokenByAddress => {
+				let currency_id = input.currency_id_at(1)?;
+
+				let token = Runtime::Erc20InfoMapping::decimals(currency_id)
+					.zip(Runtime::Erc20InfoMapping::symbol(currency_id))
+					.and_then(|(decimals, symbol)| Self::token_info(currency_id, decimals, symbol))
+					.ok_or_else(|| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "token not found".into(),
+					})?;
+
+				log::debug!(target: "evm", "token_registry: getTokenByAddress currency_id: {:?}", currency_id);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: ethabi::encode(&[token]),
+				})
+			}
+		}
+	}
+}
+
+impl<Runtime> TokenRegistryPrecompile<Runtime>
+where
+	Runtime: module_evm::Config,
+{
+	fn token_info(currency_id: CurrencyId, decimals: u8, symbol: Vec<u8>) -> Option<Token> {
+		let address = Runtime::Erc20InfoMapping::encode_evm_address(currency_id)?;
+		Some(Token::Tuple(vec![
+			Token::Address(address),
+			Token::Uint(U256::from(decimals)),
+			Token::Bytes(symbol),
+			Token::Uint(U256::from(asset_kind(currency_id))),
+		]))
+	}
+}
+
+fn currency_id_of(asset_ids: AssetIds) -> CurrencyId {
+	match asset_ids {
+		AssetIds::Erc20(address) => CurrencyId::Erc20(address),
+		AssetIds::StableAssetId(stable_asset_id) => CurrencyId::StableAssetPoolToken(stable_asset_id),
+		AssetIds::ForeignAssetId(foreign_asset_id) => CurrencyId::ForeignAsset(foreign_asset_id),
+		AssetIds::NativeAssetId(currency_id) => currency_id,
+	}
+}
+
+fn asset_kind(currency_id: CurrencyId) -> u8 {
+	match currency_id {
+		CurrencyId::Erc20(_) => 0,
+		CurrencyId::Token(_) => CurrencyIdType::Token.into(),
+		CurrencyId::DexShare(..) => CurrencyIdType::DexShare.into(),
+		CurrencyId::StableAssetPoolToken(_) => CurrencyIdType::StableAsset.into(),
+		CurrencyId::LiquidCrowdloan(_) => CurrencyIdType::LiquidCrowdloan.into(),
+		CurrencyId::ForeignAsset(_) => CurrencyIdType::ForeignAsset.into(),
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_asset_registry::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			handle.input(),
+		);
+
+		let action = input.action()?;
+
+		let cost = match action {
+			Action::GetTokens => {
+				let cursor = input.u32_at(1)?;
+				let limit = input.u32_at(2)?.min(MAX_TOKENS_PER_QUERY);
+
+				// AssetRegistry::AssetMetadatas (r: cursor)
+				let skip_tokens = WeightToGas::convert(Runtime::DbWeight::get().reads(cursor.into()));
+				let get_token = WeightToGas::convert(PrecompileWeights::<Runtime>::token_registry_get_token());
+				skip_tokens.saturating_add(get_token.saturating_mul(limit.into()))
+			}
+			Action::GetTokenByAddress => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+				let get_token =
+					WeightToGas::convert(PrecompileWeights::<Runtime>::token_registry_get_token_by_address());
+				WeightToGas::convert(Weight::from_parts(read_currency, 0)).saturating_add(get_token)
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::precompile::mock::{
+		alice_evm_addr, new_test_ext, AssetRegistry, CouncilAccount, RuntimeOrigin, Test, AUSD, DOT,
+	};
+	use frame_support::{assert_noop, assert_ok};
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use primitives::currency::{AssetMetadata, TokenInfo};
+
+	type TokenRegistryPrecompile = crate::precompile::TokenRegistryPrecompile<Test>;
+
+	fn registered_tokens() -> Vec<Token> {
+		AssetMetadatas::<Test>::iter()
+			.map(|(asset_ids, metadata)| {
+				let currency_id = currency_id_of(asset_ids);
+				Token::Tuple(vec![
+					Token::Address(currency_id.try_into().unwrap()),
+					Token::Uint(metadata.decimals.into()),
+					Token::Bytes(metadata.symbol),
+					Token::Uint(1.into()),
+				])
+			})
+			.collect()
+	}
+
+	#[test]
+	fn get_tokens_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(AssetRegistry::register_native_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				AUSD,
+				Box::new(AssetMetadata {
+					name: AUSD.name().unwrap().into(),
+					symbol: AUSD.symbol().unwrap().into(),
+					decimals: AUSD.decimals().unwrap(),
+					minimal_balance: 0
+				}),
+				false
+			));
+
+			// ACA, DOT registered in genesis and AUSD
+			let tokens = registered_tokens();
+			assert_eq!(tokens.len(), 3);
+
+			// getTokens(uint256,uint256) -> 0x494cfc6c
+			// cursor 0, limit 10
+			let input = hex! {"
+				494cfc6c
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+			let resp = TokenRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, ethabi::encode(&[Token::Array(tokens.clone())]));
+
+			// cursor 1, limit 1
+			let input = hex! {"
+				494cfc6c
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let resp = TokenRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, ethabi::encode(&[Token::Array(vec![tokens[1].clone()])]));
+
+			// cursor 3, limit 10
+			let input = hex! {"
+				494cfc6c
+				00000000000000000000000000000000 00000000000000000000000000000003
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let resp = TokenRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// the cost is charged for the skipped and returned tokens
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			assert_ok!(TokenRegistryPrecompile::execute(&mut handle));
+			// cursor 3, limit 100
+			let more_input = hex! {"
+				494cfc6c
+				00000000000000000000000000000000 00000000000000000000000000000003
+				00000000000000000000000000000000 00000000000000000000000000000064
+			"};
+			let mut more_handle = MockPrecompileHandle::new(&more_input, None, &context, false);
+			assert_ok!(TokenRegistryPrecompile::execute(&mut more_handle));
+			// limit is capped at MAX_TOKENS_PER_QUERY
+			assert_eq!(
+				more_handle.gas_used.saturating_sub(handle.gas_used),
+				WeightToGas::convert(PrecompileWeights::<Test>::token_registry_get_token())
+					.saturating_mul((MAX_TOKENS_PER_QUERY - 10).into())
+			);
+		});
+	}
+
+	#[test]
+	fn get_token_by_address_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getTokenByAddress(address) -> 0x91ded8fa
+			// DOT
+			let input = hex! {"
+				91ded8fa
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// (address, decimals, symbol, kind)
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 0000000000000000000000000000000a
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000003
+				444f540000000000000000000000000000000000000000000000000000000000
+			"};
+
+			let resp = TokenRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+			let dot_token = registered_tokens()
+				.into_iter()
+				.find(
+					|token| matches!(token, Token::Tuple(fields) if fields[0] == Token::Address(DOT.try_into().unwrap())),
+				)
+				.unwrap();
+			assert_eq!(resp.output, ethabi::encode(&[dot_token]));
+
+			// LDOT is not registered
+			let input = hex! {"
+				91ded8fa
+				000000000000000000000000 0000000000000000000100000000000000000003
+			"};
+			assert_noop!(
+				TokenRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "token not found".into(),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn token_registry_precompile_should_handle_invalid_input() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_noop!(
+				TokenRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&[0u8; 3], None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid input".into(),
+				}
+			);
+
+			// cursor overflow u32
+			let input = hex! {"
+				494cfc6c
+				00000000000000000000000000000000 00000000000000000000000100000000
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+			assert_noop!(
+				TokenRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "failed to convert uint256 into u32".into(),
+				}
+			);
+		});
+	}
+}
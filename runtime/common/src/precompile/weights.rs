@@ -54,4 +54,19 @@ impl<T: frame_system::Config> PrecompileWeights<T> {
 		Weight::from_parts(7_198_000, 0)
 			.saturating_add(T::DbWeight::get().reads(2))
 	}
+	// EVMModule::SystemContracts (r: 1, w: 0)
+	pub fn evm_query_system_contract() -> Weight {
+		Weight::from_parts(6_528_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	// AssetRegistry::AssetMetadatas (r: 1, w: 0)
+	pub fn token_registry_get_token() -> Weight {
+		Weight::from_parts(9_862_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	// AssetRegistry::AssetMetadatas (r: 3, w: 0)
+	pub fn token_registry_get_token_by_address() -> Weight {
+		Weight::from_parts(16_204_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
 }
@@ -0,0 +1,156 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The preferences of the modules aggregated by `module_preferences`.
+
+use frame_system::pallet_prelude::BlockNumberFor;
+use module_preferences::{PreferenceEntry, PreferencesHandler};
+use sp_runtime::traits::StaticLookup;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The alternative fee swap path of `module_transaction_payment`.
+pub struct AlternativeFeeSwapPathPreference<T>(PhantomData<T>);
+impl<T, Call> PreferencesHandler<T::AccountId, BlockNumberFor<T>, Call> for AlternativeFeeSwapPathPreference<T>
+where
+	T: module_transaction_payment::Config,
+	Call: From<module_transaction_payment::Call<T>>,
+{
+	fn export(who: &T::AccountId, entries: &mut Vec<PreferenceEntry<T::AccountId, BlockNumberFor<T>>>) {
+		if let Some(path) = module_transaction_payment::AlternativeFeeSwapPath::<T>::get(who) {
+			entries.push(PreferenceEntry::AlternativeFeeSwapPath(path.into_inner()));
+		}
+	}
+
+	fn setter_call(entry: &PreferenceEntry<T::AccountId, BlockNumberFor<T>>) -> Option<Call> {
+		match entry {
+			PreferenceEntry::AlternativeFeeSwapPath(path) => Some(
+				module_transaction_payment::Call::<T>::set_alternative_fee_swap_path {
+					fee_swap_path: Some(path.clone()),
+				}
+				.into(),
+			),
+			_ => None,
+		}
+	}
+}
+
+/// The claim preferences of the incentives pools of `module_incentives`.
+pub struct IncentiveClaimPreferences<T>(PhantomData<T>);
+impl<T, Call> PreferencesHandler<T::AccountId, BlockNumberFor<T>, Call> for IncentiveClaimPreferences<T>
+where
+	T: module_incentives::Config,
+	Call: From<module_incentives::Call<T>>,
+{
+	fn export(who: &T::AccountId, entries: &mut Vec<PreferenceEntry<T::AccountId, BlockNumberFor<T>>>) {
+		// the preferences are keyed by the pool first, only the runtime API iterates them.
+		for (pool_id, account, preference) in module_incentives::ClaimPreferences::<T>::iter() {
+			if account == *who {
+				entries.push(PreferenceEntry::ClaimPreference {
+					pool_id,
+					currency_id: preference.currency_id,
+					max_slippage: preference.max_slippage,
+				});
+			}
+		}
+	}
+
+	fn setter_call(entry: &PreferenceEntry<T::AccountId, BlockNumberFor<T>>) -> Option<Call> {
+		match entry {
+			PreferenceEntry::ClaimPreference {
+				pool_id,
+				currency_id,
+				max_slippage,
+			} => Some(
+				module_incentives::Call::<T>::set_claim_preference {
+					pool_id: *pool_id,
+					currency_id: Some(*currency_id),
+					max_slippage: *max_slippage,
+				}
+				.into(),
+			),
+			_ => None,
+		}
+	}
+}
+
+/// The dust consolidation beneficiary of `module_currencies`.
+pub struct DustConsolidationPreference<T>(PhantomData<T>);
+impl<T, Call> PreferencesHandler<T::AccountId, BlockNumberFor<T>, Call> for DustConsolidationPreference<T>
+where
+	T: module_currencies::Config,
+	Call: From<module_currencies::Call<T>>,
+{
+	fn export(who: &T::AccountId, entries: &mut Vec<PreferenceEntry<T::AccountId, BlockNumberFor<T>>>) {
+		if let Some(beneficiary) = module_currencies::DustConsolidations::<T>::get(who) {
+			entries.push(PreferenceEntry::DustConsolidation(beneficiary));
+		}
+	}
+
+	fn setter_call(entry: &PreferenceEntry<T::AccountId, BlockNumberFor<T>>) -> Option<Call> {
+		match entry {
+			PreferenceEntry::DustConsolidation(beneficiary) => Some(
+				module_currencies::Call::<T>::set_dust_consolidation {
+					beneficiary: Some(T::Lookup::unlookup(beneficiary.clone())),
+				}
+				.into(),
+			),
+			_ => None,
+		}
+	}
+}
+
+/// The unexpired session keys of `module_session_keys`.
+pub struct SessionKeyPreferences<T>(PhantomData<T>);
+impl<T, Call> PreferencesHandler<T::AccountId, BlockNumberFor<T>, Call> for SessionKeyPreferences<T>
+where
+	T: module_session_keys::Config,
+	Call: From<module_session_keys::Call<T>>,
+{
+	fn export(who: &T::AccountId, entries: &mut Vec<PreferenceEntry<T::AccountId, BlockNumberFor<T>>>) {
+		let now = frame_system::Pallet::<T>::block_number();
+		for (key, info) in module_session_keys::SessionKeys::<T>::iter() {
+			if info.primary == *who && info.expiry > now {
+				entries.push(PreferenceEntry::SessionKey {
+					key,
+					allowed_calls: info.allowed_calls,
+					expiry: info.expiry,
+					spend_cap: info.spend_cap,
+				});
+			}
+		}
+	}
+
+	fn setter_call(entry: &PreferenceEntry<T::AccountId, BlockNumberFor<T>>) -> Option<Call> {
+		match entry {
+			PreferenceEntry::SessionKey {
+				key,
+				allowed_calls,
+				expiry,
+				spend_cap,
+			} => Some(
+				module_session_keys::Call::<T>::register_session_key {
+					key: key.clone(),
+					allowed_calls: allowed_calls.clone(),
+					expiry: *expiry,
+					spend_cap: *spend_cap,
+				}
+				.into(),
+			),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,166 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The total value locked and the protocol revenue for the dashboards.
+
+use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+use module_support::{PoolId, PriceProvider};
+use nutsfinance_stable_asset::traits::StableAsset;
+use orml_traits::MultiCurrency;
+use parity_scale_codec::{Decode, Encode};
+use primitives::{Balance, CurrencyId};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Zero, FixedPointNumber, RuntimeDebug};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, prelude::*};
+
+/// The maximum number of the pools or the collateral types enumerated of a source.
+pub const MAX_TVL_POOLS: u32 = 100;
+
+/// A source of the value locked.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum TvlSource {
+	/// The liquidity of the DEX pools.
+	Dex,
+	/// The collateral of the CDPs.
+	Loans,
+	/// The staking currency of Homa.
+	Homa,
+	/// The native currency bonded by Earning.
+	Earning,
+	/// The assets of the StableAsset pools.
+	StableAsset,
+	/// The DEX shares deposited into the incentives.
+	IncentivesLp,
+}
+
+/// The protocol revenue of a period in the stable currency, `None` if it's not tracked for the
+/// period.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ProtocolRevenue {
+	/// The stability fees accrued as the interest of the debits.
+	pub stability_fee: Option<Balance>,
+	/// The liquidation penalties charged, including the cashback of the liquidated owners.
+	pub liquidation_penalty: Option<Balance>,
+	/// The DEX fees, which are not tracked.
+	pub dex_fee: Option<Balance>,
+	/// The fees of Honzon bridge, which charges no fee.
+	pub bridge_fee: Option<Balance>,
+}
+
+/// The statistics of the protocol. The value locked is valued by the price source `P` in the
+/// stable currency, the StableAsset pools are read from `S`.
+pub struct ProtocolStats<T, S, P>(PhantomData<(T, S, P)>);
+impl<T, S, P> ProtocolStats<T, S, P>
+where
+	T: module_dex::Config
+		+ module_loans::Config
+		+ module_homa::Config
+		+ module_incentives::Config
+		+ module_cdp_engine::Config,
+	S: StableAsset<
+		AssetId = CurrencyId,
+		AtLeast64BitUnsigned = Balance,
+		Balance = Balance,
+		AccountId = T::AccountId,
+		BlockNumber = BlockNumberFor<T>,
+	>,
+	P: PriceProvider<CurrencyId>,
+{
+	/// The value locked by each source in each currency, at most `MAX_TVL_POOLS` pools or
+	/// collateral types of each source are enumerated.
+	pub fn get_tvl() -> Vec<(TvlSource, CurrencyId, Balance, Option<Balance>)> {
+		let mut tvl = Vec::new();
+		let mut push = |source: TvlSource, amounts: BTreeMap<CurrencyId, Balance>| {
+			for (currency_id, amount) in amounts {
+				if !amount.is_zero() {
+					let value = P::get_price(currency_id).and_then(|price| price.checked_mul_int(amount));
+					tvl.push((source, currency_id, amount, value));
+				}
+			}
+		};
+
+		let mut dex = BTreeMap::new();
+		let mut incentives_lp = BTreeMap::new();
+		let incentives_account = module_incentives::Pallet::<T>::account_id();
+		for (trading_pair, (pool_0, pool_1)) in module_dex::LiquidityPool::<T>::iter().take(MAX_TVL_POOLS as usize) {
+			add_amount(&mut dex, trading_pair.first(), pool_0);
+			add_amount(&mut dex, trading_pair.second(), pool_1);
+
+			let dex_share_currency_id = trading_pair.dex_share_currency_id();
+			let deposited =
+				<T as module_incentives::Config>::Currency::free_balance(dex_share_currency_id, &incentives_account);
+			add_amount(&mut incentives_lp, dex_share_currency_id, deposited);
+		}
+		push(TvlSource::Dex, dex);
+
+		let mut loans = BTreeMap::new();
+		for (currency_id, position) in module_loans::TotalPositions::<T>::iter().take(MAX_TVL_POOLS as usize) {
+			add_amount(&mut loans, currency_id, position.collateral);
+		}
+		push(TvlSource::Loans, loans);
+
+		push(
+			TvlSource::Homa,
+			BTreeMap::from([(
+				<T as module_homa::Config>::StakingCurrencyId::get(),
+				module_homa::Pallet::<T>::get_total_staking_currency(),
+			)]),
+		);
+
+		let native_currency_id = <T as module_incentives::Config>::NativeCurrencyId::get();
+		push(
+			TvlSource::Earning,
+			BTreeMap::from([(
+				native_currency_id,
+				module_incentives::Pallet::<T>::total_shares(&PoolId::Earning(native_currency_id)),
+			)]),
+		);
+
+		let mut stable_asset = BTreeMap::new();
+		for pool_id in 0..S::pool_count().min(MAX_TVL_POOLS) {
+			if let Some(pool_info) = S::pool(pool_id) {
+				for currency_id in pool_info.assets {
+					let amount =
+						<T as module_incentives::Config>::Currency::free_balance(currency_id, &pool_info.account_id);
+					add_amount(&mut stable_asset, currency_id, amount);
+				}
+			}
+		}
+		push(TvlSource::StableAsset, stable_asset);
+
+		push(TvlSource::IncentivesLp, incentives_lp);
+		tvl
+	}
+
+	/// The protocol revenue of the revenue periods of CDP engine overlapping the trailing
+	/// `period` blocks.
+	pub fn get_protocol_revenue(period: BlockNumberFor<T>) -> ProtocolRevenue {
+		let cdp_revenue = module_cdp_engine::Pallet::<T>::get_revenue(period);
+		ProtocolRevenue {
+			stability_fee: cdp_revenue.map(|(stability_fee, _)| stability_fee),
+			liquidation_penalty: cdp_revenue.map(|(_, liquidation_penalty)| liquidation_penalty),
+			dex_fee: None,
+			bridge_fee: Some(Zero::zero()),
+		}
+	}
+}
+
+fn add_amount(amounts: &mut BTreeMap<CurrencyId, Balance>, currency_id: CurrencyId, amount: Balance) {
+	let total = amounts.entry(currency_id).or_default();
+	*total = total.saturating_add(amount);
+}
@@ -0,0 +1,38 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The EVM call target of the session keys.
+
+use frame_support::traits::IsSubType;
+use module_session_keys::EvmTargetInspector;
+use primitives::evm::EvmAddress;
+use sp_std::marker::PhantomData;
+
+/// The contract address targeted by `module_evm::Call::call`.
+pub struct EvmCallTarget<T>(PhantomData<T>);
+impl<T, Call> EvmTargetInspector<Call> for EvmCallTarget<T>
+where
+	T: module_evm::Config,
+	Call: IsSubType<module_evm::Call<T>>,
+{
+	fn evm_target(call: &Call) -> Option<EvmAddress> {
+		match call.is_sub_type() {
+			Some(module_evm::Call::call { target, .. }) => Some(*target),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,76 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The yield indexes of Homa, Earning and the incentives pools for the statistics.
+
+use frame_support::traits::Get;
+use module_statistics::{YieldIndexProvider, YieldSource};
+use module_support::{PoolId, PriceProvider};
+use primitives::CurrencyId;
+use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_std::marker::PhantomData;
+
+/// The indexes of the yield sources. The principals of the incentives pools are valued by the
+/// price source `P` in the reward currency:
+///
+/// - `Loans`: the debit value of one debit share, in the stable currency.
+/// - `Dex` and `Earning`: one share of the LP token or the bonded currency.
+/// - `NomineesElection`: one share of the bonded liquid currency.
+pub struct YieldIndexes<T, P>(PhantomData<(T, P)>);
+impl<T, P> YieldIndexProvider for YieldIndexes<T, P>
+where
+	T: module_homa::Config + module_incentives::Config + module_cdp_engine::Config,
+	P: PriceProvider<CurrencyId>,
+{
+	fn index(source: &YieldSource) -> Option<FixedU128> {
+		match source {
+			YieldSource::Homa => Some(module_homa::Pallet::<T>::current_exchange_rate()),
+			YieldSource::Earning(reward_currency_id) => module_incentives::Pallet::<T>::accumulated_reward_per_share(
+				&PoolId::Earning(<T as module_incentives::Config>::NativeCurrencyId::get()),
+				*reward_currency_id,
+			),
+			YieldSource::IncentivePool(pool_id, reward_currency_id) => {
+				module_incentives::Pallet::<T>::accumulated_reward_per_share(pool_id, *reward_currency_id)
+			}
+		}
+	}
+
+	fn principal(source: &YieldSource) -> Option<FixedU128> {
+		match source {
+			YieldSource::Homa => None,
+			YieldSource::Earning(reward_currency_id) => P::get_relative_price(
+				<T as module_incentives::Config>::NativeCurrencyId::get(),
+				*reward_currency_id,
+			),
+			YieldSource::IncentivePool(pool_id, reward_currency_id) => match pool_id {
+				PoolId::Loans(collateral_currency_id) => module_cdp_engine::Pallet::<T>::get_debit_exchange_rate(
+					*collateral_currency_id,
+				)
+				.checked_mul(&P::get_relative_price(
+					<T as module_cdp_engine::Config>::GetStableCurrencyId::get(),
+					*reward_currency_id,
+				)?),
+				PoolId::Dex(currency_id) | PoolId::Earning(currency_id) => {
+					P::get_relative_price(*currency_id, *reward_currency_id)
+				}
+				PoolId::NomineesElection => {
+					P::get_relative_price(<T as module_homa::Config>::LiquidCurrencyId::get(), *reward_currency_id)
+				}
+			},
+		}
+	}
+}
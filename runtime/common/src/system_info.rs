@@ -0,0 +1,138 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The upgrade and pause status of the chain, decoded for the token holders.
+//!
+//! `SystemInfoApi::get_chain_status` aggregates in a single call what otherwise needs the raw
+//! storage of several pallets:
+//! - the upgrade authorized by `frame_system::authorize_upgrade`, and whether the code with the
+//!   authorized hash is already enacted.
+//! - the validation code scheduled by `cumulus_pallet_parachain_system`, and whether the relay
+//!   chain currently restricts the upgrades.
+//! - the current session and the blocks until the next one, as scheduled by
+//!   `module_session_manager`.
+//! - the safe mode and emergency shutdown switches, and the number of calls and EVM precompiles
+//!   paused by `module_transaction_pause`.
+
+use frame_support::{storage_alias, traits::EstimateNextSessionRotation};
+use frame_system::pallet_prelude::BlockNumberFor;
+use module_support::SafeMode;
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Hash as HashT, Saturating},
+	RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// The upgrade authorized by `frame_system::authorize_upgrade`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AuthorizedUpgradeStatus<Hash> {
+	/// The hash of the authorized code.
+	pub code_hash: Hash,
+	/// Whether the spec name and version of the code are checked on upgrade.
+	pub check_version: bool,
+	/// Whether the current code has the authorized hash, i.e. the authorization is already used
+	/// or was made for the running code.
+	pub enacted: bool,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ChainStatus<Hash, BlockNumber> {
+	/// The upgrade authorized by governance, if any.
+	pub authorized_upgrade: Option<AuthorizedUpgradeStatus<Hash>>,
+	/// Whether a new validation code is scheduled, waiting for the go ahead of the relay chain.
+	pub pending_validation_code: bool,
+	/// Whether the relay chain currently restricts the upgrades of the parachain.
+	pub upgrade_restricted: bool,
+	/// The index of the current session.
+	pub session_index: u32,
+	/// The blocks until the next session, `None` if the session duration is zero.
+	pub blocks_to_next_session: Option<BlockNumber>,
+	/// Whether the safe mode is active.
+	pub safe_mode_active: bool,
+	/// Whether the emergency shutdown is triggered.
+	pub emergency_shutdown: bool,
+	/// The number of the calls paused by `module_transaction_pause`.
+	pub paused_calls: u32,
+	/// The number of the EVM precompiles paused by `module_transaction_pause`.
+	pub paused_evm_precompiles: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait SystemInfoApi<Hash, BlockNumber> where
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// The upgrade, session and pause status of the chain.
+		fn get_chain_status() -> ChainStatus<Hash, BlockNumber>;
+	}
+}
+
+/// `frame_system` keeps the authorization in a private type, which has the same encoding.
+#[storage_alias]
+type AuthorizedUpgrade<T: frame_system::Config> =
+	StorageValue<frame_system::Pallet<T>, (<T as frame_system::Config>::Hash, bool)>;
+
+#[storage_alias]
+type PendingValidationCode<T: cumulus_pallet_parachain_system::Config> =
+	StorageValue<cumulus_pallet_parachain_system::Pallet<T>, Vec<u8>>;
+
+#[storage_alias]
+type UpgradeRestrictionSignal<T: cumulus_pallet_parachain_system::Config> =
+	StorageValue<cumulus_pallet_parachain_system::Pallet<T>, Option<polkadot_primitives::v7::UpgradeRestriction>>;
+
+/// The status of the chain, `S` reports whether the safe mode is active.
+pub fn get_chain_status<T, S>() -> ChainStatus<T::Hash, BlockNumberFor<T>>
+where
+	T: frame_system::Config
+		+ cumulus_pallet_parachain_system::Config
+		+ pallet_session::Config
+		+ module_session_manager::Config
+		+ module_emergency_shutdown::Config
+		+ module_transaction_pause::Config,
+	S: SafeMode,
+{
+	let authorized_upgrade = AuthorizedUpgrade::<T>::get().map(|(code_hash, check_version)| {
+		let enacted = sp_io::storage::get(sp_core::storage::well_known_keys::CODE)
+			.map_or(false, |code| T::Hashing::hash(&code) == code_hash);
+		AuthorizedUpgradeStatus {
+			code_hash,
+			check_version,
+			enacted,
+		}
+	});
+
+	let now = frame_system::Pallet::<T>::block_number();
+	let blocks_to_next_session = module_session_manager::Pallet::<T>::estimate_next_session_rotation(now)
+		.0
+		.map(|next| next.saturating_sub(now));
+
+	ChainStatus {
+		authorized_upgrade,
+		// the code is only checked for emptiness, don't decode it.
+		pending_validation_code: PendingValidationCode::<T>::decode_len().map_or(false, |len| len > 0),
+		upgrade_restricted: UpgradeRestrictionSignal::<T>::get().flatten().is_some(),
+		session_index: pallet_session::Pallet::<T>::current_index(),
+		blocks_to_next_session,
+		safe_mode_active: S::is_active(),
+		emergency_shutdown: module_emergency_shutdown::Pallet::<T>::is_shutdown(),
+		paused_calls: module_transaction_pause::PausedTransactions::<T>::iter_keys().count() as u32,
+		paused_evm_precompiles: module_transaction_pause::PausedEvmPrecompiles::<T>::iter_keys().count() as u32,
+	}
+}
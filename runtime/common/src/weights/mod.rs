@@ -0,0 +1,61 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The weights generated by the benchmarks of Acala, shared by the runtimes.
+//!
+//! A runtime re-exports the weights of a pallet from here, unless the pallet is configured
+//! differently and the weights generated by the benchmarks of the runtime are kept locally.
+#![allow(clippy::unnecessary_cast)]
+
+pub mod module_aggregated_dex;
+pub mod module_asset_registry;
+pub mod module_auction_manager;
+pub mod module_cdp_engine;
+pub mod module_cdp_treasury;
+pub mod module_collator_selection;
+pub mod module_currencies;
+pub mod module_dex;
+pub mod module_dex_oracle;
+pub mod module_earning;
+pub mod module_emergency_shutdown;
+pub mod module_evm;
+pub mod module_evm_accounts;
+pub mod module_homa;
+pub mod module_homa_validator_list;
+pub mod module_honzon;
+pub mod module_idle_scheduler;
+pub mod module_incentives;
+pub mod module_liquid_crowdloan;
+pub mod module_nft;
+pub mod module_nominees_election;
+pub mod module_oracle_operator;
+pub mod module_prices;
+pub mod module_scheduler_index;
+pub mod module_session_manager;
+pub mod module_transaction_pause;
+pub mod module_transaction_payment;
+
+pub mod orml_auction;
+pub mod orml_authority;
+pub mod orml_oracle;
+pub mod orml_tokens;
+pub mod orml_vesting;
+
+pub mod nutsfinance_stable_asset;
+
+pub mod pallet_xcm;
@@ -55,18 +55,20 @@ impl<T: frame_system::Config> module_aggregated_dex::WeightInfo for WeightInfo<T
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `AggregatedDex::SwapHistories` (r:1 w:1)
+	// Proof: `AggregatedDex::SwapHistories` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `u` is `[2, 4]`.
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1842 + u * (112 ±0)`
-		//  Estimated: `6234 + u * (643 ±18)`
+		//  Measured:  `5549 + u * (112 ±0)`
+		//  Estimated: `9014 + u * (643 ±18)`
 		// Minimum execution time: 86_812 nanoseconds.
-		Weight::from_parts(66_480_055, 6234)
+		Weight::from_parts(71_937_208, 9014)
 			// Standard Error: 91_043
 			.saturating_add(Weight::from_parts(12_057_473, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(u.into())))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
 			.saturating_add(Weight::from_parts(0, 643).saturating_mul(u.into()))
 	}
@@ -78,18 +80,20 @@ impl<T: frame_system::Config> module_aggregated_dex::WeightInfo for WeightInfo<T
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `AggregatedDex::SwapHistories` (r:1 w:1)
+	// Proof: `AggregatedDex::SwapHistories` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `u` is `[2, 4]`.
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1842 + u * (112 ±0)`
-		//  Estimated: `6234 + u * (643 ±18)`
+		//  Measured:  `5549 + u * (112 ±0)`
+		//  Estimated: `9014 + u * (643 ±18)`
 		// Minimum execution time: 93_849 nanoseconds.
-		Weight::from_parts(65_961_366, 6234)
+		Weight::from_parts(71_402_519, 9014)
 			// Standard Error: 152_574
 			.saturating_add(Weight::from_parts(17_376_660, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(u.into())))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
 			.saturating_add(Weight::from_parts(0, 643).saturating_mul(u.into()))
 	}
@@ -106,4 +110,30 @@ impl<T: frame_system::Config> module_aggregated_dex::WeightInfo for WeightInfo<T
 			.saturating_add(Weight::from_parts(1_464_785, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
+	// Storage: `AggregatedDex::SwapHistories` (r:1 w:1)
+	// Proof: `AggregatedDex::SwapHistories` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn enable_swap_history() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1364`
+		//  Estimated: `4829`
+		// Minimum execution time: 28_163 nanoseconds.
+		Weight::from_parts(29_004_000, 4829)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `AggregatedDex::SwapHistories` (r:1 w:1)
+	// Proof: `AggregatedDex::SwapHistories` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn disable_swap_history() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5549`
+		//  Estimated: `9014`
+		// Minimum execution time: 32_417 nanoseconds.
+		Weight::from_parts(33_520_000, 9014)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -155,4 +155,17 @@ impl<T: frame_system::Config> module_asset_registry::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::CanonicalAssets` (r:1 w:1)
+	// Proof: `AssetRegistry::CanonicalAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_canonical_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1240`
+		//  Estimated: `4705`
+		// Minimum execution time: 18_114 nanoseconds.
+		Weight::from_parts(18_630_000, 4705)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,602 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for module_cdp_engine
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 35.0.1
+//! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `ip-172-31-41-141`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! WASM-EXECUTION: Compiled, CHAIN: Some("acala-dev"), DB CACHE: 1024
+//!
+//! The weights after the placeholder note at the end of `WeightInfo` were not produced by the
+//! benchmark CLI.
+
+// Executed Command:
+// target/production/acala
+// benchmark
+// pallet
+// --chain=acala-dev
+// --steps=50
+// --repeat=20
+// --pallet=*
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=./templates/runtime-weight-template.hbs
+// --output=./runtime/acala/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_cdp_engine.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:1)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	fn set_collateral_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1205`
+		//  Estimated: `3600`
+		// Minimum execution time: 22_576 nanoseconds.
+		Weight::from_parts(22_982_000, 3600)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::LiquidationContracts` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationContracts` (`max_values`: Some(1), `max_size`: Some(201), added: 696, mode: `MaxEncodedLen`)
+	fn register_liquidation_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1205`
+		//  Estimated: `1686`
+		// Minimum execution time: 14_709 nanoseconds.
+		Weight::from_parts(15_047_000, 1686)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::LiquidationContracts` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationContracts` (`max_values`: Some(1), `max_size`: Some(201), added: 696, mode: `MaxEncodedLen`)
+	fn deregister_liquidation_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1250`
+		//  Estimated: `1686`
+		// Minimum execution time: 15_349 nanoseconds.
+		Weight::from_parts(15_865_000, 1686)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Placeholder weights: the entries below were not produced by the benchmark CLI. They are
+	// hand-estimated from comparable extrinsics and must be regenerated with `benchmark pallet`.
+	// Storage: `Aura::CurrentSlot` (r:1 w:1)
+	// Proof: `Aura::CurrentSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `Aura::Authorities` (r:1 w:0)
+	// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LastAccumulationSecs` (r:1 w:1)
+	// Proof: `CdpEngine::LastAccumulationSecs` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:4 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `System::InherentsApplied` (r:0 w:1)
+	// Proof: `System::InherentsApplied` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `System::ParentHash` (r:0 w:1)
+	// Proof: `System::ParentHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	// Storage: `System::Digest` (r:0 w:1)
+	// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `System::BlockHash` (r:0 w:1)
+	// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	// Storage: UNKNOWN KEY `0x3a65787472696e7369635f696e646578` (r:0 w:1)
+	// Proof: UNKNOWN KEY `0x3a65787472696e7369635f696e646578` (r:0 w:1)
+	// Storage: UNKNOWN KEY `0x3a696e747261626c6f636b5f656e74726f7079` (r:0 w:1)
+	// Proof: UNKNOWN KEY `0x3a696e747261626c6f636b5f656e74726f7079` (r:0 w:1)
+	// Storage: `Timestamp::Now` (r:0 w:1)
+	// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `Timestamp::DidUpdate` (r:0 w:1)
+	// Proof: `Timestamp::DidUpdate` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 4]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(21_949_116, 3600)
+			.saturating_add(Weight::from_parts(6_303_284, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(Weight::from_parts(0, 2610).saturating_mul(c.into()))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:1 w:1)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:2 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:3 w:3)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::TotalCollateralInAuction` (r:1 w:1)
+	// Proof: `AuctionManager::TotalCollateralInAuction` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:3 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `StableAsset::Pools` (r:1 w:0)
+	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AggregatedDex::AggregatedSwapPaths` (r:1 w:0)
+	// Proof: `AggregatedDex::AggregatedSwapPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::LiquidationContracts` (r:1 w:0)
+	// Proof: `CdpEngine::LiquidationContracts` (`max_values`: Some(1), `max_size`: Some(201), added: 696, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::ExpectedCollateralAuctionSize` (r:1 w:0)
+	// Proof: `CdpTreasury::ExpectedCollateralAuctionSize` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::TotalTargetInAuction` (r:1 w:1)
+	// Proof: `AuctionManager::TotalTargetInAuction` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Auction::AuctionsIndex` (r:1 w:1)
+	// Proof: `Auction::AuctionsIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::CollateralAuctions` (r:0 w:50)
+	// Proof: `AuctionManager::CollateralAuctions` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	// Storage: `Auction::AuctionEndTime` (r:0 w:50)
+	// Proof: `Auction::AuctionEndTime` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	// Storage: `Auction::Auctions` (r:0 w:50)
+	// Proof: `Auction::Auctions` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidationStatistics` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationStatistics` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidationHistory` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationHistory` (`max_values`: None, `max_size`: Some(2001), added: 4476, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 50]`.
+	fn liquidate_by_auction(b: u32, ) -> Weight {
+		Weight::from_parts(178_905_636, 9558)
+			.saturating_add(Weight::from_parts(9_527_834, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(31))
+			.saturating_add(T::DbWeight::get().writes(17))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:1 w:1)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:2 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
+	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::ToBondPool` (r:1 w:0)
+	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
+	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:6 w:6)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:4 w:3)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::TotalCollateralInAuction` (r:1 w:0)
+	// Proof: `AuctionManager::TotalCollateralInAuction` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:4 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:2 w:2)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `StableAsset::Pools` (r:1 w:0)
+	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AggregatedDex::AggregatedSwapPaths` (r:1 w:0)
+	// Proof: `AggregatedDex::AggregatedSwapPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::LiquidationStatistics` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationStatistics` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidationHistory` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationHistory` (`max_values`: None, `max_size`: Some(2001), added: 4476, mode: `MaxEncodedLen`)
+	fn liquidate_by_dex() -> Weight {
+		Weight::from_parts(270_982_000, 16722)
+			.saturating_add(T::DbWeight::get().reads(39))
+			.saturating_add(T::DbWeight::get().writes(18))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:1 w:1)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:2 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:3 w:3)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::TotalCollateralInAuction` (r:1 w:1)
+	// Proof: `AuctionManager::TotalCollateralInAuction` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:3 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `StableAsset::Pools` (r:1 w:0)
+	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AggregatedDex::AggregatedSwapPaths` (r:1 w:0)
+	// Proof: `AggregatedDex::AggregatedSwapPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::LiquidationContracts` (r:1 w:0)
+	// Proof: `CdpEngine::LiquidationContracts` (`max_values`: Some(1), `max_size`: Some(201), added: 696, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::ExpectedCollateralAuctionSize` (r:1 w:0)
+	// Proof: `CdpTreasury::ExpectedCollateralAuctionSize` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::TotalTargetInAuction` (r:1 w:1)
+	// Proof: `AuctionManager::TotalTargetInAuction` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Auction::AuctionsIndex` (r:1 w:1)
+	// Proof: `Auction::AuctionsIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::CollateralAuctions` (r:0 w:50)
+	// Proof: `AuctionManager::CollateralAuctions` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	// Storage: `Auction::AuctionEndTime` (r:0 w:50)
+	// Proof: `Auction::AuctionEndTime` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	// Storage: `Auction::Auctions` (r:0 w:50)
+	// Proof: `Auction::Auctions` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidationStatistics` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationStatistics` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidationHistory` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationHistory` (`max_values`: None, `max_size`: Some(2001), added: 4476, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 50]`.
+	fn liquidate_partially_by_auction(b: u32, ) -> Weight {
+		Weight::from_parts(185_317_402, 9558)
+			.saturating_add(Weight::from_parts(9_611_290, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(33))
+			.saturating_add(T::DbWeight::get().writes(19))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:1 w:1)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidationStatistics` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationStatistics` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidationHistory` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationHistory` (`max_values`: None, `max_size`: Some(2001), added: 4476, mode: `MaxEncodedLen`)
+	fn settle() -> Weight {
+		Weight::from_parts(99_715_000, 6270)
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:1)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(144), added: 2619, mode: `MaxEncodedLen`)
+	fn set_max_price_age() -> Weight {
+		Weight::from_parts(25_672_000, 3609)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::CollateralParams` (r:4 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(144), added: 2619, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CrossMarginPreferences` (r:0 w:1)
+	// Proof: `CdpEngine::CrossMarginPreferences` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	fn set_cross_margin_preference() -> Weight {
+		Weight::from_parts(22_437_000, 11466)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(144), added: 2619, mode: `MaxEncodedLen`)
+	// Storage: `System::Number` (r:1 w:0)
+	// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::PromotionalRates` (r:0 w:1)
+	// Proof: `CdpEngine::PromotionalRates` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn set_promotional_rate() -> Weight {
+		Weight::from_parts(19_036_000, 3609)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(144), added: 2619, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CashbackParams` (r:0 w:1)
+	// Proof: `CdpEngine::CashbackParams` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_penalty_cashback_params() -> Weight {
+		Weight::from_parts(17_520_000, 3609)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::PendingCashbacks` (r:1 w:1)
+	// Proof: `CdpEngine::PendingCashbacks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	// Storage: `System::Number` (r:1 w:0)
+	// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:1 w:0)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(144), added: 2619, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_penalty_cashback() -> Weight {
+		Weight::from_parts(73_364_000, 6234)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `CdpEngine::PendingCashbacks` (r:1 w:1)
+	// Proof: `CdpEngine::PendingCashbacks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	// Storage: `System::Number` (r:1 w:0)
+	// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn sweep_expired_penalty_cashback() -> Weight {
+		Weight::from_parts(16_390_000, 3586)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(144), added: 2619, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:0)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidityDebitCeilings` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidityDebitCeilings` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidityCeilingMultipliers` (r:0 w:1)
+	// Proof: `CdpEngine::LiquidityCeilingMultipliers` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn set_liquidity_ceiling_multiplier() -> Weight {
+		Weight::from_parts(42_396_000, 3660)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Loans::Positions` (r:50 w:0)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Honzon::PositionAlerts` (r:50 w:50)
+	// Proof: `Honzon::PositionAlerts` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn trigger_position_alerts(n: u32, ) -> Weight {
+		Weight::from_parts(9_266_000, 6484)
+			.saturating_add(Weight::from_parts(23_815_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(n.into()))
+	}
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:1)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::NextTaskId` (r:1 w:1)
+	// Proof: `IdleScheduler::NextTaskId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::Tasks` (r:0 w:1)
+	// Proof: `IdleScheduler::Tasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_debit_rebase() -> Weight {
+		Weight::from_parts(28_704_000, 4061)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:1)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	fn pause_debit_rebase() -> Weight {
+		Weight::from_parts(16_951_000, 4061)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:1)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::NextTaskId` (r:1 w:1)
+	// Proof: `IdleScheduler::NextTaskId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::Tasks` (r:0 w:1)
+	// Proof: `IdleScheduler::Tasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn resume_debit_rebase() -> Weight {
+		Weight::from_parts(24_627_000, 4061)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:1)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::NextTaskId` (r:1 w:1)
+	// Proof: `IdleScheduler::NextTaskId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::Tasks` (r:0 w:1)
+	// Proof: `IdleScheduler::Tasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn abort_debit_rebase() -> Weight {
+		Weight::from_parts(25_918_000, 4061)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:1)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:1)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:51 w:50)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:50 w:0)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:50 w:0)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 50]`.
+	fn debit_rebase(n: u32, ) -> Weight {
+		Weight::from_parts(18_322_000, 4061)
+			.saturating_add(Weight::from_parts(30_917_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2598).saturating_mul(n.into()))
+	}
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:0)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:201 w:0)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Loans::TotalPositions` (r:1 w:0)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::RiskSnapshots` (r:0 w:1)
+	// Proof: `CdpEngine::RiskSnapshots` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 200]`.
+	fn update_risk_snapshot(n: u32, ) -> Weight {
+		Weight::from_parts(23_871_000, 4061)
+			.saturating_add(Weight::from_parts(8_672_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2598).saturating_mul(n.into()))
+	}
+	// Storage: `CdpEngine::CollateralParams` (r:2 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralSubstitutions` (r:1 w:1)
+	// Proof: `CdpEngine::CollateralSubstitutions` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn enable_collateral_substitution() -> Weight {
+		Weight::from_parts(20_893_000, 7210)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpEngine::CollateralSubstitutions` (r:1 w:1)
+	// Proof: `CdpEngine::CollateralSubstitutions` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn disable_collateral_substitution() -> Weight {
+		Weight::from_parts(14_967_000, 3574)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralSubstitutions` (r:1 w:1)
+	// Proof: `CdpEngine::CollateralSubstitutions` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitRebases` (r:2 w:0)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:2 w:2)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:2 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:4 w:4)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:2 w:2)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:2 w:2)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:2 w:2)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	fn substitute_collateral() -> Weight {
+		Weight::from_parts(151_873_000, 11244)
+			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:0)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::Positions` (r:1 w:1)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::RetiringPools` (r:1 w:0)
+	// Proof: `Incentives::RetiringPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	fn expand_position_collateral_urgent() -> Weight {
+		Weight::from_parts(76_515_000, 8834)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+}
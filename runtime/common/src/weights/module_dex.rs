@@ -49,14 +49,16 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairListings` (r:1 w:1)
+	// Proof: `Dex::TradingPairListings` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn enable_trading_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1106`
 		//  Estimated: `3660`
 		// Minimum execution time: 16_109 nanoseconds.
 		Weight::from_parts(16_801_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
@@ -107,14 +109,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
 	// Storage: `Dex::InitialShareExchangeRates` (r:0 w:1)
 	// Proof: `Dex::InitialShareExchangeRates` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairListings` (r:1 w:1)
+	// Proof: `Dex::TradingPairListings` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn end_provisioning() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1740`
 		//  Estimated: `3660`
 		// Minimum execution time: 41_978 nanoseconds.
 		Weight::from_parts(44_925_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
@@ -173,6 +177,13 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	fn add_liquidity_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_381 nanoseconds.
+		Weight::from_parts(5_542_000, 0)
+	}
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
 	// Storage: `Dex::LiquidityPool` (r:1 w:1)
@@ -215,6 +226,13 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	fn remove_liquidity_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_244 nanoseconds.
+		Weight::from_parts(5_401_000, 0)
+	}
 	// Storage: `Dex::LiquidityPool` (r:1 w:1)
 	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
 	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
@@ -246,20 +264,22 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Dex::RevealedTradingPairs` (r:3 w:0)
+	// Proof: `Dex::RevealedTradingPairs` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	/// The range of component `u` is `[2, 4]`.
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1842 + u * (112 ±0)`
-		//  Estimated: `6234 + u * (643 ±18)`
+		//  Estimated: `6234 + u * (3216 ±18)`
 		// Minimum execution time: 83_790 nanoseconds.
 		Weight::from_parts(66_397_007, 6234)
 			// Standard Error: 120_592
 			.saturating_add(Weight::from_parts(10_851_711, 0).saturating_mul(u.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(u.into())))
 			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
-			.saturating_add(Weight::from_parts(0, 643).saturating_mul(u.into()))
+			.saturating_add(Weight::from_parts(0, 3216).saturating_mul(u.into()))
 	}
 	// Storage: `Dex::TradingPairStatuses` (r:3 w:0)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
@@ -269,20 +289,22 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Dex::RevealedTradingPairs` (r:3 w:0)
+	// Proof: `Dex::RevealedTradingPairs` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	/// The range of component `u` is `[2, 4]`.
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1842 + u * (112 ±0)`
-		//  Estimated: `6234 + u * (643 ±18)`
+		//  Estimated: `6234 + u * (3216 ±18)`
 		// Minimum execution time: 83_708 nanoseconds.
 		Weight::from_parts(66_043_802, 6234)
 			// Standard Error: 142_413
 			.saturating_add(Weight::from_parts(11_426_120, 0).saturating_mul(u.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(u.into())))
 			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
-			.saturating_add(Weight::from_parts(0, 643).saturating_mul(u.into()))
+			.saturating_add(Weight::from_parts(0, 3216).saturating_mul(u.into()))
 	}
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
@@ -316,4 +338,252 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairListings` (r:0 w:1)
+	// Proof: `Dex::TradingPairListings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_trading_pair_listing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1200`
+		//  Estimated: `3660`
+		// Minimum execution time: 17_821 nanoseconds.
+		Weight::from_parts(18_374_000, 3660)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Dex::TradingPairStatuses` (r:4 w:1)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:4 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ProvisioningPool` (r:2 w:0)
+	// Proof: `Dex::ProvisioningPool` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	// Storage: `Dex::InitialShareExchangeRates` (r:4 w:1)
+	// Proof: `Dex::InitialShareExchangeRates` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:3 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairListings` (r:1 w:1)
+	// Proof: `Dex::TradingPairListings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn force_repair_trading_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2351`
+		//  Estimated: `11586`
+		// Minimum execution time: 71_903 nanoseconds.
+		Weight::from_parts(73_640_000, 11586)
+			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: `Dex::TradingPairStatuses` (r:3 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:3 w:3)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Dex::FlashSwapLocked` (r:1 w:1)
+	// Proof: `Dex::FlashSwapLocked` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:2 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::Accounts` (r:1 w:0)
+	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `EVM::Accounts` (r:1 w:0)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::Codes` (r:1 w:0)
+	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::RevealedTradingPairs` (r:3 w:0)
+	// Proof: `Dex::RevealedTradingPairs` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[2, 4]`.
+	fn flash_swap(u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1842 + u * (112 ±0)`
+		//  Estimated: `11304 + u * (3216 ±18)`
+		// Minimum execution time: 83_708 nanoseconds.
+		Weight::from_parts(104_043_802, 11304)
+			// Standard Error: 142_413
+			.saturating_add(Weight::from_parts(11_426_120, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 3216).saturating_mul(u.into()))
+	}
+	// Storage: `Dex::SwapCommits` (r:1 w:1)
+	// Proof: `Dex::SwapCommits` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	// Storage: `Dex::SwapCommitExpiries` (r:1 w:1)
+	// Proof: `Dex::SwapCommitExpiries` (`max_values`: None, `max_size`: Some(3216), added: 5691, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn commit_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1203`
+		//  Estimated: `6681`
+		// Minimum execution time: 38_412 nanoseconds.
+		Weight::from_parts(39_517_000, 6681)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `Dex::SwapCommits` (r:1 w:1)
+	// Proof: `Dex::SwapCommits` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:3 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:3 w:3)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Dex::RevealedTradingPairs` (r:0 w:3)
+	// Proof: `Dex::RevealedTradingPairs` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	// Storage: `Dex::SwapCommitExpiries` (r:1 w:1)
+	// Proof: `Dex::SwapCommitExpiries` (`max_values`: None, `max_size`: Some(3216), added: 5691, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[2, 4]`.
+	fn reveal_swap(u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2158 + u * (112 ±0)`
+		//  Estimated: `6681 + u * (643 ±18)`
+		// Minimum execution time: 112_350 nanoseconds.
+		Weight::from_parts(93_604_117, 6681)
+			// Standard Error: 131_204
+			.saturating_add(Weight::from_parts(13_208_452, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 643).saturating_mul(u.into()))
+	}
+	// Storage: `Dex::SwapCommitExpiries` (r:1 w:1)
+	// Proof: `Dex::SwapCommitExpiries` (`max_values`: None, `max_size`: Some(3216), added: 5691, mode: `MaxEncodedLen`)
+	// Storage: `Dex::SwapCommits` (r:50 w:50)
+	// Proof: `Dex::SwapCommits` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:51 w:51)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn on_initialize(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1095 + n * (212 ±0)`
+		//  Estimated: `6681 + n * (2603 ±0)`
+		// Minimum execution time: 5_874 nanoseconds.
+		Weight::from_parts(6_312_000, 6681)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_parts(29_846_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	fn pause_trading_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `3660`
+		// Minimum execution time: 17_512 nanoseconds.
+		Weight::from_parts(18_174_000, 3660)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	fn unpause_trading_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `3660`
+		// Minimum execution time: 17_390 nanoseconds.
+		Weight::from_parts(18_035_000, 3660)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ProvisioningPool` (r:1 w:0)
+	// Proof: `Dex::ProvisioningPool` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LbpPools` (r:2 w:1)
+	// Proof: `Dex::LbpPools` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	fn create_lbp() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1212`
+		//  Estimated: `6226`
+		// Minimum execution time: 33_207 nanoseconds.
+		Weight::from_parts(35_412_000, 6226)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Dex::LbpPools` (r:1 w:1)
+	// Proof: `Dex::LbpPools` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fund_lbp() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1863`
+		//  Estimated: `6196`
+		// Minimum execution time: 94_118 nanoseconds.
+		Weight::from_parts(98_236_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: `Dex::LbpPools` (r:1 w:1)
+	// Proof: `Dex::LbpPools` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn swap_with_lbp() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2051`
+		//  Estimated: `6196`
+		// Minimum execution time: 137_694 nanoseconds.
+		Weight::from_parts(142_870_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: `Dex::LbpPools` (r:1 w:1)
+	// Proof: `Dex::LbpPools` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn exit_lbp() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2051`
+		//  Estimated: `6196`
+		// Minimum execution time: 88_035 nanoseconds.
+		Weight::from_parts(91_504_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: `Dex::LbpPools` (r:11 w:10)
+	// Proof: `Dex::LbpPools` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:10 w:10)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairListings` (r:10 w:10)
+	// Proof: `Dex::TradingPairListings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::LiquidityPool` (r:10 w:10)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:10 w:10)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:50 w:50)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:20 w:20)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 10]`.
+	fn lbp_on_initialize(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1095 + n * (1284 ±0)`
+		//  Estimated: `3990 + n * (13110 ±0)`
+		// Minimum execution time: 4_712 nanoseconds.
+		Weight::from_parts(4_981_000, 3990)
+			// Standard Error: 64_000
+			.saturating_add(Weight::from_parts(263_455_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((13_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((12_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 13110).saturating_mul(n.into()))
+	}
 }
@@ -16,26 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for module_earning
+//! Placeholder weights for module_earning
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 35.0.1
-//! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `ip-172-31-38-126`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
-//! WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// target/production/acala
-// benchmark
-// pallet
-// --chain=dev
-// --steps=50
-// --repeat=20
-// --pallet=*
-// --extrinsic=*
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=./templates/runtime-weight-template.hbs
-// --output=./runtime/mandala/src/weights/
+//! These weights were not benchmarked on acala or karura. They are copied from the
+//! mandala dev benchmark and must be regenerated with `benchmark pallet` for each
+//! chain before this pallet is relied upon on a live chain.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -58,10 +43,6 @@ impl<T: frame_system::Config> module_earning::WeightInfo for WeightInfo<T> {
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn bond() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2293`
-		//  Estimated: `5758`
-		// Minimum execution time: 45_177 nanoseconds.
 		Weight::from_parts(46_166_000, 5758)
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
@@ -79,10 +60,6 @@ impl<T: frame_system::Config> module_earning::WeightInfo for WeightInfo<T> {
 	// Storage: `Rewards::PoolInfos` (r:1 w:1)
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn unbond_instant() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2989`
-		//  Estimated: `6454`
-		// Minimum execution time: 76_877 nanoseconds.
 		Weight::from_parts(78_176_000, 6454)
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
@@ -98,10 +75,6 @@ impl<T: frame_system::Config> module_earning::WeightInfo for WeightInfo<T> {
 	// Storage: `Rewards::PoolInfos` (r:1 w:1)
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn unbond() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2630`
-		//  Estimated: `6095`
-		// Minimum execution time: 50_770 nanoseconds.
 		Weight::from_parts(51_535_000, 6095)
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
@@ -117,10 +90,6 @@ impl<T: frame_system::Config> module_earning::WeightInfo for WeightInfo<T> {
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn rebond() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2882`
-		//  Estimated: `6347`
-		// Minimum execution time: 50_247 nanoseconds.
 		Weight::from_parts(51_269_000, 6347)
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
@@ -132,10 +101,6 @@ impl<T: frame_system::Config> module_earning::WeightInfo for WeightInfo<T> {
 	// Storage: `Balances::Freezes` (r:1 w:0)
 	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn withdraw_unbonded() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2532`
-		//  Estimated: `5997`
-		// Minimum execution time: 43_089 nanoseconds.
 		Weight::from_parts(44_412_000, 5997)
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
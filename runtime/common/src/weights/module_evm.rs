@@ -119,14 +119,16 @@ impl<T: frame_system::Config> module_evm::WeightInfo for WeightInfo<T> {
 	// Proof: `EVM::ContractStorageSizes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `EVM::Codes` (r:0 w:1)
 	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::SystemContracts` (r:1 w:1)
+	// Proof: `EVM::SystemContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_nft_contract() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1627`
 		//  Estimated: `7567`
 		// Minimum execution time: 151_417 nanoseconds.
 		Weight::from_parts(155_163_000, 7567)
-			.saturating_add(T::DbWeight::get().reads(13))
-			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	// Storage: `EVM::Accounts` (r:2 w:2)
 	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -144,14 +146,16 @@ impl<T: frame_system::Config> module_evm::WeightInfo for WeightInfo<T> {
 	// Proof: `EVM::ContractStorageSizes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `EVM::Codes` (r:0 w:1)
 	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::SystemContracts` (r:1 w:1)
+	// Proof: `EVM::SystemContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_predeploy_contract() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1575`
 		//  Estimated: `7515`
 		// Minimum execution time: 149_920 nanoseconds.
 		Weight::from_parts(152_354_000, 7515)
-			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
 	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
@@ -296,4 +300,37 @@ impl<T: frame_system::Config> module_evm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(8))
 	}
+	// Storage: `EVM::PendingTransactionIndex` (r:1 w:1)
+	// Proof: `EVM::PendingTransactionIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `System::ExtrinsicData` (r:100 w:0)
+	// Proof: `System::ExtrinsicData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::BlockTransactionIndex` (r:0 w:1)
+	// Proof: `EVM::BlockTransactionIndex` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn on_finalize_with_transaction_index(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1073 + n * (268 ±0)`
+		//  Estimated: `2558 + n * (2743 ±0)`
+		// Minimum execution time: 4_318 nanoseconds.
+		Weight::from_parts(5_206_713, 2558)
+			// Standard Error: 1_904
+			.saturating_add(Weight::from_parts(11_732_418, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 2743).saturating_mul(n.into()))
+	}
+	// Storage: `EVM::Accounts` (r:1 w:0)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::SystemContracts` (r:0 w:1)
+	// Proof: `EVM::SystemContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_system_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1206`
+		//  Estimated: `4671`
+		// Minimum execution time: 21_305 nanoseconds.
+		Weight::from_parts(21_847_000, 4671)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
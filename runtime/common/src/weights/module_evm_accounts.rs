@@ -79,4 +79,19 @@ impl<T: frame_system::Config> module_evm_accounts::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: `EvmAccounts::LaneNonces` (r:1 w:1)
+	// Proof: `EvmAccounts::LaneNonces` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::NonceLanes` (r:0 w:1)
+	// Proof: `EvmAccounts::NonceLanes` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn set_nonce_lanes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `3593`
+		// Minimum execution time: 15_874 nanoseconds.
+		Weight::from_parts(16_302_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
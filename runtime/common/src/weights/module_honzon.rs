@@ -22,6 +22,9 @@
 //! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `ip-172-31-41-141`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
 //! WASM-EXECUTION: Compiled, CHAIN: Some("acala-dev"), DB CACHE: 1024
+//!
+//! The weights after the placeholder note at the end of `WeightInfo` were not produced by the
+//! benchmark CLI.
 
 // Executed Command:
 // target/production/acala
@@ -159,61 +162,6 @@ impl<T: frame_system::Config> module_honzon::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(16))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
-	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
-	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	// Storage: `Loans::Positions` (r:1 w:1)
-	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
-	// Storage: `Prices::LockedPrice` (r:2 w:0)
-	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `AcalaOracle::Values` (r:1 w:0)
-	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
-	// Storage: `AssetRegistry::AssetMetadatas` (r:3 w:0)
-	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
-	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
-	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
-	// Storage: `Tokens::Accounts` (r:10 w:10)
-	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
-	// Storage: `System::Account` (r:4 w:2)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
-	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
-	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
-	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
-	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `Rewards::PoolInfos` (r:1 w:1)
-	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `Loans::TotalPositions` (r:1 w:1)
-	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
-	// Storage: `AuctionManager::TotalCollateralInAuction` (r:1 w:0)
-	// Proof: `AuctionManager::TotalCollateralInAuction` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `Dex::TradingPairStatuses` (r:4 w:0)
-	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
-	// Storage: `Dex::LiquidityPool` (r:4 w:1)
-	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
-	// Storage: `StableAsset::Pools` (r:2 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `AggregatedDex::AggregatedSwapPaths` (r:1 w:0)
-	// Proof: `AggregatedDex::AggregatedSwapPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
-	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Homa::ToBondPool` (r:1 w:0)
-	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Tokens::TotalIssuance` (r:2 w:1)
-	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
-	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn close_loan_has_debit_by_dex() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `5774`
-		//  Estimated: `27210`
-		// Minimum execution time: 501_256 nanoseconds.
-		Weight::from_parts(510_899_000, 27210)
-			.saturating_add(T::DbWeight::get().reads(46))
-			.saturating_add(T::DbWeight::get().writes(20))
-	}
 	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
 	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
 	// Storage: `Tokens::Accounts` (r:4 w:4)
@@ -357,4 +305,230 @@ impl<T: frame_system::Config> module_honzon::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(41_163_000, 7957)
 			.saturating_add(T::DbWeight::get().reads(11))
 	}
+	// Placeholder weights: the entries below were not produced by the benchmark CLI. They are
+	// hand-estimated from comparable extrinsics and must be regenerated with `benchmark pallet`.
+	fn adjust_loan_no_op() -> Weight {
+		Weight::from_parts(5_279_000, 0)
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:1 w:1)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:3 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:10 w:10)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:4 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::TotalCollateralInAuction` (r:1 w:0)
+	// Proof: `AuctionManager::TotalCollateralInAuction` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:4 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:4 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `StableAsset::Pools` (r:2 w:1)
+	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AggregatedDex::AggregatedSwapPaths` (r:1 w:0)
+	// Proof: `AggregatedDex::AggregatedSwapPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
+	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::ToBondPool` (r:1 w:0)
+	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::TotalIssuance` (r:2 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
+	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::LiquidationStatistics` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationStatistics` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::LiquidationHistory` (r:1 w:1)
+	// Proof: `CdpEngine::LiquidationHistory` (`max_values`: None, `max_size`: Some(2001), added: 4476, mode: `MaxEncodedLen`)
+	fn close_loan_has_debit_by_dex() -> Weight {
+		Weight::from_parts(510_899_000, 27210)
+			.saturating_add(T::DbWeight::get().reads(48))
+			.saturating_add(T::DbWeight::get().writes(22))
+	}
+	// Storage: `Honzon::TopUpVouchers` (r:0 w:1)
+	// Proof: `Honzon::TopUpVouchers` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	fn register_topup_voucher() -> Weight {
+		Weight::from_parts(15_832_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Honzon::TopUpVouchers` (r:1 w:1)
+	// Proof: `Honzon::TopUpVouchers` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	fn cancel_topup_voucher() -> Weight {
+		Weight::from_parts(19_513_000, 3576)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Honzon::TopUpVouchers` (r:1 w:1)
+	// Proof: `Honzon::TopUpVouchers` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:1 w:1)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:2 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	fn execute_topup_voucher() -> Weight {
+		Weight::from_parts(123_957_000, 9787)
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: `Honzon::TopUpVouchers` (r:1 w:1)
+	// Proof: `Honzon::TopUpVouchers` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	fn remove_expired_topup_voucher() -> Weight {
+		Weight::from_parts(19_925_000, 3576)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Honzon::PositionAlerts` (r:1 w:1)
+	// Proof: `Honzon::PositionAlerts` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	fn set_position_alert() -> Weight {
+		Weight::from_parts(17_402_000, 3643)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Honzon::PositionAlerts` (r:1 w:1)
+	// Proof: `Honzon::PositionAlerts` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	fn clear_position_alert() -> Weight {
+		Weight::from_parts(18_561_000, 3643)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:9 w:8)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:0)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[2, 8]`.
+	fn split_loan(p: u32, ) -> Weight {
+		Weight::from_parts(61_874_219, 6983)
+			.saturating_add(Weight::from_parts(34_905_671, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2610).saturating_mul(p.into()))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:9 w:8)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitRebases` (r:1 w:0)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:1 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:1 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Loans::TotalPositions` (r:1 w:1)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 7]`.
+	fn merge_loans(p: u32, ) -> Weight {
+		Weight::from_parts(57_093_486, 6829)
+			.saturating_add(Weight::from_parts(32_718_204, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2610).saturating_mul(p.into()))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:2 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `SafeMode::State` (r:1 w:0)
+	// Proof: `SafeMode::State` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	// Storage: `Loans::Positions` (r:2 w:2)
+	// Proof: `Loans::Positions` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:2 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:2 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::TradingPairStatuses` (r:3 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:3 w:2)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `StableAsset::Pools` (r:2 w:0)
+	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AggregatedDex::AggregatedSwapPaths` (r:1 w:0)
+	// Proof: `AggregatedDex::AggregatedSwapPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:5 w:5)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitExchangeRate` (r:2 w:0)
+	// Proof: `CdpEngine::DebitExchangeRate` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:2 w:2)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:2 w:2)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Loans::TotalPositions` (r:2 w:2)
+	// Proof: `Loans::TotalPositions` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::IssuanceByOrigin` (r:2 w:2)
+	// Proof: `CdpTreasury::IssuanceByOrigin` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::DebitRebases` (r:2 w:0)
+	// Proof: `CdpEngine::DebitRebases` (`max_values`: None, `max_size`: Some(596), added: 3071, mode: `MaxEncodedLen`)
+	fn shift_loan_collateral() -> Weight {
+		Weight::from_parts(266_204_000, 16782)
+			.saturating_add(T::DbWeight::get().reads(39))
+			.saturating_add(T::DbWeight::get().writes(20))
+	}
 }
@@ -0,0 +1,415 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for module_incentives
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 35.0.1
+//! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `ip-172-31-41-141`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! WASM-EXECUTION: Compiled, CHAIN: Some("acala-dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/production/acala
+// benchmark
+// pallet
+// --chain=acala-dev
+// --steps=50
+// --repeat=20
+// --pallet=*
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=./templates/runtime-weight-template.hbs
+// --output=./runtime/acala/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_incentives.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:5 w:0)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::IncentiveRewardAmounts` (r:8 w:0)
+	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::DepositProtections` (r:1 w:0)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::RandomizedAccumulatedWindows` (r:1 w:0)
+	// Proof: `Incentives::RandomizedAccumulatedWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 4]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1301 + c * (206 ±0)`
+		//  Estimated: `4788 + c * (5029 ±5)`
+		// Minimum execution time: 8_714 nanoseconds.
+		Weight::from_parts(12_404_068, 4788)
+			// Standard Error: 119_191
+			.saturating_add(Weight::from_parts(16_393_891, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5029).saturating_mul(c.into()))
+	}
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::DepositProtections` (r:1 w:0)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingSchedule` (r:0 w:1)
+	// Proof: `Incentives::MaturingSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deposit_dex_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1873`
+		//  Estimated: `6234`
+		// Minimum execution time: 53_114 nanoseconds.
+		Weight::from_parts(54_442_000, 6234)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	fn deposit_dex_share_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_853 nanoseconds.
+		Weight::from_parts(4_998_000, 0)
+	}
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::WithdrawalDelays` (r:1 w:0)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn withdraw_dex_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1880`
+		//  Estimated: `6234`
+		// Minimum execution time: 54_824 nanoseconds.
+		Weight::from_parts(55_922_000, 6234)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn withdraw_dex_share_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_912 nanoseconds.
+		Weight::from_parts(5_059_000, 0)
+	}
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:1)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionRates` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionRates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionCurrency` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionCurrency` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn claim_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1925`
+		//  Estimated: `5390`
+		// Minimum execution time: 82_338 nanoseconds.
+		Weight::from_parts(84_160_000, 5390)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:0)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:0)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn claim_rewards_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1124`
+		//  Estimated: `4589`
+		// Minimum execution time: 10_375 nanoseconds.
+		Weight::from_parts(10_686_000, 4589)
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	// Storage: `Incentives::IncentiveRewardAmounts` (r:4 w:4)
+	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 4]`.
+	fn update_incentive_rewards(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `759 + c * (93 ±0)`
+		//  Estimated: `2029 + c * (2475 ±0)`
+		// Minimum execution time: 4_107 nanoseconds.
+		Weight::from_parts(6_747_378, 2029)
+			// Standard Error: 84_068
+			.saturating_add(Weight::from_parts(6_365_252, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(c.into()))
+	}
+	// Storage: `Incentives::ClaimRewardDeductionRates` (r:4 w:4)
+	// Proof: `Incentives::ClaimRewardDeductionRates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 4]`.
+	fn update_claim_reward_deduction_rates(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `676 + c * (10 ±0)`
+		//  Estimated: `1698 + c * (2475 ±0)`
+		// Minimum execution time: 4_097 nanoseconds.
+		Weight::from_parts(4_885_406, 1698)
+			// Standard Error: 17_876
+			.saturating_add(Weight::from_parts(1_882_029, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(c.into()))
+	}
+	// Storage: `Incentives::ClaimRewardDeductionCurrency` (r:1 w:1)
+	// Proof: `Incentives::ClaimRewardDeductionCurrency` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_claim_reward_deduction_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1039`
+		//  Estimated: `4504`
+		// Minimum execution time: 14_806 nanoseconds.
+		Weight::from_parts(15_373_000, 4504)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::ClaimRewardVestings` (r:0 w:1)
+	// Proof: `Incentives::ClaimRewardVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_claim_reward_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_152 nanoseconds.
+		Weight::from_parts(7_481_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::VestingRewards` (r:1 w:1)
+	// Proof: `Incentives::VestingRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn claim_vested_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2418`
+		//  Estimated: `6244`
+		// Minimum execution time: 78_365 nanoseconds.
+		Weight::from_parts(80_517_000, 6244)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Incentives::DepositProtections` (r:1 w:1)
+	// Proof: `Incentives::DepositProtections` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::RandomizedAccumulatedWindows` (r:0 w:1)
+	// Proof: `Incentives::RandomizedAccumulatedWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_deposit_protection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `4508`
+		// Minimum execution time: 12_358 nanoseconds.
+		Weight::from_parts(12_904_000, 4508)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Incentives::ClaimPreferences` (r:0 w:1)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_claim_preference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_815 nanoseconds.
+		Weight::from_parts(10_236_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:1)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionRates` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionRates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionCurrency` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionCurrency` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardVestings` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardVestings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:2 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:2 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn claim_rewards_with_conversion() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3845`
+		//  Estimated: `9083`
+		// Minimum execution time: 241_097 nanoseconds.
+		Weight::from_parts(247_560_000, 9083)
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: `Incentives::MaturingSchedule` (r:100 w:100)
+	// Proof: `Incentives::MaturingSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::MaturingDeposits` (r:100 w:100)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:100 w:100)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 100]`.
+	fn mature_deposits(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1208 + c * (262 ±0)`
+		//  Estimated: `4673 + c * (2737 ±0)`
+		// Minimum execution time: 5_124 nanoseconds.
+		Weight::from_parts(6_031_220, 4673)
+			// Standard Error: 21_472
+			.saturating_add(Weight::from_parts(28_417_936, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2737).saturating_mul(c.into()))
+	}
+	// Storage: `Incentives::WithdrawalDelays` (r:0 w:1)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_withdrawal_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_642 nanoseconds.
+		Weight::from_parts(10_011_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:1)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::WithdrawalDelays` (r:1 w:0)
+	// Proof: `Incentives::WithdrawalDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingWithdrawals` (r:1 w:1)
+	// Proof: `Incentives::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_withdraw_dex_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1673`
+		//  Estimated: `5138`
+		// Minimum execution time: 38_716 nanoseconds.
+		Weight::from_parts(39_804_000, 5138)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Incentives::PendingWithdrawals` (r:1 w:1)
+	// Proof: `Incentives::PendingWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn execute_withdraw_dex_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1562`
+		//  Estimated: `6234`
+		// Minimum execution time: 41_285 nanoseconds.
+		Weight::from_parts(42_370_000, 6234)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `Incentives::RetiringPools` (r:1 w:1)
+	// Proof: `Incentives::RetiringPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:0)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::IncentiveRewardAmounts` (r:2 w:1)
+	// Proof: `Incentives::IncentiveRewardAmounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn retire_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6652`
+		// Minimum execution time: 26_318 nanoseconds.
+		Weight::from_parts(27_105_000, 6652)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Incentives::RetiringPools` (r:1 w:1)
+	// Proof: `Incentives::RetiringPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:51 w:50)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:51 w:51)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:50 w:50)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Incentives::MaturingDeposits` (r:1 w:0)
+	// Proof: `Incentives::MaturingDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:0)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimPreferences` (r:1 w:0)
+	// Proof: `Incentives::ClaimPreferences` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 50]`.
+	fn clean_retired_pool(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1394 + c * (318 ±0)`
+		//  Estimated: `4859 + c * (2793 ±0)`
+		// Minimum execution time: 14_772 nanoseconds.
+		Weight::from_parts(16_250_410, 4859)
+			// Standard Error: 24_106
+			.saturating_add(Weight::from_parts(36_904_215, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2793).saturating_mul(c.into()))
+	}
+}
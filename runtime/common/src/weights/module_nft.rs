@@ -183,4 +183,107 @@ impl<T: frame_system::Config> module_nft::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `OrmlNFT::Classes` (r:1 w:1)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::NextTokenId` (r:1 w:1)
+	// Proof: `OrmlNFT::NextTokenId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:100 w:100)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::Tokens` (r:0 w:100)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::TokensByOwner` (r:0 w:100)
+	// Proof: `OrmlNFT::TokensByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 100]`.
+	fn mint_airdrop(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2903`
+		//  Estimated: `6368`
+		// Minimum execution time: 128_417 nanoseconds.
+		Weight::from_parts(21_436_000, 6368)
+			// Standard Error: 52_000
+			.saturating_add(Weight::from_parts(117_086_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2643).saturating_mul(n.into()))
+	}
+	// Storage: `OrmlNFT::Classes` (r:1 w:0)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `NFT::NextAirdropId` (r:1 w:1)
+	// Proof: `NFT::NextAirdropId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::NextTaskId` (r:1 w:1)
+	// Proof: `IdleScheduler::NextTaskId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `NFT::Airdrops` (r:0 w:1)
+	// Proof: `NFT::Airdrops` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `IdleScheduler::Tasks` (r:0 w:1)
+	// Proof: `IdleScheduler::Tasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `NFT::AirdropRecipients` (r:0 w:1000)
+	// Proof: `NFT::AirdropRecipients` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn schedule_airdrop(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2339`
+		//  Estimated: `5804`
+		// Minimum execution time: 66_052 nanoseconds.
+		Weight::from_parts(61_218_000, 5804)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(4_872_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	// Storage: `NFT::Airdrops` (r:1 w:1)
+	// Proof: `NFT::Airdrops` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `NFT::AirdropRecipients` (r:0 w:1000)
+	// Proof: `NFT::AirdropRecipients` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn cancel_airdrop(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2781`
+		//  Estimated: `6246`
+		// Minimum execution time: 46_119 nanoseconds.
+		Weight::from_parts(42_517_000, 6246)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(2_946_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	// Storage: `NFT::Airdrops` (r:1 w:1)
+	// Proof: `NFT::Airdrops` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::Classes` (r:1 w:1)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `NFT::AirdropRecipients` (r:100 w:100)
+	// Proof: `NFT::AirdropRecipients` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::NextTokenId` (r:100 w:100)
+	// Proof: `OrmlNFT::NextTokenId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:100 w:100)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:100 w:200)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::Tokens` (r:0 w:100)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::TokensByOwner` (r:0 w:100)
+	// Proof: `OrmlNFT::TokensByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn process_airdrop(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2781`
+		//  Estimated: `6246`
+		// Minimum execution time: 24_836 nanoseconds.
+		Weight::from_parts(23_104_000, 6246)
+			// Standard Error: 61_000
+			.saturating_add(Weight::from_parts(124_731_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2643).saturating_mul(n.into()))
+	}
 }
@@ -0,0 +1,167 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_oracle_operator
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_oracle_operator.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_oracle_operator::WeightInfo for WeightInfo<T> {
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::Bonds` (r:1 w:1)
+	// Proof: `OracleOperator::Bonds` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn bond() -> Weight {
+		Weight::from_parts(41_253_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `OracleOperator::Bonds` (r:1 w:1)
+	// Proof: `OracleOperator::Bonds` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn unbond() -> Weight {
+		Weight::from_parts(19_874_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `OracleOperator::Bonds` (r:1 w:1)
+	// Proof: `OracleOperator::Bonds` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_unbonded() -> Weight {
+		Weight::from_parts(40_618_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `OracleOperator::Bonds` (r:1 w:1)
+	// Proof: `OracleOperator::Bonds` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn slash() -> Weight {
+		Weight::from_parts(45_902_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `OperatorMembershipAcala::Members` (r:1 w:0)
+	// Proof: `OperatorMembershipAcala::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::Bonds` (r:1 w:0)
+	// Proof: `OracleOperator::Bonds` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::RawValues` (r:0 w:1)
+	// Proof: `AcalaOracle::RawValues` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::LastPrimaryFeed` (r:0 w:1)
+	// Proof: `OracleOperator::LastPrimaryFeed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::Failovers` (r:1 w:1)
+	// Proof: `OracleOperator::Failovers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 10]`.
+	fn feed_values(c: u32, ) -> Weight {
+		Weight::from_parts(20_837_000, 4687)
+			.saturating_add(Weight::from_parts(10_284_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(c.into()))
+	}
+	// Storage: `OperatorMembershipAcala::Members` (r:1 w:1)
+	// Proof: `OperatorMembershipAcala::Members` (`max_values`: Some(1), `max_size`: Some(1601), added: 2096, mode: `MaxEncodedLen`)
+	// Storage: `OperatorMembershipAcala::Prime` (r:1 w:0)
+	// Proof: `OperatorMembershipAcala::Prime` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::RawValues` (r:0 w:10)
+	// Proof: `AcalaOracle::RawValues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OracleOperator::MinimumCountRelaxedUntil` (r:0 w:1)
+	// Proof: `OracleOperator::MinimumCountRelaxedUntil` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `OracleOperator::BackupFeeders` (r:0 w:10)
+	// Proof: `OracleOperator::BackupFeeders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::LastPrimaryFeed` (r:0 w:10)
+	// Proof: `OracleOperator::LastPrimaryFeed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::Failovers` (r:0 w:10)
+	// Proof: `OracleOperator::Failovers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 10]`.
+	/// The range of component `a` is `[0, 10]`.
+	fn rotate_operators(r: u32, a: u32, ) -> Weight {
+		Weight::from_parts(21_384_000, 3086)
+			.saturating_add(Weight::from_parts(38_516_000, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(24_941_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((15_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(r.into()))
+	}
+	// Storage: `OperatorMembershipAcala::Members` (r:1 w:0)
+	// Proof: `OperatorMembershipAcala::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::BackupFeeders` (r:1 w:1)
+	// Proof: `OracleOperator::BackupFeeders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn register_backup_feeder() -> Weight {
+		Weight::from_parts(17_462_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `OracleOperator::BackupFeeders` (r:1 w:1)
+	// Proof: `OracleOperator::BackupFeeders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::Failovers` (r:1 w:1)
+	// Proof: `OracleOperator::Failovers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn revoke_backup_feeder() -> Weight {
+		Weight::from_parts(15_937_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `OperatorMembershipAcala::Members` (r:1 w:0)
+	// Proof: `OperatorMembershipAcala::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::BackupFeeders` (r:1 w:0)
+	// Proof: `OracleOperator::BackupFeeders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::Bonds` (r:1 w:0)
+	// Proof: `OracleOperator::Bonds` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::LastPrimaryFeed` (r:1 w:0)
+	// Proof: `OracleOperator::LastPrimaryFeed` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::RawValues` (r:0 w:1)
+	// Proof: `AcalaOracle::RawValues` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `OracleOperator::Failovers` (r:1 w:1)
+	// Proof: `OracleOperator::Failovers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 10]`.
+	fn feed_values_as_backup(c: u32, ) -> Weight {
+		Weight::from_parts(21_518_000, 4687)
+			.saturating_add(Weight::from_parts(10_284_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(c.into()))
+	}
+}
@@ -53,6 +53,8 @@ impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
 	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Prices::LockedPrice` (r:0 w:1)
 	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Prices::TimedPriceLocks` (r:0 w:1)
+	// Proof: `Prices::TimedPriceLocks` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	fn lock_price() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1422`
@@ -60,10 +62,12 @@ impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_614 nanoseconds.
 		Weight::from_parts(24_266_000, 4887)
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	// Storage: `Prices::LockedPrice` (r:1 w:1)
 	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Prices::TimedPriceLocks` (r:0 w:1)
+	// Proof: `Prices::TimedPriceLocks` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	fn unlock_price() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1123`
@@ -71,6 +75,51 @@ impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 17_142 nanoseconds.
 		Weight::from_parts(17_897_000, 3532)
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Prices::PricingModes` (r:0 w:1)
+	// Proof: `Prices::PricingModes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn set_pricing_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1026`
+		//  Estimated: `0`
+		// Minimum execution time: 10_512 nanoseconds.
+		Weight::from_parts(10_984_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `Prices::LockedPrice` (r:1 w:1)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Prices::TimedPriceLocks` (r:1 w:1)
+	// Proof: `Prices::TimedPriceLocks` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:1 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn lock_price_at() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1422`
+		//  Estimated: `4887`
+		// Minimum execution time: 23_817 nanoseconds.
+		Weight::from_parts(24_431_000, 4887)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Prices::TimedPriceLocks` (r:{n+1} w:{n})
+	// Proof: `Prices::TimedPriceLocks` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:0 w:{n})
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 20]`.
+	fn on_initialize(n: u32,) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1026 + n * (83 ±0)`
+		//  Estimated: `3524 + n * (2534 ±0)`
+		// Minimum execution time: 4_216 nanoseconds.
+		Weight::from_parts(4_578_000, 3524)
+			// Standard Error: 6_000
+			.saturating_add(Weight::from_parts(11_742_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2534).saturating_mul(n.into()))
+	}
 }
@@ -0,0 +1,66 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_scheduler_index
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_scheduler_index.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_scheduler_index::WeightInfo for WeightInfo<T> {
+	// Storage: `SchedulerIndex::ScheduledByHash` (r:11 w:10)
+	// Proof: `SchedulerIndex::ScheduledByHash` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	// Storage: `Scheduler::Agenda` (r:10 w:10)
+	// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	// Storage: `Scheduler::Lookup` (r:0 w:10)
+	// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 10]`.
+	fn cancel_scheduled_by_hash(c: u32, ) -> Weight {
+		Weight::from_parts(17_640_515, 3517)
+			.saturating_add(Weight::from_parts(27_105_774, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41438).saturating_mul(c.into()))
+	}
+	// Storage: `SchedulerIndex::PruneCursor` (r:1 w:1)
+	// Proof: `SchedulerIndex::PruneCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	// Storage: `SchedulerIndex::ScheduledByHash` (r:101 w:100)
+	// Proof: `SchedulerIndex::ScheduledByHash` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	// Storage: `Scheduler::Agenda` (r:100 w:0)
+	// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn prune_stale_entries(n: u32, ) -> Weight {
+		Weight::from_parts(5_372_942, 3517)
+			.saturating_add(Weight::from_parts(8_806_352, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 41438).saturating_mul(n.into()))
+	}
+}
@@ -124,6 +124,13 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 		// Minimum execution time: 8_375 nanoseconds.
 		Weight::from_parts(8_631_000, 0)
 	}
+	fn with_fee_preference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `666`
+		//  Estimated: `0`
+		// Minimum execution time: 8_402 nanoseconds.
+		Weight::from_parts(8_687_000, 0)
+	}
 	fn with_fee_aggregated_path() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `666`
@@ -131,6 +138,71 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 		// Minimum execution time: 8_795 nanoseconds.
 		Weight::from_parts(9_066_000, 0)
 	}
+	// Storage: `TransactionPayment::FeeSpendTracking` (r:1 w:1)
+	// Proof: `TransactionPayment::FeeSpendTracking` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn enable_fee_spend_tracking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1438`
+		//  Estimated: `3906`
+		// Minimum execution time: 13_617 nanoseconds.
+		Weight::from_parts(14_054_000, 3906)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `TransactionPayment::FeeSpendTracking` (r:1 w:1)
+	// Proof: `TransactionPayment::FeeSpendTracking` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPayment::FeeSpends` (r:0 w:12)
+	// Proof: `TransactionPayment::FeeSpends` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[1, 12]`.
+	fn disable_fee_spend_tracking(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1455 + p * (4 ±0)`
+		//  Estimated: `3923 + p * (4 ±0)`
+		// Minimum execution time: 15_928 nanoseconds.
+		Weight::from_parts(15_541_813, 3923)
+			// Standard Error: 3_127
+			.saturating_add(Weight::from_parts(1_648_202, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 4).saturating_mul(p.into()))
+	}
+	// Storage: `TransactionPayment::TokenExchangeRate` (r:1 w:0)
+	// Proof: `TransactionPayment::TokenExchangeRate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPayment::FeePoolRebalanceParams` (r:0 w:1)
+	// Proof: `TransactionPayment::FeePoolRebalanceParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_fee_pool_rebalance_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1131`
+		//  Estimated: `3599`
+		// Minimum execution time: 14_108 nanoseconds.
+		Weight::from_parts(14_512_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `TransactionPayment::FeePoolRebalanceParams` (r:1 w:0)
+	// Proof: `TransactionPayment::FeePoolRebalanceParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPayment::PoolSize` (r:1 w:0)
+	// Proof: `TransactionPayment::PoolSize` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPayment::FeePoolRebalances` (r:1 w:1)
+	// Proof: `TransactionPayment::FeePoolRebalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn rebalance_fee_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2140`
+		//  Estimated: `8070`
+		// Minimum execution time: 96_214 nanoseconds.
+		Weight::from_parts(98_736_000, 8070)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
 	// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:1)
 	// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn on_finalize() -> Weight {
@@ -20,7 +20,11 @@
 
 use frame_support::{traits::Get, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
 use module_support::BuyWeightRate;
-use orml_traits::GetByKey;
+use module_xcm_interface_runtime_api::TransferFeeBreakdown;
+use orml_traits::{
+	location::{Parse, Reserve},
+	GetByKey,
+};
 use parity_scale_codec::Encode;
 use primitives::{evm::EvmAddress, Balance, CurrencyId};
 use sp_core::bounded::BoundedVec;
@@ -29,7 +33,7 @@ use sp_std::{marker::PhantomData, prelude::*};
 use xcm::v4::{prelude::*, Assets, Weight as XcmWeight};
 use xcm_builder::TakeRevenue;
 use xcm_executor::{
-	traits::{DropAssets, WeightTrader, XcmAssetTransfers},
+	traits::{DropAssets, WeightBounds, WeightTrader, XcmAssetTransfers},
 	AssetsInHolding,
 };
 
@@ -275,6 +279,141 @@ impl<Config: xcm_executor::Config, AccountId, Balance, AccountIdConvert, EVMBrid
 	type AssetTransactor = Config::AssetTransactor;
 }
 
+/// Estimates the fees of transferring `amount` of `currency_id` to `dest` via `orml_xtokens`.
+///
+/// The XCM is built the same way `orml_xtokens` builds it for a single asset transfer. The local
+/// weight is priced at the rate of the XCM `Trader` and the delivery fee is quoted by the XCM
+/// router, the remote execution fee is the parachain fee configured in `module_xcm_interface`.
+///
+/// NOTE: the trader collects the quoted fee as revenue when dropped, only call this outside of
+/// block execution, e.g. from a runtime API.
+pub fn estimate_transfer_fee<T, XcmConfig>(
+	currency_id: CurrencyId,
+	amount: Balance,
+	dest: Location,
+) -> Option<TransferFeeBreakdown<Balance>>
+where
+	T: orml_xtokens::Config<CurrencyId = CurrencyId, Balance = Balance> + module_xcm_interface::Config,
+	XcmConfig: xcm_executor::Config,
+{
+	let asset: Asset = (T::CurrencyIdConvert::convert(currency_id)?, amount).into();
+	let dest_chain = dest.chain_part()?;
+	let recipient = dest.non_chain_part()?;
+	let reserve = T::ReserveProvider::reserve(&asset)?;
+	let context = T::UniversalLocation::get();
+
+	let deposit_to_recipient = |at: &Location| -> Option<Xcm<()>> {
+		Some(Xcm(vec![
+			BuyExecution {
+				fees: asset.clone().reanchored(at, &context).ok()?,
+				weight_limit: Unlimited,
+			},
+			DepositAsset {
+				assets: AllCounted(1).into(),
+				beneficiary: recipient.clone(),
+			},
+		]))
+	};
+
+	let (mut local_xcm, next_hop, remote_xcm, remote_chains) = if reserve == T::SelfLocation::get() {
+		// transfer self reserve asset
+		let xcm = deposit_to_recipient(&dest_chain)?;
+		let assets: Assets = asset.clone().reanchored(&dest_chain, &context).ok()?.into();
+		(
+			Xcm(vec![TransferReserveAsset {
+				assets: asset.clone().into(),
+				dest: dest_chain.clone(),
+				xcm: xcm.clone(),
+			}]),
+			dest_chain.clone(),
+			Xcm([ReserveAssetDeposited(assets), ClearOrigin]
+				.into_iter()
+				.chain(xcm.0)
+				.collect()),
+			vec![dest_chain],
+		)
+	} else {
+		let (xcm, remote_chains) = if reserve == dest_chain {
+			// transfer to reserve
+			(deposit_to_recipient(&dest_chain)?, vec![dest_chain])
+		} else {
+			// transfer to non-reserve
+			(
+				Xcm(vec![
+					BuyExecution {
+						fees: asset.clone().reanchored(&reserve, &context).ok()?,
+						weight_limit: Unlimited,
+					},
+					DepositReserveAsset {
+						assets: AllCounted(1).into(),
+						dest: dest_chain.clone().reanchored(&reserve, &context).ok()?,
+						xcm: deposit_to_recipient(&dest_chain)?,
+					},
+				]),
+				vec![reserve.clone(), dest_chain],
+			)
+		};
+		let assets: Assets = asset.clone().reanchored(&reserve, &context).ok()?.into();
+		(
+			Xcm(vec![
+				WithdrawAsset(asset.clone().into()),
+				InitiateReserveWithdraw {
+					assets: AllCounted(1).into(),
+					reserve: reserve.clone(),
+					xcm: xcm.clone(),
+				},
+			]),
+			reserve,
+			Xcm([WithdrawAsset(assets), ClearOrigin].into_iter().chain(xcm.0).collect()),
+			remote_chains,
+		)
+	};
+
+	// fees are charged in the asset as seen by the local chain
+	let fee_asset_id = asset.id.clone().reanchored(&Location::here(), &context).ok()?;
+	let local_execution_weight = T::Weigher::weight(&mut local_xcm).ok()?;
+	let local_execution_fee = {
+		let mut trader = XcmConfig::Trader::new();
+		let payment: AssetsInHolding = Asset {
+			id: fee_asset_id.clone(),
+			fun: Fungible(Balance::MAX),
+		}
+		.into();
+		let unused = trader
+			.buy_weight(local_execution_weight, payment, &XcmContext::with_message_id([0; 32]))
+			.ok()?;
+		Balance::MAX.saturating_sub(unused.fungible.get(&fee_asset_id).copied().unwrap_or_default())
+	};
+
+	let (_, price) = validate_send::<XcmConfig::XcmSender>(next_hop, remote_xcm).ok()?;
+	let mut delivery_fee: Balance = 0;
+	for fee in price.into_inner() {
+		match fee {
+			Asset {
+				id,
+				fun: Fungible(amount),
+			} if id == fee_asset_id => delivery_fee = delivery_fee.saturating_add(amount),
+			// the delivery fee can't be expressed in the transferred asset
+			_ => return None,
+		}
+	}
+
+	let remote_execution_fee = remote_chains
+		.iter()
+		.map(module_xcm_interface::Pallet::<T>::configured_parachain_fee)
+		.try_fold(0, |total: Balance, fee| Some(total.saturating_add(fee?)));
+
+	Some(TransferFeeBreakdown {
+		local_execution_weight,
+		local_execution_fee,
+		delivery_fee,
+		remote_execution_fee,
+		total: local_execution_fee
+			.saturating_add(delivery_fee)
+			.saturating_add(remote_execution_fee.unwrap_or_default()),
+	})
+}
+
 /// Convert `AccountKey20` to `AccountId`
 pub struct AccountKey20Aliases<Network, AccountId, AddressMapping>(PhantomData<(Network, AccountId, AddressMapping)>);
 impl<Network, AccountId, AddressMapping> xcm_executor::traits::ConvertLocation<AccountId>
@@ -0,0 +1,147 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use frame_support::traits::Bounded;
+use pallet_democracy::{AccountVote, Conviction, Vote, VoteThreshold};
+use runtime_common::{BondedVoteBoost, GovernanceParameters};
+
+fn set_bonded_vote_boost(boost: Permill) {
+	assert_ok!(Parameters::set_parameter(
+		RuntimeOrigin::root(),
+		RuntimeParameters::Governance(GovernanceParameters::BondedVoteBoost(BondedVoteBoost, Some(boost)))
+	));
+}
+
+fn start_referendum() -> u32 {
+	let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	Democracy::internal_start_referendum(
+		Bounded::Inline(call.encode().try_into().unwrap()),
+		VoteThreshold::SuperMajorityApprove,
+		0,
+	)
+}
+
+fn aye(balance: Balance) -> AccountVote<Balance> {
+	AccountVote::Standard {
+		vote: Vote {
+			aye: true,
+			conviction: Conviction::Locked1x,
+		},
+		balance,
+	}
+}
+
+fn ayes(ref_index: u32) -> Balance {
+	Democracy::referendum_status(ref_index).unwrap().tally.ayes
+}
+
+#[test]
+fn bonded_balance_boosts_democracy_votes() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY))])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Earning::bond(
+				RuntimeOrigin::signed(alice()),
+				400 * dollar(NATIVE_CURRENCY)
+			));
+			let ref_index = start_referendum();
+
+			// no boost by default, the bonded balance votes as the free balance
+			assert_noop!(
+				Democracy::vote(
+					RuntimeOrigin::signed(alice()),
+					ref_index,
+					aye(1_001 * dollar(NATIVE_CURRENCY))
+				),
+				pallet_democracy::Error::<Runtime>::InsufficientFunds
+			);
+			assert_ok!(Democracy::vote(
+				RuntimeOrigin::signed(alice()),
+				ref_index,
+				aye(1_000 * dollar(NATIVE_CURRENCY))
+			));
+			assert_eq!(ayes(ref_index), 1_000 * dollar(NATIVE_CURRENCY));
+
+			// 50% boost of the active bonded balance
+			set_bonded_vote_boost(Permill::from_percent(50));
+			assert_noop!(
+				Democracy::vote(
+					RuntimeOrigin::signed(alice()),
+					ref_index,
+					aye(1_201 * dollar(NATIVE_CURRENCY))
+				),
+				pallet_democracy::Error::<Runtime>::InsufficientFunds
+			);
+			assert_ok!(Democracy::vote(
+				RuntimeOrigin::signed(alice()),
+				ref_index,
+				aye(1_200 * dollar(NATIVE_CURRENCY))
+			));
+			assert_eq!(ayes(ref_index), 1_200 * dollar(NATIVE_CURRENCY));
+			// the tokens are not transferable beyond the free balance
+			assert_eq!(Balances::free_balance(alice()), 1_000 * dollar(NATIVE_CURRENCY));
+		});
+}
+
+#[test]
+fn unbond_caps_boosted_votes_of_ongoing_referenda() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+			(bob(), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+		])
+		.build()
+		.execute_with(|| {
+			set_bonded_vote_boost(Permill::from_percent(50));
+			assert_ok!(Earning::bond(
+				RuntimeOrigin::signed(alice()),
+				400 * dollar(NATIVE_CURRENCY)
+			));
+			assert_ok!(Earning::bond(
+				RuntimeOrigin::signed(bob()),
+				400 * dollar(NATIVE_CURRENCY)
+			));
+			let ref_index = start_referendum();
+
+			assert_ok!(Democracy::vote(
+				RuntimeOrigin::signed(alice()),
+				ref_index,
+				aye(1_200 * dollar(NATIVE_CURRENCY))
+			));
+			assert_ok!(Democracy::vote(
+				RuntimeOrigin::signed(bob()),
+				ref_index,
+				aye(1_000 * dollar(NATIVE_CURRENCY))
+			));
+			assert_eq!(ayes(ref_index), 2_200 * dollar(NATIVE_CURRENCY));
+
+			// alice: 1_000 + 50% * 200 = 1_100, the vote is capped
+			assert_ok!(Earning::unbond(
+				RuntimeOrigin::signed(alice()),
+				200 * dollar(NATIVE_CURRENCY)
+			));
+			// bob: 1_000 + 50% * 200 = 1_100, the vote is under the cap and kept
+			assert_ok!(Earning::unbond(
+				RuntimeOrigin::signed(bob()),
+				200 * dollar(NATIVE_CURRENCY)
+			));
+			assert_eq!(ayes(ref_index), 2_100 * dollar(NATIVE_CURRENCY));
+		});
+}
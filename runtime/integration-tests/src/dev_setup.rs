@@ -0,0 +1,197 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use mandala_runtime::{
+	dev_setup::{canonical_setup, faucet},
+	DevSetup,
+};
+use module_dev_setup::{CollateralSetup, DevSetupOf, Error, Event, SetupStep, TradingPairSetup};
+
+fn dev_setup_events() -> Vec<Event<Runtime>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::DevSetup(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+fn test_setup() -> DevSetupOf<Runtime> {
+	DevSetupOf::<Runtime> {
+		faucet: alice(),
+		foreign_assets: vec![(
+			Location::new(1, [Parachain(2002), GeneralIndex(1)]).into(),
+			AssetMetadata {
+				name: b"Sibling Token".to_vec(),
+				symbol: b"ST".to_vec(),
+				decimals: 12,
+				minimal_balance: 1,
+			},
+		)],
+		prices: vec![(RELAY_CHAIN_CURRENCY, Price::saturating_from_integer(5))],
+		trading_pairs: vec![TradingPairSetup {
+			currency_id_a: NATIVE_CURRENCY,
+			currency_id_b: LIQUID_CURRENCY,
+			amount_a: 1_000 * dollar(NATIVE_CURRENCY),
+			amount_b: 100 * dollar(LIQUID_CURRENCY),
+		}],
+		collaterals: vec![CollateralSetup {
+			currency_id: RELAY_CHAIN_CURRENCY,
+			interest_rate_per_sec: Some(Rate::saturating_from_rational(1, 100_000)),
+			liquidation_ratio: Some(Ratio::saturating_from_rational(150, 100)),
+			liquidation_penalty: Some(Rate::saturating_from_rational(10, 100)),
+			required_collateral_ratio: Some(Ratio::saturating_from_rational(180, 100)),
+			maximum_total_debit_value: 1_000_000 * dollar(USD_CURRENCY),
+		}],
+	}
+}
+
+#[test]
+fn apply_setup_works() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY, 10_000 * dollar(NATIVE_CURRENCY)),
+			(alice(), LIQUID_CURRENCY, 10_000 * dollar(LIQUID_CURRENCY)),
+		])
+		.build()
+		.execute_with(|| {
+			let trading_pair = TradingPair::from_currency_ids(NATIVE_CURRENCY, LIQUID_CURRENCY).unwrap();
+
+			assert_noop!(
+				DevSetup::apply_setup(RuntimeOrigin::signed(alice()), test_setup().encode()),
+				BadOrigin
+			);
+			assert_noop!(
+				DevSetup::apply_setup(RuntimeOrigin::root(), vec![1, 2, 3]),
+				Error::<Runtime>::InvalidSetup
+			);
+
+			assert_ok!(DevSetup::apply_setup(RuntimeOrigin::root(), test_setup().encode()));
+			assert_eq!(
+				dev_setup_events(),
+				vec![
+					Event::StepApplied {
+						step: SetupStep::RegisterForeignAsset(CurrencyId::ForeignAsset(0))
+					},
+					Event::StepApplied {
+						step: SetupStep::FeedPrice(RELAY_CHAIN_CURRENCY)
+					},
+					Event::StepApplied {
+						step: SetupStep::EnableTradingPair(trading_pair)
+					},
+					Event::StepApplied {
+						step: SetupStep::AddLiquidity(trading_pair)
+					},
+					Event::StepApplied {
+						step: SetupStep::SetCollateralParams(RELAY_CHAIN_CURRENCY)
+					},
+					Event::SetupApplied { applied: 5, skipped: 0 },
+				]
+			);
+
+			assert!(AssetRegistry::asset_metadatas(AssetIds::ForeignAssetId(0)).is_some());
+			assert_eq!(
+				AcalaOracle::get(&RELAY_CHAIN_CURRENCY).map(|price| price.value),
+				Some(Price::saturating_from_integer(5))
+			);
+			assert_eq!(
+				Dex::get_liquidity_pool(NATIVE_CURRENCY, LIQUID_CURRENCY),
+				(1_000 * dollar(NATIVE_CURRENCY), 100 * dollar(LIQUID_CURRENCY))
+			);
+			let params = CdpEngine::collateral_params(RELAY_CHAIN_CURRENCY).unwrap();
+			assert_eq!(
+				params.liquidation_ratio,
+				Some(Ratio::saturating_from_rational(150, 100))
+			);
+			assert_eq!(params.maximum_total_debit_value, 1_000_000 * dollar(USD_CURRENCY));
+		});
+}
+
+#[test]
+fn apply_setup_again_is_noop() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY, 10_000 * dollar(NATIVE_CURRENCY)),
+			(alice(), LIQUID_CURRENCY, 10_000 * dollar(LIQUID_CURRENCY)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(DevSetup::apply_setup(RuntimeOrigin::root(), test_setup().encode()));
+			let native_balance = Currencies::free_balance(NATIVE_CURRENCY, &alice());
+			let liquid_balance = Currencies::free_balance(LIQUID_CURRENCY, &alice());
+			let params = CdpEngine::collateral_params(RELAY_CHAIN_CURRENCY);
+			System::reset_events();
+
+			assert_ok!(DevSetup::apply_setup(RuntimeOrigin::root(), test_setup().encode()));
+			let trading_pair = TradingPair::from_currency_ids(NATIVE_CURRENCY, LIQUID_CURRENCY).unwrap();
+			assert_eq!(
+				dev_setup_events(),
+				vec![
+					Event::StepSkipped {
+						step: SetupStep::RegisterForeignAsset(CurrencyId::ForeignAsset(0))
+					},
+					Event::StepSkipped {
+						step: SetupStep::FeedPrice(RELAY_CHAIN_CURRENCY)
+					},
+					Event::StepSkipped {
+						step: SetupStep::EnableTradingPair(trading_pair)
+					},
+					Event::StepSkipped {
+						step: SetupStep::AddLiquidity(trading_pair)
+					},
+					Event::StepSkipped {
+						step: SetupStep::SetCollateralParams(RELAY_CHAIN_CURRENCY)
+					},
+					Event::SetupApplied { applied: 0, skipped: 5 },
+				]
+			);
+			// only the events of the dev setup module are emitted
+			assert_eq!(System::events().len(), 6);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY, &alice()), native_balance);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY, &alice()), liquid_balance);
+			assert_eq!(CdpEngine::collateral_params(RELAY_CHAIN_CURRENCY), params);
+			assert!(AssetRegistry::asset_metadatas(AssetIds::ForeignAssetId(1)).is_none());
+		});
+}
+
+#[test]
+fn canonical_setup_works() {
+	ExtBuilder::default()
+		.balances(vec![
+			(faucet(), ACA, 10_000_000 * dollar(ACA)),
+			(faucet(), AUSD, 10_000_000 * dollar(AUSD)),
+			(faucet(), DOT, 10_000_000 * dollar(DOT)),
+		])
+		.build()
+		.execute_with(|| {
+			let steps = canonical_setup().steps();
+			assert_ok!(DevSetup::apply_setup(RuntimeOrigin::root(), canonical_setup().encode()));
+			System::reset_events();
+
+			assert_ok!(DevSetup::apply_setup(RuntimeOrigin::root(), canonical_setup().encode()));
+			assert_eq!(
+				dev_setup_events().last(),
+				Some(&Event::SetupApplied {
+					applied: 0,
+					skipped: steps
+				})
+			);
+		});
+}
@@ -235,7 +235,8 @@ fn test_trading_pair() {
 					symbol: b"ST".to_vec(),
 					decimals: 12,
 					minimal_balance: 1,
-				})
+				}),
+				false
 			));
 
 			// CurrencyId::DexShare(Token, ForeignAsset)
@@ -263,3 +264,80 @@ fn test_trading_pair() {
 			));
 		});
 }
+
+#[cfg(feature = "with-mandala-runtime")]
+#[test]
+fn trading_pair_listing_configures_dex_oracle_and_incentives() {
+	ExtBuilder::default()
+		.balances(vec![
+			(
+				AccountId::from(ALICE),
+				NATIVE_CURRENCY,
+				1_000_000 * dollar(NATIVE_CURRENCY),
+			),
+			(
+				AccountId::from(ALICE),
+				LIQUID_CURRENCY,
+				1_000_000 * dollar(LIQUID_CURRENCY),
+			),
+		])
+		.build()
+		.execute_with(|| {
+			let trading_pair = TradingPair::from_currency_ids(NATIVE_CURRENCY, LIQUID_CURRENCY).unwrap();
+			let pool_id = module_support::PoolId::Dex(trading_pair.dex_share_currency_id());
+			let listing = module_support::TradingPairListing {
+				average_price_interval: Some(12_000),
+				incentive_rewards: vec![(NATIVE_CURRENCY, 100 * dollar(NATIVE_CURRENCY))],
+			};
+
+			assert_ok!(Dex::list_provisioning(
+				RuntimeOrigin::root(),
+				NATIVE_CURRENCY,
+				LIQUID_CURRENCY,
+				dollar(NATIVE_CURRENCY),
+				dollar(LIQUID_CURRENCY),
+				100 * dollar(NATIVE_CURRENCY),
+				100 * dollar(LIQUID_CURRENCY),
+				0,
+			));
+			assert_ok!(Dex::set_trading_pair_listing(
+				RuntimeOrigin::root(),
+				NATIVE_CURRENCY,
+				LIQUID_CURRENCY,
+				Some(listing),
+			));
+			assert_ok!(Dex::add_provision(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				NATIVE_CURRENCY,
+				LIQUID_CURRENCY,
+				100 * dollar(NATIVE_CURRENCY),
+				100 * dollar(LIQUID_CURRENCY),
+			));
+
+			assert_eq!(DexOracle::average_prices(trading_pair), None);
+			assert_eq!(Incentives::incentive_reward_amounts(pool_id, NATIVE_CURRENCY), 0);
+
+			assert_ok!(Dex::end_provisioning(
+				RuntimeOrigin::signed(AccountId::from(BOB)),
+				NATIVE_CURRENCY,
+				LIQUID_CURRENCY,
+			));
+			assert_eq!(
+				Dex::trading_pair_statuses(trading_pair),
+				module_dex::TradingPairStatus::Enabled
+			);
+			assert!(DexOracle::average_prices(trading_pair).is_some());
+			assert_eq!(
+				Incentives::incentive_reward_amounts(pool_id, NATIVE_CURRENCY),
+				100 * dollar(NATIVE_CURRENCY)
+			);
+
+			assert_ok!(Dex::disable_trading_pair(
+				RuntimeOrigin::root(),
+				NATIVE_CURRENCY,
+				LIQUID_CURRENCY,
+			));
+			assert_eq!(DexOracle::average_prices(trading_pair), None);
+			assert_eq!(Incentives::incentive_reward_amounts(pool_id, NATIVE_CURRENCY), 0);
+		});
+}
@@ -0,0 +1,82 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use frame_support::traits::PalletInfoAccess;
+use module_error_registry::{decode_dispatch_error, decode_module_error};
+
+#[test]
+fn decode_known_module_errors() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the error bytes are part of the public interface, they must not change across upgrades
+		let cdp_engine_index = CdpEngine::index() as u8;
+		assert_eq!(
+			DispatchError::from(module_cdp_engine::Error::<Runtime>::BelowRequiredCollateralRatio),
+			DispatchError::Module(sp_runtime::ModuleError {
+				index: cdp_engine_index,
+				error: [1, 0, 0, 0],
+				message: Some("BelowRequiredCollateralRatio"),
+			})
+		);
+
+		let info = decode_module_error::<RuntimePalletErrors>(cdp_engine_index, [1, 0, 0, 0]).unwrap();
+		assert_eq!(info.pallet, b"CdpEngine".to_vec());
+		assert_eq!(info.error, b"BelowRequiredCollateralRatio".to_vec());
+		assert_eq!(
+			info.docs,
+			b"The collateral ratio below the required collateral ratio".to_vec()
+		);
+
+		let info = decode_module_error::<RuntimePalletErrors>(Dex::index() as u8, [1, 0, 0, 0]).unwrap();
+		assert_eq!(info.pallet, b"Dex".to_vec());
+		assert_eq!(info.error, b"MustBeEnabled".to_vec());
+
+		assert_eq!(
+			decode_module_error::<RuntimePalletErrors>(cdp_engine_index, [255, 0, 0, 0]),
+			None
+		);
+	});
+}
+
+#[test]
+fn decode_dispatch_errors() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			decode_dispatch_error::<RuntimePalletErrors>(
+				module_cdp_engine::Error::<Runtime>::BelowRequiredCollateralRatio.into()
+			),
+			b"CdpEngine::BelowRequiredCollateralRatio".to_vec()
+		);
+		assert_eq!(
+			decode_dispatch_error::<RuntimePalletErrors>(module_dex::Error::<Runtime>::MustBeEnabled.into()),
+			b"Dex::MustBeEnabled".to_vec()
+		);
+		assert_eq!(
+			decode_dispatch_error::<RuntimePalletErrors>(DispatchError::Token(
+				sp_runtime::TokenError::FundsUnavailable
+			)),
+			b"Token::FundsUnavailable".to_vec()
+		);
+		assert_eq!(
+			decode_dispatch_error::<RuntimePalletErrors>(DispatchError::Arithmetic(
+				sp_runtime::ArithmeticError::Underflow
+			)),
+			b"Arithmetic::Underflow".to_vec()
+		);
+	});
+}
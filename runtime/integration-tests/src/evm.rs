@@ -118,7 +118,8 @@ pub fn deploy_erc20_contracts() {
 	assert_ok!(AssetRegistry::register_erc20_asset(
 		RuntimeOrigin::root(),
 		erc20_address_0(),
-		1
+		1,
+		false
 	));
 
 	assert_ok!(EVM::create(
@@ -150,10 +151,12 @@ pub fn deploy_erc20_contracts() {
 		used_storage: 14027,
 	}));
 
+	// deployed from the same code, so the symbol is the same as erc20_address_0
 	assert_ok!(AssetRegistry::register_erc20_asset(
 		RuntimeOrigin::root(),
 		erc20_address_1(),
-		1
+		1,
+		true
 	));
 }
 
@@ -491,6 +494,7 @@ fn test_multicurrency_precompile_module() {
 				21000000,
 				16500,
 				vec![],
+				None,
 			));
 
 			let invoke_context = module_support::InvokeContext {
@@ -21,7 +21,7 @@ use frame_support::traits::fungible::Mutate;
 use module_evm_accounts::EvmAddressMapping;
 use module_support::{
 	evm::{AddressMapping, LiquidationEvmBridge},
-	InvokeContext,
+	InvokeContext, PriceProvider,
 };
 use primitives::evm::EvmAddress;
 use std::str::FromStr;
@@ -685,16 +685,18 @@ fn cdp_engine_minimum_collateral_amount_works() {
 		.execute_with(|| {
 			System::set_block_number(1);
 
+			// price the collaterals high enough to keep the tiny positions above the
+			// liquidation ratio
 			set_oracle_price(vec![
-				(NATIVE_CURRENCY, Price::saturating_from_rational(1, 1)),
-				(RELAY_CHAIN_CURRENCY, Price::saturating_from_rational(1, 1)),
+				(NATIVE_CURRENCY, Price::saturating_from_rational(100, 1)),
+				(RELAY_CHAIN_CURRENCY, Price::saturating_from_rational(10_000, 1)),
 			]);
 
 			assert_ok!(CdpEngine::set_collateral_params(
 				RuntimeOrigin::root(),
 				NATIVE_CURRENCY,
 				Change::NewValue(Some(Rate::zero())),
-				Change::NewValue(Some(Rate::saturating_from_rational(1, 10000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 				Change::NewValue(None),
 				Change::NewValue(None),
 				Change::NewValue(1_000_000 * dollar(NATIVE_CURRENCY)),
@@ -703,7 +705,7 @@ fn cdp_engine_minimum_collateral_amount_works() {
 				RuntimeOrigin::root(),
 				RELAY_CHAIN_CURRENCY,
 				Change::NewValue(Some(Rate::zero())),
-				Change::NewValue(Some(Rate::saturating_from_rational(1, 10000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 				Change::NewValue(None),
 				Change::NewValue(None),
 				Change::NewValue(1_000_000 * dollar(RELAY_CHAIN_CURRENCY)),
@@ -1045,3 +1047,120 @@ fn can_liquidate_cdp_via_intended_priority() {
 			);
 		});
 }
+
+#[test]
+fn protocol_stats_tvl_matches_dex_and_loans() {
+	ExtBuilder::default()
+		.balances(vec![
+			(
+				AccountId::from(ALICE),
+				RELAY_CHAIN_CURRENCY,
+				1_000 * dollar(RELAY_CHAIN_CURRENCY),
+			),
+			(AccountId::from(ALICE), USD_CURRENCY, 100_000 * dollar(USD_CURRENCY)),
+			(
+				AccountId::from(BOB),
+				RELAY_CHAIN_CURRENCY,
+				1_000 * dollar(RELAY_CHAIN_CURRENCY),
+			),
+			(AccountId::from(BOB), USD_CURRENCY, 100_000 * dollar(USD_CURRENCY)),
+			(AccountId::from(BOB), NATIVE_CURRENCY, 100_000 * dollar(NATIVE_CURRENCY)),
+		])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			set_oracle_price(vec![(RELAY_CHAIN_CURRENCY, Price::saturating_from_rational(10, 1))]);
+			assert_ok!(CdpEngine::set_collateral_params(
+				RuntimeOrigin::root(),
+				RELAY_CHAIN_CURRENCY,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 10000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(200, 100))),
+				Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(200, 100))),
+				Change::NewValue(1_000_000 * dollar(USD_CURRENCY)),
+			));
+
+			assert_ok!(Dex::add_liquidity(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				RELAY_CHAIN_CURRENCY,
+				USD_CURRENCY,
+				100 * dollar(RELAY_CHAIN_CURRENCY),
+				1_000 * dollar(USD_CURRENCY),
+				0,
+				false,
+			));
+			assert_ok!(Dex::add_liquidity(
+				RuntimeOrigin::signed(AccountId::from(BOB)),
+				NATIVE_CURRENCY,
+				USD_CURRENCY,
+				10_000 * dollar(NATIVE_CURRENCY),
+				5_000 * dollar(USD_CURRENCY),
+				0,
+				false,
+			));
+			assert_ok!(Honzon::adjust_loan(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				RELAY_CHAIN_CURRENCY,
+				50 * dollar(RELAY_CHAIN_CURRENCY) as i128,
+				100 * dollar(USD_CURRENCY) as i128
+			));
+			assert_ok!(Honzon::adjust_loan(
+				RuntimeOrigin::signed(AccountId::from(BOB)),
+				RELAY_CHAIN_CURRENCY,
+				30 * dollar(RELAY_CHAIN_CURRENCY) as i128,
+				50 * dollar(USD_CURRENCY) as i128
+			));
+
+			let mut expected_dex: Vec<(CurrencyId, Balance)> = vec![];
+			for (trading_pair, (pool_0, pool_1)) in module_dex::LiquidityPool::<Runtime>::iter() {
+				for (currency_id, amount) in [(trading_pair.first(), pool_0), (trading_pair.second(), pool_1)] {
+					match expected_dex.iter_mut().find(|(c, _)| *c == currency_id) {
+						Some((_, total)) => *total += amount,
+						None => expected_dex.push((currency_id, amount)),
+					}
+				}
+			}
+			expected_dex.sort();
+
+			let mut expected_loans: Vec<(CurrencyId, Balance)> = vec![];
+			for ((currency_id, _, _), position) in module_loans::Positions::<Runtime>::iter() {
+				match expected_loans.iter_mut().find(|(c, _)| *c == currency_id) {
+					Some((_, total)) => *total += position.collateral,
+					None => expected_loans.push((currency_id, position.collateral)),
+				}
+			}
+			expected_loans.sort();
+			assert_eq!(
+				expected_loans,
+				vec![(RELAY_CHAIN_CURRENCY, 80 * dollar(RELAY_CHAIN_CURRENCY))]
+			);
+
+			let tvl = runtime_common::ProtocolStats::<
+				Runtime,
+				StableAsset,
+				module_prices::RealTimePriceProvider<Runtime>,
+			>::get_tvl();
+			let amounts_of = |source: runtime_common::TvlSource| {
+				let mut amounts: Vec<(CurrencyId, Balance)> = tvl
+					.iter()
+					.filter(|(s, ..)| *s == source)
+					.map(|(_, currency_id, amount, _)| (*currency_id, *amount))
+					.collect();
+				amounts.sort();
+				amounts
+			};
+			assert_eq!(amounts_of(runtime_common::TvlSource::Dex), expected_dex);
+			assert_eq!(amounts_of(runtime_common::TvlSource::Loans), expected_loans);
+
+			let relay_chain_loans = tvl
+				.iter()
+				.find(|(s, c, ..)| *s == runtime_common::TvlSource::Loans && *c == RELAY_CHAIN_CURRENCY)
+				.unwrap();
+			assert_eq!(
+				relay_chain_loans.3,
+				module_prices::RealTimePriceProvider::<Runtime>::get_price(RELAY_CHAIN_CURRENCY)
+					.and_then(|price| price.checked_mul_int(80 * dollar(RELAY_CHAIN_CURRENCY)))
+			);
+			assert!(relay_chain_loans.3.is_some());
+		});
+}
@@ -0,0 +1,697 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! End-to-end user journeys crossing several modules.
+//!
+//! Every journey only uses the aliases exported by `setup`, so it runs unchanged against
+//! each runtime selected by feature. Supporting a new runtime only needs its `*_imports`
+//! module in `setup`.
+
+use crate::setup::*;
+use frame_support::dispatch::GetDispatchInfo;
+use module_asset_registry::EvmErc20InfoMapping;
+use module_evm_accounts::EvmAddressMapping;
+use module_support::{Erc20InfoMapping, LiquidationRoute};
+use primitives::evm::EvmAddress;
+use sp_runtime::traits::{Dispatchable, SignedExtension};
+
+/// The liquidity provider of the DEX pools declared by the journeys.
+const LIQUIDITY_PROVIDER: [u8; 32] = [8u8; 32];
+
+/// The shared setup of a journey: the initial balances, the oracle prices and the DEX pools.
+#[derive(Default)]
+struct Journey {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+	prices: Vec<(CurrencyId, Price)>,
+	pools: Vec<(CurrencyId, CurrencyId, Balance, Balance)>,
+}
+
+impl Journey {
+	fn balances(mut self, balances: Vec<(AccountId, CurrencyId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	fn price(mut self, currency_id: CurrencyId, price: Price) -> Self {
+		self.prices.push((currency_id, price));
+		self
+	}
+
+	fn pool(
+		mut self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		amount_a: Balance,
+		amount_b: Balance,
+	) -> Self {
+		self.pools.push((currency_id_a, currency_id_b, amount_a, amount_b));
+		self
+	}
+
+	/// Run the journey, then check the invariants which must hold whatever the journey did.
+	fn execute(self, journey: impl FnOnce()) {
+		ExtBuilder::default().balances(self.balances).build().execute_with(|| {
+			if !self.prices.is_empty() {
+				set_oracle_price(self.prices);
+			}
+
+			let provider = AccountId::from(LIQUIDITY_PROVIDER);
+			for (currency_id_a, currency_id_b, amount_a, amount_b) in self.pools {
+				for (currency_id, amount) in [(currency_id_a, amount_a), (currency_id_b, amount_b)] {
+					assert_ok!(Currencies::update_balance(
+						RuntimeOrigin::root(),
+						MultiAddress::Id(provider.clone()),
+						currency_id,
+						amount as i128,
+					));
+				}
+				assert_ok!(Dex::add_liquidity(
+					RuntimeOrigin::signed(provider.clone()),
+					currency_id_a,
+					currency_id_b,
+					amount_a,
+					amount_b,
+					0,
+					false,
+				));
+			}
+
+			journey();
+
+			assert_invariants();
+		});
+	}
+}
+
+/// The total positions of every collateral match the sum of the positions of all the owners,
+/// and the loans module holds all the collateral of them.
+fn assert_invariants() {
+	for currency_id in CdpEngine::get_collateral_currency_ids() {
+		let (collateral, debit) = module_loans::Positions::<Runtime>::iter_prefix(currency_id).fold(
+			(0, 0),
+			|(collateral, debit): (Balance, Balance), (_, position)| {
+				(collateral + position.collateral, debit + position.debit)
+			},
+		);
+		let total_positions = Loans::total_positions(currency_id);
+		assert_eq!(total_positions.collateral, collateral);
+		assert_eq!(total_positions.debit, debit);
+		assert!(Currencies::free_balance(currency_id, &Loans::account_id()) >= collateral);
+	}
+}
+
+fn set_collateral_params(currency_id: CurrencyId, liquidation_ratio: Ratio, liquidation_penalty: Rate) {
+	assert_ok!(CdpEngine::set_collateral_params(
+		RuntimeOrigin::root(),
+		currency_id,
+		Change::NewValue(Some(Rate::zero())),
+		Change::NewValue(Some(liquidation_ratio)),
+		Change::NewValue(Some(liquidation_penalty)),
+		Change::NewValue(Some(liquidation_ratio)),
+		Change::NewValue(1_000_000 * dollar(USD_CURRENCY)),
+	));
+}
+
+fn last_liquidation_route(who: &AccountId) -> Option<LiquidationRoute> {
+	CdpEngine::liquidation_history(who)
+		.last()
+		.and_then(|record| record.route)
+}
+
+#[test]
+fn cdp_is_liquidated_by_auction_and_by_dex_after_price_drop() {
+	Journey::default()
+		.balances(vec![
+			(
+				AccountId::from(ALICE),
+				RELAY_CHAIN_CURRENCY,
+				50 * dollar(RELAY_CHAIN_CURRENCY),
+			),
+			(AccountId::from(BOB), RELAY_CHAIN_CURRENCY, dollar(RELAY_CHAIN_CURRENCY)),
+		])
+		.price(RELAY_CHAIN_CURRENCY, Price::saturating_from_rational(10_000, 1))
+		.pool(
+			RELAY_CHAIN_CURRENCY,
+			USD_CURRENCY,
+			100 * dollar(RELAY_CHAIN_CURRENCY),
+			500_000 * dollar(USD_CURRENCY),
+		)
+		.execute(|| {
+			set_collateral_params(
+				RELAY_CHAIN_CURRENCY,
+				Ratio::saturating_from_rational(150, 100),
+				Rate::saturating_from_rational(20, 100),
+			);
+
+			// both loans are at 250% collateral ratio.
+			assert_ok!(Honzon::adjust_loan_by_debit_value(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				RELAY_CHAIN_CURRENCY,
+				(50 * dollar(RELAY_CHAIN_CURRENCY)) as i128,
+				(200_000 * dollar(USD_CURRENCY)) as i128,
+			));
+			assert_ok!(Honzon::adjust_loan_by_debit_value(
+				RuntimeOrigin::signed(AccountId::from(BOB)),
+				RELAY_CHAIN_CURRENCY,
+				dollar(RELAY_CHAIN_CURRENCY) as i128,
+				(4_000 * dollar(USD_CURRENCY)) as i128,
+			));
+			assert_eq!(
+				Currencies::free_balance(USD_CURRENCY, &AccountId::from(ALICE)),
+				200_000 * dollar(USD_CURRENCY)
+			);
+			assert_eq!(
+				Currencies::free_balance(USD_CURRENCY, &AccountId::from(BOB)),
+				4_000 * dollar(USD_CURRENCY)
+			);
+			assert_noop!(
+				CdpEngine::liquidate_unsafe_cdp(AccountId::from(ALICE), RELAY_CHAIN_CURRENCY),
+				module_cdp_engine::Error::<Runtime>::MustBeUnsafe
+			);
+
+			// the price halves, both loans drop to 125% collateral ratio.
+			set_oracle_price(vec![(RELAY_CHAIN_CURRENCY, Price::saturating_from_rational(5_000, 1))]);
+
+			// the DEX cannot supply the target with reasonable slippage, use auction.
+			assert_ok!(CdpEngine::liquidate_unsafe_cdp(
+				AccountId::from(ALICE),
+				RELAY_CHAIN_CURRENCY
+			));
+			System::assert_has_event(RuntimeEvent::CdpEngine(module_cdp_engine::Event::LiquidateUnsafeCDP {
+				collateral_type: RELAY_CHAIN_CURRENCY,
+				owner: AccountId::from(ALICE),
+				collateral_amount: 50 * dollar(RELAY_CHAIN_CURRENCY),
+				bad_debt_value: 200_000 * dollar(USD_CURRENCY),
+				target_amount: Rate::saturating_from_rational(20, 100)
+					.saturating_mul_acc_int(200_000 * dollar(USD_CURRENCY)),
+			}));
+			assert_eq!(
+				last_liquidation_route(&AccountId::from(ALICE)),
+				Some(LiquidationRoute::Auction)
+			);
+			assert!(AuctionManager::collateral_auctions(0).is_some());
+			assert_eq!(
+				AuctionManager::total_collateral_in_auction(RELAY_CHAIN_CURRENCY),
+				50 * dollar(RELAY_CHAIN_CURRENCY)
+			);
+			assert_eq!(CdpTreasury::debit_pool(), 200_000 * dollar(USD_CURRENCY));
+
+			// the small loan is sold on the DEX right away.
+			let (relay_pool_before, usd_pool_before) = Dex::get_liquidity_pool(RELAY_CHAIN_CURRENCY, USD_CURRENCY);
+			assert_ok!(CdpEngine::liquidate_unsafe_cdp(
+				AccountId::from(BOB),
+				RELAY_CHAIN_CURRENCY
+			));
+			let target_amount =
+				Rate::saturating_from_rational(20, 100).saturating_mul_acc_int(4_000 * dollar(USD_CURRENCY));
+			System::assert_has_event(RuntimeEvent::CdpEngine(module_cdp_engine::Event::LiquidateUnsafeCDP {
+				collateral_type: RELAY_CHAIN_CURRENCY,
+				owner: AccountId::from(BOB),
+				collateral_amount: dollar(RELAY_CHAIN_CURRENCY),
+				bad_debt_value: 4_000 * dollar(USD_CURRENCY),
+				target_amount,
+			}));
+			assert_eq!(
+				last_liquidation_route(&AccountId::from(BOB)),
+				Some(LiquidationRoute::Dex)
+			);
+
+			let (relay_pool_after, usd_pool_after) = Dex::get_liquidity_pool(RELAY_CHAIN_CURRENCY, USD_CURRENCY);
+			let collateral_sold = relay_pool_after - relay_pool_before;
+			assert_eq!(usd_pool_before - usd_pool_after, target_amount);
+			// the collateral left after covering the debit and the penalty is refunded to the owner.
+			assert_eq!(
+				Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(BOB)),
+				dollar(RELAY_CHAIN_CURRENCY) - collateral_sold
+			);
+			assert_eq!(CdpTreasury::debit_pool(), 204_000 * dollar(USD_CURRENCY));
+
+			for who in [ALICE, BOB] {
+				let position = Loans::positions(RELAY_CHAIN_CURRENCY, AccountId::from(who));
+				assert_eq!((position.collateral, position.debit), (0, 0));
+			}
+		});
+}
+
+#[test]
+fn liquid_staking_minted_used_as_collateral_and_redeemed() {
+	Journey::default()
+		.balances(vec![
+			(
+				AccountId::from(ALICE),
+				RELAY_CHAIN_CURRENCY,
+				1_000 * dollar(RELAY_CHAIN_CURRENCY),
+			),
+			(AccountId::from(BOB), NATIVE_CURRENCY, 10 * dollar(NATIVE_CURRENCY)),
+		])
+		.price(RELAY_CHAIN_CURRENCY, Price::saturating_from_rational(100, 1))
+		.price(LIQUID_CURRENCY, Price::saturating_from_rational(10, 1))
+		.execute(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::root(),
+				Some(1_000_000 * dollar(RELAY_CHAIN_CURRENCY)),
+				None,
+				None,
+				Some(Rate::saturating_from_rational(1, 100)),
+				None,
+			));
+
+			// mint the liquid currency.
+			let liquid_amount = Homa::convert_staking_to_liquid(100 * dollar(RELAY_CHAIN_CURRENCY)).unwrap();
+			assert_ok!(Homa::mint(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				100 * dollar(RELAY_CHAIN_CURRENCY)
+			));
+			System::assert_has_event(RuntimeEvent::Homa(module_homa::Event::Minted {
+				minter: AccountId::from(ALICE),
+				staking_currency_amount: 100 * dollar(RELAY_CHAIN_CURRENCY),
+				liquid_amount_received: liquid_amount,
+				liquid_amount_added_to_void: 0,
+			}));
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY, &AccountId::from(ALICE)),
+				liquid_amount
+			);
+			assert_eq!(Homa::to_bond_pool(), 100 * dollar(RELAY_CHAIN_CURRENCY));
+
+			// borrow against it.
+			set_collateral_params(
+				LIQUID_CURRENCY,
+				Ratio::saturating_from_rational(150, 100),
+				Rate::saturating_from_rational(10, 100),
+			);
+			assert_ok!(Honzon::adjust_loan_by_debit_value(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				LIQUID_CURRENCY,
+				liquid_amount as i128,
+				(2_000 * dollar(USD_CURRENCY)) as i128,
+			));
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY, &AccountId::from(ALICE)), 0);
+			assert_eq!(
+				Currencies::free_balance(USD_CURRENCY, &AccountId::from(ALICE)),
+				2_000 * dollar(USD_CURRENCY)
+			);
+			assert_eq!(
+				Loans::positions(LIQUID_CURRENCY, AccountId::from(ALICE)).collateral,
+				liquid_amount
+			);
+
+			// repay and take the collateral back.
+			assert_ok!(Honzon::adjust_loan_by_debit_value(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				LIQUID_CURRENCY,
+				-(liquid_amount as i128),
+				-((2_000 * dollar(USD_CURRENCY)) as i128),
+			));
+			assert_eq!(Currencies::free_balance(USD_CURRENCY, &AccountId::from(ALICE)), 0);
+			assert_eq!(
+				Currencies::free_balance(LIQUID_CURRENCY, &AccountId::from(ALICE)),
+				liquid_amount
+			);
+			assert_eq!(Loans::positions(LIQUID_CURRENCY, AccountId::from(ALICE)).debit, 0);
+
+			// redeem by fast match with the staking currency minted in the current era.
+			assert_ok!(Homa::request_redeem(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				liquid_amount,
+				true,
+			));
+			assert_ok!(Homa::fast_match_redeems(
+				RuntimeOrigin::signed(AccountId::from(BOB)),
+				vec![AccountId::from(ALICE)]
+			));
+			System::assert_has_event(RuntimeEvent::Homa(module_homa::Event::RedeemedByFastMatch {
+				redeemer: AccountId::from(ALICE),
+				matched_liquid_amount: liquid_amount,
+				fee_in_liquid: liquid_amount / 100,
+				redeemed_staking_amount: 99 * dollar(RELAY_CHAIN_CURRENCY),
+			}));
+			assert_eq!(
+				Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+				999 * dollar(RELAY_CHAIN_CURRENCY)
+			);
+			assert_eq!(Homa::to_bond_pool(), dollar(RELAY_CHAIN_CURRENCY));
+			assert_eq!(Currencies::total_issuance(LIQUID_CURRENCY), 0);
+			assert_eq!(Homa::redeem_requests(AccountId::from(ALICE)), None);
+		});
+}
+
+fn predeploy_dex_contract() -> (EvmAddress, Vec<u8>) {
+	let contracts: Vec<(String, String, String)> =
+		serde_json::from_str(include_str!("../../../predeploy-contracts/resources/bytecodes.json")).unwrap();
+	let (_, address, code) = contracts
+		.into_iter()
+		.find(|(name, _, _)| name == "DEX")
+		.expect("the DEX contract is predeployed");
+	(
+		EvmAddress::from_slice(&hex::decode(address.strip_prefix("0x").unwrap()).unwrap()),
+		hex::decode(code.strip_prefix("0x").unwrap()).unwrap(),
+	)
+}
+
+/// Encode the call of `swapWithExactSupply(address[],uint256,uint256)`.
+fn encode_swap_with_exact_supply(path: Vec<CurrencyId>, supply_amount: Balance, min_target_amount: Balance) -> Vec<u8> {
+	let word = |value: u128| {
+		let mut buf = [0u8; 32];
+		buf[16..].copy_from_slice(&value.to_be_bytes());
+		buf
+	};
+
+	let mut input = sp_io::hashing::keccak_256(b"swapWithExactSupply(address[],uint256,uint256)")[..4].to_vec();
+	// offset of the path, after the three head words.
+	input.extend_from_slice(&word(0x60));
+	input.extend_from_slice(&word(supply_amount));
+	input.extend_from_slice(&word(min_target_amount));
+	input.extend_from_slice(&word(path.len() as u128));
+	for currency_id in path {
+		let mut buf = [0u8; 32];
+		buf[12..].copy_from_slice(
+			EvmErc20InfoMapping::<Runtime>::encode_evm_address(currency_id)
+				.unwrap()
+				.as_bytes(),
+		);
+		input.extend_from_slice(&buf);
+	}
+	input
+}
+
+#[test]
+fn evm_contract_swaps_on_dex_paying_fee_in_stable_currency() {
+	let charlie = AccountId::from(CHARLIE);
+
+	Journey::default()
+		.balances(vec![
+			(AccountId::from(ALICE), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+			(charlie.clone(), USD_CURRENCY, 1_000 * dollar(USD_CURRENCY)),
+		])
+		.pool(
+			USD_CURRENCY,
+			NATIVE_CURRENCY,
+			100 * dollar(USD_CURRENCY),
+			1_000 * dollar(NATIVE_CURRENCY),
+		)
+		.pool(
+			RELAY_CHAIN_CURRENCY,
+			USD_CURRENCY,
+			100 * dollar(RELAY_CHAIN_CURRENCY),
+			10_000 * dollar(USD_CURRENCY),
+		)
+		.execute(|| {
+			let (dex_address, code) = predeploy_dex_contract();
+			assert_ok!(Currencies::transfer(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				MultiAddress::Id(TreasuryAccount::get()),
+				NATIVE_CURRENCY,
+				100 * dollar(NATIVE_CURRENCY)
+			));
+			assert_ok!(EVM::create_predeploy_contract(
+				RuntimeOrigin::root(),
+				dex_address,
+				code,
+				0,
+				21_000_000,
+				100_000,
+				vec![],
+				None,
+			));
+
+			// enable the stable currency to pay the fee.
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				MultiAddress::Id(TreasuryAccount::get()),
+				USD_CURRENCY,
+				dollar(USD_CURRENCY) as i128,
+			));
+			assert_ok!(TransactionPayment::enable_charge_fee_pool(
+				RuntimeOrigin::root(),
+				USD_CURRENCY,
+				5 * dollar(NATIVE_CURRENCY),
+				Ratio::saturating_from_rational(35, 100).saturating_mul_int(dollar(NATIVE_CURRENCY)),
+			));
+
+			let supply_amount = 100 * dollar(USD_CURRENCY);
+			let call = RuntimeCall::TransactionPayment(module_transaction_payment::Call::with_fee_currency {
+				currency_id: USD_CURRENCY,
+				call: Box::new(RuntimeCall::EVM(module_evm::Call::call {
+					target: dex_address,
+					input: encode_swap_with_exact_supply(vec![USD_CURRENCY, RELAY_CHAIN_CURRENCY], supply_amount, 1),
+					value: 0,
+					gas_limit: 1_000_000,
+					storage_limit: 0,
+					access_list: vec![],
+				})),
+			});
+
+			// the fee is paid in the stable currency, the caller has no native currency.
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY, &charlie), 0);
+			assert_ok!(
+				<module_transaction_payment::ChargeTransactionPayment<Runtime>>::from(0).validate(
+					&charlie,
+					&call,
+					&call.get_dispatch_info(),
+					call.encoded_size(),
+				)
+			);
+			let usd_after_fee = Currencies::free_balance(USD_CURRENCY, &charlie);
+			assert!(usd_after_fee < 1_000 * dollar(USD_CURRENCY));
+			assert_eq!(
+				Currencies::free_balance(NATIVE_CURRENCY, &charlie),
+				NativeTokenExistentialDeposit::get()
+			);
+
+			let (relay_pool_before, _) = Dex::get_liquidity_pool(RELAY_CHAIN_CURRENCY, USD_CURRENCY);
+			assert_ok!(call.dispatch(RuntimeOrigin::signed(charlie.clone())));
+
+			let charlie_evm_address = EvmAddressMapping::<Runtime>::get_evm_address(&charlie).unwrap();
+			assert!(System::events().iter().any(|record| matches!(
+				&record.event,
+				RuntimeEvent::EVM(module_evm::Event::Executed { from, contract, .. })
+					if *from == charlie_evm_address && *contract == dex_address
+			)));
+			assert!(System::events().iter().any(|record| matches!(
+				&record.event,
+				RuntimeEvent::Dex(module_dex::Event::Swap { trader, .. }) if *trader == charlie
+			)));
+
+			let (relay_pool_after, _) = Dex::get_liquidity_pool(RELAY_CHAIN_CURRENCY, USD_CURRENCY);
+			assert_eq!(
+				Currencies::free_balance(USD_CURRENCY, &charlie),
+				usd_after_fee - supply_amount
+			);
+			assert_eq!(
+				Currencies::free_balance(RELAY_CHAIN_CURRENCY, &charlie),
+				relay_pool_before - relay_pool_after
+			);
+			assert!(!Currencies::free_balance(RELAY_CHAIN_CURRENCY, &charlie).is_zero());
+		});
+}
+
+#[test]
+fn emergency_shutdown_settles_cdps_and_refunds_collaterals() {
+	Journey::default()
+		.balances(vec![(
+			AccountId::from(ALICE),
+			RELAY_CHAIN_CURRENCY,
+			100 * dollar(RELAY_CHAIN_CURRENCY),
+		)])
+		.price(RELAY_CHAIN_CURRENCY, Price::saturating_from_rational(10_000, 1))
+		.execute(|| {
+			set_collateral_params(
+				RELAY_CHAIN_CURRENCY,
+				Ratio::saturating_from_rational(150, 100),
+				Rate::saturating_from_rational(20, 100),
+			);
+			assert_ok!(Honzon::adjust_loan_by_debit_value(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				RELAY_CHAIN_CURRENCY,
+				(100 * dollar(RELAY_CHAIN_CURRENCY)) as i128,
+				(200_000 * dollar(USD_CURRENCY)) as i128,
+			));
+
+			assert_ok!(EmergencyShutdown::emergency_shutdown(RuntimeOrigin::root()));
+			assert_noop!(
+				Honzon::adjust_loan_by_debit_value(
+					RuntimeOrigin::signed(AccountId::from(ALICE)),
+					RELAY_CHAIN_CURRENCY,
+					0,
+					(1_000 * dollar(USD_CURRENCY)) as i128,
+				),
+				module_honzon::Error::<Runtime>::AlreadyShutdown
+			);
+			assert_noop!(
+				EmergencyShutdown::open_collateral_refund(RuntimeOrigin::root()),
+				module_emergency_shutdown::Error::<Runtime>::ExistUnhandledDebit
+			);
+
+			// the debit is settled by confiscating the collateral at the locked price.
+			assert_ok!(CdpEngine::settle_cdp_has_debit(
+				AccountId::from(ALICE),
+				RELAY_CHAIN_CURRENCY
+			));
+			System::assert_has_event(RuntimeEvent::CdpEngine(module_cdp_engine::Event::SettleCDPInDebit {
+				collateral_type: RELAY_CHAIN_CURRENCY,
+				owner: AccountId::from(ALICE),
+			}));
+			let position = Loans::positions(RELAY_CHAIN_CURRENCY, AccountId::from(ALICE));
+			assert_eq!(position.collateral, 80 * dollar(RELAY_CHAIN_CURRENCY));
+			assert_eq!(position.debit, 0);
+			assert_eq!(
+				CdpTreasury::total_collaterals(RELAY_CHAIN_CURRENCY),
+				20 * dollar(RELAY_CHAIN_CURRENCY)
+			);
+
+			// the remaining collateral can still be withdrawn.
+			assert_ok!(Honzon::adjust_loan(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				RELAY_CHAIN_CURRENCY,
+				-((80 * dollar(RELAY_CHAIN_CURRENCY)) as i128),
+				0,
+			));
+			assert_eq!(
+				Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+				80 * dollar(RELAY_CHAIN_CURRENCY)
+			);
+
+			assert_ok!(EmergencyShutdown::open_collateral_refund(RuntimeOrigin::root()));
+
+			// half of the stable currency in circulation is refunded half of the collateral.
+			assert_ok!(EmergencyShutdown::refund_collaterals(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				100_000 * dollar(USD_CURRENCY)
+			));
+			System::assert_has_event(RuntimeEvent::EmergencyShutdown(
+				module_emergency_shutdown::Event::Refund {
+					who: AccountId::from(ALICE),
+					stable_coin_amount: 100_000 * dollar(USD_CURRENCY),
+					refund_list: vec![(RELAY_CHAIN_CURRENCY, 10 * dollar(RELAY_CHAIN_CURRENCY))],
+				},
+			));
+			assert_eq!(
+				Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+				90 * dollar(RELAY_CHAIN_CURRENCY)
+			);
+			assert_eq!(
+				Currencies::free_balance(USD_CURRENCY, &AccountId::from(ALICE)),
+				100_000 * dollar(USD_CURRENCY)
+			);
+			assert_eq!(Currencies::total_issuance(USD_CURRENCY), 100_000 * dollar(USD_CURRENCY));
+			assert_eq!(
+				CdpTreasury::total_collaterals(RELAY_CHAIN_CURRENCY),
+				10 * dollar(RELAY_CHAIN_CURRENCY)
+			);
+		});
+}
+
+#[test]
+fn relay_chain_token_transferred_in_swapped_and_transferred_out() {
+	Journey::default()
+		.pool(
+			RELAY_CHAIN_CURRENCY,
+			USD_CURRENCY,
+			100 * dollar(RELAY_CHAIN_CURRENCY),
+			10_000 * dollar(USD_CURRENCY),
+		)
+		.execute(|| {
+			#[cfg(feature = "with-mandala-runtime")]
+			let shallow_weight = 3_000_000;
+			#[cfg(feature = "with-karura-runtime")]
+			let shallow_weight = 600_000_000;
+			#[cfg(feature = "with-acala-runtime")]
+			let shallow_weight = 600_000_000;
+
+			// receive the relay chain token.
+			let asset: Asset = (Location::parent(), 10 * dollar(RELAY_CHAIN_CURRENCY)).into();
+			let mut msg = Xcm(vec![
+				ReserveAssetDeposited(asset.clone().into()),
+				BuyExecution {
+					fees: asset,
+					weight_limit: Limited(Weight::from_parts(shallow_weight, 0)),
+				},
+				DepositAsset {
+					assets: AllCounted(u32::max_value()).into(),
+					beneficiary: Junction::AccountId32 {
+						network: None,
+						id: ALICE,
+					}
+					.into(),
+				},
+			]);
+			use xcm_executor::traits::WeightBounds;
+			let debt = <XcmConfig as xcm_executor::Config>::Weigher::weight(&mut msg).unwrap_or_default();
+			let mut hash = msg.using_encoded(sp_io::hashing::blake2_256);
+			assert_eq!(
+				XcmExecutor::<XcmConfig>::prepare_and_execute(Location::parent(), msg, &mut hash, debt, Weight::zero()),
+				Outcome::Complete { used: debt }
+			);
+
+			let received = Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE));
+			assert!(received > 9 * dollar(RELAY_CHAIN_CURRENCY));
+			assert_eq!(
+				Currencies::free_balance(RELAY_CHAIN_CURRENCY, &TreasuryAccount::get()),
+				10 * dollar(RELAY_CHAIN_CURRENCY) - received
+			);
+
+			// swap half of it.
+			let supply_amount = 5 * dollar(RELAY_CHAIN_CURRENCY);
+			assert_ok!(Dex::swap_with_exact_supply(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				vec![RELAY_CHAIN_CURRENCY, USD_CURRENCY],
+				supply_amount,
+				0,
+			));
+			let usd_received = Currencies::free_balance(USD_CURRENCY, &AccountId::from(ALICE));
+			System::assert_has_event(RuntimeEvent::Dex(module_dex::Event::Swap {
+				trader: AccountId::from(ALICE),
+				path: vec![RELAY_CHAIN_CURRENCY, USD_CURRENCY],
+				liquidity_changes: vec![supply_amount, usd_received],
+			}));
+			assert_eq!(
+				Dex::get_liquidity_pool(RELAY_CHAIN_CURRENCY, USD_CURRENCY),
+				(
+					100 * dollar(RELAY_CHAIN_CURRENCY) + supply_amount,
+					10_000 * dollar(USD_CURRENCY) - usd_received
+				)
+			);
+
+			// send the rest back to the relay chain.
+			set_relaychain_block_number(10);
+			let transfer_amount = received - supply_amount;
+			let total_issuance = Currencies::total_issuance(RELAY_CHAIN_CURRENCY);
+			assert_ok!(XTokens::transfer(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				RELAY_CHAIN_CURRENCY,
+				transfer_amount,
+				Box::new(Location::new(1, [Junction::AccountId32 { network: None, id: BOB }]).into()),
+				Unlimited,
+			));
+			assert_eq!(
+				Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+				0
+			);
+			// the relay chain is the reserve, the token is burnt locally.
+			assert_eq!(
+				Currencies::total_issuance(RELAY_CHAIN_CURRENCY),
+				total_issuance - transfer_amount
+			);
+			assert!(System::events().iter().any(|record| matches!(
+				&record.event,
+				RuntimeEvent::XTokens(orml_xtokens::Event::TransferredAssets { sender, .. })
+					if *sender == AccountId::from(ALICE)
+			)));
+		});
+}
@@ -32,6 +32,13 @@ mod setup;
 ))]
 mod authority;
 
+#[cfg(any(
+	feature = "with-mandala-runtime",
+	feature = "with-karura-runtime",
+	feature = "with-acala-runtime"
+))]
+mod democracy;
+
 #[cfg(any(
 	feature = "with-mandala-runtime",
 	feature = "with-karura-runtime",
@@ -39,6 +46,13 @@ mod authority;
 ))]
 mod dex;
 
+#[cfg(any(
+	feature = "with-mandala-runtime",
+	feature = "with-karura-runtime",
+	feature = "with-acala-runtime"
+))]
+mod error_registry;
+
 #[cfg(any(
 	feature = "with-mandala-runtime",
 	feature = "with-karura-runtime",
@@ -53,6 +67,13 @@ mod evm;
 ))]
 mod honzon;
 
+#[cfg(any(
+	feature = "with-mandala-runtime",
+	feature = "with-karura-runtime",
+	feature = "with-acala-runtime"
+))]
+mod journeys;
+
 #[cfg(any(
 	feature = "with-mandala-runtime",
 	feature = "with-karura-runtime",
@@ -95,6 +116,13 @@ mod session_manager;
 ))]
 mod stable_asset;
 
+#[cfg(any(
+	feature = "with-mandala-runtime",
+	feature = "with-karura-runtime",
+	feature = "with-acala-runtime"
+))]
+mod system_info;
+
 #[cfg(any(
 	feature = "with-mandala-runtime",
 	feature = "with-karura-runtime",
@@ -122,3 +150,9 @@ mod weights;
 	feature = "with-acala-runtime"
 ))]
 mod payment;
+
+#[cfg(feature = "with-karura-runtime")]
+mod xcm_fee;
+
+#[cfg(feature = "with-mandala-runtime")]
+mod dev_setup;
@@ -19,12 +19,15 @@
 use crate::setup::*;
 use crate::stable_asset::enable_stable_asset;
 use frame_support::{
-	dispatch::{DispatchClass, DispatchInfo, Pays, PostDispatchInfo},
+	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo},
+	traits::OnFinalize,
 	weights::Weight,
 };
-use module_support::AggregatedSwapPath;
+use module_support::{AggregatedSwapPath, PoolId};
+use module_transaction_payment::FeeCallCategory;
+use runtime_common::{BurnFeeRatio, CollatorFeeRatio, FeeParameters};
 use sp_runtime::{
-	traits::{AccountIdConversion, SignedExtension, UniqueSaturatedInto},
+	traits::{AccountIdConversion, Dispatchable, SignedExtension, UniqueSaturatedInto},
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
 	MultiAddress, Percent,
 };
@@ -94,6 +97,8 @@ fn add_liquidity(token1: CurrencyId, token2: CurrencyId, amount1: Balance, amoun
 		0,
 		false,
 	)
+	.map(|_| ())
+	.map_err(|e| e.error)
 }
 
 const CALL: <Runtime as frame_system::Config>::RuntimeCall =
@@ -121,6 +126,17 @@ pub fn with_fee_currency_call(currency_id: CurrencyId) -> <Runtime as module_tra
 	fee_call
 }
 
+pub fn with_fee_preference_call(
+	currency_id: CurrencyId,
+) -> <Runtime as module_transaction_payment::Config>::RuntimeCall {
+	let fee_call: <Runtime as module_transaction_payment::Config>::RuntimeCall =
+		RuntimeCall::TransactionPayment(module_transaction_payment::Call::with_fee_preference {
+			currency_id,
+			call: Box::new(CALL),
+		});
+	fee_call
+}
+
 pub fn with_fee_path_call(
 	fee_swap_path: Vec<CurrencyId>,
 ) -> <Runtime as module_transaction_payment::Config>::RuntimeCall {
@@ -538,3 +554,223 @@ fn with_fee_call_works(
 			return amount;
 		});
 }
+
+#[test]
+fn no_op_calls_in_batch_refund_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let no_op_calls = vec![
+			RuntimeCall::Incentives(module_incentives::Call::claim_rewards {
+				pool_id: PoolId::Loans(RELAY_CHAIN_CURRENCY),
+			}),
+			RuntimeCall::Incentives(module_incentives::Call::withdraw_dex_share {
+				lp_currency_id: LPTOKEN,
+				amount: 0,
+			}),
+			RuntimeCall::Honzon(module_honzon::Call::adjust_loan {
+				currency_id: RELAY_CHAIN_CURRENCY,
+				collateral_adjustment: 0,
+				debit_adjustment: 0,
+			}),
+			RuntimeCall::Dex(module_dex::Call::remove_liquidity {
+				currency_id_a: RELAY_CHAIN_CURRENCY,
+				currency_id_b: USD_CURRENCY,
+				remove_share: 0,
+				min_withdrawn_a: 0,
+				min_withdrawn_b: 0,
+				by_unstake: false,
+			}),
+			RuntimeCall::Homa(module_homa::Call::mint { amount: 0 }),
+		];
+		let batch_call = RuntimeCall::Utility(pallet_utility::Call::batch { calls: no_op_calls });
+		let info = batch_call.get_dispatch_info();
+		let len = batch_call.encoded_size() as u32;
+
+		let post_info = batch_call
+			.dispatch(RuntimeOrigin::signed(AccountId::from(ALICE)))
+			.unwrap();
+		assert!(post_info.actual_weight.unwrap().ref_time() < info.weight.ref_time());
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+		assert!(
+			module_transaction_payment::Pallet::<Runtime>::compute_actual_fee(len, &info, &post_info, 0)
+				< module_transaction_payment::Pallet::<Runtime>::compute_fee(len, &info, 0)
+		);
+
+		let no_op_events = System::events()
+			.iter()
+			.filter(|r| {
+				matches!(
+					r.event,
+					RuntimeEvent::Incentives(module_incentives::Event::NoOp { .. })
+						| RuntimeEvent::Honzon(module_honzon::Event::NoOp { .. })
+						| RuntimeEvent::Dex(module_dex::Event::NoOp { .. })
+						| RuntimeEvent::Homa(module_homa::Event::NoOp { .. })
+				)
+			})
+			.count();
+		assert_eq!(no_op_events, 5);
+		System::assert_last_event(RuntimeEvent::Utility(pallet_utility::Event::BatchCompleted));
+	});
+}
+
+fn set_fee_ratios(collator_ratio: Perbill, burn_ratio: Perbill) {
+	assert_ok!(Parameters::set_parameter(
+		RuntimeOrigin::root(),
+		RuntimeParameters::Fee(FeeParameters::CollatorFeeRatio(CollatorFeeRatio, Some(collator_ratio)))
+	));
+	assert_ok!(Parameters::set_parameter(
+		RuntimeOrigin::root(),
+		RuntimeParameters::Fee(FeeParameters::BurnFeeRatio(BurnFeeRatio, Some(burn_ratio)))
+	));
+}
+
+fn pay_transaction_fee(who: &AccountId, call: &<Runtime as module_transaction_payment::Config>::RuntimeCall) {
+	let pre = <module_transaction_payment::ChargeTransactionPayment<Runtime>>::from(0)
+		.pre_dispatch(who, call, &INFO, 50)
+		.unwrap();
+	assert_ok!(
+		<module_transaction_payment::ChargeTransactionPayment<Runtime>>::post_dispatch(
+			Some(pre),
+			&INFO,
+			&POST_INFO,
+			50,
+			&Ok(())
+		)
+	);
+}
+
+#[test]
+fn transaction_fees_distributed_by_ratios() {
+	let collator_pot = CollatorPotAccount::get();
+
+	ExtBuilder::default()
+		.balances(vec![
+			(
+				collator_pot.clone(),
+				NATIVE_CURRENCY,
+				NativeTokenExistentialDeposit::get(),
+			),
+			(AccountId::from(BOB), NATIVE_CURRENCY, 100 * dollar(NATIVE_CURRENCY)),
+			(AccountId::from(CHARLIE), USD_CURRENCY, 100 * dollar(USD_CURRENCY)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(add_liquidity(
+				USD_CURRENCY,
+				NATIVE_CURRENCY,
+				100 * dollar(USD_CURRENCY),
+				1000 * dollar(NATIVE_CURRENCY)
+			));
+			assert_ok!(init_charge_fee_pool(USD_CURRENCY));
+
+			// 20% to collators, 80% burned, nothing to treasury
+			set_fee_ratios(Perbill::from_percent(20), Perbill::from_percent(80));
+
+			// BOB pays in native token, CHARLIE pays in USD swapped by the charge fee pool
+			for (who, call) in [
+				(AccountId::from(BOB), CALL),
+				(AccountId::from(CHARLIE), with_fee_currency_call(USD_CURRENCY)),
+			] {
+				System::reset_events();
+				let pot_balance = Currencies::free_balance(NATIVE_CURRENCY, &collator_pot);
+				let total_issuance = Balances::total_issuance();
+
+				pay_transaction_fee(&who, &call);
+
+				let (to_treasury, to_collators, burned) = TransactionPayment::block_fees_distributed().unwrap();
+				let total = to_treasury + to_collators + burned;
+				assert!(total > 0);
+				assert_eq!(to_collators, Perbill::from_percent(20).mul_floor(total));
+				assert_eq!(burned, total - to_collators);
+				assert_eq!(to_treasury, 0);
+				assert_eq!(
+					Currencies::free_balance(NATIVE_CURRENCY, &collator_pot),
+					pot_balance + to_collators
+				);
+				assert_eq!(Balances::total_issuance(), total_issuance - burned);
+
+				TransactionPayment::on_finalize(System::block_number());
+				assert_eq!(TransactionPayment::block_fees_distributed(), None);
+				System::assert_last_event(RuntimeEvent::TransactionPayment(
+					module_transaction_payment::Event::FeesDistributed {
+						to_treasury,
+						to_collators,
+						burned,
+					},
+				));
+			}
+
+			// zero ratios leave the collator pot and the total issuance untouched
+			set_fee_ratios(Perbill::from_percent(0), Perbill::from_percent(0));
+			System::reset_events();
+			let pot_balance = Currencies::free_balance(NATIVE_CURRENCY, &collator_pot);
+
+			pay_transaction_fee(&AccountId::from(BOB), &CALL);
+
+			let (to_treasury, to_collators, burned) = TransactionPayment::block_fees_distributed().unwrap();
+			assert!(to_treasury > 0);
+			assert_eq!((to_collators, burned), (0, 0));
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY, &collator_pot), pot_balance);
+			assert!(!System::events().iter().any(|r| matches!(
+				r.event,
+				RuntimeEvent::Balances(pallet_balances::Event::Deposit { ref who, .. }) if *who == collator_pot
+			)));
+		});
+}
+
+#[test]
+fn fee_spend_tracking_classifies_batched_calls() {
+	ExtBuilder::default()
+		.balances(vec![(
+			AccountId::from(BOB),
+			NATIVE_CURRENCY,
+			100 * dollar(NATIVE_CURRENCY),
+		)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(TransactionPayment::enable_fee_spend_tracking(RuntimeOrigin::signed(
+				AccountId::from(BOB)
+			)));
+
+			let dex_call = RuntimeCall::Dex(module_dex::Call::swap_with_exact_supply {
+				path: vec![USD_CURRENCY, NATIVE_CURRENCY],
+				supply_amount: dollar(USD_CURRENCY),
+				min_target_amount: 0,
+			});
+			let native_transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+				dest: MultiAddress::Id(AccountId::from(ALICE)),
+				value: dollar(NATIVE_CURRENCY),
+			});
+			for call in [
+				RuntimeCall::Utility(pallet_utility::Call::batch_all {
+					calls: vec![CALL, native_transfer],
+				}),
+				RuntimeCall::Utility(pallet_utility::Call::batch {
+					calls: vec![CALL, dex_call.clone()],
+				}),
+				RuntimeCall::Utility(pallet_utility::Call::force_batch {
+					calls: vec![dex_call.clone(), dex_call],
+				}),
+			] {
+				pay_transaction_fee(&AccountId::from(BOB), &call);
+			}
+
+			let summary = TransactionPayment::get_fee_summary(
+				&AccountId::from(BOB),
+				TransactionPayment::current_fee_spend_period(),
+			);
+			assert_eq!(
+				summary
+					.iter()
+					.map(|spend| (spend.category, spend.currency_id, spend.count))
+					.collect::<Vec<_>>(),
+				vec![
+					(FeeCallCategory::Transfer, NATIVE_CURRENCY, 1),
+					(FeeCallCategory::Other, NATIVE_CURRENCY, 1),
+					(FeeCallCategory::Dex, NATIVE_CURRENCY, 1),
+				]
+			);
+			assert!(summary
+				.iter()
+				.all(|spend| spend.amount > 0 && spend.amount == spend.native_amount));
+		});
+}
@@ -17,7 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::setup::*;
-use module_prices::RealTimePriceProvider;
+use module_cdp_engine::CDPStatus;
+use module_prices::{PricingMode, PriorityLockedPriceProvider, RealTimePriceProvider};
 use module_support::PriceProvider;
 
 #[test]
@@ -111,3 +112,158 @@ fn test_update_liquid_currency_price() {
 			);
 		});
 }
+
+#[test]
+fn liquid_currency_collateral_priced_at_fair_value() {
+	ExtBuilder::default()
+		.balances(vec![(
+			AccountId::from(ALICE),
+			LIQUID_CURRENCY,
+			1_000 * dollar(LIQUID_CURRENCY),
+		)])
+		.build()
+		.execute_with(|| {
+			let fair_price = Price::saturating_from_integer(10) * DefaultExchangeRate::get();
+
+			// the market price deviates 20% from the fair value
+			set_oracle_price(vec![
+				(RELAY_CHAIN_CURRENCY, Price::saturating_from_integer(10)),
+				(LIQUID_CURRENCY, fair_price * Price::saturating_from_rational(80, 100)),
+			]);
+
+			assert_ok!(CdpEngine::set_collateral_params(
+				RuntimeOrigin::root(),
+				LIQUID_CURRENCY,
+				Change::NewValue(Some(Rate::zero())),
+				Change::NoChange,
+				Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
+				Change::NoChange,
+				Change::NewValue(1_000_000 * dollar(USD_CURRENCY)),
+			));
+			assert_ok!(CdpEngine::adjust_position(
+				&AccountId::from(ALICE),
+				LIQUID_CURRENCY,
+				(1_000 * dollar(LIQUID_CURRENCY)) as i128,
+				(2_500 * dollar(USD_CURRENCY)) as i128,
+			));
+			let position = Loans::positions(LIQUID_CURRENCY, AccountId::from(ALICE));
+
+			// liquidated when the collateral ratio is 10% lower than it at the fair value
+			let fair_collateral_ratio = CdpEngine::calculate_collateral_ratio(
+				LIQUID_CURRENCY,
+				position.collateral,
+				position.debit,
+				PriorityLockedPriceProvider::<Runtime>::get_relative_price(LIQUID_CURRENCY, USD_CURRENCY).unwrap(),
+			);
+			assert_ok!(CdpEngine::set_collateral_params(
+				RuntimeOrigin::root(),
+				LIQUID_CURRENCY,
+				Change::NoChange,
+				Change::NewValue(Some(fair_collateral_ratio * Ratio::saturating_from_rational(90, 100))),
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+			));
+
+			// priced at the fair value by default
+			assert_eq!(
+				CdpEngine::check_cdp_status(LIQUID_CURRENCY, position.collateral, position.debit),
+				CDPStatus::Safe
+			);
+			assert_noop!(
+				CdpEngine::liquidate_unsafe_cdp(AccountId::from(ALICE), LIQUID_CURRENCY),
+				module_cdp_engine::Error::<Runtime>::MustBeUnsafe
+			);
+
+			// the haircut is applied to the fair value
+			assert_ok!(module_prices::Pallet::<Runtime>::set_pricing_mode(
+				RuntimeOrigin::root(),
+				LIQUID_CURRENCY,
+				Some(PricingMode::LiquidStakingFairValue {
+					haircut: Permill::from_percent(5)
+				})
+			));
+			assert_eq!(
+				CdpEngine::check_cdp_status(LIQUID_CURRENCY, position.collateral, position.debit),
+				CDPStatus::Safe
+			);
+			assert_ok!(module_prices::Pallet::<Runtime>::set_pricing_mode(
+				RuntimeOrigin::root(),
+				LIQUID_CURRENCY,
+				Some(PricingMode::LiquidStakingFairValue {
+					haircut: Permill::from_percent(15)
+				})
+			));
+			assert_eq!(
+				CdpEngine::check_cdp_status(LIQUID_CURRENCY, position.collateral, position.debit),
+				CDPStatus::Unsafe
+			);
+
+			// liquidated at the market price
+			assert_ok!(module_prices::Pallet::<Runtime>::set_pricing_mode(
+				RuntimeOrigin::root(),
+				LIQUID_CURRENCY,
+				Some(PricingMode::Market)
+			));
+			assert_eq!(
+				CdpEngine::check_cdp_status(LIQUID_CURRENCY, position.collateral, position.debit),
+				CDPStatus::Unsafe
+			);
+			assert_ok!(CdpEngine::liquidate_unsafe_cdp(AccountId::from(ALICE), LIQUID_CURRENCY));
+			assert_eq!(Loans::positions(LIQUID_CURRENCY, AccountId::from(ALICE)).debit, 0);
+			assert_eq!(Loans::positions(LIQUID_CURRENCY, AccountId::from(ALICE)).collateral, 0);
+		});
+}
+
+#[cfg(feature = "with-mandala-runtime")]
+#[test]
+fn aggregated_price_follows_oracle_source_mode() {
+	use orml_traits::DataProvider;
+	use runtime_common::{OracleParameters, OracleSource, OracleSourceMode};
+
+	let set_mode = |mode: OracleSourceMode| {
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			RuntimeParameters::Oracle(OracleParameters::OracleSource(OracleSource(DOT), Some(mode)))
+		));
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let secondary_oracle = AccountId::from(ALICE);
+		assert_ok!(OperatorMembershipSecondary::add_member(
+			RuntimeOrigin::root(),
+			sp_runtime::MultiAddress::Id(secondary_oracle.clone())
+		));
+
+		set_oracle_price(vec![(DOT, Price::saturating_from_integer(10))]);
+		assert_eq!(
+			AggregatedDataProvider::get(&DOT),
+			Some(Price::saturating_from_integer(10))
+		);
+
+		set_mode(OracleSourceMode::SecondaryOnly);
+		assert_eq!(AggregatedDataProvider::get(&DOT), None);
+
+		set_mode(OracleSourceMode::PrimaryWithFallback);
+		assert_eq!(
+			AggregatedDataProvider::get(&DOT),
+			Some(Price::saturating_from_integer(10))
+		);
+
+		assert_ok!(SecondaryOracle::feed_values(
+			RuntimeOrigin::signed(secondary_oracle),
+			vec![(DOT, Price::saturating_from_integer(14))].try_into().unwrap(),
+		));
+		set_mode(OracleSourceMode::MedianOfBoth);
+		assert_eq!(
+			AggregatedDataProvider::get(&DOT),
+			Some(Price::saturating_from_integer(12))
+		);
+
+		set_mode(OracleSourceMode::SecondaryOnly);
+		assert_eq!(
+			AggregatedDataProvider::get(&DOT),
+			Some(Price::saturating_from_integer(14))
+		);
+	});
+}
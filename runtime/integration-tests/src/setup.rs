@@ -60,17 +60,19 @@ mod mandala_imports {
 	pub use mandala_runtime::xcm_config::*;
 	use mandala_runtime::AlternativeFeeSurplus;
 	pub use mandala_runtime::{
-		create_x2_parachain_location, get_all_module_accounts, AcalaOracle, AcalaSwap, AccountId, AggregatedDex,
-		AssetRegistry, AuctionManager, Aura, AuraExt, Authority, AuthoritysOriginId, Authorship, Balance, Balances,
-		BlockNumber, CDPEnginePalletId, CDPTreasuryPalletId, CdpEngine, CdpTreasury, CollatorSelection,
-		CreateClassDeposit, CreateTokenDeposit, Currencies, CurrencyId, DataDepositPerByte, DealWithFees,
-		DefaultDebitExchangeRate, DefaultExchangeRate, Dex, EmergencyShutdown, EvmAccounts, ExistentialDeposits,
-		FinancialCouncil, GetNativeCurrencyId, Homa, Honzon, IdleScheduler, Loans, MinRewardDistributeAmount,
-		MinimumDebitValue, NativeTokenExistentialDeposit, NftPalletId, OneDay, OriginCaller, ParachainInfo,
-		ParachainSystem, Proxy, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, Session, SessionKeys,
-		SessionManager, SevenDays, StableAsset, StableAssetPalletId, System, Timestamp, TokenSymbol, Tokens,
-		TransactionPayment, TransactionPaymentPalletId, TreasuryAccount, TreasuryPalletId, UncheckedExtrinsic, Utility,
-		Vesting, XcmInterface, EVM, NFT,
+		create_x2_parachain_location, get_all_module_accounts, AcalaOracle, AcalaSwap, AccountId,
+		AggregatedDataProvider, AggregatedDex, AssetRegistry, AuctionManager, Aura, AuraExt, Authority,
+		AuthoritysOriginId, Authorship, Balance, Balances, BlockNumber, CDPEnginePalletId, CDPTreasuryPalletId,
+		CdpEngine, CdpTreasury, CollatorPotAccount, CollatorSelection, CreateClassDeposit, CreateTokenDeposit,
+		Currencies, CurrencyId, DataDepositPerByte, DealWithFees, DefaultDebitExchangeRate, DefaultExchangeRate,
+		Democracy, Dex, DexOracle, Earning, EmergencyShutdown, EvmAccounts, ExistentialDeposits, FinancialCouncil,
+		GetNativeCurrencyId, Homa, Honzon, IdleScheduler, Incentives, Loans, MinRewardDistributeAmount,
+		MinimumDebitValue, NativeTokenExistentialDeposit, NftPalletId, OneDay, OperatorMembershipSecondary,
+		OriginCaller, ParachainInfo, ParachainSystem, Parameters, Proxy, Runtime, RuntimeCall, RuntimeEvent,
+		RuntimeOrigin, RuntimePalletErrors, RuntimeParameters, SafeMode, Scheduler, SecondaryOracle, Session,
+		SessionKeys, SessionManager, SevenDays, StableAsset, StableAssetPalletId, System, Timestamp, TokenSymbol,
+		Tokens, TransactionPause, TransactionPayment, TransactionPaymentPalletId, TreasuryAccount, TreasuryPalletId,
+		UncheckedExtrinsic, Utility, Vesting, XTokens, XcmInterface, EVM, NFT,
 	};
 	use primitives::TradingPair;
 	use runtime_common::{ACA, AUSD, DOT, LDOT};
@@ -108,14 +110,15 @@ mod karura_imports {
 	pub use karura_runtime::{
 		constants::parachains, create_x2_parachain_location, get_all_module_accounts, AcalaOracle, AcalaSwap,
 		AccountId, AggregatedDex, AssetRegistry, AuctionManager, Aura, AuraExt, Authority, AuthoritysOriginId, Balance,
-		Balances, BlockNumber, CDPEnginePalletId, CDPTreasuryPalletId, CdpEngine, CdpTreasury, CreateClassDeposit,
-		CreateTokenDeposit, Currencies, CurrencyId, DataDepositPerByte, DefaultDebitExchangeRate, DefaultExchangeRate,
-		Dex, EmergencyShutdown, EvmAccounts, ExistentialDeposits, FinancialCouncil, GetNativeCurrencyId, Homa, Honzon,
-		IdleScheduler, KaruraFoundationAccounts, Loans, MinimumDebitValue, NativeTokenExistentialDeposit, NftPalletId,
-		OneDay, OriginCaller, ParachainAccount, ParachainInfo, ParachainSystem, PolkadotXcm, Proxy, Runtime,
-		RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, Session, SessionManager, SevenDays, StableAsset,
-		StableAssetPalletId, System, Timestamp, TokenSymbol, Tokens, TransactionPayment, TransactionPaymentPalletId,
-		TreasuryPalletId, Utility, Vesting, XTokens, XcmInterface, EVM, NFT,
+		Balances, BlockNumber, CDPEnginePalletId, CDPTreasuryPalletId, CdpEngine, CdpTreasury, CollatorPotAccount,
+		CreateClassDeposit, CreateTokenDeposit, Currencies, CurrencyId, DataDepositPerByte, DefaultDebitExchangeRate,
+		DefaultExchangeRate, Democracy, Dex, Earning, EmergencyShutdown, EvmAccounts, ExistentialDeposits,
+		FinancialCouncil, GetNativeCurrencyId, Homa, Honzon, IdleScheduler, KaruraFoundationAccounts, Loans,
+		MinimumDebitValue, NativeTokenExistentialDeposit, NftPalletId, OneDay, OriginCaller, ParachainAccount,
+		ParachainInfo, ParachainSystem, Parameters, PolkadotXcm, Proxy, Runtime, RuntimeCall, RuntimeEvent,
+		RuntimeOrigin, RuntimePalletErrors, RuntimeParameters, Scheduler, Session, SessionManager, SevenDays,
+		StableAsset, StableAssetPalletId, System, Timestamp, TokenSymbol, Tokens, TransactionPause, TransactionPayment,
+		TransactionPaymentPalletId, TreasuryPalletId, Utility, Vesting, XTokens, XcmInterface, EVM, NFT,
 	};
 	use primitives::TradingPair;
 	use runtime_common::{KAR, KSM, KUSD, LKSM};
@@ -153,14 +156,14 @@ mod acala_imports {
 		constants::parachains, create_x2_parachain_location, get_all_module_accounts, AcalaFoundationAccounts,
 		AcalaOracle, AcalaSwap, AccountId, AggregatedDex, AssetRegistry, AuctionManager, Aura, AuraExt, Authority,
 		AuthoritysOriginId, Balance, Balances, BlockNumber, CDPEnginePalletId, CDPTreasuryPalletId, CdpEngine,
-		CdpTreasury, CreateClassDeposit, CreateTokenDeposit, Currencies, CurrencyId, DataDepositPerByte,
-		DefaultDebitExchangeRate, DefaultExchangeRate, Dex, EmergencyShutdown, EvmAccounts, ExistentialDeposits,
-		FinancialCouncil, GetNativeCurrencyId, Homa, Honzon, IdleScheduler, Loans, MinimumDebitValue,
-		NativeTokenExistentialDeposit, NftPalletId, OneDay, OriginCaller, ParachainAccount, ParachainInfo,
-		ParachainSystem, PolkadotXcm, Proxy, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, Session,
-		SessionManager, SevenDays, StableAsset, StableAssetPalletId, System, Timestamp, TokenSymbol, Tokens,
-		TransactionPayment, TransactionPaymentPalletId, TreasuryPalletId, Utility, Vesting, XTokens, XcmInterface, EVM,
-		NFT,
+		CdpTreasury, CollatorPotAccount, CreateClassDeposit, CreateTokenDeposit, Currencies, CurrencyId,
+		DataDepositPerByte, DefaultDebitExchangeRate, DefaultExchangeRate, Democracy, Dex, Earning, EmergencyShutdown,
+		EvmAccounts, ExistentialDeposits, FinancialCouncil, GetNativeCurrencyId, Homa, Honzon, IdleScheduler, Loans,
+		MinimumDebitValue, NativeTokenExistentialDeposit, NftPalletId, OneDay, OriginCaller, ParachainAccount,
+		ParachainInfo, ParachainSystem, Parameters, PolkadotXcm, Proxy, Runtime, RuntimeCall, RuntimeEvent,
+		RuntimeOrigin, RuntimePalletErrors, RuntimeParameters, Scheduler, Session, SessionManager, SevenDays,
+		StableAsset, StableAssetPalletId, System, Timestamp, TokenSymbol, Tokens, TransactionPause, TransactionPayment,
+		TransactionPaymentPalletId, TreasuryPalletId, Utility, Vesting, XTokens, XcmInterface, EVM, NFT,
 	};
 	use frame_support::parameter_types;
 	use primitives::TradingPair;
@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::evm::alice_evm_addr;
-use crate::payment::{with_fee_aggregated_path_call, with_fee_currency_call, with_fee_path_call, INFO, POST_INFO};
+use crate::payment::{with_fee_aggregated_path_call, with_fee_path_call, with_fee_preference_call, INFO, POST_INFO};
 use crate::setup::*;
 use module_aggregated_dex::SwapPath;
 use module_support::{AggregatedSwapPath, ExchangeRate, Swap, SwapLimit, EVM as EVMTrait};
@@ -55,7 +55,8 @@ pub fn enable_stable_asset(currencies: Vec<CurrencyId>, amounts: Vec<u128>, mint
 	};
 	assert_ok!(AssetRegistry::register_stable_asset(
 		RawOrigin::Root.into(),
-		Box::new(asset_metadata.clone())
+		Box::new(asset_metadata.clone()),
+		false
 	));
 
 	assert_ok!(StableAsset::mint(
@@ -121,7 +122,8 @@ fn stable_asset_mint_overflow() {
 			};
 			assert_ok!(AssetRegistry::register_stable_asset(
 				RawOrigin::Root.into(),
-				Box::new(asset_metadata.clone())
+				Box::new(asset_metadata.clone()),
+				false
 			));
 
 			assert_noop!(
@@ -406,7 +408,8 @@ fn three_usd_pool_works() {
 					symbol: b"USDT".to_vec(),
 					decimals: 12,
 					minimal_balance
-				})
+				}),
+				false
 			));
 			// deposit USDT to alith, used for liquidity provider
 			assert_ok!(Currencies::deposit(usdt, &alith, 1_000_000 * dollar));
@@ -542,7 +545,7 @@ fn three_usd_pool_works() {
 			let set_evm_origin = module_evm::SetEvmOrigin::<Runtime>::new();
 			let pre = set_evm_origin
 				.clone()
-				.pre_dispatch(&AccountId::from(BOB), &with_fee_currency_call(usdc), &INFO, 50)
+				.pre_dispatch(&AccountId::from(BOB), &with_fee_preference_call(usdc), &INFO, 50)
 				.unwrap();
 
 			let origin = <module_evm_bridge::EVMBridge<Runtime> as module_support::evm::EVMBridge<
@@ -568,7 +571,7 @@ fn three_usd_pool_works() {
 			assert_noop!(
 				<module_transaction_payment::ChargeTransactionPayment::<Runtime>>::from(0).validate(
 					&AccountId::from(BOB),
-					&with_fee_currency_call(usdc),
+					&with_fee_preference_call(usdc),
 					&INFO,
 					50
 				),
@@ -576,23 +579,24 @@ fn three_usd_pool_works() {
 			);
 
 			// set origin in SetEvmOrigin::validate() then transfer erc20 will success.
-			assert_ok!(set_evm_origin.validate(&AccountId::from(BOB), &with_fee_currency_call(usdc), &INFO, 50));
+			assert_ok!(set_evm_origin.validate(&AccountId::from(BOB), &with_fee_preference_call(usdc), &INFO, 50));
 			let origin = <module_evm_bridge::EVMBridge<Runtime> as module_support::evm::EVMBridge<
 				AccountId,
 				Balance,
 			>>::get_origin();
 			assert_eq!(origin, Some(AccountId::from(BOB)));
 
-			// USDC=Erc20(contract) or USDT=ForeignAsset(0) as fee token.
+			// USDC=Erc20(contract) or USDT=ForeignAsset(0) as fee token, BOB has enough native token so
+			// the fee is charged by `with_fee_preference`.
 			// before USDC/USDT enabled as fee pool, it works by direct swap.
-			assert_aggregated_dex_event(usdc, with_fee_currency_call(usdc), None);
-			assert_aggregated_dex_event(usdt, with_fee_currency_call(usdt), None);
+			assert_aggregated_dex_event(usdc, with_fee_preference_call(usdc), None);
+			assert_aggregated_dex_event(usdt, with_fee_preference_call(usdt), None);
 
 			// AUSD as fee token, only dex swap event produced.
 			assert_ok!(
 				<module_transaction_payment::ChargeTransactionPayment::<Runtime>>::from(0).validate(
 					&AccountId::from(BOB),
-					&with_fee_currency_call(USD_CURRENCY),
+					&with_fee_preference_call(USD_CURRENCY),
 					&INFO,
 					50
 				)
@@ -732,7 +736,7 @@ fn three_usd_pool_works() {
 			assert_ok!(
 				<module_transaction_payment::ChargeTransactionPayment<Runtime>>::from(0).validate(
 					&AccountId::from(BOB),
-					&with_fee_currency_call(usdt),
+					&with_fee_preference_call(usdt),
 					&INFO,
 					len as usize,
 				)
@@ -740,7 +744,7 @@ fn three_usd_pool_works() {
 			assert_ok!(
 				<module_transaction_payment::ChargeTransactionPayment<Runtime>>::from(0).validate(
 					&AccountId::from(BOB),
-					&with_fee_currency_call(usdc),
+					&with_fee_preference_call(usdc),
 					&INFO,
 					len as usize,
 				)
@@ -765,7 +769,7 @@ fn three_usd_pool_works() {
 			assert_ok!(
 				<module_transaction_payment::ChargeTransactionPayment<Runtime>>::from(0).validate(
 					&AccountId::from(BOB),
-					&with_fee_currency_call(usdt),
+					&with_fee_preference_call(usdt),
 					&INFO,
 					len as usize,
 				)
@@ -773,15 +777,15 @@ fn three_usd_pool_works() {
 			assert_ok!(
 				<module_transaction_payment::ChargeTransactionPayment<Runtime>>::from(0).validate(
 					&AccountId::from(BOB),
-					&with_fee_currency_call(usdc),
+					&with_fee_preference_call(usdc),
 					&INFO,
 					len as usize,
 				)
 			);
 
 			// when sub-account has not enough native token, trigger swap
-			assert_aggregated_dex_event(usdt, with_fee_currency_call(usdt), Some(len as usize));
-			assert_aggregated_dex_event(usdc, with_fee_currency_call(usdc), Some(len as usize));
+			assert_aggregated_dex_event(usdt, with_fee_preference_call(usdt), Some(len as usize));
+			assert_aggregated_dex_event(usdc, with_fee_preference_call(usdc), Some(len as usize));
 		});
 }
 
@@ -831,7 +835,8 @@ pub fn deploy_erc20_contracts() {
 	assert_ok!(AssetRegistry::register_erc20_asset(
 		RuntimeOrigin::root(),
 		erc20_address_0(),
-		100_000_000_000
+		100_000_000_000,
+		false
 	));
 }
 
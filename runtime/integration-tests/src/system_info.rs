@@ -0,0 +1,129 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use frame_support::storage::{storage_prefix, unhashed};
+use runtime_common::{system_info::get_chain_status, AuthorizedUpgradeStatus, ChainStatus};
+use sp_core::{storage::well_known_keys, H256};
+
+#[cfg(feature = "with-mandala-runtime")]
+type RuntimeSafeMode = SafeMode;
+#[cfg(any(feature = "with-karura-runtime", feature = "with-acala-runtime"))]
+type RuntimeSafeMode = ();
+
+fn chain_status() -> ChainStatus<H256, BlockNumber> {
+	get_chain_status::<Runtime, RuntimeSafeMode>()
+}
+
+#[test]
+fn system_info_reports_upgrade_status() {
+	ExtBuilder::default().build().execute_with(|| {
+		sp_io::storage::set(well_known_keys::CODE, b"current code");
+
+		let status = chain_status();
+		assert_eq!(status.authorized_upgrade, None);
+		assert!(!status.pending_validation_code);
+		assert!(!status.upgrade_restricted);
+
+		let new_code_hash = BlakeTwo256::hash(b"new code");
+		assert_ok!(System::authorize_upgrade(RuntimeOrigin::root(), new_code_hash));
+		assert_eq!(
+			chain_status().authorized_upgrade,
+			Some(AuthorizedUpgradeStatus {
+				code_hash: new_code_hash,
+				check_version: true,
+				enacted: false,
+			})
+		);
+
+		let current_code_hash = BlakeTwo256::hash(b"current code");
+		assert_ok!(System::authorize_upgrade_without_checks(
+			RuntimeOrigin::root(),
+			current_code_hash
+		));
+		assert_eq!(
+			chain_status().authorized_upgrade,
+			Some(AuthorizedUpgradeStatus {
+				code_hash: current_code_hash,
+				check_version: false,
+				enacted: true,
+			})
+		);
+
+		unhashed::put(
+			&storage_prefix(b"ParachainSystem", b"PendingValidationCode"),
+			&b"new code".to_vec(),
+		);
+		assert!(chain_status().pending_validation_code);
+
+		// `Some(UpgradeRestriction::Present)`
+		unhashed::put(
+			&storage_prefix(b"ParachainSystem", b"UpgradeRestrictionSignal"),
+			&Some(0u8),
+		);
+		assert!(chain_status().upgrade_restricted);
+	});
+}
+
+#[test]
+fn system_info_reports_session() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(chain_status().session_index, 0);
+
+		run_to_block(12);
+		let status = chain_status();
+		assert_eq!(status.session_index, 1);
+		assert_eq!(status.blocks_to_next_session, Some(8));
+	});
+}
+
+#[test]
+fn system_info_reports_pause_status() {
+	ExtBuilder::default().build().execute_with(|| {
+		let status = chain_status();
+		assert!(!status.safe_mode_active);
+		assert!(!status.emergency_shutdown);
+		assert_eq!(status.paused_calls, 0);
+		assert_eq!(status.paused_evm_precompiles, 0);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			b"transfer_allow_death".to_vec()
+		));
+		assert_ok!(TransactionPause::pause_evm_precompile(
+			RuntimeOrigin::root(),
+			H160::from_low_u64_be(1)
+		));
+		let status = chain_status();
+		assert_eq!(status.paused_calls, 1);
+		assert_eq!(status.paused_evm_precompiles, 1);
+
+		assert_ok!(EmergencyShutdown::emergency_shutdown(RuntimeOrigin::root()));
+		assert!(chain_status().emergency_shutdown);
+
+		#[cfg(feature = "with-mandala-runtime")]
+		{
+			module_safe_mode::State::<Runtime>::put(module_safe_mode::SafeModeState::Active {
+				entered_at: 1,
+				cleared_at: None,
+			});
+			assert!(chain_status().safe_mode_active);
+		}
+	});
+}
@@ -0,0 +1,125 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND;
+use karura_runtime::constants::fee::kar_per_second;
+use module_xcm_interface::XcmInterfaceOperation;
+use runtime_common::xcm_impl::estimate_transfer_fee;
+
+fn set_parachain_fee(location: Location, fee: Balance) {
+	assert_ok!(XcmInterface::update_xcm_dest_weight_and_fee(
+		RuntimeOrigin::root(),
+		vec![(XcmInterfaceOperation::ParachainFee(Box::new(location)), None, Some(fee))],
+	));
+}
+
+fn local_fee_at_kar_rate(instructions: u64) -> Balance {
+	kar_per_second() * (UnitWeightCost::get().ref_time() * instructions) as u128 / WEIGHT_REF_TIME_PER_SECOND as u128
+}
+
+#[test]
+fn estimate_transfer_fee_to_asset_hub_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_hub = Location::new(1, Parachain(parachains::asset_hub_kusama::ID));
+		let usdt = Location::new(
+			1,
+			[
+				Parachain(parachains::asset_hub_kusama::ID),
+				PalletInstance(50),
+				GeneralIndex(1984),
+			],
+		);
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			RuntimeOrigin::root(),
+			Box::new(usdt.into()),
+			Box::new(AssetMetadata {
+				name: b"Tether USD".to_vec(),
+				symbol: b"USDT".to_vec(),
+				decimals: 6,
+				// same as KAR, so weight is priced at the KAR rate
+				minimal_balance: NativeTokenExistentialDeposit::get(),
+			}),
+			false
+		));
+		let dest = asset_hub
+			.clone()
+			.appended_with(AccountId32 {
+				network: None,
+				id: bob().into(),
+			})
+			.unwrap();
+
+		// transfer to reserve: WithdrawAsset + InitiateReserveWithdraw
+		let breakdown =
+			estimate_transfer_fee::<Runtime, XcmConfig>(CurrencyId::ForeignAsset(0), 1_000_000_000, dest.clone())
+				.unwrap();
+		assert_eq!(breakdown.local_execution_weight, UnitWeightCost::get() * 2);
+		assert_eq!(breakdown.local_execution_fee, local_fee_at_kar_rate(2));
+		assert_eq!(breakdown.delivery_fee, 0);
+		assert_eq!(breakdown.remote_execution_fee, None);
+		assert_eq!(breakdown.total, local_fee_at_kar_rate(2));
+
+		set_parachain_fee(asset_hub, 20_000);
+		let breakdown =
+			estimate_transfer_fee::<Runtime, XcmConfig>(CurrencyId::ForeignAsset(0), 1_000_000_000, dest).unwrap();
+		assert_eq!(breakdown.remote_execution_fee, Some(20_000));
+		assert_eq!(breakdown.total, local_fee_at_kar_rate(2) + 20_000);
+	});
+}
+
+#[test]
+fn estimate_transfer_fee_to_sibling_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let sibling = Location::new(1, Parachain(2001));
+		let dest = sibling
+			.clone()
+			.appended_with(AccountId32 {
+				network: None,
+				id: bob().into(),
+			})
+			.unwrap();
+
+		// transfer self reserve asset: TransferReserveAsset
+		let breakdown =
+			estimate_transfer_fee::<Runtime, XcmConfig>(NATIVE_CURRENCY, 10 * dollar(NATIVE_CURRENCY), dest.clone())
+				.unwrap();
+		assert_eq!(breakdown.local_execution_weight, UnitWeightCost::get());
+		assert_eq!(breakdown.local_execution_fee, local_fee_at_kar_rate(1));
+		// no delivery fee for sibling chains
+		assert_eq!(breakdown.delivery_fee, 0);
+		assert_eq!(breakdown.remote_execution_fee, None);
+		assert_eq!(breakdown.total, local_fee_at_kar_rate(1));
+
+		set_parachain_fee(sibling, 8_000_000_000);
+		let breakdown =
+			estimate_transfer_fee::<Runtime, XcmConfig>(NATIVE_CURRENCY, 10 * dollar(NATIVE_CURRENCY), dest).unwrap();
+		assert_eq!(breakdown.remote_execution_fee, Some(8_000_000_000));
+		assert_eq!(breakdown.total, local_fee_at_kar_rate(1) + 8_000_000_000);
+
+		// the destination must contain a recipient
+		assert_eq!(
+			estimate_transfer_fee::<Runtime, XcmConfig>(
+				NATIVE_CURRENCY,
+				10 * dollar(NATIVE_CURRENCY),
+				Location::new(1, Parachain(2001))
+			),
+			None
+		);
+	});
+}
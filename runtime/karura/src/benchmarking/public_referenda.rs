@@ -0,0 +1,75 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	AccountId, GetNativeCurrencyId, PublicReferenda, PublicReferendumDeposit, PublicReferendumVotingPeriod, Runtime,
+	RuntimeCall, System,
+};
+
+use super::utils::set_balance;
+use frame_benchmarking::account;
+use frame_support::traits::{Get, OnFinalize};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn remark_call() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_public_referenda }
+
+	whitelist_call {
+	}: _(RawOrigin::Root, b"System".to_vec(), b"remark".to_vec())
+
+	remove_whitelisted_call {
+		PublicReferenda::whitelist_call(RawOrigin::Root.into(), b"System".to_vec(), b"remark".to_vec())?;
+	}: _(RawOrigin::Root, b"System".to_vec(), b"remark".to_vec())
+
+	submit {
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(GetNativeCurrencyId::get(), &caller, 2 * PublicReferendumDeposit::get());
+		PublicReferenda::whitelist_call(RawOrigin::Root.into(), b"System".to_vec(), b"remark".to_vec())?;
+	}: _(RawOrigin::Signed(caller), remark_call())
+
+	on_finalize {
+		let n in 1 .. <Runtime as module_public_referenda::Config>::MaxEndingPerBlock::get();
+
+		PublicReferenda::whitelist_call(RawOrigin::Root.into(), b"System".to_vec(), b"remark".to_vec())?;
+		for i in 0 .. n {
+			let caller: AccountId = account("caller", i, SEED);
+			set_balance(GetNativeCurrencyId::get(), &caller, 2 * PublicReferendumDeposit::get());
+			PublicReferenda::submit(RawOrigin::Signed(caller).into(), remark_call())?;
+		}
+		let end = System::block_number() + PublicReferendumVotingPeriod::get();
+	}: {
+		PublicReferenda::on_finalize(end - 1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
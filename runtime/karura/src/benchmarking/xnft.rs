@@ -0,0 +1,87 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, Runtime, XNFT};
+
+use frame_benchmarking::whitelisted_caller;
+use frame_system::RawOrigin;
+use module_xnft::RoyaltyInfo;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::nft::{ClassProperty, Properties};
+use sp_runtime::Permill;
+use sp_std::{boxed::Box, prelude::*};
+use xcm::{v3, v4::prelude::*, VersionedAssetId};
+
+fn foreign_nft_asset(index: u8) -> Box<VersionedAssetId> {
+	Box::new(VersionedAssetId::V4(AssetId(Location::new(
+		1,
+		[Parachain(2000), PalletInstance(42), GeneralIndex(index.into())],
+	))))
+}
+
+fn royalty() -> RoyaltyInfo {
+	RoyaltyInfo {
+		rate: Permill::from_percent(5),
+		beneficiary: v3::Location::new(
+			1,
+			v3::Junctions::X2(
+				v3::Junction::Parachain(2000),
+				v3::Junction::AccountId32 {
+					network: None,
+					id: [1u8; 32],
+				},
+			),
+		),
+	}
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_xnft }
+
+	register_asset {
+		// another class registered before, so the class id is not the default one
+		XNFT::register_asset(RawOrigin::Root.into(), foreign_nft_asset(0))?;
+	}: _(RawOrigin::Root, foreign_nft_asset(1))
+
+	set_class_royalty {
+		let caller: AccountId = whitelisted_caller();
+		let class_id = orml_nft::Pallet::<Runtime>::create_class(
+			&caller,
+			Vec::new(),
+			module_nft::ClassData {
+				deposit: Default::default(),
+				properties: Properties(ClassProperty::Transferable | ClassProperty::Burnable),
+				attributes: Default::default(),
+			},
+		)?;
+	}: _(RawOrigin::Signed(caller), class_id, Some(royalty()))
+
+	// worst case: the collection is registered
+	set_foreign_collection_royalty {
+		XNFT::register_asset(RawOrigin::Root.into(), foreign_nft_asset(0))?;
+	}: _(RawOrigin::Root, foreign_nft_asset(0), Some(royalty()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -52,13 +52,15 @@ use sp_version::RuntimeVersion;
 
 use frame_system::{EnsureRoot, EnsureSigned, RawOrigin};
 use module_asset_registry::{AssetIdMaps, EvmErc20InfoMapping};
-use module_cdp_engine::CollateralCurrencyIds;
+use module_cdp_engine::{CDPEngineTask, CollateralCurrencyIds};
 use module_currencies::BasicCurrencyAdapter;
+use module_emergency_shutdown::EmergencyShutdownTask;
 use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
+use module_nft::NftTask;
 use module_relaychain::RelayChainCallBuilder;
 use module_support::{AddressMapping, AssetIdMapping, DispatchableTask, ExchangeRateProvider, FractionalRate, PoolId};
-use module_transaction_payment::TargetedFeeAdjustment;
+use module_transaction_payment::{ClassifyFeeCall, FeeCallCategory, TargetedFeeAdjustment};
 
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
 use orml_traits::{
@@ -74,7 +76,7 @@ use frame_support::{
 	traits::{
 		fungible::HoldConsideration,
 		tokens::{PayFromAccount, UnityAssetBalanceConversion},
-		ConstBool, ConstU128, ConstU32, ConstU64, Contains, ContainsLengthBound, Currency as PalletCurrency, Currency,
+		ConstBool, ConstU128, ConstU32, ConstU64, Contains, ContainsLengthBound, Currency as PalletCurrency,
 		EnsureOrigin, EqualPrivilegeOnly, Get, Imbalance, InstanceFilter, LinearStoragePrice, LockIdentifier,
 		OnUnbalanced, SortedMembers,
 	},
@@ -93,8 +95,9 @@ pub use primitives::{
 	currency::AssetIds,
 	define_combined_task,
 	evm::{
-		decode_gas_limit, decode_gas_price, AccessListItem, BlockLimits, EstimateResourcesRequest,
-		EthereumTransactionMessage, EvmAddress,
+		decode_gas_limit, decode_gas_price, AccessListItem, BlockLimits, ContractPurgeProgress,
+		EstimateResourcesRequest, EstimateResourcesResponse, EthCallRequest, EthereumTransactionMessage, EvmAddress,
+		EvmTransactionIndex,
 	},
 	task::TaskResult,
 	unchecked_extrinsic::AcalaUncheckedExtrinsic,
@@ -110,8 +113,8 @@ use runtime_common::{
 	EnsureRootOrTwoThirdsGeneralCouncil, EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate,
 	ExistentialDepositsTimesOneHundred, FinancialCouncilInstance, FinancialCouncilMembershipInstance, GasToWeight,
 	GeneralCouncilInstance, GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance,
-	MaxTipsOfPriority, OperationalFeeMultiplier, OperatorMembershipInstanceAcala, Price, ProxyType, RandomnessSource,
-	Rate, Ratio, RuntimeBlockLength, RuntimeBlockWeights, TechnicalCommitteeInstance,
+	MaxTipsOfPriority, OperationalFeeMultiplier, OperatorMembershipInstanceAcala, OraclePriceTimestamp, Price,
+	ProxyType, RandomnessSource, Rate, Ratio, RuntimeBlockLength, RuntimeBlockWeights, TechnicalCommitteeInstance,
 	TechnicalCommitteeMembershipInstance, TimeStampedPrice, TipPerWeightStep, KAR, KSM, KUSD, LKSM, TAI,
 };
 use xcm::v4::prelude::*;
@@ -122,6 +125,10 @@ pub use nutsfinance_stable_asset;
 mod authority;
 mod benchmarking;
 pub mod constants;
+/// The breakdown of the reserved balances by the features reserving them.
+pub mod reserves {
+	include!("../../mandala/src/reserves.rs");
+}
 /// Weights for pallets used in the runtime.
 mod weights;
 pub mod xcm_config;
@@ -357,6 +364,8 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = ();
 }
 
+type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
+
 // pallet-treasury did not impl OnUnbalanced<Credit>, need an adapter to handle dust.
 type CreditOf = frame_support::traits::fungible::Credit<<Runtime as frame_system::Config>::AccountId, Balances>;
 pub struct DustRemovalAdapter;
@@ -432,7 +441,7 @@ impl pallet_collective::Config<GeneralCouncilInstance> for Runtime {
 	type MaxMembers = CouncilDefaultMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
 	type SetMembersOrigin = EnsureRoot<AccountId>;
-	type WeightInfo = ();
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type MaxProposalWeight = MaxProposalWeight;
 }
 
@@ -446,7 +455,7 @@ impl pallet_membership::Config<GeneralCouncilMembershipInstance> for Runtime {
 	type MembershipInitialized = GeneralCouncil;
 	type MembershipChanged = GeneralCouncil;
 	type MaxMembers = CouncilDefaultMaxMembers;
-	type WeightInfo = ();
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -462,7 +471,7 @@ impl pallet_collective::Config<FinancialCouncilInstance> for Runtime {
 	type MaxMembers = CouncilDefaultMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
 	type SetMembersOrigin = EnsureRoot<AccountId>;
-	type WeightInfo = ();
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type MaxProposalWeight = MaxProposalWeight;
 }
 
@@ -476,7 +485,7 @@ impl pallet_membership::Config<FinancialCouncilMembershipInstance> for Runtime {
 	type MembershipInitialized = FinancialCouncil;
 	type MembershipChanged = FinancialCouncil;
 	type MaxMembers = CouncilDefaultMaxMembers;
-	type WeightInfo = ();
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -492,7 +501,7 @@ impl pallet_collective::Config<HomaCouncilInstance> for Runtime {
 	type MaxMembers = CouncilDefaultMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
 	type SetMembersOrigin = EnsureRoot<AccountId>;
-	type WeightInfo = ();
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type MaxProposalWeight = MaxProposalWeight;
 }
 
@@ -506,7 +515,7 @@ impl pallet_membership::Config<HomaCouncilMembershipInstance> for Runtime {
 	type MembershipInitialized = HomaCouncil;
 	type MembershipChanged = HomaCouncil;
 	type MaxMembers = CouncilDefaultMaxMembers;
-	type WeightInfo = ();
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -522,7 +531,7 @@ impl pallet_collective::Config<TechnicalCommitteeInstance> for Runtime {
 	type MaxMembers = CouncilDefaultMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
 	type SetMembersOrigin = EnsureRoot<AccountId>;
-	type WeightInfo = ();
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type MaxProposalWeight = MaxProposalWeight;
 }
 
@@ -536,7 +545,7 @@ impl pallet_membership::Config<TechnicalCommitteeMembershipInstance> for Runtime
 	type MembershipInitialized = TechnicalCommittee;
 	type MembershipChanged = TechnicalCommittee;
 	type MaxMembers = CouncilDefaultMaxMembers;
-	type WeightInfo = ();
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_membership::Config<OperatorMembershipInstanceAcala> for Runtime {
@@ -549,7 +558,7 @@ impl pallet_membership::Config<OperatorMembershipInstanceAcala> for Runtime {
 	type MembershipInitialized = ();
 	type MembershipChanged = AcalaOracle;
 	type MaxMembers = ConstU32<50>;
-	type WeightInfo = ();
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -687,7 +696,11 @@ parameter_types! {
 
 impl pallet_democracy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type Currency = Balances;
+	type Currency = runtime_common::BoostedVotingCurrency<
+		Runtime,
+		Balances,
+		ParameterStoreAdapter<Parameters, runtime_common::GovernanceParameters>,
+	>;
 	type EnactmentPeriod = EnactmentPeriod;
 	type LaunchPeriod = LaunchPeriod;
 	type VotingPeriod = VotingPeriod;
@@ -717,7 +730,7 @@ impl pallet_democracy::Config for Runtime {
 	type VetoOrigin = pallet_collective::EnsureMember<AccountId, TechnicalCommitteeInstance>;
 	type CooloffPeriod = CooloffPeriod;
 	type Slash = Treasury;
-	type Scheduler = Scheduler;
+	type Scheduler = module_scheduler_index::IndexedScheduler<Runtime>;
 	type PalletsOrigin = OriginCaller;
 	type MaxVotes = ConstU32<100>;
 	//TODO: might need to weight for Karura
@@ -729,6 +742,26 @@ impl pallet_democracy::Config for Runtime {
 	type SubmitOrigin = EnsureSigned<AccountId>;
 }
 
+parameter_types! {
+	pub PublicReferendumDeposit: Balance = 1000 * dollar(KAR);
+	pub const PublicReferendumVotingPeriod: BlockNumber = 10 * DAYS;
+	pub PublicReferendumMinimumTurnout: Perbill = Perbill::from_percent(1);
+}
+
+impl module_public_referenda::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type SubmissionDeposit = PublicReferendumDeposit;
+	type PublicVotingPeriod = PublicReferendumVotingPeriod;
+	type PublicEnactmentPeriod = EnactmentPeriod;
+	type MinimumTurnout = PublicReferendumMinimumTurnout;
+	type MaxEndingPerBlock = ConstU32<10>;
+	type WhitelistOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type Slash = Treasury;
+	type WeightInfo = weights::module_public_referenda::WeightInfo<Runtime>;
+}
+
 impl orml_auction::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -742,7 +775,7 @@ impl orml_authority::Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type PalletsOrigin = OriginCaller;
 	type RuntimeCall = RuntimeCall;
-	type Scheduler = Scheduler;
+	type Scheduler = module_scheduler_index::IndexedScheduler<Runtime>;
 	type AsOriginId = AuthoritysOriginId;
 	type AuthorityConfig = AuthorityConfigImpl;
 	type WeightInfo = weights::orml_authority::WeightInfo<Runtime>;
@@ -750,6 +783,7 @@ impl orml_authority::Config for Runtime {
 
 parameter_types! {
 	pub const MinimumCount: u32 = 5;
+	pub const RelaxedMinimumCount: u32 = 3;
 	pub const ExpiresIn: Moment = 1000 * 60 * 60; // 1 hours
 	pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
 	pub const MaxFeedValues: u32 = 10; // max 10 values allowd to feed in one call.
@@ -768,7 +802,12 @@ type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OnNewData = ();
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
+	type CombineData = orml_oracle::DefaultCombineData<
+		Runtime,
+		module_oracle_operator::CurrentMinimumCount<Runtime>,
+		ExpiresIn,
+		AcalaDataProvider,
+	>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
 	type OracleValue = Price;
@@ -781,6 +820,38 @@ impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type BenchmarkHelper = BenchmarkHelper;
 }
 
+parameter_types! {
+	pub OracleOperatorMinimumBond: Balance = 100 * dollar(KAR);
+	pub const OracleOperatorUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub const OracleRotationGracePeriod: BlockNumber = HOURS;
+	pub const OracleFailoverThreshold: BlockNumber = 10 * MINUTES;
+}
+
+impl module_oracle_operator::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Operators = OperatorMembershipAcala;
+	type Oracle = AcalaOracle;
+	type MinimumBond = OracleOperatorMinimumBond;
+	type UnbondingPeriod = OracleOperatorUnbondingPeriod;
+	type MaxFeedsPerBlock = ConstU32<1>;
+	type MaxFeedValues = MaxFeedValues;
+	type FeedPriority =
+		runtime_common::OracleFeedPriority<ParameterStoreAdapter<Parameters, runtime_common::PriorityParameters>>;
+	type MaxLiquidationHints = ConstU32<10>;
+	type Liquidator = runtime_common::CdpEngineLiquidator<Runtime>;
+	type SlashOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type OnSlash = Treasury;
+	type OperatorMembership = runtime_common::MembershipOperators<Runtime, OperatorMembershipInstanceAcala>;
+	type OperatorValues = runtime_common::OracleRawValues<Runtime, AcalaDataProvider>;
+	type MinimumCount = MinimumCount;
+	type RelaxedMinimumCount = RelaxedMinimumCount;
+	type RotationGracePeriod = OracleRotationGracePeriod;
+	type FailoverThreshold = OracleFailoverThreshold;
+	type RotateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type WeightInfo = weights::module_oracle_operator::WeightInfo<Runtime>;
+}
+
 create_median_value_data_provider!(
 	AggregatedDataProvider,
 	CurrencyId,
@@ -889,6 +960,8 @@ parameter_type_with_key! {
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
 	pub RewardRatePerRelaychainBlock: Rate = Rate::saturating_from_rational(3_068, 100_000_000_000u128);	// 17.5% annual staking reward rate of Kusama
+	pub const MaxLockPriceDeviation: Permill = Permill::from_percent(20);
+	pub const MaxPriceLockDuration: BlockNumber = 7 * DAYS;
 }
 
 impl module_prices::Config for Runtime {
@@ -907,6 +980,8 @@ impl module_prices::Config for Runtime {
 	type RelayChainBlockNumber = RelaychainDataProvider<Runtime>;
 	type RewardRatePerRelaychainBlock = RewardRatePerRelaychainBlock;
 	type PricingPegged = PricingPegged;
+	type MaxLockPriceDeviation = MaxLockPriceDeviation;
+	type MaxPriceLockDuration = MaxPriceLockDuration;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -929,7 +1004,10 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
-	type OnDust = module_currencies::TransferDust<Runtime, KaruraTreasuryAccount>;
+	type OnDust = module_currencies::HandleDust<Runtime, KaruraTreasuryAccount>;
+	type AccountCurrencies = runtime_common::TokensAccountCurrencies<Runtime>;
+	type Swap = AcalaSwap;
+	type MaxTransferAllCurrencies = ConstU32<8>;
 }
 
 parameter_types! {
@@ -994,6 +1072,14 @@ impl pallet_scheduler::Config for Runtime {
 	type Preimages = Preimage;
 }
 
+impl module_scheduler_index::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type CancelOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type MaxCancelledByHash = ConstU32<10>;
+	type WeightInfo = weights::module_scheduler_index::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub PreimageBaseDeposit: Balance = deposit(2, 64);
 	pub PreimageByteDeposit: Balance = deposit(0, 1);
@@ -1030,6 +1116,8 @@ impl module_auction_manager::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
+	type BidPriority =
+		runtime_common::AuctionBidPriority<ParameterStoreAdapter<Parameters, runtime_common::PriorityParameters>>;
 	type EmergencyShutdown = EmergencyShutdown;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
@@ -1041,6 +1129,7 @@ impl module_loans::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type PalletId = LoansPalletId;
 	type OnUpdateLoan = module_incentives::OnUpdateLoan<Runtime>;
+	type MaxPositionsPerAccount = ConstU32<8>;
 }
 
 impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
@@ -1078,6 +1167,8 @@ where
 			frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(true),
 			module_evm::SetEvmOrigin::<Runtime>::new(),
 			module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			module_oracle_operator::CheckOracleFeed::<Runtime>::new(),
+			module_auction_manager::CheckAuctionBid::<Runtime>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1110,43 +1201,92 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: FractionalRate = FractionalRate::try_from(Rate::saturating_from_rational(8, 100))
 		.expect("Rate is in range; qed");
 	pub MinimumDebitValue: Balance = 50 * dollar(KUSD);
+	/// Hard bounds of the CDP risk params, enforced on every update whatever the origin is.
+	/// The interest rate cap catches an annual rate entered as a per sec rate.
+	///
+	/// ```
+	/// use frame_support::traits::Get;
+	/// use karura_runtime::{
+	/// 	MaxDebitCeilingIncrease, MaxInterestRatePerSec, MaxLiquidationPenalty, MaxLiquidationRatio,
+	/// 	MinLiquidationRatio,
+	/// };
+	/// use module_support::{Rate, Ratio};
+	/// use sp_runtime::FixedPointNumber;
+	///
+	/// assert_eq!(MinLiquidationRatio::get(), Ratio::saturating_from_rational(110, 100));
+	/// assert_eq!(MaxLiquidationRatio::get(), Ratio::saturating_from_integer(5));
+	/// assert_eq!(MaxLiquidationPenalty::get(), Rate::saturating_from_rational(25, 100));
+	/// assert_eq!(MaxInterestRatePerSec::get(), Rate::saturating_from_rational(1, 10_000));
+	/// assert_eq!(MaxDebitCeilingIncrease::get(), Ratio::saturating_from_integer(2));
+	/// ```
+	pub MinLiquidationRatio: Ratio = Ratio::saturating_from_rational(110, 100);
+	pub MaxLiquidationRatio: Ratio = Ratio::saturating_from_integer(5);
+	pub MaxLiquidationPenalty: Rate = Rate::saturating_from_rational(25, 100);
+	pub MaxInterestRatePerSec: Rate = Rate::saturating_from_rational(1, 10_000);
+	pub MaxDebitCeilingIncrease: Ratio = Ratio::saturating_from_integer(2);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub MaxLiquidationContractSlippage: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub PartialLiquidationThreshold: Balance = 1_000_000 * dollar(KUSD);
+	pub PartialLiquidationBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub SettleErc20EvmOrigin: AccountId = AccountId::from(hex_literal::hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")); // `u5wKvsdTcsYQXeB9nvDQ7PppNHeVefghTzBY9niAhMPXpyo`
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceTimestampSource = OraclePriceTimestamp<AggregatedDataProvider>;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
 	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
 	type MinimumDebitValue = MinimumDebitValue;
+	type MinLiquidationRatio = MinLiquidationRatio;
+	type MaxLiquidationRatio = MaxLiquidationRatio;
+	type MaxLiquidationPenalty = MaxLiquidationPenalty;
+	type MaxInterestRatePerSec = MaxInterestRatePerSec;
+	type MaxDebitCeilingIncrease = MaxDebitCeilingIncrease;
 	type MinimumCollateralAmount =
 		ExistentialDepositsTimesOneHundred<GetNativeCurrencyId, NativeTokenExistentialDeposit, ExistentialDeposits>;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
-	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
+	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority<
+		ParameterStoreAdapter<Parameters, runtime_common::PriorityParameters>,
+	>;
 	type EmergencyShutdown = EmergencyShutdown;
+	type SafeMode = ();
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = Dex;
 	type LiquidationContractsUpdateOrigin = EnsureRootOrHalfGeneralCouncil;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxCrossMarginPreferences = ConstU32<5>;
 	type LiquidationEvmBridge = module_evm_bridge::LiquidationEvmBridge<Runtime>;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type Swap = AcalaSwap;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type SettleErc20EvmOrigin = SettleErc20EvmOrigin;
+	type MaxLiquidationRecords = ConstU32<20>;
+	type PositionAlerts = Honzon;
+	type MaxPositionAlertBatch = ConstU32<50>;
+	type MaxDebitRebaseFactor = ConstU32<1_000>;
+	type RevenuePeriod = ConstU32<DAYS>;
+	type MaxRevenuePeriods = ConstU32<90>;
+	type RiskSnapshotPeriod = ConstU32<HOURS>;
+	type MaxRiskSnapshotPositions = ConstU32<200>;
+	type PartialLiquidationThreshold = PartialLiquidationThreshold;
+	type PartialLiquidationBuffer = PartialLiquidationBuffer;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_cdp_engine::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = deposit(1, 64);
+	pub TopUpBounty: Balance = 10 * cent(KAR);
+	pub TopUpRatioBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 }
 
 impl module_honzon::Config for Runtime {
@@ -1154,9 +1294,19 @@ impl module_honzon::Config for Runtime {
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
+	type OperationGuard = ();
+	type AuctionManagerHandler = AuctionManager;
+	type TopUpBounty = TopUpBounty;
+	type TopUpRatioBuffer = TopUpRatioBuffer;
+	type MaxPositionAlerts = ConstU32<5>;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const RefundSweepGracePeriod: BlockNumber = 7 * DAYS;
+	pub RefundDustThreshold: Balance = dollar(GetStableCurrencyId::get());
+}
+
 impl module_emergency_shutdown::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
@@ -1164,6 +1314,12 @@ impl module_emergency_shutdown::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRoot<AccountId>;
+	type StableCurrencyHolders = module_emergency_shutdown::TokensStableCurrencyHolders<Runtime, GetStableCurrencyId>;
+	type RefundSweepGracePeriod = RefundSweepGracePeriod;
+	type RefundDustThreshold = RefundDustThreshold;
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
 
@@ -1171,6 +1327,12 @@ parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
 	pub const ExtendedProvisioningBlocks: BlockNumber = 2 * DAYS;
 	pub const TradingPathLimit: u32 = 4;
+	pub MinSwapCommitDeposit: Balance = 10 * dollar(KAR);
+	pub const SwapRevealPeriod: BlockNumber = 5 * MINUTES;
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
+	pub const MaxSwapCommitsPerBlock: u32 = 50;
+	pub const LbpCheckpointPeriod: BlockNumber = HOURS;
+	pub const MaxLbpPools: u32 = 10;
 }
 
 impl module_dex::Config for Runtime {
@@ -1186,14 +1348,36 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
 	type OnLiquidityPoolUpdated = ();
+	type OnTradingPairEnabled = ();
+	type OnTradingPairDisabled = ();
+	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
+	type FlashSwapEvmBridge = module_evm_bridge::FlashSwapEvmBridge<Runtime>;
+	type GasToWeight = GasToWeight;
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type MinSwapCommitDeposit = MinSwapCommitDeposit;
+	type SwapRevealPeriod = SwapRevealPeriod;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = MaxSwapCommitsPerBlock;
+	type LbpCheckpointPeriod = LbpCheckpointPeriod;
+	type MaxLbpPools = MaxLbpPools;
+}
+
+parameter_types! {
+	pub DepositPerSwapRecord: Balance = deposit(0, 100);
 }
 
 impl module_aggregated_dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type DEX = Dex;
 	type StableAsset = RebasedStableAsset;
 	type GovernanceOrigin = EnsureRootOrHalfGeneralCouncil;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type DEXExchangeFee = GetExchangeFee;
+	type StableAssetFeePrecision = ConstU128<10_000_000_000>;
+	type Currency = Balances;
+	type DepositPerSwapRecord = DepositPerSwapRecord;
+	type MaxSwapHistory = ConstU32<64>;
 	type WeightInfo = weights::module_aggregated_dex::WeightInfo<Runtime>;
 }
 
@@ -1234,10 +1418,13 @@ impl module_cdp_treasury::Config for Runtime {
 	type TreasuryAccount = HonzonTreasuryAccount;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 	type StableAsset = RebasedStableAsset;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type MaxBasketAssets = ConstU32<10>;
 }
 
 impl module_transaction_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
@@ -1248,19 +1435,45 @@ parameter_types! {
 	pub const AlternativeFeeSurplus: Percent = Percent::from_percent(25);
 }
 
-type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
-pub struct DealWithFees;
-impl OnUnbalanced<NegativeImbalance> for DealWithFees {
-	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
-		if let Some(mut fees) = fees_then_tips.next() {
-			if let Some(tips) = fees_then_tips.next() {
-				tips.merge_into(&mut fees);
+parameter_types! {
+	pub TreasuryReserveAccount: AccountId = TreasuryReservePalletId::get().into_account_truncating();
+	pub CollatorPotAccount: AccountId = CollatorPotId::get().into_account_truncating();
+	pub const DefaultCollatorFeeRatio: Perbill = Perbill::from_percent(0);
+}
+
+pub type DealWithFees = runtime_common::DealWithFees<
+	Runtime,
+	TreasuryReserveAccount,
+	CollatorPotAccount,
+	runtime_common::FeeDistributionRatios<
+		DefaultCollatorFeeRatio,
+		ParameterStoreAdapter<Parameters, runtime_common::FeeParameters>,
+	>,
+>;
+
+parameter_types! {
+	pub const FeeSpendPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxFeeSpendPeriods: u32 = 12;
+}
+
+/// Classify the calls for the fee spend tracking by the pallet, the calls batched by utility are
+/// classified by the batched calls.
+pub struct FeeCallClassifier;
+impl ClassifyFeeCall<RuntimeCall> for FeeCallClassifier {
+	fn classify(call: &RuntimeCall) -> FeeCallCategory {
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+			| RuntimeCall::Utility(pallet_utility::Call::batch_all { calls })
+			| RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => Self::classify_batch(calls),
+			RuntimeCall::EVM(_) => FeeCallCategory::Evm,
+			RuntimeCall::Dex(_) | RuntimeCall::AggregatedDex(_) | RuntimeCall::StableAsset(_) => FeeCallCategory::Dex,
+			RuntimeCall::Honzon(_) | RuntimeCall::CdpEngine(_) | RuntimeCall::HonzonBridge(_) => {
+				FeeCallCategory::Honzon
 			}
-			// for fees and tips, 100% to treasury reserve
-			<Balances as Currency<AccountId>>::resolve_creating(
-				&TreasuryReservePalletId::get().into_account_truncating(),
-				fees,
-			);
+			RuntimeCall::Balances(_) | RuntimeCall::Currencies(_) | RuntimeCall::XTokens(_) => {
+				FeeCallCategory::Transfer
+			}
+			_ => FeeCallCategory::Other,
 		}
 	}
 }
@@ -1290,6 +1503,11 @@ impl module_transaction_payment::Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type FeePayer = ();
+	type CallClassifier = FeeCallClassifier;
+	type FeeSpendPeriod = FeeSpendPeriod;
+	type MaxFeeSpendPeriods = MaxFeeSpendPeriods;
+	type UnsignedPriority = runtime_common::TransactionPaymentUnsignedPriority;
 }
 
 impl module_evm_accounts::Config for Runtime {
@@ -1298,6 +1516,7 @@ impl module_evm_accounts::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
 	type ChainId = EvmChainId<Runtime>;
+	type MaxNonceLanes = ConstU32<16>;
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
@@ -1338,6 +1557,8 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const IncentivesRetirementGracePeriod: BlockNumber = 7 * DAYS;
+	pub IncentivesRetirementSharesThreshold: Balance = 10 * dollar(KAR);
 }
 
 impl module_incentives::Config for Runtime {
@@ -1349,6 +1570,13 @@ impl module_incentives::Config for Runtime {
 	type Currency = Currencies;
 	type EmergencyShutdown = EmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type Randomness = RandomnessSource<Runtime>;
+	type Swap = AcalaSwap;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type RetirementGracePeriod = IncentivesRetirementGracePeriod;
+	type RetirementSharesThreshold = IncentivesRetirementSharesThreshold;
+	type RetireOrigin = EnsureRootOrAllGeneralCouncil;
+	type MaxRetirementRemovals = ConstU32<50>;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
@@ -1365,6 +1593,9 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = ConstU32<2048>;
+	type MaxAirdropRecipients = ConstU32<1000>;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1384,6 +1615,7 @@ impl module_xnft::Config for Runtime {
 	type SelfParaId = ParachainInfo;
 	type NtfPalletLocation = xcm_config::NftPalletLocation;
 	type RegisterOrigin = EnsureRootOrOneTechnicalCommittee;
+	type WeightInfo = weights::module_xnft::WeightInfo<Runtime>;
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -1495,6 +1727,9 @@ impl pallet_proxy::Config for Runtime {
 parameter_types! {
 	pub const NewContractExtraBytes: u32 = 10_000;
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
+	pub const EvmTransactionIndexRetention: u32 = 7 * DAYS;
+	// Bounds the PoV size taken by the EVM storage writes of a block.
+	pub const EvmDefaultBlockStorageBudget: u32 = 256 * 1024;
 	pub DeveloperDeposit: Balance = 50 * dollar(KAR);
 	pub PublicationFee: Balance = 10 * dollar(KAR);
 	pub PrecompilesValue: AllPrecompiles<Runtime, module_transaction_pause::PausedPrecompileFilter<Runtime>, ()> = AllPrecompiles::<_, _, _>::karura();
@@ -1553,6 +1788,12 @@ impl module_evm::Config for Runtime {
 	type Randomness = RandomnessSource<Runtime>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type TransactionIndexRetention = EvmTransactionIndexRetention;
+	type TransactionHash = EthereumTransactionHash;
+	type BlockStorageBudget = runtime_common::EvmBlockStorageBudget<
+		EvmDefaultBlockStorageBudget,
+		ParameterStoreAdapter<Parameters, runtime_common::EvmParameters>,
+	>;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 }
 
@@ -1601,6 +1842,8 @@ parameter_types! {
 	pub MintThreshold: Balance = 10 * cent(KSM);
 	pub RedeemThreshold: Balance = 50 * cent(LKSM);
 	pub const BondingDuration: EraIndex = 28;
+	pub const NominationPoolSubAccountIndex: u16 = 10;
+	pub MaxCommissionRate: Rate = Rate::saturating_from_rational(25, 100);
 }
 
 impl module_homa::Config for Runtime {
@@ -1621,6 +1864,12 @@ impl module_homa::Config for Runtime {
 	type WeightInfo = weights::module_homa::WeightInfo<Runtime>;
 	type NominationsProvider = NomineesElection;
 	type ProcessRedeemRequestsLimit = ConstU32<1_000>;
+	type MaxRedeemRequestBuckets = ConstU32<100>;
+	type MaxRedeemRequestsPerBucket = ConstU32<100>;
+	type OperationGuard = ();
+	type NominationPoolSubAccountIndex = NominationPoolSubAccountIndex;
+	type MaxCommissionRate = MaxCommissionRate;
+	type UnixTime = Timestamp;
 }
 
 parameter_types! {
@@ -1710,6 +1959,9 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Runtime>),
+		NftTask(NftTask<Runtime>),
+		EmergencyShutdownTask(EmergencyShutdownTask<Runtime>),
+		CDPEngineTask(CDPEngineTask<Runtime>),
 	}
 }
 
@@ -1720,7 +1972,7 @@ parameter_types!(
 
 impl module_idle_scheduler::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type WeightInfo = ();
+	type WeightInfo = weights::module_idle_scheduler::WeightInfo<Runtime>;
 	type Index = Nonce;
 	type Task = ScheduledTasks;
 	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
@@ -1817,18 +2069,25 @@ impl module_earning::Config for Runtime {
 	type Currency = Balances;
 	type ParameterStore = ParameterStoreAdapter<Parameters, module_earning::Parameters>;
 	type OnBonded = module_incentives::OnEarningBonded<Runtime>;
-	type OnUnbonded = module_incentives::OnEarningUnbonded<Runtime>;
+	type OnUnbonded = (
+		module_incentives::OnEarningUnbonded<Runtime>,
+		runtime_common::CapBoostedVotesOnUnbond<Runtime>,
+	);
 	type OnUnstakeFee = Treasury; // fee goes to treasury
 	type MinBond = MinBond;
 	type UnbondingPeriod = UnbondingPeriod;
 	type MaxUnbondingChunks = ConstU32<10>;
 	type LockIdentifier = EarningLockIdentifier;
-	type WeightInfo = ();
+	type WeightInfo = weights::module_earning::WeightInfo<Runtime>;
 }
 
 define_aggregrated_parameters! {
 	pub RuntimeParameters = {
 		Earning: module_earning::Parameters = 0,
+		Priority: runtime_common::PriorityParameters = 1,
+		Governance: runtime_common::GovernanceParameters = 2,
+		Evm: runtime_common::EvmParameters = 3,
+		Fee: runtime_common::FeeParameters = 4,
 	}
 }
 
@@ -1852,6 +2111,7 @@ construct_runtime!(
 		// NOTE: IdleScheduler must be put before ParachainSystem in order to read relaychain blocknumber
 		IdleScheduler: module_idle_scheduler = 7,
 		Preimage: pallet_preimage = 8,
+		SchedulerIndex: module_scheduler_index = 9,
 
 		// Tokens & Related
 		Balances: pallet_balances = 10,
@@ -1903,6 +2163,7 @@ construct_runtime!(
 		// NOTE: OperatorMembership must be placed after Oracle or else will have race condition on initialization
 		AcalaOracle: orml_oracle::<Instance1> = 70,
 		OperatorMembershipAcala: pallet_membership::<Instance5> = 71,
+		OracleOperator: module_oracle_operator = 72,
 
 		// ORML Core
 		Auction: orml_auction = 80,
@@ -1937,6 +2198,7 @@ construct_runtime!(
 		NFT: module_nft = 121,
 		AssetRegistry: module_asset_registry = 122,
 		XNFT: module_xnft = 123,
+		PublicReferenda: module_public_referenda = 124,
 
 		// Smart contracts
 		EVM: module_evm = 130,
@@ -1954,6 +2216,22 @@ construct_runtime!(
 	}
 );
 
+/// The error types of the pallets of the runtime, read from the metadata.
+pub struct RuntimePalletErrors;
+impl module_error_registry::PalletErrors for RuntimePalletErrors {
+	fn pallet_errors() -> Vec<module_error_registry::PalletErrorMetadata> {
+		Runtime::metadata_ir()
+			.pallets
+			.into_iter()
+			.map(|pallet| module_error_registry::PalletErrorMetadata {
+				name: pallet.name,
+				index: pallet.index,
+				ty: pallet.error.map(|error| error.ty),
+			})
+			.collect()
+	}
+}
+
 /// Block header type as expected by this runtime.
 pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
 /// Block type as expected by this runtime.
@@ -1977,6 +2255,8 @@ pub type SignedExtra = (
 	// as fee token.
 	module_evm::SetEvmOrigin<Runtime>,
 	module_transaction_payment::ChargeTransactionPayment<Runtime>,
+	module_oracle_operator::CheckOracleFeed<Runtime>,
+	module_auction_manager::CheckAuctionBid<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -1985,13 +2265,28 @@ pub type UncheckedExtrinsic =
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+
+parameter_types! {
+	pub const HookMetricsThreshold: Perbill = Perbill::from_percent(10);
+	pub const HookMetricsRetention: u32 = 100;
+}
+
+/// The pallets with their `on_initialize` weight and proof size recorded per block, only with the
+/// `hook-metrics` feature.
+pub type HookMeteredPallets =
+	runtime_common::HookMetered<Runtime, AllPalletsWithSystem, HookMetricsThreshold, HookMetricsRetention>;
+#[cfg(feature = "hook-metrics")]
+type ExecutivePallets = HookMeteredPallets;
+#[cfg(not(feature = "hook-metrics"))]
+type ExecutivePallets = AllPalletsWithSystem;
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
 	Block,
 	frame_system::ChainContext<Runtime>,
 	Runtime,
-	AllPalletsWithSystem,
+	ExecutivePallets,
 	Migrations,
 >;
 
@@ -2000,7 +2295,27 @@ parameter_types! {
 }
 
 #[allow(unused_parens)]
-type Migrations = (frame_support::migrations::RemovePallet<StateTrieMigrationName, RocksDbWeight>);
+type Migrations = (
+	frame_support::migrations::RemovePallet<StateTrieMigrationName, RocksDbWeight>,
+	module_cdp_engine::AddMaxPriceAgeToCollateralParams<Runtime>,
+	module_cdp_treasury::RecordUntrackedIssuance<Runtime>,
+	module_collator_selection::DefaultRewardDestinationToFreeBalance<Runtime>,
+	module_dex::AddPausedTradingPairStatus<Runtime>,
+	module_loans::IndexPositions<Runtime>,
+);
+
+/// The names of the `Migrations`, reported by the upgrade preflight.
+pub const MIGRATIONS: &[&str] = &[
+	"frame_support::migrations::RemovePallet<StateTrieMigrationName>",
+	"module_cdp_engine::AddMaxPriceAgeToCollateralParams",
+	"module_cdp_treasury::RecordUntrackedIssuance",
+	"module_collator_selection::DefaultRewardDestinationToFreeBalance",
+	"module_dex::AddPausedTradingPairStatus",
+	"module_loans::IndexPositions",
+];
+
+/// The pallets removed from the runtime, whose leftover storage is reported by the upgrade preflight.
+pub const REMOVED_PALLETS: &[&str] = &[StateTrieMigrationName::get()];
 
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
@@ -2011,6 +2326,7 @@ mod benches {
 	define_benchmarks!(
 		[module_dex, benchmarking::dex]
 		[module_dex_oracle, benchmarking::dex_oracle]
+		[module_earning, benchmarking::earning]
 		[module_asset_registry, benchmarking::asset_registry]
 		[module_auction_manager, benchmarking::auction_manager]
 		[module_cdp_engine, benchmarking::cdp_engine]
@@ -2035,8 +2351,12 @@ mod benches {
 		[orml_authority, benchmarking::authority]
 		[nutsfinance_stable_asset, benchmarking::nutsfinance_stable_asset]
 		[module_idle_scheduler, benchmarking::idle_scheduler]
+		[module_scheduler_index, benchmarking::scheduler_index]
 		[module_aggregated_dex, benchmarking::aggregated_dex]
 		[module_nominees_election, benchmarking::nominees_election]
+		[module_oracle_operator, benchmarking::oracle_operator]
+		[module_public_referenda, benchmarking::public_referenda]
+		[module_xnft, benchmarking::xnft]
 	);
 	// frame_benchmarking::define_benchmarks!(
 	// 	// XCM
@@ -2166,14 +2486,32 @@ impl_runtime_apis! {
 		fn get_value(provider_id: DataProviderId, key: CurrencyId) -> Option<TimeStampedPrice> {
 			match provider_id {
 				DataProviderId::Acala => AcalaOracle::get_no_op(&key),
-				DataProviderId::Aggregated => <AggregatedDataProvider as DataProviderExtended<_, _>>::get_no_op(&key)
+				DataProviderId::Aggregated => <AggregatedDataProvider as DataProviderExtended<_, _>>::get_no_op(&key),
+				DataProviderId::Secondary => None,
 			}
 		}
 
 		fn get_all_values(provider_id: DataProviderId) -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
 			match provider_id {
 				DataProviderId::Acala => AcalaOracle::get_all_values(),
-				DataProviderId::Aggregated => <AggregatedDataProvider as DataProviderExtended<_, _>>::get_all_values()
+				DataProviderId::Aggregated => <AggregatedDataProvider as DataProviderExtended<_, _>>::get_all_values(),
+				DataProviderId::Secondary => vec![],
+			}
+		}
+	}
+
+	impl module_oracle_operator_runtime_api::OracleQualityApi<
+		Block,
+		DataProviderId,
+		CurrencyId,
+		runtime_common::PriceQuality,
+	> for Runtime {
+		fn get_value_with_quality(provider_id: DataProviderId, key: CurrencyId) -> Option<runtime_common::PriceQuality> {
+			match provider_id {
+				DataProviderId::Acala => runtime_common::OraclePriceQuality::<Runtime, AcalaDataProvider, ExpiresIn>::get(&key),
+				DataProviderId::Secondary => None,
+				// the aggregated prices are not combined from raw values
+				DataProviderId::Aggregated => None,
 			}
 		}
 	}
@@ -2203,6 +2541,276 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_currencies_runtime_api::ReservesApi<Block, AccountId, Balance> for Runtime {
+		fn get_reserve_breakdown(who: AccountId) -> Vec<module_currencies_runtime_api::ReserveEntry<Balance>> {
+			reserves::get_reserve_breakdown(who)
+		}
+
+		fn get_deposit_constants() -> Vec<module_currencies_runtime_api::DepositConstant<Balance>> {
+			reserves::get_deposit_constants()
+		}
+	}
+
+	impl module_asset_registry_runtime_api::AssetRegistryApi<Block, Balance> for Runtime {
+		fn get_asset_metadata(currency_id: CurrencyId) -> Option<primitives::currency::AssetMetadata<Balance>> {
+			AssetRegistry::asset_metadata(currency_id)
+		}
+
+		fn get_all_assets(
+			cursor: Option<AssetIds>,
+			limit: u32,
+		) -> (Vec<(CurrencyId, primitives::currency::AssetMetadata<Balance>)>, Option<AssetIds>) {
+			AssetRegistry::all_assets(cursor, limit)
+		}
+
+		fn get_canonical_asset(symbol: Vec<u8>) -> Option<CurrencyId> {
+			AssetRegistry::canonical_asset(&symbol)
+		}
+	}
+
+	impl module_collator_selection_runtime_api::CollatorSelectionApi<Block, AccountId, Balance> for Runtime {
+		fn expected_session_reward() -> Balance {
+			CollatorSelection::expected_session_reward()
+		}
+
+		fn expected_collator_reward(who: AccountId) -> Balance {
+			CollatorSelection::expected_collator_reward(&who)
+		}
+	}
+
+	impl module_transaction_payment_runtime_api::FeeSwapPathApi<Block, AccountId> for Runtime {
+		fn get_effective_fee_path(account: AccountId) -> Option<Vec<CurrencyId>> {
+			TransactionPayment::get_effective_fee_path(&account)
+		}
+	}
+
+	impl module_transaction_payment_runtime_api::FeeSpendApi<Block, AccountId> for Runtime {
+		fn get_fee_summary(account: AccountId, period: u32) -> Vec<module_transaction_payment_runtime_api::FeeSpend> {
+			TransactionPayment::get_fee_summary(&account, period)
+		}
+	}
+
+	impl module_nft_runtime_api::NftApi<
+		Block,
+		AccountId,
+		u32,
+		u64,
+		module_nft::ClassDetailsOf<Runtime>,
+		module_nft::TokenDetailsOf<Runtime>,
+	> for Runtime {
+		fn get_class_info(class_id: u32) -> Option<module_nft::ClassDetailsOf<Runtime>> {
+			NFT::get_class_info(class_id)
+		}
+
+		fn get_token(class_id: u32, token_id: u64) -> Option<module_nft::TokenDetailsOf<Runtime>> {
+			NFT::get_token(class_id, token_id)
+		}
+
+		fn get_tokens_by_class(
+			class_id: u32,
+			cursor: Option<u64>,
+			limit: u32,
+			filter: Option<(Vec<u8>, Vec<u8>)>,
+		) -> (Vec<module_nft::TokenDetailsOf<Runtime>>, Option<u64>) {
+			NFT::get_tokens_by_class(class_id, cursor, limit, filter)
+		}
+
+		fn get_tokens_by_owner(
+			account: AccountId,
+			cursor: Option<(u32, u64)>,
+			limit: u32,
+			filter: Option<(Vec<u8>, Vec<u8>)>,
+		) -> (Vec<module_nft::TokenDetailsOf<Runtime>>, Option<(u32, u64)>) {
+			NFT::get_tokens_by_owner(&account, cursor, limit, filter)
+		}
+	}
+
+	impl module_aggregated_dex_runtime_api::AggregatedDexApi<
+		Block,
+		AccountId,
+		module_aggregated_dex::SwapRecord<BlockNumber>,
+	> for Runtime {
+		fn get_swap_history(account: AccountId) -> Vec<module_aggregated_dex::SwapRecord<BlockNumber>> {
+			AggregatedDex::get_swap_history(&account)
+		}
+	}
+
+	impl module_xcm_interface_runtime_api::XcmFeeApi<Block, CurrencyId, Balance> for Runtime {
+		fn estimate_transfer_fee(
+			currency_id: CurrencyId,
+			amount: Balance,
+			dest: xcm::VersionedLocation,
+		) -> Option<module_xcm_interface_runtime_api::TransferFeeBreakdown<Balance>> {
+			runtime_common::xcm_impl::estimate_transfer_fee::<Runtime, xcm_config::XcmConfig>(
+				currency_id,
+				amount,
+				dest.try_into().ok()?,
+			)
+		}
+	}
+
+	impl module_auction_manager_runtime_api::AuctionManagerApi<
+		Block,
+		AuctionId,
+		module_auction_manager::AuctionInfoView<AccountId, BlockNumber>,
+	> for Runtime {
+		fn get_auction_info(auction_id: AuctionId) -> Option<module_auction_manager::AuctionInfoView<AccountId, BlockNumber>> {
+			AuctionManager::get_auction_info(auction_id)
+		}
+
+		fn get_active_auctions(
+			cursor: Option<AuctionId>,
+			limit: u32,
+		) -> (Vec<module_auction_manager::AuctionInfoView<AccountId, BlockNumber>>, Option<AuctionId>) {
+			AuctionManager::get_active_auctions(cursor, limit)
+		}
+	}
+
+	impl module_statistics_runtime_api::ProtocolStatsApi<
+		Block,
+		runtime_common::TvlSource,
+		CurrencyId,
+		Balance,
+		BlockNumber,
+		runtime_common::ProtocolRevenue,
+	> for Runtime {
+		fn get_tvl() -> Vec<(runtime_common::TvlSource, CurrencyId, Balance, Option<Balance>)> {
+			runtime_common::ProtocolStats::<Runtime, StableAsset, module_prices::RealTimePriceProvider<Runtime>>::get_tvl()
+		}
+
+		fn get_protocol_revenue(period: BlockNumber) -> runtime_common::ProtocolRevenue {
+			runtime_common::ProtocolStats::<Runtime, StableAsset, module_prices::RealTimePriceProvider<Runtime>>::get_protocol_revenue(period)
+		}
+	}
+
+	impl module_cdp_treasury_runtime_api::CdpTreasuryApi<Block> for Runtime {
+		fn get_issuance_breakdown() -> module_cdp_treasury_runtime_api::IssuanceBreakdown {
+			CdpTreasury::get_issuance_breakdown()
+		}
+
+		fn get_diversification_progress() -> Option<module_cdp_treasury_runtime_api::DiversificationProgress> {
+			CdpTreasury::get_diversification_progress()
+		}
+	}
+
+	impl module_homa_runtime_api::HomaApi<Block, BlockNumber> for Runtime {
+		fn get_staking_ledgers() -> Vec<(u16, module_homa_runtime_api::StakingLedgerView)> {
+			Homa::get_staking_ledgers()
+		}
+
+		fn get_pending_operations() -> Vec<module_homa_runtime_api::PendingOperationView> {
+			Homa::get_pending_operations().unwrap_or_default()
+		}
+
+		fn get_last_era_bump_info() -> Option<module_homa_runtime_api::EraBumpInfo<BlockNumber>> {
+			Homa::last_era_bump()
+		}
+	}
+
+	impl module_honzon_runtime_api::HonzonSnapshotApi<Block, AccountId, BlockNumber> for Runtime {
+		fn get_honzon_snapshot(
+			cursor: Option<module_honzon_runtime_api::SnapshotCursor<AccountId>>,
+			limit: u32,
+		) -> module_honzon_runtime_api::HonzonSnapshotPage<AccountId, BlockNumber> {
+			Honzon::get_honzon_snapshot(cursor, limit)
+		}
+	}
+
+	impl module_honzon_runtime_api::LiquidationHistoryApi<Block, AccountId, BlockNumber> for Runtime {
+		fn get_liquidation_history(
+			account: AccountId,
+		) -> (
+			module_honzon_runtime_api::LiquidationStats,
+			Vec<module_honzon_runtime_api::LiquidationRecord<CurrencyId, BlockNumber>>,
+		) {
+			CdpEngine::get_liquidation_history(&account)
+		}
+	}
+
+	impl module_honzon_runtime_api::RiskSnapshotApi<Block, BlockNumber> for Runtime {
+		fn get_risk_snapshot(currency_id: CurrencyId) -> Option<module_honzon_runtime_api::RiskSnapshot<BlockNumber>> {
+			CdpEngine::risk_snapshots(currency_id)
+		}
+	}
+
+	impl module_evm_accounts_runtime_api::EvmAccountsApi<Block, AccountId> for Runtime {
+		fn resolve(
+			targets: Vec<module_evm_accounts_runtime_api::AddressOrAccount<AccountId>>,
+		) -> Vec<module_evm_accounts_runtime_api::MappingInfo<AccountId>> {
+			EvmAccounts::resolve(targets, runtime_common::evm_account_state::<Runtime>)
+		}
+
+		fn get_bound_accounts(
+			cursor: Option<H160>,
+			limit: u32,
+		) -> module_evm_accounts_runtime_api::BoundAccountsPage<AccountId> {
+			EvmAccounts::get_bound_accounts(cursor, limit, runtime_common::evm_account_state::<Runtime>)
+		}
+	}
+
+	impl module_scheduler_index_runtime_api::SchedulerIndexApi<Block, BlockNumber, OriginCaller, Hash> for Runtime {
+		fn pending_scheduled_calls(
+		) -> Vec<module_scheduler_index_runtime_api::ScheduledCallInfo<BlockNumber, OriginCaller, Hash>> {
+			SchedulerIndex::pending_scheduled_calls()
+		}
+	}
+
+	impl module_error_registry_runtime_api::ErrorRegistryApi<Block> for Runtime {
+		fn decode_module_error(index: u8, error: [u8; 4]) -> Option<module_error_registry::ErrorInfo> {
+			module_error_registry::decode_module_error::<RuntimePalletErrors>(index, error)
+		}
+
+		fn decode_dispatch_error(error: sp_runtime::DispatchError) -> Vec<u8> {
+			module_error_registry::decode_dispatch_error::<RuntimePalletErrors>(error)
+		}
+	}
+
+	impl runtime_common::HookMetricsApi<Block> for Runtime {
+		fn get_hook_metrics(last_n_blocks: u32) -> Vec<runtime_common::BlockHookMetrics> {
+			HookMeteredPallets::hook_metrics(last_n_blocks)
+		}
+	}
+
+	impl runtime_common::CallPermissionApi<Block, AccountId, RuntimeCall, ProxyType> for Runtime {
+		fn can_account_call(
+			account: AccountId,
+			call: RuntimeCall,
+			via_proxy: Option<(AccountId, ProxyType)>,
+		) -> runtime_common::CallPermission {
+			runtime_common::call_permission::can_account_call::<Runtime, BaseCallFilter>(&account, &call, via_proxy)
+		}
+	}
+
+	impl runtime_common::SystemInfoApi<Block, Hash, BlockNumber> for Runtime {
+		fn get_chain_status() -> runtime_common::ChainStatus<Hash, BlockNumber> {
+			runtime_common::system_info::get_chain_status::<Runtime, ()>()
+		}
+	}
+
+	impl module_upgrade_preflight_runtime_api::UpgradePreflightApi<Block> for Runtime {
+		fn upgrade_preflight() -> module_upgrade_preflight::PreflightReport {
+			module_upgrade_preflight::preflight::<AllPalletsWithSystem>(
+				MIGRATIONS,
+				REMOVED_PALLETS,
+				vec![(b"Dex::TransitionalTradingPairs".to_vec(), Dex::transitional_trading_pairs())],
+			)
+		}
+	}
+
+	impl module_transaction_pause_runtime_api::TransactionPauseApi<Block, RuntimeCall> for Runtime {
+		fn get_paused_calls() -> Vec<(Vec<u8>, Vec<u8>)> {
+			TransactionPause::get_paused_calls()
+		}
+
+		fn is_call_paused(call: RuntimeCall) -> bool {
+			module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(&call)
+		}
+
+		fn get_paused_evm_precompiles() -> Vec<H160> {
+			TransactionPause::get_paused_evm_precompiles()
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance, AccountId> for Runtime {
 		fn block_limits() -> BlockLimits {
 			BlockLimits {
@@ -2319,6 +2927,45 @@ impl_runtime_apis! {
 
 			Self::create(from, data, value, gas_limit, storage_limit, access_list, estimate)
 		}
+
+		fn get_block_tx_index(block_number: BlockNumber) -> Option<Vec<EvmTransactionIndex<AccountId>>> {
+			EVM::get_block_tx_index(block_number)
+		}
+
+		fn get_contract_purge_progress(contract: H160) -> Option<ContractPurgeProgress<Balance>> {
+			EVM::contract_purges(contract)
+		}
+
+		fn get_system_contract(key: Vec<u8>) -> Option<H160> {
+			EVM::get_system_contract(&key)
+		}
+
+		fn batch_call(
+			requests: Vec<EthCallRequest<Balance>>,
+		) -> Result<Vec<Result<CallInfo, sp_runtime::DispatchError>>, sp_runtime::DispatchError> {
+			<Runtime as module_evm::Config>::Runner::rpc_batch_call(
+				requests,
+				runtime_common::EvmLimits::<Runtime>::max_gas_limit(),
+				<Runtime as module_evm::Config>::config(),
+			)
+		}
+
+		fn estimate_resources_v2(
+			request: EstimateResourcesRequest,
+		) -> Result<EstimateResourcesResponse<Balance>, sp_runtime::DispatchError> {
+			let max_gas_limit = runtime_common::EvmLimits::<Runtime>::max_gas_limit();
+			let max_storage_limit = runtime_common::EvmLimits::<Runtime>::max_storage_limit();
+			<Runtime as module_evm::Config>::Runner::rpc_estimate_resources(
+				request.from.unwrap_or_default(),
+				request.to,
+				request.data.unwrap_or_default(),
+				request.value.unwrap_or_default(),
+				request.gas_limit.map_or(max_gas_limit, |gas_limit| gas_limit.min(max_gas_limit)),
+				request.storage_limit.map_or(max_storage_limit, |storage_limit| storage_limit.min(max_storage_limit)),
+				request.access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+				<Runtime as module_evm::Config>::config(),
+			)
+		}
 	}
 
 	#[cfg(feature = "tracing")]
@@ -2539,7 +3186,7 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 					}
 				}
 
-				let (_, _, _, _, mortality, check_nonce, _, _, _, charge) = extra.clone();
+				let (_, _, _, _, mortality, check_nonce, _, _, _, charge, _) = extra.clone();
 
 				if mortality != frame_system::CheckEra::from(sp_runtime::generic::Era::Immortal) {
 					// require immortal
@@ -2549,7 +3196,7 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 				let nonce = check_nonce.nonce;
 				let tip = charge.0;
 
-				extra.5.mark_as_ethereum_tx(valid_until);
+				extra.5.mark_as_ethereum_tx(valid_until, tip);
 
 				Ok((
 					EthereumTransactionMessage {
@@ -2588,7 +3235,7 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 					}
 				}
 
-				let (_, _, _, _, mortality, check_nonce, _, _, _, charge) = extra.clone();
+				let (_, _, _, _, mortality, check_nonce, _, _, _, charge, _) = extra.clone();
 
 				if mortality != frame_system::CheckEra::from(sp_runtime::generic::Era::Immortal) {
 					// require immortal
@@ -2601,7 +3248,9 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 					return Err(InvalidTransaction::BadProof);
 				}
 
-				extra.5.mark_as_ethereum_tx(valid_until);
+				extra.5.mark_as_ethereum_tx(valid_until, tip);
+				// the lane index of accounts using nonce lanes is encoded in the high bits of the nonce
+				extra.5.allow_nonce_lanes();
 
 				let storage_limit = decode_gas_limit(gas_limit).1;
 
@@ -2628,13 +3277,25 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 	}
 }
 
+pub struct EthereumTransactionHash;
+impl Convert<Vec<u8>, sp_core::H256> for EthereumTransactionHash {
+	fn convert(extrinsic: Vec<u8>) -> sp_core::H256 {
+		UncheckedExtrinsic::decode_all_with_depth_limit(sp_api::MAX_EXTRINSIC_DEPTH, &mut &*extrinsic)
+			.map(|utx| utx.ethereum_transaction_hash())
+			.unwrap_or_else(|_| sp_io::hashing::keccak_256(&extrinsic).into())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::{dispatch::DispatchClass, traits::WhitelistedStorageKeys};
+	use frame_support::{
+		dispatch::{DispatchClass, GetDispatchInfo},
+		traits::WhitelistedStorageKeys,
+	};
 	use frame_system::offchain::CreateSignedTransaction;
 	use sp_core::hexdisplay::HexDisplay;
-	use sp_runtime::traits::Convert;
+	use sp_runtime::traits::{Convert, SignedExtension};
 	use std::collections::HashSet;
 
 	fn run_with_system_weight<F>(w: Weight, mut assertions: F)
@@ -2720,6 +3381,13 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn check_exchange_fee_bounds() {
+		// DEX exchange fee has no setter, keep the constant within (0, 1%].
+		let (numerator, denominator) = GetExchangeFee::get();
+		assert!(numerator > 0 && numerator.saturating_mul(100) <= denominator);
+	}
+
 	#[test]
 	fn check_call_size() {
 		assert!(
@@ -2730,6 +3398,107 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn can_account_call_reports_denying_layer() {
+		use frame_support::assert_ok;
+		use runtime_common::CallPermission;
+		use sp_runtime::MultiAddress;
+
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			let alice: AccountId = sp_runtime::AccountId32::from([1; 32]);
+			let bob: AccountId = sp_runtime::AccountId32::from([2; 32]);
+			let charlie: AccountId = sp_runtime::AccountId32::from([3; 32]);
+			for who in [&alice, &bob] {
+				<Balances as frame_support::traits::Currency<AccountId>>::make_free_balance_be(who, 100 * dollar(KAR));
+			}
+			let can_account_call = |account: &AccountId,
+			                        call: &RuntimeCall,
+			                        via_proxy: Option<(AccountId, ProxyType)>| {
+				runtime_common::call_permission::can_account_call::<Runtime, BaseCallFilter>(account, call, via_proxy)
+			};
+			let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+				dest: MultiAddress::Id(charlie.clone()),
+				value: dollar(KAR),
+			});
+			let add_proxy = RuntimeCall::Proxy(pallet_proxy::Call::add_proxy {
+				delegate: MultiAddress::Id(charlie.clone()),
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			});
+
+			assert_eq!(can_account_call(&alice, &transfer, None), CallPermission::Allowed);
+
+			// base call filter
+			let xcm_send = RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {
+				dest: Box::new(Location::parent().into()),
+				message: Box::new(Xcm(vec![]).into()),
+			});
+			assert_eq!(
+				can_account_call(&alice, &xcm_send, None),
+				CallPermission::DeniedByBaseFilter
+			);
+
+			// transaction pause
+			assert_ok!(TransactionPause::pause_transaction(
+				RuntimeOrigin::root(),
+				b"Balances".to_vec(),
+				b"transfer_keep_alive".to_vec()
+			));
+			assert_eq!(can_account_call(&alice, &transfer, None), CallPermission::Paused);
+			assert_ok!(TransactionPause::unpause_transaction(
+				RuntimeOrigin::root(),
+				b"Balances".to_vec(),
+				b"transfer_keep_alive".to_vec()
+			));
+
+			// proxy definition and proxy filter
+			assert_eq!(
+				can_account_call(&alice, &transfer, Some((bob.clone(), ProxyType::Any))),
+				CallPermission::NotProxy
+			);
+			assert_ok!(Proxy::add_proxy(
+				RuntimeOrigin::signed(bob.clone()),
+				MultiAddress::Id(alice.clone()),
+				ProxyType::Swap,
+				0
+			));
+			assert_eq!(
+				can_account_call(&alice, &transfer, Some((bob.clone(), ProxyType::Any))),
+				CallPermission::NotProxy
+			);
+			assert_eq!(
+				can_account_call(&alice, &transfer, Some((bob.clone(), ProxyType::Swap))),
+				CallPermission::DeniedByProxyFilter
+			);
+			// a proxy can't add the proxy of a superset of its type
+			assert_eq!(
+				can_account_call(&alice, &add_proxy, Some((bob.clone(), ProxyType::Swap))),
+				CallPermission::DeniedByProxyFilter
+			);
+			assert_ok!(Proxy::add_proxy(
+				RuntimeOrigin::signed(bob.clone()),
+				MultiAddress::Id(alice.clone()),
+				ProxyType::Any,
+				0
+			));
+			assert_eq!(
+				can_account_call(&alice, &transfer, Some((bob.clone(), ProxyType::Any))),
+				CallPermission::Allowed
+			);
+
+			// deposit of the call
+			assert_eq!(can_account_call(&alice, &add_proxy, None), CallPermission::Allowed);
+			assert_eq!(
+				can_account_call(&charlie, &add_proxy, None),
+				CallPermission::InsufficientDeposit
+			);
+		});
+	}
+
 	#[test]
 	fn check_on_initialize_with_bump_era_weight() {
 		use module_homa::WeightInfo;
@@ -2739,4 +3508,155 @@ mod tests {
 		let block_weight = RuntimeBlockWeights::get().max_block.div(3).mul(2);
 		assert!(weight.all_lt(block_weight));
 	}
+
+	/// Mirrors the replacement rule of the tx pool: a tx replaces the pending tx which provides the
+	/// same tag if it has a strictly higher priority.
+	fn can_replace(
+		pending: &sp_runtime::transaction_validity::ValidTransaction,
+		new: &sp_runtime::transaction_validity::ValidTransaction,
+	) -> bool {
+		new.provides.iter().any(|tag| pending.provides.contains(tag)) && new.priority > pending.priority
+	}
+
+	#[test]
+	fn speed_up_eth_tx_replaces_pending_tx() {
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			let alice: AccountId = sp_runtime::AccountId32::from([8; 32]);
+			<Balances as frame_support::traits::Currency<AccountId>>::make_free_balance_be(
+				&alice,
+				1_000_000 * dollar(KAR),
+			);
+
+			const TEN_GWEI: u64 = 10_000_000_000u64;
+			let valid_until = 30u64;
+			let validate = |tip_number: u64| {
+				// gas_price = 100 Gwei + tip_number * 10 Gwei, valid_until is encoded in the low part
+				let call = RuntimeCall::EVM(module_evm::Call::eth_call_v2 {
+					action: module_evm::TransactionAction::Create,
+					input: vec![0x01],
+					value: 0,
+					gas_price: (10 + tip_number) * TEN_GWEI + valid_until,
+					gas_limit: 2_100_000,
+					access_list: vec![],
+				});
+				let (tip, _) = decode_gas_price(
+					(10 + tip_number) * TEN_GWEI + valid_until,
+					2_100_000,
+					TxFeePerGasV2::get(),
+				)
+				.unwrap();
+
+				let extra: SignedExtra = (
+					frame_system::CheckNonZeroSender::<Runtime>::new(),
+					frame_system::CheckSpecVersion::<Runtime>::new(),
+					frame_system::CheckTxVersion::<Runtime>::new(),
+					frame_system::CheckGenesis::<Runtime>::new(),
+					frame_system::CheckEra::<Runtime>::from(generic::Era::Immortal),
+					runtime_common::CheckNonce::<Runtime>::from(0),
+					frame_system::CheckWeight::<Runtime>::new(),
+					frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+					module_evm::SetEvmOrigin::<Runtime>::new(),
+					module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+					module_oracle_operator::CheckOracleFeed::<Runtime>::new(),
+					module_auction_manager::CheckAuctionBid::<Runtime>::new(),
+				);
+				let (_, extra) = ConvertEthereumTx::convert((call.clone(), extra)).unwrap();
+
+				let info = call.get_dispatch_info();
+				let len = call.encoded_size();
+				extra.validate(&alice, &call, &info, len).unwrap()
+			};
+
+			// the pending tx with 100% tip
+			let pending = validate(10);
+			assert_eq!(pending.longevity, valid_until - u64::from(System::block_number()));
+
+			// resubmitting the same tx doesn't replace the pending one
+			assert!(!can_replace(&pending, &validate(10)));
+			// lower tip doesn't replace the pending one
+			assert!(!can_replace(&pending, &validate(9)));
+
+			// speed up with 10% higher tip
+			let replacement = validate(11);
+			assert_eq!(replacement.provides, pending.provides);
+			assert_eq!(replacement.longevity, pending.longevity);
+			assert!(can_replace(&pending, &replacement));
+
+			// speed up again
+			assert!(can_replace(&replacement, &validate(13)));
+		});
+	}
+
+	#[test]
+	fn pallets_do_not_use_unit_weight_info() {
+		fn is_unit<W: 'static>() -> bool {
+			std::any::TypeId::of::<W>() == std::any::TypeId::of::<()>()
+		}
+
+		assert!(!is_unit::<<Runtime as module_collator_selection::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_public_referenda::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_oracle_operator::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_prices::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_currencies::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_scheduler_index::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_auction_manager::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_cdp_engine::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_honzon::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_emergency_shutdown::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_dex::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_aggregated_dex::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_dex_oracle::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_cdp_treasury::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_transaction_pause::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_transaction_payment::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_evm_accounts::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_asset_registry::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_incentives::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_nft::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_xnft::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_evm::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_session_manager::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_homa::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_homa_validator_list::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_nominees_election::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_idle_scheduler::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_honzon_bridge::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as module_earning::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as orml_tokens::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as orml_vesting::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as orml_auction::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as orml_authority::Config>::WeightInfo>());
+		assert!(!is_unit::<<Runtime as nutsfinance_stable_asset::Config>::WeightInfo>());
+		assert!(!is_unit::<
+			<Runtime as pallet_collective::Config<GeneralCouncilInstance>>::WeightInfo,
+		>());
+		assert!(!is_unit::<
+			<Runtime as pallet_membership::Config<GeneralCouncilMembershipInstance>>::WeightInfo,
+		>());
+		assert!(!is_unit::<
+			<Runtime as pallet_collective::Config<FinancialCouncilInstance>>::WeightInfo,
+		>());
+		assert!(!is_unit::<
+			<Runtime as pallet_membership::Config<FinancialCouncilMembershipInstance>>::WeightInfo,
+		>());
+		assert!(!is_unit::<
+			<Runtime as pallet_collective::Config<HomaCouncilInstance>>::WeightInfo,
+		>());
+		assert!(!is_unit::<
+			<Runtime as pallet_membership::Config<HomaCouncilMembershipInstance>>::WeightInfo,
+		>());
+		assert!(!is_unit::<
+			<Runtime as pallet_collective::Config<TechnicalCommitteeInstance>>::WeightInfo,
+		>());
+		assert!(!is_unit::<
+			<Runtime as pallet_membership::Config<TechnicalCommitteeMembershipInstance>>::WeightInfo,
+		>());
+		assert!(!is_unit::<
+			<Runtime as pallet_membership::Config<OperatorMembershipInstanceAcala>>::WeightInfo,
+		>());
+	}
 }
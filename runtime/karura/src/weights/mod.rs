@@ -17,38 +17,27 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 //! A list of the different weight modules for our runtime.
+//!
+//! The pallets configured differently on Karura keep the weights generated by its benchmarks,
+//! the others use the weights shared by the runtimes.
 #![allow(clippy::unnecessary_cast)]
 
-pub mod module_aggregated_dex;
-pub mod module_asset_registry;
-pub mod module_auction_manager;
+pub use runtime_common::weights::{
+	module_aggregated_dex, module_asset_registry, module_auction_manager, module_currencies, module_dex,
+	module_dex_oracle, module_earning, module_emergency_shutdown, module_evm, module_evm_accounts,
+	module_homa_validator_list, module_idle_scheduler, module_incentives, module_nft, module_nominees_election,
+	module_oracle_operator, module_prices, module_scheduler_index, module_session_manager, module_transaction_pause,
+	nutsfinance_stable_asset, orml_authority, orml_oracle, orml_tokens, orml_vesting, pallet_xcm,
+};
+
 pub mod module_cdp_engine;
 pub mod module_cdp_treasury;
 pub mod module_collator_selection;
-pub mod module_currencies;
-pub mod module_dex;
-pub mod module_dex_oracle;
-pub mod module_emergency_shutdown;
-pub mod module_evm;
-pub mod module_evm_accounts;
 pub mod module_homa;
-pub mod module_homa_validator_list;
 pub mod module_honzon;
 pub mod module_honzon_bridge;
-pub mod module_incentives;
-pub mod module_nft;
-pub mod module_nominees_election;
-pub mod module_prices;
-pub mod module_session_manager;
-pub mod module_transaction_pause;
+pub mod module_public_referenda;
 pub mod module_transaction_payment;
+pub mod module_xnft;
 
 pub mod orml_auction;
-pub mod orml_authority;
-pub mod orml_oracle;
-pub mod orml_tokens;
-pub mod orml_vesting;
-
-pub mod nutsfinance_stable_asset;
-
-pub mod pallet_xcm;
@@ -121,6 +121,18 @@ impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T>
 		Weight::from_parts(13_254_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `CdpTreasury::CollateralAuctionLotTarget` (r:0 w:1)
+	// Proof: `CdpTreasury::CollateralAuctionLotTarget` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::MaxCollateralAuctionLots` (r:0 w:1)
+	// Proof: `CdpTreasury::MaxCollateralAuctionLots` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collateral_auction_lot_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_412 nanoseconds.
+		Weight::from_parts(13_412_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:2 w:1)
@@ -134,4 +146,67 @@ impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	// Storage: `CdpTreasury::BasketPurchases` (r:0 w:10)
+	// Proof: `CdpTreasury::BasketPurchases` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::Diversification` (r:0 w:1)
+	// Proof: `CdpTreasury::Diversification` (`max_values`: Some(1), `max_size`: Some(73), added: 568, mode: `MaxEncodedLen`)
+	fn set_diversification_program() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_214 nanoseconds.
+		Weight::from_parts(16_863_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `CdpTreasury::DiversificationBasket` (r:0 w:1)
+	// Proof: `CdpTreasury::DiversificationBasket` (`max_values`: Some(1), `max_size`: Some(421), added: 916, mode: `MaxEncodedLen`)
+	fn set_diversification_basket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_102 nanoseconds.
+		Weight::from_parts(14_580_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpTreasury::Diversification` (r:1 w:1)
+	// Proof: `CdpTreasury::Diversification` (`max_values`: Some(1), `max_size`: Some(73), added: 568, mode: `MaxEncodedLen`)
+	fn stop_diversification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1174`
+		//  Estimated: `1558`
+		// Minimum execution time: 11_406 nanoseconds.
+		Weight::from_parts(11_931_000, 1558)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpTreasury::Diversification` (r:1 w:1)
+	// Proof: `CdpTreasury::Diversification` (`max_values`: Some(1), `max_size`: Some(73), added: 568, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DiversificationBasket` (r:1 w:0)
+	// Proof: `CdpTreasury::DiversificationBasket` (`max_values`: Some(1), `max_size`: Some(421), added: 916, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:3 w:3)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:2 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::BasketPurchases` (r:1 w:1)
+	// Proof: `CdpTreasury::BasketPurchases` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 2]`.
+	fn on_initialize(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3120 + n * (296 ±0)`
+		//  Estimated: `4114 + n * (7866 ±0)`
+		// Minimum execution time: 31_708 nanoseconds.
+		Weight::from_parts(18_264_201, 4114)
+			// Standard Error: 61_827
+			.saturating_add(Weight::from_parts(97_513_416, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7866).saturating_mul(n.into()))
+	}
 }
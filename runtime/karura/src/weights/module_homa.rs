@@ -111,15 +111,27 @@ impl<T: frame_system::Config> module_homa::WeightInfo for WeightInfo<T> {
 	// Proof: `Homa::Unbondings` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Homa::TotalVoidLiquid` (r:0 w:1)
 	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::NominationPoolId` (r:1 w:0)
+	// Proof: `Homa::NominationPoolId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::NominationPoolAllocationRatio` (r:1 w:0)
+	// Proof: `Homa::NominationPoolAllocationRatio` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::NominationPoolPointsHaircut` (r:1 w:0)
+	// Proof: `Homa::NominationPoolPointsHaircut` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::NominationPoolLedgers` (r:1 w:1)
+	// Proof: `Homa::NominationPoolLedgers` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Timestamp::Now` (r:1 w:0)
+	// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	// Storage: `Homa::LastEraBump` (r:0 w:1)
+	// Proof: `Homa::LastEraBump` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn on_initialize_with_bump_era(n: u32,) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2962`
 		//  Estimated: `13852`
 		// Minimum execution time: 314_492 nanoseconds.
 		Weight::from_parts(320_994_000, 13852)
-			.saturating_add(T::DbWeight::get().reads(34))
+			.saturating_add(T::DbWeight::get().reads(39))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(19))
+			.saturating_add(T::DbWeight::get().writes(21))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
@@ -147,8 +159,25 @@ impl<T: frame_system::Config> module_homa::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
+	fn mint_no_op() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_967 nanoseconds.
+		Weight::from_parts(5_116_000, 0)
+	}
 	// Storage: `Homa::RedeemRequests` (r:1 w:1)
 	// Proof: `Homa::RedeemRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestPriorities` (r:1 w:1)
+	// Proof: `Homa::RedeemRequestPriorities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestsByTip` (r:1 w:1)
+	// Proof: `Homa::RedeemRequestsByTip` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestTipBuckets` (r:1 w:1)
+	// Proof: `Homa::RedeemRequestTipBuckets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestExpiries` (r:1 w:1)
+	// Proof: `Homa::RedeemRequestExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestExpiryEras` (r:1 w:1)
+	// Proof: `Homa::RedeemRequestExpiryEras` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:1)
@@ -159,8 +188,33 @@ impl<T: frame_system::Config> module_homa::WeightInfo for WeightInfo<T> {
 		//  Estimated: `6234`
 		// Minimum execution time: 41_391 nanoseconds.
 		Weight::from_parts(42_368_000, 6234)
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: `Homa::RedeemRequests` (r:1 w:1)
+	// Proof: `Homa::RedeemRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestPriorities` (r:1 w:1)
+	// Proof: `Homa::RedeemRequestPriorities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestsByTip` (r:2 w:2)
+	// Proof: `Homa::RedeemRequestsByTip` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestTipBuckets` (r:1 w:1)
+	// Proof: `Homa::RedeemRequestTipBuckets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestExpiries` (r:2 w:2)
+	// Proof: `Homa::RedeemRequestExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::RedeemRequestExpiryEras` (r:1 w:1)
+	// Proof: `Homa::RedeemRequestExpiryEras` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn request_redeem_with_priority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `18913`
+		//  Estimated: `21388`
+		// Minimum execution time: 60_937 nanoseconds.
+		Weight::from_parts(62_104_000, 21388)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	// Storage: `Homa::RedeemRequests` (r:50 w:50)
 	// Proof: `Homa::RedeemRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -276,4 +330,32 @@ impl<T: frame_system::Config> module_homa::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `Homa::NominationPoolId` (r:1 w:1)
+	// Proof: `Homa::NominationPoolId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::NominationPoolLedgers` (r:1 w:0)
+	// Proof: `Homa::NominationPoolLedgers` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::NominationPoolAllocationRatio` (r:0 w:1)
+	// Proof: `Homa::NominationPoolAllocationRatio` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Homa::NominationPoolPointsHaircut` (r:0 w:1)
+	// Proof: `Homa::NominationPoolPointsHaircut` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_nomination_pool_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `2592`
+		// Minimum execution time: 17_931 nanoseconds.
+		Weight::from_parts(18_462_000, 2592)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `Homa::NominationPoolLedgers` (r:1 w:1)
+	// Proof: `Homa::NominationPoolLedgers` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn reset_nomination_pool_ledger() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `2592`
+		// Minimum execution time: 13_129 nanoseconds.
+		Weight::from_parts(13_587_000, 2592)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
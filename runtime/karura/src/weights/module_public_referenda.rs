@@ -0,0 +1,90 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_public_referenda
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_public_referenda.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_public_referenda::WeightInfo for WeightInfo<T> {
+	// Storage: `PublicReferenda::WhitelistedCalls` (r:1 w:1)
+	// Proof: `PublicReferenda::WhitelistedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn whitelist_call() -> Weight {
+		Weight::from_parts(14_085_000, 4507)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `PublicReferenda::WhitelistedCalls` (r:1 w:1)
+	// Proof: `PublicReferenda::WhitelistedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_whitelisted_call() -> Weight {
+		Weight::from_parts(14_317_000, 4540)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `PublicReferenda::WhitelistedCalls` (r:1 w:0)
+	// Proof: `PublicReferenda::WhitelistedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `PublicReferenda::ReferendaEndingAt` (r:1 w:1)
+	// Proof: `PublicReferenda::ReferendaEndingAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Democracy::ReferendumCount` (r:1 w:1)
+	// Proof: `Democracy::ReferendumCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Democracy::ReferendumInfoOf` (r:0 w:1)
+	// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	// Storage: `PublicReferenda::PublicReferenda` (r:0 w:1)
+	// Proof: `PublicReferenda::PublicReferenda` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn submit() -> Weight {
+		Weight::from_parts(51_866_000, 5253)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: `PublicReferenda::ReferendaEndingAt` (r:1 w:1)
+	// Proof: `PublicReferenda::ReferendaEndingAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `PublicReferenda::PublicReferenda` (r:10 w:10)
+	// Proof: `PublicReferenda::PublicReferenda` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Democracy::ReferendumInfoOf` (r:10 w:0)
+	// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:10 w:10)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:10 w:10)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn on_finalize(n: u32, ) -> Weight {
+		Weight::from_parts(7_114_226, 4682)
+			.saturating_add(Weight::from_parts(30_874_105, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2676).saturating_mul(n.into()))
+	}
+}
@@ -0,0 +1,74 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_xnft
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_xnft.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_xnft::WeightInfo for WeightInfo<T> {
+	// Storage: `XNFT::ForeignAssetToClass` (r:1 w:1)
+	// Proof: `XNFT::ForeignAssetToClass` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::NextClassId` (r:1 w:1)
+	// Proof: `OrmlNFT::NextClassId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::Classes` (r:0 w:1)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `XNFT::ClassToForeignAsset` (r:0 w:1)
+	// Proof: `XNFT::ClassToForeignAsset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `XNFT::ForeignCollectionRoyalties` (r:1 w:0)
+	// Proof: `XNFT::ForeignCollectionRoyalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `XNFT::ClassRoyalties` (r:0 w:1)
+	// Proof: `XNFT::ClassRoyalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_asset() -> Weight {
+		Weight::from_parts(29_571_000, 4065)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: `OrmlNFT::Classes` (r:1 w:0)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `XNFT::ClassToForeignAsset` (r:1 w:0)
+	// Proof: `XNFT::ClassToForeignAsset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `XNFT::ClassRoyalties` (r:0 w:1)
+	// Proof: `XNFT::ClassRoyalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_class_royalty() -> Weight {
+		Weight::from_parts(16_316_000, 3937)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `XNFT::ForeignAssetToClass` (r:1 w:0)
+	// Proof: `XNFT::ForeignAssetToClass` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `XNFT::ForeignCollectionRoyalties` (r:0 w:1)
+	// Proof: `XNFT::ForeignCollectionRoyalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `XNFT::ClassRoyalties` (r:0 w:1)
+	// Proof: `XNFT::ClassRoyalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_foreign_collection_royalty() -> Weight {
+		Weight::from_parts(13_913_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
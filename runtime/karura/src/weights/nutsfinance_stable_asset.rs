@@ -1,248 +0,0 @@
-// This file is part of Acala.
-
-// Copyright (C) 2020-2025 Acala Foundation.
-// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
-
-// This program is free software: you can redistribute it and/or modify
-// it under the terms of the GNU General Public License as published by
-// the Free Software Foundation, either version 3 of the License, or
-// (at your option) any later version.
-
-// This program is distributed in the hope that it will be useful,
-// but WITHOUT ANY WARRANTY; without even the implied warranty of
-// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
-// GNU General Public License for more details.
-
-// You should have received a copy of the GNU General Public License
-// along with this program. If not, see <https://www.gnu.org/licenses/>.
-
-//! Autogenerated weights for nutsfinance_stable_asset
-//!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 35.0.1
-//! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `ip-172-31-40-129`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
-//! WASM-EXECUTION: Compiled, CHAIN: Some("karura-dev"), DB CACHE: 1024
-
-// Executed Command:
-// target/production/acala
-// benchmark
-// pallet
-// --chain=karura-dev
-// --steps=50
-// --repeat=20
-// --pallet=*
-// --extrinsic=*
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=./templates/runtime-weight-template.hbs
-// --output=./runtime/karura/src/weights/
-
-#![cfg_attr(rustfmt, rustfmt_skip)]
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-
-use frame_support::{traits::Get, weights::Weight};
-use sp_std::marker::PhantomData;
-
-/// Weight functions for nutsfinance_stable_asset.
-pub struct WeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> nutsfinance_stable_asset::WeightInfo for WeightInfo<T> {
-	// Storage: `StableAsset::PoolCount` (r:1 w:1)
-	// Proof: `StableAsset::PoolCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `StableAsset::Pools` (r:1 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `System::Account` (r:1 w:1)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn create_pool() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1313`
-		//  Estimated: `4778`
-		// Minimum execution time: 23_149 nanoseconds.
-		Weight::from_parts(23_884_000, 4778)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
-	}
-	// Storage: `StableAsset::Pools` (r:1 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn modify_a() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1506`
-		//  Estimated: `4971`
-		// Minimum execution time: 19_334 nanoseconds.
-		Weight::from_parts(20_052_000, 4971)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
-	}
-	// Storage: `StableAsset::Pools` (r:1 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn modify_fees() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1506`
-		//  Estimated: `4971`
-		// Minimum execution time: 18_726 nanoseconds.
-		Weight::from_parts(19_360_000, 4971)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
-	}
-	// Storage: `StableAsset::Pools` (r:1 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn modify_recipients() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1506`
-		//  Estimated: `4971`
-		// Minimum execution time: 18_812 nanoseconds.
-		Weight::from_parts(19_139_000, 4971)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
-	}
-	// Storage: `StableAsset::Pools` (r:1 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `System::Account` (r:2 w:2)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `Tokens::Accounts` (r:10 w:10)
-	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
-	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Homa::ToBondPool` (r:1 w:0)
-	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Tokens::TotalIssuance` (r:2 w:1)
-	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
-	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
-	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `u` is `[2, 5]`.
-	fn mint(u: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2336 + u * (167 ±0)`
-		//  Estimated: `6196 + u * (5244 ±0)`
-		// Minimum execution time: 146_058 nanoseconds.
-		Weight::from_parts(89_767_877, 6196)
-			// Standard Error: 207_175
-			.saturating_add(Weight::from_parts(31_614_808, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(u.into())))
-			.saturating_add(T::DbWeight::get().writes(4))
-			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
-			.saturating_add(Weight::from_parts(0, 5244).saturating_mul(u.into()))
-	}
-	// Storage: `StableAsset::Pools` (r:1 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `System::Account` (r:1 w:1)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `Tokens::Accounts` (r:6 w:3)
-	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
-	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Homa::ToBondPool` (r:1 w:0)
-	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Tokens::TotalIssuance` (r:2 w:1)
-	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
-	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
-	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `u` is `[2, 5]`.
-	fn swap(u: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2528 + u * (164 ±0)`
-		//  Estimated: `5994 + u * (2622 ±0)`
-		// Minimum execution time: 1_889_585 nanoseconds.
-		Weight::from_parts(545_074_575, 5994)
-			// Standard Error: 5_409_267
-			.saturating_add(Weight::from_parts(726_124_110, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(u.into())))
-			.saturating_add(T::DbWeight::get().writes(6))
-			.saturating_add(Weight::from_parts(0, 2622).saturating_mul(u.into()))
-	}
-	// Storage: `StableAsset::Pools` (r:1 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `System::Account` (r:1 w:1)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `Tokens::Accounts` (r:10 w:10)
-	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
-	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Homa::ToBondPool` (r:1 w:0)
-	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Tokens::TotalIssuance` (r:2 w:1)
-	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
-	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
-	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `u` is `[2, 5]`.
-	fn redeem_proportion(u: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2505 + u * (228 ±0)`
-		//  Estimated: `5975 + u * (5244 ±0)`
-		// Minimum execution time: 172_498 nanoseconds.
-		Weight::from_parts(113_168_810, 5975)
-			// Standard Error: 164_148
-			.saturating_add(Weight::from_parts(33_173_262, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(u.into())))
-			.saturating_add(T::DbWeight::get().writes(3))
-			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
-			.saturating_add(Weight::from_parts(0, 5244).saturating_mul(u.into()))
-	}
-	// Storage: `StableAsset::Pools` (r:1 w:0)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `System::Account` (r:1 w:0)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `Tokens::Accounts` (r:4 w:0)
-	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
-	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Homa::ToBondPool` (r:1 w:0)
-	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Tokens::TotalIssuance` (r:1 w:0)
-	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
-	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// The range of component `u` is `[2, 5]`.
-	fn redeem_single(u: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1598 + u * (225 ±0)`
-		//  Estimated: `5110 + u * (1016 ±0)`
-		// Minimum execution time: 1_479_493 nanoseconds.
-		Weight::from_parts(806_038_511, 5110)
-			// Standard Error: 1_260_080
-			.saturating_add(Weight::from_parts(356_752_802, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
-			.saturating_add(Weight::from_parts(0, 1016).saturating_mul(u.into()))
-	}
-	// Storage: `StableAsset::Pools` (r:1 w:1)
-	// Proof: `StableAsset::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `System::Account` (r:1 w:1)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `Tokens::Accounts` (r:10 w:10)
-	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
-	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Homa::ToBondPool` (r:1 w:0)
-	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Tokens::TotalIssuance` (r:2 w:1)
-	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
-	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
-	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `u` is `[2, 5]`.
-	fn redeem_multi(u: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2505 + u * (228 ±0)`
-		//  Estimated: `5975 + u * (5244 ±0)`
-		// Minimum execution time: 149_230 nanoseconds.
-		Weight::from_parts(97_928_824, 5975)
-			// Standard Error: 200_508
-			.saturating_add(Weight::from_parts(28_906_789, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(u.into())))
-			.saturating_add(T::DbWeight::get().writes(3))
-			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
-			.saturating_add(Weight::from_parts(0, 5244).saturating_mul(u.into()))
-	}
-}
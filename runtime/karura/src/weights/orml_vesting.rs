@@ -1,113 +0,0 @@
-// This file is part of Acala.
-
-// Copyright (C) 2020-2025 Acala Foundation.
-// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
-
-// This program is free software: you can redistribute it and/or modify
-// it under the terms of the GNU General Public License as published by
-// the Free Software Foundation, either version 3 of the License, or
-// (at your option) any later version.
-
-// This program is distributed in the hope that it will be useful,
-// but WITHOUT ANY WARRANTY; without even the implied warranty of
-// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
-// GNU General Public License for more details.
-
-// You should have received a copy of the GNU General Public License
-// along with this program. If not, see <https://www.gnu.org/licenses/>.
-
-//! Autogenerated weights for orml_vesting
-//!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 35.0.1
-//! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `ip-172-31-40-129`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
-//! WASM-EXECUTION: Compiled, CHAIN: Some("karura-dev"), DB CACHE: 1024
-
-// Executed Command:
-// target/production/acala
-// benchmark
-// pallet
-// --chain=karura-dev
-// --steps=50
-// --repeat=20
-// --pallet=*
-// --extrinsic=*
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=./templates/runtime-weight-template.hbs
-// --output=./runtime/karura/src/weights/
-
-#![cfg_attr(rustfmt, rustfmt_skip)]
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-
-use frame_support::{traits::Get, weights::Weight};
-use sp_std::marker::PhantomData;
-
-/// Weight functions for orml_vesting.
-pub struct WeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> orml_vesting::WeightInfo for WeightInfo<T> {
-	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
-	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
-	// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
-	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
-	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	// Storage: `System::Account` (r:1 w:1)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn vested_transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1654`
-		//  Estimated: `6315`
-		// Minimum execution time: 37_315 nanoseconds.
-		Weight::from_parts(38_223_000, 6315)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(3))
-	}
-	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
-	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
-	// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `Vesting::VestingSchedules` (r:1 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
-	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
-	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	/// The range of component `i` is `[1, 100]`.
-	fn claim(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1794`
-		//  Estimated: `6315`
-		// Minimum execution time: 39_104 nanoseconds.
-		Weight::from_parts(40_485_740, 6315)
-			// Standard Error: 542
-			.saturating_add(Weight::from_parts(6_547, 0).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(2))
-	}
-	// Storage: `System::Account` (r:1 w:1)
-	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Locks` (r:1 w:1)
-	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	// Storage: `Balances::Freezes` (r:1 w:0)
-	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	// Storage: `Vesting::VestingSchedules` (r:0 w:1)
-	// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2850), added: 5325, mode: `MaxEncodedLen`)
-	/// The range of component `i` is `[1, 100]`.
-	fn update_vesting_schedules(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1266`
-		//  Estimated: `4764`
-		// Minimum execution time: 30_950 nanoseconds.
-		Weight::from_parts(31_945_520, 4764)
-			// Standard Error: 446
-			.saturating_add(Weight::from_parts(40_967, 0).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
-	}
-}
@@ -0,0 +1,65 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, AccountRescue, Runtime, RuntimeEvent, System};
+
+use super::utils::{dollar, lookup_of_account, set_balance, NATIVE, STABLECOIN};
+use frame_benchmarking::whitelisted_caller;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use sp_io::hashing::keccak_256;
+
+fn owner() -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&keccak_256(b"Owner")).unwrap()
+}
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_account_rescue }
+
+	// worst case: the padded account holds the native currency and a token
+	rescue_account {
+		let evm_address = AccountRescue::eth_address(&owner());
+		let source: AccountId = AccountRescue::padded_account(&evm_address);
+		let dest: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &source, 100 * dollar(NATIVE));
+		set_balance(STABLECOIN, &source, 100 * dollar(STABLECOIN));
+		let witness = AccountRescue::eth_sign(&owner(), &source, &dest);
+	}: _(RawOrigin::Root, lookup_of_account(source.clone()), lookup_of_account(dest.clone()), witness)
+	verify {
+		assert_last_event(module_account_rescue::Event::AccountRescued {
+			source,
+			dest,
+			evm_address,
+			native_amount: 99 * dollar(NATIVE),
+			fee: dollar(NATIVE),
+		}.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
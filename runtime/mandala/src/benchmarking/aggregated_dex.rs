@@ -17,19 +17,44 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{dollar, inject_liquidity, set_balance, LIQUID, NATIVE, STABLECOIN, STAKING};
-use crate::{AccountId, CurrencyId, Runtime};
-use module_aggregated_dex::SwapPath;
+use crate::{AccountId, AggregatedDex, CurrencyId, Runtime};
+use module_aggregated_dex::{SwapHistories, SwapPath, SwapRecord, SwapRouteSummary};
 use runtime_common::{BNC, VSKSM};
 
 use sp_std::prelude::*;
 
 use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
 
 use orml_benchmarking::runtime_benchmarks;
 
 const CURRENCY_LIST: [CurrencyId; 6] = [NATIVE, STABLECOIN, LIQUID, STAKING, BNC, VSKSM];
 
+// enable the swap history of `who` and fill it up, so that the swap drops the oldest record.
+fn enable_full_swap_history(who: &AccountId) -> Result<(), sp_runtime::DispatchError> {
+	set_balance(NATIVE, who, 1_000 * dollar(NATIVE));
+	AggregatedDex::enable_swap_history(RawOrigin::Signed(who.clone()).into())?;
+	SwapHistories::<Runtime>::mutate(who, |maybe_history| {
+		if let Some((_, records)) = maybe_history {
+			let record = SwapRecord {
+				block_number: 0,
+				route: SwapRouteSummary {
+					dex_hops: 1,
+					taiga_hops: 0,
+				},
+				supply_currency_id: NATIVE,
+				supply_amount: dollar(NATIVE),
+				target_currency_id: STABLECOIN,
+				target_amount: dollar(STABLECOIN),
+				fee: 0,
+			};
+			while records.try_push(record.clone()).is_ok() {}
+		}
+	});
+	Ok(())
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_aggregated_dex }
 
@@ -54,6 +79,7 @@ runtime_benchmarks! {
 		}
 
 		set_balance(path[0], &taker, 10_000 * dollar(path[0]));
+		enable_full_swap_history(&taker)?;
 	}: swap_with_exact_supply(RawOrigin::Signed(taker), vec![SwapPath::Dex(path.clone())], 100 * dollar(path[0]), 0)
 
 	swap_with_exact_target {
@@ -77,6 +103,7 @@ runtime_benchmarks! {
 		}
 
 		set_balance(path[0], &taker, 10_000 * dollar(path[0]));
+		enable_full_swap_history(&taker)?;
 	}: swap_with_exact_target(RawOrigin::Signed(taker), vec![SwapPath::Dex(path.clone())], 10 * dollar(path[path.len() - 1]), 1_000 * dollar(path[0]))
 
 	update_aggregated_swap_paths {
@@ -89,6 +116,26 @@ runtime_benchmarks! {
 			);
 		}
 	}: _(RawOrigin::Root, updates)
+
+	enable_swap_history {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(SwapHistories::<Runtime>::contains_key(&caller));
+	}
+
+	disable_swap_history {
+		let caller: AccountId = whitelisted_caller();
+		enable_full_swap_history(&caller)?;
+		assert_eq!(
+			AggregatedDex::get_swap_history(&caller).len() as u32,
+			<Runtime as module_aggregated_dex::Config>::MaxSwapHistory::get()
+		);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!SwapHistories::<Runtime>::contains_key(&caller));
+	}
 }
 
 #[cfg(test)]
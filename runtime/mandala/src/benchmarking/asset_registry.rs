@@ -24,7 +24,7 @@ use frame_system::RawOrigin;
 use module_evm::EvmAddress;
 use module_support::AddressMapping;
 use orml_benchmarking::runtime_benchmarks;
-use primitives::currency::AssetMetadata;
+use primitives::currency::{AssetIds, AssetMetadata};
 use sp_std::{boxed::Box, str::FromStr, vec};
 use xcm::{prelude::*, v4::Location};
 
@@ -71,7 +71,7 @@ runtime_benchmarks! {
 			decimals: 12,
 			minimal_balance: 1,
 		};
-	}: _(RawOrigin::Root, Box::new(location), Box::new(asset_metadata))
+	}: _(RawOrigin::Root, Box::new(location), Box::new(asset_metadata), false)
 
 	update_foreign_asset {
 		let location = VersionedLocation::V4(Location::new(
@@ -85,8 +85,8 @@ runtime_benchmarks! {
 			minimal_balance: 1,
 		};
 
-		AssetRegistry::register_foreign_asset(RawOrigin::Root.into(), Box::new(location.clone()), Box::new(asset_metadata.clone()))?;
-	}: _(RawOrigin::Root, 0, Box::new(location), Box::new(asset_metadata))
+		AssetRegistry::register_foreign_asset(RawOrigin::Root.into(), Box::new(location.clone()), Box::new(asset_metadata.clone()), false)?;
+	}: _(RawOrigin::Root, 0, Box::new(location), Box::new(asset_metadata), false)
 
 	register_stable_asset {
 		let asset_metadata = AssetMetadata {
@@ -95,7 +95,7 @@ runtime_benchmarks! {
 			decimals: 12,
 			minimal_balance: 1,
 		};
-	}: _(RawOrigin::Root, Box::new(asset_metadata))
+	}: _(RawOrigin::Root, Box::new(asset_metadata), false)
 
 	update_stable_asset {
 		let asset_metadata = AssetMetadata {
@@ -105,12 +105,12 @@ runtime_benchmarks! {
 			minimal_balance: 1,
 		};
 
-		AssetRegistry::register_stable_asset(RawOrigin::Root.into(), Box::new(asset_metadata.clone()))?;
-	}: _(RawOrigin::Root, 0, Box::new(asset_metadata))
+		AssetRegistry::register_stable_asset(RawOrigin::Root.into(), Box::new(asset_metadata.clone()), false)?;
+	}: _(RawOrigin::Root, 0, Box::new(asset_metadata), false)
 
 	register_erc20_asset {
 		deploy_contract();
-	}: _(RawOrigin::Root, erc20_address(), 1)
+	}: _(RawOrigin::Root, erc20_address(), 1, false)
 
 	update_erc20_asset {
 		let asset_metadata = AssetMetadata {
@@ -121,8 +121,8 @@ runtime_benchmarks! {
 		};
 
 		deploy_contract();
-		AssetRegistry::register_erc20_asset(RawOrigin::Root.into(), erc20_address(), 1)?;
-	}: _(RawOrigin::Root, erc20_address(), Box::new(asset_metadata))
+		AssetRegistry::register_erc20_asset(RawOrigin::Root.into(), erc20_address(), 1, false)?;
+	}: _(RawOrigin::Root, erc20_address(), Box::new(asset_metadata), false)
 
 	register_native_asset {
 		let asset_metadata = AssetMetadata {
@@ -131,7 +131,7 @@ runtime_benchmarks! {
 			decimals: 12,
 			minimal_balance: 1,
 		};
-	}: _(RawOrigin::Root, CurrencyId::LiquidCrowdloan(0), Box::new(asset_metadata))
+	}: _(RawOrigin::Root, CurrencyId::LiquidCrowdloan(0), Box::new(asset_metadata), false)
 
 	update_native_asset {
 		let currency_id = CurrencyId::LiquidCrowdloan(0);
@@ -142,8 +142,20 @@ runtime_benchmarks! {
 			minimal_balance: 1,
 		};
 
-		AssetRegistry::register_native_asset(RawOrigin::Root.into(), currency_id, Box::new(asset_metadata.clone()))?;
-	}: _(RawOrigin::Root, currency_id, Box::new(asset_metadata))
+		AssetRegistry::register_native_asset(RawOrigin::Root.into(), currency_id, Box::new(asset_metadata.clone()), false)?;
+	}: _(RawOrigin::Root, currency_id, Box::new(asset_metadata), false)
+
+	set_canonical_asset {
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+
+		AssetRegistry::register_stable_asset(RawOrigin::Root.into(), Box::new(asset_metadata.clone()), false)?;
+		AssetRegistry::register_stable_asset(RawOrigin::Root.into(), Box::new(asset_metadata), true)?;
+	}: _(RawOrigin::Root, AssetIds::StableAssetId(1))
 }
 
 #[cfg(test)]
@@ -18,8 +18,8 @@
 
 use crate::{
 	AccountId, Address, Amount, CdpEngine, CdpTreasury, CurrencyId, DefaultDebitExchangeRate, Dex, EmergencyShutdown,
-	ExistentialDeposits, MinimumDebitValue, NativeTokenExistentialDeposit, Price, Rate, Ratio, Runtime, H160,
-	MILLISECS_PER_BLOCK,
+	ExchangeRate, ExistentialDeposits, Honzon, Loans, MinimumDebitValue, NativeTokenExistentialDeposit,
+	PartialLiquidationThreshold, Price, Rate, Ratio, Runtime, System, H160, MILLISECS_PER_BLOCK,
 };
 
 use super::{
@@ -30,19 +30,114 @@ use super::{
 	},
 };
 use frame_benchmarking::account;
-use frame_support::traits::{Get, OnInitialize};
+use frame_support::{
+	traits::{Get, OnInitialize},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
-use module_support::DEXManager;
+use module_cdp_engine::{CDPEngineTask, LiquidationHistory, PendingCashback, WeightInfo};
+use module_support::{DEXManager, DispatchableTask, LiquidationKind, LiquidationRecord};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{Change, GetByKey};
 use sp_runtime::{
-	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto},
-	FixedPointNumber,
+	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto, Zero},
+	DispatchResult, FixedPointNumber, Permill,
 };
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+// fill the liquidation history of `who` so that recording a new one rotates out the oldest
+fn fill_liquidation_history(who: &AccountId) {
+	let max_records: u32 = <Runtime as module_cdp_engine::Config>::MaxLiquidationRecords::get();
+	let records = (0..max_records)
+		.map(|_| LiquidationRecord {
+			block_number: Zero::zero(),
+			collateral_type: STAKING,
+			kind: LiquidationKind::Liquidated,
+			collateral_amount: dollar(STAKING),
+			debit_value: dollar(STABLECOIN),
+			penalty_amount: Zero::zero(),
+			route: None,
+		})
+		.collect::<Vec<_>>();
+	LiquidationHistory::<Runtime>::insert(who, BoundedVec::truncate_from(records));
+}
+
+const MAX_REBASE_POSITIONS: u32 = 50;
+
+// open `n` safe CDPs of the staking currency
+fn open_rebase_positions(n: u32) -> DispatchResult {
+	let debit_value = 100 * dollar(STABLECOIN);
+	let debit_amount = CdpEngine::get_debit_exchange_rate(STAKING)
+		.reciprocal()
+		.unwrap()
+		.saturating_mul_int(debit_value);
+	let debit_amount: Amount = debit_amount.unique_saturated_into();
+	let collateral_amount =
+		Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(2 * debit_value);
+
+	feed_price(vec![(STAKING, Price::one())])?;
+	CdpEngine::set_collateral_params(
+		RawOrigin::Root.into(),
+		STAKING,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(debit_value * 100 * (n as u128 + 1)),
+	)?;
+
+	for i in 0..n {
+		let owner: AccountId = account("owner", i, SEED);
+		set_balance(STAKING, &owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+		CdpEngine::adjust_position(&owner, STAKING, collateral_amount.try_into().unwrap(), debit_amount)?;
+	}
+	Ok(())
+}
+
+// set the staking and liquid currencies as the collateral types
+fn set_substitution_collaterals() -> DispatchResult {
+	feed_price(vec![(STAKING, Price::one()), (LIQUID, Price::one())])?;
+	for currency_id in [STAKING, LIQUID] {
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			currency_id,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(10_000 * dollar(STABLECOIN)),
+		)?;
+	}
+	Ok(())
+}
+
+// open the CDP of the staking currency of `owner` and enable the substitution of the staking
+// currency by the liquid currency, which is funded to CDP treasury
+fn enable_staking_substitution(owner: &AccountId) -> DispatchResult {
+	set_substitution_collaterals()?;
+
+	let debit_value = 100 * dollar(STABLECOIN);
+	let debit_amount: Amount = CdpEngine::try_convert_to_debit_balance(STAKING, debit_value)
+		.unwrap()
+		.unique_saturated_into();
+	let collateral_amount =
+		Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(2 * debit_value);
+	set_balance(STAKING, owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+	CdpEngine::adjust_position(owner, STAKING, collateral_amount.try_into().unwrap(), debit_amount)?;
+
+	set_balance(LIQUID, &CdpTreasury::account_id(), 10_000 * dollar(LIQUID));
+	CdpEngine::enable_collateral_substitution(
+		RawOrigin::Root.into(),
+		STAKING,
+		LIQUID,
+		ExchangeRate::saturating_from_rational(dollar(LIQUID), dollar(STAKING)),
+		System::block_number() + 100,
+		10_000 * dollar(STAKING),
+	)
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_cdp_engine }
 
@@ -86,7 +181,7 @@ runtime_benchmarks! {
 				RawOrigin::Root.into(),
 				currency_id,
 				Change::NoChange,
-				Change::NewValue(Some(Ratio::saturating_from_rational(0, 100))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(0, 100))),
 				Change::NewValue(min_debit_value * 100),
@@ -120,6 +215,7 @@ runtime_benchmarks! {
 
 		let owner: AccountId = account("owner", 0, SEED);
 		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		fill_liquidation_history(&owner);
 		let min_debit_value = MinimumDebitValue::get();
 		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
 		let collateral_price = Price::one();		// 1 USD
@@ -156,17 +252,72 @@ runtime_benchmarks! {
 			RawOrigin::Root.into(),
 			STAKING,
 			Change::NoChange,
-			Change::NewValue(Some(Ratio::saturating_from_rational(1000, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(300, 100))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		)?;
+	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
+
+	// `liquidate` of a large cdp, partially by auction
+	liquidate_partially_by_auction {
+		let b in 1 .. <Runtime as module_cdp_treasury::Config>::MaxAuctionsCount::get();
+
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		fill_liquidation_history(&owner);
+		let debit_value = 10 * PartialLiquidationThreshold::get();
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let collateral_price = Price::one();		// 1 USD
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 2 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// set balance
+		set_balance(STAKING, &owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+
+		// feed price
+		feed_price(vec![(STAKING, collateral_price)])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100),
+		)?;
+
+		// about 30% of the collateral is confiscated to restore the rest above 310%,
+		// adjust auction size so we hit MaxAuctionCount
+		let auction_size = collateral_amount / (3 * b as u128);
+		CdpTreasury::set_expected_collateral_auction_size(RawOrigin::Root.into(), STAKING, auction_size)?;
+		// adjust position
+		CdpEngine::adjust_position(&owner, STAKING, collateral_amount.try_into().unwrap(), debit_amount)?;
+
+		// modify liquidation rate to make the cdp unsafe
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(300, 100))),
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
 		)?;
 	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
+	verify {
+		assert!(!Loans::positions(STAKING, &owner).debit.is_zero());
+	}
 
 	// `liquidate` by dex
 	liquidate_by_dex {
 		let owner: AccountId = account("owner", 0, SEED);
 		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		fill_liquidation_history(&owner);
 		let funder: AccountId = account("funder", 0, SEED);
 		let debit_value = 100 * dollar(STABLECOIN);
 		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(LIQUID);
@@ -202,7 +353,7 @@ runtime_benchmarks! {
 			RawOrigin::Root.into(),
 			LIQUID,
 			Change::NoChange,
-			Change::NewValue(Some(Ratio::saturating_from_rational(1000, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(300, 100))),
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
@@ -222,6 +373,7 @@ runtime_benchmarks! {
 	settle {
 		let owner: AccountId = account("owner", 0, SEED);
 		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		fill_liquidation_history(&owner);
 		let min_debit_value = MinimumDebitValue::get();
 		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
 		let collateral_price = Price::one();		// 1 USD
@@ -260,6 +412,265 @@ runtime_benchmarks! {
 	deregister_liquidation_contract {
 		CdpEngine::register_liquidation_contract(RawOrigin::Root.into(), H160::default())?;
 	}: _(RawOrigin::Root, H160::default())
+
+	set_max_price_age {
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(100_000 * dollar(STABLECOIN)),
+		)?;
+	}: _(RawOrigin::Root, STAKING, Some(60 * 60 * 1000))
+
+	set_cross_margin_preference {
+		let owner: AccountId = account("owner", 0, SEED);
+		let currency_ids = get_benchmarking_collateral_currency_ids();
+		for currency_id in currency_ids.iter() {
+			CdpEngine::set_collateral_params(
+				RawOrigin::Root.into(),
+				*currency_id,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NewValue(100_000 * dollar(STABLECOIN)),
+			)?;
+		}
+	}: _(RawOrigin::Signed(owner), currency_ids)
+
+	set_promotional_rate {
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 1_000_000_000))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(100_000 * dollar(STABLECOIN)),
+		)?;
+	}: _(RawOrigin::Root, STAKING, Some((Rate::zero(), 100u32.into())))
+
+	set_penalty_cashback_params {
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(100_000 * dollar(STABLECOIN)),
+		)?;
+	}: _(RawOrigin::Root, STAKING, Permill::from_percent(50), 100u32.into())
+
+	claim_penalty_cashback {
+		let owner: AccountId = account("owner", 0, SEED);
+		let min_debit_value = MinimumDebitValue::get();
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let min_debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(min_debit_value);
+		let min_debit_amount: Amount = min_debit_amount.unique_saturated_into();
+		let collateral_value = 2 * min_debit_value;
+		let collateral_amount = Price::saturating_from_rational(1_000 * dollar(STAKING), 1000 * dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		set_balance(STAKING, &owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+		set_balance(STABLECOIN, &CdpTreasury::account_id(), 10 * dollar(STABLECOIN));
+		feed_price(vec![(STAKING, Price::one())])?;
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(min_debit_value * 100),
+		)?;
+		CdpEngine::adjust_position(&owner, STAKING, collateral_amount.try_into().unwrap(), min_debit_amount)?;
+		module_cdp_engine::PendingCashbacks::<Runtime>::insert(
+			STAKING,
+			&owner,
+			PendingCashback { amount: dollar(STABLECOIN), expires_at: 100u32.into() },
+		);
+	}: _(RawOrigin::Signed(owner.clone()), STAKING)
+	verify {
+		assert!(CdpEngine::pending_cashbacks(STAKING, &owner).is_none());
+	}
+
+	sweep_expired_penalty_cashback {
+		let caller: AccountId = account("caller", 0, SEED);
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		module_cdp_engine::PendingCashbacks::<Runtime>::insert(
+			STAKING,
+			&owner,
+			PendingCashback { amount: dollar(STABLECOIN), expires_at: Zero::zero() },
+		);
+	}: _(RawOrigin::Signed(caller), STAKING, owner_lookup)
+	verify {
+		assert!(CdpEngine::pending_cashbacks(STAKING, &owner).is_none());
+	}
+
+	set_liquidity_ceiling_multiplier {
+		let funder: AccountId = account("funder", 0, SEED);
+		inject_liquidity(funder, STAKING, STABLECOIN, 10_000 * dollar(STAKING), 10_000 * dollar(STABLECOIN), false)?;
+		feed_price(vec![(STAKING, Price::one())])?;
+
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(1_000 * dollar(STABLECOIN)),
+		)?;
+	}: _(RawOrigin::Root, STAKING, Some(Ratio::saturating_from_integer(2)))
+	verify {
+		assert!(CdpEngine::liquidity_debit_ceilings(STAKING).is_some());
+	}
+
+	trigger_position_alerts {
+		let n in 1 .. <Runtime as module_cdp_engine::Config>::MaxPositionAlertBatch::get();
+
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 2 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		feed_price(vec![(STAKING, Price::one())])?;
+
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100 * n as u128),
+		)?;
+
+		let mut owners = (0..n).map(|i| account("owner", i, SEED)).collect::<Vec<AccountId>>();
+		owners.sort();
+		for owner in owners.iter() {
+			set_balance(STAKING, owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+			// collateral ratio is 200%, the alert of 300% is tripped
+			CdpEngine::adjust_position(owner, STAKING, collateral_amount.try_into().unwrap(), debit_amount)?;
+			Honzon::set_position_alert(
+				RawOrigin::Signed(owner.clone()).into(),
+				STAKING,
+				Ratio::saturating_from_rational(300, 100),
+			)?;
+		}
+		let owners: BoundedVec<AccountId, _> = owners.try_into().unwrap();
+	}: _(RawOrigin::None, STAKING, owners)
+
+	schedule_debit_rebase {
+		open_rebase_positions(1)?;
+	}: _(RawOrigin::Root, STAKING, 1_000, 1)
+
+	pause_debit_rebase {
+		open_rebase_positions(1)?;
+		CdpEngine::schedule_debit_rebase(RawOrigin::Root.into(), STAKING, 1_000, 1)?;
+	}: _(RawOrigin::Root, STAKING)
+
+	resume_debit_rebase {
+		open_rebase_positions(1)?;
+		CdpEngine::schedule_debit_rebase(RawOrigin::Root.into(), STAKING, 1_000, 1)?;
+		CdpEngine::pause_debit_rebase(RawOrigin::Root.into(), STAKING)?;
+	}: _(RawOrigin::Root, STAKING)
+
+	// abort the paused rebase after some positions are rebased, the task is scheduled again
+	abort_debit_rebase {
+		open_rebase_positions(2)?;
+		CdpEngine::schedule_debit_rebase(RawOrigin::Root.into(), STAKING, 1_000, 1)?;
+		let weight = <Runtime as module_cdp_engine::Config>::WeightInfo::debit_rebase(1);
+		let _ = CDPEngineTask::<Runtime>::DebitRebase {
+			currency_id: STAKING,
+			scheduled_at: System::block_number(),
+		}
+		.dispatch(weight);
+		CdpEngine::pause_debit_rebase(RawOrigin::Root.into(), STAKING)?;
+	}: _(RawOrigin::Root, STAKING)
+
+	// rebase the positions by idle scheduler, the last batch completes the rebase
+	debit_rebase {
+		let n in 0 .. MAX_REBASE_POSITIONS;
+		open_rebase_positions(n)?;
+		CdpEngine::schedule_debit_rebase(RawOrigin::Root.into(), STAKING, 1_000, 1)?;
+		let weight = <Runtime as module_cdp_engine::Config>::WeightInfo::debit_rebase(n);
+	}: {
+		let _ = CDPEngineTask::<Runtime>::DebitRebase {
+			currency_id: STAKING,
+			scheduled_at: System::block_number(),
+		}
+		.dispatch(weight);
+	}
+	verify {
+		assert_eq!(CdpEngine::debit_rebases(STAKING), None);
+	}
+
+	enable_collateral_substitution {
+		set_substitution_collaterals()?;
+		let rate = ExchangeRate::saturating_from_rational(dollar(LIQUID), dollar(STAKING));
+		let deadline = System::block_number() + 100;
+	}: _(RawOrigin::Root, STAKING, LIQUID, rate, deadline, 10_000 * dollar(STAKING))
+
+	disable_collateral_substitution {
+		let owner: AccountId = account("owner", 0, SEED);
+		enable_staking_substitution(&owner)?;
+	}: _(RawOrigin::Root, STAKING)
+
+	substitute_collateral {
+		let owner: AccountId = account("owner", 0, SEED);
+		enable_staking_substitution(&owner)?;
+	}: _(RawOrigin::Signed(owner.clone()), STAKING)
+	verify {
+		assert!(!module_loans::Pallet::<Runtime>::positions(LIQUID, &owner).debit.is_zero());
+	}
+
+	// take the risk snapshot of the staking currency, all of its positions are sampled
+	update_risk_snapshot {
+		let n in 0 .. <Runtime as module_cdp_engine::Config>::MaxRiskSnapshotPositions::get();
+		open_rebase_positions(n)?;
+	}: {
+		CdpEngine::update_risk_snapshot(STAKING, System::block_number());
+	}
+	verify {
+		assert_eq!(CdpEngine::risk_snapshots(STAKING).map(|snapshot| snapshot.sampled_positions), Some(n));
+	}
+
+	// top up the unsafe position to be safe again
+	expand_position_collateral_urgent {
+		let owner: AccountId = account("owner", 0, SEED);
+		let min_debit_value = MinimumDebitValue::get();
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let min_debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(min_debit_value);
+		let min_debit_amount: Amount = min_debit_amount.unique_saturated_into();
+		let collateral_value = 2 * min_debit_value;
+		let collateral_amount = Price::saturating_from_rational(1_000 * dollar(STAKING), 1000 * dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		set_balance(STAKING, &owner, 2 * collateral_amount + ExistentialDeposits::get(&STAKING));
+		feed_price(vec![(STAKING, Price::one())])?;
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(min_debit_value * 100),
+		)?;
+		CdpEngine::adjust_position(&owner, STAKING, collateral_amount.try_into().unwrap(), min_debit_amount)?;
+
+		// the collateral ratio drops to 100%
+		feed_price(vec![(STAKING, Price::saturating_from_rational(1, 2))])?;
+	}: _(RawOrigin::Signed(owner.clone()), STAKING, 0, collateral_amount)
+	verify {
+		assert_eq!(module_loans::Pallet::<Runtime>::positions(STAKING, &owner).collateral, 2 * collateral_amount);
+	}
 }
 
 #[cfg(test)]
@@ -16,15 +16,30 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, CdpTreasury, Currencies, Runtime};
+use crate::{AccountId, CdpTreasury, Currencies, Runtime, System};
 
-use super::utils::{dollar, initialize_swap_pools, set_balance, STABLECOIN, STAKING};
+use super::utils::{dollar, feed_price, initialize_swap_pools, set_balance, LIQUID, STABLECOIN, STAKING};
 use frame_benchmarking::whitelisted_caller;
-use frame_support::traits::Get;
+use frame_support::traits::{Get, OnInitialize};
 use frame_system::RawOrigin;
-use module_support::{CDPTreasury, SwapLimit};
+use module_cdp_treasury::BasketAsset;
+use module_support::{CDPTreasury, IssuanceOrigin, Price, Ratio, SwapLimit};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
+use sp_runtime::traits::One;
+use sp_std::prelude::*;
+
+fn basket(n: u32) -> Vec<BasketAsset> {
+	[STAKING, LIQUID]
+		.into_iter()
+		.take(n as usize)
+		.map(|currency_id| BasketAsset {
+			currency_id,
+			weight: 1,
+			max_position: 1_000_000 * dollar(currency_id),
+		})
+		.collect()
+}
 
 runtime_benchmarks! {
 	{ Runtime, module_cdp_treasury }
@@ -50,9 +65,38 @@ runtime_benchmarks! {
 	set_expected_collateral_auction_size {
 	}: _(RawOrigin::Root, STAKING, 200 * dollar(STAKING))
 
+	set_collateral_auction_lot_params {
+	}: _(RawOrigin::Root, 1_000 * dollar(STABLECOIN), 2)
+
 	extract_surplus_to_treasury {
-		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
+		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN), IssuanceOrigin::System)?;
 	}: _(RawOrigin::Root, 200 * dollar(STABLECOIN))
+
+	set_diversification_program {
+	}: _(RawOrigin::Root, 10, 1_000 * dollar(STABLECOIN), 10_000 * dollar(STABLECOIN), Ratio::one())
+
+	set_diversification_basket {
+	}: _(RawOrigin::Root, basket(2))
+
+	stop_diversification {
+		CdpTreasury::set_diversification_program(RawOrigin::Root.into(), 10, 1_000 * dollar(STABLECOIN), 10_000 * dollar(STABLECOIN), Ratio::one())?;
+	}: _(RawOrigin::Root)
+
+	on_initialize {
+		let n in 1 .. 2;
+
+		let caller: AccountId = whitelisted_caller();
+		initialize_swap_pools(caller)?;
+		feed_price(vec![(STAKING, Price::one())])?;
+		set_balance(STABLECOIN, &<Runtime as module_cdp_treasury::Config>::TreasuryAccount::get(), 1_000 * dollar(STABLECOIN));
+
+		CdpTreasury::set_diversification_program(RawOrigin::Root.into(), 1, 10 * dollar(STABLECOIN), 100 * dollar(STABLECOIN), Ratio::one())?;
+		CdpTreasury::set_diversification_basket(RawOrigin::Root.into(), basket(n))?;
+		let now = CdpTreasury::diversification().unwrap().next_period_at;
+		System::set_block_number(now);
+	}: {
+		CdpTreasury::on_initialize(now);
+	}
 }
 
 #[cfg(test)]
@@ -28,10 +28,11 @@ use frame_support::{
 	traits::{Currency, Get, OnInitialize},
 };
 use frame_system::RawOrigin;
-use module_collator_selection::POINT_PER_BLOCK;
+use module_collator_selection::{RewardDestination, POINT_PER_BLOCK};
 use orml_benchmarking::{runtime_benchmarks, whitelist_account};
 use pallet_authorship::EventHandler;
 use pallet_session::SessionManager;
+use sp_runtime::Permill;
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -98,6 +99,20 @@ runtime_benchmarks! {
 		assert_last_event(module_collator_selection::Event::NewCandidacyBond{new_candidacy_bond: bond}.into());
 	}
 
+	set_smoothing_factor {
+		let smoothing_factor = Permill::from_percent(50);
+	}: _(RawOrigin::Root, smoothing_factor)
+	verify {
+		assert_last_event(module_collator_selection::Event::NewSmoothingFactor{new_smoothing_factor: smoothing_factor}.into());
+	}
+
+	set_reward_destination {
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), RewardDestination::Bond)
+	verify {
+		assert_last_event(module_collator_selection::Event::RewardDestinationSet{who: caller, destination: RewardDestination::Bond}.into());
+	}
+
 	// worse case is when we have all the max-candidate slots filled except one, and we fill that
 	// one.
 	register_as_candidate {
@@ -17,7 +17,10 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{dollar, lookup_of_account, set_balance, NATIVE, STAKING};
-use crate::{AccountId, Amount, Balance, Currencies, NativeTokenExistentialDeposit, Runtime, Tokens, TreasuryPalletId};
+use crate::{
+	AccountId, Amount, Balance, Currencies, CurrencyId, NativeTokenExistentialDeposit, Runtime, TokenSymbol, Tokens,
+	TreasuryPalletId,
+};
 
 use sp_std::prelude::*;
 
@@ -163,6 +166,57 @@ runtime_benchmarks! {
 			vec![]
 		);
 	}
+
+	// `transfer_all_currencies` in worst case
+	// * will create the `to` accounts of all the currencies.
+	// * will kill the `from` account.
+	transfer_all_currencies {
+		let c in 1..8u32;
+		let currency_ids: Vec<CurrencyId> = vec![
+			TokenSymbol::AUSD,
+			TokenSymbol::DOT,
+			TokenSymbol::LDOT,
+			TokenSymbol::TAP,
+			TokenSymbol::TAI,
+			TokenSymbol::KUSD,
+			TokenSymbol::KSM,
+			TokenSymbol::LKSM,
+		].into_iter().map(CurrencyId::Token).collect();
+		let native_amount: Balance = NativeTokenExistentialDeposit::get().saturating_mul(1000);
+		let from: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &from, native_amount);
+		for currency_id in &currency_ids[..c as usize] {
+			set_balance(*currency_id, &from, dollar(*currency_id));
+		}
+
+		let to: AccountId = account("to", 0, SEED);
+		let to_lookup = lookup_of_account(to.clone());
+	}: _(RawOrigin::Signed(from), to_lookup, module_currencies::DustHandling::Skip)
+	verify {
+		for currency_id in &currency_ids[..c as usize] {
+			assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(*currency_id, &to), dollar(*currency_id));
+		}
+		assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(NATIVE, &to), native_amount);
+	}
+
+	set_currency_dust_mode {
+		let provider: AccountId = account("provider", 0, SEED);
+	}: _(RawOrigin::Root, STAKING, Some(module_currencies::DustMode::CreditToProvider(provider.clone())))
+	verify {
+		assert_eq!(
+			Currencies::currency_dust_modes(STAKING),
+			Some(module_currencies::DustMode::CreditToProvider(provider))
+		);
+	}
+
+	set_dust_consolidation {
+		let who: AccountId = whitelisted_caller();
+		let beneficiary: AccountId = account("beneficiary", 0, SEED);
+		let beneficiary_lookup = lookup_of_account(beneficiary.clone());
+	}: _(RawOrigin::Signed(who.clone()), Some(beneficiary_lookup))
+	verify {
+		assert_eq!(Currencies::dust_consolidations(&who), Some(beneficiary));
+	}
 }
 
 #[cfg(test)]
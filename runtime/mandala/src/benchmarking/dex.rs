@@ -17,15 +17,20 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{dollar, inject_liquidity, LIQUID, NATIVE, STABLECOIN, STAKING};
-use crate::{AccountId, Currencies, CurrencyId, Dex, ExtendedProvisioningBlocks, Runtime, RuntimeEvent, System};
+use crate::{
+	AccountId, Currencies, CurrencyId, Dex, ExtendedProvisioningBlocks, MaxLbpPools, MaxSwapCommitsPerBlock,
+	MinSwapCommitDeposit, Runtime, RuntimeEvent, System,
+};
 use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
-use module_dex::TradingPairStatus;
+use module_dex::{LbpEndAction, LbpPools, SwapCommitExpiries, TradingPairStatus};
+use module_support::{SwapLimit, TradingPairListing};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::TradingPair;
 use runtime_common::{BNC, VSKSM};
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{traits::UniqueSaturatedInto, Permill};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -36,6 +41,38 @@ fn assert_last_event(generic_event: RuntimeEvent) {
 	System::assert_last_event(generic_event.into());
 }
 
+fn create_funded_lbp(owner: &AccountId, project: CurrencyId, base: CurrencyId, end: u32) -> Result<(), &'static str> {
+	Dex::create_lbp(
+		RawOrigin::Root.into(),
+		owner.clone(),
+		project,
+		base,
+		Permill::from_percent(90),
+		Permill::from_percent(50),
+		System::block_number() + 1,
+		end,
+		LbpEndAction::ConvertToDex,
+	)?;
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(
+		project,
+		owner,
+		(10_000 * dollar(project)).unique_saturated_into(),
+	)?;
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(
+		base,
+		owner,
+		(10_000 * dollar(base)).unique_saturated_into(),
+	)?;
+	Dex::fund_lbp(
+		RawOrigin::Signed(owner.clone()).into(),
+		project,
+		base,
+		9_000 * dollar(project),
+		1_000 * dollar(base),
+	)?;
+	Ok(())
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_dex }
 
@@ -61,6 +98,29 @@ runtime_benchmarks! {
 		assert_last_event(module_dex::Event::DisableTradingPair{trading_pair}.into());
 	}
 
+	// pause a Enabled trading pair
+	pause_trading_pair {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Disabled = Dex::trading_pair_statuses(trading_pair) {
+			let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second());
+		}
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
+	verify {
+		assert_last_event(module_dex::Event::PauseTradingPair{trading_pair}.into());
+	}
+
+	// unpause a Paused trading pair
+	unpause_trading_pair {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Disabled = Dex::trading_pair_statuses(trading_pair) {
+			let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second());
+		}
+		Dex::pause_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
+	verify {
+		assert_last_event(module_dex::Event::UnpauseTradingPair{trading_pair}.into());
+	}
+
 	// list a Provisioning trading pair
 	list_provisioning {
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
@@ -220,6 +280,11 @@ runtime_benchmarks! {
 		inject_liquidity(first_maker.clone(), trading_pair.first(), trading_pair.second(), amount_a, amount_b, true)?;
 	}: add_liquidity(RawOrigin::Signed(second_maker), trading_pair.first(), trading_pair.second(), amount_a, amount_b, Default::default(), true)
 
+	add_liquidity_no_op {
+		let maker: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+	}: add_liquidity(RawOrigin::Signed(maker), trading_pair.first(), trading_pair.second(), 0, 0, Default::default(), false)
+
 	// remove liquidity by liquid lp share
 	remove_liquidity {
 		let maker: AccountId = whitelisted_caller();
@@ -234,6 +299,11 @@ runtime_benchmarks! {
 		inject_liquidity(maker.clone(), trading_pair.first(), trading_pair.second(), 100 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), true)?;
 	}: remove_liquidity(RawOrigin::Signed(maker), trading_pair.first(), trading_pair.second(), 50 * dollar(trading_pair.first()), Default::default(), Default::default(), true)
 
+	remove_liquidity_no_op {
+		let maker: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+	}: remove_liquidity(RawOrigin::Signed(maker), trading_pair.first(), trading_pair.second(), 0, Default::default(), Default::default(), false)
+
 	swap_with_exact_supply {
 		let u in 2 .. <Runtime as module_dex::Config>::TradingPathLimit::get();
 
@@ -358,6 +428,191 @@ runtime_benchmarks! {
 
 		System::set_block_number(ExtendedProvisioningBlocks::get() + 1);
 	}: _(RawOrigin::Signed(whitelisted_caller()), trading_pair.first(), trading_pair.second())
+
+	// set the listing bundle of a Disabled trading pair
+	set_trading_pair_listing {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		let listing = TradingPairListing {
+			average_price_interval: Some(12_000),
+			incentive_rewards: vec![(NATIVE, dollar(NATIVE))],
+		};
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Some(listing.clone()))
+	verify {
+		assert_last_event(module_dex::Event::TradingPairListingUpdated{trading_pair, listing: Some(listing)}.into());
+	}
+
+	// repair a Provisioning trading pair to Enabled, injecting its provision
+	force_repair_trading_pair {
+		let founder: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		Dex::list_provisioning(
+			RawOrigin::Root.into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			dollar(trading_pair.first()),
+			dollar(trading_pair.second()),
+			100 * dollar(trading_pair.first()),
+			100 * dollar(trading_pair.second()),
+			0
+		)?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &founder, (100 * dollar(trading_pair.first())).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &founder, (100 * dollar(trading_pair.second())).unique_saturated_into())?;
+		Dex::add_provision(
+			RawOrigin::Signed(founder).into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			10 * dollar(trading_pair.first()),
+			10 * dollar(trading_pair.second()),
+		)?;
+		let old_status = Dex::trading_pair_statuses(trading_pair);
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), TradingPairStatus::Enabled)
+	verify {
+		assert_last_event(module_dex::Event::TradingPairRepaired{
+			trading_pair,
+			old_status,
+			new_status: TradingPairStatus::Enabled,
+			injected_provision_0: 10 * dollar(trading_pair.first()),
+			injected_provision_1: 10 * dollar(trading_pair.second()),
+			issued_share_amount: 20 * dollar(trading_pair.first()),
+		}.into());
+	}
+
+	commit_swap {
+		let who: AccountId = whitelisted_caller();
+		let hash = Dex::swap_commit_hash(&who, &[NATIVE, STABLECOIN], SwapLimit::ExactSupply(dollar(NATIVE), 0), [0u8; 32]);
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &who, (10 * MinSwapCommitDeposit::get()).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(who.clone()), hash, MinSwapCommitDeposit::get())
+	verify {
+		assert_last_event(module_dex::Event::SwapCommitted{
+			who,
+			hash,
+			deposit: MinSwapCommitDeposit::get(),
+		}.into());
+	}
+
+	reveal_swap {
+		let u in 2 .. <Runtime as module_dex::Config>::TradingPathLimit::get();
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+
+		let mut path: Vec<CurrencyId> = vec![];
+		for i in 1 .. u {
+			if i == 1 {
+				let cur0 = CURRENCY_LIST[0];
+				let cur1 = CURRENCY_LIST[1];
+				path.push(cur0);
+				path.push(cur1);
+				inject_liquidity(maker.clone(), cur0, cur1, 10_000 * dollar(cur0), 10_000 * dollar(cur1), false)?;
+			} else {
+				path.push(CURRENCY_LIST[i as usize]);
+				inject_liquidity(maker.clone(), CURRENCY_LIST[i as usize - 1], CURRENCY_LIST[i as usize], 10_000 * dollar(CURRENCY_LIST[i as usize - 1]), 10_000 * dollar(CURRENCY_LIST[i as usize]), false)?;
+			}
+		}
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
+		let limit = SwapLimit::ExactSupply(100 * dollar(path[0]), 0);
+		let hash = Dex::swap_commit_hash(&taker, &path, limit, [0u8; 32]);
+		Dex::commit_swap(RawOrigin::Signed(taker.clone()).into(), hash, MinSwapCommitDeposit::get())?;
+		System::set_block_number(System::block_number() + 1);
+	}: _(RawOrigin::Signed(taker.clone()), path.clone(), limit, [0u8; 32])
+	verify {
+		assert_last_event(module_dex::Event::SwapRevealed{who: taker, hash}.into());
+	}
+
+	on_initialize {
+		let n in 0 .. MaxSwapCommitsPerBlock::get();
+
+		for i in 0 .. n {
+			let who: AccountId = account("committer", i, SEED);
+			let hash = Dex::swap_commit_hash(&who, &[NATIVE, STABLECOIN], SwapLimit::ExactSupply(dollar(NATIVE), 0), [0u8; 32]);
+			<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &who, (10 * MinSwapCommitDeposit::get()).unique_saturated_into())?;
+			Dex::commit_swap(RawOrigin::Signed(who).into(), hash, MinSwapCommitDeposit::get())?;
+		}
+		let expiry = System::block_number() + <Runtime as module_dex::Config>::SwapRevealPeriod::get() + 1;
+		assert_eq!(SwapCommitExpiries::<Runtime>::get(expiry).len() as u32, n);
+	}: {
+		Dex::on_initialize(expiry);
+	}
+	verify {
+		assert!(SwapCommitExpiries::<Runtime>::get(expiry).is_empty());
+	}
+
+	create_lbp {
+		let owner: AccountId = account("owner", 0, SEED);
+		let trading_pair = TradingPair::from_currency_ids(NATIVE, STABLECOIN).unwrap();
+		let start = System::block_number() + 1;
+	}: _(RawOrigin::Root, owner, NATIVE, STABLECOIN, Permill::from_percent(90), Permill::from_percent(50), start, start + 100, LbpEndAction::ConvertToDex)
+	verify {
+		assert!(LbpPools::<Runtime>::contains_key(trading_pair));
+	}
+
+	fund_lbp {
+		let owner: AccountId = account("owner", 0, SEED);
+		let start = System::block_number() + 1;
+		Dex::create_lbp(RawOrigin::Root.into(), owner.clone(), NATIVE, STABLECOIN, Permill::from_percent(90), Permill::from_percent(50), start, start + 100, LbpEndAction::ConvertToDex)?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &owner, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &owner, (10_000 * dollar(STABLECOIN)).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(owner), NATIVE, STABLECOIN, 9_000 * dollar(NATIVE), 1_000 * dollar(STABLECOIN))
+	verify {
+		assert_last_event(module_dex::Event::LbpFunded{
+			trading_pair: TradingPair::from_currency_ids(NATIVE, STABLECOIN).unwrap(),
+			project_amount: 9_000 * dollar(NATIVE),
+			base_amount: 1_000 * dollar(STABLECOIN),
+		}.into());
+	}
+
+	swap_with_lbp {
+		let owner: AccountId = account("owner", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		create_funded_lbp(&owner, NATIVE, STABLECOIN, System::block_number() + 100)?;
+		System::set_block_number(System::block_number() + 1);
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &taker, (1_000 * dollar(STABLECOIN)).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(taker), STABLECOIN, NATIVE, SwapLimit::ExactSupply(10 * dollar(STABLECOIN), 0))
+	verify {
+		assert!(LbpPools::<Runtime>::get(TradingPair::from_currency_ids(NATIVE, STABLECOIN).unwrap()).unwrap().base_reserve > 1_000 * dollar(STABLECOIN));
+	}
+
+	exit_lbp {
+		let owner: AccountId = account("owner", 0, SEED);
+		let end = System::block_number() + 100;
+		create_funded_lbp(&owner, NATIVE, STABLECOIN, end)?;
+		System::set_block_number(end);
+	}: _(RawOrigin::Signed(owner), NATIVE, STABLECOIN)
+	verify {
+		assert!(!LbpPools::<Runtime>::contains_key(TradingPair::from_currency_ids(NATIVE, STABLECOIN).unwrap()));
+	}
+
+	// settle n liquidity bootstrapping pools by converting them into the liquidity pools
+	lbp_on_initialize {
+		let n in 0 .. MaxLbpPools::get();
+
+		let owner: AccountId = account("owner", 0, SEED);
+		let end = System::block_number() + 100;
+		let mut created = 0;
+		'pairs: for i in 0 .. CURRENCY_LIST.len() {
+			for j in i + 1 .. CURRENCY_LIST.len() {
+				if created == n {
+					break 'pairs;
+				}
+				create_funded_lbp(&owner, CURRENCY_LIST[i], CURRENCY_LIST[j], end)?;
+				created += 1;
+			}
+		}
+		assert_eq!(LbpPools::<Runtime>::iter().count() as u32, n);
+	}: {
+		Dex::on_initialize(end);
+	}
+	verify {
+		assert_eq!(LbpPools::<Runtime>::iter().count(), 0);
+	}
 }
 
 #[cfg(test)]
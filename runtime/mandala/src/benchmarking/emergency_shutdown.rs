@@ -16,20 +16,32 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, CdpTreasury, CurrencyId, EmergencyShutdown, Price, Runtime};
+use crate::{AccountId, CdpTreasury, CurrencyId, EmergencyShutdown, Price, Runtime, System};
 
 use super::{
 	get_benchmarking_collateral_currency_ids,
 	utils::{dollar, feed_price, set_balance, STABLECOIN},
 };
 use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
-use module_support::CDPTreasury;
+use module_emergency_shutdown::{EmergencyShutdownTask, WeightInfo};
+use module_support::{CDPTreasury, DispatchableTask, IssuanceOrigin};
 use orml_benchmarking::runtime_benchmarks;
 use sp_runtime::traits::One;
 use sp_std::vec;
 
 const SEED: u32 = 0;
+const MAX_SWEEP_ACCOUNTS: u32 = 100;
+
+fn open_refund_and_pass_grace_period() -> Result<(), sp_runtime::DispatchError> {
+	EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
+	EmergencyShutdown::open_collateral_refund(RawOrigin::Root.into())?;
+	System::set_block_number(
+		System::block_number() + <Runtime as module_emergency_shutdown::Config>::RefundSweepGracePeriod::get(),
+	);
+	Ok(())
+}
 
 runtime_benchmarks! {
 	{ Runtime, module_emergency_shutdown }
@@ -67,12 +79,45 @@ runtime_benchmarks! {
 		}
 		feed_price(values)?;
 
-		CdpTreasury::issue_debit(&caller, 1_000 * dollar(STABLECOIN), true)?;
-		CdpTreasury::issue_debit(&funder, 1_000 * dollar(STABLECOIN), true)?;
+		CdpTreasury::issue_debit(&caller, 1_000 * dollar(STABLECOIN), true, IssuanceOrigin::System)?;
+		CdpTreasury::issue_debit(&funder, 1_000 * dollar(STABLECOIN), true, IssuanceOrigin::System)?;
 
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 		EmergencyShutdown::open_collateral_refund(RawOrigin::Root.into())?;
 	}: _(RawOrigin::Signed(caller),  1_000 * dollar(STABLECOIN))
+
+	start_refund_sweep {
+		open_refund_and_pass_grace_period()?;
+	}: _(RawOrigin::Root, MAX_SWEEP_ACCOUNTS)
+
+	// refund the stable currency holders by idle scheduler
+	refund_sweep {
+		let n in 0 .. MAX_SWEEP_ACCOUNTS;
+		let currency_ids = get_benchmarking_collateral_currency_ids();
+		let funder: AccountId = account("funder", 0, SEED);
+		let mut values = vec![];
+
+		for currency_id in currency_ids {
+			if matches!(currency_id, CurrencyId::StableAssetPoolToken(_)) {
+				continue;
+			}
+			values.push((currency_id, Price::one()));
+			set_balance(currency_id, &funder, 100 * dollar(currency_id));
+			CdpTreasury::deposit_collateral(&funder, currency_id, 100 * dollar(currency_id))?;
+		}
+		feed_price(values)?;
+
+		for i in 0 .. n {
+			let holder: AccountId = account("holder", i, SEED);
+			CdpTreasury::issue_debit(&holder, 1_000 * dollar(STABLECOIN), true, IssuanceOrigin::System)?;
+		}
+
+		open_refund_and_pass_grace_period()?;
+		EmergencyShutdown::start_refund_sweep(RawOrigin::Root.into(), MAX_SWEEP_ACCOUNTS)?;
+		let weight = <Runtime as module_emergency_shutdown::Config>::WeightInfo::refund_sweep(n);
+	}: {
+		let _ = EmergencyShutdownTask::<Runtime>::RefundSweep { started_at: System::block_number() }.dispatch(weight);
+	}
 }
 
 #[cfg(test)]
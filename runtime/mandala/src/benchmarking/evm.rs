@@ -16,16 +16,20 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, EvmAccounts, Runtime, RuntimeEvent, RuntimeOrigin, System, EVM};
+use crate::{
+	AccountId, EvmAccounts, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, UncheckedExtrinsic, EVM,
+};
 
 use super::utils::{dollar, set_balance, NATIVE};
+use frame_support::traits::Hooks;
 use frame_system::RawOrigin;
-use module_evm::MaxCodeSize;
+use module_evm::{EvmTransactionIndex, MaxCodeSize};
 use module_support::AddressMapping;
 use orml_benchmarking::{runtime_benchmarks, whitelist_account};
+use parity_scale_codec::Encode;
 use sp_core::{H160, H256};
 use sp_io::hashing::keccak_256;
-use sp_runtime::DispatchError;
+use sp_runtime::{traits::Extrinsic, DispatchError};
 use sp_std::{str::FromStr, vec};
 
 fn contract_addr() -> H160 {
@@ -139,7 +143,7 @@ runtime_benchmarks! {
 		let account_id = <Runtime as module_evm::Config>::TreasuryAccount::get();
 		set_balance(NATIVE, &account_id, 1_000_000 * dollar(NATIVE));
 		let address = primitives::evm::MIRRORED_TOKENS_ADDRESS_START | H160::from_low_u64_be(EVM::network_contract_index());
-	}: _(RawOrigin::Root, EMPTY_CONTRACT.to_vec(), 0, 2_100_000, 15_000, vec![])
+	}: _(RawOrigin::Root, EMPTY_CONTRACT.to_vec(), 0, 2_100_000, 15_000, vec![], Some(b"NFT".to_vec().try_into().unwrap()))
 	verify {
 		let code_hash = EVM::code_hash_at_address(&address);
 		assert!(module_evm::Codes::<Runtime>::contains_key(code_hash));
@@ -149,12 +153,23 @@ runtime_benchmarks! {
 		let account_id = <Runtime as module_evm::Config>::TreasuryAccount::get();
 		set_balance(NATIVE, &account_id, 1_000_000 * dollar(NATIVE));
 		let address = H160::from_low_u64_be(1);
-	}: _(RawOrigin::Root, address, EMPTY_CONTRACT.to_vec(), 0, 2_100_000, 15_000, vec![])
+	}: _(RawOrigin::Root, address, EMPTY_CONTRACT.to_vec(), 0, 2_100_000, 15_000, vec![], Some(b"PREDEPLOY".to_vec().try_into().unwrap()))
 	verify {
 		let code_hash = EVM::code_hash_at_address(&address);
 		assert!(module_evm::Codes::<Runtime>::contains_key(code_hash));
 	}
 
+	set_system_contract {
+		let account_id = <Runtime as module_evm::Config>::TreasuryAccount::get();
+		set_balance(NATIVE, &account_id, 1_000_000 * dollar(NATIVE));
+		let address = H160::from_low_u64_be(1);
+		EVM::create_predeploy_contract(RawOrigin::Root.into(), address, EMPTY_CONTRACT.to_vec(), 0, 2_100_000, 15_000, vec![], None)?;
+		let key: primitives::evm::SystemContractKey = b"PREDEPLOY".to_vec().try_into().unwrap();
+	}: _(RawOrigin::Root, key.clone(), Some(address))
+	verify {
+		assert_eq!(EVM::system_contracts(key), Some(address));
+	}
+
 	call {
 		// Storage.store(1)
 		let input = hex_literal::hex!("6057361d0000000000000000000000000000000000000000000000000000000000000001").to_vec();
@@ -253,6 +268,37 @@ runtime_benchmarks! {
 
 		whitelist_account!(alice_account);
 	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
+	on_finalize_with_transaction_index {
+		let n in 0 .. 100;
+
+		let call = RuntimeCall::EVM(module_evm::Call::call {
+			target: contract_addr(),
+			input: vec![0; 1024],
+			value: 0,
+			gas_limit: 1_000_000,
+			storage_limit: 1_000,
+			access_list: vec![],
+		});
+		let extrinsic = <UncheckedExtrinsic as Extrinsic>::new(call, None).unwrap().encode();
+		for i in 0 .. n {
+			System::set_extrinsic_index(i);
+			System::note_extrinsic(extrinsic.clone());
+			module_evm::PendingTransactionIndex::<Runtime>::append(EvmTransactionIndex {
+				extrinsic_index: i,
+				transaction_hash: Default::default(),
+				from: contract_addr(),
+				account_id: Some(alice_account_id()),
+				contract_address: None,
+				log_count: 1,
+			});
+		}
+	}: {
+		EVM::on_finalize(System::block_number());
+	}
+	verify {
+		assert_eq!(EVM::get_block_tx_index(System::block_number()).map_or(0, |txs| txs.len() as u32), n);
+	}
 }
 
 #[cfg(test)]
@@ -54,6 +54,10 @@ runtime_benchmarks! {
 	claim_default_account {
 		let caller = whitelisted_caller();
 	}: _(RawOrigin::Signed(caller))
+
+	set_nonce_lanes {
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), 4)
 }
 
 #[cfg(test)]
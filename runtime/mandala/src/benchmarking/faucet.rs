@@ -0,0 +1,71 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, Faucet, FaucetDripBasket, Runtime, RuntimeEvent, System};
+
+use super::utils::set_balance;
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_faucet }
+
+	request_tokens {
+		let caller: AccountId = whitelisted_caller();
+		let basket = FaucetDripBasket::get();
+		for (currency_id, amount) in basket.iter() {
+			set_balance(*currency_id, &Faucet::account_id(), 10 * amount);
+		}
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_last_event(module_faucet::Event::TokensDripped{ who: caller, basket }.into());
+	}
+
+	blocklist_account {
+		let who: AccountId = account("who", 0, SEED);
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert_last_event(module_faucet::Event::AccountBlocklisted{ who }.into());
+	}
+
+	unblocklist_account {
+		let who: AccountId = account("who", 0, SEED);
+		Faucet::blocklist_account(RawOrigin::Root.into(), who.clone())?;
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert_last_event(module_faucet::Event::AccountUnblocklisted{ who }.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
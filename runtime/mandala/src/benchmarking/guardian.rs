@@ -0,0 +1,107 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, Guardian, GuardianMinDelay, Hash, Runtime, RuntimeCall, System};
+
+use super::utils::{dollar, lookup_of_account, STABLECOIN};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::traits::Hash as HashT;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn guardians() -> Vec<AccountId> {
+	let max_guardians: u32 = <Runtime as module_guardian::Config>::MaxGuardians::get();
+	(0..max_guardians).map(|i| account("guardian", i, SEED)).collect()
+}
+
+fn set_guardian(who: &AccountId) -> Result<(), &'static str> {
+	Guardian::set_guardian(
+		RawOrigin::Signed(who.clone()).into(),
+		guardians(),
+		false,
+		1_000 * dollar(STABLECOIN),
+		GuardianMinDelay::get(),
+	)?;
+	Ok(())
+}
+
+fn announce_others(who: &AccountId) -> Result<(), &'static str> {
+	let max_pending_actions: u32 = <Runtime as module_guardian::Config>::MaxPendingActions::get();
+	for i in 1..max_pending_actions {
+		Guardian::announce(RawOrigin::Signed(who.clone()).into(), Hash::repeat_byte(i as u8))?;
+	}
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_guardian }
+
+	set_guardian {
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), guardians(), true, 1_000 * dollar(STABLECOIN), GuardianMinDelay::get())
+
+	remove_guardian {
+		let caller: AccountId = whitelisted_caller();
+		set_guardian(&caller)?;
+		module_guardian::ExecutingFor::<Runtime>::put(&caller);
+	}: _(RawOrigin::Signed(caller))
+
+	announce {
+		let caller: AccountId = whitelisted_caller();
+		set_guardian(&caller)?;
+		announce_others(&caller)?;
+	}: _(RawOrigin::Signed(caller), Hash::zero())
+
+	approve_pending {
+		let caller: AccountId = whitelisted_caller();
+		let guardian = guardians().pop().unwrap();
+		set_guardian(&caller)?;
+		announce_others(&caller)?;
+		Guardian::announce(RawOrigin::Signed(caller.clone()).into(), Hash::zero())?;
+	}: _(RawOrigin::Signed(guardian), lookup_of_account(caller), Hash::zero())
+
+	veto_pending {
+		let caller: AccountId = whitelisted_caller();
+		let guardian = guardians().pop().unwrap();
+		set_guardian(&caller)?;
+		announce_others(&caller)?;
+		Guardian::announce(RawOrigin::Signed(caller.clone()).into(), Hash::zero())?;
+	}: _(RawOrigin::Signed(guardian), lookup_of_account(caller), Hash::zero())
+
+	execute_pending {
+		let caller: AccountId = whitelisted_caller();
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		set_guardian(&caller)?;
+		announce_others(&caller)?;
+		Guardian::announce(RawOrigin::Signed(caller.clone()).into(), <Runtime as frame_system::Config>::Hashing::hash_of(&call))?;
+		System::set_block_number(System::block_number() + GuardianMinDelay::get());
+	}: _(RawOrigin::Signed(caller), Box::new(call))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
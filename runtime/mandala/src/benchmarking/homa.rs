@@ -17,22 +17,59 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	AccountId, ActiveSubAccountsIndexList, Balance, Currencies, Homa, Rate, RedeemThreshold, RelaychainDataProvider,
-	Runtime,
+	AccountId, ActiveSubAccountsIndexList, Balance, Currencies, ExchangeRate, Homa, Rate, Ratio, RedeemThreshold,
+	RelaychainDataProvider, Runtime,
 };
 
 use super::utils::{set_balance, LIQUID, STAKING};
 use frame_benchmarking::{account, whitelisted_caller};
-use frame_support::traits::{ExistenceRequirement, OnInitialize};
+use frame_support::{
+	storage::bounded_btree_set::BoundedBTreeSet,
+	traits::{ExistenceRequirement, Get, OnInitialize},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use module_homa::UnlockChunk;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
+use primitives::EraIndex;
 use sp_runtime::{traits::BlockNumberProvider, FixedPointNumber};
-use sp_std::prelude::*;
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
 
 const SEED: u32 = 0;
 
+// Fill the buckets of `tips` and `eras` with redeemers up to one below the bound, and the tips and
+// eras of buckets up to one below the bound, the worst case to update the priority of a request.
+fn fill_redeem_request_buckets(tips: Vec<Balance>, eras: Vec<EraIndex>) {
+	let max_buckets = <Runtime as module_homa::Config>::MaxRedeemRequestBuckets::get();
+	let max_requests = <Runtime as module_homa::Config>::MaxRedeemRequestsPerBucket::get();
+	let redeemers: BoundedVec<AccountId, _> = BoundedVec::truncate_from(
+		(1..max_requests)
+			.map(|i| account("redeemer", i, SEED))
+			.collect::<Vec<_>>(),
+	);
+
+	let mut tip_buckets: BTreeSet<Balance> = tips.iter().copied().collect();
+	let mut era_buckets: BTreeSet<EraIndex> = eras.iter().copied().collect();
+	for i in 1..max_buckets {
+		if tip_buckets.len() + 1 < max_buckets as usize {
+			tip_buckets.insert(i.into());
+		}
+		if era_buckets.len() + 1 < max_buckets as usize {
+			era_buckets.insert(i + 10);
+		}
+	}
+
+	for tip in tips {
+		module_homa::RedeemRequestsByTip::<Runtime>::insert(tip, redeemers.clone());
+	}
+	for era in eras {
+		module_homa::RedeemRequestExpiries::<Runtime>::insert(era, redeemers.clone());
+	}
+	module_homa::RedeemRequestTipBuckets::<Runtime>::put(BoundedBTreeSet::try_from(tip_buckets).unwrap());
+	module_homa::RedeemRequestExpiryEras::<Runtime>::put(BoundedBTreeSet::try_from(era_buckets).unwrap());
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_homa }
 
@@ -68,6 +105,13 @@ runtime_benchmarks! {
 			None,
 			None,
 		)?;
+		// need to process the nomination pool
+		Homa::update_nomination_pool_params(
+			RawOrigin::Root.into(),
+			Some(Some(1)),
+			Some(Ratio::saturating_from_rational(10, 100)),
+			None,
+		)?;
 		RelaychainDataProvider::<Runtime>::set_block_number(10);
 		Homa::update_bump_era_params(RawOrigin::Root.into(), None, Some(1))?;
 
@@ -98,12 +142,29 @@ runtime_benchmarks! {
 		set_balance(STAKING, &caller, amount * 2);
 	}: _(RawOrigin::Signed(caller), amount)
 
+	mint_no_op {
+		let caller: AccountId = whitelisted_caller();
+	}: mint(RawOrigin::Signed(caller), 0)
+
 	request_redeem {
 		let caller: AccountId = whitelisted_caller();
 		let amount = 10_000_000_000_000;
 
-		set_balance(LIQUID, &caller, amount * 2);
-	}: _(RawOrigin::Signed(caller), amount, true)
+		set_balance(LIQUID, &caller, amount * 3);
+		// overwrite the request at the end of full buckets
+		fill_redeem_request_buckets(vec![amount / 10], vec![1]);
+		Homa::request_redeem_with_priority(RawOrigin::Signed(caller.clone()).into(), amount, true, amount / 10, Some(1))?;
+	}: _(RawOrigin::Signed(caller), amount * 2, true)
+
+	request_redeem_with_priority {
+		let caller: AccountId = whitelisted_caller();
+		let amount = 10_000_000_000_000;
+
+		set_balance(LIQUID, &caller, amount * 3);
+		// move the request at the end of full buckets to the end of other full buckets
+		fill_redeem_request_buckets(vec![amount / 10, amount / 5], vec![1, 2]);
+		Homa::request_redeem_with_priority(RawOrigin::Signed(caller.clone()).into(), amount, true, amount / 10, Some(1))?;
+	}: _(RawOrigin::Signed(caller), amount * 2, true, amount / 5, Some(2))
 
 	fast_match_redeems {
 		let n in 1 .. 50;
@@ -165,6 +226,20 @@ runtime_benchmarks! {
 	}: _(RawOrigin::Root, updates)
 
 	reset_current_era {}: _(RawOrigin::Root, 1)
+
+	update_nomination_pool_params {}: _(
+		RawOrigin::Root,
+		Some(Some(1)),
+		Some(Ratio::saturating_from_rational(10, 100)),
+		Some(Ratio::saturating_from_rational(5, 100))
+	)
+
+	reset_nomination_pool_ledger {}: _(
+		RawOrigin::Root,
+		Some(1_000_000_000_000),
+		Some(ExchangeRate::saturating_from_rational(11, 10)),
+		Some(vec![UnlockChunk{value: 1, era: 1}])
+	)
 }
 
 #[cfg(test)]
@@ -17,8 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	AccountId, Amount, CdpEngine, CurrencyId, DepositPerAuthorization, ExistentialDeposits, Honzon,
-	NativeTokenExistentialDeposit, Price, Rate, Ratio, Runtime,
+	AccountId, Amount, Balance, CdpEngine, CurrencyId, DepositPerAuthorization, ExistentialDeposits, Honzon,
+	NativeTokenExistentialDeposit, Price, Rate, Ratio, Runtime, System, TopUpBounty,
 };
 
 use super::{
@@ -28,18 +28,59 @@ use super::{
 	},
 };
 use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
-use module_support::HonzonManager;
+use module_support::{HonzonManager, PriceProvider};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{Change, GetByKey};
 use sp_runtime::{
-	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto},
-	FixedPointNumber,
+	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto, Zero},
+	DispatchError, FixedPointNumber,
 };
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+// open the CDP of `who` which can be split into `count` equal parts, returns the parts
+fn open_loan_of_parts(
+	who: &AccountId,
+	currency_id: CurrencyId,
+	count: u32,
+) -> Result<Vec<(Balance, Balance)>, DispatchError> {
+	let debit_value = 100 * dollar(STABLECOIN);
+	let debit_amount = CdpEngine::try_convert_to_debit_balance(currency_id, debit_value).unwrap();
+	let collateral_value = 10 * debit_value;
+	let collateral_amount =
+		Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+	let count_balance: Balance = count.into();
+
+	// set balance
+	set_balance(currency_id, who, collateral_amount * count_balance * 2);
+
+	// feed price
+	feed_price(vec![(currency_id, Price::one())])?;
+
+	// set risk params
+	CdpEngine::set_collateral_params(
+		RawOrigin::Root.into(),
+		currency_id,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(debit_value * count_balance * 100),
+	)?;
+
+	CdpEngine::adjust_position(
+		who,
+		currency_id,
+		(collateral_amount * count_balance).unique_saturated_into(),
+		(debit_amount * count_balance).unique_saturated_into(),
+	)?;
+
+	Ok(vec![(collateral_amount, debit_amount); count as usize])
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_honzon }
 
@@ -116,6 +157,11 @@ runtime_benchmarks! {
 		)?;
 	}: _(RawOrigin::Signed(caller), currency_id, collateral_amount.try_into().unwrap(), debit_amount)
 
+	adjust_loan_no_op {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
+	}: adjust_loan(RawOrigin::Signed(caller), currency_id, 0, 0)
+
 	transfer_loan_from {
 		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
 		let sender: AccountId = account("sender", 0, SEED);
@@ -341,6 +387,215 @@ runtime_benchmarks! {
 	}: {
 		Honzon::get_current_collateral_ratio(&sender, LIQUID);
 	}
+
+	register_topup_voucher {
+		let caller: AccountId = whitelisted_caller();
+
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(10_000 * dollar(STABLECOIN)),
+		)?;
+	}: _(RawOrigin::Signed(caller), STAKING, dollar(STAKING), Ratio::saturating_from_rational(200, 100), 100)
+
+	cancel_topup_voucher {
+		let caller: AccountId = whitelisted_caller();
+
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(10_000 * dollar(STABLECOIN)),
+		)?;
+		Honzon::register_topup_voucher(
+			RawOrigin::Signed(caller.clone()).into(),
+			STAKING,
+			dollar(STAKING),
+			Ratio::saturating_from_rational(200, 100),
+			100,
+		)?;
+	}: _(RawOrigin::Signed(caller), STAKING)
+
+	set_position_alert {
+		let caller: AccountId = whitelisted_caller();
+
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(10_000 * dollar(STABLECOIN)),
+		)?;
+	}: _(RawOrigin::Signed(caller), STAKING, Ratio::saturating_from_rational(200, 100))
+
+	clear_position_alert {
+		let caller: AccountId = whitelisted_caller();
+
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(10_000 * dollar(STABLECOIN)),
+		)?;
+		Honzon::set_position_alert(
+			RawOrigin::Signed(caller.clone()).into(),
+			STAKING,
+			Ratio::saturating_from_rational(200, 100),
+		)?;
+	}: _(RawOrigin::Signed(caller), STAKING, Ratio::saturating_from_rational(200, 100))
+
+	execute_topup_voucher {
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let caller: AccountId = whitelisted_caller();
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 2 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// set balance
+		set_balance(STAKING, &owner, collateral_amount * 2);
+		set_balance(NATIVE, &owner, TopUpBounty::get() + NativeTokenExistentialDeposit::get());
+
+		feed_price(vec![(STAKING, Price::one())])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100),
+		)?;
+
+		// initialize owner's loan at the collateral ratio of 200%
+		Honzon::adjust_loan(
+			RawOrigin::Signed(owner.clone()).into(),
+			STAKING,
+			collateral_amount.try_into().unwrap(),
+			debit_amount,
+		)?;
+
+		// triggered below 300%
+		Honzon::register_topup_voucher(
+			RawOrigin::Signed(owner).into(),
+			STAKING,
+			collateral_amount,
+			Ratio::saturating_from_rational(300, 100),
+			100,
+		)?;
+	}: _(RawOrigin::Signed(caller), owner_lookup, STAKING)
+
+	remove_expired_topup_voucher {
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let caller: AccountId = whitelisted_caller();
+
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(10_000 * dollar(STABLECOIN)),
+		)?;
+		Honzon::register_topup_voucher(
+			RawOrigin::Signed(owner).into(),
+			STAKING,
+			dollar(STAKING),
+			Ratio::saturating_from_rational(200, 100),
+			100,
+		)?;
+		System::set_block_number(101);
+	}: _(RawOrigin::Signed(caller), owner_lookup, STAKING)
+
+	split_loan {
+		let p in 2 .. <Runtime as module_loans::Config>::MaxPositionsPerAccount::get();
+
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
+		let parts = open_loan_of_parts(&caller, currency_id, p)?;
+	}: _(RawOrigin::Signed(caller), currency_id, parts.try_into().unwrap())
+
+	merge_loans {
+		let p in 1 .. <Runtime as module_loans::Config>::MaxPositionsPerAccount::get() - 1;
+
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
+		let parts = open_loan_of_parts(&caller, currency_id, p + 1)?;
+		Honzon::split_loan(RawOrigin::Signed(caller.clone()).into(), currency_id, parts.try_into().unwrap())?;
+	}: _(RawOrigin::Signed(caller), currency_id)
+	verify {
+		assert_eq!(module_loans::Pallet::<Runtime>::positions_of(currency_id, &whitelisted_caller()).len(), 1);
+	}
+
+	shift_loan_collateral {
+		let from_currency_id: CurrencyId = STAKING;
+		let to_currency_id: CurrencyId = LIQUID;
+		let sender: AccountId = whitelisted_caller();
+		let maker: AccountId = account("maker", 0, SEED);
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(from_currency_id);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let collateral_value = 10 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(from_currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		set_balance(from_currency_id, &sender, collateral_amount + ExistentialDeposits::get(&from_currency_id));
+		feed_price(vec![(STAKING, Price::one())])?;
+
+		// inject liquidity at the oracle price for the swap path
+		let liquid_price = <Runtime as module_cdp_engine::Config>::PriceSource::get_relative_price(LIQUID, STAKING).unwrap();
+		inject_liquidity(
+			maker,
+			STAKING,
+			LIQUID,
+			100_000 * dollar(STAKING),
+			liquid_price.reciprocal().unwrap().saturating_mul_int(100_000 * dollar(LIQUID)),
+			false,
+		)?;
+
+		// set risk params
+		for currency_id in [from_currency_id, to_currency_id] {
+			CdpEngine::set_collateral_params(
+				RawOrigin::Root.into(),
+				currency_id,
+				Change::NoChange,
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(debit_value * 100),
+			)?;
+		}
+
+		// initialize sender's loan
+		Honzon::adjust_loan(
+			RawOrigin::Signed(sender.clone()).into(),
+			from_currency_id,
+			collateral_amount.try_into().unwrap(),
+			debit_amount.try_into().unwrap(),
+		)?;
+	}: _(RawOrigin::Signed(sender), from_currency_id, to_currency_id, 0, collateral_amount)
+	verify {
+		assert_eq!(module_loans::Pallet::<Runtime>::positions(from_currency_id, &whitelisted_caller()).debit, 0);
+		assert!(!module_loans::Pallet::<Runtime>::positions(to_currency_id, &whitelisted_caller()).debit.is_zero());
+	}
 }
 
 #[cfg(test)]
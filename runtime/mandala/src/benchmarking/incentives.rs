@@ -16,20 +16,26 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, AccumulatePeriod, Currencies, CurrencyId, Incentives, Rate, Rewards, Runtime, System};
+use crate::{
+	AccountId, AccumulatePeriod, Currencies, CurrencyId, Incentives, IncentivesRetirementGracePeriod, Price, Rate,
+	Ratio, Rewards, Runtime, System,
+};
 
 use super::{
 	get_benchmarking_collateral_currency_ids,
-	utils::{dollar, set_balance, NATIVE, STABLECOIN, STAKING},
+	utils::{dollar, feed_price, inject_liquidity, set_balance, NATIVE, STABLECOIN, STAKING},
 };
-use frame_benchmarking::whitelisted_caller;
+use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::{assert_ok, traits::OnInitialize};
 use frame_system::RawOrigin;
 use module_support::PoolId;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
+use sp_runtime::{FixedPointNumber, Permill};
 use sp_std::prelude::*;
 
+const SEED: u32 = 0;
+
 runtime_benchmarks! {
 	{ Runtime, module_incentives }
 
@@ -54,15 +60,22 @@ runtime_benchmarks! {
 		Incentives::on_initialize(System::block_number());
 	}
 
+	// worst case: the deposit has to reach the minimum age
 	deposit_dex_share {
 		let caller: AccountId = whitelisted_caller();
 		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		Incentives::update_deposit_protection(RawOrigin::Root.into(), PoolId::Dex(native_stablecoin_lp), AccumulatePeriod::get(), true)?;
 		set_balance(native_stablecoin_lp, &caller, 10_000 * dollar(STABLECOIN));
 	}: _(RawOrigin::Signed(caller), native_stablecoin_lp, 10_000 * dollar(STABLECOIN))
 
+	// worst case: the instant exit fee is charged
 	withdraw_dex_share {
 		let caller: AccountId = whitelisted_caller();
+		let depositor: AccountId = account("depositor", 0, SEED);
 		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		Incentives::update_withdrawal_delay(RawOrigin::Root.into(), PoolId::Dex(native_stablecoin_lp), AccumulatePeriod::get(), Permill::from_percent(1))?;
+		set_balance(native_stablecoin_lp, &depositor, 10_000 * dollar(STABLECOIN));
+		Incentives::deposit_dex_share(RawOrigin::Signed(depositor).into(), native_stablecoin_lp, 10_000 * dollar(STABLECOIN))?;
 		set_balance(native_stablecoin_lp, &caller, 10_000 * dollar(STABLECOIN));
 		Incentives::deposit_dex_share(
 			RawOrigin::Signed(caller.clone()).into(),
@@ -71,6 +84,16 @@ runtime_benchmarks! {
 		)?;
 	}: _(RawOrigin::Signed(caller), native_stablecoin_lp, 8000 * dollar(STABLECOIN))
 
+	deposit_dex_share_no_op {
+		let caller: AccountId = whitelisted_caller();
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+	}: deposit_dex_share(RawOrigin::Signed(caller), native_stablecoin_lp, 0)
+
+	withdraw_dex_share_no_op {
+		let caller: AccountId = whitelisted_caller();
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+	}: withdraw_dex_share(RawOrigin::Signed(caller), native_stablecoin_lp, 0)
+
 	claim_rewards {
 		let caller: AccountId = whitelisted_caller();
 		let pool_id = PoolId::Loans(STAKING);
@@ -80,6 +103,34 @@ runtime_benchmarks! {
 		Rewards::accumulate_reward(&pool_id, NATIVE, 80 * dollar(NATIVE))?;
 	}: _(RawOrigin::Signed(caller), pool_id)
 
+	set_claim_preference {
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), PoolId::Loans(STAKING), Some(STABLECOIN), Ratio::saturating_from_rational(5, 100))
+
+	// worst case: the native rewards are swapped into the stable currency
+	claim_rewards_with_conversion {
+		let caller: AccountId = whitelisted_caller();
+		let maker: AccountId = account("maker", 0, SEED);
+		let pool_id = PoolId::Loans(STAKING);
+
+		inject_liquidity(maker, NATIVE, STABLECOIN, 10_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN), false)?;
+		feed_price(vec![(NATIVE, Price::saturating_from_integer(1))])?;
+		Incentives::set_claim_preference(
+			RawOrigin::Signed(caller.clone()).into(),
+			pool_id,
+			Some(STABLECOIN),
+			Ratio::saturating_from_rational(10, 100),
+		)?;
+		assert_ok!(Rewards::add_share(&caller, &pool_id, dollar(NATIVE)));
+		Currencies::deposit(NATIVE, &Incentives::account_id(), 80 * dollar(NATIVE))?;
+		Rewards::accumulate_reward(&pool_id, NATIVE, 80 * dollar(NATIVE))?;
+	}: claim_rewards(RawOrigin::Signed(caller), pool_id)
+
+	claim_rewards_no_op {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::Loans(STAKING);
+	}: claim_rewards(RawOrigin::Signed(caller), pool_id)
+
 	update_incentive_rewards {
 		let c in 0 .. get_benchmarking_collateral_currency_ids().len() as u32;
 		let currency_ids = get_benchmarking_collateral_currency_ids();
@@ -104,6 +155,91 @@ runtime_benchmarks! {
 
 	update_claim_reward_deduction_currency {
 	}: _(RawOrigin::Root, PoolId::Earning(NATIVE), Some(NATIVE))
+
+	update_claim_reward_vesting {
+	}: _(RawOrigin::Root, PoolId::Earning(NATIVE), Rate::saturating_from_rational(1, 2), 100)
+
+	// worst case: vesting rewards of both the native currency and the stable currency
+	claim_vested_rewards {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::Loans(STAKING);
+
+		Incentives::update_claim_reward_vesting(RawOrigin::Root.into(), pool_id, Rate::saturating_from_rational(1, 2), 100)?;
+		assert_ok!(Rewards::add_share(&caller, &pool_id, dollar(NATIVE)));
+		for currency_id in [NATIVE, STABLECOIN] {
+			Currencies::deposit(currency_id, &Incentives::account_id(), 80 * dollar(currency_id))?;
+			Rewards::accumulate_reward(&pool_id, currency_id, 80 * dollar(currency_id))?;
+		}
+		Incentives::claim_rewards(RawOrigin::Signed(caller.clone()).into(), pool_id)?;
+		System::set_block_number(System::block_number() + 50);
+	}: _(RawOrigin::Signed(caller), pool_id)
+
+	update_deposit_protection {
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+	}: _(RawOrigin::Root, PoolId::Dex(native_stablecoin_lp), AccumulatePeriod::get(), true)
+
+	update_withdrawal_delay {
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+	}: _(RawOrigin::Root, PoolId::Dex(native_stablecoin_lp), AccumulatePeriod::get(), Permill::from_percent(1))
+
+	request_withdraw_dex_share {
+		let caller: AccountId = whitelisted_caller();
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		Incentives::update_withdrawal_delay(RawOrigin::Root.into(), PoolId::Dex(native_stablecoin_lp), AccumulatePeriod::get(), Permill::from_percent(1))?;
+		set_balance(native_stablecoin_lp, &caller, 10_000 * dollar(STABLECOIN));
+		Incentives::deposit_dex_share(RawOrigin::Signed(caller.clone()).into(), native_stablecoin_lp, 10_000 * dollar(STABLECOIN))?;
+		Incentives::request_withdraw_dex_share(RawOrigin::Signed(caller.clone()).into(), native_stablecoin_lp, 1000 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Signed(caller), native_stablecoin_lp, 8000 * dollar(STABLECOIN))
+
+	execute_withdraw_dex_share {
+		let caller: AccountId = whitelisted_caller();
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		Incentives::update_withdrawal_delay(RawOrigin::Root.into(), PoolId::Dex(native_stablecoin_lp), AccumulatePeriod::get(), Permill::from_percent(1))?;
+		set_balance(native_stablecoin_lp, &caller, 10_000 * dollar(STABLECOIN));
+		Incentives::deposit_dex_share(RawOrigin::Signed(caller.clone()).into(), native_stablecoin_lp, 10_000 * dollar(STABLECOIN))?;
+		Incentives::request_withdraw_dex_share(RawOrigin::Signed(caller.clone()).into(), native_stablecoin_lp, 8000 * dollar(STABLECOIN))?;
+		System::set_block_number(System::block_number() + AccumulatePeriod::get());
+	}: _(RawOrigin::Signed(caller), native_stablecoin_lp)
+
+	mature_deposits {
+		let c in 0 .. 100;
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		let block_number = AccumulatePeriod::get();
+
+		Incentives::update_deposit_protection(RawOrigin::Root.into(), PoolId::Dex(native_stablecoin_lp), 1, false)?;
+		for i in 0 .. c {
+			let who: AccountId = account("depositor", i, SEED);
+			set_balance(native_stablecoin_lp, &who, 100 * dollar(STABLECOIN));
+			Incentives::deposit_dex_share(RawOrigin::Signed(who).into(), native_stablecoin_lp, 100 * dollar(STABLECOIN))?;
+		}
+		System::set_block_number(block_number);
+	}: {
+		Incentives::on_initialize(System::block_number());
+	}
+
+	retire_pool {
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		let pool_id = PoolId::Dex(native_stablecoin_lp);
+		Incentives::update_incentive_rewards(RawOrigin::Root.into(), vec![(pool_id, vec![(NATIVE, 100 * dollar(NATIVE))])])?;
+	}: _(RawOrigin::Root, pool_id)
+
+	clean_retired_pool {
+		let c in 0 .. 50;
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		let pool_id = PoolId::Dex(native_stablecoin_lp);
+
+		for i in 0 .. c {
+			let who: AccountId = account("depositor", i, SEED);
+			set_balance(native_stablecoin_lp, &who, 100 * dollar(STABLECOIN));
+			Incentives::deposit_dex_share(RawOrigin::Signed(who).into(), native_stablecoin_lp, 100 * dollar(STABLECOIN))?;
+		}
+		Currencies::deposit(NATIVE, &Incentives::account_id(), 80 * dollar(NATIVE))?;
+		Rewards::accumulate_reward(&pool_id, NATIVE, 80 * dollar(NATIVE))?;
+		Incentives::retire_pool(RawOrigin::Root.into(), pool_id)?;
+		System::set_block_number(System::block_number() + IncentivesRetirementGracePeriod::get() + 1);
+	}: {
+		Incentives::on_initialize(System::block_number());
+	}
 }
 
 #[cfg(test)]
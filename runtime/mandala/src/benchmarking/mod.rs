@@ -26,6 +26,7 @@ use sp_std::prelude::*;
 pub mod utils;
 
 // module benchmarking
+pub mod account_rescue;
 pub mod aggregated_dex;
 pub mod asset_registry;
 pub mod auction_manager;
@@ -39,6 +40,8 @@ pub mod earning;
 pub mod emergency_shutdown;
 pub mod evm;
 pub mod evm_accounts;
+pub mod faucet;
+pub mod guardian;
 pub mod homa;
 pub mod homa_validator_list;
 pub mod honzon;
@@ -47,10 +50,20 @@ pub mod incentives;
 pub mod liquid_crowdloan;
 pub mod nominees_election;
 pub mod nutsfinance_stable_asset;
+pub mod oracle_operator;
+pub mod otc;
+pub mod peg_monitor;
+pub mod preferences;
 pub mod prices;
+pub mod reference_rate;
+pub mod safe_mode;
+pub mod scheduler_index;
+pub mod session_keys;
 pub mod session_manager;
+pub mod statistics;
 pub mod transaction_pause;
 pub mod transaction_payment;
+pub mod vesting_consolidation;
 
 // orml benchmarking
 pub mod auction;
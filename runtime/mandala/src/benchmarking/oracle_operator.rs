@@ -0,0 +1,142 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, OperatorMembershipAcala, OracleOperator, Price, Runtime, RuntimeOrigin, System};
+
+use super::utils::{set_balance, NATIVE, STAKING};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::{traits::One, MultiAddress};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn bonded_operator(who: &AccountId) -> Result<(), sp_runtime::DispatchError> {
+	let bond = <Runtime as module_oracle_operator::Config>::MinimumBond::get();
+	set_balance(NATIVE, who, bond * 2);
+	OperatorMembershipAcala::add_member(RawOrigin::Root.into(), MultiAddress::Id(who.clone()))
+		.map_or_else(|e| Err(e.error), |_| Ok(()))?;
+	OracleOperator::bond(RuntimeOrigin::signed(who.clone()), bond)?;
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_oracle_operator }
+
+	bond {
+		let caller: AccountId = whitelisted_caller();
+		let bond = <Runtime as module_oracle_operator::Config>::MinimumBond::get();
+		set_balance(NATIVE, &caller, bond * 2);
+	}: _(RawOrigin::Signed(caller), bond)
+
+	unbond {
+		let caller: AccountId = whitelisted_caller();
+		bonded_operator(&caller)?;
+		let bond = <Runtime as module_oracle_operator::Config>::MinimumBond::get();
+	}: _(RawOrigin::Signed(caller), bond)
+
+	withdraw_unbonded {
+		let caller: AccountId = whitelisted_caller();
+		bonded_operator(&caller)?;
+		let bond = <Runtime as module_oracle_operator::Config>::MinimumBond::get();
+		OracleOperator::unbond(RuntimeOrigin::signed(caller.clone()), bond)?;
+		System::set_block_number(
+			System::block_number() + <Runtime as module_oracle_operator::Config>::UnbondingPeriod::get()
+		);
+	}: _(RawOrigin::Signed(caller))
+
+	slash {
+		let operator: AccountId = account("operator", 0, SEED);
+		bonded_operator(&operator)?;
+		let bond = <Runtime as module_oracle_operator::Config>::MinimumBond::get();
+		OracleOperator::unbond(RuntimeOrigin::signed(operator.clone()), bond / 2)?;
+	}: _(RawOrigin::Root, operator, bond)
+
+	feed_values {
+		let c in 1 .. <Runtime as module_oracle_operator::Config>::MaxFeedValues::get();
+
+		let caller: AccountId = whitelisted_caller();
+		bonded_operator(&caller)?;
+		let values = vec![(STAKING, Price::one()); c as usize];
+	}: _(RawOrigin::Signed(caller), values.try_into().unwrap())
+
+	// the raw values of the removed operators are purged
+	rotate_operators {
+		let r in 0 .. 10;
+		let a in 0 .. 10;
+
+		let max_feed_values = <Runtime as module_oracle_operator::Config>::MaxFeedValues::get();
+		let mut remove = vec![];
+		for i in 0 .. r {
+			let operator: AccountId = account("operator", i, SEED);
+			bonded_operator(&operator)?;
+			let values = vec![(STAKING, Price::one()); max_feed_values as usize];
+			OracleOperator::feed_values(RuntimeOrigin::signed(operator.clone()), values.try_into().unwrap())?;
+			remove.push(operator);
+		}
+		let add: Vec<AccountId> = (0 .. a).map(|i| account("new_operator", i, SEED)).collect();
+	}: _(RawOrigin::Root, remove, add)
+
+	register_backup_feeder {
+		let caller: AccountId = whitelisted_caller();
+		bonded_operator(&caller)?;
+		let backup: AccountId = account("backup", 0, SEED);
+	}: _(RawOrigin::Signed(caller), backup)
+
+	// the failover is deactivated
+	revoke_backup_feeder {
+		let caller: AccountId = whitelisted_caller();
+		bonded_operator(&caller)?;
+		let backup: AccountId = account("backup", 0, SEED);
+		OracleOperator::register_backup_feeder(RuntimeOrigin::signed(caller.clone()), backup.clone())?;
+		OracleOperator::feed_values_as_backup(
+			RuntimeOrigin::signed(backup),
+			caller.clone(),
+			vec![(STAKING, Price::one())].try_into().unwrap(),
+		)?;
+	}: _(RawOrigin::Signed(caller))
+
+	// the primary key is silent and the failover is activated
+	feed_values_as_backup {
+		let c in 1 .. <Runtime as module_oracle_operator::Config>::MaxFeedValues::get();
+
+		let operator: AccountId = account("operator", 0, SEED);
+		bonded_operator(&operator)?;
+		let caller: AccountId = whitelisted_caller();
+		OracleOperator::register_backup_feeder(RuntimeOrigin::signed(operator.clone()), caller.clone())?;
+		OracleOperator::feed_values(
+			RuntimeOrigin::signed(operator.clone()),
+			vec![(STAKING, Price::one())].try_into().unwrap(),
+		)?;
+		System::set_block_number(
+			System::block_number() + <Runtime as module_oracle_operator::Config>::FailoverThreshold::get() + 1
+		);
+		let values = vec![(STAKING, Price::one()); c as usize];
+	}: _(RawOrigin::Signed(caller), operator, values.try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,117 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, BlockNumber, Otc, Runtime, RuntimeEvent, RuntimeOrigin, System};
+
+use super::utils::{dollar, lookup_of_account, set_balance, NATIVE, STABLECOIN, STAKING};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::{Get, OnInitialize};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+
+const SEED: u32 = 0;
+const EXPIRY: BlockNumber = 100;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+fn create_offer(maker: AccountId, taker: AccountId) -> module_otc::OfferId {
+	let offer_id = Otc::next_offer_id();
+	set_balance(STAKING, &maker, 100 * dollar(STAKING));
+	Otc::create_offer(
+		RuntimeOrigin::signed(maker),
+		lookup_of_account(taker),
+		STAKING,
+		100 * dollar(STAKING),
+		STABLECOIN,
+		1_000 * dollar(STABLECOIN),
+		EXPIRY,
+	)
+	.unwrap();
+	offer_id
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_otc }
+
+	create_offer {
+		let maker: AccountId = whitelisted_caller();
+		let taker: AccountId = account("taker", 0, SEED);
+		set_balance(STAKING, &maker, 100 * dollar(STAKING));
+	}: _(RawOrigin::Signed(maker.clone()), lookup_of_account(taker.clone()), STAKING, 100 * dollar(STAKING), STABLECOIN, 1_000 * dollar(STABLECOIN), EXPIRY)
+	verify {
+		assert_last_event(module_otc::Event::OfferCreated {
+			offer_id: 0,
+			maker,
+			taker,
+			supply_currency_id: STAKING,
+			supply_amount: 100 * dollar(STAKING),
+			target_currency_id: STABLECOIN,
+			target_amount: 1_000 * dollar(STABLECOIN),
+			expiry: EXPIRY,
+		}.into());
+	}
+
+	accept_offer {
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		let offer_id = create_offer(maker.clone(), taker.clone());
+		set_balance(STABLECOIN, &taker, 1_000 * dollar(STABLECOIN));
+		set_balance(NATIVE, &taker, 10 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(taker.clone()), offer_id)
+	verify {
+		assert_last_event(module_otc::Event::OfferAccepted {
+			offer_id,
+			maker,
+			taker,
+			fee: crate::OtcSettlementFee::get(),
+		}.into());
+	}
+
+	cancel_offer {
+		let maker: AccountId = whitelisted_caller();
+		let taker: AccountId = account("taker", 0, SEED);
+		let offer_id = create_offer(maker.clone(), taker);
+	}: _(RawOrigin::Signed(maker.clone()), offer_id)
+	verify {
+		assert_last_event(module_otc::Event::OfferCancelled { offer_id, who: maker }.into());
+	}
+
+	on_initialize {
+		let n in 0 .. 100;
+		let taker: AccountId = account("taker", 0, SEED);
+		for i in 0 .. n {
+			create_offer(account("maker", i, SEED), taker.clone());
+		}
+	}: {
+		Otc::on_initialize(EXPIRY);
+	}
+	verify {
+		assert_eq!(module_otc::Offers::<Runtime>::iter().count(), 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
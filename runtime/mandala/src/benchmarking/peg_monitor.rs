@@ -0,0 +1,84 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, PegMonitor, PegMonitorPeriod, Price, Runtime, RuntimeEvent, System};
+
+use super::utils::{dollar, feed_price, inject_liquidity, STABLECOIN, STAKING};
+use frame_benchmarking::account;
+use frame_support::traits::{Get, OnInitialize};
+use frame_system::RawOrigin;
+use module_peg_monitor::{PegSource, WeightedPegSource};
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::FixedPointNumber;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+fn peg_sources(c: u32) -> Vec<WeightedPegSource> {
+	(0..c)
+		.map(|_| WeightedPegSource {
+			source: PegSource::DexPair(STAKING),
+			weight: 1,
+		})
+		.collect()
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_peg_monitor }
+
+	on_initialize {
+		let c in 0 .. <Runtime as module_peg_monitor::Config>::MaxSources::get();
+		let maker: AccountId = account("maker", 0, SEED);
+		let block_number = PegMonitorPeriod::get();
+
+		inject_liquidity(maker, STABLECOIN, STAKING, 1_000 * dollar(STABLECOIN), 100 * dollar(STAKING), false)?;
+		feed_price(vec![(STAKING, Price::saturating_from_integer(10))])?;
+		PegMonitor::set_peg_sources(RawOrigin::Root.into(), peg_sources(c))?;
+		PegMonitor::set_deviation_threshold(RawOrigin::Root.into(), 0)?;
+		System::set_block_number(block_number);
+	}: {
+		PegMonitor::on_initialize(System::block_number());
+	}
+
+	set_peg_sources {
+		let c in 0 .. <Runtime as module_peg_monitor::Config>::MaxSources::get();
+		let sources = peg_sources(c);
+	}: _(RawOrigin::Root, sources.clone())
+	verify {
+		assert_last_event(module_peg_monitor::Event::PegSourcesUpdated{ sources }.into());
+	}
+
+	set_deviation_threshold {
+	}: _(RawOrigin::Root, 50)
+	verify {
+		assert_last_event(module_peg_monitor::Event::DeviationThresholdUpdated{ threshold_bps: 50 }.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,59 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, Runtime, RuntimeEvent, System};
+
+use frame_benchmarking::whitelisted_caller;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use module_preferences::{PreferenceEntry, PreferencesBlob};
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_preferences }
+
+	// the weights of the setter calls are added to the weight of the import, the entries rejected
+	// right away by the setter calls measure the overhead of the import.
+	import_preferences {
+		let n in 1 .. <Runtime as module_preferences::Config>::MaxPreferenceEntries::get();
+
+		let caller: AccountId = whitelisted_caller();
+		let entries = vec![PreferenceEntry::DustConsolidation(caller.clone()); n as usize];
+	}: _(RawOrigin::Signed(caller.clone()), PreferencesBlob::V1(entries))
+	verify {
+		assert_last_event(module_preferences::Event::PreferencesImported {
+			who: caller,
+			applied: 0,
+			failed: n,
+		}.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -16,11 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Prices, Runtime, RuntimeOrigin};
+use crate::{CurrencyId, Price, Prices, Runtime, RuntimeOrigin, System};
 
-use super::utils::{dollar, feed_price, STAKING};
+use super::utils::{dollar, feed_price, LIQUID, STAKING};
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
+use module_prices::{LockedPrice, PricingMode, RealTimePriceProvider, TimedPriceLock, TimedPriceLocks};
+use module_support::PriceProvider;
 use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::Permill;
 use sp_std::vec;
 
 runtime_benchmarks! {
@@ -36,6 +40,29 @@ runtime_benchmarks! {
 		feed_price(vec![(STAKING, dollar(STAKING).into())])?;
 		Prices::lock_price(RuntimeOrigin::root(), STAKING)?;
 	}: _(RawOrigin::Root, STAKING)
+
+	set_pricing_mode {
+	}: _(RawOrigin::Root, LIQUID, Some(PricingMode::LiquidStakingFairValue { haircut: Permill::from_percent(5) }))
+
+	lock_price_at {
+		// feed price
+		feed_price(vec![(STAKING, dollar(STAKING).into())])?;
+		let price = RealTimePriceProvider::<Runtime>::get_price(STAKING).unwrap();
+		Prices::lock_price_at(RuntimeOrigin::root(), STAKING, price, 1, 0)?;
+	}: _(RawOrigin::Root, STAKING, price, 100, 1)
+
+	on_initialize {
+		let n in 0 .. 20;
+
+		for i in 0 .. n {
+			let currency_id = CurrencyId::ForeignAsset(i as u16);
+			LockedPrice::<Runtime>::insert(currency_id, Price::from_inner(1));
+			TimedPriceLocks::<Runtime>::insert(currency_id, TimedPriceLock { expire_at: 1, reason: 0 });
+		}
+		System::set_block_number(1);
+	}: {
+		Prices::on_initialize(1);
+	}
 }
 
 #[cfg(test)]
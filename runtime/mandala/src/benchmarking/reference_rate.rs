@@ -0,0 +1,79 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Price, ReferenceRate, ReferenceRatePeriod, Runtime, RuntimeEvent, System};
+
+use super::utils::{feed_price, STAKING};
+use frame_support::traits::{Get, OnInitialize};
+use frame_system::RawOrigin;
+use module_reference_rate::{ComponentSource, WeightedComponent};
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::FixedPointNumber;
+use sp_std::prelude::*;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+fn components(c: u32) -> Vec<WeightedComponent> {
+	(0..c)
+		.map(|_| WeightedComponent {
+			source: ComponentSource::OraclePrice(STAKING),
+			weight: 1,
+		})
+		.collect()
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_reference_rate }
+
+	on_initialize {
+		let c in 0 .. <Runtime as module_reference_rate::Config>::MaxComponents::get();
+		let block_number = ReferenceRatePeriod::get();
+
+		feed_price(vec![(STAKING, Price::saturating_from_integer(10))])?;
+		ReferenceRate::set_components(RawOrigin::Root.into(), components(c))?;
+		ReferenceRate::set_movement_threshold(RawOrigin::Root.into(), 0)?;
+		System::set_block_number(block_number);
+	}: {
+		ReferenceRate::on_initialize(System::block_number());
+	}
+
+	set_components {
+		let c in 0 .. <Runtime as module_reference_rate::Config>::MaxComponents::get();
+		let components = components(c);
+	}: _(RawOrigin::Root, components.clone())
+	verify {
+		assert_last_event(module_reference_rate::Event::ComponentsUpdated{ components }.into());
+	}
+
+	set_movement_threshold {
+	}: _(RawOrigin::Root, 50)
+	verify {
+		assert_last_event(module_reference_rate::Event::MovementThresholdUpdated{ threshold_bps: 50 }.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,146 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CurrencyId, Price, Runtime, RuntimeCall, RuntimeEvent, SafeMode, System};
+
+use super::utils::feed_price;
+use frame_support::traits::{Get, GetCallMetadata, OnInitialize};
+use frame_system::RawOrigin;
+use module_safe_mode::TriggerParams;
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::FixedPointNumber;
+use sp_std::prelude::*;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+fn collaterals(c: u32) -> Vec<CurrencyId> {
+	(0..c).map(|i| CurrencyId::ForeignAsset(i as u16)).collect()
+}
+
+fn safe_mode_calls(c: u32) -> Vec<(Vec<u8>, Vec<u8>)> {
+	RuntimeCall::get_module_names()
+		.iter()
+		.filter(|pallet_name| **pallet_name != "TransactionPause")
+		.flat_map(|pallet_name| {
+			RuntimeCall::get_call_names(pallet_name)
+				.iter()
+				.map(move |function_name| (pallet_name.as_bytes().to_vec(), function_name.as_bytes().to_vec()))
+		})
+		.take(c as usize)
+		.collect()
+}
+
+// enter safe mode by excessive liquidation at the next block
+fn trigger_liquidation(block_number: u32) {
+	SafeMode::set_trigger_params(
+		RawOrigin::Root.into(),
+		TriggerParams {
+			max_liquidated_value: Some(0),
+			liquidation_window: 100,
+			..Default::default()
+		},
+	)
+	.unwrap();
+	System::set_block_number(block_number);
+	SafeMode::on_initialize(block_number);
+	module_cdp_engine::TotalLiquidatedValue::<Runtime>::put(1);
+	System::set_block_number(block_number + 1);
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_safe_mode }
+
+	on_initialize {
+		let c in 0 .. <Runtime as module_safe_mode::Config>::MaxMonitoredCollaterals::get();
+		let prices = collaterals(c).into_iter().map(|currency_id| (currency_id, Price::saturating_from_integer(1))).collect();
+
+		feed_price(prices)?;
+		SafeMode::set_monitored_collaterals(RawOrigin::Root.into(), collaterals(c))?;
+		SafeMode::set_trigger_params(RawOrigin::Root.into(), TriggerParams {
+			max_peg_deviation_bps: Some(10_000),
+			max_price_age: Some(100),
+			max_liquidated_value: Some(1_000),
+			liquidation_window: 100,
+		})?;
+		System::set_block_number(1);
+	}: {
+		SafeMode::on_initialize(System::block_number());
+	}
+	verify {
+		assert!(!SafeMode::is_active());
+	}
+
+	enter_safe_mode {
+		let c in 0 .. <Runtime as module_safe_mode::Config>::MaxSafeModeCalls::get();
+		SafeMode::set_safe_mode_calls(RawOrigin::Root.into(), safe_mode_calls(c))?;
+		trigger_liquidation(1);
+	}: {
+		SafeMode::on_initialize(System::block_number());
+	}
+	verify {
+		assert!(SafeMode::is_active());
+	}
+
+	exit_safe_mode {
+		let c in 0 .. <Runtime as module_safe_mode::Config>::MaxSafeModeCalls::get();
+		SafeMode::set_safe_mode_calls(RawOrigin::Root.into(), safe_mode_calls(c))?;
+		trigger_liquidation(1);
+		SafeMode::on_initialize(System::block_number());
+	}: _(RawOrigin::Root)
+	verify {
+		assert_last_event(module_safe_mode::Event::SafeModeExited{ by_governance: true }.into());
+	}
+
+	set_trigger_params {
+		let params = TriggerParams {
+			max_peg_deviation_bps: Some(500),
+			max_price_age: Some(100),
+			max_liquidated_value: Some(1_000),
+			liquidation_window: 100,
+		};
+	}: _(RawOrigin::Root, params)
+	verify {
+		assert_last_event(module_safe_mode::Event::TriggerParamsUpdated{ params }.into());
+	}
+
+	set_monitored_collaterals {
+		let c in 0 .. <Runtime as module_safe_mode::Config>::MaxMonitoredCollaterals::get();
+	}: _(RawOrigin::Root, collaterals(c))
+	verify {
+		assert_last_event(module_safe_mode::Event::MonitoredCollateralsUpdated{ collaterals: collaterals(c) }.into());
+	}
+
+	set_safe_mode_calls {
+		let c in 0 .. <Runtime as module_safe_mode::Config>::MaxSafeModeCalls::get();
+		let calls = safe_mode_calls(c);
+	}: _(RawOrigin::Root, calls.clone())
+	verify {
+		assert_last_event(module_safe_mode::Event::SafeModeCallsUpdated{ calls }.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
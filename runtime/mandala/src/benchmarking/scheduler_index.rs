@@ -0,0 +1,75 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{OriginCaller, Preimage, Runtime, RuntimeCall, RuntimeOrigin, Scheduler, SchedulerIndex};
+use frame_support::traits::{
+	schedule::{v3::Anon, DispatchTime},
+	Get, StorePreimage,
+};
+use frame_system::RawOrigin;
+use module_scheduler_index::IndexedScheduler;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+fn schedule_remark(when: u32) -> Result<<Runtime as frame_system::Config>::Hash, sp_runtime::DispatchError> {
+	let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+	let bounded_call = Preimage::bound(call)?;
+	let call_hash = bounded_call.hash();
+	<IndexedScheduler<Runtime> as Anon<_, _, _>>::schedule(
+		DispatchTime::At(when),
+		None,
+		0,
+		OriginCaller::system(RawOrigin::Root),
+		bounded_call,
+	)?;
+	Ok(call_hash)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_scheduler_index }
+
+	cancel_scheduled_by_hash {
+		let c in 1 .. <Runtime as module_scheduler_index::Config>::MaxCancelledByHash::get();
+
+		let mut call_hash = Default::default();
+		for i in 0 .. c {
+			call_hash = schedule_remark(100 + i)?;
+		}
+	}: _(RuntimeOrigin::root(), call_hash, true)
+
+	prune_stale_entries {
+		let n in 1 .. module_scheduler_index::MAX_PRUNED_PER_IDLE;
+
+		// cancelled bypassing the index, all the entries are stale
+		for i in 0 .. n {
+			schedule_remark(100 + i)?;
+			Scheduler::cancel(RuntimeOrigin::root(), 100 + i, 0)?;
+		}
+	}: {
+		SchedulerIndex::prune_stale_entries(n);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,104 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, DappSessionKeys, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, System};
+
+use super::utils::{dollar, set_balance, NATIVE};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use module_session_keys::CallPattern;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+fn remark_patterns(c: u32) -> Vec<CallPattern> {
+	vec![
+		CallPattern {
+			pallet_name: b"System".to_vec(),
+			function_name: b"remark".to_vec(),
+			evm_target: None,
+		};
+		c as usize
+	]
+}
+
+fn register_session_key(primary: &AccountId, key: &AccountId) -> Result<(), sp_runtime::DispatchError> {
+	set_balance(NATIVE, primary, 100 * dollar(NATIVE));
+	DappSessionKeys::register_session_key(
+		RuntimeOrigin::signed(primary.clone()),
+		key.clone(),
+		remark_patterns(1),
+		System::block_number() + 100,
+		10 * dollar(NATIVE),
+	)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_session_keys }
+
+	register_session_key {
+		let c in 1 .. <Runtime as module_session_keys::Config>::MaxCallPatterns::get();
+
+		let caller: AccountId = whitelisted_caller();
+		let key: AccountId = account("key", 0, SEED);
+		set_balance(NATIVE, &caller, 100 * dollar(NATIVE));
+		let expiry = System::block_number() + 100;
+	}: _(RawOrigin::Signed(caller.clone()), key.clone(), remark_patterns(c), expiry, 10 * dollar(NATIVE))
+	verify {
+		assert_last_event(module_session_keys::Event::SessionKeyRegistered {
+			primary: caller,
+			key,
+			expiry,
+			spend_cap: 10 * dollar(NATIVE),
+		}.into());
+	}
+
+	revoke_session_key {
+		let caller: AccountId = whitelisted_caller();
+		let key: AccountId = account("key", 0, SEED);
+		register_session_key(&caller, &key)?;
+	}: _(RawOrigin::Signed(caller.clone()), key.clone())
+	verify {
+		assert_last_event(module_session_keys::Event::SessionKeyRevoked { primary: caller, key }.into());
+	}
+
+	session_call {
+		let primary: AccountId = account("primary", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		register_session_key(&primary, &caller)?;
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	}: _(RawOrigin::Signed(caller.clone()), Box::new(call))
+	verify {
+		assert_last_event(module_session_keys::Event::SessionCallDispatched { primary, key: caller, spent: 0 }.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
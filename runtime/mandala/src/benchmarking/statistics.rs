@@ -0,0 +1,110 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{AccountId, Rewards, Runtime, RuntimeEvent, Statistics, StatisticsPeriod, System};
+
+use super::utils::{dollar, NATIVE};
+use frame_benchmarking::account;
+use frame_support::{
+	assert_ok,
+	traits::{Get, OnInitialize},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use module_statistics::{Checkpoint, Checkpoints, YieldSource};
+use module_support::PoolId;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::CurrencyId;
+use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+fn yield_sources(c: u32) -> Vec<YieldSource> {
+	(0..c)
+		.map(|i| match i {
+			0 => YieldSource::Homa,
+			_ => YieldSource::IncentivePool(PoolId::Loans(CurrencyId::ForeignAsset(i as u16)), NATIVE),
+		})
+		.collect()
+}
+
+fn fill_checkpoints(sources: &[YieldSource]) {
+	let max_checkpoints = <Runtime as module_statistics::Config>::MaxCheckpoints::get();
+	for source in sources {
+		let checkpoints: BoundedVec<_, _> = (0..max_checkpoints)
+			.map(|i| Checkpoint {
+				block_number: i,
+				index: FixedU128::saturating_from_integer(1),
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		Checkpoints::<Runtime>::insert(source, checkpoints);
+	}
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_statistics }
+
+	on_initialize {
+		let c in 0 .. <Runtime as module_statistics::Config>::MaxSources::get();
+		let sharer: AccountId = account("sharer", 0, SEED);
+		let sources = yield_sources(c);
+
+		for source in sources.iter() {
+			if let YieldSource::IncentivePool(pool_id, reward_currency_id) = source {
+				assert_ok!(Rewards::add_share(&sharer, pool_id, dollar(NATIVE)));
+				Rewards::accumulate_reward(pool_id, *reward_currency_id, dollar(NATIVE))?;
+			}
+		}
+		Statistics::set_tracked_sources(RawOrigin::Root.into(), sources.clone())?;
+		fill_checkpoints(&sources);
+		System::set_block_number(StatisticsPeriod::get());
+	}: {
+		Statistics::on_initialize(System::block_number());
+	}
+
+	set_tracked_sources {
+		let c in 0 .. <Runtime as module_statistics::Config>::MaxSources::get();
+		let max_sources = <Runtime as module_statistics::Config>::MaxSources::get();
+
+		// the previous sources are all untracked, and their checkpoints are removed
+		let previous_sources: Vec<YieldSource> = (0..max_sources)
+			.map(|i| YieldSource::Earning(CurrencyId::ForeignAsset(i as u16)))
+			.collect();
+		Statistics::set_tracked_sources(RawOrigin::Root.into(), previous_sources.clone())?;
+		fill_checkpoints(&previous_sources);
+		let sources = yield_sources(c);
+	}: _(RawOrigin::Root, sources.clone())
+	verify {
+		assert_last_event(module_statistics::Event::TrackedSourcesUpdated{ sources }.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
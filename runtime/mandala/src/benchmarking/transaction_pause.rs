@@ -25,11 +25,11 @@ runtime_benchmarks! {
 	{ Runtime, module_transaction_pause }
 
 	pause_transaction {
-	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer".to_vec())
+	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer_allow_death".to_vec())
 
 	unpause_transaction {
-		TransactionPause::pause_transaction(RuntimeOrigin::root(), b"Balances".to_vec(), b"transfer".to_vec())?;
-	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer".to_vec())
+		TransactionPause::pause_transaction(RuntimeOrigin::root(), b"Balances".to_vec(), b"transfer_allow_death".to_vec())?;
+	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer_allow_death".to_vec())
 
 	pause_evm_precompile {
 	}: _(RawOrigin::Root, H160::from_low_u64_be(1))
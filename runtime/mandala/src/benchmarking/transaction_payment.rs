@@ -18,17 +18,21 @@
 
 use super::utils::{dollar, inject_liquidity, set_balance, LIQUID, NATIVE, STABLECOIN, STAKING};
 use crate::{
-	AccountId, AssetRegistry, Balance, Currencies, CurrencyId, Dex, NativeTokenExistentialDeposit, Runtime,
-	RuntimeEvent, RuntimeOrigin, StableAsset, System, TransactionPayment, TreasuryPalletId,
+	AccountId, AssetRegistry, Balance, Currencies, CurrencyId, Dex, MaxFeeSpendPeriods, NativeTokenExistentialDeposit,
+	Runtime, RuntimeEvent, RuntimeOrigin, StableAsset, System, TransactionPayment, TreasuryPalletId,
 };
 use frame_benchmarking::{account, whitelisted_caller};
-use frame_support::{assert_ok, traits::OnFinalize};
+use frame_support::{assert_ok, traits::OnFinalize, BoundedVec};
 use frame_system::RawOrigin;
 use module_support::{AggregatedSwapPath, DEXManager, Ratio, SwapLimit};
+use module_transaction_payment::{FeeCallCategory, FeeSpend, RebalanceParams};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
 use primitives::currency::AssetMetadata;
-use sp_runtime::traits::{AccountIdConversion, One};
+use sp_runtime::{
+	traits::{AccountIdConversion, One},
+	Permill,
+};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -101,7 +105,8 @@ fn enable_stable_asset() {
 	};
 	assert_ok!(AssetRegistry::register_stable_asset(
 		RawOrigin::Root.into(),
-		Box::new(asset_metadata.clone())
+		Box::new(asset_metadata.clone()),
+		true
 	));
 	assert_ok!(StableAsset::mint(
 		RuntimeOrigin::signed(funder.clone()),
@@ -127,6 +132,7 @@ runtime_benchmarks! {
 	set_alternative_fee_swap_path {
 		let caller: AccountId = whitelisted_caller();
 		set_balance(NATIVE, &caller, 2 * NativeTokenExistentialDeposit::get());
+		enable_fee_pool();
 	}: _(RawOrigin::Signed(caller.clone()), Some(vec![STABLECOIN, NATIVE]))
 	verify {
 		assert_eq!(TransactionPayment::alternative_fee_swap_path(&caller).unwrap().into_inner(), vec![STABLECOIN, NATIVE]);
@@ -207,6 +213,27 @@ runtime_benchmarks! {
 		}.into());
 	}: _(RawOrigin::Signed(caller.clone()), STABLECOIN, call)
 
+	with_fee_preference {
+		System::set_block_number(1);
+
+		let caller: AccountId = whitelisted_caller();
+		let call = Box::new(frame_system::Call::remark { remark: vec![] }.into());
+		set_balance(STABLECOIN, &caller, 100 * dollar(STABLECOIN));
+		set_balance(NATIVE, &caller, 100 * dollar(NATIVE));
+
+		let (sub_account, stable_ed, pool_size, swap_threshold) = enable_fee_pool();
+		TransactionPayment::enable_charge_fee_pool(RawOrigin::Root.into(), STABLECOIN, pool_size, swap_threshold).unwrap();
+
+		let exchange_rate = TransactionPayment::token_exchange_rate(STABLECOIN).unwrap();
+		assert_has_event(module_transaction_payment::Event::ChargeFeePoolEnabled {
+			sub_account,
+			currency_id: STABLECOIN,
+			exchange_rate,
+			pool_size,
+			swap_threshold
+		}.into());
+	}: _(RawOrigin::Signed(caller.clone()), STABLECOIN, call)
+
 	with_fee_aggregated_path {
 		System::set_block_number(1);
 
@@ -224,6 +251,74 @@ runtime_benchmarks! {
 		];
 	}: _(RawOrigin::Signed(caller.clone()), fee_aggregated_path, call)
 
+	enable_fee_spend_tracking {
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(TransactionPayment::fee_spend_tracking(&caller).is_some());
+	}
+
+	disable_fee_spend_tracking {
+		let p in 1 .. MaxFeeSpendPeriods::get();
+
+		let caller: AccountId = whitelisted_caller();
+		let spend = FeeSpend {
+			category: FeeCallCategory::Transfer,
+			currency_id: NATIVE,
+			amount: dollar(NATIVE),
+			native_amount: dollar(NATIVE),
+			count: 1,
+		};
+		let periods: Vec<u32> = (0..p).collect();
+		for period in periods.iter() {
+			module_transaction_payment::FeeSpends::<Runtime>::insert(&caller, period, vec![spend.clone()]);
+		}
+		module_transaction_payment::FeeSpendTracking::<Runtime>::insert(&caller, BoundedVec::try_from(periods).unwrap());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(TransactionPayment::fee_spend_tracking(&caller).is_none());
+		assert!(TransactionPayment::fee_spends(&caller, 0).is_empty());
+	}
+
+	set_fee_pool_rebalance_params {
+		let (_, _, pool_size, swap_threshold) = enable_fee_pool();
+		assert_ok!(TransactionPayment::enable_charge_fee_pool(RuntimeOrigin::root(), STABLECOIN, pool_size, swap_threshold));
+		let params = RebalanceParams {
+			native_lower_band: Permill::from_percent(50),
+			max_supply_amount: dollar(STABLECOIN),
+			max_slippage: Permill::from_percent(1),
+			period: 100,
+			max_rebalances_per_period: 2,
+		};
+	}: _(RawOrigin::Root, STABLECOIN, Some(params))
+	verify {
+		assert_eq!(TransactionPayment::fee_pool_rebalance_params(STABLECOIN), Some(params));
+	}
+
+	rebalance_fee_pool {
+		let (sub_account, _, pool_size, swap_threshold) = enable_fee_pool();
+		assert_ok!(TransactionPayment::enable_charge_fee_pool(RuntimeOrigin::root(), STABLECOIN, pool_size, swap_threshold));
+		assert_ok!(TransactionPayment::set_fee_pool_rebalance_params(RuntimeOrigin::root(), STABLECOIN, Some(RebalanceParams {
+			native_lower_band: Permill::from_percent(50),
+			max_supply_amount: dollar(STABLECOIN),
+			max_slippage: Permill::from_percent(1),
+			period: 100,
+			max_rebalances_per_period: 2,
+		})));
+
+		// the pool is drained below the band by the fees paid in the stable currency.
+		set_balance(NATIVE, &sub_account, swap_threshold);
+		set_balance(STABLECOIN, &sub_account, 10 * dollar(STABLECOIN));
+		let (_, min_target_amount) = Dex::get_swap_amount(
+			&vec![STABLECOIN, NATIVE],
+			SwapLimit::ExactSupply(dollar(STABLECOIN), 0),
+		).unwrap();
+	}: _(RawOrigin::None, STABLECOIN, dollar(STABLECOIN), min_target_amount)
+	verify {
+		assert_eq!(TransactionPayment::fee_pool_rebalances(STABLECOIN), (0, 1));
+		assert_eq!(<Currencies as MultiCurrency<AccountId>>::free_balance(STABLECOIN, &sub_account), 9 * dollar(STABLECOIN));
+	}
+
 	on_finalize {
 	}: {
 		TransactionPayment::on_finalize(System::block_number());
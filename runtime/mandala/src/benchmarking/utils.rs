@@ -68,7 +68,8 @@ pub fn register_native_asset(assets: Vec<CurrencyId>) {
 				symbol: asset.symbol().unwrap().as_bytes().to_vec(),
 				decimals: asset.decimals().unwrap(),
 				minimal_balance: ed,
-			})
+			}),
+			false
 		));
 	});
 }
@@ -164,7 +165,7 @@ pub fn register_stable_asset() -> DispatchResult {
 		decimals: 12,
 		minimal_balance: 1,
 	};
-	AssetRegistry::register_stable_asset(RawOrigin::Root.into(), Box::new(asset_metadata.clone()))
+	AssetRegistry::register_stable_asset(RawOrigin::Root.into(), Box::new(asset_metadata.clone()), true)
 }
 
 pub fn create_stable_pools(assets: Vec<CurrencyId>, precisions: Vec<u128>, initial_a: u128) -> DispatchResult {
@@ -0,0 +1,82 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	get_vesting_account,
+	utils::{lookup_of_account, set_balance, NATIVE},
+};
+use crate::{dollar, AccountId, Balance, BlockNumber, Runtime, RuntimeEvent, System, Vesting};
+
+use sp_std::prelude::*;
+
+use frame_benchmarking::whitelisted_caller;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+
+use orml_benchmarking::runtime_benchmarks;
+use orml_vesting::VestingSchedule;
+
+pub type Schedule = VestingSchedule<BlockNumber, Balance>;
+
+fn assert_last_event(generic_event: RuntimeEvent) {
+	System::assert_last_event(generic_event.into());
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_vesting_consolidation }
+
+	// worst case: `MaxVestingSchedules` still vesting schedules merged into one
+	consolidate_schedules {
+		let max_schedules = <Runtime as orml_vesting::Config>::MaxVestingSchedules::get();
+		let schedule = Schedule {
+			start: 0,
+			period: 2,
+			period_count: 3,
+			per_period: dollar(NATIVE),
+		};
+
+		let from: AccountId = get_vesting_account();
+		// extra 1 dollar to pay fees
+		set_balance(NATIVE, &from, schedule.total_amount().unwrap() * max_schedules as u128 + dollar(NATIVE));
+
+		let to: AccountId = whitelisted_caller();
+		let to_lookup = lookup_of_account(to.clone());
+
+		for _ in 0..max_schedules {
+			Vesting::vested_transfer(RawOrigin::Signed(from.clone()).into(), to_lookup.clone(), schedule.clone())?;
+		}
+	}: _(RawOrigin::Signed(to.clone()), max_schedules)
+	verify {
+		assert_eq!(Vesting::vesting_schedules(&to).len(), 1);
+		assert_last_event(module_vesting_consolidation::Event::SchedulesConsolidated {
+			who: to,
+			claimed: 0,
+			merged: max_schedules - 1,
+			remaining: 1,
+		}.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,95 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The canonical setup of the dev chain, applied by `module_dev_setup`.
+//!
+//! The dev chain spec puts the SCALE-encoded `canonical_setup()` into the genesis of
+//! `module_dev_setup`, it can also be applied to a running network with `DevSetup::apply_setup`.
+
+use crate::{AccountId, Runtime};
+use module_dev_setup::{CollateralSetup, DevSetupOf, TradingPairSetup};
+use module_support::{Price, Rate, Ratio};
+use primitives::currency::AssetMetadata;
+use runtime_common::{dollar, ACA, AUSD, DOT, LDOT};
+use sp_runtime::{traits::Zero, FixedPointNumber};
+use sp_std::prelude::*;
+use xcm::prelude::*;
+
+/// The `//Alice` dev account, which is endowed by the dev chain spec.
+pub fn faucet() -> AccountId {
+	AccountId::from(hex_literal::hex!(
+		"d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+	))
+}
+
+pub fn canonical_setup() -> DevSetupOf<Runtime> {
+	DevSetupOf::<Runtime> {
+		faucet: faucet(),
+		foreign_assets: vec![(
+			Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]).into(),
+			AssetMetadata {
+				name: b"Tether USD".to_vec(),
+				symbol: b"USDT".to_vec(),
+				decimals: 6,
+				minimal_balance: 10_000,
+			},
+		)],
+		prices: vec![
+			(ACA, Price::saturating_from_integer(2)),
+			(DOT, Price::saturating_from_integer(5)),
+		],
+		trading_pairs: vec![
+			TradingPairSetup {
+				currency_id_a: AUSD,
+				currency_id_b: DOT,
+				amount_a: 1_000_000 * dollar(AUSD),
+				amount_b: 200_000 * dollar(DOT),
+			},
+			TradingPairSetup {
+				currency_id_a: ACA,
+				currency_id_b: AUSD,
+				amount_a: 1_000_000 * dollar(ACA),
+				amount_b: 2_000_000 * dollar(AUSD),
+			},
+			TradingPairSetup {
+				currency_id_a: ACA,
+				currency_id_b: DOT,
+				amount_a: 250_000 * dollar(ACA),
+				amount_b: 100_000 * dollar(DOT),
+			},
+		],
+		collaterals: vec![
+			CollateralSetup {
+				currency_id: DOT,
+				interest_rate_per_sec: Some(Rate::zero()),
+				liquidation_ratio: Some(Ratio::saturating_from_rational(150, 100)),
+				liquidation_penalty: Some(Rate::saturating_from_rational(10, 100)),
+				required_collateral_ratio: Some(Ratio::saturating_from_rational(150, 100)),
+				maximum_total_debit_value: 10_000_000 * dollar(AUSD),
+			},
+			CollateralSetup {
+				currency_id: LDOT,
+				interest_rate_per_sec: Some(Rate::zero()),
+				liquidation_ratio: Some(Ratio::saturating_from_rational(150, 100)),
+				liquidation_penalty: Some(Rate::saturating_from_rational(10, 100)),
+				required_collateral_ratio: Some(Ratio::saturating_from_rational(180, 100)),
+				maximum_total_debit_value: 10_000_000 * dollar(AUSD),
+			},
+		],
+	}
+}
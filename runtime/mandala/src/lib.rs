@@ -48,20 +48,22 @@ use frame_support::{
 };
 use frame_system::{EnsureRoot, EnsureSigned, RawOrigin};
 use module_asset_registry::{AssetIdMaps, EvmErc20InfoMapping};
-use module_cdp_engine::CollateralCurrencyIds;
+use module_cdp_engine::{CDPEngineTask, CollateralCurrencyIds};
 use module_currencies::BasicCurrencyAdapter;
+use module_emergency_shutdown::EmergencyShutdownTask;
 use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
+use module_nft::NftTask;
 use module_relaychain::RelayChainCallBuilder;
 use module_support::{AddressMapping, AssetIdMapping, DispatchableTask, ExchangeRateProvider, FractionalRate, PoolId};
-use module_transaction_payment::TargetedFeeAdjustment;
+use module_transaction_payment::{ClassifyFeeCall, FeeCallCategory, TargetedFeeAdjustment};
 use parity_scale_codec::{Decode, DecodeLimit, Encode};
 use scale_info::TypeInfo;
 
 use orml_tokens::CurrencyAdapter;
 use orml_traits::{
-	create_median_value_data_provider, define_aggregrated_parameters, parameter_type_with_key,
-	parameters::ParameterStoreAdapter, DataFeeder, DataProviderExtended, GetByKey, MultiCurrency,
+	define_aggregrated_parameters, parameter_type_with_key, parameters::ParameterStoreAdapter, DataProviderExtended,
+	GetByKey, MultiCurrency,
 };
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use primitives::{
@@ -96,7 +98,10 @@ pub use authority::AuthorityConfigImpl;
 pub use constants::{fee::*, time::*};
 pub use primitives::{
 	currency::AssetIds,
-	evm::{BlockLimits, EstimateResourcesRequest},
+	evm::{
+		BlockLimits, ContractPurgeProgress, EstimateResourcesRequest, EstimateResourcesResponse, EthCallRequest,
+		EvmTransactionIndex,
+	},
 	AccountId, AccountIndex, Address, Amount, AuctionId, AuthoritysOriginId, Balance, BlockNumber, CurrencyId,
 	DataProviderId, EraIndex, Hash, Lease, Moment, Multiplier, Nonce, ReserveIdentifier, Share, Signature, TokenSymbol,
 	TradingPair,
@@ -110,9 +115,10 @@ use runtime_common::{
 	EnsureRootOrTwoThirdsGeneralCouncil, EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate,
 	ExistentialDepositsTimesOneHundred, FinancialCouncilInstance, FinancialCouncilMembershipInstance, GasToWeight,
 	GeneralCouncilInstance, GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance,
-	MaxTipsOfPriority, OperationalFeeMultiplier, OperatorMembershipInstanceAcala, Price, ProxyType, RandomnessSource,
-	Rate, Ratio, RuntimeBlockLength, RuntimeBlockWeights, TechnicalCommitteeInstance,
-	TechnicalCommitteeMembershipInstance, TimeStampedPrice, TipPerWeightStep, ACA, AUSD, DOT, KSM, LCDOT, LDOT,
+	MaxTipsOfPriority, OperationalFeeMultiplier, OperatorMembershipInstanceAcala, OperatorMembershipInstanceSecondary,
+	OraclePriceTimestamp, Price, ProxyType, RandomnessSource, Rate, Ratio, RuntimeBlockLength, RuntimeBlockWeights,
+	TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice, TipPerWeightStep, ACA, AUSD,
+	DOT, KSM, LCDOT, LDOT,
 };
 use xcm::prelude::*;
 
@@ -122,6 +128,10 @@ pub use nutsfinance_stable_asset;
 mod authority;
 mod benchmarking;
 pub mod constants;
+#[cfg(feature = "dev-setup")]
+pub mod dev_setup;
+/// The breakdown of the reserved balances by the features reserving them.
+pub mod reserves;
 /// Weights for pallets used in the runtime.
 mod weights;
 pub mod xcm_config;
@@ -179,6 +189,8 @@ parameter_types! {
 	pub const LiquidCrowdloanPalletId: PalletId = PalletId(*b"aca/lqcl");
 	// Ecosystem modules
 	pub const StableAssetPalletId: PalletId = PalletId(*b"nuts/sta");
+	pub const FaucetPalletId: PalletId = PalletId(*b"aca/fcet");
+	pub const OtcPalletId: PalletId = PalletId(*b"aca/otce");
 	// lock identifier for earning module
 	pub const EarningLockIdentifier: LockIdentifier = *b"aca/earn";
 }
@@ -197,6 +209,8 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		CollatorPotId::get().into_account_truncating(),
 		UnreleasedNativeVaultAccountId::get(),
 		StableAssetPalletId::get().into_account_truncating(),
+		FaucetPalletId::get().into_account_truncating(),
+		OtcPalletId::get().into_account_truncating(),
 	]
 }
 
@@ -327,6 +341,8 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = ();
 }
 
+type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
+
 // pallet-treasury did not impl OnUnbalanced<Credit>, need an adapter to handle dust.
 type CreditOf = frame_support::traits::fungible::Credit<<Runtime as frame_system::Config>::AccountId, Balances>;
 pub struct DustRemovalAdapter;
@@ -375,6 +391,57 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub FaucetDripBasket: Vec<(CurrencyId, Balance)> = vec![
+		(ACA, 100 * dollar(ACA)),
+		(AUSD, 100 * dollar(AUSD)),
+		(DOT, 10 * dollar(DOT)),
+	];
+}
+
+impl module_faucet::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type PalletId = FaucetPalletId;
+	type DripBasket = FaucetDripBasket;
+	type DripPeriod = ConstU32<DAYS>;
+	type MaxDripsPerAccount = ConstU32<10>;
+	type MaxDripsPerBlock = ConstU32<20>;
+	type PersonhoodCheck = ();
+	type WeightInfo = weights::module_faucet::WeightInfo<Runtime>;
+}
+
+#[cfg(feature = "dev-setup")]
+impl module_dev_setup::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Oracle = AcalaOracle;
+	type WeightInfo = ();
+}
+
+#[cfg(feature = "debug-error-events")]
+impl module_error_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletErrors = RuntimePalletErrors;
+}
+
+parameter_types! {
+	pub const StateDiffRetainedBlocks: BlockNumber = 2 * HOURS;
+}
+
+#[cfg(feature = "state-diff")]
+impl module_state_diff::Config for Runtime {
+	type TrackedPrefixes = frame_support::traits::Everything;
+	type RetainedBlocks = StateDiffRetainedBlocks;
+	type MaxChangesPerBlock = ConstU32<4096>;
+}
+
+/// The hooks recording the changed keys for the state diff, nothing without the `state-diff`
+/// feature.
+#[cfg(feature = "state-diff")]
+type StateDiffHooks = StateDiff;
+#[cfg(not(feature = "state-diff"))]
+type StateDiffHooks = ();
+
 parameter_types! {
 	pub const GeneralCouncilMotionDuration: BlockNumber = 7 * DAYS;
 	pub const CouncilDefaultMaxProposals: u32 = 100;
@@ -511,6 +578,19 @@ impl pallet_membership::Config<OperatorMembershipInstanceAcala> for Runtime {
 	type WeightInfo = ();
 }
 
+impl pallet_membership::Config<OperatorMembershipInstanceSecondary> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type RemoveOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type SwapOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type ResetOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type PrimeOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type MembershipInitialized = ();
+	type MembershipChanged = SecondaryOracle;
+	type MaxMembers = ConstU32<50>;
+	type WeightInfo = ();
+}
+
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -647,6 +727,39 @@ impl pallet_recovery::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub AccountRescueProcessingFee: Balance = dollar(ACA);
+}
+
+impl module_account_rescue::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type TransferAll = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type ProcessingFee = AccountRescueProcessingFee;
+	type TreasuryAccount = TreasuryAccount;
+	type ChainId = EvmChainId<Runtime>;
+	type RescueOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type WeightInfo = weights::module_account_rescue::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub OtcSettlementFee: Balance = 10 * cent(ACA);
+}
+
+impl module_otc::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type SettlementFee = OtcSettlementFee;
+	type TreasuryAccount = TreasuryAccount;
+	type PalletId = OtcPalletId;
+	type MaxOpenOffers = ConstU32<20>;
+	type MaxExpiringOffersPerBlock = ConstU32<100>;
+	type WeightInfo = weights::module_otc::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const LaunchPeriod: BlockNumber = 2 * HOURS;
 	pub const VotingPeriod: BlockNumber = HOURS;
@@ -658,7 +771,11 @@ parameter_types! {
 
 impl pallet_democracy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type Currency = Balances;
+	type Currency = runtime_common::BoostedVotingCurrency<
+		Runtime,
+		Balances,
+		ParameterStoreAdapter<Parameters, runtime_common::GovernanceParameters>,
+	>;
 	type EnactmentPeriod = EnactmentPeriod;
 	type LaunchPeriod = LaunchPeriod;
 	type VotingPeriod = VotingPeriod;
@@ -688,7 +805,7 @@ impl pallet_democracy::Config for Runtime {
 	type VetoOrigin = pallet_collective::EnsureMember<AccountId, TechnicalCommitteeInstance>;
 	type CooloffPeriod = CooloffPeriod;
 	type Slash = Treasury;
-	type Scheduler = Scheduler;
+	type Scheduler = module_scheduler_index::IndexedScheduler<Runtime>;
 	type PalletsOrigin = OriginCaller;
 	type MaxVotes = ConstU32<100>;
 	//TODO: might need to weight for Mandala
@@ -713,7 +830,7 @@ impl orml_authority::Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type PalletsOrigin = OriginCaller;
 	type RuntimeCall = RuntimeCall;
-	type Scheduler = Scheduler;
+	type Scheduler = module_scheduler_index::IndexedScheduler<Runtime>;
 	type AsOriginId = AuthoritysOriginId;
 	type AuthorityConfig = AuthorityConfigImpl;
 	type WeightInfo = weights::orml_authority::WeightInfo<Runtime>;
@@ -791,6 +908,7 @@ impl pallet_elections_phragmen::Config for Runtime {
 
 parameter_types! {
 	pub const MinimumCount: u32 = 1;
+	pub const RelaxedMinimumCount: u32 = 1;
 	pub const ExpiresIn: Moment = 1000 * 60 * 60; // 1 hours
 	pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
 	pub const MaxFeedValues: u32 = 10; // max 10 values allowd to feed in one call.
@@ -809,7 +927,12 @@ type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OnNewData = ();
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
+	type CombineData = orml_oracle::DefaultCombineData<
+		Runtime,
+		module_oracle_operator::CurrentMinimumCount<Runtime>,
+		ExpiresIn,
+		AcalaDataProvider,
+	>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
 	type OracleValue = Price;
@@ -822,20 +945,63 @@ impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type BenchmarkHelper = BenchmarkHelper;
 }
 
-create_median_value_data_provider!(
-	AggregatedDataProvider,
-	CurrencyId,
-	Price,
-	TimeStampedPrice,
-	[AcalaOracle]
-);
-// Aggregated data provider cannot feed.
-impl DataFeeder<CurrencyId, Price, AccountId> for AggregatedDataProvider {
-	fn feed_value(_: Option<AccountId>, _: CurrencyId, _: Price) -> DispatchResult {
-		Err("Not supported".into())
-	}
+parameter_types! {
+	pub OracleOperatorMinimumBond: Balance = 100 * dollar(ACA);
+	pub const OracleOperatorUnbondingPeriod: BlockNumber = DAYS;
+	pub const OracleRotationGracePeriod: BlockNumber = HOURS;
+	pub const OracleFailoverThreshold: BlockNumber = 10 * MINUTES;
+}
+
+impl module_oracle_operator::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Operators = OperatorMembershipAcala;
+	type Oracle = AcalaOracle;
+	type MinimumBond = OracleOperatorMinimumBond;
+	type UnbondingPeriod = OracleOperatorUnbondingPeriod;
+	type MaxFeedsPerBlock = ConstU32<1>;
+	type MaxFeedValues = MaxFeedValues;
+	type FeedPriority =
+		runtime_common::OracleFeedPriority<ParameterStoreAdapter<Parameters, runtime_common::PriorityParameters>>;
+	type MaxLiquidationHints = ConstU32<10>;
+	type Liquidator = runtime_common::CdpEngineLiquidator<Runtime>;
+	type SlashOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type OnSlash = Treasury;
+	type OperatorMembership = runtime_common::MembershipOperators<Runtime, OperatorMembershipInstanceAcala>;
+	type OperatorValues = runtime_common::OracleRawValues<Runtime, AcalaDataProvider>;
+	type MinimumCount = MinimumCount;
+	type RelaxedMinimumCount = RelaxedMinimumCount;
+	type RotationGracePeriod = OracleRotationGracePeriod;
+	type FailoverThreshold = OracleFailoverThreshold;
+	type RotateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type WeightInfo = weights::module_oracle_operator::WeightInfo<Runtime>;
+}
+
+type SecondaryDataProvider = orml_oracle::Instance2;
+impl orml_oracle::Config<SecondaryDataProvider> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OnNewData = ();
+	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, SecondaryDataProvider>;
+	type Time = Timestamp;
+	type OracleKey = CurrencyId;
+	type OracleValue = Price;
+	type RootOperatorAccountId = RootOperatorAccountId;
+	type Members = OperatorMembershipSecondary;
+	type MaxHasDispatchedSize = ConstU32<40>;
+	type WeightInfo = weights::orml_oracle::WeightInfo<Runtime>;
+	type MaxFeedValues = MaxFeedValues;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BenchmarkHelper;
 }
 
+pub type AggregatedDataProvider = runtime_common::MultiSourceDataProvider<
+	AcalaOracle,
+	SecondaryOracle,
+	ParameterStoreAdapter<Parameters, runtime_common::OracleParameters>,
+	Timestamp,
+	ExpiresIn,
+>;
+
 pub struct DustRemovalWhitelist;
 impl Contains<AccountId> for DustRemovalWhitelist {
 	fn contains(a: &AccountId) -> bool {
@@ -903,7 +1069,7 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type WeightInfo = weights::orml_tokens::WeightInfo<Runtime>;
 	type ExistentialDeposits = ExistentialDeposits;
-	type CurrencyHooks = CurrencyHooks<Runtime, TreasuryAccount>;
+	type CurrencyHooks = CurrencyHooks<Runtime, TreasuryAccount, StateDiffHooks>;
 	type MaxLocks = MaxLocks;
 	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = ReserveIdentifier;
@@ -929,6 +1095,8 @@ parameter_type_with_key! {
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
 	pub RewardRatePerRelaychainBlock: Rate = Rate::saturating_from_rational(2_492, 100_000_000_000u128);	// 14% annual staking reward rate of Polkadot
+	pub const MaxLockPriceDeviation: Permill = Permill::from_percent(20);
+	pub const MaxPriceLockDuration: BlockNumber = 7 * DAYS;
 }
 
 impl module_prices::Config for Runtime {
@@ -947,6 +1115,8 @@ impl module_prices::Config for Runtime {
 	type RelayChainBlockNumber = RelaychainDataProvider<Runtime>;
 	type RewardRatePerRelaychainBlock = RewardRatePerRelaychainBlock;
 	type PricingPegged = PricingPegged;
+	type MaxLockPriceDeviation = MaxLockPriceDeviation;
+	type MaxPriceLockDuration = MaxPriceLockDuration;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -967,7 +1137,10 @@ impl module_currencies::Config for Runtime {
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
-	type OnDust = module_currencies::TransferDust<Runtime, TreasuryAccount>;
+	type OnDust = module_currencies::HandleDust<Runtime, TreasuryAccount>;
+	type AccountCurrencies = runtime_common::TokensAccountCurrencies<Runtime>;
+	type Swap = AcalaSwap;
+	type MaxTransferAllCurrencies = ConstU32<8>;
 }
 
 pub struct EnsureRootOrTreasury;
@@ -1006,6 +1179,11 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
 }
 
+impl module_vesting_consolidation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::module_vesting_consolidation::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
 }
@@ -1023,6 +1201,14 @@ impl pallet_scheduler::Config for Runtime {
 	type Preimages = Preimage;
 }
 
+impl module_scheduler_index::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type CancelOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type MaxCancelledByHash = ConstU32<10>;
+	type WeightInfo = weights::module_scheduler_index::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub PreimageBaseDeposit: Balance = deposit(2, 64);
 	pub PreimageByteDeposit: Balance = deposit(0, 1);
@@ -1059,6 +1245,8 @@ impl module_auction_manager::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
+	type BidPriority =
+		runtime_common::AuctionBidPriority<ParameterStoreAdapter<Parameters, runtime_common::PriorityParameters>>;
 	type EmergencyShutdown = EmergencyShutdown;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
@@ -1069,7 +1257,8 @@ impl module_loans::Config for Runtime {
 	type RiskManager = CdpEngine;
 	type CDPTreasury = CdpTreasury;
 	type PalletId = LoansPalletId;
-	type OnUpdateLoan = module_incentives::OnUpdateLoan<Runtime>;
+	type OnUpdateLoan = (module_incentives::OnUpdateLoan<Runtime>, StateDiffHooks);
+	type MaxPositionsPerAccount = ConstU32<8>;
 }
 
 impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
@@ -1106,6 +1295,9 @@ where
 			frame_system::CheckWeight::<Runtime>::new(),
 			module_evm::SetEvmOrigin::<Runtime>::new(),
 			module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			module_oracle_operator::CheckOracleFeed::<Runtime>::new(),
+			module_auction_manager::CheckAuctionBid::<Runtime>::new(),
+			module_session_keys::CheckSessionKey::<Runtime>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1138,43 +1330,74 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: FractionalRate = FractionalRate::try_from(Rate::saturating_from_rational(5, 100))
 	.expect("Rate is in range; qed");
 	pub MinimumDebitValue: Balance = dollar(AUSD);
+	pub MinLiquidationRatio: Ratio = Ratio::saturating_from_rational(110, 100);
+	pub MaxLiquidationRatio: Ratio = Ratio::saturating_from_integer(10);
+	pub MaxLiquidationPenalty: Rate = Rate::saturating_from_rational(50, 100);
+	pub MaxInterestRatePerSec: Rate = Rate::saturating_from_rational(1, 1_000);
+	pub MaxDebitCeilingIncrease: Ratio = Ratio::saturating_from_integer(10);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub MaxLiquidationContractSlippage: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub PartialLiquidationThreshold: Balance = 1_000_000 * dollar(AUSD);
+	pub PartialLiquidationBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 	pub SettleErc20EvmOrigin: AccountId = AccountId::from(hex_literal::hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")); // `5HrN7fHLXWcFiXPwwtq2EkSGns9eMt5P7SpeTPewumZy6ftb`
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceTimestampSource = OraclePriceTimestamp<AggregatedDataProvider>;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
 	type DefaultLiquidationPenalty = DefaultLiquidationPenalty;
 	type MinimumDebitValue = MinimumDebitValue;
+	type MinLiquidationRatio = MinLiquidationRatio;
+	type MaxLiquidationRatio = MaxLiquidationRatio;
+	type MaxLiquidationPenalty = MaxLiquidationPenalty;
+	type MaxInterestRatePerSec = MaxInterestRatePerSec;
+	type MaxDebitCeilingIncrease = MaxDebitCeilingIncrease;
 	type MinimumCollateralAmount =
 		ExistentialDepositsTimesOneHundred<GetNativeCurrencyId, NativeTokenExistentialDeposit, ExistentialDeposits>;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
-	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
+	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority<
+		ParameterStoreAdapter<Parameters, runtime_common::PriorityParameters>,
+	>;
 	type EmergencyShutdown = EmergencyShutdown;
+	type SafeMode = SafeMode;
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = Dex;
 	type LiquidationContractsUpdateOrigin = EnsureRootOrHalfGeneralCouncil;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxCrossMarginPreferences = ConstU32<5>;
 	type LiquidationEvmBridge = module_evm_bridge::LiquidationEvmBridge<Runtime>;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type Swap = AcalaSwap;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type SettleErc20EvmOrigin = SettleErc20EvmOrigin;
+	type MaxLiquidationRecords = ConstU32<20>;
+	type PositionAlerts = Honzon;
+	type MaxPositionAlertBatch = ConstU32<50>;
+	type MaxDebitRebaseFactor = ConstU32<1_000>;
+	type RevenuePeriod = ConstU32<DAYS>;
+	type MaxRevenuePeriods = ConstU32<90>;
+	type RiskSnapshotPeriod = ConstU32<HOURS>;
+	type MaxRiskSnapshotPositions = ConstU32<200>;
+	type PartialLiquidationThreshold = PartialLiquidationThreshold;
+	type PartialLiquidationBuffer = PartialLiquidationBuffer;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_cdp_engine::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = dollar(ACA);
+	pub TopUpBounty: Balance = 10 * cent(ACA);
+	pub TopUpRatioBuffer: Ratio = Ratio::saturating_from_rational(10, 100);
 }
 
 impl module_honzon::Config for Runtime {
@@ -1182,9 +1405,130 @@ impl module_honzon::Config for Runtime {
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
+	type OperationGuard = Guardian;
+	type AuctionManagerHandler = AuctionManager;
+	type TopUpBounty = TopUpBounty;
+	type TopUpRatioBuffer = TopUpRatioBuffer;
+	type MaxPositionAlerts = ConstU32<5>;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
+pub struct GuardianRecoveryFriends;
+impl module_guardian::RecoveryFriends<AccountId> for GuardianRecoveryFriends {
+	fn friends(who: &AccountId) -> Vec<AccountId> {
+		// the fields of `RecoveryConfig` are private, decode the friends from its encoding
+		// (delay_period, deposit, friends, threshold).
+		pallet_recovery::Recoverable::<Runtime>::get(who)
+			.and_then(|config| {
+				<(BlockNumber, Balance, Vec<AccountId>, u16)>::decode(&mut &config.encode()[..])
+					.ok()
+					.map(|(_, _, friends, _)| friends)
+			})
+			.unwrap_or_default()
+	}
+}
+
+parameter_types! {
+	pub const GuardianMinDelay: BlockNumber = HOURS;
+}
+
+impl module_guardian::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type RecoveryFriends = GuardianRecoveryFriends;
+	type MaxGuardians = ConstU32<9>;
+	type MaxPendingActions = ConstU32<16>;
+	type MinDelay = GuardianMinDelay;
+	type WeightInfo = weights::module_guardian::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const PegMonitorPeriod: BlockNumber = 10 * MINUTES;
+}
+
+impl module_peg_monitor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type AccumulatePeriod = PegMonitorPeriod;
+	type DEX = Dex;
+	type StablePoolSwap = module_aggregated_dex::TaigaSwap<Runtime>;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type MaxSources = ConstU32<8>;
+	type MaxHistory = ConstU32<24>;
+	type WeightInfo = weights::module_peg_monitor::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const ReferenceRatePeriod: BlockNumber = 10 * MINUTES;
+}
+
+impl module_reference_rate::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AccumulatePeriod = ReferenceRatePeriod;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type DEXPriceSource = module_dex_oracle::AverageDEXPriceProvider<Runtime>;
+	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type MaxComponents = ConstU32<8>;
+	type MaxHistory = ConstU32<24>;
+	type WeightInfo = weights::module_reference_rate::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const SafeModeCooldown: BlockNumber = 2 * HOURS;
+}
+
+impl module_safe_mode::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PegStatus = PegMonitor;
+	type PriceTimestamp = OraclePriceTimestamp<AggregatedDataProvider>;
+	type LiquidatedValue = CdpEngine;
+	type TransactionPauser = TransactionPause;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type SafeModeCooldown = SafeModeCooldown;
+	type MaxMonitoredCollaterals = ConstU32<8>;
+	type MaxSafeModeCalls = ConstU32<16>;
+	type WeightInfo = weights::module_safe_mode::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub SessionKeyDeposit: Balance = deposit(1, 128);
+	pub const MaxSessionDuration: BlockNumber = 7 * DAYS;
+}
+
+impl module_session_keys::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type EvmTarget = runtime_common::EvmCallTarget<Runtime>;
+	type SessionKeyDeposit = SessionKeyDeposit;
+	type MaxSessionDuration = MaxSessionDuration;
+	type MaxCallPatterns = ConstU32<8>;
+	type WeightInfo = weights::module_session_keys::WeightInfo<Runtime>;
+}
+
+impl module_preferences::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Preferences = (
+		runtime_common::AlternativeFeeSwapPathPreference<Runtime>,
+		runtime_common::IncentiveClaimPreferences<Runtime>,
+		runtime_common::DustConsolidationPreference<Runtime>,
+		runtime_common::SessionKeyPreferences<Runtime>,
+	);
+	type MaxPreferenceEntries = ConstU32<32>;
+	type WeightInfo = weights::module_preferences::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const RefundSweepGracePeriod: BlockNumber = DAYS;
+	pub RefundDustThreshold: Balance = dollar(GetStableCurrencyId::get());
+}
+
 impl module_emergency_shutdown::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
@@ -1192,6 +1536,12 @@ impl module_emergency_shutdown::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRootOrHalfGeneralCouncil;
+	type StableCurrencyHolders = module_emergency_shutdown::TokensStableCurrencyHolders<Runtime, GetStableCurrencyId>;
+	type RefundSweepGracePeriod = RefundSweepGracePeriod;
+	type RefundDustThreshold = RefundDustThreshold;
+	type TreasuryAccount = TreasuryAccount;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
 
@@ -1205,6 +1555,12 @@ parameter_types! {
 	];
 	pub const ExtendedProvisioningBlocks: BlockNumber = 2 * DAYS;
 	pub const TradingPathLimit: u32 = 4;
+	pub MinSwapCommitDeposit: Balance = 10 * dollar(ACA);
+	pub const SwapRevealPeriod: BlockNumber = 5 * MINUTES;
+	pub const ExpiredSwapCommitPenalty: Percent = Percent::from_percent(10);
+	pub const MaxSwapCommitsPerBlock: u32 = 50;
+	pub const LbpCheckpointPeriod: BlockNumber = HOURS;
+	pub const MaxLbpPools: u32 = 10;
 	pub AlternativeSwapPathJointList: Vec<Vec<CurrencyId>> = vec![
 		vec![GetStakingCurrencyId::get()],
 		vec![GetStableCurrencyId::get()],
@@ -1224,15 +1580,37 @@ impl module_dex::Config for Runtime {
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
-	type OnLiquidityPoolUpdated = ();
+	type OnLiquidityPoolUpdated = StateDiffHooks;
+	type OnTradingPairEnabled = (DexOracle, Incentives);
+	type OnTradingPairDisabled = (DexOracle, Incentives);
+	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
+	type FlashSwapEvmBridge = module_evm_bridge::FlashSwapEvmBridge<Runtime>;
+	type GasToWeight = GasToWeight;
+	type TreasuryAccount = TreasuryAccount;
+	type MinSwapCommitDeposit = MinSwapCommitDeposit;
+	type SwapRevealPeriod = SwapRevealPeriod;
+	type ExpiredSwapCommitPenalty = ExpiredSwapCommitPenalty;
+	type MaxSwapCommitsPerBlock = MaxSwapCommitsPerBlock;
+	type LbpCheckpointPeriod = LbpCheckpointPeriod;
+	type MaxLbpPools = MaxLbpPools;
+}
+
+parameter_types! {
+	pub DepositPerSwapRecord: Balance = deposit(0, 100);
 }
 
 impl module_aggregated_dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type DEX = Dex;
 	type StableAsset = RebasedStableAsset;
 	type GovernanceOrigin = EnsureRootOrHalfGeneralCouncil;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type DEXExchangeFee = GetExchangeFee;
+	type StableAssetFeePrecision = ConstU128<10_000_000_000>;
+	type Currency = Balances;
+	type DepositPerSwapRecord = DepositPerSwapRecord;
+	type MaxSwapHistory = ConstU32<64>;
 	type WeightInfo = weights::module_aggregated_dex::WeightInfo<Runtime>;
 }
 
@@ -1268,10 +1646,13 @@ impl module_cdp_treasury::Config for Runtime {
 	type TreasuryAccount = HonzonTreasuryAccount;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 	type StableAsset = RebasedStableAsset;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type MaxBasketAssets = ConstU32<10>;
 }
 
 impl module_transaction_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
@@ -1282,23 +1663,42 @@ parameter_types! {
 	pub const AlternativeFeeSurplus: Percent = Percent::from_percent(25);
 }
 
-type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
-pub struct DealWithFees;
-impl OnUnbalanced<NegativeImbalance> for DealWithFees {
-	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
-		if let Some(mut fees) = fees_then_tips.next() {
-			if let Some(tips) = fees_then_tips.next() {
-				tips.merge_into(&mut fees);
-			}
-			// for fees and tips, 80% to treasury, 20% to collator-selection pot.
-			let split = fees.ration(80, 20);
-			Treasury::on_unbalanced(split.0);
+parameter_types! {
+	pub CollatorPotAccount: AccountId = CollatorPotId::get().into_account_truncating();
+	pub const DefaultCollatorFeeRatio: Perbill = Perbill::from_percent(20);
+}
+
+pub type DealWithFees = runtime_common::DealWithFees<
+	Runtime,
+	TreasuryAccount,
+	CollatorPotAccount,
+	runtime_common::FeeDistributionRatios<
+		DefaultCollatorFeeRatio,
+		ParameterStoreAdapter<Parameters, runtime_common::FeeParameters>,
+	>,
+>;
+
+parameter_types! {
+	pub const FeeSpendPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxFeeSpendPeriods: u32 = 12;
+}
 
-			Balances::resolve_creating(&CollatorSelection::account_id(), split.1);
-			// Due to performance consideration remove the event.
-			// let numeric_amount = split.1.peek();
-			// let staking_pot = CollatorSelection::account_id();
-			// System::deposit_event(pallet_balances::Event::Deposit(staking_pot, numeric_amount));
+/// Classify the calls for the fee spend tracking by the pallet, the calls batched by utility are
+/// classified by the batched calls.
+pub struct FeeCallClassifier;
+impl ClassifyFeeCall<RuntimeCall> for FeeCallClassifier {
+	fn classify(call: &RuntimeCall) -> FeeCallCategory {
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+			| RuntimeCall::Utility(pallet_utility::Call::batch_all { calls })
+			| RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => Self::classify_batch(calls),
+			RuntimeCall::EVM(_) => FeeCallCategory::Evm,
+			RuntimeCall::Dex(_) | RuntimeCall::AggregatedDex(_) | RuntimeCall::StableAsset(_) => FeeCallCategory::Dex,
+			RuntimeCall::Honzon(_) | RuntimeCall::CdpEngine(_) => FeeCallCategory::Honzon,
+			RuntimeCall::Balances(_) | RuntimeCall::Currencies(_) | RuntimeCall::XTokens(_) => {
+				FeeCallCategory::Transfer
+			}
+			_ => FeeCallCategory::Other,
 		}
 	}
 }
@@ -1329,6 +1729,11 @@ impl module_transaction_payment::Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type FeePayer = DappSessionKeys;
+	type CallClassifier = FeeCallClassifier;
+	type FeeSpendPeriod = FeeSpendPeriod;
+	type MaxFeeSpendPeriods = MaxFeeSpendPeriods;
+	type UnsignedPriority = runtime_common::TransactionPaymentUnsignedPriority;
 }
 
 impl module_earning::Config for Runtime {
@@ -1336,7 +1741,10 @@ impl module_earning::Config for Runtime {
 	type Currency = Balances;
 	type ParameterStore = ParameterStoreAdapter<Parameters, module_earning::Parameters>;
 	type OnBonded = module_incentives::OnEarningBonded<Runtime>;
-	type OnUnbonded = module_incentives::OnEarningUnbonded<Runtime>;
+	type OnUnbonded = (
+		module_incentives::OnEarningUnbonded<Runtime>,
+		runtime_common::CapBoostedVotesOnUnbond<Runtime>,
+	);
 	type OnUnstakeFee = Treasury; // fee goes to treasury
 	type MinBond = ConstU128<100>;
 	type UnbondingPeriod = ConstU32<3>;
@@ -1351,6 +1759,7 @@ impl module_evm_accounts::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
 	type ChainId = EvmChainId<Runtime>;
+	type MaxNonceLanes = ConstU32<16>;
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
@@ -1391,6 +1800,8 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const IncentivesRetirementGracePeriod: BlockNumber = 7 * DAYS;
+	pub IncentivesRetirementSharesThreshold: Balance = 10 * dollar(ACA);
 }
 
 impl module_incentives::Config for Runtime {
@@ -1402,9 +1813,32 @@ impl module_incentives::Config for Runtime {
 	type Currency = Currencies;
 	type EmergencyShutdown = EmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type Randomness = RandomnessSource<Runtime>;
+	type Swap = AcalaSwap;
+	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type RetirementGracePeriod = IncentivesRetirementGracePeriod;
+	type RetirementSharesThreshold = IncentivesRetirementSharesThreshold;
+	type RetireOrigin = EnsureRootOrAllGeneralCouncil;
+	type MaxRetirementRemovals = ConstU32<50>;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const StatisticsPeriod: BlockNumber = DAYS;
+	pub const BlocksPerYear: BlockNumber = 365 * DAYS;
+}
+
+impl module_statistics::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AccumulatePeriod = StatisticsPeriod;
+	type BlocksPerYear = BlocksPerYear;
+	type IndexProvider = runtime_common::YieldIndexes<Runtime, module_prices::RealTimePriceProvider<Runtime>>;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type MaxSources = ConstU32<16>;
+	type MaxCheckpoints = ConstU32<31>;
+	type WeightInfo = weights::module_statistics::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
@@ -1429,6 +1863,8 @@ parameter_types! {
 	pub MintThreshold: Balance = dollar(DOT);
 	pub RedeemThreshold: Balance = 10 * dollar(LDOT);
 	pub const BondingDuration: EraIndex = 28;
+	pub const NominationPoolSubAccountIndex: u16 = 10;
+	pub MaxCommissionRate: Rate = Rate::saturating_from_rational(50, 100);
 }
 
 impl module_homa::Config for Runtime {
@@ -1449,6 +1885,12 @@ impl module_homa::Config for Runtime {
 	type WeightInfo = weights::module_homa::WeightInfo<Runtime>;
 	type NominationsProvider = NomineesElection;
 	type ProcessRedeemRequestsLimit = ConstU32<1_000>;
+	type MaxRedeemRequestBuckets = ConstU32<100>;
+	type MaxRedeemRequestsPerBucket = ConstU32<100>;
+	type OperationGuard = Guardian;
+	type NominationPoolSubAccountIndex = NominationPoolSubAccountIndex;
+	type MaxCommissionRate = MaxCommissionRate;
+	type UnixTime = Timestamp;
 }
 
 parameter_types! {
@@ -1528,6 +1970,9 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = ConstU32<2048>;
+	type MaxAirdropRecipients = ConstU32<1000>;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1649,6 +2094,9 @@ impl pallet_proxy::Config for Runtime {
 
 parameter_types! {
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
+	pub const EvmTransactionIndexRetention: u32 = 7 * DAYS;
+	// Bounds the PoV size taken by the EVM storage writes of a block.
+	pub const EvmDefaultBlockStorageBudget: u32 = 1024 * 1024;
 	pub PrecompilesValue: AllPrecompiles<Runtime, module_transaction_pause::PausedPrecompileFilter<Runtime>, AcalaPrecompiles<Runtime>> = AllPrecompiles::<_, _, _>::mandala();
 }
 
@@ -1726,6 +2174,12 @@ impl module_evm::Config for Runtime {
 	type Randomness = RandomnessSource<Runtime>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type TransactionIndexRetention = EvmTransactionIndexRetention;
+	type TransactionHash = EthereumTransactionHash;
+	type BlockStorageBudget = runtime_common::EvmBlockStorageBudget<
+		EvmDefaultBlockStorageBudget,
+		ParameterStoreAdapter<Parameters, runtime_common::EvmParameters>,
+	>;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 
 	#[cfg(feature = "with-ethereum-compatibility")]
@@ -1845,6 +2299,9 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Runtime>),
+		NftTask(NftTask<Runtime>),
+		EmergencyShutdownTask(EmergencyShutdownTask<Runtime>),
+		CDPEngineTask(CDPEngineTask<Runtime>),
 	}
 }
 
@@ -1884,6 +2341,11 @@ impl module_liquid_crowdloan::Config for Runtime {
 define_aggregrated_parameters! {
 	pub RuntimeParameters = {
 		Earning: module_earning::Parameters = 0,
+		Priority: runtime_common::PriorityParameters = 1,
+		Governance: runtime_common::GovernanceParameters = 2,
+		Evm: runtime_common::EvmParameters = 3,
+		Fee: runtime_common::FeeParameters = 4,
+		Oracle: runtime_common::OracleParameters = 5,
 	}
 }
 
@@ -1921,7 +2383,7 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 					}
 				}
 
-				let (_, _, _, _, mortality, check_nonce, _, _, charge) = extra.clone();
+				let (_, _, _, _, mortality, check_nonce, _, _, charge, _, _, _) = extra.clone();
 
 				if mortality != frame_system::CheckEra::from(sp_runtime::generic::Era::Immortal) {
 					// require immortal
@@ -1931,7 +2393,7 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 				let nonce = check_nonce.nonce;
 				let tip = charge.0;
 
-				extra.5.mark_as_ethereum_tx(valid_until);
+				extra.5.mark_as_ethereum_tx(valid_until, tip);
 
 				Ok((
 					EthereumTransactionMessage {
@@ -1970,7 +2432,7 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 					}
 				}
 
-				let (_, _, _, _, mortality, check_nonce, _, _, charge) = extra.clone();
+				let (_, _, _, _, mortality, check_nonce, _, _, charge, _, _, _) = extra.clone();
 
 				if mortality != frame_system::CheckEra::from(sp_runtime::generic::Era::Immortal) {
 					// require immortal
@@ -1983,7 +2445,9 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 					return Err(InvalidTransaction::BadProof);
 				}
 
-				extra.5.mark_as_ethereum_tx(valid_until);
+				extra.5.mark_as_ethereum_tx(valid_until, tip);
+				// the lane index of accounts using nonce lanes is encoded in the high bits of the nonce
+				extra.5.allow_nonce_lanes();
 
 				let storage_limit = decode_gas_limit(gas_limit).1;
 
@@ -2010,6 +2474,15 @@ impl Convert<(RuntimeCall, SignedExtra), Result<(EthereumTransactionMessage, Sig
 	}
 }
 
+pub struct EthereumTransactionHash;
+impl Convert<Vec<u8>, sp_core::H256> for EthereumTransactionHash {
+	fn convert(extrinsic: Vec<u8>) -> sp_core::H256 {
+		UncheckedExtrinsic::decode_all_with_depth_limit(sp_api::MAX_EXTRINSIC_DEPTH, &mut &*extrinsic)
+			.map(|utx| utx.ethereum_transaction_hash())
+			.unwrap_or_else(|_| sp_io::hashing::keccak_256(&extrinsic).into())
+	}
+}
+
 /// Block header type as expected by this runtime.
 pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
 /// Block type as expected by this runtime.
@@ -2032,6 +2505,9 @@ pub type SignedExtra = (
 	// as fee token.
 	module_evm::SetEvmOrigin<Runtime>,
 	module_transaction_payment::ChargeTransactionPayment<Runtime>,
+	module_oracle_operator::CheckOracleFeed<Runtime>,
+	module_auction_manager::CheckAuctionBid<Runtime>,
+	module_session_keys::CheckSessionKey<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -2040,18 +2516,51 @@ pub type UncheckedExtrinsic =
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+
+parameter_types! {
+	pub const HookMetricsThreshold: Perbill = Perbill::from_percent(10);
+	pub const HookMetricsRetention: u32 = 100;
+}
+
+/// The pallets with their `on_initialize` weight and proof size recorded per block, only with the
+/// `hook-metrics` feature.
+pub type HookMeteredPallets =
+	runtime_common::HookMetered<Runtime, AllPalletsWithSystem, HookMetricsThreshold, HookMetricsRetention>;
+#[cfg(feature = "hook-metrics")]
+type ExecutivePallets = HookMeteredPallets;
+#[cfg(not(feature = "hook-metrics"))]
+type ExecutivePallets = AllPalletsWithSystem;
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
 	Block,
 	frame_system::ChainContext<Runtime>,
 	Runtime,
-	AllPalletsWithSystem,
+	ExecutivePallets,
 	Migrations,
 >;
 
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	module_cdp_engine::AddMaxPriceAgeToCollateralParams<Runtime>,
+	module_cdp_treasury::RecordUntrackedIssuance<Runtime>,
+	module_collator_selection::DefaultRewardDestinationToFreeBalance<Runtime>,
+	module_dex::AddPausedTradingPairStatus<Runtime>,
+	module_loans::IndexPositions<Runtime>,
+);
+
+/// The names of the `Migrations`, reported by the upgrade preflight.
+pub const MIGRATIONS: &[&str] = &[
+	"module_cdp_engine::AddMaxPriceAgeToCollateralParams",
+	"module_cdp_treasury::RecordUntrackedIssuance",
+	"module_collator_selection::DefaultRewardDestinationToFreeBalance",
+	"module_dex::AddPausedTradingPairStatus",
+	"module_loans::IndexPositions",
+];
+
+/// The pallets removed from the runtime, whose leftover storage is reported by the upgrade preflight.
+pub const REMOVED_PALLETS: &[&str] = &[];
 
 construct_runtime!(
 	pub enum Runtime {
@@ -2068,6 +2577,7 @@ construct_runtime!(
 		Currencies: module_currencies = 12,
 		Vesting: orml_vesting = 13,
 		TransactionPayment: module_transaction_payment = 14,
+		VestingConsolidation: module_vesting_consolidation = 15,
 
 		// Treasury
 		Treasury: pallet_treasury = 20,
@@ -2081,6 +2591,8 @@ construct_runtime!(
 		Proxy: pallet_proxy = 33,
 		// NOTE: IdleScheduler must be put before ParachainSystem in order to read relaychain blocknumber
 		IdleScheduler: module_idle_scheduler = 34,
+		SchedulerIndex: module_scheduler_index = 35,
+		AccountRescue: module_account_rescue = 36,
 
 		Indices: pallet_indices = 40,
 
@@ -2102,7 +2614,10 @@ construct_runtime!(
 		//
 		// NOTE: OperatorMembership must be placed after Oracle or else will have race condition on initialization
 		AcalaOracle: orml_oracle::<Instance1> = 80,
+		SecondaryOracle: orml_oracle::<Instance2> = 81,
 		OperatorMembershipAcala: pallet_membership::<Instance5> = 82,
+		OracleOperator: module_oracle_operator = 83,
+		OperatorMembershipSecondary: pallet_membership::<Instance6> = 84,
 
 		// ORML Core
 		Auction: orml_auction = 100,
@@ -2115,6 +2630,7 @@ construct_runtime!(
 		Dex: module_dex = 111,
 		DexOracle: module_dex_oracle = 112,
 		AggregatedDex: module_aggregated_dex = 113,
+		Otc: module_otc = 114,
 
 		// Honzon
 		AuctionManager: module_auction_manager = 120,
@@ -2123,6 +2639,10 @@ construct_runtime!(
 		CdpTreasury: module_cdp_treasury = 123,
 		CdpEngine: module_cdp_engine = 124,
 		EmergencyShutdown: module_emergency_shutdown = 125,
+		Guardian: module_guardian = 126,
+		PegMonitor: module_peg_monitor = 127,
+		SafeMode: module_safe_mode = 128,
+		ReferenceRate: module_reference_rate = 129,
 
 		// Homa
 		NomineesElection: module_nominees_election = 131,
@@ -2135,6 +2655,9 @@ construct_runtime!(
 		NFT: module_nft = 141,
 		AssetRegistry: module_asset_registry = 142,
 		LiquidCrowdloan: module_liquid_crowdloan = 143,
+		Statistics: module_statistics = 144,
+		DappSessionKeys: module_session_keys = 145,
+		Preferences: module_preferences = 146,
 
 		// Parachain
 		ParachainInfo: parachain_info exclude_parts { Call } = 161,
@@ -2173,10 +2696,33 @@ construct_runtime!(
 		ParachainSystem: cumulus_pallet_parachain_system = 160,
 
 		// Dev
+		#[cfg(feature = "debug-error-events")]
+		ErrorRegistry: module_error_registry = 252,
+		#[cfg(feature = "state-diff")]
+		StateDiff: module_state_diff = 251,
+		Faucet: module_faucet = 253,
+		#[cfg(feature = "dev-setup")]
+		DevSetup: module_dev_setup = 254,
 		Sudo: pallet_sudo = 255,
 	}
 );
 
+/// The error types of the pallets of the runtime, read from the metadata.
+pub struct RuntimePalletErrors;
+impl module_error_registry::PalletErrors for RuntimePalletErrors {
+	fn pallet_errors() -> Vec<module_error_registry::PalletErrorMetadata> {
+		Runtime::metadata_ir()
+			.pallets
+			.into_iter()
+			.map(|pallet| module_error_registry::PalletErrorMetadata {
+				name: pallet.name,
+				index: pallet.index,
+				ty: pallet.error.map(|error| error.ty),
+			})
+			.collect()
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate orml_benchmarking;
@@ -2195,9 +2741,18 @@ mod benches {
 		[module_homa, benchmarking::homa]
 		[module_homa_validator_list, benchmarking::homa_validator_list]
 		[module_honzon, benchmarking::honzon]
+		[module_guardian, benchmarking::guardian]
+		[module_peg_monitor, benchmarking::peg_monitor]
+		[module_reference_rate, benchmarking::reference_rate]
+		[module_safe_mode, benchmarking::safe_mode]
+		[module_session_keys, benchmarking::session_keys]
+		[module_statistics, benchmarking::statistics]
+		[module_preferences, benchmarking::preferences]
+		[module_faucet, benchmarking::faucet]
 		[module_cdp_treasury, benchmarking::cdp_treasury]
 		[module_collator_selection, benchmarking::collator_selection]
 		[module_nominees_election, benchmarking::nominees_election]
+		[module_oracle_operator, benchmarking::oracle_operator]
 		[module_transaction_pause, benchmarking::transaction_pause]
 		[module_transaction_payment, benchmarking::transaction_payment]
 		[module_incentives, benchmarking::incentives]
@@ -2208,10 +2763,14 @@ mod benches {
 		[module_liquid_crowdloan, benchmarking::liquid_crowdloan]
 		[orml_tokens, benchmarking::tokens]
 		[orml_vesting, benchmarking::vesting]
+		[module_vesting_consolidation, benchmarking::vesting_consolidation]
+		[module_account_rescue, benchmarking::account_rescue]
+		[module_otc, benchmarking::otc]
 		[orml_auction, benchmarking::auction]
 		[orml_authority, benchmarking::authority]
 		[nutsfinance_stable_asset, benchmarking::nutsfinance_stable_asset]
 		[module_idle_scheduler, benchmarking::idle_scheduler]
+		[module_scheduler_index, benchmarking::scheduler_index]
 		[module_aggregated_dex, benchmarking::aggregated_dex]
 	);
 	// frame_benchmarking::define_benchmarks!(
@@ -2341,6 +2900,7 @@ impl_runtime_apis! {
 		fn get_value(provider_id: DataProviderId ,key: CurrencyId) -> Option<TimeStampedPrice> {
 			match provider_id {
 				DataProviderId::Acala => AcalaOracle::get_no_op(&key),
+				DataProviderId::Secondary => SecondaryOracle::get_no_op(&key),
 				DataProviderId::Aggregated => <AggregatedDataProvider as DataProviderExtended<_, _>>::get_no_op(&key)
 			}
 		}
@@ -2348,11 +2908,28 @@ impl_runtime_apis! {
 		fn get_all_values(provider_id: DataProviderId) -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
 			match provider_id {
 				DataProviderId::Acala => AcalaOracle::get_all_values(),
+				DataProviderId::Secondary => SecondaryOracle::get_all_values(),
 				DataProviderId::Aggregated => <AggregatedDataProvider as DataProviderExtended<_, _>>::get_all_values()
 			}
 		}
 	}
 
+	impl module_oracle_operator_runtime_api::OracleQualityApi<
+		Block,
+		DataProviderId,
+		CurrencyId,
+		runtime_common::PriceQuality,
+	> for Runtime {
+		fn get_value_with_quality(provider_id: DataProviderId, key: CurrencyId) -> Option<runtime_common::PriceQuality> {
+			match provider_id {
+				DataProviderId::Acala => runtime_common::OraclePriceQuality::<Runtime, AcalaDataProvider, ExpiresIn>::get(&key),
+				DataProviderId::Secondary => runtime_common::OraclePriceQuality::<Runtime, SecondaryDataProvider, ExpiresIn>::get(&key),
+				// the aggregated prices are not combined from raw values
+				DataProviderId::Aggregated => None,
+			}
+		}
+	}
+
 	impl orml_tokens_runtime_api::TokensApi<
 		Block,
 		CurrencyId,
@@ -2378,6 +2955,323 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_currencies_runtime_api::ReservesApi<Block, AccountId, Balance> for Runtime {
+		fn get_reserve_breakdown(who: AccountId) -> Vec<module_currencies_runtime_api::ReserveEntry<Balance>> {
+			reserves::get_reserve_breakdown(who)
+		}
+
+		fn get_deposit_constants() -> Vec<module_currencies_runtime_api::DepositConstant<Balance>> {
+			reserves::get_deposit_constants()
+		}
+	}
+
+	impl module_asset_registry_runtime_api::AssetRegistryApi<Block, Balance> for Runtime {
+		fn get_asset_metadata(currency_id: CurrencyId) -> Option<primitives::currency::AssetMetadata<Balance>> {
+			AssetRegistry::asset_metadata(currency_id)
+		}
+
+		fn get_all_assets(
+			cursor: Option<AssetIds>,
+			limit: u32,
+		) -> (Vec<(CurrencyId, primitives::currency::AssetMetadata<Balance>)>, Option<AssetIds>) {
+			AssetRegistry::all_assets(cursor, limit)
+		}
+
+		fn get_canonical_asset(symbol: Vec<u8>) -> Option<CurrencyId> {
+			AssetRegistry::canonical_asset(&symbol)
+		}
+	}
+
+	impl module_collator_selection_runtime_api::CollatorSelectionApi<Block, AccountId, Balance> for Runtime {
+		fn expected_session_reward() -> Balance {
+			CollatorSelection::expected_session_reward()
+		}
+
+		fn expected_collator_reward(who: AccountId) -> Balance {
+			CollatorSelection::expected_collator_reward(&who)
+		}
+	}
+
+	impl module_transaction_payment_runtime_api::FeeSwapPathApi<Block, AccountId> for Runtime {
+		fn get_effective_fee_path(account: AccountId) -> Option<Vec<CurrencyId>> {
+			TransactionPayment::get_effective_fee_path(&account)
+		}
+	}
+
+	impl module_transaction_payment_runtime_api::FeeSpendApi<Block, AccountId> for Runtime {
+		fn get_fee_summary(account: AccountId, period: u32) -> Vec<module_transaction_payment_runtime_api::FeeSpend> {
+			TransactionPayment::get_fee_summary(&account, period)
+		}
+	}
+
+	impl module_nft_runtime_api::NftApi<
+		Block,
+		AccountId,
+		u32,
+		u64,
+		module_nft::ClassDetailsOf<Runtime>,
+		module_nft::TokenDetailsOf<Runtime>,
+	> for Runtime {
+		fn get_class_info(class_id: u32) -> Option<module_nft::ClassDetailsOf<Runtime>> {
+			NFT::get_class_info(class_id)
+		}
+
+		fn get_token(class_id: u32, token_id: u64) -> Option<module_nft::TokenDetailsOf<Runtime>> {
+			NFT::get_token(class_id, token_id)
+		}
+
+		fn get_tokens_by_class(
+			class_id: u32,
+			cursor: Option<u64>,
+			limit: u32,
+			filter: Option<(Vec<u8>, Vec<u8>)>,
+		) -> (Vec<module_nft::TokenDetailsOf<Runtime>>, Option<u64>) {
+			NFT::get_tokens_by_class(class_id, cursor, limit, filter)
+		}
+
+		fn get_tokens_by_owner(
+			account: AccountId,
+			cursor: Option<(u32, u64)>,
+			limit: u32,
+			filter: Option<(Vec<u8>, Vec<u8>)>,
+		) -> (Vec<module_nft::TokenDetailsOf<Runtime>>, Option<(u32, u64)>) {
+			NFT::get_tokens_by_owner(&account, cursor, limit, filter)
+		}
+	}
+
+	impl module_aggregated_dex_runtime_api::AggregatedDexApi<
+		Block,
+		AccountId,
+		module_aggregated_dex::SwapRecord<BlockNumber>,
+	> for Runtime {
+		fn get_swap_history(account: AccountId) -> Vec<module_aggregated_dex::SwapRecord<BlockNumber>> {
+			AggregatedDex::get_swap_history(&account)
+		}
+	}
+
+	impl module_xcm_interface_runtime_api::XcmFeeApi<Block, CurrencyId, Balance> for Runtime {
+		fn estimate_transfer_fee(
+			currency_id: CurrencyId,
+			amount: Balance,
+			dest: xcm::VersionedLocation,
+		) -> Option<module_xcm_interface_runtime_api::TransferFeeBreakdown<Balance>> {
+			runtime_common::xcm_impl::estimate_transfer_fee::<Runtime, xcm_config::XcmConfig>(
+				currency_id,
+				amount,
+				dest.try_into().ok()?,
+			)
+		}
+	}
+
+	impl module_auction_manager_runtime_api::AuctionManagerApi<
+		Block,
+		AuctionId,
+		module_auction_manager::AuctionInfoView<AccountId, BlockNumber>,
+	> for Runtime {
+		fn get_auction_info(auction_id: AuctionId) -> Option<module_auction_manager::AuctionInfoView<AccountId, BlockNumber>> {
+			AuctionManager::get_auction_info(auction_id)
+		}
+
+		fn get_active_auctions(
+			cursor: Option<AuctionId>,
+			limit: u32,
+		) -> (Vec<module_auction_manager::AuctionInfoView<AccountId, BlockNumber>>, Option<AuctionId>) {
+			AuctionManager::get_active_auctions(cursor, limit)
+		}
+	}
+
+	impl module_cdp_treasury_runtime_api::CdpTreasuryApi<Block> for Runtime {
+		fn get_issuance_breakdown() -> module_cdp_treasury_runtime_api::IssuanceBreakdown {
+			CdpTreasury::get_issuance_breakdown()
+		}
+
+		fn get_diversification_progress() -> Option<module_cdp_treasury_runtime_api::DiversificationProgress> {
+			CdpTreasury::get_diversification_progress()
+		}
+	}
+
+	impl module_homa_runtime_api::HomaApi<Block, BlockNumber> for Runtime {
+		fn get_staking_ledgers() -> Vec<(u16, module_homa_runtime_api::StakingLedgerView)> {
+			Homa::get_staking_ledgers()
+		}
+
+		fn get_pending_operations() -> Vec<module_homa_runtime_api::PendingOperationView> {
+			Homa::get_pending_operations().unwrap_or_default()
+		}
+
+		fn get_last_era_bump_info() -> Option<module_homa_runtime_api::EraBumpInfo<BlockNumber>> {
+			Homa::last_era_bump()
+		}
+	}
+
+	impl module_honzon_runtime_api::HonzonSnapshotApi<Block, AccountId, BlockNumber> for Runtime {
+		fn get_honzon_snapshot(
+			cursor: Option<module_honzon_runtime_api::SnapshotCursor<AccountId>>,
+			limit: u32,
+		) -> module_honzon_runtime_api::HonzonSnapshotPage<AccountId, BlockNumber> {
+			Honzon::get_honzon_snapshot(cursor, limit)
+		}
+	}
+
+	impl module_honzon_runtime_api::LiquidationHistoryApi<Block, AccountId, BlockNumber> for Runtime {
+		fn get_liquidation_history(
+			account: AccountId,
+		) -> (
+			module_honzon_runtime_api::LiquidationStats,
+			Vec<module_honzon_runtime_api::LiquidationRecord<CurrencyId, BlockNumber>>,
+		) {
+			CdpEngine::get_liquidation_history(&account)
+		}
+	}
+
+	impl module_honzon_runtime_api::RiskSnapshotApi<Block, BlockNumber> for Runtime {
+		fn get_risk_snapshot(currency_id: CurrencyId) -> Option<module_honzon_runtime_api::RiskSnapshot<BlockNumber>> {
+			CdpEngine::risk_snapshots(currency_id)
+		}
+	}
+
+	impl module_evm_accounts_runtime_api::EvmAccountsApi<Block, AccountId> for Runtime {
+		fn resolve(
+			targets: Vec<module_evm_accounts_runtime_api::AddressOrAccount<AccountId>>,
+		) -> Vec<module_evm_accounts_runtime_api::MappingInfo<AccountId>> {
+			EvmAccounts::resolve(targets, runtime_common::evm_account_state::<Runtime>)
+		}
+
+		fn get_bound_accounts(
+			cursor: Option<H160>,
+			limit: u32,
+		) -> module_evm_accounts_runtime_api::BoundAccountsPage<AccountId> {
+			EvmAccounts::get_bound_accounts(cursor, limit, runtime_common::evm_account_state::<Runtime>)
+		}
+	}
+
+	impl module_scheduler_index_runtime_api::SchedulerIndexApi<Block, BlockNumber, OriginCaller, Hash> for Runtime {
+		fn pending_scheduled_calls(
+		) -> Vec<module_scheduler_index_runtime_api::ScheduledCallInfo<BlockNumber, OriginCaller, Hash>> {
+			SchedulerIndex::pending_scheduled_calls()
+		}
+	}
+
+	impl module_peg_monitor_runtime_api::PegMonitorApi<Block, module_support::PegStatus<BlockNumber>> for Runtime {
+		fn get_peg_status() -> Option<module_support::PegStatus<BlockNumber>> {
+			PegMonitor::get_peg_status()
+		}
+	}
+
+	impl module_reference_rate_runtime_api::ReferenceRateApi<Block, module_support::ReferenceRate<BlockNumber>> for Runtime {
+		fn get_reference_rate() -> Option<module_support::ReferenceRate<BlockNumber>> {
+			ReferenceRate::get_reference_rate()
+		}
+	}
+
+	impl module_statistics_runtime_api::StatisticsApi<Block, module_statistics::YieldSource, BlockNumber, Rate> for Runtime {
+		fn get_apy(source: module_statistics::YieldSource, window: BlockNumber) -> Option<Rate> {
+			Statistics::get_apy(source, window)
+		}
+	}
+
+	impl module_statistics_runtime_api::ProtocolStatsApi<
+		Block,
+		runtime_common::TvlSource,
+		CurrencyId,
+		Balance,
+		BlockNumber,
+		runtime_common::ProtocolRevenue,
+	> for Runtime {
+		fn get_tvl() -> Vec<(runtime_common::TvlSource, CurrencyId, Balance, Option<Balance>)> {
+			runtime_common::ProtocolStats::<Runtime, StableAsset, module_prices::RealTimePriceProvider<Runtime>>::get_tvl()
+		}
+
+		fn get_protocol_revenue(period: BlockNumber) -> runtime_common::ProtocolRevenue {
+			runtime_common::ProtocolStats::<Runtime, StableAsset, module_prices::RealTimePriceProvider<Runtime>>::get_protocol_revenue(period)
+		}
+	}
+
+	impl module_preferences_runtime_api::PreferencesApi<
+		Block,
+		AccountId,
+		module_preferences::PreferencesBlob<AccountId, BlockNumber>,
+	> for Runtime {
+		fn export_preferences(who: AccountId) -> module_preferences::PreferencesBlob<AccountId, BlockNumber> {
+			Preferences::export_preferences(&who)
+		}
+	}
+
+	impl module_faucet_runtime_api::FaucetApi<Block, AccountId, BlockNumber> for Runtime {
+		fn next_request_at(who: AccountId) -> Option<BlockNumber> {
+			Faucet::next_request_at(&who)
+		}
+	}
+
+	impl module_error_registry_runtime_api::ErrorRegistryApi<Block> for Runtime {
+		fn decode_module_error(index: u8, error: [u8; 4]) -> Option<module_error_registry::ErrorInfo> {
+			module_error_registry::decode_module_error::<RuntimePalletErrors>(index, error)
+		}
+
+		fn decode_dispatch_error(error: sp_runtime::DispatchError) -> Vec<u8> {
+			module_error_registry::decode_dispatch_error::<RuntimePalletErrors>(error)
+		}
+	}
+
+	impl module_state_diff_runtime_api::StateDiffApi<Block, AccountId, BlockNumber> for Runtime {
+		fn get_block_changes(block: BlockNumber) -> Option<module_state_diff::BlockChanges<AccountId>> {
+			#[cfg(feature = "state-diff")]
+			let changes = StateDiff::block_changes(block);
+			#[cfg(not(feature = "state-diff"))]
+			let changes = {
+				let _ = block;
+				None
+			};
+			changes
+		}
+	}
+
+	impl runtime_common::HookMetricsApi<Block> for Runtime {
+		fn get_hook_metrics(last_n_blocks: u32) -> Vec<runtime_common::BlockHookMetrics> {
+			HookMeteredPallets::hook_metrics(last_n_blocks)
+		}
+	}
+
+	impl runtime_common::CallPermissionApi<Block, AccountId, RuntimeCall, ProxyType> for Runtime {
+		fn can_account_call(
+			account: AccountId,
+			call: RuntimeCall,
+			via_proxy: Option<(AccountId, ProxyType)>,
+		) -> runtime_common::CallPermission {
+			runtime_common::call_permission::can_account_call::<Runtime, BaseCallFilter>(&account, &call, via_proxy)
+		}
+	}
+
+	impl runtime_common::SystemInfoApi<Block, Hash, BlockNumber> for Runtime {
+		fn get_chain_status() -> runtime_common::ChainStatus<Hash, BlockNumber> {
+			runtime_common::system_info::get_chain_status::<Runtime, SafeMode>()
+		}
+	}
+
+	impl module_upgrade_preflight_runtime_api::UpgradePreflightApi<Block> for Runtime {
+		fn upgrade_preflight() -> module_upgrade_preflight::PreflightReport {
+			module_upgrade_preflight::preflight::<AllPalletsWithSystem>(
+				MIGRATIONS,
+				REMOVED_PALLETS,
+				vec![(b"Dex::TransitionalTradingPairs".to_vec(), Dex::transitional_trading_pairs())],
+			)
+		}
+	}
+
+	impl module_transaction_pause_runtime_api::TransactionPauseApi<Block, RuntimeCall> for Runtime {
+		fn get_paused_calls() -> Vec<(Vec<u8>, Vec<u8>)> {
+			TransactionPause::get_paused_calls()
+		}
+
+		fn is_call_paused(call: RuntimeCall) -> bool {
+			module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(&call)
+		}
+
+		fn get_paused_evm_precompiles() -> Vec<H160> {
+			TransactionPause::get_paused_evm_precompiles()
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance, AccountId> for Runtime {
 		fn block_limits() -> BlockLimits {
 			BlockLimits {
@@ -2494,6 +3388,45 @@ impl_runtime_apis! {
 
 			Self::create(from, data, value, gas_limit, storage_limit, access_list, estimate)
 		}
+
+		fn get_block_tx_index(block_number: BlockNumber) -> Option<Vec<EvmTransactionIndex<AccountId>>> {
+			EVM::get_block_tx_index(block_number)
+		}
+
+		fn get_contract_purge_progress(contract: H160) -> Option<ContractPurgeProgress<Balance>> {
+			EVM::contract_purges(contract)
+		}
+
+		fn get_system_contract(key: Vec<u8>) -> Option<H160> {
+			EVM::get_system_contract(&key)
+		}
+
+		fn batch_call(
+			requests: Vec<EthCallRequest<Balance>>,
+		) -> Result<Vec<Result<CallInfo, sp_runtime::DispatchError>>, sp_runtime::DispatchError> {
+			<Runtime as module_evm::Config>::Runner::rpc_batch_call(
+				requests,
+				runtime_common::EvmLimits::<Runtime>::max_gas_limit(),
+				<Runtime as module_evm::Config>::config(),
+			)
+		}
+
+		fn estimate_resources_v2(
+			request: EstimateResourcesRequest,
+		) -> Result<EstimateResourcesResponse<Balance>, sp_runtime::DispatchError> {
+			let max_gas_limit = runtime_common::EvmLimits::<Runtime>::max_gas_limit();
+			let max_storage_limit = runtime_common::EvmLimits::<Runtime>::max_storage_limit();
+			<Runtime as module_evm::Config>::Runner::rpc_estimate_resources(
+				request.from.unwrap_or_default(),
+				request.to,
+				request.data.unwrap_or_default(),
+				request.value.unwrap_or_default(),
+				request.gas_limit.map_or(max_gas_limit, |gas_limit| gas_limit.min(max_gas_limit)),
+				request.storage_limit.map_or(max_storage_limit, |storage_limit| storage_limit.min(max_storage_limit)),
+				request.access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+				<Runtime as module_evm::Config>::config(),
+			)
+		}
 	}
 
 	#[cfg(feature = "tracing")]
@@ -2691,7 +3624,10 @@ cumulus_pallet_parachain_system::register_validate_block!(
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::{dispatch::DispatchInfo, traits::WhitelistedStorageKeys};
+	use frame_support::{
+		dispatch::{DispatchInfo, GetDispatchInfo},
+		traits::WhitelistedStorageKeys,
+	};
 	use frame_system::offchain::CreateSignedTransaction;
 	use sp_core::hexdisplay::HexDisplay;
 	use sp_runtime::traits::SignedExtension;
@@ -2753,6 +3689,13 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn check_exchange_fee_bounds() {
+		// DEX exchange fee has no setter, keep the constant within (0, 1%].
+		let (numerator, denominator) = GetExchangeFee::get();
+		assert!(numerator > 0 && numerator.saturating_mul(100) <= denominator);
+	}
+
 	#[test]
 	fn check_call_size() {
 		assert!(
@@ -2763,6 +3706,88 @@ mod tests {
 		);
 	}
 
+	/// Mirrors the replacement rule of the tx pool: a tx replaces the pending tx which provides the
+	/// same tag if it has a strictly higher priority.
+	fn can_replace(
+		pending: &sp_runtime::transaction_validity::ValidTransaction,
+		new: &sp_runtime::transaction_validity::ValidTransaction,
+	) -> bool {
+		new.provides.iter().any(|tag| pending.provides.contains(tag)) && new.priority > pending.priority
+	}
+
+	#[test]
+	fn speed_up_eth_tx_replaces_pending_tx() {
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			let alice: AccountId = sp_runtime::AccountId32::from([8; 32]);
+			<Balances as frame_support::traits::Currency<AccountId>>::make_free_balance_be(
+				&alice,
+				1_000_000 * dollar(ACA),
+			);
+
+			const TEN_GWEI: u64 = 10_000_000_000u64;
+			let valid_until = 30u64;
+			let validate = |tip_number: u64| {
+				// gas_price = 100 Gwei + tip_number * 10 Gwei, valid_until is encoded in the low part
+				let call = RuntimeCall::EVM(module_evm::Call::eth_call_v2 {
+					action: module_evm::TransactionAction::Create,
+					input: vec![0x01],
+					value: 0,
+					gas_price: (10 + tip_number) * TEN_GWEI + valid_until,
+					gas_limit: 2_100_000,
+					access_list: vec![],
+				});
+				let (tip, _) = decode_gas_price(
+					(10 + tip_number) * TEN_GWEI + valid_until,
+					2_100_000,
+					TxFeePerGasV2::get(),
+				)
+				.unwrap();
+
+				let extra: SignedExtra = (
+					frame_system::CheckNonZeroSender::<Runtime>::new(),
+					frame_system::CheckSpecVersion::<Runtime>::new(),
+					frame_system::CheckTxVersion::<Runtime>::new(),
+					frame_system::CheckGenesis::<Runtime>::new(),
+					frame_system::CheckEra::<Runtime>::from(generic::Era::Immortal),
+					runtime_common::CheckNonce::<Runtime>::from(0),
+					frame_system::CheckWeight::<Runtime>::new(),
+					module_evm::SetEvmOrigin::<Runtime>::new(),
+					module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+					module_oracle_operator::CheckOracleFeed::<Runtime>::new(),
+					module_auction_manager::CheckAuctionBid::<Runtime>::new(),
+					module_session_keys::CheckSessionKey::<Runtime>::new(),
+				);
+				let (_, extra) = ConvertEthereumTx::convert((call.clone(), extra)).unwrap();
+
+				let info = call.get_dispatch_info();
+				let len = call.encoded_size();
+				extra.validate(&alice, &call, &info, len).unwrap()
+			};
+
+			// the pending tx with 100% tip
+			let pending = validate(10);
+			assert_eq!(pending.longevity, valid_until - u64::from(System::block_number()));
+
+			// resubmitting the same tx doesn't replace the pending one
+			assert!(!can_replace(&pending, &validate(10)));
+			// lower tip doesn't replace the pending one
+			assert!(!can_replace(&pending, &validate(9)));
+
+			// speed up with 10% higher tip
+			let replacement = validate(11);
+			assert_eq!(replacement.provides, pending.provides);
+			assert_eq!(replacement.longevity, pending.longevity);
+			assert!(can_replace(&pending, &replacement));
+
+			// speed up again
+			assert!(can_replace(&replacement, &validate(13)));
+		});
+	}
+
 	#[test]
 	fn convert_tx_check_evm_nonce() {
 		sp_io::TestExternalities::new_empty().execute_with(|| {
@@ -2801,10 +3826,13 @@ mod tests {
 				frame_system::CheckWeight::<Runtime>::new(),
 				module_evm::SetEvmOrigin::<Runtime>::new(),
 				module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+				module_oracle_operator::CheckOracleFeed::<Runtime>::new(),
+				module_auction_manager::CheckAuctionBid::<Runtime>::new(),
+				module_session_keys::CheckSessionKey::<Runtime>::new(),
 			);
 
 			let mut expected_extra = extra.clone();
-			expected_extra.5.mark_as_ethereum_tx(30);
+			expected_extra.5.mark_as_ethereum_tx(30, 0);
 
 			assert_eq!(
 				ConvertEthereumTx::convert((call.clone(), extra.clone())).unwrap(),
@@ -0,0 +1,378 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{AccountId, Balance, CurrencyId, GetNativeCurrencyId, Runtime};
+use frame_support::traits::{Get, ReservableCurrency};
+use module_currencies_runtime_api::{DepositConstant, ReserveEntry};
+use module_support::AddressMapping;
+use parity_scale_codec::Encode;
+use primitives::{currency::TokenInfo, evm::convert_decimals_from_evm, ReserveIdentifier};
+use scale_info::prelude::{format, string::String};
+use sp_core::hexdisplay::HexDisplay;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::prelude::*;
+
+fn entry(
+	identifier: Option<ReserveIdentifier>,
+	currency_id: CurrencyId,
+	amount: Balance,
+	tag: String,
+) -> ReserveEntry<Balance> {
+	ReserveEntry {
+		identifier,
+		currency_id,
+		amount,
+		tag: tag.into_bytes(),
+	}
+}
+
+fn hex_tag(bytes: &[u8]) -> String {
+	format!("0x{}", HexDisplay::from(&bytes))
+}
+
+fn currency_tag(currency_id: &CurrencyId) -> String {
+	match currency_id.symbol() {
+		Some(symbol) => symbol.into(),
+		None => hex_tag(&currency_id.encode()),
+	}
+}
+
+fn feature_tag(id: ReserveIdentifier) -> &'static str {
+	match id {
+		ReserveIdentifier::CollatorSelection => "collator candidacy bond",
+		ReserveIdentifier::EvmStorageDeposit => "evm storage deposit",
+		ReserveIdentifier::EvmDeveloperDeposit => "evm developer deposit",
+		ReserveIdentifier::Honzon => "honzon authorization",
+		ReserveIdentifier::Nft => "nft deposit",
+		ReserveIdentifier::TransactionPayment => "transaction fee",
+		ReserveIdentifier::TransactionPaymentDeposit => "alternative fee swap path deposit",
+		ReserveIdentifier::PublicReferenda => "public referenda deposit",
+		ReserveIdentifier::OracleOperator => "oracle operator bond",
+		ReserveIdentifier::Count => "unknown",
+	}
+}
+
+/// The entries of the module storages explaining the native named reserve `id` of `who`.
+fn explain_named_reserve(id: ReserveIdentifier, who: &AccountId, amount: Balance) -> Vec<ReserveEntry<Balance>> {
+	let native = GetNativeCurrencyId::get();
+	match id {
+		ReserveIdentifier::Honzon => module_honzon::Authorization::<Runtime>::iter_prefix(who)
+			.map(|((currency_id, authorizee), deposit)| {
+				let tag = format!(
+					"honzon authorization: {} to {}",
+					currency_tag(&currency_id),
+					hex_tag(authorizee.as_ref())
+				);
+				entry(Some(id), native, deposit, tag)
+			})
+			.collect(),
+		ReserveIdentifier::Nft => {
+			// the class deposit is reserved by the owner account of the class
+			let classes = orml_nft::Classes::<Runtime>::iter()
+				.filter(|(_, class_info)| class_info.owner == *who)
+				.map(|(class_id, class_info)| {
+					entry(
+						Some(id),
+						native,
+						class_info.data.deposit,
+						format!("nft class: {}", class_id),
+					)
+				});
+			let tokens =
+				orml_nft::TokensByOwner::<Runtime>::iter_prefix((who,)).filter_map(|((class_id, token_id), _)| {
+					orml_nft::Tokens::<Runtime>::get(class_id, token_id).map(|token_info| {
+						entry(
+							Some(id),
+							native,
+							token_info.data.deposit,
+							format!("nft token: {}/{}", class_id, token_id),
+						)
+					})
+				});
+			classes.chain(tokens).collect()
+		}
+		ReserveIdentifier::TransactionPaymentDeposit => {
+			module_transaction_payment::AlternativeFeeSwapPath::<Runtime>::get(who)
+				.map(|path| {
+					let path = path.iter().map(currency_tag).collect::<Vec<_>>().join(" -> ");
+					vec![entry(
+						Some(id),
+						native,
+						amount,
+						format!("alternative fee swap path: {}", path),
+					)]
+				})
+				.unwrap_or_default()
+		}
+		ReserveIdentifier::EvmStorageDeposit => <Runtime as module_evm::Config>::AddressMapping::get_evm_address(who)
+			.filter(module_evm::Pallet::<Runtime>::is_contract)
+			.map(|address| {
+				let tag = format!(
+					"evm contract storage: {} bytes of {}",
+					module_evm::ContractStorageSizes::<Runtime>::get(address),
+					hex_tag(address.as_bytes())
+				);
+				vec![entry(Some(id), native, amount, tag)]
+			})
+			.unwrap_or_default(),
+		_ => vec![],
+	}
+}
+
+/// Pushes the `explained` entries of the reserve `amount`, and an entry of the part of `amount`
+/// not explained by any of them.
+fn push_explained(
+	entries: &mut Vec<ReserveEntry<Balance>>,
+	identifier: Option<ReserveIdentifier>,
+	currency_id: CurrencyId,
+	amount: Balance,
+	explained: Vec<ReserveEntry<Balance>>,
+	tag: String,
+) {
+	let explained_amount = explained
+		.iter()
+		.fold(Balance::zero(), |total, entry| total.saturating_add(entry.amount));
+	entries.extend(explained);
+	let unexplained = amount.saturating_sub(explained_amount);
+	if !unexplained.is_zero() {
+		entries.push(entry(identifier, currency_id, unexplained, tag));
+	}
+}
+
+/// The reserved balances of `who`, explained by the features reserving them.
+///
+/// Every named reserve is covered by the entries of its identifier. The anonymous reserves of the
+/// native currency are explained by the deposits of proxies and announcements, the rest of them
+/// are mostly the deposits of multisigs, which can't be looked up by the depositor.
+pub fn get_reserve_breakdown(who: AccountId) -> Vec<ReserveEntry<Balance>> {
+	let native = GetNativeCurrencyId::get();
+	let mut entries = vec![];
+
+	let mut named_total = Balance::zero();
+	for reserve in pallet_balances::Reserves::<Runtime>::get(&who) {
+		named_total = named_total.saturating_add(reserve.amount);
+		let explained = explain_named_reserve(reserve.id, &who, reserve.amount);
+		let tag = feature_tag(reserve.id).into();
+		push_explained(&mut entries, Some(reserve.id), native, reserve.amount, explained, tag);
+	}
+
+	for (currency_id, reserves) in orml_tokens::Reserves::<Runtime>::iter_prefix(&who) {
+		for reserve in reserves {
+			let tag = format!("{}: {}", feature_tag(reserve.id), currency_tag(&currency_id));
+			entries.push(entry(Some(reserve.id), currency_id, reserve.amount, tag));
+		}
+	}
+
+	let mut anonymous = vec![];
+	let (_, proxy_deposit) = pallet_proxy::Proxies::<Runtime>::get(&who);
+	if !proxy_deposit.is_zero() {
+		anonymous.push(entry(None, native, proxy_deposit, "proxy deposit".into()));
+	}
+	let (_, announcement_deposit) = pallet_proxy::Announcements::<Runtime>::get(&who);
+	if !announcement_deposit.is_zero() {
+		anonymous.push(entry(
+			None,
+			native,
+			announcement_deposit,
+			"proxy announcement deposit".into(),
+		));
+	}
+	let anonymous_total = pallet_balances::Pallet::<Runtime>::reserved_balance(&who).saturating_sub(named_total);
+	push_explained(
+		&mut entries,
+		None,
+		native,
+		anonymous_total,
+		anonymous,
+		"multisig or other deposit".into(),
+	);
+
+	entries
+}
+
+/// The currently configured deposits of the features reserving balances.
+pub fn get_deposit_constants() -> Vec<DepositConstant<Balance>> {
+	let storage_deposit_per_byte =
+		convert_decimals_from_evm(<Runtime as module_evm::Config>::StorageDepositPerByte::get()).unwrap_or_default();
+	[
+		(
+			"proxy_deposit_base",
+			<Runtime as pallet_proxy::Config>::ProxyDepositBase::get(),
+		),
+		(
+			"proxy_deposit_factor",
+			<Runtime as pallet_proxy::Config>::ProxyDepositFactor::get(),
+		),
+		(
+			"announcement_deposit_base",
+			<Runtime as pallet_proxy::Config>::AnnouncementDepositBase::get(),
+		),
+		(
+			"announcement_deposit_factor",
+			<Runtime as pallet_proxy::Config>::AnnouncementDepositFactor::get(),
+		),
+		(
+			"multisig_deposit_base",
+			<Runtime as pallet_multisig::Config>::DepositBase::get(),
+		),
+		(
+			"multisig_deposit_factor",
+			<Runtime as pallet_multisig::Config>::DepositFactor::get(),
+		),
+		(
+			"honzon_deposit_per_authorization",
+			<Runtime as module_honzon::Config>::DepositPerAuthorization::get(),
+		),
+		(
+			"nft_create_class_deposit",
+			<Runtime as module_nft::Config>::CreateClassDeposit::get(),
+		),
+		(
+			"nft_create_token_deposit",
+			<Runtime as module_nft::Config>::CreateTokenDeposit::get(),
+		),
+		(
+			"nft_data_deposit_per_byte",
+			<Runtime as module_nft::Config>::DataDepositPerByte::get(),
+		),
+		(
+			"alternative_fee_swap_deposit",
+			<Runtime as module_transaction_payment::Config>::AlternativeFeeSwapDeposit::get(),
+		),
+		(
+			"evm_developer_deposit",
+			<Runtime as module_evm::Config>::DeveloperDeposit::get(),
+		),
+		("evm_storage_deposit_per_byte", storage_deposit_per_byte),
+		(
+			"collator_candidacy_bond",
+			module_collator_selection::CandidacyBond::<Runtime>::get(),
+		),
+		(
+			"oracle_operator_minimum_bond",
+			<Runtime as module_oracle_operator::Config>::MinimumBond::get(),
+		),
+	]
+	.into_iter()
+	.map(|(name, amount)| DepositConstant {
+		name: name.as_bytes().to_vec(),
+		amount,
+	})
+	.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::GetStableCurrencyId;
+	use frame_support::traits::{Currency, NamedReservableCurrency};
+	use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+	use parity_scale_codec::Decode;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		ext.execute_with(|| frame_system::Pallet::<Runtime>::set_block_number(1));
+		ext
+	}
+
+	fn sum(entries: &[ReserveEntry<Balance>], filter: impl Fn(&ReserveEntry<Balance>) -> bool) -> Balance {
+		entries
+			.iter()
+			.filter(|entry| filter(entry))
+			.map(|entry| entry.amount)
+			.sum()
+	}
+
+	#[test]
+	fn every_reserve_identifier_is_explained() {
+		new_test_ext().execute_with(|| {
+			let alice = AccountId::new([1u8; 32]);
+			let bob = AccountId::new([2u8; 32]);
+			let native = GetNativeCurrencyId::get();
+			let stable = GetStableCurrencyId::get();
+			let identifiers = (0..ReserveIdentifier::Count as u8)
+				.map(|index| ReserveIdentifier::decode(&mut &[index][..]).unwrap())
+				.collect::<Vec<_>>();
+
+			let _ = pallet_balances::Pallet::<Runtime>::deposit_creating(&alice, 1_000_000_000_000_000);
+			assert!(orml_tokens::Pallet::<Runtime>::deposit(stable, &alice, 1_000_000_000_000_000).is_ok());
+			for (index, id) in identifiers.iter().enumerate() {
+				let amount = (index as Balance + 1) * 1_000;
+				assert!(pallet_balances::Pallet::<Runtime>::reserve_named(id, &alice, amount).is_ok());
+				assert!(orml_tokens::Pallet::<Runtime>::reserve_named(id, stable, &alice, amount).is_ok());
+			}
+			module_honzon::Authorization::<Runtime>::insert(&alice, (stable, bob), 500);
+			pallet_proxy::Proxies::<Runtime>::insert(&alice, (Default::default(), 3_000));
+			assert!(pallet_balances::Pallet::<Runtime>::reserve(&alice, 3_700).is_ok());
+
+			let entries = get_reserve_breakdown(alice.clone());
+
+			for id in identifiers {
+				let native_reserved = pallet_balances::Pallet::<Runtime>::reserved_balance_named(&id, &alice);
+				let stable_reserved = orml_tokens::Pallet::<Runtime>::reserved_balance_named(&id, stable, &alice);
+				assert!(entries
+					.iter()
+					.any(|entry| entry.identifier == Some(id) && entry.currency_id == native));
+				assert_eq!(
+					sum(&entries, |entry| entry.identifier == Some(id)
+						&& entry.currency_id == native),
+					native_reserved
+				);
+				assert_eq!(
+					sum(&entries, |entry| entry.identifier == Some(id)
+						&& entry.currency_id == stable),
+					stable_reserved
+				);
+			}
+
+			assert!(entries
+				.iter()
+				.any(|entry| entry.identifier == Some(ReserveIdentifier::Honzon)
+					&& entry.amount == 500
+					&& entry.tag.starts_with(b"honzon authorization: ")));
+			assert!(entries.contains(&entry(None, native, 3_000, "proxy deposit".into())));
+			assert!(entries.contains(&entry(None, native, 700, "multisig or other deposit".into())));
+			assert_eq!(
+				sum(&entries, |entry| entry.currency_id == native),
+				pallet_balances::Pallet::<Runtime>::reserved_balance(&alice)
+			);
+		});
+	}
+
+	#[test]
+	fn deposit_constants_are_named() {
+		new_test_ext().execute_with(|| {
+			let constants = get_deposit_constants();
+			assert!(!constants.is_empty());
+			let mut names = constants
+				.iter()
+				.map(|constant| constant.name.clone())
+				.collect::<Vec<_>>();
+			names.sort();
+			names.dedup();
+			assert_eq!(names.len(), constants.len());
+			assert!(constants
+				.iter()
+				.any(|constant| constant.name == b"proxy_deposit_base".to_vec() && !constant.amount.is_zero()));
+		});
+	}
+}
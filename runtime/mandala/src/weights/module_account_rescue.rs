@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for module_liquid_crowdloan
+//! Autogenerated weights for module_account_rescue
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 35.0.1
 //! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
@@ -44,36 +44,22 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
-/// Weight functions for module_liquid_crowdloan.
+/// Weight functions for module_account_rescue.
 pub struct WeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> module_liquid_crowdloan::WeightInfo for WeightInfo<T> {
-	// Storage: `LiquidCrowdloan::RedeemCurrencyId` (r:1 w:0)
-	// Proof: `LiquidCrowdloan::RedeemCurrencyId` (`max_values`: Some(1), `max_size`: Some(43), added: 538, mode: `MaxEncodedLen`)
-	// Storage: `Tokens::Accounts` (r:3 w:3)
-	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
-	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
-	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	// Storage: `EvmAccounts::EvmAddresses` (r:2 w:0)
-	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
-	// Storage: `System::Account` (r:1 w:1)
+impl<T: frame_system::Config> module_account_rescue::WeightInfo for WeightInfo<T> {
+	// Storage: `System::Account` (r:3 w:3)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn redeem() -> Weight {
+	// Storage: `System::BlockHash` (r:1 w:0)
+	// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn rescue_account() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2964`
+		//  Measured:  `1874`
 		//  Estimated: `8856`
-		// Minimum execution time: 69_452 nanoseconds.
-		Weight::from_parts(70_667_000, 8856)
-			.saturating_add(T::DbWeight::get().reads(8))
+		// Minimum execution time: 118_304 nanoseconds.
+		Weight::from_parts(124_510_000, 8856)
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
-	// Storage: `LiquidCrowdloan::RedeemCurrencyId` (r:0 w:1)
-	// Proof: `LiquidCrowdloan::RedeemCurrencyId` (`max_values`: Some(1), `max_size`: Some(43), added: 538, mode: `MaxEncodedLen`)
-	fn set_redeem_currency_id() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1001`
-		//  Estimated: `0`
-		// Minimum execution time: 11_747 nanoseconds.
-		Weight::from_parts(11_946_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1))
-	}
 }
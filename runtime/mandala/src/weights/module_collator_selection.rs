@@ -232,4 +232,24 @@ impl<T: frame_system::Config> module_collator_selection::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(c.into()))
 	}
+	// Storage: `CollatorSelection::SmoothingFactor` (r:0 w:1)
+	// Proof: `CollatorSelection::SmoothingFactor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_smoothing_factor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1001`
+		//  Estimated: `0`
+		// Minimum execution time: 11_412 nanoseconds.
+		Weight::from_parts(11_705_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CollatorSelection::RewardDestinations` (r:0 w:1)
+	// Proof: `CollatorSelection::RewardDestinations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1001`
+		//  Estimated: `0`
+		// Minimum execution time: 12_104 nanoseconds.
+		Weight::from_parts(12_531_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -114,4 +114,50 @@ impl<T: frame_system::Config> module_emergency_shutdown::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 1770).saturating_mul(c.into()))
 	}
+	// Storage: `EmergencyShutdown::CanRefund` (r:1 w:0)
+	// Proof: `EmergencyShutdown::CanRefund` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `EmergencyShutdown::RefundSweep` (r:1 w:1)
+	// Proof: `EmergencyShutdown::RefundSweep` (`max_values`: Some(1), `max_size`: Some(276), added: 771, mode: `MaxEncodedLen`)
+	// Storage: `EmergencyShutdown::RefundOpenedAt` (r:1 w:0)
+	// Proof: `EmergencyShutdown::RefundOpenedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::NextTaskId` (r:1 w:1)
+	// Proof: `IdleScheduler::NextTaskId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `IdleScheduler::Tasks` (r:0 w:1)
+	// Proof: `IdleScheduler::Tasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn start_refund_sweep() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1211`
+		//  Estimated: `2696`
+		// Minimum execution time: 14_872 nanoseconds.
+		Weight::from_parts(15_391_000, 2696)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `EmergencyShutdown::RefundSweep` (r:1 w:1)
+	// Proof: `EmergencyShutdown::RefundSweep` (`max_values`: Some(1), `max_size`: Some(276), added: 771, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:401 w:401)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpEngine::CollateralParams` (r:3 w:0)
+	// Proof: `CdpEngine::CollateralParams` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:100 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:100 w:100)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn refund_sweep(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2156 + n * (251 ±0)`
+		//  Estimated: `8820 + n * (7866 ±0)`
+		// Minimum execution time: 21_384 nanoseconds.
+		Weight::from_parts(22_670_215, 8820)
+			// Standard Error: 41_362
+			.saturating_add(Weight::from_parts(43_518_406, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7866).saturating_mul(n.into()))
+	}
 }
@@ -0,0 +1,95 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_guardian
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_guardian.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_guardian::WeightInfo for WeightInfo<T> {
+	// Storage: `Guardian::GuardianConfigs` (r:1 w:1)
+	// Proof: `Guardian::GuardianConfigs` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	// Storage: `Guardian::GuardedUsage` (r:0 w:1)
+	// Proof: `Guardian::GuardedUsage` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn set_guardian() -> Weight {
+		Weight::from_parts(22_827_000, 3621)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Guardian::GuardianConfigs` (r:1 w:1)
+	// Proof: `Guardian::GuardianConfigs` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	// Storage: `Guardian::ExecutingFor` (r:1 w:0)
+	// Proof: `Guardian::ExecutingFor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	// Storage: `Guardian::GuardedUsage` (r:0 w:1)
+	// Proof: `Guardian::GuardedUsage` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn remove_guardian() -> Weight {
+		Weight::from_parts(21_295_000, 3621)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `Guardian::GuardianConfigs` (r:1 w:0)
+	// Proof: `Guardian::GuardianConfigs` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	// Storage: `Guardian::PendingActions` (r:1 w:1)
+	// Proof: `Guardian::PendingActions` (`max_values`: None, `max_size`: Some(778), added: 3253, mode: `MaxEncodedLen`)
+	fn announce() -> Weight {
+		Weight::from_parts(20_114_000, 4243)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Guardian::GuardianConfigs` (r:1 w:0)
+	// Proof: `Guardian::GuardianConfigs` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	// Storage: `Recovery::Recoverable` (r:1 w:0)
+	// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	// Storage: `Guardian::PendingActions` (r:1 w:1)
+	// Proof: `Guardian::PendingActions` (`max_values`: None, `max_size`: Some(778), added: 3253, mode: `MaxEncodedLen`)
+	fn approve_pending() -> Weight {
+		Weight::from_parts(23_929_000, 4243)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Guardian::GuardianConfigs` (r:1 w:0)
+	// Proof: `Guardian::GuardianConfigs` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	// Storage: `Recovery::Recoverable` (r:1 w:0)
+	// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	// Storage: `Guardian::PendingActions` (r:1 w:1)
+	// Proof: `Guardian::PendingActions` (`max_values`: None, `max_size`: Some(778), added: 3253, mode: `MaxEncodedLen`)
+	fn veto_pending() -> Weight {
+		Weight::from_parts(23_376_000, 4243)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Guardian::PendingActions` (r:1 w:1)
+	// Proof: `Guardian::PendingActions` (`max_values`: None, `max_size`: Some(778), added: 3253, mode: `MaxEncodedLen`)
+	// Storage: `Guardian::ExecutingFor` (r:1 w:1)
+	// Proof: `Guardian::ExecutingFor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn execute_pending() -> Weight {
+		Weight::from_parts(26_520_000, 4243)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
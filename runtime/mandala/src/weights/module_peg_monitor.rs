@@ -0,0 +1,70 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_peg_monitor
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_peg_monitor.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_peg_monitor::WeightInfo for WeightInfo<T> {
+	// Storage: `PegMonitor::PegSources` (r:1 w:0)
+	// Proof: `PegMonitor::PegSources` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:8 w:0)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:8 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:8 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `PegMonitor::PegHistory` (r:1 w:1)
+	// Proof: `PegMonitor::PegHistory` (`max_values`: Some(1), `max_size`: Some(1057), added: 1552, mode: `MaxEncodedLen`)
+	// Storage: `PegMonitor::DeviationThreshold` (r:1 w:0)
+	// Proof: `PegMonitor::DeviationThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 8]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(12_406_551, 3532)
+			.saturating_add(Weight::from_parts(9_183_213, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2601).saturating_mul(c.into()))
+	}
+	// Storage: `PegMonitor::PegSources` (r:0 w:1)
+	// Proof: `PegMonitor::PegSources` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 8]`.
+	fn set_peg_sources(c: u32, ) -> Weight {
+		Weight::from_parts(9_243_105, 0)
+			.saturating_add(Weight::from_parts(312_418, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `PegMonitor::DeviationThreshold` (r:0 w:1)
+	// Proof: `PegMonitor::DeviationThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_deviation_threshold() -> Weight {
+		Weight::from_parts(8_284_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for module_prices
+//! Autogenerated weights for module_preferences
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 35.0.1
 //! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
@@ -44,33 +44,17 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
-/// Weight functions for module_prices.
+/// Weight functions for module_preferences.
 pub struct WeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
-	// Storage: `AcalaOracle::Values` (r:1 w:0)
-	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
-	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
-	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `Prices::LockedPrice` (r:0 w:1)
-	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	fn lock_price() -> Weight {
+impl<T: frame_system::Config> module_preferences::WeightInfo for WeightInfo<T> {
+	/// The range of component `n` is `[1, 32]`.
+	fn import_preferences(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1460`
-		//  Estimated: `4925`
-		// Minimum execution time: 23_062 nanoseconds.
-		Weight::from_parts(23_660_000, 4925)
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
-	}
-	// Storage: `Prices::LockedPrice` (r:1 w:1)
-	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	fn unlock_price() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1160`
-		//  Estimated: `3532`
-		// Minimum execution time: 17_337 nanoseconds.
-		Weight::from_parts(17_763_000, 3532)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_812 nanoseconds.
+		Weight::from_parts(8_974_270, 0)
+			// Standard Error: 2_104
+			.saturating_add(Weight::from_parts(3_216_482, 0).saturating_mul(n.into()))
 	}
 }
@@ -0,0 +1,72 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_reference_rate
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_reference_rate.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_reference_rate::WeightInfo for WeightInfo<T> {
+	// Storage: `ReferenceRate::Components` (r:1 w:0)
+	// Proof: `ReferenceRate::Components` (`max_values`: Some(1), `max_size`: Some(273), added: 768, mode: `MaxEncodedLen`)
+	// Storage: `DexOracle::AveragePrices` (r:8 w:0)
+	// Proof: `DexOracle::AveragePrices` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Prices::LockedPrice` (r:8 w:0)
+	// Proof: `Prices::LockedPrice` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AcalaOracle::Values` (r:8 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `ReferenceRate::ReferenceRateHistory` (r:1 w:1)
+	// Proof: `ReferenceRate::ReferenceRateHistory` (`max_values`: Some(1), `max_size`: Some(1033), added: 1528, mode: `MaxEncodedLen`)
+	// Storage: `ReferenceRate::LastUpdatedRate` (r:1 w:1)
+	// Proof: `ReferenceRate::LastUpdatedRate` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	// Storage: `ReferenceRate::MovementThreshold` (r:1 w:0)
+	// Proof: `ReferenceRate::MovementThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 8]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(13_102_884, 3532)
+			.saturating_add(Weight::from_parts(9_457_306, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 2601).saturating_mul(c.into()))
+	}
+	// Storage: `ReferenceRate::Components` (r:0 w:1)
+	// Proof: `ReferenceRate::Components` (`max_values`: Some(1), `max_size`: Some(273), added: 768, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 8]`.
+	fn set_components(c: u32, ) -> Weight {
+		Weight::from_parts(9_318_447, 0)
+			.saturating_add(Weight::from_parts(338_105, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `ReferenceRate::MovementThreshold` (r:0 w:1)
+	// Proof: `ReferenceRate::MovementThreshold` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_movement_threshold() -> Weight {
+		Weight::from_parts(8_251_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
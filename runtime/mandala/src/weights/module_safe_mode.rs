@@ -0,0 +1,124 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_safe_mode
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_safe_mode.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_safe_mode::WeightInfo for WeightInfo<T> {
+	// Storage: `SafeMode::Params` (r:1 w:0)
+	// Proof: `SafeMode::Params` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	// Storage: `PegMonitor::PegHistory` (r:1 w:0)
+	// Proof: `PegMonitor::PegHistory` (`max_values`: Some(1), `max_size`: Some(1057), added: 1552, mode: `MaxEncodedLen`)
+	// Storage: `SafeMode::MonitoredCollaterals` (r:1 w:0)
+	// Proof: `SafeMode::MonitoredCollaterals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `AcalaOracle::Values` (r:8 w:0)
+	// Proof: `AcalaOracle::Values` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	// Storage: `SafeMode::PriceUpdatedAt` (r:8 w:8)
+	// Proof: `SafeMode::PriceUpdatedAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `CdpEngine::TotalLiquidatedValue` (r:1 w:0)
+	// Proof: `CdpEngine::TotalLiquidatedValue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `SafeMode::LiquidationWindow` (r:1 w:1)
+	// Proof: `SafeMode::LiquidationWindow` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `SafeMode::State` (r:1 w:0)
+	// Proof: `SafeMode::State` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 8]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(14_218_337, 3540)
+			.saturating_add(Weight::from_parts(4_126_590, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2569).saturating_mul(c.into()))
+	}
+	// Storage: `SafeMode::SafeModeCalls` (r:1 w:0)
+	// Proof: `SafeMode::SafeModeCalls` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PausedTransactions` (r:16 w:16)
+	// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `SafeMode::PausedBySafeMode` (r:0 w:1)
+	// Proof: `SafeMode::PausedBySafeMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `SafeMode::State` (r:0 w:1)
+	// Proof: `SafeMode::State` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 16]`.
+	fn enter_safe_mode(c: u32, ) -> Weight {
+		Weight::from_parts(11_352_460, 1663)
+			.saturating_add(Weight::from_parts(6_083_195, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(c.into()))
+	}
+	// Storage: `SafeMode::State` (r:1 w:1)
+	// Proof: `SafeMode::State` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `SafeMode::PausedBySafeMode` (r:1 w:1)
+	// Proof: `SafeMode::PausedBySafeMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PausedTransactions` (r:0 w:16)
+	// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 16]`.
+	fn exit_safe_mode(c: u32, ) -> Weight {
+		Weight::from_parts(10_649_082, 1675)
+			.saturating_add(Weight::from_parts(4_872_604, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 52).saturating_mul(c.into()))
+	}
+	// Storage: `SafeMode::Params` (r:0 w:1)
+	// Proof: `SafeMode::Params` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	fn set_trigger_params() -> Weight {
+		Weight::from_parts(7_592_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `SafeMode::MonitoredCollaterals` (r:1 w:1)
+	// Proof: `SafeMode::MonitoredCollaterals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `SafeMode::PriceUpdatedAt` (r:0 w:8)
+	// Proof: `SafeMode::PriceUpdatedAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 8]`.
+	fn set_monitored_collaterals(c: u32, ) -> Weight {
+		Weight::from_parts(9_487_215, 1598)
+			.saturating_add(Weight::from_parts(1_103_862, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 6).saturating_mul(c.into()))
+	}
+	// Storage: `SafeMode::State` (r:1 w:0)
+	// Proof: `SafeMode::State` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `SafeMode::SafeModeCalls` (r:0 w:1)
+	// Proof: `SafeMode::SafeModeCalls` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 16]`.
+	fn set_safe_mode_calls(c: u32, ) -> Weight {
+		Weight::from_parts(9_583_904, 1489)
+			.saturating_add(Weight::from_parts(1_238_451, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
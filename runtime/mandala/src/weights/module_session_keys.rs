@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for module_evm_accounts
+//! Autogenerated weights for module_session_keys
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 35.0.1
 //! DATE: 2024-04-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
@@ -44,39 +44,49 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
-/// Weight functions for module_evm_accounts.
+/// Weight functions for module_session_keys.
 pub struct WeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> module_evm_accounts::WeightInfo for WeightInfo<T> {
-	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:1)
-	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
-	// Storage: `EvmAccounts::Accounts` (r:1 w:1)
-	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
-	// Storage: `EVM::ChainId` (r:1 w:0)
-	// Proof: `EVM::ChainId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	// Storage: `System::BlockHash` (r:1 w:0)
-	// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
-	// Storage: `System::Account` (r:1 w:0)
+impl<T: frame_system::Config> module_session_keys::WeightInfo for WeightInfo<T> {
+	// Storage: `DappSessionKeys::SessionKeys` (r:1 w:1)
+	// Proof: `DappSessionKeys::SessionKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn claim_account() -> Weight {
+	/// The range of component `c` is `[1, 8]`.
+	fn register_session_key(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2236`
-		//  Estimated: `3721`
-		// Minimum execution time: 77_589 nanoseconds.
-		Weight::from_parts(78_537_000, 3721)
-			.saturating_add(T::DbWeight::get().reads(5))
+		//  Measured:  `1174`
+		//  Estimated: `4639`
+		// Minimum execution time: 33_862 nanoseconds.
+		Weight::from_parts(34_951_207, 4639)
+			// Standard Error: 6_134
+			.saturating_add(Weight::from_parts(1_187_326, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:1)
-	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
-	// Storage: `EvmAccounts::Accounts` (r:0 w:1)
-	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
-	fn claim_default_account() -> Weight {
+	// Storage: `DappSessionKeys::SessionKeys` (r:1 w:1)
+	// Proof: `DappSessionKeys::SessionKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn revoke_session_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1143`
-		//  Estimated: `3525`
-		// Minimum execution time: 18_959 nanoseconds.
-		Weight::from_parts(19_311_000, 3525)
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Measured:  `1413`
+		//  Estimated: `4878`
+		// Minimum execution time: 30_517 nanoseconds.
+		Weight::from_parts(31_284_000, 4878)
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: `DappSessionKeys::SessionKeys` (r:1 w:1)
+	// Proof: `DappSessionKeys::SessionKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn session_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1436`
+		//  Estimated: `6196`
+		// Minimum execution time: 21_749 nanoseconds.
+		Weight::from_parts(22_406_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,69 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2025 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for module_statistics
+//!
+//! These weights were not produced by the benchmark CLI. They are hand-estimated
+//! from comparable extrinsics and must be regenerated with `benchmark pallet`
+//! before this pallet is relied upon on a live chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_statistics.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_statistics::WeightInfo for WeightInfo<T> {
+	// Storage: `Statistics::TrackedSources` (r:1 w:0)
+	// Proof: `Statistics::TrackedSources` (`max_values`: Some(1), `max_size`: Some(145), added: 640, mode: `MaxEncodedLen`)
+	// Storage: `Homa::TotalStakingBonded` (r:1 w:0)
+	// Proof: `Homa::TotalStakingBonded` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Homa::ToBondPool` (r:1 w:0)
+	// Proof: `Homa::ToBondPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Homa::TotalVoidLiquid` (r:1 w:0)
+	// Proof: `Homa::TotalVoidLiquid` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:15 w:0)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Statistics::Checkpoints` (r:16 w:16)
+	// Proof: `Statistics::Checkpoints` (`max_values`: None, `max_size`: Some(820), added: 3295, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 16]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(6_284_117, 3532)
+			.saturating_add(Weight::from_parts(14_262_581, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 3439).saturating_mul(c.into()))
+	}
+	// Storage: `Statistics::TrackedSources` (r:1 w:1)
+	// Proof: `Statistics::TrackedSources` (`max_values`: Some(1), `max_size`: Some(145), added: 640, mode: `MaxEncodedLen`)
+	// Storage: `Statistics::Checkpoints` (r:0 w:16)
+	// Proof: `Statistics::Checkpoints` (`max_values`: None, `max_size`: Some(820), added: 3295, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 16]`.
+	fn set_tracked_sources(c: u32, ) -> Weight {
+		Weight::from_parts(43_575_304, 1630)
+			.saturating_add(Weight::from_parts(618_201, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(17))
+	}
+}
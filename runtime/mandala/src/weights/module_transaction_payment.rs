@@ -132,6 +132,16 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 	}
 	// Storage: `TransactionPause::PausedTransactions` (r:1 w:0)
 	// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn with_fee_preference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `841`
+		//  Estimated: `4306`
+		// Minimum execution time: 11_984 nanoseconds.
+		Weight::from_parts(12_512_000, 4306)
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	// Storage: `TransactionPause::PausedTransactions` (r:1 w:0)
+	// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn with_fee_aggregated_path() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `841`
@@ -140,6 +150,71 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 		Weight::from_parts(13_263_000, 4306)
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	// Storage: `TransactionPayment::FeeSpendTracking` (r:1 w:1)
+	// Proof: `TransactionPayment::FeeSpendTracking` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn enable_fee_spend_tracking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1442`
+		//  Estimated: `3910`
+		// Minimum execution time: 13_721 nanoseconds.
+		Weight::from_parts(14_170_000, 3910)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `TransactionPayment::FeeSpendTracking` (r:1 w:1)
+	// Proof: `TransactionPayment::FeeSpendTracking` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPayment::FeeSpends` (r:0 w:12)
+	// Proof: `TransactionPayment::FeeSpends` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[1, 12]`.
+	fn disable_fee_spend_tracking(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1459 + p * (4 ±0)`
+		//  Estimated: `3927 + p * (4 ±0)`
+		// Minimum execution time: 16_032 nanoseconds.
+		Weight::from_parts(15_657_813, 3927)
+			// Standard Error: 3_127
+			.saturating_add(Weight::from_parts(1_648_202, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 4).saturating_mul(p.into()))
+	}
+	// Storage: `TransactionPayment::TokenExchangeRate` (r:1 w:0)
+	// Proof: `TransactionPayment::TokenExchangeRate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPayment::FeePoolRebalanceParams` (r:0 w:1)
+	// Proof: `TransactionPayment::FeePoolRebalanceParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_fee_pool_rebalance_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1135`
+		//  Estimated: `3603`
+		// Minimum execution time: 14_215 nanoseconds.
+		Weight::from_parts(14_627_000, 3603)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `TransactionPayment::FeePoolRebalanceParams` (r:1 w:0)
+	// Proof: `TransactionPayment::FeePoolRebalanceParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPayment::PoolSize` (r:1 w:0)
+	// Proof: `TransactionPayment::PoolSize` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPayment::FeePoolRebalances` (r:1 w:1)
+	// Proof: `TransactionPayment::FeePoolRebalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:1)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn rebalance_fee_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2144`
+		//  Estimated: `8074`
+		// Minimum execution time: 96_603 nanoseconds.
+		Weight::from_parts(99_105_000, 8074)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
 	// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:1)
 	// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn on_finalize() -> Weight {
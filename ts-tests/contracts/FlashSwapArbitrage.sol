@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: GPL-3.0
+
+pragma solidity ^0.8.2;
+
+import "@openzeppelin/contracts/token/ERC20/IERC20.sol";
+
+interface IDEX {
+    function swapWithExactSupply(address who, address[] calldata path, uint256 supplyAmount, uint256 minTargetAmount) external returns (uint256);
+}
+
+// Example of the flash swap callback of `Dex::flash_swap`.
+// The target tokens are sold on another venue, then the repayment is left at `repayDest` for the DEX
+// to collect, and the profit is sent to the origin.
+contract FlashSwapArbitrage {
+    address constant DEX = 0x0000000000000000000000000000000000000405;
+
+    event FlashSwapArbitraged(address supplyToken, address targetToken, uint256 targetAmount, uint256 repayment);
+
+    // `data` is abi encoded `(address venue, bytes venueCall, uint256 shortfall, bool reenter)`:
+    // - `venue` is called with `venueCall` after approved to spend the target tokens.
+    // - `shortfall` is deducted from the repayment, used to test under-repayment.
+    // - `reenter` swaps back on the DEX in the callback, used to test the reentrancy guard.
+    function onFlashSwap(
+        address supplyToken,
+        address targetToken,
+        uint256 targetAmount,
+        uint256 repayment,
+        address repayDest,
+        bytes calldata data
+    ) public {
+        (address venue, bytes memory venueCall, uint256 shortfall, bool reenter) = abi.decode(data, (address, bytes, uint256, bool));
+
+        if (reenter) {
+            address[] memory path = new address[](2);
+            path[0] = targetToken;
+            path[1] = supplyToken;
+            IDEX(DEX).swapWithExactSupply(address(this), path, targetAmount, 0);
+        }
+
+        if (venue != address(0)) {
+            IERC20(targetToken).approve(venue, targetAmount);
+            (bool success, ) = venue.call(venueCall);
+            require(success, "venue call failed");
+        }
+
+        // the DEX collects the repayment from this contract
+        require(repayDest == address(this), "unexpected repay dest");
+        uint256 repaid = repayment - shortfall;
+        uint256 balance = IERC20(supplyToken).balanceOf(address(this));
+        require(balance >= repaid, "repay failed");
+        if (balance > repaid) {
+            require(IERC20(supplyToken).transfer(tx.origin, balance - repaid), "profit transfer failed");
+        }
+
+        emit FlashSwapArbitraged(supplyToken, targetToken, targetAmount, repayment);
+    }
+}